@@ -0,0 +1,612 @@
+//! Submodule generating the Rust code of structs mapping the rows of the
+//! tables of a database.
+//!
+//! Each table becomes a struct named after the table in PascalCase, prefixed
+//! by its schema when it has one other than `public`, with a public field per
+//! column named after the column in snake_case. The columns which may hold
+//! `NULL` become `Option` fields, and the documentation of the tables and of
+//! the columns becomes the doc comments of the structs and of their fields.
+//! Each struct comes with constants holding the names of its table and of its
+//! columns, so that queries can be written without repeating them as string
+//! literals. Enumeration types become Rust enums, with a method returning the
+//! SQL label of each variant.
+//!
+//! The data types are normalized under the dialect of the database, as by
+//! [`ColumnLike::normalized_type`], so that a MySQL `TINYINT(1)` becomes a
+//! `bool`, and mapped to Rust types through a [`TypeMapper`], by default a
+//! [`TypeMap`] mapping the data types without a Rust primitive to the types
+//! of the crates most commonly used for them, which the generated code then
+//! expects to be available:
+//!
+//! | SQL                        | Rust                            |
+//! |----------------------------|---------------------------------|
+//! | `DATE`                     | `chrono::NaiveDate`             |
+//! | `TIME`                     | `chrono::NaiveTime`             |
+//! | `TIMESTAMP`                | `chrono::NaiveDateTime`         |
+//! | `TIMESTAMP WITH TIME ZONE` | `chrono::DateTime<chrono::Utc>` |
+//! | `UUID`                     | `uuid::Uuid`                    |
+//! | `NUMERIC`                  | `rust_decimal::Decimal`         |
+//! | `JSON`, `JSONB`            | `serde_json::Value`             |
+//!
+//! The data types which are not recognized are mapped to `String`. The
+//! [`sea_orm`] submodule generates SeaORM entities with the same mapping.
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{codegen, prelude::*};
+//! use sqlparser::dialect::PostgreSqlDialect;
+//!
+//! let db = ParserDB::parse::<PostgreSqlDialect>(
+//!     "
+//! CREATE TYPE post_status AS ENUM ('draft', 'published');
+//! -- A post written by a user.
+//! CREATE TABLE posts (
+//!     id INT PRIMARY KEY,
+//!     title TEXT NOT NULL,
+//!     status post_status NOT NULL,
+//!     published_at TIMESTAMP
+//! );
+//! ",
+//! )?;
+//! let code = codegen::to_rust(&db);
+//! assert!(code.contains("pub enum PostStatus {\n    /// The `draft` label.\n    Draft,\n"));
+//! assert!(code.contains("/// A post written by a user.\n#[derive(Debug, Clone, PartialEq)]\n"));
+//! assert!(code.contains("    pub status: PostStatus,\n"));
+//! assert!(code.contains("    pub published_at: Option<chrono::NaiveDateTime>,\n"));
+//! assert!(code.contains("    pub const TABLE_NAME: &str = \"posts\";\n"));
+//! assert!(code.contains("    pub const PUBLISHED_AT_COLUMN: &str = \"published_at\";\n"));
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+pub mod sea_orm;
+mod type_mapper;
+
+pub use type_mapper::{TypeMap, TypeMapper};
+
+use crate::{
+    traits::{ColumnLike, CustomTypeLike, DatabaseLike, DialectLike, TableLike},
+    utils::rust_keywords::{RAW_KEYWORDS, RESERVED_KEYWORDS},
+};
+
+/// Options controlling the generated code.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     codegen::{self, CodegenOptions},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY);")?;
+/// let options = CodegenOptions::default().with_derive("serde::Serialize");
+/// let code = codegen::to_rust_with_options(&db, &options);
+/// assert!(code.contains("#[derive(Debug, Clone, PartialEq, serde::Serialize)]\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodegenOptions<M = TypeMap> {
+    /// Paths of the traits derived by the generated structs and enums.
+    derives: Vec<String>,
+    /// Mapper of the data types of the columns to the types of the fields.
+    type_mapper: M,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            derives: vec!["Debug".to_string(), "Clone".to_string(), "PartialEq".to_string()],
+            type_mapper: TypeMap::default(),
+        }
+    }
+}
+
+impl<M> CodegenOptions<M> {
+    /// Adds a trait to derive on the generated structs and enums, after
+    /// `Debug`, `Clone` and `PartialEq` which are derived by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `derive` - The path of the trait to derive.
+    #[must_use]
+    pub fn with_derive(mut self, derive: &str) -> Self {
+        self.derives.push(derive.to_string());
+        self
+    }
+
+    /// Removes the traits derived on the generated structs and enums,
+    /// including the default ones.
+    #[must_use]
+    pub fn without_derives(mut self) -> Self {
+        self.derives.clear();
+        self
+    }
+
+    /// Returns the paths of the traits derived on the generated structs and
+    /// enums.
+    pub fn derives(&self) -> impl Iterator<Item = &str> {
+        self.derives.iter().map(String::as_str)
+    }
+
+    /// Sets the mapper of the data types of the columns to the types of the
+    /// fields, which is a default [`TypeMap`] otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_mapper` - The mapper of the data types.
+    #[must_use]
+    pub fn with_type_mapper<N: TypeMapper>(self, type_mapper: N) -> CodegenOptions<N> {
+        CodegenOptions { derives: self.derives, type_mapper }
+    }
+
+    /// Returns the mapper of the data types of the columns to the types of
+    /// the fields.
+    #[must_use]
+    pub fn type_mapper(&self) -> &M {
+        &self.type_mapper
+    }
+}
+
+/// Generates the Rust code of the enums of the enumeration types and of the
+/// structs of the tables of the provided database.
+///
+/// The enums come first, in the order of [`DatabaseLike::custom_types`],
+/// followed by the structs, in the order of [`DatabaseLike::tables`].
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are mapped.
+#[must_use]
+pub fn to_rust<DB: DatabaseLike>(database: &DB) -> String {
+    to_rust_with_options(database, &CodegenOptions::default())
+}
+
+/// Same as [`to_rust`], generating the code following the provided options.
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are mapped.
+/// * `options` - The options controlling the generated code.
+#[must_use]
+pub fn to_rust_with_options<DB: DatabaseLike, M: TypeMapper>(
+    database: &DB,
+    options: &CodegenOptions<M>,
+) -> String {
+    let mut items: Vec<String> = database
+        .custom_types()
+        .filter(|custom_type| custom_type.is_enum())
+        .map(|custom_type| enum_code(database, custom_type, options))
+        .collect();
+    items.extend(database.tables().map(|table| table_struct(database, table, options)));
+    items.join("\n")
+}
+
+/// Generates the Rust code of the struct mapping the rows of the provided
+/// table, along with the constants holding the names of the table and of its
+/// columns.
+///
+/// # Arguments
+///
+/// * `database` - The database the table belongs to.
+/// * `table` - The table to map.
+/// * `options` - The options controlling the generated code.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     codegen::{self, CodegenOptions},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE tags (name TEXT NOT NULL);")?;
+/// let table = db.table(None, "tags").unwrap();
+/// assert_eq!(
+///     codegen::table_struct(&db, table, &CodegenOptions::default().without_derives()),
+///     "/// Row of the `tags` table.
+/// pub struct Tags {
+///     /// Value of the `name` column.
+///     pub name: String,
+/// }
+///
+/// impl Tags {
+///     /// Schema of the `tags` table, if any.
+///     pub const TABLE_SCHEMA: Option<&str> = None;
+///     /// Name of the `tags` table.
+///     pub const TABLE_NAME: &str = \"tags\";
+///     /// Names of the columns of the `tags` table.
+///     pub const COLUMN_NAMES: &[&str] = &[\"name\"];
+///     /// Name of the `name` column.
+///     pub const NAME_COLUMN: &str = \"name\";
+/// }
+/// "
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn table_struct<DB: DatabaseLike, M: TypeMapper>(
+    database: &DB,
+    table: &DB::Table,
+    options: &CodegenOptions<M>,
+) -> String {
+    let table_name = table.table_name();
+    let struct_name = table_type_name(database, table);
+    let mut code =
+        doc_comment(table.table_doc(database), &format!("Row of the `{table_name}` table."), "");
+    code.push_str(&derive_attribute(options));
+    let _ = writeln!(code, "pub struct {struct_name} {{");
+    let fields = field_names(database, table);
+    let mut column_names: Vec<&str> = Vec::new();
+    for (column, field) in table.columns(database).zip(&fields) {
+        let column_name = column.column_name();
+        code.push_str(&doc_comment(
+            column.column_doc(database),
+            &format!("Value of the `{column_name}` column."),
+            "    ",
+        ));
+        let field_type = field_type(database, column, options.type_mapper());
+        let _ = writeln!(code, "    pub {}: {field_type},", identifier(field));
+        column_names.push(column_name);
+    }
+    code.push_str("}\n\n");
+
+    let _ = writeln!(code, "impl {struct_name} {{");
+    let _ = writeln!(code, "    /// Schema of the `{table_name}` table, if any.");
+    let _ =
+        writeln!(code, "    pub const TABLE_SCHEMA: Option<&str> = {:?};", table.table_schema());
+    let _ = writeln!(code, "    /// Name of the `{table_name}` table.");
+    let _ = writeln!(code, "    pub const TABLE_NAME: &str = {table_name:?};");
+    let _ = writeln!(code, "    /// Names of the columns of the `{table_name}` table.");
+    let _ = writeln!(code, "    pub const COLUMN_NAMES: &[&str] = &{column_names:?};");
+    for (field, column_name) in fields.iter().zip(column_names) {
+        let _ = writeln!(code, "    /// Name of the `{column_name}` column.");
+        let _ = writeln!(
+            code,
+            "    pub const {}_COLUMN: &str = {column_name:?};",
+            field.to_ascii_uppercase()
+        );
+    }
+    code.push_str("}\n");
+    code
+}
+
+/// Generates the Rust code of the enum of the provided enumeration type,
+/// along with the method returning the SQL labels of its variants.
+fn enum_code<DB: DatabaseLike, M>(
+    database: &DB,
+    custom_type: &DB::CustomType,
+    options: &CodegenOptions<M>,
+) -> String {
+    let enum_name = custom_type_name(database, custom_type);
+    let mut code = format!("/// Variants of the `{}` enumeration.\n", custom_type.name());
+    code.push_str(&derive_attribute(options));
+    let _ = writeln!(code, "pub enum {enum_name} {{");
+    let mut variants: Vec<String> = Vec::new();
+    let mut labels: Vec<&str> = Vec::new();
+    for label in custom_type.enum_variants() {
+        let variant = unique_name(&identifier(&pascal_case(label)), &variants);
+        let _ = writeln!(code, "    /// The `{label}` label.\n    {variant},");
+        variants.push(variant);
+        labels.push(label);
+    }
+    code.push_str("}\n\n");
+
+    let _ = writeln!(code, "impl {enum_name} {{");
+    code.push_str("    /// Returns the SQL label of the variant.\n");
+    code.push_str("    #[must_use]\n");
+    code.push_str("    pub const fn as_str(&self) -> &'static str {\n");
+    code.push_str("        match *self {\n");
+    for (variant, label) in variants.iter().zip(labels) {
+        let _ = writeln!(code, "            Self::{variant} => {label:?},");
+    }
+    code.push_str("        }\n    }\n}\n");
+    code
+}
+
+/// Returns the names of the fields of the columns of the provided table, in
+/// snake_case and made unique by a numeric suffix.
+fn field_names<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    for column in table.columns(database) {
+        let field = unique_name(&snake_case(column.column_name()), &fields);
+        fields.push(field);
+    }
+    fields
+}
+
+/// Returns the Rust type of the field of the provided column, mapping its
+/// normalized data type with the provided mapper.
+fn field_type<DB: DatabaseLike, M: TypeMapper>(
+    database: &DB,
+    column: &DB::Column,
+    type_mapper: &M,
+) -> String {
+    let enumeration = column.custom_type(database).filter(|custom_type| custom_type.is_enum());
+    let field_type = match enumeration {
+        Some(custom_type) => custom_type_name(database, custom_type),
+        None => {
+            let data_type = column.normalized_type(database);
+            match data_type.trim().strip_suffix("[]") {
+                Some(element_type) => format!("Vec<{}>", type_mapper.rust_type(element_type)),
+                None => type_mapper.rust_type(data_type),
+            }
+        }
+    };
+    if column.is_nullable(database) { format!("Option<{field_type}>") } else { field_type }
+}
+
+/// Renders the provided documentation as a doc comment with the provided
+/// indentation, falling back to the provided default when there is none.
+fn doc_comment(doc: Option<&str>, default: &str, indentation: &str) -> String {
+    let mut comment = String::new();
+    for line in doc.unwrap_or(default).lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            let _ = writeln!(comment, "{indentation}///");
+        } else {
+            let _ = writeln!(comment, "{indentation}/// {line}");
+        }
+    }
+    comment
+}
+
+/// Renders the derive attribute of the provided options, or nothing if they
+/// derive no trait.
+fn derive_attribute<M>(options: &CodegenOptions<M>) -> String {
+    if options.derives.is_empty() {
+        String::new()
+    } else {
+        format!("#[derive({})]\n", options.derives.join(", "))
+    }
+}
+
+/// Returns the name of the Rust type of an object, in PascalCase, prefixed
+/// by the provided schema, if any.
+fn type_name(schema: Option<&str>, name: &str) -> String {
+    let mut type_name = schema.map_or_else(String::new, pascal_case);
+    type_name.push_str(&pascal_case(name));
+    identifier(&type_name)
+}
+
+/// Returns the name of the Rust type of the provided table, prefixed by its
+/// schema when it has one other than `public`.
+fn table_type_name<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> String {
+    let casing = database.dialect().identifier_casing();
+    type_name(
+        casing.non_default_schema(table.table_schema(), table.table_schema_is_quoted()),
+        table.table_name(),
+    )
+}
+
+/// Returns the name of the Rust type of the provided custom type, prefixed
+/// by its schema when it has one other than `public`.
+fn custom_type_name<DB: DatabaseLike>(database: &DB, custom_type: &DB::CustomType) -> String {
+    let casing = database.dialect().identifier_casing();
+    type_name(
+        casing.non_default_schema(custom_type.schema(), custom_type.schema_is_quoted()),
+        custom_type.name(),
+    )
+}
+
+/// Returns the provided name, suffixed by the first number making it
+/// different from all of the provided names.
+fn unique_name(candidate: &str, names: &[String]) -> String {
+    let mut name = candidate.to_string();
+    let mut suffix = 1;
+    while names.contains(&name) {
+        suffix += 1;
+        name = format!("{candidate}_{suffix}");
+    }
+    name
+}
+
+/// Returns the provided name as a Rust identifier, as a raw identifier when
+/// it is a keyword, or followed by an underscore when it is a keyword which
+/// cannot be a raw identifier.
+fn identifier(name: &str) -> String {
+    if RAW_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else if RESERVED_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Converts the provided name to PascalCase, as in `BlogPosts` for
+/// `blog_posts`.
+fn pascal_case(name: &str) -> String {
+    let mut pascal_case = String::with_capacity(name.len());
+    for word in name.split(|character: char| !character.is_ascii_alphanumeric()) {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            pascal_case.push(first.to_ascii_uppercase());
+            pascal_case.push_str(characters.as_str());
+        }
+    }
+    if pascal_case.is_empty()
+        || pascal_case.starts_with(|character: char| character.is_ascii_digit())
+    {
+        pascal_case.insert(0, '_');
+    }
+    pascal_case
+}
+
+/// Converts the provided name to snake_case, as in `display_name` for
+/// `displayName`, replacing the characters Rust identifiers do not allow with
+/// underscores.
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+    let mut previous: Option<char> = None;
+    for character in name.chars() {
+        if character.is_ascii_uppercase() {
+            if previous
+                .is_some_and(|previous| previous.is_ascii_lowercase() || previous.is_ascii_digit())
+            {
+                snake_case.push('_');
+            }
+            snake_case.push(character.to_ascii_lowercase());
+        } else if character.is_ascii_alphanumeric() {
+            snake_case.push(character);
+        } else {
+            snake_case.push('_');
+        }
+        previous = Some(character);
+    }
+    if snake_case.is_empty() || snake_case.starts_with(|character: char| character.is_ascii_digit())
+    {
+        snake_case.insert(0, '_');
+    }
+    snake_case
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    #[test]
+    fn test_table_struct() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TYPE app.visibility AS ENUM ('public', 'self', 'in review');
+            CREATE TABLE app.user_posts (
+                id BIGINT PRIMARY KEY,
+                \"type\" TEXT NOT NULL,
+                \"authorId\" INT,
+                author_id UUID NOT NULL,
+                scores REAL[] NOT NULL,
+                visibility app.visibility
+            );",
+        )
+        .unwrap();
+        assert_eq!(
+            to_rust_with_options(&db, &CodegenOptions::default().with_derive("Eq")),
+            "/// Variants of the `visibility` enumeration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppVisibility {
+    /// The `public` label.
+    Public,
+    /// The `self` label.
+    Self_,
+    /// The `in review` label.
+    InReview,
+}
+
+impl AppVisibility {
+    /// Returns the SQL label of the variant.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Public => \"public\",
+            Self::Self_ => \"self\",
+            Self::InReview => \"in review\",
+        }
+    }
+}
+
+/// Row of the `user_posts` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppUserPosts {
+    /// Value of the `id` column.
+    pub id: i64,
+    /// Value of the `type` column.
+    pub r#type: String,
+    /// Value of the `authorId` column.
+    pub author_id: Option<i32>,
+    /// Value of the `author_id` column.
+    pub author_id_2: uuid::Uuid,
+    /// Value of the `scores` column.
+    pub scores: Vec<f32>,
+    /// Value of the `visibility` column.
+    pub visibility: Option<AppVisibility>,
+}
+
+impl AppUserPosts {
+    /// Schema of the `user_posts` table, if any.
+    pub const TABLE_SCHEMA: Option<&str> = Some(\"app\");
+    /// Name of the `user_posts` table.
+    pub const TABLE_NAME: &str = \"user_posts\";
+    /// Names of the columns of the `user_posts` table.
+    pub const COLUMN_NAMES: &[&str] = &[\"id\", \"type\", \"authorId\", \"author_id\", \"scores\", \"visibility\"];
+    /// Name of the `id` column.
+    pub const ID_COLUMN: &str = \"id\";
+    /// Name of the `type` column.
+    pub const TYPE_COLUMN: &str = \"type\";
+    /// Name of the `authorId` column.
+    pub const AUTHOR_ID_COLUMN: &str = \"authorId\";
+    /// Name of the `author_id` column.
+    pub const AUTHOR_ID_2_COLUMN: &str = \"author_id\";
+    /// Name of the `scores` column.
+    pub const SCORES_COLUMN: &str = \"scores\";
+    /// Name of the `visibility` column.
+    pub const VISIBILITY_COLUMN: &str = \"visibility\";
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_documented_table() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "-- Registered users.
+            CREATE TABLE users (
+                -- Unique identifier of the user.
+                id INT PRIMARY KEY
+            );",
+        )
+        .unwrap();
+        let code = to_rust(&db);
+        assert!(code.starts_with("/// Registered users.\n#[derive(Debug, Clone, PartialEq)]\n"));
+        assert!(code.contains("    /// Unique identifier of the user.\n    pub id: i32,\n"));
+    }
+
+    #[test]
+    fn test_mysql_normalized_types() {
+        let db = ParserDB::parse::<MySqlDialect>(
+            "CREATE TABLE flags (
+                id INT PRIMARY KEY,
+                active TINYINT(1) NOT NULL,
+                score FLOAT NOT NULL,
+                body LONGTEXT NOT NULL,
+                created DATETIME
+            );",
+        )
+        .unwrap();
+        let code = to_rust(&db);
+        assert!(code.contains("    pub active: bool,\n"));
+        assert!(code.contains("    pub score: f32,\n"));
+        assert!(code.contains("    pub body: String,\n"));
+        assert!(code.contains("    pub created: Option<chrono::NaiveDateTime>,\n"));
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(snake_case("displayName"), "display_name");
+        assert_eq!(snake_case("userID"), "user_id");
+        assert_eq!(snake_case("unit price"), "unit_price");
+        assert_eq!(snake_case("2fa"), "_2fa");
+        assert_eq!(identifier("match"), "r#match");
+        assert_eq!(identifier("crate"), "crate_");
+        assert_eq!(type_name(None, "order_items"), "OrderItems");
+        assert_eq!(type_name(Some("app"), "order_items"), "AppOrderItems");
+        assert_eq!(
+            doc_comment(Some("First.\n\nSecond."), "", ""),
+            "/// First.\n///\n/// Second.\n"
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Submodule generating the [SeaORM] entities of the tables of a database.
+//!
+//! Each table with a primary key becomes the module of an entity, in the
+//! layout written by `sea-orm-cli generate entity`: a `Model` struct with a
+//! field per column, typed through the [`TypeMapper`] of the options, and a
+//! `Relation` enum with a relation per foreign key of the table and per
+//! foreign key referencing it. The tables without a primary key, which
+//! SeaORM cannot represent, are skipped. Enumeration types become the active
+//! enums of the `sea_orm_active_enums` module.
+//!
+//! SeaORM derives a single `Related` implementation between two entities, so
+//! only the foreign keys linking two tables no other foreign key links get
+//! one, along with the reverse `has_many` or `has_one` relation.
+//!
+//! The generated structs derive the traits of the options along with the
+//! SeaORM ones, and SeaORM requires them to derive at least `Debug`, `Clone`
+//! and `PartialEq`.
+//!
+//! [SeaORM]: https://www.sea-ql.org/SeaORM
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{
+//!     codegen::{CodegenOptions, sea_orm},
+//!     prelude::*,
+//! };
+//!
+//! let db = ParserDB::parse::<GenericDialect>(
+//!     "
+//! CREATE TABLE users (id SERIAL PRIMARY KEY, email TEXT NOT NULL UNIQUE);
+//! CREATE TABLE posts (id INT PRIMARY KEY, author_id INT NOT NULL REFERENCES users (id));
+//! ",
+//! )?;
+//! let files = sea_orm::entities(&db, &CodegenOptions::default());
+//! assert_eq!(
+//!     files.keys().map(String::as_str).collect::<Vec<_>>(),
+//!     ["mod.rs", "posts.rs", "prelude.rs", "users.rs"]
+//! );
+//! assert!(files["users.rs"].contains("    #[sea_orm(unique)]\n    pub email: String,\n"));
+//! assert!(files["users.rs"].contains("    #[sea_orm(has_many = \"super::posts::Entity\")]\n"));
+//! assert!(files["prelude.rs"].contains("pub use super::users::Entity as Users;\n"));
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use super::{
+    CodegenOptions, TypeMapper, custom_type_name, doc_comment, field_names, field_type, identifier,
+    pascal_case, snake_case, table_type_name, unique_name,
+};
+use crate::traits::{
+    ColumnLike, CustomTypeLike, DatabaseLike, DialectLike, ForeignKeyLike, IndexLike, TableLike,
+    UniqueIndexLike,
+};
+
+/// Generates the files of the SeaORM entities of the tables of the provided
+/// database, keyed by their names: a file per entity, the `mod.rs` file
+/// declaring their modules, the `prelude.rs` file re-exporting them, and the
+/// `sea_orm_active_enums.rs` file when the database has enumeration types.
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are mapped.
+/// * `options` - The options controlling the generated code.
+#[must_use]
+pub fn entities<DB: DatabaseLike, M: TypeMapper>(
+    database: &DB,
+    options: &CodegenOptions<M>,
+) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    let mut modules = String::from("//! SeaORM entities of the tables of the database.\n\n");
+    modules.push_str("pub mod prelude;\n\n");
+    if let Some(active_enums) = active_enums::<DB, M>(database, options) {
+        modules.push_str("pub mod sea_orm_active_enums;\n");
+        files.insert("sea_orm_active_enums.rs".to_string(), active_enums);
+    }
+    let mut prelude =
+        String::from("//! Entities of the tables of the database, named after their tables.\n\n");
+    for table in database.tables().filter(|table| has_primary_key(database, table)) {
+        let module = module_name(database, table);
+        let _ = writeln!(modules, "pub mod {module};");
+        let _ = writeln!(
+            prelude,
+            "pub use super::{module}::Entity as {};",
+            table_type_name(database, table)
+        );
+        files.insert(
+            format!("{}.rs", module.trim_start_matches("r#")),
+            entity(database, table, options),
+        );
+    }
+    files.insert("mod.rs".to_string(), modules);
+    files.insert("prelude.rs".to_string(), prelude);
+    files
+}
+
+/// Generates the SeaORM entity of the provided table, which is expected to
+/// have a primary key.
+///
+/// # Arguments
+///
+/// * `database` - The database the table belongs to.
+/// * `table` - The table to map.
+/// * `options` - The options controlling the generated code.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     codegen::{CodegenOptions, sea_orm},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id SERIAL PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT NOT NULL REFERENCES users (id));
+/// ",
+/// )?;
+/// let posts = db.table(None, "posts").unwrap();
+/// assert_eq!(
+///     sea_orm::entity(&db, posts, &CodegenOptions::default()),
+///     "//! SeaORM entity of the `posts` table.
+///
+/// use sea_orm::entity::prelude::*;
+///
+/// /// Row of the `posts` table.
+/// #[derive(Debug, Clone, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = \"posts\")]
+/// pub struct Model {
+///     /// Value of the `id` column.
+///     #[sea_orm(primary_key, auto_increment = false)]
+///     pub id: i32,
+///     /// Value of the `author_id` column.
+///     pub author_id: i32,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {
+///     #[sea_orm(
+///         belongs_to = \"super::users::Entity\",
+///         from = \"Column::AuthorId\",
+///         to = \"super::users::Column::Id\"
+///     )]
+///     Users,
+/// }
+///
+/// impl Related<super::users::Entity> for Entity {
+///     fn to() -> RelationDef {
+///         Relation::Users.def()
+///     }
+/// }
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// "
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn entity<DB: DatabaseLike, M: TypeMapper>(
+    database: &DB,
+    table: &DB::Table,
+    options: &CodegenOptions<M>,
+) -> String {
+    let table_name = table.table_name();
+    let mut code = format!("//! SeaORM entity of the `{table_name}` table.\n\n");
+    code.push_str("use sea_orm::entity::prelude::*;\n");
+    let active_enums: BTreeSet<String> = table
+        .columns(database)
+        .filter_map(|column| column.custom_type(database))
+        .filter(|custom_type| custom_type.is_enum())
+        .map(|custom_type| custom_type_name(database, custom_type))
+        .collect();
+    if !active_enums.is_empty() {
+        code.push('\n');
+        for active_enum in &active_enums {
+            let _ = writeln!(code, "use super::sea_orm_active_enums::{active_enum};");
+        }
+    }
+
+    code.push('\n');
+    code.push_str(&doc_comment(
+        table.table_doc(database),
+        &format!("Row of the `{table_name}` table."),
+        "",
+    ));
+    let _ = writeln!(code, "#[derive({})]", derives(options, &["DeriveEntityModel"]));
+    let schema = table
+        .table_schema()
+        .map_or_else(String::new, |schema| format!(", schema_name = {schema:?}"));
+    let _ = writeln!(code, "#[sea_orm(table_name = {table_name:?}{schema})]");
+    code.push_str("pub struct Model {\n");
+    for (column, field) in table.columns(database).zip(field_names(database, table)) {
+        let column_name = column.column_name();
+        code.push_str(&doc_comment(
+            column.column_doc(database),
+            &format!("Value of the `{column_name}` column."),
+            "    ",
+        ));
+        let mut attributes: Vec<String> = Vec::new();
+        if column.is_primary_key(database) {
+            attributes.push("primary_key".to_string());
+            if !column.is_auto_generated(database) {
+                attributes.push("auto_increment = false".to_string());
+            }
+        } else if is_unique(database, column) {
+            attributes.push("unique".to_string());
+        }
+        if field != column_name {
+            attributes.push(format!("column_name = {column_name:?}"));
+        }
+        if !attributes.is_empty() {
+            let _ = writeln!(code, "    #[sea_orm({})]", attributes.join(", "));
+        }
+        let field_type = field_type(database, column, options.type_mapper());
+        let _ = writeln!(code, "    pub {}: {field_type},", identifier(&field));
+    }
+    code.push_str("}\n\n");
+    code.push_str(&relations(database, table));
+    code.push_str("\nimpl ActiveModelBehavior for ActiveModel {}\n");
+    code
+}
+
+/// Generates the active enums of the enumeration types of the provided
+/// database, or nothing if it has none.
+fn active_enums<DB: DatabaseLike, M>(database: &DB, options: &CodegenOptions<M>) -> Option<String> {
+    let active_enums: Vec<String> = database
+        .custom_types()
+        .filter(|custom_type| custom_type.is_enum())
+        .map(|custom_type| {
+            let mut code = format!("/// Variants of the `{}` enumeration.\n", custom_type.name());
+            let _ = writeln!(
+                code,
+                "#[derive({})]",
+                derives(options, &["EnumIter", "DeriveActiveEnum"])
+            );
+            let _ = writeln!(
+                code,
+                "#[sea_orm(rs_type = \"String\", db_type = \"Enum\", enum_name = {:?})]",
+                custom_type.name()
+            );
+            let _ = writeln!(code, "pub enum {} {{", custom_type_name(database, custom_type));
+            let mut variants: Vec<String> = Vec::new();
+            for label in custom_type.enum_variants() {
+                let variant = unique_name(&identifier(&pascal_case(label)), &variants);
+                let _ = writeln!(code, "    /// The `{label}` label.");
+                let _ = writeln!(code, "    #[sea_orm(string_value = {label:?})]\n    {variant},");
+                variants.push(variant);
+            }
+            code.push_str("}\n");
+            code
+        })
+        .collect();
+    if active_enums.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "//! SeaORM active enums of the enumeration types of the database.\n\n\
+         use sea_orm::entity::prelude::*;\n\n{}",
+        active_enums.join("\n")
+    ))
+}
+
+/// Generates the `Relation` enum of the entity of the provided table, along
+/// with the `Related` implementations of the entities it is linked to by a
+/// single foreign key.
+fn relations<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> String {
+    // The variant, the attribute and the related entity of each relation.
+    let mut relations: Vec<(String, String, Option<String>)> = Vec::new();
+    for foreign_key in table.foreign_keys(database) {
+        let Some(referenced_table) = foreign_key.referenced_table(database) else {
+            continue;
+        };
+        if !has_primary_key(database, referenced_table) {
+            continue;
+        }
+        let (variant, entity, columns) = if foreign_key.is_self_referential(database) {
+            ("SelfRef".to_string(), "Entity".to_string(), "Column".to_string())
+        } else {
+            let module = module_name(database, referenced_table);
+            (
+                table_type_name(database, referenced_table),
+                format!("super::{module}::Entity"),
+                format!("super::{module}::Column"),
+            )
+        };
+        let from = column_variants(database, table, foreign_key.host_columns(database), "Column");
+        let to = column_variants(
+            database,
+            referenced_table,
+            foreign_key.referenced_columns(database),
+            &columns,
+        );
+        let mut attribute = String::from("    #[sea_orm(\n");
+        let _ = writeln!(attribute, "        belongs_to = \"{entity}\",");
+        let _ = writeln!(attribute, "        from = \"{from}\",");
+        let _ = writeln!(attribute, "        to = \"{to}\"\n    )]");
+        let related = (!foreign_key.is_self_referential(database)
+            && links(database, table, referenced_table) == 1)
+            .then_some(entity);
+        relations.push((variant, attribute, related));
+    }
+    for foreign_key in database.tables().flat_map(|table| table.foreign_keys(database)) {
+        let host_table = foreign_key.host_table(database);
+        if foreign_key.referenced_table(database) != Some(table)
+            || foreign_key.is_self_referential(database)
+            || !has_primary_key(database, host_table)
+            || links(database, table, host_table) != 1
+        {
+            continue;
+        }
+        let kind = if foreign_key.is_host_primary_key(database) { "has_one" } else { "has_many" };
+        let entity = format!("super::{}::Entity", module_name(database, host_table));
+        relations.push((
+            table_type_name(database, host_table),
+            format!("    #[sea_orm({kind} = \"{entity}\")]\n"),
+            Some(entity),
+        ));
+    }
+
+    let mut code = String::from("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
+    code.push_str("pub enum Relation {\n");
+    let mut variants: Vec<String> = Vec::new();
+    let mut related_entities = String::new();
+    for (variant, attribute, related) in relations {
+        let variant = unique_variant(&variant, &variants);
+        code.push_str(&attribute);
+        let _ = writeln!(code, "    {variant},");
+        if let Some(entity) = related {
+            let _ = writeln!(
+                related_entities,
+                "\nimpl Related<{entity}> for Entity {{\n    fn to() -> RelationDef {{\n        \
+                 Relation::{variant}.def()\n    }}\n}}"
+            );
+        }
+        variants.push(variant);
+    }
+    code.push_str("}\n");
+    code.push_str(&related_entities);
+    code
+}
+
+/// Returns the `Column` variants of the provided columns of the provided
+/// table, prefixed by the provided path, as a tuple when there are several.
+fn column_variants<'db, DB: DatabaseLike + 'db>(
+    database: &'db DB,
+    table: &'db DB::Table,
+    columns: impl Iterator<Item = &'db DB::Column>,
+    path: &str,
+) -> String {
+    let fields = field_names(database, table);
+    let variants: Vec<String> = columns
+        .filter_map(|column| {
+            let position = table.columns(database).position(|other| other == column)?;
+            Some(format!("{path}::{}", pascal_case(&fields[position])))
+        })
+        .collect();
+    if variants.len() == 1 { variants.concat() } else { format!("({})", variants.join(", ")) }
+}
+
+/// Returns the number of foreign keys between the two provided tables, in
+/// either direction.
+fn links<DB: DatabaseLike>(database: &DB, table: &DB::Table, other: &DB::Table) -> usize {
+    database
+        .tables()
+        .flat_map(|table| table.foreign_keys(database))
+        .filter(|foreign_key| {
+            let host_table = foreign_key.host_table(database);
+            let referenced_table = foreign_key.referenced_table(database);
+            (host_table == table && referenced_table == Some(other))
+                || (host_table == other && referenced_table == Some(table))
+        })
+        .count()
+}
+
+/// Returns the provided variant, suffixed by the first number from 2 making
+/// it different from all of the provided variants.
+fn unique_variant(candidate: &str, variants: &[String]) -> String {
+    let mut variant = candidate.to_string();
+    let mut suffix = 1;
+    while variants.contains(&variant) {
+        suffix += 1;
+        variant = format!("{candidate}{suffix}");
+    }
+    variant
+}
+
+/// Returns the traits derived by the provided options, followed by the
+/// provided SeaORM ones.
+fn derives<M>(options: &CodegenOptions<M>, sea_orm_derives: &[&str]) -> String {
+    options.derives().chain(sea_orm_derives.iter().copied()).collect::<Vec<_>>().join(", ")
+}
+
+/// Returns the name of the module of the entity of the provided table, in
+/// snake_case, prefixed by its schema when it has one other than `public`.
+fn module_name<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> String {
+    let mut module = database
+        .dialect()
+        .identifier_casing()
+        .non_default_schema(table.table_schema(), table.table_schema_is_quoted())
+        .map_or_else(String::new, |schema| format!("{}_", snake_case(schema)));
+    module.push_str(&snake_case(table.table_name()));
+    identifier(&module)
+}
+
+/// Returns whether the provided table has a primary key.
+fn has_primary_key<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> bool {
+    table.primary_key_columns(database).next().is_some()
+}
+
+/// Returns whether the provided column is unique on its own, without being
+/// its primary key.
+fn is_unique<DB: DatabaseLike>(database: &DB, column: &DB::Column) -> bool {
+    column.unique_indices(database).any(|unique_index| {
+        !unique_index.is_primary_key(database) && unique_index.columns(database).count() == 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{codegen::TypeMap, structs::ParserDB};
+
+    #[test]
+    fn test_entities() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TYPE app.role AS ENUM ('reader', 'writer');
+            CREATE TABLE app.users (
+                id UUID PRIMARY KEY,
+                \"type\" app.role NOT NULL,
+                manager_id UUID REFERENCES app.users (id)
+            );
+            CREATE TABLE app.profiles (id UUID PRIMARY KEY REFERENCES app.users (id));
+            CREATE TABLE app.messages (
+                id BIGSERIAL PRIMARY KEY,
+                sender_id UUID NOT NULL REFERENCES app.users (id),
+                recipient_id UUID NOT NULL REFERENCES app.users (id)
+            );
+            CREATE TABLE logs (message TEXT);",
+        )
+        .unwrap();
+        let options = CodegenOptions::default()
+            .with_type_mapper(TypeMap::default().with_mapping("UUID", "String"));
+        let files = entities(&db, &options);
+        assert_eq!(
+            files.keys().map(String::as_str).collect::<Vec<_>>(),
+            [
+                "app_messages.rs",
+                "app_profiles.rs",
+                "app_users.rs",
+                "mod.rs",
+                "prelude.rs",
+                "sea_orm_active_enums.rs"
+            ]
+        );
+        assert_eq!(
+            files["app_users.rs"],
+            "//! SeaORM entity of the `users` table.
+
+use sea_orm::entity::prelude::*;
+
+use super::sea_orm_active_enums::AppRole;
+
+/// Row of the `users` table.
+#[derive(Debug, Clone, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = \"users\", schema_name = \"app\")]
+pub struct Model {
+    /// Value of the `id` column.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    /// Value of the `type` column.
+    pub r#type: AppRole,
+    /// Value of the `manager_id` column.
+    pub manager_id: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = \"Entity\",
+        from = \"Column::ManagerId\",
+        to = \"Column::Id\"
+    )]
+    SelfRef,
+    #[sea_orm(has_one = \"super::app_profiles::Entity\")]
+    AppProfiles,
+}
+
+impl Related<super::app_profiles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AppProfiles.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+"
+        );
+        let messages = &files["app_messages.rs"];
+        assert!(messages.contains("    #[sea_orm(primary_key)]\n    pub id: i64,\n"));
+        assert!(messages.contains("    AppUsers,\n"));
+        assert!(messages.contains("    AppUsers2,\n"));
+        assert!(!messages.contains("impl Related"));
+        assert!(files["sea_orm_active_enums.rs"].contains(
+            "    /// The `writer` label.\n    #[sea_orm(string_value = \"writer\")]\n    Writer,\n"
+        ));
+        assert_eq!(
+            files["mod.rs"],
+            "//! SeaORM entities of the tables of the database.
+
+pub mod prelude;
+
+pub mod sea_orm_active_enums;
+pub mod app_messages;
+pub mod app_profiles;
+pub mod app_users;
+"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Submodule providing the mapping of SQL data types to the Rust types of
+//! their values, shared by the code generation backends.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use crate::utils::data_type_aliases::canonical_data_type;
+
+/// Rust types of the values of the SQL data types mapped by default, keyed by
+/// the names their aliases resolve to.
+const DEFAULT_MAPPINGS: &[(&str, &str)] = &[
+    ("TINYINT", "i8"),
+    ("SMALLINT", "i16"),
+    ("INT", "i32"),
+    ("BIGINT", "i64"),
+    ("REAL", "f32"),
+    ("DOUBLE PRECISION", "f64"),
+    ("BOOLEAN", "bool"),
+    ("BYTEA", "Vec<u8>"),
+    ("BLOB", "Vec<u8>"),
+    ("NUMERIC", "rust_decimal::Decimal"),
+    ("DATE", "chrono::NaiveDate"),
+    ("TIME", "chrono::NaiveTime"),
+    ("TIMESTAMP", "chrono::NaiveDateTime"),
+    ("TIMESTAMP WITH TIME ZONE", "chrono::DateTime<chrono::Utc>"),
+    ("UUID", "uuid::Uuid"),
+    ("JSON", "serde_json::Value"),
+    ("JSONB", "serde_json::Value"),
+];
+
+/// Trait for the mappings of SQL data types to the Rust types of their
+/// values, used by the code generation backends.
+///
+/// The backends resolve the domains, the enumeration types and the arrays
+/// themselves, so that mappers only see the names of base data types: the
+/// canonical data types of
+/// [`ColumnLike::normalized_type`](crate::traits::ColumnLike::normalized_type)
+/// when the dialect knows them, and the data types as declared otherwise.
+/// The default [`TypeMap`] resolves the aliases of the latter through the
+/// table the exporters of [`crate::export`] also use.
+pub trait TypeMapper {
+    /// Returns the Rust type of the values of the provided SQL data type.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - The name of the SQL data type, as in `BIGINT`.
+    fn rust_type(&self, data_type: &str) -> String;
+}
+
+/// Configurable table mapping SQL data types to Rust types.
+///
+/// The default table maps the data types with a Rust primitive to it, and
+/// the others to the types of the crates most commonly used for them, such
+/// as `chrono::NaiveDateTime` for `TIMESTAMP` or `uuid::Uuid` for `UUID`.
+/// The data types without a mapping of their own are mapped as the data
+/// type they are an alias of, as `int4` is mapped as `INT`. The data types
+/// without any mapping are mapped to the fallback type, which is `String` by
+/// default.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::codegen::{TypeMap, TypeMapper};
+///
+/// let type_map = TypeMap::default().with_mapping("uuid", "String").with_fallback("Vec<u8>");
+/// assert_eq!(type_map.rust_type("UUID"), "String");
+/// assert_eq!(type_map.rust_type("bigint"), "i64");
+/// assert_eq!(type_map.rust_type("timestamptz"), "chrono::DateTime<chrono::Utc>");
+/// assert_eq!(type_map.rust_type("GEOMETRY"), "Vec<u8>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeMap {
+    /// Rust types of the SQL data types, keyed by their upper-case names.
+    mappings: BTreeMap<String, String>,
+    /// Rust type of the SQL data types without a mapping.
+    fallback: String,
+}
+
+impl Default for TypeMap {
+    fn default() -> Self {
+        Self {
+            mappings: DEFAULT_MAPPINGS
+                .iter()
+                .map(|(data_type, rust_type)| ((*data_type).to_string(), (*rust_type).to_string()))
+                .collect(),
+            fallback: "String".to_string(),
+        }
+    }
+}
+
+impl TypeMap {
+    /// Creates a new table without mappings, mapping every data type to the
+    /// provided fallback type.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The Rust type of the data types without a mapping.
+    #[must_use]
+    pub fn new(fallback: &str) -> Self {
+        Self { mappings: BTreeMap::new(), fallback: fallback.to_string() }
+    }
+
+    /// Maps the provided SQL data type, compared case-insensitively, to the
+    /// provided Rust type, replacing its previous mapping if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - The name of the SQL data type.
+    /// * `rust_type` - The Rust type of its values.
+    #[must_use]
+    pub fn with_mapping(mut self, data_type: &str, rust_type: &str) -> Self {
+        self.mappings.insert(normalize(data_type), rust_type.to_string());
+        self
+    }
+
+    /// Sets the Rust type of the data types without a mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `fallback` - The Rust type of the data types without a mapping.
+    #[must_use]
+    pub fn with_fallback(mut self, fallback: &str) -> Self {
+        self.fallback = fallback.to_string();
+        self
+    }
+
+    /// Returns the Rust type the provided SQL data type is mapped to, if it
+    /// or the data type it is an alias of has a mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - The name of the SQL data type.
+    #[must_use]
+    pub fn mapping(&self, data_type: &str) -> Option<&str> {
+        self.mappings
+            .get(&normalize(data_type))
+            .or_else(|| self.mappings.get(&canonical_data_type(data_type)))
+            .map(String::as_str)
+    }
+
+    /// Returns the Rust type of the data types without a mapping.
+    #[must_use]
+    pub fn fallback(&self) -> &str {
+        &self.fallback
+    }
+}
+
+impl TypeMapper for TypeMap {
+    fn rust_type(&self, data_type: &str) -> String {
+        self.mapping(data_type).unwrap_or(&self.fallback).to_string()
+    }
+}
+
+/// Returns the key of the provided data type in the mappings.
+fn normalize(data_type: &str) -> String {
+    data_type.trim().trim_matches('"').to_ascii_uppercase()
+}
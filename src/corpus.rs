@@ -0,0 +1,310 @@
+//! Submodule shipping a corpus of realistic schemas together with the golden
+//! outputs of the analyses run on them.
+//!
+//! The corpus is meant to be reused by downstream crates to test their
+//! integrations against known-good results: each [`CorpusSchema`] provides
+//! its SQL source, the dialect it is written in, and the expected renderings
+//! of its table dependency DAG and of its foreign keys. Any
+//! [`DatabaseLike`] implementation built from the same schema can be checked
+//! against those renderings through [`CorpusSchema::verify`].
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+use crate::{
+    structs::ParserDB,
+    traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike},
+};
+
+/// The SQL dialect a corpus schema is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorpusDialect {
+    /// `PostgreSQL`.
+    PostgreSql,
+    /// `SQLite`.
+    Sqlite,
+}
+
+/// A schema of the corpus along with its golden outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CorpusSchema {
+    /// Short identifier of the schema.
+    name: &'static str,
+    /// Dialect the schema is written in.
+    dialect: CorpusDialect,
+    /// SQL source of the schema.
+    sql: &'static str,
+    /// Golden rendering of the table dependency DAG.
+    table_dag: &'static str,
+    /// Golden rendering of the foreign keys.
+    foreign_keys: &'static str,
+}
+
+/// Multi-tenant application exposed through PostgREST, with API roles, row
+/// level security policies, grants and a maintenance trigger.
+pub const MULTI_TENANT: CorpusSchema = CorpusSchema {
+    name: "multi_tenant",
+    dialect: CorpusDialect::PostgreSql,
+    sql: include_str!("corpus/multi_tenant/schema.sql"),
+    table_dag: include_str!("corpus/multi_tenant/table_dag.txt"),
+    foreign_keys: include_str!("corpus/multi_tenant/foreign_keys.txt"),
+};
+
+/// Scientific sample-tracking schema, with self-referential hierarchies of
+/// taxa and samples.
+pub const SAMPLE_TRACKING: CorpusSchema = CorpusSchema {
+    name: "sample_tracking",
+    dialect: CorpusDialect::PostgreSql,
+    sql: include_str!("corpus/sample_tracking/schema.sql"),
+    table_dag: include_str!("corpus/sample_tracking/table_dag.txt"),
+    foreign_keys: include_str!("corpus/sample_tracking/foreign_keys.txt"),
+};
+
+/// `SQLite` schema of an offline-first mobile notes application.
+pub const MOBILE_NOTES: CorpusSchema = CorpusSchema {
+    name: "mobile_notes",
+    dialect: CorpusDialect::Sqlite,
+    sql: include_str!("corpus/mobile_notes/schema.sql"),
+    table_dag: include_str!("corpus/mobile_notes/table_dag.txt"),
+    foreign_keys: include_str!("corpus/mobile_notes/foreign_keys.txt"),
+};
+
+/// All the schemas of the corpus.
+pub const SCHEMAS: &[CorpusSchema] = &[MULTI_TENANT, SAMPLE_TRACKING, MOBILE_NOTES];
+
+/// Mismatch between an analysis output and its golden rendering.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "Golden `{golden}` of corpus schema `{schema}` differs:\nexpected:\n{expected}\nactual:\n{actual}"
+)]
+pub struct GoldenMismatch {
+    /// Name of the corpus schema.
+    pub schema: &'static str,
+    /// Name of the golden output which differs.
+    pub golden: &'static str,
+    /// The golden rendering.
+    pub expected: &'static str,
+    /// The rendering obtained from the database.
+    pub actual: String,
+}
+
+impl CorpusSchema {
+    /// Returns the short identifier of the schema.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the dialect the schema is written in.
+    #[must_use]
+    #[inline]
+    pub fn dialect(&self) -> CorpusDialect {
+        self.dialect
+    }
+
+    /// Returns the SQL source of the schema.
+    #[must_use]
+    #[inline]
+    pub fn sql(&self) -> &'static str {
+        self.sql
+    }
+
+    /// Returns the golden rendering of the table dependency DAG, as produced
+    /// by [`render_table_dag`].
+    #[must_use]
+    #[inline]
+    pub fn golden_table_dag(&self) -> &'static str {
+        self.table_dag
+    }
+
+    /// Returns the golden rendering of the foreign keys, as produced by
+    /// [`render_foreign_keys`].
+    #[must_use]
+    #[inline]
+    pub fn golden_foreign_keys(&self) -> &'static str {
+        self.foreign_keys
+    }
+
+    /// Parses the schema into a [`ParserDB`] using its dialect.
+    ///
+    /// # Errors
+    ///
+    /// * If the schema cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{corpus::MOBILE_NOTES, prelude::*};
+    ///
+    /// let db = MOBILE_NOTES.parse()?;
+    /// assert!(db.table(None, "notes").is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(&self) -> Result<ParserDB, crate::errors::Error> {
+        match self.dialect {
+            CorpusDialect::PostgreSql => ParserDB::parse::<PostgreSqlDialect>(self.sql),
+            CorpusDialect::Sqlite => ParserDB::parse::<SQLiteDialect>(self.sql),
+        }
+    }
+
+    /// Checks the analyses run on the provided database against the golden
+    /// outputs of the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A database built from the SQL source of the schema.
+    ///
+    /// # Errors
+    ///
+    /// * If any of the renderings differs from its golden output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::corpus::SCHEMAS;
+    ///
+    /// for schema in SCHEMAS {
+    ///     schema.verify(&schema.parse()?)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify<DB: DatabaseLike>(&self, database: &DB) -> Result<(), GoldenMismatch> {
+        let goldens = [
+            ("table_dag", self.table_dag, render_table_dag(database)),
+            ("foreign_keys", self.foreign_keys, render_foreign_keys(database)),
+        ];
+        for (golden, expected, actual) in goldens {
+            if expected != actual {
+                return Err(GoldenMismatch { schema: self.name, golden, expected, actual });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the table, qualified by its schema if any.
+fn qualified_table_name<T: TableLike>(table: &T) -> String {
+    match table.table_schema() {
+        Some(schema) => format!("{schema}.{}", table.table_name()),
+        None => table.table_name().to_string(),
+    }
+}
+
+/// Renders the table dependency DAG of the database, one line per level.
+///
+/// Tables without foreign keys to other tables lie on level zero, and every
+/// other table lies one level above the highest table it references.
+/// Self-references are ignored, as in [`DatabaseLike::table_dependency_graph`],
+/// the tables of a cycle of foreign keys lie on the same level, and the
+/// tables of a level are sorted by their qualified name.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{corpus::render_table_dag, prelude::*};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE a (id INT PRIMARY KEY);
+///      CREATE TABLE b (id INT PRIMARY KEY, a_id INT REFERENCES a (id));
+///      CREATE TABLE c (id INT PRIMARY KEY);",
+/// )?;
+/// assert_eq!(render_table_dag(&db), "0: a, c\n1: b\n");
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn render_table_dag<DB: DatabaseLike>(database: &DB) -> String {
+    // The strongly connected components come after the components they
+    // reference, and are cycles of foreign keys when holding several tables.
+    let components = database.table_dependency_graph().strongly_connected_components();
+    let mut levels: Vec<usize> = Vec::with_capacity(components.len());
+
+    for component in &components {
+        let level = component
+            .iter()
+            .flat_map(|table| table.foreign_keys(database))
+            .filter_map(|foreign_key| {
+                let referenced_table = foreign_key.referenced_table(database)?;
+                // References within the component, self-references included,
+                // do not raise its level.
+                let referenced_component = components[..levels.len()]
+                    .iter()
+                    .position(|candidate| candidate.contains(&referenced_table))?;
+                Some(levels[referenced_component] + 1)
+            })
+            .max()
+            .unwrap_or(0);
+        levels.push(level);
+    }
+
+    let mut tables_per_level: Vec<Vec<String>> =
+        vec![Vec::new(); levels.iter().max().map_or(0, |max| max + 1)];
+    for (component, level) in components.iter().zip(levels) {
+        tables_per_level[level].extend(component.iter().map(|table| qualified_table_name(*table)));
+    }
+
+    tables_per_level
+        .into_iter()
+        .enumerate()
+        .map(|(level, mut tables)| {
+            tables.sort_unstable();
+            format!("{level}: {}\n", tables.join(", "))
+        })
+        .collect()
+}
+
+/// Renders the foreign keys of the database, one sorted line per foreign
+/// key.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{corpus::render_foreign_keys, prelude::*};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE a (id INT PRIMARY KEY);
+///      CREATE TABLE b (id INT PRIMARY KEY, a_id INT REFERENCES a (id) ON DELETE CASCADE);",
+/// )?;
+/// assert_eq!(render_foreign_keys(&db), "b (a_id) -> a (id) ON DELETE CASCADE\n");
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn render_foreign_keys<DB: DatabaseLike>(database: &DB) -> String {
+    let column_list = |columns: Vec<&str>| columns.join(", ");
+
+    let mut lines: Vec<String> = database
+        .tables()
+        .flat_map(|table| {
+            table.foreign_keys(database).filter_map(move |foreign_key| {
+                let referenced_table = foreign_key.referenced_table(database)?;
+                let host_columns = column_list(
+                    foreign_key.host_columns(database).map(ColumnLike::column_name).collect(),
+                );
+                let referenced_columns = column_list(
+                    foreign_key.referenced_columns(database).map(ColumnLike::column_name).collect(),
+                );
+                let on_delete =
+                    if foreign_key.on_delete_cascade(database) { " ON DELETE CASCADE" } else { "" };
+                Some(format!(
+                    "{} ({host_columns}) -> {} ({referenced_columns}){on_delete}\n",
+                    qualified_table_name(table),
+                    qualified_table_name(referenced_table),
+                ))
+            })
+        })
+        .collect();
+    lines.sort_unstable();
+    lines.concat()
+}
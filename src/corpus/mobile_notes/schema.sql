@@ -0,0 +1,56 @@
+-- SQLite schema of an offline-first mobile notes application, synchronized
+-- with a remote server through per-device cursors.
+
+-- Accounts signed in on the device.
+CREATE TABLE accounts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    email TEXT NOT NULL UNIQUE,
+    display_name TEXT
+);
+
+-- Notebooks grouping the notes of an account.
+CREATE TABLE notebooks (
+    id INTEGER PRIMARY KEY,
+    account_id INTEGER NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    title TEXT NOT NULL
+);
+
+-- Notes written in a notebook.
+CREATE TABLE notes (
+    id INTEGER PRIMARY KEY,
+    notebook_id INTEGER NOT NULL REFERENCES notebooks (id) ON DELETE CASCADE,
+    body TEXT NOT NULL DEFAULT '',
+    pinned INTEGER NOT NULL DEFAULT 0 CHECK (pinned IN (0, 1)),
+    updated_at INTEGER NOT NULL
+);
+
+-- Labels defined by an account.
+CREATE TABLE tags (
+    id INTEGER PRIMARY KEY,
+    account_id INTEGER NOT NULL REFERENCES accounts (id) ON DELETE CASCADE,
+    label TEXT NOT NULL,
+    UNIQUE (account_id, label)
+);
+
+-- Association between notes and tags.
+CREATE TABLE note_tags (
+    note_id INTEGER NOT NULL REFERENCES notes (id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+    PRIMARY KEY (note_id, tag_id)
+);
+
+-- Files attached to a note.
+CREATE TABLE attachments (
+    id INTEGER PRIMARY KEY,
+    note_id INTEGER NOT NULL REFERENCES notes (id) ON DELETE CASCADE,
+    mime_type TEXT NOT NULL,
+    byte_size INTEGER NOT NULL CHECK (byte_size >= 0)
+);
+
+-- Last synchronization token received by each device.
+CREATE TABLE sync_cursors (
+    device_id TEXT PRIMARY KEY,
+    last_token TEXT NOT NULL
+);
+
+CREATE INDEX notes_by_notebook ON notes (notebook_id, updated_at DESC);
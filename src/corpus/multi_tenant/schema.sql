@@ -0,0 +1,89 @@
+-- PostgREST-style multi-tenant application: the API roles switch between
+-- `anon` and `authenticated`, and row level security scopes every project and
+-- task to the tenant carried by the request.
+
+CREATE ROLE authenticator NOINHERIT LOGIN;
+CREATE ROLE anon NOLOGIN;
+CREATE ROLE authenticated NOLOGIN;
+
+CREATE SCHEMA app;
+
+-- Organizations using the application.
+CREATE TABLE app.tenants (
+    id SERIAL PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    display_name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Members of a tenant.
+CREATE TABLE app.users (
+    id SERIAL PRIMARY KEY,
+    tenant_id INT NOT NULL REFERENCES app.tenants (id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    membership TEXT NOT NULL DEFAULT 'member' CHECK (membership IN ('owner', 'admin', 'member')),
+    UNIQUE (tenant_id, email)
+);
+
+-- Projects owned by a tenant member.
+CREATE TABLE app.projects (
+    id SERIAL PRIMARY KEY,
+    tenant_id INT NOT NULL REFERENCES app.tenants (id) ON DELETE CASCADE,
+    owner_id INT NOT NULL REFERENCES app.users (id),
+    name TEXT NOT NULL,
+    archived BOOLEAN NOT NULL DEFAULT false
+);
+
+-- Units of work within a project.
+CREATE TABLE app.tasks (
+    id SERIAL PRIMARY KEY,
+    project_id INT NOT NULL REFERENCES app.projects (id) ON DELETE CASCADE,
+    assignee_id INT REFERENCES app.users (id),
+    title TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'in_progress', 'done')),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Discussion attached to a task.
+CREATE TABLE app.task_comments (
+    id SERIAL PRIMARY KEY,
+    task_id INT NOT NULL REFERENCES app.tasks (id) ON DELETE CASCADE,
+    author_id INT NOT NULL REFERENCES app.users (id),
+    body TEXT NOT NULL CHECK (length(body) > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Append-only log of tenant events, kept without foreign keys so that it
+-- outlives the rows it describes.
+CREATE TABLE app.audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id INT NOT NULL,
+    event TEXT NOT NULL,
+    payload JSONB,
+    logged_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX tasks_by_project ON app.tasks (project_id, status);
+CREATE INDEX audit_log_by_tenant ON app.audit_log (tenant_id, logged_at DESC);
+
+CREATE FUNCTION app.touch_updated_at() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tasks_touch_updated_at BEFORE UPDATE ON app.tasks
+    FOR EACH ROW EXECUTE FUNCTION app.touch_updated_at();
+
+ALTER TABLE app.projects ENABLE ROW LEVEL SECURITY;
+ALTER TABLE app.tasks ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY projects_tenant_isolation ON app.projects TO authenticated
+    USING (tenant_id = current_setting('request.jwt.claims.tenant_id')::INT);
+CREATE POLICY tasks_tenant_isolation ON app.tasks TO authenticated
+    USING (project_id IN (SELECT id FROM app.projects));
+
+GRANT SELECT ON app.tenants TO anon;
+GRANT SELECT, INSERT, UPDATE ON app.projects, app.tasks TO authenticated;
+GRANT SELECT, INSERT ON app.task_comments TO authenticated;
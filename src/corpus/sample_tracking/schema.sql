@@ -0,0 +1,70 @@
+-- Scientific sample-tracking schema: field samples are split into aliquots
+-- stored in freezers, extracted, and acquired on analytical instruments.
+
+-- Taxonomic tree of the sampled organisms.
+CREATE TABLE taxa (
+    id INT PRIMARY KEY,
+    parent_id INT REFERENCES taxa (id),
+    scientific_name TEXT NOT NULL UNIQUE,
+    taxon_rank TEXT NOT NULL
+);
+
+-- Research projects collecting samples.
+CREATE TABLE projects (
+    id INT PRIMARY KEY,
+    code TEXT NOT NULL UNIQUE,
+    title TEXT NOT NULL
+);
+
+-- Analytical instruments available in the laboratory.
+CREATE TABLE instruments (
+    id INT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    vendor TEXT NOT NULL
+);
+
+-- Freezer shelves hosting the aliquots.
+CREATE TABLE storage_locations (
+    id INT PRIMARY KEY,
+    freezer TEXT NOT NULL,
+    shelf INT NOT NULL CHECK (shelf >= 0),
+    UNIQUE (freezer, shelf)
+);
+
+-- Field samples, possibly derived from a parent sample.
+CREATE TABLE samples (
+    id INT PRIMARY KEY,
+    project_id INT NOT NULL REFERENCES projects (id),
+    taxon_id INT NOT NULL REFERENCES taxa (id),
+    parent_sample_id INT REFERENCES samples (id),
+    collected_at TIMESTAMP NOT NULL,
+    mass_mg NUMERIC(10, 3) CHECK (mass_mg > 0)
+);
+
+-- Portions of a sample stored in a location.
+CREATE TABLE aliquots (
+    id INT PRIMARY KEY,
+    sample_id INT NOT NULL REFERENCES samples (id) ON DELETE CASCADE,
+    location_id INT NOT NULL REFERENCES storage_locations (id),
+    volume_ul NUMERIC(10, 2) NOT NULL CHECK (volume_ul > 0)
+);
+
+-- Extractions performed on an aliquot.
+CREATE TABLE extractions (
+    id INT PRIMARY KEY,
+    aliquot_id INT NOT NULL REFERENCES aliquots (id) ON DELETE CASCADE,
+    protocol TEXT NOT NULL,
+    performed_at TIMESTAMP NOT NULL
+);
+
+-- Instrument runs of an extraction.
+CREATE TABLE acquisitions (
+    id INT PRIMARY KEY,
+    extraction_id INT NOT NULL REFERENCES extractions (id) ON DELETE CASCADE,
+    instrument_id INT NOT NULL REFERENCES instruments (id),
+    acquired_at TIMESTAMP NOT NULL,
+    raw_file TEXT NOT NULL UNIQUE
+);
+
+CREATE INDEX samples_by_project ON samples (project_id, collected_at);
+CREATE INDEX acquisitions_by_instrument ON acquisitions (instrument_id, acquired_at DESC);
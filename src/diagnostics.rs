@@ -0,0 +1,229 @@
+//! Submodule rendering errors as diagnostics quoting the SQL which raised
+//! them.
+//!
+//! Messages such as "table X not found" are hard to act upon in schemas
+//! spanning thousands of lines: a [`Diagnostics`] renderer prefixes them with
+//! the location of the offending statement and quotes its lines, underlining
+//! the part of each line the statement covers.
+//!
+//! ```text
+//! error: Table `missing` not found for index `idx`.
+//!  --> schema.sql:2:14
+//!   |
+//! 2 | CREATE INDEX idx ON missing (id);
+//!   |              ^^^^^^^^^^^^^^^^^^^
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use sqlparser::tokenizer::Span;
+
+use crate::{errors::Error, structs::ParseReport};
+
+/// Maximal number of lines of a statement quoted in a diagnostic.
+const MAX_QUOTED_LINES: usize = 3;
+
+/// Renderer of errors as diagnostics quoting the SQL they were raised by.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{diagnostics::Diagnostics, prelude::*, structs::ParserOptions};
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let sql = "CREATE TABLE t (id INT);\nCREATE INDEX idx ON missing (id);";
+/// let report = ParserDB::parse_with_report::<PostgreSqlDialect>(sql, ParserOptions::default())?;
+/// let rendering = Diagnostics::new(sql).with_path("schema.sql").render_report(&report);
+/// assert!(rendering.contains(" --> schema.sql:2:"));
+/// assert!(rendering.contains("2 | CREATE INDEX idx ON missing (id);\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diagnostics<'src> {
+    /// SQL the errors were raised by.
+    source: &'src str,
+    /// Path of the file the SQL was read from, if any.
+    path: Option<&'src str>,
+}
+
+impl<'src> Diagnostics<'src> {
+    /// Creates a new renderer quoting the provided SQL.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The SQL the errors were raised by.
+    #[must_use]
+    pub fn new(source: &'src str) -> Self {
+        Self { source, path: None }
+    }
+
+    /// Sets the path of the file the SQL was read from, shown along with
+    /// the location of the offending statements.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    #[must_use]
+    pub fn with_path(mut self, path: &'src str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Renders the provided error, quoting the lines covered by the span if
+    /// it points within the SQL.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error to render.
+    /// * `span` - The span of the statement raising the error, if known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{diagnostics::Diagnostics, errors::Error};
+    /// use sqlparser::tokenizer::{Location, Span};
+    ///
+    /// let error = Error::DropTableNotFound { table_name: "archived".to_string() };
+    /// let span = Span::new(Location::new(1, 1), Location::new(1, 21));
+    /// let rendering = Diagnostics::new("DROP TABLE archived;").render(&error, Some(span));
+    /// assert!(rendering.starts_with("error: "));
+    /// assert!(rendering.ends_with(" --> 1:1\n  |\n1 | DROP TABLE archived;\n  | ^^^^^^^^^^^^^^^^^^^^\n"));
+    /// ```
+    #[must_use]
+    pub fn render(&self, error: &Error, span: Option<Span>) -> String {
+        let mut rendering = String::new();
+        let _ = writeln!(rendering, "error: {error}");
+        let Some(span) = span.filter(|span| self.quotable(*span)) else {
+            return rendering;
+        };
+
+        let first_line = usize::try_from(span.start.line).unwrap_or(usize::MAX);
+        let last_line = usize::try_from(span.end.line).unwrap_or(usize::MAX).max(first_line);
+        let quoted_last_line = last_line.min(first_line + MAX_QUOTED_LINES - 1);
+        let gutter = quoted_last_line.to_string().len();
+
+        let _ = write!(rendering, "{:gutter$}--> ", "");
+        if let Some(path) = self.path {
+            let _ = write!(rendering, "{path}:");
+        }
+        let _ = writeln!(rendering, "{}:{}", span.start.line, span.start.column);
+        let _ = writeln!(rendering, "{:gutter$} |", "");
+
+        for (number, line) in self.source.lines().enumerate().skip(first_line - 1) {
+            let number = number + 1;
+            if number > quoted_last_line {
+                break;
+            }
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let start = if number == first_line {
+                usize::try_from(span.start.column).unwrap_or(1).max(1)
+            } else {
+                line.chars().position(|c| !c.is_whitespace()).map_or(1, |position| position + 1)
+            };
+            let end = if number == last_line {
+                usize::try_from(span.end.column).unwrap_or(usize::MAX)
+            } else {
+                line.chars().count() + 1
+            };
+            let _ = writeln!(rendering, "{number:>gutter$} | {line}");
+            let _ = write!(rendering, "{:gutter$} | ", "");
+            // Tabs are kept so that the carets line up with the quoted line.
+            for c in line.chars().take(start - 1) {
+                rendering.push(if c == '\t' { '\t' } else { ' ' });
+            }
+            let carets = end.saturating_sub(start).max(1);
+            rendering.extend(core::iter::repeat_n('^', carets));
+            rendering.push('\n');
+        }
+        if last_line > quoted_last_line {
+            let _ = writeln!(rendering, "{:gutter$} | ...", "");
+        }
+        rendering
+    }
+
+    /// Renders every error of the provided report, separated by blank lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The report whose errors to render.
+    #[must_use]
+    pub fn render_report(&self, report: &ParseReport) -> String {
+        report
+            .errors()
+            .iter()
+            .zip(report.error_spans())
+            .map(|(error, span)| self.render(error, *span))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns whether the span points within the SQL.
+    fn quotable(&self, span: Span) -> bool {
+        span.start.line >= 1
+            && span.start.column >= 1
+            && usize::try_from(span.start.line)
+                .is_ok_and(|line| line <= self.source.lines().count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::tokenizer::Location;
+
+    use super::*;
+
+    fn span(start: (u64, u64), end: (u64, u64)) -> Span {
+        Span::new(Location::new(start.0, start.1), Location::new(end.0, end.1))
+    }
+
+    fn error() -> Error {
+        Error::DropTableNotFound { table_name: "archived".to_string() }
+    }
+
+    #[test]
+    fn test_errors_without_span_only_show_the_message() {
+        let diagnostics = Diagnostics::new("DROP TABLE archived;");
+        let expected = format!("error: {}\n", error());
+        assert_eq!(diagnostics.render(&error(), None), expected);
+        assert_eq!(diagnostics.render(&error(), Some(Span::empty())), expected);
+        assert_eq!(diagnostics.render(&error(), Some(span((4, 1), (4, 5)))), expected);
+    }
+
+    #[test]
+    fn test_statement_on_a_single_line_is_underlined() {
+        let sql = "CREATE TABLE t (id INT);\n  DROP TABLE archived;";
+        let rendering = Diagnostics::new(sql)
+            .with_path("schema.sql")
+            .render(&error(), Some(span((2, 3), (2, 23))));
+        assert_eq!(
+            rendering,
+            format!(
+                "error: {}\n --> schema.sql:2:3\n  |\n2 |   DROP TABLE archived;\n  |   {}\n",
+                error(),
+                "^".repeat(20)
+            )
+        );
+    }
+
+    #[test]
+    fn test_long_statements_are_truncated() {
+        let sql = "CREATE TABLE t (\n\tid INT,\n\tname TEXT,\n\tage INT\n);";
+        let rendering = Diagnostics::new(sql).render(&error(), Some(span((1, 1), (5, 3))));
+        assert_eq!(
+            rendering,
+            format!(
+                "error: {}\n --> 1:1\n  |\n1 | CREATE TABLE t (\n  | {}\n2 | \tid INT,\n  | \t{}\n3 | \tname TEXT,\n  | \t{}\n  | ...\n",
+                error(),
+                "^".repeat(16),
+                "^".repeat(7),
+                "^".repeat(10)
+            )
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Submodule providing utilities to compare database schemas and to reason
+//! about the impact of the differences between them.
+
+mod access;
+mod migration_safety;
+mod schema_diff;
+mod type_change;
+
+pub use access::{AccessChange, AccessDiff, Privilege, RoleAttributes};
+pub use migration_safety::{ClassifiedStatement, MigrationPlan, MigrationSafety};
+pub use schema_diff::{AttributeChange, MigrationScript, ObjectPath, SchemaChange, SchemaDiff};
+pub use type_change::{ColumnTypeChange, TypeChangeKind};
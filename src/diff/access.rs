@@ -0,0 +1,710 @@
+//! Submodule comparing the roles and privileges of two databases, and
+//! rendering the statements needed to reconcile them.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+use sqlparser::ast::{Action, Grantee, GranteeName, GranteesType};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{
+        ColumnGrantLike, DatabaseLike, DialectLike, GrantLike, RoleLike, TableGrantLike, TableLike,
+    },
+    utils::object_name::{object_name_last_part, render_normalized_identifier},
+};
+
+/// Attributes of a role, as set by `CREATE ROLE` and `ALTER ROLE`.
+///
+/// The [`Default`] value matches the attributes PostgreSQL assigns to a role
+/// created without options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoleAttributes {
+    /// Whether the role is a superuser.
+    pub superuser: bool,
+    /// Whether the role can create databases.
+    pub create_db: bool,
+    /// Whether the role can create other roles.
+    pub create_role: bool,
+    /// Whether the role inherits the privileges of the roles it is a member
+    /// of.
+    pub inherit: bool,
+    /// Whether the role can log in.
+    pub login: bool,
+    /// Whether the role is a replication role.
+    pub replication: bool,
+    /// Whether the role bypasses row-level security.
+    pub bypass_rls: bool,
+    /// The connection limit of the role, `None` meaning unlimited.
+    pub connection_limit: Option<i32>,
+}
+
+impl Default for RoleAttributes {
+    fn default() -> Self {
+        Self {
+            superuser: false,
+            create_db: false,
+            create_role: false,
+            inherit: true,
+            login: false,
+            replication: false,
+            bypass_rls: false,
+            connection_limit: None,
+        }
+    }
+}
+
+impl<R: RoleLike> From<&R> for RoleAttributes {
+    fn from(role: &R) -> Self {
+        Self {
+            superuser: role.is_superuser(),
+            create_db: role.can_create_db(),
+            create_role: role.can_create_role(),
+            inherit: role.inherits(),
+            login: role.can_login(),
+            replication: role.is_replication(),
+            bypass_rls: role.can_bypass_rls(),
+            connection_limit: role.connection_limit(),
+        }
+    }
+}
+
+impl RoleAttributes {
+    /// Returns the role options turning `baseline` into these attributes.
+    fn options_from(&self, baseline: &Self) -> Vec<String> {
+        let flags = [
+            (self.superuser, baseline.superuser, "SUPERUSER"),
+            (self.create_db, baseline.create_db, "CREATEDB"),
+            (self.create_role, baseline.create_role, "CREATEROLE"),
+            (self.inherit, baseline.inherit, "INHERIT"),
+            (self.login, baseline.login, "LOGIN"),
+            (self.replication, baseline.replication, "REPLICATION"),
+            (self.bypass_rls, baseline.bypass_rls, "BYPASSRLS"),
+        ];
+        let mut options: Vec<String> = flags
+            .into_iter()
+            .filter(|(value, baseline, _)| value != baseline)
+            .map(
+                |(value, _, option)| {
+                    if value { option.to_string() } else { format!("NO{option}") }
+                },
+            )
+            .collect();
+        if self.connection_limit != baseline.connection_limit {
+            options.push(format!("CONNECTION LIMIT {}", self.connection_limit.unwrap_or(-1)));
+        }
+        options
+    }
+}
+
+/// A single privilege held by a grantee on a table, or on one of its columns.
+///
+/// All names are stored normalized following PostgreSQL identifier semantics,
+/// so that privileges spelled differently in the two databases compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Privilege {
+    /// The grantee, `None` standing for `PUBLIC`.
+    grantee: Option<String>,
+    /// The schema of the table, if qualified.
+    table_schema: Option<String>,
+    /// The name of the table.
+    table_name: String,
+    /// The column the privilege is restricted to, if any.
+    column: Option<String>,
+    /// The privilege keyword, such as `SELECT` or `ALL PRIVILEGES`.
+    action: String,
+}
+
+impl Privilege {
+    /// Returns the normalized name of the grantee, or `None` for `PUBLIC`.
+    #[must_use]
+    #[inline]
+    pub fn grantee(&self) -> Option<&str> {
+        self.grantee.as_deref()
+    }
+
+    /// Returns the normalized schema of the table, if qualified.
+    #[must_use]
+    #[inline]
+    pub fn table_schema(&self) -> Option<&str> {
+        self.table_schema.as_deref()
+    }
+
+    /// Returns the normalized name of the table.
+    #[must_use]
+    #[inline]
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Returns the normalized name of the column the privilege is restricted
+    /// to, if any.
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> Option<&str> {
+        self.column.as_deref()
+    }
+
+    /// Returns the privilege keyword, such as `SELECT` or `ALL PRIVILEGES`.
+    #[must_use]
+    #[inline]
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// Writes the `<action> ON <table>` part of a `GRANT` or `REVOKE`.
+    fn fmt_target(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.action)?;
+        if let Some(column) = &self.column {
+            write!(f, " ({})", render_normalized_identifier(column))?;
+        }
+        f.write_str(" ON ")?;
+        if let Some(schema) = &self.table_schema {
+            write!(f, "{}.", render_normalized_identifier(schema))?;
+        }
+        write!(f, "{}", render_normalized_identifier(&self.table_name))
+    }
+
+    /// Writes the grantee of a `GRANT` or `REVOKE`.
+    fn fmt_grantee(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.grantee {
+            Some(grantee) => write!(f, "{}", render_normalized_identifier(grantee)),
+            None => f.write_str("PUBLIC"),
+        }
+    }
+}
+
+/// A single change needed to reconcile the roles and privileges of two
+/// databases.
+///
+/// The [`Display`] implementation renders the change as a PostgreSQL
+/// statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccessChange {
+    /// A role missing from the current database.
+    CreateRole {
+        /// The normalized name of the role.
+        role: String,
+        /// The attributes of the role.
+        attributes: RoleAttributes,
+    },
+    /// A role whose attributes differ between the databases.
+    AlterRole {
+        /// The normalized name of the role.
+        role: String,
+        /// The attributes in the current database.
+        from: RoleAttributes,
+        /// The attributes in the desired database.
+        to: RoleAttributes,
+    },
+    /// A role membership missing from the current database.
+    GrantMembership {
+        /// The normalized name of the role being granted.
+        role: String,
+        /// The normalized name of the member role.
+        member: String,
+    },
+    /// A role membership absent from the desired database.
+    RevokeMembership {
+        /// The normalized name of the role being revoked.
+        role: String,
+        /// The normalized name of the member role.
+        member: String,
+    },
+    /// A privilege missing from the current database, or whose grant option
+    /// is missing.
+    GrantPrivilege {
+        /// The privilege to grant.
+        privilege: Privilege,
+        /// Whether the privilege is granted `WITH GRANT OPTION`.
+        with_grant_option: bool,
+    },
+    /// A privilege absent from the desired database.
+    RevokePrivilege {
+        /// The privilege to revoke.
+        privilege: Privilege,
+    },
+    /// A privilege held in both databases, but only with grant option in the
+    /// current one.
+    RevokeGrantOption {
+        /// The privilege whose grant option is revoked.
+        privilege: Privilege,
+    },
+}
+
+impl Display for AccessChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateRole { role, attributes } => {
+                write!(f, "CREATE ROLE {}", render_normalized_identifier(role))?;
+                let options = attributes.options_from(&RoleAttributes::default());
+                if !options.is_empty() {
+                    write!(f, " WITH {}", options.join(" "))?;
+                }
+            }
+            Self::AlterRole { role, from, to } => {
+                write!(
+                    f,
+                    "ALTER ROLE {} WITH {}",
+                    render_normalized_identifier(role),
+                    to.options_from(from).join(" ")
+                )?;
+            }
+            Self::GrantMembership { role, member } => {
+                write!(
+                    f,
+                    "GRANT {} TO {}",
+                    render_normalized_identifier(role),
+                    render_normalized_identifier(member)
+                )?;
+            }
+            Self::RevokeMembership { role, member } => {
+                write!(
+                    f,
+                    "REVOKE {} FROM {}",
+                    render_normalized_identifier(role),
+                    render_normalized_identifier(member)
+                )?;
+            }
+            Self::GrantPrivilege { privilege, with_grant_option } => {
+                f.write_str("GRANT ")?;
+                privilege.fmt_target(f)?;
+                f.write_str(" TO ")?;
+                privilege.fmt_grantee(f)?;
+                if *with_grant_option {
+                    f.write_str(" WITH GRANT OPTION")?;
+                }
+            }
+            Self::RevokePrivilege { privilege } => {
+                f.write_str("REVOKE ")?;
+                privilege.fmt_target(f)?;
+                f.write_str(" FROM ")?;
+                privilege.fmt_grantee(f)?;
+            }
+            Self::RevokeGrantOption { privilege } => {
+                f.write_str("REVOKE GRANT OPTION FOR ")?;
+                privilege.fmt_target(f)?;
+                f.write_str(" FROM ")?;
+                privilege.fmt_grantee(f)?;
+            }
+        }
+        f.write_str(";")
+    }
+}
+
+/// Differences between the roles and privileges of two databases.
+///
+/// Unlike a full schema comparison, only roles, role memberships and the
+/// privileges granted on tables and columns are compared: tables and columns
+/// themselves are ignored, and the rendered statements assume the tables they
+/// reference exist. Grants are expanded into one [`Privilege`] per grantee,
+/// table, column and action, so that grants written differently but
+/// conferring the same privileges produce no changes.
+///
+/// Roles only present in the current database are reported by
+/// [`extra_roles`](Self::extra_roles) but never dropped, as dropping a role
+/// requires reassigning the objects it owns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessDiff {
+    /// The changes, in the order they should be applied.
+    changes: Vec<AccessChange>,
+    /// The normalized names of the roles only present in the current database.
+    extra_roles: Vec<String>,
+}
+
+impl AccessDiff {
+    /// Compares the roles and privileges of `current` against those of
+    /// `desired`.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The database to be reconciled.
+    /// * `desired` - The database whose roles and privileges are the target.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::AccessDiff, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let staging = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE TABLE t (id INT);
+    /// GRANT SELECT, DELETE ON t TO reader;
+    /// ",
+    /// )?;
+    /// let production = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE ROLE writer LOGIN;
+    /// CREATE TABLE t (id INT);
+    /// GRANT SELECT ON t TO reader;
+    /// GRANT INSERT ON t TO writer;
+    /// ",
+    /// )?;
+    /// let diff = AccessDiff::new(&staging, &production);
+    /// let statements: Vec<String> = diff.statements().collect();
+    /// assert_eq!(
+    ///     statements,
+    ///     [
+    ///         "CREATE ROLE writer WITH LOGIN;",
+    ///         "REVOKE DELETE ON t FROM reader;",
+    ///         "GRANT INSERT ON t TO writer;",
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new<A: DatabaseLike, B: DatabaseLike>(current: &A, desired: &B) -> Self {
+        let current_roles = collect_roles(current);
+        let desired_roles = collect_roles(desired);
+        let current_memberships = collect_memberships(current);
+        let desired_memberships = collect_memberships(desired);
+        let current_privileges = collect_privileges(current);
+        let desired_privileges = collect_privileges(desired);
+
+        let mut changes = Vec::new();
+
+        // Roles are created first, as every following statement may need them.
+        for (role, attributes) in &desired_roles {
+            match current_roles.get(role) {
+                None => {
+                    changes.push(AccessChange::CreateRole {
+                        role: role.clone(),
+                        attributes: *attributes,
+                    });
+                }
+                Some(current_attributes) if current_attributes != attributes => {
+                    changes.push(AccessChange::AlterRole {
+                        role: role.clone(),
+                        from: *current_attributes,
+                        to: *attributes,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        // Revocations precede grants, so that revoking a broad privilege such
+        // as `ALL PRIVILEGES` cannot undo a narrower one granted afterwards.
+        for (privilege, with_grant_option) in &current_privileges {
+            match desired_privileges.get(privilege) {
+                None => {
+                    changes.push(AccessChange::RevokePrivilege { privilege: privilege.clone() });
+                }
+                Some(false) if *with_grant_option => {
+                    changes.push(AccessChange::RevokeGrantOption { privilege: privilege.clone() });
+                }
+                Some(_) => {}
+            }
+        }
+        for (role, member) in current_memberships.difference(&desired_memberships) {
+            changes.push(AccessChange::RevokeMembership {
+                role: role.clone(),
+                member: member.clone(),
+            });
+        }
+        for (role, member) in desired_memberships.difference(&current_memberships) {
+            changes
+                .push(AccessChange::GrantMembership { role: role.clone(), member: member.clone() });
+        }
+        for (privilege, with_grant_option) in &desired_privileges {
+            match current_privileges.get(privilege) {
+                None => {
+                    changes.push(AccessChange::GrantPrivilege {
+                        privilege: privilege.clone(),
+                        with_grant_option: *with_grant_option,
+                    });
+                }
+                Some(false) if *with_grant_option => {
+                    changes.push(AccessChange::GrantPrivilege {
+                        privilege: privilege.clone(),
+                        with_grant_option: true,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        let extra_roles =
+            current_roles.into_keys().filter(|role| !desired_roles.contains_key(role)).collect();
+
+        Self { changes, extra_roles }
+    }
+
+    /// Returns the changes, in the order they should be applied.
+    #[must_use]
+    #[inline]
+    pub fn changes(&self) -> &[AccessChange] {
+        &self.changes
+    }
+
+    /// Returns the normalized names of the roles only present in the current
+    /// database.
+    #[must_use]
+    #[inline]
+    pub fn extra_roles(&self) -> &[String] {
+        &self.extra_roles
+    }
+
+    /// Returns whether the two databases grant the same privileges to the
+    /// same roles.
+    ///
+    /// Extra roles in the current database are not taken into account, as
+    /// they are never dropped.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns an iterator over the statements reconciling the current
+    /// database with the desired one, in the order they should be executed.
+    pub fn statements(&self) -> impl Iterator<Item = String> + '_ {
+        self.changes.iter().map(ToString::to_string)
+    }
+}
+
+impl Display for AccessDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of a role, normalized under the provided casing.
+fn role_key<R: RoleLike>(role: &R, casing: IdentifierCasing) -> String {
+    casing.normalize(role.name(), role.name_is_quoted()).into_owned()
+}
+
+/// Collects the attributes of the roles of a database, by normalized name.
+pub(super) fn collect_roles<DB: DatabaseLike>(database: &DB) -> BTreeMap<String, RoleAttributes> {
+    let casing = database.dialect().identifier_casing();
+    database.roles().map(|role| (role_key(role, casing), RoleAttributes::from(role))).collect()
+}
+
+/// Collects the role memberships of a database as `(role, member)` pairs.
+pub(super) fn collect_memberships<DB: DatabaseLike>(database: &DB) -> BTreeSet<(String, String)> {
+    let casing = database.dialect().identifier_casing();
+    database
+        .roles()
+        .flat_map(|member| {
+            member
+                .member_of(database)
+                .map(move |role| (role_key(role, casing), role_key(member, casing)))
+        })
+        .collect()
+}
+
+/// Returns the grantee normalized under the provided casing, `None` standing
+/// for `PUBLIC`.
+fn grantee_key(grantee: &Grantee, casing: IdentifierCasing) -> Option<String> {
+    if grantee.grantee_type == GranteesType::Public {
+        return None;
+    }
+    let (name, quoted) = match &grantee.name {
+        Some(GranteeName::ObjectName(name)) => object_name_last_part(name).map_or_else(
+            || (name.to_string(), false),
+            |(value, quoted)| (value.to_string(), quoted),
+        ),
+        _ => (grantee.to_string(), false),
+    };
+    if !quoted && name.eq_ignore_ascii_case("PUBLIC") {
+        return None;
+    }
+    Some(casing.normalize(&name, quoted).into_owned())
+}
+
+/// Splits an action into its privilege keyword and the columns it is
+/// restricted to, normalized under the provided casing, `None` standing for
+/// the whole table.
+fn action_targets(action: &Action, casing: IdentifierCasing) -> (String, Vec<Option<String>>) {
+    let (keyword, columns) = match action {
+        Action::Select { columns } => ("SELECT", columns),
+        Action::Insert { columns } => ("INSERT", columns),
+        Action::Update { columns } => ("UPDATE", columns),
+        Action::References { columns } => ("REFERENCES", columns),
+        _ => return (action.to_string(), vec![None]),
+    };
+    let columns = match columns {
+        Some(columns) => columns
+            .iter()
+            .map(|column| {
+                Some(casing.normalize(&column.value, column.quote_style.is_some()).into_owned())
+            })
+            .collect(),
+        None => vec![None],
+    };
+    (keyword.to_string(), columns)
+}
+
+/// Records the privileges a grant confers on a table.
+fn insert_grant<G: GrantLike>(
+    privileges: &mut BTreeMap<Privilege, bool>,
+    grant: &G,
+    table: &<G::DB as DatabaseLike>::Table,
+    database: &G::DB,
+) {
+    let casing = database.dialect().identifier_casing();
+    let table_schema = table
+        .table_schema()
+        .map(|schema| casing.normalize(schema, table.table_schema_is_quoted()).into_owned());
+    let table_name =
+        casing.normalize(table.table_name(), table.table_name_is_quoted()).into_owned();
+    let actions: Vec<(String, Vec<Option<String>>)> = if grant.is_all_privileges() {
+        vec![("ALL PRIVILEGES".to_string(), vec![None])]
+    } else {
+        grant.privileges(database).map(|action| action_targets(action, casing)).collect()
+    };
+
+    for grantee in grant.grantees(database) {
+        let grantee = grantee_key(grantee, casing);
+        for (action, columns) in &actions {
+            for column in columns {
+                let privilege = Privilege {
+                    grantee: grantee.clone(),
+                    table_schema: table_schema.clone(),
+                    table_name: table_name.clone(),
+                    column: column.clone(),
+                    action: action.clone(),
+                };
+                *privileges.entry(privilege).or_default() |= grant.with_grant_option();
+            }
+        }
+    }
+}
+
+/// Collects the privileges granted in a database, mapped to whether they are
+/// held with grant option.
+pub(super) fn collect_privileges<DB: DatabaseLike>(database: &DB) -> BTreeMap<Privilege, bool> {
+    let mut privileges = BTreeMap::new();
+    for grant in database.table_grants() {
+        for table in grant.tables(database) {
+            insert_grant(&mut privileges, grant, table, database);
+        }
+    }
+    for grant in database.column_grants() {
+        if let Some(table) = grant.table(database) {
+            insert_grant(&mut privileges, grant, table, database);
+        }
+    }
+    privileges
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    fn parse(sql: &str) -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse")
+    }
+
+    fn statements(current: &ParserDB, desired: &ParserDB) -> Vec<String> {
+        AccessDiff::new(current, desired).statements().collect()
+    }
+
+    #[test]
+    fn test_identical_access_has_no_changes() {
+        let current = parse(
+            "
+            CREATE ROLE App;
+            CREATE TABLE T (id INT);
+            GRANT SELECT, INSERT ON T TO App;
+            ",
+        );
+        let desired = parse(
+            "
+            CREATE ROLE app;
+            CREATE TABLE t (id INT);
+            GRANT INSERT ON t TO app;
+            GRANT SELECT ON t TO app;
+            ",
+        );
+        let diff = AccessDiff::new(&current, &desired);
+        assert!(diff.is_empty());
+        assert!(diff.extra_roles().is_empty());
+    }
+
+    #[test]
+    fn test_role_attributes_and_memberships() {
+        let current = parse(
+            "
+            CREATE ROLE staff;
+            CREATE ROLE auditor;
+            CREATE ROLE alice LOGIN IN ROLE auditor;
+            ",
+        );
+        let desired = parse(
+            "
+            CREATE ROLE staff;
+            CREATE ROLE alice LOGIN CREATEDB CONNECTION LIMIT 5 IN ROLE staff;
+            ",
+        );
+        let diff = AccessDiff::new(&current, &desired);
+        assert_eq!(
+            diff.statements().collect::<Vec<_>>(),
+            [
+                "ALTER ROLE alice WITH CREATEDB CONNECTION LIMIT 5;",
+                "REVOKE auditor FROM alice;",
+                "GRANT staff TO alice;",
+            ]
+        );
+        assert_eq!(diff.extra_roles(), ["auditor"]);
+    }
+
+    #[test]
+    fn test_grant_option_and_column_privileges() {
+        let current = parse(
+            "
+            CREATE ROLE r;
+            CREATE TABLE t (id INT, secret TEXT);
+            GRANT SELECT ON t TO r WITH GRANT OPTION;
+            GRANT UPDATE (id, secret) ON t TO r;
+            ",
+        );
+        let desired = parse(
+            "
+            CREATE ROLE r;
+            CREATE TABLE t (id INT, secret TEXT);
+            GRANT SELECT ON t TO r;
+            GRANT UPDATE (id) ON t TO r;
+            GRANT TRUNCATE ON t TO PUBLIC;
+            ",
+        );
+        assert_eq!(
+            statements(&current, &desired),
+            [
+                "REVOKE GRANT OPTION FOR SELECT ON t FROM r;",
+                "REVOKE UPDATE (secret) ON t FROM r;",
+                "GRANT TRUNCATE ON t TO PUBLIC;",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quoted_identifiers_are_rendered_quoted() {
+        let current = parse("CREATE TABLE \"Orders\" (id INT);");
+        let desired = parse(
+            "
+            CREATE ROLE \"Reporting\";
+            CREATE TABLE \"Orders\" (id INT);
+            GRANT SELECT ON \"Orders\" TO \"Reporting\";
+            ",
+        );
+        assert_eq!(
+            statements(&current, &desired),
+            ["CREATE ROLE \"Reporting\";", "GRANT SELECT ON \"Orders\" TO \"Reporting\";"]
+        );
+    }
+}
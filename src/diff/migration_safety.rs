@@ -0,0 +1,706 @@
+//! Submodule classifying migration statements by the operational risk of
+//! running them against a live database.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use sqlparser::{
+    ast::{
+        AlterColumnOperation, AlterTableOperation, ColumnOption, Expr, Function, FunctionArg,
+        FunctionArgExpr, FunctionArguments, ObjectType, Statement,
+    },
+    dialect::Dialect,
+    keywords::Keyword,
+    parser::Parser,
+    tokenizer::{Token, TokenWithSpan, Tokenizer},
+};
+
+use crate::{
+    impls::SqlparserDialect,
+    structs::DialectCapability,
+    utils::{
+        byte_offset,
+        object_name::{object_name_last_part, schema_from_object_name},
+    },
+};
+
+/// Operational risk of running a migration statement against a live
+/// database.
+///
+/// Variants are ordered by increasing severity, so that the riskiest
+/// statement of a migration can be obtained with [`Ord::max`]. The
+/// classification follows the locking behaviour of PostgreSQL, which other
+/// engines match or improve upon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MigrationSafety {
+    /// The statement only changes the catalog, or does its work without
+    /// blocking reads and writes.
+    Online,
+    /// The statement blocks writes, and possibly reads, for a time
+    /// proportional to the size of the table, e.g. while building an index
+    /// or validating a constraint.
+    Locking,
+    /// The statement rewrites the whole table while holding an exclusive
+    /// lock on it.
+    Rewrite,
+}
+
+impl Display for MigrationSafety {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Online => "online",
+            Self::Locking => "locking",
+            Self::Rewrite => "rewrite",
+        })
+    }
+}
+
+/// Built-in functions of PostgreSQL which are `STABLE` or `IMMUTABLE`, and
+/// are thus evaluated once when they provide the default of an added column.
+/// The volatility of the other functions is unknown without the database,
+/// so they are assumed to be `VOLATILE`, as are `random()` and
+/// `gen_random_uuid()`.
+const NON_VOLATILE_FUNCTIONS: &[&str] = &[
+    "now",
+    "current_timestamp",
+    "current_date",
+    "current_time",
+    "localtimestamp",
+    "localtime",
+    "transaction_timestamp",
+    "statement_timestamp",
+    "current_user",
+    "current_role",
+    "session_user",
+    "current_schema",
+    "current_database",
+    "current_setting",
+    "coalesce",
+    "nullif",
+    "greatest",
+    "least",
+    "lower",
+    "upper",
+    "length",
+    "concat",
+    "abs",
+    "round",
+    "to_char",
+    "to_date",
+    "to_timestamp",
+    "date_trunc",
+    "make_date",
+    "make_interval",
+];
+
+/// Returns whether the function is a built-in function of PostgreSQL which
+/// is not `VOLATILE`, called with arguments which are themselves constant.
+fn is_non_volatile_call(function: &Function) -> bool {
+    let Some((name, quoted)) = object_name_last_part(&function.name) else {
+        return false;
+    };
+    let built_in = schema_from_object_name(&function.name)
+        .is_none_or(|(schema, quoted)| !quoted && schema.eq_ignore_ascii_case("pg_catalog"));
+    // Unquoted names are folded to lowercase by PostgreSQL.
+    let non_volatile = NON_VOLATILE_FUNCTIONS.iter().any(|candidate| {
+        if quoted { name == *candidate } else { name.eq_ignore_ascii_case(candidate) }
+    });
+    if !built_in || !non_volatile {
+        return false;
+    }
+    match &function.args {
+        FunctionArguments::None => true,
+        FunctionArguments::List(list) => list.args.iter().all(|argument| match argument {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))
+            | FunctionArg::Named { arg: FunctionArgExpr::Expr(expr), .. } => {
+                is_constant_expression(expr)
+            }
+            _ => false,
+        }),
+        FunctionArguments::Subquery(_) => false,
+    }
+}
+
+/// Returns whether the expression is a constant, or a call to a function
+/// which is not `VOLATILE`, which PostgreSQL evaluates once and stores in the
+/// catalog instead of writing it to every existing row.
+fn is_constant_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Value(_) => true,
+        Expr::UnaryOp { expr, .. } | Expr::Nested(expr) | Expr::Cast { expr, .. } => {
+            is_constant_expression(expr)
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            is_constant_expression(left) && is_constant_expression(right)
+        }
+        Expr::Function(function) => is_non_volatile_call(function),
+        _ => false,
+    }
+}
+
+/// Classifies a single `ALTER TABLE` operation.
+fn classify_alter_table_operation(
+    operation: &AlterTableOperation,
+) -> (MigrationSafety, &'static str) {
+    use MigrationSafety::{Locking, Online, Rewrite};
+
+    match operation {
+        AlterTableOperation::AddColumn { column_def, .. } => column_def
+            .options
+            .iter()
+            .map(|option| &option.option)
+            .fold((Online, "adds a column without touching existing rows"), |current, option| {
+                let classification = match option {
+                    ColumnOption::Generated { .. } => {
+                        (Rewrite, "computes the generated column for every existing row")
+                    }
+                    ColumnOption::Default(expr) if !is_constant_expression(expr) => {
+                        (Rewrite, "writes the non-constant default to every existing row")
+                    }
+                    ColumnOption::PrimaryKey(_) | ColumnOption::Unique(_) => {
+                        (Locking, "blocks writes to the table while the index is built")
+                    }
+                    ColumnOption::Check(_) | ColumnOption::ForeignKey(_) => {
+                        (Locking, "scans the table to validate the constraint")
+                    }
+                    _ => return current,
+                };
+                if classification.0 > current.0 { classification } else { current }
+            }),
+        AlterTableOperation::AddConstraint { .. } => {
+            (Locking, "blocks writes to the table while existing rows are validated")
+        }
+        AlterTableOperation::AlterColumn { op, .. } => match op {
+            AlterColumnOperation::SetDataType { .. } => {
+                (Rewrite, "converts the column of every existing row")
+            }
+            AlterColumnOperation::SetNotNull => {
+                (Locking, "scans the table to validate the constraint")
+            }
+            _ => (Online, "only changes the catalog"),
+        },
+        AlterTableOperation::DropColumn { .. }
+        | AlterTableOperation::DropConstraint { .. }
+        | AlterTableOperation::RenameColumn { .. }
+        | AlterTableOperation::RenameTable { .. }
+        | AlterTableOperation::EnableRowLevelSecurity
+        | AlterTableOperation::DisableRowLevelSecurity
+        | AlterTableOperation::ForceRowLevelSecurity
+        | AlterTableOperation::NoForceRowLevelSecurity => (Online, "only changes the catalog"),
+        _ => (Locking, "unclassified operation, assumed to lock the table"),
+    }
+}
+
+/// Classifies a migration statement, which drops its indexes `CONCURRENTLY`
+/// if flagged so.
+fn classify(statement: &Statement, concurrent_drop: bool) -> (MigrationSafety, &'static str) {
+    use MigrationSafety::{Locking, Online};
+
+    match statement {
+        Statement::CreateIndex(create_index) if create_index.concurrently => {
+            (Online, "builds the index without blocking writes, outside of a transaction block")
+        }
+        Statement::CreateIndex(_) => {
+            (Locking, "blocks writes to the table while the index is built")
+        }
+        Statement::AlterTable(alter_table) => alter_table
+            .operations
+            .iter()
+            .map(classify_alter_table_operation)
+            .max_by_key(|(safety, _)| *safety)
+            .unwrap_or((Online, "only changes the catalog")),
+        Statement::Drop { object_type: ObjectType::Index, .. } if concurrent_drop => (
+            Online,
+            "drops the index without blocking reads and writes, outside of a transaction block",
+        ),
+        Statement::Drop { object_type: ObjectType::Index, .. } => {
+            (Locking, "blocks reads and writes to the table while the index is dropped")
+        }
+        Statement::Insert(_) | Statement::Update(_) | Statement::Delete(_) => {
+            (Locking, "locks the affected rows until the migration commits")
+        }
+        Statement::CreateTable(_)
+        | Statement::CreateView(_)
+        | Statement::CreateSchema { .. }
+        | Statement::AlterSchema(_)
+        | Statement::CreateFunction(_)
+        | Statement::DropFunction(_)
+        | Statement::CreateTrigger(_)
+        | Statement::DropTrigger(_)
+        | Statement::CreatePolicy(_)
+        | Statement::AlterPolicy(_)
+        | Statement::DropPolicy(_)
+        | Statement::CreateRole(_)
+        | Statement::Grant(_)
+        | Statement::Revoke(_)
+        | Statement::Drop { .. } => (Online, "only changes the catalog"),
+        _ => (Locking, "unclassified statement, assumed to lock the objects it touches"),
+    }
+}
+
+/// Migration statement annotated with its operational risk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassifiedStatement {
+    /// The statement.
+    statement: Statement,
+    /// Whether the statement is a `DROP INDEX CONCURRENTLY`, whose flag
+    /// `sqlparser` does not parse, and which is therefore kept aside.
+    concurrent_drop: bool,
+    /// The operational risk of the statement.
+    safety: MigrationSafety,
+    /// Why the statement was given its classification.
+    reason: &'static str,
+}
+
+impl ClassifiedStatement {
+    /// Classifies the provided statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The migration statement to classify.
+    #[must_use]
+    pub fn new(statement: Statement) -> Self {
+        Self::with_concurrent_drop(statement, false)
+    }
+
+    /// Classifies the provided statement, which drops its index
+    /// `CONCURRENTLY` if flagged so.
+    fn with_concurrent_drop(statement: Statement, concurrent_drop: bool) -> Self {
+        let concurrent_drop = concurrent_drop && is_concurrent_drop_candidate(&statement);
+        let (safety, reason) = classify(&statement, concurrent_drop);
+        Self { statement, concurrent_drop, safety, reason }
+    }
+
+    /// Returns the statement.
+    ///
+    /// The `CONCURRENTLY` flag of a `DROP INDEX` statement is not part of
+    /// the returned statement, as `sqlparser` does not model it, but is
+    /// reported by [`Self::drops_concurrently`] and kept when the classified
+    /// statement is displayed.
+    #[must_use]
+    #[inline]
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Returns whether the statement is a `DROP INDEX CONCURRENTLY`.
+    #[must_use]
+    #[inline]
+    pub fn drops_concurrently(&self) -> bool {
+        self.concurrent_drop
+    }
+
+    /// Returns the operational risk of the statement.
+    #[must_use]
+    #[inline]
+    pub fn safety(&self) -> MigrationSafety {
+        self.safety
+    }
+
+    /// Returns why the statement was given its classification.
+    #[must_use]
+    #[inline]
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl Display for ClassifiedStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-- {}: {}\n", self.safety, self.reason)?;
+        let statement = self.statement.to_string();
+        match statement.strip_prefix("DROP INDEX ").filter(|_| self.concurrent_drop) {
+            Some(rest) => write!(f, "DROP INDEX CONCURRENTLY {rest};"),
+            None => write!(f, "{statement};"),
+        }
+    }
+}
+
+/// Returns whether the statement is a `DROP INDEX` which may be run
+/// `CONCURRENTLY`, which PostgreSQL only allows for a single index and
+/// without `CASCADE`.
+fn is_concurrent_drop_candidate(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::Drop { object_type: ObjectType::Index, names, cascade: false, .. }
+            if names.len() == 1
+    )
+}
+
+/// Returns whether the token is the provided keyword.
+fn is_keyword(token: &TokenWithSpan, keyword: Keyword) -> bool {
+    matches!(&token.token, Token::Word(word) if word.keyword == keyword)
+}
+
+/// Blanks out the `CONCURRENTLY` flags of `DROP INDEX` statements, which
+/// `sqlparser` does not parse, returning the SQL without them along with
+/// whether each `DROP INDEX` statement, in order, was flagged.
+///
+/// The flags are replaced by spaces, so that the locations of the remaining
+/// tokens are preserved. SQL which cannot be tokenized is returned as is,
+/// leaving the parser to report the error.
+fn strip_concurrent_drops(dialect: &dyn Dialect, sql: &str) -> (String, Vec<bool>) {
+    let mut stripped = String::from(sql);
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return (stripped, Vec::new());
+    };
+    let tokens: Vec<TokenWithSpan> =
+        tokens.into_iter().filter(|token| !matches!(token.token, Token::Whitespace(_))).collect();
+
+    let mut flags = Vec::new();
+    for (position, window) in tokens.windows(3).enumerate() {
+        let [drop, index, flag] = window else {
+            continue;
+        };
+        // The `DROP INDEX` clause of `ALTER TABLE` in MySQL is not a
+        // statement of its own.
+        let starts_statement =
+            position == 0 || matches!(tokens[position - 1].token, Token::SemiColon);
+        if !starts_statement
+            || !is_keyword(drop, Keyword::DROP)
+            || !is_keyword(index, Keyword::INDEX)
+        {
+            continue;
+        }
+        let concurrent = is_keyword(flag, Keyword::CONCURRENTLY);
+        if concurrent
+            && let (Some(start), Some(end)) =
+                (byte_offset(sql, flag.span.start), byte_offset(sql, flag.span.end))
+        {
+            stripped.replace_range(start..end, &" ".repeat(end - start));
+        }
+        flags.push(concurrent);
+    }
+    (stripped, flags)
+}
+
+/// Migration whose statements are classified by operational risk.
+///
+/// Rendering the plan yields the migration SQL with every statement preceded
+/// by a comment stating its classification, so that reviewers can spot
+/// statements which lock or rewrite tables before running them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationPlan {
+    /// The classified statements, in the order they should be applied.
+    statements: Vec<ClassifiedStatement>,
+    /// The dialect the migration is written in.
+    dialect: SqlparserDialect,
+}
+
+impl MigrationPlan {
+    /// Parses and classifies the statements of a migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql` - The SQL of the migration.
+    ///
+    /// # Errors
+    ///
+    /// * If the SQL cannot be parsed in the provided dialect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::diff::{MigrationPlan, MigrationSafety};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let plan = MigrationPlan::parse::<PostgreSqlDialect>(
+    ///     "
+    /// ALTER TABLE users ADD COLUMN active BOOLEAN DEFAULT true;
+    /// CREATE INDEX users_active ON users (active);
+    /// ",
+    /// )?;
+    /// assert_eq!(plan.safety(), MigrationSafety::Locking);
+    /// assert_eq!(
+    ///     plan.to_string(),
+    ///     "-- online: adds a column without touching existing rows
+    /// ALTER TABLE users ADD COLUMN active BOOLEAN DEFAULT true;
+    /// -- locking: blocks writes to the table while the index is built
+    /// CREATE INDEX users_active ON users(active);
+    /// "
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse<D: Dialect + Default + 'static>(sql: &str) -> Result<Self, crate::errors::Error> {
+        let dialect = D::default();
+        let (sql, concurrent_drops) = strip_concurrent_drops(&dialect, sql);
+        let statements = Parser::new(&dialect).try_with_sql(&sql)?.parse_statements()?;
+        let mut concurrent_drops = concurrent_drops.into_iter();
+        Ok(Self {
+            statements: statements
+                .into_iter()
+                .map(|statement| {
+                    let concurrent_drop =
+                        matches!(statement, Statement::Drop { object_type: ObjectType::Index, .. })
+                            && concurrent_drops.next().unwrap_or(false);
+                    ClassifiedStatement::with_concurrent_drop(statement, concurrent_drop)
+                })
+                .collect(),
+            dialect: SqlparserDialect::of::<D>(),
+        })
+    }
+
+    /// Rewrites the statements into their online-safe variants, where the
+    /// dialect of the migration supports them.
+    ///
+    /// Currently, `CREATE INDEX` is rewritten into `CREATE INDEX
+    /// CONCURRENTLY`, and `DROP INDEX` into `DROP INDEX CONCURRENTLY` unless
+    /// it drops several indexes or cascades, for dialects supporting
+    /// [`DialectCapability::ConcurrentIndexes`]. Concurrent index builds and
+    /// drops cannot run inside a transaction block, so the migration runner
+    /// must not wrap the rewritten statements into one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::diff::{MigrationPlan, MigrationSafety};
+    /// use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+    ///
+    /// let sql = "CREATE INDEX users_email ON users (email);";
+    /// let plan = MigrationPlan::parse::<PostgreSqlDialect>(sql)?.prefer_online();
+    /// assert_eq!(plan.safety(), MigrationSafety::Online);
+    /// assert_eq!(
+    ///     plan.statements()[0].statement().to_string(),
+    ///     "CREATE INDEX CONCURRENTLY users_email ON users(email)"
+    /// );
+    ///
+    /// let plan = MigrationPlan::parse::<MySqlDialect>(sql)?.prefer_online();
+    /// assert_eq!(plan.safety(), MigrationSafety::Locking);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn prefer_online(mut self) -> Self {
+        if !self.dialect.supports(DialectCapability::ConcurrentIndexes) {
+            return self;
+        }
+        for classified in &mut self.statements {
+            if let Statement::CreateIndex(create_index) = &mut classified.statement
+                && !create_index.concurrently
+            {
+                create_index.concurrently = true;
+            } else if !classified.concurrent_drop
+                && is_concurrent_drop_candidate(&classified.statement)
+            {
+                classified.concurrent_drop = true;
+            } else {
+                continue;
+            }
+            (classified.safety, classified.reason) =
+                classify(&classified.statement, classified.concurrent_drop);
+        }
+        self
+    }
+
+    /// Returns the classified statements, in the order they should be
+    /// applied.
+    #[must_use]
+    #[inline]
+    pub fn statements(&self) -> &[ClassifiedStatement] {
+        &self.statements
+    }
+
+    /// Returns the operational risk of the riskiest statement of the
+    /// migration, or [`MigrationSafety::Online`] if it has no statements.
+    #[must_use]
+    pub fn safety(&self) -> MigrationSafety {
+        self.statements
+            .iter()
+            .map(ClassifiedStatement::safety)
+            .max()
+            .unwrap_or(MigrationSafety::Online)
+    }
+}
+
+impl Display for MigrationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for statement in &self.statements {
+            writeln!(f, "{statement}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    fn classifications(sql: &str) -> Vec<MigrationSafety> {
+        MigrationPlan::parse::<PostgreSqlDialect>(sql)
+            .expect("Failed to parse")
+            .statements()
+            .iter()
+            .map(ClassifiedStatement::safety)
+            .collect()
+    }
+
+    #[test]
+    fn test_add_column_defaults() {
+        assert_eq!(
+            classifications(
+                "
+                ALTER TABLE t ADD COLUMN a INT;
+                ALTER TABLE t ADD COLUMN b INT DEFAULT -1;
+                ALTER TABLE t ADD COLUMN c TEXT DEFAULT 'x'::TEXT;
+                ALTER TABLE t ADD COLUMN d UUID DEFAULT gen_random_uuid();
+                ALTER TABLE t ADD COLUMN e INT UNIQUE;
+                "
+            ),
+            [
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Rewrite,
+                MigrationSafety::Locking,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_column_non_volatile_defaults() {
+        assert_eq!(
+            classifications(
+                "
+                ALTER TABLE t ADD COLUMN a TIMESTAMPTZ DEFAULT now();
+                ALTER TABLE t ADD COLUMN b TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP;
+                ALTER TABLE t ADD COLUMN c TIMESTAMPTZ DEFAULT pg_catalog.now();
+                ALTER TABLE t ADD COLUMN d TEXT DEFAULT lower('X') || '-' || current_user;
+                ALTER TABLE t ADD COLUMN e FLOAT DEFAULT random();
+                ALTER TABLE t ADD COLUMN f TEXT DEFAULT lower(md5(random()::TEXT));
+                ALTER TABLE t ADD COLUMN g TIMESTAMPTZ DEFAULT app.now();
+                "
+            ),
+            [
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Rewrite,
+                MigrationSafety::Rewrite,
+                MigrationSafety::Rewrite,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alter_column_operations() {
+        assert_eq!(
+            classifications(
+                "
+                ALTER TABLE t ALTER COLUMN a SET DEFAULT 0;
+                ALTER TABLE t ALTER COLUMN a SET NOT NULL;
+                ALTER TABLE t ALTER COLUMN a TYPE BIGINT;
+                ALTER TABLE t DROP COLUMN b, ALTER COLUMN a TYPE BIGINT;
+                "
+            ),
+            [
+                MigrationSafety::Online,
+                MigrationSafety::Locking,
+                MigrationSafety::Rewrite,
+                MigrationSafety::Rewrite,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catalog_and_data_statements() {
+        assert_eq!(
+            classifications(
+                "
+                CREATE TABLE t (id INT);
+                GRANT SELECT ON t TO reader;
+                ALTER TABLE t ADD CONSTRAINT t_pk PRIMARY KEY (id);
+                DROP INDEX t_idx;
+                UPDATE t SET id = id + 1;
+                "
+            ),
+            [
+                MigrationSafety::Online,
+                MigrationSafety::Online,
+                MigrationSafety::Locking,
+                MigrationSafety::Locking,
+                MigrationSafety::Locking,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drop_index_concurrently() {
+        let plan = MigrationPlan::parse::<PostgreSqlDialect>(
+            "
+            DROP INDEX CONCURRENTLY IF EXISTS a;
+            ALTER TABLE t ADD COLUMN b INT;
+            DROP INDEX c;
+            ",
+        )
+        .expect("Failed to parse");
+        let statements = plan.statements();
+        assert!(statements[0].drops_concurrently());
+        assert_eq!(statements[0].safety(), MigrationSafety::Online);
+        assert!(!statements[2].drops_concurrently());
+        assert_eq!(statements[2].safety(), MigrationSafety::Locking);
+        assert_eq!(
+            plan.to_string(),
+            "-- online: drops the index without blocking reads and writes, outside of a transaction block
+DROP INDEX CONCURRENTLY IF EXISTS a;
+-- online: adds a column without touching existing rows
+ALTER TABLE t ADD COLUMN b INT;
+-- locking: blocks reads and writes to the table while the index is dropped
+DROP INDEX c;
+"
+        );
+    }
+
+    #[test]
+    fn test_prefer_online_drops_indexes_concurrently() {
+        let plan = MigrationPlan::parse::<PostgreSqlDialect>(
+            "
+            DROP INDEX a;
+            DROP INDEX b, c;
+            DROP INDEX d CASCADE;
+            ",
+        )
+        .expect("Failed to parse")
+        .prefer_online();
+        let concurrent: Vec<bool> =
+            plan.statements().iter().map(ClassifiedStatement::drops_concurrently).collect();
+        assert_eq!(concurrent, [true, false, false]);
+        assert_eq!(plan.statements()[0].safety(), MigrationSafety::Online);
+        assert_eq!(plan.safety(), MigrationSafety::Locking);
+        assert!(plan.to_string().contains("DROP INDEX CONCURRENTLY a;"));
+
+        let plan = MigrationPlan::parse::<sqlparser::dialect::MySqlDialect>("DROP INDEX a ON t;")
+            .expect("Failed to parse")
+            .prefer_online();
+        assert!(!plan.statements()[0].drops_concurrently());
+    }
+
+    #[test]
+    fn test_prefer_online_keeps_other_statements() {
+        let plan = MigrationPlan::parse::<PostgreSqlDialect>(
+            "
+            CREATE INDEX CONCURRENTLY a ON t (a);
+            CREATE UNIQUE INDEX b ON t (b);
+            ALTER TABLE t ALTER COLUMN c TYPE BIGINT;
+            ",
+        )
+        .expect("Failed to parse")
+        .prefer_online();
+        assert_eq!(
+            plan.to_string(),
+            "-- online: builds the index without blocking writes, outside of a transaction block
+CREATE INDEX CONCURRENTLY a ON t(a);
+-- online: builds the index without blocking writes, outside of a transaction block
+CREATE UNIQUE INDEX CONCURRENTLY b ON t(b);
+-- rewrite: converts the column of every existing row
+ALTER TABLE t ALTER COLUMN c TYPE BIGINT;
+"
+        );
+        assert_eq!(plan.safety(), MigrationSafety::Rewrite);
+    }
+}
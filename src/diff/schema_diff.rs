@@ -0,0 +1,1001 @@
+//! Submodule comparing the structure of two databases: their tables, columns,
+//! indices, constraints, policies, roles and grants.
+
+mod migration_script;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+pub use migration_script::MigrationScript;
+
+use super::{
+    ColumnTypeChange,
+    access::{RoleAttributes, collect_memberships, collect_privileges, collect_roles},
+};
+use crate::{
+    structs::IdentifierCasing,
+    traits::{
+        CheckConstraintLike, ColumnLike, DatabaseLike, DialectLike, ForeignKeyLike, IndexLike,
+        ObjectKind, PolicyLike, TableLike, UniqueIndexLike,
+    },
+    utils::object_name::render_normalized_identifier,
+};
+
+/// Name of the schema objects without an explicit schema belong to.
+const DEFAULT_SCHEMA: &str = "public";
+
+/// Identifies an object compared by a [`SchemaDiff`].
+///
+/// Schema and table names are stored normalized under the
+/// [`IdentifierCasing`] of the dialect of their database, the `public` schema being represented by `None`, so
+/// that objects spelled differently in the two databases are matched.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectPath {
+    /// The kind of the object.
+    kind: ObjectKind,
+    /// The normalized schema of the table the object belongs to.
+    schema: Option<String>,
+    /// The normalized name of the table the object belongs to.
+    table: Option<String>,
+    /// The name of the object as rendered in SQL.
+    name: String,
+}
+
+impl ObjectPath {
+    /// Returns the kind of the object.
+    #[must_use]
+    #[inline]
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    /// Returns the normalized schema of the table the object belongs to, or
+    /// `None` for the `public` schema and for roles.
+    #[must_use]
+    #[inline]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the normalized name of the table the object belongs to, which
+    /// is the table itself for tables and `None` for roles.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    /// Returns the name of the object as rendered in SQL. Objects which may
+    /// be unnamed, such as check constraints and grants, are named after
+    /// their definition.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Writes the qualified name of the table the object belongs to.
+    fn fmt_table(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(schema) = &self.schema {
+            write!(f, "{}.", render_normalized_identifier(schema))?;
+        }
+        if let Some(table) = &self.table {
+            write!(f, "{}", render_normalized_identifier(table))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ObjectPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.kind)?;
+        match self.kind {
+            ObjectKind::Table => self.fmt_table(f),
+            _ if self.table.is_none() => f.write_str(&self.name),
+            _ => {
+                write!(f, "{} on ", self.name)?;
+                self.fmt_table(f)
+            }
+        }
+    }
+}
+
+/// A difference in one attribute of an object present in both databases.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttributeChange {
+    /// The name of the attribute, such as `data type` or `nullable`.
+    attribute: &'static str,
+    /// The value of the attribute in the old database, if set.
+    old: Option<String>,
+    /// The value of the attribute in the new database, if set.
+    new: Option<String>,
+}
+
+impl AttributeChange {
+    /// Returns the name of the attribute, such as `data type` or `nullable`.
+    #[must_use]
+    #[inline]
+    pub fn attribute(&self) -> &'static str {
+        self.attribute
+    }
+
+    /// Returns the value of the attribute in the old database, if set.
+    #[must_use]
+    #[inline]
+    pub fn old_value(&self) -> Option<&str> {
+        self.old.as_deref()
+    }
+
+    /// Returns the value of the attribute in the new database, if set.
+    #[must_use]
+    #[inline]
+    pub fn new_value(&self) -> Option<&str> {
+        self.new.as_deref()
+    }
+}
+
+impl Display for AttributeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} -> {}",
+            self.attribute,
+            self.old.as_deref().unwrap_or("none"),
+            self.new.as_deref().unwrap_or("none")
+        )
+    }
+}
+
+/// A single structural difference between two databases.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaChange {
+    /// An object only present in the new database.
+    Added(ObjectPath),
+    /// An object only present in the old database.
+    Removed(ObjectPath),
+    /// An object present in both databases with differing attributes.
+    Changed {
+        /// The changed object.
+        object: ObjectPath,
+        /// The attributes differing between the databases.
+        attributes: Vec<AttributeChange>,
+    },
+}
+
+impl SchemaChange {
+    /// Returns the object the change concerns.
+    #[must_use]
+    pub fn object(&self) -> &ObjectPath {
+        match self {
+            Self::Added(object) | Self::Removed(object) | Self::Changed { object, .. } => object,
+        }
+    }
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(object) => write!(f, "added {object}"),
+            Self::Removed(object) => write!(f, "removed {object}"),
+            Self::Changed { object, attributes } => {
+                write!(f, "changed {object}: ")?;
+                for (position, attribute) in attributes.iter().enumerate() {
+                    if position > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{attribute}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Structural differences between two databases.
+///
+/// Tables, columns, indices, unique indices, foreign keys, check constraints,
+/// policies, roles and grants are matched by their normalized names, so that
+/// identifiers spelled differently but resolving to the same object compare
+/// equal. Unnamed objects are matched by their definition, so that a change
+/// of definition is reported as a removal followed by an addition.
+///
+/// Objects belonging to an added table and declared by its `CREATE TABLE`
+/// statement, namely its columns and constraints, are not reported
+/// separately, nor are the objects belonging to a removed table.
+///
+/// The statements reconciling the databases can be rendered with
+/// [`SchemaDiff::to_sql`], which covers columns, indices and named
+/// constraints.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SchemaDiff {
+    /// The changes, sorted by object kind and path.
+    changes: Vec<SchemaChange>,
+    /// The attributes of the changed objects, and of the objects rebuilt
+    /// around them, in the old and new databases.
+    definitions: BTreeMap<ObjectPath, (Option<Attributes>, Option<Attributes>)>,
+    /// The objects depending on the columns whose data type changes, in the
+    /// old and new databases, as collected by [`ColumnTypeChange`].
+    dependents: BTreeMap<ObjectPath, (Vec<ObjectPath>, Vec<ObjectPath>)>,
+}
+
+impl SchemaDiff {
+    /// Compares the structure of `old` against that of `new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The database before the changes.
+    /// * `new` - The database after the changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::SchemaDiff, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let old = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, age INT);
+    /// CREATE TABLE logs (id INT);
+    /// ",
+    /// )?;
+    /// let new = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, age BIGINT NOT NULL, email TEXT);
+    /// CREATE INDEX users_email_idx ON users (email);
+    /// ",
+    /// )?;
+    /// let changes: Vec<String> =
+    ///     SchemaDiff::between(&old, &new).changes().iter().map(ToString::to_string).collect();
+    /// assert_eq!(
+    ///     changes,
+    ///     [
+    ///         "removed table logs",
+    ///         "changed column age on users: data type INT -> BIGINT; nullable true -> false",
+    ///         "added column email on users",
+    ///         "added index users_email_idx on users",
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn between<A: DatabaseLike, B: DatabaseLike>(old: &A, new: &B) -> Self {
+        Self::compare(old, new, false)
+    }
+
+    /// Compares the structure of `old` against that of `new`, matching the
+    /// indices and constraints by their definition rather than by their
+    /// name, so that renaming them is not reported as a change.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The database before the changes.
+    /// * `new` - The database after the changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::SchemaDiff, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let old = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE users (id INT, CONSTRAINT users_id_key UNIQUE (id));",
+    /// )?;
+    /// let new = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE users (id INT, CONSTRAINT users_unique_id UNIQUE (id));",
+    /// )?;
+    /// assert!(!SchemaDiff::between(&old, &new).is_empty());
+    /// assert!(SchemaDiff::between_ignoring_constraint_names(&old, &new).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn between_ignoring_constraint_names<A: DatabaseLike, B: DatabaseLike>(
+        old: &A,
+        new: &B,
+    ) -> Self {
+        Self::compare(old, new, true)
+    }
+
+    /// Compares the structure of `old` against that of `new`, matching the
+    /// indices and constraints by their definition if `ignore_constraint_names`
+    /// is set.
+    fn compare<A: DatabaseLike, B: DatabaseLike>(
+        old: &A,
+        new: &B,
+        ignore_constraint_names: bool,
+    ) -> Self {
+        let mut old_objects = collect_objects(old, ignore_constraint_names);
+        let mut new_objects = collect_objects(new, ignore_constraint_names);
+
+        let mut changes = Vec::new();
+        for (object, old_attributes) in &old_objects {
+            let Some(new_attributes) = new_objects.get(object) else {
+                changes.push(SchemaChange::Removed(object.clone()));
+                continue;
+            };
+            let attributes: Vec<AttributeChange> = old_attributes
+                .iter()
+                .zip(new_attributes)
+                .filter(|((_, old), (_, new))| old != new)
+                .map(|((attribute, old), (_, new))| AttributeChange {
+                    attribute: *attribute,
+                    old: old.clone(),
+                    new: new.clone(),
+                })
+                .collect();
+            if !attributes.is_empty() {
+                changes.push(SchemaChange::Changed { object: object.clone(), attributes });
+            }
+        }
+        changes.extend(
+            new_objects
+                .keys()
+                .filter(|object| !old_objects.contains_key(object))
+                .cloned()
+                .map(SchemaChange::Added),
+        );
+        changes.sort_by(|left, right| left.object().cmp(right.object()));
+
+        let scopes = |added: bool| -> BTreeSet<(Option<String>, Option<String>)> {
+            changes
+                .iter()
+                .filter_map(|change| match change {
+                    SchemaChange::Added(object) if added => Some(object),
+                    SchemaChange::Removed(object) if !added => Some(object),
+                    _ => None,
+                })
+                .filter(|object| object.kind == ObjectKind::Table)
+                .map(|object| (object.schema.clone(), object.table.clone()))
+                .collect()
+        };
+        let added_tables = scopes(true);
+        let removed_tables = scopes(false);
+        changes.retain(|change| {
+            let object = change.object();
+            if object.kind == ObjectKind::Table || object.table.is_none() {
+                return true;
+            }
+            let scope = (object.schema.clone(), object.table.clone());
+            let declared_inline =
+                !matches!(object.kind, ObjectKind::Index | ObjectKind::Policy | ObjectKind::Grant);
+            !removed_tables.contains(&scope) && !(declared_inline && added_tables.contains(&scope))
+        });
+
+        let mut definitions: BTreeMap<ObjectPath, (Option<Attributes>, Option<Attributes>)> =
+            changes
+                .iter()
+                .map(|change| {
+                    let object = change.object();
+                    (object.clone(), (old_objects.remove(object), new_objects.remove(object)))
+                })
+                .collect();
+
+        // The objects depending on a column whose data type changes are
+        // rebuilt around the change, unless they change themselves.
+        let is_unchanged = |(object, _): &(ObjectPath, Attributes)| {
+            changes.binary_search_by(|change| change.object().cmp(object)).is_err()
+        };
+        let mut dependents = BTreeMap::new();
+        for change in &changes {
+            let SchemaChange::Changed { object, attributes } = change else {
+                continue;
+            };
+            let Some(data_type) =
+                attributes.iter().find(|attribute| attribute.attribute == "data type")
+            else {
+                continue;
+            };
+            let old_dependents: Vec<(ObjectPath, Attributes)> = type_change_dependents(
+                old,
+                object,
+                data_type.new.as_deref().unwrap_or_default(),
+                ignore_constraint_names,
+            )
+            .into_iter()
+            .filter(is_unchanged)
+            .collect();
+            let new_dependents: Vec<(ObjectPath, Attributes)> = type_change_dependents(
+                new,
+                object,
+                data_type.old.as_deref().unwrap_or_default(),
+                ignore_constraint_names,
+            )
+            .into_iter()
+            .filter(is_unchanged)
+            .collect();
+            let old_paths = old_dependents.iter().map(|(path, _)| path.clone()).collect();
+            let new_paths = new_dependents.iter().map(|(path, _)| path.clone()).collect();
+            for (path, attributes) in old_dependents {
+                definitions.entry(path).or_default().0 = Some(attributes);
+            }
+            for (path, attributes) in new_dependents {
+                definitions.entry(path).or_default().1 = Some(attributes);
+            }
+            dependents.insert(object.clone(), (old_paths, new_paths));
+        }
+
+        Self { changes, definitions, dependents }
+    }
+
+    /// Returns the attributes of a changed object in the old database, in the
+    /// order [`collect_objects`] records them.
+    fn old_attributes(&self, object: &ObjectPath) -> Option<&Attributes> {
+        self.definitions.get(object).and_then(|(old, _)| old.as_ref())
+    }
+
+    /// Returns the attributes of a changed object in the new database, in the
+    /// order [`collect_objects`] records them.
+    fn new_attributes(&self, object: &ObjectPath) -> Option<&Attributes> {
+        self.definitions.get(object).and_then(|(_, new)| new.as_ref())
+    }
+
+    /// Returns the objects depending on a column whose data type changes, in
+    /// the old and new databases.
+    fn dependents(&self, column: &ObjectPath) -> Option<&(Vec<ObjectPath>, Vec<ObjectPath>)> {
+        self.dependents.get(column)
+    }
+
+    /// Returns the changes, sorted by object kind and path.
+    #[must_use]
+    #[inline]
+    pub fn changes(&self) -> &[SchemaChange] {
+        &self.changes
+    }
+
+    /// Iterates over the changes concerning objects of the provided kind.
+    pub fn changes_of_kind(&self, kind: ObjectKind) -> impl Iterator<Item = &SchemaChange> {
+        self.changes.iter().filter(move |change| change.object().kind() == kind)
+    }
+
+    /// Returns whether the two databases have the same structure.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Attributes describing an object, compared pairwise between the databases.
+type Attributes = Vec<(&'static str, Option<String>)>;
+
+/// Renders a boolean attribute.
+fn flag(value: bool) -> Option<String> {
+    Some(value.to_string())
+}
+
+/// Renders an identifier normalized under the provided casing.
+fn rendered(value: &str, quoted: bool, casing: IdentifierCasing) -> String {
+    render_normalized_identifier(&casing.normalize(value, quoted))
+}
+
+/// Returns whether the normalized schema is the `public` schema under the
+/// provided casing.
+fn is_default_schema(schema: &str, casing: IdentifierCasing) -> bool {
+    schema == casing.normalize(DEFAULT_SCHEMA, false)
+}
+
+/// Returns the schema and name of a table normalized under the provided
+/// casing, the `public` schema being represented by `None`.
+fn table_scope<T: TableLike>(table: &T, casing: IdentifierCasing) -> (Option<String>, String) {
+    let schema = table
+        .table_schema()
+        .map(|schema| casing.normalize(schema, table.table_schema_is_quoted()).into_owned())
+        .filter(|schema| !is_default_schema(schema, casing));
+    (schema, casing.normalize(table.table_name(), table.table_name_is_quoted()).into_owned())
+}
+
+/// Renders the columns as a comma separated list.
+fn column_list<'db, C: ColumnLike + 'db>(
+    columns: impl Iterator<Item = &'db C>,
+    casing: IdentifierCasing,
+) -> String {
+    columns
+        .map(|column| rendered(column.column_name(), column.column_name_is_quoted(), casing))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the path of an object belonging to the provided table, whose
+/// names are normalized under the provided casing.
+fn table_object_path<T: TableLike>(
+    table: &T,
+    kind: ObjectKind,
+    name: String,
+    casing: IdentifierCasing,
+) -> ObjectPath {
+    let (schema, table_name) = table_scope(table, casing);
+    ObjectPath { kind, schema, table: Some(table_name), name }
+}
+
+/// Returns the name identifying an index or constraint, which is its
+/// definition if it is unnamed or if `ignore_constraint_names` is set.
+fn constraint_name(
+    name: Option<String>,
+    definition: &str,
+    ignore_constraint_names: bool,
+) -> String {
+    match name {
+        Some(name) if !ignore_constraint_names => name,
+        _ => definition.to_string(),
+    }
+}
+
+/// Returns the path and the attributes of an index.
+fn index_entry<DB: DatabaseLike>(
+    database: &DB,
+    index: &DB::Index,
+    ignore_constraint_names: bool,
+) -> (ObjectPath, Attributes) {
+    let casing = database.dialect().identifier_casing();
+    let definition = index.expression(database).to_string();
+    let name = constraint_name(
+        index.index_name().map(|name| rendered(name, index.index_name_is_quoted(), casing)),
+        &definition,
+        ignore_constraint_names,
+    );
+    (
+        table_object_path(IndexLike::table(index, database), ObjectKind::Index, name, casing),
+        vec![
+            ("definition", Some(definition)),
+            ("predicate", index.predicate().map(ToString::to_string)),
+        ],
+    )
+}
+
+/// Returns the path and the attributes of a unique index.
+fn unique_index_entry<DB: DatabaseLike>(
+    database: &DB,
+    unique_index: &DB::UniqueIndex,
+    ignore_constraint_names: bool,
+) -> (ObjectPath, Attributes) {
+    let casing = database.dialect().identifier_casing();
+    let definition = unique_index.expression(database).to_string();
+    let name = constraint_name(
+        unique_index
+            .index_name()
+            .map(|name| rendered(name, unique_index.index_name_is_quoted(), casing)),
+        &definition,
+        ignore_constraint_names,
+    );
+    (
+        table_object_path(
+            IndexLike::table(unique_index, database),
+            ObjectKind::UniqueIndex,
+            name,
+            casing,
+        ),
+        vec![
+            ("definition", Some(definition)),
+            ("primary key", flag(unique_index.is_primary_key(database))),
+        ],
+    )
+}
+
+/// Returns the path and the attributes of a foreign key, or `None` if its
+/// referenced table is not in the database.
+fn foreign_key_entry<DB: DatabaseLike>(
+    database: &DB,
+    foreign_key: &DB::ForeignKey,
+    ignore_constraint_names: bool,
+) -> Option<(ObjectPath, Attributes)> {
+    let casing = database.dialect().identifier_casing();
+    let referenced_table = foreign_key.referenced_table(database)?;
+    let (referenced_schema, referenced_name) = table_scope(referenced_table, casing);
+    let referenced_name = match referenced_schema {
+        Some(referenced_schema) => {
+            format!(
+                "{}.{}",
+                render_normalized_identifier(&referenced_schema),
+                render_normalized_identifier(&referenced_name)
+            )
+        }
+        None => render_normalized_identifier(&referenced_name),
+    };
+    let definition = format!(
+        "({}) REFERENCES {referenced_name} ({})",
+        column_list(foreign_key.host_columns(database), casing),
+        column_list(foreign_key.referenced_columns(database), casing)
+    );
+    let name = constraint_name(
+        foreign_key.foreign_key_name().map(|name| rendered(name, false, casing)),
+        &definition,
+        ignore_constraint_names,
+    );
+    Some((
+        table_object_path(foreign_key.host_table(database), ObjectKind::ForeignKey, name, casing),
+        vec![
+            ("definition", Some(definition)),
+            ("on delete cascade", flag(foreign_key.on_delete_cascade(database))),
+        ],
+    ))
+}
+
+/// Returns the path and the attributes of a check constraint.
+fn check_constraint_entry<DB: DatabaseLike>(
+    database: &DB,
+    check_constraint: &DB::CheckConstraint,
+) -> (ObjectPath, Attributes) {
+    (
+        table_object_path(
+            CheckConstraintLike::table(check_constraint, database),
+            ObjectKind::CheckConstraint,
+            check_constraint.expression(database).to_string(),
+            database.dialect().identifier_casing(),
+        ),
+        Vec::new(),
+    )
+}
+
+/// Returns the path and the attributes of a policy.
+fn policy_entry<DB: DatabaseLike>(database: &DB, policy: &DB::Policy) -> (ObjectPath, Attributes) {
+    // Policies without roles apply to every role.
+    let roles: Vec<String> = policy.roles(database).map(ToString::to_string).collect();
+    let roles = if roles.is_empty() { "PUBLIC".to_string() } else { roles.join(", ") };
+    let casing = database.dialect().identifier_casing();
+    (
+        table_object_path(
+            PolicyLike::table(policy, database),
+            ObjectKind::Policy,
+            rendered(policy.name(), false, casing),
+            casing,
+        ),
+        vec![
+            ("command", Some(policy.command().to_string())),
+            ("roles", Some(roles)),
+            ("using", policy.using_expression(database).map(ToString::to_string)),
+            ("with check", policy.check_expression(database).map(ToString::to_string)),
+        ],
+    )
+}
+
+/// Returns the paths and the attributes of the objects depending on the
+/// column at `column`, which must be rebuilt around a change of its data type
+/// to `target_type`.
+fn type_change_dependents<DB: DatabaseLike>(
+    database: &DB,
+    column: &ObjectPath,
+    target_type: &str,
+    ignore_constraint_names: bool,
+) -> Vec<(ObjectPath, Attributes)> {
+    let casing = database.dialect().identifier_casing();
+    let Some(changed_column) = database
+        .tables()
+        .filter(|table| {
+            let (schema, name) = table_scope(*table, casing);
+            schema.as_deref() == column.schema() && column.table() == Some(name.as_str())
+        })
+        .flat_map(|table| table.columns(database))
+        .find(|candidate| {
+            rendered(candidate.column_name(), candidate.column_name_is_quoted(), casing)
+                == column.name()
+        })
+    else {
+        return Vec::new();
+    };
+
+    let change = ColumnTypeChange::new(database, changed_column, target_type);
+    let mut dependents = Vec::new();
+    dependents.extend(
+        change.indices().iter().map(|index| index_entry(database, *index, ignore_constraint_names)),
+    );
+    dependents.extend(
+        change
+            .unique_indices()
+            .iter()
+            .map(|index| unique_index_entry(database, *index, ignore_constraint_names)),
+    );
+    dependents.extend(change.foreign_keys().iter().filter_map(|foreign_key| {
+        foreign_key_entry(database, *foreign_key, ignore_constraint_names)
+    }));
+    dependents.extend(
+        change
+            .check_constraints()
+            .iter()
+            .map(|check_constraint| check_constraint_entry(database, *check_constraint)),
+    );
+    dependents.extend(change.policies().iter().map(|policy| policy_entry(database, *policy)));
+    dependents
+}
+
+/// Collects the objects of a database along with the attributes describing
+/// them. Indices and constraints are named after their definition if
+/// `ignore_constraint_names` is set.
+fn collect_objects<DB: DatabaseLike>(
+    database: &DB,
+    ignore_constraint_names: bool,
+) -> BTreeMap<ObjectPath, Attributes> {
+    let casing = database.dialect().identifier_casing();
+    let mut objects = BTreeMap::new();
+
+    for table in database.tables() {
+        objects.insert(
+            table_object_path(
+                table,
+                ObjectKind::Table,
+                rendered(table.table_name(), table.table_name_is_quoted(), casing),
+                casing,
+            ),
+            vec![
+                ("row level security", flag(table.has_row_level_security(database))),
+                ("forced row level security", flag(table.has_forced_row_level_security(database))),
+            ],
+        );
+        for column in table.columns(database) {
+            objects.insert(
+                table_object_path(
+                    table,
+                    ObjectKind::Column,
+                    rendered(column.column_name(), column.column_name_is_quoted(), casing),
+                    casing,
+                ),
+                vec![
+                    ("data type", Some(column.normalized_data_type(database).to_string())),
+                    ("nullable", flag(column.is_nullable(database))),
+                    ("default", column.default_value()),
+                ],
+            );
+        }
+        objects.extend(
+            table
+                .indices(database)
+                .map(|index| index_entry(database, index, ignore_constraint_names)),
+        );
+        objects.extend(
+            table
+                .unique_indices(database)
+                .map(|index| unique_index_entry(database, index, ignore_constraint_names)),
+        );
+        objects.extend(table.foreign_keys(database).filter_map(|foreign_key| {
+            foreign_key_entry(database, foreign_key, ignore_constraint_names)
+        }));
+        objects.extend(
+            table
+                .check_constraints(database)
+                .map(|check_constraint| check_constraint_entry(database, check_constraint)),
+        );
+        objects.extend(table.policies(database).map(|policy| policy_entry(database, policy)));
+    }
+
+    let memberships = collect_memberships(database);
+    for (role, attributes) in collect_roles(database) {
+        let member_of: Vec<String> = memberships
+            .iter()
+            .filter(|(_, member)| *member == role)
+            .map(|(granted, _)| render_normalized_identifier(granted))
+            .collect();
+        let mut attributes = role_attributes(&attributes);
+        attributes.push(("member of", (!member_of.is_empty()).then(|| member_of.join(", "))));
+        objects.insert(
+            ObjectPath {
+                kind: ObjectKind::Role,
+                schema: None,
+                table: None,
+                name: render_normalized_identifier(&role),
+            },
+            attributes,
+        );
+    }
+
+    for (privilege, with_grant_option) in collect_privileges(database) {
+        let mut name = privilege.action().to_string();
+        if let Some(column) = privilege.column() {
+            name.push_str(&format!(" ({})", render_normalized_identifier(column)));
+        }
+        name.push_str(" TO ");
+        name.push_str(
+            &privilege.grantee().map_or_else(|| "PUBLIC".to_string(), render_normalized_identifier),
+        );
+        objects.insert(
+            ObjectPath {
+                kind: ObjectKind::Grant,
+                schema: privilege
+                    .table_schema()
+                    .filter(|schema| !is_default_schema(schema, casing))
+                    .map(ToString::to_string),
+                table: Some(privilege.table_name().to_string()),
+                name,
+            },
+            vec![("with grant option", flag(with_grant_option))],
+        );
+    }
+
+    objects
+}
+
+/// Returns the attributes describing a role.
+fn role_attributes(attributes: &RoleAttributes) -> Attributes {
+    vec![
+        ("superuser", flag(attributes.superuser)),
+        ("create database", flag(attributes.create_db)),
+        ("create role", flag(attributes.create_role)),
+        ("inherit", flag(attributes.inherit)),
+        ("login", flag(attributes.login)),
+        ("replication", flag(attributes.replication)),
+        ("bypass row level security", flag(attributes.bypass_rls)),
+        ("connection limit", attributes.connection_limit.map(|limit| limit.to_string())),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    fn parse(sql: &str) -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse")
+    }
+
+    fn changes(old: &str, new: &str) -> Vec<String> {
+        SchemaDiff::between(&parse(old), &parse(new))
+            .changes()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_schemas_have_no_changes() {
+        let diff = SchemaDiff::between(
+            &parse(
+                "
+                CREATE ROLE Reader;
+                CREATE TABLE Users (id INT PRIMARY KEY, name TEXT CHECK (name <> ''));
+                GRANT SELECT ON Users TO Reader;
+                ",
+            ),
+            &parse(
+                "
+                CREATE ROLE reader;
+                CREATE TABLE public.users (id INT PRIMARY KEY, name TEXT CHECK (name <> ''));
+                GRANT SELECT ON users TO reader;
+                ",
+            ),
+        );
+        assert!(diff.is_empty(), "{diff}");
+    }
+
+    #[test]
+    fn test_names_follow_the_casing_of_the_dialect() {
+        let old = ParserDB::parse::<MySqlDialect>("CREATE TABLE `Users` (`Id` INT NOT NULL);")
+            .expect("Failed to parse");
+        let new = ParserDB::parse::<MySqlDialect>("CREATE TABLE users (id INT NOT NULL);")
+            .expect("Failed to parse");
+        let diff = SchemaDiff::between(&old, &new);
+        assert!(diff.is_empty(), "{diff}");
+    }
+
+    #[test]
+    fn test_constraints_and_indices() {
+        assert_eq!(
+            changes(
+                "
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (
+                    id INT,
+                    parent_id INT,
+                    FOREIGN KEY (parent_id) REFERENCES parent (id),
+                    CONSTRAINT child_id_key UNIQUE (id),
+                    CHECK (id > 0)
+                );
+                CREATE INDEX child_parent_idx ON child (parent_id);
+                ",
+                "
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (
+                    id INT,
+                    parent_id INT,
+                    FOREIGN KEY (parent_id) REFERENCES parent (id) ON DELETE CASCADE,
+                    CONSTRAINT child_id_key UNIQUE (id, parent_id),
+                    CHECK (id >= 0)
+                );
+                CREATE INDEX child_parent_idx ON child (parent_id) WHERE parent_id IS NOT NULL;
+                ",
+            ),
+            [
+                "changed index child_parent_idx on child: predicate none -> parent_id IS NOT NULL",
+                "changed unique index child_id_key on child: definition (id) -> (id, parent_id)",
+                "changed foreign key (parent_id) REFERENCES parent (id) on child: on delete cascade false -> true",
+                "removed check constraint id > 0 on child",
+                "added check constraint id >= 0 on child",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_policies_roles_and_grants() {
+        let old = "
+            CREATE ROLE reader;
+            CREATE TABLE t (id INT);
+            CREATE POLICY visible ON t USING (id > 0);
+            GRANT SELECT ON t TO reader;
+        ";
+        let new = "
+            CREATE ROLE reader LOGIN;
+            CREATE ROLE writer IN ROLE reader;
+            CREATE TABLE t (id INT);
+            CREATE POLICY visible ON t TO reader USING (id > 0);
+            GRANT INSERT ON t TO writer;
+        ";
+        assert_eq!(
+            changes(old, new),
+            [
+                "changed policy visible on t: roles PUBLIC -> reader",
+                "changed role reader: login false -> true",
+                "added role writer",
+                "added grant INSERT TO writer on t",
+                "removed grant SELECT TO reader on t",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_objects_of_added_and_removed_tables_are_omitted() {
+        let diff = SchemaDiff::between(
+            &parse("CREATE TABLE old_table (id INT UNIQUE); CREATE INDEX i ON old_table (id);"),
+            &parse("CREATE TABLE new_table (id INT UNIQUE); CREATE INDEX j ON new_table (id);"),
+        );
+        let changes: Vec<String> = diff.changes().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            changes,
+            ["added table new_table", "removed table old_table", "added index j on new_table"]
+        );
+        assert_eq!(diff.changes_of_kind(ObjectKind::Table).count(), 2);
+    }
+
+    #[test]
+    fn test_constraint_names_can_be_ignored() {
+        let old = parse(
+            "
+            CREATE TABLE parent (id INT, CONSTRAINT parent_pkey PRIMARY KEY (id));
+            CREATE TABLE child (
+                id INT,
+                parent_id INT CONSTRAINT child_parent_fk REFERENCES parent (id),
+                CONSTRAINT child_positive CHECK (id > 0)
+            );
+            CREATE INDEX child_parent_idx ON child (parent_id);
+            ",
+        );
+        let new = parse(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (id INT CHECK (id > 0), parent_id INT);
+            ALTER TABLE child ADD CONSTRAINT child_parent_id_fkey
+                FOREIGN KEY (parent_id) REFERENCES parent (id);
+            CREATE INDEX child_parent_id_idx ON child (parent_id);
+            ",
+        );
+        assert!(!SchemaDiff::between(&old, &new).is_empty());
+        let diff = SchemaDiff::between_ignoring_constraint_names(&old, &new);
+        assert!(diff.is_empty(), "{diff}");
+
+        let new = parse(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (id INT CHECK (id > 0), parent_id INT REFERENCES parent (id));
+            CREATE INDEX child_parent_id_idx ON child (id);
+            ",
+        );
+        let changes: Vec<String> = SchemaDiff::between_ignoring_constraint_names(&old, &new)
+            .changes()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(changes, ["added index (id) on child", "removed index (parent_id) on child"]);
+    }
+}
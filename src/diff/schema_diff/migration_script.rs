@@ -0,0 +1,533 @@
+//! Submodule rendering the statements applying and reverting a
+//! [`SchemaDiff`].
+
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{AttributeChange, Attributes, ObjectPath, SchemaChange, SchemaDiff};
+use crate::{
+    impls::SqlparserDialect, traits::ObjectKind, utils::object_name::render_normalized_identifier,
+};
+
+/// Statements applying and reverting a [`SchemaDiff`], as rendered by
+/// [`SchemaDiff::to_sql`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MigrationScript {
+    /// The statements migrating the old database into the new one.
+    up: Vec<String>,
+    /// The statements migrating the new database back into the old one.
+    down: Vec<String>,
+    /// The changes no statement could be rendered for.
+    unsupported: Vec<SchemaChange>,
+}
+
+impl MigrationScript {
+    /// Returns the statements migrating the old database into the new one,
+    /// in the order they should be applied.
+    #[must_use]
+    #[inline]
+    pub fn up(&self) -> &[String] {
+        &self.up
+    }
+
+    /// Returns the statements migrating the new database back into the old
+    /// one, in the order they should be applied.
+    #[must_use]
+    #[inline]
+    pub fn down(&self) -> &[String] {
+        &self.down
+    }
+
+    /// Returns the changes no statement could be rendered for, which are left
+    /// out of both directions of the migration.
+    #[must_use]
+    #[inline]
+    pub fn unsupported(&self) -> &[SchemaChange] {
+        &self.unsupported
+    }
+
+    /// Returns whether every change of the diff was rendered.
+    #[must_use]
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.unsupported.is_empty()
+    }
+}
+
+/// Statements applying a change along with the statements reverting it.
+type Step = (Vec<String>, Vec<String>);
+
+impl SchemaDiff {
+    /// Renders the statements applying the diff and reverting it in the
+    /// provided dialect.
+    ///
+    /// Only columns, indices, unique constraints and foreign keys are
+    /// rendered. Indices and constraints must be named, as reverting their
+    /// creation requires dropping them by name, and a change of definition
+    /// is rendered as a drop followed by a creation. The other changes, as
+    /// well as the ones the dialect has no statement for, such as column
+    /// alterations in SQLite, are reported by
+    /// [`MigrationScript::unsupported`].
+    ///
+    /// Removals are applied first, in reverse order so that constraints are
+    /// dropped before the columns they cover, followed by alterations and
+    /// additions. The indices and constraints depending on a column whose
+    /// data type changes, as collected by
+    /// [`ColumnTypeChange`](crate::diff::ColumnTypeChange), are dropped
+    /// before the alterations and recreated after them; if any of them cannot
+    /// be rendered, the change of the column is reported as unsupported.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialect` - The dialect to render the statements in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::SchemaDiff, impls::SqlparserDialect, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let old = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE users (id INT, age INT);")?;
+    /// let new = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, age BIGINT NOT NULL, email TEXT);
+    /// CREATE INDEX users_email_idx ON users (email);
+    /// ",
+    /// )?;
+    /// let script = SchemaDiff::between(&old, &new).to_sql(SqlparserDialect::PostgreSql);
+    /// assert!(script.is_complete());
+    /// assert_eq!(
+    ///     script.up(),
+    ///     [
+    ///         "ALTER TABLE users ALTER COLUMN age TYPE BIGINT;",
+    ///         "ALTER TABLE users ALTER COLUMN age SET NOT NULL;",
+    ///         "ALTER TABLE users ADD COLUMN email TEXT;",
+    ///         "CREATE INDEX users_email_idx ON users (email);",
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     script.down(),
+    ///     [
+    ///         "DROP INDEX users_email_idx;",
+    ///         "ALTER TABLE users DROP COLUMN email;",
+    ///         "ALTER TABLE users ALTER COLUMN age DROP NOT NULL;",
+    ///         "ALTER TABLE users ALTER COLUMN age TYPE INT;",
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_sql(&self, dialect: SqlparserDialect) -> MigrationScript {
+        let removals =
+            self.changes.iter().rev().filter(|change| matches!(change, SchemaChange::Removed(_)));
+        let alterations =
+            self.changes.iter().filter(|change| matches!(change, SchemaChange::Changed { .. }));
+        let additions =
+            self.changes.iter().filter(|change| matches!(change, SchemaChange::Added(_)));
+
+        let mut steps: Vec<Step> = Vec::new();
+        let mut unsupported = Vec::new();
+        for change in removals {
+            match self.render_change(change, dialect) {
+                Some(step) => steps.push(step),
+                None => unsupported.push(change.clone()),
+            }
+        }
+
+        // The objects depending on a column whose data type changes are
+        // dropped before the alterations and recreated after them.
+        let mut altered: Vec<Step> = Vec::new();
+        let mut old_dependents: BTreeSet<&ObjectPath> = BTreeSet::new();
+        let mut new_dependents: BTreeSet<&ObjectPath> = BTreeSet::new();
+        for change in alterations {
+            let dependents = self.dependents(change.object());
+            let renderable = dependents.is_none_or(|(old, new)| {
+                old.iter().all(|object| self.rebuild_statements(object, false, dialect).is_some())
+                    && new
+                        .iter()
+                        .all(|object| self.rebuild_statements(object, true, dialect).is_some())
+            });
+            match self.render_change(change, dialect).filter(|_| renderable) {
+                Some(step) => {
+                    altered.push(step);
+                    if let Some((old, new)) = dependents {
+                        old_dependents.extend(old);
+                        new_dependents.extend(new);
+                    }
+                }
+                None => unsupported.push(change.clone()),
+            }
+        }
+        let rebuilt = |dependents: &BTreeSet<&ObjectPath>, new: bool| -> Step {
+            let (drops, creates): (Vec<String>, Vec<String>) = dependents
+                .iter()
+                .filter_map(|object| self.rebuild_statements(object, new, dialect))
+                .unzip();
+            (drops.into_iter().rev().collect(), creates)
+        };
+        let (drop_old, create_old) = rebuilt(&old_dependents, false);
+        let (drop_new, create_new) = rebuilt(&new_dependents, true);
+        steps.push((drop_old, create_old));
+        steps.extend(altered);
+        steps.push((create_new, drop_new));
+
+        for change in additions {
+            match self.render_change(change, dialect) {
+                Some(step) => steps.push(step),
+                None => unsupported.push(change.clone()),
+            }
+        }
+
+        MigrationScript {
+            up: steps.iter().flat_map(|(up, _)| up.iter().cloned()).collect(),
+            down: steps.iter().rev().flat_map(|(_, down)| down.iter().cloned()).collect(),
+            unsupported,
+        }
+    }
+
+    /// Renders the statements applying and reverting a change, or `None` if
+    /// the change is not supported.
+    fn render_change(&self, change: &SchemaChange, dialect: SqlparserDialect) -> Option<Step> {
+        match change {
+            SchemaChange::Added(object) => {
+                let create = create_statement(object, self.new_attributes(object)?, dialect)?;
+                Some((vec![create], vec![drop_statement(object, dialect)?]))
+            }
+            SchemaChange::Removed(object) => {
+                let create = create_statement(object, self.old_attributes(object)?, dialect)?;
+                Some((vec![drop_statement(object, dialect)?], vec![create]))
+            }
+            SchemaChange::Changed { object, attributes } if object.kind == ObjectKind::Column => {
+                alter_column_statements(object, attributes, self.new_attributes(object)?, dialect)
+                    .zip(alter_column_statements(
+                        object,
+                        &attributes.iter().map(AttributeChange::reversed).collect::<Vec<_>>(),
+                        self.old_attributes(object)?,
+                        dialect,
+                    ))
+                    .map(|(up, mut down)| {
+                        down.reverse();
+                        (up, down)
+                    })
+            }
+            SchemaChange::Changed { object, .. } => {
+                let drop = drop_statement(object, dialect)?;
+                let create_old = create_statement(object, self.old_attributes(object)?, dialect)?;
+                let create_new = create_statement(object, self.new_attributes(object)?, dialect)?;
+                Some((vec![drop.clone(), create_new], vec![drop, create_old]))
+            }
+        }
+    }
+
+    /// Renders the statements dropping and creating an object rebuilt around
+    /// a change of the data type of a column, as defined in the new database
+    /// if `new` is set and in the old one otherwise.
+    fn rebuild_statements(
+        &self,
+        object: &ObjectPath,
+        new: bool,
+        dialect: SqlparserDialect,
+    ) -> Option<(String, String)> {
+        let attributes =
+            if new { self.new_attributes(object)? } else { self.old_attributes(object)? };
+        Some((drop_statement(object, dialect)?, create_statement(object, attributes, dialect)?))
+    }
+}
+
+impl AttributeChange {
+    /// Returns the change reverting this one.
+    fn reversed(&self) -> Self {
+        Self { attribute: self.attribute, old: self.new.clone(), new: self.old.clone() }
+    }
+}
+
+/// Returns the value of an attribute, if set.
+fn attribute<'a>(attributes: &'a Attributes, name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(attribute, _)| *attribute == name)
+        .and_then(|(_, value)| value.as_deref())
+}
+
+/// Returns the qualified name of the table the object belongs to.
+fn qualified_table(object: &ObjectPath) -> String {
+    let table = render_normalized_identifier(object.table().unwrap_or_default());
+    match object.schema() {
+        Some(schema) => alloc::format!("{}.{table}", render_normalized_identifier(schema)),
+        None => table,
+    }
+}
+
+/// Returns the definition of an index or constraint, provided that it is
+/// named: unnamed objects are identified by their definition.
+fn named_definition<'a>(object: &ObjectPath, attributes: &'a Attributes) -> Option<&'a str> {
+    attribute(attributes, "definition").filter(|definition| *definition != object.name())
+}
+
+/// Renders the definition of a column, as in `ADD COLUMN`.
+fn column_definition(object: &ObjectPath, attributes: &Attributes) -> Option<String> {
+    let mut definition =
+        alloc::format!("{} {}", object.name(), attribute(attributes, "data type")?);
+    if let Some(default) = attribute(attributes, "default") {
+        definition.push_str(" DEFAULT ");
+        definition.push_str(default);
+    }
+    if attribute(attributes, "nullable") == Some("false") {
+        definition.push_str(" NOT NULL");
+    }
+    Some(definition)
+}
+
+/// Renders the statement creating an object with the provided attributes.
+fn create_statement(
+    object: &ObjectPath,
+    attributes: &Attributes,
+    dialect: SqlparserDialect,
+) -> Option<String> {
+    let table = qualified_table(object);
+    let name = object.name();
+    match object.kind {
+        ObjectKind::Column => Some(alloc::format!(
+            "ALTER TABLE {table} ADD COLUMN {};",
+            column_definition(object, attributes)?
+        )),
+        ObjectKind::Index => {
+            let definition = named_definition(object, attributes)?;
+            let predicate = attribute(attributes, "predicate")
+                .map(|predicate| alloc::format!(" WHERE {predicate}"))
+                .unwrap_or_default();
+            Some(alloc::format!("CREATE INDEX {name} ON {table} {definition}{predicate};"))
+        }
+        ObjectKind::UniqueIndex if !dialect.is_sqlite() => {
+            let definition = named_definition(object, attributes)?;
+            let constraint = if attribute(attributes, "primary key") == Some("true") {
+                "PRIMARY KEY"
+            } else {
+                "UNIQUE"
+            };
+            Some(alloc::format!(
+                "ALTER TABLE {table} ADD CONSTRAINT {name} {constraint} {definition};"
+            ))
+        }
+        ObjectKind::ForeignKey if !dialect.is_sqlite() => {
+            let definition = named_definition(object, attributes)?;
+            let on_delete = if attribute(attributes, "on delete cascade") == Some("true") {
+                " ON DELETE CASCADE"
+            } else {
+                ""
+            };
+            Some(alloc::format!(
+                "ALTER TABLE {table} ADD CONSTRAINT {name} FOREIGN KEY {definition}{on_delete};"
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Renders the statement dropping an object.
+fn drop_statement(object: &ObjectPath, dialect: SqlparserDialect) -> Option<String> {
+    let table = qualified_table(object);
+    let name = object.name();
+    match object.kind {
+        ObjectKind::Column => Some(alloc::format!("ALTER TABLE {table} DROP COLUMN {name};")),
+        ObjectKind::Index if dialect == SqlparserDialect::MySql => {
+            Some(alloc::format!("DROP INDEX {name} ON {table};"))
+        }
+        ObjectKind::Index => match object.schema() {
+            Some(schema) => {
+                Some(alloc::format!("DROP INDEX {}.{name};", render_normalized_identifier(schema)))
+            }
+            None => Some(alloc::format!("DROP INDEX {name};")),
+        },
+        ObjectKind::UniqueIndex | ObjectKind::ForeignKey if !dialect.is_sqlite() => {
+            Some(alloc::format!("ALTER TABLE {table} DROP CONSTRAINT {name};"))
+        }
+        _ => None,
+    }
+}
+
+/// Renders the statements applying the changes to the attributes of a
+/// column, `attributes` being the attributes the column ends up with.
+fn alter_column_statements(
+    object: &ObjectPath,
+    changes: &[AttributeChange],
+    attributes: &Attributes,
+    dialect: SqlparserDialect,
+) -> Option<Vec<String>> {
+    let table = qualified_table(object);
+    let name = object.name();
+    if dialect.is_sqlite() {
+        return None;
+    }
+    if dialect == SqlparserDialect::MySql {
+        return Some(vec![alloc::format!(
+            "ALTER TABLE {table} MODIFY COLUMN {};",
+            column_definition(object, attributes)?
+        )]);
+    }
+    Some(
+        changes
+            .iter()
+            .map(|change| {
+                let action = match (change.attribute, change.new_value()) {
+                    ("data type", Some(data_type)) => alloc::format!("TYPE {data_type}"),
+                    ("nullable", Some("false")) => "SET NOT NULL".to_string(),
+                    ("nullable", _) => "DROP NOT NULL".to_string(),
+                    ("default", Some(default)) => alloc::format!("SET DEFAULT {default}"),
+                    _ => "DROP DEFAULT".to_string(),
+                };
+                alloc::format!("ALTER TABLE {table} ALTER COLUMN {name} {action};")
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    #[test]
+    fn test_named_constraints_are_replaced() {
+        let old = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (
+                id INT,
+                parent_id INT,
+                CONSTRAINT child_parent_fk FOREIGN KEY (parent_id) REFERENCES parent (id),
+                CHECK (id > 0)
+            );
+            ",
+        )
+        .expect("Failed to parse");
+        let new = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (
+                id INT,
+                parent_id INT,
+                CONSTRAINT child_parent_fk FOREIGN KEY (parent_id) REFERENCES parent (id)
+                    ON DELETE CASCADE,
+                CONSTRAINT child_id_key UNIQUE (id)
+            );
+            ",
+        )
+        .expect("Failed to parse");
+
+        let script = SchemaDiff::between(&old, &new).to_sql(SqlparserDialect::PostgreSql);
+        assert_eq!(
+            script.up(),
+            [
+                "ALTER TABLE child DROP CONSTRAINT child_parent_fk;",
+                "ALTER TABLE child ADD CONSTRAINT child_parent_fk FOREIGN KEY (parent_id) \
+                 REFERENCES parent (id) ON DELETE CASCADE;",
+                "ALTER TABLE child ADD CONSTRAINT child_id_key UNIQUE (id);",
+            ]
+        );
+        assert_eq!(
+            script.down(),
+            [
+                "ALTER TABLE child DROP CONSTRAINT child_id_key;",
+                "ALTER TABLE child DROP CONSTRAINT child_parent_fk;",
+                "ALTER TABLE child ADD CONSTRAINT child_parent_fk FOREIGN KEY (parent_id) \
+                 REFERENCES parent (id);",
+            ]
+        );
+        assert_eq!(script.unsupported().len(), 1);
+        assert_eq!(script.unsupported()[0].object().kind(), ObjectKind::CheckConstraint);
+    }
+
+    #[test]
+    fn test_dialect_specific_statements() {
+        let old = ParserDB::parse::<MySqlDialect>(
+            "CREATE TABLE t (id INT, name TEXT); CREATE INDEX t_name ON t (name);",
+        )
+        .expect("Failed to parse");
+        let new = ParserDB::parse::<MySqlDialect>("CREATE TABLE t (id INT, name TEXT NOT NULL);")
+            .expect("Failed to parse");
+        let diff = SchemaDiff::between(&old, &new);
+
+        let mysql = diff.to_sql(SqlparserDialect::MySql);
+        assert_eq!(
+            mysql.up(),
+            ["DROP INDEX t_name ON t;", "ALTER TABLE t MODIFY COLUMN name TEXT NOT NULL;"]
+        );
+        assert_eq!(
+            mysql.down(),
+            ["ALTER TABLE t MODIFY COLUMN name TEXT;", "CREATE INDEX t_name ON t (name);"]
+        );
+
+        let sqlite = diff.to_sql(SqlparserDialect::SQLite);
+        assert_eq!(sqlite.up(), ["DROP INDEX t_name;"]);
+        assert!(!sqlite.is_complete());
+        assert_eq!(sqlite.unsupported()[0].object().kind(), ObjectKind::Column);
+    }
+
+    #[test]
+    fn test_dependents_are_rebuilt_around_type_changes() {
+        let schema = |id_type: &str| {
+            ParserDB::parse::<PostgreSqlDialect>(&alloc::format!(
+                "
+                CREATE TABLE owners (id {id_type}, CONSTRAINT owners_pkey PRIMARY KEY (id));
+                CREATE TABLE pets (
+                    id INT,
+                    owner_id {id_type},
+                    CONSTRAINT pets_owner_fk FOREIGN KEY (owner_id) REFERENCES owners (id)
+                );
+                CREATE INDEX pets_owner_idx ON pets (owner_id);
+                "
+            ))
+            .expect("Failed to parse")
+        };
+
+        let script = SchemaDiff::between(&schema("INT"), &schema("BIGINT"))
+            .to_sql(SqlparserDialect::PostgreSql);
+        assert!(script.is_complete());
+        assert_eq!(
+            script.up(),
+            [
+                "ALTER TABLE pets DROP CONSTRAINT pets_owner_fk;",
+                "ALTER TABLE owners DROP CONSTRAINT owners_pkey;",
+                "DROP INDEX pets_owner_idx;",
+                "ALTER TABLE owners ALTER COLUMN id TYPE BIGINT;",
+                "ALTER TABLE pets ALTER COLUMN owner_id TYPE BIGINT;",
+                "CREATE INDEX pets_owner_idx ON pets (owner_id);",
+                "ALTER TABLE owners ADD CONSTRAINT owners_pkey PRIMARY KEY (id);",
+                "ALTER TABLE pets ADD CONSTRAINT pets_owner_fk FOREIGN KEY (owner_id) \
+                 REFERENCES owners (id);",
+            ]
+        );
+        assert_eq!(
+            script.down(),
+            [
+                "ALTER TABLE pets DROP CONSTRAINT pets_owner_fk;",
+                "ALTER TABLE owners DROP CONSTRAINT owners_pkey;",
+                "DROP INDEX pets_owner_idx;",
+                "ALTER TABLE pets ALTER COLUMN owner_id TYPE INT;",
+                "ALTER TABLE owners ALTER COLUMN id TYPE INT;",
+                "CREATE INDEX pets_owner_idx ON pets (owner_id);",
+                "ALTER TABLE owners ADD CONSTRAINT owners_pkey PRIMARY KEY (id);",
+                "ALTER TABLE pets ADD CONSTRAINT pets_owner_fk FOREIGN KEY (owner_id) \
+                 REFERENCES owners (id);",
+            ]
+        );
+
+        // Unnamed constraints cannot be dropped by name.
+        let old = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (n INT CHECK (n > 0));")
+            .expect("Failed to parse");
+        let new = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (n BIGINT CHECK (n > 0));")
+            .expect("Failed to parse");
+        let script = SchemaDiff::between(&old, &new).to_sql(SqlparserDialect::PostgreSql);
+        assert!(script.up().is_empty());
+        assert_eq!(script.unsupported().len(), 1);
+        assert_eq!(script.unsupported()[0].object().kind(), ObjectKind::Column);
+    }
+}
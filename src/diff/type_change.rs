@@ -0,0 +1,613 @@
+//! Submodule classifying column data type changes and collecting the objects
+//! depending on the altered column.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::{
+    ast::{CharacterLength, DataType, ExactNumberInfo, TimezoneInfo},
+    dialect::GenericDialect,
+    parser::Parser,
+};
+
+use crate::{
+    traits::{ColumnLike, DatabaseLike, DialectLike, ForeignKeyLike, PolicyLike, TableLike},
+    utils::columns_in_expression::expression_references_column,
+};
+
+/// Classification of a change of the data type of a column.
+///
+/// Variants are ordered by increasing severity, so that the most severe
+/// change among several can be obtained with [`Ord::max`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TypeChangeKind {
+    /// The two types are the same once normalized.
+    Unchanged,
+    /// Every value of the original type is exactly representable in the new
+    /// type.
+    Widening,
+    /// Values are preserved, but some of them may fall outside of the range of
+    /// the new type, making the conversion fail.
+    Narrowing,
+    /// The conversion always succeeds, but may lose information such as
+    /// precision, fractional digits or time zones.
+    Lossy,
+    /// There is no meaningful conversion between the two types.
+    Incompatible,
+}
+
+/// Canonical type families known to the classifier, along with the
+/// modifiers bounding their values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeModel {
+    /// Integer types, with their width in bits.
+    Integer(u8),
+    /// Binary floating point types, with the bits of their mantissa.
+    Float(u8),
+    /// Arbitrary precision decimal types, with their precision and scale if
+    /// bounded.
+    Decimal(Option<(i128, i128)>),
+    /// Boolean type.
+    Boolean,
+    /// Textual types, ranked by how many values they can hold: fixed-length
+    /// characters, variable-length characters and unbounded text, with their
+    /// maximal length if bounded.
+    Text(u8, Option<u64>),
+    /// Binary string types.
+    Bytes,
+    /// Calendar date.
+    Date,
+    /// Time of day without time zone.
+    Time,
+    /// Time of day with time zone.
+    TimeTz,
+    /// Date and time without time zone.
+    Timestamp,
+    /// Date and time with time zone.
+    TimestampTz,
+    /// UUID type.
+    Uuid,
+    /// Textual JSON.
+    Json,
+    /// Binary JSON.
+    Jsonb,
+}
+
+impl TypeModel {
+    /// Maps a type name, modifiers included, to its type model, if known.
+    fn from_type_name(type_name: &str) -> Option<Self> {
+        let data_type =
+            Parser::new(&GenericDialect {}).try_with_sql(type_name).ok()?.parse_data_type().ok()?;
+        Self::from_data_type(&data_type)
+    }
+
+    /// Maps a parsed data type to its type model, if known.
+    fn from_data_type(data_type: &DataType) -> Option<Self> {
+        Some(match data_type {
+            DataType::TinyInt(_) => Self::Integer(8),
+            DataType::SmallInt(_) | DataType::Int2(_) => Self::Integer(16),
+            DataType::MediumInt(_) => Self::Integer(24),
+            DataType::Int(_) | DataType::Integer(_) | DataType::Int4(_) => Self::Integer(32),
+            DataType::BigInt(_) | DataType::Int8(_) => Self::Integer(64),
+            DataType::Real | DataType::Float4 => Self::Float(24),
+            DataType::Float(_)
+            | DataType::Float8
+            | DataType::Double(_)
+            | DataType::DoublePrecision => Self::Float(53),
+            DataType::Decimal(info) | DataType::Dec(info) | DataType::Numeric(info) => {
+                Self::Decimal(match info {
+                    ExactNumberInfo::None => None,
+                    ExactNumberInfo::Precision(precision) => Some((i128::from(*precision), 0)),
+                    ExactNumberInfo::PrecisionAndScale(precision, scale) => {
+                        Some((i128::from(*precision), i128::from(*scale)))
+                    }
+                })
+            }
+            DataType::Bool | DataType::Boolean => Self::Boolean,
+            DataType::Char(length) | DataType::Character(length) => {
+                Self::Text(0, character_length(length.as_ref()))
+            }
+            DataType::Varchar(length)
+            | DataType::CharacterVarying(length)
+            | DataType::Nvarchar(length) => Self::Text(1, character_length(length.as_ref())),
+            DataType::Text | DataType::Clob(_) | DataType::String(_) => Self::Text(2, None),
+            DataType::Bytea
+            | DataType::Bytes(_)
+            | DataType::Blob(_)
+            | DataType::Binary(_)
+            | DataType::Varbinary(_) => Self::Bytes,
+            DataType::Date => Self::Date,
+            DataType::Time(_, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone) => Self::Time,
+            DataType::Time(_, TimezoneInfo::WithTimeZone | TimezoneInfo::Tz) => Self::TimeTz,
+            DataType::Timestamp(_, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone)
+            | DataType::Datetime(_) => Self::Timestamp,
+            DataType::Timestamp(_, TimezoneInfo::WithTimeZone | TimezoneInfo::Tz) => {
+                Self::TimestampTz
+            }
+            DataType::Uuid => Self::Uuid,
+            DataType::JSON => Self::Json,
+            DataType::JSONB => Self::Jsonb,
+            // Aliases the parser does not know about, such as the serial
+            // pseudo-types, are kept as custom types.
+            DataType::Custom(name, modifiers) if modifiers.is_empty() => {
+                match name.to_string().trim_matches('"').to_ascii_uppercase().as_str() {
+                    "SMALLSERIAL" | "INT2" => Self::Integer(16),
+                    "SERIAL" | "INT4" => Self::Integer(32),
+                    "BIGSERIAL" | "INT8" => Self::Integer(64),
+                    "FLOAT4" => Self::Float(24),
+                    "FLOAT8" => Self::Float(53),
+                    "BOOL" => Self::Boolean,
+                    "TIMETZ" => Self::TimeTz,
+                    "TIMESTAMPTZ" => Self::TimestampTz,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// Classifies the conversion from `self` to `target`.
+    fn change_to(self, target: Self) -> TypeChangeKind {
+        use TypeChangeKind::{Incompatible, Lossy, Narrowing, Unchanged, Widening};
+        use TypeModel::{
+            Date, Decimal, Float, Integer, Json, Jsonb, Text, Time, TimeTz, Timestamp, TimestampTz,
+        };
+
+        if self == target {
+            return Unchanged;
+        }
+
+        match (self, target) {
+            (Integer(from), Integer(to)) => {
+                if from < to {
+                    Widening
+                } else {
+                    Narrowing
+                }
+            }
+            (Text(from_rank, from_length), Text(to_rank, to_length)) => {
+                if from_rank <= to_rank && length_fits(from_length, to_length) {
+                    Widening
+                } else {
+                    Narrowing
+                }
+            }
+            (Float(from), Float(to)) => {
+                if from < to {
+                    Widening
+                } else {
+                    Lossy
+                }
+            }
+            (Integer(bits), Float(mantissa)) => {
+                if bits <= mantissa {
+                    Widening
+                } else {
+                    Lossy
+                }
+            }
+            (Integer(bits), Decimal(bounds)) => {
+                if bounds.is_none_or(|(precision, scale)| {
+                    precision - scale >= integer_decimal_digits(bits)
+                }) {
+                    Widening
+                } else {
+                    Narrowing
+                }
+            }
+            (Decimal(from), Decimal(to)) => match (from, to) {
+                (_, None) => Widening,
+                (None, Some(_)) => Narrowing,
+                (Some((from_precision, from_scale)), Some((to_precision, to_scale))) => {
+                    if to_scale < from_scale {
+                        Lossy
+                    } else if to_precision - to_scale < from_precision - from_scale {
+                        Narrowing
+                    } else {
+                        Widening
+                    }
+                }
+            },
+            (Date, Timestamp | TimestampTz) | (Jsonb, Json) | (_, Text(1 | 2, None)) => Widening,
+            (Float(_), Decimal(_)) | (_, Text(..)) | (Text(..), _) => Narrowing,
+            (Float(_) | Decimal(_), Integer(_))
+            | (Decimal(_), Float(_))
+            | (Timestamp | TimestampTz, Date | Time | TimeTz | Timestamp | TimestampTz)
+            | (Time | TimeTz, Time | TimeTz)
+            | (Json, Jsonb) => Lossy,
+            _ => Incompatible,
+        }
+    }
+}
+
+/// Returns whether every value of the `from` length fits within the `to`
+/// length, where `None` stands for an unbounded length.
+fn length_fits(from: Option<u64>, to: Option<u64>) -> bool {
+    match (from, to) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(from), Some(to)) => from <= to,
+    }
+}
+
+/// Returns the number of decimal digits needed to hold every value of an
+/// integer type of the provided width in bits.
+fn integer_decimal_digits(bits: u8) -> i128 {
+    match bits {
+        8 => 3,
+        16 => 5,
+        24 => 7,
+        32 => 10,
+        _ => 19,
+    }
+}
+
+/// Returns the length bounding a character type, if any.
+fn character_length(length: Option<&CharacterLength>) -> Option<u64> {
+    match length {
+        Some(CharacterLength::IntegerLength { length, .. }) => Some(*length),
+        Some(CharacterLength::Max) | None => None,
+    }
+}
+
+impl TypeChangeKind {
+    /// Classifies the change from the `from` data type to the `to` data type.
+    ///
+    /// Both types are parsed as SQL data types, so that common aliases such
+    /// as `INT4` or `TIMESTAMPTZ` are recognized and modifiers such as the
+    /// length of `VARCHAR(255)` or the precision and scale of
+    /// `NUMERIC(10, 2)` are taken into account. Types not known to the
+    /// classifier are only considered unchanged when their names match.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The original data type of the column.
+    /// * `to` - The new data type of the column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::diff::TypeChangeKind;
+    ///
+    /// assert_eq!(TypeChangeKind::classify("INT4", "INT"), TypeChangeKind::Unchanged);
+    /// assert_eq!(TypeChangeKind::classify("INT", "BIGINT"), TypeChangeKind::Widening);
+    /// assert_eq!(TypeChangeKind::classify("BIGINT", "SMALLINT"), TypeChangeKind::Narrowing);
+    /// assert_eq!(TypeChangeKind::classify("NUMERIC", "INT"), TypeChangeKind::Lossy);
+    /// assert_eq!(
+    ///     TypeChangeKind::classify("VARCHAR(255)", "VARCHAR(10)"),
+    ///     TypeChangeKind::Narrowing
+    /// );
+    /// assert_eq!(TypeChangeKind::classify("UUID", "INT"), TypeChangeKind::Incompatible);
+    /// ```
+    #[must_use]
+    pub fn classify(from: &str, to: &str) -> Self {
+        match (TypeModel::from_type_name(from), TypeModel::from_type_name(to)) {
+            (Some(from_model), Some(to_model)) => from_model.change_to(to_model),
+            _ if from.trim().eq_ignore_ascii_case(to.trim()) => Self::Unchanged,
+            (_, Some(TypeModel::Text(rank, None))) if rank > 0 => Self::Widening,
+            (_, Some(TypeModel::Text(..))) | (Some(TypeModel::Text(..)), _) => Self::Narrowing,
+            _ => Self::Incompatible,
+        }
+    }
+
+    /// Returns whether the change preserves every existing value, so that no
+    /// data may be rejected or altered by the conversion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::diff::TypeChangeKind;
+    ///
+    /// assert!(TypeChangeKind::Widening.is_safe());
+    /// assert!(!TypeChangeKind::Narrowing.is_safe());
+    /// ```
+    #[must_use]
+    pub fn is_safe(self) -> bool {
+        matches!(self, Self::Unchanged | Self::Widening)
+    }
+}
+
+/// Impact analysis of a change of the data type of a column.
+///
+/// Besides classifying the change, it collects the objects depending on the
+/// column which may need to be dropped before the `ALTER COLUMN ... TYPE` and
+/// recreated afterwards.
+#[derive(Debug, Clone)]
+pub struct ColumnTypeChange<'db, DB: DatabaseLike> {
+    /// The column whose data type changes.
+    column: &'db DB::Column,
+    /// The new data type of the column.
+    target_type: String,
+    /// The classification of the change.
+    kind: TypeChangeKind,
+    /// The indices involving the column.
+    indices: Vec<&'db DB::Index>,
+    /// The unique indices involving the column.
+    unique_indices: Vec<&'db DB::UniqueIndex>,
+    /// The foreign keys either hosted on or referencing the column.
+    foreign_keys: Vec<&'db DB::ForeignKey>,
+    /// The check constraints involving the column.
+    check_constraints: Vec<&'db DB::CheckConstraint>,
+    /// The policies whose expressions mention the column.
+    policies: Vec<&'db DB::Policy>,
+}
+
+impl<'db, DB: DatabaseLike> ColumnTypeChange<'db, DB> {
+    /// Analyzes the impact of changing the data type of the provided column
+    /// to `target_type`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the column belongs to.
+    /// * `column` - The column whose data type changes.
+    /// * `target_type` - The new data type of the column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::{ColumnTypeChange, TypeChangeKind}, prelude::*};
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE owners (id INT PRIMARY KEY);
+    /// CREATE TABLE pets (id INT PRIMARY KEY, owner_id INT REFERENCES owners (id));
+    /// CREATE INDEX pets_owner ON pets (owner_id);
+    /// ",
+    /// )?;
+    /// let owner_id = db.table(None, "owners").unwrap().column("id", &db).unwrap();
+    /// let change = ColumnTypeChange::new(&db, owner_id, "BIGINT");
+    /// assert_eq!(change.kind(), TypeChangeKind::Widening);
+    /// // The foreign key referencing the column must be rebuilt.
+    /// assert_eq!(change.foreign_keys().len(), 1);
+    /// assert!(change.has_dependents());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, column: &'db DB::Column, target_type: &str) -> Self {
+        let kind = TypeChangeKind::classify(column.normalized_data_type(database), target_type);
+        let table: &'db DB::Table = ColumnLike::table(column, database);
+
+        let mut foreign_keys: Vec<&'db DB::ForeignKey> = column.foreign_keys(database).collect();
+        for foreign_key in database.tables().flat_map(|host| host.foreign_keys(database)) {
+            if !foreign_keys.contains(&foreign_key)
+                && foreign_key.referenced_columns(database).any(|referenced| referenced == column)
+            {
+                foreign_keys.push(foreign_key);
+            }
+        }
+
+        let casing = database.dialect().identifier_casing();
+        let policies = table
+            .policies(database)
+            .filter(|policy| {
+                policy
+                    .using_expression(database)
+                    .into_iter()
+                    .chain(policy.check_expression(database))
+                    .any(|expr| expression_references_column(expr, column, casing))
+            })
+            .collect();
+
+        Self {
+            column,
+            target_type: target_type.into(),
+            kind,
+            indices: column.indices(database).collect(),
+            unique_indices: column.unique_indices(database).collect(),
+            foreign_keys,
+            check_constraints: column.check_constraints(database).collect(),
+            policies,
+        }
+    }
+
+    /// Returns the column whose data type changes.
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> &'db DB::Column {
+        self.column
+    }
+
+    /// Returns the new data type of the column.
+    #[must_use]
+    #[inline]
+    pub fn target_type(&self) -> &str {
+        &self.target_type
+    }
+
+    /// Returns the classification of the change.
+    #[must_use]
+    #[inline]
+    pub fn kind(&self) -> TypeChangeKind {
+        self.kind
+    }
+
+    /// Returns the indices involving the column.
+    #[must_use]
+    #[inline]
+    pub fn indices(&self) -> &[&'db DB::Index] {
+        &self.indices
+    }
+
+    /// Returns the unique indices, including the primary key, involving the
+    /// column.
+    #[must_use]
+    #[inline]
+    pub fn unique_indices(&self) -> &[&'db DB::UniqueIndex] {
+        &self.unique_indices
+    }
+
+    /// Returns the foreign keys hosted on the column or referencing it.
+    #[must_use]
+    #[inline]
+    pub fn foreign_keys(&self) -> &[&'db DB::ForeignKey] {
+        &self.foreign_keys
+    }
+
+    /// Returns the check constraints involving the column.
+    #[must_use]
+    #[inline]
+    pub fn check_constraints(&self) -> &[&'db DB::CheckConstraint] {
+        &self.check_constraints
+    }
+
+    /// Returns the policies whose expressions mention the column.
+    #[must_use]
+    #[inline]
+    pub fn policies(&self) -> &[&'db DB::Policy] {
+        &self.policies
+    }
+
+    /// Returns whether any object depends on the column and may need to be
+    /// rebuilt around the type change.
+    #[must_use]
+    pub fn has_dependents(&self) -> bool {
+        !(self.indices.is_empty()
+            && self.unique_indices.is_empty()
+            && self.foreign_keys.is_empty()
+            && self.check_constraints.is_empty()
+            && self.policies.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::GenericDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    #[test]
+    fn test_classify_numeric_changes() {
+        assert_eq!(TypeChangeKind::classify("SMALLINT", "INT"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("INT", "SMALLINT"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("INT", "NUMERIC"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("INT", "DOUBLE PRECISION"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("BIGINT", "DOUBLE PRECISION"), TypeChangeKind::Lossy);
+        assert_eq!(TypeChangeKind::classify("REAL", "DOUBLE PRECISION"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("DOUBLE PRECISION", "REAL"), TypeChangeKind::Lossy);
+        assert_eq!(TypeChangeKind::classify("REAL", "NUMERIC"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("NUMERIC", "INT"), TypeChangeKind::Lossy);
+        assert_eq!(TypeChangeKind::classify("BOOLEAN", "INT"), TypeChangeKind::Incompatible);
+    }
+
+    #[test]
+    fn test_classify_changes_of_modifiers() {
+        assert_eq!(TypeChangeKind::classify("INT", "NUMERIC(10,2)"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("INT", "NUMERIC(12, 2)"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("SMALLINT", "DECIMAL(5)"), TypeChangeKind::Widening);
+        assert_eq!(
+            TypeChangeKind::classify("NUMERIC(10, 2)", "NUMERIC(12, 4)"),
+            TypeChangeKind::Widening
+        );
+        assert_eq!(
+            TypeChangeKind::classify("NUMERIC(10, 2)", "NUMERIC(8, 2)"),
+            TypeChangeKind::Narrowing
+        );
+        assert_eq!(
+            TypeChangeKind::classify("NUMERIC(10, 2)", "NUMERIC(10, 1)"),
+            TypeChangeKind::Lossy
+        );
+        assert_eq!(TypeChangeKind::classify("NUMERIC(10, 2)", "NUMERIC"), TypeChangeKind::Widening);
+        assert_eq!(
+            TypeChangeKind::classify("NUMERIC", "NUMERIC(10, 2)"),
+            TypeChangeKind::Narrowing
+        );
+    }
+
+    #[test]
+    fn test_classify_changes_of_lengths() {
+        assert_eq!(
+            TypeChangeKind::classify("VARCHAR(255)", "VARCHAR(10)"),
+            TypeChangeKind::Narrowing
+        );
+        assert_eq!(
+            TypeChangeKind::classify("VARCHAR(10)", "VARCHAR(255)"),
+            TypeChangeKind::Widening
+        );
+        assert_eq!(
+            TypeChangeKind::classify("VARCHAR(255)", "CHARACTER VARYING(255)"),
+            TypeChangeKind::Unchanged
+        );
+        assert_eq!(TypeChangeKind::classify("CHAR(10)", "VARCHAR(20)"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("VARCHAR(10)", "TEXT"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("TEXT", "VARCHAR(10)"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("INT", "VARCHAR(5)"), TypeChangeKind::Narrowing);
+    }
+
+    #[test]
+    fn test_classify_textual_and_temporal_changes() {
+        assert_eq!(TypeChangeKind::classify("VARCHAR", "TEXT"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("TEXT", "CHAR"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("INT", "TEXT"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("TEXT", "UUID"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("DATE", "TIMESTAMP"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("TIMESTAMP", "DATE"), TypeChangeKind::Lossy);
+        assert_eq!(TypeChangeKind::classify("TIMESTAMP", "TIMESTAMPTZ"), TypeChangeKind::Lossy);
+        assert_eq!(
+            TypeChangeKind::classify("TIMESTAMP WITH TIME ZONE", "TIMESTAMPTZ"),
+            TypeChangeKind::Unchanged
+        );
+        assert_eq!(TypeChangeKind::classify("TIME", "DATE"), TypeChangeKind::Incompatible);
+        assert_eq!(TypeChangeKind::classify("JSONB", "JSON"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("JSON", "JSONB"), TypeChangeKind::Lossy);
+    }
+
+    #[test]
+    fn test_classify_unknown_types() {
+        assert_eq!(TypeChangeKind::classify("mood", "MOOD"), TypeChangeKind::Unchanged);
+        assert_eq!(TypeChangeKind::classify("mood", "TEXT"), TypeChangeKind::Widening);
+        assert_eq!(TypeChangeKind::classify("TEXT", "mood"), TypeChangeKind::Narrowing);
+        assert_eq!(TypeChangeKind::classify("mood", "INT"), TypeChangeKind::Incompatible);
+    }
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(TypeChangeKind::Unchanged < TypeChangeKind::Widening);
+        assert!(TypeChangeKind::Narrowing < TypeChangeKind::Lossy);
+        assert_eq!(
+            TypeChangeKind::Widening.max(TypeChangeKind::Incompatible),
+            TypeChangeKind::Incompatible
+        );
+    }
+
+    #[test]
+    fn test_dependents_are_collected() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE owners (id INT PRIMARY KEY, name TEXT);
+            CREATE TABLE pets (
+                id INT PRIMARY KEY,
+                owner_id INT REFERENCES owners (id),
+                age INT CHECK (age >= 0),
+                UNIQUE (owner_id, age)
+            );
+            CREATE INDEX pets_owner ON pets (owner_id);
+            CREATE POLICY adult_pets ON pets USING (age > 1);
+            ",
+        )
+        .expect("Failed to parse");
+        let pets = db.table(None, "pets").unwrap();
+
+        let owner_id = pets.column("owner_id", &db).unwrap();
+        let change = ColumnTypeChange::new(&db, owner_id, "BIGINT");
+        assert_eq!(change.kind(), TypeChangeKind::Widening);
+        assert_eq!(change.target_type(), "BIGINT");
+        assert_eq!(change.indices().len(), 1);
+        assert_eq!(change.unique_indices().len(), 1);
+        assert_eq!(change.foreign_keys().len(), 1);
+        assert!(change.check_constraints().is_empty());
+        assert!(change.policies().is_empty());
+
+        let age = pets.column("age", &db).unwrap();
+        let change = ColumnTypeChange::new(&db, age, "SMALLINT");
+        assert_eq!(change.kind(), TypeChangeKind::Narrowing);
+        assert_eq!(change.check_constraints().len(), 1);
+        assert_eq!(change.policies().len(), 1);
+        assert!(change.foreign_keys().is_empty());
+
+        let name = db.table(None, "owners").unwrap().column("name", &db).unwrap();
+        let change = ColumnTypeChange::new(&db, name, "VARCHAR");
+        assert_eq!(change.kind(), TypeChangeKind::Narrowing);
+        assert!(!change.has_dependents());
+    }
+}
@@ -4,6 +4,8 @@ use alloc::{string::String, vec::Vec};
 
 use sqlparser::parser::ParserError;
 
+use crate::{structs::SourceLocation, traits::ObjectKind};
+
 /// Errors produced by identifier-aware lookup and resolution APIs.
 #[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
 pub enum LookupError {
@@ -89,6 +91,40 @@ pub enum Error {
         /// Name of the host table containing the foreign key.
         host_table: String,
     },
+    #[error(
+        "Foreign key in table `{host_table}` has {host_columns} host columns but {referenced_columns} referenced columns in table `{referenced_table}`."
+    )]
+    /// Error indicating that a foreign key has a different number of host and
+    /// referenced columns.
+    ForeignKeyColumnCountMismatch {
+        /// Name of the host table containing the foreign key.
+        host_table: String,
+        /// Name of the referenced table.
+        referenced_table: String,
+        /// Number of host columns.
+        host_columns: usize,
+        /// Number of referenced columns.
+        referenced_columns: usize,
+    },
+    #[error(
+        "Host column `{host_column}` of type `{host_type}` in table `{host_table}` cannot reference column `{referenced_column}` of type `{referenced_type}` in table `{referenced_table}`."
+    )]
+    /// Error indicating that a host column of a foreign key has a type whose
+    /// values cannot be compared with those of the referenced column.
+    ForeignKeyColumnTypeMismatch {
+        /// Name of the host table containing the foreign key.
+        host_table: String,
+        /// Name of the host column.
+        host_column: String,
+        /// Normalized data type of the host column.
+        host_type: String,
+        /// Name of the referenced table.
+        referenced_table: String,
+        /// Name of the referenced column.
+        referenced_column: String,
+        /// Normalized data type of the referenced column.
+        referenced_type: String,
+    },
     #[error("Table `{table_name}` not found for trigger `{trigger_name}`.")]
     /// Error indicating that a trigger references a table that does not exist.
     TableNotFoundForTrigger {
@@ -113,6 +149,14 @@ pub enum Error {
         /// Reason why the index is invalid.
         reason: String,
     },
+    #[error("Invalid primary key of table `{table_name}`: {reason}")]
+    /// Error indicating that a primary key definition is invalid.
+    InvalidPrimaryKey {
+        /// Name of the table of the primary key.
+        table_name: String,
+        /// Reason why the primary key is invalid.
+        reason: String,
+    },
     #[error("Function `{function_name}` not found for trigger `{trigger_name}`.")]
     /// Error indicating that a trigger references a function that does not
     /// exist.
@@ -138,10 +182,64 @@ pub enum Error {
     #[cfg(feature = "git")]
     #[error("Git error: {0}")]
     GitError(#[from] git2::Error),
+    /// Error indicating that a `down.sql` file drops an object which its
+    /// sibling `up.sql` file does not create. Only available with the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    #[error("Rollback mismatch: {0}")]
+    RollbackMismatch(crate::structs::RollbackMismatch),
+    /// Wrapper around SQLite errors. Only available with the `sqlite` feature.
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    SqliteError(#[from] rusqlite::Error),
     /// Wrapper around IO errors. Only available with the `std` feature.
+    ///
+    /// The crate no longer returns this variant: the files and directories
+    /// which cannot be read are reported as [`Error::Io`], along with their
+    /// path.
     #[cfg(feature = "std")]
+    #[deprecated(note = "IO errors are reported as `Error::Io`, along with their path")]
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+    /// Error indicating that a file or directory could not be read while
+    /// loading a database from paths. Only available with the `std` feature.
+    #[cfg(feature = "std")]
+    #[error("IO error on `{}`: {error}", path.display())]
+    Io {
+        /// Path of the file or directory which could not be read.
+        path: std::path::PathBuf,
+        /// The underlying IO error.
+        #[source]
+        error: std::io::Error,
+    },
+    /// Error indicating that a database which was not loaded from paths was
+    /// asked to update from changed paths. Only available with the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    #[error("The database was not loaded from paths.")]
+    NotLoadedFromPaths,
+    /// Error indicating that a database loaded from paths under a dialect
+    /// was asked to update under another one. Only available with the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    #[error("The database was loaded under the {loaded:?} dialect, not under {requested:?}.")]
+    DialectMismatch {
+        /// The dialect the database was loaded under.
+        loaded: crate::impls::SqlparserDialect,
+        /// The dialect the update was requested under.
+        requested: crate::impls::SqlparserDialect,
+    },
+    /// Error indicating that a schema snapshot was written in a format
+    /// version which is not supported. Only available with the `serde`
+    /// feature.
+    #[cfg(feature = "serde")]
+    #[error("Unsupported snapshot format version {found}, expected {expected}.")]
+    UnsupportedSnapshotVersion {
+        /// Format version of the snapshot.
+        found: u32,
+        /// Format version supported by this version of the crate.
+        expected: u32,
+    },
     /// Wrapper around sql_doc errors
     #[error("Table Doc Error: {0}")]
     TableDocError(#[from] sql_docs::error::DocError),
@@ -158,12 +256,49 @@ pub enum Error {
         /// Human-readable explanation of the unsupported semantics.
         reason: String,
     },
+    /// Error indicating that a statement defines schema objects which are not
+    /// modelled, so that ignoring it would silently yield a wrong schema.
+    #[error("Unsupported statement `{statement}` at {location}.")]
+    UnsupportedStatement {
+        /// The unsupported statement rendered by sqlparser.
+        statement: String,
+        /// Location of the statement in the parsed SQL.
+        location: SourceLocation,
+    },
+    /// Error indicating that a clause of a statement is not modelled, so that
+    /// the statement is applied without it.
+    #[error("Unsupported clause `{clause}` dropped from statement `{statement}`.")]
+    UnsupportedClause {
+        /// The statement the clause belongs to.
+        statement: String,
+        /// The dropped clause.
+        clause: String,
+    },
+    /// Error indicating that the body of a function written in SQL or
+    /// PL/pgSQL cannot be parsed, so that the tables it reads and writes are
+    /// not known when they are dropped.
+    #[error(
+        "The body of function `{function_name}` cannot be parsed, so the tables it reads and writes are not tracked."
+    )]
+    UnparsableFunctionBody {
+        /// Name of the function.
+        function_name: String,
+    },
     #[error("Role `{role_name}` not found for grant.")]
     /// Error indicating that a grant references a role that does not exist.
     RoleNotFoundForGrant {
         /// Name of the undefined role.
         role_name: String,
     },
+    #[error("Granting role `{role_name}` to `{member_name}` would create a membership cycle.")]
+    /// Error indicating that a `GRANT role TO role` statement would make a
+    /// role a member of itself, directly or transitively.
+    RoleMembershipCycle {
+        /// Name of the role whose membership is granted.
+        role_name: String,
+        /// Name of the role the membership is granted to.
+        member_name: String,
+    },
     #[error("Table `{table_name}` not found for grant.")]
     /// Error indicating that a grant references a table that does not exist.
     TableNotFoundForGrant {
@@ -199,6 +334,174 @@ pub enum Error {
         /// Name of the table being dropped.
         table_name: String,
     },
+    #[error("Cannot drop table `{table_name}`: view `{view_name}` selects from it.")]
+    /// Error indicating that a DROP TABLE statement references a table
+    /// that a view still selects from.
+    TableReferencedByView {
+        /// Name of the table being dropped.
+        table_name: String,
+        /// Name of the view selecting from the table.
+        view_name: String,
+    },
+    #[error("Cannot drop table `{table_name}`: function `{function_name}` references it.")]
+    /// Error indicating that a DROP TABLE statement references a table
+    /// that the body of a function still reads or writes.
+    TableReferencedByFunction {
+        /// Name of the table being dropped.
+        table_name: String,
+        /// Name of the function referencing the table.
+        function_name: String,
+    },
+    #[error("Table `{table_name}` already exists.")]
+    /// Error indicating that a CREATE TABLE statement tries to create a table
+    /// that already exists, without `IF NOT EXISTS` or `OR REPLACE`.
+    TableAlreadyExists {
+        /// Name of the table that already exists.
+        table_name: String,
+    },
+    #[error("Function `{function_name}` already exists with the same argument types.")]
+    /// Error indicating that a CREATE FUNCTION statement tries to create a
+    /// function that already exists with the same argument types, without
+    /// `IF NOT EXISTS`, `OR REPLACE` or `OR ALTER`.
+    FunctionAlreadyExists {
+        /// Name of the function that already exists.
+        function_name: String,
+    },
+    #[error("View `{view_name}` already exists.")]
+    /// Error indicating that a CREATE VIEW statement tries to create a view
+    /// that already exists.
+    ViewAlreadyExists {
+        /// Name of the view that already exists.
+        view_name: String,
+    },
+    #[error("View `{view_name}` not found for DROP VIEW statement.")]
+    /// Error indicating that a DROP VIEW statement references a view that
+    /// does not exist.
+    DropViewNotFound {
+        /// Name of the view that was not found.
+        view_name: String,
+    },
+    #[error("Sequence `{sequence_name}` already exists.")]
+    /// Error indicating that a CREATE SEQUENCE statement tries to create a
+    /// sequence that already exists.
+    SequenceAlreadyExists {
+        /// Name of the sequence that already exists.
+        sequence_name: String,
+    },
+    #[error("Sequence `{sequence_name}` not found for DROP SEQUENCE statement.")]
+    /// Error indicating that a DROP SEQUENCE statement references a sequence
+    /// that does not exist.
+    DropSequenceNotFound {
+        /// Name of the sequence that was not found.
+        sequence_name: String,
+    },
+    #[error("Column `{owner}` owning sequence `{sequence_name}` not found.")]
+    /// Error indicating that the OWNED BY clause of a CREATE SEQUENCE
+    /// statement references a column that does not exist.
+    SequenceOwnerNotFound {
+        /// Name of the sequence being created.
+        sequence_name: String,
+        /// Qualified name of the owning column.
+        owner: String,
+    },
+    #[error(
+        "Cannot drop sequence `{sequence_name}`: the default of column `{column_name}` of table `{table_name}` uses it."
+    )]
+    /// Error indicating that a DROP SEQUENCE statement without `CASCADE`
+    /// references a sequence still advanced by the default value of a column.
+    SequenceReferenced {
+        /// Name of the sequence being dropped.
+        sequence_name: String,
+        /// Name of the column whose default uses the sequence.
+        column_name: String,
+        /// Name of the table of the column.
+        table_name: String,
+    },
+    #[error(
+        "Default value `{default}` of column `{column_name}` of table `{table_name}` is of type `{default_type}`, incompatible with the column type `{column_type}`."
+    )]
+    /// Error indicating that the default value of a column is of a type which
+    /// cannot be stored in the column.
+    DefaultTypeMismatch {
+        /// Name of the table of the column.
+        table_name: String,
+        /// Name of the column.
+        column_name: String,
+        /// Normalized data type of the column.
+        column_type: String,
+        /// The default value, as written in SQL.
+        default: String,
+        /// Inferred data type of the default value.
+        default_type: String,
+    },
+    #[error(
+        "No overload of function `{function_name}` matches the call `{call}` in an expression of table `{table_name}`."
+    )]
+    /// Error indicating that a function called by a check constraint or a
+    /// policy has no overload accepting the number and types of the
+    /// arguments of the call.
+    NoMatchingFunctionOverload {
+        /// Name of the called function.
+        function_name: String,
+        /// The call, as written in SQL.
+        call: String,
+        /// Name of the table of the check constraint or policy.
+        table_name: String,
+    },
+    #[error("Type `{type_name}` already exists.")]
+    /// Error indicating that a CREATE TYPE or CREATE DOMAIN statement tries
+    /// to create a type that already exists.
+    CustomTypeAlreadyExists {
+        /// Name of the type that already exists.
+        type_name: String,
+    },
+    #[error("Type `{type_name}` not found for DROP TYPE statement.")]
+    /// Error indicating that a DROP TYPE or DROP DOMAIN statement references
+    /// a type that does not exist.
+    DropCustomTypeNotFound {
+        /// Name of the type that was not found.
+        type_name: String,
+    },
+    #[error("Extension `{extension_name}` already exists.")]
+    /// Error indicating that a CREATE EXTENSION statement tries to install an
+    /// extension that is already installed.
+    ExtensionAlreadyExists {
+        /// Name of the extension that already exists.
+        extension_name: String,
+    },
+    #[error("Extension `{extension_name}` not found for DROP EXTENSION statement.")]
+    /// Error indicating that a DROP EXTENSION statement references an
+    /// extension that is not installed.
+    DropExtensionNotFound {
+        /// Name of the extension that was not found.
+        extension_name: String,
+    },
+    #[error(
+        "Cannot drop extension `{extension_name}`: its function `{function_name}` is still referenced in the schema."
+    )]
+    /// Error indicating that a DROP EXTENSION statement references an
+    /// extension providing a function still used by other schema objects.
+    ExtensionReferenced {
+        /// Name of the extension being dropped.
+        extension_name: String,
+        /// Name of the referenced function provided by the extension.
+        function_name: String,
+    },
+    #[error(
+        "Name `{name}` is used by both a {first_kind} and a {second_kind} in schema `{schema}`."
+    )]
+    /// Error indicating that two objects sharing the relation namespace of a
+    /// schema, such as a table and an index, have the same name.
+    NameCollision {
+        /// Normalized name of the schema holding both objects.
+        schema: String,
+        /// Normalized name shared by both objects.
+        name: String,
+        /// Kind of the first object using the name.
+        first_kind: ObjectKind,
+        /// Kind of the second object using the name.
+        second_kind: ObjectKind,
+    },
     #[error("Index `{index_name}` not found for DROP INDEX statement.")]
     /// Error indicating that a DROP INDEX statement references an index
     /// that does not exist.
@@ -213,6 +516,15 @@ pub enum Error {
         /// Name of the trigger that was not found.
         trigger_name: String,
     },
+    #[error(
+        "Trigger `{trigger_name}` is defined on several tables: DROP TRIGGER must specify the table with an ON clause."
+    )]
+    /// Error indicating that a DROP TRIGGER statement without an `ON` clause
+    /// matches triggers defined on more than one table.
+    DropTriggerAmbiguous {
+        /// Name of the ambiguous trigger.
+        trigger_name: String,
+    },
     #[error("Policy `{policy_name}` not found for DROP POLICY statement.")]
     /// Error indicating that a DROP POLICY statement references a policy
     /// that does not exist.
@@ -227,9 +539,13 @@ pub enum Error {
         /// Name of the role that was not found.
         role_name: String,
     },
-    #[error("Cannot drop role `{role_name}`: still referenced by grants.")]
+    #[error(
+        "Cannot drop role `{role_name}`: still referenced by grants, default privileges or role \
+         memberships."
+    )]
     /// Error indicating that a DROP ROLE statement references a role
-    /// that is still used as a grantee in existing grants.
+    /// that is still used as a grantee in existing grants or default
+    /// privileges, or that other roles are still members of.
     RoleReferenced {
         /// Name of the role being dropped.
         role_name: String,
@@ -262,6 +578,54 @@ pub enum Error {
         /// Name of the table that was not found.
         table_name: String,
     },
+    #[error("Table `{table_name}` not found for ALTER TABLE statement.")]
+    /// Error indicating that an ALTER TABLE statement references a table
+    /// that does not exist.
+    AlterTableNotFound {
+        /// Name of the table that was not found.
+        table_name: String,
+    },
+    #[error("Column `{column_name}` already exists in table `{table_name}`.")]
+    /// Error indicating that an ALTER TABLE ... ADD COLUMN statement adds a
+    /// column whose name is already used by the table.
+    ColumnAlreadyExists {
+        /// Name of the column being added.
+        column_name: String,
+        /// Name of the table the column is added to.
+        table_name: String,
+    },
+    #[error("Column `{column_name}` not found in table `{table_name}` for DROP COLUMN statement.")]
+    /// Error indicating that an ALTER TABLE ... DROP COLUMN statement
+    /// references a column that does not exist.
+    DropColumnNotFound {
+        /// Name of the column that was not found.
+        column_name: String,
+        /// Name of the table the column was looked up in.
+        table_name: String,
+    },
+    #[error(
+        "Cannot drop column `{column_name}` of table `{table_name}`: still referenced in the schema."
+    )]
+    /// Error indicating that an ALTER TABLE ... DROP COLUMN statement
+    /// references a column that is still used by indices, unique indices,
+    /// check constraints, foreign keys or column grants.
+    ColumnReferenced {
+        /// Name of the column being dropped.
+        column_name: String,
+        /// Name of the table the column belongs to.
+        table_name: String,
+    },
+    #[error(
+        "Constraint `{constraint_name}` not found in table `{table_name}` for DROP CONSTRAINT statement."
+    )]
+    /// Error indicating that an ALTER TABLE ... DROP CONSTRAINT statement
+    /// references a constraint that does not exist.
+    DropConstraintNotFound {
+        /// Name of the constraint that was not found.
+        constraint_name: String,
+        /// Name of the table the constraint was looked up in.
+        table_name: String,
+    },
     #[error("Policy `{policy_name}` not found for ALTER POLICY statement.")]
     /// Error indicating that an ALTER POLICY statement references a policy
     /// that does not exist.
@@ -276,6 +640,45 @@ pub enum Error {
         /// Name of the schema that was not found.
         schema_name: String,
     },
+    #[error("Index `{index_name}` not found for ALTER INDEX statement.")]
+    /// Error indicating that an ALTER INDEX statement references an index
+    /// that does not exist.
+    AlterIndexNotFound {
+        /// Name of the index that was not found.
+        index_name: String,
+    },
+    #[error("Role `{role_name}` not found for ALTER ROLE statement.")]
+    /// Error indicating that an ALTER ROLE statement references a role that
+    /// does not exist.
+    AlterRoleNotFound {
+        /// Name of the role that was not found.
+        role_name: String,
+    },
+    #[error("Table `{table_name}` not found for COMMENT ON TABLE statement.")]
+    /// Error indicating that a COMMENT ON TABLE statement references a table
+    /// that does not exist.
+    CommentTableNotFound {
+        /// Name of the table that was not found.
+        table_name: String,
+    },
+    #[error(
+        "Column `{column_name}` of table `{table_name}` not found for COMMENT ON COLUMN statement."
+    )]
+    /// Error indicating that a COMMENT ON COLUMN statement references a
+    /// column that does not exist.
+    CommentColumnNotFound {
+        /// Name of the table of the column.
+        table_name: String,
+        /// Name of the column that was not found.
+        column_name: String,
+    },
+    #[error("Function `{function_name}` not found for COMMENT ON FUNCTION statement.")]
+    /// Error indicating that a COMMENT ON FUNCTION statement references a
+    /// function that does not exist.
+    CommentFunctionNotFound {
+        /// Name of the function that was not found.
+        function_name: String,
+    },
 }
 
 impl From<ParserError> for Error {
@@ -0,0 +1,7 @@
+//! Submodule exporting database schemas to the formats of the tools
+//! consuming them.
+
+pub mod diagram;
+pub mod diesel;
+pub mod graphql;
+pub mod json_schema;
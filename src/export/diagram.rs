@@ -0,0 +1,558 @@
+//! Submodule rendering the tables of a database as entity-relationship
+//! diagrams, in the Graphviz DOT language or as Mermaid `erDiagram`s.
+//!
+//! Each table is drawn as an entity listing its columns with their data
+//! types, marking the columns of its primary key with `PK` and the columns
+//! of its foreign keys with `FK`. Each foreign key is drawn as an edge from
+//! the referencing table to the referenced table, labelled with the name of
+//! the constraint or, when unnamed, with its columns.
+//!
+//! Provided with a role through [`DiagramOptions::with_role`], the diagrams
+//! render the schema as seen by the role, as described by [`RoleView`]: the
+//! tables and columns it can neither read nor write are omitted, along with
+//! the foreign keys involving them, the columns it can only write are marked
+//! `write-only`, and the tables whose rows are filtered by row-level
+//! security are marked `(row-level security)`.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use crate::{
+    structs::{RoleView, TableView},
+    traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike},
+};
+
+/// Options controlling how the diagrams are rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     export::diagram::{self, DiagramOptions},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE app.users (id INT PRIMARY KEY); CREATE TABLE logs (message TEXT);",
+/// )?;
+/// let dot = diagram::to_dot_with_options(&db, DiagramOptions::default().with_schema_groups(true));
+/// assert!(dot.contains("    subgraph cluster_0 {\n        label=\"app\";\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DiagramOptions<'db, DB: DatabaseLike> {
+    /// Whether the tables are grouped by schema.
+    schema_groups: bool,
+    /// The role whose view of the schema is rendered, if any.
+    role: Option<&'db DB::Role>,
+}
+
+impl<DB: DatabaseLike> Clone for DiagramOptions<'_, DB> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<DB: DatabaseLike> Copy for DiagramOptions<'_, DB> {}
+
+impl<DB: DatabaseLike> PartialEq for DiagramOptions<'_, DB> {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema_groups == other.schema_groups && self.role == other.role
+    }
+}
+
+impl<DB: DatabaseLike> Eq for DiagramOptions<'_, DB> {}
+
+impl<DB: DatabaseLike> Default for DiagramOptions<'_, DB> {
+    fn default() -> Self {
+        Self { schema_groups: false, role: None }
+    }
+}
+
+impl<'db, DB: DatabaseLike> DiagramOptions<'db, DB> {
+    /// Sets whether the tables qualified by a schema are grouped in a
+    /// cluster per schema. Only the DOT rendering groups tables, as Mermaid
+    /// entity-relationship diagrams cannot.
+    #[must_use]
+    pub fn with_schema_groups(mut self, schema_groups: bool) -> Self {
+        self.schema_groups = schema_groups;
+        self
+    }
+
+    /// Returns whether the tables are grouped by schema.
+    #[must_use]
+    pub fn schema_groups(&self) -> bool {
+        self.schema_groups
+    }
+
+    /// Sets the role whose view of the schema is rendered, hiding the tables
+    /// and the columns it cannot access.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role to render the schema for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{
+    ///     export::diagram::{self, DiagramOptions},
+    ///     prelude::*,
+    /// };
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE partner;
+    /// CREATE TABLE users (id INT PRIMARY KEY, password TEXT);
+    /// CREATE TABLE audit (user_id INT REFERENCES users (id));
+    /// ALTER TABLE users ENABLE ROW LEVEL SECURITY;
+    /// GRANT SELECT (id), UPDATE (password) ON users TO partner;
+    /// ",
+    /// )?;
+    /// let options = DiagramOptions::default().with_role(db.role("partner").unwrap());
+    /// assert_eq!(
+    ///     diagram::to_dot_with_options(&db, options),
+    ///     concat!(
+    ///         "digraph er {\n",
+    ///         "    rankdir=LR;\n",
+    ///         "    node [shape=record];\n",
+    ///         "    t1 [label=\"{users (row-level security)|id : INT PK\\l",
+    ///         "|password : TEXT write-only\\l}\"];\n",
+    ///         "}\n",
+    ///     )
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_role(mut self, role: &'db DB::Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Returns the role whose view of the schema is rendered, if any.
+    #[must_use]
+    pub fn role(&self) -> Option<&'db DB::Role> {
+        self.role
+    }
+}
+
+/// Renders the provided database as a Graphviz DOT digraph, with a record
+/// node per table and an edge per foreign key.
+///
+/// # Arguments
+///
+/// * `database` - The database to render.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{export::diagram, prelude::*};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, name TEXT);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+/// ",
+/// )?;
+/// assert_eq!(
+///     diagram::to_dot(&db),
+///     concat!(
+///         "digraph er {\n",
+///         "    rankdir=LR;\n",
+///         "    node [shape=record];\n",
+///         "    t0 [label=\"{posts|id : INT PK\\l|author_id : INT FK\\l}\"];\n",
+///         "    t1 [label=\"{users|id : INT PK\\l|name : TEXT\\l}\"];\n",
+///         "    t0 -> t1 [label=\"author_id\"];\n",
+///         "}\n",
+///     )
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn to_dot<DB: DatabaseLike>(database: &DB) -> String {
+    to_dot_with_options(database, DiagramOptions::default())
+}
+
+/// Same as [`to_dot`], rendering the database following the provided
+/// options.
+///
+/// # Arguments
+///
+/// * `database` - The database to render.
+/// * `options` - The options controlling the rendering.
+#[must_use]
+pub fn to_dot_with_options<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    options: DiagramOptions<'db, DB>,
+) -> String {
+    let view = options.role().map(|role| RoleView::new(database, role));
+    let mut rendering = String::from("digraph er {\n    rankdir=LR;\n    node [shape=record];\n");
+    // The schema of the cluster being rendered, if any.
+    let mut cluster: Option<&str> = None;
+    let mut clusters = 0;
+    for (index, table) in database.tables().enumerate() {
+        if view.as_ref().is_some_and(|view| view.table(table).is_none()) {
+            continue;
+        }
+        let table_view = view.as_ref().and_then(|view| view.table(table));
+        let schema = table.table_schema().filter(|_| options.schema_groups());
+        if cluster != schema {
+            if cluster.is_some() {
+                rendering.push_str("    }\n");
+            }
+            if let Some(schema) = schema {
+                let _ = writeln!(
+                    rendering,
+                    "    subgraph cluster_{clusters} {{\n        label=\"{}\";",
+                    dot_string(schema)
+                );
+                clusters += 1;
+            }
+            cluster = schema;
+        }
+        let indentation = if cluster.is_some() { "        " } else { "    " };
+        let mut label = dot_record(&table_label(table, table_view));
+        for column in visible_columns(database, table, table_view) {
+            let _ = write!(
+                label,
+                "|{} : {}",
+                dot_record(column.column_name()),
+                dot_record(column.data_type(database))
+            );
+            for key in column_keys(database, column) {
+                let _ = write!(label, " {key}");
+            }
+            if table_view.is_some_and(|table_view| table_view.is_write_only(column)) {
+                label.push_str(" write-only");
+            }
+            label.push_str("\\l");
+        }
+        let _ = writeln!(rendering, "{indentation}t{index} [label=\"{{{label}}}\"];");
+    }
+    if cluster.is_some() {
+        rendering.push_str("    }\n");
+    }
+    for (referencing, referenced, foreign_key) in foreign_key_edges(database, view.as_ref()) {
+        let label = dot_string(&foreign_key_label(database, foreign_key));
+        let _ = writeln!(rendering, "    t{referencing} -> t{referenced} [label=\"{label}\"];");
+    }
+    rendering.push_str("}\n");
+    rendering
+}
+
+/// Renders the provided database as a Mermaid entity-relationship diagram,
+/// with an entity per table and a relationship per foreign key.
+///
+/// The relationships go from the referencing tables, of which there may be
+/// many unless the foreign key spans their primary key, to the referenced
+/// tables, of which there is exactly one unless a column of the foreign key
+/// is nullable.
+///
+/// # Arguments
+///
+/// * `database` - The database to render.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{export::diagram, prelude::*};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, name TEXT);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT NOT NULL REFERENCES users (id));
+/// ",
+/// )?;
+/// assert_eq!(
+///     diagram::to_mermaid(&db),
+///     "erDiagram
+///     t0[\"posts\"] {
+///         INT id PK
+///         INT author_id FK
+///     }
+///     t1[\"users\"] {
+///         INT id PK
+///         TEXT name
+///     }
+///     t0 }o--|| t1 : \"author_id\"
+/// "
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn to_mermaid<DB: DatabaseLike>(database: &DB) -> String {
+    to_mermaid_with_options(database, DiagramOptions::default())
+}
+
+/// Same as [`to_mermaid`], rendering the database following the provided
+/// options.
+///
+/// # Arguments
+///
+/// * `database` - The database to render.
+/// * `options` - The options controlling the rendering.
+#[must_use]
+pub fn to_mermaid_with_options<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    options: DiagramOptions<'db, DB>,
+) -> String {
+    let view = options.role().map(|role| RoleView::new(database, role));
+    let mut rendering = String::from("erDiagram\n");
+    for (index, table) in database.tables().enumerate() {
+        if view.as_ref().is_some_and(|view| view.table(table).is_none()) {
+            continue;
+        }
+        let table_view = view.as_ref().and_then(|view| view.table(table));
+        let label = mermaid_string(&table_label(table, table_view));
+        let columns: Vec<String> = visible_columns(database, table, table_view)
+            .map(|column| {
+                let mut attribute = format!(
+                    "        {} {}",
+                    mermaid_word(column.data_type(database)),
+                    mermaid_word(column.column_name())
+                );
+                let keys = column_keys(database, column);
+                if !keys.is_empty() {
+                    let _ = write!(attribute, " {}", keys.join(", "));
+                }
+                if table_view.is_some_and(|table_view| table_view.is_write_only(column)) {
+                    attribute.push_str(" \"write-only\"");
+                }
+                attribute
+            })
+            .collect();
+        if columns.is_empty() {
+            let _ = writeln!(rendering, "    t{index}[\"{label}\"]");
+        } else {
+            let columns = columns.join("\n");
+            let _ = writeln!(rendering, "    t{index}[\"{label}\"] {{\n{columns}\n    }}");
+        }
+    }
+    for (referencing, referenced, foreign_key) in foreign_key_edges(database, view.as_ref()) {
+        let left = if foreign_key.is_host_primary_key(database) { "|o" } else { "}o" };
+        let right = if foreign_key.has_nullable_host_columns(database) { "o|" } else { "||" };
+        let label = mermaid_string(&foreign_key_label(database, foreign_key));
+        let _ =
+            writeln!(rendering, "    t{referencing} {left}--{right} t{referenced} : \"{label}\"");
+    }
+    rendering
+}
+
+/// Returns the columns of the table rendered under the provided view.
+fn visible_columns<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    table: &'db DB::Table,
+    table_view: Option<&TableView<'db, DB>>,
+) -> impl Iterator<Item = &'db DB::Column> {
+    table
+        .columns(database)
+        .filter(move |column| table_view.is_none_or(|table_view| table_view.is_visible(*column)))
+}
+
+/// Returns the label of the provided table: its qualified name, marked when
+/// its rows are filtered by row-level security for the role of the view.
+fn table_label<DB: DatabaseLike>(
+    table: &DB::Table,
+    table_view: Option<&TableView<'_, DB>>,
+) -> String {
+    let name = qualified_table_name(table);
+    if table_view.is_some_and(TableView::has_row_level_security) {
+        format!("{name} (row-level security)")
+    } else {
+        name
+    }
+}
+
+/// Returns the foreign keys of the database, along with the indices of their
+/// referencing and referenced tables among the tables of the database.
+///
+/// Under the view of a role, the foreign keys whose tables or host columns
+/// are hidden from the role are skipped.
+fn foreign_key_edges<'view, 'db: 'view, DB: DatabaseLike>(
+    database: &'db DB,
+    view: Option<&'view RoleView<'db, DB>>,
+) -> impl Iterator<Item = (usize, usize, &'db DB::ForeignKey)> {
+    database.tables().enumerate().flat_map(move |(index, table)| {
+        table.foreign_keys(database).filter_map(move |foreign_key| {
+            let referenced_table = foreign_key.referenced_table(database)?;
+            if let Some(view) = view {
+                let host = view.table(table)?;
+                view.table(referenced_table)?;
+                if !foreign_key.host_columns(database).all(|column| host.is_visible(column)) {
+                    return None;
+                }
+            }
+            let referenced = referenced_table.table_id(database)?;
+            Some((index, referenced, foreign_key))
+        })
+    })
+}
+
+/// Returns the keys the column is part of, among `PK` for the primary key
+/// and `FK` for the foreign keys.
+fn column_keys<DB: DatabaseLike>(database: &DB, column: &DB::Column) -> Vec<&'static str> {
+    let mut keys = Vec::new();
+    if column.is_primary_key(database) {
+        keys.push("PK");
+    }
+    if column.is_part_of_foreign_key(database) {
+        keys.push("FK");
+    }
+    keys
+}
+
+/// Returns the label of the provided foreign key: the name of its
+/// constraint, or its columns when unnamed.
+fn foreign_key_label<DB: DatabaseLike>(database: &DB, foreign_key: &DB::ForeignKey) -> String {
+    foreign_key.foreign_key_name().map_or_else(
+        || {
+            foreign_key
+                .host_columns(database)
+                .map(ColumnLike::column_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        },
+        ToString::to_string,
+    )
+}
+
+/// Returns the name of the table, qualified by its schema when it has one.
+fn qualified_table_name<T: TableLike>(table: &T) -> String {
+    match table.table_schema() {
+        Some(schema) => format!("{schema}.{}", table.table_name()),
+        None => table.table_name().to_string(),
+    }
+}
+
+/// Escapes the provided text for a DOT double-quoted string.
+fn dot_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes the provided text for a field of a DOT record label.
+fn dot_record(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if matches!(character, '\\' | '"' | '{' | '}' | '|' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+/// Escapes the provided text for a Mermaid double-quoted string.
+fn mermaid_string(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Converts the provided text to a word Mermaid accepts as the type or the
+/// name of an attribute, replacing the characters it does not allow with
+/// underscores.
+fn mermaid_word(text: &str) -> String {
+    text.chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() || "_-()[]".contains(character) {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    const SCHEMA: &str = "
+        CREATE TABLE app.users (id INT PRIMARY KEY, \"display name\" DOUBLE PRECISION);
+        CREATE TABLE app.profiles (
+            id INT PRIMARY KEY,
+            CONSTRAINT profiles_user_fk FOREIGN KEY (id) REFERENCES app.users (id)
+        );
+        CREATE TABLE audit.events (id INT, user_id INT REFERENCES app.users (id));
+        CREATE TABLE settings (name TEXT);
+    ";
+
+    #[test]
+    fn test_dot_grouped_by_schema() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SCHEMA).unwrap();
+        assert_eq!(
+            to_dot_with_options(&db, DiagramOptions::default().with_schema_groups(true)),
+            "digraph er {
+    rankdir=LR;
+    node [shape=record];
+    t0 [label=\"{settings|name : TEXT\\l}\"];
+    subgraph cluster_0 {
+        label=\"app\";
+        t1 [label=\"{app.profiles|id : INT PK FK\\l}\"];
+        t2 [label=\"{app.users|id : INT PK\\l|display name : DOUBLE PRECISION\\l}\"];
+    }
+    subgraph cluster_1 {
+        label=\"audit\";
+        t3 [label=\"{audit.events|id : INT\\l|user_id : INT FK\\l}\"];
+    }
+    t1 -> t2 [label=\"profiles_user_fk\"];
+    t3 -> t2 [label=\"user_id\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_mermaid_cardinalities() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SCHEMA).unwrap();
+        let mermaid = to_mermaid(&db);
+        assert!(mermaid.contains("        DOUBLE_PRECISION display_name\n"));
+        assert!(mermaid.contains("        INT id PK, FK\n"));
+        assert!(mermaid.contains("    t1 |o--|| t2 : \"profiles_user_fk\"\n"));
+        assert!(mermaid.contains("    t3 }o--o| t2 : \"user_id\"\n"));
+    }
+
+    #[test]
+    fn test_mermaid_for_role() {
+        let sql = format!(
+            "{SCHEMA}
+            CREATE ROLE viewer;
+            CREATE ROLE readers;
+            GRANT SELECT ON app.users TO readers;
+            GRANT SELECT (id) ON audit.events TO readers;
+            GRANT INSERT ON settings TO viewer;
+            GRANT readers TO viewer;
+            "
+        );
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        let options = DiagramOptions::default().with_role(db.role("viewer").unwrap());
+        assert_eq!(
+            to_mermaid_with_options(&db, options),
+            "erDiagram
+    t0[\"settings\"] {
+        TEXT name \"write-only\"
+    }
+    t2[\"app.users\"] {
+        INT id PK
+        DOUBLE_PRECISION display_name
+    }
+    t3[\"audit.events\"] {
+        INT id
+    }
+"
+        );
+    }
+}
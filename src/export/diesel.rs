@@ -0,0 +1,541 @@
+//! Submodule rendering the tables of a database as the `schema.rs` module of
+//! [Diesel], in the format written by `diesel print-schema`.
+//!
+//! Each table with a primary key becomes a `diesel::table!` macro with a
+//! column per column of the table, wrapped in `Nullable` when the column may
+//! hold `NULL`. The tables without a primary key, which Diesel cannot
+//! represent, are skipped. Each foreign key on a single column referencing
+//! the primary key of another table becomes a `diesel::joinable!` macro,
+//! unless several foreign keys link the same two tables, and the tables are
+//! allowed to appear in the same query. Enumeration types become the SQL
+//! types of the `sql_types` module, and the tables of the schemas other than
+//! `public` are grouped in a module per schema.
+//!
+//! Diesel cannot join tables across modules, so the foreign keys referencing
+//! a table of another schema do not become `diesel::joinable!` macros. The
+//! data types which are not recognized are rendered as `Text`.
+//!
+//! [Diesel]: https://diesel.rs
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{export::diesel, prelude::*};
+//! use sqlparser::dialect::PostgreSqlDialect;
+//!
+//! let db = ParserDB::parse::<PostgreSqlDialect>(
+//!     "
+//! CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255) NOT NULL);
+//! CREATE TABLE posts (
+//!     id INT PRIMARY KEY,
+//!     author_id INT NOT NULL REFERENCES users (id),
+//!     body TEXT
+//! );
+//! ",
+//! )?;
+//! assert_eq!(
+//!     diesel::to_schema(&db),
+//!     "// @generated automatically by sql-traits.
+//!
+//! diesel::table! {
+//!     posts (id) {
+//!         id -> Int4,
+//!         author_id -> Int4,
+//!         body -> Nullable<Text>,
+//!     }
+//! }
+//!
+//! diesel::table! {
+//!     users (id) {
+//!         id -> Int4,
+//!         name -> Varchar,
+//!     }
+//! }
+//!
+//! diesel::joinable!(posts -> users (author_id));
+//!
+//! diesel::allow_tables_to_appear_in_same_query!(
+//!     posts,
+//!     users,
+//! );
+//! "
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use crate::{
+    traits::{ColumnLike, CustomTypeLike, DatabaseLike, DialectLike, ForeignKeyLike, TableLike},
+    utils::{data_type_aliases::canonical_data_type, rust_keywords::is_rust_keyword},
+};
+
+/// Renders the tables of the provided database as the `schema.rs` module of
+/// Diesel.
+///
+/// The SQL types of the enumeration types come first, followed by the tables
+/// of the `public` schema or without a schema and by the modules of the other
+/// schemas, sorted by name.
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are rendered.
+#[must_use]
+pub fn to_schema<DB: DatabaseLike>(database: &DB) -> String {
+    let mut items = vec![String::from("// @generated automatically by sql-traits.\n")];
+    let sql_types: Vec<String> = database
+        .custom_types()
+        .filter(|custom_type| custom_type.is_enum())
+        .map(|custom_type| sql_type_definition(database, custom_type))
+        .collect();
+    if !sql_types.is_empty() {
+        items.push(format!("pub mod sql_types {{\n{}}}\n", indent(&sql_types.join("\n"))));
+    }
+
+    let casing = database.dialect().identifier_casing();
+    let mut modules: BTreeMap<Option<&str>, Vec<&DB::Table>> = BTreeMap::new();
+    for table in database.tables() {
+        if table.primary_key_columns(database).next().is_none() {
+            continue;
+        }
+        let module =
+            casing.non_default_schema(table.table_schema(), table.table_schema_is_quoted());
+        modules.entry(module).or_default().push(table);
+    }
+    for (module, tables) in modules {
+        let module_items = module_items(database, &tables, module.is_some());
+        match module {
+            Some(schema) => {
+                items.push(format!(
+                    "pub mod {} {{\n{}}}\n",
+                    identifier(schema),
+                    indent(&module_items.join("\n"))
+                ));
+            }
+            None => items.extend(module_items),
+        }
+    }
+    items.join("\n")
+}
+
+/// Renders the items of the module holding the provided tables: their
+/// `diesel::table!` macros, the `diesel::joinable!` macros of the foreign
+/// keys between them, and the macro allowing them to appear in the same
+/// query.
+fn module_items<DB: DatabaseLike>(
+    database: &DB,
+    tables: &[&DB::Table],
+    nested: bool,
+) -> Vec<String> {
+    let mut items: Vec<String> =
+        tables.iter().map(|table| table_macro(database, table, nested)).collect();
+    let joinables = joinables(database, tables);
+    if !joinables.is_empty() {
+        items.push(joinables);
+    }
+    if tables.len() > 1 {
+        let mut allowed = String::from("diesel::allow_tables_to_appear_in_same_query!(\n");
+        for table in tables {
+            let _ = writeln!(allowed, "    {},", identifier(table.table_name()));
+        }
+        allowed.push_str(");\n");
+        items.push(allowed);
+    }
+    items
+}
+
+/// Renders the `diesel::table!` macro of the provided table, nested in the
+/// module of its schema or not.
+fn table_macro<DB: DatabaseLike>(database: &DB, table: &DB::Table, nested: bool) -> String {
+    let sql_types_path = if nested { "super::super::sql_types" } else { "super::sql_types" };
+    let mut sql_types: BTreeSet<String> = BTreeSet::new();
+    let mut columns = String::new();
+    for column in table.columns(database) {
+        columns.push_str(&doc_comment(column.column_doc(database), "        "));
+        let name = identifier(column.column_name());
+        if name != column.column_name() {
+            let _ = writeln!(columns, "        #[sql_name = {:?}]", column.column_name());
+        }
+        let (column_type, sql_type) = column_type(database, column);
+        sql_types.extend(sql_type);
+        let _ = writeln!(columns, "        {name} -> {column_type},");
+    }
+
+    let mut rendering = String::from("diesel::table! {\n");
+    if !sql_types.is_empty() {
+        rendering.push_str("    use diesel::sql_types::*;\n");
+        for sql_type in &sql_types {
+            let _ = writeln!(rendering, "    use {sql_types_path}::{sql_type};");
+        }
+        rendering.push('\n');
+    }
+    rendering.push_str(&doc_comment(table.table_doc(database), "    "));
+    let name = identifier(table.table_name());
+    if name != table.table_name() {
+        let _ = writeln!(rendering, "    #[sql_name = {:?}]", table.table_name());
+    }
+    let qualified_name = match table.table_schema() {
+        Some(schema) if nested => format!("{}.{name}", identifier(schema)),
+        _ => name,
+    };
+    let primary_key: Vec<String> = table
+        .primary_key_columns(database)
+        .map(|column| identifier(column.column_name()))
+        .collect();
+    let _ = writeln!(rendering, "    {qualified_name} ({}) {{", primary_key.join(", "));
+    rendering.push_str(&columns);
+    rendering.push_str("    }\n}\n");
+    rendering
+}
+
+/// Renders the `diesel::joinable!` macros of the foreign keys between the
+/// provided tables, on a single column referencing the primary key of
+/// another table. Diesel allows a single join between two tables, so the
+/// foreign keys linking two tables which another foreign key also links are
+/// skipped.
+fn joinables<DB: DatabaseLike>(database: &DB, tables: &[&DB::Table]) -> String {
+    let mut candidates: Vec<(&DB::Table, &DB::Table, &DB::Column)> = Vec::new();
+    for &table in tables {
+        for foreign_key in table.foreign_keys(database) {
+            let Some(referenced_table) = foreign_key.referenced_table(database) else {
+                continue;
+            };
+            if foreign_key.is_self_referential(database)
+                || !tables.contains(&referenced_table)
+                || !foreign_key.is_referenced_primary_key(database)
+            {
+                continue;
+            }
+            let mut host_columns = foreign_key.host_columns(database);
+            if let (Some(column), None) = (host_columns.next(), host_columns.next()) {
+                candidates.push((table, referenced_table, column));
+            }
+        }
+    }
+
+    let mut rendering = String::new();
+    for &(host_table, referenced_table, column) in &candidates {
+        let links = candidates
+            .iter()
+            .filter(|(other_host, other_referenced, _)| {
+                (*other_host == host_table && *other_referenced == referenced_table)
+                    || (*other_host == referenced_table && *other_referenced == host_table)
+            })
+            .count();
+        if links == 1 {
+            let _ = writeln!(
+                rendering,
+                "diesel::joinable!({} -> {} ({}));",
+                identifier(host_table.table_name()),
+                identifier(referenced_table.table_name()),
+                identifier(column.column_name())
+            );
+        }
+    }
+    rendering
+}
+
+/// Renders the definition of the SQL type of the provided enumeration type.
+fn sql_type_definition<DB: DatabaseLike>(database: &DB, custom_type: &DB::CustomType) -> String {
+    let schema =
+        custom_type.schema().map_or_else(String::new, |schema| format!(", schema = {schema:?}"));
+    format!(
+        "#[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]\n\
+         #[diesel(postgres_type(name = {:?}{schema}))]\n\
+         pub struct {};\n",
+        custom_type.name(),
+        custom_type_name(database, custom_type)
+    )
+}
+
+/// Returns the Diesel type of the provided column, along with the name of the
+/// SQL type of its enumeration type, if any.
+fn column_type<DB: DatabaseLike>(database: &DB, column: &DB::Column) -> (String, Option<String>) {
+    let enumeration = column.custom_type(database).filter(|custom_type| custom_type.is_enum());
+    let (column_type, sql_type) = match enumeration {
+        Some(custom_type) => {
+            let name = custom_type_name(database, custom_type);
+            (name.clone(), Some(name))
+        }
+        None => {
+            let data_type = diesel_data_type(database, column);
+            let column_type = match data_type.trim().strip_suffix("[]") {
+                Some(element_type) => format!("Array<Nullable<{}>>", diesel_type(element_type)),
+                None => diesel_type(data_type).to_string(),
+            };
+            (column_type, None)
+        }
+    };
+    if column.is_nullable(database) {
+        (format!("Nullable<{column_type}>"), sql_type)
+    } else {
+        (column_type, sql_type)
+    }
+}
+
+/// Returns the data type of the provided column to map to a Diesel SQL type:
+/// its normalized data type, unless it is declared as `JSONB` or `BYTEA`,
+/// which Diesel tells apart from the `JSON` and `BLOB` they are normalized
+/// to.
+fn diesel_data_type<'db, DB: DatabaseLike>(database: &'db DB, column: &'db DB::Column) -> &'db str {
+    let data_type = column.resolved_data_type(database);
+    match canonical_data_type(data_type).as_str() {
+        "JSONB" | "BYTEA" => data_type,
+        _ => column.normalized_type(database),
+    }
+}
+
+/// Returns the Diesel SQL type of the values of the provided data type.
+/// Unknown data types are rendered as `Text`.
+fn diesel_type(data_type: &str) -> &'static str {
+    match canonical_data_type(data_type).as_str() {
+        "TINYINT" => "TinyInt",
+        "SMALLINT" => "Int2",
+        "INT" => "Int4",
+        "BIGINT" => "Int8",
+        "REAL" => "Float4",
+        "DOUBLE PRECISION" => "Float8",
+        "NUMERIC" => "Numeric",
+        "MONEY" => "Money",
+        "BOOLEAN" => "Bool",
+        "CHAR" => "Bpchar",
+        "VARCHAR" => "Varchar",
+        "BYTEA" => "Bytea",
+        "BLOB" => "Binary",
+        "DATE" => "Date",
+        "TIME" => "Time",
+        "TIMESTAMP" => "Timestamp",
+        "TIMESTAMP WITH TIME ZONE" => "Timestamptz",
+        "INTERVAL" => "Interval",
+        "UUID" => "Uuid",
+        "JSON" => "Json",
+        "JSONB" => "Jsonb",
+        "INET" => "Inet",
+        "CIDR" => "Cidr",
+        "MACADDR" => "MacAddr",
+        _ => "Text",
+    }
+}
+
+/// Renders the provided documentation as a doc comment with the provided
+/// indentation, or as nothing if there is none.
+fn doc_comment(doc: Option<&str>, indentation: &str) -> String {
+    let mut comment = String::new();
+    for line in doc.into_iter().flat_map(str::lines) {
+        let line = line.trim_end();
+        if line.is_empty() {
+            let _ = writeln!(comment, "{indentation}///");
+        } else {
+            let _ = writeln!(comment, "{indentation}/// {line}");
+        }
+    }
+    comment
+}
+
+/// Indents the non-empty lines of the provided text by four spaces.
+fn indent(text: &str) -> String {
+    let mut indented = String::with_capacity(text.len());
+    for line in text.lines() {
+        if !line.is_empty() {
+            indented.push_str("    ");
+            indented.push_str(line);
+        }
+        indented.push('\n');
+    }
+    indented
+}
+
+/// Returns the name of the Rust type of the provided custom type, in
+/// PascalCase, prefixed by its schema when it has one other than `public`.
+fn custom_type_name<DB: DatabaseLike>(database: &DB, custom_type: &DB::CustomType) -> String {
+    let mut type_name = database
+        .dialect()
+        .identifier_casing()
+        .non_default_schema(custom_type.schema(), custom_type.schema_is_quoted())
+        .map_or_else(String::new, pascal_case);
+    type_name.push_str(&pascal_case(custom_type.name()));
+    type_name
+}
+
+/// Converts the provided name to PascalCase, as in `PostStatus` for
+/// `post_status`.
+fn pascal_case(name: &str) -> String {
+    let mut pascal_case = String::with_capacity(name.len());
+    for word in name.split(|character: char| !character.is_ascii_alphanumeric()) {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            pascal_case.push(first.to_ascii_uppercase());
+            pascal_case.push_str(characters.as_str());
+        }
+    }
+    if pascal_case.is_empty()
+        || pascal_case.starts_with(|character: char| character.is_ascii_digit())
+        || pascal_case == "Self"
+    {
+        pascal_case.insert(0, '_');
+    }
+    pascal_case
+}
+
+/// Converts the provided name to the Rust identifier Diesel names it with,
+/// replacing the characters Rust identifiers do not allow with underscores
+/// and following keywords with an underscore.
+fn identifier(name: &str) -> String {
+    let is_valid = |character: char| character.is_ascii_alphanumeric() || character == '_';
+    let mut identifier: String =
+        name.chars().map(|character| if is_valid(character) { character } else { '_' }).collect();
+    if identifier.is_empty() || identifier.starts_with(|character: char| character.is_ascii_digit())
+    {
+        identifier.insert(0, '_');
+    }
+    if is_rust_keyword(&identifier) {
+        identifier.push('_');
+    }
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    #[test]
+    fn test_schema_modules() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TYPE app.role AS ENUM ('reader', 'writer');
+            CREATE TABLE app.users (id INT PRIMARY KEY, \"role\" app.role NOT NULL);
+            -- Posts written by the users.
+            CREATE TABLE app.posts (
+                id BIGINT PRIMARY KEY,
+                \"type\" TEXT NOT NULL,
+                author_id INT NOT NULL REFERENCES app.users (id),
+                tags TEXT[]
+            );
+            CREATE TABLE audit.events (id UUID PRIMARY KEY, user_id INT REFERENCES app.users (id));
+            CREATE TABLE logs (message TEXT);",
+        )
+        .unwrap();
+        assert_eq!(
+            to_schema(&db),
+            "// @generated automatically by sql-traits.
+
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = \"role\", schema = \"app\"))]
+    pub struct AppRole;
+}
+
+pub mod app {
+    diesel::table! {
+        /// Posts written by the users.
+        app.posts (id) {
+            id -> Int8,
+            #[sql_name = \"type\"]
+            type_ -> Text,
+            author_id -> Int4,
+            tags -> Nullable<Array<Nullable<Text>>>,
+        }
+    }
+
+    diesel::table! {
+        use diesel::sql_types::*;
+        use super::super::sql_types::AppRole;
+
+        app.users (id) {
+            id -> Int4,
+            role -> AppRole,
+        }
+    }
+
+    diesel::joinable!(posts -> users (author_id));
+
+    diesel::allow_tables_to_appear_in_same_query!(
+        posts,
+        users,
+    );
+}
+
+pub mod audit {
+    diesel::table! {
+        audit.events (id) {
+            id -> Uuid,
+            user_id -> Nullable<Int4>,
+        }
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_public_schema_follows_the_casing_of_the_dialect() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            r#"CREATE SCHEMA "Public";
+            CREATE TABLE PUBLIC.users (id INT PRIMARY KEY);
+            CREATE TABLE "Public".posts (id INT PRIMARY KEY);"#,
+        )
+        .unwrap();
+        let schema = to_schema(&db);
+        assert_eq!(schema.matches("pub mod ").count(), 1, "{schema}");
+    }
+
+    #[test]
+    fn test_ambiguous_joins_are_skipped() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE users (id INT PRIMARY KEY, manager_id INT REFERENCES users (id));
+            CREATE TABLE teams (id INT PRIMARY KEY, name TEXT UNIQUE);
+            CREATE TABLE messages (
+                id INT PRIMARY KEY,
+                sender_id INT REFERENCES users (id),
+                recipient_id INT REFERENCES users (id),
+                team_name TEXT REFERENCES teams (name)
+            );
+            CREATE TABLE memberships (
+                user_id INT REFERENCES users (id),
+                team_id INT REFERENCES teams (id),
+                PRIMARY KEY (user_id, team_id)
+            );",
+        )
+        .unwrap();
+        let schema = to_schema(&db);
+        assert!(schema.contains("    memberships (user_id, team_id) {\n"));
+        let joinables: Vec<&str> =
+            schema.lines().filter(|line| line.starts_with("diesel::joinable!")).collect();
+        assert_eq!(
+            joinables,
+            [
+                "diesel::joinable!(memberships -> users (user_id));",
+                "diesel::joinable!(memberships -> teams (team_id));",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalized_types() {
+        let db = ParserDB::parse::<MySqlDialect>(
+            "CREATE TABLE flags (id INT PRIMARY KEY, active TINYINT(1) NOT NULL, body LONGTEXT);",
+        )
+        .unwrap();
+        let schema = to_schema(&db);
+        assert!(schema.contains("        active -> Bool,\n"));
+        assert!(schema.contains("        body -> Nullable<Text>,\n"));
+
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE documents (id INT8 PRIMARY KEY, body JSONB NOT NULL, raw BYTEA);",
+        )
+        .unwrap();
+        let schema = to_schema(&db);
+        assert!(schema.contains("        id -> Int8,\n"));
+        assert!(schema.contains("        body -> Jsonb,\n"));
+        assert!(schema.contains("        raw -> Nullable<Bytea>,\n"));
+    }
+}
@@ -0,0 +1,355 @@
+//! Submodule rendering the tables of a database as the types of a [GraphQL]
+//! schema, written in its schema definition language.
+//!
+//! Each table becomes an object type named after the table in PascalCase,
+//! prefixed by its schema when it has one other than `public`, with a field
+//! per column. The columns declared `NOT NULL` become non-null fields, and
+//! each foreign key adds a field of the type of the referenced table, named
+//! after its column without the `_id` suffix or, failing that, after the
+//! referenced table. Enumeration types become GraphQL enums, and the data
+//! types without a built-in GraphQL scalar are declared as custom scalars.
+//!
+//! [GraphQL]: https://spec.graphql.org
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{export::graphql, prelude::*};
+//! use sqlparser::dialect::PostgreSqlDialect;
+//!
+//! let db = ParserDB::parse::<PostgreSqlDialect>(
+//!     "
+//! CREATE TYPE post_status AS ENUM ('draft', 'published');
+//! CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL);
+//! CREATE TABLE blog_posts (
+//!     id INT PRIMARY KEY,
+//!     author_id INT NOT NULL REFERENCES users (id),
+//!     reviewer_id INT REFERENCES users (id),
+//!     status post_status NOT NULL,
+//!     published_at TIMESTAMP
+//! );
+//! ",
+//! )?;
+//! assert_eq!(
+//!     graphql::to_sdl(&db),
+//!     "scalar DateTime
+//!
+//! enum PostStatus {
+//!   draft
+//!   published
+//! }
+//!
+//! type BlogPosts {
+//!   id: Int!
+//!   author_id: Int!
+//!   reviewer_id: Int
+//!   status: PostStatus!
+//!   published_at: DateTime
+//!   author: Users!
+//!   reviewer: Users
+//! }
+//!
+//! type Users {
+//!   id: Int!
+//!   name: String!
+//! }
+//! "
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    traits::{ColumnLike, CustomTypeLike, DatabaseLike, DialectLike, ForeignKeyLike, TableLike},
+    utils::data_type_aliases::canonical_data_type,
+};
+
+/// Renders the tables of the provided database as a GraphQL schema, in its
+/// schema definition language.
+///
+/// The custom scalars come first, sorted by name, followed by the enums of
+/// the enumeration types and by the object types of the tables, in the
+/// order of [`DatabaseLike::tables`].
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are rendered.
+#[must_use]
+pub fn to_sdl<DB: DatabaseLike>(database: &DB) -> String {
+    let mut scalars: BTreeSet<&'static str> = BTreeSet::new();
+    let object_types: Vec<String> =
+        database.tables().map(|table| object_type(database, table, &mut scalars)).collect();
+
+    let mut definitions: Vec<String> =
+        scalars.iter().map(|scalar| format!("scalar {scalar}")).collect();
+    definitions.extend(
+        database
+            .custom_types()
+            .filter(|custom_type| custom_type.is_enum())
+            .map(|custom_type| enum_type(database, custom_type)),
+    );
+    definitions.extend(object_types);
+
+    let mut sdl = definitions.join("\n\n");
+    if !sdl.is_empty() {
+        sdl.push('\n');
+    }
+    sdl
+}
+
+/// Renders the provided table as a GraphQL object type, recording the custom
+/// scalars its fields use.
+fn object_type<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    scalars: &mut BTreeSet<&'static str>,
+) -> String {
+    let casing = database.dialect().identifier_casing();
+    let mut definition = description(table.table_doc(database), "");
+    let schema = casing.non_default_schema(table.table_schema(), table.table_schema_is_quoted());
+    definition.push_str(&format!("type {} {{\n", type_name(schema, table.table_name())));
+    let mut field_names: Vec<String> = Vec::new();
+    for column in table.columns(database) {
+        let name = graphql_name(column.column_name());
+        definition.push_str(&description(column.column_doc(database), "  "));
+        definition.push_str(&format!("  {name}: {}\n", field_type(database, column, scalars)));
+        field_names.push(name);
+    }
+    for foreign_key in table.foreign_keys(database) {
+        let Some(referenced_table) = foreign_key.referenced_table(database) else {
+            continue;
+        };
+        let name = relation_name(database, foreign_key, referenced_table, &field_names);
+        let referenced_schema = casing.non_default_schema(
+            referenced_table.table_schema(),
+            referenced_table.table_schema_is_quoted(),
+        );
+        let referenced_type = type_name(referenced_schema, referenced_table.table_name());
+        let nullable =
+            foreign_key.host_columns(database).any(|column| column.is_nullable(database));
+        definition
+            .push_str(&format!("  {name}: {referenced_type}{}\n", if nullable { "" } else { "!" }));
+        field_names.push(name);
+    }
+    definition.push('}');
+    definition
+}
+
+/// Returns the name of the field of the provided foreign key, which none of
+/// the provided field names takes.
+fn relation_name<DB: DatabaseLike>(
+    database: &DB,
+    foreign_key: &DB::ForeignKey,
+    referenced_table: &DB::Table,
+    field_names: &[String],
+) -> String {
+    let mut host_columns = foreign_key.host_columns(database);
+    let column_name = match (host_columns.next(), host_columns.next()) {
+        (Some(column), None) => {
+            column.column_name().strip_suffix("_id").filter(|name| !name.is_empty())
+        }
+        _ => None,
+    };
+    let candidate = graphql_name(column_name.unwrap_or_else(|| referenced_table.table_name()));
+    let mut name = candidate.clone();
+    let mut suffix = 1;
+    while field_names.contains(&name) {
+        suffix += 1;
+        name = format!("{candidate}_{suffix}");
+    }
+    name
+}
+
+/// Returns the GraphQL type of the field of the provided column, recording
+/// the custom scalar it uses, if any.
+fn field_type<DB: DatabaseLike>(
+    database: &DB,
+    column: &DB::Column,
+    scalars: &mut BTreeSet<&'static str>,
+) -> String {
+    let enumeration = column.custom_type(database).filter(|custom_type| custom_type.is_enum());
+    let field_type = match enumeration {
+        Some(custom_type) => custom_type_name(database, custom_type),
+        None => {
+            let data_type = column.normalized_type(database);
+            match data_type.trim().strip_suffix("[]") {
+                Some(element_type) => format!("[{}]", scalar(element_type, scalars)),
+                None => scalar(data_type, scalars).to_string(),
+            }
+        }
+    };
+    if column.is_nullable(database) { field_type } else { format!("{field_type}!") }
+}
+
+/// Returns the GraphQL scalar of the values of the provided data type,
+/// recording it when it is a custom scalar. Unknown data types are rendered
+/// as strings.
+fn scalar(data_type: &str, scalars: &mut BTreeSet<&'static str>) -> &'static str {
+    let scalar = match canonical_data_type(data_type).as_str() {
+        "TINYINT" | "SMALLINT" | "INT" => return "Int",
+        "REAL" | "DOUBLE PRECISION" => return "Float",
+        "BOOLEAN" => return "Boolean",
+        "BIGINT" => "BigInt",
+        "NUMERIC" => "Decimal",
+        "BYTEA" | "BLOB" => "Bytes",
+        "DATE" => "Date",
+        "TIME" | "TIME WITH TIME ZONE" => "Time",
+        "TIMESTAMP" | "TIMESTAMP WITH TIME ZONE" => "DateTime",
+        "UUID" => "UUID",
+        "JSON" | "JSONB" => "JSON",
+        _ => return "String",
+    };
+    scalars.insert(scalar);
+    scalar
+}
+
+/// Renders the provided enumeration type as a GraphQL enum.
+fn enum_type<DB: DatabaseLike>(database: &DB, custom_type: &DB::CustomType) -> String {
+    let values: Vec<String> = custom_type
+        .enum_variants()
+        .map(|variant| {
+            let value = graphql_name(variant);
+            // `true`, `false` and `null` are not valid enum values.
+            if matches!(value.as_str(), "true" | "false" | "null") {
+                format!("  {value}_")
+            } else {
+                format!("  {value}")
+            }
+        })
+        .collect();
+    format!("enum {} {{\n{}\n}}", custom_type_name(database, custom_type), values.join("\n"))
+}
+
+/// Renders the provided documentation as a GraphQL description, on its own
+/// line with the provided indentation, or as nothing if there is none.
+fn description(doc: Option<&str>, indentation: &str) -> String {
+    doc.map_or_else(String::new, |doc| {
+        format!("{indentation}\"\"\"{}\"\"\"\n", doc.replace("\"\"\"", "\\\"\"\""))
+    })
+}
+
+/// Returns the name of the GraphQL type of the provided object, in
+/// PascalCase, prefixed by the provided schema, if any.
+fn type_name(schema: Option<&str>, name: &str) -> String {
+    let mut type_name = schema.map_or_else(String::new, pascal_case);
+    type_name.push_str(&pascal_case(name));
+    type_name
+}
+
+/// Returns the name of the GraphQL type of the provided custom type,
+/// prefixed by its schema when it has one other than `public`.
+fn custom_type_name<DB: DatabaseLike>(database: &DB, custom_type: &DB::CustomType) -> String {
+    let casing = database.dialect().identifier_casing();
+    type_name(
+        casing.non_default_schema(custom_type.schema(), custom_type.schema_is_quoted()),
+        custom_type.name(),
+    )
+}
+
+/// Converts the provided name to PascalCase, as in `BlogPosts` for
+/// `blog_posts`.
+fn pascal_case(name: &str) -> String {
+    let mut pascal_case = String::with_capacity(name.len());
+    for word in name.split(|character: char| !character.is_ascii_alphanumeric()) {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            pascal_case.push(first.to_ascii_uppercase());
+            pascal_case.push_str(characters.as_str());
+        }
+    }
+    if pascal_case.is_empty()
+        || pascal_case.starts_with(|character: char| character.is_ascii_digit())
+    {
+        pascal_case.insert(0, '_');
+    }
+    pascal_case
+}
+
+/// Converts the provided name to a valid GraphQL name, replacing the
+/// characters GraphQL names do not allow with underscores.
+fn graphql_name(name: &str) -> String {
+    let is_valid = |character: char| character.is_ascii_alphanumeric() || character == '_';
+    let mut graphql_name: String =
+        name.chars().map(|character| if is_valid(character) { character } else { '_' }).collect();
+    if graphql_name.is_empty()
+        || graphql_name.starts_with(|character: char| character.is_ascii_digit())
+    {
+        graphql_name.insert(0, '_');
+    }
+    graphql_name
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    #[test]
+    fn test_relation_names_avoid_columns() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE app.users (id INT PRIMARY KEY);
+            CREATE TABLE app.follows (
+                follower INT NOT NULL,
+                follower_id INT NOT NULL REFERENCES app.users (id),
+                id INT REFERENCES app.users (id),
+                payload JSONB,
+                tags TEXT[] NOT NULL
+            );",
+        )
+        .unwrap();
+        assert_eq!(
+            to_sdl(&db),
+            "scalar JSON
+
+type AppFollows {
+  follower: Int!
+  follower_id: Int!
+  id: Int
+  payload: JSON
+  tags: [String]!
+  follower_2: AppUsers!
+  users: AppUsers
+}
+
+type AppUsers {
+  id: Int!
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_public_schema_follows_the_casing_of_the_dialect() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            r#"CREATE SCHEMA "Public";
+            CREATE TABLE PUBLIC.users (id INT PRIMARY KEY);
+            CREATE TABLE "Public".users (id INT PRIMARY KEY);"#,
+        )
+        .unwrap();
+        let sdl = to_sdl(&db);
+        assert!(sdl.contains("type Users {"), "{sdl}");
+        assert!(sdl.contains("type PublicUsers {"), "{sdl}");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(pascal_case("order-items"), "OrderItems");
+        assert_eq!(pascal_case("2fa_codes"), "_2faCodes");
+        assert_eq!(graphql_name("unit price"), "unit_price");
+        assert_eq!(type_name(None, "users"), "Users");
+        assert_eq!(type_name(Some("app"), "users"), "AppUsers");
+        assert_eq!(
+            description(Some("Quoted \"\"\" text"), "  "),
+            "  \"\"\"Quoted \\\"\"\" text\"\"\"\n"
+        );
+    }
+}
@@ -0,0 +1,509 @@
+//! Submodule rendering the tables of a database as [JSON Schema] objects,
+//! which can be published as the component schemas of an [OpenAPI]
+//! document.
+//!
+//! Each column becomes a property of the object of its table, typed after
+//! its data type, with the columns declared `NOT NULL` listed as required
+//! and the nullable columns also accepting `null`. The check constraints on
+//! a single column refine its property: lists of allowed values become an
+//! `enum`, text length bounds become `minLength` and `maxLength`, numeric
+//! bounds become `minimum` and `maximum`, exclusive or not, and patterns
+//! become a `pattern`. The values of enumeration types also become an
+//! `enum`.
+//!
+//! Provided with a role through [`JsonSchemaOptions::with_role`], the
+//! objects render the schema as seen by the role, as described by
+//! [`RoleView`]: the tables and columns it can neither read nor write are
+//! omitted, the columns it can only write are marked `writeOnly`, and the
+//! tables whose rows are filtered by row-level security are marked with the
+//! `x-row-level-security` extension.
+//!
+//! [JSON Schema]: https://json-schema.org
+//! [OpenAPI]: https://spec.openapis.org/oas/latest.html
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{export::json_schema, prelude::*};
+//! use sqlparser::dialect::PostgreSqlDialect;
+//!
+//! let db = ParserDB::parse::<PostgreSqlDialect>(
+//!     "CREATE TABLE posts (
+//!         id INT PRIMARY KEY CHECK (id > 0),
+//!         status TEXT NOT NULL CHECK (status IN ('draft', 'published')),
+//!         title VARCHAR(100) CHECK (length(title) <= 100)
+//!     );",
+//! )?;
+//! let posts = db.table(None, "posts").unwrap();
+//! assert_eq!(
+//!     json_schema::table_schema(&db, posts),
+//!     concat!(
+//!         r#"{"type":"object","title":"posts","properties":{"#,
+//!         r#""id":{"type":"integer","exclusiveMinimum":0},"#,
+//!         r#""status":{"type":"string","enum":["draft","published"]},"#,
+//!         r#""title":{"type":["string","null"],"maxLength":100}"#,
+//!         r#"},"required":["id","status"],"additionalProperties":false}"#,
+//!     )
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Ordering, ops::Bound};
+
+use sqlparser::ast::Value;
+
+use crate::{
+    structs::{RoleView, TableView},
+    traits::{CheckConstraintLike, ColumnLike, CustomTypeLike, DatabaseLike, TableLike},
+    utils::data_type_aliases::canonical_data_type,
+};
+
+/// Options controlling how the JSON Schema objects are rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     export::json_schema::{self, JsonSchemaOptions},
+///     prelude::*,
+/// };
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "
+/// CREATE ROLE partner;
+/// CREATE TABLE orders (id INT NOT NULL, margin INT);
+/// CREATE TABLE feedback (message TEXT);
+/// CREATE TABLE audit (id INT);
+/// ALTER TABLE orders ENABLE ROW LEVEL SECURITY;
+/// GRANT SELECT (id) ON orders TO partner;
+/// GRANT INSERT ON feedback TO partner;
+/// ",
+/// )?;
+/// let options = JsonSchemaOptions::default().with_role(db.role("partner").unwrap());
+/// assert_eq!(
+///     json_schema::component_schemas_with_options(&db, options),
+///     concat!(
+///         r#"{"feedback":{"type":"object","title":"feedback","#,
+///         r#""properties":{"message":{"type":["string","null"],"writeOnly":true}},"#,
+///         r#""additionalProperties":false},"#,
+///         r#""orders":{"type":"object","title":"orders","x-row-level-security":true,"#,
+///         r#""properties":{"id":{"type":"integer"}},"#,
+///         r#""required":["id"],"additionalProperties":false}}"#,
+///     )
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct JsonSchemaOptions<'db, DB: DatabaseLike> {
+    /// The role whose view of the schema is rendered, if any.
+    role: Option<&'db DB::Role>,
+}
+
+impl<DB: DatabaseLike> Clone for JsonSchemaOptions<'_, DB> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<DB: DatabaseLike> Copy for JsonSchemaOptions<'_, DB> {}
+
+impl<DB: DatabaseLike> Default for JsonSchemaOptions<'_, DB> {
+    fn default() -> Self {
+        Self { role: None }
+    }
+}
+
+impl<'db, DB: DatabaseLike> JsonSchemaOptions<'db, DB> {
+    /// Sets the role whose view of the schema is rendered, hiding the tables
+    /// and the columns it cannot access.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role to render the schema for.
+    #[must_use]
+    pub fn with_role(mut self, role: &'db DB::Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Returns the role whose view of the schema is rendered, if any.
+    #[must_use]
+    pub fn role(&self) -> Option<&'db DB::Role> {
+        self.role
+    }
+}
+
+/// Renders the provided table as a JSON Schema object.
+///
+/// # Arguments
+///
+/// * `database` - The database the table belongs to.
+/// * `table` - The table to render.
+#[must_use]
+pub fn table_schema<DB: DatabaseLike>(database: &DB, table: &DB::Table) -> String {
+    render_table(database, table, None)
+}
+
+/// Same as [`table_schema`], rendering the table following the provided
+/// options.
+///
+/// Returns `None` when the table is hidden from the role of the options.
+///
+/// # Arguments
+///
+/// * `database` - The database the table belongs to.
+/// * `table` - The table to render.
+/// * `options` - The options controlling the rendering.
+#[must_use]
+pub fn table_schema_with_options<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    table: &'db DB::Table,
+    options: JsonSchemaOptions<'db, DB>,
+) -> Option<String> {
+    match options.role() {
+        Some(role) => {
+            let view = RoleView::new(database, role);
+            Some(render_table(database, table, Some(view.table(table)?)))
+        }
+        None => Some(render_table(database, table, None)),
+    }
+}
+
+/// Renders the provided table as a JSON Schema object, restricted to the
+/// provided view of it if any.
+fn render_table<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    view: Option<&TableView<'_, DB>>,
+) -> String {
+    let mut properties: Vec<(&str, String)> = Vec::new();
+    let mut required: Vec<String> = Vec::new();
+    for column in table.columns(database) {
+        if view.is_some_and(|view| !view.is_visible(column)) {
+            continue;
+        }
+        let write_only = view.is_some_and(|view| view.is_write_only(column));
+        properties.push((column.column_name(), column_schema(database, column, write_only)));
+        if !column.is_nullable(database) {
+            required.push(json_string(column.column_name()));
+        }
+    }
+
+    let mut schema: Vec<(&str, String)> =
+        vec![("type", json_string("object")), ("title", json_string(table.table_name()))];
+    if let Some(doc) = table.table_doc(database) {
+        schema.push(("description", json_string(doc)));
+    }
+    if view.is_some_and(TableView::has_row_level_security) {
+        schema.push(("x-row-level-security", "true".to_string()));
+    }
+    schema.push(("properties", json_object(&properties)));
+    if !required.is_empty() {
+        schema.push(("required", json_array(&required)));
+    }
+    schema.push(("additionalProperties", "false".to_string()));
+    json_object(&schema)
+}
+
+/// Renders the tables of the provided database as a JSON object mapping the
+/// name of each table, qualified by its schema when it has one, to its JSON
+/// Schema object, as expected under `components.schemas` in an OpenAPI
+/// document.
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{export::json_schema, prelude::*};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE tags (name TEXT NOT NULL); CREATE TABLE app.users (id INT NOT NULL);",
+/// )?;
+/// let components = json_schema::component_schemas(&db);
+/// assert!(components.starts_with(r#"{"tags":{"type":"object","title":"tags","#));
+/// assert!(components.contains(r#","app.users":{"type":"object","title":"users","#));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn component_schemas<DB: DatabaseLike>(database: &DB) -> String {
+    component_schemas_with_options(database, JsonSchemaOptions::default())
+}
+
+/// Same as [`component_schemas`], rendering the tables following the
+/// provided options.
+///
+/// # Arguments
+///
+/// * `database` - The database whose tables are rendered.
+/// * `options` - The options controlling the rendering.
+#[must_use]
+pub fn component_schemas_with_options<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    options: JsonSchemaOptions<'db, DB>,
+) -> String {
+    let view = options.role().map(|role| RoleView::new(database, role));
+    let schemas: Vec<(String, String)> = database
+        .tables()
+        .filter_map(|table| {
+            let table_view = match &view {
+                Some(view) => Some(view.table(table)?),
+                None => None,
+            };
+            let name = match table.table_schema() {
+                Some(schema) => format!("{schema}.{}", table.table_name()),
+                None => table.table_name().to_string(),
+            };
+            Some((name, render_table(database, table, table_view)))
+        })
+        .collect();
+    json_object(&schemas)
+}
+
+/// Renders the provided column as a JSON Schema object, marked `writeOnly`
+/// if `write_only` is set.
+fn column_schema<DB: DatabaseLike>(database: &DB, column: &DB::Column, write_only: bool) -> String {
+    let nullable = column.is_nullable(database);
+    let enumeration = column.custom_type(database).filter(|custom_type| custom_type.is_enum());
+    let (json_type, format) = if enumeration.is_some() {
+        (Some("string"), None)
+    } else {
+        json_type(column.normalized_type(database))
+    };
+
+    let mut allowed_values: Option<Vec<String>> =
+        enumeration.map(|custom_type| custom_type.enum_variants().map(json_string).collect());
+    let mut bounds = (Bound::Unbounded, Bound::Unbounded);
+    let mut min_length: Option<usize> = None;
+    let mut max_length: Option<usize> = None;
+    let mut pattern: Option<String> = None;
+    for check in column.check_constraints(database) {
+        if allowed_values.is_none() {
+            allowed_values = check
+                .allowed_values(database, column)
+                .and_then(|values| values.iter().map(json_value).collect::<Option<Vec<String>>>());
+        }
+        if let Some((lower, upper)) = check.numeric_bounds(database, column) {
+            bounds = (tighter(bounds.0, lower, true), tighter(bounds.1, upper, false));
+        }
+        if pattern.is_none() {
+            pattern = check.pattern(database, column);
+        }
+        // The text length bounds are those of the whole constraint, so they
+        // can only be attributed to the column when it is its only column.
+        if check.columns(database).count() == 1 {
+            if let Some(lower) = check.is_lower_bounded_text_constraint(database) {
+                min_length = Some(min_length.map_or(lower, |min_length| min_length.max(lower)));
+            }
+            if let Some(upper) = check.is_upper_bounded_text_constraint(database) {
+                let upper = upper.saturating_sub(1);
+                max_length = Some(max_length.map_or(upper, |max_length| max_length.min(upper)));
+            }
+        }
+    }
+
+    let mut schema: Vec<(&str, String)> = Vec::new();
+    if let Some(json_type) = json_type {
+        schema.push((
+            "type",
+            if nullable {
+                json_array(&[json_string(json_type), json_string("null")])
+            } else {
+                json_string(json_type)
+            },
+        ));
+    }
+    if let Some(format) = format {
+        schema.push(("format", json_string(format)));
+    }
+    if let Some(doc) = column.column_doc(database) {
+        schema.push(("description", json_string(doc)));
+    }
+    if let Some(mut allowed_values) = allowed_values {
+        if nullable {
+            allowed_values.push("null".to_string());
+        }
+        schema.push(("enum", json_array(&allowed_values)));
+    }
+    if let Some(min_length) = min_length {
+        schema.push(("minLength", min_length.to_string()));
+    }
+    if let Some(max_length) = max_length {
+        schema.push(("maxLength", max_length.to_string()));
+    }
+    match bounds.0 {
+        Bound::Included(value) if value.is_finite() => schema.push(("minimum", value.to_string())),
+        Bound::Excluded(value) if value.is_finite() => {
+            schema.push(("exclusiveMinimum", value.to_string()));
+        }
+        _ => {}
+    }
+    match bounds.1 {
+        Bound::Included(value) if value.is_finite() => schema.push(("maximum", value.to_string())),
+        Bound::Excluded(value) if value.is_finite() => {
+            schema.push(("exclusiveMaximum", value.to_string()));
+        }
+        _ => {}
+    }
+    if let Some(pattern) = pattern {
+        schema.push(("pattern", json_string(&pattern)));
+    }
+    if write_only {
+        schema.push(("writeOnly", "true".to_string()));
+    }
+    json_object(&schema)
+}
+
+/// Returns the JSON type of the values of the provided data type, and the
+/// format refining it, or `None` for the data types whose values may be of
+/// any JSON type, such as `JSON` columns or unknown types.
+fn json_type(data_type: &str) -> (Option<&'static str>, Option<&'static str>) {
+    match canonical_data_type(data_type).as_str() {
+        "TINYINT" | "SMALLINT" | "INT" | "BIGINT" => (Some("integer"), None),
+        "REAL" | "DOUBLE PRECISION" | "NUMERIC" => (Some("number"), None),
+        "BOOLEAN" => (Some("boolean"), None),
+        "CHAR" | "VARCHAR" | "TEXT" => (Some("string"), None),
+        "BYTEA" | "BLOB" => (Some("string"), Some("byte")),
+        "DATE" => (Some("string"), Some("date")),
+        "TIME" | "TIME WITH TIME ZONE" => (Some("string"), Some("time")),
+        "TIMESTAMP" | "TIMESTAMP WITH TIME ZONE" => (Some("string"), Some("date-time")),
+        "UUID" => (Some("string"), Some("uuid")),
+        _ => (None, None),
+    }
+}
+
+/// Returns the tighter of two lower bounds, or of two upper bounds.
+fn tighter(left: Bound<f64>, right: Bound<f64>, lower: bool) -> Bound<f64> {
+    let (Bound::Included(left_value) | Bound::Excluded(left_value)) = left else {
+        return right;
+    };
+    let (Bound::Included(right_value) | Bound::Excluded(right_value)) = right else {
+        return left;
+    };
+    match left_value.partial_cmp(&right_value) {
+        Some(Ordering::Equal) if matches!(left, Bound::Excluded(_)) => left,
+        Some(Ordering::Equal) => right,
+        Some(Ordering::Greater) if lower => left,
+        Some(Ordering::Less) if !lower => left,
+        _ => right,
+    }
+}
+
+/// Renders the provided SQL literal as a JSON value, if it is a string, a
+/// number or a boolean.
+fn json_value(value: &Value) -> Option<String> {
+    match value {
+        Value::SingleQuotedString(value) => Some(json_string(value)),
+        Value::Number(number, _) => number.parse::<f64>().is_ok().then(|| number.to_string()),
+        Value::Boolean(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Renders the provided text as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut rendered = String::with_capacity(text.len() + 2);
+    rendered.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => rendered.push_str("\\\""),
+            '\\' => rendered.push_str("\\\\"),
+            '\n' => rendered.push_str("\\n"),
+            '\r' => rendered.push_str("\\r"),
+            '\t' => rendered.push_str("\\t"),
+            character if character.is_control() => {
+                rendered.push_str(&format!("\\u{:04x}", u32::from(character)));
+            }
+            character => rendered.push(character),
+        }
+    }
+    rendered.push('"');
+    rendered
+}
+
+/// Renders the provided rendered JSON values as a JSON array.
+fn json_array(values: &[String]) -> String {
+    format!("[{}]", values.join(","))
+}
+
+/// Renders the provided keys and rendered JSON values as a JSON object.
+fn json_object<K: AsRef<str>>(entries: &[(K, String)]) -> String {
+    let entries: Vec<String> = entries
+        .iter()
+        .map(|(key, value)| format!("{}:{value}", json_string(key.as_ref())))
+        .collect();
+    format!("{{{}}}", entries.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    fn column_schema_of(sql: &str, column: &str) -> String {
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).unwrap();
+        let table = db.tables().next().unwrap();
+        column_schema(&db, table.column(column, &db).unwrap(), false)
+    }
+
+    #[test]
+    fn test_types_and_formats() {
+        let sql = "CREATE TABLE t (
+            id UUID NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL,
+            score DOUBLE PRECISION NOT NULL,
+            payload JSONB
+        );";
+        assert_eq!(column_schema_of(sql, "id"), r#"{"type":"string","format":"uuid"}"#);
+        assert_eq!(
+            column_schema_of(sql, "created_at"),
+            r#"{"type":"string","format":"date-time"}"#
+        );
+        assert_eq!(column_schema_of(sql, "score"), r#"{"type":"number"}"#);
+        assert_eq!(column_schema_of(sql, "payload"), "{}");
+    }
+
+    #[test]
+    fn test_enumerations_and_bounds() {
+        let sql = "CREATE TYPE mood AS ENUM ('happy', 'sad');
+            CREATE TABLE t (
+                feeling mood,
+                age INT NOT NULL CHECK (age >= 0) CHECK (age < 150 AND age >= 18),
+                code TEXT NOT NULL CHECK (length(code) >= 2 AND length(code) < 5),
+                handle TEXT NOT NULL CHECK (handle LIKE '@%')
+            );";
+        assert_eq!(
+            column_schema_of(sql, "feeling"),
+            r#"{"type":["string","null"],"enum":["happy","sad",null]}"#
+        );
+        assert_eq!(
+            column_schema_of(sql, "age"),
+            r#"{"type":"integer","minimum":18,"exclusiveMaximum":150}"#
+        );
+        assert_eq!(
+            column_schema_of(sql, "code"),
+            r#"{"type":"string","minLength":2,"maxLength":4}"#
+        );
+        assert_eq!(column_schema_of(sql, "handle"), r#"{"type":"string","pattern":"^(?:@.*)$"}"#);
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+}
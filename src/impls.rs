@@ -4,4 +4,6 @@
 mod sqlparser;
 
 pub use sqlparser::{SqlparserDialect, apply_revoke_to_grant};
-pub(crate) use sqlparser::{has_unsupported_column_scoped_revoke, partition_grantees_for_revoke};
+pub(crate) use sqlparser::{
+    has_unsupported_column_scoped_revoke, partition_grantees_for_revoke, split_grant_by_scope,
+};
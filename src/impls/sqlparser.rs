@@ -9,15 +9,22 @@ mod create_policy;
 mod create_role;
 mod create_table;
 mod create_trigger;
+mod create_view;
+mod custom_type;
 mod data_statement;
+mod default_privilege;
 mod dialect;
 mod dml;
 mod dql;
 mod foreign_key_constraint;
 mod grant;
+mod primary_key_constraint;
 mod schema;
+mod sequence;
 mod unique_constraint;
 
 pub use dialect::SqlparserDialect;
 pub use grant::apply_revoke_to_grant;
-pub(crate) use grant::{has_unsupported_column_scoped_revoke, partition_grantees_for_revoke};
+pub(crate) use grant::{
+    has_unsupported_column_scoped_revoke, partition_grantees_for_revoke, split_grant_by_scope,
+};
@@ -1,19 +1,58 @@
 //! Implement the [`CheckConstraint`] trait for the `sqlparser` crate's
 
-use sqlparser::ast::{CheckConstraint, CreateTable, Expr};
+use alloc::{borrow::Cow, string::ToString};
+
+use sqlparser::{
+    ast::{CheckConstraint, CreateTable, Expr},
+    tokenizer::Span,
+};
 
 use crate::{
-    structs::{ParserDB, TableAttribute, metadata::CheckMetadata},
-    traits::{CheckConstraintLike, DatabaseLike, Metadata},
+    structs::{ParserDB, SourceLocation, TableAttribute, metadata::CheckMetadata},
+    traits::{CheckConstraintLike, DatabaseLike, Metadata, ObjectKind, TableLike},
+    utils::object_name::ident_span,
 };
 
 impl Metadata for TableAttribute<CreateTable, CheckConstraint> {
     type Meta = CheckMetadata<Self>;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::CheckConstraint
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        match &self.attribute().name {
+            Some(name) => Cow::Borrowed(name.value.as_str()),
+            None => Cow::Owned(self.attribute().to_string()),
+        }
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.attribute().name.as_ref().and_then(ident_span)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl CheckConstraintLike for TableAttribute<CreateTable, CheckConstraint> {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database
+            .check_constraint_metadata(self)
+            .and_then(|metadata| self.object_source_location(metadata))
+    }
+
     #[inline]
     fn expression<'db>(&'db self, _database: &'db Self::DB) -> &'db Expr {
         self.attribute().expr.as_ref()
@@ -27,6 +66,14 @@ impl CheckConstraintLike for TableAttribute<CreateTable, CheckConstraint> {
             .table()
     }
 
+    #[inline]
+    fn no_inherit(&self, database: &Self::DB) -> bool {
+        database
+            .check_constraint_metadata(self)
+            .expect("Check constraint must exist in database")
+            .no_inherit()
+    }
+
     #[inline]
     fn columns<'db>(
         &'db self,
@@ -1,20 +1,42 @@
 //! Submodule implementing the [`ColumnLike`] trait for `sqlparser`'s
 //! [`ColumnDef`] struct.
 
-use alloc::string::{String, ToString};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
 
-use sqlparser::ast::{ColumnDef, CreateTable};
+use sqlparser::{
+    ast::{ColumnDef, ColumnOption, CreateTable},
+    tokenizer::Span,
+};
 
 use crate::{
     structs::{ParserDB, TableAttribute},
-    traits::{ColumnLike, DatabaseLike, Metadata},
-    utils::normalize_sqlparser_type,
+    traits::{ColumnLike, DatabaseLike, Metadata, ObjectKind, TableLike},
+    utils::{nextval::nextval_sequence_name, normalize_sqlparser_type, object_name::ident_span},
 };
 
 const GENERATED_TYPES: &[&str] = &["SERIAL", "BIGSERIAL", "SMALLSERIAL"];
 
 impl Metadata for TableAttribute<CreateTable, ColumnDef> {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Column
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.attribute().name.value.as_str())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        ident_span(&self.attribute().name)
+    }
 }
 
 impl ColumnLike for TableAttribute<CreateTable, ColumnDef> {
@@ -35,11 +57,22 @@ impl ColumnLike for TableAttribute<CreateTable, ColumnDef> {
     where
         Self: 'db,
     {
-        database
-            .table_metadata(self.table())
-            .expect("Table must exist in database")
-            .table_doc()
-            .and_then(|d| d.column(self.column_name()).ok().and_then(|c| c.doc()))
+        let metadata = database.table_metadata(self.table()).expect("Table must exist in database");
+        // `COMMENT ON COLUMN` takes precedence over an inline `COMMENT`
+        // option, which takes precedence over the SQL comments.
+        metadata
+            .column_comment(self.column_name())
+            .or_else(|| {
+                self.attribute().options.iter().find_map(|option| match &option.option {
+                    ColumnOption::Comment(comment) => Some(comment.as_str()),
+                    _ => None,
+                })
+            })
+            .or_else(|| {
+                metadata
+                    .table_doc()
+                    .and_then(|d| d.column(self.column_name()).ok().and_then(|c| c.doc()))
+            })
     }
 
     #[inline]
@@ -73,6 +106,24 @@ impl ColumnLike for TableAttribute<CreateTable, ColumnDef> {
         })
     }
 
+    fn is_auto_generated(&self, _database: &Self::DB) -> bool {
+        self.attribute().options.iter().any(|option| match &option.option {
+            // `GENERATED ... AS IDENTITY`, unlike the generated columns
+            // computed from an expression.
+            ColumnOption::Generated { generation_expr: None, .. }
+            // SQL Server `IDENTITY(seed, increment)`.
+            | ColumnOption::Identity(_) => true,
+            // SQLite `AUTOINCREMENT` and MySQL `AUTO_INCREMENT`.
+            ColumnOption::DialectSpecific(tokens) => tokens.iter().any(|token| {
+                let token = token.to_string();
+                token.eq_ignore_ascii_case("AUTOINCREMENT")
+                    || token.eq_ignore_ascii_case("AUTO_INCREMENT")
+            }),
+            _ => false,
+        }) || self.is_generated()
+            || self.default_value().is_some_and(|value| nextval_sequence_name(&value).is_some())
+    }
+
     #[inline]
     fn table<'a>(&'a self, _database: &'a Self::DB) -> &'a <Self::DB as DatabaseLike>::Table
     where
@@ -90,7 +141,7 @@ mod tests {
 
     use crate::{
         prelude::ParserDB,
-        traits::{DatabaseLike, DialectLike, TableLike, TypeMatch, TypeMatchLike},
+        traits::{ColumnLike, DatabaseLike, DialectLike, TableLike, TypeMatch, TypeMatchLike},
     };
 
     fn parse_with<D: sqlparser::dialect::Dialect + Default + 'static>(sql: &str) -> ParserDB {
@@ -230,4 +281,45 @@ mod tests {
         let db = parse_with::<SQLiteDialect>("CREATE TABLE t (id INTEGER);");
         assert_eq!(uuid_of(&db, "id"), TypeMatch::No);
     }
+
+    // ---------------- is_auto_generated ----------------
+
+    fn auto_generated_of(db: &ParserDB, col: &str) -> bool {
+        let table = db.table(None, "t").expect("table t exists");
+        table.column(col, db).expect("column exists").is_auto_generated(db)
+    }
+
+    #[test]
+    fn is_auto_generated_sqlite_autoincrement() {
+        let db = parse_with::<SQLiteDialect>(
+            "CREATE TABLE t (id INTEGER PRIMARY KEY AUTOINCREMENT, n INTEGER);",
+        );
+        assert!(auto_generated_of(&db, "id"));
+        assert!(!auto_generated_of(&db, "n"));
+    }
+
+    #[test]
+    fn is_auto_generated_mysql_auto_increment() {
+        let db = parse_with::<MySqlDialect>("CREATE TABLE t (id INT AUTO_INCREMENT, n INT);");
+        assert!(auto_generated_of(&db, "id"));
+        assert!(!auto_generated_of(&db, "n"));
+    }
+
+    #[test]
+    fn is_auto_generated_mssql_identity() {
+        let db = parse_with::<MsSqlDialect>("CREATE TABLE t (id INT IDENTITY(1, 1), n INT);");
+        assert!(auto_generated_of(&db, "id"));
+        assert!(!auto_generated_of(&db, "n"));
+    }
+
+    #[test]
+    fn is_auto_generated_postgres_identity_but_not_computed_columns() {
+        let db = parse_with::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT GENERATED BY DEFAULT AS IDENTITY, n INT, \
+             twice INT GENERATED ALWAYS AS (n * 2) STORED);",
+        );
+        assert!(auto_generated_of(&db, "id"));
+        assert!(!auto_generated_of(&db, "n"));
+        assert!(!auto_generated_of(&db, "twice"));
+    }
 }
@@ -1,24 +1,66 @@
 //! Implementation of the `FunctionLike` trait for sqlparser's `CreateFunction`
 //! type.
 
-use sqlparser::ast::{
-    CreateFunction, CreateFunctionBody, Expr, FunctionReturnType, ObjectNamePart, Value,
-    ValueWithSpan,
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+
+use sqlparser::{
+    ast::{
+        CreateFunction, CreateFunctionBody, Expr, FunctionBehavior, FunctionReturnType,
+        FunctionSecurity, ObjectNamePart, Statement, Value, ValueWithSpan,
+    },
+    tokenizer::Span,
 };
 
 use crate::{
-    structs::ParserDB,
-    traits::{FunctionLike, Metadata},
-    utils::{last_str, normalize_sqlparser_type},
+    errors::Error,
+    structs::{ParserDB, SourceLocation, metadata::FunctionMetadata},
+    traits::{DatabaseLike, FunctionLike, Metadata, ObjectKind},
+    utils::{
+        function_body::parse_create_function_body,
+        last_str, normalize_sqlparser_type,
+        object_name::{object_name_span, schema_from_object_name},
+    },
 };
 
 impl Metadata for CreateFunction {
-    type Meta = ();
+    type Meta = FunctionMetadata;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Function
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(last_str(&self.name))
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        schema_from_object_name(&self.name).map(|(schema, _)| schema)
+    }
+
+    fn object_doc<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a str> {
+        meta.comment()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        object_name_span(&self.name)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl FunctionLike for CreateFunction {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database.function_metadata(self).and_then(|metadata| self.object_source_location(metadata))
+    }
+
     #[inline]
     fn name(&self) -> &str {
         last_str(&self.name)
@@ -35,6 +77,16 @@ impl FunctionLike for CreateFunction {
         }
     }
 
+    #[inline]
+    fn schema(&self) -> Option<&str> {
+        schema_from_object_name(&self.name).map(|(schema, _)| schema)
+    }
+
+    #[inline]
+    fn schema_is_quoted(&self) -> bool {
+        schema_from_object_name(&self.name).is_some_and(|(_, quoted)| quoted)
+    }
+
     #[inline]
     fn argument_type_names<'db>(
         &'db self,
@@ -76,4 +128,40 @@ impl FunctionLike for CreateFunction {
             _ => None,
         }
     }
+
+    fn parsed_body(&self, database: &Self::DB) -> Result<Vec<Statement>, Error> {
+        Ok(parse_create_function_body(database.dialect().sqlparser_dialect(), self)?)
+    }
+
+    #[inline]
+    fn security(&self) -> FunctionSecurity {
+        match &self.security {
+            Some(FunctionSecurity::Definer) => FunctionSecurity::Definer,
+            Some(FunctionSecurity::Invoker) | None => FunctionSecurity::Invoker,
+        }
+    }
+
+    #[inline]
+    fn volatility(&self) -> FunctionBehavior {
+        match &self.behavior {
+            Some(FunctionBehavior::Immutable) => FunctionBehavior::Immutable,
+            Some(FunctionBehavior::Stable) => FunctionBehavior::Stable,
+            Some(FunctionBehavior::Volatile) | None => FunctionBehavior::Volatile,
+        }
+    }
+
+    #[inline]
+    fn sets_configuration_parameter(&self, parameter: &str) -> bool {
+        self.set_params.iter().any(|param| param.name.to_string().eq_ignore_ascii_case(parameter))
+    }
+
+    #[inline]
+    fn is_builtin(&self, database: &Self::DB) -> bool {
+        database.function_metadata(self).is_some_and(FunctionMetadata::is_builtin)
+    }
+
+    #[inline]
+    fn function_doc<'db>(&'db self, database: &'db Self::DB) -> Option<&'db str> {
+        database.function_metadata(self).and_then(|metadata| self.object_doc(metadata))
+    }
 }
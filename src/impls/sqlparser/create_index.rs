@@ -1,19 +1,56 @@
 //! Implement the `IndexLike` trait for `sqlparser`'s `CreateIndex`.
 
-use sqlparser::ast::{CreateIndex, CreateTable, Expr};
+use alloc::{borrow::Cow, string::ToString};
+
+use sqlparser::{
+    ast::{CreateIndex, CreateTable, Expr, IndexColumn},
+    tokenizer::Span,
+};
 
 use crate::{
-    structs::{ParserDB, TableAttribute, metadata::IndexMetadata},
-    traits::{DatabaseLike, IndexLike, Metadata},
+    structs::{ParserDB, SourceLocation, TableAttribute, metadata::IndexMetadata},
+    traits::{DatabaseLike, IndexLike, Metadata, ObjectKind, TableLike},
+    utils::{last_str, object_name::object_name_span},
 };
 
 impl Metadata for TableAttribute<CreateTable, CreateIndex> {
     type Meta = IndexMetadata<Self>;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Index
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        match &self.attribute().name {
+            Some(name) => Cow::Borrowed(last_str(name)),
+            None => Cow::Owned(self.attribute().to_string()),
+        }
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.attribute().name.as_ref().and_then(object_name_span)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl IndexLike for TableAttribute<CreateTable, CreateIndex> {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database.index_metadata(self).and_then(|metadata| self.object_source_location(metadata))
+    }
+
     #[inline]
     fn table<'db>(&'db self, _database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
@@ -27,6 +64,21 @@ impl IndexLike for TableAttribute<CreateTable, CreateIndex> {
         self.attribute().name.as_ref()
     }
 
+    #[inline]
+    fn index_columns(&self) -> &[IndexColumn] {
+        &self.attribute().columns
+    }
+
+    #[inline]
+    fn predicate(&self) -> Option<&Expr> {
+        self.attribute().predicate.as_ref()
+    }
+
+    #[inline]
+    fn storage_parameters(&self) -> &[Expr] {
+        &self.attribute().with
+    }
+
     #[inline]
     fn expression<'db>(&'db self, database: &'db Self::DB) -> &'db Expr
     where
@@ -34,4 +86,9 @@ impl IndexLike for TableAttribute<CreateTable, CreateIndex> {
     {
         database.index_metadata(self).expect("Index must exist in database").expression()
     }
+
+    #[inline]
+    fn is_clustered(&self, database: &Self::DB) -> bool {
+        database.index_metadata(self).is_some_and(IndexMetadata::clustered)
+    }
 }
@@ -1,15 +1,40 @@
 //! Implementation of the `PolicyLike` trait for `CreatePolicy` struct.
 
-use sqlparser::ast::{CreatePolicy, CreatePolicyCommand, Expr, Owner};
+use alloc::borrow::Cow;
+
+use sqlparser::{
+    ast::{CreatePolicy, CreatePolicyCommand, Expr, Owner},
+    tokenizer::Span,
+};
 
 use crate::{
-    structs::{ParserDB, metadata::PolicyMetadata},
-    traits::{DatabaseLike, DocumentationMetadata, Metadata, PolicyLike},
-    utils::last_str,
+    structs::{ParserDB, SourceLocation, metadata::PolicyMetadata},
+    traits::{DatabaseLike, DocumentationMetadata, Metadata, ObjectKind, PolicyLike},
+    utils::object_name::{ident_span, schema_from_object_name},
 };
 
 impl Metadata for CreatePolicy {
     type Meta = PolicyMetadata<Self>;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Policy
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name.value.as_str())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        schema_from_object_name(&self.table_name).map(|(schema, _)| schema)
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        ident_span(&self.name)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl DocumentationMetadata for CreatePolicy {
@@ -19,6 +44,14 @@ impl DocumentationMetadata for CreatePolicy {
 impl PolicyLike for CreatePolicy {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database.policy_metadata(self).and_then(|metadata| self.object_source_location(metadata))
+    }
+
     fn name(&self) -> &str {
         &self.name.value
     }
@@ -27,9 +60,11 @@ impl PolicyLike for CreatePolicy {
     where
         Self: 'db,
     {
-        let table_name = last_str(&self.table_name);
-
-        database.table(None, table_name).expect("Table referenced by policy not found")
+        database
+            .resolve_table_object_name(&self.table_name)
+            .ok()
+            .flatten()
+            .expect("Table referenced by policy not found")
     }
 
     fn command(&self) -> CreatePolicyCommand {
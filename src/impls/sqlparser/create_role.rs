@@ -1,15 +1,32 @@
 //! Implementation of the `RoleLike` trait for sqlparser's `CreateRole` type.
 
-use sqlparser::ast::CreateRole;
+use alloc::borrow::Cow;
+
+use sqlparser::{ast::CreateRole, tokenizer::Span};
 
 use crate::{
-    structs::ParserDB,
-    traits::{DatabaseLike, Metadata, PolicyLike, RoleLike},
-    utils::last_str,
+    structs::{IdentifierCasing, ParserDB},
+    traits::{DatabaseLike, DialectLike, Metadata, ObjectKind, PolicyLike, RoleLike},
+    utils::{
+        last_str,
+        object_name::{object_name_last_part, object_name_span},
+    },
 };
 
 impl Metadata for CreateRole {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Role
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(RoleLike::name(self))
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.names.first().and_then(object_name_span)
+    }
 }
 
 impl RoleLike for CreateRole {
@@ -19,6 +36,10 @@ impl RoleLike for CreateRole {
         last_str(self.names.first().expect("CREATE ROLE must have a name"))
     }
 
+    fn name_is_quoted(&self) -> bool {
+        self.names.first().and_then(object_name_last_part).is_some_and(|(_, quoted)| quoted)
+    }
+
     fn is_superuser(&self) -> bool {
         self.superuser == Some(true)
     }
@@ -67,24 +88,51 @@ impl RoleLike for CreateRole {
         database: &'db Self::DB,
     ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role> {
         // IN ROLE clause specifies roles this role is a member of
-        self.in_role.iter().filter_map(move |role_ident| database.role(&role_ident.value))
+        let casing = database.dialect().identifier_casing();
+        self.in_role.iter().filter_map(move |role_ident| {
+            database.roles().find(|role| {
+                casing.matches(
+                    role.name(),
+                    role.name_is_quoted(),
+                    &role_ident.value,
+                    role_ident.quote_style.is_some(),
+                )
+            })
+        })
+    }
+
+    fn members<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role> {
+        database.roles().filter(move |role| role.member_of(database).any(|parent| parent == self))
     }
 
     fn policies<'db>(
         &'db self,
         database: &'db Self::DB,
     ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Policy> {
-        let role_name = self.name();
+        let casing = database.dialect().identifier_casing();
         database.policies().filter(move |policy| {
-            policy.roles(database).any(|owner| owner_matches_role(owner, role_name))
+            policy.roles(database).any(|owner| owner_matches_role(owner, self, casing))
         })
     }
 }
 
-/// Helper function to check if an Owner matches a role name.
-fn owner_matches_role(owner: &sqlparser::ast::Owner, role_name: &str) -> bool {
+/// Helper function to check if an Owner matches a role, under the casing of
+/// the dialect.
+fn owner_matches_role(
+    owner: &sqlparser::ast::Owner,
+    role: &CreateRole,
+    casing: IdentifierCasing,
+) -> bool {
     match owner {
-        sqlparser::ast::Owner::Ident(ident) => ident.value == role_name,
+        sqlparser::ast::Owner::Ident(ident) => casing.matches(
+            role.name(),
+            role.name_is_quoted(),
+            &ident.value,
+            ident.quote_style.is_some(),
+        ),
         sqlparser::ast::Owner::CurrentUser
         | sqlparser::ast::Owner::CurrentRole
         | sqlparser::ast::Owner::SessionUser => false,
@@ -95,7 +143,10 @@ fn owner_matches_role(owner: &sqlparser::ast::Owner, role_name: &str) -> bool {
 mod tests {
     use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
 
-    use crate::{structs::ParserDB, traits::RoleLike};
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, PolicyLike, RoleLike},
+    };
 
     /// Helper to parse SQL using PostgreSQL dialect
     fn parse_postgres(sql: &str) -> ParserDB {
@@ -181,4 +232,34 @@ mod tests {
 
         assert_eq!(policies.len(), 2);
     }
+
+    #[test]
+    fn test_quoted_role_membership_and_policies() {
+        let db = parse_postgres(
+            r#"
+            CREATE ROLE "Admins";
+            CREATE ROLE admins;
+            CREATE ROLE alice;
+            GRANT "Admins" TO alice;
+            CREATE TABLE t (id INT);
+            CREATE POLICY p ON t TO "Admins" USING (true);
+            CREATE POLICY q ON t TO ADMINS USING (true);
+        "#,
+        );
+
+        let alice = db.role("alice").unwrap();
+        let parents: Vec<_> = alice.member_of(&db).collect();
+        assert_eq!(parents.len(), 1);
+        assert!(parents[0].name_is_quoted());
+        assert_eq!(parents[0].name(), "Admins");
+
+        let quoted = parents[0];
+        let unquoted = db.roles().find(|role| !role.name_is_quoted() && role.name() == "admins");
+        let quoted_policies: Vec<_> = quoted.policies(&db).map(PolicyLike::name).collect();
+        let unquoted_policies: Vec<_> =
+            unquoted.unwrap().policies(&db).map(PolicyLike::name).collect();
+        assert_eq!(quoted_policies, ["p"]);
+        assert_eq!(unquoted_policies, ["q"]);
+        assert_eq!(quoted.members(&db).map(RoleLike::name).collect::<Vec<_>>(), ["alice"]);
+    }
 }
@@ -1,17 +1,50 @@
 //! Submodule implementing the [`TableLike`] trait for `sqlparser`'s
 //! [`CreateTable`] struct.
 
-use ::sqlparser::ast::{CreateTable, Ident, ObjectNamePart};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use ::sqlparser::{
+    ast::{CreateTable, CreateTableOptions, Ident, ObjectNamePart},
+    tokenizer::Span,
+};
 use sql_docs::docs::TableDoc;
 
 use crate::{
-    structs::{ParserDB, TableMetadata},
-    traits::{DatabaseLike, DocumentationMetadata, Metadata, TableLike},
-    utils::last_str,
+    structs::{ParserDB, SourceLocation, TableMetadata},
+    traits::{DatabaseLike, DocumentationMetadata, Metadata, ObjectKind, TableLike},
+    utils::{last_str, object_name::object_name_span},
 };
 
 impl Metadata for CreateTable {
     type Meta = TableMetadata<CreateTable>;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Table
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.table_name())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table_schema()
+    }
+
+    fn object_doc<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a str> {
+        meta.comment().or_else(|| meta.table_doc().and_then(|d| d.doc()))
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        object_name_span(&self.name)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl DocumentationMetadata for CreateTable {
@@ -21,6 +54,14 @@ impl DocumentationMetadata for CreateTable {
 impl TableLike for CreateTable {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database.table_metadata(self).and_then(|metadata| self.object_source_location(metadata))
+    }
+
     #[inline]
     fn table_name(&self) -> &str {
         last_str(&self.name)
@@ -38,28 +79,21 @@ impl TableLike for CreateTable {
     where
         Self: 'db,
     {
-        database
-            .table_metadata(self)
-            .expect("Table must exist in database")
-            .table_doc()
-            .and_then(|d| d.doc())
+        let metadata = database.table_metadata(self).expect("Table must exist in database");
+        self.object_doc(metadata)
     }
 
     #[inline]
     fn table_schema(&self) -> Option<&str> {
         let object_name_parts = &self.name.0;
-        if object_name_parts.len() > 1 {
-            let schema_part = &object_name_parts[0];
-            match schema_part {
-                sqlparser::ast::ObjectNamePart::Identifier(Ident { value, .. }) => {
-                    Some(value.as_str())
-                }
-                sqlparser::ast::ObjectNamePart::Function(_) => {
-                    panic!("Unexpected object name part in CreateTable: {schema_part:?}")
-                }
-            }
-        } else {
-            None
+        if object_name_parts.len() <= 1 {
+            return None;
+        }
+        // A schema written as a function call is not an identifier, and is
+        // left out as `sqlparser` only yields it for invalid table names.
+        match &object_name_parts[0] {
+            sqlparser::ast::ObjectNamePart::Identifier(Ident { value, .. }) => Some(value.as_str()),
+            sqlparser::ast::ObjectNamePart::Function(_) => None,
         }
     }
 
@@ -73,6 +107,30 @@ impl TableLike for CreateTable {
         )
     }
 
+    fn table_options(&self) -> Vec<(String, String)> {
+        let options = match &self.table_options {
+            CreateTableOptions::None => return Vec::new(),
+            CreateTableOptions::With(options)
+            | CreateTableOptions::Options(options)
+            | CreateTableOptions::Plain(options)
+            | CreateTableOptions::TableProperties(options) => options,
+        };
+        // Options are rendered as `NAME = value`, whichever their kind.
+        options
+            .iter()
+            .filter_map(|option| {
+                let option = option.to_string();
+                let (name, value) = option.split_once('=')?;
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('\'')
+                    .and_then(|value| value.strip_suffix('\''))
+                    .map_or_else(|| value.to_string(), |value| value.replace("''", "'"));
+                Some((name.trim().to_ascii_uppercase(), value))
+            })
+            .collect()
+    }
+
     fn columns<'db>(
         &'db self,
         database: &'db Self::DB,
@@ -83,6 +141,16 @@ impl TableLike for CreateTable {
         database.table_metadata(self).expect("Table must exist in database").columns()
     }
 
+    fn primary_key<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::PrimaryKey>
+    where
+        Self: 'db,
+    {
+        database.table_metadata(self).expect("Table must exist in database").primary_key()
+    }
+
     fn primary_key_columns<'db>(
         &'db self,
         database: &'db Self::DB,
@@ -133,6 +201,16 @@ impl TableLike for CreateTable {
         database.table_metadata(self).expect("Table must exist in database").foreign_keys()
     }
 
+    fn default_privileges<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::DefaultPrivilege>
+    where
+        Self: 'db,
+    {
+        database.table_metadata(self).expect("Table must exist in database").default_privileges()
+    }
+
     #[inline]
     fn has_row_level_security(&self, database: &Self::DB) -> bool {
         database.table_metadata(self).expect("Table must exist in database").rls_enabled()
@@ -1,16 +1,40 @@
 //! Implementation of the `TriggerLike` trait for sqlparser's `CreateTrigger`
 //! type.
 
-use sqlparser::ast::{CreateTrigger, ObjectNamePart};
+use alloc::borrow::Cow;
+
+use sqlparser::{
+    ast::{CreateTrigger, Expr, ObjectNamePart},
+    tokenizer::Span,
+};
 
 use crate::{
     structs::ParserDB,
-    traits::{DatabaseLike, FunctionLike, Metadata, TriggerLike},
-    utils::{identifier_resolution::identifiers_match, last_str},
+    traits::{DatabaseLike, Metadata, ObjectKind, TriggerLike},
+    utils::{
+        last_str,
+        object_name::{object_name_last_part, object_name_span},
+    },
 };
 
 impl Metadata for CreateTrigger {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Trigger
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(last_str(&self.name))
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        object_name_span(&self.name)
+    }
 }
 
 impl TriggerLike for CreateTrigger {
@@ -21,12 +45,47 @@ impl TriggerLike for CreateTrigger {
         last_str(&self.name)
     }
 
+    #[inline]
+    fn name_is_quoted(&self) -> bool {
+        object_name_last_part(&self.name).is_some_and(|(_, quoted)| quoted)
+    }
+
     #[inline]
     fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
         Self: 'db,
     {
-        database.table(None, last_str(&self.table_name)).expect("table must exist")
+        database
+            .resolve_table_object_name(&self.table_name)
+            .ok()
+            .flatten()
+            .expect("table must exist")
+    }
+
+    #[inline]
+    fn table_name(&self) -> &str {
+        last_str(&self.table_name)
+    }
+
+    #[inline]
+    fn table_name_is_quoted(&self) -> bool {
+        object_name_last_part(&self.table_name).is_some_and(|(_, quoted)| quoted)
+    }
+
+    #[inline]
+    fn table_schema(&self) -> Option<&str> {
+        match self.table_name.0.as_slice() {
+            [.., ObjectNamePart::Identifier(schema), _] => Some(schema.value.as_str()),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn table_schema_is_quoted(&self) -> bool {
+        matches!(
+            self.table_name.0.as_slice(),
+            [.., ObjectNamePart::Identifier(schema), _] if schema.quote_style.is_some()
+        )
     }
 
     #[inline]
@@ -44,6 +103,11 @@ impl TriggerLike for CreateTrigger {
         self.trigger_object
     }
 
+    #[inline]
+    fn condition(&self) -> Option<&Expr> {
+        self.condition.as_ref()
+    }
+
     #[inline]
     fn function<'db>(
         &'db self,
@@ -52,15 +116,7 @@ impl TriggerLike for CreateTrigger {
     where
         Self: 'db,
     {
-        let (function_name, function_quoted) = self.function_name_ident()?;
-        database.functions().find(|function| {
-            identifiers_match(
-                function.name(),
-                function.name_is_quoted(),
-                function_name,
-                function_quoted,
-            )
-        })
+        database.resolve_function_object_name(&self.exec_body.as_ref()?.func_desc.name)
     }
 
     #[inline]
@@ -0,0 +1,188 @@
+//! Implementation of the `ViewLike` trait for sqlparser's `CreateView` type.
+
+use alloc::borrow::Cow;
+use core::ops::ControlFlow;
+
+use sqlparser::{
+    ast::{CreateView, Visit},
+    tokenizer::Span,
+};
+
+use crate::{
+    structs::ParserDB,
+    traits::{
+        ColumnLike, DataStatementLike, DatabaseLike, DialectLike, Metadata, ObjectKind, TableLike,
+        ViewLike,
+    },
+    utils::{
+        last_str,
+        object_name::{object_name_last_part, object_name_span, schema_from_object_name},
+        query_identifiers::QueryIdentifiers,
+    },
+};
+
+impl Metadata for CreateView {
+    type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::View
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.view_name())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.view_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        object_name_span(&self.name)
+    }
+}
+
+impl ViewLike for CreateView {
+    type DB = ParserDB;
+
+    fn view_name(&self) -> &str {
+        last_str(&self.name)
+    }
+
+    fn view_name_is_quoted(&self) -> bool {
+        object_name_last_part(&self.name).is_some_and(|(_, quoted)| quoted)
+    }
+
+    fn view_schema(&self) -> Option<&str> {
+        schema_from_object_name(&self.name).map(|(schema, _)| schema)
+    }
+
+    fn view_schema_is_quoted(&self) -> bool {
+        schema_from_object_name(&self.name).is_some_and(|(_, quoted)| quoted)
+    }
+
+    fn is_materialized(&self) -> bool {
+        self.materialized
+    }
+
+    fn referenced_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        // The relations of the query are resolved when the view is created,
+        // so that any lookup error is reported while parsing.
+        self.query.referenced_tables(database).unwrap_or_default().into_iter()
+    }
+
+    fn referenced_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db,
+    {
+        let mut collector = QueryIdentifiers::default();
+        let ControlFlow::Continue(()) = self.query.visit(&mut collector);
+        let casing = database.dialect().identifier_casing();
+
+        ViewLike::referenced_tables(self, database)
+            .flat_map(move |table| table.columns(database))
+            .filter(move |column| {
+                collector.wildcard
+                    || collector.identifiers.iter().any(|(name, quoted)| {
+                        casing.matches(
+                            column.column_name(),
+                            column.column_name_is_quoted(),
+                            name,
+                            *quoted,
+                        )
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
+
+    use super::*;
+
+    const SQL: &str = "
+        CREATE TABLE users (id INT, name TEXT, email TEXT);
+        CREATE TABLE orders (id INT, user_id INT, total INT);
+    ";
+
+    fn parse(views: &str) -> ParserDB {
+        ParserDB::parse::<GenericDialect>(&format!("{SQL} {views}")).unwrap()
+    }
+
+    fn column_names(db: &ParserDB, view: &str) -> Vec<String> {
+        let view = db.view(None, view).unwrap();
+        view.referenced_columns(db)
+            .map(|column| format!("{}.{}", column.table(db).table_name(), column.column_name()))
+            .collect()
+    }
+
+    #[test]
+    fn test_metadata() {
+        let db = parse("CREATE VIEW app.user_ids AS SELECT id FROM users;");
+        let view = db.view(Some("app"), "user_ids").unwrap();
+        assert_eq!(view.object_kind(), ObjectKind::View);
+        assert_eq!(view.display_name(), "user_ids");
+        assert_eq!(view.object_schema(), Some("app"));
+        assert!(view.source_span().is_some());
+    }
+
+    #[test]
+    fn test_qualified_columns_of_join() {
+        let db = parse(
+            "CREATE VIEW user_totals AS
+                SELECT u.name, o.total FROM users u JOIN orders o ON o.user_id = u.id;",
+        );
+        assert_eq!(
+            column_names(&db, "user_totals"),
+            ["users.id", "users.name", "orders.id", "orders.user_id", "orders.total"]
+        );
+    }
+
+    #[test]
+    fn test_wildcard_selects_every_column() {
+        let db = parse("CREATE VIEW all_users AS SELECT * FROM users;");
+        assert_eq!(column_names(&db, "all_users"), ["users.id", "users.name", "users.email"]);
+    }
+
+    #[test]
+    fn test_subquery_and_cte_tables() {
+        let db = parse(
+            "CREATE VIEW big_spenders AS
+                WITH totals AS (SELECT user_id, total FROM orders)
+                SELECT name FROM users WHERE id IN (SELECT user_id FROM totals WHERE total > 100);",
+        );
+        let view = db.view(None, "big_spenders").unwrap();
+        let tables: Vec<&str> = view.referenced_tables(&db).map(TableLike::table_name).collect();
+        assert_eq!(tables, ["orders", "users"]);
+        assert_eq!(
+            column_names(&db, "big_spenders"),
+            ["orders.id", "orders.user_id", "orders.total", "users.id", "users.name"]
+        );
+    }
+
+    #[test]
+    fn test_quoted_column_names() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            r#"
+            CREATE TABLE t ("Name" TEXT, name TEXT);
+            CREATE VIEW v AS SELECT "Name" FROM t;
+            "#,
+        )
+        .unwrap();
+        let view = db.view(None, "v").unwrap();
+        let columns: Vec<&str> =
+            view.referenced_columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["Name"]);
+    }
+}
@@ -0,0 +1,105 @@
+//! Implementation of the `CustomTypeLike` trait for the `CustomType` struct.
+
+use alloc::{borrow::Cow, string::String};
+
+use sqlparser::ast::Expr;
+
+use crate::{
+    structs::{CustomType, CustomTypeDefinition, ParserDB},
+    traits::{CustomTypeLike, Metadata, ObjectKind},
+    utils::normalize_sqlparser_type,
+};
+
+impl Metadata for CustomType {
+    type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::CustomType
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.schema()
+    }
+}
+
+impl CustomTypeLike for CustomType {
+    type DB = ParserDB;
+
+    fn name(&self) -> &str {
+        CustomType::name(self)
+    }
+
+    fn name_is_quoted(&self) -> bool {
+        self.is_quoted()
+    }
+
+    fn schema(&self) -> Option<&str> {
+        CustomType::schema(self)
+    }
+
+    fn schema_is_quoted(&self) -> bool {
+        CustomType::schema_is_quoted(self)
+    }
+
+    fn is_enum(&self) -> bool {
+        matches!(self.definition(), CustomTypeDefinition::Enum(_))
+    }
+
+    fn enum_variants(&self) -> impl Iterator<Item = &str> {
+        let labels = match self.definition() {
+            CustomTypeDefinition::Enum(labels) => labels.as_slice(),
+            _ => &[],
+        };
+        labels.iter().map(String::as_str)
+    }
+
+    fn domain_base_type(&self) -> Option<&str> {
+        match self.definition() {
+            CustomTypeDefinition::Domain { base_type, .. } => {
+                Some(normalize_sqlparser_type(base_type))
+            }
+            _ => None,
+        }
+    }
+
+    fn domain_checks(&self) -> impl Iterator<Item = &Expr> {
+        let checks = match self.definition() {
+            CustomTypeDefinition::Domain { checks, .. } => checks.as_slice(),
+            _ => &[],
+        };
+        checks.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::DatabaseLike;
+
+    #[test]
+    fn test_custom_type_metadata() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TYPE app.mood AS ENUM ('sad');")
+            .expect("type should parse");
+        let mood = db.custom_type(Some("app"), "mood").expect("type");
+        assert_eq!(mood.object_kind(), ObjectKind::CustomType);
+        assert_eq!(mood.display_name(), "mood");
+        assert_eq!(mood.object_schema(), Some("app"));
+    }
+
+    #[test]
+    fn test_composite_type_is_neither_enum_nor_domain() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TYPE pair AS (a INT, b INT);")
+            .expect("type should parse");
+        let pair = db.custom_type(None, "pair").expect("type");
+        assert!(!pair.is_enum());
+        assert!(!pair.is_domain());
+        assert_eq!(pair.enum_variants().count(), 0);
+        assert_eq!(pair.domain_checks().count(), 0);
+    }
+}
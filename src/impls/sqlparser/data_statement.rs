@@ -1,5 +1,6 @@
 //! Implementations of [`DataStatementLike`] for the sqlparser data-statement
-//! AST nodes (`Query`, `Insert`, `Update`, `Delete`).
+//! AST nodes (`Query`, `Insert`, `Update`, `Delete`), along with any
+//! `Statement` and sequences of statements, such as the body of a function.
 //!
 //! `referenced_tables` is built on sqlparser's [`visit_relations`], which
 //! recurses through subqueries, CTE bodies, and set operations, and which
@@ -10,7 +11,9 @@
 use alloc::vec::Vec;
 use core::ops::ControlFlow;
 
-use sqlparser::ast::{Delete, Insert, ObjectName, Query, Update, Visit, visit_relations};
+use sqlparser::ast::{
+    Delete, Insert, ObjectName, Query, Statement, Update, Visit, visit_relations,
+};
 
 use crate::{
     errors::LookupError,
@@ -85,6 +88,22 @@ fn collect_referenced_tables<'db, N: Visit, DB: DatabaseLike>(
     }
 }
 
+/// Records the base tables referenced by a statement, including the targets
+/// of a MySQL multi-table `DELETE`.
+fn collect_statement_tables<'db, DB: DatabaseLike>(
+    statement: &Statement,
+    database: &'db DB,
+    accumulator: &mut ReferencedTables<'db, DB>,
+) -> Result<(), LookupError> {
+    collect_referenced_tables(statement, database, accumulator)?;
+    if let Statement::Delete(delete) = statement {
+        for object_name in &delete.tables {
+            accumulator.resolve_and_add(object_name, database)?;
+        }
+    }
+    Ok(())
+}
+
 impl<DB: DatabaseLike> DataStatementLike<DB> for Query {
     fn referenced_tables<'db>(
         &self,
@@ -135,6 +154,30 @@ impl<DB: DatabaseLike> DataStatementLike<DB> for Delete {
     }
 }
 
+impl<DB: DatabaseLike> DataStatementLike<DB> for Statement {
+    fn referenced_tables<'db>(
+        &self,
+        database: &'db DB,
+    ) -> Result<Vec<&'db DB::Table>, LookupError> {
+        let mut accumulator = ReferencedTables::new();
+        collect_statement_tables(self, database, &mut accumulator)?;
+        Ok(accumulator.tables)
+    }
+}
+
+impl<DB: DatabaseLike> DataStatementLike<DB> for [Statement] {
+    fn referenced_tables<'db>(
+        &self,
+        database: &'db DB,
+    ) -> Result<Vec<&'db DB::Table>, LookupError> {
+        let mut accumulator = ReferencedTables::new();
+        for statement in self {
+            collect_statement_tables(statement, database, &mut accumulator)?;
+        }
+        Ok(accumulator.tables)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::{string::ToString, vec::Vec};
@@ -298,4 +341,25 @@ mod tests {
         let result = as_query(&statement).referenced_tables(&db);
         assert!(matches!(result, Err(LookupError::InvalidObjectName { .. })), "got {result:?}");
     }
+
+    #[test]
+    fn statement_sequence_deduplicates_across_statements() {
+        let db = schema_db();
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "INSERT INTO audit (note) SELECT name FROM users;
+             DELETE FROM users;
+             SELECT id FROM audit",
+        )
+        .expect("statements parse");
+        assert_eq!(referenced_names(&statements[2], &db), vec!["audit".to_string()]);
+        let names: Vec<&str> = statements
+            .as_slice()
+            .referenced_tables(&db)
+            .expect("referenced_tables succeeds")
+            .iter()
+            .map(TableLike::table_name)
+            .collect();
+        assert_eq!(names, ["audit", "users"]);
+    }
 }
@@ -0,0 +1,114 @@
+//! Implementation of the `DefaultPrivilegeLike` trait for the
+//! `DefaultPrivilege` struct.
+
+use alloc::{borrow::Cow, string::ToString};
+
+use sqlparser::ast::{CreateRole, Ident};
+
+use crate::{
+    privileges::TablePrivilege,
+    structs::{DefaultPrivilege, ParserDB},
+    traits::{DatabaseLike, DefaultPrivilegeLike, DialectLike, Metadata, ObjectKind},
+    utils::object_name::object_name_last_part,
+};
+
+/// Returns whether the identifier designates `PUBLIC` rather than a role.
+fn is_public(ident: &Ident) -> bool {
+    ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("public")
+}
+
+impl Metadata for DefaultPrivilege {
+    type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Grant
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl DefaultPrivilegeLike for DefaultPrivilege {
+    type DB = ParserDB;
+
+    fn target_roles<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db,
+    {
+        self.target_role_idents().iter().filter_map(|ident| database.role(&ident.value))
+    }
+
+    fn schemas(&self) -> impl Iterator<Item = &str> {
+        self.schema_idents().iter().map(|ident| ident.value.as_str())
+    }
+
+    fn privileges(&self) -> impl Iterator<Item = TablePrivilege> {
+        self.table_privileges().iter().copied()
+    }
+
+    fn grantees<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db,
+    {
+        self.grantee_idents()
+            .iter()
+            .filter(|ident| !is_public(ident))
+            .filter_map(|ident| database.role(&ident.value))
+    }
+
+    fn is_grantable(&self) -> bool {
+        DefaultPrivilege::is_grantable(self)
+    }
+
+    fn applies_to_role(
+        &self,
+        role: &<Self::DB as DatabaseLike>::Role,
+        database: &Self::DB,
+    ) -> bool {
+        let role: &CreateRole = role;
+        let casing = database.dialect().identifier_casing();
+        self.grantee_idents().iter().any(|grantee| {
+            is_public(grantee)
+                || role.names.iter().filter_map(object_name_last_part).any(|(name, quoted)| {
+                    casing.matches(
+                        name,
+                        quoted,
+                        grantee.value.as_str(),
+                        grantee.quote_style.is_some(),
+                    )
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    #[test]
+    fn test_default_privilege_metadata() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE reader;
+            ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+            ",
+        )
+        .expect("default privileges should parse");
+        let default_privilege = db.default_privileges().next().expect("default privilege");
+        assert_eq!(default_privilege.object_kind(), ObjectKind::Grant);
+        assert_eq!(
+            default_privilege.display_name(),
+            "ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader"
+        );
+        assert_eq!(default_privilege.object_schema(), None);
+    }
+}
@@ -9,20 +9,26 @@
 //! `INTEGER` used as a boolean by convention, MySQL `CHAR(36)` used to hold
 //! a UUID).
 
+use alloc::{string::ToString, vec::Vec};
 use core::any::TypeId;
 
 use sqlparser::{
-    ast::{ColumnDef, CreateTable, DataType, ObjectName, ObjectNamePart},
+    ast::{ColumnDef, CreateTable, DataType, Expr, ObjectName, ObjectNamePart, Statement},
     dialect::{
         AnsiDialect, BigQueryDialect, ClickHouseDialect, DatabricksDialect, Dialect, DuckDbDialect,
-        HiveDialect, MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect,
+        GenericDialect, HiveDialect, MsSqlDialect, MySqlDialect, OracleDialect, PostgreSqlDialect,
         RedshiftSqlDialect, SQLiteDialect, SnowflakeDialect, SparkSqlDialect, TeradataDialect,
     },
+    parser::{Parser, ParserError},
+    tokenizer::Token,
 };
 
 use crate::{
-    structs::{ParserDB, TableAttribute},
-    traits::{DialectLike, TypeMatch},
+    structs::{
+        DialectCapability, IdentifierCasing, MySqlTypeNormalizer, ParserDB, PostgresTypeNormalizer,
+        SqliteTypeNormalizer, TableAttribute,
+    },
+    traits::{ColumnLike, CustomTypeLike, DialectLike, TypeMatch, TypeNormalizer},
 };
 
 /// Closed enumeration of every stock `sqlparser` dialect.
@@ -36,6 +42,7 @@ use crate::{
 /// implementations classify as [`SqlparserDialect::Generic`], which behaves
 /// permissively.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlparserDialect {
     /// ANSI SQL dialect.
     Ansi,
@@ -136,6 +143,141 @@ impl SqlparserDialect {
     pub fn is_sqlite(self) -> bool {
         matches!(self, Self::SQLite)
     }
+
+    /// Returns whether the engines of this dialect reject two tables, views,
+    /// sequences or indexes sharing a name within a schema, as checked
+    /// through [`crate::structs::NameRegistry`] at the end of parsing.
+    ///
+    /// Only PostgreSQL is validated: MySQL scopes index names to their table,
+    /// and SQLite does not name the indexes backing its constraints.
+    #[must_use]
+    #[inline]
+    pub fn shares_relation_namespace(self) -> bool {
+        matches!(self, Self::PostgreSql)
+    }
+
+    /// Returns the `sqlparser` dialect this variant stands for.
+    pub(crate) fn sqlparser_dialect(self) -> &'static dyn Dialect {
+        match self {
+            Self::Ansi => &AnsiDialect {},
+            Self::BigQuery => &BigQueryDialect {},
+            Self::ClickHouse => &ClickHouseDialect {},
+            Self::Databricks => &DatabricksDialect {},
+            Self::DuckDb => &DuckDbDialect {},
+            Self::Generic => &GenericDialect {},
+            Self::Hive => &HiveDialect {},
+            Self::MsSql => &MsSqlDialect {},
+            Self::MySql => &MySqlDialect {},
+            Self::Oracle => &OracleDialect {},
+            Self::PostgreSql => &PostgreSqlDialect {},
+            Self::Redshift => &RedshiftSqlDialect {},
+            Self::Snowflake => &SnowflakeDialect {},
+            Self::Spark => &SparkSqlDialect {},
+            Self::SQLite => &SQLiteDialect {},
+            Self::Teradata => &TeradataDialect {},
+        }
+    }
+
+    /// Parses a single SQL expression under this dialect, rejecting any
+    /// trailing input.
+    pub(crate) fn parse_expression(self, sql: &str) -> Result<Expr, ParserError> {
+        let mut parser = Parser::new(self.sqlparser_dialect()).try_with_sql(sql)?;
+        let expr = parser.parse_expr()?;
+        parser.expect_token(&Token::EOF)?;
+        Ok(expr)
+    }
+
+    /// Parses a sequence of SQL statements under this dialect.
+    pub(crate) fn parse_statements(self, sql: &str) -> Result<Vec<Statement>, ParserError> {
+        Parser::parse_sql(self.sqlparser_dialect(), sql)
+    }
+
+    /// Returns the human-readable name of the dialect.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Ansi => "ANSI SQL",
+            Self::BigQuery => "BigQuery",
+            Self::ClickHouse => "ClickHouse",
+            Self::Databricks => "Databricks",
+            Self::DuckDb => "DuckDB",
+            Self::Generic => "generic SQL",
+            Self::Hive => "Hive",
+            Self::MsSql => "SQL Server",
+            Self::MySql => "MySQL",
+            Self::Oracle => "Oracle",
+            Self::PostgreSql => "PostgreSQL",
+            Self::Redshift => "Redshift",
+            Self::Snowflake => "Snowflake",
+            Self::Spark => "Spark SQL",
+            Self::SQLite => "SQLite",
+            Self::Teradata => "Teradata",
+        }
+    }
+
+    /// Returns whether the engines of this dialect support the provided
+    /// capability.
+    ///
+    /// This is the capability matrix consulted while parsing: statements
+    /// requiring an unsupported capability are still applied, but produce a
+    /// [`crate::structs::DialectWarning`].
+    /// [`SqlparserDialect::Generic`] supports every capability.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::SQLiteDialect;
+    ///
+    /// let db = ParserDB::parse::<SQLiteDialect>("CREATE TABLE t (id INT);")?;
+    /// assert!(db.dialect().supports(DialectCapability::Triggers));
+    /// assert!(!db.dialect().supports(DialectCapability::Roles));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn supports(self, capability: DialectCapability) -> bool {
+        match capability {
+            DialectCapability::Roles => {
+                !matches!(self, Self::BigQuery | Self::DuckDb | Self::Spark | Self::SQLite)
+            }
+            DialectCapability::Policies => matches!(self, Self::PostgreSql | Self::Generic),
+            DialectCapability::Grants => !matches!(self, Self::DuckDb | Self::SQLite),
+            DialectCapability::Triggers => matches!(
+                self,
+                Self::Ansi
+                    | Self::Generic
+                    | Self::MsSql
+                    | Self::MySql
+                    | Self::Oracle
+                    | Self::PostgreSql
+                    | Self::SQLite
+                    | Self::Teradata
+            ),
+            DialectCapability::Functions | DialectCapability::Schemas => {
+                !matches!(self, Self::SQLite)
+            }
+            DialectCapability::ConcurrentIndexes | DialectCapability::Extensions => {
+                matches!(self, Self::PostgreSql | Self::Generic)
+            }
+            DialectCapability::Sequences => matches!(
+                self,
+                Self::Ansi
+                    | Self::DuckDb
+                    | Self::Generic
+                    | Self::MsSql
+                    | Self::Oracle
+                    | Self::PostgreSql
+                    | Self::Snowflake
+                    | Self::Teradata
+            ),
+            DialectCapability::CustomTypes => matches!(
+                self,
+                Self::DuckDb | Self::Generic | Self::MsSql | Self::Oracle | Self::PostgreSql
+            ),
+        }
+    }
 }
 
 /// Returns true when the [`ObjectName`] segments spell a single identifier
@@ -244,6 +386,45 @@ impl DialectLike for SqlparserDialect {
     ) -> TypeMatch {
         classify_uuid(*self, &column.attribute().data_type)
     }
+
+    #[inline]
+    fn identifier_casing(&self) -> IdentifierCasing {
+        match self {
+            Self::MySql | Self::MsSql | Self::SQLite => IdentifierCasing::Insensitive,
+            Self::Oracle | Self::Snowflake => IdentifierCasing::Uppercase,
+            _ => IdentifierCasing::Lowercase,
+        }
+    }
+
+    #[inline]
+    fn type_normalizer(&self) -> &dyn TypeNormalizer {
+        if self.is_mysql_family() {
+            &MySqlTypeNormalizer
+        } else if self.is_sqlite() {
+            &SqliteTypeNormalizer
+        } else {
+            &PostgresTypeNormalizer
+        }
+    }
+
+    fn canonical_type(
+        &self,
+        database: &Self::DB,
+        column: &TableAttribute<CreateTable, ColumnDef>,
+    ) -> Option<&'static str> {
+        // The declared data type keeps its arguments, which `data_type` drops,
+        // and only custom data types may name a user-defined type.
+        let data_type = &column.attribute().data_type;
+        if !matches!(data_type, DataType::Custom(..)) {
+            return self.type_normalizer().canonical_type(&data_type.to_string());
+        }
+        match column.custom_type(database) {
+            Some(custom_type) => custom_type
+                .domain_base_type()
+                .and_then(|base_type| self.type_normalizer().canonical_type(base_type)),
+            None => self.type_normalizer().canonical_type(&data_type.to_string()),
+        }
+    }
 }
 
 #[cfg(test)]
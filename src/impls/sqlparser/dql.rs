@@ -17,11 +17,9 @@ use sqlparser::ast::{
 
 use crate::{
     errors::LookupError,
-    traits::{ColumnLike, DQLLike, DatabaseLike, TableLike},
-    utils::{
-        identifier_resolution::identifiers_match,
-        object_name::{object_name_last_part, render_table_candidate, resolve_object_name},
-    },
+    structs::IdentifierCasing,
+    traits::{ColumnLike, DQLLike, DatabaseLike, DialectLike, TableLike},
+    utils::object_name::{object_name_last_part, render_table_candidate, resolve_object_name},
 };
 
 /// A `FROM` relation that resolved to a base table, paired with the identifier
@@ -48,30 +46,35 @@ fn collect_cte_names(query: &Query) -> Vec<(&str, bool)> {
 
 /// Returns whether `name` is a reference to one of the outer CTE names (a bare,
 /// single-part identifier matching a declared CTE).
-fn is_cte_reference(name: &ObjectName, cte_names: &[(&str, bool)]) -> bool {
+fn is_cte_reference(
+    name: &ObjectName,
+    cte_names: &[(&str, bool)],
+    casing: IdentifierCasing,
+) -> bool {
     if name.0.len() != 1 {
         return false;
     }
     match object_name_last_part(name) {
         Some((value, quoted)) => {
             cte_names.iter().any(|(cte_value, cte_quoted)| {
-                identifiers_match(cte_value, *cte_quoted, value, quoted)
+                casing.matches(cte_value, *cte_quoted, value, quoted)
             })
         }
         None => false,
     }
 }
 
-/// Returns whether `table` exposes a column matching `column`, applying
-/// PostgreSQL identifier semantics (including the projection identifier's own
-/// quoting).
+/// Returns whether `table` exposes a column matching `column`, applying the
+/// identifier semantics of the dialect (including the projection identifier's
+/// own quoting).
 fn table_exposes_column<'db, DB: DatabaseLike>(
     table: &'db DB::Table,
     column: &Ident,
     database: &'db DB,
 ) -> bool {
+    let casing = database.dialect().identifier_casing();
     table.columns(database).any(|candidate| {
-        identifiers_match(
+        casing.matches(
             candidate.column_name(),
             candidate.column_name_is_quoted(),
             column.value.as_str(),
@@ -85,10 +88,11 @@ fn base_for_qualifier<'db, DB: DatabaseLike>(
     bases: &[FromTableRef<'_, 'db, DB>],
     value: &str,
     quoted: bool,
+    casing: IdentifierCasing,
 ) -> Option<&'db DB::Table> {
     bases
         .iter()
-        .find(|base| identifiers_match(base.key_value, base.key_quoted, value, quoted))
+        .find(|base| casing.matches(base.key_value, base.key_quoted, value, quoted))
         .map(|base| base.table)
 }
 
@@ -143,6 +147,7 @@ fn column_source<'db, DB: DatabaseLike>(
                 bases,
                 qualifier.value.as_str(),
                 qualifier.quote_style.is_some(),
+                database.dialect().identifier_casing(),
             ) {
                 Some(table) if table_exposes_column(table, column, database) => Ok(Some(table)),
                 _ => Ok(None),
@@ -172,7 +177,9 @@ fn collect_factor<'a, 'db, DB: DatabaseLike>(
             };
             // A table-valued function call or a CTE reference is not a base
             // table, even if a base table shares the CTE's name.
-            if args.is_some() || is_cte_reference(name, cte_names) {
+            if args.is_some()
+                || is_cte_reference(name, cte_names, database.dialect().identifier_casing())
+            {
                 *has_opaque = true;
             } else {
                 match resolve_object_name(name, database)? {
@@ -212,6 +219,7 @@ impl<DB: DatabaseLike> DQLLike<DB> for Query {
         }
 
         let cte_names = collect_cte_names(self);
+        let casing = database.dialect().identifier_casing();
 
         let mut bases: Vec<FromTableRef<'_, 'db, DB>> = Vec::new();
         let mut from_entry_count: usize = 0;
@@ -253,7 +261,9 @@ impl<DB: DatabaseLike> DQLLike<DB> for Query {
                     match kind {
                         SelectItemQualifiedWildcardKind::ObjectName(object_name) => {
                             match object_name_last_part(object_name) {
-                                Some((value, quoted)) => base_for_qualifier(&bases, value, quoted),
+                                Some((value, quoted)) => {
+                                    base_for_qualifier(&bases, value, quoted, casing)
+                                }
                                 None => None,
                             }
                         }
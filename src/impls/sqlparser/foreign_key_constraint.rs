@@ -1,17 +1,41 @@
 //! Implement the [`ForeignKeyConstraint`] trait for the `sqlparser` crate's
 
-use alloc::{string::ToString, vec::Vec};
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 
-use sqlparser::ast::{ConstraintReferenceMatchKind, CreateTable, ForeignKeyConstraint};
+use sqlparser::{
+    ast::{ConstraintReferenceMatchKind, CreateTable, ForeignKeyConstraint, Ident},
+    tokenizer::Span,
+};
 
 use crate::{
     structs::{ParserDB, TableAttribute},
-    traits::{ForeignKeyLike, Metadata, database::DatabaseLike, table::TableLike},
-    utils::{identifier_resolution::identifiers_match, object_name::object_name_last_part},
+    traits::{
+        DialectLike, ForeignKeyLike, Metadata, ObjectKind, database::DatabaseLike, table::TableLike,
+    },
+    utils::object_name::{ident_span, object_name_last_part},
 };
 
 impl Metadata for TableAttribute<CreateTable, ForeignKeyConstraint> {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::ForeignKey
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        match &self.attribute().name {
+            Some(name) => Cow::Borrowed(name.value.as_str()),
+            None => Cow::Owned(self.attribute().to_string()),
+        }
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.attribute().name.as_ref().and_then(ident_span)
+    }
 }
 
 impl ForeignKeyLike for TableAttribute<CreateTable, ForeignKeyConstraint> {
@@ -36,33 +60,24 @@ impl ForeignKeyLike for TableAttribute<CreateTable, ForeignKeyConstraint> {
     fn referenced_table<'db>(
         &self,
         database: &'db Self::DB,
-    ) -> &'db <Self::DB as DatabaseLike>::Table {
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Table> {
         let foreign_table = &self.attribute().foreign_table;
-        let (referenced_name, referenced_quoted) = object_name_last_part(foreign_table)
-            .unwrap_or_else(|| {
-                let host_table = self.host_table(database);
-                panic!(
-                    "Foreign key in table `{}` has an empty referenced table name",
-                    host_table.table_name()
-                )
-            });
-        database
-            .tables()
-            .find(|table: &&<Self::DB as DatabaseLike>::Table| {
-                identifiers_match(
-                    table.table_name(),
-                    table.table_name_is_quoted(),
-                    referenced_name,
-                    referenced_quoted,
-                )
-            })
-            .unwrap_or_else(|| {
-                let host_table = self.host_table(database);
-                panic!(
-                    "Referenced table `{referenced_name}` not found for foreign key in table `{}`",
-                    host_table.table_name()
-                )
-            })
+        // Foreign keys are validated against the schema-qualified name while
+        // parsing, so that only the databases assembled from a builder need
+        // to fall back to matching the name of the table alone.
+        if let Ok(Some(table)) = database.resolve_table_object_name(foreign_table) {
+            return Some(table);
+        }
+        let (referenced_name, referenced_quoted) = object_name_last_part(foreign_table)?;
+        let casing = database.dialect().identifier_casing();
+        database.tables().find(|table: &&<Self::DB as DatabaseLike>::Table| {
+            casing.matches(
+                table.table_name(),
+                table.table_name_is_quoted(),
+                referenced_name,
+                referenced_quoted,
+            )
+        })
     }
 
     #[inline]
@@ -83,22 +98,7 @@ impl ForeignKeyLike for TableAttribute<CreateTable, ForeignKeyConstraint> {
         Self: 'db,
     {
         let host_table = self.host_table(database);
-        self.attribute().columns.iter().map(move |col_name| {
-            host_table
-                .columns(database)
-                .find(|col: &&<Self::DB as DatabaseLike>::Column| &col.attribute().name == col_name)
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Host column `{}` not found in table `{}` for foreign key, options: {:?}",
-                        col_name,
-                        host_table.table_name(),
-                        host_table
-                            .columns(database)
-                            .map(|c| c.attribute().name.to_string())
-                            .collect::<Vec<_>>()
-                    )
-                })
-        })
+        resolve_columns(database, host_table, &self.attribute().columns).into_iter().flatten()
     }
 
     fn referenced_columns<'db>(
@@ -108,33 +108,78 @@ impl ForeignKeyLike for TableAttribute<CreateTable, ForeignKeyConstraint> {
     where
         Self: 'db,
     {
-        let host_table = self.host_table(database);
-        let referenced_table = self.referenced_table(database);
-        self.attribute().referred_columns.iter().map(move |col_name| {
-            referenced_table
-                .columns(database)
-                .find(|col: &&<Self::DB as DatabaseLike>::Column| &col.attribute().name == col_name)
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Referenced column `{}` in table `{}` not found in table `{}` for foreign key",
-                        col_name,
-                        host_table.table_name(),
-                        referenced_table.table_name()
-                    )
-                })
-        })
+        self.referenced_table(database)
+            .and_then(|referenced_table| {
+                resolve_columns(database, referenced_table, &self.attribute().referred_columns)
+            })
+            .into_iter()
+            .flatten()
     }
 }
 
+/// Helper function to resolve the columns of a foreign key in the provided
+/// table, returning `None` if any of them is not in the table.
+fn resolve_columns<'db>(
+    database: &'db ParserDB,
+    table: &'db CreateTable,
+    column_names: &[Ident],
+) -> Option<Vec<&'db <ParserDB as DatabaseLike>::Column>> {
+    let casing = database.dialect().identifier_casing();
+    column_names
+        .iter()
+        .map(|column_name| {
+            table.columns(database).find(|column: &&<ParserDB as DatabaseLike>::Column| {
+                casing.matches(
+                    &column.attribute().name.value,
+                    column.attribute().name.quote_style.is_some(),
+                    &column_name.value,
+                    column_name.quote_style.is_some(),
+                )
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::sync::Arc;
+
     use sqlparser::dialect::GenericDialect;
 
     use crate::{
         prelude::ParserDB,
-        traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike},
+        traits::{ColumnLike, DatabaseLike, ForeignKeyLike, Metadata, ObjectKind, TableLike},
     };
 
+    /// Named foreign keys are described by their name, unnamed ones by their
+    /// definition, and both report the schema of their host table.
+    #[test]
+    fn test_foreign_key_description() {
+        let sql = "
+            CREATE SCHEMA s;
+            CREATE TABLE s.parent (id INT PRIMARY KEY);
+            CREATE TABLE s.child (
+                id INT PRIMARY KEY,
+                a INT REFERENCES s.parent(id),
+                b INT,
+                CONSTRAINT fk_b FOREIGN KEY (b) REFERENCES s.parent(id)
+            );
+        ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let child = db.table(Some("s"), "child").unwrap();
+        let names: Vec<String> =
+            child.foreign_keys(&db).map(|fk| fk.display_name().into_owned()).collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.iter().any(|name| name == "fk_b"));
+        assert!(names.iter().any(|name| name.contains("REFERENCES")));
+        for fk in child.foreign_keys(&db) {
+            assert_eq!(fk.object_kind(), ObjectKind::ForeignKey);
+            assert_eq!(fk.object_schema(), Some("s"));
+        }
+        let named = child.foreign_keys(&db).find(|fk| fk.display_name() == "fk_b").unwrap();
+        assert!(named.source_span().is_some());
+    }
+
     /// An unnamed inline `REFERENCES` clause produces a foreign key whose
     /// `foreign_key_name()` returns `None`.
     #[test]
@@ -203,7 +248,7 @@ mod tests {
         let fk = t.foreign_keys(&db).next().expect("FK should exist");
 
         assert_eq!(fk.host_table(&db).table_name(), "t");
-        assert_eq!(fk.referenced_table(&db).table_name(), "t");
+        assert_eq!(fk.referenced_table(&db).unwrap().table_name(), "t");
     }
 
     /// The referenced table resolves under PostgreSQL identifier folding even
@@ -220,7 +265,7 @@ mod tests {
         let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
         let child = db.table(None, "child").unwrap();
         let fk = child.foreign_keys(&db).next().expect("FK should exist");
-        assert_eq!(fk.referenced_table(&db).table_name(), "parent");
+        assert_eq!(fk.referenced_table(&db).unwrap().table_name(), "parent");
     }
 
     /// `match_kind()` defaults to `Simple` when no `MATCH` clause is given.
@@ -237,4 +282,36 @@ mod tests {
         let fk = child.foreign_keys(&db).next().expect("FK should exist");
         assert!(matches!(fk.match_kind(&db), ConstraintReferenceMatchKind::Simple));
     }
+
+    /// A foreign key whose referenced table is left out of a database
+    /// assembled from a builder resolves to no table and no referenced
+    /// columns, rather than panicking.
+    #[test]
+    fn test_dangling_foreign_key() {
+        let sql = "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
+        ";
+        let parsed = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let child = parsed.table(None, "child").unwrap();
+        let fk = child.foreign_keys(&parsed).next().expect("FK should exist");
+        let db: ParserDB = ParserDB::new("test".to_string(), *parsed.dialect())
+            .add_table(Arc::new(child.clone()), parsed.table_metadata(child).unwrap().clone())
+            .expect("add table")
+            .add_columns(child.columns(&parsed).map(|column| (Arc::new(column.clone()), ())))
+            .add_foreign_key(Arc::new(fk.clone()), ())
+            .into();
+
+        let child = db.table(None, "child").unwrap();
+        let fk = child.foreign_keys(&db).next().expect("FK should exist");
+        assert!(fk.referenced_table(&db).is_none());
+        let host: Vec<&str> = fk.host_columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(host, ["parent_id"]);
+        assert_eq!(fk.referenced_columns(&db).count(), 0);
+        assert!(!fk.is_self_referential(&db));
+        assert!(!fk.is_singleton(&db));
+        assert!(child.referenced_tables(&db).is_empty());
+        let ordered = db.table_dependency_graph().topological_order().expect("no cycle");
+        assert_eq!(ordered, [child]);
+    }
 }
@@ -4,7 +4,7 @@
 //! by the same `Grant` struct. This module implements all grant traits
 //! on `Grant` to support both use cases.
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec::Vec};
 use core::mem;
 
 use sqlparser::ast::{
@@ -13,18 +13,16 @@ use sqlparser::ast::{
 };
 
 use crate::{
-    structs::ParserDB,
+    privileges::TablePrivilege,
+    structs::{IdentifierCasing, ParserDB},
     traits::{
-        ColumnGrantLike, ColumnLike, DatabaseLike, GrantLike, Metadata, RoleLike, TableGrantLike,
-        TableLike,
-    },
-    utils::{
-        identifier_resolution::identifiers_match,
-        object_name::{object_name_last_part, table_matches_object_name},
+        ColumnGrantLike, ColumnLike, DatabaseLike, DialectLike, GrantLike, Metadata, ObjectKind,
+        RoleLike, TableGrantLike, TableLike,
     },
+    utils::object_name::{object_name_last_part, table_matches_object_name},
 };
 
-fn object_names_match(left: &ObjectName, right: &ObjectName) -> bool {
+fn object_names_match(left: &ObjectName, right: &ObjectName, casing: IdentifierCasing) -> bool {
     if left.0.len() != right.0.len() {
         return false;
     }
@@ -32,7 +30,7 @@ fn object_names_match(left: &ObjectName, right: &ObjectName) -> bool {
     left.0.iter().zip(right.0.iter()).all(|(left_part, right_part)| {
         match (left_part, right_part) {
             (ObjectNamePart::Identifier(left_ident), ObjectNamePart::Identifier(right_ident)) => {
-                identifiers_match(
+                casing.matches(
                     left_ident.value.as_str(),
                     left_ident.quote_style.is_some(),
                     right_ident.value.as_str(),
@@ -40,7 +38,7 @@ fn object_names_match(left: &ObjectName, right: &ObjectName) -> bool {
                 )
             }
             (ObjectNamePart::Function(left_fn), ObjectNamePart::Function(right_fn)) => {
-                identifiers_match(
+                casing.matches(
                     left_fn.name.value.as_str(),
                     left_fn.name.quote_style.is_some(),
                     right_fn.name.value.as_str(),
@@ -52,28 +50,36 @@ fn object_names_match(left: &ObjectName, right: &ObjectName) -> bool {
     })
 }
 
-fn role_matches_ident(role: &CreateRole, lookup_name: &str, lookup_quoted: bool) -> bool {
+fn role_matches_ident(
+    role: &CreateRole,
+    lookup_name: &str,
+    lookup_quoted: bool,
+    casing: IdentifierCasing,
+) -> bool {
     role.names.iter().any(|role_name| {
         object_name_last_part(role_name).is_some_and(|(role_name, role_quoted)| {
-            identifiers_match(role_name, role_quoted, lookup_name, lookup_quoted)
+            casing.matches(role_name, role_quoted, lookup_name, lookup_quoted)
         })
     })
 }
 
-fn grantee_matches_role(grantee: &Grantee, role: &CreateRole) -> bool {
+fn grantee_matches_role(grantee: &Grantee, role: &CreateRole, casing: IdentifierCasing) -> bool {
     if grantee.grantee_type == GranteesType::Public {
         return true;
     }
 
     if let Some(GranteeName::ObjectName(name)) = &grantee.name {
-        role.names.iter().any(|role_name| object_names_match(name, role_name))
+        role.names.iter().any(|role_name| object_names_match(name, role_name, casing))
     } else {
-        let role_name = role.name();
-        format!("{grantee}").eq_ignore_ascii_case(role_name)
+        casing.matches(role.name(), role.name_is_quoted(), &grantee.to_string(), false)
     }
 }
 
-fn schema_matches_table<T: TableLike>(schema_name: &ObjectName, table: &T) -> bool {
+fn schema_matches_table<T: TableLike>(
+    schema_name: &ObjectName,
+    table: &T,
+    casing: IdentifierCasing,
+) -> bool {
     let Some(table_schema) = table.table_schema() else {
         return false;
     };
@@ -81,7 +87,7 @@ fn schema_matches_table<T: TableLike>(schema_name: &ObjectName, table: &T) -> bo
         return false;
     };
 
-    identifiers_match(
+    casing.matches(
         table_schema,
         table.table_schema_is_quoted(),
         lookup_schema,
@@ -89,7 +95,11 @@ fn schema_matches_table<T: TableLike>(schema_name: &ObjectName, table: &T) -> bo
     )
 }
 
-fn grantees_match(left_grantee: &Grantee, right_grantee: &Grantee) -> bool {
+fn grantees_match(
+    left_grantee: &Grantee,
+    right_grantee: &Grantee,
+    casing: IdentifierCasing,
+) -> bool {
     if left_grantee.grantee_type == GranteesType::Public
         || right_grantee.grantee_type == GranteesType::Public
     {
@@ -98,39 +108,52 @@ fn grantees_match(left_grantee: &Grantee, right_grantee: &Grantee) -> bool {
 
     match (&left_grantee.name, &right_grantee.name) {
         (Some(GranteeName::ObjectName(left_name)), Some(GranteeName::ObjectName(right_name))) => {
-            object_names_match(left_name, right_name)
+            object_names_match(left_name, right_name, casing)
         }
-        _ => format!("{left_grantee}").eq_ignore_ascii_case(&format!("{right_grantee}")),
+        _ => casing.matches(&left_grantee.to_string(), false, &right_grantee.to_string(), false),
     }
 }
 
-fn grantee_matches_any(grantee: &Grantee, candidates: &[Grantee]) -> bool {
-    candidates.iter().any(|candidate| grantees_match(grantee, candidate))
+fn grantee_matches_any(
+    grantee: &Grantee,
+    candidates: &[Grantee],
+    casing: IdentifierCasing,
+) -> bool {
+    candidates.iter().any(|candidate| grantees_match(grantee, candidate, casing))
 }
 
-fn grantees_overlap(left: &[Grantee], right: &[Grantee]) -> bool {
-    left.iter().any(|left_grantee| grantee_matches_any(left_grantee, right))
+fn grantees_overlap(left: &[Grantee], right: &[Grantee], casing: IdentifierCasing) -> bool {
+    left.iter().any(|left_grantee| grantee_matches_any(left_grantee, right, casing))
 }
 
 pub(crate) fn partition_grantees_for_revoke(
     grant_grantees: &[Grantee],
     revoke_grantees: &[Grantee],
+    casing: IdentifierCasing,
 ) -> (Vec<Grantee>, Vec<Grantee>) {
     grant_grantees
         .iter()
         .cloned()
-        .partition(|grant_grantee| grantee_matches_any(grant_grantee, revoke_grantees))
+        .partition(|grant_grantee| grantee_matches_any(grant_grantee, revoke_grantees, casing))
 }
 
-fn object_name_lists_match(left: &[ObjectName], right: &[ObjectName]) -> bool {
+fn object_name_lists_match(
+    left: &[ObjectName],
+    right: &[ObjectName],
+    casing: IdentifierCasing,
+) -> bool {
     left.len() == right.len()
         && left
             .iter()
             .zip(right.iter())
-            .all(|(left_name, right_name)| object_names_match(left_name, right_name))
+            .all(|(left_name, right_name)| object_names_match(left_name, right_name, casing))
 }
 
-fn grant_objects_inner_match(left: &GrantObjects, right: &GrantObjects) -> bool {
+fn grant_objects_inner_match(
+    left: &GrantObjects,
+    right: &GrantObjects,
+    casing: IdentifierCasing,
+) -> bool {
     match (left, right) {
         (
             GrantObjects::AllSequencesInSchema { schemas: left_schemas },
@@ -175,11 +198,11 @@ fn grant_objects_inner_match(left: &GrantObjects, right: &GrantObjects) -> bool
         | (
             GrantObjects::FutureSequencesInSchema { schemas: left_schemas },
             GrantObjects::FutureSequencesInSchema { schemas: right_schemas },
-        ) => object_name_lists_match(left_schemas, right_schemas),
+        ) => object_name_lists_match(left_schemas, right_schemas, casing),
         (
             GrantObjects::FutureSchemasInDatabase { databases: left_databases },
             GrantObjects::FutureSchemasInDatabase { databases: right_databases },
-        ) => object_name_lists_match(left_databases, right_databases),
+        ) => object_name_lists_match(left_databases, right_databases, casing),
         (GrantObjects::Databases(left_objects), GrantObjects::Databases(right_objects))
         | (GrantObjects::Schemas(left_objects), GrantObjects::Schemas(right_objects))
         | (GrantObjects::Sequences(left_objects), GrantObjects::Sequences(right_objects))
@@ -202,7 +225,7 @@ fn grant_objects_inner_match(left: &GrantObjects, right: &GrantObjects) -> bool
         | (
             GrantObjects::ExternalVolumes(left_objects),
             GrantObjects::ExternalVolumes(right_objects),
-        ) => object_name_lists_match(left_objects, right_objects),
+        ) => object_name_lists_match(left_objects, right_objects, casing),
         (
             GrantObjects::Procedure { name: left_name, arg_types: left_arg_types },
             GrantObjects::Procedure { name: right_name, arg_types: right_arg_types },
@@ -210,16 +233,20 @@ fn grant_objects_inner_match(left: &GrantObjects, right: &GrantObjects) -> bool
         | (
             GrantObjects::Function { name: left_name, arg_types: left_arg_types },
             GrantObjects::Function { name: right_name, arg_types: right_arg_types },
-        ) => object_names_match(left_name, right_name) && left_arg_types == right_arg_types,
+        ) => object_names_match(left_name, right_name, casing) && left_arg_types == right_arg_types,
         (left_objects, right_objects) => left_objects == right_objects,
     }
 }
 
-fn grant_objects_match(left: Option<&GrantObjects>, right: Option<&GrantObjects>) -> bool {
+fn grant_objects_match(
+    left: Option<&GrantObjects>,
+    right: Option<&GrantObjects>,
+    casing: IdentifierCasing,
+) -> bool {
     match (left, right) {
         (None, None) => true,
         (Some(left_objects), Some(right_objects)) => {
-            grant_objects_inner_match(left_objects, right_objects)
+            grant_objects_inner_match(left_objects, right_objects, casing)
         }
         _ => false,
     }
@@ -264,6 +291,51 @@ fn is_unsupported_column_scoped_revoke_against_table_wide_action(
         && matches!((action_columns(grant_action), action_columns(revoke_action)), (None, Some(_)))
 }
 
+/// Splits a grant into the part granting privileges on whole objects and the
+/// part granting privileges on columns, respectively stored among the table
+/// and the column grants.
+///
+/// Actions listing columns, as in `GRANT SELECT (a, b) ON t`, form the
+/// column grant, while the remaining actions form the table grant. Grants
+/// of `ALL PRIVILEGES`, schema-wide grants and grants on objects other than
+/// tables never list columns, and are therefore table grants.
+pub(crate) fn split_grant_by_scope(grant: Grant) -> (Option<Grant>, Option<Grant>) {
+    let Privileges::Actions(actions) = &grant.privileges else {
+        return (Some(grant), None);
+    };
+    let (column_actions, table_actions): (Vec<Action>, Vec<Action>) =
+        actions.iter().cloned().partition(|action| action_columns(action).is_some());
+    if column_actions.is_empty() {
+        return (Some(grant), None);
+    }
+    if table_actions.is_empty() {
+        return (None, Some(grant));
+    }
+    let mut table_grant = grant.clone();
+    table_grant.privileges = Privileges::Actions(table_actions);
+    let mut column_grant = grant;
+    column_grant.privileges = Privileges::Actions(column_actions);
+    (Some(table_grant), Some(column_grant))
+}
+
+/// Returns the explicit actions a grant stands for.
+///
+/// `ALL PRIVILEGES` on tables is expanded into the actions of
+/// [`TablePrivilege::ALL`], so that single actions can be revoked from it.
+/// On other objects, which privileges `ALL` stands for is not modelled and
+/// `None` is returned.
+fn explicit_actions(grant: &Grant) -> Option<Cow<'_, [Action]>> {
+    match &grant.privileges {
+        Privileges::Actions(actions) => Some(Cow::Borrowed(actions)),
+        Privileges::All { .. } => match grant.objects {
+            Some(GrantObjects::Tables(_) | GrantObjects::AllTablesInSchema { .. }) => {
+                Some(Cow::Owned(TablePrivilege::ALL.map(TablePrivilege::to_action).to_vec()))
+            }
+            _ => None,
+        },
+    }
+}
+
 /// Returns whether a `REVOKE` targets an unrepresentable case for this grant:
 /// revoking specific columns from a table-wide action grant.
 ///
@@ -272,17 +344,21 @@ fn is_unsupported_column_scoped_revoke_against_table_wide_action(
 ///   explicit column lists (for example `SELECT (a, b) ON t`).
 /// - It cannot represent "table-wide minus subset" (for example `SELECT ON t`
 ///   minus column `a`).
-pub(crate) fn has_unsupported_column_scoped_revoke(grant: &Grant, revoke: &Revoke) -> bool {
-    if !grant_objects_match(grant.objects.as_ref(), revoke.objects.as_ref()) {
+pub(crate) fn has_unsupported_column_scoped_revoke(
+    grant: &Grant,
+    revoke: &Revoke,
+    casing: IdentifierCasing,
+) -> bool {
+    if !grant_objects_match(grant.objects.as_ref(), revoke.objects.as_ref(), casing) {
         return false;
     }
 
-    if !grantees_overlap(&grant.grantees, &revoke.grantees) {
+    if !grantees_overlap(&grant.grantees, &revoke.grantees, casing) {
         return false;
     }
 
-    match (&grant.privileges, &revoke.privileges) {
-        (Privileges::Actions(grant_actions), Privileges::Actions(revoke_actions)) => {
+    match (explicit_actions(grant), &revoke.privileges) {
+        (Some(grant_actions), Privileges::Actions(revoke_actions)) => {
             grant_actions.iter().any(|grant_action| {
                 revoke_actions.iter().any(|revoke_action| {
                     is_unsupported_column_scoped_revoke_against_table_wide_action(
@@ -299,6 +375,7 @@ pub(crate) fn has_unsupported_column_scoped_revoke(grant: &Grant, revoke: &Revok
 fn apply_revoke_action_to_grant_action(
     grant_action: &Action,
     revoke_action: &Action,
+    casing: IdentifierCasing,
 ) -> (bool, Option<Action>) {
     if mem::discriminant(grant_action) != mem::discriminant(revoke_action) {
         return (false, Some(grant_action.clone()));
@@ -318,7 +395,7 @@ fn apply_revoke_action_to_grant_action(
                 .iter()
                 .filter(|grant_ident| {
                     !revoke_columns.iter().any(|revoke_ident| {
-                        identifiers_match(
+                        casing.matches(
                             grant_ident.value.as_str(),
                             grant_ident.quote_style.is_some(),
                             revoke_ident.value.as_str(),
@@ -349,37 +426,47 @@ pub struct RevokeApplication {
 }
 
 /// Applies a REVOKE statement to a grant and returns the resulting grant (if
-/// any).
+/// any), comparing the names of the objects, grantees and columns through
+/// the provided casing.
+///
+/// The revoked actions are subtracted from the granted ones, and the grant
+/// keeps the privileges still held. `GRANT ALL` on tables is first expanded
+/// into the actions it stands for.
 ///
 /// Representation notes:
-/// - `GRANT ALL` minus a subset of actions is not representable; the grant is
-///   preserved unchanged and treated as matched.
+/// - `GRANT ALL` minus a subset of actions on objects other than tables is
+///   not representable; the grant is preserved unchanged and treated as
+///   matched.
 /// - Column-scoped revoke from a table-wide action grant is also
 ///   unrepresentable and is surfaced via higher-level
 ///   `Error::UnsupportedRevoke`.
 #[must_use]
-pub fn apply_revoke_to_grant(grant: &Grant, revoke: &Revoke) -> RevokeApplication {
+pub fn apply_revoke_to_grant(
+    grant: &Grant,
+    revoke: &Revoke,
+    casing: IdentifierCasing,
+) -> RevokeApplication {
     // Objects must match for a revoke to apply to this grant.
-    if !grant_objects_match(grant.objects.as_ref(), revoke.objects.as_ref()) {
+    if !grant_objects_match(grant.objects.as_ref(), revoke.objects.as_ref(), casing) {
         return RevokeApplication { matched: false, updated_grant: Some(grant.clone()) };
     }
 
     // At least one grantee must overlap.
-    if !grantees_overlap(&grant.grantees, &revoke.grantees) {
+    if !grantees_overlap(&grant.grantees, &revoke.grantees, casing) {
         return RevokeApplication { matched: false, updated_grant: Some(grant.clone()) };
     }
 
-    match (&grant.privileges, &revoke.privileges) {
+    match (explicit_actions(grant), &revoke.privileges) {
         (_, Privileges::All { .. }) => RevokeApplication { matched: true, updated_grant: None },
-        (Privileges::All { .. }, Privileges::Actions(_)) => {
-            // We cannot represent "ALL minus X" in this model.
+        (None, Privileges::Actions(_)) => {
+            // We cannot represent "ALL minus X" on these objects.
             RevokeApplication { matched: true, updated_grant: Some(grant.clone()) }
         }
-        (Privileges::Actions(grant_actions), Privileges::Actions(revoke_actions)) => {
+        (Some(grant_actions), Privileges::Actions(revoke_actions)) => {
             let mut matched = false;
             let mut updated_actions = Vec::new();
 
-            for grant_action in grant_actions {
+            for grant_action in grant_actions.iter() {
                 let mut current = Some(grant_action.clone());
 
                 for revoke_action in revoke_actions {
@@ -387,7 +474,7 @@ pub fn apply_revoke_to_grant(grant: &Grant, revoke: &Revoke) -> RevokeApplicatio
                         break;
                     };
                     let (action_matched, next_action) =
-                        apply_revoke_action_to_grant_action(&current_action, revoke_action);
+                        apply_revoke_action_to_grant_action(&current_action, revoke_action, casing);
                     if action_matched {
                         matched = true;
                     }
@@ -416,6 +503,14 @@ pub fn apply_revoke_to_grant(grant: &Grant, revoke: &Revoke) -> RevokeApplicatio
 
 impl Metadata for Grant {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Grant
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
 }
 
 impl GrantLike for Grant {
@@ -454,17 +549,23 @@ impl GrantLike for Grant {
         &'a self,
         database: &'a Self::DB,
     ) -> Option<&'a <Self::DB as DatabaseLike>::Role> {
+        let casing = database.dialect().identifier_casing();
         self.granted_by.as_ref().and_then(|ident| {
             database.roles().find(|role| {
                 let role: &CreateRole = role;
-                role_matches_ident(role, ident.value.as_str(), ident.quote_style.is_some())
+                role_matches_ident(role, ident.value.as_str(), ident.quote_style.is_some(), casing)
             })
         })
     }
 
-    fn applies_to_role(&self, role: &<Self::DB as DatabaseLike>::Role) -> bool {
+    fn applies_to_role(
+        &self,
+        role: &<Self::DB as DatabaseLike>::Role,
+        database: &Self::DB,
+    ) -> bool {
         let role: &CreateRole = role;
-        self.grantees.iter().any(|grantee| grantee_matches_role(grantee, role))
+        let casing = database.dialect().identifier_casing();
+        self.grantees.iter().any(|grantee| grantee_matches_role(grantee, role, casing))
     }
 }
 
@@ -473,19 +574,22 @@ impl TableGrantLike for Grant {
         &'a self,
         database: &'a Self::DB,
     ) -> impl Iterator<Item = &'a <Self::DB as DatabaseLike>::Table> {
+        let casing = database.dialect().identifier_casing();
         let direct_tables: Box<dyn Iterator<Item = &<Self::DB as DatabaseLike>::Table> + 'a> =
             match &self.objects {
                 Some(GrantObjects::Tables(tables)) => {
                     Box::new(database.tables().filter(move |table| {
                         tables
                             .iter()
-                            .any(|table_name| table_matches_object_name(*table, table_name))
+                            .any(|table_name| table_matches_object_name(*table, table_name, casing))
                     }))
                 }
                 Some(GrantObjects::AllTablesInSchema { schemas }) => {
                     // For ALL TABLES IN SCHEMA, return all tables matching the schema
                     Box::new(database.tables().filter(move |table| {
-                        schemas.iter().any(|schema_name| schema_matches_table(schema_name, *table))
+                        schemas
+                            .iter()
+                            .any(|schema_name| schema_matches_table(schema_name, *table, casing))
                     }))
                 }
                 _ => Box::new(core::iter::empty()),
@@ -496,14 +600,16 @@ impl TableGrantLike for Grant {
     fn applies_to_table(
         &self,
         table: &<Self::DB as DatabaseLike>::Table,
-        _database: &Self::DB,
+        database: &Self::DB,
     ) -> bool {
         match &self.objects {
             Some(GrantObjects::Tables(tables)) => {
-                tables.iter().any(|table_name| table_matches_object_name(table, table_name))
+                let casing = database.dialect().identifier_casing();
+                tables.iter().any(|table_name| table_matches_object_name(table, table_name, casing))
             }
             Some(GrantObjects::AllTablesInSchema { schemas }) => {
-                schemas.iter().any(|schema_name| schema_matches_table(schema_name, table))
+                let casing = database.dialect().identifier_casing();
+                schemas.iter().any(|schema_name| schema_matches_table(schema_name, table, casing))
             }
             _ => false,
         }
@@ -542,9 +648,17 @@ impl ColumnGrantLike for Grant {
             }
         };
 
-        table
-            .columns(database)
-            .filter(move |col| column_idents.iter().any(|ident| ident.value == col.column_name()))
+        let casing = database.dialect().identifier_casing();
+        table.columns(database).filter(move |col| {
+            column_idents.iter().any(|ident| {
+                casing.matches(
+                    col.column_name(),
+                    col.column_name_is_quoted(),
+                    &ident.value,
+                    ident.quote_style.is_some(),
+                )
+            })
+        })
     }
 
     fn table<'a>(
@@ -555,7 +669,10 @@ impl ColumnGrantLike for Grant {
         match &self.objects {
             Some(GrantObjects::Tables(tables)) => {
                 let table_name = tables.first()?;
-                database.tables().find(|table| table_matches_object_name(*table, table_name))
+                let casing = database.dialect().identifier_casing();
+                database
+                    .tables()
+                    .find(|table| table_matches_object_name(*table, table_name, casing))
             }
             _ => None,
         }
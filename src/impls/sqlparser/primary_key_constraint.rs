@@ -0,0 +1,115 @@
+//! Implement the [`PrimaryKeyLike`] trait for the `sqlparser` crate's
+//! [`PrimaryKeyConstraint`].
+
+use alloc::{borrow::Cow, string::ToString};
+
+use sqlparser::{
+    ast::{CreateTable, PrimaryKeyConstraint},
+    tokenizer::Span,
+};
+
+use crate::{
+    structs::{ParserDB, TableAttribute},
+    traits::{DatabaseLike, Metadata, ObjectKind, PrimaryKeyLike, TableLike},
+    utils::object_name::ident_span,
+};
+
+impl Metadata for TableAttribute<CreateTable, PrimaryKeyConstraint> {
+    type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::PrimaryKey
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        match &self.attribute().name {
+            Some(name) => Cow::Borrowed(name.value.as_str()),
+            None if self.is_inline() => Cow::Borrowed("PRIMARY KEY"),
+            None => Cow::Owned(self.attribute().to_string()),
+        }
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.attribute().name.as_ref().and_then(ident_span)
+    }
+}
+
+impl PrimaryKeyLike for TableAttribute<CreateTable, PrimaryKeyConstraint> {
+    type DB = ParserDB;
+
+    #[inline]
+    fn primary_key_name(&self) -> Option<&str> {
+        self.attribute().name.as_ref().map(|name| name.value.as_str())
+    }
+
+    /// A primary key declared as a column option is parsed without columns,
+    /// which are only listed by table constraints.
+    #[inline]
+    fn is_inline(&self) -> bool {
+        self.attribute().columns.is_empty()
+    }
+
+    #[inline]
+    fn table<'db>(&'db self, _database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
+    where
+        Self: 'db,
+    {
+        self.table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::GenericDialect;
+
+    use crate::{
+        structs::ParserDB,
+        traits::{ColumnLike, DatabaseLike, Metadata, ObjectKind, PrimaryKeyLike, TableLike},
+    };
+
+    #[test]
+    fn inline_primary_key_takes_the_name_of_its_column_option() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (id INT CONSTRAINT t_pkey PRIMARY KEY, name TEXT);",
+        )
+        .unwrap();
+        let table = db.table(None, "t").unwrap();
+        let primary_key = table.primary_key(&db).unwrap();
+        assert!(primary_key.is_inline());
+        assert_eq!(primary_key.primary_key_name(), Some("t_pkey"));
+        assert_eq!(primary_key.object_kind(), ObjectKind::PrimaryKey);
+        assert_eq!(primary_key.display_name(), "t_pkey");
+        let columns: Vec<&str> = primary_key.columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["id"]);
+    }
+
+    #[test]
+    fn primary_key_follows_alter_table_constraints() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE t (a INT, b INT);
+            ALTER TABLE t ADD CONSTRAINT t_pkey PRIMARY KEY (b, a);
+            ",
+        )
+        .unwrap();
+        let table = db.table(None, "t").unwrap();
+        let primary_key = table.primary_key(&db).unwrap();
+        assert!(!primary_key.is_inline());
+        assert!(primary_key.is_composite(&db));
+        assert!(primary_key.unique_index(&db).is_some());
+
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE t (a INT, b INT, CONSTRAINT t_pkey PRIMARY KEY (a, b));
+            ALTER TABLE t DROP CONSTRAINT t_pkey;
+            ",
+        )
+        .unwrap();
+        let table = db.table(None, "t").unwrap();
+        assert!(table.primary_key(&db).is_none());
+    }
+}
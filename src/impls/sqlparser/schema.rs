@@ -1,12 +1,22 @@
 //! Implementation of the `SchemaLike` trait for the `Schema` struct.
 
+use alloc::borrow::Cow;
+
 use crate::{
     structs::{ParserDB, Schema},
-    traits::{Metadata, SchemaLike},
+    traits::{Metadata, ObjectKind, SchemaLike},
 };
 
 impl Metadata for Schema {
     type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Schema
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name())
+    }
 }
 
 impl SchemaLike for Schema {
@@ -23,4 +33,8 @@ impl SchemaLike for Schema {
     fn authorization(&self) -> Option<&str> {
         Schema::authorization(self)
     }
+
+    fn authorization_is_quoted(&self) -> bool {
+        self.is_authorization_quoted()
+    }
 }
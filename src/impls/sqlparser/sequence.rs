@@ -0,0 +1,99 @@
+//! Implementation of the `SequenceLike` trait for the `Sequence` struct.
+
+use alloc::borrow::Cow;
+
+use sqlparser::ast::{ColumnDef, CreateTable};
+
+use crate::{
+    structs::{ParserDB, Sequence, TableAttribute},
+    traits::{
+        ColumnLike, DatabaseLike, DialectLike, Metadata, ObjectKind, SequenceLike, TableLike,
+    },
+    utils::object_name::resolve_table_object_name_with_implicit_public_in_iter,
+};
+
+impl Metadata for Sequence {
+    type Meta = ();
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Sequence
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name())
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.schema()
+    }
+}
+
+impl SequenceLike for Sequence {
+    type DB = ParserDB;
+
+    fn name(&self) -> &str {
+        Sequence::name(self)
+    }
+
+    fn name_is_quoted(&self) -> bool {
+        self.is_quoted()
+    }
+
+    fn schema(&self) -> Option<&str> {
+        Sequence::schema(self)
+    }
+
+    fn schema_is_quoted(&self) -> bool {
+        Sequence::schema_is_quoted(self)
+    }
+
+    fn is_temporary(&self) -> bool {
+        Sequence::is_temporary(self)
+    }
+
+    fn is_unlogged(&self) -> bool {
+        Sequence::is_unlogged(self)
+    }
+
+    fn owner_column<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db TableAttribute<CreateTable, ColumnDef>>
+    where
+        Self: 'db,
+    {
+        let (table_name, column_name) = self.owned_by()?;
+        let casing = database.dialect().identifier_casing();
+        let table = resolve_table_object_name_with_implicit_public_in_iter(
+            database.tables(),
+            table_name,
+            casing,
+        )
+        .ok()??;
+        table.columns(database).find(|column| {
+            casing.matches(
+                column.column_name(),
+                column.column_name_is_quoted(),
+                column_name.value.as_str(),
+                column_name.quote_style.is_some(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    #[test]
+    fn test_sequence_metadata() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE SEQUENCE app.users_id_seq;")
+            .expect("sequence should parse");
+        let sequence = db.sequence(Some("app"), "users_id_seq").expect("sequence");
+        assert_eq!(sequence.object_kind(), ObjectKind::Sequence);
+        assert_eq!(sequence.display_name(), "users_id_seq");
+        assert_eq!(sequence.object_schema(), Some("app"));
+    }
+}
@@ -1,19 +1,58 @@
 //! Implement the [`UniqueConstraint`] trait for the `sqlparser` crate's
 
-use sqlparser::ast::{CreateTable, Expr, UniqueConstraint};
+use alloc::{borrow::Cow, string::ToString};
+
+use sqlparser::{
+    ast::{CreateTable, Expr, IndexColumn, UniqueConstraint},
+    tokenizer::Span,
+};
 
 use crate::{
-    structs::{ParserDB, TableAttribute, metadata::UniqueIndexMetadata},
-    traits::{DatabaseLike, IndexLike, Metadata},
+    structs::{ParserDB, SourceLocation, TableAttribute, metadata::UniqueIndexMetadata},
+    traits::{DatabaseLike, IndexLike, Metadata, ObjectKind, TableLike},
+    utils::object_name::ident_span,
 };
 
 impl Metadata for TableAttribute<CreateTable, UniqueConstraint> {
     type Meta = UniqueIndexMetadata<Self>;
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::UniqueIndex
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        match &self.attribute().name {
+            Some(name) => Cow::Borrowed(name.value.as_str()),
+            None => Cow::Owned(self.attribute().to_string()),
+        }
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.table().table_schema()
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.attribute().name.as_ref().and_then(ident_span)
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        meta.source_location()
+    }
 }
 
 impl IndexLike for TableAttribute<CreateTable, UniqueConstraint> {
     type DB = ParserDB;
 
+    #[inline]
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        database
+            .unique_index_metadata(self)
+            .and_then(|metadata| self.object_source_location(metadata))
+    }
+
     #[inline]
     fn table<'db>(&'db self, _database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
@@ -33,6 +72,27 @@ impl IndexLike for TableAttribute<CreateTable, UniqueConstraint> {
         None
     }
 
+    #[inline]
+    fn index_name(&self) -> Option<&str> {
+        let constraint = self.attribute();
+        constraint.name.as_ref().or(constraint.index_name.as_ref()).map(|name| name.value.as_str())
+    }
+
+    #[inline]
+    fn index_name_is_quoted(&self) -> bool {
+        let constraint = self.attribute();
+        constraint
+            .name
+            .as_ref()
+            .or(constraint.index_name.as_ref())
+            .is_some_and(|name| name.quote_style.is_some())
+    }
+
+    #[inline]
+    fn index_columns(&self) -> &[IndexColumn] {
+        &self.attribute().columns
+    }
+
     #[inline]
     fn expression<'db>(&'db self, database: &'db Self::DB) -> &'db Expr
     where
@@ -4,11 +4,22 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "test-corpus")]
+pub mod corpus;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod diff;
 pub mod errors;
+pub mod export;
 mod impls;
+pub mod lint;
+pub mod privileges;
 pub mod structs;
 pub mod traits;
 pub mod utils;
+pub mod v1;
 
 /// Prelude module re-exporting commonly used items from the crate.
 pub mod prelude {
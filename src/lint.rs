@@ -0,0 +1,33 @@
+//! Submodule providing a linter reporting questionable modeling choices in a
+//! database schema.
+//!
+//! A [`LintRuleset`] gathers [`LintRule`]s, either the built-in ones listed in
+//! [`rules`] or custom rules implementing the trait, and
+//! [`DatabaseLike::lint`](crate::traits::DatabaseLike::lint) runs them
+//! against a database, collecting the [`Lint`]s they raise in a
+//! [`LintReport`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{
+//!     lint::{LintRuleset, Severity},
+//!     prelude::*,
+//! };
+//!
+//! let db = ParserDB::parse::<GenericDialect>("CREATE TABLE logs (message TEXT);")?;
+//! let ruleset = LintRuleset::recommended().with_severity("missing-primary-key", Severity::Error);
+//! let report = db.lint(&ruleset);
+//! assert!(report.has_errors());
+//! assert_eq!(report.lints_of("missing-primary-key").count(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+mod report;
+mod rule;
+pub mod rules;
+
+pub use report::{Lint, LintReport, Severity};
+pub use rule::{LintRule, LintRuleset};
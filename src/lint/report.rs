@@ -0,0 +1,181 @@
+//! Submodule defining the lints raised by the lint rules and the report
+//! collecting them.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use crate::traits::ObjectKind;
+
+/// Severity of a lint.
+///
+/// Variants are ordered by increasing severity, so that the most severe lint
+/// of a report can be obtained with [`Ord::max`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// The lint points at a convention the schema does not follow.
+    Info,
+    /// The lint points at a likely modeling mistake.
+    Warning,
+    /// The lint points at a problem which should block the schema.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// A problem raised by a [`LintRule`](crate::lint::LintRule) about an object
+/// of a database.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lint {
+    /// Name of the rule raising the lint.
+    rule: &'static str,
+    /// Severity of the lint.
+    severity: Severity,
+    /// Kind of the object the lint is about.
+    object_kind: ObjectKind,
+    /// Name of the object the lint is about, qualified by its table or schema
+    /// when it has one.
+    object: String,
+    /// Description of the problem.
+    message: String,
+}
+
+impl Lint {
+    /// Creates a new lint.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - The name of the rule raising the lint.
+    /// * `severity` - The severity of the lint.
+    /// * `object_kind` - The kind of the object the lint is about.
+    /// * `object` - The name of the object the lint is about.
+    /// * `message` - The description of the problem.
+    #[must_use]
+    pub fn new(
+        rule: &'static str,
+        severity: Severity,
+        object_kind: ObjectKind,
+        object: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self { rule, severity, object_kind, object: object.into(), message: message.into() }
+    }
+
+    /// Returns the name of the rule raising the lint.
+    #[must_use]
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// Returns the severity of the lint.
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the kind of the object the lint is about.
+    #[must_use]
+    pub fn object_kind(&self) -> ObjectKind {
+        self.object_kind
+    }
+
+    /// Returns the name of the object the lint is about.
+    #[must_use]
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+
+    /// Returns the description of the problem.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the lint with the provided severity.
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.rule, self.message)
+    }
+}
+
+/// The lints raised by a [`LintRuleset`](crate::lint::LintRuleset) against a
+/// database, in the order of the rules raising them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+    /// The lints raised.
+    lints: Vec<Lint>,
+}
+
+impl LintReport {
+    /// Creates a report of the provided lints.
+    pub(crate) fn new(lints: Vec<Lint>) -> Self {
+        Self { lints }
+    }
+
+    /// Returns the lints of the report.
+    #[must_use]
+    pub fn lints(&self) -> &[Lint] {
+        &self.lints
+    }
+
+    /// Iterates over the lints raised by the rule with the provided name.
+    pub fn lints_of<'report>(
+        &'report self,
+        rule: &'report str,
+    ) -> impl Iterator<Item = &'report Lint> {
+        self.lints.iter().filter(move |lint| lint.rule == rule)
+    }
+
+    /// Returns whether no lint was raised.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lints.is_empty()
+    }
+
+    /// Returns the number of lints raised.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lints.len()
+    }
+
+    /// Returns the severity of the most severe lint, if any was raised.
+    #[must_use]
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.lints.iter().map(Lint::severity).max()
+    }
+
+    /// Returns whether any lint of [`Severity::Error`] was raised.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.max_severity() == Some(Severity::Error)
+    }
+
+    /// Consumes the report, returning its lints.
+    #[must_use]
+    pub fn into_lints(self) -> Vec<Lint> {
+        self.lints
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for lint in &self.lints {
+            writeln!(f, "{lint}")?;
+        }
+        Ok(())
+    }
+}
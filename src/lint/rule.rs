@@ -0,0 +1,206 @@
+//! Submodule defining the `LintRule` trait and the rulesets gathering the
+//! rules to run against a database.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Debug};
+
+use crate::{
+    lint::{
+        Lint, LintReport, Severity,
+        rules::{
+            ColumnNameStyle, MissingPrimaryKey, NullableForeignKeyColumn,
+            SecurityDefinerSearchPath, SuperuserLogin, TableWithoutComment, TautologicalCheck,
+            UnindexedForeignKey,
+        },
+    },
+    traits::DatabaseLike,
+};
+
+/// A rule checking a database for a questionable modeling choice.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{Lint, LintRule, LintRuleset, Severity},
+///     prelude::*,
+/// };
+///
+/// /// Flags the tables whose name is not plural.
+/// struct PluralTableNames;
+///
+/// impl<DB: DatabaseLike> LintRule<DB> for PluralTableNames {
+///     fn name(&self) -> &'static str {
+///         "plural-table-names"
+///     }
+///
+///     fn default_severity(&self) -> Severity {
+///         Severity::Info
+///     }
+///
+///     fn check(&self, database: &DB) -> Vec<Lint> {
+///         database
+///             .tables()
+///             .filter(|table| !table.table_name().ends_with('s'))
+///             .map(|table| {
+///                 Lint::new(
+///                     self.name(),
+///                     self.default_severity(),
+///                     ObjectKind::Table,
+///                     table.table_name(),
+///                     format!("table `{}` is not plural", table.table_name()),
+///                 )
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY); CREATE TABLE staff (id INT PRIMARY KEY);",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(PluralTableNames));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "staff");
+/// # Ok(())
+/// # }
+/// ```
+pub trait LintRule<DB: DatabaseLike>: Send + Sync {
+    /// Returns the name of the rule, in kebab case, which identifies it
+    /// within a ruleset.
+    fn name(&self) -> &'static str;
+
+    /// Returns the severity of the lints raised by the rule, which rulesets
+    /// may override.
+    fn default_severity(&self) -> Severity;
+
+    /// Returns the lints raised by the rule against the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to check.
+    fn check(&self, database: &DB) -> Vec<Lint>;
+}
+
+/// A set of [`LintRule`]s to run against a database, along with the
+/// severities overriding the default ones of the rules.
+pub struct LintRuleset<DB: DatabaseLike> {
+    /// The rules to run, in the order of their lints in the reports.
+    rules: Vec<Box<dyn LintRule<DB>>>,
+    /// The severities overriding the default severity of the rule with the
+    /// associated name.
+    severities: Vec<(&'static str, Severity)>,
+}
+
+impl<DB: DatabaseLike> LintRuleset<DB> {
+    /// Creates an empty ruleset.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { rules: Vec::new(), severities: Vec::new() }
+    }
+
+    /// Creates a ruleset with all the built-in rules of
+    /// [`crate::lint::rules`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{lint::LintRuleset, prelude::*};
+    ///
+    /// let ruleset = LintRuleset::<ParserDB>::recommended();
+    /// assert!(ruleset.rule_names().any(|name| name == "unindexed-foreign-key"));
+    /// ```
+    #[must_use]
+    pub fn recommended() -> Self {
+        Self::new()
+            .with_rule(MissingPrimaryKey)
+            .with_rule(NullableForeignKeyColumn)
+            .with_rule(TautologicalCheck)
+            .with_rule(TableWithoutComment)
+            .with_rule(ColumnNameStyle)
+            .with_rule(UnindexedForeignKey)
+            .with_rule(SuperuserLogin)
+            .with_rule(SecurityDefinerSearchPath)
+    }
+
+    /// Adds the provided rule to the ruleset, replacing any rule with the
+    /// same name.
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl LintRule<DB> + 'static) -> Self {
+        self.rules.retain(|existing| existing.name() != rule.name());
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Removes the rule with the provided name from the ruleset, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{lint::LintRuleset, prelude::*};
+    ///
+    /// let ruleset = LintRuleset::<ParserDB>::recommended().without_rule("column-name-style");
+    /// assert!(ruleset.rule_names().all(|name| name != "column-name-style"));
+    /// ```
+    #[must_use]
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Sets the severity of the lints raised by the rule with the provided
+    /// name, overriding the default severity of the rule.
+    #[must_use]
+    pub fn with_severity(mut self, name: &'static str, severity: Severity) -> Self {
+        self.severities.retain(|(existing, _)| *existing != name);
+        self.severities.push((name, severity));
+        self
+    }
+
+    /// Iterates over the names of the rules of the ruleset.
+    pub fn rule_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().map(|rule| rule.name())
+    }
+
+    /// Returns the severity overriding the one of the lints raised by the
+    /// rule with the provided name, if any.
+    fn severity_override(&self, name: &str) -> Option<Severity> {
+        self.severities.iter().find_map(|(rule, severity)| (*rule == name).then_some(*severity))
+    }
+
+    /// Runs the rules of the ruleset against the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to check.
+    #[must_use]
+    pub fn lint(&self, database: &DB) -> LintReport {
+        let mut lints = Vec::new();
+        for rule in &self.rules {
+            let raised = rule.check(database);
+            match self.severity_override(rule.name()) {
+                Some(severity) => {
+                    lints.extend(raised.into_iter().map(|lint| lint.with_severity(severity)));
+                }
+                None => lints.extend(raised),
+            }
+        }
+        LintReport::new(lints)
+    }
+}
+
+/// The default ruleset is the [`LintRuleset::recommended`] one.
+impl<DB: DatabaseLike> Default for LintRuleset<DB> {
+    fn default() -> Self {
+        Self::recommended()
+    }
+}
+
+impl<DB: DatabaseLike> Debug for LintRuleset<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LintRuleset")
+            .field("rules", &self.rule_names().collect::<Vec<_>>())
+            .field("severities", &self.severities)
+            .finish()
+    }
+}
@@ -0,0 +1,511 @@
+//! Submodule providing the built-in [`LintRule`]s, gathered by
+//! [`LintRuleset::recommended`](crate::lint::LintRuleset::recommended) except
+//! for the [`NamingConvention`], which is meant to be configured.
+
+mod naming;
+
+pub use naming::{NameCase, NamingConvention};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use sqlparser::ast::FunctionSecurity;
+
+use crate::{
+    lint::{Lint, LintRule, Severity},
+    traits::{
+        CheckConstraintLike, ColumnLike, DatabaseLike, ForeignKeyLike, FunctionLike, Metadata,
+        ObjectKind, RoleLike, TableLike,
+    },
+};
+
+/// Returns the name of the provided table, qualified by its schema if any.
+fn table_path<T: TableLike>(table: &T) -> String {
+    match table.table_schema() {
+        Some(schema) => format!("{schema}.{}", table.table_name()),
+        None => table.table_name().to_string(),
+    }
+}
+
+/// Returns the name of the provided column, qualified by its table.
+fn column_path<C: ColumnLike>(column: &C, database: &C::DB) -> String {
+    format!("{}.{}", table_path(column.table(database)), column.column_name())
+}
+
+/// Flags the tables without a primary key, whose rows cannot be referenced
+/// nor reliably updated.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::MissingPrimaryKey},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY); CREATE TABLE logs (message TEXT);",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(MissingPrimaryKey));
+/// assert_eq!(report.lints()[0].object(), "logs");
+/// assert_eq!(report.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MissingPrimaryKey;
+
+impl<DB: DatabaseLike> LintRule<DB> for MissingPrimaryKey {
+    fn name(&self) -> &'static str {
+        "missing-primary-key"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .tables()
+            .filter(|table| !table.has_primary_key(database))
+            .map(|table| {
+                let path = table_path(table);
+                let message = format!("table `{path}` has no primary key");
+                Lint::new(self.name(), self.default_severity(), ObjectKind::Table, path, message)
+            })
+            .collect()
+    }
+}
+
+/// Flags the nullable columns of foreign keys, whose rows may silently not
+/// reference anything.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::NullableForeignKeyColumn},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (
+///     id INT PRIMARY KEY,
+///     author_id INT NOT NULL REFERENCES users (id),
+///     editor_id INT REFERENCES users (id)
+/// );
+/// ",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(NullableForeignKeyColumn));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "posts.editor_id");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NullableForeignKeyColumn;
+
+impl<DB: DatabaseLike> LintRule<DB> for NullableForeignKeyColumn {
+    fn name(&self) -> &'static str {
+        "nullable-foreign-key-column"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .columns()
+            .filter(|column| {
+                column.is_nullable(database) && column.foreign_keys(database).next().is_some()
+            })
+            .map(|column| {
+                let path = column_path(column, database);
+                let message = format!("column `{path}` of a foreign key is nullable");
+                Lint::new(self.name(), self.default_severity(), ObjectKind::Column, path, message)
+            })
+            .collect()
+    }
+}
+
+/// Flags the check constraints which always hold, and thus constrain
+/// nothing.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::TautologicalCheck},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE t (id INT PRIMARY KEY, CHECK (1 = 1), CHECK (id > 0));",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(TautologicalCheck));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object_kind(), ObjectKind::CheckConstraint);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TautologicalCheck;
+
+impl<DB: DatabaseLike> LintRule<DB> for TautologicalCheck {
+    fn name(&self) -> &'static str {
+        "tautological-check"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .tables()
+            .flat_map(|table| table.check_constraints(database))
+            .filter(|check| check.is_tautology(database))
+            .map(|check| {
+                let table = table_path(check.table(database));
+                let name = check.display_name();
+                let message = format!("check constraint `{name}` of table `{table}` always holds");
+                Lint::new(
+                    self.name(),
+                    self.default_severity(),
+                    ObjectKind::CheckConstraint,
+                    format!("{table}: {name}"),
+                    message,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags the tables with neither a comment nor a documentation comment.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::TableWithoutComment},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// -- Registered users
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE logs (id INT PRIMARY KEY);
+/// ",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(TableWithoutComment));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "logs");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TableWithoutComment;
+
+impl<DB: DatabaseLike> LintRule<DB> for TableWithoutComment {
+    fn name(&self) -> &'static str {
+        "table-without-comment"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .tables()
+            .filter(|table| table.table_doc(database).is_none_or(|doc| doc.trim().is_empty()))
+            .map(|table| {
+                let path = table_path(table);
+                let message = format!("table `{path}` has no comment");
+                Lint::new(self.name(), self.default_severity(), ObjectKind::Table, path, message)
+            })
+            .collect()
+    }
+}
+
+/// Flags the columns whose name is not in snake case, such as `UserId` or
+/// `user__id`. [`NamingConvention`] checks further conventions.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::ColumnNameStyle},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, \"firstName\" TEXT, last_name2 TEXT);",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(ColumnNameStyle));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "users.firstName");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColumnNameStyle;
+
+impl<DB: DatabaseLike> LintRule<DB> for ColumnNameStyle {
+    fn name(&self) -> &'static str {
+        "column-name-style"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .columns()
+            .filter(|column| !NameCase::SnakeCase.matches(column.column_name()))
+            .map(|column| {
+                let path = column_path(column, database);
+                let message = format!("column `{path}` is not named in snake case");
+                Lint::new(self.name(), self.default_severity(), ObjectKind::Column, path, message)
+            })
+            .collect()
+    }
+}
+
+/// Flags the foreign keys not covered by any index of their table, as
+/// reported by [`DatabaseLike::unindexed_foreign_keys`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::UnindexedForeignKey},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+/// ",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(UnindexedForeignKey));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object_kind(), ObjectKind::ForeignKey);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnindexedForeignKey;
+
+impl<DB: DatabaseLike> LintRule<DB> for UnindexedForeignKey {
+    fn name(&self) -> &'static str {
+        "unindexed-foreign-key"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .unindexed_foreign_keys()
+            .map(|foreign_key| {
+                let table = table_path(foreign_key.host_table(database));
+                let name = foreign_key.display_name();
+                let message =
+                    format!("foreign key `{name}` of table `{table}` is not covered by an index");
+                Lint::new(
+                    self.name(),
+                    self.default_severity(),
+                    ObjectKind::ForeignKey,
+                    format!("{table}: {name}"),
+                    message,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags the superuser roles which can log in, bypassing every permission
+/// check from an interactive session.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, Severity, rules::SuperuserLogin},
+///     prelude::*,
+/// };
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "CREATE ROLE admin SUPERUSER LOGIN; CREATE ROLE maintenance SUPERUSER NOLOGIN;",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(SuperuserLogin));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "admin");
+/// assert_eq!(report.max_severity(), Some(Severity::Error));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SuperuserLogin;
+
+impl<DB: DatabaseLike> LintRule<DB> for SuperuserLogin {
+    fn name(&self) -> &'static str {
+        "superuser-login"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .roles()
+            .filter(|role| role.is_superuser() && role.can_login())
+            .map(|role| {
+                let message = format!("role `{}` is a superuser which can log in", role.name());
+                Lint::new(
+                    self.name(),
+                    self.default_severity(),
+                    ObjectKind::Role,
+                    role.name(),
+                    message,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags the `SECURITY DEFINER` functions which do not fix their
+/// `search_path`, and may thus be tricked into running objects planted by
+/// their callers with the privileges of their owner.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::SecurityDefinerSearchPath},
+///     prelude::*,
+/// };
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "
+/// CREATE FUNCTION safe() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL
+///     SECURITY DEFINER SET search_path = pg_catalog;
+/// CREATE FUNCTION unsafe() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL SECURITY DEFINER;
+/// CREATE FUNCTION plain() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+/// ",
+/// )?;
+/// let report = db.lint(&LintRuleset::new().with_rule(SecurityDefinerSearchPath));
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.lints()[0].object(), "unsafe");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SecurityDefinerSearchPath;
+
+impl<DB: DatabaseLike> LintRule<DB> for SecurityDefinerSearchPath {
+    fn name(&self) -> &'static str {
+        "security-definer-search-path"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        database
+            .functions()
+            .filter(|function| {
+                function.security() == FunctionSecurity::Definer
+                    && !function.has_fixed_search_path()
+            })
+            .map(|function| {
+                let path = match function.schema() {
+                    Some(schema) => format!("{schema}.{}", function.name()),
+                    None => function.name().to_string(),
+                };
+                let message =
+                    format!("`SECURITY DEFINER` function `{path}` does not set its `search_path`");
+                Lint::new(self.name(), self.default_severity(), ObjectKind::Function, path, message)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        lint::{LintRuleset, Severity},
+        structs::ParserDB,
+        traits::DatabaseLike,
+    };
+
+    #[test]
+    fn recommended_ruleset_reports_every_rule() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE admin SUPERUSER LOGIN;
+            CREATE FUNCTION elevate() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL SECURITY DEFINER;
+            CREATE TABLE users (id INT PRIMARY KEY);
+            CREATE TABLE posts (
+                \"postId\" INT,
+                author_id INT REFERENCES users (id),
+                CHECK (1 = 1)
+            );
+            ",
+        )
+        .unwrap();
+        let report = db.lint(&LintRuleset::recommended());
+        for rule in [
+            "missing-primary-key",
+            "nullable-foreign-key-column",
+            "tautological-check",
+            "table-without-comment",
+            "column-name-style",
+            "unindexed-foreign-key",
+            "superuser-login",
+            "security-definer-search-path",
+        ] {
+            assert!(report.lints_of(rule).next().is_some(), "{rule} raised no lint");
+        }
+        assert!(report.has_errors());
+        assert!(report.to_string().contains("error[superuser-login]: role `admin`"));
+    }
+
+    #[test]
+    fn ruleset_overrides_severities_and_removes_rules() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE logs (message TEXT);").unwrap();
+        let ruleset = LintRuleset::recommended()
+            .without_rule("table-without-comment")
+            .with_severity("missing-primary-key", Severity::Error);
+        let report = db.lint(&ruleset);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.lints()[0].rule(), "missing-primary-key");
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+    }
+}
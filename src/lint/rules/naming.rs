@@ -0,0 +1,375 @@
+//! Submodule providing the configurable naming conventions rule.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+use super::{column_path, table_path};
+use crate::{
+    lint::{Lint, LintRule, Severity},
+    traits::{
+        ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, ObjectKind, TableLike, TriggerLike,
+    },
+};
+
+/// Name of the naming conventions rule.
+const RULE_NAME: &str = "naming-convention";
+/// Placeholder of the name templates standing for the name of the table of
+/// the object.
+const TABLE_PLACEHOLDER: &str = "{table}";
+/// Placeholder of the name templates standing for the names of the columns
+/// of the object, joined by underscores.
+const COLUMNS_PLACEHOLDER: &str = "{columns}";
+/// Placeholder of the foreign key name templates standing for the name of
+/// the referenced table.
+const REFERENCED_TABLE_PLACEHOLDER: &str = "{referenced_table}";
+
+/// Letter case convention of identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameCase {
+    /// Lowercase words separated by single underscores, such as `user_id`.
+    SnakeCase,
+    /// Uppercase words separated by single underscores, such as `USER_ID`.
+    ScreamingSnakeCase,
+    /// Words joined without separator, the first one in lowercase and the
+    /// others capitalized, such as `userId`.
+    CamelCase,
+    /// Capitalized words joined without separator, such as `UserId`.
+    PascalCase,
+}
+
+impl NameCase {
+    /// Returns whether the provided name follows the convention. Names must
+    /// start with a letter and only contain ASCII letters, digits and, for
+    /// the snake cases, underscores.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::lint::rules::NameCase;
+    ///
+    /// assert!(NameCase::SnakeCase.matches("user_id2"));
+    /// assert!(!NameCase::SnakeCase.matches("user__id"));
+    /// assert!(NameCase::ScreamingSnakeCase.matches("USER_ID"));
+    /// assert!(NameCase::CamelCase.matches("userId"));
+    /// assert!(NameCase::PascalCase.matches("UserId"));
+    /// assert!(!NameCase::PascalCase.matches("user_id"));
+    /// ```
+    #[must_use]
+    pub fn matches(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else {
+            return false;
+        };
+        match self {
+            Self::SnakeCase | Self::ScreamingSnakeCase => {
+                let is_letter = |character: char| {
+                    if self == Self::SnakeCase {
+                        character.is_ascii_lowercase()
+                    } else {
+                        character.is_ascii_uppercase()
+                    }
+                };
+                is_letter(first)
+                    && !name.ends_with('_')
+                    && !name.contains("__")
+                    && name.chars().all(|character| {
+                        is_letter(character) || character.is_ascii_digit() || character == '_'
+                    })
+            }
+            Self::CamelCase | Self::PascalCase => {
+                let first_matches = if self == Self::CamelCase {
+                    first.is_ascii_lowercase()
+                } else {
+                    first.is_ascii_uppercase()
+                };
+                first_matches && name.chars().all(|character| character.is_ascii_alphanumeric())
+            }
+        }
+    }
+}
+
+impl Display for NameCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SnakeCase => "snake_case",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+            Self::CamelCase => "camelCase",
+            Self::PascalCase => "PascalCase",
+        })
+    }
+}
+
+/// Flags the objects whose name does not follow the configured naming
+/// conventions.
+///
+/// Each convention is optional: by default, only the names of the tables and
+/// of the columns are checked, against [`NameCase::SnakeCase`]. Templates
+/// describe the expected name of indices and foreign keys, where `{table}`
+/// stands for the name of the table, `{columns}` for the names of the
+/// columns joined by underscores and, for foreign keys only,
+/// `{referenced_table}` for the name of the referenced table. Unnamed
+/// indices and foreign keys are not checked, as their name is chosen by the
+/// database.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{
+///     lint::{LintRuleset, rules::NamingConvention},
+///     prelude::*,
+/// };
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, name TEXT);
+/// CREATE TABLE posts (
+///     id INT PRIMARY KEY,
+///     author_id INT,
+///     CONSTRAINT posts_author FOREIGN KEY (author_id) REFERENCES users (id)
+/// );
+/// CREATE INDEX idx_users_name ON users (name);
+/// CREATE INDEX posts_by_author ON posts (author_id);
+/// ",
+/// )?;
+/// let naming = NamingConvention::default()
+///     .with_index_template("idx_{table}_{columns}")
+///     .with_foreign_key_template("fk_{table}_{referenced_table}");
+/// let report = db.lint(&LintRuleset::new().with_rule(naming));
+/// let objects: Vec<&str> = report.lints().iter().map(|lint| lint.object()).collect();
+/// assert_eq!(objects, ["posts: posts_by_author", "posts: posts_author"]);
+/// assert!(report.lints()[0].message().contains("expected `idx_posts_author_id`"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamingConvention {
+    /// Case of the names of the tables, if checked.
+    table_case: Option<NameCase>,
+    /// Case of the names of the columns, if checked.
+    column_case: Option<NameCase>,
+    /// Template of the names of the indices, if checked.
+    index_template: Option<String>,
+    /// Template of the names of the foreign keys, if checked.
+    foreign_key_template: Option<String>,
+    /// Prefix of the names of the triggers, if checked.
+    trigger_prefix: Option<String>,
+}
+
+impl Default for NamingConvention {
+    fn default() -> Self {
+        Self::new().with_table_case(NameCase::SnakeCase).with_column_case(NameCase::SnakeCase)
+    }
+}
+
+impl NamingConvention {
+    /// Creates a rule checking no convention.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            table_case: None,
+            column_case: None,
+            index_template: None,
+            foreign_key_template: None,
+            trigger_prefix: None,
+        }
+    }
+
+    /// Checks the names of the tables against the provided case.
+    #[must_use]
+    pub fn with_table_case(mut self, case: NameCase) -> Self {
+        self.table_case = Some(case);
+        self
+    }
+
+    /// Checks the names of the columns against the provided case.
+    #[must_use]
+    pub fn with_column_case(mut self, case: NameCase) -> Self {
+        self.column_case = Some(case);
+        self
+    }
+
+    /// Checks the names of the indices against the provided template, such
+    /// as `idx_{table}_{columns}`.
+    #[must_use]
+    pub fn with_index_template(mut self, template: &str) -> Self {
+        self.index_template = Some(template.to_string());
+        self
+    }
+
+    /// Checks the names of the foreign keys against the provided template,
+    /// such as `{table}_{columns}_fkey`.
+    #[must_use]
+    pub fn with_foreign_key_template(mut self, template: &str) -> Self {
+        self.foreign_key_template = Some(template.to_string());
+        self
+    }
+
+    /// Checks that the names of the triggers start with the provided prefix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{
+    ///     lint::{LintRuleset, rules::NamingConvention},
+    ///     prelude::*,
+    /// };
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE t (id INT PRIMARY KEY);
+    /// CREATE FUNCTION touch() RETURNS TRIGGER AS $$ BEGIN END; $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER trg_touch BEFORE UPDATE ON t FOR EACH ROW EXECUTE FUNCTION touch();
+    /// CREATE TRIGGER touch_t BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION touch();
+    /// ",
+    /// )?;
+    /// let naming = NamingConvention::new().with_trigger_prefix("trg_");
+    /// let report = db.lint(&LintRuleset::new().with_rule(naming));
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!(report.lints()[0].object(), "t: touch_t");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_trigger_prefix(mut self, prefix: &str) -> Self {
+        self.trigger_prefix = Some(prefix.to_string());
+        self
+    }
+}
+
+/// Renders the provided name template.
+fn render_template(
+    template: &str,
+    table: &str,
+    columns: &[&str],
+    referenced_table: Option<&str>,
+) -> String {
+    let rendered =
+        template.replace(TABLE_PLACEHOLDER, table).replace(COLUMNS_PLACEHOLDER, &columns.join("_"));
+    match referenced_table {
+        Some(referenced_table) => rendered.replace(REFERENCED_TABLE_PLACEHOLDER, referenced_table),
+        None => rendered,
+    }
+}
+
+/// Returns the lint about an object whose name does not follow the expected
+/// pattern.
+fn naming_lint(kind: ObjectKind, object: String, message: String) -> Lint {
+    Lint::new(RULE_NAME, Severity::Info, kind, object, message)
+}
+
+impl<DB: DatabaseLike> LintRule<DB> for NamingConvention {
+    fn name(&self) -> &'static str {
+        RULE_NAME
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, database: &DB) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        if let Some(case) = self.table_case {
+            for table in database.tables().filter(|table| !case.matches(table.table_name())) {
+                let path = table_path(table);
+                let message = format!("table `{path}` is not named in {case}");
+                lints.push(naming_lint(ObjectKind::Table, path, message));
+            }
+        }
+        if let Some(case) = self.column_case {
+            for column in database.columns().filter(|column| !case.matches(column.column_name())) {
+                let path = column_path(column, database);
+                let message = format!("column `{path}` is not named in {case}");
+                lints.push(naming_lint(ObjectKind::Column, path, message));
+            }
+        }
+        if let Some(template) = &self.index_template {
+            for index in database.indexes() {
+                let Some(name) = index.index_name() else {
+                    continue;
+                };
+                let table = IndexLike::table(index, database);
+                let columns: Vec<&str> =
+                    index.columns(database).map(ColumnLike::column_name).collect();
+                let expected = render_template(template, table.table_name(), &columns, None);
+                if !name.eq_ignore_ascii_case(&expected) {
+                    let path = table_path(table);
+                    let message = format!(
+                        "index `{name}` of table `{path}` does not match `{template}`, \
+                         expected `{expected}`"
+                    );
+                    lints.push(naming_lint(ObjectKind::Index, format!("{path}: {name}"), message));
+                }
+            }
+        }
+        if let Some(template) = &self.foreign_key_template {
+            for table in database.tables() {
+                for foreign_key in table.foreign_keys(database) {
+                    let (Some(name), Some(referenced_table)) =
+                        (foreign_key.foreign_key_name(), foreign_key.referenced_table(database))
+                    else {
+                        continue;
+                    };
+                    let columns: Vec<&str> =
+                        foreign_key.host_columns(database).map(ColumnLike::column_name).collect();
+                    let expected = render_template(
+                        template,
+                        table.table_name(),
+                        &columns,
+                        Some(referenced_table.table_name()),
+                    );
+                    if !name.eq_ignore_ascii_case(&expected) {
+                        let path = table_path(table);
+                        let message = format!(
+                            "foreign key `{name}` of table `{path}` does not match \
+                             `{template}`, expected `{expected}`"
+                        );
+                        lints.push(naming_lint(
+                            ObjectKind::ForeignKey,
+                            format!("{path}: {name}"),
+                            message,
+                        ));
+                    }
+                }
+            }
+        }
+        if let Some(prefix) = &self.trigger_prefix {
+            for trigger in
+                database.triggers().filter(|trigger| !trigger.name().starts_with(prefix.as_str()))
+            {
+                let path = table_path(TriggerLike::table(trigger, database));
+                let name = trigger.name();
+                let message =
+                    format!("trigger `{name}` of table `{path}` does not start with `{prefix}`");
+                lints.push(naming_lint(ObjectKind::Trigger, format!("{path}: {name}"), message));
+            }
+        }
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_template;
+
+    #[test]
+    fn templates_render_every_placeholder() {
+        assert_eq!(
+            render_template("{table}_{columns}_fkey", "posts", &["author_id", "blog_id"], None),
+            "posts_author_id_blog_id_fkey"
+        );
+        assert_eq!(
+            render_template(
+                "fk_{table}_{referenced_table}",
+                "posts",
+                &["author_id"],
+                Some("users")
+            ),
+            "fk_posts_users"
+        );
+    }
+}
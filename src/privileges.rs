@@ -0,0 +1,474 @@
+//! Submodule resolving the privileges a role effectively holds on a table.
+//!
+//! Privileges reach a role through several paths: the grants naming it, the
+//! grants to `PUBLIC`, and the grants to the roles it is a member of, when it
+//! inherits their privileges. The default privileges declared with `ALTER
+//! DEFAULT PRIVILEGES` before a table is created are granted on it as well.
+//! [`DatabaseLike::effective_privileges`] combines them all into a
+//! [`PrivilegeSet`], keeping track of which privileges are held `WITH GRANT
+//! OPTION`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use sql_traits::{privileges::TablePrivilege, prelude::*};
+//! use sqlparser::dialect::PostgreSqlDialect;
+//!
+//! let db = ParserDB::parse::<PostgreSqlDialect>(
+//!     "
+//! CREATE ROLE staff;
+//! CREATE ROLE alice IN ROLE staff;
+//! CREATE TABLE documents (id INT, body TEXT);
+//! GRANT SELECT ON documents TO PUBLIC;
+//! GRANT UPDATE (body) ON documents TO staff WITH GRANT OPTION;
+//! ",
+//! )?;
+//! let documents = db.table(None, "documents").unwrap();
+//! let privileges = db.effective_privileges(db.role("alice").unwrap(), documents);
+//! assert!(privileges.has(TablePrivilege::Select));
+//! assert!(!privileges.has(TablePrivilege::Update));
+//! assert!(privileges.is_column_grantable("body", false, TablePrivilege::Update));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`DatabaseLike::effective_privileges`]: crate::traits::DatabaseLike::effective_privileges
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use sqlparser::ast::{Action, Ident};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{
+        ColumnGrantLike, DatabaseLike, DefaultPrivilegeLike, DialectLike, GrantLike, RoleLike,
+        TableGrantLike, TableLike,
+    },
+};
+
+/// A privilege which can be granted on a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TablePrivilege {
+    /// The `SELECT` privilege, which may be restricted to columns.
+    Select,
+    /// The `INSERT` privilege, which may be restricted to columns.
+    Insert,
+    /// The `UPDATE` privilege, which may be restricted to columns.
+    Update,
+    /// The `DELETE` privilege.
+    Delete,
+    /// The `TRUNCATE` privilege.
+    Truncate,
+    /// The `REFERENCES` privilege, which may be restricted to columns.
+    References,
+    /// The `TRIGGER` privilege.
+    Trigger,
+}
+
+impl TablePrivilege {
+    /// All the table privileges, which `ALL PRIVILEGES` stands for.
+    pub const ALL: [Self; 7] = [
+        Self::Select,
+        Self::Insert,
+        Self::Update,
+        Self::Delete,
+        Self::Truncate,
+        Self::References,
+        Self::Trigger,
+    ];
+
+    /// Returns the privilege granted by the provided action, along with the
+    /// columns it is restricted to, if any.
+    ///
+    /// Returns `None` for the actions which are not table privileges.
+    fn from_action(action: &Action) -> Option<(Self, Option<&[Ident]>)> {
+        Some(match action {
+            Action::Select { columns } => (Self::Select, columns.as_deref()),
+            Action::Insert { columns } => (Self::Insert, columns.as_deref()),
+            Action::Update { columns } => (Self::Update, columns.as_deref()),
+            Action::References { columns } => (Self::References, columns.as_deref()),
+            Action::Delete => (Self::Delete, None),
+            Action::Truncate => (Self::Truncate, None),
+            Action::Trigger => (Self::Trigger, None),
+            _ => return None,
+        })
+    }
+
+    /// Returns the action granting the privilege on the whole table.
+    pub(crate) fn to_action(self) -> Action {
+        match self {
+            Self::Select => Action::Select { columns: None },
+            Self::Insert => Action::Insert { columns: None },
+            Self::Update => Action::Update { columns: None },
+            Self::Delete => Action::Delete,
+            Self::Truncate => Action::Truncate,
+            Self::References => Action::References { columns: None },
+            Self::Trigger => Action::Trigger,
+        }
+    }
+}
+
+impl Display for TablePrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Select => "SELECT",
+            Self::Insert => "INSERT",
+            Self::Update => "UPDATE",
+            Self::Delete => "DELETE",
+            Self::Truncate => "TRUNCATE",
+            Self::References => "REFERENCES",
+            Self::Trigger => "TRIGGER",
+        })
+    }
+}
+
+/// The privileges a role effectively holds on a table, as resolved by
+/// [`DatabaseLike::effective_privileges`].
+///
+/// Privileges are held either on the whole table or on some of its columns,
+/// and each of them may be held `WITH GRANT OPTION`. Column names are
+/// compared under the identifier casing of the dialect of the database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrivilegeSet {
+    /// The casing the column names are normalized under.
+    casing: IdentifierCasing,
+    /// The privileges held on the whole table, mapped to whether they are
+    /// held with grant option.
+    table: BTreeMap<TablePrivilege, bool>,
+    /// The privileges held on single columns, by normalized column name,
+    /// mapped to whether they are held with grant option.
+    columns: BTreeMap<String, BTreeMap<TablePrivilege, bool>>,
+}
+
+impl PrivilegeSet {
+    /// Records a privilege, upgrading it to be held with grant option if
+    /// `grantable` is set.
+    fn insert(&mut self, column: Option<&Ident>, privilege: TablePrivilege, grantable: bool) {
+        let privileges = match column {
+            Some(column) => {
+                let column = self.casing.normalize(&column.value, column.quote_style.is_some());
+                self.columns.entry(column.into_owned()).or_default()
+            }
+            None => &mut self.table,
+        };
+        *privileges.entry(privilege).or_default() |= grantable;
+    }
+
+    /// Returns the privileges held on the provided column only.
+    fn column_privileges(
+        &self,
+        column: &str,
+        quoted: bool,
+    ) -> Option<&BTreeMap<TablePrivilege, bool>> {
+        self.columns.get(self.casing.normalize(column, quoted).as_ref())
+    }
+
+    /// Returns whether no privilege is held, on the table or on any of its
+    /// columns.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty() && self.columns.is_empty()
+    }
+
+    /// Returns whether the privilege is held on the whole table.
+    ///
+    /// # Arguments
+    ///
+    /// * `privilege` - The privilege to check.
+    #[must_use]
+    pub fn has(&self, privilege: TablePrivilege) -> bool {
+        self.table.contains_key(&privilege)
+    }
+
+    /// Returns whether the privilege is held on the whole table with grant
+    /// option.
+    ///
+    /// # Arguments
+    ///
+    /// * `privilege` - The privilege to check.
+    #[must_use]
+    pub fn is_grantable(&self, privilege: TablePrivilege) -> bool {
+        self.table.get(&privilege).copied().unwrap_or(false)
+    }
+
+    /// Returns whether the privilege is held on the provided column, either
+    /// directly or through the whole table.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the column, without quotes.
+    /// * `quoted` - Whether the name of the column is quoted.
+    /// * `privilege` - The privilege to check.
+    #[must_use]
+    pub fn has_column(&self, column: &str, quoted: bool, privilege: TablePrivilege) -> bool {
+        self.has(privilege)
+            || self
+                .column_privileges(column, quoted)
+                .is_some_and(|privileges| privileges.contains_key(&privilege))
+    }
+
+    /// Returns whether the privilege is held on the provided column with
+    /// grant option, either directly or through the whole table.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The name of the column, without quotes.
+    /// * `quoted` - Whether the name of the column is quoted.
+    /// * `privilege` - The privilege to check.
+    #[must_use]
+    pub fn is_column_grantable(
+        &self,
+        column: &str,
+        quoted: bool,
+        privilege: TablePrivilege,
+    ) -> bool {
+        self.is_grantable(privilege)
+            || self
+                .column_privileges(column, quoted)
+                .and_then(|privileges| privileges.get(&privilege))
+                .copied()
+                .unwrap_or(false)
+    }
+
+    /// Iterates over the privileges held on the whole table, along with
+    /// whether they are held with grant option.
+    pub fn privileges(&self) -> impl Iterator<Item = (TablePrivilege, bool)> + '_ {
+        self.table.iter().map(|(privilege, grantable)| (*privilege, *grantable))
+    }
+
+    /// Iterates over the normalized names of the columns the privilege is held
+    /// on without being held on the whole table.
+    ///
+    /// # Arguments
+    ///
+    /// * `privilege` - The privilege to look up.
+    pub fn columns_with(&self, privilege: TablePrivilege) -> impl Iterator<Item = &str> + '_ {
+        let table_wide = self.has(privilege);
+        self.columns
+            .iter()
+            .filter(move |(_, privileges)| !table_wide && privileges.contains_key(&privilege))
+            .map(|(column, _)| column.as_str())
+    }
+}
+
+/// Returns the provided role along with the roles whose privileges it
+/// inherits, directly or transitively.
+fn inherited_roles<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    role: &'db DB::Role,
+) -> Vec<&'db DB::Role> {
+    let mut roles = vec![role];
+    let mut pending = vec![role];
+    while let Some(current) = pending.pop() {
+        if !current.inherits() {
+            continue;
+        }
+        for parent in current.member_of(database) {
+            if !roles.contains(&parent) {
+                roles.push(parent);
+                pending.push(parent);
+            }
+        }
+    }
+    roles
+}
+
+/// Records the privileges a grant confers, on the whole table or on the
+/// columns its actions are restricted to.
+fn insert_grant<G: GrantLike>(privileges: &mut PrivilegeSet, grant: &G, database: &G::DB) {
+    let grantable = grant.with_grant_option();
+    if grant.is_all_privileges() {
+        for privilege in TablePrivilege::ALL {
+            privileges.insert(None, privilege, grantable);
+        }
+        return;
+    }
+    for (privilege, columns) in grant.privileges(database).filter_map(TablePrivilege::from_action) {
+        match columns {
+            Some(columns) => {
+                for column in columns {
+                    privileges.insert(Some(column), privilege, grantable);
+                }
+            }
+            None => privileges.insert(None, privilege, grantable),
+        }
+    }
+}
+
+/// Resolves the privileges the role effectively holds on the table.
+pub(crate) fn effective_privileges<DB: DatabaseLike>(
+    database: &DB,
+    role: &DB::Role,
+    table: &DB::Table,
+) -> PrivilegeSet {
+    let roles = inherited_roles(database, role);
+
+    let mut privileges =
+        PrivilegeSet { casing: database.dialect().identifier_casing(), ..PrivilegeSet::default() };
+    for grant in database.table_grants() {
+        if grant.applies_to_table(table, database)
+            && roles.iter().any(|role| grant.applies_to_role(role, database))
+        {
+            insert_grant(&mut privileges, grant, database);
+        }
+    }
+    for grant in database.column_grants() {
+        if grant.table(database).is_some_and(|granted| granted == table)
+            && roles.iter().any(|role| grant.applies_to_role(role, database))
+        {
+            insert_grant(&mut privileges, grant, database);
+        }
+    }
+    for default_privilege in table.default_privileges(database) {
+        if roles.iter().any(|role| default_privilege.applies_to_role(role, database)) {
+            for privilege in default_privilege.privileges() {
+                privileges.insert(None, privilege, default_privilege.is_grantable());
+            }
+        }
+    }
+    privileges
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::TablePrivilege;
+    use crate::{structs::ParserDB, traits::DatabaseLike};
+
+    fn parse(sql: &str) -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse")
+    }
+
+    #[test]
+    fn test_all_privileges_with_grant_option() {
+        let db = parse(
+            "
+            CREATE ROLE owner;
+            CREATE TABLE t (id INT);
+            GRANT ALL PRIVILEGES ON t TO owner WITH GRANT OPTION;
+            ",
+        );
+        let privileges =
+            db.effective_privileges(db.role("owner").unwrap(), db.table(None, "t").unwrap());
+        for privilege in TablePrivilege::ALL {
+            assert!(privileges.is_grantable(privilege), "{privilege} is not grantable");
+        }
+    }
+
+    #[test]
+    fn test_membership_propagates_privileges_and_grant_option() {
+        let db = parse(
+            "
+            CREATE ROLE admins;
+            CREATE ROLE staff IN ROLE admins;
+            CREATE ROLE alice IN ROLE staff;
+            CREATE ROLE bob NOINHERIT IN ROLE staff;
+            CREATE TABLE t (id INT, secret TEXT);
+            GRANT SELECT ON t TO staff;
+            GRANT INSERT ON t TO admins WITH GRANT OPTION;
+            GRANT UPDATE (id) ON t TO alice;
+            ",
+        );
+        let table = db.table(None, "t").unwrap();
+
+        let alice = db.effective_privileges(db.role("alice").unwrap(), table);
+        assert_eq!(
+            alice.privileges().collect::<Vec<_>>(),
+            [(TablePrivilege::Select, false), (TablePrivilege::Insert, true)]
+        );
+        assert!(alice.has_column("ID", false, TablePrivilege::Update));
+        assert!(!alice.has_column("secret", false, TablePrivilege::Update));
+        assert_eq!(alice.columns_with(TablePrivilege::Update).collect::<Vec<_>>(), ["id"]);
+
+        let bob = db.effective_privileges(db.role("bob").unwrap(), table);
+        assert!(bob.is_empty());
+    }
+
+    #[test]
+    fn test_default_privileges_apply_to_later_tables() {
+        let db = parse(
+            "
+            CREATE ROLE reader;
+            CREATE ROLE writer;
+            CREATE SCHEMA app;
+            CREATE TABLE existing (id INT);
+            ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+            ALTER DEFAULT PRIVILEGES GRANT INSERT, DELETE ON TABLES TO writer WITH GRANT OPTION;
+            CREATE TABLE created (id INT);
+            CREATE TABLE app.other (id INT);
+            ALTER DEFAULT PRIVILEGES REVOKE DELETE ON TABLES FROM writer;
+            ALTER DEFAULT PRIVILEGES REVOKE GRANT OPTION FOR INSERT ON TABLES FROM writer;
+            CREATE TABLE latest (id INT);
+            ",
+        );
+        let reader = db.role("reader").unwrap();
+        let writer = db.role("writer").unwrap();
+
+        let existing = db.table(None, "existing").unwrap();
+        assert!(db.effective_privileges(reader, existing).is_empty());
+        assert!(db.effective_privileges(writer, existing).is_empty());
+
+        let created = db.table(None, "created").unwrap();
+        assert!(db.effective_privileges(reader, created).has(TablePrivilege::Select));
+        let written = db.effective_privileges(writer, created);
+        assert!(written.is_grantable(TablePrivilege::Insert));
+        assert!(written.is_grantable(TablePrivilege::Delete));
+
+        let other = db.table(Some("app"), "other").unwrap();
+        assert!(db.effective_privileges(reader, other).is_empty());
+        assert!(db.effective_privileges(writer, other).has(TablePrivilege::Insert));
+
+        let latest = db.effective_privileges(writer, db.table(None, "latest").unwrap());
+        assert_eq!(latest.privileges().collect::<Vec<_>>(), [(TablePrivilege::Insert, false)]);
+    }
+
+    #[test]
+    fn test_column_privileges_follow_identifier_casing() {
+        let db = parse(
+            r#"
+            CREATE ROLE support;
+            CREATE TABLE t ("Email" TEXT, name TEXT);
+            GRANT SELECT ("Email", NAME) ON t TO support;
+            "#,
+        );
+        let privileges =
+            db.effective_privileges(db.role("support").unwrap(), db.table(None, "t").unwrap());
+        assert!(privileges.has_column("Email", true, TablePrivilege::Select));
+        assert!(!privileges.has_column("Email", false, TablePrivilege::Select));
+        assert!(privileges.has_column("Name", false, TablePrivilege::Select));
+        assert!(!privileges.has_column("Name", true, TablePrivilege::Select));
+
+        let db = ParserDB::parse::<MySqlDialect>(
+            "
+            CREATE ROLE support;
+            CREATE TABLE t (`Email` TEXT);
+            GRANT SELECT (`EMAIL`) ON t TO support;
+            ",
+        )
+        .expect("Failed to parse");
+        let privileges =
+            db.effective_privileges(db.role("support").unwrap(), db.table(None, "t").unwrap());
+        assert!(privileges.has_column("email", true, TablePrivilege::Select));
+    }
+
+    #[test]
+    fn test_public_grants_apply_to_every_role() {
+        let db = parse(
+            "
+            CREATE ROLE anyone;
+            CREATE TABLE t (id INT);
+            CREATE TABLE other (id INT);
+            GRANT SELECT, TRUNCATE ON t TO PUBLIC;
+            ",
+        );
+        let anyone = db.role("anyone").unwrap();
+        let privileges = db.effective_privileges(anyone, db.table(None, "t").unwrap());
+        assert!(privileges.has(TablePrivilege::Truncate));
+        assert!(!privileges.is_grantable(TablePrivilege::Select));
+        assert!(db.effective_privileges(anyone, db.table(None, "other").unwrap()).is_empty());
+    }
+}
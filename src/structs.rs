@@ -1,11 +1,86 @@
 //! Submodule providing general structs for representing database schemas.
 
+mod anonymize_options;
+mod builtin_registry;
+mod constraint_examples;
+mod custom_type;
+mod default_privilege;
+mod dialect_warning;
+mod expression_analysis;
+mod extension;
+mod extension_hierarchy;
+mod extension_strategy;
 pub(crate) mod fingerprint;
+mod function_dependency_graph;
 pub mod generic_db;
 pub use generic_db::{GenericDB, ParserDB, ParserDBBuilder};
+mod identifier_casing;
 pub mod metadata;
+mod name_registry;
+mod object_filter;
+mod parse_report;
+mod parser_options;
+mod policy_coverage;
+mod qualified_name;
+mod role_report;
+mod role_view;
+#[cfg(feature = "std")]
+mod rollback_check;
 mod schema;
+#[cfg(feature = "serde")]
+mod schema_snapshot;
+mod semantic_kind;
+mod sequence;
+mod source_location;
+mod statement_handlers;
+mod table_dependency_graph;
+mod trigger_cascade_graph;
+mod type_normalizer;
+mod validation_warning;
+mod write_path_summary;
 
+pub use anonymize_options::AnonymizeOptions;
+pub use builtin_registry::{BuiltinFunction, BuiltinRegistry};
+pub use constraint_examples::{
+    ConstraintExamples, ConstraintViolation, ExampleRow, ExampleValue, ViolationKind,
+};
+pub use custom_type::{CustomType, CustomTypeDefinition};
+pub use default_privilege::DefaultPrivilege;
+pub use dialect_warning::{DialectCapability, DialectWarning};
+pub use expression_analysis::{ExpressionAnalysis, TypeMismatch};
+pub use extension::Extension;
+pub use extension_hierarchy::{ExtensionDiamond, ExtensionHierarchy};
+pub use extension_strategy::{
+    EXTENDS_MARKER, ExtensionCandidate, ExtensionPredicate, ExtensionStrategy,
+};
 pub use fingerprint::{AlgorithmId, FingerprintError, SchemaFingerprint, canonical_bytes_v1};
+pub use function_dependency_graph::FunctionDependencyGraph;
+pub use identifier_casing::IdentifierCasing;
 pub use metadata::{TableAttribute, TableMetadata};
+pub use name_registry::{NameRegistry, RegisteredName};
+pub use object_filter::ObjectFilter;
+pub use parse_report::ParseReport;
+pub use parser_options::{ParserOptions, ValidationClass, ValidationLevel};
+pub use policy_coverage::{POLICY_COMMANDS, PolicyCoverage};
+pub use qualified_name::QualifiedName;
+pub use role_report::RoleReport;
+pub use role_view::{RoleView, TableView};
+#[cfg(feature = "std")]
+pub use rollback_check::{RollbackMismatch, RollbackSeverity};
+#[cfg(feature = "std")]
+pub(crate) use rollback_check::{rollback_mismatches, search_down_files};
 pub use schema::Schema;
+#[cfg(feature = "serde")]
+pub use schema_snapshot::{
+    ColumnSnapshot, ForeignKeySnapshot, FunctionSnapshot, IndexSnapshot, SNAPSHOT_FORMAT_VERSION,
+    SchemaSnapshot, TableSnapshot,
+};
+pub use semantic_kind::{SemanticKind, SemanticRules};
+pub use sequence::Sequence;
+pub use source_location::SourceLocation;
+pub use statement_handlers::{StatementHandler, StatementHandlers};
+pub use table_dependency_graph::{TableCycle, TableDependencyGraph};
+pub use trigger_cascade_graph::TriggerCascadeGraph;
+pub use type_normalizer::{MySqlTypeNormalizer, PostgresTypeNormalizer, SqliteTypeNormalizer};
+pub use validation_warning::ValidationWarning;
+pub use write_path_summary::{FiredTrigger, WritePath, WritePathSummary};
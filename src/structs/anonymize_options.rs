@@ -0,0 +1,61 @@
+//! Submodule defining the `AnonymizeOptions` struct, controlling how a
+//! database is anonymized.
+
+/// Options controlling how [`crate::structs::ParserDB::anonymize`] rewrites a
+/// database.
+///
+/// By default, identifiers are replaced by pseudonyms and string literals are
+/// redacted. Documentation is always stripped.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::AnonymizeOptions;
+///
+/// let options = AnonymizeOptions::default().keep_literals();
+/// assert!(options.renames_identifiers());
+/// assert!(!options.redacts_literals());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnonymizeOptions {
+    /// Whether identifiers are replaced by pseudonyms.
+    rename_identifiers: bool,
+    /// Whether string literals are redacted.
+    redact_literals: bool,
+}
+
+impl Default for AnonymizeOptions {
+    fn default() -> Self {
+        Self { rename_identifiers: true, redact_literals: true }
+    }
+}
+
+impl AnonymizeOptions {
+    /// Keeps the identifiers as they are written.
+    #[must_use]
+    pub fn keep_identifiers(mut self) -> Self {
+        self.rename_identifiers = false;
+        self
+    }
+
+    /// Keeps the string literals as they are written.
+    #[must_use]
+    pub fn keep_literals(mut self) -> Self {
+        self.redact_literals = false;
+        self
+    }
+
+    /// Returns whether identifiers are replaced by pseudonyms.
+    #[must_use]
+    #[inline]
+    pub fn renames_identifiers(self) -> bool {
+        self.rename_identifiers
+    }
+
+    /// Returns whether string literals are redacted.
+    #[must_use]
+    #[inline]
+    pub fn redacts_literals(self) -> bool {
+        self.redact_literals
+    }
+}
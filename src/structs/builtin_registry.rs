@@ -0,0 +1,231 @@
+//! Submodule defining the `BuiltinRegistry` struct, holding the signatures
+//! of the built-in functions a database resolves the calls of.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::{DataType, ExactNumberInfo, Ident, ObjectName, ObjectNamePart, TimezoneInfo};
+
+use crate::impls::SqlparserDialect;
+
+/// Signature of a built-in function, as registered in a [`BuiltinRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuiltinFunction {
+    /// Name of the function.
+    name: String,
+    /// Data types of the arguments of the function.
+    arguments: Vec<DataType>,
+    /// Whether the last argument may be repeated any number of times.
+    variadic: bool,
+    /// Data type returned by the function.
+    return_type: DataType,
+}
+
+impl BuiltinFunction {
+    /// Returns the name of the function.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the data types of the arguments of the function.
+    #[must_use]
+    pub fn arguments(&self) -> &[DataType] {
+        &self.arguments
+    }
+
+    /// Returns whether the last argument may be repeated any number of times.
+    #[must_use]
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    /// Returns the data type returned by the function.
+    #[must_use]
+    pub fn return_type(&self) -> &DataType {
+        &self.return_type
+    }
+}
+
+/// Registry of the built-in functions added to a database parsed from SQL,
+/// so that their calls within check constraints, defaults, policies and
+/// views resolve as any other function.
+///
+/// [`BuiltinRegistry::for_dialect`] provides the functions of a dialect,
+/// which are used unless a registry is set through
+/// [`ParserOptions::with_builtin_registry`]. Functions provided by
+/// extensions, such as those of PostGIS, or helper functions defined
+/// outside of the parsed SQL can be registered on top of them.
+///
+/// [`ParserOptions::with_builtin_registry`]: crate::structs::ParserOptions::with_builtin_registry
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{impls::SqlparserDialect, prelude::*};
+/// use sqlparser::{
+///     ast::{DataType, Ident, ObjectName, ObjectNamePart},
+///     dialect::PostgreSqlDialect,
+/// };
+///
+/// let geometry_name = ObjectName(vec![ObjectNamePart::Identifier(Ident::new("geometry"))]);
+/// let geometry = DataType::Custom(geometry_name, vec![]);
+/// let mut registry = BuiltinRegistry::for_dialect(SqlparserDialect::PostgreSql);
+/// registry.register("st_distance", [geometry.clone(), geometry], DataType::DoublePrecision);
+///
+/// let db = ParserDB::parse_with_options::<PostgreSqlDialect>(
+///     "CREATE TABLE t (id INT);",
+///     ParserOptions::default().with_builtin_registry(registry),
+/// )?;
+/// assert!(db.function("st_distance").is_some());
+/// assert!(db.function("now").is_some());
+/// // `len` is a SQL Server function.
+/// assert!(db.function("len").is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BuiltinRegistry {
+    /// Registered functions, in registration order.
+    functions: Vec<BuiltinFunction>,
+}
+
+impl BuiltinRegistry {
+    /// Returns the registry of the built-in functions of the provided
+    /// dialect.
+    ///
+    /// Every dialect provides the standard string, date and time, aggregate
+    /// and session functions. `len` is specific to SQL Server, and the UUID
+    /// generators `gen_random_uuid`, `uuidv4` and `uuidv7` to `PostgreSQL`.
+    /// The generic dialect provides every function.
+    #[must_use]
+    pub fn for_dialect(dialect: SqlparserDialect) -> Self {
+        let any = Self::any_type();
+        let mut registry = Self::default();
+        registry
+            .register("length", [DataType::Text], DataType::Int(None))
+            .register("char_length", [DataType::Text], DataType::Int(None))
+            .register("character_length", [DataType::Text], DataType::Int(None))
+            .register("octet_length", [DataType::Text], DataType::Int(None))
+            .register_variadic("coalesce", any.clone(), any.clone())
+            .register("nullif", [any.clone(), any.clone()], any.clone())
+            .register("now", [], DataType::Timestamp(None, TimezoneInfo::WithTimeZone))
+            .register(
+                "current_timestamp",
+                [],
+                DataType::Timestamp(None, TimezoneInfo::WithTimeZone),
+            )
+            .register("current_date", [], DataType::Date)
+            .register("current_time", [], DataType::Time(None, TimezoneInfo::WithTimeZone))
+            .register("localtimestamp", [], DataType::Timestamp(None, TimezoneInfo::None))
+            .register("localtime", [], DataType::Time(None, TimezoneInfo::None))
+            .register("count", [any.clone()], DataType::BigInt(None))
+            .register("sum", [any.clone()], DataType::Numeric(ExactNumberInfo::None))
+            .register("avg", [any.clone()], DataType::Numeric(ExactNumberInfo::None))
+            .register("min", [any.clone()], any.clone())
+            .register("max", [any.clone()], any)
+            .register("current_user", [], DataType::Text)
+            .register("session_user", [], DataType::Text)
+            .register("user", [], DataType::Text);
+        if matches!(dialect, SqlparserDialect::MsSql | SqlparserDialect::Generic) {
+            registry.register("len", [DataType::Text], DataType::Int(None));
+        }
+        if matches!(dialect, SqlparserDialect::PostgreSql | SqlparserDialect::Generic) {
+            registry
+                .register("gen_random_uuid", [], DataType::Uuid)
+                .register("uuidv4", [], DataType::Uuid)
+                .register("uuidv7", [], DataType::Uuid)
+                .register(
+                    "uuidv7",
+                    [DataType::Interval { fields: None, precision: None }],
+                    DataType::Uuid,
+                );
+        }
+        registry
+    }
+
+    /// Returns the pseudo data type `"any"`, accepting and returning values
+    /// of any data type, as used by polymorphic functions such as `coalesce`.
+    #[must_use]
+    pub fn any_type() -> DataType {
+        DataType::Custom(
+            ObjectName(vec![ObjectNamePart::Identifier(Ident::with_quote('"', "any"))]),
+            vec![],
+        )
+    }
+
+    /// Registers a function, alongside any function of the same name
+    /// registered with other arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    /// * `arguments` - The data types of its arguments.
+    /// * `return_type` - The data type it returns.
+    pub fn register(
+        &mut self,
+        name: &str,
+        arguments: impl IntoIterator<Item = DataType>,
+        return_type: DataType,
+    ) -> &mut Self {
+        self.functions.push(BuiltinFunction {
+            name: String::from(name),
+            arguments: arguments.into_iter().collect(),
+            variadic: false,
+            return_type,
+        });
+        self
+    }
+
+    /// Registers a function taking any number of arguments of the provided
+    /// data type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function.
+    /// * `argument` - The data type of its arguments.
+    /// * `return_type` - The data type it returns.
+    pub fn register_variadic(
+        &mut self,
+        name: &str,
+        argument: DataType,
+        return_type: DataType,
+    ) -> &mut Self {
+        self.functions.push(BuiltinFunction {
+            name: String::from(name),
+            arguments: vec![argument],
+            variadic: true,
+            return_type,
+        });
+        self
+    }
+
+    /// Returns the registered functions, in registration order.
+    pub fn functions(&self) -> impl Iterator<Item = &BuiltinFunction> {
+        self.functions.iter()
+    }
+
+    /// Returns whether a function of the provided name is registered.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.iter().any(|function| function.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins_are_scoped_by_dialect() {
+        let postgres = BuiltinRegistry::for_dialect(SqlparserDialect::PostgreSql);
+        let mssql = BuiltinRegistry::for_dialect(SqlparserDialect::MsSql);
+        let generic = BuiltinRegistry::for_dialect(SqlparserDialect::Generic);
+        assert!(postgres.contains("gen_random_uuid") && !postgres.contains("len"));
+        assert!(mssql.contains("len") && !mssql.contains("uuidv7"));
+        assert!(generic.contains("len") && generic.contains("uuidv7"));
+        assert!(postgres.contains("coalesce") && mssql.contains("coalesce"));
+        assert_eq!(generic.functions().filter(|function| function.name() == "uuidv7").count(), 2);
+        assert!(generic.functions().any(BuiltinFunction::is_variadic));
+    }
+}
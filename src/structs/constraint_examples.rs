@@ -0,0 +1,599 @@
+//! Submodule defining the `ConstraintExamples` struct, generating example rows
+//! which satisfy or violate the constraints of a table.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+use sqlparser::ast::{BinaryOperator, Expr, UnaryOperator, Value};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{CheckConstraintLike, ColumnLike, DatabaseLike, DialectLike, TableLike},
+};
+
+/// A value of an example row.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExampleValue {
+    /// The `NULL` value.
+    Null,
+    /// A boolean value.
+    Boolean(bool),
+    /// An integer value.
+    Integer(i64),
+    /// A textual value.
+    Text(String),
+}
+
+impl Display for ExampleValue {
+    /// Formats the value as a SQL literal.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => write!(f, "NULL"),
+            Self::Boolean(true) => write!(f, "TRUE"),
+            Self::Boolean(false) => write!(f, "FALSE"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "'{}'", value.replace('\'', "''")),
+        }
+    }
+}
+
+/// An example row, mapping the names of the columns to their values.
+pub type ExampleRow = BTreeMap<String, ExampleValue>;
+
+/// The kind of constraint an example row violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// A `NULL` value in a `NOT NULL` column.
+    NotNull,
+    /// A text longer than the upper bound of a length check constraint.
+    TextTooLong,
+    /// A text shorter than the lower bound of a length check constraint.
+    TextTooShort,
+    /// An empty text in a column checked to be not empty.
+    EmptyText,
+    /// A number outside of the range allowed by a check constraint.
+    OutOfRange,
+    /// A value outside of the list allowed by a check constraint.
+    DisallowedValue,
+}
+
+/// An example row violating a single constraint of a table.
+#[derive(Debug, Clone)]
+pub struct ConstraintViolation<'db, DB: DatabaseLike> {
+    /// The column holding the violating value.
+    column: &'db DB::Column,
+    /// The violated check constraint, if the violation concerns one.
+    check_constraint: Option<&'db DB::CheckConstraint>,
+    /// The kind of the violation.
+    kind: ViolationKind,
+    /// The violating row.
+    row: ExampleRow,
+}
+
+impl<'db, DB: DatabaseLike> ConstraintViolation<'db, DB> {
+    /// Returns the column holding the violating value.
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> &'db DB::Column {
+        self.column
+    }
+
+    /// Returns the violated check constraint, if the violation concerns one.
+    #[must_use]
+    #[inline]
+    pub fn check_constraint(&self) -> Option<&'db DB::CheckConstraint> {
+        self.check_constraint
+    }
+
+    /// Returns the kind of the violation.
+    #[must_use]
+    #[inline]
+    pub fn kind(&self) -> ViolationKind {
+        self.kind
+    }
+
+    /// Returns the violating row.
+    #[must_use]
+    #[inline]
+    pub fn row(&self) -> &ExampleRow {
+        &self.row
+    }
+
+    /// Returns the violating value.
+    #[must_use]
+    pub fn value(&self) -> &ExampleValue {
+        &self.row[self.column.column_name()]
+    }
+}
+
+/// Example rows of a table, one satisfying all of its constraints and one
+/// per violated constraint.
+///
+/// The examples compose the analyses of the columns and check constraints of
+/// the table: `NOT NULL` columns, text length bounds, not-empty texts,
+/// integer ranges (comparisons with integer literals and `BETWEEN`) and
+/// allowed value lists (`IN`). Each violating row differs from the
+/// satisfying row in the value of a single column. Generated columns are
+/// left out of the rows, and values of types the generator cannot produce
+/// are `NULL` or empty texts, so the satisfying row is a best effort.
+#[derive(Debug, Clone)]
+pub struct ConstraintExamples<'db, DB: DatabaseLike> {
+    /// The table the examples are about.
+    table: &'db DB::Table,
+    /// The row satisfying all of the constraints.
+    valid_row: ExampleRow,
+    /// The rows violating a single constraint.
+    violations: Vec<ConstraintViolation<'db, DB>>,
+}
+
+/// Inclusive range of the integers allowed in a column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct IntegerRange {
+    /// The minimum allowed value, if any.
+    min: Option<i64>,
+    /// The maximum allowed value, if any.
+    max: Option<i64>,
+}
+
+impl IntegerRange {
+    /// Returns the intersection of the two ranges.
+    fn intersect(self, other: Self) -> Self {
+        Self {
+            min: self.min.max(other.min),
+            max: match (self.max, other.max) {
+                (Some(left), Some(right)) => Some(left.min(right)),
+                (left, right) => left.or(right),
+            },
+        }
+    }
+}
+
+/// Returns whether the expression is the provided column.
+fn is_column<C: ColumnLike>(expr: &Expr, column: &C, casing: IdentifierCasing) -> bool {
+    match expr {
+        Expr::Identifier(ident) => casing.matches(
+            column.column_name(),
+            column.column_name_is_quoted(),
+            &ident.value,
+            ident.quote_style.is_some(),
+        ),
+        Expr::Nested(inner) => is_column(inner, column, casing),
+        _ => false,
+    }
+}
+
+/// Returns the value of a literal expression.
+fn literal_value(expr: &Expr) -> Option<ExampleValue> {
+    match expr {
+        Expr::Value(value) => match &value.value {
+            Value::Number(number, _) => number.parse().ok().map(ExampleValue::Integer),
+            Value::SingleQuotedString(text) => Some(ExampleValue::Text(text.clone())),
+            Value::Boolean(boolean) => Some(ExampleValue::Boolean(*boolean)),
+            _ => None,
+        },
+        Expr::UnaryOp { op: UnaryOperator::Minus, expr } => match literal_value(expr)? {
+            ExampleValue::Integer(value) => value.checked_neg().map(ExampleValue::Integer),
+            _ => None,
+        },
+        Expr::Nested(inner) | Expr::Cast { expr: inner, .. } => literal_value(inner),
+        _ => None,
+    }
+}
+
+/// Returns the value of an integer literal expression.
+fn integer_literal(expr: &Expr) -> Option<i64> {
+    match literal_value(expr)? {
+        ExampleValue::Integer(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Returns the range enforced on the column by a `column <op> value`
+/// comparison.
+fn comparison_range(op: &BinaryOperator, value: i64) -> Option<IntegerRange> {
+    Some(match op {
+        BinaryOperator::Gt => IntegerRange { min: value.checked_add(1), max: None },
+        BinaryOperator::GtEq => IntegerRange { min: Some(value), max: None },
+        BinaryOperator::Lt => IntegerRange { min: None, max: value.checked_sub(1) },
+        BinaryOperator::LtEq => IntegerRange { min: None, max: Some(value) },
+        BinaryOperator::Eq => IntegerRange { min: Some(value), max: Some(value) },
+        _ => return None,
+    })
+}
+
+/// Returns the integer range the expression enforces on the column, if any.
+fn integer_range<C: ColumnLike>(
+    expr: &Expr,
+    column: &C,
+    casing: IdentifierCasing,
+) -> Option<IntegerRange> {
+    match expr {
+        Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+            match (integer_range(left, column, casing), integer_range(right, column, casing)) {
+                (Some(left), Some(right)) => Some(left.intersect(right)),
+                (left, right) => left.or(right),
+            }
+        }
+        Expr::BinaryOp { left, op, right } => {
+            if is_column(left, column, casing) {
+                comparison_range(op, integer_literal(right)?)
+            } else if is_column(right, column, casing) {
+                let swapped = match op {
+                    BinaryOperator::Gt => BinaryOperator::Lt,
+                    BinaryOperator::GtEq => BinaryOperator::LtEq,
+                    BinaryOperator::Lt => BinaryOperator::Gt,
+                    BinaryOperator::LtEq => BinaryOperator::GtEq,
+                    other => other.clone(),
+                };
+                comparison_range(&swapped, integer_literal(left)?)
+            } else {
+                None
+            }
+        }
+        Expr::Between { expr, negated: false, low, high } if is_column(expr, column, casing) => {
+            Some(IntegerRange { min: integer_literal(low), max: integer_literal(high) })
+        }
+        Expr::Nested(inner) => integer_range(inner, column, casing),
+        _ => None,
+    }
+}
+
+/// Returns the values the expression allows in the column, if it is an `IN`
+/// list of literals.
+fn allowed_values<C: ColumnLike>(
+    expr: &Expr,
+    column: &C,
+    casing: IdentifierCasing,
+) -> Option<Vec<ExampleValue>> {
+    match expr {
+        Expr::InList { expr, list, negated: false } if is_column(expr, column, casing) => {
+            list.iter().map(literal_value).collect()
+        }
+        Expr::Nested(inner) => allowed_values(inner, column, casing),
+        _ => None,
+    }
+}
+
+/// Returns a value not contained in the provided list of allowed values.
+fn disallowed_value(allowed: &[ExampleValue]) -> ExampleValue {
+    let max_integer = allowed
+        .iter()
+        .filter_map(|value| match value {
+            ExampleValue::Integer(value) => Some(*value),
+            _ => None,
+        })
+        .max();
+    if let Some(value) = max_integer.and_then(|max| max.checked_add(1)) {
+        return ExampleValue::Integer(value);
+    }
+    let mut text = String::from("?");
+    while allowed.contains(&ExampleValue::Text(text.clone())) {
+        text.push('?');
+    }
+    ExampleValue::Text(text)
+}
+
+/// Returns a text of the provided length.
+fn text_of_length(length: usize) -> ExampleValue {
+    ExampleValue::Text("a".repeat(length))
+}
+
+/// Constraints collected on a single column.
+struct ColumnConstraints<'db, DB: DatabaseLike> {
+    /// The exclusive upper bound of the text length, with its constraint.
+    max_length: Option<(usize, &'db DB::CheckConstraint)>,
+    /// The inclusive lower bound of the text length, with its constraint.
+    min_length: Option<(usize, &'db DB::CheckConstraint)>,
+    /// The constraint requiring the text not to be empty.
+    not_empty: Option<&'db DB::CheckConstraint>,
+    /// The allowed integer range, with its constraints.
+    ranges: Vec<(IntegerRange, &'db DB::CheckConstraint)>,
+    /// The allowed values, with their constraint.
+    allowed: Option<(Vec<ExampleValue>, &'db DB::CheckConstraint)>,
+}
+
+impl<'db, DB: DatabaseLike> ColumnConstraints<'db, DB> {
+    /// Collects the single-column check constraints of the column.
+    fn new(database: &'db DB, column: &'db DB::Column) -> Self {
+        let mut constraints = Self {
+            max_length: None,
+            min_length: None,
+            not_empty: None,
+            ranges: Vec::new(),
+            allowed: None,
+        };
+        let casing = database.dialect().identifier_casing();
+        for check in column.check_constraints(database) {
+            if check.number_of_columns(database) != 1 || check.is_tautology(database) {
+                continue;
+            }
+            let expr = check.expression(database);
+            if let Some(bound) = check.is_upper_bounded_text_constraint(database)
+                && constraints.max_length.is_none_or(|(current, _)| bound < current)
+            {
+                constraints.max_length = Some((bound, check));
+            }
+            if let Some(bound) = check.is_lower_bounded_text_constraint(database)
+                && constraints.min_length.is_none_or(|(current, _)| bound > current)
+            {
+                constraints.min_length = Some((bound, check));
+            }
+            if constraints.not_empty.is_none() && check.is_not_empty_text_constraint(database) {
+                constraints.not_empty = Some(check);
+            }
+            if let Some(range) = integer_range(expr, column, casing) {
+                constraints.ranges.push((range, check));
+            }
+            if constraints.allowed.is_none()
+                && let Some(values) = allowed_values(expr, column, casing)
+                && !values.is_empty()
+            {
+                constraints.allowed = Some((values, check));
+            }
+        }
+        constraints
+    }
+
+    /// Returns the intersection of the allowed integer ranges.
+    fn range(&self) -> IntegerRange {
+        self.ranges
+            .iter()
+            .fold(IntegerRange::default(), |range, (other, _)| range.intersect(*other))
+    }
+
+    /// Returns a value of the column satisfying the collected constraints.
+    fn valid_value(&self, database: &DB, column: &DB::Column) -> ExampleValue {
+        if let Some((values, _)) = &self.allowed {
+            return values[0].clone();
+        }
+        if column.is_textual(database) {
+            let min_length = self.min_length.map_or(0, |(bound, _)| bound);
+            let min_length = if self.not_empty.is_some() { min_length.max(1) } else { min_length };
+            return text_of_length(min_length.max(1));
+        }
+        match column.normalized_data_type(database) {
+            "boolean" => ExampleValue::Boolean(true),
+            "SMALLINT" | "INT" | "BIGINT" | "TINYINT" | "MEDIUMINT" | "INT2" | "INT4" | "INT8"
+            | "numeric" | "real" | "double precision" => {
+                let range = self.range();
+                ExampleValue::Integer(match (range.min, range.max) {
+                    (Some(min), _) => min,
+                    (None, Some(max)) => max.min(1),
+                    (None, None) => 1,
+                })
+            }
+            "UUID" => ExampleValue::Text("00000000-0000-0000-0000-000000000000".to_string()),
+            "date" => ExampleValue::Text("2000-01-01".to_string()),
+            "timestamp without time zone" | "timestamp with time zone" => {
+                ExampleValue::Text("2000-01-01 00:00:00".to_string())
+            }
+            _ if column.is_nullable(database) => ExampleValue::Null,
+            _ => ExampleValue::Text(String::new()),
+        }
+    }
+
+    /// Returns the values of the column violating the collected check
+    /// constraints.
+    fn violations(&self) -> Vec<(ExampleValue, ViolationKind, &'db DB::CheckConstraint)> {
+        let mut violations = Vec::new();
+        if let Some((bound, check)) = self.max_length {
+            violations.push((text_of_length(bound), ViolationKind::TextTooLong, check));
+        }
+        if let Some((bound, check)) = self.min_length
+            && bound > 0
+        {
+            violations.push((text_of_length(bound - 1), ViolationKind::TextTooShort, check));
+        }
+        if let Some(check) = self.not_empty {
+            violations.push((text_of_length(0), ViolationKind::EmptyText, check));
+        }
+        for (range, check) in &self.ranges {
+            let below = range.min.and_then(|min| min.checked_sub(1));
+            let above = range.max.and_then(|max| max.checked_add(1));
+            for value in below.into_iter().chain(above) {
+                violations.push((ExampleValue::Integer(value), ViolationKind::OutOfRange, *check));
+            }
+        }
+        if let Some((values, check)) = &self.allowed {
+            violations.push((disallowed_value(values), ViolationKind::DisallowedValue, *check));
+        }
+        violations
+    }
+}
+
+impl<'db, DB: DatabaseLike> ConstraintExamples<'db, DB> {
+    /// Generates the example rows of the provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the table belongs to.
+    /// * `table` - The table to generate the examples for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE products (
+    ///         name TEXT NOT NULL CHECK (length(name) <= 5),
+    ///         stock INT CHECK (stock BETWEEN 0 AND 10),
+    ///         status TEXT CHECK (status IN ('draft', 'published'))
+    ///     );",
+    /// )?;
+    /// let examples = ConstraintExamples::new(&db, db.table(None, "products").unwrap());
+    ///
+    /// assert_eq!(examples.valid_row()["name"].to_string(), "'a'");
+    /// assert_eq!(examples.valid_row()["stock"].to_string(), "0");
+    /// assert_eq!(examples.valid_row()["status"].to_string(), "'draft'");
+    ///
+    /// let kinds: Vec<ViolationKind> =
+    ///     examples.violations().iter().map(ConstraintViolation::kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         ViolationKind::NotNull,
+    ///         ViolationKind::TextTooLong,
+    ///         ViolationKind::OutOfRange,
+    ///         ViolationKind::OutOfRange,
+    ///         ViolationKind::DisallowedValue,
+    ///     ]
+    /// );
+    /// assert_eq!(examples.violations()[1].value().to_string(), "'aaaaaa'");
+    /// assert_eq!(examples.violations()[3].value().to_string(), "11");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, table: &'db DB::Table) -> Self {
+        let columns: Vec<(&'db DB::Column, ColumnConstraints<'db, DB>)> = table
+            .columns(database)
+            .filter(|column| !column.is_generated())
+            .map(|column| (column, ColumnConstraints::new(database, column)))
+            .collect();
+
+        let valid_row: ExampleRow = columns
+            .iter()
+            .map(|(column, constraints)| {
+                (column.column_name().to_string(), constraints.valid_value(database, column))
+            })
+            .collect();
+
+        let mut violations = Vec::new();
+        for (column, constraints) in &columns {
+            let column = *column;
+            let mut violate = |value, kind, check_constraint| {
+                let mut row = valid_row.clone();
+                row.insert(column.column_name().to_string(), value);
+                violations.push(ConstraintViolation { column, check_constraint, kind, row });
+            };
+            if !column.is_nullable(database) {
+                violate(ExampleValue::Null, ViolationKind::NotNull, None);
+            }
+            for (value, kind, check_constraint) in constraints.violations() {
+                violate(value, kind, Some(check_constraint));
+            }
+        }
+
+        Self { table, valid_row, violations }
+    }
+
+    /// Returns the table the examples are about.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns the row satisfying all of the constraints of the table.
+    #[must_use]
+    #[inline]
+    pub fn valid_row(&self) -> &ExampleRow {
+        &self.valid_row
+    }
+
+    /// Returns the rows violating a single constraint of the table, ordered
+    /// by column.
+    #[must_use]
+    #[inline]
+    pub fn violations(&self) -> &[ConstraintViolation<'db, DB>] {
+        &self.violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::GenericDialect;
+
+    use super::{ConstraintExamples, ExampleValue, ViolationKind};
+    use crate::{
+        structs::ParserDB,
+        traits::{ColumnLike, DatabaseLike},
+    };
+
+    #[test]
+    fn test_text_bounds_and_emptiness() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (
+                code TEXT CHECK (length(code) >= 3 AND length(code) < 6),
+                label TEXT CHECK (label <> '')
+            );",
+        )
+        .expect("Failed to parse");
+        let examples = ConstraintExamples::new(&db, db.table(None, "t").unwrap());
+
+        assert_eq!(examples.valid_row()["code"], ExampleValue::Text("aaa".to_string()));
+        assert_eq!(examples.valid_row()["label"], ExampleValue::Text("a".to_string()));
+
+        let violations: Vec<(&str, ViolationKind, String)> = examples
+            .violations()
+            .iter()
+            .map(|violation| {
+                (violation.column().column_name(), violation.kind(), violation.value().to_string())
+            })
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                ("code", ViolationKind::TextTooLong, "'aaaaaa'".to_string()),
+                ("code", ViolationKind::TextTooShort, "'aa'".to_string()),
+                ("label", ViolationKind::EmptyText, "''".to_string()),
+            ]
+        );
+        assert!(
+            examples.violations().iter().all(|violation| violation.check_constraint().is_some())
+        );
+    }
+
+    #[test]
+    fn test_integer_ranges_and_allowed_values() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (
+                id INT PRIMARY KEY,
+                priority INT NOT NULL CHECK (priority > -3),
+                level INT CHECK (10 >= level),
+                flag BOOLEAN,
+                tier INT CHECK (tier IN (1, 2, 3))
+            );",
+        )
+        .expect("Failed to parse");
+        let examples = ConstraintExamples::new(&db, db.table(None, "t").unwrap());
+
+        assert_eq!(examples.valid_row()["priority"], ExampleValue::Integer(-2));
+        assert_eq!(examples.valid_row()["level"], ExampleValue::Integer(1));
+        assert_eq!(examples.valid_row()["flag"], ExampleValue::Boolean(true));
+        assert_eq!(examples.valid_row()["tier"], ExampleValue::Integer(1));
+
+        let violations: Vec<(&str, ViolationKind, String)> = examples
+            .violations()
+            .iter()
+            .map(|violation| {
+                (violation.column().column_name(), violation.kind(), violation.value().to_string())
+            })
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                ("id", ViolationKind::NotNull, "NULL".to_string()),
+                ("priority", ViolationKind::NotNull, "NULL".to_string()),
+                ("priority", ViolationKind::OutOfRange, "-3".to_string()),
+                ("level", ViolationKind::OutOfRange, "11".to_string()),
+                ("tier", ViolationKind::DisallowedValue, "4".to_string()),
+            ]
+        );
+
+        // Each violating row only differs from the valid row in one column.
+        for violation in examples.violations() {
+            let differing = violation
+                .row()
+                .iter()
+                .filter(|(name, value)| examples.valid_row()[name.as_str()] != **value)
+                .count();
+            assert_eq!(differing, 1);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Custom type struct for storing parsed user-defined types.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::{DataType, Expr};
+
+/// Definition of a user-defined type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CustomTypeDefinition {
+    /// An enumeration, as created by `CREATE TYPE ... AS ENUM`, with its
+    /// labels in declaration order.
+    Enum(Vec<String>),
+    /// A domain, as created by `CREATE DOMAIN`.
+    Domain {
+        /// The underlying data type of the domain.
+        base_type: DataType,
+        /// The expressions of the CHECK constraints of the domain.
+        checks: Vec<Expr>,
+    },
+    /// Any other user-defined type, such as a composite type.
+    Other,
+}
+
+/// A user-defined type parsed from a CREATE TYPE or CREATE DOMAIN statement.
+///
+/// This struct stores the type name, its optional schema and its definition.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomType {
+    /// The name of the type.
+    name: String,
+    /// Whether the type identifier was quoted in SQL.
+    quoted: bool,
+    /// The schema of the type, with whether it was quoted in SQL.
+    schema: Option<(String, bool)>,
+    /// The definition of the type.
+    definition: CustomTypeDefinition,
+}
+
+impl CustomType {
+    /// Creates a new `CustomType` with the given name, quoted-name metadata
+    /// and definition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::{CustomType, CustomTypeDefinition};
+    ///
+    /// let t = CustomType::new(
+    ///     "mood".to_string(),
+    ///     false,
+    ///     CustomTypeDefinition::Enum(vec!["sad".to_string(), "happy".to_string()]),
+    /// );
+    /// assert_eq!(t.name(), "mood");
+    /// assert!(!t.is_quoted());
+    /// assert_eq!(t.schema(), None);
+    /// ```
+    #[must_use]
+    pub fn new(name: String, quoted: bool, definition: CustomTypeDefinition) -> Self {
+        Self { name, quoted, schema: None, definition }
+    }
+
+    /// Sets the schema of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::{CustomType, CustomTypeDefinition};
+    ///
+    /// let t = CustomType::new("mood".to_string(), false, CustomTypeDefinition::Other)
+    ///     .with_schema("app".to_string(), false);
+    /// assert_eq!(t.schema(), Some("app"));
+    /// assert!(!t.schema_is_quoted());
+    /// ```
+    #[must_use]
+    pub fn with_schema(mut self, schema: String, quoted: bool) -> Self {
+        self.schema = Some((schema, quoted));
+        self
+    }
+
+    /// Returns the name of the type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether this type name was quoted in SQL.
+    #[must_use]
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the schema of the type, if specified.
+    #[must_use]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_ref().map(|(schema, _)| schema.as_str())
+    }
+
+    /// Returns whether the schema of the type was quoted in SQL.
+    #[must_use]
+    pub fn schema_is_quoted(&self) -> bool {
+        self.schema.as_ref().is_some_and(|(_, quoted)| *quoted)
+    }
+
+    /// Returns the definition of the type.
+    #[must_use]
+    pub fn definition(&self) -> &CustomTypeDefinition {
+        &self.definition
+    }
+}
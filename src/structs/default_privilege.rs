@@ -0,0 +1,253 @@
+//! Default privilege struct for storing parsed `ALTER DEFAULT PRIVILEGES`
+//! statements.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+use sqlparser::ast::Ident;
+
+use crate::{privileges::TablePrivilege, structs::IdentifierCasing};
+
+/// Returns whether two identifiers designate the same object.
+fn idents_match(left: &Ident, right: &Ident, casing: IdentifierCasing) -> bool {
+    casing.matches(
+        left.value.as_str(),
+        left.quote_style.is_some(),
+        right.value.as_str(),
+        right.quote_style.is_some(),
+    )
+}
+
+/// Returns whether both lists designate the same objects, regardless of
+/// their order.
+fn same_idents(left: &[Ident], right: &[Ident], casing: IdentifierCasing) -> bool {
+    left.iter().all(|ident| right.iter().any(|other| idents_match(ident, other, casing)))
+        && right.iter().all(|ident| left.iter().any(|other| idents_match(ident, other, casing)))
+}
+
+/// Writes the identifiers separated by commas.
+fn write_idents(f: &mut fmt::Formatter<'_>, idents: &[Ident]) -> fmt::Result {
+    for (index, ident) in idents.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{ident}")?;
+    }
+    Ok(())
+}
+
+/// Privileges granted on the tables created in the future, as declared by
+/// `ALTER DEFAULT PRIVILEGES ... GRANT ... ON TABLES`.
+///
+/// The default privileges apply to the tables created by the target roles,
+/// or by the role running the statement when none is provided, in the listed
+/// schemas, or in any schema when none is provided. Only the default
+/// privileges on tables are tracked.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefaultPrivilege {
+    /// The roles of the `FOR ROLE` clause.
+    target_roles: Vec<Ident>,
+    /// The schemas of the `IN SCHEMA` clause.
+    schemas: Vec<Ident>,
+    /// The privileges granted, in declaration order.
+    privileges: Vec<TablePrivilege>,
+    /// The roles receiving the privileges, which may include `PUBLIC`.
+    grantees: Vec<Ident>,
+    /// Whether the privileges are granted `WITH GRANT OPTION`.
+    grantable: bool,
+}
+
+impl DefaultPrivilege {
+    /// Creates a new `DefaultPrivilege` granting the given privileges to the
+    /// given grantees, in any schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{privileges::TablePrivilege, structs::DefaultPrivilege};
+    /// use sqlparser::ast::Ident;
+    ///
+    /// let p = DefaultPrivilege::new(vec![TablePrivilege::Select], vec![Ident::new("reader")]);
+    /// assert_eq!(p.table_privileges(), [TablePrivilege::Select]);
+    /// assert!(p.schema_idents().is_empty());
+    /// assert!(!p.is_grantable());
+    /// assert_eq!(p.to_string(), "ALTER DEFAULT PRIVILEGES GRANT SELECT ON TABLES TO reader");
+    /// ```
+    #[must_use]
+    pub fn new(privileges: Vec<TablePrivilege>, grantees: Vec<Ident>) -> Self {
+        Self {
+            target_roles: Vec::new(),
+            schemas: Vec::new(),
+            privileges,
+            grantees,
+            grantable: false,
+        }
+    }
+
+    /// Sets the roles whose future tables the privileges are granted on, as
+    /// listed by the `FOR ROLE` clause.
+    #[must_use]
+    pub fn with_target_roles(mut self, target_roles: Vec<Ident>) -> Self {
+        self.target_roles = target_roles;
+        self
+    }
+
+    /// Sets the schemas whose future tables the privileges are granted on, as
+    /// listed by the `IN SCHEMA` clause.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{privileges::TablePrivilege, structs::DefaultPrivilege};
+    /// use sqlparser::ast::Ident;
+    ///
+    /// let p = DefaultPrivilege::new(vec![TablePrivilege::Insert], vec![Ident::new("writer")])
+    ///     .with_schemas(vec![Ident::new("app")])
+    ///     .with_grantable(true);
+    /// let sql = p.to_string();
+    /// assert!(sql.starts_with("ALTER DEFAULT PRIVILEGES IN SCHEMA app GRANT INSERT ON TABLES"));
+    /// assert!(sql.ends_with("TO writer WITH GRANT OPTION"));
+    /// ```
+    #[must_use]
+    pub fn with_schemas(mut self, schemas: Vec<Ident>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
+    /// Sets whether the privileges are granted `WITH GRANT OPTION`.
+    #[must_use]
+    pub fn with_grantable(mut self, grantable: bool) -> Self {
+        self.grantable = grantable;
+        self
+    }
+
+    /// Returns the roles of the `FOR ROLE` clause.
+    #[must_use]
+    pub fn target_role_idents(&self) -> &[Ident] {
+        &self.target_roles
+    }
+
+    /// Returns the schemas of the `IN SCHEMA` clause.
+    #[must_use]
+    pub fn schema_idents(&self) -> &[Ident] {
+        &self.schemas
+    }
+
+    /// Returns the privileges granted, in declaration order.
+    #[must_use]
+    pub fn table_privileges(&self) -> &[TablePrivilege] {
+        &self.privileges
+    }
+
+    /// Returns the roles receiving the privileges.
+    #[must_use]
+    pub fn grantee_idents(&self) -> &[Ident] {
+        &self.grantees
+    }
+
+    /// Returns whether the privileges are granted `WITH GRANT OPTION`.
+    #[must_use]
+    pub fn is_grantable(&self) -> bool {
+        self.grantable
+    }
+
+    /// Returns whether the default privileges apply to the tables created in
+    /// the provided schema, where tables without a schema are created in
+    /// `public`.
+    pub(crate) fn applies_to_schema(
+        &self,
+        schema: Option<(&str, bool)>,
+        casing: IdentifierCasing,
+    ) -> bool {
+        let (schema, quoted) = schema.unwrap_or(("public", false));
+        self.schemas.is_empty()
+            || self.schemas.iter().any(|ident| {
+                casing.matches(&ident.value, ident.quote_style.is_some(), schema, quoted)
+            })
+    }
+
+    /// Returns whether the provided role is a target role or a grantee of the
+    /// default privileges.
+    pub(crate) fn mentions_role(
+        &self,
+        role_name: &str,
+        role_quoted: bool,
+        casing: IdentifierCasing,
+    ) -> bool {
+        self.target_roles.iter().chain(&self.grantees).any(|ident| {
+            casing.matches(&ident.value, ident.quote_style.is_some(), role_name, role_quoted)
+        })
+    }
+
+    /// Applies the provided revocation, returning the default privileges
+    /// replacing these ones, or `None` when they are left untouched.
+    ///
+    /// The revocation only applies to the default privileges declared for
+    /// the same target roles and schemas. When its grantable flag is set, it
+    /// stands for `REVOKE GRANT OPTION FOR`, and only withdraws the grant
+    /// option of the privileges.
+    pub(crate) fn revoked(&self, revoke: &Self, casing: IdentifierCasing) -> Option<Vec<Self>> {
+        if !same_idents(&self.target_roles, &revoke.target_roles, casing)
+            || !same_idents(&self.schemas, &revoke.schemas, casing)
+        {
+            return None;
+        }
+        let (targeted, untouched): (Vec<Ident>, Vec<Ident>) =
+            self.grantees.iter().cloned().partition(|grantee| {
+                revoke.grantees.iter().any(|revoked| idents_match(grantee, revoked, casing))
+            });
+        let (revoked, kept): (Vec<TablePrivilege>, Vec<TablePrivilege>) = self
+            .privileges
+            .iter()
+            .copied()
+            .partition(|privilege| revoke.privileges.contains(privilege));
+        if targeted.is_empty() || revoked.is_empty() || (revoke.grantable && !self.grantable) {
+            return None;
+        }
+
+        let mut replacement = Vec::new();
+        if !untouched.is_empty() {
+            replacement.push(Self { grantees: untouched, ..self.clone() });
+        }
+        if !kept.is_empty() {
+            replacement.push(Self { privileges: kept, grantees: targeted.clone(), ..self.clone() });
+        }
+        if revoke.grantable {
+            replacement.push(Self {
+                privileges: revoked,
+                grantees: targeted,
+                grantable: false,
+                ..self.clone()
+            });
+        }
+        Some(replacement)
+    }
+}
+
+impl Display for DefaultPrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ALTER DEFAULT PRIVILEGES")?;
+        if !self.target_roles.is_empty() {
+            f.write_str(" FOR ROLE ")?;
+            write_idents(f, &self.target_roles)?;
+        }
+        if !self.schemas.is_empty() {
+            f.write_str(" IN SCHEMA ")?;
+            write_idents(f, &self.schemas)?;
+        }
+        f.write_str(" GRANT ")?;
+        for (index, privilege) in self.privileges.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{privilege}")?;
+        }
+        f.write_str(" ON TABLES TO ")?;
+        write_idents(f, &self.grantees)?;
+        if self.grantable {
+            f.write_str(" WITH GRANT OPTION")?;
+        }
+        Ok(())
+    }
+}
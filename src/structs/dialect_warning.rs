@@ -0,0 +1,146 @@
+//! Submodule defining the warnings emitted while parsing statements which fall
+//! outside the capabilities of the declared SQL dialect.
+
+use core::fmt::Display;
+
+/// Feature of a database engine which only some SQL dialects support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DialectCapability {
+    /// Database roles, as created by `CREATE ROLE`.
+    Roles,
+    /// Row level security policies, as created by `CREATE POLICY`.
+    Policies,
+    /// Privileges, as assigned by `GRANT` and `REVOKE`.
+    Grants,
+    /// Triggers, as created by `CREATE TRIGGER`.
+    Triggers,
+    /// User-defined functions, as created by `CREATE FUNCTION`.
+    Functions,
+    /// Named schemas, as created by `CREATE SCHEMA`.
+    Schemas,
+    /// Index builds which do not block writes, as run by `CREATE INDEX
+    /// CONCURRENTLY`.
+    ConcurrentIndexes,
+    /// Sequences, as created by `CREATE SEQUENCE`.
+    Sequences,
+    /// User-defined types, as created by `CREATE TYPE` and `CREATE DOMAIN`.
+    CustomTypes,
+    /// Extensions, as installed by `CREATE EXTENSION`.
+    Extensions,
+}
+
+impl DialectCapability {
+    /// Returns a plural, human-readable description of the capability.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::DialectCapability;
+    ///
+    /// assert_eq!(DialectCapability::Roles.description(), "roles");
+    /// ```
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Roles => "roles",
+            Self::Policies => "row level security policies",
+            Self::Grants => "grants",
+            Self::Triggers => "triggers",
+            Self::Functions => "user-defined functions",
+            Self::Schemas => "schemas",
+            Self::ConcurrentIndexes => "concurrent index builds",
+            Self::Sequences => "sequences",
+            Self::CustomTypes => "user-defined types",
+            Self::Extensions => "extensions",
+        }
+    }
+}
+
+/// Warning emitted when a statement requiring a capability the declared
+/// dialect lacks is parsed.
+///
+/// The statement is still applied to the database, so that schemas written
+/// for a broader dialect keep loading, but the resulting objects are unlikely
+/// to make sense for the target engine.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sqlparser::dialect::SQLiteDialect;
+///
+/// let db = ParserDB::parse::<SQLiteDialect>(
+///     "CREATE TABLE t (id INT); CREATE ROLE reader; GRANT SELECT ON t TO reader;",
+/// )?;
+/// let warnings: Vec<String> = db.dialect_warnings().iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     warnings,
+///     [
+///         "CREATE ROLE statements parsed but SQLite has no roles",
+///         "GRANT statements parsed but SQLite has no grants",
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DialectWarning {
+    /// Kind of the statement which triggered the warning.
+    statement: &'static str,
+    /// Capability required by the statement.
+    capability: DialectCapability,
+    /// Name of the dialect lacking the capability.
+    dialect: &'static str,
+}
+
+impl DialectWarning {
+    /// Creates a new warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The kind of the statement, e.g. `CREATE ROLE`.
+    /// * `capability` - The capability required by the statement.
+    /// * `dialect` - The name of the dialect lacking the capability.
+    #[must_use]
+    pub fn new(
+        statement: &'static str,
+        capability: DialectCapability,
+        dialect: &'static str,
+    ) -> Self {
+        Self { statement, capability, dialect }
+    }
+
+    /// Returns the kind of the statement which triggered the warning.
+    #[must_use]
+    #[inline]
+    pub fn statement(&self) -> &'static str {
+        self.statement
+    }
+
+    /// Returns the capability required by the statement.
+    #[must_use]
+    #[inline]
+    pub fn capability(&self) -> DialectCapability {
+        self.capability
+    }
+
+    /// Returns the name of the dialect lacking the capability.
+    #[must_use]
+    #[inline]
+    pub fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+}
+
+impl Display for DialectWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} statements parsed but {} has no {}",
+            self.statement,
+            self.dialect,
+            self.capability.description()
+        )
+    }
+}
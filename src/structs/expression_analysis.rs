@@ -0,0 +1,433 @@
+//! Submodule defining the `ExpressionAnalysis` struct, describing a single SQL
+//! expression resolved against a table of a database.
+
+use alloc::vec::Vec;
+use core::{fmt::Display, ops::ControlFlow};
+
+use sqlparser::ast::{BinaryOperator, Expr, ObjectName, Value, visit_expressions};
+
+use crate::{
+    traits::{
+        ColumnLike, DatabaseLike, DialectLike, FunctionLike, TableLike,
+        check_constraint::{
+            is_mutual_nullability_expr, is_negation_expr, is_not_empty_text_expr,
+            is_tautology_expr, lower_text_length_bound, upper_text_length_bound,
+        },
+    },
+    utils::{columns_in_expression::columns_in_expression, object_name::object_name_last_part},
+};
+
+/// Normalized data types of the numeric columns.
+const NUMERIC_TYPES: &[&str] = &[
+    "INT",
+    "SMALLINT",
+    "BIGINT",
+    "TINYINT",
+    "MEDIUMINT",
+    "INT2",
+    "INT4",
+    "INT8",
+    "REAL",
+    "FLOAT",
+    "DOUBLE",
+    "DOUBLE PRECISION",
+    "DECIMAL",
+    "NUMERIC",
+];
+
+/// A comparison between a column and a literal of an incompatible type, such
+/// as a textual column compared with a number.
+#[derive(Debug, Clone)]
+pub struct TypeMismatch<'db, DB: DatabaseLike> {
+    /// The column being compared.
+    column: &'db DB::Column,
+    /// The normalized data type of the column.
+    data_type: &'db str,
+    /// The kind of the literal the column is compared with.
+    literal: &'static str,
+}
+
+impl<'db, DB: DatabaseLike> TypeMismatch<'db, DB> {
+    /// Returns the column being compared.
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> &'db DB::Column {
+        self.column
+    }
+
+    /// Returns the normalized data type of the column.
+    #[must_use]
+    #[inline]
+    pub fn data_type(&self) -> &'db str {
+        self.data_type
+    }
+
+    /// Returns the kind of the literal the column is compared with, either
+    /// `"number"` or `"string"`.
+    #[must_use]
+    #[inline]
+    pub fn literal(&self) -> &'static str {
+        self.literal
+    }
+}
+
+impl<DB: DatabaseLike> Display for TypeMismatch<'_, DB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "column `{}` of type {} is compared with a {}",
+            self.column.column_name(),
+            self.data_type,
+            self.literal
+        )
+    }
+}
+
+/// Analysis of a standalone SQL expression, such as a candidate CHECK
+/// constraint or policy predicate, resolved against a table.
+///
+/// The analysis reports the columns and functions the expression uses,
+/// comparisons between columns and literals of incompatible types, and runs
+/// the same tautology and text length analyses available for the CHECK
+/// constraints of the database.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+///
+/// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT, name TEXT);")?;
+/// let users = db.table(None, "users").unwrap();
+/// let analysis = db.analyze_expression(users, "length(name) > 3 AND id > 'x'")?;
+/// let columns: Vec<&str> = analysis.columns().iter().map(|c| c.column_name()).collect();
+/// assert_eq!(columns, ["name", "id"]);
+/// assert_eq!(analysis.lower_text_length_bound(), Some(4));
+/// assert_eq!(
+///     analysis.type_mismatches()[0].to_string(),
+///     "column `id` of type INT is compared with a string"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExpressionAnalysis<'db, DB: DatabaseLike> {
+    /// The table the expression was resolved against.
+    table: &'db DB::Table,
+    /// The analyzed expression.
+    expression: Expr,
+    /// The columns of the table used by the expression.
+    columns: Vec<&'db DB::Column>,
+    /// The user-defined functions called by the expression.
+    functions: Vec<&'db DB::Function>,
+    /// The comparisons between columns and literals of incompatible types.
+    type_mismatches: Vec<TypeMismatch<'db, DB>>,
+    /// Whether the expression always holds.
+    is_tautology: bool,
+    /// Whether the expression can never hold.
+    is_negation: bool,
+    /// Whether the expression states that columns are all NULL or all NOT
+    /// NULL.
+    is_mutual_nullability: bool,
+    /// Whether the expression forbids empty strings in a textual column.
+    is_not_empty_text: bool,
+    /// The exclusive upper bound on the length of a textual column.
+    upper_text_length_bound: Option<usize>,
+    /// The inclusive lower bound on the length of a textual column.
+    lower_text_length_bound: Option<usize>,
+}
+
+impl<'db, DB: DatabaseLike> ExpressionAnalysis<'db, DB> {
+    /// Analyzes the provided expression against the provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the table belongs to.
+    /// * `table` - The table whose columns the expression may use.
+    /// * `expression` - The expression to analyze.
+    ///
+    /// # Errors
+    ///
+    /// * If the expression uses a column which does not exist in the table.
+    pub fn new(
+        database: &'db DB,
+        table: &'db DB::Table,
+        expression: Expr,
+    ) -> Result<Self, crate::errors::Error> {
+        let table_columns = table.columns(database).collect::<Vec<_>>();
+        let columns = columns_in_expression(
+            &expression,
+            table.table_name(),
+            &table_columns,
+            database.dialect().identifier_casing(),
+        )?;
+        let functions = called_functions(database, &expression);
+        let type_mismatches = type_mismatches(database, &columns, &expression);
+
+        Ok(Self {
+            table,
+            is_tautology: is_tautology_expr(database, &columns, &expression),
+            is_negation: is_negation_expr(database, table, &columns, &expression),
+            is_mutual_nullability: is_mutual_nullability_expr(&expression),
+            is_not_empty_text: is_not_empty_text_expr(database, &columns, &expression),
+            upper_text_length_bound: upper_text_length_bound(
+                database,
+                table,
+                &columns,
+                &expression,
+            ),
+            lower_text_length_bound: lower_text_length_bound(
+                database,
+                table,
+                &columns,
+                &expression,
+            ),
+            expression,
+            columns,
+            functions,
+            type_mismatches,
+        })
+    }
+
+    /// Returns the table the expression was resolved against.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns the analyzed expression.
+    #[must_use]
+    #[inline]
+    pub fn expression(&self) -> &Expr {
+        &self.expression
+    }
+
+    /// Returns the columns of the table used by the expression, in order of
+    /// first appearance.
+    #[must_use]
+    #[inline]
+    pub fn columns(&self) -> &[&'db DB::Column] {
+        &self.columns
+    }
+
+    /// Returns the user-defined functions called by the expression.
+    #[must_use]
+    #[inline]
+    pub fn functions(&self) -> &[&'db DB::Function] {
+        &self.functions
+    }
+
+    /// Returns the comparisons between columns and literals of incompatible
+    /// types.
+    #[must_use]
+    #[inline]
+    pub fn type_mismatches(&self) -> &[TypeMismatch<'db, DB>] {
+        &self.type_mismatches
+    }
+
+    /// Returns whether the expression always holds, as
+    /// [`CheckConstraintLike::is_tautology`](crate::traits::CheckConstraintLike::is_tautology).
+    #[must_use]
+    #[inline]
+    pub fn is_tautology(&self) -> bool {
+        self.is_tautology
+    }
+
+    /// Returns whether the expression can never hold, as
+    /// [`CheckConstraintLike::is_negation`](crate::traits::CheckConstraintLike::is_negation).
+    #[must_use]
+    #[inline]
+    pub fn is_negation(&self) -> bool {
+        self.is_negation
+    }
+
+    /// Returns whether the expression states that a group of columns are
+    /// either all NULL or all NOT NULL.
+    #[must_use]
+    #[inline]
+    pub fn is_mutual_nullability_constraint(&self) -> bool {
+        self.is_mutual_nullability
+    }
+
+    /// Returns whether the expression forbids empty strings in a textual
+    /// column.
+    #[must_use]
+    #[inline]
+    pub fn is_not_empty_text_constraint(&self) -> bool {
+        self.is_not_empty_text
+    }
+
+    /// Returns the exclusive upper bound the expression enforces on the
+    /// length of a textual column, if any.
+    #[must_use]
+    #[inline]
+    pub fn upper_text_length_bound(&self) -> Option<usize> {
+        self.upper_text_length_bound
+    }
+
+    /// Returns the inclusive lower bound the expression enforces on the
+    /// length of a textual column, if any.
+    #[must_use]
+    #[inline]
+    pub fn lower_text_length_bound(&self) -> Option<usize> {
+        self.lower_text_length_bound
+    }
+}
+
+/// Returns the user-defined functions of the database called anywhere in the
+/// expression.
+fn called_functions<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    expression: &Expr,
+) -> Vec<&'db DB::Function> {
+    let mut names: Vec<ObjectName> = Vec::new();
+    let _ = visit_expressions(expression, |expr| {
+        if let Expr::Function(function) = expr {
+            names.push(function.name.clone());
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    let casing = database.dialect().identifier_casing();
+    database
+        .functions()
+        .filter(|function| {
+            names.iter().filter_map(object_name_last_part).any(|(name, quoted)| {
+                casing.matches(function.name(), function.name_is_quoted(), name, quoted)
+            })
+        })
+        .collect()
+}
+
+/// Returns the comparisons between columns and literals of incompatible types
+/// in the expression.
+fn type_mismatches<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    columns: &[&'db DB::Column],
+    expression: &Expr,
+) -> Vec<TypeMismatch<'db, DB>> {
+    let mut mismatches = Vec::new();
+    let _ = visit_expressions(expression, |expr| {
+        if let Expr::BinaryOp { left, op, right } = expr
+            && matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+            )
+        {
+            for (operand, other) in [(left, right), (right, left)] {
+                if let Some(mismatch) = type_mismatch(database, columns, operand, other) {
+                    mismatches.push(mismatch);
+                }
+            }
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    mismatches
+}
+
+/// Returns the mismatch between the column named by `operand` and the
+/// literal `other`, if their types are incompatible.
+fn type_mismatch<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    columns: &[&'db DB::Column],
+    operand: &Expr,
+    other: &Expr,
+) -> Option<TypeMismatch<'db, DB>> {
+    let name = match operand {
+        Expr::Identifier(ident) => ident,
+        Expr::CompoundIdentifier(idents) => idents.last()?,
+        _ => return None,
+    };
+    let Expr::Value(value) = other else {
+        return None;
+    };
+    let column = columns.iter().copied().find(|column| column.column_name() == name.value)?;
+    let data_type = column.normalized_data_type(database);
+    let literal = match &value.value {
+        Value::Number(..) if column.is_textual(database) => "number",
+        Value::SingleQuotedString(_) if NUMERIC_TYPES.contains(&data_type) => "string",
+        _ => return None,
+    };
+    Some(TypeMismatch { column, data_type, literal })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::GenericDialect;
+
+    use crate::{
+        errors::Error,
+        structs::ParserDB,
+        traits::{ColumnLike, DatabaseLike, FunctionLike},
+    };
+
+    #[test]
+    fn test_analysis_matches_check_constraint_analyses() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (a TEXT NOT NULL, b TEXT CHECK (length(b) < 10));",
+        )
+        .expect("schema should parse");
+        let table = db.table(None, "t").expect("table");
+
+        let tautology = db.analyze_expression(table, "a IS NOT NULL").expect("analysis");
+        assert!(tautology.is_tautology());
+        assert!(!tautology.is_negation());
+
+        let bounded =
+            db.analyze_expression(table, "length(a) < length(b) AND a <> ''").expect("analysis");
+        assert_eq!(bounded.upper_text_length_bound(), Some(10));
+        assert!(bounded.is_not_empty_text_constraint());
+        assert!(bounded.type_mismatches().is_empty());
+
+        let contradiction =
+            db.analyze_expression(table, "length(a) > 5 AND length(a) < 3").expect("analysis");
+        assert!(contradiction.is_negation());
+
+        let nullability = db
+            .analyze_expression(
+                table,
+                "(a IS NULL AND b IS NULL) OR (a IS NOT NULL AND b IS NOT NULL)",
+            )
+            .expect("analysis");
+        assert!(nullability.is_mutual_nullability_constraint());
+    }
+
+    #[test]
+    fn test_analysis_resolves_user_defined_functions() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE FUNCTION is_valid(TEXT) RETURNS BOOLEAN; CREATE TABLE t (name TEXT);",
+        )
+        .expect("schema should parse");
+        let table = db.table(None, "t").expect("table");
+        let analysis = db.analyze_expression(table, "IS_VALID(name)").expect("analysis");
+        assert_eq!(analysis.functions().len(), 1);
+        assert_eq!(analysis.functions()[0].name(), "is_valid");
+        assert_eq!(analysis.columns()[0].column_name(), "name");
+    }
+
+    #[test]
+    fn test_analysis_reports_type_mismatches_and_unknown_columns() {
+        let db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT, name TEXT);")
+            .expect("schema should parse");
+        let table = db.table(None, "t").expect("table");
+
+        let analysis = db.analyze_expression(table, "1 = name").expect("analysis");
+        assert_eq!(analysis.type_mismatches().len(), 1);
+        assert_eq!(analysis.type_mismatches()[0].literal(), "number");
+        assert_eq!(analysis.type_mismatches()[0].data_type(), "TEXT");
+
+        assert!(matches!(
+            db.analyze_expression(table, "missing > 0"),
+            Err(Error::UnknownColumnInCheckConstraint { .. })
+        ));
+        assert!(matches!(
+            db.analyze_expression(table, "id > 0 id"),
+            Err(Error::SqlParserError { .. })
+        ));
+    }
+}
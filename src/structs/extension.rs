@@ -0,0 +1,103 @@
+//! Extension struct for storing parsed extension information.
+
+use alloc::string::String;
+
+/// A database extension installed by a CREATE EXTENSION statement.
+///
+/// This struct stores the extension name, the schema its objects were
+/// installed into and the requested version, if any.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Extension {
+    /// The name of the extension.
+    name: String,
+    /// Whether the extension identifier was quoted in SQL.
+    quoted: bool,
+    /// The schema of the extension, with whether it was quoted in SQL.
+    schema: Option<(String, bool)>,
+    /// The requested version of the extension, if specified.
+    version: Option<String>,
+}
+
+impl Extension {
+    /// Creates a new `Extension` with the given name and quoted-name metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Extension;
+    ///
+    /// let e = Extension::new("uuid-ossp".to_string(), true);
+    /// assert_eq!(e.name(), "uuid-ossp");
+    /// assert!(e.is_quoted());
+    /// assert_eq!(e.schema(), None);
+    /// assert_eq!(e.version(), None);
+    /// ```
+    #[must_use]
+    pub fn new(name: String, quoted: bool) -> Self {
+        Self { name, quoted, schema: None, version: None }
+    }
+
+    /// Sets the schema the extension was installed into.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Extension;
+    ///
+    /// let e = Extension::new("pgcrypto".to_string(), false).with_schema("ext".to_string(), false);
+    /// assert_eq!(e.schema(), Some("ext"));
+    /// assert!(!e.schema_is_quoted());
+    /// ```
+    #[must_use]
+    pub fn with_schema(mut self, schema: String, quoted: bool) -> Self {
+        self.schema = Some((schema, quoted));
+        self
+    }
+
+    /// Sets the requested version of the extension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Extension;
+    ///
+    /// let e = Extension::new("postgis".to_string(), false).with_version("3.4.0".to_string());
+    /// assert_eq!(e.version(), Some("3.4.0"));
+    /// ```
+    #[must_use]
+    pub fn with_version(mut self, version: String) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns the name of the extension.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether this extension name was quoted in SQL.
+    #[must_use]
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the schema of the extension, if specified.
+    #[must_use]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_ref().map(|(schema, _)| schema.as_str())
+    }
+
+    /// Returns whether the schema of the extension was quoted in SQL.
+    #[must_use]
+    pub fn schema_is_quoted(&self) -> bool {
+        self.schema.as_ref().is_some_and(|(_, quoted)| *quoted)
+    }
+
+    /// Returns the requested version of the extension, if specified.
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+}
@@ -0,0 +1,451 @@
+//! Submodule defining the `ExtensionHierarchy` struct, describing the forest
+//! of extension relationships between the tables of a database.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::traits::{DatabaseLike, TableLike};
+
+/// Table extending several tables which share a common ancestor.
+///
+/// Rows of such a table are reachable from the shared ancestor through more
+/// than one extension path, which code generators mapping extensions onto
+/// single inheritance cannot represent.
+#[derive(Debug, Clone)]
+pub struct ExtensionDiamond<'db, DB: DatabaseLike> {
+    /// The table closing the diamond.
+    table: &'db DB::Table,
+    /// The tables directly extended by the table.
+    extended_tables: Vec<&'db DB::Table>,
+    /// The ancestors reachable through more than one extended table.
+    common_ancestors: Vec<&'db DB::Table>,
+}
+
+impl<'db, DB: DatabaseLike> ExtensionDiamond<'db, DB> {
+    /// Returns the table closing the diamond.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns the tables directly extended by the table.
+    #[must_use]
+    #[inline]
+    pub fn extended_tables(&self) -> &[&'db DB::Table] {
+        &self.extended_tables
+    }
+
+    /// Returns the ancestors reachable through more than one of the extended
+    /// tables.
+    #[must_use]
+    #[inline]
+    pub fn common_ancestors(&self) -> &[&'db DB::Table] {
+        &self.common_ancestors
+    }
+}
+
+/// Forest of the extension relationships between the tables of a database.
+///
+/// A table extends another table when its primary key is also a foreign key
+/// to the primary key of the other table. The hierarchy only contains tables
+/// taking part in at least one such relationship: its roots are the base
+/// tables which are extended without extending any table themselves, and its
+/// edges go from each extended table to the tables extending it.
+#[derive(Debug, Clone)]
+pub struct ExtensionHierarchy<'db, DB: DatabaseLike> {
+    /// The tables of the hierarchy, in the order of the database.
+    tables: Vec<&'db DB::Table>,
+    /// The `(extended, extension)` edges, as indices into `tables`.
+    edges: Vec<(usize, usize)>,
+    /// The diamonds of the hierarchy.
+    diamonds: Vec<ExtensionDiamond<'db, DB>>,
+}
+
+/// Returns the name of the table, qualified by its schema if any.
+fn qualified_table_name<T: TableLike>(table: &T) -> String {
+    match table.table_schema() {
+        Some(schema) => format!("{schema}.{}", table.table_name()),
+        None => table.table_name().into(),
+    }
+}
+
+impl<'db, DB: DatabaseLike> ExtensionHierarchy<'db, DB> {
+    /// Builds the extension hierarchy of the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to build the hierarchy for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE owners (id INT PRIMARY KEY, dog_id INT REFERENCES dogs(id));
+    /// ",
+    /// )?;
+    /// let hierarchy = ExtensionHierarchy::new(&db);
+    /// let tables: Vec<&str> = hierarchy.tables().iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(tables, ["animals", "dogs"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB) -> Self {
+        let mut relationships: Vec<(&'db DB::Table, &'db DB::Table)> = Vec::new();
+        for extension in database.tables() {
+            for extended in extension.extended_tables(database) {
+                relationships.push((extended, extension));
+            }
+        }
+
+        let tables: Vec<&'db DB::Table> = database
+            .tables()
+            .filter(|table| {
+                relationships
+                    .iter()
+                    .any(|(extended, extension)| extended == table || extension == table)
+            })
+            .collect();
+        let position = |table: &DB::Table| {
+            tables.iter().position(|candidate| *candidate == table).unwrap_or_default()
+        };
+
+        let mut edges: Vec<(usize, usize)> = relationships
+            .into_iter()
+            .map(|(extended, extension)| (position(extended), position(extension)))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut diamonds = Vec::new();
+        for (index, &table) in tables.iter().enumerate() {
+            let extended_tables: Vec<&'db DB::Table> = edges
+                .iter()
+                .filter(|(_, extension)| *extension == index)
+                .map(|(extended, _)| tables[*extended])
+                .collect();
+            if extended_tables.len() < 2 {
+                continue;
+            }
+
+            let lineages: Vec<Vec<&'db DB::Table>> = extended_tables
+                .iter()
+                .map(|&extended| {
+                    let mut lineage = extended.ancestral_extended_tables(database);
+                    lineage.push(extended);
+                    lineage
+                })
+                .collect();
+            let mut common_ancestors: Vec<&'db DB::Table> = lineages
+                .iter()
+                .flatten()
+                .copied()
+                .filter(|ancestor| {
+                    lineages.iter().filter(|lineage| lineage.contains(ancestor)).count() > 1
+                })
+                .collect();
+            common_ancestors.sort_unstable();
+            common_ancestors.dedup();
+
+            if !common_ancestors.is_empty() {
+                diamonds.push(ExtensionDiamond { table, extended_tables, common_ancestors });
+            }
+        }
+
+        Self { tables, edges, diamonds }
+    }
+
+    /// Returns the tables taking part in at least one extension relationship,
+    /// in the order of the database.
+    #[must_use]
+    #[inline]
+    pub fn tables(&self) -> &[&'db DB::Table] {
+        &self.tables
+    }
+
+    /// Returns whether the database has no extension relationships.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Returns an iterator over the base tables of the hierarchy, i.e. the
+    /// tables which are extended without extending any table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE puppies (id INT PRIMARY KEY REFERENCES dogs(id));
+    /// CREATE TABLE plants (id INT PRIMARY KEY);
+    /// CREATE TABLE trees (id INT PRIMARY KEY REFERENCES plants(id));
+    /// ",
+    /// )?;
+    /// let hierarchy = db.extension_hierarchy();
+    /// let roots: Vec<&str> = hierarchy.roots().map(|t| t.table_name()).collect();
+    /// assert_eq!(roots, ["animals", "plants"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roots(&self) -> impl Iterator<Item = &'db DB::Table> + '_ {
+        self.tables
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.edges.iter().any(|(_, extension)| extension == index))
+            .map(|(_, table)| *table)
+    }
+
+    /// Returns an iterator over the tables directly extending the provided
+    /// table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The extended table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE puppies (id INT PRIMARY KEY REFERENCES dogs(id));
+    /// ",
+    /// )?;
+    /// let hierarchy = db.extension_hierarchy();
+    /// let animals = db.table(None, "animals").unwrap();
+    /// let extensions: Vec<&str> = hierarchy.extensions(animals).map(|t| t.table_name()).collect();
+    /// assert_eq!(extensions, ["dogs"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extensions<'a>(
+        &'a self,
+        table: &'a DB::Table,
+    ) -> impl Iterator<Item = &'db DB::Table> + 'a {
+        self.edges
+            .iter()
+            .filter(move |(extended, _)| self.tables[*extended] == table)
+            .map(|(_, extension)| self.tables[*extension])
+    }
+
+    /// Returns the diamonds of the hierarchy, i.e. the tables extending
+    /// several tables which share a common ancestor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE pets (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE pet_dogs (
+    ///     id INT PRIMARY KEY,
+    ///     FOREIGN KEY (id) REFERENCES pets(id),
+    ///     FOREIGN KEY (id) REFERENCES dogs(id)
+    /// );
+    /// ",
+    /// )?;
+    /// let hierarchy = db.extension_hierarchy();
+    /// let diamond = &hierarchy.diamonds()[0];
+    /// assert_eq!(diamond.table().table_name(), "pet_dogs");
+    /// assert_eq!(diamond.common_ancestors()[0].table_name(), "animals");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn diamonds(&self) -> &[ExtensionDiamond<'db, DB>] {
+        &self.diamonds
+    }
+
+    /// Renders the hierarchy as a Mermaid flowchart, with edges going from
+    /// each extended table to the tables extending it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// ",
+    /// )?;
+    /// assert_eq!(
+    ///     db.extension_hierarchy().to_mermaid(),
+    ///     "flowchart TD\n    t0[\"animals\"]\n    t1[\"dogs\"]\n    t0 --> t1\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let mut rendering = String::from("flowchart TD\n");
+        for (index, table) in self.tables.iter().enumerate() {
+            let label = qualified_table_name(*table).replace('"', "#quot;");
+            let _ = writeln!(rendering, "    t{index}[\"{label}\"]");
+        }
+        for (extended, extension) in &self.edges {
+            let _ = writeln!(rendering, "    t{extended} --> t{extension}");
+        }
+        rendering
+    }
+
+    /// Renders the hierarchy as a Graphviz DOT digraph, with edges going from
+    /// each extended table to the tables extending it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// ",
+    /// )?;
+    /// assert_eq!(
+    ///     db.extension_hierarchy().to_dot(),
+    ///     "digraph extension_hierarchy {\n    t0 [label=\"animals\"];\n    t1 [label=\"dogs\"];\n    t0 -> t1;\n}\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut rendering = String::from("digraph extension_hierarchy {\n");
+        for (index, table) in self.tables.iter().enumerate() {
+            let label = qualified_table_name(*table).replace('\\', "\\\\").replace('"', "\\\"");
+            let _ = writeln!(rendering, "    t{index} [label=\"{label}\"];");
+        }
+        for (extended, extension) in &self.edges {
+            let _ = writeln!(rendering, "    t{extended} -> t{extension};");
+        }
+        rendering.push_str("}\n");
+        rendering
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::GenericDialect;
+
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, TableLike},
+    };
+
+    const DIAMOND: &str = "
+        CREATE TABLE animals (id INT PRIMARY KEY);
+        CREATE TABLE pets (id INT PRIMARY KEY REFERENCES animals(id));
+        CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+        CREATE TABLE pet_dogs (
+            id INT PRIMARY KEY,
+            FOREIGN KEY (id) REFERENCES pets(id),
+            FOREIGN KEY (id) REFERENCES dogs(id)
+        );
+        CREATE TABLE service_dogs (id INT PRIMARY KEY REFERENCES pet_dogs(id));
+        CREATE TABLE owners (id INT PRIMARY KEY, dog_id INT REFERENCES dogs(id));
+    ";
+
+    fn names<'a>(
+        tables: impl IntoIterator<Item = &'a <ParserDB as DatabaseLike>::Table>,
+    ) -> Vec<&'a str> {
+        tables.into_iter().map(TableLike::table_name).collect()
+    }
+
+    #[test]
+    fn test_forest_skips_plain_foreign_keys() {
+        let db = ParserDB::parse::<GenericDialect>(DIAMOND).unwrap();
+        let hierarchy = db.extension_hierarchy();
+        assert_eq!(
+            names(hierarchy.tables().iter().copied()),
+            ["animals", "dogs", "pet_dogs", "pets", "service_dogs"]
+        );
+        assert_eq!(names(hierarchy.roots()), ["animals"]);
+        let animals = db.table(None, "animals").unwrap();
+        assert_eq!(names(hierarchy.extensions(animals)), ["dogs", "pets"]);
+        let pet_dogs = db.table(None, "pet_dogs").unwrap();
+        assert_eq!(names(hierarchy.extensions(pet_dogs)), ["service_dogs"]);
+    }
+
+    #[test]
+    fn test_diamond_detection() {
+        let db = ParserDB::parse::<GenericDialect>(DIAMOND).unwrap();
+        let hierarchy = db.extension_hierarchy();
+        let diamonds = hierarchy.diamonds();
+        assert_eq!(diamonds.len(), 1);
+        assert_eq!(names([diamonds[0].table()]), ["pet_dogs"]);
+        assert_eq!(names(diamonds[0].extended_tables().iter().copied()), ["dogs", "pets"]);
+        assert_eq!(names(diamonds[0].common_ancestors().iter().copied()), ["animals"]);
+    }
+
+    #[test]
+    fn test_multiple_unrelated_bases_are_not_diamonds() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE a (id INT PRIMARY KEY);
+            CREATE TABLE b (id INT PRIMARY KEY);
+            CREATE TABLE c (
+                id INT PRIMARY KEY,
+                FOREIGN KEY (id) REFERENCES a(id),
+                FOREIGN KEY (id) REFERENCES b(id)
+            );
+            ",
+        )
+        .unwrap();
+        let hierarchy = db.extension_hierarchy();
+        assert_eq!(names(hierarchy.roots()), ["a", "b"]);
+        assert!(hierarchy.diamonds().is_empty());
+    }
+
+    #[test]
+    fn test_renderings() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE app.a (id INT PRIMARY KEY);
+            CREATE TABLE app.b (id INT PRIMARY KEY REFERENCES app.a(id));
+            CREATE TABLE c (id INT PRIMARY KEY);
+            ",
+        )
+        .unwrap();
+        let hierarchy = db.extension_hierarchy();
+        assert_eq!(
+            hierarchy.to_mermaid(),
+            "flowchart TD\n    t0[\"app.a\"]\n    t1[\"app.b\"]\n    t0 --> t1\n"
+        );
+        assert_eq!(
+            hierarchy.to_dot(),
+            "digraph extension_hierarchy {\n    t0 [label=\"app.a\"];\n    t1 [label=\"app.b\"];\n    t0 -> t1;\n}\n"
+        );
+
+        let empty = ParserDB::parse::<GenericDialect>("CREATE TABLE c (id INT);").unwrap();
+        assert!(empty.extension_hierarchy().is_empty());
+        assert_eq!(empty.extension_hierarchy().to_mermaid(), "flowchart TD\n");
+    }
+}
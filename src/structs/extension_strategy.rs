@@ -0,0 +1,402 @@
+//! Submodule defining the `ExtensionStrategy` enum, controlling which foreign
+//! keys make a table an extension of the table they reference.
+
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{ColumnLike, ForeignKeyLike, TableLike},
+};
+
+/// Marker introducing, in the documentation or comment of a table, the name
+/// of a table it extends under [`ExtensionStrategy::ExplicitComment`].
+pub const EXTENDS_MARKER: &str = "@extends";
+
+/// Predicate deciding whether a foreign key defines an extension, used by
+/// [`ExtensionStrategy::Custom`].
+pub type ExtensionPredicate = dyn Fn(&ExtensionCandidate<'_>) -> bool + Send + Sync;
+
+/// Rule detecting the foreign keys which make their host table an extension
+/// of the table they reference, as reported by
+/// [`ForeignKeyLike::is_extension_foreign_key`].
+///
+/// Self-referential foreign keys never define an extension under the
+/// built-in rules.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sql_traits::structs::{ExtensionStrategy, ParserOptions};
+///
+/// let sql = "
+/// CREATE TABLE parent (id INT PRIMARY KEY);
+/// CREATE TABLE child (parent_id INT PRIMARY KEY REFERENCES parent (id));
+/// ";
+/// let db = ParserDB::parse::<GenericDialect>(sql)?;
+/// assert!(db.table(None, "child").unwrap().is_extension(&db));
+///
+/// let options = ParserOptions::default().with_extension_strategy(ExtensionStrategy::PkFkSameName);
+/// let db = ParserDB::parse_with_options::<GenericDialect>(sql, options)?;
+/// assert!(!db.table(None, "child").unwrap().is_extension(&db));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub enum ExtensionStrategy {
+    /// A foreign key from the primary key of its table to the primary key of
+    /// the referenced table, with each host column named as the column it
+    /// references.
+    PkFkSameName,
+    /// A foreign key from the primary key of its table to the primary key of
+    /// the referenced table, whatever the names of the columns.
+    #[default]
+    PkFkAnyName,
+    /// A foreign key whose table is documented or commented with
+    /// [`EXTENDS_MARKER`] followed by the name of the referenced table, such
+    /// as `@extends parent` or `@extends app.parent`.
+    ExplicitComment,
+    /// A foreign key accepted by the provided predicate.
+    Custom(Arc<ExtensionPredicate>),
+}
+
+impl ExtensionStrategy {
+    /// Creates a strategy accepting the foreign keys accepted by the provided
+    /// predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sql_traits::structs::{ExtensionStrategy, ParserOptions};
+    ///
+    /// let strategy = ExtensionStrategy::custom(|candidate| {
+    ///     candidate.is_host_primary_key() && candidate.host_table_name().ends_with("_details")
+    /// });
+    /// let db = ParserDB::parse_with_options::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE users_details (id INT PRIMARY KEY REFERENCES users (id));
+    /// CREATE TABLE admins (id INT PRIMARY KEY REFERENCES users (id));
+    /// ",
+    ///     ParserOptions::default().with_extension_strategy(strategy),
+    /// )?;
+    /// assert!(db.table(None, "users_details").unwrap().is_extension(&db));
+    /// assert!(!db.table(None, "admins").unwrap().is_extension(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn custom(
+        predicate: impl Fn(&ExtensionCandidate<'_>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+
+    /// Returns whether the provided table documentation marks the provided
+    /// table as extended, comparing the names under the provided casing.
+    pub(crate) fn documents_extension<T: TableLike>(
+        documentation: &str,
+        table: &T,
+        casing: IdentifierCasing,
+    ) -> bool {
+        let matches_name = |target: &str| {
+            casing.matches_lookup(table.table_name(), table.table_name_is_quoted(), target)
+        };
+        let mut words = documentation.split_whitespace();
+        while words.any(|word| word == EXTENDS_MARKER) {
+            let Some(target) = words.next() else {
+                return false;
+            };
+            let target = target.trim_end_matches([',', ';', '.']);
+            let matches = match target.rsplit_once('.') {
+                Some((target_schema, target_name)) => {
+                    table.table_schema().is_some_and(|schema| {
+                        casing.matches_lookup(schema, table.table_schema_is_quoted(), target_schema)
+                    }) && matches_name(target_name)
+                }
+                None => matches_name(target),
+            };
+            if matches {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Debug for ExtensionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PkFkSameName => f.write_str("PkFkSameName"),
+            Self::PkFkAnyName => f.write_str("PkFkAnyName"),
+            Self::ExplicitComment => f.write_str("ExplicitComment"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Custom strategies are equal when they share the same predicate.
+impl PartialEq for ExtensionStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(first), Self::Custom(second)) => Arc::ptr_eq(first, second),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for ExtensionStrategy {}
+
+impl Hash for ExtensionStrategy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        if let Self::Custom(predicate) = self {
+            Arc::as_ptr(predicate).cast::<()>().hash(state);
+        }
+    }
+}
+
+/// Description of a foreign key submitted to an
+/// [`ExtensionStrategy::Custom`] predicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionCandidate<'a> {
+    /// Name of the foreign key, if it has one.
+    foreign_key_name: Option<&'a str>,
+    /// Schema of the table defining the foreign key, if any.
+    host_table_schema: Option<&'a str>,
+    /// Name of the table defining the foreign key.
+    host_table_name: &'a str,
+    /// Documentation of the table defining the foreign key, if any.
+    host_table_doc: Option<&'a str>,
+    /// Names of the columns of the foreign key in the host table.
+    host_columns: Vec<&'a str>,
+    /// Schema of the referenced table, if any.
+    referenced_table_schema: Option<&'a str>,
+    /// Name of the referenced table.
+    referenced_table_name: &'a str,
+    /// Names of the referenced columns.
+    referenced_columns: Vec<&'a str>,
+    /// Whether the host columns are the primary key of the host table.
+    host_primary_key: bool,
+    /// Whether the referenced columns are the primary key of the referenced
+    /// table.
+    referenced_primary_key: bool,
+    /// Whether the foreign key references its own table.
+    self_referential: bool,
+}
+
+impl<'a> ExtensionCandidate<'a> {
+    /// Describes the provided foreign key, or returns `None` if its
+    /// referenced table is not in the database.
+    pub(crate) fn new<F: ForeignKeyLike>(foreign_key: &'a F, database: &'a F::DB) -> Option<Self> {
+        let host_table = foreign_key.host_table(database);
+        let referenced_table = foreign_key.referenced_table(database)?;
+        Some(Self {
+            foreign_key_name: foreign_key.foreign_key_name(),
+            host_table_schema: host_table.table_schema(),
+            host_table_name: host_table.table_name(),
+            host_table_doc: host_table.table_doc(database),
+            host_columns: foreign_key.host_columns(database).map(ColumnLike::column_name).collect(),
+            referenced_table_schema: referenced_table.table_schema(),
+            referenced_table_name: referenced_table.table_name(),
+            referenced_columns: foreign_key
+                .referenced_columns(database)
+                .map(ColumnLike::column_name)
+                .collect(),
+            host_primary_key: foreign_key.is_host_primary_key(database),
+            referenced_primary_key: foreign_key.is_referenced_primary_key(database),
+            self_referential: foreign_key.is_self_referential(database),
+        })
+    }
+
+    /// Returns the name of the foreign key, if it has one.
+    #[must_use]
+    pub fn foreign_key_name(&self) -> Option<&'a str> {
+        self.foreign_key_name
+    }
+
+    /// Returns the schema of the table defining the foreign key, if any.
+    #[must_use]
+    pub fn host_table_schema(&self) -> Option<&'a str> {
+        self.host_table_schema
+    }
+
+    /// Returns the name of the table defining the foreign key.
+    #[must_use]
+    pub fn host_table_name(&self) -> &'a str {
+        self.host_table_name
+    }
+
+    /// Returns the documentation or comment of the table defining the
+    /// foreign key, if any.
+    #[must_use]
+    pub fn host_table_doc(&self) -> Option<&'a str> {
+        self.host_table_doc
+    }
+
+    /// Returns the names of the columns of the foreign key in the host table.
+    #[must_use]
+    pub fn host_columns(&self) -> &[&'a str] {
+        &self.host_columns
+    }
+
+    /// Returns the schema of the referenced table, if any.
+    #[must_use]
+    pub fn referenced_table_schema(&self) -> Option<&'a str> {
+        self.referenced_table_schema
+    }
+
+    /// Returns the name of the referenced table.
+    #[must_use]
+    pub fn referenced_table_name(&self) -> &'a str {
+        self.referenced_table_name
+    }
+
+    /// Returns the names of the referenced columns.
+    #[must_use]
+    pub fn referenced_columns(&self) -> &[&'a str] {
+        &self.referenced_columns
+    }
+
+    /// Returns whether the host columns are the primary key of the host
+    /// table.
+    #[must_use]
+    pub fn is_host_primary_key(&self) -> bool {
+        self.host_primary_key
+    }
+
+    /// Returns whether the referenced columns are the primary key of the
+    /// referenced table.
+    #[must_use]
+    pub fn is_referenced_primary_key(&self) -> bool {
+        self.referenced_primary_key
+    }
+
+    /// Returns whether the foreign key references its own table.
+    #[must_use]
+    pub fn is_self_referential(&self) -> bool {
+        self.self_referential
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{ast::CreateTable, dialect::PostgreSqlDialect};
+
+    use super::ExtensionStrategy;
+    use crate::{
+        structs::{IdentifierCasing, ParserDB, ParserOptions},
+        traits::{DatabaseLike, TableLike},
+    };
+
+    fn parse(sql: &str, strategy: ExtensionStrategy) -> ParserDB {
+        ParserDB::parse_with_options::<PostgreSqlDialect>(
+            sql,
+            ParserOptions::default().with_extension_strategy(strategy),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn explicit_comment_requires_the_marker() {
+        let db = parse(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE marked (id INT PRIMARY KEY REFERENCES parent (id));
+            CREATE TABLE unmarked (id INT PRIMARY KEY REFERENCES parent (id));
+            CREATE TABLE referrer (id INT PRIMARY KEY, parent_id INT REFERENCES parent (id));
+            COMMENT ON TABLE marked IS 'Details of a parent. @extends parent';
+            COMMENT ON TABLE referrer IS '@extends public.parent';
+            ",
+            ExtensionStrategy::ExplicitComment,
+        );
+        let parent = db.table(None, "parent").unwrap();
+        let children: Vec<&str> =
+            parent.extension_children(&db).map(TableLike::table_name).collect();
+        assert_eq!(children, ["marked"]);
+        assert!(db.table(None, "unmarked").unwrap().extension_parent(&db).is_none());
+    }
+
+    #[test]
+    fn explicit_comment_does_not_need_primary_keys() {
+        let db = parse(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent (id));
+            COMMENT ON TABLE child IS '@extends parent';
+            ",
+            ExtensionStrategy::ExplicitComment,
+        );
+        let child = db.table(None, "child").unwrap();
+        assert_eq!(child.extension_parent(&db), db.table(None, "parent"));
+    }
+
+    #[test]
+    fn same_name_strategy_compares_column_names() {
+        const SQL: &str = "
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE same (id INT PRIMARY KEY REFERENCES parent (id));
+            CREATE TABLE renamed (parent_id INT PRIMARY KEY REFERENCES parent (id));
+        ";
+        let db = parse(SQL, ExtensionStrategy::PkFkSameName);
+        let parent = db.table(None, "parent").unwrap();
+        let children: Vec<&str> =
+            parent.extension_children(&db).map(TableLike::table_name).collect();
+        assert_eq!(children, ["same"]);
+
+        let db = parse(SQL, ExtensionStrategy::default());
+        assert_eq!(db.table(None, "parent").unwrap().extension_children(&db).count(), 2);
+    }
+
+    #[test]
+    fn documents_extension_matches_qualified_names() {
+        let db = parse(
+            r#"
+            CREATE SCHEMA app;
+            CREATE TABLE app.parent (id INT PRIMARY KEY);
+            CREATE TABLE parent (id INT PRIMARY KEY);
+            CREATE TABLE "Quoted" (id INT PRIMARY KEY);
+            "#,
+            ExtensionStrategy::ExplicitComment,
+        );
+        let casing = IdentifierCasing::Lowercase;
+        let qualified = db.table(Some("app"), "parent").unwrap();
+        let parent = db.table(None, "parent").unwrap();
+        let quoted = db.table(None, "\"Quoted\"").unwrap();
+
+        let documents = |documentation: &str, table: &CreateTable| {
+            ExtensionStrategy::documents_extension(documentation, table, casing)
+        };
+        assert!(documents("@extends app.parent.", qualified));
+        assert!(documents("@extends Parent", parent));
+        assert!(!documents("@extends other.parent", qualified));
+        assert!(!documents("extends parent", parent));
+        assert!(!documents("@extends", parent));
+        assert!(documents("@extends \"Quoted\"", quoted));
+        assert!(!documents("@extends Quoted", quoted));
+        // MySQL ignores case, even within quotes.
+        assert!(ExtensionStrategy::documents_extension(
+            "@extends quoted",
+            quoted,
+            IdentifierCasing::Insensitive
+        ));
+    }
+
+    #[test]
+    fn custom_strategies_compare_by_predicate() {
+        let strategy = ExtensionStrategy::custom(|candidate| candidate.is_host_primary_key());
+        assert_eq!(strategy, strategy.clone());
+        assert_ne!(
+            strategy,
+            ExtensionStrategy::custom(|candidate| candidate.is_host_primary_key())
+        );
+        assert_ne!(strategy, ExtensionStrategy::PkFkAnyName);
+    }
+}
@@ -0,0 +1,201 @@
+//! Submodule defining the `FunctionDependencyGraph` struct, describing the
+//! tables which the bodies of the functions of a database read or write.
+
+use alloc::vec::Vec;
+
+use crate::traits::{DatabaseLike, FunctionLike};
+
+/// Graph of the dependencies between the functions of a database and the
+/// tables their bodies read or write.
+///
+/// The edges of the graph go from each function to the tables it references,
+/// as returned by [`FunctionLike::referenced_tables`]. Functions whose body
+/// cannot be analyzed, because it cannot be parsed or references an
+/// ambiguous relation, have no edges and are listed by
+/// [`FunctionDependencyGraph::unanalyzed_functions`].
+#[derive(Debug, Clone)]
+pub struct FunctionDependencyGraph<'db, DB: DatabaseLike> {
+    /// The functions of the database, in the order of the database.
+    functions: Vec<&'db DB::Function>,
+    /// The tables referenced by at least one function, in first-seen order.
+    tables: Vec<&'db DB::Table>,
+    /// The sorted `(function, table)` edges, as indices into `functions` and
+    /// `tables`.
+    edges: Vec<(usize, usize)>,
+    /// The indices of the functions whose body cannot be analyzed.
+    unanalyzed: Vec<usize>,
+}
+
+impl<'db, DB: DatabaseLike> FunctionDependencyGraph<'db, DB> {
+    /// Builds the dependency graph of the functions of the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to build the graph for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE FUNCTION user_count() RETURNS BIGINT AS 'SELECT count(*) FROM users;' LANGUAGE SQL;
+    /// CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+    /// ",
+    /// )?;
+    /// let graph = FunctionDependencyGraph::new(&db);
+    /// assert_eq!(graph.functions().len(), 2);
+    /// let tables: Vec<&str> = graph.tables().iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(tables, ["users"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB) -> Self {
+        let functions: Vec<&'db DB::Function> = database.functions().collect();
+        let mut tables: Vec<&'db DB::Table> = Vec::new();
+        let mut edges = Vec::new();
+        let mut unanalyzed = Vec::new();
+        for (function_number, function) in functions.iter().enumerate() {
+            let Ok(referenced_tables) = function.referenced_tables(database) else {
+                unanalyzed.push(function_number);
+                continue;
+            };
+            for table in referenced_tables {
+                let table_number =
+                    tables.iter().position(|candidate| *candidate == table).unwrap_or_else(|| {
+                        tables.push(table);
+                        tables.len() - 1
+                    });
+                edges.push((function_number, table_number));
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+
+        Self { functions, tables, edges, unanalyzed }
+    }
+
+    /// Returns the functions of the graph, in the order of the database.
+    #[must_use]
+    #[inline]
+    pub fn functions(&self) -> &[&'db DB::Function] {
+        &self.functions
+    }
+
+    /// Returns the tables referenced by at least one function, in the order
+    /// they are first referenced.
+    #[must_use]
+    #[inline]
+    pub fn tables(&self) -> &[&'db DB::Table] {
+        &self.tables
+    }
+
+    /// Returns the index of the provided function in the graph, if any.
+    fn function_number(&self, function: &DB::Function) -> Option<usize> {
+        self.functions.iter().position(|candidate| *candidate == function)
+    }
+
+    /// Returns an iterator over the tables which the body of the provided
+    /// function reads or writes.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The referencing function.
+    pub fn dependencies<'a>(
+        &'a self,
+        function: &'a DB::Function,
+    ) -> impl Iterator<Item = &'db DB::Table> + 'a {
+        let function_number = self.function_number(function);
+        self.edges
+            .iter()
+            .filter(move |(referencing, _)| Some(*referencing) == function_number)
+            .map(|(_, referenced)| self.tables[*referenced])
+    }
+
+    /// Returns an iterator over the functions whose body reads or writes the
+    /// provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The referenced table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE TABLE logs (id INT);
+    /// CREATE FUNCTION purge() RETURNS VOID AS 'DELETE FROM users;' LANGUAGE SQL;
+    /// ",
+    /// )?;
+    /// let graph = db.function_dependency_graph();
+    /// let users = db.table(None, "users").unwrap();
+    /// let logs = db.table(None, "logs").unwrap();
+    /// let dependents: Vec<&str> = graph.dependents(users).map(FunctionLike::name).collect();
+    /// assert_eq!(dependents, ["purge"]);
+    /// assert_eq!(graph.dependents(logs).count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dependents<'a>(
+        &'a self,
+        table: &'a DB::Table,
+    ) -> impl Iterator<Item = &'db DB::Function> + 'a {
+        let table_number = self.tables.iter().position(|candidate| *candidate == table);
+        self.edges
+            .iter()
+            .filter(move |(_, referenced)| Some(*referenced) == table_number)
+            .map(|(referencing, _)| self.functions[*referencing])
+    }
+
+    /// Returns an iterator over the functions whose body cannot be analyzed,
+    /// and whose dependencies are therefore unknown.
+    pub fn unanalyzed_functions(&self) -> impl Iterator<Item = &'db DB::Function> + '_ {
+        self.unanalyzed.iter().map(|function_number| self.functions[*function_number])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, FunctionLike, TableLike},
+    };
+
+    #[test]
+    fn test_dependencies_and_unanalyzed_functions() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE users (id INT);
+            CREATE TABLE orders (id INT, user_id INT);
+            CREATE FUNCTION purge_user(target INT) RETURNS VOID AS $$
+            BEGIN
+                DELETE FROM orders WHERE user_id = target;
+                DELETE FROM users WHERE id = target;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE FUNCTION broken() RETURNS VOID AS 'INSERT INTO;' LANGUAGE SQL;
+            ",
+        )
+        .expect("Failed to parse");
+
+        let graph = db.function_dependency_graph();
+        let purge_user = db.function("purge_user").unwrap();
+        let tables: Vec<&str> = graph.dependencies(purge_user).map(TableLike::table_name).collect();
+        assert_eq!(tables, ["orders", "users"]);
+        let unanalyzed: Vec<&str> = graph.unanalyzed_functions().map(FunctionLike::name).collect();
+        assert_eq!(unanalyzed, ["broken"]);
+        assert_eq!(graph.dependencies(db.function("broken").unwrap()).count(), 0);
+    }
+}
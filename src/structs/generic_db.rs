@@ -4,32 +4,161 @@ mod builder;
 mod database;
 mod sqlparser;
 
-use alloc::{
-    string::{String, ToString},
-    sync::Arc,
-    vec::Vec,
-};
-use core::fmt::Debug;
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+use core::{cmp::Ordering, fmt::Debug, ops::Range};
 
 pub use builder::GenericDBBuilder;
 pub use sqlparser::{ParserDB, ParserDBBuilder};
 
 use crate::{
+    structs::{DialectWarning, Extension, ExtensionStrategy, IdentifierCasing, ValidationWarning},
     traits::{
-        CheckConstraintLike, ColumnGrantLike, ColumnLike, DialectLike, ForeignKeyLike,
-        FunctionLike, IndexLike, PolicyLike, RoleLike, SchemaLike, TableGrantLike, TableLike,
-        TriggerLike, UniqueIndexLike,
+        CheckConstraintLike, ColumnGrantLike, ColumnLike, CustomTypeLike, DefaultPrivilegeLike,
+        DialectLike, ForeignKeyLike, FunctionLike, IndexLike, PolicyLike, PrimaryKeyLike, RoleLike,
+        SchemaLike, SequenceLike, TableGrantLike, TableLike, TriggerLike, UniqueIndexLike,
+        ViewLike,
     },
-    utils::identifier_resolution::stored_identifier_matches_lookup,
+    utils::identifier_resolution::parse_lookup_identifier,
 };
 
+/// Total order used to store functions: overloads sharing the same name,
+/// normalized with the identifier casing of the dialect, are contiguous, and
+/// ties are broken by the full function ordering so that the layout does not
+/// depend on insertion order.
+fn cmp_functions<Func: FunctionLike>(
+    left: &Func,
+    right: &Func,
+    casing: IdentifierCasing,
+) -> Ordering {
+    casing
+        .normalize(left.name(), left.name_is_quoted())
+        .cmp(&casing.normalize(right.name(), right.name_is_quoted()))
+        .then_with(|| left.cmp(right))
+}
+
+/// Total order used to store columns: columns sharing the same name,
+/// normalized with the identifier casing of the dialect, are contiguous, and
+/// ties are broken by the full column ordering.
+fn cmp_columns<C: ColumnLike>(left: &C, right: &C, casing: IdentifierCasing) -> Ordering {
+    casing
+        .normalize(left.column_name(), left.column_name_is_quoted())
+        .cmp(&casing.normalize(right.column_name(), right.column_name_is_quoted()))
+        .then_with(|| left.cmp(right))
+}
+
+/// Total order used to store roles: roles are ordered by their name,
+/// normalized with the identifier casing of the dialect, and ties are broken
+/// by the full role ordering.
+fn cmp_roles<R: RoleLike>(left: &R, right: &R, casing: IdentifierCasing) -> Ordering {
+    casing
+        .normalize(left.name(), left.name_is_quoted())
+        .cmp(&casing.normalize(right.name(), right.name_is_quoted()))
+        .then_with(|| left.cmp(right))
+}
+
+/// Returns the range of the entries of a list sorted by the normalized key of
+/// their name whose key is the one of the provided lookup, where a lookup
+/// wrapped in double quotes is a quoted identifier.
+fn lookup_range<X>(
+    entries: &[X],
+    lookup: &str,
+    casing: IdentifierCasing,
+    key: impl Fn(&X) -> Cow<'_, str>,
+) -> Range<usize> {
+    let lookup = parse_lookup_identifier(lookup);
+    let lookup = casing.normalize(lookup.value(), lookup.is_quoted());
+    let start = entries.partition_point(|entry| key(entry).as_ref() < lookup.as_ref());
+    let length = entries[start..].partition_point(|entry| key(entry).as_ref() == lookup.as_ref());
+    start..start + length
+}
+
+/// Key used to store triggers: trigger names are only unique within the table
+/// they are defined on, so triggers are keyed by their table first. Every part
+/// is normalized with the identifier casing of the dialect.
+fn trigger_key<Tr: TriggerLike>(
+    trigger: &Tr,
+    casing: IdentifierCasing,
+) -> (Option<Cow<'_, str>>, Cow<'_, str>, Cow<'_, str>) {
+    (
+        trigger
+            .table_schema()
+            .map(|schema| casing.normalize(schema, trigger.table_schema_is_quoted())),
+        casing.normalize(trigger.table_name(), trigger.table_name_is_quoted()),
+        casing.normalize(trigger.name(), trigger.name_is_quoted()),
+    )
+}
+
+/// Total order used to store triggers: triggers are ordered by their
+/// normalized key, and ties are broken by their key as spelled in SQL.
+fn cmp_triggers<Tr: TriggerLike>(left: &Tr, right: &Tr, casing: IdentifierCasing) -> Ordering {
+    trigger_key(left, casing).cmp(&trigger_key(right, casing)).then_with(|| {
+        (left.table_schema(), left.table_name(), left.name()).cmp(&(
+            right.table_schema(),
+            right.table_name(),
+            right.name(),
+        ))
+    })
+}
+
+/// Key used to store tables: a table is identified by its schema and name,
+/// as spelled in SQL.
+fn table_key<T: TableLike>(table: &T) -> (Option<&str>, &str) {
+    (table.table_schema(), table.table_name())
+}
+
+/// Total order used to store policies: policy names are only unique within
+/// the table they are defined on, and policies do not expose their table
+/// without a database, so ties between homonymous policies are broken by the
+/// full policy ordering.
+fn cmp_policies<P: PolicyLike>(left: &P, right: &P) -> Ordering {
+    left.name().cmp(right.name()).then_with(|| left.cmp(right))
+}
+
+/// Key used to store views: a view is identified by its schema and name.
+fn view_key<V: ViewLike>(view: &V) -> (Option<&str>, &str) {
+    (view.view_schema(), view.view_name())
+}
+
+/// Key used to store sequences: a sequence is identified by its schema and
+/// name.
+fn sequence_key<Sq: SequenceLike>(sequence: &Sq) -> (Option<&str>, &str) {
+    (sequence.schema(), sequence.name())
+}
+
+/// Key used to store user-defined types: a type is identified by its schema
+/// and name.
+fn custom_type_key<Ty: CustomTypeLike>(custom_type: &Ty) -> (Option<&str>, &str) {
+    (custom_type.schema(), custom_type.name())
+}
+
 /// A generic representation of a database schema.
-pub struct GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+///
+/// # Storage order
+///
+/// Each kind of object is stored sorted by a canonical key, which the
+/// metadata lookups binary search on:
+///
+/// * tables, views, sequences and user-defined types by schema and name,
+/// * schemas and roles by name,
+/// * triggers by the schema and name of their table, then by name,
+/// * functions by normalized name, so that overloads are contiguous, then by
+///   their full ordering,
+/// * policies by name, then by their full ordering, as homonymous policies
+///   may be defined on distinct tables,
+/// * columns by normalized name, so that homonymous columns are contiguous,
+///   then by their full ordering,
+/// * indices, unique indices, primary keys, foreign keys, check constraints
+///   and extensions by their full ordering.
+///
+/// Names are compared as spelled in SQL, without case folding. Grants are
+/// kept in declaration order, as their order may be significant.
+pub struct GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -39,6 +168,10 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     /// SQL dialect of the database.
@@ -55,14 +188,28 @@ where
     indices: Vec<(Arc<I>, I::Meta)>,
     /// List of unique indices in the database.
     unique_indices: Vec<(Arc<U>, U::Meta)>,
+    /// List of primary keys in the database.
+    primary_keys: Vec<(Arc<PK>, PK::Meta)>,
     /// List of foreign keys in the database.
     foreign_keys: Vec<(Arc<F>, F::Meta)>,
+    /// Reverse index of the foreign keys, as pairs of the position of the
+    /// referenced table and of the foreign key sorted by the former, if it
+    /// was computed while building the database.
+    foreign_key_references: Option<Vec<(usize, usize)>>,
     /// List of functions created in the database.
     functions: Vec<(Arc<Func>, Func::Meta)>,
     /// List of triggers created in the database.
     triggers: Vec<(Arc<Tr>, Tr::Meta)>,
+    /// Index of the triggers by table, as pairs of the position of the table
+    /// and of the trigger sorted by the former, if it was computed while
+    /// building the database.
+    table_triggers: Option<Vec<(usize, usize)>>,
     /// List of policies created in the database.
     policies: Vec<(Arc<P>, P::Meta)>,
+    /// Index of the policies by table, as pairs of the position of the table
+    /// and of the policy sorted by the former, if it was computed while
+    /// building the database.
+    table_policies: Option<Vec<(usize, usize)>>,
     /// List of check constraints in the database.
     check_constraints: Vec<(Arc<Ch>, Ch::Meta)>,
     /// List of roles in the database.
@@ -73,15 +220,39 @@ where
     column_grants: Vec<(Arc<CG>, CG::Meta)>,
     /// List of schemas in the database.
     schemas: Vec<(Arc<S>, S::Meta)>,
+    /// List of views in the database.
+    views: Vec<(Arc<V>, V::Meta)>,
+    /// List of sequences in the database.
+    sequences: Vec<(Arc<Sq>, Sq::Meta)>,
+    /// List of user-defined types in the database.
+    custom_types: Vec<(Arc<Ty>, Ty::Meta)>,
+    /// List of default privileges in the database, in declaration order.
+    default_privileges: Vec<(Arc<DP>, DP::Meta)>,
+    /// List of extensions installed in the database.
+    extensions: Vec<Extension>,
+    /// Warnings about statements outside the capabilities of the dialect.
+    dialect_warnings: Vec<DialectWarning>,
+    /// Warnings about statements skipped for failing a validation.
+    validation_warnings: Vec<ValidationWarning>,
+    /// Rule detecting the foreign keys defining table extensions.
+    extension_strategy: ExtensionStrategy,
+    /// Schemas searched, in order, for the objects referred to without a
+    /// schema.
+    search_path: Vec<String>,
+    /// The SQL files the database was loaded from, kept to only re-parse the
+    /// changed ones in [`ParserDB::update_from_paths`].
+    #[cfg(feature = "std")]
+    source_cache: Option<Arc<sqlparser::SourceCache>>,
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D> Debug
-    for GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D> Debug
+    for GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -91,6 +262,10 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -102,26 +277,40 @@ where
             .field("columns", &self.columns.len())
             .field("indices", &self.indices.len())
             .field("unique_indices", &self.unique_indices.len())
+            .field("primary_keys", &self.primary_keys.len())
             .field("foreign_keys", &self.foreign_keys.len())
+            .field("foreign_key_references", &self.foreign_key_references.as_ref().map(Vec::len))
             .field("functions", &self.functions.len())
             .field("triggers", &self.triggers.len())
+            .field("table_triggers", &self.table_triggers.as_ref().map(Vec::len))
             .field("policies", &self.policies.len())
+            .field("table_policies", &self.table_policies.as_ref().map(Vec::len))
             .field("check_constraints", &self.check_constraints.len())
             .field("roles", &self.roles.len())
             .field("table_grants", &self.table_grants.len())
             .field("column_grants", &self.column_grants.len())
             .field("schemas", &self.schemas.len())
+            .field("views", &self.views.len())
+            .field("sequences", &self.sequences.len())
+            .field("custom_types", &self.custom_types.len())
+            .field("default_privileges", &self.default_privileges.len())
+            .field("extensions", &self.extensions.len())
+            .field("dialect_warnings", &self.dialect_warnings.len())
+            .field("validation_warnings", &self.validation_warnings.len())
+            .field("extension_strategy", &self.extension_strategy)
+            .field("search_path", &self.search_path)
             .finish()
     }
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D> Clone
-    for GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D> Clone
+    for GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -131,6 +320,10 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     fn clone(&self) -> Self {
@@ -142,26 +335,42 @@ where
             columns: self.columns.clone(),
             indices: self.indices.clone(),
             unique_indices: self.unique_indices.clone(),
+            primary_keys: self.primary_keys.clone(),
             foreign_keys: self.foreign_keys.clone(),
+            foreign_key_references: self.foreign_key_references.clone(),
             functions: self.functions.clone(),
             triggers: self.triggers.clone(),
+            table_triggers: self.table_triggers.clone(),
             policies: self.policies.clone(),
+            table_policies: self.table_policies.clone(),
             check_constraints: self.check_constraints.clone(),
             roles: self.roles.clone(),
             table_grants: self.table_grants.clone(),
             column_grants: self.column_grants.clone(),
             schemas: self.schemas.clone(),
+            views: self.views.clone(),
+            sequences: self.sequences.clone(),
+            custom_types: self.custom_types.clone(),
+            default_privileges: self.default_privileges.clone(),
+            extensions: self.extensions.clone(),
+            dialect_warnings: self.dialect_warnings.clone(),
+            validation_warnings: self.validation_warnings.clone(),
+            extension_strategy: self.extension_strategy.clone(),
+            search_path: self.search_path.clone(),
+            #[cfg(feature = "std")]
+            source_cache: self.source_cache.clone(),
         }
     }
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
-    GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
+    GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -171,6 +380,10 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     /// Creates a new `GenericDBBuilder` instance.
@@ -179,7 +392,7 @@ where
     pub fn new(
         catalog_name: String,
         dialect: D,
-    ) -> GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D> {
+    ) -> GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D> {
         GenericDBBuilder::new(catalog_name, dialect)
     }
 
@@ -206,18 +419,7 @@ where
     /// ```
     pub fn table_metadata(&self, table: &T) -> Option<&T::Meta> {
         self.tables
-            .binary_search_by_key(
-                &(
-                    table.table_schema().map(alloc::string::ToString::to_string),
-                    table.table_name().to_string(),
-                ),
-                |(t, _)| {
-                    (
-                        t.table_schema().map(alloc::string::ToString::to_string),
-                        t.table_name().to_string(),
-                    )
-                },
-            )
+            .binary_search_by(|(t, _)| table_key(t.as_ref()).cmp(&table_key(table)))
             .ok()
             .map(|index| &self.tables[index].1)
     }
@@ -241,7 +443,9 @@ where
     /// ```
     pub fn column_metadata(&self, column: &C) -> Option<&C::Meta> {
         self.columns
-            .binary_search_by(|(c, _)| c.as_ref().cmp(column))
+            .binary_search_by(|(c, _)| {
+                cmp_columns(c.as_ref(), column, self.dialect.identifier_casing())
+            })
             .ok()
             .map(|index| &self.columns[index].1)
     }
@@ -294,6 +498,13 @@ where
             .map(|index| &self.check_constraints[index].1)
     }
 
+    /// Iterates mutably over the check constraints and their metadata.
+    pub(crate) fn check_constraints_metadata_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&Ch, &mut Ch::Meta)> {
+        self.check_constraints.iter_mut().map(|(c, m)| ((*c).as_ref(), m))
+    }
+
     /// Returns a reference to the metadata of the specified foreign key, if it
     /// exists in the database.
     ///
@@ -347,6 +558,17 @@ where
             .map(|index| &self.indices[index].1)
     }
 
+    /// Iterates mutably over the indices and their metadata.
+    pub(crate) fn indices_metadata_mut(&mut self) -> impl Iterator<Item = (&I, &mut I::Meta)> {
+        self.indices.iter_mut().map(|(i, m)| ((*i).as_ref(), m))
+    }
+
+    /// Iterates mutably over the sequences, which may be replaced provided
+    /// that their schema and name are kept.
+    pub(crate) fn sequences_mut(&mut self) -> impl Iterator<Item = &mut Arc<Sq>> {
+        self.sequences.iter_mut().map(|(s, _)| s)
+    }
+
     /// Returns a reference of the function by name.
     ///
     /// # Arguments
@@ -369,12 +591,151 @@ where
     /// ```
     #[must_use]
     pub fn function(&self, name: &str) -> Option<&Func> {
-        self.functions.iter().find_map(|(function, _)| {
-            stored_identifier_matches_lookup(function.name(), function.name_is_quoted(), name)
-                .then_some(function.as_ref())
+        self.function_overloads(name).next()
+    }
+
+    /// Returns the index range of the columns matching the provided name.
+    fn column_range(&self, name: &str) -> Range<usize> {
+        let casing = self.dialect.identifier_casing();
+        lookup_range(&self.columns, name, casing, |(column, _)| {
+            casing.normalize(column.column_name(), column.column_name_is_quoted())
+        })
+    }
+
+    /// Builds an index from the tables to the provided objects, as pairs of
+    /// the position of the table and of the object sorted by the former,
+    /// leaving out the objects whose table cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `tables` - The table of each object, if it can be resolved.
+    fn table_index<'db>(&self, tables: impl Iterator<Item = Option<&'db T>>) -> Vec<(usize, usize)>
+    where
+        T: 'db,
+    {
+        let mut index: Vec<(usize, usize)> = tables
+            .enumerate()
+            .filter_map(|(position, table)| {
+                let table = table?;
+                let table_id = self
+                    .tables
+                    .binary_search_by(|(t, _)| table_key(t.as_ref()).cmp(&table_key(table)))
+                    .ok()?;
+                Some((table_id, position))
+            })
+            .collect();
+        index.sort_unstable();
+        index
+    }
+
+    /// Returns the entries of an index built by [`Self::table_index`] for the
+    /// provided table.
+    fn table_entries<'index>(
+        &self,
+        index: &'index [(usize, usize)],
+        table: &T,
+    ) -> &'index [(usize, usize)] {
+        let Ok(table_id) =
+            self.tables.binary_search_by(|(t, _)| table_key(t.as_ref()).cmp(&table_key(table)))
+        else {
+            return &[];
+        };
+        let start = index.partition_point(|(id, _)| *id < table_id);
+        let length = index[start..].partition_point(|(id, _)| *id == table_id);
+        &index[start..start + length]
+    }
+
+    /// Builds the reverse index of the foreign keys, leaving out those whose
+    /// referenced table cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `referenced_table` - Resolves the table referenced by a foreign key.
+    fn index_foreign_key_references(
+        &mut self,
+        referenced_table: impl for<'db> Fn(&'db Self, &'db F) -> Option<&'db T>,
+    ) {
+        let references = self.table_index(
+            self.foreign_keys
+                .iter()
+                .map(|(foreign_key, _)| referenced_table(self, foreign_key.as_ref())),
+        );
+        self.foreign_key_references = Some(references);
+    }
+
+    /// Builds the index of the triggers by table, leaving out those whose
+    /// table cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger_table` - Resolves the table a trigger is defined on.
+    fn index_table_triggers(
+        &mut self,
+        trigger_table: impl for<'db> Fn(&'db Self, &'db Tr) -> Option<&'db T>,
+    ) {
+        let triggers = self.table_index(
+            self.triggers.iter().map(|(trigger, _)| trigger_table(self, trigger.as_ref())),
+        );
+        self.table_triggers = Some(triggers);
+    }
+
+    /// Builds the index of the policies by table, leaving out those whose
+    /// table cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_table` - Resolves the table a policy is defined on.
+    fn index_table_policies(
+        &mut self,
+        policy_table: impl for<'db> Fn(&'db Self, &'db P) -> Option<&'db T>,
+    ) {
+        let policies = self.table_index(
+            self.policies.iter().map(|(policy, _)| policy_table(self, policy.as_ref())),
+        );
+        self.table_policies = Some(policies);
+    }
+
+    /// Returns the index range of the overloads of the function with the
+    /// provided name.
+    fn function_overload_range(&self, name: &str) -> Range<usize> {
+        let casing = self.dialect.identifier_casing();
+        lookup_range(&self.functions, name, casing, |(function, _)| {
+            casing.normalize(function.name(), function.name_is_quoted())
         })
     }
 
+    /// Returns an iterator over all the overloads of the function with the
+    /// provided name, in a deterministic order.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function whose overloads to retrieve.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT);")?;
+    /// // `uuidv7` is registered both without arguments and with an interval.
+    /// let arities: Vec<usize> =
+    ///     db.function_overloads("uuidv7").map(|f| f.argument_type_names(&db).count()).collect();
+    /// assert_eq!(arities, vec![0, 1]);
+    /// assert_eq!(db.function_overloads("non_existent").count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn function_overloads(&self, name: &str) -> impl Iterator<Item = &Func> {
+        let casing = self.dialect.identifier_casing();
+        self.functions[self.function_overload_range(name)]
+            .iter()
+            .map(|(function, _)| function.as_ref())
+            .filter(move |function| {
+                casing.matches_lookup(function.name(), function.name_is_quoted(), name)
+            })
+    }
+
     /// Returns a reference to the metadata of the specified function, if it
     /// exists in the database.
     ///
@@ -397,12 +758,20 @@ where
     /// ```
     pub fn function_metadata(&self, function: &Func) -> Option<&Func::Meta> {
         self.functions
-            .iter()
-            .find_map(|(candidate, metadata)| (candidate.as_ref() == function).then_some(metadata))
+            .binary_search_by(|(candidate, _)| {
+                cmp_functions(candidate.as_ref(), function, self.dialect.identifier_casing())
+            })
+            .ok()
+            .map(|index| &self.functions[index].1)
     }
 
     /// Returns a reference of the trigger by name.
     ///
+    /// Trigger names are only unique within a table: when several tables
+    /// define a trigger with the same name, the one on the first table in
+    /// `(schema, table)` order is returned. Use [`TableLike::trigger`] to
+    /// disambiguate.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the trigger to retrieve.
@@ -428,10 +797,11 @@ where
     /// ```
     #[must_use]
     pub fn trigger(&self, name: &str) -> Option<&Tr> {
+        let casing = self.dialect.identifier_casing();
         self.triggers
-            .binary_search_by(|(t, _)| t.name().cmp(name))
-            .ok()
-            .map(|index| self.triggers[index].0.as_ref())
+            .iter()
+            .map(|(t, _)| t.as_ref())
+            .find(|t| casing.matches_lookup(t.name(), t.name_is_quoted(), name))
     }
 
     /// Returns a reference to the metadata of the specified trigger, if it
@@ -461,7 +831,9 @@ where
     /// ```
     pub fn trigger_metadata(&self, trigger: &Tr) -> Option<&Tr::Meta> {
         self.triggers
-            .binary_search_by(|(t, _)| t.name().cmp(trigger.name()))
+            .binary_search_by(|(t, _)| {
+                cmp_triggers(t.as_ref(), trigger, self.dialect.identifier_casing())
+            })
             .ok()
             .map(|index| &self.triggers[index].1)
     }
@@ -488,7 +860,7 @@ where
     /// ```
     pub fn policy_metadata(&self, policy: &P) -> Option<&P::Meta> {
         self.policies
-            .binary_search_by(|(p, _)| p.name().cmp(policy.name()))
+            .binary_search_by(|(p, _)| cmp_policies(p.as_ref(), policy))
             .ok()
             .map(|index| &self.policies[index].1)
     }
@@ -516,10 +888,11 @@ where
     /// ```
     #[must_use]
     pub fn role(&self, name: &str) -> Option<&R> {
-        self.roles
-            .binary_search_by(|(r, _)| r.name().cmp(name))
-            .ok()
-            .map(|index| self.roles[index].0.as_ref())
+        let casing = self.dialect.identifier_casing();
+        let range = lookup_range(&self.roles, name, casing, |(role, _)| {
+            casing.normalize(role.name(), role.name_is_quoted())
+        });
+        self.roles[range].first().map(|(role, _)| role.as_ref())
     }
 
     /// Returns a reference to the metadata of the specified role, if it
@@ -543,7 +916,9 @@ where
     /// ```
     pub fn role_metadata(&self, role: &R) -> Option<&R::Meta> {
         self.roles
-            .binary_search_by(|(r, _)| r.name().cmp(role.name()))
+            .binary_search_by(|(r, _)| {
+                cmp_roles(r.as_ref(), role, self.dialect.identifier_casing())
+            })
             .ok()
             .map(|index| &self.roles[index].1)
     }
@@ -567,6 +942,109 @@ where
         &self.catalog_name
     }
 
+    /// Returns the warnings about statements which were parsed despite
+    /// falling outside the capabilities of the dialect of the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+    ///
+    /// let sql = "CREATE ROLE reader;";
+    /// assert!(ParserDB::parse::<PostgreSqlDialect>(sql)?.dialect_warnings().is_empty());
+    /// let warnings = ParserDB::parse::<SQLiteDialect>(sql)?.dialect_warnings().to_vec();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].capability(), DialectCapability::Roles);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn dialect_warnings(&self) -> &[DialectWarning] {
+        &self.dialect_warnings
+    }
+
+    /// Returns the warnings about statements which were skipped for failing
+    /// a validation downgraded to [`crate::structs::ValidationLevel::Warn`],
+    /// in the order they were encountered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sql_traits::structs::{ParserOptions, ValidationClass, ValidationLevel};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let sql = "DROP TABLE archived; CREATE TABLE t (id INT);";
+    /// assert!(ParserDB::parse::<PostgreSqlDialect>(sql).is_err());
+    /// let options =
+    ///     ParserOptions::default().with_level(ValidationClass::MissingObjects, ValidationLevel::Warn);
+    /// let db = ParserDB::parse_with_options::<PostgreSqlDialect>(sql, options)?;
+    /// assert!(db.table(None, "t").is_some());
+    /// assert_eq!(db.validation_warnings().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn validation_warnings(&self) -> &[ValidationWarning] {
+        &self.validation_warnings
+    }
+
+    /// Returns the extensions installed in the database, sorted by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE EXTENSION pgcrypto; CREATE EXTENSION \"uuid-ossp\";",
+    /// )?;
+    /// let names: Vec<&str> = db.extensions().iter().map(Extension::name).collect();
+    /// assert_eq!(names, ["pgcrypto", "uuid-ossp"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    /// Returns the extension with the provided name, if installed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the extension. Surround it with double quotes
+    ///   to match it case-sensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE EXTENSION pgcrypto;")?;
+    /// assert!(db.extension("PGCRYPTO").is_some());
+    /// assert!(db.extension("postgis").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn extension(&self, name: &str) -> Option<&Extension> {
+        let casing = self.dialect.identifier_casing();
+        self.extensions
+            .iter()
+            .find(|extension| casing.matches_lookup(extension.name(), extension.is_quoted(), name))
+    }
+
     /// Iterates over the table and metadata
     ///
     /// # Example
@@ -701,9 +1179,9 @@ where
     /// ```
     #[must_use]
     pub fn schema(&self, name: &str) -> Option<&S> {
+        let casing = self.dialect.identifier_casing();
         self.schemas.iter().find_map(|(s, _)| {
-            stored_identifier_matches_lookup(s.name(), s.name_is_quoted(), name)
-                .then_some(s.as_ref())
+            casing.matches_lookup(s.name(), s.name_is_quoted(), name).then_some(s.as_ref())
         })
     }
 
@@ -1,22 +1,32 @@
 //! Builder for constructing a `GenericDB` instance.
 
+mod journal;
+mod name_index;
+
 use alloc::{
     string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
+use core::cmp::Ordering;
 
 use crate::{
     errors::LookupError,
-    structs::GenericDB,
+    structs::{
+        DialectWarning, Extension, ExtensionStrategy, GenericDB, IdentifierCasing, ObjectFilter,
+        ValidationWarning,
+    },
     traits::{
-        CheckConstraintLike, ColumnGrantLike, ColumnLike, DialectLike, ForeignKeyLike,
-        FunctionLike, IndexLike, PolicyLike, RoleLike, SchemaLike, TableGrantLike, TableLike,
-        TriggerLike, UniqueIndexLike,
+        CheckConstraintLike, ColumnGrantLike, ColumnLike, CustomTypeLike, DefaultPrivilegeLike,
+        DialectLike, ForeignKeyLike, FunctionLike, IndexLike, PolicyLike, PrimaryKeyLike, RoleLike,
+        SchemaLike, SequenceLike, TableGrantLike, TableLike, TriggerLike, UniqueIndexLike,
+        ViewLike,
     },
-    utils::identifier_resolution::identifiers_match,
 };
 
+use journal::{Journal, Journaled, JournaledValue};
+use name_index::NameIndex;
+
 fn format_identifier(value: &str, quoted: bool) -> String {
     if quoted { format!("\"{}\"", value.replace('\"', "\"\"")) } else { value.to_string() }
 }
@@ -32,8 +42,32 @@ fn format_table_lookup_key<T: TableLike>(table: &T) -> String {
     }
 }
 
-fn table_names_match_semantically<T: TableLike>(left: &T, right: &T) -> bool {
-    identifiers_match(
+/// Returns the key under which a table is indexed: its normalized name.
+fn table_index_key<T: TableLike>(table: &T, casing: IdentifierCasing) -> String {
+    casing.normalize(table.table_name(), table.table_name_is_quoted()).into_owned()
+}
+
+/// Returns the key under which a function is indexed: its normalized name.
+fn function_index_key<Func: FunctionLike>(function: &Func, casing: IdentifierCasing) -> String {
+    casing.normalize(function.name(), function.name_is_quoted()).into_owned()
+}
+
+/// Returns the key under which a role is indexed: its normalized name.
+fn role_index_key<R: RoleLike>(role: &R, casing: IdentifierCasing) -> String {
+    casing.normalize(role.name(), role.name_is_quoted()).into_owned()
+}
+
+/// Returns the key under which a trigger is indexed: its normalized name.
+fn trigger_index_key<Tr: TriggerLike>(trigger: &Tr, casing: IdentifierCasing) -> String {
+    casing.normalize(trigger.name(), trigger.name_is_quoted()).into_owned()
+}
+
+fn table_names_match_semantically<T: TableLike>(
+    left: &T,
+    right: &T,
+    casing: IdentifierCasing,
+) -> bool {
+    casing.matches(
         left.table_name(),
         left.table_name_is_quoted(),
         right.table_name(),
@@ -41,18 +75,22 @@ fn table_names_match_semantically<T: TableLike>(left: &T, right: &T) -> bool {
     )
 }
 
-fn table_schema_is_public<T: TableLike>(table: &T) -> bool {
+fn table_schema_is_public<T: TableLike>(table: &T, casing: IdentifierCasing) -> bool {
     table.table_schema().is_some_and(|schema_name| {
-        identifiers_match(schema_name, table.table_schema_is_quoted(), "public", false)
+        casing.matches(schema_name, table.table_schema_is_quoted(), "public", false)
     })
 }
 
-fn tables_share_semantic_identity<T: TableLike>(left: &T, right: &T) -> bool {
-    table_names_match_semantically(left, right)
+fn tables_share_semantic_identity<T: TableLike>(
+    left: &T,
+    right: &T,
+    casing: IdentifierCasing,
+) -> bool {
+    table_names_match_semantically(left, right, casing)
         && match (left.table_schema(), right.table_schema()) {
             (None, None) => true,
             (Some(left_schema), Some(right_schema)) => {
-                identifiers_match(
+                casing.matches(
                     left_schema,
                     left.table_schema_is_quoted(),
                     right_schema,
@@ -63,19 +101,24 @@ fn tables_share_semantic_identity<T: TableLike>(left: &T, right: &T) -> bool {
         }
 }
 
-fn creates_implicit_public_ambiguity<T: TableLike>(left: &T, right: &T) -> bool {
-    table_names_match_semantically(left, right)
-        && ((left.table_schema().is_none() && table_schema_is_public(right))
-            || (right.table_schema().is_none() && table_schema_is_public(left)))
+fn creates_implicit_public_ambiguity<T: TableLike>(
+    left: &T,
+    right: &T,
+    casing: IdentifierCasing,
+) -> bool {
+    table_names_match_semantically(left, right, casing)
+        && ((left.table_schema().is_none() && table_schema_is_public(right, casing))
+            || (right.table_schema().is_none() && table_schema_is_public(left, casing)))
 }
 
 /// Builder for constructing a `GenericDB` instance.
-pub struct GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+pub struct GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -85,6 +128,10 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     /// SQL dialect of the database.
@@ -92,42 +139,80 @@ where
     /// Catalog name of the database.
     catalog_name: String,
     /// Timezone of the database.
-    timezone: Option<String>,
+    timezone: JournaledValue<Option<String>>,
     /// List of tables in the database.
-    tables: Vec<(Arc<T>, T::Meta)>,
+    tables: Journaled<(Arc<T>, T::Meta)>,
     /// List of columns in the database.
-    columns: Vec<(Arc<C>, C::Meta)>,
+    columns: Journaled<(Arc<C>, C::Meta)>,
     /// List of indices in the database.
-    indices: Vec<(Arc<I>, I::Meta)>,
+    indices: Journaled<(Arc<I>, I::Meta)>,
     /// List of unique indices in the database.
-    unique_indices: Vec<(Arc<U>, U::Meta)>,
+    unique_indices: Journaled<(Arc<U>, U::Meta)>,
+    /// List of primary keys in the database.
+    primary_keys: Journaled<(Arc<PK>, PK::Meta)>,
     /// List of foreign keys in the database.
-    foreign_keys: Vec<(Arc<F>, F::Meta)>,
+    foreign_keys: Journaled<(Arc<F>, F::Meta)>,
     /// List of functions created in the database.
-    functions: Vec<(Arc<Func>, Func::Meta)>,
+    functions: Journaled<(Arc<Func>, Func::Meta)>,
     /// List of triggers created in the database.
-    triggers: Vec<(Arc<Tr>, Tr::Meta)>,
+    triggers: Journaled<(Arc<Tr>, Tr::Meta)>,
     /// List of policies created in the database.
-    policies: Vec<(Arc<P>, P::Meta)>,
+    policies: Journaled<(Arc<P>, P::Meta)>,
     /// List of check constraints in the database.
-    check_constraints: Vec<(Arc<Ch>, Ch::Meta)>,
+    check_constraints: Journaled<(Arc<Ch>, Ch::Meta)>,
     /// List of roles in the database.
-    roles: Vec<(Arc<R>, R::Meta)>,
+    roles: Journaled<(Arc<R>, R::Meta)>,
     /// List of schemas in the database.
-    schemas: Vec<(Arc<S>, S::Meta)>,
+    schemas: Journaled<(Arc<S>, S::Meta)>,
     /// List of table grants in the database.
-    table_grants: Vec<(Arc<TG>, TG::Meta)>,
+    table_grants: Journaled<(Arc<TG>, TG::Meta)>,
     /// List of column grants in the database.
-    column_grants: Vec<(Arc<CG>, CG::Meta)>,
+    column_grants: Journaled<(Arc<CG>, CG::Meta)>,
+    /// List of views in the database.
+    views: Journaled<(Arc<V>, V::Meta)>,
+    /// List of sequences in the database.
+    sequences: Journaled<(Arc<Sq>, Sq::Meta)>,
+    /// List of user-defined types in the database.
+    custom_types: Journaled<(Arc<Ty>, Ty::Meta)>,
+    /// List of default privileges in the database, in declaration order.
+    default_privileges: Journaled<(Arc<DP>, DP::Meta)>,
+    /// List of extensions installed in the database.
+    extensions: Journaled<Extension>,
+    /// Filter selecting the schemas and tables to load.
+    object_filter: ObjectFilter,
+    /// Warnings about statements outside the capabilities of the dialect.
+    dialect_warnings: Journaled<DialectWarning>,
+    /// Warnings about statements skipped for failing a validation.
+    validation_warnings: Journaled<ValidationWarning>,
+    /// Rule detecting the foreign keys defining table extensions.
+    extension_strategy: ExtensionStrategy,
+    /// Schemas searched, in order, for the objects referred to without a
+    /// schema.
+    search_path: JournaledValue<Vec<String>>,
+    /// Search path set through the builder, restored by `SET search_path TO
+    /// DEFAULT`.
+    default_search_path: Vec<String>,
+    /// Whether the foreign keys referencing missing tables or columns are
+    /// kept unresolved rather than rejected.
+    deferred_foreign_keys: bool,
+    /// Index of the tables by name.
+    table_index: NameIndex,
+    /// Index of the functions by name.
+    function_index: NameIndex,
+    /// Index of the roles by name.
+    role_index: NameIndex,
+    /// Index of the triggers by name.
+    trigger_index: NameIndex,
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
-    GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D> Clone
+    for GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -137,26 +222,174 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
-    /// Returns a mutable reference to the tables list.
-    pub(crate) fn tables_mut(&mut self) -> &mut Vec<(Arc<T>, T::Meta)> {
-        &mut self.tables
+    fn clone(&self) -> Self {
+        Self {
+            dialect: self.dialect.clone(),
+            catalog_name: self.catalog_name.clone(),
+            timezone: self.timezone.clone(),
+            tables: self.tables.clone(),
+            columns: self.columns.clone(),
+            indices: self.indices.clone(),
+            unique_indices: self.unique_indices.clone(),
+            primary_keys: self.primary_keys.clone(),
+            foreign_keys: self.foreign_keys.clone(),
+            functions: self.functions.clone(),
+            triggers: self.triggers.clone(),
+            policies: self.policies.clone(),
+            check_constraints: self.check_constraints.clone(),
+            roles: self.roles.clone(),
+            schemas: self.schemas.clone(),
+            table_grants: self.table_grants.clone(),
+            column_grants: self.column_grants.clone(),
+            views: self.views.clone(),
+            sequences: self.sequences.clone(),
+            custom_types: self.custom_types.clone(),
+            default_privileges: self.default_privileges.clone(),
+            extensions: self.extensions.clone(),
+            object_filter: self.object_filter.clone(),
+            dialect_warnings: self.dialect_warnings.clone(),
+            validation_warnings: self.validation_warnings.clone(),
+            extension_strategy: self.extension_strategy.clone(),
+            search_path: self.search_path.clone(),
+            default_search_path: self.default_search_path.clone(),
+            deferred_foreign_keys: self.deferred_foreign_keys,
+            table_index: self.table_index.clone(),
+            function_index: self.function_index.clone(),
+            role_index: self.role_index.clone(),
+            trigger_index: self.trigger_index.clone(),
+        }
+    }
+}
+
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
+    GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
+where
+    T: TableLike,
+    C: ColumnLike,
+    I: IndexLike,
+    U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
+    F: ForeignKeyLike,
+    Func: FunctionLike,
+    Ch: CheckConstraintLike,
+    Tr: TriggerLike,
+    P: PolicyLike,
+    R: RoleLike,
+    S: SchemaLike,
+    TG: TableGrantLike,
+    CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
+    D: DialectLike,
+{
+    /// Returns a mutable slice of the tables with their metadata.
+    ///
+    /// The tables must keep their names, as the builder indexes them by name:
+    /// use [`Self::retain_tables`], [`Self::remove_table_at`] or
+    /// [`Self::sort_tables_by`] to change the list itself.
+    pub(crate) fn tables_mut(&mut self) -> &mut [(Arc<T>, T::Meta)] {
+        self.tables.make_mut()
+    }
+
+    /// Retains only the tables for which the predicate returns `true`.
+    pub(crate) fn retain_tables(&mut self, predicate: impl FnMut(&(Arc<T>, T::Meta)) -> bool) {
+        self.tables.make_mut().retain(predicate);
+        self.reindex_tables();
+    }
+
+    /// Removes and returns the table at the provided position.
+    pub(crate) fn remove_table_at(&mut self, position: usize) -> (Arc<T>, T::Meta) {
+        let table = self.tables.make_mut().remove(position);
+        self.reindex_tables();
+        table
+    }
+
+    /// Sorts the tables with the provided comparison function.
+    pub(crate) fn sort_tables_by(
+        &mut self,
+        compare: impl FnMut(&(Arc<T>, T::Meta), &(Arc<T>, T::Meta)) -> Ordering,
+    ) {
+        self.tables.make_mut().sort_by(compare);
+        self.reindex_tables();
+    }
+
+    /// Rebuilds the index of the tables by name.
+    fn reindex_tables(&mut self) {
+        let casing = self.identifier_casing();
+        self.table_index =
+            NameIndex::new(self.tables.iter().map(|(t, _)| table_index_key(t, casing)));
+    }
+
+    /// Returns the tables whose name matches the provided identifier,
+    /// whatever their schema.
+    pub(crate) fn tables_named(&self, name: &str, quoted: bool) -> impl Iterator<Item = &T> {
+        self.table_index
+            .positions(&self.identifier_casing().normalize(name, quoted))
+            .iter()
+            .map(move |&position| self.tables[position].0.as_ref())
+    }
+
+    /// Returns the position of the provided table among the tables.
+    pub(crate) fn table_position(&self, table: &T) -> Option<usize> {
+        self.table_index
+            .positions(&table_index_key(table, self.identifier_casing()))
+            .iter()
+            .copied()
+            .find(|&position| core::ptr::eq(self.tables[position].0.as_ref(), table))
     }
 
     /// Returns a mutable reference to the table grants list.
     pub(crate) fn table_grants_mut(&mut self) -> &mut Vec<(Arc<TG>, TG::Meta)> {
-        &mut self.table_grants
+        self.table_grants.make_mut()
     }
 
     /// Returns a mutable reference to the column grants list.
     pub(crate) fn column_grants_mut(&mut self) -> &mut Vec<(Arc<CG>, CG::Meta)> {
-        &mut self.column_grants
+        self.column_grants.make_mut()
+    }
+
+    /// Returns a slice of function Arc references with their metadata.
+    pub(crate) fn functions(&self) -> &[(Arc<Func>, Func::Meta)] {
+        &self.functions
+    }
+
+    /// Returns a mutable slice of the functions with their metadata, which
+    /// must keep their names.
+    pub(crate) fn functions_mut(&mut self) -> &mut [(Arc<Func>, Func::Meta)] {
+        self.functions.make_mut()
     }
 
-    /// Returns a mutable reference to the functions list.
-    pub(crate) fn functions_mut(&mut self) -> &mut Vec<(Arc<Func>, Func::Meta)> {
-        &mut self.functions
+    /// Retains only the functions for which the predicate returns `true`.
+    pub(crate) fn retain_functions(
+        &mut self,
+        predicate: impl FnMut(&(Arc<Func>, Func::Meta)) -> bool,
+    ) {
+        self.functions.make_mut().retain(predicate);
+        self.reindex_functions();
+    }
+
+    /// Rebuilds the index of the functions by name.
+    fn reindex_functions(&mut self) {
+        let casing = self.identifier_casing();
+        self.function_index =
+            NameIndex::new(self.functions.iter().map(|(f, _)| function_index_key(f, casing)));
+    }
+
+    /// Returns the functions whose name matches the provided identifier,
+    /// whatever their schema and arguments.
+    pub(crate) fn functions_named(&self, name: &str, quoted: bool) -> impl Iterator<Item = &Func> {
+        self.function_index
+            .positions(&self.identifier_casing().normalize(name, quoted))
+            .iter()
+            .map(move |&position| self.functions[position].0.as_ref())
     }
 
     /// Returns a slice of check constraint Arc references with their metadata.
@@ -164,6 +397,16 @@ where
         &self.check_constraints
     }
 
+    /// Returns a slice of index Arc references with their metadata.
+    pub(crate) fn indices(&self) -> &[(Arc<I>, I::Meta)] {
+        &self.indices
+    }
+
+    /// Returns a slice of unique index Arc references with their metadata.
+    pub(crate) fn unique_indices(&self) -> &[(Arc<U>, U::Meta)] {
+        &self.unique_indices
+    }
+
     /// Returns a slice of policy Arc references with their metadata.
     pub(crate) fn policies(&self) -> &[(Arc<P>, P::Meta)] {
         &self.policies
@@ -191,42 +434,114 @@ where
 
     /// Returns a mutable reference to the columns list.
     pub(crate) fn columns_mut(&mut self) -> &mut Vec<(Arc<C>, C::Meta)> {
-        &mut self.columns
+        self.columns.make_mut()
     }
 
     /// Returns a mutable reference to the indices list.
     pub(crate) fn indices_mut(&mut self) -> &mut Vec<(Arc<I>, I::Meta)> {
-        &mut self.indices
+        self.indices.make_mut()
     }
 
     /// Returns a mutable reference to the unique indices list.
     pub(crate) fn unique_indices_mut(&mut self) -> &mut Vec<(Arc<U>, U::Meta)> {
-        &mut self.unique_indices
+        self.unique_indices.make_mut()
+    }
+
+    /// Returns a mutable reference to the primary keys list.
+    pub(crate) fn primary_keys_mut(&mut self) -> &mut Vec<(Arc<PK>, PK::Meta)> {
+        self.primary_keys.make_mut()
     }
 
     /// Returns a mutable reference to the foreign keys list.
     pub(crate) fn foreign_keys_mut(&mut self) -> &mut Vec<(Arc<F>, F::Meta)> {
-        &mut self.foreign_keys
+        self.foreign_keys.make_mut()
     }
 
     /// Returns a mutable reference to the check constraints list.
     pub(crate) fn check_constraints_mut(&mut self) -> &mut Vec<(Arc<Ch>, Ch::Meta)> {
-        &mut self.check_constraints
+        self.check_constraints.make_mut()
     }
 
-    /// Returns a mutable reference to the triggers list.
-    pub(crate) fn triggers_mut(&mut self) -> &mut Vec<(Arc<Tr>, Tr::Meta)> {
-        &mut self.triggers
+    /// Retains only the triggers for which the predicate returns `true`.
+    pub(crate) fn retain_triggers(&mut self, predicate: impl FnMut(&(Arc<Tr>, Tr::Meta)) -> bool) {
+        self.triggers.make_mut().retain(predicate);
+        self.reindex_triggers();
+    }
+
+    /// Removes the trigger at the provided position.
+    pub(crate) fn remove_trigger_at(&mut self, position: usize) {
+        self.triggers.make_mut().remove(position);
+        self.reindex_triggers();
+    }
+
+    /// Rebuilds the index of the triggers by name.
+    fn reindex_triggers(&mut self) {
+        let casing = self.identifier_casing();
+        self.trigger_index =
+            NameIndex::new(self.triggers.iter().map(|(t, _)| trigger_index_key(t, casing)));
+    }
+
+    /// Returns the positions of the triggers whose name matches the provided
+    /// identifier, in increasing order.
+    pub(crate) fn trigger_positions(&self, name: &str, quoted: bool) -> &[usize] {
+        self.trigger_index.positions(&self.identifier_casing().normalize(name, quoted))
     }
 
     /// Returns a mutable reference to the policies list.
     pub(crate) fn policies_mut(&mut self) -> &mut Vec<(Arc<P>, P::Meta)> {
-        &mut self.policies
+        self.policies.make_mut()
+    }
+
+    /// Returns a mutable slice of the roles with their metadata, which must
+    /// keep their names.
+    pub(crate) fn roles_mut(&mut self) -> &mut [(Arc<R>, R::Meta)] {
+        self.roles.make_mut()
+    }
+
+    /// Retains only the roles for which the predicate returns `true`.
+    pub(crate) fn retain_roles(&mut self, predicate: impl FnMut(&(Arc<R>, R::Meta)) -> bool) {
+        self.roles.make_mut().retain(predicate);
+        self.reindex_roles();
     }
 
-    /// Returns a mutable reference to the roles list.
-    pub(crate) fn roles_mut(&mut self) -> &mut Vec<(Arc<R>, R::Meta)> {
-        &mut self.roles
+    /// Rebuilds the index of the roles by name.
+    fn reindex_roles(&mut self) {
+        let casing = self.identifier_casing();
+        self.role_index = NameIndex::new(self.roles.iter().map(|(r, _)| role_index_key(r, casing)));
+    }
+
+    /// Returns the position of the role whose name matches the provided
+    /// identifier, if any.
+    pub(crate) fn role_position(&self, name: &str, quoted: bool) -> Option<usize> {
+        self.role_index
+            .positions(&self.identifier_casing().normalize(name, quoted))
+            .first()
+            .copied()
+    }
+
+    /// Returns a mutable reference to the views list.
+    pub(crate) fn views_mut(&mut self) -> &mut Vec<(Arc<V>, V::Meta)> {
+        self.views.make_mut()
+    }
+
+    /// Returns a mutable reference to the sequences list.
+    pub(crate) fn sequences_mut(&mut self) -> &mut Vec<(Arc<Sq>, Sq::Meta)> {
+        self.sequences.make_mut()
+    }
+
+    /// Returns a mutable reference to the user-defined types list.
+    pub(crate) fn custom_types_mut(&mut self) -> &mut Vec<(Arc<Ty>, Ty::Meta)> {
+        self.custom_types.make_mut()
+    }
+
+    /// Returns a mutable reference to the default privileges list.
+    pub(crate) fn default_privileges_mut(&mut self) -> &mut Vec<(Arc<DP>, DP::Meta)> {
+        self.default_privileges.make_mut()
+    }
+
+    /// Returns a mutable reference to the extensions list.
+    pub(crate) fn extensions_mut(&mut self) -> &mut Vec<Extension> {
+        self.extensions.make_mut()
     }
 
     /// Returns a slice of schema Arc references with their metadata.
@@ -236,7 +551,7 @@ where
 
     /// Returns a mutable reference to the schemas list.
     pub(crate) fn schemas_mut(&mut self) -> &mut Vec<(Arc<S>, S::Meta)> {
-        &mut self.schemas
+        self.schemas.make_mut()
     }
 
     #[must_use]
@@ -245,31 +560,49 @@ where
         Self {
             dialect,
             catalog_name,
-            timezone: None,
-            tables: Vec::new(),
-            columns: Vec::new(),
-            indices: Vec::new(),
-            unique_indices: Vec::new(),
-            foreign_keys: Vec::new(),
-            functions: Vec::new(),
-            triggers: Vec::new(),
-            policies: Vec::new(),
-            check_constraints: Vec::new(),
-            roles: Vec::new(),
-            schemas: Vec::new(),
-            table_grants: Vec::new(),
-            column_grants: Vec::new(),
+            timezone: JournaledValue::default(),
+            tables: Journaled::default(),
+            columns: Journaled::default(),
+            indices: Journaled::default(),
+            unique_indices: Journaled::default(),
+            primary_keys: Journaled::default(),
+            foreign_keys: Journaled::default(),
+            functions: Journaled::default(),
+            triggers: Journaled::default(),
+            policies: Journaled::default(),
+            check_constraints: Journaled::default(),
+            roles: Journaled::default(),
+            schemas: Journaled::default(),
+            table_grants: Journaled::default(),
+            column_grants: Journaled::default(),
+            views: Journaled::default(),
+            sequences: Journaled::default(),
+            custom_types: Journaled::default(),
+            default_privileges: Journaled::default(),
+            extensions: Journaled::default(),
+            object_filter: ObjectFilter::default(),
+            dialect_warnings: Journaled::default(),
+            validation_warnings: Journaled::default(),
+            extension_strategy: ExtensionStrategy::default(),
+            search_path: JournaledValue::default(),
+            default_search_path: Vec::new(),
+            deferred_foreign_keys: false,
+            table_index: NameIndex::default(),
+            function_index: NameIndex::default(),
+            role_index: NameIndex::default(),
+            trigger_index: NameIndex::default(),
         }
     }
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
-    GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
+    GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -279,13 +612,18 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     fn ensure_table_lookup_invariants(&self, table: &T) -> Result<(), LookupError> {
-        for (existing, _) in self.tables() {
-            let existing = existing.as_ref();
-            if tables_share_semantic_identity(existing, table)
-                || creates_implicit_public_ambiguity(existing, table)
+        // Both conflicts require the names to match, so only the tables
+        // sharing the name of the new table are checked.
+        for existing in self.tables_named(table.table_name(), table.table_name_is_quoted()) {
+            if tables_share_semantic_identity(existing, table, self.identifier_casing())
+                || creates_implicit_public_ambiguity(existing, table, self.identifier_casing())
             {
                 return Err(LookupError::TableLookupConflict {
                     table: format_table_lookup_key(table),
@@ -300,10 +638,166 @@ where
     #[must_use]
     #[inline]
     pub fn timezone(mut self, timezone: String) -> Self {
-        self.timezone = Some(timezone);
+        self.set_timezone(timezone);
+        self
+    }
+
+    /// Changes the timezone of the database, as done by a `SET TIME ZONE`
+    /// statement.
+    #[inline]
+    pub(crate) fn set_timezone(&mut self, timezone: String) {
+        self.timezone.set(Some(timezone));
+    }
+
+    /// Sets the filter selecting the schemas and tables to load.
+    #[must_use]
+    #[inline]
+    pub fn object_filter(mut self, object_filter: ObjectFilter) -> Self {
+        self.object_filter = object_filter;
+        self
+    }
+
+    /// Sets the rule detecting the foreign keys defining table extensions.
+    #[must_use]
+    #[inline]
+    pub fn extension_strategy(mut self, extension_strategy: ExtensionStrategy) -> Self {
+        self.extension_strategy = extension_strategy;
         self
     }
 
+    /// Sets the schemas searched, in order, for the objects referred to
+    /// without a schema, which `SET search_path TO DEFAULT` restores.
+    #[must_use]
+    #[inline]
+    pub fn search_path(mut self, search_path: Vec<String>) -> Self {
+        self.default_search_path.clone_from(&search_path);
+        self.search_path.set(search_path);
+        self
+    }
+
+    /// Changes the schemas searched for the objects referred to without a
+    /// schema, as done by a `SET search_path` statement, or restores the
+    /// search path set through the builder if `None`.
+    #[inline]
+    pub(crate) fn set_search_path(&mut self, search_path: Option<Vec<String>>) {
+        let search_path = search_path.unwrap_or_else(|| self.default_search_path.clone());
+        self.search_path.set(search_path);
+    }
+
+    /// Returns the schemas searched, in order, for the objects referred to
+    /// without a schema.
+    #[inline]
+    pub(crate) fn searched_schemas(&self) -> &[String] {
+        &self.search_path
+    }
+
+    /// Sets whether the foreign keys referencing tables or columns which do
+    /// not exist when they are declared are kept unresolved rather than
+    /// rejected.
+    #[must_use]
+    #[inline]
+    pub fn deferred_foreign_keys(mut self, deferred_foreign_keys: bool) -> Self {
+        self.deferred_foreign_keys = deferred_foreign_keys;
+        self
+    }
+
+    /// Returns whether the foreign keys referencing missing tables or columns
+    /// are kept unresolved rather than rejected.
+    #[inline]
+    pub(crate) fn defers_foreign_keys(&self) -> bool {
+        self.deferred_foreign_keys
+    }
+
+    /// Returns the filter selecting the schemas and tables to load.
+    #[inline]
+    pub(crate) fn filter(&self) -> &ObjectFilter {
+        &self.object_filter
+    }
+
+    /// Returns the rule comparing identifiers under the dialect the
+    /// statements are parsed with.
+    #[inline]
+    pub(crate) fn identifier_casing(&self) -> IdentifierCasing {
+        self.dialect.identifier_casing()
+    }
+
+    /// Returns the dialect the statements are parsed with.
+    #[inline]
+    pub(crate) fn dialect(&self) -> &D {
+        &self.dialect
+    }
+
+    /// Returns the journaled objects of the builder.
+    fn journals(&mut self) -> [&mut dyn Journal; 23] {
+        [
+            &mut self.timezone,
+            &mut self.search_path,
+            &mut self.tables,
+            &mut self.columns,
+            &mut self.indices,
+            &mut self.unique_indices,
+            &mut self.primary_keys,
+            &mut self.foreign_keys,
+            &mut self.functions,
+            &mut self.triggers,
+            &mut self.policies,
+            &mut self.check_constraints,
+            &mut self.roles,
+            &mut self.schemas,
+            &mut self.table_grants,
+            &mut self.column_grants,
+            &mut self.views,
+            &mut self.sequences,
+            &mut self.custom_types,
+            &mut self.default_privileges,
+            &mut self.extensions,
+            &mut self.dialect_warnings,
+            &mut self.validation_warnings,
+        ]
+    }
+
+    /// Sets a savepoint, from which the changes later made to the builder
+    /// can be rolled back by [`Self::rollback`].
+    ///
+    /// Appending objects is recorded at no cost, while the first change in
+    /// place to a list of objects since the savepoint copies the list.
+    pub(crate) fn savepoint(&mut self) {
+        for journal in self.journals() {
+            journal.savepoint();
+        }
+    }
+
+    /// Discards the savepoint, keeping the changes made since.
+    pub(crate) fn release(&mut self) {
+        for journal in self.journals() {
+            journal.release();
+        }
+    }
+
+    /// Restores the builder as it was at the savepoint, discarding it.
+    pub(crate) fn rollback(&mut self) {
+        for journal in self.journals() {
+            journal.rollback();
+        }
+        self.reindex_tables();
+        self.reindex_functions();
+        self.reindex_roles();
+        self.reindex_triggers();
+    }
+
+    /// Records a warning about a statement outside the capabilities of the
+    /// dialect, unless an identical warning was already recorded.
+    pub(crate) fn add_dialect_warning(&mut self, warning: DialectWarning) {
+        if !self.dialect_warnings.contains(&warning) {
+            self.dialect_warnings.push(warning);
+        }
+    }
+
+    /// Records a warning about a statement skipped for failing a validation.
+    pub(crate) fn add_validation_warning(&mut self, warning: ValidationWarning) {
+        self.validation_warnings.push(warning);
+    }
+
     /// Adds a table with its metadata to the builder.
     ///
     /// # Errors
@@ -311,9 +805,26 @@ where
     /// Returns an error if adding the table would introduce semantic lookup
     /// ambiguity.
     pub fn add_table(mut self, table: Arc<T>, metadata: T::Meta) -> Result<Self, LookupError> {
+        self.push_table(table, metadata)?;
+        Ok(self)
+    }
+
+    /// Adds a table with its metadata to the builder in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if adding the table would introduce semantic lookup
+    /// ambiguity.
+    pub(crate) fn push_table(
+        &mut self,
+        table: Arc<T>,
+        metadata: T::Meta,
+    ) -> Result<(), LookupError> {
         self.ensure_table_lookup_invariants(table.as_ref())?;
+        self.table_index
+            .insert(table_index_key(table.as_ref(), self.identifier_casing()), self.tables.len());
         self.tables.push((table, metadata));
-        Ok(self)
+        Ok(())
     }
 
     /// Adds multiple tables with their metadata to the builder.
@@ -335,10 +846,16 @@ where
     #[must_use]
     #[inline]
     pub fn add_column(mut self, column: Arc<C>, metadata: C::Meta) -> Self {
-        self.columns.push((column, metadata));
+        self.push_column(column, metadata);
         self
     }
 
+    /// Adds a column with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_column(&mut self, column: Arc<C>, metadata: C::Meta) {
+        self.columns.push((column, metadata));
+    }
+
     /// Adds multiple columns with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -351,10 +868,16 @@ where
     #[must_use]
     #[inline]
     pub fn add_index(mut self, index: Arc<I>, metadata: I::Meta) -> Self {
-        self.indices.push((index, metadata));
+        self.push_index(index, metadata);
         self
     }
 
+    /// Adds an index with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_index(&mut self, index: Arc<I>, metadata: I::Meta) {
+        self.indices.push((index, metadata));
+    }
+
     /// Adds multiple indices with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -367,10 +890,16 @@ where
     #[must_use]
     #[inline]
     pub fn add_unique_index(mut self, index: Arc<U>, metadata: U::Meta) -> Self {
-        self.unique_indices.push((index, metadata));
+        self.push_unique_index(index, metadata);
         self
     }
 
+    /// Adds a unique index with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_unique_index(&mut self, index: Arc<U>, metadata: U::Meta) {
+        self.unique_indices.push((index, metadata));
+    }
+
     /// Adds multiple unique indices with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -382,14 +911,42 @@ where
         self
     }
 
+    /// Adds a primary key with its metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_primary_key(mut self, key: Arc<PK>, metadata: PK::Meta) -> Self {
+        self.push_primary_key(key, metadata);
+        self
+    }
+
+    /// Adds a primary key with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_primary_key(&mut self, key: Arc<PK>, metadata: PK::Meta) {
+        self.primary_keys.push((key, metadata));
+    }
+
+    /// Adds multiple primary keys with their metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_primary_keys(mut self, keys: impl IntoIterator<Item = (Arc<PK>, PK::Meta)>) -> Self {
+        self.primary_keys.extend(keys);
+        self
+    }
+
     /// Adds a foreign key with its metadata to the builder.
     #[must_use]
     #[inline]
     pub fn add_foreign_key(mut self, key: Arc<F>, metadata: F::Meta) -> Self {
-        self.foreign_keys.push((key, metadata));
+        self.push_foreign_key(key, metadata);
         self
     }
 
+    /// Adds a foreign key with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_foreign_key(&mut self, key: Arc<F>, metadata: F::Meta) {
+        self.foreign_keys.push((key, metadata));
+    }
+
     /// Adds multiple foreign keys with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -402,26 +959,52 @@ where
     #[must_use]
     #[inline]
     pub fn add_function(mut self, function: Arc<Func>, metadata: Func::Meta) -> Self {
-        self.functions.push((function, metadata));
+        self.push_function(function, metadata);
         self
     }
 
+    /// Adds a function with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_function(&mut self, function: Arc<Func>, metadata: Func::Meta) {
+        self.function_index.insert(
+            function_index_key(function.as_ref(), self.identifier_casing()),
+            self.functions.len(),
+        );
+        self.functions.push((function, metadata));
+    }
+
     /// Adds a trigger with its metadata to the builder.
     #[must_use]
     #[inline]
     pub fn add_trigger(mut self, trigger: Arc<Tr>, metadata: Tr::Meta) -> Self {
-        self.triggers.push((trigger, metadata));
+        self.push_trigger(trigger, metadata);
         self
     }
 
+    /// Adds a trigger with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_trigger(&mut self, trigger: Arc<Tr>, metadata: Tr::Meta) {
+        self.trigger_index.insert(
+            trigger_index_key(trigger.as_ref(), self.identifier_casing()),
+            self.triggers.len(),
+        );
+        self.triggers.push((trigger, metadata));
+    }
+
     /// Adds a policy with its metadata to the builder.
     #[must_use]
     #[inline]
     pub fn add_policy(mut self, policy: Arc<P>, metadata: P::Meta) -> Self {
-        self.policies.push((policy, metadata));
+        self.push_policy(policy, metadata);
         self
     }
 
+    /// Adds a policy with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_policy(&mut self, policy: Arc<P>, metadata: P::Meta) {
+        self.policies.push((policy, metadata));
+    }
+
     /// Adds multiple policies with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -434,11 +1017,12 @@ where
     #[must_use]
     #[inline]
     pub fn add_functions(
-        mut self,
+        self,
         functions: impl IntoIterator<Item = (Arc<Func>, Func::Meta)>,
     ) -> Self {
-        self.functions.extend(functions);
-        self
+        functions
+            .into_iter()
+            .fold(self, |builder, (function, metadata)| builder.add_function(function, metadata))
     }
 
     /// Returns a vector of function Arc references.
@@ -459,37 +1043,202 @@ where
         &self.roles
     }
 
+    /// Returns a slice of view Arc references with their metadata.
+    #[must_use]
+    pub fn views(&self) -> &[(Arc<V>, V::Meta)] {
+        &self.views
+    }
+
+    /// Returns a slice of sequence Arc references with their metadata.
+    #[must_use]
+    pub fn sequences(&self) -> &[(Arc<Sq>, Sq::Meta)] {
+        &self.sequences
+    }
+
+    /// Returns a slice of user-defined type Arc references with their
+    /// metadata.
+    #[must_use]
+    pub fn custom_types(&self) -> &[(Arc<Ty>, Ty::Meta)] {
+        &self.custom_types
+    }
+
+    /// Returns a slice of default privilege Arc references with their
+    /// metadata, in declaration order.
+    #[must_use]
+    pub fn default_privileges(&self) -> &[(Arc<DP>, DP::Meta)] {
+        &self.default_privileges
+    }
+
+    /// Returns a slice of the extensions installed in the database.
+    #[must_use]
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
     /// Adds a check constraint with its metadata to the builder.
     #[must_use]
     pub fn add_check_constraint(mut self, constraint: Arc<Ch>, metadata: Ch::Meta) -> Self {
-        self.check_constraints.push((constraint, metadata));
+        self.push_check_constraint(constraint, metadata);
         self
     }
 
+    /// Adds a check constraint with its metadata to the builder in place.
+    pub(crate) fn push_check_constraint(&mut self, constraint: Arc<Ch>, metadata: Ch::Meta) {
+        self.check_constraints.push((constraint, metadata));
+    }
+
     /// Adds a role with its metadata to the builder.
     #[must_use]
     #[inline]
     pub fn add_role(mut self, role: Arc<R>, metadata: R::Meta) -> Self {
-        self.roles.push((role, metadata));
+        self.push_role(role, metadata);
         self
     }
 
+    /// Adds a role with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_role(&mut self, role: Arc<R>, metadata: R::Meta) {
+        self.role_index
+            .insert(role_index_key(role.as_ref(), self.identifier_casing()), self.roles.len());
+        self.roles.push((role, metadata));
+    }
+
     /// Adds multiple roles with their metadata to the builder.
     #[must_use]
     #[inline]
-    pub fn add_roles(mut self, roles: impl IntoIterator<Item = (Arc<R>, R::Meta)>) -> Self {
-        self.roles.extend(roles);
+    pub fn add_roles(self, roles: impl IntoIterator<Item = (Arc<R>, R::Meta)>) -> Self {
+        roles.into_iter().fold(self, |builder, (role, metadata)| builder.add_role(role, metadata))
+    }
+
+    /// Adds a view with its metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_view(mut self, view: Arc<V>, metadata: V::Meta) -> Self {
+        self.push_view(view, metadata);
+        self
+    }
+
+    /// Adds a view with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_view(&mut self, view: Arc<V>, metadata: V::Meta) {
+        self.views.push((view, metadata));
+    }
+
+    /// Adds multiple views with their metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_views(mut self, views: impl IntoIterator<Item = (Arc<V>, V::Meta)>) -> Self {
+        self.views.extend(views);
+        self
+    }
+
+    /// Adds a sequence with its metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_sequence(mut self, sequence: Arc<Sq>, metadata: Sq::Meta) -> Self {
+        self.push_sequence(sequence, metadata);
+        self
+    }
+
+    /// Adds a sequence with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_sequence(&mut self, sequence: Arc<Sq>, metadata: Sq::Meta) {
+        self.sequences.push((sequence, metadata));
+    }
+
+    /// Adds multiple sequences with their metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_sequences(
+        mut self,
+        sequences: impl IntoIterator<Item = (Arc<Sq>, Sq::Meta)>,
+    ) -> Self {
+        self.sequences.extend(sequences);
         self
     }
 
+    /// Adds a user-defined type with its metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_custom_type(mut self, custom_type: Arc<Ty>, metadata: Ty::Meta) -> Self {
+        self.push_custom_type(custom_type, metadata);
+        self
+    }
+
+    /// Adds a user-defined type with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_custom_type(&mut self, custom_type: Arc<Ty>, metadata: Ty::Meta) {
+        self.custom_types.push((custom_type, metadata));
+    }
+
+    /// Adds multiple user-defined types with their metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_custom_types(
+        mut self,
+        custom_types: impl IntoIterator<Item = (Arc<Ty>, Ty::Meta)>,
+    ) -> Self {
+        self.custom_types.extend(custom_types);
+        self
+    }
+
+    /// Adds a default privilege with its metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_default_privilege(mut self, default_privilege: Arc<DP>, metadata: DP::Meta) -> Self {
+        self.push_default_privilege(default_privilege, metadata);
+        self
+    }
+
+    /// Adds a default privilege with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_default_privilege(
+        &mut self,
+        default_privilege: Arc<DP>,
+        metadata: DP::Meta,
+    ) {
+        self.default_privileges.push((default_privilege, metadata));
+    }
+
+    /// Adds multiple default privileges with their metadata to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_default_privileges(
+        mut self,
+        default_privileges: impl IntoIterator<Item = (Arc<DP>, DP::Meta)>,
+    ) -> Self {
+        self.default_privileges.extend(default_privileges);
+        self
+    }
+
+    /// Adds an extension to the builder.
+    #[must_use]
+    #[inline]
+    pub fn add_extension(mut self, extension: Extension) -> Self {
+        self.push_extension(extension);
+        self
+    }
+
+    /// Adds an extension to the builder in place.
+    #[inline]
+    pub(crate) fn push_extension(&mut self, extension: Extension) {
+        self.extensions.push(extension);
+    }
+
     /// Adds a schema with its metadata to the builder.
     #[must_use]
     #[inline]
     pub fn add_schema(mut self, schema: Arc<S>, metadata: S::Meta) -> Self {
-        self.schemas.push((schema, metadata));
+        self.push_schema(schema, metadata);
         self
     }
 
+    /// Adds a schema with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_schema(&mut self, schema: Arc<S>, metadata: S::Meta) {
+        self.schemas.push((schema, metadata));
+    }
+
     /// Adds multiple schemas with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -502,10 +1251,16 @@ where
     #[must_use]
     #[inline]
     pub fn add_table_grant(mut self, grant: Arc<TG>, metadata: TG::Meta) -> Self {
-        self.table_grants.push((grant, metadata));
+        self.push_table_grant(grant, metadata);
         self
     }
 
+    /// Adds a table grant with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_table_grant(&mut self, grant: Arc<TG>, metadata: TG::Meta) {
+        self.table_grants.push((grant, metadata));
+    }
+
     /// Adds multiple table grants with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -521,10 +1276,16 @@ where
     #[must_use]
     #[inline]
     pub fn add_column_grant(mut self, grant: Arc<CG>, metadata: CG::Meta) -> Self {
-        self.column_grants.push((grant, metadata));
+        self.push_column_grant(grant, metadata);
         self
     }
 
+    /// Adds a column grant with its metadata to the builder in place.
+    #[inline]
+    pub(crate) fn push_column_grant(&mut self, grant: Arc<CG>, metadata: CG::Meta) {
+        self.column_grants.push((grant, metadata));
+    }
+
     /// Adds multiple column grants with their metadata to the builder.
     #[must_use]
     #[inline]
@@ -537,14 +1298,15 @@ where
     }
 }
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
-    From<GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>>
-    for GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
+    From<GenericDBBuilder<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>>
+    for GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike,
     C: ColumnLike,
     I: IndexLike,
     U: UniqueIndexLike,
+    PK: PrimaryKeyLike,
     F: ForeignKeyLike,
     Func: FunctionLike,
     Ch: CheckConstraintLike,
@@ -554,49 +1316,116 @@ where
     S: SchemaLike,
     TG: TableGrantLike,
     CG: ColumnGrantLike,
+    V: ViewLike,
+    Sq: SequenceLike,
+    Ty: CustomTypeLike,
+    DP: DefaultPrivilegeLike,
     D: DialectLike,
 {
     fn from(
-        mut builder: GenericDBBuilder<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>,
+        mut builder: GenericDBBuilder<
+            T,
+            C,
+            I,
+            U,
+            PK,
+            F,
+            Func,
+            Ch,
+            Tr,
+            P,
+            R,
+            S,
+            TG,
+            CG,
+            V,
+            Sq,
+            Ty,
+            DP,
+            D,
+        >,
     ) -> Self {
         let catalog_name = builder.catalog_name;
+        let casing = builder.identifier_casing();
 
-        builder.tables.sort_unstable_by_key(|(table, _)| {
-            (
-                table.table_schema().map(alloc::string::ToString::to_string),
-                table.table_name().to_string(),
-            )
+        builder.tables.make_mut().sort_unstable_by(|(a, _), (b, _)| {
+            super::table_key(a.as_ref()).cmp(&super::table_key(b.as_ref()))
         });
 
-        builder.columns.sort_unstable_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
-        builder.indices.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-        builder.unique_indices.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-        builder.foreign_keys.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-        builder.functions.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
-        builder.triggers.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
-        builder.policies.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
-        builder.check_constraints.sort_unstable_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
-        builder.roles.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
-        builder.schemas.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
-        // Grants are not sorted as their order may be significant
+        builder
+            .columns
+            .make_mut()
+            .sort_unstable_by(|(a, _), (b, _)| super::cmp_columns(a.as_ref(), b.as_ref(), casing));
+        builder.indices.make_mut().sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        builder.unique_indices.make_mut().sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        builder.primary_keys.make_mut().sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        builder.foreign_keys.make_mut().sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        builder.functions.make_mut().sort_unstable_by(|(a, _), (b, _)| {
+            super::cmp_functions(a.as_ref(), b.as_ref(), casing)
+        });
+        builder.functions.make_mut().dedup_by(|(a, _), (b, _)| a == b);
+        builder
+            .triggers
+            .make_mut()
+            .sort_by(|(a, _), (b, _)| super::cmp_triggers(a.as_ref(), b.as_ref(), casing));
+        builder
+            .policies
+            .make_mut()
+            .sort_unstable_by(|(a, _), (b, _)| super::cmp_policies(a.as_ref(), b.as_ref()));
+        builder
+            .check_constraints
+            .make_mut()
+            .sort_unstable_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+        builder
+            .roles
+            .make_mut()
+            .sort_unstable_by(|(a, _), (b, _)| super::cmp_roles(a.as_ref(), b.as_ref(), casing));
+        builder.schemas.make_mut().sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+        builder.views.make_mut().sort_unstable_by(|(a, _), (b, _)| {
+            super::view_key(a.as_ref()).cmp(&super::view_key(b.as_ref()))
+        });
+        builder.sequences.make_mut().sort_unstable_by(|(a, _), (b, _)| {
+            super::sequence_key(a.as_ref()).cmp(&super::sequence_key(b.as_ref()))
+        });
+        builder.custom_types.make_mut().sort_unstable_by(|(a, _), (b, _)| {
+            super::custom_type_key(a.as_ref()).cmp(&super::custom_type_key(b.as_ref()))
+        });
+        builder.extensions.make_mut().sort_unstable();
+        // Grants and default privileges are not sorted as their order may be
+        // significant
 
         GenericDB {
             dialect: builder.dialect,
             catalog_name,
-            timezone: builder.timezone,
-            tables: builder.tables,
-            columns: builder.columns,
-            indices: builder.indices,
-            unique_indices: builder.unique_indices,
-            foreign_keys: builder.foreign_keys,
-            functions: builder.functions,
-            triggers: builder.triggers,
-            policies: builder.policies,
-            check_constraints: builder.check_constraints,
-            roles: builder.roles,
-            schemas: builder.schemas,
-            table_grants: builder.table_grants,
-            column_grants: builder.column_grants,
+            timezone: builder.timezone.into_inner(),
+            tables: builder.tables.into_inner(),
+            columns: builder.columns.into_inner(),
+            indices: builder.indices.into_inner(),
+            unique_indices: builder.unique_indices.into_inner(),
+            primary_keys: builder.primary_keys.into_inner(),
+            foreign_keys: builder.foreign_keys.into_inner(),
+            foreign_key_references: None,
+            functions: builder.functions.into_inner(),
+            triggers: builder.triggers.into_inner(),
+            table_triggers: None,
+            policies: builder.policies.into_inner(),
+            table_policies: None,
+            check_constraints: builder.check_constraints.into_inner(),
+            roles: builder.roles.into_inner(),
+            schemas: builder.schemas.into_inner(),
+            table_grants: builder.table_grants.into_inner(),
+            column_grants: builder.column_grants.into_inner(),
+            views: builder.views.into_inner(),
+            sequences: builder.sequences.into_inner(),
+            custom_types: builder.custom_types.into_inner(),
+            default_privileges: builder.default_privileges.into_inner(),
+            extensions: builder.extensions.into_inner(),
+            dialect_warnings: builder.dialect_warnings.into_inner(),
+            validation_warnings: builder.validation_warnings.into_inner(),
+            extension_strategy: builder.extension_strategy,
+            search_path: builder.search_path.into_inner(),
+            #[cfg(feature = "std")]
+            source_cache: None,
         }
     }
 }
@@ -0,0 +1,213 @@
+//! Undo log of the builder, restoring the objects as they were before a
+//! statement should the statement be skipped, without copying the objects
+//! the statement leaves untouched.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// Object of the builder whose changes since a savepoint can be rolled back.
+pub(super) trait Journal {
+    /// Sets the savepoint to the current state, replacing any previous one.
+    fn savepoint(&mut self);
+
+    /// Discards the savepoint, keeping the changes made since.
+    fn release(&mut self);
+
+    /// Restores the state at the savepoint and discards it, returning
+    /// whether the state changed since.
+    fn rollback(&mut self) -> bool;
+}
+
+/// State of a [`Journaled`] list when its savepoint was set.
+#[derive(Clone)]
+enum Saved<X> {
+    /// The list was at most appended to since, and is restored by
+    /// truncating it to this length.
+    Length(usize),
+    /// The list was changed in place since, and is restored from this copy.
+    Items(Vec<X>),
+}
+
+/// List whose changes since its savepoint can be rolled back.
+///
+/// Appending to the list is recorded by its length alone, while the first
+/// change in place since the savepoint copies the items the list had.
+#[derive(Clone)]
+pub(super) struct Journaled<X> {
+    /// The items of the list.
+    items: Vec<X>,
+    /// The state of the list at its savepoint, if one is set.
+    saved: Option<Saved<X>>,
+}
+
+impl<X> Default for Journaled<X> {
+    fn default() -> Self {
+        Self { items: Vec::new(), saved: None }
+    }
+}
+
+impl<X> Deref for Journaled<X> {
+    type Target = [X];
+
+    fn deref(&self) -> &[X] {
+        &self.items
+    }
+}
+
+impl<X: Clone> Journaled<X> {
+    /// Appends an item to the list.
+    pub(super) fn push(&mut self, item: X) {
+        self.items.push(item);
+    }
+
+    /// Appends the provided items to the list.
+    pub(super) fn extend(&mut self, items: impl IntoIterator<Item = X>) {
+        self.items.extend(items);
+    }
+
+    /// Returns the items of the list to be changed in place, copying them
+    /// first if they were not changed in place since the savepoint.
+    pub(super) fn make_mut(&mut self) -> &mut Vec<X> {
+        if let Some(Saved::Length(length)) = self.saved {
+            self.saved = Some(Saved::Items(self.items[..length].to_vec()));
+        }
+        &mut self.items
+    }
+
+    /// Returns the items of the list.
+    pub(super) fn into_inner(self) -> Vec<X> {
+        self.items
+    }
+}
+
+impl<X: Clone> Journal for Journaled<X> {
+    fn savepoint(&mut self) {
+        self.saved = Some(Saved::Length(self.items.len()));
+    }
+
+    fn release(&mut self) {
+        self.saved = None;
+    }
+
+    fn rollback(&mut self) -> bool {
+        match self.saved.take() {
+            None => false,
+            Some(Saved::Length(length)) => {
+                let changed = self.items.len() != length;
+                self.items.truncate(length);
+                changed
+            }
+            Some(Saved::Items(items)) => {
+                self.items = items;
+                true
+            }
+        }
+    }
+}
+
+/// Value whose changes since its savepoint can be rolled back.
+#[derive(Clone, Default)]
+pub(super) struct JournaledValue<X> {
+    /// The current value.
+    value: X,
+    /// The value at the savepoint, if one is set, once the value changed.
+    saved: Option<Option<X>>,
+}
+
+impl<X> Deref for JournaledValue<X> {
+    type Target = X;
+
+    fn deref(&self) -> &X {
+        &self.value
+    }
+}
+
+impl<X> JournaledValue<X> {
+    /// Replaces the value.
+    pub(super) fn set(&mut self, value: X) {
+        let previous = core::mem::replace(&mut self.value, value);
+        if let Some(saved @ None) = &mut self.saved {
+            *saved = Some(previous);
+        }
+    }
+
+    /// Returns the value.
+    pub(super) fn into_inner(self) -> X {
+        self.value
+    }
+}
+
+impl<X> Journal for JournaledValue<X> {
+    fn savepoint(&mut self) {
+        self.saved = Some(None);
+    }
+
+    fn release(&mut self) {
+        self.saved = None;
+    }
+
+    fn rollback(&mut self) -> bool {
+        let Some(Some(value)) = self.saved.take() else {
+            return false;
+        };
+        self.value = value;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollback_of_appended_list() {
+        let mut list = Journaled::default();
+        list.push(1);
+        list.savepoint();
+        list.extend([2, 3]);
+        assert_eq!(*list, [1, 2, 3]);
+        assert!(list.rollback());
+        assert_eq!(*list, [1]);
+        assert!(!list.rollback());
+    }
+
+    #[test]
+    fn test_rollback_of_list_changed_in_place() {
+        let mut list = Journaled::default();
+        list.extend([1, 2, 3]);
+        list.savepoint();
+        list.push(4);
+        list.make_mut().retain(|item| item % 2 == 0);
+        list.make_mut()[0] = 5;
+        assert_eq!(*list, [5, 4]);
+        assert!(list.rollback());
+        assert_eq!(*list, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_release_keeps_changes() {
+        let mut list = Journaled::default();
+        let mut value = JournaledValue::default();
+        list.savepoint();
+        value.savepoint();
+        list.push(1);
+        value.set(Some(1));
+        list.release();
+        value.release();
+        assert!(!list.rollback());
+        assert!(!value.rollback());
+        assert_eq!(*list, [1]);
+        assert_eq!(*value, Some(1));
+    }
+
+    #[test]
+    fn test_rollback_of_value() {
+        let mut value = JournaledValue::default();
+        value.set(1);
+        value.savepoint();
+        value.set(2);
+        value.set(3);
+        assert!(value.rollback());
+        assert_eq!(*value, 1);
+    }
+}
@@ -0,0 +1,51 @@
+//! Index of the objects of a builder by name, sparing the lookups performed
+//! while building a database a scan of all the objects.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+/// Positions of the objects of a list, grouped by the key of their name.
+#[derive(Debug, Clone, Default)]
+pub(super) struct NameIndex {
+    /// The positions of the objects sharing each key, in increasing order.
+    positions: BTreeMap<String, Vec<usize>>,
+}
+
+impl NameIndex {
+    /// Builds the index of the objects with the provided keys, in the order
+    /// of their positions.
+    pub(super) fn new(keys: impl IntoIterator<Item = String>) -> Self {
+        let mut index = Self::default();
+        for (position, key) in keys.into_iter().enumerate() {
+            index.insert(key, position);
+        }
+        index
+    }
+
+    /// Records the object with the provided key at the provided position,
+    /// which must follow the positions already recorded.
+    pub(super) fn insert(&mut self, key: String, position: usize) {
+        self.positions.entry(key).or_default().push(position);
+    }
+
+    /// Returns the positions of the objects with the provided key, in
+    /// increasing order.
+    pub(super) fn positions(&self, key: &str) -> &[usize] {
+        self.positions.get(key).map_or(&[][..], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let mut index = NameIndex::new(["a", "b", "a"].map(ToString::to_string));
+        index.insert("b".to_string(), 3);
+        assert_eq!(index.positions("a"), [0, 2]);
+        assert_eq!(index.positions("b"), [1, 3]);
+        assert!(index.positions("c").is_empty());
+    }
+}
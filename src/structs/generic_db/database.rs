@@ -1,22 +1,23 @@
 //! Implementation of the `DatabaseLike` trait for `GenericDB`.
 
 use crate::{
-    structs::GenericDB,
+    structs::{ExtensionStrategy, GenericDB, IdentifierCasing},
     traits::{
-        CheckConstraintLike, ColumnGrantLike, ColumnLike, DatabaseLike, DialectLike,
-        ForeignKeyLike, FunctionLike, IndexLike, PolicyLike, RoleLike, SchemaLike, TableGrantLike,
-        TableLike, TriggerLike, UniqueIndexLike,
+        CheckConstraintLike, ColumnGrantLike, ColumnLike, CustomTypeLike, DatabaseLike,
+        DefaultPrivilegeLike, DialectLike, ForeignKeyLike, FunctionLike, IndexLike, PolicyLike,
+        PrimaryKeyLike, RoleLike, SchemaLike, SequenceLike, TableGrantLike, TableLike, TriggerLike,
+        UniqueIndexLike, ViewLike,
     },
-    utils::identifier_resolution::stored_identifier_matches_lookup,
 };
 
-impl<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D> DatabaseLike
-    for GenericDB<T, C, I, U, F, Func, Ch, Tr, P, R, S, TG, CG, D>
+impl<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D> DatabaseLike
+    for GenericDB<T, C, I, U, PK, F, Func, Ch, Tr, P, R, S, TG, CG, V, Sq, Ty, DP, D>
 where
     T: TableLike<DB = Self>,
     C: ColumnLike<DB = Self>,
     I: IndexLike<DB = Self>,
     U: UniqueIndexLike<DB = Self>,
+    PK: PrimaryKeyLike<DB = Self>,
     F: ForeignKeyLike<DB = Self>,
     Func: FunctionLike<DB = Self>,
     Ch: CheckConstraintLike<DB = Self>,
@@ -26,6 +27,10 @@ where
     S: SchemaLike<DB = Self>,
     TG: TableGrantLike<DB = Self>,
     CG: ColumnGrantLike<DB = Self>,
+    V: ViewLike<DB = Self>,
+    Sq: SequenceLike<DB = Self>,
+    Ty: CustomTypeLike<DB = Self>,
+    DP: DefaultPrivilegeLike<DB = Self>,
     D: DialectLike<DB = Self>,
 {
     type Table = T;
@@ -34,6 +39,7 @@ where
     type ForeignKey = F;
     type Function = Func;
     type UniqueIndex = U;
+    type PrimaryKey = PK;
     type CheckConstraint = Ch;
     type Trigger = Tr;
     type Policy = P;
@@ -41,6 +47,10 @@ where
     type TableGrant = TG;
     type ColumnGrant = CG;
     type Schema = S;
+    type View = V;
+    type Sequence = Sq;
+    type CustomType = Ty;
+    type DefaultPrivilege = DP;
     type Dialect = D;
 
     #[inline]
@@ -48,6 +58,11 @@ where
         &self.dialect
     }
 
+    #[inline]
+    fn extension_strategy(&self) -> &ExtensionStrategy {
+        &self.extension_strategy
+    }
+
     #[inline]
     fn catalog_name(&self) -> &str {
         &self.catalog_name
@@ -63,31 +78,110 @@ where
         self.timezone.as_deref()
     }
 
+    #[inline]
+    fn search_path(&self) -> &[String] {
+        &self.search_path
+    }
+
     fn table(&self, schema: Option<&str>, table_name: &str) -> Option<&Self::Table> {
         self.tables.iter().map(|(table, _)| table.as_ref()).find(|table| {
-            stored_identifier_matches_lookup(
-                table.table_name(),
-                table.table_name_is_quoted(),
-                table_name,
-            ) && match (schema, table.table_schema()) {
-                (None, None) => true,
-                (Some(lookup_schema), Some(table_schema)) => {
-                    stored_identifier_matches_lookup(
-                        table_schema,
-                        table.table_schema_is_quoted(),
-                        lookup_schema,
-                    )
-                }
-                _ => false,
-            }
+            table_matches_lookup(*table, schema, table_name, self.dialect.identifier_casing())
         })
     }
 
+    #[inline]
+    fn columns(&self) -> impl Iterator<Item = &Self::Column> {
+        self.columns.iter().map(|(column, _)| column.as_ref())
+    }
+
+    fn column(
+        &self,
+        schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> Option<&Self::Column> {
+        let casing = self.dialect.identifier_casing();
+        self.columns[self.column_range(column_name)]
+            .iter()
+            .map(|(column, _)| column.as_ref())
+            .filter(|column| {
+                casing.matches_lookup(
+                    column.column_name(),
+                    column.column_name_is_quoted(),
+                    column_name,
+                )
+            })
+            .find(|column| table_matches_lookup(column.table(self), schema, table_name, casing))
+    }
+
+    fn references_to<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::ForeignKey> {
+        // Databases assembled directly from a builder have no reverse index,
+        // and fall back to scanning their foreign keys.
+        let (indexed, scanned) = match &self.foreign_key_references {
+            Some(references) => (
+                Some(
+                    self.table_entries(references, table)
+                        .iter()
+                        .map(|(_, position)| self.foreign_keys[*position].0.as_ref()),
+                ),
+                None,
+            ),
+            None => (
+                None,
+                Some(
+                    self.foreign_keys.iter().map(|(foreign_key, _)| foreign_key.as_ref()).filter(
+                        move |foreign_key| foreign_key.referenced_table(self) == Some(table),
+                    ),
+                ),
+            ),
+        };
+        indexed.into_iter().flatten().chain(scanned.into_iter().flatten())
+    }
+
+    fn triggers_on<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::Trigger> {
+        let (indexed, scanned) = match &self.table_triggers {
+            Some(triggers) => (
+                Some(
+                    self.table_entries(triggers, table)
+                        .iter()
+                        .map(|(_, position)| self.triggers[*position].0.as_ref()),
+                ),
+                None,
+            ),
+            None => {
+                (None, Some(self.triggers().filter(move |trigger| trigger.table(self) == table)))
+            }
+        };
+        indexed.into_iter().flatten().chain(scanned.into_iter().flatten())
+    }
+
+    fn policies_on<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::Policy> {
+        let (indexed, scanned) = match &self.table_policies {
+            Some(policies) => (
+                Some(
+                    self.table_entries(policies, table)
+                        .iter()
+                        .map(|(_, position)| self.policies[*position].0.as_ref()),
+                ),
+                None,
+            ),
+            None => (None, Some(self.policies().filter(move |policy| policy.table(self) == table))),
+        };
+        indexed.into_iter().flatten().chain(scanned.into_iter().flatten())
+    }
+
     fn table_id(&self, table: &Self::Table) -> Option<usize> {
         self.tables
-            .binary_search_by_key(&(table.table_schema(), table.table_name()), |(t, _)| {
-                (t.table_schema(), t.table_name())
-            })
+            .binary_search_by(|(t, _)| super::table_key(t.as_ref()).cmp(&super::table_key(table)))
             .ok()
     }
 
@@ -116,10 +210,11 @@ where
     }
 
     fn function(&self, name: &str) -> Option<&Self::Function> {
-        self.functions.iter().find_map(|(function, _)| {
-            stored_identifier_matches_lookup(function.name(), function.name_is_quoted(), name)
-                .then_some(function.as_ref())
-        })
+        GenericDB::function(self, name)
+    }
+
+    fn function_overloads(&self, name: &str) -> impl Iterator<Item = &Self::Function> {
+        GenericDB::function_overloads(self, name)
     }
 
     fn policies(&self) -> impl Iterator<Item = &Self::Policy> {
@@ -141,4 +236,38 @@ where
     fn schemas(&self) -> impl Iterator<Item = &Self::Schema> {
         self.schemas.iter().map(|(s, _)| s.as_ref())
     }
+
+    fn views(&self) -> impl Iterator<Item = &Self::View> {
+        self.views.iter().map(|(v, _)| v.as_ref())
+    }
+
+    fn sequences(&self) -> impl Iterator<Item = &Self::Sequence> {
+        self.sequences.iter().map(|(s, _)| s.as_ref())
+    }
+
+    fn custom_types(&self) -> impl Iterator<Item = &Self::CustomType> {
+        self.custom_types.iter().map(|(t, _)| t.as_ref())
+    }
+
+    fn default_privileges(&self) -> impl Iterator<Item = &Self::DefaultPrivilege> {
+        self.default_privileges.iter().map(|(p, _)| p.as_ref())
+    }
+}
+
+/// Returns whether the table matches the provided schema and name, following
+/// the identifier rules of [`DatabaseLike::table`].
+fn table_matches_lookup<T: TableLike>(
+    table: &T,
+    schema: Option<&str>,
+    table_name: &str,
+    casing: IdentifierCasing,
+) -> bool {
+    casing.matches_lookup(table.table_name(), table.table_name_is_quoted(), table_name)
+        && match (schema, table.table_schema()) {
+            (None, None) => true,
+            (Some(lookup_schema), Some(table_schema)) => {
+                casing.matches_lookup(table_schema, table.table_schema_is_quoted(), lookup_schema)
+            }
+            _ => false,
+        }
 }
@@ -2,13 +2,18 @@
 
 use alloc::{
     borrow::ToOwned,
+    boxed::Box,
     string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
+use core::ops::ControlFlow;
 #[cfg(feature = "std")]
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "std")]
+use crate::structs::{RollbackMismatch, RollbackSeverity, rollback_mismatches, search_down_files};
+
 #[cfg(feature = "git")]
 use git2::Repository;
 use sql_docs::SqlDoc;
@@ -16,16 +21,17 @@ use sql_docs::SqlDoc;
 use sqlparser::parser::ParserError;
 use sqlparser::{
     ast::{
-        AlterPolicy, AlterPolicyOperation, AlterSchema, AlterSchemaOperation, AlterTableOperation,
-        CheckConstraint, ColumnDef, ColumnOption, CreateFunction, CreateFunctionBody, CreateIndex,
-        CreatePolicy, CreateRole, CreateTable, CreateTrigger, DataType, ExactNumberInfo, Expr,
-        ForeignKeyConstraint, FunctionReturnType, Grant, GranteeName, GranteesType, Ident,
-        IndexColumn, ObjectName, ObjectNamePart, OperateFunctionArg, OrderByExpr, OrderByOptions,
-        RenameTableNameKind, SchemaName, Statement, TableConstraint, TimezoneInfo,
-        UniqueConstraint, Value, ValueWithSpan,
+        AlterIndexOperation, AlterPolicy, AlterPolicyOperation, AlterRoleOperation, AlterSchema,
+        AlterSchemaOperation, AlterTableOperation, CheckConstraint, ColumnDef, ColumnOption,
+        CommentObject, CreateDomain, CreateFunction, CreateFunctionBody, CreateIndex, CreatePolicy,
+        CreateRole, CreateTable, CreateTrigger, CreateView, DataType, Expr, ForeignKeyConstraint,
+        FunctionReturnType, Grant, GrantObjects, GranteeName, GranteesType, Ident, IndexColumn,
+        IndexConstraint, ObjectName, ObjectNamePart, OperateFunctionArg, OrderByExpr,
+        OrderByOptions, PrimaryKeyConstraint, RenameTableNameKind, RoleOption, SchemaName, Spanned,
+        Statement, TableConstraint, UniqueConstraint, UserDefinedTypeRepresentation, Value,
+        ValueWithSpan, visit_relations,
     },
-    dialect::{Dialect, GenericDialect},
-    parser::Parser,
+    dialect::Dialect,
     tokenizer::Span,
 };
 
@@ -33,22 +39,60 @@ use crate::{
     errors::LookupError,
     impls::SqlparserDialect,
     structs::{
-        GenericDB, Schema, TableAttribute, TableMetadata,
-        metadata::{CheckMetadata, IndexMetadata, PolicyMetadata, UniqueIndexMetadata},
+        BuiltinRegistry, CustomType, CustomTypeDefinition, DefaultPrivilege, DialectCapability,
+        DialectWarning, ExpressionAnalysis, Extension, GenericDB, IdentifierCasing, ObjectFilter,
+        ParseReport, ParserOptions, QualifiedName, Schema, Sequence, StatementHandler,
+        TableAttribute, TableMetadata, ValidationClass, ValidationLevel,
+        metadata::{
+            CheckMetadata, FunctionMetadata, IndexMetadata, PolicyMetadata, UniqueIndexMetadata,
+        },
+    },
+    traits::{
+        ColumnLike, DatabaseLike, DialectLike, FunctionLike, ParseObserver, TableLike, ViewLike,
     },
-    traits::{ColumnLike, FunctionLike, TableLike},
     utils::{
         columns_in_expression,
-        identifier_resolution::identifiers_match,
+        function_body::create_function_body_relations,
+        identifier_resolution::parse_lookup_identifier,
         last_str,
+        nextval::nextval_sequence_name,
         object_name::{
-            object_name_identifiers, object_name_last_part, resolve_table_object_name_in_iter,
-            resolve_table_object_name_with_implicit_public_in_iter,
+            object_name_identifiers, object_name_last_part,
+            resolve_table_object_name_in_search_path,
+            resolve_table_object_name_with_implicit_public_in_iter, schema_from_object_name,
+            table_matches_object_name,
         },
+        try_normalize_sqlparser_type,
     },
 };
 
+mod anonymize;
+mod ddl;
+mod default_types;
+mod drop_checks;
+mod extension_functions;
+mod function_calls;
 mod functions_in_expression;
+#[cfg(feature = "std")]
+mod incremental;
+mod index_settings;
+mod mssql;
+mod no_inherit;
+mod pre_passes;
+mod role_memberships;
+#[cfg(feature = "serde")]
+mod snapshot;
+mod source_locations;
+#[cfg(feature = "sqlite")]
+mod sqlite_catalog;
+mod unlogged_sequences;
+mod unparsed_statements;
+mod validator;
+
+#[cfg(feature = "std")]
+pub(crate) use incremental::SourceCache;
+use pre_passes::StrippedSql;
+use validator::Validator;
 
 /// A type alias for a `GenericDBBuilder` specialized for `sqlparser`'s
 /// `CreateTable`.
@@ -57,6 +101,7 @@ pub type ParserDBBuilder = super::GenericDBBuilder<
     TableAttribute<CreateTable, ColumnDef>,
     TableAttribute<CreateTable, CreateIndex>,
     TableAttribute<CreateTable, UniqueConstraint>,
+    TableAttribute<CreateTable, PrimaryKeyConstraint>,
     TableAttribute<CreateTable, ForeignKeyConstraint>,
     CreateFunction,
     TableAttribute<CreateTable, CheckConstraint>,
@@ -66,344 +111,423 @@ pub type ParserDBBuilder = super::GenericDBBuilder<
     Schema,
     Grant,
     Grant,
+    CreateView,
+    Sequence,
+    CustomType,
+    DefaultPrivilege,
     SqlparserDialect,
 >;
 
 impl ParserDBBuilder {
-    /// Checks if a function with the given name is referenced by any schema
-    /// object.
-    ///
-    /// Returns `true` if the function is used by:
-    /// - Check constraints (via their metadata)
-    /// - Policies (via USING or WITH CHECK expressions)
-    /// - Triggers (via EXECUTE FUNCTION)
-    fn is_function_used(&self, function_name: &str, function_name_quoted: bool) -> bool {
-        use crate::traits::{FunctionLike, TriggerLike};
-
-        // Check if any check constraint references the function
-        for (_, metadata) in self.check_constraints() {
-            if metadata.functions().any(|f| {
-                identifiers_match(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
-            }) {
-                return true;
-            }
-        }
+    fn resolve_schema_ident(&self, ident: &Ident) -> Option<&Schema> {
+        resolve_schema_ident_in_iter(
+            self.schemas().iter().map(|(schema, ())| schema.as_ref()),
+            ident,
+            self.identifier_casing(),
+        )
+    }
 
-        // Check if any policy references the function
-        for (_, metadata) in self.policies() {
-            if metadata.using_functions().any(|f| {
-                identifiers_match(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
-            }) {
-                return true;
-            }
-            if metadata.check_functions().any(|f| {
-                identifiers_match(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
-            }) {
-                return true;
-            }
-        }
+    /// Returns the schema the objects created without a schema are placed
+    /// in, which is the first schema of the search path that exists, as in
+    /// `PostgreSQL`, or `None` if no schema of the path exists.
+    fn creation_schema(&self) -> Option<Ident> {
+        self.searched_schemas().iter().find_map(|schema| {
+            let schema = parse_lookup_identifier(schema);
+            let ident = if schema.is_quoted() {
+                Ident::with_quote('"', schema.value())
+            } else {
+                Ident::new(schema.value())
+            };
+            self.resolve_schema_ident(&ident).map(|schema| {
+                if schema.is_quoted() {
+                    Ident::with_quote('"', schema.name())
+                } else {
+                    Ident::new(schema.name())
+                }
+            })
+        })
+    }
 
-        // Check if any trigger executes the function
-        for (trigger, ()) in self.triggers() {
-            if trigger.function_name_ident().is_some_and(|(name, quoted)| {
-                identifiers_match(name, quoted, function_name, function_name_quoted)
-            }) {
-                return true;
-            }
-        }
+    fn resolve_table_object_name(
+        &self,
+        object_name: &ObjectName,
+    ) -> Result<Option<&CreateTable>, LookupError> {
+        let (_, table_ident) = object_name_identifiers(object_name)?;
+        resolve_table_object_name_in_search_path(
+            self.tables_named(&table_ident.value, table_ident.quote_style.is_some()),
+            object_name,
+            self.searched_schemas(),
+            self.identifier_casing(),
+        )
+    }
 
-        false
+    /// Checks whether a role with the given name exists.
+    fn role_exists(&self, ident: &Ident) -> bool {
+        self.role_position(&ident.value, ident.quote_style.is_some()).is_some()
     }
 
-    /// Checks if a table with the given name is referenced by foreign keys from
-    /// other tables.
-    ///
-    /// Returns `true` if any other table has a foreign key pointing to this
-    /// table.
-    fn is_table_referenced(
+    /// Returns the position of the policy with the given name on the table
+    /// with the given name, as policy names are only unique per table.
+    fn policy_position(
         &self,
-        table_name: &str,
-        table_name_quoted: bool,
-        schema_name: Option<&str>,
-        schema_quoted: bool,
-    ) -> bool {
-        for (fk, ()) in self.foreign_keys() {
-            // Check if this FK references the table being dropped
-            // and is NOT from the same table (self-referential FKs are OK to drop)
-            let Some(referenced_table) = resolve_table_object_name_in_iter(
-                self.tables().iter().map(|(table, _)| table.as_ref()),
-                &fk.attribute().foreign_table,
-            )
-            .ok()
-            .flatten() else {
-                continue;
-            };
-            let Some(host_table) = resolve_table_object_name_in_iter(
-                self.tables().iter().map(|(table, _)| table.as_ref()),
-                &fk.table().name,
-            )
-            .ok()
-            .flatten() else {
-                continue;
-            };
+        name: &Ident,
+        table_name: &ObjectName,
+    ) -> Result<Option<usize>, LookupError> {
+        let Some(table) = self.resolve_table_object_name(table_name)? else {
+            return Ok(None);
+        };
+        let casing = self.identifier_casing();
+        Ok(self.policies().iter().position(|(policy, _)| {
+            casing.matches(
+                &policy.name.value,
+                policy.name.quote_style.is_some(),
+                &name.value,
+                name.quote_style.is_some(),
+            ) && self
+                .resolve_table_object_name(&policy.table_name)
+                .ok()
+                .flatten()
+                .is_some_and(|policy_table| core::ptr::eq(policy_table, table))
+        }))
+    }
+}
 
-            let referenced_matches = table_matches_resolved_identity(
-                referenced_table,
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            );
-            let host_matches = table_matches_resolved_identity(
-                host_table,
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            );
+/// A type alias for the result of processing check constraints.
+type CheckConstraintResult =
+    (Vec<Arc<TableAttribute<CreateTable, ColumnDef>>>, Vec<Arc<CreateFunction>>);
 
-            if referenced_matches && !host_matches {
-                return true;
-            }
-        }
+/// A type alias for the result of processing unique constraints.
+type UniqueConstraintResult = (
+    Arc<TableAttribute<CreateTable, UniqueConstraint>>,
+    UniqueIndexMetadata<TableAttribute<CreateTable, UniqueConstraint>>,
+);
+
+/// A type alias for the result of processing inline index constraints.
+type IndexConstraintResult = (
+    Arc<TableAttribute<CreateTable, CreateIndex>>,
+    IndexMetadata<TableAttribute<CreateTable, CreateIndex>>,
+);
 
-        false
+fn object_name_last_identifier(object_name: &ObjectName) -> Option<&Ident> {
+    match object_name.0.last() {
+        Some(ObjectNamePart::Identifier(ident)) => Some(ident),
+        _ => None,
     }
+}
 
-    /// Removes a table and all its associated schema objects.
-    ///
-    /// This removes:
-    /// - The table itself
-    /// - All columns belonging to the table
-    /// - All indices on the table
-    /// - All unique indices on the table
-    /// - All foreign keys from the table
-    /// - All check constraints on the table
-    /// - All triggers on the table
-    /// - All policies on the table
-    /// - All grants on the table
-    fn remove_table(
-        &mut self,
-        table_name: &str,
-        table_name_quoted: bool,
-        schema_name: Option<&str>,
-        schema_quoted: bool,
-    ) {
-        // Remove the table
-        self.tables_mut().retain(|(t, _)| {
-            !table_matches_resolved_identity(
-                t,
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Returns whether the view is named by the provided object name.
+fn view_matches_object_name(
+    view: &CreateView,
+    object_name: &ObjectName,
+    casing: IdentifierCasing,
+) -> bool {
+    let (Some((view_name, view_quoted)), Some((name, quoted))) =
+        (object_name_last_part(&view.name), object_name_last_part(object_name))
+    else {
+        return false;
+    };
+    casing.matches(view_name, view_quoted, name, quoted)
+        && match (schema_from_object_name(&view.name), schema_from_object_name(object_name)) {
+            (None, None) => true,
+            (Some((view_schema, view_schema_quoted)), Some((schema, schema_quoted))) => {
+                casing.matches(view_schema, view_schema_quoted, schema, schema_quoted)
+            }
+            _ => false,
+        }
+}
 
-        // Remove columns belonging to this table
-        self.columns_mut().retain(|(c, ())| {
-            !table_matches_resolved_identity(
-                TableAttribute::table(c),
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Returns the metadata of a function created by a statement, holding the
+/// relations its body reads or writes unless the body cannot be parsed.
+fn created_function_metadata(
+    builder: &ParserDBBuilder,
+    function: &CreateFunction,
+) -> FunctionMetadata {
+    let mut metadata = FunctionMetadata::default();
+    metadata.set_referenced_relations(
+        create_function_body_relations(builder.dialect().sqlparser_dialect(), function).ok(),
+    );
+    metadata
+}
 
-        // Remove indices on this table
-        self.indices_mut().retain(|(i, _)| {
-            !table_matches_resolved_identity(
-                TableAttribute::table(i),
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
+/// Returns the position of the function created with the same name, schema
+/// and argument types as the provided one, ignoring the built-in functions.
+fn same_signature_position(builder: &ParserDBBuilder, function: &CreateFunction) -> Option<usize> {
+    let argument_types = |function: &CreateFunction| -> Vec<String> {
+        function
+            .args
+            .iter()
+            .flatten()
+            .map(|arg| {
+                try_normalize_sqlparser_type(&arg.data_type)
+                    .map_or_else(|| arg.data_type.to_string(), String::from)
+            })
+            .collect()
+    };
+    let arguments = argument_types(function);
+    let casing = builder.identifier_casing();
+    builder.functions().iter().position(|(existing, metadata)| {
+        !metadata.is_builtin()
+            && casing.matches(
+                existing.name(),
+                existing.name_is_quoted(),
+                function.name(),
+                function.name_is_quoted(),
             )
-        });
+            && match (existing.schema(), function.schema()) {
+                (None, None) => true,
+                (Some(existing_schema), Some(schema)) => casing.matches(
+                    existing_schema,
+                    existing.schema_is_quoted(),
+                    schema,
+                    function.schema_is_quoted(),
+                ),
+                _ => false,
+            }
+            && argument_types(existing) == arguments
+    })
+}
 
-        // Remove unique indices on this table
-        self.unique_indices_mut().retain(|(u, _)| {
-            !table_matches_resolved_identity(
-                TableAttribute::table(u),
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Qualifies the unqualified names of the provided table objects with the
+/// schema of the tables they resolve to through the search path, so that the
+/// grants and revokes naming them match whatever the search path.
+fn qualify_table_objects(builder: &ParserDBBuilder, objects: Option<&mut GrantObjects>) {
+    if builder.searched_schemas().is_empty() {
+        return;
+    }
+    let Some(GrantObjects::Tables(tables)) = objects else {
+        return;
+    };
+    for table_name in tables {
+        if let Ok(Some(table)) = builder.resolve_table_object_name(table_name) {
+            qualify_object_name(table_name, &table.name);
+        }
+    }
+}
 
-        // Remove foreign keys from this table
-        self.foreign_keys_mut().retain(|(fk, ())| {
-            !table_matches_resolved_identity(
-                TableAttribute::table(fk),
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Qualifies the unqualified name of the object created by the statement
+/// with the schema of the search path the object is created in, so that a
+/// `SET search_path` places the objects created after it.
+fn qualify_created_object(builder: &ParserDBBuilder, statement: &mut Statement) {
+    let name = match statement {
+        Statement::CreateTable(CreateTable { name, temporary: false, .. })
+        | Statement::CreateView(CreateView { name, .. })
+        | Statement::CreateFunction(CreateFunction { name, .. })
+        | Statement::CreateSequence { name, .. }
+        | Statement::CreateType { name, .. }
+        | Statement::CreateDomain(CreateDomain { name, .. }) => name,
+        _ => return,
+    };
+    if name.0.len() != 1 {
+        return;
+    }
+    if let Some(schema) = builder.creation_schema() {
+        name.0.insert(0, ObjectNamePart::Identifier(schema));
+    }
+}
 
-        // Remove check constraints on this table
-        self.check_constraints_mut().retain(|(c, _)| {
-            !table_matches_resolved_identity(
-                TableAttribute::table(c),
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Replaces an unqualified name with the qualified name of the object it
+/// resolved to, so that it keeps designating that object whatever the search
+/// path set later in the script.
+fn qualify_object_name(object_name: &mut ObjectName, resolved_name: &ObjectName) {
+    if object_name.0.len() == 1 && resolved_name.0.len() > 1 {
+        object_name.clone_from(resolved_name);
+    }
+}
 
-        // Remove triggers on this table
-        self.triggers_mut().retain(|(t, ())| {
-            !object_name_matches_resolved_identity(
-                &t.table_name,
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Returns the schema named by a value of a `SET search_path` statement, in
+/// the form taken by [`ParserOptions::with_search_path`], or `None` if the
+/// value does not name a schema.
+fn search_path_schema(value: &Expr) -> Option<String> {
+    let (name, quoted) = match value {
+        Expr::Identifier(ident) => (ident.value.as_str(), ident.quote_style.is_some()),
+        // String literals name schemas exactly, as quoted identifiers do.
+        Expr::Value(ValueWithSpan { value: Value::SingleQuotedString(name), .. }) => {
+            (name.as_str(), true)
+        }
+        _ => return None,
+    };
+    let folded = name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    Some(if quoted && (!folded || name.is_empty()) {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    } else {
+        String::from(name)
+    })
+}
 
-        // Remove policies on this table
-        self.policies_mut().retain(|(p, _)| {
-            !object_name_matches_resolved_identity(
-                &p.table_name,
-                table_name,
-                table_name_quoted,
-                schema_name,
-                schema_quoted,
-            )
-        });
+/// Returns whether the function is designated by the provided name.
+fn function_matches_name(
+    function: &CreateFunction,
+    name: &QualifiedName,
+    casing: IdentifierCasing,
+) -> bool {
+    name.matches_with_casing(
+        casing,
+        function.schema(),
+        function.schema_is_quoted(),
+        function.name(),
+        function.name_is_quoted(),
+    )
+}
 
-        // Remove table grants for this table
-        self.table_grants_mut().retain(|(g, ())| {
-            use sqlparser::ast::GrantObjects;
-            !matches!(&g.objects, Some(GrantObjects::Tables(tables)) if tables.iter().any(|t| {
-                object_name_matches_resolved_identity(
-                    t,
-                    table_name,
-                    table_name_quoted,
-                    schema_name,
-                    schema_quoted,
-                )
-            }))
-        });
+/// Returns the name designating the functions named by the provided object
+/// name, resolving unqualified names through the search path, or `None` if
+/// no function is found.
+fn resolve_function_name(
+    builder: &ParserDBBuilder,
+    object_name: &ObjectName,
+) -> Option<QualifiedName> {
+    let name = QualifiedName::from_object_name(object_name)?;
+    name.search_candidates(builder.searched_schemas()).into_iter().find(|candidate| {
+        builder
+            .functions_named(candidate.name(), candidate.name_is_quoted())
+            .any(|function| function_matches_name(function, candidate, builder.identifier_casing()))
+    })
+}
 
-        // Remove column grants for this table
-        self.column_grants_mut().retain(|(g, ())| {
-            use sqlparser::ast::GrantObjects;
-            !matches!(&g.objects, Some(GrantObjects::Tables(tables)) if tables.iter().any(|t| {
-                object_name_matches_resolved_identity(
-                    t,
-                    table_name,
-                    table_name_quoted,
-                    schema_name,
-                    schema_quoted,
-                )
-            }))
-        });
-    }
+/// Returns whether an index named as the provided one already exists in the
+/// schema of its table.
+fn index_exists(
+    builder: &ParserDBBuilder,
+    index: &TableAttribute<CreateTable, CreateIndex>,
+) -> bool {
+    let Some(name) = index.attribute().name.as_ref().and_then(object_name_last_part) else {
+        return false;
+    };
+    let table = index.table();
+    let name = QualifiedName::from_parts(
+        table.table_schema().map(|schema| (schema, table.table_schema_is_quoted())),
+        name,
+    );
+    let casing = builder.identifier_casing();
+    builder.indices().iter().any(|(existing, _)| index_matches_name(existing, &name, casing))
+}
 
-    /// Checks if a role with the given name is referenced by any grants.
-    ///
-    /// Returns `true` if the role is a grantee in any table or column grant.
-    fn is_role_referenced(&self, role_name: &str, role_quoted: bool) -> bool {
-        let check_grantees = |grantees: &[sqlparser::ast::Grantee]| -> bool {
-            grantees.iter().any(|g| {
-                matches!(
-                    &g.name,
-                    Some(GranteeName::ObjectName(name))
-                        if object_name_last_identifier(name).is_some_and(|grantee_ident| {
-                            identifiers_match(
-                                grantee_ident.value.as_str(),
-                                grantee_ident.quote_style.is_some(),
-                                role_name,
-                                role_quoted,
-                            )
-                        })
-                )
-            })
-        };
+/// Returns whether the index is designated by the provided name, an index
+/// belonging to the schema of its table.
+fn index_matches_name(
+    index: &TableAttribute<CreateTable, CreateIndex>,
+    name: &QualifiedName,
+    casing: IdentifierCasing,
+) -> bool {
+    let table = index.table();
+    index.attribute().name.as_ref().and_then(object_name_last_part).is_some_and(
+        |(index_name, index_quoted)| {
+            name.matches_with_casing(
+                casing,
+                table.table_schema(),
+                table.table_schema_is_quoted(),
+                index_name,
+                index_quoted,
+            )
+        },
+    )
+}
+
+/// Returns the position of the index designated by the provided object name,
+/// resolving unqualified names through the search path.
+fn index_position(builder: &ParserDBBuilder, object_name: &ObjectName) -> Option<usize> {
+    let name = QualifiedName::from_object_name(object_name)?;
+    let casing = builder.identifier_casing();
+    name.search_candidates(builder.searched_schemas()).iter().find_map(|candidate| {
+        builder.indices().iter().position(|(index, _)| index_matches_name(index, candidate, casing))
+    })
+}
 
-        // Check table grants
-        for (grant, ()) in self.table_grants() {
-            if check_grantees(&grant.grantees) {
-                return true;
+/// Returns whether the sequence is named by the provided object name.
+fn sequence_matches_object_name(
+    sequence: &Sequence,
+    object_name: &ObjectName,
+    casing: IdentifierCasing,
+) -> bool {
+    let Some((name, quoted)) = object_name_last_part(object_name) else {
+        return false;
+    };
+    casing.matches(sequence.name(), sequence.is_quoted(), name, quoted)
+        && match (sequence.schema(), schema_from_object_name(object_name)) {
+            (None, None) => true,
+            (Some(sequence_schema), Some((schema, schema_quoted))) => {
+                casing.matches(sequence_schema, sequence.schema_is_quoted(), schema, schema_quoted)
             }
+            _ => false,
         }
+}
 
-        // Check column grants
-        for (grant, ()) in self.column_grants() {
-            if check_grantees(&grant.grantees) {
-                return true;
+/// Returns whether the default value of the column advances the sequence
+/// through a `nextval('...')` call.
+fn default_advances_sequence(
+    column: &TableAttribute<CreateTable, ColumnDef>,
+    sequence: &Sequence,
+    casing: IdentifierCasing,
+) -> bool {
+    let Some(default_value) = column.default_value() else {
+        return false;
+    };
+    let Some((schema, name)) = nextval_sequence_name(&default_value) else {
+        return false;
+    };
+    casing.matches_lookup(sequence.name(), sequence.is_quoted(), name)
+        && match (sequence.schema(), schema) {
+            (None, None) => true,
+            (Some(sequence_schema), Some(schema)) => {
+                casing.matches_lookup(sequence_schema, sequence.schema_is_quoted(), schema)
             }
+            _ => false,
         }
-
-        false
-    }
-
-    /// Checks if a schema contains any objects (tables).
-    ///
-    /// Returns `true` if any table belongs to this schema.
-    fn is_schema_non_empty(&self, schema_name: &str, schema_quoted: bool) -> bool {
-        use crate::traits::TableLike;
-
-        // Check if any table is in this schema
-        self.tables().iter().any(|(t, _)| {
-            t.table_schema().is_some_and(|table_schema| {
-                identifiers_match(
-                    table_schema,
-                    t.table_schema_is_quoted(),
-                    schema_name,
-                    schema_quoted,
-                )
-            })
-        })
-    }
-
-    fn resolve_schema_ident(&self, ident: &Ident) -> Option<&Schema> {
-        resolve_schema_ident_in_iter(
-            self.schemas().iter().map(|(schema, ())| schema.as_ref()),
-            ident,
-        )
-    }
-
-    fn resolve_table_object_name(
-        &self,
-        object_name: &ObjectName,
-    ) -> Result<Option<&CreateTable>, LookupError> {
-        resolve_table_object_name_in_iter(
-            self.tables().iter().map(|(table, _)| table.as_ref()),
-            object_name,
-        )
-    }
 }
 
-/// A type alias for the result of processing check constraints.
-type CheckConstraintResult =
-    (Vec<Arc<TableAttribute<CreateTable, ColumnDef>>>, Vec<Arc<CreateFunction>>);
+/// Returns whether the user-defined type is named by the provided object
+/// name.
+fn custom_type_matches_object_name(
+    custom_type: &CustomType,
+    object_name: &ObjectName,
+    casing: IdentifierCasing,
+) -> bool {
+    let Some((name, quoted)) = object_name_last_part(object_name) else {
+        return false;
+    };
+    casing.matches(custom_type.name(), custom_type.is_quoted(), name, quoted)
+        && match (custom_type.schema(), schema_from_object_name(object_name)) {
+            (None, None) => true,
+            (Some(type_schema), Some((schema, schema_quoted))) => {
+                casing.matches(type_schema, custom_type.schema_is_quoted(), schema, schema_quoted)
+            }
+            _ => false,
+        }
+}
 
-/// A type alias for the result of processing unique constraints.
-type UniqueConstraintResult = (
-    Arc<TableAttribute<CreateTable, UniqueConstraint>>,
-    UniqueIndexMetadata<TableAttribute<CreateTable, UniqueConstraint>>,
-);
+/// Returns the user-defined type named by the provided object name with the
+/// provided definition.
+fn custom_type_from_object_name(
+    object_name: &ObjectName,
+    definition: CustomTypeDefinition,
+) -> Result<CustomType, LookupError> {
+    let (schema_ident, type_ident) = object_name_identifiers(object_name)?;
+    let custom_type =
+        CustomType::new(type_ident.value.clone(), type_ident.quote_style.is_some(), definition);
+    Ok(match schema_ident {
+        Some(schema_ident) => {
+            custom_type.with_schema(schema_ident.value.clone(), schema_ident.quote_style.is_some())
+        }
+        None => custom_type,
+    })
+}
 
-fn object_name_last_identifier(object_name: &ObjectName) -> Option<&Ident> {
-    match object_name.0.last() {
-        Some(ObjectNamePart::Identifier(ident)) => Some(ident),
-        _ => None,
-    }
+/// Returns whether the `OWNED BY` clause of a sequence is `OWNED BY NONE`.
+fn is_owned_by_none(owned_by: &ObjectName) -> bool {
+    matches!(
+        owned_by.0.as_slice(),
+        [ObjectNamePart::Identifier(ident)]
+            if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("none")
+    )
 }
 
 fn resolve_schema_ident_in_iter<'a>(
     mut schemas: impl Iterator<Item = &'a Schema>,
     ident: &Ident,
+    casing: IdentifierCasing,
 ) -> Option<&'a Schema> {
     schemas.find(|schema| {
-        identifiers_match(
+        casing.matches(
             schema.name(),
             schema.is_quoted(),
             ident.value.as_str(),
@@ -418,8 +542,9 @@ fn table_matches_resolved_identity(
     table_name_quoted: bool,
     schema_name: Option<&str>,
     schema_quoted: bool,
+    casing: IdentifierCasing,
 ) -> bool {
-    if !identifiers_match(
+    if !casing.matches(
         table.table_name(),
         table.table_name_is_quoted(),
         table_name,
@@ -431,12 +556,7 @@ fn table_matches_resolved_identity(
     match (table.table_schema(), schema_name) {
         (None, None) => true,
         (Some(table_schema), Some(schema_name)) => {
-            identifiers_match(
-                table_schema,
-                table.table_schema_is_quoted(),
-                schema_name,
-                schema_quoted,
-            )
+            casing.matches(table_schema, table.table_schema_is_quoted(), schema_name, schema_quoted)
         }
         _ => false,
     }
@@ -448,12 +568,13 @@ fn object_name_matches_resolved_identity(
     table_name_quoted: bool,
     schema_name: Option<&str>,
     schema_quoted: bool,
+    casing: IdentifierCasing,
 ) -> bool {
     let Ok((schema_ident, table_ident)) = object_name_identifiers(object_name) else {
         return false;
     };
 
-    if !identifiers_match(
+    if !casing.matches(
         table_ident.value.as_str(),
         table_ident.quote_style.is_some(),
         table_name,
@@ -464,22 +585,287 @@ fn object_name_matches_resolved_identity(
 
     match (schema_ident, schema_name) {
         (None, None) => true,
-        (Some(schema_ident), Some(schema_name)) => {
-            identifiers_match(
-                schema_ident.value.as_str(),
-                schema_ident.quote_style.is_some(),
-                schema_name,
-                schema_quoted,
-            )
-        }
+        (Some(schema_ident), Some(schema_name)) => casing.matches(
+            schema_ident.value.as_str(),
+            schema_ident.quote_style.is_some(),
+            schema_name,
+            schema_quoted,
+        ),
         _ => false,
     }
 }
 
-fn role_matches_lookup_ident(role: &CreateRole, lookup_ident: &Ident) -> bool {
+/// Returns the columns a grant action is restricted to, if any.
+fn grant_action_columns(action: &sqlparser::ast::Action) -> Option<&[Ident]> {
+    use sqlparser::ast::Action;
+
+    match action {
+        Action::Select { columns }
+        | Action::Insert { columns }
+        | Action::Update { columns }
+        | Action::References { columns } => columns.as_deref(),
+        _ => None,
+    }
+}
+
+/// Returns a mutable reference to the columns a grant action is restricted
+/// to, if any.
+fn grant_action_columns_mut(action: &mut sqlparser::ast::Action) -> Option<&mut Vec<Ident>> {
+    use sqlparser::ast::Action;
+
+    match action {
+        Action::Select { columns }
+        | Action::Insert { columns }
+        | Action::Update { columns }
+        | Action::References { columns } => columns.as_mut(),
+        _ => None,
+    }
+}
+
+/// Removes a dropped column from the column-scoped privileges of the grants
+/// depending on it, discarding the grants left without privileges.
+fn retain_grants_without_column(
+    grants: Vec<(Arc<Grant>, ())>,
+    grant_depends: impl Fn(&Grant) -> bool,
+    names_column: impl Fn(&Ident) -> bool,
+) -> Vec<(Arc<Grant>, ())> {
+    grants
+        .into_iter()
+        .filter_map(|(grant, ())| {
+            if !grant_depends(&grant) {
+                return Some((grant, ()));
+            }
+            let mut grant = (*grant).clone();
+            if let sqlparser::ast::Privileges::Actions(actions) = &mut grant.privileges {
+                actions.retain_mut(|action| match grant_action_columns_mut(action) {
+                    Some(columns) => {
+                        columns.retain(|column| !names_column(column));
+                        !columns.is_empty()
+                    }
+                    None => true,
+                });
+                if actions.is_empty() {
+                    return None;
+                }
+            }
+            Some((Arc::new(grant), ()))
+        })
+        .collect()
+}
+
+/// Removes a dropped sequence from the grants on sequences naming it,
+/// discarding the grants left without sequences.
+fn retain_grants_without_sequence(
+    grants: Vec<(Arc<Grant>, ())>,
+    sequence: &Sequence,
+    casing: IdentifierCasing,
+) -> Vec<(Arc<Grant>, ())> {
+    grants
+        .into_iter()
+        .filter_map(|(grant, ())| {
+            let Some(sqlparser::ast::GrantObjects::Sequences(sequences)) = &grant.objects else {
+                return Some((grant, ()));
+            };
+            if !sequences.iter().any(|name| sequence_matches_object_name(sequence, name, casing)) {
+                return Some((grant, ()));
+            }
+            let mut grant = (*grant).clone();
+            if let Some(sqlparser::ast::GrantObjects::Sequences(sequences)) = &mut grant.objects {
+                sequences.retain(|name| !sequence_matches_object_name(sequence, name, casing));
+                if sequences.is_empty() {
+                    return None;
+                }
+            }
+            Some((Arc::new(grant), ()))
+        })
+        .collect()
+}
+
+/// Returns the statement restricted to the objects admitted by the filter, or
+/// `None` when it only concerns excluded objects.
+fn filter_statement(
+    mut statement: Statement,
+    filter: &ObjectFilter,
+    casing: IdentifierCasing,
+) -> Option<Statement> {
+    let retain_tables = |objects: &mut Option<sqlparser::ast::GrantObjects>| match objects {
+        Some(sqlparser::ast::GrantObjects::Tables(tables)) => {
+            tables.retain(|table| filter.admits_object_name(table, casing));
+            !tables.is_empty()
+        }
+        _ => true,
+    };
+
+    let admitted = match &mut statement {
+        Statement::CreateTable(create_table) => {
+            filter.admits_object_name(&create_table.name, casing)
+        }
+        Statement::CreateIndex(create_index) => {
+            filter.admits_object_name(&create_index.table_name, casing)
+        }
+        Statement::AlterTable(alter_table) => filter.admits_object_name(&alter_table.name, casing),
+        Statement::CreateTrigger(create_trigger) => {
+            filter.admits_object_name(&create_trigger.table_name, casing)
+        }
+        Statement::DropTrigger(drop_trigger) => drop_trigger
+            .table_name
+            .as_ref()
+            .is_none_or(|table_name| filter.admits_object_name(table_name, casing)),
+        Statement::CreatePolicy(policy) => filter.admits_object_name(&policy.table_name, casing),
+        Statement::CreateView(create_view) => filter.admits_object_name(&create_view.name, casing),
+        Statement::CreateSequence { name, .. }
+        | Statement::CreateType { name, .. }
+        | Statement::CreateDomain(CreateDomain { name, .. }) => {
+            filter.admits_object_name(name, casing)
+        }
+        Statement::DropPolicy(drop_policy) => {
+            filter.admits_object_name(&drop_policy.table_name, casing)
+        }
+        Statement::AlterPolicy(AlterPolicy { table_name, .. }) => {
+            filter.admits_object_name(table_name, casing)
+        }
+        Statement::RenameTable(renames) => {
+            renames.retain(|rename| filter.admits_object_name(&rename.old_name, casing));
+            !renames.is_empty()
+        }
+        Statement::Drop {
+            object_type:
+                sqlparser::ast::ObjectType::Table
+                | sqlparser::ast::ObjectType::Sequence
+                | sqlparser::ast::ObjectType::Type,
+            names,
+            ..
+        } => {
+            names.retain(|name| filter.admits_object_name(name, casing));
+            !names.is_empty()
+        }
+        Statement::Drop { object_type: sqlparser::ast::ObjectType::Schema, names, .. } => {
+            names.retain(|name| filter.admits_schema_object_name(name, casing));
+            !names.is_empty()
+        }
+        Statement::CreateSchema {
+            schema_name: SchemaName::Simple(name) | SchemaName::NamedAuthorization(name, _),
+            ..
+        }
+        | Statement::AlterSchema(AlterSchema { name, .. }) => {
+            filter.admits_schema_object_name(name, casing)
+        }
+        Statement::CreateSchema { schema_name: SchemaName::UnnamedAuthorization(auth), .. } => {
+            filter.admits_schema(&casing.normalize(&auth.value, auth.quote_style.is_some()))
+        }
+        Statement::Comment { object_type: CommentObject::Table, object_name, .. } => {
+            filter.admits_object_name(object_name, casing)
+        }
+        Statement::Comment { object_type: CommentObject::Column, object_name, .. } => {
+            let table_parts = object_name.0.split_last().map_or(&[][..], |(_, parts)| parts);
+            filter.admits_object_name(&ObjectName(table_parts.to_vec()), casing)
+        }
+        Statement::Grant(grant) => retain_tables(&mut grant.objects),
+        Statement::Revoke(revoke) => retain_tables(&mut revoke.objects),
+        _ => true,
+    };
+
+    admitted.then_some(statement)
+}
+
+/// Builds the definition of a function known to the database without being
+/// created by the parsed statements, such as a builtin or a function provided
+/// by an extension.
+fn catalog_function(
+    name: &str,
+    args: Vec<OperateFunctionArg>,
+    return_type: DataType,
+    language: &str,
+    body: String,
+) -> CreateFunction {
+    CreateFunction {
+        or_alter: false,
+        or_replace: false,
+        temporary: false,
+        if_not_exists: false,
+        name: ObjectName(vec![ObjectNamePart::Identifier(Ident::new(name))]),
+        args: Some(args),
+        return_type: Some(FunctionReturnType::DataType(return_type)),
+        function_body: Some(CreateFunctionBody::AsBeforeOptions {
+            body: Expr::Value(ValueWithSpan {
+                value: Value::SingleQuotedString(body),
+                span: Span::empty(),
+            }),
+            link_symbol: None,
+        }),
+        behavior: None,
+        called_on_null: None,
+        parallel: None,
+        using: None,
+        language: Some(Ident::new(language)),
+        determinism_specifier: None,
+        options: None,
+        remote_connection: None,
+        security: None,
+        set_params: vec![],
+    }
+}
+
+/// Returns whether the function was registered by the extension whose
+/// library is provided.
+fn is_provided_by_extension(function: &CreateFunction, library: &str) -> bool {
+    function.language.as_ref().is_some_and(|language| language.value == "c")
+        && matches!(
+            &function.function_body,
+            Some(CreateFunctionBody::AsBeforeOptions {
+                body: Expr::Value(ValueWithSpan { value: Value::SingleQuotedString(body), .. }),
+                ..
+            }) if body == library
+        )
+}
+
+/// Returns the kind of the statement along with the dialect capability it
+/// requires, if any.
+fn required_capability(statement: &Statement) -> Option<(&'static str, DialectCapability)> {
+    Some(match statement {
+        Statement::CreateRole(_) => ("CREATE ROLE", DialectCapability::Roles),
+        Statement::AlterRole { .. } => ("ALTER ROLE", DialectCapability::Roles),
+        Statement::Drop { object_type: sqlparser::ast::ObjectType::Role, .. } => {
+            ("DROP ROLE", DialectCapability::Roles)
+        }
+        Statement::CreatePolicy(_) => ("CREATE POLICY", DialectCapability::Policies),
+        Statement::AlterPolicy(_) => ("ALTER POLICY", DialectCapability::Policies),
+        Statement::DropPolicy(_) => ("DROP POLICY", DialectCapability::Policies),
+        Statement::Grant(_) => ("GRANT", DialectCapability::Grants),
+        Statement::Revoke(_) => ("REVOKE", DialectCapability::Grants),
+        Statement::CreateTrigger(_) => ("CREATE TRIGGER", DialectCapability::Triggers),
+        Statement::DropTrigger(_) => ("DROP TRIGGER", DialectCapability::Triggers),
+        Statement::CreateFunction(_) => ("CREATE FUNCTION", DialectCapability::Functions),
+        Statement::DropFunction(_) => ("DROP FUNCTION", DialectCapability::Functions),
+        Statement::CreateSchema { .. } => ("CREATE SCHEMA", DialectCapability::Schemas),
+        Statement::AlterSchema(_) => ("ALTER SCHEMA", DialectCapability::Schemas),
+        Statement::Drop { object_type: sqlparser::ast::ObjectType::Schema, .. } => {
+            ("DROP SCHEMA", DialectCapability::Schemas)
+        }
+        Statement::CreateSequence { .. } => ("CREATE SEQUENCE", DialectCapability::Sequences),
+        Statement::Drop { object_type: sqlparser::ast::ObjectType::Sequence, .. } => {
+            ("DROP SEQUENCE", DialectCapability::Sequences)
+        }
+        Statement::CreateType { .. } => ("CREATE TYPE", DialectCapability::CustomTypes),
+        Statement::CreateDomain(_) => ("CREATE DOMAIN", DialectCapability::CustomTypes),
+        Statement::Drop { object_type: sqlparser::ast::ObjectType::Type, .. } => {
+            ("DROP TYPE", DialectCapability::CustomTypes)
+        }
+        Statement::DropDomain(_) => ("DROP DOMAIN", DialectCapability::CustomTypes),
+        Statement::CreateExtension(_) => ("CREATE EXTENSION", DialectCapability::Extensions),
+        Statement::DropExtension(_) => ("DROP EXTENSION", DialectCapability::Extensions),
+        _ => return None,
+    })
+}
+
+fn role_matches_lookup_ident(
+    role: &CreateRole,
+    lookup_ident: &Ident,
+    casing: IdentifierCasing,
+) -> bool {
     role.names.iter().any(|role_name| {
         object_name_last_identifier(role_name).is_some_and(|role_ident| {
-            identifiers_match(
+            casing.matches(
                 role_ident.value.as_str(),
                 role_ident.quote_style.is_some(),
                 lookup_ident.value.as_str(),
@@ -489,6 +875,22 @@ fn role_matches_lookup_ident(role: &CreateRole, lookup_ident: &Ident) -> bool {
     })
 }
 
+/// Applies an option of an `ALTER ROLE` statement to the stored role.
+fn apply_role_option(role: &mut CreateRole, option: RoleOption) {
+    match option {
+        RoleOption::BypassRLS(value) => role.bypassrls = Some(value),
+        RoleOption::ConnectionLimit(limit) => role.connection_limit = Some(limit),
+        RoleOption::CreateDB(value) => role.create_db = Some(value),
+        RoleOption::CreateRole(value) => role.create_role = Some(value),
+        RoleOption::Inherit(value) => role.inherit = Some(value),
+        RoleOption::Login(value) => role.login = Some(value),
+        RoleOption::Password(password) => role.password = Some(password),
+        RoleOption::Replication(value) => role.replication = Some(value),
+        RoleOption::SuperUser(value) => role.superuser = Some(value),
+        RoleOption::ValidUntil(valid_until) => role.valid_until = Some(valid_until),
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 struct RevokeStoreApplication {
     matched_any: bool,
@@ -498,6 +900,7 @@ struct RevokeStoreApplication {
 fn apply_revoke_to_grant_store(
     grants: &mut Vec<(Arc<Grant>, ())>,
     revoke: &sqlparser::ast::Revoke,
+    casing: IdentifierCasing,
 ) -> RevokeStoreApplication {
     let mut matched_any = false;
     let mut has_unsupported_column_scoped_revoke = false;
@@ -506,7 +909,7 @@ fn apply_revoke_to_grant_store(
 
     for (grant, ()) in original_grants {
         let (targeted_grantees, untouched_grantees) =
-            crate::impls::partition_grantees_for_revoke(&grant.grantees, &revoke.grantees);
+            crate::impls::partition_grantees_for_revoke(&grant.grantees, &revoke.grantees, casing);
 
         if targeted_grantees.is_empty() {
             updated_grants.push((grant, ()));
@@ -516,13 +919,13 @@ fn apply_revoke_to_grant_store(
         let mut targeted_grant = grant.as_ref().clone();
         targeted_grant.grantees = targeted_grantees;
 
-        if crate::impls::has_unsupported_column_scoped_revoke(&targeted_grant, revoke) {
+        if crate::impls::has_unsupported_column_scoped_revoke(&targeted_grant, revoke, casing) {
             has_unsupported_column_scoped_revoke = true;
             updated_grants.push((grant, ()));
             continue;
         }
 
-        let application = crate::impls::apply_revoke_to_grant(&targeted_grant, revoke);
+        let application = crate::impls::apply_revoke_to_grant(&targeted_grant, revoke, casing);
 
         if !application.matched {
             updated_grants.push((grant, ()));
@@ -531,7 +934,8 @@ fn apply_revoke_to_grant_store(
         matched_any = true;
 
         // Preserve the original storage entry when revoke matched but did not
-        // change the targeted grantee's privileges (e.g. ALL minus action).
+        // change the targeted grantee's privileges (e.g. ALL minus action on a
+        // function).
         if application.updated_grant.as_ref().is_some_and(|g| g == &targeted_grant) {
             updated_grants.push((grant, ()));
             continue;
@@ -591,6 +995,7 @@ pub type ParserDB = GenericDB<
     TableAttribute<CreateTable, ColumnDef>,
     TableAttribute<CreateTable, CreateIndex>,
     TableAttribute<CreateTable, UniqueConstraint>,
+    TableAttribute<CreateTable, PrimaryKeyConstraint>,
     TableAttribute<CreateTable, ForeignKeyConstraint>,
     CreateFunction,
     TableAttribute<CreateTable, CheckConstraint>,
@@ -600,6 +1005,10 @@ pub type ParserDB = GenericDB<
     Schema,
     Grant,
     Grant,
+    CreateView,
+    Sequence,
+    CustomType,
+    DefaultPrivilege,
     SqlparserDialect,
 >;
 
@@ -611,14 +1020,20 @@ impl ParserDB {
     /// - unquoted identifiers are folded to lowercase.
     #[must_use]
     pub fn resolve_schema_ident(&self, ident: &Ident) -> Option<&Schema> {
-        resolve_schema_ident_in_iter(self.schemas.iter().map(|(schema, ())| schema.as_ref()), ident)
+        resolve_schema_ident_in_iter(
+            self.schemas.iter().map(|(schema, ())| schema.as_ref()),
+            ident,
+            self.dialect().identifier_casing(),
+        )
     }
 
     /// Resolves a table from a one-part or two-part SQL object name.
     ///
-    /// For one-part names, only schema-less tables are considered.
-    /// For two-part names, the first part is treated as schema and the second
-    /// part as table.
+    /// For one-part names, schema-less tables are considered first, then the
+    /// tables of each schema of the
+    /// [search path](DatabaseLike::search_path), in order. For two-part
+    /// names, the first part is treated as schema and the second part as
+    /// table.
     ///
     /// # Errors
     ///
@@ -628,9 +1043,11 @@ impl ParserDB {
         &self,
         object_name: &ObjectName,
     ) -> Result<Option<&CreateTable>, LookupError> {
-        resolve_table_object_name_in_iter(
+        resolve_table_object_name_in_search_path(
             self.tables.iter().map(|(table, _)| table.as_ref()),
             object_name,
+            self.search_path(),
+            self.dialect().identifier_casing(),
         )
     }
 
@@ -651,24 +1068,71 @@ impl ParserDB {
         resolve_table_object_name_with_implicit_public_in_iter(
             self.tables.iter().map(|(table, _)| table.as_ref()),
             object_name,
+            self.dialect().identifier_casing(),
         )
     }
 
-    /// Checks that every foreign key resolves: the referenced table exists in
-    /// this database and each referenced column exists on it.
+    /// Resolves a function from an SQL object name, returning the first
+    /// function created under that name whatever its arguments.
     ///
-    /// Order-insensitive: it runs against the fully ingested database, so
-    /// forward and self-references pass. Targets resolve under the same
-    /// implicit-`public` policy as other object-name lookups. Opt-in, so
-    /// partial schemas still parse; call it after parse to enforce closure.
+    /// For one-part names, schema-less functions are considered first, then
+    /// the functions of each schema of the
+    /// [search path](DatabaseLike::search_path), in order. Names with more
+    /// parts are resolved by their last two parts.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns the first unresolved constraint as
-    /// [`ReferencedTableNotFoundForForeignKey`](crate::errors::Error::ReferencedTableNotFoundForForeignKey)
-    /// or
-    /// [`ReferencedColumnNotFoundForForeignKey`](crate::errors::Error::ReferencedColumnNotFoundForForeignKey).
-    /// A malformed target name surfaces as
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{
+    ///     ast::{Ident, ObjectName},
+    ///     dialect::PostgreSqlDialect,
+    /// };
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    ///     CREATE SCHEMA app;
+    ///     CREATE FUNCTION app.one() RETURNS INT AS 'SELECT 1' LANGUAGE sql;
+    ///     ",
+    /// )?;
+    /// let qualified = ObjectName::from(vec![Ident::new("app"), Ident::new("one")]);
+    /// assert!(db.resolve_function_object_name(&qualified).is_some());
+    /// let unqualified = ObjectName::from(vec![Ident::new("one")]);
+    /// assert!(db.resolve_function_object_name(&unqualified).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn resolve_function_object_name(
+        &self,
+        object_name: &ObjectName,
+    ) -> Option<&CreateFunction> {
+        let name = QualifiedName::from_object_name(object_name)?;
+        let casing = self.dialect().identifier_casing();
+        name.search_candidates(self.search_path()).iter().find_map(|candidate| {
+            self.functions
+                .iter()
+                .map(|(function, _)| function.as_ref())
+                .find(|function| function_matches_name(function, candidate, casing))
+        })
+    }
+
+    /// Checks that every foreign key resolves: the referenced table exists in
+    /// this database and each referenced column exists on it.
+    ///
+    /// Order-insensitive: it runs against the fully ingested database, so
+    /// forward and self-references pass. Targets resolve under the same
+    /// implicit-`public` policy as other object-name lookups. Opt-in, so
+    /// partial schemas still parse; call it after parse to enforce closure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first unresolved constraint as
+    /// [`ReferencedTableNotFoundForForeignKey`](crate::errors::Error::ReferencedTableNotFoundForForeignKey)
+    /// or
+    /// [`ReferencedColumnNotFoundForForeignKey`](crate::errors::Error::ReferencedColumnNotFoundForForeignKey).
+    /// A malformed target name surfaces as
     /// [`IdentifierLookupError`](crate::errors::Error::IdentifierLookupError).
     ///
     /// # Examples
@@ -692,6 +1156,7 @@ impl ParserDB {
     /// # Ok::<(), sql_traits::errors::Error>(())
     /// ```
     pub fn validate_foreign_key_targets(&self) -> Result<(), crate::errors::Error> {
+        let casing = self.dialect().identifier_casing();
         for (fk, ()) in &self.foreign_keys {
             let constraint = fk.attribute();
             let host_table = fk.table();
@@ -705,13 +1170,17 @@ impl ParserDB {
             };
 
             for referred in &constraint.referred_columns {
-                let column_exists = referenced_table.columns.iter().any(|column| {
-                    identifiers_match(
-                        column.name.value.as_str(),
-                        column.name.quote_style.is_some(),
-                        referred.value.as_str(),
-                        referred.quote_style.is_some(),
-                    )
+                // Columns added through `ALTER TABLE ... ADD COLUMN` are only
+                // tracked in the table metadata, not in the `CREATE TABLE`.
+                let column_exists = self.table_metadata(referenced_table).is_some_and(|metadata| {
+                    metadata.column_arcs().any(|column| {
+                        casing.matches(
+                            column.column_name(),
+                            column.column_name_is_quoted(),
+                            referred.value.as_str(),
+                            referred.quote_style.is_some(),
+                        )
+                    })
                 });
                 if !column_exists {
                     return Err(crate::errors::Error::ReferencedColumnNotFoundForForeignKey {
@@ -725,6 +1194,183 @@ impl ParserDB {
         Ok(())
     }
 
+    /// Checks that every foreign key of this database has as many host
+    /// columns as referenced columns, and that the values of each host column
+    /// can be compared with those of the matching referenced column.
+    ///
+    /// Foreign keys omitting the referenced columns are checked against the
+    /// primary key of the referenced table. Columns of data types without a
+    /// known family, such as custom types, are not compared, and neither are
+    /// the columns of SQLite databases, which only have a type affinity.
+    /// Foreign keys whose targets cannot be resolved are left to
+    /// [`ParserDB::validate_foreign_key_targets`]. Opt-in, as the engines
+    /// differ in how strictly they compare the types.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first mismatch as
+    /// [`ForeignKeyColumnCountMismatch`](crate::errors::Error::ForeignKeyColumnCountMismatch)
+    /// or
+    /// [`ForeignKeyColumnTypeMismatch`](crate::errors::Error::ForeignKeyColumnTypeMismatch).
+    /// A malformed target name surfaces as
+    /// [`IdentifierLookupError`](crate::errors::Error::IdentifierLookupError).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sql_traits::{errors::Error, prelude::*};
+    /// use sqlparser::dialect::GenericDialect;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    ///     CREATE TABLE parent (id INT, code TEXT, PRIMARY KEY (id, code));
+    ///     CREATE TABLE child (parent_id INT, parent_code TEXT,
+    ///         FOREIGN KEY (parent_id, parent_code) REFERENCES parent(id, code));
+    ///     ",
+    /// )?;
+    /// assert!(db.validate_foreign_key_columns().is_ok());
+    ///
+    /// let swapped = ParserDB::parse::<GenericDialect>(
+    ///     "
+    ///     CREATE TABLE parent (id INT, code TEXT, PRIMARY KEY (id, code));
+    ///     CREATE TABLE child (parent_id INT, parent_code TEXT,
+    ///         FOREIGN KEY (parent_code, parent_id) REFERENCES parent(id, code));
+    ///     ",
+    /// )?;
+    /// assert!(matches!(
+    ///     swapped.validate_foreign_key_columns(),
+    ///     Err(Error::ForeignKeyColumnTypeMismatch { host_column, referenced_column, .. })
+    ///         if host_column == "parent_code" && referenced_column == "id"
+    /// ));
+    /// # Ok::<(), sql_traits::errors::Error>(())
+    /// ```
+    pub fn validate_foreign_key_columns(&self) -> Result<(), crate::errors::Error> {
+        let casing = self.dialect().identifier_casing();
+        let table_column = |table: &CreateTable, name: &Ident| {
+            self.table_metadata(table)?.columns().find(|column| {
+                casing.matches(
+                    column.column_name(),
+                    column.column_name_is_quoted(),
+                    name.value.as_str(),
+                    name.quote_style.is_some(),
+                )
+            })
+        };
+        let compare_types = !self.dialect().is_sqlite();
+
+        for (fk, ()) in &self.foreign_keys {
+            let constraint = fk.attribute();
+            let host_table = fk.table();
+            let Some(referenced_table) =
+                self.resolve_table_object_name_with_implicit_public(&constraint.foreign_table)?
+            else {
+                continue;
+            };
+            let Some(host_columns) = constraint
+                .columns
+                .iter()
+                .map(|name| table_column(host_table, name))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let referenced_columns = if constraint.referred_columns.is_empty() {
+                self.table_metadata(referenced_table)
+                    .map(|metadata| metadata.primary_key_columns().collect::<Vec<_>>())
+            } else {
+                constraint
+                    .referred_columns
+                    .iter()
+                    .map(|name| table_column(referenced_table, name))
+                    .collect::<Option<Vec<_>>>()
+            };
+            let Some(referenced_columns) = referenced_columns else {
+                continue;
+            };
+
+            if host_columns.len() != referenced_columns.len() {
+                return Err(crate::errors::Error::ForeignKeyColumnCountMismatch {
+                    host_table: host_table.name.to_string(),
+                    referenced_table: referenced_table.name.to_string(),
+                    host_columns: host_columns.len(),
+                    referenced_columns: referenced_columns.len(),
+                });
+            }
+            if !compare_types {
+                continue;
+            }
+            for (host_column, referenced_column) in host_columns.into_iter().zip(referenced_columns)
+            {
+                let (Some(host_type), Some(referenced_type)) = (
+                    try_normalize_sqlparser_type(&host_column.attribute().data_type),
+                    try_normalize_sqlparser_type(&referenced_column.attribute().data_type),
+                ) else {
+                    continue;
+                };
+                let comparable = match (
+                    default_types::TypeFamily::of(host_type),
+                    default_types::TypeFamily::of(referenced_type),
+                ) {
+                    (Some(host_family), Some(referenced_family)) => {
+                        host_family == referenced_family
+                    }
+                    _ => true,
+                };
+                if !comparable {
+                    return Err(crate::errors::Error::ForeignKeyColumnTypeMismatch {
+                        host_table: host_table.name.to_string(),
+                        host_column: host_column.column_name().to_string(),
+                        host_type: host_type.to_string(),
+                        referenced_table: referenced_table.name.to_string(),
+                        referenced_column: referenced_column.column_name().to_string(),
+                        referenced_type: referenced_type.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a single SQL expression under the dialect of the database and
+    /// analyzes it against the provided table, without going through a full
+    /// statement.
+    ///
+    /// This suits interactive tools validating user-entered predicates, such
+    /// as an additional CHECK constraint or a policy `USING` clause.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table whose columns the expression may use.
+    /// * `expression` - The SQL text of the expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqlParserError`](crate::errors::Error::SqlParserError) when
+    /// the text is not a single expression, and
+    /// [`UnknownColumnInCheckConstraint`](crate::errors::Error::UnknownColumnInCheckConstraint)
+    /// when it uses a column the table lacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::GenericDialect;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE users (name TEXT NOT NULL);")?;
+    /// let users = db.table(None, "users").unwrap();
+    /// assert!(db.analyze_expression(users, "name IS NOT NULL")?.is_tautology());
+    /// assert!(db.analyze_expression(users, "age > 3").is_err());
+    /// # Ok::<(), sql_traits::errors::Error>(())
+    /// ```
+    pub fn analyze_expression<'db>(
+        &'db self,
+        table: &'db CreateTable,
+        expression: &str,
+    ) -> Result<ExpressionAnalysis<'db, Self>, crate::errors::Error> {
+        let expression = self.dialect().parse_expression(expression)?;
+        ExpressionAnalysis::new(self, table, expression)
+    }
+
     /// Helper function to process check constraints.
     fn process_check_constraint(
         check_expr: &Expr,
@@ -737,28 +1383,46 @@ impl ParserDB {
                 check_expr,
                 &create_table.name.to_string(),
                 table_metadata.column_arc_slice(),
+                builder.identifier_casing(),
             )?;
         let functions_in_expression = functions_in_expression::functions_in_expression::<Self>(
             check_expr,
             builder.function_arc_vec().as_slice(),
+            builder.identifier_casing(),
         );
         Ok((columns_in_expression, functions_in_expression))
     }
 
     /// Helper function to create an index expression from columns.
+    ///
+    /// A single column yields a nested expression and several columns yield
+    /// a tuple, while per-column ordering options and operator classes are
+    /// left to [`IndexLike::index_columns`](crate::traits::IndexLike::index_columns).
     fn create_index_expression(columns: &[IndexColumn]) -> Option<Expr> {
-        if columns.is_empty() {
-            return None;
+        match columns {
+            [] => None,
+            [column] => Some(Expr::Nested(Box::new(column.column.expr.clone()))),
+            _ => {
+                Some(Expr::Tuple(columns.iter().map(|column| column.column.expr.clone()).collect()))
+            }
         }
-        let expression_string = format!(
-            "({})",
-            columns.iter().map(|ident| ident.column.to_string()).collect::<Vec<_>>().join(", ")
-        );
-        Parser::new(&GenericDialect)
-            .try_with_sql(expression_string.as_str())
-            .ok()?
-            .parse_expr()
-            .ok()
+    }
+
+    /// Helper function to set the primary key of a table, replacing the one
+    /// the table may already have.
+    fn set_primary_key(
+        builder: &mut ParserDBBuilder,
+        table_metadata: &mut TableMetadata<CreateTable>,
+        primary_key: Arc<TableAttribute<CreateTable, PrimaryKeyConstraint>>,
+        primary_key_columns: Vec<Arc<TableAttribute<CreateTable, ColumnDef>>>,
+    ) {
+        if let Some(previous) = table_metadata.primary_key() {
+            builder
+                .primary_keys_mut()
+                .retain(|(candidate, ())| !core::ptr::eq(candidate.as_ref(), previous));
+        }
+        table_metadata.set_primary_key(primary_key.clone(), primary_key_columns);
+        builder.push_primary_key(primary_key, ());
     }
 
     /// Helper function to process unique constraints.
@@ -772,6 +1436,210 @@ impl ParserDB {
         Some((unique_index, unique_index_metadata))
     }
 
+    /// Helper function to process the indices declared inline in a table,
+    /// such as MySQL's `KEY idx (col)`, as if they were created by a
+    /// separate `CREATE INDEX` statement.
+    fn process_index_constraint(
+        index_constraint: &IndexConstraint,
+        create_table: &Arc<CreateTable>,
+    ) -> Option<IndexConstraintResult> {
+        let create_index = CreateIndex {
+            name: index_constraint
+                .name
+                .clone()
+                .map(|name| ObjectName(vec![ObjectNamePart::Identifier(name)])),
+            table_name: create_table.name.clone(),
+            using: index_constraint.index_type.clone(),
+            columns: index_constraint.columns.clone(),
+            unique: false,
+            concurrently: false,
+            if_not_exists: false,
+            include: vec![],
+            nulls_distinct: None,
+            with: vec![],
+            predicate: None,
+            index_options: index_constraint.index_options.clone(),
+            alter_options: vec![],
+        };
+        let index = Arc::new(TableAttribute::new(create_table.clone(), create_index));
+        let expression = Self::create_index_expression(&index.attribute().columns)?;
+        let index_metadata = IndexMetadata::new(expression, create_table.clone());
+        Some((index, index_metadata))
+    }
+
+    /// Adds a user-defined type to the builder, failing if a type with the
+    /// same name already exists.
+    fn add_custom_type_checked(
+        builder: &mut ParserDBBuilder,
+        name: &ObjectName,
+        definition: CustomTypeDefinition,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        if builder
+            .custom_types()
+            .iter()
+            .any(|(custom_type, ())| custom_type_matches_object_name(custom_type, name, casing))
+        {
+            return Err(crate::errors::Error::CustomTypeAlreadyExists {
+                type_name: last_str(name).to_string(),
+            });
+        }
+        let custom_type = custom_type_from_object_name(name, definition)?;
+        builder.push_custom_type(Arc::new(custom_type), ());
+        Ok(())
+    }
+
+    /// Removes a user-defined type from the builder, failing if it does not
+    /// exist unless `if_exists` is set.
+    fn drop_custom_type_checked(
+        builder: &mut ParserDBBuilder,
+        name: &ObjectName,
+        if_exists: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let Some(position) = builder.custom_types().iter().position(|(custom_type, ())| {
+            custom_type_matches_object_name(custom_type, name, casing)
+        }) else {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::DropCustomTypeNotFound {
+                type_name: last_str(name).to_string(),
+            });
+        };
+        builder.custom_types_mut().remove(position);
+        Ok(())
+    }
+
+    /// Helper function to drop a sequence, as done by `DROP SEQUENCE`.
+    ///
+    /// Unless `cascade` is set, the sequence may not be advanced by the
+    /// default value of any column. With `cascade`, those defaults are dropped
+    /// along with the sequence, while the columns themselves are kept. The
+    /// grants on the sequence are dropped in either case.
+    fn drop_sequence_checked(
+        builder: &mut ParserDBBuilder,
+        name: &ObjectName,
+        if_exists: bool,
+        cascade: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let Some(position) = builder
+            .sequences()
+            .iter()
+            .position(|(sequence, ())| sequence_matches_object_name(sequence, name, casing))
+        else {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::DropSequenceNotFound {
+                sequence_name: last_str(name).to_string(),
+            });
+        };
+
+        let sequence = builder.sequences()[position].0.clone();
+        if !cascade
+            && let Some(column) = builder
+                .tables()
+                .iter()
+                .flat_map(|(_, metadata)| metadata.column_arcs())
+                .find(|column| default_advances_sequence(column, &sequence, casing))
+        {
+            return Err(crate::errors::Error::SequenceReferenced {
+                sequence_name: sequence.name().to_string(),
+                column_name: column.column_name().to_string(),
+                table_name: column.table().table_name().to_string(),
+            });
+        }
+        builder.sequences_mut().remove(position);
+
+        for table_position in 0..builder.tables().len() {
+            let (table, mut metadata) = builder.tables()[table_position].clone();
+            let dependent_columns: Vec<_> = metadata
+                .column_arcs()
+                .filter(|column| default_advances_sequence(column, &sequence, casing))
+                .cloned()
+                .collect();
+            for column in dependent_columns {
+                let mut column_def = column.attribute().clone();
+                column_def
+                    .options
+                    .retain(|option| !matches!(option.option, ColumnOption::Default(_)));
+                let replacement = Arc::new(TableAttribute::new(table.clone(), column_def));
+                metadata.replace_column(&column, &replacement);
+                for (candidate, ()) in builder.columns_mut() {
+                    if Arc::ptr_eq(candidate, &column) {
+                        *candidate = replacement.clone();
+                    }
+                }
+            }
+            builder.tables_mut()[table_position].1 = metadata;
+        }
+
+        let table_grants = core::mem::take(builder.table_grants_mut());
+        *builder.table_grants_mut() =
+            retain_grants_without_sequence(table_grants, &sequence, casing);
+        let column_grants = core::mem::take(builder.column_grants_mut());
+        *builder.column_grants_mut() =
+            retain_grants_without_sequence(column_grants, &sequence, casing);
+
+        Ok(())
+    }
+
+    /// Helper function to process create sequence statements, resolving the
+    /// column of its `OWNED BY` clause, if any.
+    fn process_create_sequence(
+        name: &ObjectName,
+        owned_by: Option<ObjectName>,
+        builder: &ParserDBBuilder,
+    ) -> Result<Sequence, crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let (schema_ident, sequence_ident) = object_name_identifiers(name)?;
+        let mut sequence =
+            Sequence::new(sequence_ident.value.clone(), sequence_ident.quote_style.is_some());
+        if let Some(schema_ident) = schema_ident {
+            sequence = sequence
+                .with_schema(schema_ident.value.clone(), schema_ident.quote_style.is_some());
+        }
+
+        let Some(owned_by) = owned_by.filter(|owned_by| !is_owned_by_none(owned_by)) else {
+            return Ok(sequence);
+        };
+        let owner_not_found = || crate::errors::Error::SequenceOwnerNotFound {
+            sequence_name: sequence_ident.value.clone(),
+            owner: owned_by.to_string(),
+        };
+        let mut table_parts = owned_by.0.clone();
+        let Some(ObjectNamePart::Identifier(column_ident)) = table_parts.pop() else {
+            return Err(owner_not_found());
+        };
+        if table_parts.is_empty() {
+            return Err(owner_not_found());
+        }
+        let table_name = ObjectName(table_parts);
+
+        let (_, table_ident) = object_name_identifiers(&table_name)?;
+        let table = resolve_table_object_name_with_implicit_public_in_iter(
+            builder.tables_named(&table_ident.value, table_ident.quote_style.is_some()),
+            &table_name,
+            builder.identifier_casing(),
+        )?
+        .ok_or_else(owner_not_found)?;
+        let has_column = table.columns.iter().any(|column| {
+            casing.matches(
+                column.name.value.as_str(),
+                column.name.quote_style.is_some(),
+                column_ident.value.as_str(),
+                column_ident.quote_style.is_some(),
+            )
+        });
+        if !has_column {
+            return Err(owner_not_found());
+        }
+
+        Ok(sequence.with_owner(table_name, column_ident))
+    }
+
     #[allow(clippy::type_complexity)]
     /// Helper function to process create index statements.
     fn process_create_index(
@@ -809,18 +1677,254 @@ impl ParserDB {
         Ok((index_arc, metadata))
     }
 
+    /// Helper function to update the definition of an existing index, e.g.
+    /// for `ALTER INDEX`, keeping the tables referencing it in sync.
+    fn alter_index_checked(
+        builder: &mut ParserDBBuilder,
+        name: &ObjectName,
+        update: impl FnOnce(&mut CreateIndex),
+    ) -> Result<(), crate::errors::Error> {
+        let Some(position) = index_position(builder, name) else {
+            return Err(crate::errors::Error::AlterIndexNotFound {
+                index_name: last_str(name).to_string(),
+            });
+        };
+        let (index, _) = &mut builder.indices_mut()[position];
+
+        let mut create_index = index.attribute().clone();
+        update(&mut create_index);
+        let altered = Arc::new(TableAttribute::new(Arc::new(index.table().clone()), create_index));
+        let old_index = core::mem::replace(index, altered.clone());
+
+        for (_, table_meta) in builder.tables_mut() {
+            table_meta.replace_index(&old_index, &altered);
+        }
+        Ok(())
+    }
+
+    /// Helper function to apply a statement stripped from the SQL before
+    /// parsing.
+    fn apply_unparsed_statement(
+        builder: &mut ParserDBBuilder,
+        statement: &unparsed_statements::UnparsedStatement,
+    ) -> Result<(), crate::errors::Error> {
+        match statement {
+            unparsed_statements::UnparsedStatement::IndexSettings { name, change } => {
+                let casing = builder.identifier_casing();
+                Self::alter_index_checked(builder, name, |create_index| {
+                    change.apply(&mut create_index.with, casing);
+                })
+            }
+            unparsed_statements::UnparsedStatement::FunctionComment {
+                name,
+                arguments,
+                comment,
+            } => Self::comment_function_checked(
+                builder,
+                name,
+                arguments.as_deref(),
+                comment.as_deref(),
+            ),
+            unparsed_statements::UnparsedStatement::RoleMembership {
+                grant,
+                roles,
+                members,
+                ..
+            } => Self::role_membership_checked(builder, *grant, roles, members),
+            unparsed_statements::UnparsedStatement::DefaultPrivileges {
+                grant,
+                default_privilege,
+            } => Self::default_privileges_checked(builder, *grant, default_privilege),
+        }
+    }
+
+    /// Helper function to grant or revoke default privileges, as done by
+    /// `ALTER DEFAULT PRIVILEGES`.
+    ///
+    /// Granted default privileges apply to the tables created afterwards.
+    /// Revoking default privileges which were not granted is not an error,
+    /// as in PostgreSQL, where it withdraws the builtin ones.
+    fn default_privileges_checked(
+        builder: &mut ParserDBBuilder,
+        grant: bool,
+        default_privilege: &DefaultPrivilege,
+    ) -> Result<(), crate::errors::Error> {
+        let grantees = default_privilege.grantee_idents().iter().filter(|grantee| {
+            grantee.quote_style.is_some() || !grantee.value.eq_ignore_ascii_case("public")
+        });
+        for ident in default_privilege.target_role_idents().iter().chain(grantees) {
+            if !builder.role_exists(ident) {
+                return Err(crate::errors::Error::RoleNotFoundForGrant {
+                    role_name: ident.value.clone(),
+                });
+            }
+        }
+
+        if grant {
+            builder.push_default_privilege(Arc::new(default_privilege.clone()), ());
+            return Ok(());
+        }
+        let casing = builder.identifier_casing();
+        for (stored, ()) in core::mem::take(builder.default_privileges_mut()) {
+            match stored.revoked(default_privilege, casing) {
+                Some(replacement) => builder
+                    .default_privileges_mut()
+                    .extend(replacement.into_iter().map(|revoked| (Arc::new(revoked), ()))),
+                None => builder.default_privileges_mut().push((stored, ())),
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function to set the comment of the functions targeted by a
+    /// `COMMENT ON FUNCTION` statement.
+    ///
+    /// When the argument types are omitted, the comment is set on all the
+    /// overloads of the function.
+    fn comment_function_checked(
+        builder: &mut ParserDBBuilder,
+        name: &ObjectName,
+        arguments: Option<&[DataType]>,
+        comment: Option<&str>,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let not_found = || crate::errors::Error::CommentFunctionNotFound {
+            function_name: last_str(name).to_string(),
+        };
+        let (function_name, function_quoted) = object_name_last_part(name).ok_or_else(not_found)?;
+        let schema = schema_from_object_name(name);
+
+        let mut found = false;
+        for (function, metadata) in builder.functions_mut() {
+            let same_name = casing.matches(
+                function.name(),
+                function.name_is_quoted(),
+                function_name,
+                function_quoted,
+            );
+            // Functions declared without a schema match any qualification.
+            let same_schema = schema.is_none_or(|(schema, schema_quoted)| {
+                function.schema().is_none_or(|function_schema| {
+                    casing.matches(
+                        function_schema,
+                        function.schema_is_quoted(),
+                        schema,
+                        schema_quoted,
+                    )
+                })
+            });
+            let same_arguments = arguments.is_none_or(|arguments| {
+                let declared = function.args.as_deref().unwrap_or_default();
+                declared.len() == arguments.len()
+                    && declared.iter().zip(arguments).all(|(argument, data_type)| {
+                        // Types which cannot be normalized are compared as
+                        // written.
+                        match (
+                            try_normalize_sqlparser_type(&argument.data_type),
+                            try_normalize_sqlparser_type(data_type),
+                        ) {
+                            (Some(declared), Some(commented)) => declared == commented,
+                            _ => argument.data_type.to_string() == data_type.to_string(),
+                        }
+                    })
+            });
+            if same_name && same_schema && same_arguments {
+                metadata.set_comment(comment.map(str::to_owned));
+                found = true;
+            }
+        }
+        if !found {
+            return Err(not_found());
+        }
+        Ok(())
+    }
+
+    /// Helper function to apply a `COMMENT ON TABLE` or `COMMENT ON COLUMN`
+    /// statement to the metadata of the commented table.
+    fn comment_on_checked(
+        builder: &mut ParserDBBuilder,
+        object_type: CommentObject,
+        object_name: &ObjectName,
+        comment: Option<String>,
+        if_exists: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let (table_name, column) = match object_type {
+            CommentObject::Table => (object_name.clone(), None),
+            CommentObject::Column => {
+                let Some((ObjectNamePart::Identifier(column), table_parts)) =
+                    object_name.0.split_last()
+                else {
+                    return Ok(());
+                };
+                let column = (column.value.as_str(), column.quote_style.is_some());
+                (ObjectName(table_parts.to_vec()), Some(column))
+            }
+            // Comments on other objects are not tracked.
+            _ => return Ok(()),
+        };
+
+        let table_position = builder
+            .resolve_table_object_name(&table_name)?
+            .and_then(|resolved_table| builder.table_position(resolved_table));
+        let Some(table_position) = table_position else {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(match column {
+                None => crate::errors::Error::CommentTableNotFound {
+                    table_name: last_str(&table_name).to_string(),
+                },
+                Some(_) => crate::errors::Error::CommentColumnNotFound {
+                    table_name: last_str(&table_name).to_string(),
+                    column_name: last_str(object_name).to_string(),
+                },
+            });
+        };
+
+        let metadata = &mut builder.tables_mut()[table_position].1;
+        match column {
+            None => metadata.set_comment(comment),
+            Some((column_name, column_quoted)) => {
+                let Some(stored_name) = metadata
+                    .columns()
+                    .find(|column| {
+                        casing.matches(
+                            column.column_name(),
+                            column.column_name_is_quoted(),
+                            column_name,
+                            column_quoted,
+                        )
+                    })
+                    .map(|column| column.column_name().to_string())
+                else {
+                    if if_exists {
+                        return Ok(());
+                    }
+                    return Err(crate::errors::Error::CommentColumnNotFound {
+                        table_name: last_str(&table_name).to_string(),
+                        column_name: column_name.to_string(),
+                    });
+                };
+                metadata.set_column_comment(&stored_name, comment);
+            }
+        }
+        Ok(())
+    }
+
     /// Helper function to rename a table while preserving lookup invariants.
     fn rename_table_checked(
-        mut builder: ParserDBBuilder,
+        builder: &mut ParserDBBuilder,
         old_name: &ObjectName,
         new_name: ObjectName,
         if_exists: bool,
-    ) -> Result<ParserDBBuilder, crate::errors::Error> {
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
         use crate::traits::TableLike;
 
         let Some(resolved_table) = builder.resolve_table_object_name(old_name)? else {
             if if_exists {
-                return Ok(builder);
+                return Ok(());
             }
             return Err(crate::errors::Error::RenameTableNotFound {
                 table_name: last_str(old_name).to_string(),
@@ -838,65 +1942,464 @@ impl ParserDB {
                 resolved_table_quoted,
                 resolved_schema_name.as_deref(),
                 resolved_schema_quoted,
+                casing,
             )
         }) else {
             if if_exists {
-                return Ok(builder);
+                return Ok(());
             }
             return Err(crate::errors::Error::RenameTableNotFound {
                 table_name: last_str(old_name).to_string(),
             });
         };
 
-        let (old_table, meta) = builder.tables_mut().remove(table_position);
+        let (old_table, meta) = builder.remove_table_at(table_position);
         let mut renamed_table = (*old_table).clone();
         renamed_table.name = new_name;
 
-        builder = builder.add_table(Arc::new(renamed_table), meta)?;
-        builder.tables_mut().sort_by(|(a, _), (b, _)| {
-            (a.table_schema(), a.table_name()).cmp(&(b.table_schema(), b.table_name()))
+        builder.push_table(Arc::new(renamed_table), meta)?;
+        builder.sort_tables_by(|(a, _), (b, _)| {
+            super::table_key(a.as_ref()).cmp(&super::table_key(b.as_ref()))
         });
 
-        Ok(builder)
+        Ok(())
     }
 
-    /// Helper function to process column options.
-    fn process_column_options(
-        column: &Arc<TableAttribute<CreateTable, ColumnDef>>,
-        create_table: &Arc<CreateTable>,
-        table_metadata: &mut TableMetadata<CreateTable>,
-        mut builder: ParserDBBuilder,
-    ) -> Result<ParserDBBuilder, crate::errors::Error> {
-        for option in &column.attribute().options {
-            match option.option.clone() {
-                ColumnOption::Check(check_constraint) => {
-                    let check_arc = Arc::new(TableAttribute::new(
-                        create_table.clone(),
-                        check_constraint.clone(),
-                    ));
-                    table_metadata.add_check_constraint(check_arc.clone());
-                    let (columns_in_expression, functions_in_expression) =
-                        Self::process_check_constraint(
-                            &check_constraint.expr,
-                            create_table,
-                            table_metadata,
-                            &builder,
-                        )?;
-                    builder = builder.add_check_constraint(
-                        check_arc,
-                        CheckMetadata::new(
-                            *check_constraint.expr.clone(),
-                            create_table.clone(),
-                            columns_in_expression,
-                            functions_in_expression,
-                        ),
-                    );
-                }
-                ColumnOption::ForeignKey(mut foreign_key) => {
-                    foreign_key.columns.push(column.attribute().name.clone());
+    /// Helper function to find the position of the table altered by an
+    /// `ALTER TABLE` statement among the tables of the builder.
+    ///
+    /// Returns `None` when the table does not exist and `if_exists` is set.
+    fn altered_table_position(
+        builder: &ParserDBBuilder,
+        table_name: &ObjectName,
+        if_exists: bool,
+    ) -> Result<Option<usize>, crate::errors::Error> {
+        let position = builder
+            .resolve_table_object_name(table_name)?
+            .and_then(|resolved_table| builder.table_position(resolved_table));
+        if position.is_none() && !if_exists {
+            return Err(crate::errors::Error::AlterTableNotFound {
+                table_name: last_str(table_name).to_string(),
+            });
+        }
+        Ok(position)
+    }
+
+    /// Helper function to add a table constraint to an existing table, as
+    /// done by `ALTER TABLE ... ADD CONSTRAINT`.
+    ///
+    /// The constraint goes through the same validation as the constraints
+    /// of a `CREATE TABLE`.
+    fn add_constraint_checked(
+        builder: &mut ParserDBBuilder,
+        table_name: &ObjectName,
+        constraint: TableConstraint,
+        if_exists: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let Some(table_position) = Self::altered_table_position(builder, table_name, if_exists)?
+        else {
+            return Ok(());
+        };
+
+        let (create_table, mut table_metadata) = builder.tables()[table_position].clone();
+        Self::process_table_constraints(
+            &[constraint],
+            &create_table,
+            &mut table_metadata,
+            builder,
+        )?;
+        builder.tables_mut()[table_position].1 = table_metadata;
+
+        Ok(())
+    }
+
+    /// Helper function to drop a named constraint from an existing table, as
+    /// done by `ALTER TABLE ... DROP CONSTRAINT`.
+    ///
+    /// Foreign keys, unique constraints and check constraints are looked up
+    /// by name. Dropping the unique constraint backing the primary key also
+    /// clears the primary key of the table.
+    fn drop_constraint_checked(
+        builder: &mut ParserDBBuilder,
+        table_name: &ObjectName,
+        constraint_name: &Ident,
+        if_table_exists: bool,
+        if_constraint_exists: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let Some(table_position) =
+            Self::altered_table_position(builder, table_name, if_table_exists)?
+        else {
+            return Ok(());
+        };
+        let table = builder.tables()[table_position].0.clone();
+        let on_table = |candidate: &CreateTable| {
+            table_matches_resolved_identity(
+                candidate,
+                table.table_name(),
+                table.table_name_is_quoted(),
+                table.table_schema(),
+                table.table_schema_is_quoted(),
+                casing,
+            )
+        };
+
+        let named = |name: Option<&Ident>| {
+            name.is_some_and(|name| {
+                casing.matches(
+                    name.value.as_str(),
+                    name.quote_style.is_some(),
+                    constraint_name.value.as_str(),
+                    constraint_name.quote_style.is_some(),
+                )
+            })
+        };
+        let foreign_key_named = |fk: &TableAttribute<CreateTable, ForeignKeyConstraint>| {
+            on_table(TableAttribute::table(fk)) && named(fk.attribute().name.as_ref())
+        };
+        let unique_index_named = |index: &TableAttribute<CreateTable, UniqueConstraint>| {
+            on_table(TableAttribute::table(index)) && named(index.attribute().name.as_ref())
+        };
+        let check_named = |check: &TableAttribute<CreateTable, CheckConstraint>| {
+            on_table(TableAttribute::table(check)) && named(check.attribute().name.as_ref())
+        };
+
+        let metadata = &builder.tables()[table_position].1;
+        let exists = metadata.foreign_keys().any(foreign_key_named)
+            || metadata.external_foreign_keys().any(foreign_key_named)
+            || metadata.unique_indices().any(unique_index_named)
+            || metadata.check_constraints().any(check_named);
+        if !exists {
+            if if_constraint_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::DropConstraintNotFound {
+                constraint_name: constraint_name.value.clone(),
+                table_name: table.name.to_string(),
+            });
+        }
+
+        let dropped_unique_columns: Vec<Vec<Expr>> = metadata
+            .unique_indices()
+            .filter(|index| unique_index_named(index))
+            .map(|index| index.attribute().columns.iter().map(|c| c.column.expr.clone()).collect())
+            .collect();
+
+        builder.foreign_keys_mut().retain(|(fk, ())| !foreign_key_named(fk));
+        builder.unique_indices_mut().retain(|(index, _)| !unique_index_named(index));
+        builder.check_constraints_mut().retain(|(check, _)| !check_named(check));
+
+        let metadata = &mut builder.tables_mut()[table_position].1;
+        metadata.retain_foreign_keys(|fk| !foreign_key_named(fk));
+        metadata.retain_external_foreign_keys(|fk| !foreign_key_named(fk));
+        metadata.retain_unique_indices(|index| !unique_index_named(index));
+        metadata.retain_check_constraints(|check| !check_named(check));
+
+        let primary_key_dropped = dropped_unique_columns.iter().any(|columns| {
+            columns.len() == metadata.primary_key_columns().count()
+                && metadata.primary_key_columns().all(|pk_column| {
+                    columns.iter().any(|expr| {
+                        matches!(expr, Expr::Identifier(ident) if casing.matches(
+                            pk_column.column_name(),
+                            pk_column.column_name_is_quoted(),
+                            ident.value.as_str(),
+                            ident.quote_style.is_some(),
+                        ))
+                    })
+                })
+        });
+        if primary_key_dropped {
+            metadata.clear_primary_key();
+            builder
+                .primary_keys_mut()
+                .retain(|(primary_key, ())| !on_table(TableAttribute::table(primary_key)));
+        }
+
+        Ok(())
+    }
+
+    /// Helper function to add a column to an existing table, as done by
+    /// `ALTER TABLE ... ADD COLUMN`.
+    ///
+    /// The column is registered in the metadata of the owning table and its
+    /// options are processed as for the columns of a `CREATE TABLE`.
+    fn add_column_checked(
+        builder: &mut ParserDBBuilder,
+        table_name: &ObjectName,
+        column_def: ColumnDef,
+        if_exists: bool,
+        if_not_exists: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let Some(table_position) = Self::altered_table_position(builder, table_name, if_exists)?
+        else {
+            return Ok(());
+        };
+
+        let (create_table, mut table_metadata) = builder.tables()[table_position].clone();
+
+        let column_exists = table_metadata.column_arcs().any(|column| {
+            casing.matches(
+                column.column_name(),
+                column.column_name_is_quoted(),
+                column_def.name.value.as_str(),
+                column_def.name.quote_style.is_some(),
+            )
+        });
+        if column_exists {
+            if if_not_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::ColumnAlreadyExists {
+                column_name: column_def.name.value.clone(),
+                table_name: create_table.name.to_string(),
+            });
+        }
+
+        let column = Arc::new(TableAttribute::new(create_table.clone(), column_def));
+        table_metadata.add_column(column.clone());
+        Self::process_column_options(&column, &create_table, &mut table_metadata, builder)?;
+        builder.push_column(column, ());
+        builder.tables_mut()[table_position].1 = table_metadata;
+
+        Ok(())
+    }
+
+    /// Helper function to drop a column from an existing table, as done by
+    /// `ALTER TABLE ... DROP COLUMN`.
+    ///
+    /// Unless `cascade` is set, the column may not be used by any index,
+    /// unique index, check constraint, foreign key or column grant. With
+    /// `cascade`, those objects are dropped along with the column, while
+    /// column grants only lose the dropped column.
+    fn drop_column_checked(
+        builder: &mut ParserDBBuilder,
+        table_name: &ObjectName,
+        column_name: &Ident,
+        if_table_exists: bool,
+        if_column_exists: bool,
+        cascade: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        let Some(resolved_table) = builder.resolve_table_object_name(table_name)? else {
+            if if_table_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::AlterTableNotFound {
+                table_name: last_str(table_name).to_string(),
+            });
+        };
+        let resolved_table_name = resolved_table.table_name().to_string();
+        let resolved_table_quoted = resolved_table.table_name_is_quoted();
+        let resolved_schema_name = resolved_table.table_schema().map(str::to_string);
+        let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+        let on_table = |table: &CreateTable| {
+            table_matches_resolved_identity(
+                table,
+                &resolved_table_name,
+                resolved_table_quoted,
+                resolved_schema_name.as_deref(),
+                resolved_schema_quoted,
+                casing,
+            )
+        };
+        let on_table_name = |object_name: &ObjectName| {
+            object_name_matches_resolved_identity(
+                object_name,
+                &resolved_table_name,
+                resolved_table_quoted,
+                resolved_schema_name.as_deref(),
+                resolved_schema_quoted,
+                casing,
+            )
+        };
+
+        let Some(table_position) =
+            builder.tables().iter().position(|(table, _)| on_table(table.as_ref()))
+        else {
+            if if_table_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::AlterTableNotFound {
+                table_name: last_str(table_name).to_string(),
+            });
+        };
+
+        let Some(column) = builder.tables()[table_position]
+            .1
+            .column_arcs()
+            .find(|column| {
+                casing.matches(
+                    column.column_name(),
+                    column.column_name_is_quoted(),
+                    column_name.value.as_str(),
+                    column_name.quote_style.is_some(),
+                )
+            })
+            .cloned()
+        else {
+            if if_column_exists {
+                return Ok(());
+            }
+            return Err(crate::errors::Error::DropColumnNotFound {
+                column_name: column_name.value.clone(),
+                table_name: resolved_table_name.clone(),
+            });
+        };
+
+        let names_column = |ident: &Ident| {
+            casing.matches(
+                column.column_name(),
+                column.column_name_is_quoted(),
+                ident.value.as_str(),
+                ident.quote_style.is_some(),
+            )
+        };
+        let mentions_column = |expr: &Expr| {
+            crate::utils::columns_in_expression::expression_references_column(
+                expr,
+                column.as_ref(),
+                casing,
+            )
+        };
+        let index_depends = |index: &TableAttribute<CreateTable, CreateIndex>| {
+            on_table(TableAttribute::table(index))
+                && (index.attribute().columns.iter().any(|c| mentions_column(&c.column.expr))
+                    || index.attribute().predicate.as_ref().is_some_and(mentions_column))
+        };
+        let unique_index_depends = |index: &TableAttribute<CreateTable, UniqueConstraint>| {
+            on_table(TableAttribute::table(index))
+                && index.attribute().columns.iter().any(|c| mentions_column(&c.column.expr))
+        };
+        let check_depends = |check: &TableAttribute<CreateTable, CheckConstraint>| {
+            on_table(TableAttribute::table(check)) && mentions_column(&check.attribute().expr)
+        };
+        let foreign_key_depends = |fk: &TableAttribute<CreateTable, ForeignKeyConstraint>| {
+            (on_table(TableAttribute::table(fk)) && fk.attribute().columns.iter().any(names_column))
+                || (on_table_name(&fk.attribute().foreign_table)
+                    && fk.attribute().referred_columns.iter().any(names_column))
+        };
+        let grant_depends = |grant: &Grant| {
+            matches!(
+                &grant.objects,
+                Some(sqlparser::ast::GrantObjects::Tables(tables)) if tables.iter().any(on_table_name)
+            ) && matches!(
+                &grant.privileges,
+                sqlparser::ast::Privileges::Actions(actions)
+                    if actions.iter().any(|action| {
+                        grant_action_columns(action).is_some_and(|columns| columns.iter().any(names_column))
+                    })
+            )
+        };
+
+        let referenced = builder.indices().iter().any(|(index, _)| index_depends(index))
+            || builder
+                .unique_indices()
+                .iter()
+                .any(|(unique_index, _)| unique_index_depends(unique_index))
+            || builder.check_constraints().iter().any(|(check, _)| check_depends(check))
+            || builder.foreign_keys().iter().any(|(fk, ())| foreign_key_depends(fk))
+            || builder.tables()[table_position].1.external_foreign_keys().any(foreign_key_depends)
+            || builder.column_grants().iter().any(|(grant, ())| grant_depends(grant));
+        if referenced && !cascade {
+            return Err(crate::errors::Error::ColumnReferenced {
+                column_name: column.column_name().to_string(),
+                table_name: resolved_table_name.clone(),
+            });
+        }
+
+        let drops_primary_key = builder.tables()[table_position]
+            .1
+            .primary_key_columns()
+            .any(|pk_column| pk_column == column.as_ref());
+
+        builder.columns_mut().retain(|(candidate, ())| !Arc::ptr_eq(candidate, &column));
+        builder.indices_mut().retain(|(index, _)| !index_depends(index));
+        if drops_primary_key {
+            builder
+                .primary_keys_mut()
+                .retain(|(primary_key, ())| !on_table(TableAttribute::table(primary_key)));
+        }
+        builder
+            .unique_indices_mut()
+            .retain(|(unique_index, _)| !unique_index_depends(unique_index));
+        builder.check_constraints_mut().retain(|(check, _)| !check_depends(check));
+        builder.foreign_keys_mut().retain(|(fk, ())| !foreign_key_depends(fk));
+        builder.sequences_mut().retain(|(sequence, ())| {
+            !sequence.owned_by().is_some_and(|(owner_table, owner_column)| {
+                on_table_name(owner_table) && names_column(owner_column)
+            })
+        });
+        let table_grants = core::mem::take(builder.table_grants_mut());
+        *builder.table_grants_mut() =
+            retain_grants_without_column(table_grants, &grant_depends, &names_column);
+        let column_grants = core::mem::take(builder.column_grants_mut());
+        *builder.column_grants_mut() =
+            retain_grants_without_column(column_grants, &grant_depends, &names_column);
+
+        for (table, metadata) in builder.tables_mut() {
+            metadata.retain_foreign_keys(|fk| !foreign_key_depends(fk));
+            if !on_table(table.as_ref()) {
+                continue;
+            }
+            metadata.retain_columns(|candidate| !Arc::ptr_eq(candidate, &column));
+            metadata.retain_indices(|index| !index_depends(index));
+            metadata.retain_unique_indices(|unique_index| !unique_index_depends(unique_index));
+            metadata.retain_check_constraints(|check| !check_depends(check));
+            metadata.retain_external_foreign_keys(|fk| !foreign_key_depends(fk));
+            if drops_primary_key {
+                metadata.clear_primary_key();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Helper function to process column options.
+    fn process_column_options(
+        column: &Arc<TableAttribute<CreateTable, ColumnDef>>,
+        create_table: &Arc<CreateTable>,
+        table_metadata: &mut TableMetadata<CreateTable>,
+        builder: &mut ParserDBBuilder,
+    ) -> Result<(), crate::errors::Error> {
+        for option in &column.attribute().options {
+            match option.option.clone() {
+                ColumnOption::Check(check_constraint) => {
+                    let check_arc = Arc::new(TableAttribute::new(
+                        create_table.clone(),
+                        check_constraint.clone(),
+                    ));
+                    table_metadata.add_check_constraint(check_arc.clone());
+                    let (columns_in_expression, functions_in_expression) =
+                        Self::process_check_constraint(
+                            &check_constraint.expr,
+                            create_table,
+                            table_metadata,
+                            builder,
+                        )?;
+                    builder.push_check_constraint(
+                        check_arc,
+                        CheckMetadata::new(
+                            *check_constraint.expr.clone(),
+                            create_table.clone(),
+                            columns_in_expression,
+                            functions_in_expression,
+                        ),
+                    );
+                }
+                ColumnOption::ForeignKey(mut foreign_key) => {
+                    foreign_key.columns.push(column.attribute().name.clone());
                     let fk = Arc::new(TableAttribute::new(create_table.clone(), foreign_key));
+                    if !builder.filter().admits_object_name(
+                        &fk.attribute().foreign_table,
+                        builder.identifier_casing(),
+                    ) {
+                        table_metadata.add_external_foreign_key(fk);
+                        continue;
+                    }
                     table_metadata.add_foreign_key(fk.clone());
-                    builder = builder.add_foreign_key(fk, ());
+                    builder.push_foreign_key(fk, ());
                 }
                 ColumnOption::Unique(mut unique_constraint) => {
                     unique_constraint.columns.push(IndexColumn {
@@ -911,10 +2414,10 @@ impl ParserDB {
                         Self::process_unique_constraint(unique_constraint, create_table)
                     {
                         table_metadata.add_unique_index(unique_index.clone());
-                        builder = builder.add_unique_index(unique_index, unique_index_metadata);
+                        builder.push_unique_index(unique_index, unique_index_metadata);
                     }
                 }
-                ColumnOption::PrimaryKey(_) => {
+                ColumnOption::PrimaryKey(mut primary_key) => {
                     let primary_key_unique_constraint = UniqueConstraint {
                         name: None,
                         index_name: None,
@@ -937,15 +2440,26 @@ impl ParserDB {
                         Self::process_unique_constraint(primary_key_unique_constraint, create_table)
                     {
                         table_metadata.add_unique_index(unique_index.clone());
-                        builder = builder.add_unique_index(unique_index, unique_index_metadata);
+                        builder.push_unique_index(unique_index, unique_index_metadata);
                     }
 
-                    table_metadata.set_primary_key(vec![column.clone()]);
+                    // The constraint of an inline primary key is kept without
+                    // columns, as parsed, while its name is the one given to
+                    // the column option.
+                    if primary_key.name.is_none() {
+                        primary_key.name.clone_from(&option.name);
+                    }
+                    Self::set_primary_key(
+                        builder,
+                        table_metadata,
+                        Arc::new(TableAttribute::new(create_table.clone(), primary_key)),
+                        vec![column.clone()],
+                    );
                 }
                 _ => {}
             }
         }
-        Ok(builder)
+        Ok(())
     }
 
     /// Helper function to process a foreign key table constraint.
@@ -953,11 +2467,12 @@ impl ParserDB {
         fk: &ForeignKeyConstraint,
         create_table: &Arc<CreateTable>,
         table_metadata: &mut TableMetadata<CreateTable>,
-        builder: ParserDBBuilder,
-    ) -> Result<ParserDBBuilder, crate::errors::Error> {
+        builder: &mut ParserDBBuilder,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
         for col_ident in &fk.columns {
             let column_exists = table_metadata.column_arcs().any(|col| {
-                identifiers_match(
+                casing.matches(
                     col.column_name(),
                     col.column_name_is_quoted(),
                     col_ident.value.as_str(),
@@ -973,34 +2488,68 @@ impl ParserDB {
             }
         }
 
+        // References to tables excluded by the object filter cannot be
+        // validated, and are kept aside as external references.
+        if !builder.filter().admits_object_name(&fk.foreign_table, builder.identifier_casing()) {
+            table_metadata.add_external_foreign_key(Arc::new(TableAttribute::new(
+                create_table.clone(),
+                fk.clone(),
+            )));
+            return Ok(());
+        }
+
         let referenced_table_name = fk.foreign_table.to_string();
 
-        let referenced_table = resolve_table_object_name_in_iter(
+        let (_, referenced_ident) = object_name_identifiers(&fk.foreign_table)?;
+        let referenced_table = resolve_table_object_name_in_search_path(
             builder
-                .tables()
-                .iter()
-                .map(|(t, _)| t.as_ref())
+                .tables_named(&referenced_ident.value, referenced_ident.quote_style.is_some())
                 .chain(core::iter::once(create_table.as_ref())),
             &fk.foreign_table,
+            builder.searched_schemas(),
+            builder.identifier_casing(),
         )?;
         let Some(referenced_table) = referenced_table else {
+            // The referenced table may still be created by a later statement,
+            // or never be, so the foreign key is kept as declared.
+            if builder.defers_foreign_keys() {
+                let fk_arc = Arc::new(TableAttribute::new(create_table.clone(), fk.clone()));
+                table_metadata.add_foreign_key(fk_arc.clone());
+                builder.push_foreign_key(fk_arc, ());
+                return Ok(());
+            }
             return Err(crate::errors::Error::ReferencedTableNotFoundForForeignKey {
                 referenced_table: referenced_table_name.clone(),
                 host_table: create_table.name.to_string(),
             });
         };
 
+        let referenced_metadata = builder
+            .tables()
+            .iter()
+            .find(|(table, _)| core::ptr::eq(table.as_ref(), referenced_table))
+            .map(|(_, metadata)| metadata);
+
         for ref_col_ident in &fk.referred_columns {
             let column_exists = referenced_table.columns.iter().any(|col| {
-                identifiers_match(
+                casing.matches(
                     col.name.value.as_str(),
                     col.name.quote_style.is_some(),
                     ref_col_ident.value.as_str(),
                     ref_col_ident.quote_style.is_some(),
                 )
+            }) || referenced_metadata.is_some_and(|metadata| {
+                metadata.column_arcs().any(|col| {
+                    casing.matches(
+                        col.column_name(),
+                        col.column_name_is_quoted(),
+                        ref_col_ident.value.as_str(),
+                        ref_col_ident.quote_style.is_some(),
+                    )
+                })
             });
 
-            if !column_exists {
+            if !column_exists && !builder.defers_foreign_keys() {
                 return Err(crate::errors::Error::ReferencedColumnNotFoundForForeignKey {
                     referenced_column: ref_col_ident.value.clone(),
                     referenced_table: referenced_table_name.clone(),
@@ -1009,10 +2558,12 @@ impl ParserDB {
             }
         }
 
-        let fk_arc = Arc::new(TableAttribute::new(create_table.clone(), fk.clone()));
+        let mut fk = fk.clone();
+        qualify_object_name(&mut fk.foreign_table, &referenced_table.name);
+        let fk_arc = Arc::new(TableAttribute::new(create_table.clone(), fk));
         table_metadata.add_foreign_key(fk_arc.clone());
-        let builder = builder.add_foreign_key(fk_arc, ());
-        Ok(builder)
+        builder.push_foreign_key(fk_arc, ());
+        Ok(())
     }
 
     /// Helper function to process table constraints.
@@ -1020,8 +2571,9 @@ impl ParserDB {
         constraints: &[TableConstraint],
         create_table: &Arc<CreateTable>,
         table_metadata: &mut TableMetadata<CreateTable>,
-        mut builder: ParserDBBuilder,
-    ) -> Result<ParserDBBuilder, crate::errors::Error> {
+        builder: &mut ParserDBBuilder,
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
         for constraint in constraints {
             match constraint {
                 TableConstraint::Unique(uc) => {
@@ -1029,11 +2581,19 @@ impl ParserDB {
                         Self::process_unique_constraint(uc.clone(), create_table)
                     {
                         table_metadata.add_unique_index(unique_index.clone());
-                        builder = builder.add_unique_index(unique_index, unique_index_metadata);
+                        builder.push_unique_index(unique_index, unique_index_metadata);
+                    }
+                }
+                TableConstraint::Index(index_constraint) => {
+                    if let Some((index, index_metadata)) =
+                        Self::process_index_constraint(index_constraint, create_table)
+                    {
+                        table_metadata.add_index(index.clone());
+                        builder.push_index(index, index_metadata);
                     }
                 }
                 TableConstraint::ForeignKey(fk) => {
-                    builder = Self::process_foreign_key_table_constraint(
+                    Self::process_foreign_key_table_constraint(
                         fk,
                         create_table,
                         table_metadata,
@@ -1049,9 +2609,9 @@ impl ParserDB {
                             &check.expr,
                             create_table,
                             table_metadata,
-                            &builder,
+                            builder,
                         )?;
-                    builder = builder.add_check_constraint(
+                    builder.push_check_constraint(
                         check_arc,
                         CheckMetadata::new(
                             *check.expr.clone(),
@@ -1064,17 +2624,24 @@ impl ParserDB {
                 TableConstraint::PrimaryKey(pk) => {
                     let mut primary_key_columns = Vec::new();
                     for col_name in &pk.columns {
-                        let Expr::Identifier(column_name) = &col_name.column.expr else {
-                            unreachable!(
-                                "Unexpected expression in primary key column: {:?}",
-                                col_name
-                            )
+                        let mut expr = &col_name.column.expr;
+                        while let Expr::Nested(nested) = expr {
+                            expr = nested;
+                        }
+                        let Expr::Identifier(column_name) = expr else {
+                            return Err(crate::errors::Error::InvalidPrimaryKey {
+                                table_name: create_table.name.to_string(),
+                                reason: format!(
+                                    "`{}` is an expression rather than a column",
+                                    col_name.column.expr
+                                ),
+                            });
                         };
                         primary_key_columns.extend(
                             table_metadata
                                 .column_arcs()
                                 .filter(|col: &&Arc<TableAttribute<CreateTable, ColumnDef>>| {
-                                    identifiers_match(
+                                    casing.matches(
                                         col.column_name(),
                                         col.column_name_is_quoted(),
                                         column_name.value.as_str(),
@@ -1100,15 +2667,20 @@ impl ParserDB {
                         Self::process_unique_constraint(primary_key_unique_constraint, create_table)
                     {
                         table_metadata.add_unique_index(unique_index.clone());
-                        builder = builder.add_unique_index(unique_index, unique_index_metadata);
+                        builder.push_unique_index(unique_index, unique_index_metadata);
                     }
 
-                    table_metadata.set_primary_key(primary_key_columns);
+                    Self::set_primary_key(
+                        builder,
+                        table_metadata,
+                        Arc::new(TableAttribute::new(create_table.clone(), pk.clone())),
+                        primary_key_columns,
+                    );
                 }
                 _ => {}
             }
         }
-        Ok(builder)
+        Ok(())
     }
 
     /// Creates a new `ParserDB` from a vector of SQL statements and a catalog
@@ -1119,14 +2691,12 @@ impl ParserDB {
     /// * `statements` - A vector of SQL statements to parse.
     /// * `catalog_name` - The name of the database catalog.
     ///
-    /// # Panics
-    ///
-    /// Panics if an unsupported statement is encountered.
-    ///
     /// # Errors
     ///
     /// Returns an error if validation fails (e.g., foreign key references
-    /// non-existent tables or columns).
+    /// non-existent tables or columns). Statements defining schema objects
+    /// which are not modelled, such as `ALTER TYPE`, are skipped and recorded
+    /// as [`GenericDB::validation_warnings`].
     ///
     /// # Example
     ///
@@ -1146,7 +2716,6 @@ impl ParserDB {
     /// let db = ParserDB::from_statements(statements, "test".to_string()).unwrap();
     /// assert_eq!(db.catalog_name(), "test");
     /// ```
-    #[allow(clippy::too_many_lines)]
     pub fn from_statements(
         statements: Vec<Statement>,
         catalog_name: String,
@@ -1163,953 +2732,1750 @@ impl ParserDB {
     ///
     /// Returns an error if validation fails (e.g. a foreign key references a
     /// non-existent table or column).
-    #[allow(clippy::too_many_lines)]
     pub fn from_statements_with_dialect(
         statements: Vec<Statement>,
         catalog_name: String,
         dialect: SqlparserDialect,
     ) -> Result<Self, crate::errors::Error> {
-        let mut builder: ParserDBBuilder = super::GenericDBBuilder::new(catalog_name, dialect);
+        Self::from_filtered_statements(
+            statements,
+            catalog_name,
+            dialect,
+            ObjectFilter::default(),
+            &[],
+            &[],
+            &mut Validator::aborting(ParserOptions::default()),
+            None,
+        )
+    }
 
-        let any_type = DataType::Custom(
-            ObjectName(vec![ObjectNamePart::Identifier(Ident::with_quote('"', "any"))]),
-            vec![],
-        );
+    /// Same as [`Self::from_statements`] but only loads the schemas and
+    /// tables admitted by the provided [`ObjectFilter`].
+    ///
+    /// Statements concerning excluded objects are skipped before being
+    /// processed. Foreign keys referencing excluded tables are not validated:
+    /// they are kept aside as external references, available through
+    /// [`TableMetadata::external_foreign_keys`], and are not part of the
+    /// foreign keys of the database.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails (e.g. a foreign key references a
+    /// non-existent table or column).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+    ///
+    /// let sql = "
+    /// CREATE TABLE audit.events (id INT PRIMARY KEY);
+    /// CREATE TABLE app.users (id INT PRIMARY KEY, last_event INT REFERENCES audit.events (id));
+    /// ";
+    ///
+    /// let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+    /// let filter = ObjectFilter::default().exclude_schema("audit");
+    /// let db = ParserDB::from_statements_with_filter(statements, "test".to_string(), filter)
+    ///     .unwrap();
+    ///
+    /// let users = db.table(Some("app"), "users").unwrap();
+    /// assert!(db.table(Some("audit"), "events").is_none());
+    /// assert_eq!(users.foreign_keys(&db).count(), 0);
+    /// assert_eq!(db.table_metadata(users).unwrap().external_foreign_keys().count(), 1);
+    /// ```
+    pub fn from_statements_with_filter(
+        statements: Vec<Statement>,
+        catalog_name: String,
+        object_filter: ObjectFilter,
+    ) -> Result<Self, crate::errors::Error> {
+        Self::from_filtered_statements(
+            statements,
+            catalog_name,
+            SqlparserDialect::default(),
+            object_filter,
+            &[],
+            &[],
+            &mut Validator::aborting(ParserOptions::default()),
+            None,
+        )
+    }
 
-        let arg = |data_type: DataType| {
-            OperateFunctionArg { mode: None, name: None, data_type, default_expr: None }
-        };
+    /// Same as [`Self::from_statements`] but notifies the provided
+    /// [`ParseObserver`] of the outcome of each statement, whether it is
+    /// processed, ignored as it does not concern the schema, unsupported or
+    /// failing a validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails (e.g. a foreign key references a
+    /// non-existent table or column), or if a statement defines schema
+    /// objects which are not modelled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+    ///
+    /// #[derive(Default)]
+    /// struct Counts {
+    ///     processed: usize,
+    ///     ignored: usize,
+    /// }
+    ///
+    /// impl ParseObserver for Counts {
+    ///     fn on_processed(&mut self, _statement: &Statement) {
+    ///         self.processed += 1;
+    ///     }
+    ///
+    ///     fn on_ignored(&mut self, _statement: &Statement) {
+    ///         self.ignored += 1;
+    ///     }
+    /// }
+    ///
+    /// let sql = "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// INSERT INTO users VALUES (1);
+    /// SELECT * FROM users;
+    /// ";
+    /// let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql)?;
+    /// let mut counts = Counts::default();
+    /// ParserDB::from_statements_with_observer(statements, "test".to_string(), &mut counts)?;
+    /// assert_eq!((counts.processed, counts.ignored), (1, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_statements_with_observer(
+        statements: Vec<Statement>,
+        catalog_name: String,
+        observer: &mut impl ParseObserver,
+    ) -> Result<Self, crate::errors::Error> {
+        Self::from_filtered_statements(
+            statements,
+            catalog_name,
+            SqlparserDialect::default(),
+            ObjectFilter::default(),
+            &[],
+            &[],
+            &mut Validator::aborting(ParserOptions::default()),
+            Some(observer),
+        )
+    }
 
-        let variadic_arg = |data_type: DataType| {
-            OperateFunctionArg {
-                mode: None,
-                name: Some(Ident::new("VARIADIC")),
-                data_type,
-                default_expr: None,
-            }
-        };
+    /// Builds the database from the statements concerning the objects
+    /// admitted by the provided filter, applying the statements stripped from
+    /// the SQL before parsing once the statements preceding them are
+    /// processed, and recording the location of the objects they create
+    /// within the provided files, and notifying the provided observer of the
+    /// outcome of each statement.
+    #[allow(clippy::too_many_arguments)]
+    fn from_filtered_statements(
+        statements: Vec<Statement>,
+        catalog_name: String,
+        dialect: SqlparserDialect,
+        object_filter: ObjectFilter,
+        unparsed: &[unparsed_statements::Unparsed],
+        source_files: &[source_locations::SourceFile],
+        validator: &mut Validator,
+        observer: Option<&mut dyn ParseObserver>,
+    ) -> Result<Self, crate::errors::Error> {
+        let mut builder = Self::initial_builder(catalog_name, dialect, &object_filter, validator);
+        Self::apply_statements(
+            &mut builder,
+            statements,
+            &object_filter,
+            unparsed,
+            source_files,
+            validator,
+            observer,
+        )?;
+        Self::build(builder, validator)
+    }
 
-        let builtins = vec![
-            ("length", vec![arg(DataType::Text)], DataType::Int(None)),
-            ("len", vec![arg(DataType::Text)], DataType::Int(None)),
-            ("char_length", vec![arg(DataType::Text)], DataType::Int(None)),
-            ("character_length", vec![arg(DataType::Text)], DataType::Int(None)),
-            ("octet_length", vec![arg(DataType::Text)], DataType::Int(None)),
-            ("coalesce", vec![variadic_arg(any_type.clone())], any_type.clone()),
-            ("nullif", vec![arg(any_type.clone()), arg(any_type.clone())], any_type.clone()),
-            ("now", vec![], DataType::Timestamp(None, TimezoneInfo::WithTimeZone)),
-            ("current_timestamp", vec![], DataType::Timestamp(None, TimezoneInfo::WithTimeZone)),
-            ("current_date", vec![], DataType::Date),
-            ("current_time", vec![], DataType::Time(None, TimezoneInfo::WithTimeZone)),
-            ("localtimestamp", vec![], DataType::Timestamp(None, TimezoneInfo::None)),
-            ("localtime", vec![], DataType::Time(None, TimezoneInfo::None)),
-            ("gen_random_uuid", vec![], DataType::Uuid),
-            ("uuidv4", vec![], DataType::Uuid),
-            ("uuidv7", vec![], DataType::Uuid),
-            (
-                "uuidv7",
-                vec![arg(DataType::Interval { fields: None, precision: None })],
-                DataType::Uuid,
-            ),
-            ("count", vec![arg(any_type.clone())], DataType::BigInt(None)),
-            ("sum", vec![arg(any_type.clone())], DataType::Numeric(ExactNumberInfo::None)),
-            ("avg", vec![arg(any_type.clone())], DataType::Numeric(ExactNumberInfo::None)),
-            ("min", vec![arg(any_type.clone())], any_type.clone()),
-            ("max", vec![arg(any_type.clone())], any_type.clone()),
-            ("current_user", vec![], DataType::Text),
-            ("session_user", vec![], DataType::Text),
-            ("user", vec![], DataType::Text),
-        ];
-
-        for (name, args, return_type) in builtins {
-            let create_function = CreateFunction {
-                or_alter: false,
-                or_replace: false,
-                temporary: false,
-                if_not_exists: false,
-                name: ObjectName(vec![ObjectNamePart::Identifier(Ident::new(name))]),
-                args: Some(args),
-                return_type: Some(FunctionReturnType::DataType(return_type)),
-                function_body: Some(CreateFunctionBody::AsBeforeOptions {
-                    body: Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(String::new()),
-                        span: Span::empty(),
-                    }),
-                    link_symbol: None,
-                }),
-                behavior: None,
-                called_on_null: None,
-                parallel: None,
-                using: None,
-                language: Some(Ident::new("internal")),
-                determinism_specifier: None,
-                options: None,
-                remote_connection: None,
-                security: None,
-                set_params: vec![],
-            };
-            builder = builder.add_function(Arc::new(create_function), ());
+    /// Returns the builder of a database holding only the built-in functions
+    /// of the validator, or of the dialect if the validator has none.
+    fn initial_builder(
+        catalog_name: String,
+        dialect: SqlparserDialect,
+        object_filter: &ObjectFilter,
+        validator: &Validator,
+    ) -> ParserDBBuilder {
+        let mut builder: ParserDBBuilder = super::GenericDBBuilder::new(catalog_name, dialect)
+            .object_filter(object_filter.clone())
+            .extension_strategy(validator.extension_strategy().clone())
+            .search_path(validator.search_path().to_vec())
+            .deferred_foreign_keys(validator.defers_foreign_keys());
+
+        let builtins = validator
+            .builtin_registry()
+            .cloned()
+            .unwrap_or_else(|| BuiltinRegistry::for_dialect(dialect));
+        for builtin in builtins.functions() {
+            let args = builtin
+                .arguments()
+                .iter()
+                .map(|data_type| {
+                    OperateFunctionArg {
+                        mode: None,
+                        name: builtin.is_variadic().then(|| Ident::new("VARIADIC")),
+                        data_type: data_type.clone(),
+                        default_expr: None,
+                    }
+                })
+                .collect();
+            let create_function = catalog_function(
+                builtin.name(),
+                args,
+                builtin.return_type().clone(),
+                "internal",
+                String::new(),
+            );
+            let mut metadata = FunctionMetadata::default();
+            metadata.set_builtin(true);
+            builder = builder.add_function(Arc::new(create_function), metadata);
         }
+        builder
+    }
 
-        for statement in statements {
-            match statement {
-                Statement::CreateFunction(create_function) => {
-                    builder = builder.add_function(Arc::new(create_function), ());
+    /// Applies to the builder the statements concerning the objects admitted
+    /// by the provided filter, applying the statements stripped from the SQL
+    /// before parsing once the statements preceding them are processed, and
+    /// recording the location of the objects they create within the provided
+    /// files, and notifying the provided observer of the outcome of each
+    /// statement.
+    #[allow(clippy::too_many_lines)]
+    fn apply_statements(
+        builder: &mut ParserDBBuilder,
+        statements: Vec<Statement>,
+        object_filter: &ObjectFilter,
+        unparsed: &[unparsed_statements::Unparsed],
+        source_files: &[source_locations::SourceFile],
+        validator: &mut Validator,
+        mut observer: Option<&mut dyn ParseObserver>,
+    ) -> Result<(), crate::errors::Error> {
+        let dialect = *builder.dialect();
+        let casing = builder.identifier_casing();
+        // The functions the builder already holds have no location.
+        let builtin_functions = builder.function_arc_vec();
+
+        let mut pending_unparsed = unparsed.iter().peekable();
+        for (position, statement) in statements.into_iter().enumerate() {
+            validator.leave_statement();
+            while let Some(unparsed) =
+                pending_unparsed.next_if(|unparsed| unparsed.position <= position)
+            {
+                Self::apply_unparsed_validated(builder, validator, &unparsed.statement)?;
+            }
+            // The statement is consumed once applied, so the observer is
+            // notified with a copy of it.
+            let mut observed =
+                observer.as_deref_mut().map(|observer| (observer, statement.clone()));
+            let Some(statement) = filter_statement(statement, object_filter, casing) else {
+                if let Some((observer, statement)) = &mut observed {
+                    observer.on_ignored(statement);
                 }
-                Statement::DropFunction(drop_function) => {
-                    for func_desc in &drop_function.func_desc {
-                        let Some((function_name, function_quoted)) =
-                            object_name_last_part(&func_desc.name)
-                        else {
-                            return Err(crate::errors::Error::DropFunctionNotFound {
-                                function_name: last_str(&func_desc.name).to_string(),
-                            });
-                        };
-
-                        // Check if function exists
-                        let function_exists = builder.function_arc_vec().iter().any(|f| {
-                            identifiers_match(
-                                f.name(),
-                                f.name_is_quoted(),
-                                function_name,
-                                function_quoted,
-                            )
-                        });
+                continue;
+            };
+            if let Some((statement_kind, capability)) = required_capability(&statement)
+                && !dialect.supports(capability)
+            {
+                builder.add_dialect_warning(DialectWarning::new(
+                    statement_kind,
+                    capability,
+                    dialect.name(),
+                ));
+            }
 
-                        if !function_exists {
-                            if drop_function.if_exists {
-                                continue;
-                            }
-                            return Err(crate::errors::Error::DropFunctionNotFound {
-                                function_name: function_name.to_string(),
-                            });
+            validator.enter_statement(|| statement.span());
+            let handler = validator.statement_handlers().handler(&statement);
+            let strict = validator.is_strict();
+            let mut concerns_schema = None;
+            let creates_function = matches!(statement, Statement::CreateFunction(_));
+            let last_function = builder.functions().last().map(|(function, _)| function.clone());
+            Self::apply_validated(builder, validator, |builder| {
+                let applied = match &handler {
+                    Some(handler) => {
+                        Self::apply_handler(builder, handler, &statement, strict).map(|()| true)
+                    }
+                    None => Self::apply_statement(builder, statement),
+                };
+                let applied = applied.map_err(|error| {
+                    let error = source_locations::locate_unsupported_statement(
+                        error,
+                        source_files,
+                        position,
+                    );
+                    if let Some((observer, statement)) = &mut observed {
+                        if matches!(error, crate::errors::Error::UnsupportedStatement { .. }) {
+                            observer.on_unsupported(statement, &error);
+                        } else {
+                            observer.on_failed(statement, &error);
                         }
+                    }
+                    error
+                })?;
+                concerns_schema = Some(applied);
+                Ok(())
+            })?;
+            if creates_function && concerns_schema.is_some() {
+                Self::report_unparsable_function_body(builder, validator, last_function.as_ref())?;
+            }
+            if let (Some((observer, statement)), Some(concerns_schema)) =
+                (&mut observed, concerns_schema)
+            {
+                if concerns_schema {
+                    observer.on_processed(statement);
+                } else {
+                    observer.on_ignored(statement);
+                }
+            }
+            source_locations::record_source_locations(
+                builder,
+                source_files,
+                position,
+                &builtin_functions,
+            );
+        }
+        validator.leave_statement();
+        for unparsed in pending_unparsed {
+            Self::apply_unparsed_validated(builder, validator, &unparsed.statement)?;
+        }
+        Ok(())
+    }
 
-                        // Check for references in check constraints, policies, or triggers
-                        if builder.is_function_used(function_name, function_quoted) {
-                            return Err(crate::errors::Error::FunctionReferenced {
-                                function_name: function_name.to_string(),
-                            });
+    /// Helper function to report the function created by the last applied
+    /// statement as [`crate::errors::Error::UnparsableFunctionBody`] if its
+    /// body cannot be parsed, the provided function being the last one the
+    /// builder held beforehand.
+    fn report_unparsable_function_body(
+        builder: &mut ParserDBBuilder,
+        validator: &mut Validator,
+        previous_last_function: Option<&Arc<CreateFunction>>,
+    ) -> Result<(), crate::errors::Error> {
+        // Created and replacing functions are both added last, while
+        // `CREATE FUNCTION IF NOT EXISTS` may leave the functions untouched.
+        let Some((function, metadata)) = builder.functions().last() else {
+            return Ok(());
+        };
+        if previous_last_function.is_some_and(|previous| Arc::ptr_eq(previous, function))
+            || metadata.referenced_relations().is_some()
+        {
+            return Ok(());
+        }
+        let error = crate::errors::Error::UnparsableFunctionBody {
+            function_name: function.name().to_string(),
+        };
+        if let Some(warning) = validator.handle(error)? {
+            builder.add_validation_warning(warning);
+        }
+        Ok(())
+    }
+
+    /// Helper function to apply a statement stripped from the SQL as
+    /// [`Self::apply_validated`] does, and then to report the clauses it
+    /// drops as [`crate::errors::Error::UnsupportedClause`].
+    ///
+    /// The dropped clauses are only reported once the statement is applied,
+    /// as a skipped statement is already reported by the error skipping it.
+    fn apply_unparsed_validated(
+        builder: &mut ParserDBBuilder,
+        validator: &mut Validator,
+        statement: &unparsed_statements::UnparsedStatement,
+    ) -> Result<(), crate::errors::Error> {
+        let mut applied = false;
+        Self::apply_validated(builder, validator, |builder| {
+            Self::apply_unparsed_statement(builder, statement)?;
+            applied = true;
+            Ok(())
+        })?;
+        if !applied {
+            return Ok(());
+        }
+        for error in statement.dropped_clause_errors() {
+            if let Some(warning) = validator.handle(error)? {
+                builder.add_validation_warning(warning);
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the database from the builder, indexing the references between
+    /// its objects and validating them as a whole.
+    fn build(
+        builder: ParserDBBuilder,
+        validator: &mut Validator,
+    ) -> Result<Self, crate::errors::Error> {
+        let dialect = *builder.dialect();
+        let mut db: Self = builder.into();
+        db.index_foreign_key_references(|db, foreign_key| {
+            db.resolve_table_object_name(&foreign_key.attribute().foreign_table).ok().flatten()
+        });
+        db.index_table_triggers(|db, trigger| {
+            db.resolve_table_object_name(&trigger.table_name).ok().flatten()
+        });
+        db.index_table_policies(|db, policy| {
+            db.resolve_table_object_name(&policy.table_name).ok().flatten()
+        });
+        // SQLite columns only have a type affinity, so any default is stored
+        // and any argument is accepted.
+        let check_types = !dialect.is_sqlite()
+            && validator.level(ValidationClass::Types) != ValidationLevel::Ignore;
+        let mut warnings = Vec::new();
+        let mut report = |error: crate::errors::Error| -> Result<(), crate::errors::Error> {
+            warnings.extend(validator.handle(error)?);
+            Ok(())
+        };
+        if dialect.shares_relation_namespace() {
+            for (first, second) in db.name_registry().collisions() {
+                report(crate::errors::Error::NameCollision {
+                    schema: first.schema().to_string(),
+                    name: first.name().to_string(),
+                    first_kind: first.kind(),
+                    second_kind: second.kind(),
+                })?;
+            }
+        }
+        if check_types {
+            default_types::check_default_types(&db, &mut report)?;
+            function_calls::check_function_calls(&db, &mut report)?;
+        }
+        db.validation_warnings.extend(warnings);
+        Ok(db)
+    }
+
+    /// Helper function to apply a statement to the builder, skipping it when
+    /// it fails a validation the validator does not abort on.
+    ///
+    /// The changes of a skipped statement are rolled back from a savepoint
+    /// of the builder, which only copies the lists the statement changes in
+    /// place.
+    fn apply_validated(
+        builder: &mut ParserDBBuilder,
+        validator: &mut Validator,
+        apply: impl FnOnce(&mut ParserDBBuilder) -> Result<(), crate::errors::Error>,
+    ) -> Result<(), crate::errors::Error> {
+        if validator.is_strict() {
+            return apply(builder);
+        }
+        builder.savepoint();
+        match apply(builder) {
+            Ok(()) => builder.release(),
+            Err(error) => {
+                builder.rollback();
+                if let Some(warning) = validator.handle(error)? {
+                    builder.add_validation_warning(warning);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function to apply a statement through the handler registered
+    /// for its kind.
+    ///
+    /// Handlers take the builder by value, so unless `strict`, in which case
+    /// any error aborts the parsing, a copy of the builder is kept to resume
+    /// from should the handler fail.
+    fn apply_handler(
+        builder: &mut ParserDBBuilder,
+        handler: &StatementHandler,
+        statement: &Statement,
+        strict: bool,
+    ) -> Result<(), crate::errors::Error> {
+        let fallback = if strict {
+            super::GenericDBBuilder::new(String::new(), *builder.dialect())
+        } else {
+            builder.clone()
+        };
+        let owned = core::mem::replace(builder, fallback);
+        *builder = handler(owned, statement)?;
+        Ok(())
+    }
+
+    /// Helper function to apply a parsed statement to the builder, returning
+    /// whether the statement concerns the schema.
+    #[allow(clippy::too_many_lines)]
+    fn apply_statement(
+        builder: &mut ParserDBBuilder,
+        mut statement: Statement,
+    ) -> Result<bool, crate::errors::Error> {
+        qualify_created_object(builder, &mut statement);
+        let casing = builder.identifier_casing();
+        match statement {
+            Statement::CreateFunction(create_function) => {
+                match same_signature_position(builder, &create_function) {
+                    Some(_) if create_function.if_not_exists => {}
+                    Some(position) if create_function.or_replace || create_function.or_alter => {
+                        // The replacing function is added last, so that the
+                        // location of its statement is recorded, keeping the
+                        // comment of the replaced one.
+                        let (replaced, replaced_metadata) = builder.functions()[position].clone();
+                        builder.retain_functions(|(function, _)| !Arc::ptr_eq(function, &replaced));
+                        let mut metadata = created_function_metadata(builder, &create_function);
+                        metadata.set_comment(replaced_metadata.comment().map(str::to_owned));
+                        builder.push_function(Arc::new(create_function), metadata);
+                    }
+                    Some(_) => {
+                        return Err(crate::errors::Error::FunctionAlreadyExists {
+                            function_name: create_function.name().to_string(),
+                        });
+                    }
+                    None => {
+                        let metadata = created_function_metadata(builder, &create_function);
+                        builder.push_function(Arc::new(create_function), metadata);
+                    }
+                }
+            }
+            Statement::DropFunction(drop_function) => {
+                for func_desc in &drop_function.func_desc {
+                    // Check if function exists
+                    let Some(function_name) = resolve_function_name(builder, &func_desc.name)
+                    else {
+                        if drop_function.if_exists {
+                            continue;
                         }
+                        return Err(crate::errors::Error::DropFunctionNotFound {
+                            function_name: last_str(&func_desc.name).to_string(),
+                        });
+                    };
 
-                        // Remove the function
-                        let functions = builder.functions_mut();
-                        functions.retain(|(f, ())| {
-                            !identifiers_match(
-                                f.name(),
-                                f.name_is_quoted(),
-                                function_name,
-                                function_quoted,
-                            )
+                    // Check for references in check constraints, policies, or triggers
+                    if builder
+                        .is_function_used(function_name.name(), function_name.name_is_quoted())
+                    {
+                        return Err(crate::errors::Error::FunctionReferenced {
+                            function_name: function_name.name().to_string(),
                         });
                     }
+
+                    // Remove the function
+                    builder.retain_functions(|(f, _)| {
+                        !function_matches_name(f, &function_name, casing)
+                    });
+                }
+            }
+            Statement::CreateExtension(create_extension) => {
+                let name = &create_extension.name;
+                let quoted = name.quote_style.is_some();
+                if builder.extensions().iter().any(|extension| {
+                    casing.matches(extension.name(), extension.is_quoted(), &name.value, quoted)
+                }) {
+                    if create_extension.if_not_exists {
+                        return Ok(true);
+                    }
+                    return Err(crate::errors::Error::ExtensionAlreadyExists {
+                        extension_name: name.value.clone(),
+                    });
                 }
-                Statement::Drop {
-                    object_type: sqlparser::ast::ObjectType::Table,
-                    if_exists,
-                    names,
-                    cascade,
-                    ..
-                } => {
-                    for name in names {
-                        let table_name = last_str(&name);
 
-                        // Check if table exists and resolve the canonical stored table.
-                        let maybe_table = builder.resolve_table_object_name(&name)?;
+                let mut extension = Extension::new(name.value.clone(), quoted);
+                if let Some(schema) = &create_extension.schema {
+                    extension =
+                        extension.with_schema(schema.value.clone(), schema.quote_style.is_some());
+                }
+                if let Some(version) = &create_extension.version {
+                    extension = extension.with_version(version.value.clone());
+                }
 
-                        let Some(table) = maybe_table else {
-                            if if_exists {
-                                continue;
-                            }
-                            return Err(crate::errors::Error::DropTableNotFound {
-                                table_name: table_name.to_string(),
-                            });
-                        };
-                        let resolved_table_name = table.table_name().to_string();
-                        let resolved_table_quoted = table.table_name_is_quoted();
-                        let resolved_schema_name = table.table_schema().map(str::to_string);
-                        let resolved_schema_quoted = table.table_schema_is_quoted();
-
-                        // Check for references from other tables (unless CASCADE)
-                        if !cascade
-                            && builder.is_table_referenced(
-                                &resolved_table_name,
-                                resolved_table_quoted,
-                                resolved_schema_name.as_deref(),
-                                resolved_schema_quoted,
-                            )
-                        {
-                            return Err(crate::errors::Error::TableReferenced {
-                                table_name: resolved_table_name.clone(),
-                            });
+                let library = extension_functions::extension_library(&name.value, quoted, casing);
+                for (function_name, args, return_type) in
+                    extension_functions::extension_functions(&name.value, quoted, casing)
+                {
+                    let create_function =
+                        catalog_function(function_name, args, return_type, "c", library.clone());
+                    builder.push_function(Arc::new(create_function), FunctionMetadata::default());
+                }
+                builder.push_extension(extension);
+            }
+            Statement::DropExtension(drop_extension) => {
+                for name in &drop_extension.names {
+                    let quoted = name.quote_style.is_some();
+                    let Some(position) = builder.extensions().iter().position(|extension| {
+                        casing.matches(extension.name(), extension.is_quoted(), &name.value, quoted)
+                    }) else {
+                        if drop_extension.if_exists {
+                            continue;
                         }
+                        return Err(crate::errors::Error::DropExtensionNotFound {
+                            extension_name: name.value.clone(),
+                        });
+                    };
 
-                        // Remove the table and all associated objects
-                        builder.remove_table(
+                    // The functions provided by the extension go away with it
+                    let library =
+                        extension_functions::extension_library(&name.value, quoted, casing);
+                    if let Some(function) =
+                        builder.function_arc_vec().into_iter().find(|function| {
+                            is_provided_by_extension(function, &library)
+                                && builder
+                                    .is_function_used(function.name(), function.name_is_quoted())
+                        })
+                    {
+                        return Err(crate::errors::Error::ExtensionReferenced {
+                            extension_name: name.value.clone(),
+                            function_name: function.name().to_string(),
+                        });
+                    }
+                    builder.retain_functions(|(function, _)| {
+                        !is_provided_by_extension(function, &library)
+                    });
+                    builder.extensions_mut().remove(position);
+                }
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Table,
+                if_exists,
+                names,
+                cascade,
+                ..
+            } => {
+                for name in names {
+                    let table_name = last_str(&name);
+
+                    // Check if table exists and resolve the canonical stored table.
+                    let maybe_table = builder.resolve_table_object_name(&name)?;
+
+                    let Some(table) = maybe_table else {
+                        if if_exists {
+                            continue;
+                        }
+                        return Err(crate::errors::Error::DropTableNotFound {
+                            table_name: table_name.to_string(),
+                        });
+                    };
+                    let resolved_table_name = table.table_name().to_string();
+                    let resolved_table_quoted = table.table_name_is_quoted();
+                    let resolved_schema_name = table.table_schema().map(str::to_string);
+                    let resolved_schema_quoted = table.table_schema_is_quoted();
+
+                    // Check for views selecting from the table (unless CASCADE)
+                    if !cascade
+                        && let Some(view_name) = builder.dependent_view_name(
                             &resolved_table_name,
                             resolved_table_quoted,
                             resolved_schema_name.as_deref(),
                             resolved_schema_quoted,
-                        );
+                        )
+                    {
+                        return Err(crate::errors::Error::TableReferencedByView {
+                            table_name: resolved_table_name.clone(),
+                            view_name,
+                        });
                     }
-                }
-                Statement::Drop {
-                    object_type: sqlparser::ast::ObjectType::Index,
-                    if_exists,
-                    names,
-                    ..
-                } => {
-                    for name in names {
-                        let index_name = last_str(&name);
 
-                        // Find the index
-                        let index_exists = builder.indices_mut().iter().any(|(idx, _)| {
-                            idx.attribute().name.as_ref().is_some_and(|n| last_str(n) == index_name)
+                    // Check for functions reading or writing the table (unless CASCADE)
+                    if !cascade
+                        && let Some(function_name) = builder.dependent_function_name(
+                            &resolved_table_name,
+                            resolved_table_quoted,
+                            resolved_schema_name.as_deref(),
+                            resolved_schema_quoted,
+                        )
+                    {
+                        return Err(crate::errors::Error::TableReferencedByFunction {
+                            table_name: resolved_table_name.clone(),
+                            function_name,
                         });
+                    }
 
-                        if !index_exists {
-                            if if_exists {
-                                continue;
-                            }
-                            return Err(crate::errors::Error::DropIndexNotFound {
-                                index_name: index_name.to_string(),
-                            });
-                        }
-
-                        // Remove from builder's indices list
-                        builder.indices_mut().retain(|(idx, _)| {
-                            idx.attribute().name.as_ref().is_none_or(|n| last_str(n) != index_name)
+                    // Check for references from other tables (unless CASCADE)
+                    if !cascade
+                        && builder.is_table_referenced(
+                            &resolved_table_name,
+                            resolved_table_quoted,
+                            resolved_schema_name.as_deref(),
+                            resolved_schema_quoted,
+                        )
+                    {
+                        return Err(crate::errors::Error::TableReferenced {
+                            table_name: resolved_table_name.clone(),
                         });
+                    }
 
-                        // Remove from table metadata
-                        for (_, table_meta) in builder.tables_mut() {
-                            table_meta.retain_indices(|idx| {
-                                idx.attribute()
-                                    .name
-                                    .as_ref()
-                                    .is_none_or(|n| last_str(n) != index_name)
-                            });
+                    // Remove the table and all associated objects
+                    builder.remove_table(
+                        &resolved_table_name,
+                        resolved_table_quoted,
+                        resolved_schema_name.as_deref(),
+                        resolved_schema_quoted,
+                    );
+                }
+            }
+            Statement::Drop {
+                object_type:
+                    sqlparser::ast::ObjectType::View | sqlparser::ast::ObjectType::MaterializedView,
+                if_exists,
+                names,
+                ..
+            } => {
+                for name in names {
+                    let Some(position) = builder
+                        .views()
+                        .iter()
+                        .position(|(view, ())| view_matches_object_name(view, &name, casing))
+                    else {
+                        if if_exists {
+                            continue;
                         }
-                    }
+                        return Err(crate::errors::Error::DropViewNotFound {
+                            view_name: last_str(&name).to_string(),
+                        });
+                    };
+                    builder.views_mut().remove(position);
                 }
-                Statement::CreateTrigger(create_trigger) => {
-                    let table_name = last_str(&create_trigger.table_name);
-                    let table_exists =
-                        builder.resolve_table_object_name(&create_trigger.table_name)?.is_some();
-
-                    if !table_exists {
-                        return Err(crate::errors::Error::TableNotFoundForTrigger {
-                            table_name: table_name.to_string(),
-                            trigger_name: last_str(&create_trigger.name).to_string(),
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Sequence,
+                if_exists,
+                names,
+                cascade,
+                ..
+            } => {
+                for name in names {
+                    Self::drop_sequence_checked(builder, &name, if_exists, cascade)?;
+                }
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Type,
+                if_exists,
+                names,
+                ..
+            } => {
+                for name in names {
+                    Self::drop_custom_type_checked(builder, &name, if_exists)?;
+                }
+            }
+            Statement::DropDomain(drop_domain) => {
+                Self::drop_custom_type_checked(builder, &drop_domain.name, drop_domain.if_exists)?;
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Index,
+                if_exists,
+                names,
+                ..
+            } => {
+                for name in names {
+                    // Find the index
+                    let Some(position) = index_position(builder, &name) else {
+                        if if_exists {
+                            continue;
+                        }
+                        return Err(crate::errors::Error::DropIndexNotFound {
+                            index_name: last_str(&name).to_string(),
                         });
-                    }
+                    };
 
-                    if let Some(exec_body) = &create_trigger.exec_body {
-                        let Some((function_name, function_quoted)) =
-                            object_name_last_part(&exec_body.func_desc.name)
-                        else {
-                            return Err(crate::errors::Error::FunctionNotFoundForTrigger {
-                                function_name: last_str(&exec_body.func_desc.name).to_string(),
-                                trigger_name: last_str(&create_trigger.name).to_string(),
-                            });
-                        };
-                        let function_exists = builder.function_arc_vec().iter().any(|f| {
-                            identifiers_match(
-                                f.name(),
-                                f.name_is_quoted(),
-                                function_name,
-                                function_quoted,
-                            )
-                        });
+                    // Remove from builder's indices list
+                    let (dropped, _) = builder.indices_mut().remove(position);
 
-                        if !function_exists {
-                            return Err(crate::errors::Error::FunctionNotFoundForTrigger {
-                                function_name: function_name.to_string(),
-                                trigger_name: last_str(&create_trigger.name).to_string(),
-                            });
-                        }
+                    // Remove from table metadata
+                    for (_, table_meta) in builder.tables_mut() {
+                        table_meta.retain_indices(|idx| !Arc::ptr_eq(idx, &dropped));
                     }
-
-                    builder = builder.add_trigger(Arc::new(create_trigger), ());
                 }
-                Statement::DropTrigger(drop_trigger) => {
-                    let trigger_name = last_str(&drop_trigger.trigger_name);
+            }
+            Statement::Comment { object_type, object_name, comment, if_exists } => {
+                Self::comment_on_checked(builder, object_type, &object_name, comment, if_exists)?;
+            }
+            Statement::AlterIndex {
+                name,
+                operation: AlterIndexOperation::RenameIndex { index_name },
+            } => {
+                Self::alter_index_checked(builder, &name, |create_index| {
+                    // The index stays in the schema of the old name.
+                    let mut renamed = name.clone();
+                    if let (Some(last), Some(new_last)) =
+                        (renamed.0.last_mut(), index_name.0.last())
+                    {
+                        *last = new_last.clone();
+                    }
+                    create_index.name = Some(renamed);
+                })?;
+            }
+            Statement::CreateTrigger(mut create_trigger) => {
+                let Some(table) = builder.resolve_table_object_name(&create_trigger.table_name)?
+                else {
+                    return Err(crate::errors::Error::TableNotFoundForTrigger {
+                        table_name: last_str(&create_trigger.table_name).to_string(),
+                        trigger_name: last_str(&create_trigger.name).to_string(),
+                    });
+                };
+                let table_name = table.name.clone();
+                qualify_object_name(&mut create_trigger.table_name, &table_name);
+
+                if let Some(exec_body) = &mut create_trigger.exec_body {
+                    let Some(function_name) =
+                        resolve_function_name(builder, &exec_body.func_desc.name)
+                    else {
+                        return Err(crate::errors::Error::FunctionNotFoundForTrigger {
+                            function_name: last_str(&exec_body.func_desc.name).to_string(),
+                            trigger_name: last_str(&create_trigger.name).to_string(),
+                        });
+                    };
+                    qualify_object_name(
+                        &mut exec_body.func_desc.name,
+                        &function_name.to_object_name(),
+                    );
+                }
 
-                    // Find the trigger
-                    let trigger_exists =
-                        builder.triggers().iter().any(|(t, ())| last_str(&t.name) == trigger_name);
+                builder.push_trigger(Arc::new(create_trigger), ());
+            }
+            Statement::DropTrigger(drop_trigger) => {
+                let (trigger_name, trigger_quoted) =
+                    object_name_last_part(&drop_trigger.trigger_name).unwrap_or_default();
+
+                // Trigger names are only unique per table: when the `ON`
+                // clause is present, restrict the drop to that table.
+                let target_table = drop_trigger
+                    .table_name
+                    .as_ref()
+                    .map(|table_name| builder.resolve_table_object_name(table_name))
+                    .transpose()?;
+                // A schema-qualified trigger name restricts the drop to the
+                // tables of that schema.
+                let trigger_schema = schema_from_object_name(&drop_trigger.trigger_name);
+
+                // Find the matching triggers
+                let matches: Vec<usize> = builder
+                    .trigger_positions(trigger_name, trigger_quoted)
+                    .iter()
+                    .copied()
+                    .filter(|&position| {
+                        let trigger_table = builder
+                            .resolve_table_object_name(&builder.triggers()[position].0.table_name)
+                            .ok()
+                            .flatten();
+                        target_table.is_none_or(|target| {
+                            target.is_some_and(|target| {
+                                trigger_table.is_some_and(|table| core::ptr::eq(table, target))
+                            })
+                        }) && trigger_schema.is_none_or(|(schema, schema_quoted)| {
+                            trigger_table.is_some_and(|table| {
+                                table.table_schema().is_some_and(|table_schema| {
+                                    casing.matches(
+                                        table_schema,
+                                        table.table_schema_is_quoted(),
+                                        schema,
+                                        schema_quoted,
+                                    )
+                                })
+                            })
+                        })
+                    })
+                    .collect();
 
-                    if !trigger_exists {
+                match matches.len() {
+                    0 => {
                         if drop_trigger.if_exists {
-                            continue;
+                            return Ok(true);
                         }
                         return Err(crate::errors::Error::DropTriggerNotFound {
                             trigger_name: trigger_name.to_string(),
                         });
                     }
-
-                    // Remove the trigger
-                    builder.triggers_mut().retain(|(t, ())| last_str(&t.name) != trigger_name);
+                    1 => {}
+                    _ => {
+                        return Err(crate::errors::Error::DropTriggerAmbiguous {
+                            trigger_name: trigger_name.to_string(),
+                        });
+                    }
                 }
-                Statement::DropPolicy(drop_policy) => {
-                    let policy_name = drop_policy.name.value.as_str();
 
-                    // Find the policy
-                    let policy_exists =
-                        builder.policies().iter().any(|(p, _)| p.name.value == policy_name);
+                // Remove the trigger
+                builder.remove_trigger_at(matches[0]);
+            }
+            Statement::DropPolicy(drop_policy) => {
+                let Some(position) =
+                    builder.policy_position(&drop_policy.name, &drop_policy.table_name)?
+                else {
+                    if drop_policy.if_exists {
+                        return Ok(true);
+                    }
+                    return Err(crate::errors::Error::DropPolicyNotFound {
+                        policy_name: drop_policy.name.value,
+                    });
+                };
+
+                // Remove the policy
+                builder.policies_mut().remove(position);
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Role,
+                if_exists,
+                names,
+                ..
+            } => {
+                // Note: DROP ROLE doesn't support CASCADE/RESTRICT in PostgreSQL syntax.
+                // We always use RESTRICT semantics (fail if role is referenced).
+                for name in names {
+                    let Some(role_ident) = object_name_last_identifier(&name) else {
+                        continue;
+                    };
+                    let role_name = role_ident.value.as_str();
+                    let role_quoted = role_ident.quote_style.is_some();
+
+                    // Check if role exists
+                    let role_exists = builder.role_exists(role_ident);
 
-                    if !policy_exists {
-                        if drop_policy.if_exists {
+                    if !role_exists {
+                        if if_exists {
                             continue;
                         }
-                        return Err(crate::errors::Error::DropPolicyNotFound {
-                            policy_name: policy_name.to_string(),
+                        return Err(crate::errors::Error::DropRoleNotFound {
+                            role_name: role_name.to_string(),
                         });
                     }
 
-                    // Remove the policy
-                    builder.policies_mut().retain(|(p, _)| p.name.value != policy_name);
-                }
-                Statement::Drop {
-                    object_type: sqlparser::ast::ObjectType::Role,
-                    if_exists,
-                    names,
-                    ..
-                } => {
-                    // Note: DROP ROLE doesn't support CASCADE/RESTRICT in PostgreSQL syntax.
-                    // We always use RESTRICT semantics (fail if role is referenced).
-                    for name in names {
-                        let Some(role_ident) = object_name_last_identifier(&name) else {
-                            continue;
-                        };
-                        let role_name = role_ident.value.as_str();
-                        let role_quoted = role_ident.quote_style.is_some();
-
-                        // Check if role exists
-                        let role_exists = builder
-                            .roles()
-                            .iter()
-                            .any(|(role, ())| role_matches_lookup_ident(role, role_ident));
+                    // Check for references from grants
+                    if builder.is_role_referenced(role_name, role_quoted) {
+                        return Err(crate::errors::Error::RoleReferenced {
+                            role_name: role_name.to_string(),
+                        });
+                    }
 
-                        if !role_exists {
-                            if if_exists {
-                                continue;
-                            }
-                            return Err(crate::errors::Error::DropRoleNotFound {
-                                role_name: role_name.to_string(),
-                            });
-                        }
+                    // Remove the role
+                    builder
+                        .retain_roles(|(r, ())| !role_matches_lookup_ident(r, role_ident, casing));
+                }
+            }
+            Statement::Drop {
+                object_type: sqlparser::ast::ObjectType::Schema,
+                if_exists,
+                names,
+                cascade,
+                ..
+            } => {
+                for name in names {
+                    let schema_name = last_str(&name);
+                    let maybe_schema = object_name_last_identifier(&name)
+                        .and_then(|ident| builder.resolve_schema_ident(ident));
 
-                        // Check for references from grants
-                        if builder.is_role_referenced(role_name, role_quoted) {
-                            return Err(crate::errors::Error::RoleReferenced {
-                                role_name: role_name.to_string(),
-                            });
+                    let Some(schema) = maybe_schema else {
+                        if if_exists {
+                            continue;
                         }
+                        return Err(crate::errors::Error::DropSchemaNotFound {
+                            schema_name: schema_name.to_string(),
+                        });
+                    };
+                    let resolved_schema_name = schema.name().to_string();
+                    let resolved_schema_quoted = schema.is_quoted();
 
-                        // Remove the role
-                        builder
-                            .roles_mut()
-                            .retain(|(r, ())| !role_matches_lookup_ident(r, role_ident));
+                    // Check for contained objects unless CASCADE is specified
+                    if !cascade
+                        && builder
+                            .is_schema_non_empty(&resolved_schema_name, resolved_schema_quoted)
+                    {
+                        return Err(crate::errors::Error::SchemaNotEmpty {
+                            schema_name: resolved_schema_name.clone(),
+                        });
                     }
-                }
-                Statement::Drop {
-                    object_type: sqlparser::ast::ObjectType::Schema,
-                    if_exists,
-                    names,
-                    cascade,
-                    ..
-                } => {
-                    for name in names {
-                        let schema_name = last_str(&name);
-                        let maybe_schema = object_name_last_identifier(&name)
-                            .and_then(|ident| builder.resolve_schema_ident(ident));
-
-                        let Some(schema) = maybe_schema else {
-                            if if_exists {
-                                continue;
-                            }
-                            return Err(crate::errors::Error::DropSchemaNotFound {
-                                schema_name: schema_name.to_string(),
-                            });
-                        };
-                        let resolved_schema_name = schema.name().to_string();
-                        let resolved_schema_quoted = schema.is_quoted();
-
-                        // Check for contained objects unless CASCADE is specified
-                        if !cascade
-                            && builder
-                                .is_schema_non_empty(&resolved_schema_name, resolved_schema_quoted)
-                        {
-                            return Err(crate::errors::Error::SchemaNotEmpty {
-                                schema_name: resolved_schema_name.clone(),
-                            });
-                        }
 
-                        // If CASCADE, remove all tables in the schema first
-                        if cascade {
-                            use crate::traits::TableLike;
-                            let tables_to_remove: Vec<_> = builder
-                                .tables()
-                                .iter()
-                                .filter(|(t, _)| {
-                                    t.table_schema().is_some_and(|table_schema| {
-                                        identifiers_match(
-                                            table_schema,
-                                            t.table_schema_is_quoted(),
-                                            &resolved_schema_name,
-                                            resolved_schema_quoted,
-                                        )
-                                    })
-                                })
-                                .map(|(t, _)| {
-                                    (
-                                        t.table_name().to_string(),
-                                        t.table_name_is_quoted(),
-                                        t.table_schema().map(str::to_string),
+                    // If CASCADE, remove all tables in the schema first
+                    if cascade {
+                        use crate::traits::TableLike;
+                        let tables_to_remove: Vec<_> = builder
+                            .tables()
+                            .iter()
+                            .filter(|(t, _)| {
+                                t.table_schema().is_some_and(|table_schema| {
+                                    casing.matches(
+                                        table_schema,
                                         t.table_schema_is_quoted(),
+                                        &resolved_schema_name,
+                                        resolved_schema_quoted,
                                     )
                                 })
-                                .collect();
-
-                            for (
-                                table_name,
+                            })
+                            .map(|(t, _)| {
+                                (
+                                    t.table_name().to_string(),
+                                    t.table_name_is_quoted(),
+                                    t.table_schema().map(str::to_string),
+                                    t.table_schema_is_quoted(),
+                                )
+                            })
+                            .collect();
+
+                        for (
+                            table_name,
+                            table_name_quoted,
+                            table_schema_name,
+                            table_schema_quoted,
+                        ) in tables_to_remove
+                        {
+                            builder.remove_table(
+                                &table_name,
                                 table_name_quoted,
-                                table_schema_name,
+                                table_schema_name.as_deref(),
                                 table_schema_quoted,
-                            ) in tables_to_remove
-                            {
-                                builder.remove_table(
-                                    &table_name,
-                                    table_name_quoted,
-                                    table_schema_name.as_deref(),
-                                    table_schema_quoted,
-                                );
-                            }
+                            );
                         }
+                    }
 
-                        // Remove the schema
-                        builder.schemas_mut().retain(|(s, ())| {
-                            !identifiers_match(
-                                s.name(),
-                                s.is_quoted(),
-                                &resolved_schema_name,
-                                resolved_schema_quoted,
-                            )
+                    // ...and then its remaining functions, views, sequences and types
+                    if cascade {
+                        let in_schema = |object_schema: Option<&str>, quoted: bool| {
+                            object_schema.is_some_and(|object_schema| {
+                                casing.matches(
+                                    object_schema,
+                                    quoted,
+                                    &resolved_schema_name,
+                                    resolved_schema_quoted,
+                                )
+                            })
+                        };
+                        builder.retain_functions(|(f, _)| {
+                            !in_schema(f.schema(), f.schema_is_quoted())
                         });
+                        builder.views_mut().retain(|(v, ())| {
+                            !in_schema(v.view_schema(), v.view_schema_is_quoted())
+                        });
+                        builder
+                            .sequences_mut()
+                            .retain(|(s, ())| !in_schema(s.schema(), s.schema_is_quoted()));
+                        builder
+                            .custom_types_mut()
+                            .retain(|(t, ())| !in_schema(t.schema(), t.schema_is_quoted()));
                     }
-                }
-                Statement::CreateIndex(create_index) => {
-                    let (index, metadata) = Self::process_create_index(create_index, &builder)?;
-                    let resolved_table = index.table();
-                    let resolved_table_name = resolved_table.table_name().to_string();
-                    let resolved_table_quoted = resolved_table.table_name_is_quoted();
-                    let resolved_schema_name = resolved_table.table_schema().map(str::to_string);
-                    let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
-
-                    if let Some(entry) = builder.tables_mut().iter_mut().find(|(table, _)| {
-                        table_matches_resolved_identity(
-                            table.as_ref(),
-                            &resolved_table_name,
-                            resolved_table_quoted,
-                            resolved_schema_name.as_deref(),
+
+                    // Remove the schema
+                    builder.schemas_mut().retain(|(s, ())| {
+                        !casing.matches(
+                            s.name(),
+                            s.is_quoted(),
+                            &resolved_schema_name,
                             resolved_schema_quoted,
                         )
-                    }) {
-                        entry.1.add_index(index.clone());
-                    }
-                    builder = builder.add_index(index, metadata);
+                    });
                 }
-                Statement::AlterTable(alter_table) => {
-                    for operation in alter_table.operations {
-                        match operation {
-                            AlterTableOperation::EnableRowLevelSecurity => {
-                                let Some(resolved_table) =
-                                    builder.resolve_table_object_name(&alter_table.name)?
-                                else {
-                                    continue;
-                                };
-                                let resolved_table_name = resolved_table.table_name().to_string();
-                                let resolved_table_quoted = resolved_table.table_name_is_quoted();
-                                let resolved_schema_name =
-                                    resolved_table.table_schema().map(str::to_string);
-                                let resolved_schema_quoted =
-                                    resolved_table.table_schema_is_quoted();
-
-                                if let Some(entry) =
-                                    builder.tables_mut().iter_mut().find(|(table, _)| {
-                                        table_matches_resolved_identity(
-                                            table.as_ref(),
-                                            &resolved_table_name,
-                                            resolved_table_quoted,
-                                            resolved_schema_name.as_deref(),
-                                            resolved_schema_quoted,
-                                        )
-                                    })
-                                {
-                                    entry.1.set_rls_enabled(true);
-                                }
-                            }
-                            AlterTableOperation::DisableRowLevelSecurity => {
-                                let Some(resolved_table) =
-                                    builder.resolve_table_object_name(&alter_table.name)?
-                                else {
-                                    continue;
-                                };
-                                let resolved_table_name = resolved_table.table_name().to_string();
-                                let resolved_table_quoted = resolved_table.table_name_is_quoted();
-                                let resolved_schema_name =
-                                    resolved_table.table_schema().map(str::to_string);
-                                let resolved_schema_quoted =
-                                    resolved_table.table_schema_is_quoted();
-
-                                if let Some(entry) =
-                                    builder.tables_mut().iter_mut().find(|(table, _)| {
-                                        table_matches_resolved_identity(
-                                            table.as_ref(),
-                                            &resolved_table_name,
-                                            resolved_table_quoted,
-                                            resolved_schema_name.as_deref(),
-                                            resolved_schema_quoted,
-                                        )
-                                    })
-                                {
-                                    entry.1.set_rls_enabled(false);
-                                }
+            }
+            Statement::CreateIndex(create_index) => {
+                let if_not_exists = create_index.if_not_exists;
+                let (index, metadata) = Self::process_create_index(create_index, builder)?;
+                if if_not_exists && index_exists(builder, &index) {
+                    return Ok(true);
+                }
+                let resolved_table = index.table();
+                let resolved_table_name = resolved_table.table_name().to_string();
+                let resolved_table_quoted = resolved_table.table_name_is_quoted();
+                let resolved_schema_name = resolved_table.table_schema().map(str::to_string);
+                let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+                if let Some(entry) = builder.tables_mut().iter_mut().find(|(table, _)| {
+                    table_matches_resolved_identity(
+                        table.as_ref(),
+                        &resolved_table_name,
+                        resolved_table_quoted,
+                        resolved_schema_name.as_deref(),
+                        resolved_schema_quoted,
+                        casing,
+                    )
+                }) {
+                    entry.1.add_index(index.clone());
+                }
+                builder.push_index(index, metadata);
+            }
+            Statement::AlterTable(alter_table) => {
+                for operation in alter_table.operations {
+                    match operation {
+                        AlterTableOperation::EnableRowLevelSecurity => {
+                            let Some(resolved_table) =
+                                builder.resolve_table_object_name(&alter_table.name)?
+                            else {
+                                continue;
+                            };
+                            let resolved_table_name = resolved_table.table_name().to_string();
+                            let resolved_table_quoted = resolved_table.table_name_is_quoted();
+                            let resolved_schema_name =
+                                resolved_table.table_schema().map(str::to_string);
+                            let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+                            if let Some(entry) =
+                                builder.tables_mut().iter_mut().find(|(table, _)| {
+                                    table_matches_resolved_identity(
+                                        table.as_ref(),
+                                        &resolved_table_name,
+                                        resolved_table_quoted,
+                                        resolved_schema_name.as_deref(),
+                                        resolved_schema_quoted,
+                                        casing,
+                                    )
+                                })
+                            {
+                                entry.1.set_rls_enabled(true);
                             }
-                            AlterTableOperation::ForceRowLevelSecurity => {
-                                let Some(resolved_table) =
-                                    builder.resolve_table_object_name(&alter_table.name)?
-                                else {
-                                    continue;
-                                };
-                                let resolved_table_name = resolved_table.table_name().to_string();
-                                let resolved_table_quoted = resolved_table.table_name_is_quoted();
-                                let resolved_schema_name =
-                                    resolved_table.table_schema().map(str::to_string);
-                                let resolved_schema_quoted =
-                                    resolved_table.table_schema_is_quoted();
-
-                                if let Some(entry) =
-                                    builder.tables_mut().iter_mut().find(|(table, _)| {
-                                        table_matches_resolved_identity(
-                                            table.as_ref(),
-                                            &resolved_table_name,
-                                            resolved_table_quoted,
-                                            resolved_schema_name.as_deref(),
-                                            resolved_schema_quoted,
-                                        )
-                                    })
-                                {
-                                    entry.1.set_rls_forced(true);
-                                }
+                        }
+                        AlterTableOperation::DisableRowLevelSecurity => {
+                            let Some(resolved_table) =
+                                builder.resolve_table_object_name(&alter_table.name)?
+                            else {
+                                continue;
+                            };
+                            let resolved_table_name = resolved_table.table_name().to_string();
+                            let resolved_table_quoted = resolved_table.table_name_is_quoted();
+                            let resolved_schema_name =
+                                resolved_table.table_schema().map(str::to_string);
+                            let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+                            if let Some(entry) =
+                                builder.tables_mut().iter_mut().find(|(table, _)| {
+                                    table_matches_resolved_identity(
+                                        table.as_ref(),
+                                        &resolved_table_name,
+                                        resolved_table_quoted,
+                                        resolved_schema_name.as_deref(),
+                                        resolved_schema_quoted,
+                                        casing,
+                                    )
+                                })
+                            {
+                                entry.1.set_rls_enabled(false);
                             }
-                            AlterTableOperation::NoForceRowLevelSecurity => {
-                                let Some(resolved_table) =
-                                    builder.resolve_table_object_name(&alter_table.name)?
-                                else {
-                                    continue;
-                                };
-                                let resolved_table_name = resolved_table.table_name().to_string();
-                                let resolved_table_quoted = resolved_table.table_name_is_quoted();
-                                let resolved_schema_name =
-                                    resolved_table.table_schema().map(str::to_string);
-                                let resolved_schema_quoted =
-                                    resolved_table.table_schema_is_quoted();
-
-                                if let Some(entry) =
-                                    builder.tables_mut().iter_mut().find(|(table, _)| {
-                                        table_matches_resolved_identity(
-                                            table.as_ref(),
-                                            &resolved_table_name,
-                                            resolved_table_quoted,
-                                            resolved_schema_name.as_deref(),
-                                            resolved_schema_quoted,
-                                        )
-                                    })
-                                {
-                                    entry.1.set_rls_forced(false);
-                                }
+                        }
+                        AlterTableOperation::ForceRowLevelSecurity => {
+                            let Some(resolved_table) =
+                                builder.resolve_table_object_name(&alter_table.name)?
+                            else {
+                                continue;
+                            };
+                            let resolved_table_name = resolved_table.table_name().to_string();
+                            let resolved_table_quoted = resolved_table.table_name_is_quoted();
+                            let resolved_schema_name =
+                                resolved_table.table_schema().map(str::to_string);
+                            let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+                            if let Some(entry) =
+                                builder.tables_mut().iter_mut().find(|(table, _)| {
+                                    table_matches_resolved_identity(
+                                        table.as_ref(),
+                                        &resolved_table_name,
+                                        resolved_table_quoted,
+                                        resolved_schema_name.as_deref(),
+                                        resolved_schema_quoted,
+                                        casing,
+                                    )
+                                })
+                            {
+                                entry.1.set_rls_forced(true);
+                            }
+                        }
+                        AlterTableOperation::NoForceRowLevelSecurity => {
+                            let Some(resolved_table) =
+                                builder.resolve_table_object_name(&alter_table.name)?
+                            else {
+                                continue;
+                            };
+                            let resolved_table_name = resolved_table.table_name().to_string();
+                            let resolved_table_quoted = resolved_table.table_name_is_quoted();
+                            let resolved_schema_name =
+                                resolved_table.table_schema().map(str::to_string);
+                            let resolved_schema_quoted = resolved_table.table_schema_is_quoted();
+
+                            if let Some(entry) =
+                                builder.tables_mut().iter_mut().find(|(table, _)| {
+                                    table_matches_resolved_identity(
+                                        table.as_ref(),
+                                        &resolved_table_name,
+                                        resolved_table_quoted,
+                                        resolved_schema_name.as_deref(),
+                                        resolved_schema_quoted,
+                                        casing,
+                                    )
+                                })
+                            {
+                                entry.1.set_rls_forced(false);
                             }
-                            AlterTableOperation::RenameTable { table_name } => {
-                                let new_name = match table_name {
-                                    RenameTableNameKind::As(name)
-                                    | RenameTableNameKind::To(name) => name,
-                                };
-                                builder = Self::rename_table_checked(
+                        }
+                        AlterTableOperation::AddColumn { if_not_exists, column_def, .. } => {
+                            Self::add_column_checked(
+                                builder,
+                                &alter_table.name,
+                                column_def,
+                                alter_table.if_exists,
+                                if_not_exists,
+                            )?;
+                        }
+                        AlterTableOperation::AddConstraint { constraint, .. } => {
+                            Self::add_constraint_checked(
+                                builder,
+                                &alter_table.name,
+                                constraint,
+                                alter_table.if_exists,
+                            )?;
+                        }
+                        AlterTableOperation::DropConstraint { if_exists, name, .. } => {
+                            Self::drop_constraint_checked(
+                                builder,
+                                &alter_table.name,
+                                &name,
+                                alter_table.if_exists,
+                                if_exists,
+                            )?;
+                        }
+                        AlterTableOperation::DropColumn {
+                            column_names,
+                            if_exists,
+                            drop_behavior,
+                            ..
+                        } => {
+                            for column_name in &column_names {
+                                Self::drop_column_checked(
                                     builder,
                                     &alter_table.name,
-                                    new_name,
+                                    column_name,
                                     alter_table.if_exists,
+                                    if_exists,
+                                    matches!(
+                                        drop_behavior,
+                                        Some(sqlparser::ast::DropBehavior::Cascade)
+                                    ),
                                 )?;
                             }
-                            _ => {}
                         }
+                        AlterTableOperation::RenameTable { table_name } => {
+                            let new_name = match table_name {
+                                RenameTableNameKind::As(name) | RenameTableNameKind::To(name) => {
+                                    name
+                                }
+                            };
+                            Self::rename_table_checked(
+                                builder,
+                                &alter_table.name,
+                                new_name,
+                                alter_table.if_exists,
+                            )?;
+                        }
+                        _ => {}
                     }
                 }
-                Statement::CreateTable(create_table) => {
-                    let create_table = Arc::new(create_table);
-                    let mut table_metadata: TableMetadata<CreateTable> = TableMetadata::default();
-
-                    for column in create_table.columns.clone() {
-                        let column_arc =
-                            Arc::new(TableAttribute::new(create_table.clone(), column));
-                        table_metadata.add_column(column_arc.clone());
+            }
+            Statement::CreateTable(create_table) => {
+                if let Some((table_name, table_quoted)) = object_name_last_part(&create_table.name)
+                    && builder.tables_named(table_name, table_quoted).any(|table| {
+                        table_matches_object_name(
+                            table,
+                            &create_table.name,
+                            builder.identifier_casing(),
+                        )
+                    })
+                {
+                    if create_table.if_not_exists {
+                        return Ok(true);
                     }
-
-                    for column in table_metadata.clone().column_arcs() {
-                        builder = Self::process_column_options(
-                            column,
-                            &create_table,
-                            &mut table_metadata,
-                            builder,
-                        )?;
-                        builder = builder.add_column(column.clone(), ());
+                    if !create_table.or_replace {
+                        return Err(crate::errors::Error::TableAlreadyExists {
+                            table_name: table_name.to_string(),
+                        });
                     }
+                    // The replaced table is dropped along with its objects.
+                    let schema = schema_from_object_name(&create_table.name);
+                    builder.remove_table(
+                        table_name,
+                        table_quoted,
+                        schema.map(|(schema_name, _)| schema_name),
+                        schema.is_some_and(|(_, schema_quoted)| schema_quoted),
+                    );
+                }
+                let create_table = Arc::new(create_table);
+                let mut table_metadata: TableMetadata<CreateTable> = TableMetadata::default();
+
+                for column in create_table.columns.clone() {
+                    let column_arc = Arc::new(TableAttribute::new(create_table.clone(), column));
+                    table_metadata.add_column(column_arc.clone());
+                }
 
-                    builder = Self::process_table_constraints(
-                        &create_table.constraints,
+                for column in table_metadata.clone().column_arcs() {
+                    Self::process_column_options(
+                        column,
                         &create_table,
                         &mut table_metadata,
                         builder,
                     )?;
+                    builder.push_column(column.clone(), ());
+                }
 
-                    builder = builder.add_table(create_table, table_metadata)?;
+                Self::process_table_constraints(
+                    &create_table.constraints,
+                    &create_table,
+                    &mut table_metadata,
+                    builder,
+                )?;
+
+                // Default privileges are granted on the table as they stand
+                // when it is created.
+                let schema = create_table
+                    .table_schema()
+                    .map(|schema| (schema, create_table.table_schema_is_quoted()));
+                for (default_privilege, ()) in builder.default_privileges() {
+                    if default_privilege.applies_to_schema(schema, casing) {
+                        table_metadata.add_default_privilege(default_privilege.clone());
+                    }
+                }
+
+                builder.push_table(create_table, table_metadata)?;
+            }
+            Statement::CreateView(create_view) => {
+                // Resolve the relations of the query, so that malformed or
+                // ambiguous table names are reported when the view is created
+                if let ControlFlow::Break(error) =
+                    visit_relations(&create_view.query, |object_name| {
+                        match builder.resolve_table_object_name(object_name) {
+                            Ok(_) => ControlFlow::Continue(()),
+                            Err(error) => ControlFlow::Break(error),
+                        }
+                    })
+                {
+                    return Err(error.into());
                 }
-                Statement::CreatePolicy(policy) => {
-                    let using_functions = if let Some(using_expr) = &policy.using {
-                        functions_in_expression::functions_in_expression::<Self>(
-                            using_expr,
-                            builder.function_arc_vec().as_slice(),
-                        )
-                    } else {
-                        Vec::new()
-                    };
 
-                    let check_functions = if let Some(check_expr) = &policy.with_check {
-                        functions_in_expression::functions_in_expression::<Self>(
-                            check_expr,
-                            builder.function_arc_vec().as_slice(),
+                let existing = builder.views().iter().position(|(view, ())| {
+                    view_matches_object_name(view, &create_view.name, casing)
+                });
+                match existing {
+                    Some(_) if create_view.if_not_exists => {}
+                    Some(position) if create_view.or_replace => {
+                        builder.views_mut()[position] = (Arc::new(create_view), ());
+                    }
+                    Some(_) => {
+                        return Err(crate::errors::Error::ViewAlreadyExists {
+                            view_name: last_str(&create_view.name).to_string(),
+                        });
+                    }
+                    None => {
+                        builder.push_view(Arc::new(create_view), ());
+                    }
+                }
+            }
+            Statement::CreateSequence { temporary, if_not_exists, name, owned_by, .. } => {
+                let exists = builder
+                    .sequences()
+                    .iter()
+                    .any(|(sequence, ())| sequence_matches_object_name(sequence, &name, casing));
+                if exists && !if_not_exists {
+                    return Err(crate::errors::Error::SequenceAlreadyExists {
+                        sequence_name: last_str(&name).to_string(),
+                    });
+                }
+                if !exists {
+                    let sequence = Self::process_create_sequence(&name, owned_by, builder)?
+                        .with_temporary(temporary);
+                    builder.push_sequence(Arc::new(sequence), ());
+                }
+            }
+            Statement::CreateType { name, representation, .. } => {
+                let definition = match representation {
+                    Some(UserDefinedTypeRepresentation::Enum { labels, .. }) => {
+                        CustomTypeDefinition::Enum(
+                            labels.into_iter().map(|label| label.value).collect(),
                         )
-                    } else {
-                        Vec::new()
-                    };
+                    }
+                    _ => CustomTypeDefinition::Other,
+                };
+                Self::add_custom_type_checked(builder, &name, definition)?;
+            }
+            Statement::CreateDomain(create_domain) => {
+                let checks = create_domain
+                    .constraints
+                    .into_iter()
+                    .filter_map(|constraint| match constraint {
+                        TableConstraint::Check(check) => Some(*check.expr),
+                        _ => None,
+                    })
+                    .collect();
+                let definition =
+                    CustomTypeDefinition::Domain { base_type: create_domain.data_type, checks };
+                Self::add_custom_type_checked(builder, &create_domain.name, definition)?;
+            }
+            Statement::CreatePolicy(mut policy) => {
+                if let Ok(Some(table)) = builder.resolve_table_object_name(&policy.table_name) {
+                    let table_name = table.name.clone();
+                    qualify_object_name(&mut policy.table_name, &table_name);
+                }
+                let using_functions = if let Some(using_expr) = &policy.using {
+                    functions_in_expression::functions_in_expression::<Self>(
+                        using_expr,
+                        builder.function_arc_vec().as_slice(),
+                        casing,
+                    )
+                } else {
+                    Vec::new()
+                };
+
+                let check_functions = if let Some(check_expr) = &policy.with_check {
+                    functions_in_expression::functions_in_expression::<Self>(
+                        check_expr,
+                        builder.function_arc_vec().as_slice(),
+                        casing,
+                    )
+                } else {
+                    Vec::new()
+                };
 
-                    let metadata = PolicyMetadata::new(using_functions, check_functions);
-                    builder = builder.add_policy(Arc::new(policy), metadata);
+                let metadata = PolicyMetadata::new(using_functions, check_functions);
+                builder.push_policy(Arc::new(policy), metadata);
+            }
+            Statement::CreateRole(create_role) => {
+                // Each of the roles created by the statement is stored on its
+                // own, so that roles are looked up by their single name.
+                for name in &create_role.names {
+                    if create_role.if_not_exists
+                        && object_name_last_part(name).is_some_and(|(role_name, quoted)| {
+                            builder.role_position(role_name, quoted).is_some()
+                        })
+                    {
+                        continue;
+                    }
+                    let role = CreateRole { names: vec![name.clone()], ..create_role.clone() };
+                    builder.push_role(Arc::new(role), ());
                 }
-                Statement::CreateRole(create_role) => {
-                    builder = builder.add_role(Arc::new(create_role), ());
+            }
+            Statement::AlterRole { name, operation } => {
+                let Some(position) = builder.role_position(&name.value, name.quote_style.is_some())
+                else {
+                    return Err(crate::errors::Error::AlterRoleNotFound { role_name: name.value });
+                };
+                let (role, ()) = &mut builder.roles_mut()[position];
+                // Renames, memberships and session defaults such as
+                // `SET search_path` are not tracked.
+                if let AlterRoleOperation::WithOptions { options } = operation {
+                    let role = Arc::make_mut(role);
+                    for option in options {
+                        apply_role_option(role, option);
+                    }
                 }
-                Statement::CreateSchema { schema_name, if_not_exists, .. } => {
-                    let (name, quoted, authorization) = match &schema_name {
-                        SchemaName::Simple(name) => {
-                            let schema_ident = object_name_last_identifier(name);
-                            (
-                                schema_ident.map_or_else(
-                                    || last_str(name).to_string(),
-                                    |ident| ident.value.clone(),
-                                ),
-                                schema_ident.is_some_and(|ident| ident.quote_style.is_some()),
-                                None,
-                            )
-                        }
-                        SchemaName::UnnamedAuthorization(auth) => {
-                            // CREATE SCHEMA AUTHORIZATION admin creates schema named "admin"
-                            (
-                                auth.value.clone(),
-                                auth.quote_style.is_some(),
-                                Some(auth.value.clone()),
-                            )
-                        }
-                        SchemaName::NamedAuthorization(name, auth) => {
-                            let schema_ident = object_name_last_identifier(name);
-                            (
-                                schema_ident.map_or_else(
-                                    || last_str(name).to_string(),
-                                    |ident| ident.value.clone(),
-                                ),
-                                schema_ident.is_some_and(|ident| ident.quote_style.is_some()),
-                                Some(auth.value.clone()),
-                            )
-                        }
-                    };
+            }
+            Statement::CreateSchema { schema_name, if_not_exists, .. } => {
+                let (name, quoted, authorization) = match &schema_name {
+                    SchemaName::Simple(name) => {
+                        let schema_ident = object_name_last_identifier(name);
+                        (
+                            schema_ident.map_or_else(
+                                || last_str(name).to_string(),
+                                |ident| ident.value.clone(),
+                            ),
+                            schema_ident.is_some_and(|ident| ident.quote_style.is_some()),
+                            None,
+                        )
+                    }
+                    SchemaName::UnnamedAuthorization(auth) => {
+                        // CREATE SCHEMA AUTHORIZATION admin creates schema named "admin"
+                        (auth.value.clone(), auth.quote_style.is_some(), Some(auth))
+                    }
+                    SchemaName::NamedAuthorization(name, auth) => {
+                        let schema_ident = object_name_last_identifier(name);
+                        (
+                            schema_ident.map_or_else(
+                                || last_str(name).to_string(),
+                                |ident| ident.value.clone(),
+                            ),
+                            schema_ident.is_some_and(|ident| ident.quote_style.is_some()),
+                            Some(auth),
+                        )
+                    }
+                };
 
-                    // Check if schema already exists
-                    let schema_exists = builder
-                        .schemas()
-                        .iter()
-                        .any(|(s, ())| identifiers_match(s.name(), s.is_quoted(), &name, quoted));
+                // Check if schema already exists
+                let schema_exists = builder
+                    .schemas()
+                    .iter()
+                    .any(|(s, ())| casing.matches(s.name(), s.is_quoted(), &name, quoted));
 
-                    if schema_exists {
-                        if !if_not_exists {
-                            return Err(crate::errors::Error::SchemaAlreadyExists {
-                                schema_name: name.clone(),
-                            });
-                        }
-                        // IF NOT EXISTS - skip adding duplicate
-                    } else {
-                        let schema = match authorization {
-                            Some(auth) => Schema::with_authorization_and_quoted(name, auth, quoted),
-                            None => Schema::with_quoted(name, quoted),
-                        };
-                        builder = builder.add_schema(Arc::new(schema), ());
+                if schema_exists {
+                    if !if_not_exists {
+                        return Err(crate::errors::Error::SchemaAlreadyExists {
+                            schema_name: name.clone(),
+                        });
                     }
-                }
-                Statement::Grant(grant) => {
-                    // Validate grantees exist (closed world assumption)
-                    for grantee in &grant.grantees {
-                        if grantee.grantee_type == GranteesType::Public {
-                            continue;
+                    // IF NOT EXISTS - skip adding duplicate
+                } else {
+                    let schema = match authorization {
+                        Some(auth) => {
+                            Schema::with_authorization_and_quoted(name, auth.value.clone(), quoted)
+                                .with_quoted_authorization(auth.quote_style.is_some())
                         }
+                        None => Schema::with_quoted(name, quoted),
+                    };
+                    builder.push_schema(Arc::new(schema), ());
+                }
+            }
+            Statement::Grant(mut grant) => {
+                // Validate grantees exist (closed world assumption)
+                for grantee in &grant.grantees {
+                    if grantee.grantee_type == GranteesType::Public {
+                        continue;
+                    }
 
-                        let Some(GranteeName::ObjectName(grantee_name)) = &grantee.name else {
-                            continue;
-                        };
-                        let Some(grantee_ident) = object_name_last_identifier(grantee_name) else {
-                            continue;
-                        };
+                    let Some(GranteeName::ObjectName(grantee_name)) = &grantee.name else {
+                        continue;
+                    };
+                    let Some(grantee_ident) = object_name_last_identifier(grantee_name) else {
+                        continue;
+                    };
 
-                        // Skip PUBLIC pseudo-role spelled as identifier.
-                        if grantee_ident.quote_style.is_none()
-                            && grantee_ident.value.eq_ignore_ascii_case("PUBLIC")
-                        {
-                            continue;
-                        }
+                    // Skip PUBLIC pseudo-role spelled as identifier.
+                    if grantee_ident.quote_style.is_none()
+                        && grantee_ident.value.eq_ignore_ascii_case("PUBLIC")
+                    {
+                        continue;
+                    }
 
-                        let role_exists = builder
-                            .roles()
-                            .iter()
-                            .any(|(role, ())| role_matches_lookup_ident(role, grantee_ident));
-                        if !role_exists {
-                            return Err(crate::errors::Error::RoleNotFoundForGrant {
-                                role_name: grantee_ident.value.clone(),
-                            });
-                        }
+                    let role_exists = builder.role_exists(grantee_ident);
+                    if !role_exists {
+                        return Err(crate::errors::Error::RoleNotFoundForGrant {
+                            role_name: grantee_ident.value.clone(),
+                        });
                     }
+                }
 
-                    // Validate tables exist (for table grants)
-                    if let Some(sqlparser::ast::GrantObjects::Tables(tables)) = &grant.objects {
-                        for table_obj in tables {
-                            let table_name = last_str(table_obj);
-                            let table_exists =
-                                builder.resolve_table_object_name(table_obj)?.is_some();
-                            if !table_exists {
-                                return Err(crate::errors::Error::TableNotFoundForGrant {
-                                    table_name: table_name.to_string(),
-                                });
-                            }
+                // Validate tables exist (for table grants)
+                if let Some(sqlparser::ast::GrantObjects::Tables(tables)) = &grant.objects {
+                    for table_obj in tables {
+                        let table_name = last_str(table_obj);
+                        let table_exists = builder.resolve_table_object_name(table_obj)?.is_some();
+                        if !table_exists {
+                            return Err(crate::errors::Error::TableNotFoundForGrant {
+                                table_name: table_name.to_string(),
+                            });
                         }
                     }
+                }
+                qualify_table_objects(builder, grant.objects.as_mut());
 
-                    builder = builder.add_table_grant(Arc::new(grant.clone()), ());
-                    builder = builder.add_column_grant(Arc::new(grant), ());
+                let (table_grant, column_grant) = crate::impls::split_grant_by_scope(grant);
+                if let Some(table_grant) = table_grant {
+                    builder.push_table_grant(Arc::new(table_grant), ());
+                }
+                if let Some(column_grant) = column_grant {
+                    builder.push_column_grant(Arc::new(column_grant), ());
+                }
+            }
+            Statement::Revoke(mut revoke) => {
+                qualify_table_objects(builder, revoke.objects.as_mut());
+                // Apply revoke semantics to both canonical grant stores.
+                let table_application =
+                    apply_revoke_to_grant_store(builder.table_grants_mut(), &revoke, casing);
+                let column_application =
+                    apply_revoke_to_grant_store(builder.column_grants_mut(), &revoke, casing);
+
+                // We fail fast on revoke shapes that this model cannot
+                // represent (for example column-subset revoke from a
+                // table-wide action grant).
+                if table_application.has_unsupported_column_scoped_revoke
+                    || column_application.has_unsupported_column_scoped_revoke
+                {
+                    return Err(crate::errors::Error::UnsupportedRevoke {
+                        statement: revoke.to_string(),
+                        reason: "column-scoped REVOKE against a table-wide action grant is \
+                                 not representable in this model"
+                            .to_string(),
+                    });
                 }
-                Statement::Revoke(revoke) => {
-                    // Apply revoke semantics to both canonical grant stores.
-                    let table_application =
-                        apply_revoke_to_grant_store(builder.table_grants_mut(), &revoke);
-                    let column_application =
-                        apply_revoke_to_grant_store(builder.column_grants_mut(), &revoke);
-
-                    // We fail fast on revoke shapes that this model cannot
-                    // represent (for example column-subset revoke from a
-                    // table-wide action grant).
-                    if table_application.has_unsupported_column_scoped_revoke
-                        || column_application.has_unsupported_column_scoped_revoke
-                    {
-                        return Err(crate::errors::Error::UnsupportedRevoke {
-                            statement: revoke.to_string(),
-                            reason: "column-scoped REVOKE against a table-wide action grant is \
-                                     not representable in this model"
-                                .to_string(),
-                        });
-                    }
 
-                    if !table_application.matched_any && !column_application.matched_any {
-                        return Err(crate::errors::Error::RevokeNotFound(format!(
-                            "No matching grant found for REVOKE: {revoke}"
-                        )));
-                    }
+                if !table_application.matched_any && !column_application.matched_any {
+                    return Err(crate::errors::Error::RevokeNotFound(format!(
+                        "No matching grant found for REVOKE: {revoke}"
+                    )));
                 }
-                Statement::Set(sqlparser::ast::Set::SetTimeZone { local, value }) => {
-                    if local {
-                        builder = builder.timezone("LOCAL".to_string());
-                    } else if let Expr::Value(ValueWithSpan {
-                        value: Value::SingleQuotedString(lit),
-                        ..
-                    }) = value
-                    {
-                        builder = builder.timezone(lit);
-                    }
-                    // Ignore unsupported SET TIME ZONE expressions (e.g.,
-                    // binary ops)
+            }
+            Statement::Set(sqlparser::ast::Set::SetTimeZone { local, value }) => {
+                if local {
+                    builder.set_timezone("LOCAL".to_string());
+                } else if let Expr::Value(ValueWithSpan {
+                    value: Value::SingleQuotedString(lit),
+                    ..
+                }) = value
+                {
+                    builder.set_timezone(lit);
+                } else {
+                    return Err(source_locations::unsupported_statement(
+                        format!("SET TIME ZONE {value}"),
+                        value.span(),
+                    ));
                 }
-                Statement::RenameTable(renames) => {
-                    for rename in renames {
-                        builder = Self::rename_table_checked(
-                            builder,
-                            &rename.old_name,
-                            rename.new_name,
-                            false,
-                        )?;
+            }
+            Statement::Set(sqlparser::ast::Set::SingleAssignment { variable, values, .. })
+                if last_str(&variable).eq_ignore_ascii_case("search_path") =>
+            {
+                let is_default = matches!(values.as_slice(), [Expr::Identifier(ident)]
+                    if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("DEFAULT"));
+                if is_default {
+                    builder.set_search_path(None);
+                } else {
+                    let search_path = values
+                        .iter()
+                        .map(|value| {
+                            search_path_schema(value).ok_or_else(|| {
+                                source_locations::unsupported_statement(
+                                    format!("SET search_path = {value}"),
+                                    value.span(),
+                                )
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    builder.set_search_path(Some(search_path));
+                }
+            }
+            Statement::RenameTable(renames) => {
+                for rename in renames {
+                    Self::rename_table_checked(builder, &rename.old_name, rename.new_name, false)?;
+                }
+            }
+            Statement::AlterPolicy(AlterPolicy { name, table_name, operation }) => {
+                let Some(position) = builder.policy_position(&name, &table_name)? else {
+                    return Err(crate::errors::Error::AlterPolicyNotFound {
+                        policy_name: name.value,
+                    });
+                };
+
+                match operation {
+                    AlterPolicyOperation::Rename { new_name } => {
+                        // Update the policy name
+                        let policies = builder.policies_mut();
+                        let (old_policy, meta) = policies.remove(position);
+                        let mut new_policy = (*old_policy).clone();
+                        new_policy.name = new_name;
+                        policies.push((Arc::new(new_policy), meta));
+                    }
+                    AlterPolicyOperation::Apply { .. } => {
+                        // For Apply operations (changing USING/WITH CHECK
+                        // expressions),
+                        // we would need to update the policy metadata with
+                        // new function refs.
+                        // This is complex and would require re-parsing
+                        // expressions. For now,
+                        // we skip detailed tracking of expression changes.
                     }
                 }
-                Statement::AlterPolicy(AlterPolicy { name, table_name, operation }) => {
-                    use crate::traits::PolicyLike;
-
-                    let policy_name = &name.value;
-                    let _table_name = last_str(&table_name);
+            }
+            Statement::AlterSchema(AlterSchema { name, if_exists, operations }) => {
+                let schema_name = last_str(&name);
 
-                    // Check if policy exists
-                    let policy_exists =
-                        builder.policies().iter().any(|(p, _)| p.name() == policy_name);
+                // Check if schema exists
+                let resolved_schema = object_name_last_identifier(&name)
+                    .and_then(|ident| builder.resolve_schema_ident(ident));
 
-                    if !policy_exists {
-                        return Err(crate::errors::Error::AlterPolicyNotFound {
-                            policy_name: policy_name.clone(),
-                        });
+                let Some(resolved_schema) = resolved_schema else {
+                    if if_exists {
+                        return Ok(true);
                     }
+                    return Err(crate::errors::Error::AlterSchemaNotFound {
+                        schema_name: schema_name.to_string(),
+                    });
+                };
 
+                let mut current_schema_name = resolved_schema.name().to_string();
+                let mut current_schema_quoted = resolved_schema.is_quoted();
+
+                for operation in &operations {
                     match operation {
-                        AlterPolicyOperation::Rename { new_name } => {
-                            // Update the policy name
-                            let policies = builder.policies_mut();
-                            if let Some(idx) =
-                                policies.iter().position(|(p, _)| p.name() == policy_name)
-                            {
-                                let (old_policy, meta) = policies.remove(idx);
-                                let mut new_policy = (*old_policy).clone();
-                                new_policy.name = new_name.clone();
-                                policies.push((Arc::new(new_policy), meta));
+                        AlterSchemaOperation::Rename { name: new_name } => {
+                            let new_schema_ident = object_name_last_identifier(new_name);
+                            let new_schema_name = new_schema_ident.map_or_else(
+                                || last_str(new_name).to_string(),
+                                |ident| ident.value.clone(),
+                            );
+                            let new_schema_quoted =
+                                new_schema_ident.is_some_and(|ident| ident.quote_style.is_some());
+                            let schemas = builder.schemas_mut();
+                            let Some(idx) = schemas.iter().position(|(schema, ())| {
+                                casing.matches(
+                                    schema.name(),
+                                    schema.is_quoted(),
+                                    &current_schema_name,
+                                    current_schema_quoted,
+                                )
+                            }) else {
+                                continue;
+                            };
+
+                            let duplicate_exists =
+                                schemas.iter().enumerate().any(|(existing_idx, (schema, ()))| {
+                                    existing_idx != idx
+                                        && casing.matches(
+                                            schema.name(),
+                                            schema.is_quoted(),
+                                            &new_schema_name,
+                                            new_schema_quoted,
+                                        )
+                                });
+                            if duplicate_exists {
+                                return Err(crate::errors::Error::SchemaAlreadyExists {
+                                    schema_name: new_schema_name.clone(),
+                                });
                             }
-                        }
-                        AlterPolicyOperation::Apply { .. } => {
-                            // For Apply operations (changing USING/WITH CHECK
-                            // expressions),
-                            // we would need to update the policy metadata with
-                            // new function refs.
-                            // This is complex and would require re-parsing
-                            // expressions. For now,
-                            // we skip detailed tracking of expression changes.
-                        }
-                    }
-                }
-                Statement::AlterSchema(AlterSchema { name, if_exists, operations }) => {
-                    let schema_name = last_str(&name);
-
-                    // Check if schema exists
-                    let resolved_schema = object_name_last_identifier(&name)
-                        .and_then(|ident| builder.resolve_schema_ident(ident));
 
-                    let Some(resolved_schema) = resolved_schema else {
-                        if if_exists {
-                            continue;
+                            let (old_schema, ()) = schemas.remove(idx);
+                            let new_schema = if let Some(auth) = old_schema.authorization() {
+                                Schema::with_authorization_and_quoted(
+                                    new_schema_name.clone(),
+                                    auth.to_string(),
+                                    new_schema_quoted,
+                                )
+                                .with_quoted_authorization(old_schema.is_authorization_quoted())
+                            } else {
+                                Schema::with_quoted(new_schema_name.clone(), new_schema_quoted)
+                            };
+                            schemas.push((Arc::new(new_schema), ()));
+                            schemas.sort_by(|(a, ()), (b, ())| a.name().cmp(b.name()));
+                            current_schema_name = new_schema_name;
+                            current_schema_quoted = new_schema_quoted;
                         }
-                        return Err(crate::errors::Error::AlterSchemaNotFound {
-                            schema_name: schema_name.to_string(),
-                        });
-                    };
-
-                    let mut current_schema_name = resolved_schema.name().to_string();
-                    let mut current_schema_quoted = resolved_schema.is_quoted();
-
-                    for operation in &operations {
-                        match operation {
-                            AlterSchemaOperation::Rename { name: new_name } => {
-                                let new_schema_ident = object_name_last_identifier(new_name);
-                                let new_schema_name = new_schema_ident.map_or_else(
-                                    || last_str(new_name).to_string(),
-                                    |ident| ident.value.clone(),
-                                );
-                                let new_schema_quoted = new_schema_ident
-                                    .is_some_and(|ident| ident.quote_style.is_some());
-                                let schemas = builder.schemas_mut();
-                                let Some(idx) = schemas.iter().position(|(schema, ())| {
-                                    identifiers_match(
-                                        schema.name(),
-                                        schema.is_quoted(),
-                                        &current_schema_name,
-                                        current_schema_quoted,
-                                    )
-                                }) else {
-                                    continue;
-                                };
-
-                                let duplicate_exists = schemas.iter().enumerate().any(
-                                    |(existing_idx, (schema, ()))| {
-                                        existing_idx != idx
-                                            && identifiers_match(
-                                                schema.name(),
-                                                schema.is_quoted(),
-                                                &new_schema_name,
-                                                new_schema_quoted,
-                                            )
-                                    },
-                                );
-                                if duplicate_exists {
-                                    return Err(crate::errors::Error::SchemaAlreadyExists {
-                                        schema_name: new_schema_name.clone(),
-                                    });
+                        AlterSchemaOperation::OwnerTo { owner } => {
+                            // Update the authorization
+                            let (owner_name, owner_quoted) = match owner {
+                                sqlparser::ast::Owner::Ident(ident) => {
+                                    (ident.value.clone(), ident.quote_style.is_some())
                                 }
-
-                                let (old_schema, ()) = schemas.remove(idx);
-                                let new_schema = if let Some(auth) = old_schema.authorization() {
-                                    Schema::with_authorization_and_quoted(
-                                        new_schema_name.clone(),
-                                        auth.to_string(),
-                                        new_schema_quoted,
-                                    )
-                                } else {
-                                    Schema::with_quoted(new_schema_name.clone(), new_schema_quoted)
-                                };
-                                schemas.push((Arc::new(new_schema), ()));
-                                schemas.sort_by(|(a, ()), (b, ())| a.name().cmp(b.name()));
-                                current_schema_name = new_schema_name;
-                                current_schema_quoted = new_schema_quoted;
-                            }
-                            AlterSchemaOperation::OwnerTo { owner } => {
-                                // Update the authorization
-                                let owner_name = match owner {
-                                    sqlparser::ast::Owner::Ident(ident) => ident.value.clone(),
-                                    sqlparser::ast::Owner::CurrentRole
-                                    | sqlparser::ast::Owner::CurrentUser
-                                    | sqlparser::ast::Owner::SessionUser => continue,
-                                };
-                                let schemas = builder.schemas_mut();
-                                let Some(idx) = schemas.iter().position(|(schema, ())| {
-                                    identifiers_match(
-                                        schema.name(),
-                                        schema.is_quoted(),
-                                        &current_schema_name,
-                                        current_schema_quoted,
-                                    )
-                                }) else {
-                                    continue;
-                                };
-                                let (old_schema, ()) = schemas.remove(idx);
-                                let new_schema = Schema::with_authorization_and_quoted(
-                                    old_schema.name().to_string(),
-                                    owner_name,
-                                    old_schema.is_quoted(),
-                                );
-                                schemas.push((Arc::new(new_schema), ()));
-                            }
-                            // Other operations don't affect our schema tracking
-                            AlterSchemaOperation::SetDefaultCollate { .. }
-                            | AlterSchemaOperation::AddReplica { .. }
-                            | AlterSchemaOperation::DropReplica { .. }
-                            | AlterSchemaOperation::SetOptionsParens { .. } => {}
+                                sqlparser::ast::Owner::CurrentRole
+                                | sqlparser::ast::Owner::CurrentUser
+                                | sqlparser::ast::Owner::SessionUser => continue,
+                            };
+                            let schemas = builder.schemas_mut();
+                            let Some(idx) = schemas.iter().position(|(schema, ())| {
+                                casing.matches(
+                                    schema.name(),
+                                    schema.is_quoted(),
+                                    &current_schema_name,
+                                    current_schema_quoted,
+                                )
+                            }) else {
+                                continue;
+                            };
+                            let (old_schema, ()) = schemas.remove(idx);
+                            let new_schema = Schema::with_authorization_and_quoted(
+                                old_schema.name().to_string(),
+                                owner_name,
+                                old_schema.is_quoted(),
+                            )
+                            .with_quoted_authorization(owner_quoted);
+                            schemas.push((Arc::new(new_schema), ()));
                         }
+                        // Other operations don't affect our schema tracking
+                        AlterSchemaOperation::SetDefaultCollate { .. }
+                        | AlterSchemaOperation::AddReplica { .. }
+                        | AlterSchemaOperation::DropReplica { .. }
+                        | AlterSchemaOperation::SetOptionsParens { .. } => {}
                     }
                 }
-                _ => {
-                    // Ignored statements - no schema tracking needed
-                }
+            }
+            statement @ (Statement::AlterType { .. }
+            | Statement::AlterView { .. }
+            | Statement::CreateProcedure { .. }
+            | Statement::CreateMacro { .. }) => {
+                return Err(source_locations::unsupported_statement(
+                    statement.to_string(),
+                    statement.span(),
+                ));
+            }
+            _ => {
+                // Ignored statements - no schema tracking needed
+                return Ok(false);
             }
         }
-
-        Ok(builder.into())
+        Ok(true)
     }
 
     /// Parses SQL using the specified dialect.
@@ -2125,7 +4491,14 @@ impl ParserDB {
     /// # Errors
     ///
     /// Returns an error if the SQL cannot be parsed or if there are
-    /// validation errors.
+    /// validation errors, such as a column default value whose type cannot be
+    /// stored in the column or a function call in a check constraint or
+    /// policy matching none of the overloads of the function, which
+    /// [`Self::parse_lenient`] does not check. Statements defining schema
+    /// objects which are not modelled, such as `ALTER TYPE`, are skipped and
+    /// recorded as [`GenericDB::validation_warnings`], unless
+    /// [`ValidationClass::UnsupportedStatements`] is raised to an error
+    /// through [`Self::parse_with_options`].
     ///
     /// # Example
     ///
@@ -2145,78 +4518,253 @@ impl ParserDB {
     /// # }
     /// ```
     pub fn parse<D: Dialect + Default + 'static>(sql: &str) -> Result<Self, crate::errors::Error> {
-        let dialect = D::default();
-        let mut parser = Parser::new(&dialect).try_with_sql(sql)?;
-        let statements = parser.parse_statements()?;
-        let mut db = Self::from_statements_with_dialect(
-            statements,
-            "unknown_catalog".to_string(),
-            SqlparserDialect::of::<D>(),
-        )?;
-
-        if let Ok(documentation) = SqlDoc::builder_from_str(sql).build::<D>() {
-            for (table, metadata) in db.tables_metadata_mut() {
-                if let Ok(table_doc) = documentation.table(table.table_name(), table.table_schema())
-                {
-                    metadata.set_doc(table_doc.to_owned());
-                }
-            }
-        }
-        Ok(db)
+        Self::parse_with_options::<D>(sql, ParserOptions::default())
     }
 
-    /// Constructs a `ParserDB` from a git URL.
+    /// Same as [`Self::parse`] but does not validate the default values of
+    /// the columns against their data types, nor the arguments of the
+    /// function calls of check constraints and policies against the
+    /// signatures of the functions, for schemas relying on conversions the
+    /// type inference does not model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL cannot be parsed or if there are other
+    /// validation errors.
     ///
     /// # Example
     ///
-    /// ```
-    /// use sql_traits::prelude::ParserDB;
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
     /// use sqlparser::dialect::PostgreSqlDialect;
     ///
-    /// let url = "https://github.com/earth-metabolome-initiative/asset-procedure-schema.git";
-    /// let db = ParserDB::from_git_url::<PostgreSqlDialect>(url).unwrap();
+    /// let sql = "CREATE TABLE counters (value INT DEFAULT 'abc');";
+    /// assert!(matches!(
+    ///     ParserDB::parse::<PostgreSqlDialect>(sql),
+    ///     Err(sql_traits::errors::Error::DefaultTypeMismatch { .. })
+    /// ));
+    /// let db = ParserDB::parse_lenient::<PostgreSqlDialect>(sql)?;
+    /// assert!(db.table(None, "counters").is_some());
+    /// # Ok(())
+    /// # }
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the repository cannot be cloned or if the SQL files
-    /// cannot be parsed.
-    #[cfg(feature = "git")]
-    pub fn from_git_url<D: Dialect + Default>(url: &str) -> Result<Self, crate::errors::Error> {
-        let dir = tempfile::tempdir()?;
-        Repository::clone(url, dir.path())?;
-        Self::from_path::<D>(dir.path())
-    }
-
-    /// Constructs a `ParserDB` from a git URL using a specific dialect.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the repository cannot be cloned or if the SQL files
-    /// cannot be parsed.
-    #[cfg(feature = "git")]
-    pub fn from_git_url_with_dialect<D: Dialect + Default>(
-        url: &str,
+    pub fn parse_lenient<D: Dialect + Default + 'static>(
+        sql: &str,
     ) -> Result<Self, crate::errors::Error> {
-        let dir = tempfile::tempdir()?;
-        Repository::clone(url, dir.path())?;
-        Self::from_path::<D>(dir.path())
+        Self::parse_with_options::<D>(
+            sql,
+            ParserOptions::default().with_level(ValidationClass::Types, ValidationLevel::Ignore),
+        )
     }
 
-    /// Parses SQL from a file or directory path.
-    ///
-    /// If the path is a directory, all `.sql` files (except `down.sql`) will be
-    /// parsed recursively.
-    ///
-    /// # Arguments
+    /// Same as [`Self::parse`] but with the severity of each class of
+    /// validation errors set by the provided options, so that schemas which
+    /// are only partially available can still be loaded.
     ///
-    /// * `path` - The path to a SQL file or directory containing SQL files.
-    /// * `dialect` - The SQL dialect to use for parsing.
+    /// Statements failing a validation set to [`ValidationLevel::Warn`] or
+    /// [`ValidationLevel::Ignore`] are skipped, the former being reported by
+    /// [`GenericDB::validation_warnings`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the path doesn't exist, files can't be read, or
-    /// parsing fails.
+    /// Returns an error if the SQL cannot be parsed or if there are
+    /// validation errors of a class set to [`ValidationLevel::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sql_traits::structs::{ParserOptions, ValidationClass, ValidationLevel};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let sql = "CREATE TABLE t (id INT);
+    ///            GRANT SELECT ON t TO reader;
+    ///            CREATE INDEX idx ON missing (id);";
+    /// assert!(ParserDB::parse::<PostgreSqlDialect>(sql).is_err());
+    ///
+    /// let options = ParserOptions::default()
+    ///     .with_level(ValidationClass::MissingObjects, ValidationLevel::Ignore);
+    /// let db = ParserDB::parse_with_options::<PostgreSqlDialect>(sql, options)?;
+    /// assert!(db.table(None, "t").is_some());
+    /// assert!(db.validation_warnings().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_options<D: Dialect + Default + 'static>(
+        sql: &str,
+        options: ParserOptions,
+    ) -> Result<Self, crate::errors::Error> {
+        Self::parse_validated::<D>(sql, &mut Validator::aborting(options))
+    }
+
+    /// Same as [`Self::parse_with_options`] but keeps going past the
+    /// validation errors of the classes set to [`ValidationLevel::Error`],
+    /// skipping the failing statements, so that every problem of a schema
+    /// is reported in a single pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL cannot be parsed: validation errors are
+    /// instead collected in the returned [`ParseReport`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sql_traits::{errors::Error, structs::ParserOptions};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let report = ParserDB::parse_with_report::<PostgreSqlDialect>(
+    ///     "CREATE TABLE t (id INT DEFAULT 'abc');
+    ///      CREATE INDEX idx ON missing (id);
+    ///      CREATE TABLE u (id INT);",
+    ///     ParserOptions::default(),
+    /// )?;
+    /// assert!(matches!(
+    ///     report.errors(),
+    ///     [Error::TableNotFoundForIndex { .. }, Error::DefaultTypeMismatch { .. }]
+    /// ));
+    /// assert!(report.database().table(None, "u").is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with_report<D: Dialect + Default + 'static>(
+        sql: &str,
+        options: ParserOptions,
+    ) -> Result<ParseReport, crate::errors::Error> {
+        let mut validator = Validator::collecting(options);
+        let db = Self::parse_validated::<D>(sql, &mut validator)?;
+        Ok(ParseReport::new(db, validator.into_errors()))
+    }
+
+    /// Same as [`Self::from_statements`] but keeps going past the validation
+    /// errors, skipping the failing statements, so that every problem of a
+    /// schema is reported in a single pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be built at all: validation
+    /// errors are instead collected in the returned [`ParseReport`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+    ///
+    /// let sql = "DROP TABLE a; DROP TABLE b; CREATE TABLE c (id INT);";
+    /// let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+    /// let report = ParserDB::from_statements_with_report(statements, "test".to_string()).unwrap();
+    /// assert_eq!(report.errors().len(), 2);
+    /// assert_eq!(report.database().tables().count(), 1);
+    /// ```
+    pub fn from_statements_with_report(
+        statements: Vec<Statement>,
+        catalog_name: String,
+    ) -> Result<ParseReport, crate::errors::Error> {
+        let mut validator = Validator::collecting(ParserOptions::default());
+        let db = Self::from_filtered_statements(
+            statements,
+            catalog_name,
+            SqlparserDialect::default(),
+            ObjectFilter::default(),
+            &[],
+            &[],
+            &mut validator,
+            None,
+        )?;
+        Ok(ParseReport::new(db, validator.into_errors()))
+    }
+
+    /// Parses SQL using the specified dialect, handling the validation
+    /// errors with the provided validator.
+    fn parse_validated<D: Dialect + Default + 'static>(
+        sql: &str,
+        validator: &mut Validator,
+    ) -> Result<Self, crate::errors::Error> {
+        let dialect = D::default();
+        let stripped_sql = StrippedSql::new(&dialect, sql);
+        let (statements, stripped_syntax) = stripped_sql.parse(&dialect)?;
+        let mut db = Self::from_filtered_statements(
+            statements,
+            "unknown_catalog".to_string(),
+            SqlparserDialect::of::<D>(),
+            ObjectFilter::default(),
+            &stripped_sql.unparsed,
+            &[],
+            validator,
+            None,
+        )?;
+        db.restore_stripped_syntax(&stripped_syntax);
+
+        if let Ok(documentation) = SqlDoc::builder_from_str(&stripped_sql.sql).build::<D>() {
+            for (table, metadata) in db.tables_metadata_mut() {
+                if let Ok(table_doc) = documentation.table(table.table_name(), table.table_schema())
+                {
+                    metadata.set_doc(table_doc.to_owned());
+                }
+            }
+        }
+        Ok(db)
+    }
+
+    /// Constructs a `ParserDB` from a git URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sql_traits::prelude::ParserDB;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let url = "https://github.com/earth-metabolome-initiative/asset-procedure-schema.git";
+    /// let db = ParserDB::from_git_url::<PostgreSqlDialect>(url).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be cloned or if the SQL files
+    /// cannot be parsed.
+    #[cfg(feature = "git")]
+    pub fn from_git_url<D: Dialect + Default + 'static>(
+        url: &str,
+    ) -> Result<Self, crate::errors::Error> {
+        let dir = tempfile::tempdir()?;
+        Repository::clone(url, dir.path())?;
+        Self::from_path::<D>(dir.path())
+    }
+
+    /// Constructs a `ParserDB` from a git URL using a specific dialect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be cloned or if the SQL files
+    /// cannot be parsed.
+    #[cfg(feature = "git")]
+    pub fn from_git_url_with_dialect<D: Dialect + Default + 'static>(
+        url: &str,
+    ) -> Result<Self, crate::errors::Error> {
+        let dir = tempfile::tempdir()?;
+        Repository::clone(url, dir.path())?;
+        Self::from_path::<D>(dir.path())
+    }
+
+    /// Parses SQL from a file or directory path.
+    ///
+    /// If the path is a directory, all `.sql` files (except `down.sql`) will be
+    /// parsed recursively.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to a SQL file or directory containing SQL files.
+    /// * `dialect` - The SQL dialect to use for parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path doesn't exist, files can't be read, or
+    /// parsing fails.
     ///
     /// # Example
     ///
@@ -2229,7 +4777,9 @@ impl ParserDB {
     /// let db = ParserDB::from_path::<PostgreSqlDialect>(Path::new("migrations/")).unwrap();
     /// ```
     #[cfg(feature = "std")]
-    pub fn from_path<D: Dialect + Default>(path: &Path) -> Result<Self, crate::errors::Error> {
+    pub fn from_path<D: Dialect + Default + 'static>(
+        path: &Path,
+    ) -> Result<Self, crate::errors::Error> {
         Self::from_paths::<D>(&[path])
     }
 
@@ -2245,11 +4795,98 @@ impl ParserDB {
     /// Returns an error if any path doesn't exist, files can't be read, or
     /// parsing fails.
     #[cfg(feature = "std")]
-    pub fn from_paths<D: Dialect + Default>(paths: &[&Path]) -> Result<Self, crate::errors::Error> {
-        let mut statements = Vec::new();
-        let mut sql_str: Vec<(String, PathBuf)> = Vec::new();
+    pub fn from_paths<D: Dialect + Default + 'static>(
+        paths: &[&Path],
+    ) -> Result<Self, crate::errors::Error> {
+        Self::from_paths_with_filter::<D>(paths, ObjectFilter::default())
+    }
 
-        for path in paths {
+    /// Parses SQL from multiple file or directory paths, only loading the
+    /// schemas and tables admitted by the provided [`ObjectFilter`].
+    ///
+    /// See [`Self::from_statements_with_filter`] for how excluded objects
+    /// and the foreign keys referencing them are handled. The statements
+    /// parsed from each file are kept, so that [`Self::update_from_paths`]
+    /// only re-parses the files which changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - A slice of paths to SQL files or directories.
+    /// * `object_filter` - The filter selecting the schemas and tables to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any path doesn't exist, files can't be read, or
+    /// parsing fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let filter = ObjectFilter::default().include_schema("app").exclude_table("*_archive");
+    /// let db =
+    ///     ParserDB::from_paths_with_filter::<PostgreSqlDialect>(&[Path::new("migrations/")], filter)
+    ///         .unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_paths_with_filter<D: Dialect + Default + 'static>(
+        paths: &[&Path],
+        object_filter: ObjectFilter,
+    ) -> Result<Self, crate::errors::Error> {
+        Self::from_paths_with_options::<D>(paths, object_filter, ParserOptions::default())
+    }
+
+    /// Same as [`Self::from_paths_with_filter`], but validating the schema
+    /// with the provided [`ParserOptions`], as [`Self::parse_with_options`]
+    /// does.
+    ///
+    /// The filter and the options are kept along with the parsed files, so
+    /// that [`Self::update_from_paths`] rebuilds the database with them.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - A slice of paths to SQL files or directories.
+    /// * `object_filter` - The filter selecting the schemas and tables to load.
+    /// * `options` - The options validating the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any path doesn't exist, files can't be read,
+    /// parsing fails, or if there are validation errors of a class set to
+    /// [`ValidationLevel::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use sql_traits::{
+    ///     prelude::*,
+    ///     structs::{ParserOptions, ValidationClass, ValidationLevel},
+    /// };
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let options = ParserOptions::default()
+    ///     .with_level(ValidationClass::MissingObjects, ValidationLevel::Warn);
+    /// let db = ParserDB::from_paths_with_options::<PostgreSqlDialect>(
+    ///     &[Path::new("migrations/")],
+    ///     ObjectFilter::default(),
+    ///     options,
+    /// )
+    /// .unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_paths_with_options<D: Dialect + Default + 'static>(
+        paths: &[&Path],
+        object_filter: ObjectFilter,
+        options: ParserOptions,
+    ) -> Result<Self, crate::errors::Error> {
+        let mut files = Vec::new();
+        for (root, path) in paths.iter().enumerate() {
             if !path.exists() {
                 return Err(ParserError::TokenizerError(format!(
                     "Path does not exist: {}",
@@ -2258,31 +4895,68 @@ impl ParserDB {
                 .into());
             }
 
-            let mut sql_paths = search_sql_documents(path);
+            let mut sql_paths = search_sql_documents(path)?;
             sql_paths.sort_unstable();
 
             for sql_path in sql_paths {
-                let sql_content = std::fs::read_to_string(&sql_path)
-                    .map_err(|e| ParserError::TokenizerError(e.to_string()))
-                    .map_err(|e| {
-                        crate::errors::Error::SqlParserError {
-                            error: e,
-                            file: Some(sql_path.clone()),
-                        }
-                    })?;
-
-                let dialect = D::default();
-                let mut parser = Parser::new(&dialect).try_with_sql(&sql_content).map_err(|e| {
-                    crate::errors::Error::SqlParserError { error: e, file: Some(sql_path.clone()) }
-                })?;
-                statements.extend(parser.parse_statements().map_err(|e| {
-                    crate::errors::Error::SqlParserError { error: e, file: Some(sql_path.clone()) }
-                })?);
-                sql_str.push((sql_content, sql_path));
+                files.push(incremental::ParsedFile::parse::<D>(root, sql_path)?);
             }
         }
 
-        let mut db = Self::from_statements(statements, "unknown_catalog".to_string())?;
+        Self::from_source_cache::<D>(SourceCache {
+            roots: paths.iter().map(|path| path.to_path_buf()).collect(),
+            dialect: SqlparserDialect::of::<D>(),
+            object_filter,
+            options,
+            files,
+        })
+    }
+
+    /// Builds a database from the statements of the provided parsed files,
+    /// only loading the schemas and tables admitted by the provided
+    /// [`ObjectFilter`] and validating them with the provided
+    /// [`ParserOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database is invalid.
+    #[cfg(feature = "std")]
+    fn from_parsed_files<D: Dialect + Default + 'static>(
+        files: &[incremental::ParsedFile],
+        object_filter: ObjectFilter,
+        options: ParserOptions,
+    ) -> Result<Self, crate::errors::Error> {
+        let mut statements = Vec::new();
+        let mut stripped_syntax = pre_passes::StrippedSyntax::default();
+        let mut unparsed = Vec::new();
+        let mut sql_str: Vec<(String, PathBuf)> = Vec::new();
+        let mut source_files = Vec::new();
+
+        for file in files {
+            stripped_syntax.extend(&file.stripped_syntax);
+            unparsed.extend(file.unparsed.iter().cloned().map(|mut file_unparsed| {
+                file_unparsed.position += statements.len();
+                file_unparsed
+            }));
+            source_files.push(source_locations::SourceFile {
+                first_statement: statements.len(),
+                path: Arc::from(file.path.display().to_string()),
+            });
+            statements.extend(file.statements.iter().cloned());
+            sql_str.push((file.stripped_sql.clone(), file.path.clone()));
+        }
+
+        let mut db = Self::from_filtered_statements(
+            statements,
+            "unknown_catalog".to_string(),
+            SqlparserDialect::of::<D>(),
+            object_filter,
+            &unparsed,
+            &source_files,
+            &mut Validator::aborting(options),
+            None,
+        )?;
+        db.restore_stripped_syntax(&stripped_syntax);
 
         if let Ok(documentation) = SqlDoc::builder_from_strs_with_paths(&sql_str).build::<D>() {
             for (table, metadata) in db.tables_metadata_mut() {
@@ -2294,21 +4968,112 @@ impl ParserDB {
         }
         Ok(db)
     }
+
+    /// Verifies that the `down.sql` files found in the provided paths only
+    /// drop objects created by their sibling `up.sql` files.
+    ///
+    /// While [`Self::from_paths`] skips the `down.sql` files, a rollback
+    /// dropping a table created by another migration, or a column which does
+    /// not exist yet, would only fail once it is run. Each `down.sql` file is
+    /// parsed and compared against the `up.sql` file of the same directory,
+    /// which is considered empty when missing. The columns and constraints of
+    /// a table created by the `up.sql` file may all be dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - A slice of paths to migration directories.
+    /// * `severity` - How the mismatches are reported: ignored, returned, or
+    ///   turned into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files can't be read or parsed, or with
+    /// [`RollbackSeverity::Deny`] if a `down.sql` file drops an object not
+    /// created by its `up.sql` file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let mismatches = ParserDB::verify_rollbacks::<PostgreSqlDialect>(
+    ///     &[Path::new("migrations/")],
+    ///     RollbackSeverity::Warn,
+    /// )
+    /// .unwrap();
+    /// for mismatch in mismatches {
+    ///     eprintln!("{mismatch}");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn verify_rollbacks<D: Dialect + Default>(
+        paths: &[&Path],
+        severity: RollbackSeverity,
+    ) -> Result<Vec<RollbackMismatch>, crate::errors::Error> {
+        if severity == RollbackSeverity::Ignore {
+            return Ok(Vec::new());
+        }
+
+        let parse_file = |sql_path: &Path| -> Result<Vec<Statement>, crate::errors::Error> {
+            let file_error = |error| crate::errors::Error::SqlParserError {
+                error,
+                file: Some(sql_path.to_path_buf()),
+            };
+            let sql_content = std::fs::read_to_string(sql_path).map_err(|error| {
+                crate::errors::Error::Io { path: sql_path.to_path_buf(), error }
+            })?;
+            let dialect = D::default();
+            StrippedSql::new(&dialect, &sql_content)
+                .parse(&dialect)
+                .map(|(statements, _)| statements)
+                .map_err(file_error)
+        };
+
+        let casing = SqlparserDialect::of::<D>().identifier_casing();
+        let mut mismatches = Vec::new();
+        for path in paths {
+            for down_path in search_down_files(path)? {
+                let up_path = down_path.with_file_name("up.sql");
+                let up = if up_path.exists() { parse_file(&up_path)? } else { Vec::new() };
+                let down = parse_file(&down_path)?;
+                for mismatch in rollback_mismatches(&up, &down, &down_path, casing) {
+                    if severity == RollbackSeverity::Deny {
+                        return Err(crate::errors::Error::RollbackMismatch(mismatch));
+                    }
+                    mismatches.push(mismatch);
+                }
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Returns whether the path is a SQL file loaded from a directory, that is
+/// any `.sql` file but the `down.sql` rollbacks.
+#[cfg(feature = "std")]
+fn is_sql_document(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "sql")
+        && path.file_name().is_some_and(|file_name| file_name != "down.sql")
 }
 
+/// Returns the SQL files found in the provided path, recursively.
+///
+/// # Errors
+///
+/// Returns an error if a directory or one of its entries can't be read.
 #[cfg(feature = "std")]
-fn search_sql_documents(path: &Path) -> Vec<PathBuf> {
+fn search_sql_documents(path: &Path) -> Result<Vec<PathBuf>, crate::errors::Error> {
+    let io_error = |error| crate::errors::Error::Io { path: path.to_path_buf(), error };
     let mut sql_files = Vec::new();
     if path.is_dir() {
-        for entry in std::fs::read_dir(path).expect("Failed to read directory") {
-            let entry = entry.expect("Failed to read directory entry");
-            let path = entry.path();
+        for entry in std::fs::read_dir(path).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
             if path.is_dir() {
-                sql_files.extend(search_sql_documents(&path));
-            } else if let Some(extension) = path.extension()
-                && extension == "sql"
-                && path.file_name().unwrap() != "down.sql"
-            {
+                sql_files.extend(search_sql_documents(&path)?);
+            } else if is_sql_document(&path) {
                 sql_files.push(path);
             }
         }
@@ -2317,2255 +5082,8 @@ fn search_sql_documents(path: &Path) -> Vec<PathBuf> {
     {
         sql_files.push(path.to_path_buf());
     }
-    sql_files
+    Ok(sql_files)
 }
 
 #[cfg(test)]
-mod tests {
-    use sqlparser::dialect::GenericDialect;
-
-    use super::*;
-    use crate::{
-        errors::{Error, LookupError},
-        traits::{DatabaseLike, TableLike},
-    };
-
-    mod identifier_aware_lookup {
-        use sqlparser::{
-            ast::{Ident, ObjectName, ObjectNamePart},
-            dialect::PostgreSqlDialect,
-        };
-
-        use super::*;
-
-        fn ident(value: &str, quoted: bool) -> Ident {
-            if quoted { Ident::with_quote('"', value) } else { Ident::new(value) }
-        }
-
-        fn object_name(parts: &[(&str, bool)]) -> ObjectName {
-            ObjectName(
-                parts
-                    .iter()
-                    .map(|(value, quoted)| ObjectNamePart::Identifier(ident(value, *quoted)))
-                    .collect(),
-            )
-        }
-
-        fn parse_postgres(sql: &str) -> ParserDB {
-            ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse PostgreSQL SQL")
-        }
-
-        #[test]
-        fn quoted_table_lookup_requires_exact_case() {
-            let db = parse_postgres("CREATE TABLE \"Camel\" (id INT);");
-
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("Camel", true)]))
-                    .expect("Lookup should succeed")
-                    .is_some()
-            );
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("camel", true)]))
-                    .expect("Lookup should succeed")
-                    .is_none()
-            );
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("camel", false)]))
-                    .expect("Lookup should succeed")
-                    .is_none()
-            );
-        }
-
-        #[test]
-        fn unquoted_table_lookup_resolves_via_folding() {
-            let db = parse_postgres("CREATE TABLE Foo (id INT);");
-
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("foo", false)]))
-                    .expect("Lookup should succeed")
-                    .is_some()
-            );
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("FOO", false)]))
-                    .expect("Lookup should succeed")
-                    .is_some()
-            );
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("foo", true)]))
-                    .expect("Lookup should succeed")
-                    .is_some()
-            );
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("Foo", true)]))
-                    .expect("Lookup should succeed")
-                    .is_none()
-            );
-        }
-
-        #[test]
-        fn schema_ident_resolution_handles_quoted_and_unquoted() {
-            let db = parse_postgres(
-                r#"
-                CREATE SCHEMA Foo;
-                CREATE SCHEMA "Bar";
-                "#,
-            );
-
-            assert!(db.resolve_schema_ident(&ident("foo", false)).is_some());
-            assert!(db.resolve_schema_ident(&ident("FOO", false)).is_some());
-            assert!(db.resolve_schema_ident(&ident("foo", true)).is_some());
-            assert!(db.resolve_schema_ident(&ident("Foo", true)).is_none());
-
-            assert!(db.resolve_schema_ident(&ident("Bar", true)).is_some());
-            assert!(db.resolve_schema_ident(&ident("bar", false)).is_none());
-        }
-
-        #[test]
-        fn alter_table_rls_lookup_uses_resolver_rules() {
-            let db = parse_postgres(
-                r"
-                CREATE TABLE Foo (id INT);
-                ALTER TABLE FOO ENABLE ROW LEVEL SECURITY;
-                ",
-            );
-            let foo =
-                db.table(None, "foo").expect("Expected `foo` table to exist after ALTER TABLE");
-            assert!(
-                foo.has_row_level_security(&db),
-                "Unquoted ALTER TABLE lookup should resolve via identifier folding"
-            );
-
-            let db = parse_postgres(
-                r#"
-                CREATE TABLE Foo (id INT);
-                ALTER TABLE "Foo" ENABLE ROW LEVEL SECURITY;
-                "#,
-            );
-            let foo =
-                db.table(None, "foo").expect("Expected `foo` table to exist after ALTER TABLE");
-            assert!(
-                !foo.has_row_level_security(&db),
-                "Quoted ALTER TABLE name should not match unquoted table with different case"
-            );
-        }
-
-        #[test]
-        fn grant_table_lookup_uses_resolver_rules() {
-            let sql = r"
-                CREATE TABLE Foo (id INT);
-                CREATE ROLE app_role;
-                GRANT SELECT ON FOO TO app_role;
-            ";
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(result.is_ok());
-
-            let sql = r#"
-                CREATE TABLE Foo (id INT);
-                CREATE ROLE app_role;
-                GRANT SELECT ON "Foo" TO app_role;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::TableNotFoundForGrant { table_name }) if table_name == "Foo"
-            ));
-        }
-
-        #[test]
-        fn create_index_attaches_to_correct_schema_table() {
-            let db = parse_postgres(
-                r"
-                CREATE SCHEMA s1;
-                CREATE SCHEMA s2;
-                CREATE TABLE s1.t (id INT);
-                CREATE TABLE s2.t (id INT);
-                CREATE INDEX idx_s2_t_id ON s2.t (id);
-                ",
-            );
-
-            let s1_t = db
-                .resolve_table_object_name(&object_name(&[("s1", false), ("t", false)]))
-                .expect("Lookup should succeed")
-                .expect("Expected table s1.t to exist");
-            let s2_t = db
-                .resolve_table_object_name(&object_name(&[("s2", false), ("t", false)]))
-                .expect("Lookup should succeed")
-                .expect("Expected table s2.t to exist");
-
-            assert_eq!(s1_t.indices(&db).count(), 0);
-            assert_eq!(s2_t.indices(&db).count(), 1);
-        }
-
-        #[test]
-        fn create_index_attachment_respects_quoted_schema_and_table_identity() {
-            let db = parse_postgres(
-                r#"
-                CREATE SCHEMA s;
-                CREATE SCHEMA "S";
-                CREATE TABLE s.t (id INT);
-                CREATE TABLE "S"."T" (id INT);
-                CREATE INDEX idx_quoted_t ON "S"."T" (id);
-                "#,
-            );
-
-            let unquoted = db
-                .resolve_table_object_name(&object_name(&[("s", false), ("t", false)]))
-                .expect("Lookup should succeed")
-                .expect("Expected table s.t to exist");
-            let quoted = db
-                .resolve_table_object_name(&object_name(&[("S", true), ("T", true)]))
-                .expect("Lookup should succeed")
-                .expect("Expected table \"S\".\"T\" to exist");
-
-            assert_eq!(unquoted.indices(&db).count(), 0);
-            assert_eq!(quoted.indices(&db).count(), 1);
-        }
-
-        #[test]
-        fn rename_table_lookup_uses_resolver_rules() {
-            let sql = r"
-                CREATE TABLE Foo (id INT);
-                ALTER TABLE FOO RENAME TO bar;
-            ";
-            let db = parse_postgres(sql);
-            assert!(db.table(None, "foo").is_none());
-            assert!(db.table(None, "bar").is_some());
-
-            let sql = r#"
-                CREATE TABLE Foo (id INT);
-                ALTER TABLE "Foo" RENAME TO bar;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::RenameTableNotFound { table_name }) if table_name == "Foo"
-            ));
-        }
-
-        #[test]
-        fn rename_table_statement_lookup_uses_resolver_rules() {
-            let sql = r"
-                CREATE TABLE Foo (id INT);
-                RENAME TABLE FOO TO bar;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql)
-                .expect("Expected unquoted RENAME TABLE lookup to resolve");
-            assert!(db.table(None, "foo").is_none());
-            assert!(db.table(None, "bar").is_some());
-
-            let sql = r#"
-                CREATE TABLE Foo (id INT);
-                RENAME TABLE "Foo" TO bar;
-            "#;
-            let result = ParserDB::parse::<GenericDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::RenameTableNotFound { table_name }) if table_name == "Foo"
-            ));
-        }
-
-        #[test]
-        fn alter_schema_rename_rejects_semantic_duplicate() {
-            let sql = r"
-                CREATE SCHEMA foo;
-                CREATE SCHEMA bar;
-                ALTER SCHEMA bar RENAME TO FOO;
-            ";
-
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::SchemaAlreadyExists { schema_name })
-                    if schema_name.eq_ignore_ascii_case("foo")
-            ));
-        }
-
-        #[test]
-        fn alter_schema_rename_rejects_quoted_unquoted_equivalent_duplicate() {
-            let sql = r#"
-                CREATE SCHEMA foo;
-                CREATE SCHEMA bar;
-                ALTER SCHEMA bar RENAME TO "foo";
-            "#;
-
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::SchemaAlreadyExists { schema_name }) if schema_name == "foo"
-            ));
-        }
-
-        #[test]
-        fn implicit_public_helper_handles_mixed_public_cases() {
-            let db = parse_postgres(
-                r#"
-                CREATE TABLE public.foo (id INT);
-                CREATE SCHEMA "Public";
-                CREATE TABLE "Public".bar (id INT);
-                "#,
-            );
-
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("foo", false)]))
-                    .expect("Lookup should succeed")
-                    .is_none()
-            );
-
-            let resolved = db
-                .resolve_table_object_name_with_implicit_public(&object_name(&[("foo", false)]))
-                .expect("Lookup should succeed");
-            let resolved = resolved.expect("Expected implicit public fallback to resolve");
-            assert_eq!(
-                resolved.table_schema(),
-                Some("public"),
-                "Unqualified lookup should fallback to schema public"
-            );
-
-            assert!(
-                db.resolve_table_object_name_with_implicit_public(&object_name(&[("bar", false)]))
-                    .expect("Lookup should succeed")
-                    .is_none()
-            );
-
-            assert!(
-                db.resolve_table_object_name(&object_name(&[("Public", true), ("bar", false)]))
-                    .expect("Lookup should succeed")
-                    .is_some()
-            );
-        }
-
-        #[test]
-        fn invalid_object_name_is_reported() {
-            let db = parse_postgres("CREATE TABLE t (id INT);");
-            let invalid = object_name(&[("a", false), ("b", false), ("c", false)]);
-
-            let result = db.resolve_table_object_name(&invalid);
-            assert!(matches!(
-                result,
-                Err(LookupError::InvalidObjectName { object_name, .. }) if object_name == "a.b.c"
-            ));
-        }
-
-        #[test]
-        fn ambiguous_unqualified_and_public_tables_fail_at_build_time() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE TABLE public.t (id INT);
-            ";
-
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-            assert!(matches!(
-                result,
-                Err(Error::IdentifierLookupError(LookupError::TableLookupConflict {
-                    table,
-                    conflicting_table
-                })) if table == "public.t" && conflicting_table == "t"
-            ));
-        }
-    }
-
-    mod parser_variant_compatibility {
-        use sqlparser::dialect::{MsSqlDialect, PostgreSqlDialect};
-
-        use super::*;
-
-        #[test]
-        fn waitfor_statement_is_ignored_without_breaking_parse() {
-            let sql = "
-                WAITFOR DELAY '00:00:00';
-                CREATE TABLE t (id INT);
-            ";
-            let db = ParserDB::parse::<MsSqlDialect>(sql).expect("WAITFOR should be ignored");
-            assert!(db.table(None, "t").is_some());
-        }
-
-        #[test]
-        fn comment_on_role_statement_is_ignored_without_breaking_parse() {
-            let sql = "
-                CREATE ROLE app_role;
-                COMMENT ON ROLE app_role IS 'Application role';
-            ";
-            let db =
-                ParserDB::parse::<PostgreSqlDialect>(sql).expect("COMMENT ON ROLE should parse");
-            assert!(db.role("app_role").is_some());
-        }
-    }
-
-    mod drop_function_errors {
-        use super::*;
-
-        #[test]
-        fn test_drop_function_not_found_error_type() {
-            let sql = "DROP FUNCTION nonexistent_func;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropFunctionNotFound { function_name }) if function_name == "nonexistent_func"
-            ));
-        }
-
-        #[test]
-        fn test_drop_function_referenced_error_type() {
-            let sql = r"
-                CREATE FUNCTION is_valid(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
-                CREATE TABLE t (id INT CHECK (is_valid(id)));
-                DROP FUNCTION is_valid;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::FunctionReferenced { function_name }) if function_name == "is_valid"
-            ));
-        }
-
-        #[test]
-        fn test_drop_function_if_exists_not_found_succeeds() {
-            let sql = "DROP FUNCTION IF EXISTS nonexistent_func;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(result.is_ok());
-        }
-
-        #[test]
-        fn test_drop_function_if_exists_referenced_still_fails() {
-            let sql = r"
-                CREATE FUNCTION is_valid(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
-                CREATE TABLE t (id INT CHECK (is_valid(id)));
-                DROP FUNCTION IF EXISTS is_valid;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            // IF EXISTS doesn't bypass the reference check
-            assert!(matches!(
-                result,
-                Err(Error::FunctionReferenced { function_name }) if function_name == "is_valid"
-            ));
-        }
-
-        #[test]
-        fn test_drop_quoted_function_succeeds() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
-                DROP FUNCTION "FooBar";
-            "#;
-            let db = ParserDB::parse::<GenericDialect>(sql)
-                .expect("Quoted DROP FUNCTION should match quoted CREATE FUNCTION");
-
-            assert!(db.function("\"FooBar\"").is_none());
-        }
-
-        #[test]
-        fn test_drop_unquoted_does_not_match_quoted_function() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
-                DROP FUNCTION foobar;
-            "#;
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropFunctionNotFound { function_name }) if function_name == "foobar"
-            ));
-        }
-    }
-
-    mod drop_table_errors {
-        use super::*;
-
-        #[test]
-        fn test_drop_table_not_found_error_type() {
-            let sql = "DROP TABLE nonexistent_table;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropTableNotFound { table_name }) if table_name == "nonexistent_table"
-            ));
-        }
-
-        #[test]
-        fn test_drop_table_referenced_error_type() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-                DROP TABLE parent;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::TableReferenced { table_name }) if table_name == "parent"
-            ));
-        }
-
-        #[test]
-        fn test_drop_table_if_exists_not_found_succeeds() {
-            let sql = "DROP TABLE IF EXISTS nonexistent_table;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(result.is_ok());
-        }
-
-        #[test]
-        fn test_drop_table_if_exists_referenced_still_fails() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-                DROP TABLE IF EXISTS parent;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            // IF EXISTS doesn't bypass the reference check
-            assert!(matches!(
-                result,
-                Err(Error::TableReferenced { table_name }) if table_name == "parent"
-            ));
-        }
-
-        #[test]
-        fn test_drop_table_cascade_bypasses_reference_check() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-                DROP TABLE parent CASCADE;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(result.is_ok());
-            let db = result.unwrap();
-            assert!(db.table(None, "parent").is_none());
-            assert!(db.table(None, "child").is_some());
-        }
-
-        #[test]
-        fn test_drop_multiple_tables() {
-            let sql = r"
-                CREATE TABLE t1 (id INT PRIMARY KEY);
-                CREATE TABLE t2 (id INT PRIMARY KEY);
-                CREATE TABLE t3 (id INT PRIMARY KEY);
-                DROP TABLE t1, t2;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(result.is_ok());
-            let db = result.unwrap();
-            assert!(db.table(None, "t1").is_none());
-            assert!(db.table(None, "t2").is_none());
-            assert!(db.table(None, "t3").is_some());
-        }
-
-        #[test]
-        fn test_drop_multiple_tables_one_referenced_fails() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-                CREATE TABLE other (id INT PRIMARY KEY);
-                DROP TABLE parent, other;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            // Should fail because parent is referenced
-            assert!(matches!(
-                result,
-                Err(Error::TableReferenced { table_name }) if table_name == "parent"
-            ));
-        }
-
-        #[test]
-        fn test_drop_table_with_same_name_in_other_schema_only_removes_target() {
-            let sql = r"
-                CREATE SCHEMA s1;
-                CREATE SCHEMA s2;
-                CREATE TABLE s1.t (id INT PRIMARY KEY);
-                CREATE TABLE s2.t (id INT PRIMARY KEY);
-                CREATE INDEX idx_s2_t_id ON s2.t (id);
-                DROP TABLE s1.t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            assert!(db.table(Some("s1"), "t").is_none());
-            let s2_t = db.table(Some("s2"), "t").expect("s2.t should still exist");
-            assert_eq!(
-                s2_t.indices(&db).count(),
-                1,
-                "Dropping s1.t must not remove indices attached to s2.t",
-            );
-        }
-
-        #[test]
-        fn test_drop_table_reference_check_is_schema_aware() {
-            let sql = r"
-                CREATE SCHEMA s1;
-                CREATE SCHEMA s2;
-                CREATE TABLE s1.parent (id INT PRIMARY KEY);
-                CREATE TABLE s1.child (id INT, parent_id INT REFERENCES s1.parent(id));
-                CREATE TABLE s2.parent (id INT PRIMARY KEY);
-                DROP TABLE s2.parent;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect(
-                "References to s1.parent must not block dropping same-name table s2.parent",
-            );
-
-            assert!(db.table(Some("s2"), "parent").is_none());
-            assert!(db.table(Some("s1"), "parent").is_some());
-            assert!(db.table(Some("s1"), "child").is_some());
-        }
-    }
-
-    mod is_function_used_tests {
-        use super::*;
-
-        #[test]
-        fn test_is_function_used_returns_false_when_no_references() {
-            // Parse SQL with a function but no references
-            let sql = r"
-                CREATE FUNCTION my_func() RETURNS INT AS 'SELECT 1;';
-                CREATE TABLE t (id INT);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // The function exists but isn't used by any schema object
-            assert!(db.function("my_func").is_some());
-        }
-
-        #[test]
-        fn test_function_used_by_check_constraint() {
-            let sql = r"
-                CREATE FUNCTION is_positive(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
-                CREATE TABLE t (id INT CHECK (is_positive(id)));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Function should exist and be used
-            assert!(db.function("is_positive").is_some());
-
-            // Verify dropping it would fail
-            let drop_sql = format!("{sql}\nDROP FUNCTION is_positive;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
-        }
-
-        #[test]
-        fn test_function_used_by_policy_using_clause() {
-            let sql = r"
-                CREATE FUNCTION check_access() RETURNS BOOLEAN AS 'SELECT true;';
-                CREATE TABLE t (id INT);
-                CREATE POLICY my_policy ON t USING (check_access());
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Function should exist
-            assert!(db.function("check_access").is_some());
-
-            // Verify dropping it would fail
-            let drop_sql = format!("{sql}\nDROP FUNCTION check_access;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
-        }
-
-        #[test]
-        fn test_function_used_by_policy_with_check_clause() {
-            let sql = r"
-                CREATE FUNCTION validate() RETURNS BOOLEAN AS 'SELECT true;';
-                CREATE TABLE t (id INT);
-                CREATE POLICY my_policy ON t WITH CHECK (validate());
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Function should exist
-            assert!(db.function("validate").is_some());
-
-            // Verify dropping it would fail
-            let drop_sql = format!("{sql}\nDROP FUNCTION validate;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
-        }
-
-        #[test]
-        fn test_function_used_by_trigger() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Function should exist
-            assert!(db.function("trigger_fn").is_some());
-
-            // Verify dropping it would fail
-            let drop_sql = format!("{sql}\nDROP FUNCTION trigger_fn;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
-        }
-
-        #[test]
-        fn test_function_used_by_schema_qualified_call() {
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE FUNCTION check_access() RETURNS BOOLEAN AS 'SELECT true;';
-                CREATE TABLE t (id INT CHECK (s.check_access()));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(db.function("check_access").is_some());
-
-            let drop_sql = format!("{sql}\nDROP FUNCTION check_access;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
-        }
-
-        #[test]
-        fn test_quoted_function_used_by_check_constraint() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
-                CREATE TABLE t (id INT CHECK ("FooBar"(id)));
-                DROP FUNCTION "FooBar";
-            "#;
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
-            ));
-        }
-
-        #[test]
-        fn test_quoted_function_used_by_policy() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"() RETURNS BOOLEAN AS 'SELECT true;';
-                CREATE TABLE t (id INT);
-                CREATE POLICY p ON t USING ("FooBar"());
-                DROP FUNCTION "FooBar";
-            "#;
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
-            ));
-        }
-
-        #[test]
-        fn test_quoted_function_used_by_trigger() {
-            let sql = r#"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION "FooBar"() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION "FooBar"();
-                DROP FUNCTION "FooBar";
-            "#;
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
-            ));
-        }
-    }
-
-    mod function_lookup_identifier_semantics {
-        use super::*;
-        use crate::traits::DatabaseLike;
-
-        #[test]
-        fn quoted_function_lookup_requires_exact_quoted_name() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
-            "#;
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(db.function("\"FooBar\"").is_some());
-            assert!(db.function("foobar").is_none());
-            assert!(db.function("\"foobar\"").is_none());
-        }
-
-        #[test]
-        fn unquoted_function_lookup_uses_identifier_folding() {
-            let sql = r"
-                CREATE FUNCTION foobar() RETURNS INT AS 'SELECT 1;';
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(db.function("foobar").is_some());
-            assert!(db.function("FOOBAR").is_some());
-            assert!(db.function("\"FOOBAR\"").is_none());
-        }
-
-        #[test]
-        fn database_like_function_lookup_is_identifier_aware() {
-            let sql = r#"
-                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
-            "#;
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(<ParserDB as DatabaseLike>::function(&db, "\"FooBar\"").is_some());
-            assert!(<ParserDB as DatabaseLike>::function(&db, "foobar").is_none());
-            assert!(<ParserDB as DatabaseLike>::function(&db, "\"foobar\"").is_none());
-        }
-    }
-
-    mod is_table_referenced_tests {
-        use super::*;
-
-        #[test]
-        fn test_table_not_referenced() {
-            let sql = r"
-                CREATE TABLE standalone (id INT PRIMARY KEY);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Table exists
-            assert!(db.table(None, "standalone").is_some());
-
-            // Can be dropped
-            let drop_sql = format!("{sql}\nDROP TABLE standalone;");
-            let result = ParserDB::parse::<GenericDialect>(&drop_sql);
-            assert!(result.is_ok());
-        }
-
-        #[test]
-        fn test_table_referenced_by_single_fk() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Both tables exist
-            assert!(db.table(None, "parent").is_some());
-            assert!(db.table(None, "child").is_some());
-
-            // Parent cannot be dropped (referenced)
-            let drop_parent = format!("{sql}\nDROP TABLE parent;");
-            assert!(matches!(
-                ParserDB::parse::<GenericDialect>(&drop_parent),
-                Err(Error::TableReferenced { table_name }) if table_name == "parent"
-            ));
-
-            // Child can be dropped (not referenced)
-            let drop_child = format!("{sql}\nDROP TABLE child;");
-            assert!(ParserDB::parse::<GenericDialect>(&drop_child).is_ok());
-        }
-
-        #[test]
-        fn test_table_referenced_by_multiple_fks() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child1 (id INT, parent_id INT REFERENCES parent(id));
-                CREATE TABLE child2 (id INT, parent_id INT REFERENCES parent(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(db.table(None, "parent").is_some());
-
-            // Parent cannot be dropped
-            let drop_sql = format!("{sql}\nDROP TABLE parent;");
-            assert!(matches!(
-                ParserDB::parse::<GenericDialect>(&drop_sql),
-                Err(Error::TableReferenced { .. })
-            ));
-        }
-
-        #[test]
-        fn test_self_referential_table_not_blocked() {
-            let sql = r"
-                CREATE TABLE tree (id INT PRIMARY KEY, parent_id INT REFERENCES tree(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            assert!(db.table(None, "tree").is_some());
-
-            // Self-referential table CAN be dropped
-            let drop_sql = format!("{sql}\nDROP TABLE tree;");
-            assert!(ParserDB::parse::<GenericDialect>(&drop_sql).is_ok());
-        }
-
-        #[test]
-        fn test_chain_of_references() {
-            let sql = r"
-                CREATE TABLE grandparent (id INT PRIMARY KEY);
-                CREATE TABLE parent (id INT PRIMARY KEY, gp_id INT REFERENCES grandparent(id));
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-            ";
-
-            // Cannot drop grandparent (referenced by parent)
-            let drop_gp = format!("{sql}\nDROP TABLE grandparent;");
-            assert!(matches!(
-                ParserDB::parse::<GenericDialect>(&drop_gp),
-                Err(Error::TableReferenced { table_name }) if table_name == "grandparent"
-            ));
-
-            // Cannot drop parent (referenced by child)
-            let drop_parent = format!("{sql}\nDROP TABLE parent;");
-            assert!(matches!(
-                ParserDB::parse::<GenericDialect>(&drop_parent),
-                Err(Error::TableReferenced { table_name }) if table_name == "parent"
-            ));
-
-            // Can drop child (not referenced)
-            let drop_child = format!("{sql}\nDROP TABLE child;");
-            assert!(ParserDB::parse::<GenericDialect>(&drop_child).is_ok());
-        }
-    }
-
-    mod remove_table_tests {
-        use super::*;
-        use crate::traits::{DatabaseLike, TableLike};
-
-        #[test]
-        fn test_remove_table_removes_columns() {
-            let sql = r"
-                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT, age INT);
-                CREATE TABLE t2 (id INT PRIMARY KEY);
-                DROP TABLE t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // t1 should be gone
-            assert!(db.table(None, "t1").is_none());
-
-            // t2 should still have its column
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.columns(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_remove_table_removes_indices() {
-            let sql = r"
-                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX idx_name ON t1(name);
-                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
-                CREATE INDEX idx_value ON t2(value);
-                DROP TABLE t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // t1's index should be gone, t2's should remain
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.indices(&db).count(), 1);
-
-            // Total indices across all tables should be 1 (only t2's index)
-            let total_indices: usize = db.tables().map(|t| t.indices(&db).count()).sum();
-            assert_eq!(total_indices, 1);
-        }
-
-        #[test]
-        fn test_remove_table_removes_foreign_keys() {
-            let sql = r"
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
-                DROP TABLE child;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Parent should exist with no foreign keys (parent doesn't have any FKs
-            // pointing out)
-            let parent = db.table(None, "parent").expect("parent should exist");
-            assert_eq!(parent.foreign_keys(&db).count(), 0);
-
-            // No foreign keys in the database (child's FK was removed with child)
-            let total_fks: usize = db.tables().map(|t| t.foreign_keys(&db).count()).sum();
-            assert_eq!(total_fks, 0);
-        }
-
-        #[test]
-        fn test_remove_table_removes_check_constraints() {
-            let sql = r"
-                CREATE TABLE t1 (id INT CHECK (id > 0));
-                CREATE TABLE t2 (value INT CHECK (value < 100));
-                DROP TABLE t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Only t2's check constraint should remain
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.check_constraints(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_remove_table_removes_triggers() {
-            let sql = r"
-                CREATE TABLE t1 (id INT);
-                CREATE TABLE t2 (id INT);
-                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER trg1 BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION fn1();
-                CREATE TRIGGER trg2 BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION fn2();
-                DROP TABLE t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Only t2's trigger should remain
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.triggers(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_remove_table_removes_policies() {
-            let sql = r"
-                CREATE TABLE t1 (id INT);
-                CREATE TABLE t2 (id INT);
-                CREATE POLICY p1 ON t1 USING (true);
-                CREATE POLICY p2 ON t2 USING (true);
-                DROP TABLE t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
-
-            // Only t2's policy should remain
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.policies(&db).count(), 1);
-        }
-    }
-
-    mod drop_index_tests {
-        use super::*;
-
-        #[test]
-        fn test_drop_index_basic() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX idx_name ON t(name);
-                DROP INDEX idx_name;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Index should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.indices(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_index_if_exists_when_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX idx_name ON t(name);
-                DROP INDEX IF EXISTS idx_name;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Index should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.indices(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_index_if_exists_when_not_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY);
-                DROP INDEX IF EXISTS nonexistent_idx;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Should succeed without error
-            assert!(db.table(None, "t").is_some());
-        }
-
-        #[test]
-        fn test_drop_index_not_found_error_type() {
-            let sql = "DROP INDEX nonexistent_idx;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropIndexNotFound { index_name }) if index_name == "nonexistent_idx"
-            ));
-        }
-
-        #[test]
-        fn test_drop_multiple_indices() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, age INT);
-                CREATE INDEX idx_name ON t(name);
-                CREATE INDEX idx_age ON t(age);
-                DROP INDEX idx_name;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Only idx_age should remain
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.indices(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_index_then_recreate() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX idx_name ON t(name);
-                DROP INDEX idx_name;
-                CREATE INDEX idx_name ON t(name);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Index should exist again
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.indices(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_index_keeps_other_table_indices() {
-            let sql = r"
-                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
-                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
-                CREATE INDEX idx_t1_name ON t1(name);
-                CREATE INDEX idx_t2_value ON t2(value);
-                DROP INDEX idx_t1_name;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // t1 should have no indices
-            let t1 = db.table(None, "t1").expect("t1 should exist");
-            assert_eq!(t1.indices(&db).count(), 0);
-
-            // t2 should still have its index
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.indices(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_index_table_still_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, age INT);
-                CREATE INDEX idx_name ON t(name);
-                DROP INDEX idx_name;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Table should still exist with its columns
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.columns(&db).count(), 3);
-        }
-    }
-
-    mod index_enumeration_tests {
-        use super::*;
-        use crate::traits::IndexLike;
-
-        #[test]
-        fn test_indexes_enumerates_named_indexes() {
-            let sql = r"
-                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX idx_name ON t1(name);
-                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
-                CREATE INDEX idx_value ON t2(value);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let names: Vec<String> =
-                db.indexes().filter_map(|i| i.name().map(ToString::to_string)).collect();
-            assert_eq!(names, vec!["idx_name", "idx_value"]);
-        }
-
-        #[test]
-        fn test_anonymous_index_has_no_name() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
-                CREATE INDEX ON t(name);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let index = db.indexes().next().expect("index should exist");
-            assert!(index.name().is_none());
-        }
-
-        #[test]
-        fn test_indexes_excludes_unique_constraints() {
-            let sql = r"
-                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, UNIQUE (name));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            assert_eq!(db.indexes().count(), 0);
-
-            // A unique constraint viewed as an `IndexLike` exposes no
-            // `ObjectName` name accessor; its index name is an `Ident`.
-            let table = db.table(None, "t").expect("table should exist");
-            for ui in table.unique_indices(&db) {
-                assert!(IndexLike::name(ui).is_none());
-            }
-        }
-    }
-
-    mod drop_trigger_tests {
-        use super::*;
-
-        #[test]
-        fn test_drop_trigger_basic() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-                DROP TRIGGER my_trigger ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Trigger should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.triggers(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_trigger_if_exists_when_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-                DROP TRIGGER IF EXISTS my_trigger ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Trigger should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.triggers(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_trigger_if_exists_when_not_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                DROP TRIGGER IF EXISTS nonexistent_trigger ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Should succeed without error
-            assert!(db.table(None, "t").is_some());
-        }
-
-        #[test]
-        fn test_drop_trigger_not_found_error_type() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                DROP TRIGGER nonexistent_trigger ON t;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropTriggerNotFound { trigger_name }) if trigger_name == "nonexistent_trigger"
-            ));
-        }
-
-        #[test]
-        fn test_drop_one_of_multiple_triggers() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER trigger1 BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION fn1();
-                CREATE TRIGGER trigger2 BEFORE UPDATE ON t FOR EACH ROW EXECUTE FUNCTION fn2();
-                DROP TRIGGER trigger1 ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Only trigger2 should remain
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.triggers(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_trigger_then_recreate() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-                DROP TRIGGER my_trigger ON t;
-                CREATE TRIGGER my_trigger AFTER INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Trigger should exist again
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.triggers(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_trigger_keeps_other_table_triggers() {
-            let sql = r"
-                CREATE TABLE t1 (id INT);
-                CREATE TABLE t2 (id INT);
-                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER trigger1 BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION fn1();
-                CREATE TRIGGER trigger2 BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION fn2();
-                DROP TRIGGER trigger1 ON t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // t1 should have no triggers
-            let t1 = db.table(None, "t1").expect("t1 should exist");
-            assert_eq!(t1.triggers(&db).count(), 0);
-
-            // t2 should still have its trigger
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.triggers(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_trigger_function_still_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
-                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
-                DROP TRIGGER my_trigger ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Function should still exist after dropping trigger
-            assert!(db.function("trigger_fn").is_some());
-        }
-    }
-
-    mod drop_policy_tests {
-        use super::*;
-
-        #[test]
-        fn test_drop_policy_basic() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE POLICY my_policy ON t USING (true);
-                DROP POLICY my_policy ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Policy should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.policies(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_policy_if_exists_when_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE POLICY my_policy ON t USING (true);
-                DROP POLICY IF EXISTS my_policy ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Policy should be removed
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.policies(&db).count(), 0);
-        }
-
-        #[test]
-        fn test_drop_policy_if_exists_when_not_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                DROP POLICY IF EXISTS nonexistent_policy ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Should succeed without error
-            assert!(db.table(None, "t").is_some());
-        }
-
-        #[test]
-        fn test_drop_policy_not_found_error_type() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                DROP POLICY nonexistent_policy ON t;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropPolicyNotFound { policy_name }) if policy_name == "nonexistent_policy"
-            ));
-        }
-
-        #[test]
-        fn test_drop_one_of_multiple_policies() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE POLICY policy1 ON t USING (true);
-                CREATE POLICY policy2 ON t USING (false);
-                DROP POLICY policy1 ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Only policy2 should remain
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.policies(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_policy_then_recreate() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE POLICY my_policy ON t USING (true);
-                DROP POLICY my_policy ON t;
-                CREATE POLICY my_policy ON t USING (false);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Policy should exist again
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.policies(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_policy_keeps_other_table_policies() {
-            let sql = r"
-                CREATE TABLE t1 (id INT);
-                CREATE TABLE t2 (id INT);
-                CREATE POLICY policy1 ON t1 USING (true);
-                CREATE POLICY policy2 ON t2 USING (true);
-                DROP POLICY policy1 ON t1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // t1 should have no policies
-            let t1 = db.table(None, "t1").expect("t1 should exist");
-            assert_eq!(t1.policies(&db).count(), 0);
-
-            // t2 should still have its policy
-            let t2 = db.table(None, "t2").expect("t2 should exist");
-            assert_eq!(t2.policies(&db).count(), 1);
-        }
-
-        #[test]
-        fn test_drop_policy_table_still_exists() {
-            let sql = r"
-                CREATE TABLE t (id INT, name TEXT);
-                CREATE POLICY my_policy ON t USING (true);
-                DROP POLICY my_policy ON t;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Table should still exist with its columns
-            let table = db.table(None, "t").expect("Table should exist");
-            assert_eq!(table.columns(&db).count(), 2);
-        }
-    }
-
-    mod drop_role_tests {
-        use super::*;
-
-        #[test]
-        fn test_drop_role_basic() {
-            let sql = r"
-                CREATE ROLE my_role;
-                DROP ROLE my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Role should be removed
-            assert!(db.role("my_role").is_none());
-        }
-
-        #[test]
-        fn test_drop_role_if_exists_when_exists() {
-            let sql = r"
-                CREATE ROLE my_role;
-                DROP ROLE IF EXISTS my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Role should be removed
-            assert!(db.role("my_role").is_none());
-        }
-
-        #[test]
-        fn test_drop_role_if_exists_when_not_exists() {
-            let sql = r"
-                DROP ROLE IF EXISTS nonexistent_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Should succeed without error
-            assert_eq!(db.roles().count(), 0);
-        }
-
-        #[test]
-        fn test_drop_role_not_found_error_type() {
-            let sql = "DROP ROLE nonexistent_role;";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::DropRoleNotFound { role_name }) if role_name == "nonexistent_role"
-            ));
-        }
-
-        #[test]
-        fn test_drop_role_referenced_by_grant_fails() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON t TO my_role;
-                DROP ROLE my_role;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::RoleReferenced { role_name }) if role_name == "my_role"
-            ));
-        }
-
-        #[test]
-        fn test_drop_role_after_revoking_grant_succeeds() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON t TO my_role;
-                REVOKE SELECT ON t FROM my_role;
-                DROP ROLE my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Should succeed after revoking");
-
-            // Role should be removed
-            assert!(db.role("my_role").is_none());
-        }
-
-        #[test]
-        fn test_drop_one_of_multiple_roles() {
-            let sql = r"
-                CREATE ROLE role1;
-                CREATE ROLE role2;
-                DROP ROLE role1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // role1 should be removed
-            assert!(db.role("role1").is_none());
-
-            // role2 should still exist
-            assert!(db.role("role2").is_some());
-        }
-
-        #[test]
-        fn test_drop_role_then_recreate() {
-            let sql = r"
-                CREATE ROLE my_role;
-                DROP ROLE my_role;
-                CREATE ROLE my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            // Role should exist again
-            assert!(db.role("my_role").is_some());
-        }
-    }
-
-    mod grant_revoke_semantics {
-        use sqlparser::{ast::Action, dialect::PostgreSqlDialect};
-
-        use super::*;
-        use crate::traits::{GrantLike, TableLike};
-
-        #[test]
-        fn test_revoke_partial_privilege_preserves_other_actions() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT, INSERT ON t TO my_role;
-                REVOKE SELECT ON t FROM my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let grants: Vec<_> = db.table_grants().collect();
-            assert_eq!(grants.len(), 1);
-            let grant = grants[0];
-            assert!(!grant.is_all_privileges());
-
-            let remaining_privileges: Vec<_> = grant.privileges(&db).collect();
-            assert_eq!(remaining_privileges.len(), 1);
-            assert!(matches!(remaining_privileges[0], Action::Insert { .. }));
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role = db.role("my_role").expect("Role should exist");
-            assert!(!table.can_select(role, &db), "SELECT should be revoked while INSERT remains");
-            assert!(table.can_insert(role, &db));
-        }
-
-        #[test]
-        fn test_revoke_column_scoped_against_table_wide_action_is_unsupported() {
-            let sql = r"
-                CREATE TABLE t (id INT, name TEXT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON t TO my_role;
-                REVOKE SELECT (id) ON t FROM my_role;
-            ";
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-
-            assert!(matches!(
-                result,
-                Err(Error::UnsupportedRevoke { reason, .. })
-                    if reason.contains("column-scoped REVOKE against a table-wide action grant")
-            ));
-        }
-
-        #[test]
-        fn test_revoke_column_scoped_from_column_scoped_grant_keeps_remaining_columns() {
-            let sql = r"
-                CREATE TABLE t (id INT, name TEXT);
-                CREATE ROLE my_role;
-                GRANT SELECT (id, name) ON t TO my_role;
-                REVOKE SELECT (id) ON t FROM my_role;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
-
-            let grant = db.table_grants().next().expect("Expected a remaining grant");
-            let remaining_privileges: Vec<_> = grant.privileges(&db).collect();
-
-            assert_eq!(remaining_privileges.len(), 1);
-            match remaining_privileges[0] {
-                Action::Select { columns: Some(columns) } => {
-                    assert_eq!(columns.len(), 1);
-                    assert_eq!(columns[0].value, "name");
-                }
-                other => panic!("Expected SELECT with one remaining column, got {other:?}"),
-            }
-        }
-
-        #[test]
-        fn test_revoke_from_first_grantee_keeps_second_grantee_privileges() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE a;
-                CREATE ROLE b;
-                GRANT SELECT ON t TO a, b;
-                REVOKE SELECT ON t FROM a;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role_a = db.role("a").expect("Role a should exist");
-            let role_b = db.role("b").expect("Role b should exist");
-
-            assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
-            assert!(table.can_select(role_b, &db), "SELECT should remain for b");
-        }
-
-        #[test]
-        fn test_revoke_from_second_grantee_keeps_first_grantee_privileges() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE a;
-                CREATE ROLE b;
-                GRANT SELECT ON t TO a, b;
-                REVOKE SELECT ON t FROM b;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role_a = db.role("a").expect("Role a should exist");
-            let role_b = db.role("b").expect("Role b should exist");
-
-            assert!(table.can_select(role_a, &db), "SELECT should remain for a");
-            assert!(!table.can_select(role_b, &db), "SELECT should be revoked for b");
-        }
-
-        #[test]
-        fn test_revoke_partial_privilege_from_one_grantee_preserves_other_grantee_actions() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE a;
-                CREATE ROLE b;
-                GRANT SELECT, INSERT ON t TO a, b;
-                REVOKE SELECT ON t FROM a;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role_a = db.role("a").expect("Role a should exist");
-            let role_b = db.role("b").expect("Role b should exist");
-
-            assert_eq!(db.table_grants().count(), 2);
-            assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
-            assert!(table.can_insert(role_a, &db), "INSERT should remain for a");
-            assert!(table.can_select(role_b, &db), "SELECT should remain for b");
-            assert!(table.can_insert(role_b, &db), "INSERT should remain for b");
-        }
-
-        #[test]
-        fn test_revoke_all_from_one_grantee_preserves_other_grantee_actions() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE a;
-                CREATE ROLE b;
-                GRANT SELECT, INSERT ON t TO a, b;
-                REVOKE ALL ON t FROM a;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role_a = db.role("a").expect("Role a should exist");
-            let role_b = db.role("b").expect("Role b should exist");
-
-            assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
-            assert!(!table.can_insert(role_a, &db), "INSERT should be revoked for a");
-            assert!(table.can_select(role_b, &db), "SELECT should remain for b");
-            assert!(table.can_insert(role_b, &db), "INSERT should remain for b");
-        }
-
-        #[test]
-        fn test_revoke_all_removes_matching_grants() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT, INSERT ON t TO my_role;
-                REVOKE ALL ON t FROM my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            assert_eq!(db.table_grants().count(), 0);
-            assert_eq!(db.column_grants().count(), 0);
-        }
-
-        #[test]
-        fn test_public_grant_applies_to_non_public_role() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE app_user;
-                GRANT SELECT ON t TO PUBLIC;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let app_user = db.role("app_user").expect("Role should exist");
-            assert!(table.can_select(app_user, &db));
-        }
-
-        #[test]
-        fn test_schema_qualified_grant_applies_only_to_target_table() {
-            let sql = r"
-                CREATE SCHEMA s1;
-                CREATE SCHEMA s2;
-                CREATE TABLE s1.t (id INT);
-                CREATE TABLE s2.t (id INT);
-                CREATE ROLE app_role;
-                GRANT SELECT ON s1.t TO app_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
-
-            let role = db.role("app_role").expect("Role should exist");
-            let s1_t = db.table(Some("s1"), "t").expect("s1.t should exist");
-            let s2_t = db.table(Some("s2"), "t").expect("s2.t should exist");
-
-            assert!(s1_t.can_select(role, &db));
-            assert!(!s2_t.can_select(role, &db));
-        }
-
-        #[test]
-        fn test_revoke_object_matching_is_case_insensitive_for_unquoted_identifiers() {
-            let sql = r"
-                CREATE TABLE T (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON T TO my_role;
-                REVOKE SELECT ON t FROM my_role;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
-
-            let table = db.table(None, "t").expect("Table should exist");
-            let role = db.role("my_role").expect("Role should exist");
-            assert!(!table.can_select(role, &db));
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        #[test]
-        fn test_revoke_object_matching_preserves_quoted_identifier_semantics() {
-            let sql = r#"
-                CREATE TABLE T (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON T TO my_role;
-                REVOKE SELECT ON "T" FROM my_role;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        #[test]
-        fn test_revoke_object_matching_does_not_match_quoted_grant_with_unquoted_lookup() {
-            let sql = r#"
-                CREATE TABLE "T" (id INT);
-                CREATE ROLE my_role;
-                GRANT SELECT ON "T" TO my_role;
-                REVOKE SELECT ON t FROM my_role;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        #[test]
-        fn test_revoke_function_object_matching_is_case_insensitive_for_unquoted_identifiers() {
-            let sql = r"
-                CREATE ROLE my_role;
-                GRANT EXECUTE ON FUNCTION F() TO my_role;
-                REVOKE EXECUTE ON FUNCTION f() FROM my_role;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
-
-            assert_eq!(db.table_grants().count(), 0);
-            assert_eq!(db.column_grants().count(), 0);
-        }
-
-        #[test]
-        fn test_revoke_function_object_matching_preserves_quoted_identifier_semantics() {
-            let sql = r#"
-                CREATE ROLE my_role;
-                GRANT EXECUTE ON FUNCTION F() TO my_role;
-                REVOKE EXECUTE ON FUNCTION "F"() FROM my_role;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        #[test]
-        fn test_revoke_function_object_matching_does_not_match_quoted_grant_with_unquoted_lookup() {
-            let sql = r#"
-                CREATE ROLE my_role;
-                GRANT EXECUTE ON FUNCTION "F"() TO my_role;
-                REVOKE EXECUTE ON FUNCTION f() FROM my_role;
-            "#;
-            let result = ParserDB::parse::<PostgreSqlDialect>(sql);
-
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        // ----------------------------------------------------------------
-        // Coverage-targeted tests for `impls/sqlparser/grant.rs`:
-        // `partition_grantees_for_revoke`, `apply_revoke_to_grant`,
-        // `grant_objects_inner_match`, the per-column accessor branches,
-        // and the GrantObjects variants the existing tests don't reach.
-        // ----------------------------------------------------------------
-
-        /// `apply_revoke_to_grant` `(Privileges::All, Privileges::Actions)`
-        /// arm: the implementation cannot represent "ALL minus X" in
-        /// its grant model, so a partial revoke against a `GRANT ALL
-        /// PRIVILEGES` is treated as matched-but-no-op — the grant
-        /// stays intact at ALL PRIVILEGES. This is a documented
-        /// limitation in `impls/sqlparser/grant.
-        /// rs::apply_revoke_to_grant`.
-        #[test]
-        fn test_revoke_select_against_all_privileges_is_a_documented_noop() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE my_role;
-                GRANT ALL PRIVILEGES ON t TO my_role;
-                REVOKE SELECT ON t FROM my_role;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-
-            let grant = db.table_grants().next().expect("grant must remain");
-            assert!(
-                grant.is_all_privileges(),
-                "grant stays ALL PRIVILEGES; the model cannot represent ALL-minus-Actions",
-            );
-        }
-
-        /// `tables()` `GrantObjects::AllTablesInSchema` branch: a grant on
-        /// `ALL TABLES IN SCHEMA` should parse and produce one entry in the
-        /// `table_grants` iterator without panicking.
-        #[test]
-        fn test_grant_all_tables_in_schema_parses_and_is_indexed() {
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE TABLE s.t (id INT);
-                CREATE ROLE r;
-                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 1);
-        }
-
-        /// `grant_objects_inner_match` Function arm: an EXECUTE grant on a
-        /// concrete function object lands in `table_grants`.
-        #[test]
-        fn test_grant_execute_on_function() {
-            let sql = r"
-                CREATE FUNCTION f(x INT) RETURNS INT AS 'SELECT $1';
-                CREATE ROLE r;
-                GRANT EXECUTE ON FUNCTION f(INT) TO r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 1);
-        }
-
-        /// `apply_revoke_to_grant`'s object-mismatch fast-return: revoking
-        /// against a different table than the grant covers must leave the
-        /// original grant untouched and itself be a no-op (no error).
-        #[test]
-        fn test_revoke_on_different_table_leaves_original_grant_untouched() {
-            let sql = r"
-                CREATE TABLE t1 (id INT);
-                CREATE TABLE t2 (id INT);
-                CREATE ROLE r;
-                GRANT SELECT ON t1 TO r;
-                REVOKE SELECT ON t2 FROM r;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-            // A REVOKE that matches no grant returns `RevokeNotFound`.
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        /// `partition_grantees_for_revoke` grantee-mismatch path: revoking
-        /// from a role that doesn't appear as a grantee on the matching
-        /// grant must surface as `RevokeNotFound`.
-        #[test]
-        fn test_revoke_from_different_grantee_returns_not_found() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE r1;
-                CREATE ROLE r2;
-                GRANT SELECT ON t TO r1;
-                REVOKE SELECT ON t FROM r2;
-            ";
-            let result = ParserDB::parse::<GenericDialect>(sql);
-            assert!(matches!(result, Err(Error::RevokeNotFound(_))));
-        }
-
-        /// `columns()` per-column INSERT/UPDATE/REFERENCES arms: a multi-
-        /// action column-scoped grant must surface column grants for each
-        /// privileged action.
-        #[test]
-        fn test_grant_per_column_insert_update_references_creates_column_grants() {
-            let sql = r"
-                CREATE TABLE t (a INT, b INT);
-                CREATE ROLE r;
-                GRANT INSERT (a), UPDATE (b), REFERENCES (a) ON t TO r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            // Each per-column action creates its own column grant.
-            assert!(db.column_grants().count() >= 1, "at least one column grant expected");
-        }
-
-        /// `apply_revoke_to_grant`'s "drop the whole grant when no actions
-        /// remain" path: REVOKE ALL from a single-grantee grant removes
-        /// the grant entirely.
-        #[test]
-        fn test_revoke_all_privileges_from_only_grantee_drops_grant() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE r;
-                GRANT SELECT, INSERT ON t TO r;
-                REVOKE ALL PRIVILEGES ON t FROM r;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `apply_revoke_action_to_grant_action` partial-column path: a
-        /// column-scoped revoke that drops some columns but keeps others
-        /// must preserve the remaining columns under the same action.
-        #[test]
-        fn test_revoke_subset_of_column_scoped_grant_keeps_unrevoked_columns() {
-            let sql = r"
-                CREATE TABLE t (a INT, b INT, c INT);
-                CREATE ROLE r;
-                GRANT SELECT (a, b, c) ON t TO r;
-                REVOKE SELECT (b) ON t FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-
-            let grant = db.table_grants().next().expect("grant must remain");
-            let privileges: Vec<_> = grant.privileges(&db).collect();
-            assert_eq!(privileges.len(), 1);
-            match privileges[0] {
-                Action::Select { columns: Some(columns) } => {
-                    let names: Vec<_> = columns.iter().map(|c| c.value.as_str()).collect();
-                    assert_eq!(names, vec!["a", "c"], "only `b` should be revoked");
-                }
-                other => panic!("expected SELECT with columns, got {other:?}"),
-            }
-        }
-
-        /// `partition_grantees_for_revoke` multi-grantee path: revoking
-        /// from one of two grantees must keep the grant alive for the
-        /// other grantee (covers the `unmatched` partition + split).
-        #[test]
-        fn test_revoke_select_from_one_of_two_grantees_keeps_grant_for_other() {
-            let sql = r"
-                CREATE TABLE t (id INT);
-                CREATE ROLE r1;
-                CREATE ROLE r2;
-                GRANT SELECT ON t TO r1, r2;
-                REVOKE SELECT ON t FROM r1;
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-
-            let table = db.table(None, "t").unwrap();
-            let r1 = db.role("r1").unwrap();
-            let r2 = db.role("r2").unwrap();
-            assert!(!table.can_select(r1, &db), "r1 should have had SELECT revoked");
-            assert!(table.can_select(r2, &db), "r2 should still have SELECT");
-        }
-
-        /// `grant_objects_inner_match` schema-list arm: REVOKE on
-        /// `ALL TABLES IN SCHEMA` matching a same-shape GRANT goes
-        /// through the giant merged arm that covers AllSequences/
-        /// AllTables/AllViews/AllMaterializedViews/... InSchema.
-        #[test]
-        fn test_revoke_all_tables_in_schema_matches_corresponding_grant() {
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE TABLE s.t (id INT);
-                CREATE ROLE r;
-                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
-                REVOKE SELECT ON ALL TABLES IN SCHEMA s FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0, "matching revoke removes the grant");
-        }
-
-        /// `grant_objects_inner_match` Schemas object-list arm:
-        /// GRANT USAGE ON SCHEMA + matching REVOKE traverses the
-        /// merged ObjectName-list arm.
-        #[test]
-        fn test_revoke_usage_on_schema_matches_grant() {
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE ROLE r;
-                GRANT USAGE ON SCHEMA s TO r;
-                REVOKE USAGE ON SCHEMA s FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `grant_objects_inner_match` Function arm: REVOKE on a
-        /// specific function-by-signature matches the corresponding
-        /// GRANT EXECUTE on that function.
-        #[test]
-        fn test_revoke_execute_on_function_matches_grant() {
-            let sql = r"
-                CREATE FUNCTION f(x INT) RETURNS INT AS 'SELECT $1';
-                CREATE ROLE r;
-                GRANT EXECUTE ON FUNCTION f(INT) TO r;
-                REVOKE EXECUTE ON FUNCTION f(INT) FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `applies_to_table` AllTablesInSchema branch: querying whether
-        /// an `ALL TABLES IN SCHEMA s` grant applies to a specific table
-        /// returns true iff the table's schema matches.
-        #[test]
-        fn test_all_tables_in_schema_grant_applies_to_matching_table() {
-            use crate::traits::TableGrantLike;
-
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE TABLE s.in_scope (id INT);
-                CREATE TABLE out_of_scope (id INT);
-                CREATE ROLE r;
-                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            let grant = db.table_grants().next().expect("grant");
-            let in_scope = db.table(Some("s"), "in_scope").expect("in_scope table");
-            let out_of_scope = db.table(None, "out_of_scope").expect("out_of_scope table");
-
-            assert!(grant.applies_to_table(in_scope, &db));
-            assert!(!grant.applies_to_table(out_of_scope, &db));
-        }
-
-        /// `grant_objects_inner_match` Sequences object-list arm:
-        /// GRANT USAGE ON SEQUENCE + matching REVOKE traverses the
-        /// merged ObjectName-list arm (covers another row of the merged
-        /// pattern at lines ~239–261).
-        #[test]
-        fn test_revoke_usage_on_sequence_matches_grant() {
-            let sql = r"
-                CREATE ROLE r;
-                GRANT USAGE ON SEQUENCE my_seq TO r;
-                REVOKE USAGE ON SEQUENCE my_seq FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `grant_objects_inner_match` All-future-tables-in-schema arm.
-        #[test]
-        fn test_revoke_all_sequences_in_schema_matches_grant() {
-            let sql = r"
-                CREATE SCHEMA s;
-                CREATE ROLE r;
-                GRANT USAGE ON ALL SEQUENCES IN SCHEMA s TO r;
-                REVOKE USAGE ON ALL SEQUENCES IN SCHEMA s FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `grant_objects_inner_match` fallback arm (`(left, right) =>
-        /// left == right`): a GRANT and REVOKE on the same simple
-        /// pseudo-object that doesn't match a specific variant goes
-        /// through the structural-equality arm.
-        #[test]
-        fn test_revoke_on_database_object_matches_grant() {
-            let sql = r"
-                CREATE ROLE r;
-                GRANT CREATE ON DATABASE my_db TO r;
-                REVOKE CREATE ON DATABASE my_db FROM r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            assert_eq!(db.table_grants().count(), 0);
-        }
-
-        /// `ColumnGrantLike::columns` per-action branches: a multi-action
-        /// column grant (`INSERT (a), UPDATE (b), REFERENCES (c)`) surfaces
-        /// the union of columns across all three arms in `columns()`.
-        #[test]
-        fn test_column_grant_columns_iterator_covers_all_action_arms() {
-            use crate::traits::ColumnGrantLike;
-
-            let sql = r"
-                CREATE TABLE t (a INT, b INT, c INT);
-                CREATE ROLE r;
-                GRANT INSERT (a), UPDATE (b), REFERENCES (c) ON t TO r;
-            ";
-            let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
-            let table = db.table(None, "t").expect("table");
-
-            // Iterate every column grant and call `.columns(table, &db)`.
-            // This routes through the Insert/Update/References match
-            // arms in `impls/sqlparser/grant.rs::columns`.
-            let mut all_cols: Vec<&str> = Vec::new();
-            for cg in db.column_grants() {
-                for col in cg.columns(table, &db) {
-                    all_cols.push(col.column_name());
-                }
-            }
-            all_cols.sort_unstable();
-            all_cols.dedup();
-            // The three actions reference three distinct columns.
-            assert!(all_cols.contains(&"a"));
-            assert!(all_cols.contains(&"b"));
-            assert!(all_cols.contains(&"c"));
-        }
-    }
-
-    mod foreign_key_target_validation {
-        use sqlparser::dialect::PostgreSqlDialect;
-
-        use super::*;
-
-        #[test]
-        fn column_option_reference_to_existing_target_validates() {
-            let sql = "
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            assert!(db.validate_foreign_key_targets().is_ok());
-        }
-
-        #[test]
-        fn table_constraint_reference_to_existing_target_validates() {
-            let sql = "
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (
-                    id INT PRIMARY KEY,
-                    parent_id INT,
-                    FOREIGN KEY (parent_id) REFERENCES parent(id)
-                );
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            assert!(db.validate_foreign_key_targets().is_ok());
-        }
-
-        #[test]
-        fn reference_to_missing_table_errors_naming_target_and_host() {
-            let sql = "
-                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES orders(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            match db.validate_foreign_key_targets() {
-                Err(Error::ReferencedTableNotFoundForForeignKey {
-                    referenced_table,
-                    host_table,
-                }) => {
-                    assert_eq!(referenced_table, "orders");
-                    assert_eq!(host_table, "child");
-                }
-                other => panic!("expected dangling-table error, got {other:?}"),
-            }
-        }
-
-        #[test]
-        fn reference_to_missing_column_errors_naming_column() {
-            let sql = "
-                CREATE TABLE parent (id INT PRIMARY KEY);
-                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(missing));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            match db.validate_foreign_key_targets() {
-                Err(Error::ReferencedColumnNotFoundForForeignKey {
-                    referenced_column,
-                    referenced_table,
-                    host_table,
-                }) => {
-                    assert_eq!(referenced_column, "missing");
-                    assert_eq!(referenced_table, "parent");
-                    assert_eq!(host_table, "child");
-                }
-                other => panic!("expected dangling-column error, got {other:?}"),
-            }
-        }
-
-        #[test]
-        fn bare_and_public_qualified_targets_resolve_identically() {
-            let bare = "
-                CREATE TABLE public.parent (id INT PRIMARY KEY);
-                CREATE TABLE public.child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
-            ";
-            let qualified = "
-                CREATE TABLE public.parent (id INT PRIMARY KEY);
-                CREATE TABLE public.child (
-                    id INT PRIMARY KEY,
-                    parent_id INT REFERENCES public.parent(id)
-                );
-            ";
-            let bare_db = ParserDB::parse::<PostgreSqlDialect>(bare).expect("parse");
-            let qualified_db = ParserDB::parse::<PostgreSqlDialect>(qualified).expect("parse");
-            assert!(bare_db.validate_foreign_key_targets().is_ok());
-            assert!(qualified_db.validate_foreign_key_targets().is_ok());
-        }
-
-        #[test]
-        fn forward_reference_validates() {
-            let sql = "
-                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
-                CREATE TABLE parent (id INT PRIMARY KEY);
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            assert!(db.validate_foreign_key_targets().is_ok());
-        }
-
-        #[test]
-        fn self_referential_reference_validates() {
-            let sql = "
-                CREATE TABLE tree (
-                    id INT PRIMARY KEY,
-                    parent_id INT REFERENCES tree(id)
-                );
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            assert!(db.validate_foreign_key_targets().is_ok());
-        }
-
-        #[test]
-        fn multiple_dangling_constraints_report_deterministic_first_error() {
-            let sql = "
-                CREATE TABLE a (id INT PRIMARY KEY, x INT REFERENCES missing_a(id));
-                CREATE TABLE b (id INT PRIMARY KEY, y INT REFERENCES missing_b(id));
-            ";
-            let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
-            let first = db.validate_foreign_key_targets().expect_err("dangling FKs must error");
-            let second = db.validate_foreign_key_targets().expect_err("dangling FKs must error");
-            assert_eq!(format!("{first}"), format!("{second}"));
-            match first {
-                Error::ReferencedTableNotFoundForForeignKey { referenced_table, .. } => {
-                    assert_eq!(referenced_table, "missing_a");
-                }
-                other => panic!("expected dangling-table error, got {other:?}"),
-            }
-        }
-    }
-}
+mod tests;
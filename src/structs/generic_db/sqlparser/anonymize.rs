@@ -0,0 +1,680 @@
+//! Anonymization of a [`ParserDB`], replacing its identifiers with stable
+//! pseudonyms and redacting its string literals.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::Infallible, ops::ControlFlow};
+
+use sqlparser::ast::{
+    Action, ArrayElemTypeDef, ColumnOption, DataType, Expr, GrantObjects, GranteeName, Ident,
+    ObjectName, ObjectNamePart, Owner, Privileges, SchemaName, Statement, TableConstraint,
+    UserDefinedTypeRepresentation, Value, VisitMut, VisitorMut,
+};
+
+use super::{ParserDB, no_inherit, unlogged_sequences};
+use crate::{
+    structs::{AnonymizeOptions, IdentifierCasing},
+    traits::{DatabaseLike, DialectLike},
+};
+
+/// Name of the schema left untouched, as every database shares it.
+const DEFAULT_SCHEMA: &str = "public";
+
+/// The namespaces pseudonyms are assigned in, so that an object and a column
+/// sharing a name are told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Namespace {
+    /// Schemas.
+    Schema,
+    /// Tables, views, sequences and indices.
+    Relation,
+    /// Columns of tables and views.
+    Column,
+    /// Constraints, triggers and policies.
+    Constraint,
+    /// User-defined types and domains.
+    Type,
+    /// User-defined functions.
+    Function,
+    /// Roles.
+    Role,
+    /// String literals.
+    Literal,
+}
+
+/// Rewrites the statements rendered from a database.
+///
+/// Statements are walked twice: the first walk assigns a pseudonym to every
+/// declared object, in declaration order, and the second one replaces the
+/// declarations and the references to them, so that references preceding
+/// their declaration are renamed as well.
+struct Anonymizer {
+    /// The options of the anonymization.
+    options: AnonymizeOptions,
+    /// The casing the names are normalized under.
+    casing: IdentifierCasing,
+    /// The pseudonyms of the normalized names in each namespace.
+    pseudonyms: BTreeMap<(Namespace, String), String>,
+    /// The number of pseudonyms assigned with each prefix.
+    counts: BTreeMap<&'static str, usize>,
+    /// Whether the walk assigns pseudonyms rather than replacing names.
+    declaring: bool,
+    /// Whether the expressions walked belong to a domain, where the `VALUE`
+    /// identifier does not refer to a column.
+    in_domain: bool,
+}
+
+/// Returns the identifiers making up an object name.
+fn object_name_parts(name: &mut ObjectName) -> Vec<&mut Ident> {
+    name.0
+        .iter_mut()
+        .map(|part| match part {
+            ObjectNamePart::Identifier(ident) => ident,
+            ObjectNamePart::Function(function_part) => &mut function_part.name,
+        })
+        .collect()
+}
+
+/// Returns the name of a table constraint, if it has one.
+fn constraint_name(constraint: &mut TableConstraint) -> Option<&mut Ident> {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => primary_key.name.as_mut(),
+        TableConstraint::Unique(unique) => unique.name.as_mut(),
+        TableConstraint::ForeignKey(foreign_key) => foreign_key.name.as_mut(),
+        TableConstraint::Check(check) => check.name.as_mut(),
+        _ => None,
+    }
+}
+
+impl Anonymizer {
+    /// Creates an anonymizer with the provided options, normalizing names
+    /// under the provided casing.
+    fn new(options: AnonymizeOptions, casing: IdentifierCasing) -> Self {
+        Self {
+            options,
+            casing,
+            pseudonyms: BTreeMap::new(),
+            counts: BTreeMap::new(),
+            declaring: options.renames_identifiers(),
+            in_domain: false,
+        }
+    }
+
+    /// Returns a new pseudonym with the provided prefix.
+    fn next_pseudonym(&mut self, prefix: &'static str) -> String {
+        let count = self.counts.entry(prefix).or_default();
+        *count += 1;
+        format!("{prefix}_{count}")
+    }
+
+    /// Returns the pseudonym of a normalized name, if it was declared.
+    fn pseudonym(&self, namespace: Namespace, name: &str, quoted: bool) -> Option<&String> {
+        self.pseudonyms.get(&(namespace, self.casing.normalize(name, quoted).into_owned()))
+    }
+
+    /// Returns whether the normalized name is the `public` schema.
+    fn is_default_schema(&self, name: &str) -> bool {
+        name == self.casing.normalize(DEFAULT_SCHEMA, false)
+    }
+
+    /// Assigns a pseudonym to a declared identifier while declaring, or
+    /// replaces it with its pseudonym otherwise.
+    fn declare(&mut self, namespace: Namespace, prefix: &'static str, ident: &mut Ident) {
+        if !self.declaring {
+            self.rename(namespace, ident);
+            return;
+        }
+        let name = self.casing.normalize(&ident.value, ident.quote_style.is_some()).into_owned();
+        if (namespace == Namespace::Schema && self.is_default_schema(&name))
+            || self.pseudonyms.contains_key(&(namespace, name.clone()))
+        {
+            return;
+        }
+        let pseudonym = self.next_pseudonym(prefix);
+        self.pseudonyms.insert((namespace, name), pseudonym);
+    }
+
+    /// Replaces an identifier with its pseudonym, if it was declared.
+    fn rename(&mut self, namespace: Namespace, ident: &mut Ident) {
+        if self.declaring {
+            return;
+        }
+        if let Some(pseudonym) =
+            self.pseudonym(namespace, &ident.value, ident.quote_style.is_some())
+        {
+            ident.value.clone_from(pseudonym);
+            ident.quote_style = None;
+        }
+    }
+
+    /// Replaces the qualifiers of a path, namely the table of a column and
+    /// the schema of an object.
+    fn rename_qualifiers(&mut self, namespace: Namespace, qualifiers: Vec<&mut Ident>) {
+        let parents: &[Namespace] = if namespace == Namespace::Column {
+            &[Namespace::Relation, Namespace::Schema]
+        } else {
+            &[Namespace::Schema]
+        };
+        for (ident, parent) in qualifiers.into_iter().rev().zip(parents) {
+            self.rename(*parent, ident);
+        }
+    }
+
+    /// Replaces the identifiers of a path referencing an object.
+    fn rename_path(&mut self, namespace: Namespace, mut path: Vec<&mut Ident>) {
+        if let Some(last) = path.pop() {
+            self.rename(namespace, last);
+        }
+        self.rename_qualifiers(namespace, path);
+    }
+
+    /// Replaces the identifiers of an object name referencing an object.
+    fn rename_name(&mut self, namespace: Namespace, name: &mut ObjectName) {
+        self.rename_path(namespace, object_name_parts(name));
+    }
+
+    /// Declares the object named by an object name.
+    fn declare_name(&mut self, namespace: Namespace, prefix: &'static str, name: &mut ObjectName) {
+        let mut path = object_name_parts(name);
+        if let Some(last) = path.pop() {
+            self.declare(namespace, prefix, last);
+        }
+        self.rename_qualifiers(namespace, path);
+    }
+
+    /// Replaces the user-defined types referenced by a data type.
+    fn rename_data_type(&mut self, data_type: &mut DataType) {
+        match data_type {
+            DataType::Custom(name, _) => self.rename_name(Namespace::Type, name),
+            DataType::Array(
+                ArrayElemTypeDef::AngleBracket(inner)
+                | ArrayElemTypeDef::SquareBracket(inner, _)
+                | ArrayElemTypeDef::Parenthesis(inner),
+            ) => self.rename_data_type(inner),
+            _ => {}
+        }
+    }
+
+    /// Returns the pseudonym of a string literal naming a relation, as in
+    /// `nextval('users_id_seq')`, if it was declared.
+    fn renamed_relation(&self, literal: &str) -> Option<String> {
+        let lookup = |namespace: Namespace, part: &str| match part
+            .strip_prefix('"')
+            .and_then(|part| part.strip_suffix('"'))
+        {
+            Some(part) => self.pseudonym(namespace, part, true),
+            None => self.pseudonym(namespace, part, false),
+        };
+        match literal.split('.').collect::<Vec<_>>().as_slice() {
+            [relation] => lookup(Namespace::Relation, relation).cloned(),
+            [schema, relation] => {
+                let relation = lookup(Namespace::Relation, relation)?;
+                if self.is_default_schema(&self.casing.normalize(schema, false)) {
+                    Some(format!("{schema}.{relation}"))
+                } else {
+                    Some(format!("{}.{relation}", lookup(Namespace::Schema, schema)?))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Redacts a string literal, replacing it with a pseudonym shared by the
+    /// occurrences of the same literal.
+    fn redact(&mut self, literal: &mut String) {
+        if !self.options.redacts_literals() {
+            return;
+        }
+        let key = (Namespace::Literal, literal.clone());
+        let pseudonym = match self.pseudonyms.get(&key) {
+            Some(pseudonym) => pseudonym.clone(),
+            None => {
+                let pseudonym = self.next_pseudonym("value");
+                self.pseudonyms.insert(key, pseudonym.clone());
+                pseudonym
+            }
+        };
+        *literal = pseudonym;
+    }
+
+    /// Anonymizes a literal value: strings naming a relation are renamed
+    /// along with it, while other strings are redacted.
+    fn anonymize_value(&mut self, value: &mut Value) {
+        let (Value::SingleQuotedString(literal)
+        | Value::DoubleQuotedString(literal)
+        | Value::EscapedStringLiteral(literal)
+        | Value::NationalStringLiteral(literal)) = value
+        else {
+            return;
+        };
+        match self.renamed_relation(literal) {
+            Some(relation) => *literal = relation,
+            None => self.redact(literal),
+        }
+    }
+
+    /// Replaces the names declared and referenced by a table constraint.
+    fn anonymize_constraint(&mut self, constraint: &mut TableConstraint) {
+        if let Some(name) = constraint_name(constraint) {
+            self.declare(Namespace::Constraint, "constraint", name);
+        }
+        if let TableConstraint::ForeignKey(foreign_key) = constraint {
+            for column in foreign_key.columns.iter_mut().chain(&mut foreign_key.referred_columns) {
+                self.rename(Namespace::Column, column);
+            }
+            self.rename_name(Namespace::Relation, &mut foreign_key.foreign_table);
+        }
+    }
+
+    /// Walks a statement, declaring or replacing the names it declares and
+    /// references.
+    fn anonymize_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::CreateSchema { schema_name, .. } => match schema_name {
+                SchemaName::Simple(name) => self.declare_name(Namespace::Schema, "schema", name),
+                SchemaName::UnnamedAuthorization(role) => self.rename(Namespace::Role, role),
+                SchemaName::NamedAuthorization(name, role) => {
+                    self.declare_name(Namespace::Schema, "schema", name);
+                    self.rename(Namespace::Role, role);
+                }
+            },
+            Statement::CreateRole(create_role) => {
+                for name in &mut create_role.names {
+                    self.declare_name(Namespace::Role, "role", name);
+                }
+                for role in create_role
+                    .in_role
+                    .iter_mut()
+                    .chain(&mut create_role.in_group)
+                    .chain(&mut create_role.role)
+                    .chain(&mut create_role.user)
+                    .chain(&mut create_role.admin)
+                {
+                    self.rename(Namespace::Role, role);
+                }
+            }
+            Statement::CreateExtension(create_extension) => {
+                if let Some(schema) = &mut create_extension.schema {
+                    self.rename(Namespace::Schema, schema);
+                }
+            }
+            Statement::CreateType { name, representation, .. } => {
+                self.declare_name(Namespace::Type, "type", name);
+                if !self.declaring
+                    && let Some(UserDefinedTypeRepresentation::Enum { labels, .. }) = representation
+                {
+                    for label in labels {
+                        self.redact(&mut label.value);
+                    }
+                }
+            }
+            Statement::CreateDomain(create_domain) => {
+                self.declare_name(Namespace::Type, "type", &mut create_domain.name);
+                self.rename_data_type(&mut create_domain.data_type);
+                self.in_domain = true;
+            }
+            Statement::CreateFunction(create_function) => {
+                self.declare_name(Namespace::Function, "function", &mut create_function.name);
+                for argument in create_function.args.iter_mut().flatten() {
+                    self.rename_data_type(&mut argument.data_type);
+                }
+                // Function bodies are kept verbatim.
+                return;
+            }
+            Statement::CreateSequence { name, owned_by, .. } => {
+                self.declare_name(Namespace::Relation, "sequence", name);
+                if let Some(owned_by) = owned_by {
+                    self.rename_name(Namespace::Column, owned_by);
+                }
+            }
+            Statement::CreateTable(create_table) => {
+                self.declare_name(Namespace::Relation, "table", &mut create_table.name);
+                for column in &mut create_table.columns {
+                    self.declare(Namespace::Column, "column", &mut column.name);
+                    self.rename_data_type(&mut column.data_type);
+                    column
+                        .options
+                        .retain(|option| !matches!(option.option, ColumnOption::Comment(_)));
+                    for name in column.options.iter_mut().filter_map(|option| option.name.as_mut())
+                    {
+                        self.declare(Namespace::Constraint, "constraint", name);
+                    }
+                }
+                for constraint in &mut create_table.constraints {
+                    self.anonymize_constraint(constraint);
+                }
+            }
+            Statement::AlterTable(alter_table) => {
+                self.rename_name(Namespace::Relation, &mut alter_table.name);
+            }
+            Statement::CreateIndex(create_index) => {
+                if let Some(name) = &mut create_index.name {
+                    self.declare_name(Namespace::Relation, "index", name);
+                }
+                self.rename_name(Namespace::Relation, &mut create_index.table_name);
+                for column in &mut create_index.include {
+                    self.rename(Namespace::Column, column);
+                }
+            }
+            Statement::CreateView(create_view) => {
+                self.declare_name(Namespace::Relation, "view", &mut create_view.name);
+                for column in &mut create_view.columns {
+                    self.declare(Namespace::Column, "column", &mut column.name);
+                }
+            }
+            Statement::CreateTrigger(create_trigger) => {
+                self.declare_name(Namespace::Constraint, "trigger", &mut create_trigger.name);
+                self.rename_name(Namespace::Relation, &mut create_trigger.table_name);
+                if let Some(exec_body) = &mut create_trigger.exec_body {
+                    self.rename_name(Namespace::Function, &mut exec_body.func_desc.name);
+                }
+            }
+            Statement::CreatePolicy(create_policy) => {
+                self.declare(Namespace::Constraint, "policy", &mut create_policy.name);
+                self.rename_name(Namespace::Relation, &mut create_policy.table_name);
+                for owner in create_policy.to.iter_mut().flatten() {
+                    if let Owner::Ident(role) = owner {
+                        self.rename(Namespace::Role, role);
+                    }
+                }
+            }
+            Statement::Grant(grant) => {
+                if let Privileges::Actions(actions) = &mut grant.privileges {
+                    for action in actions {
+                        if let Action::Select { columns: Some(columns) }
+                        | Action::Insert { columns: Some(columns) }
+                        | Action::Update { columns: Some(columns) }
+                        | Action::References { columns: Some(columns) } = action
+                        {
+                            for column in columns {
+                                self.rename(Namespace::Column, column);
+                            }
+                        }
+                    }
+                }
+                match &mut grant.objects {
+                    Some(GrantObjects::Tables(names) | GrantObjects::Sequences(names)) => {
+                        for name in names {
+                            self.rename_name(Namespace::Relation, name);
+                        }
+                    }
+                    Some(GrantObjects::Schemas(names)) => {
+                        for name in names {
+                            self.rename_name(Namespace::Schema, name);
+                        }
+                    }
+                    _ => {}
+                }
+                for grantee in &mut grant.grantees {
+                    if let Some(GranteeName::ObjectName(name)) = &mut grantee.name {
+                        self.rename_name(Namespace::Role, name);
+                    }
+                }
+                if let Some(granted_by) = &mut grant.granted_by {
+                    self.rename(Namespace::Role, granted_by);
+                }
+            }
+            _ => {}
+        }
+
+        if !self.declaring {
+            let ControlFlow::Continue(()) = statement.visit(self);
+        }
+        self.in_domain = false;
+    }
+}
+
+impl VisitorMut for Anonymizer {
+    type Break = Infallible;
+
+    fn pre_visit_relation(&mut self, relation: &mut ObjectName) -> ControlFlow<Self::Break> {
+        self.rename_name(Namespace::Relation, relation);
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        match expr {
+            Expr::Identifier(ident) if !self.in_domain => self.rename(Namespace::Column, ident),
+            Expr::CompoundIdentifier(idents) => {
+                self.rename_path(Namespace::Column, idents.iter_mut().collect());
+            }
+            Expr::Function(function) => self.rename_name(Namespace::Function, &mut function.name),
+            Expr::Cast { data_type, .. } => self.rename_data_type(data_type),
+            Expr::Value(value) => self.anonymize_value(&mut value.value),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl ParserDB {
+    /// Returns a structurally identical database whose identifiers are
+    /// replaced by stable pseudonyms and whose string literals are redacted,
+    /// so that the schema can be shared without exposing its naming.
+    ///
+    /// The database is rendered with [`ParserDB::to_sql`] in its own dialect,
+    /// and the statements are rewritten before being parsed back:
+    ///
+    /// * Schemas, tables, columns, views, sequences, indices, constraints,
+    ///   triggers, policies, roles, user-defined types and functions are
+    ///   renamed after their kind and declaration order, as in `table_1` or
+    ///   `column_3`. Columns sharing a name share their pseudonym, and the
+    ///   `public` schema keeps its name.
+    /// * String literals of defaults, constraints, policies and views, as well
+    ///   as enumeration labels, are replaced by `value_1`, `value_2` and so on,
+    ///   the same literal always receiving the same pseudonym. Literals naming
+    ///   a relation, as in `nextval('users_id_seq')`, are renamed along with
+    ///   it. Numeric and boolean literals are kept.
+    /// * Documentation and column comments are dropped, while `NO INHERIT`
+    ///   flags are preserved.
+    ///
+    /// The bodies of functions and the aliases used within view queries are
+    /// kept verbatim.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Which of the identifiers and literals are anonymized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rewritten statements fail to parse or to
+    /// validate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{impls::SqlparserDialect, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE customers (id INT PRIMARY KEY, tier TEXT DEFAULT 'gold');
+    /// CREATE TABLE invoices (id INT PRIMARY KEY, customer_id INT REFERENCES customers (id));
+    /// ",
+    /// )?;
+    /// let anonymized = db.anonymize(AnonymizeOptions::default())?;
+    /// let sql = anonymized.to_sql(SqlparserDialect::PostgreSql);
+    /// assert!(sql.starts_with(
+    ///     "CREATE TABLE table_1 (column_1 INT, column_2 TEXT DEFAULT 'value_1', PRIMARY KEY (column_1));"
+    /// ));
+    /// assert!(!sql.contains("customer") && !sql.contains("gold"));
+    /// assert_eq!(anonymized.table(None, "table_2").unwrap().foreign_keys(&anonymized).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn anonymize(&self, options: AnonymizeOptions) -> Result<Self, crate::errors::Error> {
+        let dialect = *DatabaseLike::dialect(self);
+        let (sql, no_inherit_spans) =
+            no_inherit::strip_no_inherit(dialect.sqlparser_dialect(), &self.to_sql(dialect));
+        let (sql, unlogged_spans) =
+            unlogged_sequences::strip_unlogged_sequences(dialect.sqlparser_dialect(), &sql);
+        let mut statements = dialect.parse_statements(&sql)?;
+        let mut anonymizer = Anonymizer::new(options, dialect.identifier_casing());
+        if anonymizer.declaring {
+            for statement in &mut statements {
+                anonymizer.anonymize_statement(statement);
+            }
+            anonymizer.declaring = false;
+        }
+        for statement in &mut statements {
+            anonymizer.anonymize_statement(statement);
+        }
+        let catalog_name = if options.renames_identifiers() {
+            "catalog".to_string()
+        } else {
+            self.catalog_name().to_string()
+        };
+        let no_inherit_constraints = no_inherit::no_inherit_checks(&statements, &no_inherit_spans);
+        let unlogged = unlogged_sequences::unlogged_sequences(&statements, &unlogged_spans);
+        let mut db = Self::from_statements_with_dialect(statements, catalog_name, dialect)?;
+        db.mark_no_inherit_checks(&no_inherit_constraints);
+        db.mark_unlogged_sequences(&unlogged);
+        Ok(db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use crate::{
+        impls::SqlparserDialect,
+        structs::{AnonymizeOptions, ParserDB},
+        traits::{CheckConstraintLike, DatabaseLike, TableLike},
+    };
+
+    const SCHEMA: &str = "
+        CREATE ROLE acme_reader;
+        CREATE SCHEMA acme;
+        CREATE TYPE acme.acme_mood AS ENUM ('hush_happy', 'hush_sad');
+        CREATE SEQUENCE acme.acme_seq;
+        -- Customers of the shop.
+        CREATE TABLE acme.acme_users (
+            acme_id INT PRIMARY KEY DEFAULT nextval('acme.acme_seq'),
+            acme_tier TEXT NOT NULL DEFAULT 'hush_gold',
+            acme_feeling acme.acme_mood,
+            CONSTRAINT acme_tier_check CHECK (acme_tier <> 'hush_none')
+        );
+        CREATE TABLE acme.acme_orders (
+            acme_id INT PRIMARY KEY,
+            acme_user_id INT REFERENCES acme.acme_users (acme_id),
+            acme_note TEXT DEFAULT 'hush_gold'
+        );
+        CREATE INDEX acme_tier_idx ON acme.acme_users (acme_tier);
+        CREATE VIEW acme.acme_active AS SELECT acme_id FROM acme.acme_users WHERE acme_tier = 'hush_gold';
+        CREATE POLICY acme_policy ON acme.acme_users TO acme_reader USING (acme_tier <> 'hush_gold');
+        GRANT SELECT ON acme.acme_users TO acme_reader;
+        ";
+
+    fn parse() -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(SCHEMA).expect("Failed to parse")
+    }
+
+    #[test]
+    fn test_anonymize_hides_identifiers_and_literals() {
+        let db = parse();
+        let anonymized = db.anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        let sql = anonymized.to_sql(SqlparserDialect::PostgreSql);
+        assert!(!sql.contains("acme"), "{sql}");
+        assert!(!sql.contains("hush"), "{sql}");
+        assert!(sql.contains("nextval('schema_1.sequence_1')"), "{sql}");
+        assert!(sql.contains("DEFAULT 'value_"), "{sql}");
+        assert_eq!(anonymized.catalog_name(), "catalog");
+    }
+
+    #[test]
+    fn test_anonymize_preserves_structure() {
+        let db = parse();
+        let anonymized = db.anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        assert_eq!(anonymized.tables().count(), db.tables().count());
+        assert_eq!(anonymized.schemas().count(), db.schemas().count());
+        assert_eq!(anonymized.indexes().count(), db.indexes().count());
+        assert_eq!(anonymized.views().count(), db.views().count());
+        assert_eq!(anonymized.policies().count(), db.policies().count());
+        assert_eq!(anonymized.roles().count(), db.roles().count());
+        assert_eq!(anonymized.table_grants().count(), db.table_grants().count());
+        for (table, anonymized_table) in db.tables().zip(anonymized.tables()) {
+            assert_eq!(table.columns(&db).count(), anonymized_table.columns(&anonymized).count());
+            assert_eq!(
+                table.foreign_keys(&db).count(),
+                anonymized_table.foreign_keys(&anonymized).count()
+            );
+            assert_eq!(
+                table.check_constraints(&db).count(),
+                anonymized_table.check_constraints(&anonymized).count()
+            );
+        }
+    }
+
+    #[test]
+    fn test_anonymize_is_stable() {
+        let anonymized =
+            parse().anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        let sql = anonymized.to_sql(SqlparserDialect::PostgreSql);
+        let again = anonymized.anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        assert_eq!(again.to_sql(SqlparserDialect::PostgreSql), sql);
+    }
+
+    #[test]
+    fn test_anonymize_options_are_honored() {
+        let db = parse();
+        let sql = db.to_sql(SqlparserDialect::PostgreSql);
+        let kept = db
+            .anonymize(AnonymizeOptions::default().keep_identifiers().keep_literals())
+            .expect("Failed to anonymize");
+        assert_eq!(kept.to_sql(SqlparserDialect::PostgreSql), sql);
+
+        let renamed = db
+            .anonymize(AnonymizeOptions::default().keep_literals())
+            .expect("Failed to anonymize")
+            .to_sql(SqlparserDialect::PostgreSql);
+        assert!(!renamed.contains("acme") && renamed.contains("'hush_gold'"), "{renamed}");
+
+        let redacted = db
+            .anonymize(AnonymizeOptions::default().keep_identifiers())
+            .expect("Failed to anonymize")
+            .to_sql(SqlparserDialect::PostgreSql);
+        assert!(redacted.contains("acme_users") && !redacted.contains("hush"), "{redacted}");
+    }
+
+    #[test]
+    fn test_anonymize_preserves_no_inherit() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE acme_parent (acme_id INT, CHECK (acme_id > 0) NO INHERIT);",
+        )
+        .expect("Failed to parse");
+        let anonymized = db.anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        let table = anonymized.table(None, "table_1").unwrap();
+        let flags: Vec<bool> = table
+            .check_constraints(&anonymized)
+            .map(|check| check.no_inherit(&anonymized))
+            .collect();
+        assert_eq!(flags, [true]);
+        let sql: String = anonymized.to_sql(SqlparserDialect::PostgreSql);
+        assert_eq!(sql, "CREATE TABLE table_1 (column_1 INT, CHECK (column_1 > 0) NO INHERIT);\n");
+    }
+
+    #[test]
+    fn test_anonymize_follows_the_casing_of_the_dialect() {
+        let db = ParserDB::parse::<MySqlDialect>(
+            "CREATE TABLE `Acme_Users` (`Acme_Id` INT PRIMARY KEY);
+             CREATE TABLE acme_orders (
+                 acme_id INT PRIMARY KEY,
+                 acme_user_id INT,
+                 FOREIGN KEY (acme_user_id) REFERENCES acme_users (acme_id)
+             );",
+        )
+        .expect("Failed to parse");
+        let anonymized = db.anonymize(AnonymizeOptions::default()).expect("Failed to anonymize");
+        let sql = anonymized.to_sql(SqlparserDialect::MySql);
+        assert!(!sql.to_lowercase().contains("acme"), "{sql}");
+        let orders = anonymized.table(None, "table_2").unwrap();
+        assert_eq!(orders.foreign_keys(&anonymized).count(), 1);
+    }
+}
@@ -0,0 +1,390 @@
+//! Rendering of a [`ParserDB`] back into the DDL statements creating it.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use sqlparser::ast::{
+    ColumnDef, ColumnOption, CreateTable, ForeignKeyConstraint, Ident, Statement, TableConstraint,
+};
+
+use super::{ParserDB, extension_functions::extension_library, is_provided_by_extension};
+use crate::{
+    impls::SqlparserDialect,
+    structs::{CustomType, CustomTypeDefinition, DialectCapability, Sequence, TableAttribute},
+    traits::{
+        CheckConstraintLike, DatabaseLike, DialectLike, ForeignKeyLike, FunctionLike, IndexLike,
+        RoleLike, TableLike, UniqueIndexLike,
+    },
+};
+
+/// Returns the definition of a column without the constraints declared
+/// inline, which are held by its table.
+pub(super) fn column_definition(column: &ColumnDef) -> ColumnDef {
+    let mut column_def = column.clone();
+    column_def.options.retain(|option| {
+        !matches!(
+            option.option,
+            ColumnOption::PrimaryKey(_)
+                | ColumnOption::Unique(_)
+                | ColumnOption::ForeignKey(_)
+                | ColumnOption::Check(_)
+        )
+    });
+    column_def
+}
+
+/// Renders an identifier, quoting it if it was quoted in SQL.
+fn identifier(value: &str, quoted: bool) -> String {
+    if quoted { Ident::with_quote('"', value).to_string() } else { value.to_string() }
+}
+
+/// Renders a possibly schema-qualified name.
+fn qualified_name(schema: Option<(&str, bool)>, name: &str, quoted: bool) -> String {
+    match schema {
+        Some((schema, schema_quoted)) => {
+            alloc::format!("{}.{}", identifier(schema, schema_quoted), identifier(name, quoted))
+        }
+        None => identifier(name, quoted),
+    }
+}
+
+/// Renders the statement creating a sequence.
+fn create_sequence(sequence: &Sequence) -> String {
+    let name = qualified_name(
+        sequence.schema().map(|schema| (schema, sequence.schema_is_quoted())),
+        sequence.name(),
+        sequence.is_quoted(),
+    );
+    let persistence = if sequence.is_temporary() {
+        "TEMPORARY "
+    } else if sequence.is_unlogged() {
+        "UNLOGGED "
+    } else {
+        ""
+    };
+    match sequence.owned_by() {
+        Some((table, column)) => {
+            alloc::format!("CREATE {persistence}SEQUENCE {name} OWNED BY {table}.{column};")
+        }
+        None => alloc::format!("CREATE {persistence}SEQUENCE {name};"),
+    }
+}
+
+/// Renders the statement creating a user-defined type, or `None` for types
+/// whose definition is not tracked.
+fn create_custom_type(custom_type: &CustomType) -> Option<String> {
+    let name = qualified_name(
+        custom_type.schema().map(|schema| (schema, custom_type.schema_is_quoted())),
+        custom_type.name(),
+        custom_type.is_quoted(),
+    );
+    match custom_type.definition() {
+        CustomTypeDefinition::Enum(labels) => {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|label| alloc::format!("'{}'", label.replace('\'', "''")))
+                .collect();
+            Some(alloc::format!("CREATE TYPE {name} AS ENUM ({});", labels.join(", ")))
+        }
+        CustomTypeDefinition::Domain { base_type, checks } => {
+            let mut statement = alloc::format!("CREATE DOMAIN {name} AS {base_type}");
+            for check in checks {
+                statement.push_str(&alloc::format!(" CHECK ({check})"));
+            }
+            statement.push(';');
+            Some(statement)
+        }
+        CustomTypeDefinition::Other => None,
+    }
+}
+
+impl ParserDB {
+    /// Renders the database back into the DDL statements creating it, one
+    /// statement per line.
+    ///
+    /// Statements are emitted in dependency order: roles, schemas,
+    /// extensions, types, functions and sequences come first, followed by the
+    /// tables in the order of [`TableDependencyGraph::topological_order`], and
+    /// by the indices, views, triggers, policies and grants depending on them.
+    /// Tables are rendered from their current columns and constraints, so
+    /// that the effect of `ALTER TABLE` statements is preserved, with every
+    /// constraint declared at the table level. When the foreign keys form a
+    /// cycle, the tables of each cycle are created in the order of the
+    /// database, and the foreign keys referencing a table created later are
+    /// added by `ALTER TABLE` statements once the indices are created.
+    ///
+    /// Objects requiring a capability the provided dialect lacks are left
+    /// out, as are builtin functions, the functions provided by extensions
+    /// and user-defined types other than enumerations and domains.
+    ///
+    /// # Arguments
+    ///
+    /// * `dialect` - The dialect the statements are rendered for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{diff::SchemaDiff, impls::SqlparserDialect, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ALTER TABLE users ADD COLUMN name TEXT NOT NULL;
+    /// ",
+    /// )?;
+    /// let sql = db.to_sql(SqlparserDialect::PostgreSql);
+    /// assert!(sql.starts_with("CREATE TABLE users (id INT, name TEXT NOT NULL, PRIMARY KEY (id));"));
+    ///
+    /// let reparsed = ParserDB::parse::<PostgreSqlDialect>(&sql)?;
+    /// assert!(SchemaDiff::between(&db, &reparsed).is_empty());
+    /// assert_eq!(reparsed.to_sql(SqlparserDialect::PostgreSql), sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TableDependencyGraph::topological_order`]: crate::structs::TableDependencyGraph::topological_order
+    #[must_use]
+    pub fn to_sql(&self, dialect: SqlparserDialect) -> String {
+        let mut statements: Vec<String> = Vec::new();
+
+        if dialect.supports(DialectCapability::Roles) {
+            statements.extend(
+                self.roles_in_membership_order()
+                    .into_iter()
+                    .map(|role| alloc::format!("{};", Statement::CreateRole(role.clone()))),
+            );
+        }
+        if dialect.supports(DialectCapability::Schemas) {
+            statements.extend(DatabaseLike::schemas(self).map(|schema| {
+                let name = identifier(schema.name(), schema.is_quoted());
+                match schema.authorization() {
+                    Some(authorization) => {
+                        let authorization =
+                            identifier(authorization, schema.is_authorization_quoted());
+                        alloc::format!("CREATE SCHEMA {name} AUTHORIZATION {authorization};")
+                    }
+                    None => alloc::format!("CREATE SCHEMA {name};"),
+                }
+            }));
+        }
+        if dialect.supports(DialectCapability::Extensions) {
+            statements.extend(self.extensions().iter().map(|extension| {
+                let mut statement = alloc::format!(
+                    "CREATE EXTENSION {}",
+                    identifier(extension.name(), extension.is_quoted())
+                );
+                if extension.schema().is_some() || extension.version().is_some() {
+                    statement.push_str(" WITH");
+                }
+                if let Some(schema) = extension.schema() {
+                    statement.push_str(" SCHEMA ");
+                    statement.push_str(&identifier(schema, extension.schema_is_quoted()));
+                }
+                if let Some(version) = extension.version() {
+                    statement
+                        .push_str(&alloc::format!(" VERSION '{}'", version.replace('\'', "''")));
+                }
+                statement.push(';');
+                statement
+            }));
+        }
+        if dialect.supports(DialectCapability::CustomTypes) {
+            statements.extend(self.custom_types().filter_map(create_custom_type));
+        }
+        if dialect.supports(DialectCapability::Functions) {
+            // The libraries are named under the casing the database was
+            // built with, rather than the one it is rendered for.
+            let casing = DatabaseLike::dialect(self).identifier_casing();
+            statements.extend(
+                self.functions()
+                    .filter(|function| {
+                        !function.is_builtin(self)
+                            && !self.extensions().iter().any(|extension| {
+                                is_provided_by_extension(
+                                    function,
+                                    &extension_library(
+                                        extension.name(),
+                                        extension.is_quoted(),
+                                        casing,
+                                    ),
+                                )
+                            })
+                    })
+                    .map(|function| {
+                        alloc::format!("{};", Statement::CreateFunction(function.clone()))
+                    }),
+            );
+        }
+        // Sequences owned by a column can only be created once its table is.
+        let sequences = dialect.supports(DialectCapability::Sequences).then(|| {
+            self.sequences().partition::<Vec<_>, _>(|sequence| sequence.owned_by().is_none())
+        });
+        if let Some((unowned, _)) = &sequences {
+            statements.extend(unowned.iter().copied().map(create_sequence));
+        }
+
+        let graph = self.table_dependency_graph();
+        let tables = graph.topological_order().unwrap_or_else(|_| {
+            graph.strongly_connected_components().into_iter().flatten().collect()
+        });
+        let tables = tables.as_slice();
+        for (position, table) in tables.iter().enumerate() {
+            statements.push(self.create_table(tables, position, dialect));
+            if table.has_row_level_security(self) {
+                statements
+                    .push(alloc::format!("ALTER TABLE {} ENABLE ROW LEVEL SECURITY;", table.name));
+            }
+            if table.has_forced_row_level_security(self) {
+                statements
+                    .push(alloc::format!("ALTER TABLE {} FORCE ROW LEVEL SECURITY;", table.name));
+            }
+        }
+
+        if let Some((_, owned)) = &sequences {
+            statements.extend(owned.iter().copied().map(create_sequence));
+        }
+        statements.extend(tables.iter().flat_map(|table| {
+            table.indices(self).map(|index| {
+                alloc::format!("{};", Statement::CreateIndex(index.attribute().clone()))
+            })
+        }));
+        statements.extend(tables.iter().enumerate().flat_map(|(position, table)| {
+            table
+                .foreign_keys(self)
+                .filter(move |foreign_key| self.is_deferred(tables, position, foreign_key))
+                .map(|foreign_key| {
+                    alloc::format!(
+                        "ALTER TABLE {} ADD {};",
+                        table.name,
+                        TableConstraint::ForeignKey(foreign_key.attribute().clone())
+                    )
+                })
+        }));
+        statements.extend(
+            self.views().map(|view| alloc::format!("{};", Statement::CreateView(view.clone()))),
+        );
+        if dialect.supports(DialectCapability::Triggers) {
+            statements.extend(
+                self.triggers().map(|trigger| {
+                    alloc::format!("{};", Statement::CreateTrigger(trigger.clone()))
+                }),
+            );
+        }
+        if dialect.supports(DialectCapability::Policies) {
+            statements.extend(
+                self.policies()
+                    .map(|policy| alloc::format!("{};", Statement::CreatePolicy(policy.clone()))),
+            );
+        }
+        if dialect.supports(DialectCapability::Grants) {
+            // Grants mixing table and column privileges are split between the
+            // table and the column grants, and rendered as two statements.
+            statements.extend(
+                self.table_grants()
+                    .chain(self.column_grants())
+                    .map(|grant| alloc::format!("{};", Statement::Grant(grant.clone()))),
+            );
+            // Default privileges only apply to the tables created after them.
+            statements.extend(
+                self.default_privileges()
+                    .map(|default_privilege| alloc::format!("{default_privilege};")),
+            );
+        }
+
+        let mut sql = statements.join("\n");
+        if !sql.is_empty() {
+            sql.push('\n');
+        }
+        sql
+    }
+
+    /// Returns the roles ordered so that every role comes after the roles it
+    /// is a member of. Roles involved in membership cycles come last, in
+    /// storage order.
+    fn roles_in_membership_order(&self) -> Vec<&sqlparser::ast::CreateRole> {
+        let mut pending: Vec<_> = self.roles().collect();
+        let mut ordered = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let (ready, blocked): (Vec<_>, Vec<_>) = pending
+                .into_iter()
+                .partition(|role| role.member_of(self).all(|parent| ordered.contains(&parent)));
+            if ready.is_empty() {
+                ordered.extend(blocked);
+                break;
+            }
+            ordered.extend(ready);
+            pending = blocked;
+        }
+        ordered
+    }
+
+    /// Returns whether the foreign key of the table at the provided position
+    /// references a table created after it, which only happens within cycles
+    /// of foreign keys, so that it is added once every table is created.
+    fn is_deferred(
+        &self,
+        tables: &[&CreateTable],
+        position: usize,
+        foreign_key: &TableAttribute<CreateTable, ForeignKeyConstraint>,
+    ) -> bool {
+        foreign_key
+            .referenced_table(self)
+            .is_some_and(|referenced_table| tables[position + 1..].contains(&referenced_table))
+    }
+
+    /// Renders the statement creating the table at the provided position from
+    /// its current columns and constraints, leaving out the foreign keys
+    /// referencing tables created after it.
+    fn create_table(
+        &self,
+        tables: &[&CreateTable],
+        position: usize,
+        dialect: SqlparserDialect,
+    ) -> String {
+        let table = tables[position];
+        // Constraints declared inline are rendered at the table level.
+        let mut elements: Vec<String> = table
+            .columns(self)
+            .map(|column| column_definition(column.attribute()).to_string())
+            .collect();
+        for unique_index in table.unique_indices(self) {
+            if unique_index.is_primary_key(self) {
+                let constraint = unique_index
+                    .attribute()
+                    .name
+                    .as_ref()
+                    .map(|name| alloc::format!("CONSTRAINT {name} "))
+                    .unwrap_or_default();
+                elements.push(alloc::format!(
+                    "{constraint}PRIMARY KEY {}",
+                    unique_index.expression(self)
+                ));
+            } else {
+                elements
+                    .push(TableConstraint::Unique(unique_index.attribute().clone()).to_string());
+            }
+        }
+        elements.extend(
+            table
+                .foreign_keys(self)
+                .filter(|foreign_key| !self.is_deferred(tables, position, foreign_key))
+                .map(|foreign_key| {
+                    TableConstraint::ForeignKey(foreign_key.attribute().clone()).to_string()
+                }),
+        );
+        elements.extend(table.check_constraints(self).map(|check_constraint| {
+            let mut constraint =
+                TableConstraint::Check(check_constraint.attribute().clone()).to_string();
+            if dialect == SqlparserDialect::PostgreSql && check_constraint.no_inherit(self) {
+                constraint.push_str(" NO INHERIT");
+            }
+            constraint
+        }));
+        alloc::format!("CREATE TABLE {} ({});", table.name, elements.join(", "))
+    }
+}
@@ -0,0 +1,204 @@
+//! Validation of the default values of the columns against their data types.
+//!
+//! The type of a default value is inferred with [`infer_type`], and only
+//! rejected when it belongs to a family of data types which cannot be stored
+//! in the column, such as a timestamp in a numeric column. String literals
+//! are read as the engines do, by parsing their content as a value of the
+//! type of the column.
+
+use alloc::string::ToString;
+
+use sqlparser::ast::{ColumnOption, Expr, Value};
+
+use crate::{
+    errors::Error,
+    structs::ParserDB,
+    traits::{ColumnLike, DatabaseLike, TableLike},
+    utils::{infer_type, try_normalize_sqlparser_type, typing::is_numeric_type},
+};
+
+/// Families of data types whose values can be converted into one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TypeFamily {
+    /// Integer, fixed-point and floating-point numbers.
+    Numeric,
+    /// Booleans.
+    Boolean,
+    /// Character strings.
+    Textual,
+    /// Dates, times and timestamps.
+    Temporal,
+    /// UUIDs.
+    Uuid,
+}
+
+impl TypeFamily {
+    /// Returns the family of the normalized data type, if it is known.
+    pub(super) fn of(data_type: &str) -> Option<Self> {
+        let data_type = data_type.to_ascii_uppercase();
+        if is_numeric_type(&data_type) {
+            Some(Self::Numeric)
+        } else if matches!(data_type.as_str(), "BOOLEAN" | "BOOL") {
+            Some(Self::Boolean)
+        } else if matches!(data_type.as_str(), "TEXT" | "VARCHAR" | "CHAR" | "CLOB" | "NVARCHAR") {
+            Some(Self::Textual)
+        } else if matches!(data_type.as_str(), "DATE" | "DATETIME") || data_type.starts_with("TIME")
+        {
+            Some(Self::Temporal)
+        } else if data_type == "UUID" {
+            Some(Self::Uuid)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the values of this family can be stored in a column
+    /// of the provided family.
+    pub(super) fn converts_to(self, column: Self, mysql_family: bool) -> bool {
+        match (self, column) {
+            // Any value can be stored as its textual representation, and
+            // integers stand for booleans in the dialects lacking them.
+            (_, Self::Textual) | (Self::Numeric, Self::Boolean) => true,
+            // MySQL stores booleans as integers.
+            (Self::Boolean, Self::Numeric) => mysql_family,
+            _ => self == column,
+        }
+    }
+
+    /// Returns whether a string literal can be read as a value of this
+    /// family.
+    fn accepts_literal(self, literal: &str) -> bool {
+        let literal = literal.trim();
+        match self {
+            Self::Numeric => literal.parse::<f64>().is_ok(),
+            Self::Boolean => matches!(
+                literal.to_ascii_lowercase().as_str(),
+                "t" | "true" | "y" | "yes" | "on" | "1" | "f" | "false" | "n" | "no" | "off" | "0"
+            ),
+            Self::Textual | Self::Temporal | Self::Uuid => true,
+        }
+    }
+}
+
+/// Returns the content of a string literal expression.
+fn string_literal(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Value(value) => match &value.value {
+            Value::SingleQuotedString(literal)
+            | Value::EscapedStringLiteral(literal)
+            | Value::NationalStringLiteral(literal) => Some(literal),
+            _ => None,
+        },
+        Expr::Nested(inner) => string_literal(inner),
+        _ => None,
+    }
+}
+
+/// Checks that the default value of every column of the database can be
+/// stored in the column, passing the mismatches to the provided reporter.
+///
+/// # Errors
+///
+/// Returns the first error the reporter returns for an
+/// [`Error::DefaultTypeMismatch`].
+pub(super) fn check_default_types(
+    database: &ParserDB,
+    report: &mut impl FnMut(Error) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mysql_family = database.dialect().is_mysql_family();
+    for table in database.tables() {
+        for column in table.columns(database) {
+            let Some(default) =
+                column.attribute().options.iter().find_map(|option| match &option.option {
+                    ColumnOption::Default(default) => Some(default),
+                    _ => None,
+                })
+            else {
+                continue;
+            };
+            // Columns of data types the normalizer does not support, such as
+            // schema-qualified custom types, are not validated.
+            let Some(column_type) = try_normalize_sqlparser_type(&column.attribute().data_type)
+            else {
+                continue;
+            };
+            let Some(column_family) = TypeFamily::of(column_type) else {
+                continue;
+            };
+            let default_type = infer_type(default, table, database);
+            let compatible = match string_literal(default) {
+                Some(literal) => column_family.accepts_literal(literal),
+                None => default_type.and_then(TypeFamily::of).is_none_or(|default_family| {
+                    default_family.converts_to(column_family, mysql_family)
+                }),
+            };
+            if !compatible {
+                report(Error::DefaultTypeMismatch {
+                    table_name: table.table_name().to_string(),
+                    column_name: column.column_name().to_string(),
+                    column_type: column_type.to_string(),
+                    default: default.to_string(),
+                    default_type: default_type.unwrap_or_default().to_string(),
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect, SQLiteDialect};
+
+    use crate::{errors::Error, structs::ParserDB};
+
+    #[test]
+    fn test_compatible_defaults_are_accepted() {
+        ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE events (
+                 id UUID DEFAULT gen_random_uuid(),
+                 attempts INT DEFAULT '3',
+                 weight REAL DEFAULT 1,
+                 active BOOLEAN DEFAULT 'yes',
+                 label TEXT DEFAULT 42,
+                 happened_on DATE DEFAULT now(),
+                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+                 tier INT DEFAULT length('gold') + 1
+             );",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_incompatible_defaults_are_rejected() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (a INT DEFAULT 'abc');"),
+            Err(Error::DefaultTypeMismatch { column_name, column_type, default_type, .. })
+                if column_name == "a" && column_type == "INT" && default_type == "TEXT"
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE TABLE t (a INT);
+                 ALTER TABLE t ADD COLUMN b INT DEFAULT now();"
+            ),
+            Err(Error::DefaultTypeMismatch { default_type, .. })
+                if default_type == "TIMESTAMP WITH TIME ZONE"
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (a BOOLEAN DEFAULT 'maybe');"),
+            Err(Error::DefaultTypeMismatch { .. })
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (a INT DEFAULT TRUE);"),
+            Err(Error::DefaultTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dialects_storing_any_default_are_lenient() {
+        ParserDB::parse::<MySqlDialect>("CREATE TABLE t (a INT DEFAULT TRUE);").unwrap();
+        ParserDB::parse::<SQLiteDialect>("CREATE TABLE t (a INT DEFAULT 'abc');").unwrap();
+        ParserDB::parse_lenient::<PostgreSqlDialect>("CREATE TABLE t (a INT DEFAULT 'abc');")
+            .unwrap();
+    }
+}
@@ -0,0 +1,488 @@
+//! Checks of the objects depending on the tables, functions, roles and
+//! schemas being dropped, and removal of the objects depending on a table.
+
+use alloc::string::{String, ToString};
+use core::ops::ControlFlow;
+
+use sqlparser::ast::{CreateView, GranteeName, visit_relations};
+
+use super::{
+    ParserDBBuilder, object_name_last_identifier, object_name_matches_resolved_identity,
+    table_matches_resolved_identity,
+};
+use crate::{
+    structs::TableAttribute,
+    traits::{FunctionLike, TableLike, TriggerLike, ViewLike},
+    utils::last_str,
+};
+
+impl ParserDBBuilder {
+    /// Checks if a function with the given name is referenced by any schema
+    /// object.
+    ///
+    /// Returns `true` if the function is used by:
+    /// - Check constraints (via their metadata)
+    /// - Policies (via USING or WITH CHECK expressions)
+    /// - Triggers (via EXECUTE FUNCTION)
+    pub(super) fn is_function_used(&self, function_name: &str, function_name_quoted: bool) -> bool {
+        let casing = self.identifier_casing();
+
+        // Check if any check constraint references the function
+        for (_, metadata) in self.check_constraints() {
+            if metadata.functions().any(|f| {
+                casing.matches(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
+            }) {
+                return true;
+            }
+        }
+
+        // Check if any policy references the function
+        for (_, metadata) in self.policies() {
+            if metadata.using_functions().any(|f| {
+                casing.matches(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
+            }) {
+                return true;
+            }
+            if metadata.check_functions().any(|f| {
+                casing.matches(f.name(), f.name_is_quoted(), function_name, function_name_quoted)
+            }) {
+                return true;
+            }
+        }
+
+        // Check if any trigger executes the function
+        for (trigger, ()) in self.triggers() {
+            if trigger.function_name_ident().is_some_and(|(name, quoted)| {
+                casing.matches(name, quoted, function_name, function_name_quoted)
+            }) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Checks if a table with the given name is referenced by foreign keys from
+    /// other tables.
+    ///
+    /// Returns `true` if any other table has a foreign key pointing to this
+    /// table.
+    pub(super) fn is_table_referenced(
+        &self,
+        table_name: &str,
+        table_name_quoted: bool,
+        schema_name: Option<&str>,
+        schema_quoted: bool,
+    ) -> bool {
+        let casing = self.identifier_casing();
+        for (fk, ()) in self.foreign_keys() {
+            // Check if this FK references the table being dropped
+            // and is NOT from the same table (self-referential FKs are OK to drop)
+            let Some(referenced_table) =
+                self.resolve_table_object_name(&fk.attribute().foreign_table).ok().flatten()
+            else {
+                continue;
+            };
+            let Some(host_table) = self.resolve_table_object_name(&fk.table().name).ok().flatten()
+            else {
+                continue;
+            };
+
+            let referenced_matches = table_matches_resolved_identity(
+                referenced_table,
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            );
+            let host_matches = table_matches_resolved_identity(
+                host_table,
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            );
+
+            if referenced_matches && !host_matches {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Checks if the query of a view selects from the table with the given
+    /// name.
+    pub(super) fn view_depends_on_table(
+        &self,
+        view: &CreateView,
+        table_name: &str,
+        table_name_quoted: bool,
+        schema_name: Option<&str>,
+        schema_quoted: bool,
+    ) -> bool {
+        let casing = self.identifier_casing();
+        visit_relations(&view.query, |object_name| {
+            match self.resolve_table_object_name(object_name) {
+                Ok(Some(table))
+                    if table_matches_resolved_identity(
+                        table,
+                        table_name,
+                        table_name_quoted,
+                        schema_name,
+                        schema_quoted,
+                        casing,
+                    ) =>
+                {
+                    ControlFlow::Break(())
+                }
+                _ => ControlFlow::Continue(()),
+            }
+        })
+        .is_break()
+    }
+
+    /// Returns the name of a view selecting from the table with the given
+    /// name, if any.
+    pub(super) fn dependent_view_name(
+        &self,
+        table_name: &str,
+        table_name_quoted: bool,
+        schema_name: Option<&str>,
+        schema_quoted: bool,
+    ) -> Option<String> {
+        self.views()
+            .iter()
+            .find(|(view, ())| {
+                self.view_depends_on_table(
+                    view,
+                    table_name,
+                    table_name_quoted,
+                    schema_name,
+                    schema_quoted,
+                )
+            })
+            .map(|(view, ())| last_str(&view.name).to_string())
+    }
+
+    /// Returns the name of a function whose body reads or writes the table
+    /// with the given name, if any.
+    ///
+    /// The relations of the bodies are collected when the functions are
+    /// created, so that function bodies which cannot be parsed are not
+    /// considered.
+    pub(super) fn dependent_function_name(
+        &self,
+        table_name: &str,
+        table_name_quoted: bool,
+        schema_name: Option<&str>,
+        schema_quoted: bool,
+    ) -> Option<String> {
+        let casing = self.identifier_casing();
+        self.functions()
+            .iter()
+            .find(|(_, metadata)| {
+                metadata.referenced_relations().unwrap_or_default().iter().any(|object_name| {
+                    matches!(
+                        self.resolve_table_object_name(object_name),
+                        Ok(Some(table)) if table_matches_resolved_identity(
+                            table,
+                            table_name,
+                            table_name_quoted,
+                            schema_name,
+                            schema_quoted,
+                            casing,
+                        )
+                    )
+                })
+            })
+            .map(|(function, _)| last_str(&function.name).to_string())
+    }
+
+    /// Removes a table and all its associated schema objects.
+    ///
+    /// This removes:
+    /// - All views selecting from the table
+    /// - All sequences owned by a column of the table
+    /// - The table itself
+    /// - All columns belonging to the table
+    /// - All indices on the table
+    /// - All unique indices on the table
+    /// - All foreign keys from the table
+    /// - All check constraints on the table
+    /// - All triggers on the table
+    /// - All policies on the table
+    /// - All grants on the table
+    pub(super) fn remove_table(
+        &mut self,
+        table_name: &str,
+        table_name_quoted: bool,
+        schema_name: Option<&str>,
+        schema_quoted: bool,
+    ) {
+        let casing = self.identifier_casing();
+        // Remove the views selecting from the table, while the table can still
+        // be resolved
+        let views = core::mem::take(self.views_mut());
+        let retained_views = views
+            .into_iter()
+            .filter(|(view, ())| {
+                !self.view_depends_on_table(
+                    view,
+                    table_name,
+                    table_name_quoted,
+                    schema_name,
+                    schema_quoted,
+                )
+            })
+            .collect();
+        *self.views_mut() = retained_views;
+
+        // Remove the sequences owned by a column of the table
+        self.sequences_mut().retain(|(sequence, ())| {
+            !sequence.owned_by().is_some_and(|(owner_table, _)| {
+                object_name_matches_resolved_identity(
+                    owner_table,
+                    table_name,
+                    table_name_quoted,
+                    schema_name,
+                    schema_quoted,
+                    casing,
+                )
+            })
+        });
+
+        // Remove the table
+        self.retain_tables(|(t, _)| {
+            !table_matches_resolved_identity(
+                t,
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove columns belonging to this table
+        self.columns_mut().retain(|(c, ())| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(c),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove indices on this table
+        self.indices_mut().retain(|(i, _)| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(i),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove unique indices on this table
+        self.unique_indices_mut().retain(|(u, _)| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(u),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove the primary key of this table
+        self.primary_keys_mut().retain(|(primary_key, ())| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(primary_key),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove foreign keys from this table
+        self.foreign_keys_mut().retain(|(fk, ())| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(fk),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove check constraints on this table
+        self.check_constraints_mut().retain(|(c, _)| {
+            !table_matches_resolved_identity(
+                TableAttribute::table(c),
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove triggers on this table
+        self.retain_triggers(|(t, ())| {
+            !object_name_matches_resolved_identity(
+                &t.table_name,
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove policies on this table
+        self.policies_mut().retain(|(p, _)| {
+            !object_name_matches_resolved_identity(
+                &p.table_name,
+                table_name,
+                table_name_quoted,
+                schema_name,
+                schema_quoted,
+                casing,
+            )
+        });
+
+        // Remove table grants for this table
+        self.table_grants_mut().retain(|(g, ())| {
+            use sqlparser::ast::GrantObjects;
+            !matches!(&g.objects, Some(GrantObjects::Tables(tables)) if tables.iter().any(|t| {
+                object_name_matches_resolved_identity(
+                    t,
+                    table_name,
+                    table_name_quoted,
+                    schema_name,
+                    schema_quoted,
+                    casing,
+                )
+            }))
+        });
+
+        // Remove column grants for this table
+        self.column_grants_mut().retain(|(g, ())| {
+            use sqlparser::ast::GrantObjects;
+            !matches!(&g.objects, Some(GrantObjects::Tables(tables)) if tables.iter().any(|t| {
+                object_name_matches_resolved_identity(
+                    t,
+                    table_name,
+                    table_name_quoted,
+                    schema_name,
+                    schema_quoted,
+                    casing,
+                )
+            }))
+        });
+    }
+
+    /// Checks if a role with the given name is referenced by any grants,
+    /// default privileges, role memberships or schema ownerships.
+    ///
+    /// Returns `true` if the role is a grantee in any table or column grant,
+    /// is named by default privileges, if another role is a member of it, or
+    /// if it owns a schema.
+    pub(super) fn is_role_referenced(&self, role_name: &str, role_quoted: bool) -> bool {
+        let casing = self.identifier_casing();
+        let check_grantees = |grantees: &[sqlparser::ast::Grantee]| -> bool {
+            grantees.iter().any(|g| {
+                matches!(
+                    &g.name,
+                    Some(GranteeName::ObjectName(name))
+                        if object_name_last_identifier(name).is_some_and(|grantee_ident| {
+                            casing.matches(
+                                grantee_ident.value.as_str(),
+                                grantee_ident.quote_style.is_some(),
+                                role_name,
+                                role_quoted,
+                            )
+                        })
+                )
+            })
+        };
+
+        // Check table grants
+        for (grant, ()) in self.table_grants() {
+            if check_grantees(&grant.grantees) {
+                return true;
+            }
+        }
+
+        // Check column grants
+        for (grant, ()) in self.column_grants() {
+            if check_grantees(&grant.grantees) {
+                return true;
+            }
+        }
+
+        // Check default privileges, including the ones granted on tables
+        if self.default_privileges().iter().any(|(default_privilege, ())| {
+            default_privilege.mentions_role(role_name, role_quoted, casing)
+        }) || self.tables().iter().any(|(_, metadata)| {
+            metadata.default_privileges().any(|default_privilege| {
+                default_privilege.mentions_role(role_name, role_quoted, casing)
+            })
+        }) {
+            return true;
+        }
+
+        // Check schemas owned by this role
+        if self.schemas().iter().any(|(schema, ())| {
+            schema.authorization().is_some_and(|owner| {
+                casing.matches(owner, schema.is_authorization_quoted(), role_name, role_quoted)
+            })
+        }) {
+            return true;
+        }
+
+        // Check memberships of other roles in this role
+        self.roles().iter().any(|(role, ())| {
+            role.in_role.iter().any(|parent| {
+                casing.matches(
+                    parent.value.as_str(),
+                    parent.quote_style.is_some(),
+                    role_name,
+                    role_quoted,
+                )
+            })
+        })
+    }
+
+    /// Checks if a schema contains any objects.
+    ///
+    /// Returns `true` if any table, function or view belongs to this schema.
+    pub(super) fn is_schema_non_empty(&self, schema_name: &str, schema_quoted: bool) -> bool {
+        let casing = self.identifier_casing();
+        let in_schema = |object_schema: Option<&str>, object_schema_quoted: bool| {
+            object_schema.is_some_and(|object_schema| {
+                casing.matches(object_schema, object_schema_quoted, schema_name, schema_quoted)
+            })
+        };
+
+        self.tables().iter().any(|(t, _)| in_schema(t.table_schema(), t.table_schema_is_quoted()))
+            || self.functions().iter().any(|(f, _)| in_schema(f.schema(), f.schema_is_quoted()))
+            || self
+                .views()
+                .iter()
+                .any(|(v, ())| in_schema(v.view_schema(), v.view_schema_is_quoted()))
+            || self.sequences().iter().any(|(s, ())| in_schema(s.schema(), s.schema_is_quoted()))
+            || self.custom_types().iter().any(|(t, ())| in_schema(t.schema(), t.schema_is_quoted()))
+    }
+}
@@ -0,0 +1,133 @@
+//! Signatures of the functions provided by well-known PostgreSQL extensions.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::{DataType, Ident, ObjectName, ObjectNamePart, OperateFunctionArg};
+
+use crate::structs::IdentifierCasing;
+
+/// Argument and return types appearing in the extension signatures.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Text,
+    Bytea,
+    Int,
+    Uuid,
+    Boolean,
+    Double,
+    Geometry,
+    Citext,
+}
+
+impl Kind {
+    /// Returns the data type corresponding to the kind.
+    fn data_type(self) -> DataType {
+        let custom = |name: &str| {
+            DataType::Custom(ObjectName(vec![ObjectNamePart::Identifier(Ident::new(name))]), vec![])
+        };
+        match self {
+            Self::Text => DataType::Text,
+            Self::Bytea => DataType::Bytea,
+            Self::Int => DataType::Int(None),
+            Self::Uuid => DataType::Uuid,
+            Self::Boolean => DataType::Boolean,
+            Self::Double => DataType::DoublePrecision,
+            Self::Geometry => custom("geometry"),
+            Self::Citext => custom("citext"),
+        }
+    }
+}
+
+/// Name, argument kinds and return kind of a function provided by an
+/// extension.
+type Signature = (&'static str, &'static [Kind], Kind);
+
+/// Functions provided by the `pgcrypto` extension.
+const PGCRYPTO: &[Signature] = &[
+    ("crypt", &[Kind::Text, Kind::Text], Kind::Text),
+    ("gen_salt", &[Kind::Text], Kind::Text),
+    ("gen_salt", &[Kind::Text, Kind::Int], Kind::Text),
+    ("digest", &[Kind::Text, Kind::Text], Kind::Bytea),
+    ("digest", &[Kind::Bytea, Kind::Text], Kind::Bytea),
+    ("hmac", &[Kind::Text, Kind::Text, Kind::Text], Kind::Bytea),
+    ("hmac", &[Kind::Bytea, Kind::Bytea, Kind::Text], Kind::Bytea),
+    ("pgp_sym_encrypt", &[Kind::Text, Kind::Text], Kind::Bytea),
+    ("pgp_sym_decrypt", &[Kind::Bytea, Kind::Text], Kind::Text),
+    ("gen_random_bytes", &[Kind::Int], Kind::Bytea),
+];
+
+/// Functions provided by the `uuid-ossp` extension.
+const UUID_OSSP: &[Signature] = &[
+    ("uuid_generate_v1", &[], Kind::Uuid),
+    ("uuid_generate_v1mc", &[], Kind::Uuid),
+    ("uuid_generate_v3", &[Kind::Uuid, Kind::Text], Kind::Uuid),
+    ("uuid_generate_v4", &[], Kind::Uuid),
+    ("uuid_generate_v5", &[Kind::Uuid, Kind::Text], Kind::Uuid),
+    ("uuid_nil", &[], Kind::Uuid),
+    ("uuid_ns_dns", &[], Kind::Uuid),
+    ("uuid_ns_url", &[], Kind::Uuid),
+    ("uuid_ns_oid", &[], Kind::Uuid),
+    ("uuid_ns_x500", &[], Kind::Uuid),
+];
+
+/// Functions provided by the `postgis` extension.
+const POSTGIS: &[Signature] = &[
+    ("st_isvalid", &[Kind::Geometry], Kind::Boolean),
+    ("st_isempty", &[Kind::Geometry], Kind::Boolean),
+    ("st_srid", &[Kind::Geometry], Kind::Int),
+    ("st_geometrytype", &[Kind::Geometry], Kind::Text),
+    ("st_x", &[Kind::Geometry], Kind::Double),
+    ("st_y", &[Kind::Geometry], Kind::Double),
+    ("st_area", &[Kind::Geometry], Kind::Double),
+    ("st_makepoint", &[Kind::Double, Kind::Double], Kind::Geometry),
+    ("st_setsrid", &[Kind::Geometry, Kind::Int], Kind::Geometry),
+    ("st_distance", &[Kind::Geometry, Kind::Geometry], Kind::Double),
+    ("st_within", &[Kind::Geometry, Kind::Geometry], Kind::Boolean),
+    ("st_contains", &[Kind::Geometry, Kind::Geometry], Kind::Boolean),
+    ("st_intersects", &[Kind::Geometry, Kind::Geometry], Kind::Boolean),
+];
+
+/// Functions provided by the `citext` extension.
+const CITEXT: &[Signature] = &[("citext", &[Kind::Text], Kind::Citext)];
+
+/// Returns the library of the extension, named after the extension as
+/// normalized under the provided casing, which the functions it provides
+/// declare as their body so that they can be told apart from user-defined
+/// functions when the extension is dropped.
+pub(super) fn extension_library(name: &str, quoted: bool, casing: IdentifierCasing) -> String {
+    alloc::format!("$libdir/{}", casing.normalize(name, quoted))
+}
+
+/// Iterates over the name, arguments and return type of the functions
+/// provided by the extension, whose name is compared to the catalog under
+/// the provided casing. Extensions outside the catalog provide no function.
+pub(super) fn extension_functions(
+    name: &str,
+    quoted: bool,
+    casing: IdentifierCasing,
+) -> impl Iterator<Item = (&'static str, Vec<OperateFunctionArg>, DataType)> {
+    // The catalog lists the names as PostgreSQL stores them.
+    let signatures = [
+        ("pgcrypto", PGCRYPTO),
+        ("uuid-ossp", UUID_OSSP),
+        ("postgis", POSTGIS),
+        ("citext", CITEXT),
+    ]
+    .into_iter()
+    .find_map(|(extension, signatures)| {
+        casing.matches(name, quoted, extension, true).then_some(signatures)
+    })
+    .unwrap_or_default();
+    signatures.iter().map(|(function_name, arguments, return_kind)| {
+        let arguments = arguments
+            .iter()
+            .map(|kind| OperateFunctionArg {
+                mode: None,
+                name: None,
+                data_type: kind.data_type(),
+                default_expr: None,
+            })
+            .collect();
+        (*function_name, arguments, return_kind.data_type())
+    })
+}
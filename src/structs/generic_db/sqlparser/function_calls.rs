@@ -0,0 +1,291 @@
+//! Validation of the function calls of check constraints and policies against
+//! the signatures of the functions of the database.
+//!
+//! Only the calls to known functions, either declared in the schema, provided
+//! by an extension or built in, are validated: a call matches an overload
+//! when it passes as many arguments as the overload accepts, each of a type
+//! the engines implicitly convert to the declared one.
+
+use alloc::{string::ToString, vec::Vec};
+use core::ops::ControlFlow;
+
+use sqlparser::ast::{
+    ArgMode, CreateFunction, CreateTable, Expr, Function, FunctionArg, FunctionArgExpr,
+    FunctionArguments, OperateFunctionArg, UnaryOperator, Value, visit_expressions,
+};
+
+use super::default_types::TypeFamily;
+use crate::{
+    errors::Error,
+    structs::ParserDB,
+    traits::{CheckConstraintLike, DatabaseLike, DialectLike, FunctionLike, PolicyLike, TableLike},
+    utils::{
+        infer_type, last_str, object_name::object_name_last_part, try_normalize_sqlparser_type,
+        typing::numeric_rank,
+    },
+};
+
+/// Normalized name of the polymorphic type of the built-in functions.
+const ANY: &str = "any";
+
+/// Name of the trailing parameter of the variadic built-in functions, which
+/// accepts any number of arguments.
+const VARIADIC: &str = "VARIADIC";
+
+/// Returns whether the expression is a literal, possibly negated.
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Value(_) => true,
+        Expr::Nested(inner)
+        | Expr::UnaryOp { op: UnaryOperator::Minus | UnaryOperator::Plus, expr: inner } => {
+            is_literal(inner)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the expression is a string literal, whose type is
+/// resolved from the parameter it is passed to.
+fn is_string_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Value(value) => matches!(
+            value.value,
+            Value::SingleQuotedString(_)
+                | Value::EscapedStringLiteral(_)
+                | Value::NationalStringLiteral(_)
+                | Value::DollarQuotedString(_)
+        ),
+        Expr::Nested(inner) => is_string_literal(inner),
+        _ => false,
+    }
+}
+
+/// Returns whether the argument can be passed to a parameter of the declared
+/// normalized type.
+///
+/// Arguments which are not expressions, and parameters of data types the
+/// normalizer does not support, are not validated.
+fn argument_matches(
+    declared: Option<&str>,
+    argument: Option<&Expr>,
+    table: &CreateTable,
+    database: &ParserDB,
+) -> bool {
+    let (Some(declared), Some(argument)) = (declared, argument) else {
+        return true;
+    };
+    if declared == ANY || is_string_literal(argument) {
+        return true;
+    }
+    let Some(inferred) = infer_type(argument, table, database) else {
+        return true;
+    };
+    if inferred.eq_ignore_ascii_case(declared) {
+        return true;
+    }
+    match (numeric_rank(inferred), numeric_rank(declared)) {
+        // Numbers are implicitly widened, and numeric literals take the type
+        // of the parameter.
+        (Some(inferred_rank), Some(declared_rank)) => {
+            inferred_rank <= declared_rank || is_literal(argument)
+        }
+        // MySQL converts the arguments as it converts the stored values.
+        _ if database.dialect().is_mysql_family() => {
+            match (TypeFamily::of(inferred), TypeFamily::of(declared)) {
+                (Some(inferred), Some(declared)) => inferred.converts_to(declared, true),
+                _ => true,
+            }
+        }
+        (None, None) => {
+            TypeFamily::of(inferred).is_some_and(|family| TypeFamily::of(declared) == Some(family))
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the overload accepts the arguments of a call.
+fn overload_matches(
+    overload: &CreateFunction,
+    arguments: &[Option<&Expr>],
+    table: &CreateTable,
+    database: &ParserDB,
+) -> bool {
+    // Output parameters are not passed by the callers.
+    let parameters: Vec<&OperateFunctionArg> = overload
+        .args
+        .iter()
+        .flatten()
+        .filter(|parameter| !matches!(parameter.mode, Some(ArgMode::Out)))
+        .collect();
+    let variadic = parameters.last().copied().filter(|parameter| {
+        parameter.name.as_ref().is_some_and(|name| name.value.eq_ignore_ascii_case(VARIADIC))
+    });
+    let required = parameters.iter().filter(|parameter| parameter.default_expr.is_none()).count();
+    if arguments.len() < required || (variadic.is_none() && arguments.len() > parameters.len()) {
+        return false;
+    }
+    arguments.iter().enumerate().all(|(position, argument)| {
+        let Some(parameter) = parameters.get(position).copied().or(variadic) else {
+            return false;
+        };
+        argument_matches(
+            try_normalize_sqlparser_type(&parameter.data_type),
+            *argument,
+            table,
+            database,
+        )
+    })
+}
+
+/// Checks that a call to a known function matches one of its overloads.
+fn check_call(function: &Function, table: &CreateTable, database: &ParserDB) -> Result<(), Error> {
+    let Some((name, quoted)) = object_name_last_part(&function.name) else {
+        return Ok(());
+    };
+    let casing = database.dialect().identifier_casing();
+    let mut overloads = database
+        .functions()
+        .filter(|overload| casing.matches(overload.name(), overload.name_is_quoted(), name, quoted))
+        .peekable();
+    // Calls to unknown functions are not validated.
+    if overloads.peek().is_none() {
+        return Ok(());
+    }
+
+    let arguments: Vec<Option<&Expr>> = match &function.args {
+        FunctionArguments::List(list) => list
+            .args
+            .iter()
+            .map(|argument| match argument {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))
+                | FunctionArg::Named { arg: FunctionArgExpr::Expr(expr), .. } => Some(expr),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    if overloads.any(|overload| overload_matches(overload, &arguments, table, database)) {
+        return Ok(());
+    }
+    Err(Error::NoMatchingFunctionOverload {
+        function_name: last_str(&function.name).to_string(),
+        call: function.to_string(),
+        table_name: table.table_name().to_string(),
+    })
+}
+
+/// Checks the function calls appearing in an expression evaluated on the
+/// rows of the provided table, passing the mismatches to the reporter.
+fn check_calls(
+    expr: &Expr,
+    table: &CreateTable,
+    database: &ParserDB,
+    report: &mut impl FnMut(Error) -> Result<(), Error>,
+) -> Result<(), Error> {
+    match visit_expressions(expr, |expr| {
+        if let Expr::Function(function) = expr
+            && let Err(error) = check_call(function, table, database).or_else(&mut *report)
+        {
+            return ControlFlow::Break(error);
+        }
+        ControlFlow::Continue(())
+    }) {
+        ControlFlow::Break(error) => Err(error),
+        ControlFlow::Continue(()) => Ok(()),
+    }
+}
+
+/// Checks that the function calls of the check constraints and policies of
+/// the database match the signatures of the called functions, passing the
+/// mismatches to the provided reporter.
+///
+/// # Errors
+///
+/// Returns the first error the reporter returns for an
+/// [`Error::NoMatchingFunctionOverload`].
+pub(super) fn check_function_calls(
+    database: &ParserDB,
+    report: &mut impl FnMut(Error) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for table in database.tables() {
+        for check_constraint in table.check_constraints(database) {
+            check_calls(check_constraint.expression(database), table, database, report)?;
+        }
+    }
+    for policy in database.policies() {
+        let table = policy.table(database);
+        for expr in [policy.using_expression(database), policy.check_expression(database)]
+            .into_iter()
+            .flatten()
+        {
+            check_calls(expr, table, database, report)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use crate::{errors::Error, structs::ParserDB};
+
+    #[test]
+    fn test_matching_calls_are_accepted() {
+        ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE FUNCTION in_range(x BIGINT, low INT, high INT) RETURNS BOOLEAN
+                 AS 'SELECT x BETWEEN low AND high;' LANGUAGE SQL;
+             CREATE FUNCTION in_range(x TEXT, pattern TEXT) RETURNS BOOLEAN
+                 AS 'SELECT x LIKE pattern;' LANGUAGE SQL;
+             CREATE FUNCTION is_small(x INT, bound INT DEFAULT 10) RETURNS BOOLEAN
+                 AS 'SELECT x < bound;' LANGUAGE SQL;
+             CREATE TABLE t (
+                 id SMALLINT CHECK (in_range(id, -1, 10) AND is_small(id)),
+                 code VARCHAR CHECK (in_range(code, 'A%')),
+                 name TEXT CHECK (length(coalesce(name, 'x', '')) > 0)
+             );
+             CREATE POLICY visible ON t USING (in_range(id, 0, length(name)));",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_mismatching_calls_are_rejected() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE FUNCTION is_positive(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;' LANGUAGE SQL;
+                 CREATE TABLE t (id INT CHECK (is_positive(id, 1)));"
+            ),
+            Err(Error::NoMatchingFunctionOverload { function_name, table_name, .. })
+                if function_name == "is_positive" && table_name == "t"
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE FUNCTION is_positive(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;' LANGUAGE SQL;
+                 CREATE TABLE t (id BIGINT CHECK (is_positive(id)));"
+            ),
+            Err(Error::NoMatchingFunctionOverload { .. })
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE TABLE t (id INT, created_at TIMESTAMP);
+                 CREATE POLICY recent ON t USING (length(created_at) > 0);"
+            ),
+            Err(Error::NoMatchingFunctionOverload { call, .. }) if call == "length(created_at)"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_functions_and_lenient_parsing_are_not_validated() {
+        ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT CHECK (id = current_setting('app.id')::INT));",
+        )
+        .unwrap();
+        ParserDB::parse::<SQLiteDialect>("CREATE TABLE t (id INT CHECK (length(id) > 0));")
+            .unwrap();
+        ParserDB::parse_lenient::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT CHECK (length(id) > 0));",
+        )
+        .unwrap();
+    }
+}
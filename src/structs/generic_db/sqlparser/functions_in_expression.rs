@@ -5,17 +5,18 @@ use alloc::{sync::Arc, vec::Vec};
 use sqlparser::ast::{Expr, ObjectName, ObjectNamePart};
 
 use crate::{
+    structs::IdentifierCasing,
     traits::{DatabaseLike, function_like::FunctionLike},
-    utils::identifier_resolution::identifiers_match,
 };
 
 fn function_matches_object_name<DB: DatabaseLike>(
     function: &DB::Function,
     object_name: &ObjectName,
+    casing: IdentifierCasing,
 ) -> bool {
     match object_name.0.last() {
         Some(ObjectNamePart::Identifier(ident)) => {
-            identifiers_match(
+            casing.matches(
                 function.name(),
                 function.name_is_quoted(),
                 ident.value.as_str(),
@@ -23,7 +24,7 @@ fn function_matches_object_name<DB: DatabaseLike>(
             )
         }
         Some(ObjectNamePart::Function(function_part)) => {
-            identifiers_match(
+            casing.matches(
                 function.name(),
                 function.name_is_quoted(),
                 function_part.name.value.as_str(),
@@ -37,6 +38,7 @@ fn function_matches_object_name<DB: DatabaseLike>(
 pub(super) fn functions_in_expression<DB: DatabaseLike>(
     expr: &Expr,
     functions: &[Arc<DB::Function>],
+    casing: IdentifierCasing,
 ) -> Vec<Arc<DB::Function>> {
     let mut result = Vec::new();
 
@@ -46,7 +48,7 @@ pub(super) fn functions_in_expression<DB: DatabaseLike>(
             result.extend(
                 functions
                     .iter()
-                    .filter(|f| function_matches_object_name::<DB>(f.as_ref(), &func.name))
+                    .filter(|f| function_matches_object_name::<DB>(f.as_ref(), &func.name, casing))
                     .cloned(),
             );
 
@@ -61,7 +63,7 @@ pub(super) fn functions_in_expression<DB: DatabaseLike>(
                         | sqlparser::ast::FunctionArg::Unnamed(
                             sqlparser::ast::FunctionArgExpr::Expr(expr),
                         ) => {
-                            result.extend(functions_in_expression::<DB>(expr, functions));
+                            result.extend(functions_in_expression::<DB>(expr, functions, casing));
                         }
                         sqlparser::ast::FunctionArg::ExprNamed { .. }
                         | sqlparser::ast::FunctionArg::Named { .. }
@@ -71,31 +73,31 @@ pub(super) fn functions_in_expression<DB: DatabaseLike>(
             }
         }
         Expr::BinaryOp { left, right, .. } => {
-            result.extend(functions_in_expression::<DB>(left, functions));
-            result.extend(functions_in_expression::<DB>(right, functions));
+            result.extend(functions_in_expression::<DB>(left, functions, casing));
+            result.extend(functions_in_expression::<DB>(right, functions, casing));
         }
         Expr::Nested(nested_expr) => {
-            result.extend(functions_in_expression::<DB>(nested_expr, functions));
+            result.extend(functions_in_expression::<DB>(nested_expr, functions, casing));
         }
         Expr::Between { expr, negated: _, low, high } => {
-            result.extend(functions_in_expression::<DB>(expr, functions));
-            result.extend(functions_in_expression::<DB>(low, functions));
-            result.extend(functions_in_expression::<DB>(high, functions));
+            result.extend(functions_in_expression::<DB>(expr, functions, casing));
+            result.extend(functions_in_expression::<DB>(low, functions, casing));
+            result.extend(functions_in_expression::<DB>(high, functions, casing));
         }
         Expr::UnaryOp { expr, .. }
         | Expr::Cast { expr, .. }
         | Expr::IsNull(expr)
         | Expr::IsNotNull(expr) => {
-            result.extend(functions_in_expression::<DB>(expr, functions));
+            result.extend(functions_in_expression::<DB>(expr, functions, casing));
         }
         Expr::InList { expr, list, .. } => {
-            result.extend(functions_in_expression::<DB>(expr, functions));
+            result.extend(functions_in_expression::<DB>(expr, functions, casing));
             for list_expr in list {
-                result.extend(functions_in_expression::<DB>(list_expr, functions));
+                result.extend(functions_in_expression::<DB>(list_expr, functions, casing));
             }
         }
         Expr::InSubquery { expr, .. } => {
-            result.extend(functions_in_expression::<DB>(expr, functions));
+            result.extend(functions_in_expression::<DB>(expr, functions, casing));
             // Note: We don't traverse into subqueries as they have their own
             // scope
         }
@@ -0,0 +1,349 @@
+//! Incremental re-parsing of the SQL files a [`ParserDB`] was loaded from.
+//!
+//! The statements parsed from each file are kept along with the SHA-256
+//! digest of its content, so that updating the database only re-parses the
+//! files whose content changed before rebuilding it from all the statements.
+
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use sqlparser::{ast::Statement, dialect::Dialect, parser::ParserError};
+
+use super::{
+    ParserDB, is_sql_document,
+    pre_passes::{StrippedSql, StrippedSyntax},
+    search_sql_documents,
+    unparsed_statements::Unparsed,
+};
+use crate::{
+    impls::SqlparserDialect,
+    structs::{ObjectFilter, ParserOptions},
+};
+
+/// A SQL file parsed while loading a database from paths.
+#[derive(Debug, Clone)]
+pub(super) struct ParsedFile {
+    /// Index of the path the file was found under, among the loaded paths.
+    pub(super) root: usize,
+    /// Path of the file.
+    pub(super) path: PathBuf,
+    /// Digest of the content of the file.
+    content_hash: [u8; 32],
+    /// Content of the file, without the statements stripped before parsing.
+    pub(super) stripped_sql: String,
+    /// Statements parsed from the file.
+    pub(super) statements: Vec<Statement>,
+    /// Syntax stripped from the statements of the file before parsing.
+    pub(super) stripped_syntax: StrippedSyntax,
+    /// Statements stripped from the file, positioned among its statements.
+    pub(super) unparsed: Vec<Unparsed>,
+}
+
+/// Returns the digest of the content of a file, which unlike the hashers of
+/// the standard library is stable across builds and processes.
+fn content_hash(content: &str) -> [u8; 32] {
+    Sha256::digest(content.as_bytes()).into()
+}
+
+impl ParsedFile {
+    /// Reads and parses the SQL file at the provided path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed.
+    pub(super) fn parse<D: Dialect + Default>(
+        root: usize,
+        path: PathBuf,
+    ) -> Result<Self, crate::errors::Error> {
+        let file_error =
+            |error| crate::errors::Error::SqlParserError { error, file: Some(path.clone()) };
+        let sql_content = std::fs::read_to_string(&path)
+            .map_err(|error| crate::errors::Error::Io { path: path.clone(), error })?;
+        Self::parse_content::<D>(root, path.clone(), &sql_content).map_err(file_error)
+    }
+
+    /// Parses the provided content of the SQL file at the provided path.
+    fn parse_content<D: Dialect + Default>(
+        root: usize,
+        path: PathBuf,
+        sql_content: &str,
+    ) -> Result<Self, ParserError> {
+        let dialect = D::default();
+        let stripped_sql = StrippedSql::new(&dialect, sql_content);
+        let (statements, stripped_syntax) = stripped_sql.parse(&dialect)?;
+        Ok(Self {
+            root,
+            path,
+            content_hash: content_hash(sql_content),
+            stripped_sql: stripped_sql.sql,
+            statements,
+            stripped_syntax,
+            unparsed: stripped_sql.unparsed,
+        })
+    }
+}
+
+/// The SQL files a database was loaded from, along with how they were
+/// loaded.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceCache {
+    /// The loaded paths, each either a SQL file or a directory.
+    pub(super) roots: Vec<PathBuf>,
+    /// The dialect the files were parsed under.
+    pub(super) dialect: SqlparserDialect,
+    /// The filter selecting the schemas and tables to load.
+    pub(super) object_filter: ObjectFilter,
+    /// The options validating the schema.
+    pub(super) options: ParserOptions,
+    /// The parsed files, sorted by the index of their root and then by path.
+    pub(super) files: Vec<ParsedFile>,
+}
+
+impl SourceCache {
+    /// Returns the index of the loaded path the provided path is found
+    /// under, if any.
+    fn root_of(&self, path: &Path) -> Option<usize> {
+        self.roots.iter().position(|root| path.starts_with(root))
+    }
+
+    /// Updates the files from the provided changed paths, returning whether
+    /// any of them changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a changed file can't be read or parsed.
+    fn update<D: Dialect + Default>(
+        &mut self,
+        changed: &[PathBuf],
+    ) -> Result<bool, crate::errors::Error> {
+        let file_count = self.files.len();
+        self.files.retain(|file| file.path.exists());
+        let mut updated = self.files.len() != file_count;
+
+        for changed_path in changed {
+            if !changed_path.exists() {
+                continue;
+            }
+            let sql_paths = if changed_path.is_dir() {
+                search_sql_documents(changed_path)?
+            } else if is_sql_document(changed_path) {
+                vec![changed_path.clone()]
+            } else {
+                Vec::new()
+            };
+            for sql_path in sql_paths {
+                let Some(root) = self.root_of(&sql_path) else {
+                    continue;
+                };
+                let position = self
+                    .files
+                    .binary_search_by(|file| (file.root, &file.path).cmp(&(root, &sql_path)));
+                let sql_content = std::fs::read_to_string(&sql_path)
+                    .map_err(|error| crate::errors::Error::Io { path: sql_path.clone(), error })?;
+                if let Ok(position) = position
+                    && self.files[position].content_hash == content_hash(&sql_content)
+                {
+                    continue;
+                }
+                let file = ParsedFile::parse_content::<D>(root, sql_path.clone(), &sql_content)
+                    .map_err(|error| crate::errors::Error::SqlParserError {
+                        error,
+                        file: Some(sql_path),
+                    })?;
+                match position {
+                    Ok(position) => self.files[position] = file,
+                    Err(position) => self.files.insert(position, file),
+                }
+                updated = true;
+            }
+        }
+        Ok(updated)
+    }
+}
+
+impl ParserDB {
+    /// Updates a database loaded with [`Self::from_paths`] from the provided
+    /// changed paths, only re-parsing the files whose content changed before
+    /// rebuilding the database.
+    ///
+    /// Each changed path may be a SQL file which was modified, added or
+    /// removed, or a directory whose SQL files are all checked. Added files
+    /// are only loaded when found under one of the paths the database was
+    /// loaded from, and removed files are dropped wherever they were. The
+    /// database is rebuilt with the [`ObjectFilter`] and the [`ParserOptions`]
+    /// it was loaded with, and is left untouched when an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `changed` - The paths of the changed files or directories.
+    ///
+    /// The dialect must be the one the database was loaded under, as the
+    /// files which did not change are not parsed again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database was not loaded from paths, if it was
+    /// loaded under another dialect, if a changed file can't be read or
+    /// parsed, or if the rebuilt database is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let mut db = ParserDB::from_path::<PostgreSqlDialect>(Path::new("migrations/")).unwrap();
+    /// // ... `migrations/0002_users/up.sql` is edited ...
+    /// db.update_from_paths::<PostgreSqlDialect>(&[PathBuf::from("migrations/0002_users/up.sql")])
+    ///     .unwrap();
+    /// ```
+    pub fn update_from_paths<D: Dialect + Default + 'static>(
+        &mut self,
+        changed: &[PathBuf],
+    ) -> Result<(), crate::errors::Error> {
+        let Some(source_cache) = &self.source_cache else {
+            return Err(crate::errors::Error::NotLoadedFromPaths);
+        };
+        let requested = SqlparserDialect::of::<D>();
+        if requested != source_cache.dialect {
+            return Err(crate::errors::Error::DialectMismatch {
+                loaded: source_cache.dialect,
+                requested,
+            });
+        }
+        let mut source_cache = SourceCache::clone(source_cache);
+        if source_cache.update::<D>(changed)? {
+            *self = Self::from_source_cache::<D>(source_cache)?;
+        }
+        Ok(())
+    }
+
+    /// Builds a database from the files of the provided source cache, with
+    /// the filter and the options it holds, which the database keeps for
+    /// later updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database is invalid.
+    pub(super) fn from_source_cache<D: Dialect + Default + 'static>(
+        source_cache: SourceCache,
+    ) -> Result<Self, crate::errors::Error> {
+        let mut db = Self::from_parsed_files::<D>(
+            &source_cache.files,
+            source_cache.object_filter.clone(),
+            source_cache.options.clone(),
+        )?;
+        db.source_cache = Some(Arc::new(source_cache));
+        Ok(db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::{
+        structs::{ValidationClass, ValidationLevel},
+        traits::DatabaseLike,
+    };
+
+    #[test]
+    fn test_update_from_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        let users = dir.join("01_users.sql");
+        let posts = dir.join("02_posts.sql");
+        std::fs::write(&users, "CREATE TABLE users (id INT PRIMARY KEY);").unwrap();
+        std::fs::write(&posts, "CREATE TABLE posts (id INT PRIMARY KEY);").unwrap();
+
+        let mut db = ParserDB::from_path::<GenericDialect>(dir).unwrap();
+        assert_eq!(db.tables().count(), 2);
+
+        std::fs::write(&posts, "CREATE TABLE posts (id INT, user_id INT REFERENCES users (id));")
+            .unwrap();
+        let comments = dir.join("03_comments.sql");
+        std::fs::write(&comments, "CREATE TABLE comments (id INT);").unwrap();
+        std::fs::remove_file(&users).unwrap();
+        assert!(db.update_from_paths::<GenericDialect>(&[posts.clone(), users.clone()]).is_err());
+        assert_eq!(db.tables().count(), 2);
+
+        std::fs::write(&users, "CREATE TABLE users (id INT PRIMARY KEY);").unwrap();
+        db.update_from_paths::<GenericDialect>(&[posts, comments, users]).unwrap();
+        assert_eq!(db.tables().count(), 3);
+        assert_eq!(db.foreign_keys().count(), 1);
+    }
+
+    #[test]
+    fn test_update_keeps_options() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path();
+        let schema = dir.join("schema.sql");
+        std::fs::write(&schema, "CREATE TABLE t (id INT); CREATE INDEX a ON missing (id);")
+            .unwrap();
+
+        let options = ParserOptions::default()
+            .with_level(ValidationClass::MissingObjects, ValidationLevel::Warn);
+        assert!(ParserDB::from_path::<GenericDialect>(dir).is_err());
+        let mut db = ParserDB::from_paths_with_options::<GenericDialect>(
+            &[dir],
+            ObjectFilter::default(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(db.validation_warnings().len(), 1);
+
+        std::fs::write(
+            &schema,
+            "CREATE TABLE t (id INT); CREATE INDEX a ON missing (id); CREATE INDEX b ON gone (id);",
+        )
+        .unwrap();
+        db.update_from_paths::<GenericDialect>(&[schema]).unwrap();
+        assert_eq!(db.tables().count(), 1);
+        assert_eq!(db.validation_warnings().len(), 2);
+    }
+
+    #[test]
+    fn test_update_rejects_another_dialect() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let schema = temp_dir.path().join("schema.sql");
+        std::fs::write(&schema, "CREATE TABLE t (id INT);").unwrap();
+
+        let mut db = ParserDB::from_path::<GenericDialect>(temp_dir.path()).unwrap();
+        std::fs::write(&schema, "CREATE TABLE t (id INT); CREATE TABLE u (id INT);").unwrap();
+        assert!(matches!(
+            db.update_from_paths::<PostgreSqlDialect>(&[schema.clone()]),
+            Err(crate::errors::Error::DialectMismatch {
+                loaded: SqlparserDialect::Generic,
+                requested: SqlparserDialect::PostgreSql,
+            })
+        ));
+        assert_eq!(db.tables().count(), 1);
+
+        db.update_from_paths::<GenericDialect>(&[schema]).unwrap();
+        assert_eq!(db.tables().count(), 2);
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        // The SHA-256 digest of the empty string.
+        assert_eq!(content_hash("")[..4], [0xe3, 0xb0, 0xc4, 0x42]);
+        assert_ne!(content_hash("CREATE TABLE t (id INT);"), content_hash("CREATE TABLE t ();"));
+    }
+
+    #[test]
+    fn test_update_without_paths() {
+        let mut db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT);").unwrap();
+        assert!(matches!(
+            db.update_from_paths::<GenericDialect>(&[]),
+            Err(crate::errors::Error::NotLoadedFromPaths)
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+//! Tracking of the storage parameters changed by `ALTER INDEX ... SET` and
+//! `ALTER INDEX ... RESET`.
+//!
+//! As `sqlparser` only parses the `RENAME TO` form of `ALTER INDEX`, the
+//! statements changing storage parameters are stripped from the SQL before
+//! parsing, see the `unparsed_statements` module.
+
+use alloc::vec::Vec;
+
+use sqlparser::{
+    ast::{BinaryOperator, Expr, Ident, ObjectName},
+    dialect::Dialect,
+    keywords::Keyword,
+    parser::{Parser, ParserError},
+    tokenizer::{Token, TokenWithSpan},
+};
+
+use crate::structs::IdentifierCasing;
+
+/// A change of the storage parameters of an index.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum IndexSettingsChange {
+    /// `SET (name = value, ...)`, setting the provided parameters.
+    Set(Vec<Expr>),
+    /// `RESET (name, ...)`, restoring the provided parameters to their
+    /// defaults.
+    Reset(Vec<Ident>),
+}
+
+/// Returns the name of a storage parameter, written either `name = value`
+/// or as a bare `name`.
+fn parameter_name(parameter: &Expr) -> Option<&Ident> {
+    match parameter {
+        Expr::BinaryOp { left, op: BinaryOperator::Eq, .. } => match left.as_ref() {
+            Expr::Identifier(ident) => Some(ident),
+            _ => None,
+        },
+        Expr::Identifier(ident) => Some(ident),
+        _ => None,
+    }
+}
+
+/// Returns whether the storage parameter has the provided name.
+fn is_named(parameter: &Expr, name: &Ident, casing: IdentifierCasing) -> bool {
+    parameter_name(parameter).is_some_and(|parameter_name| {
+        casing.matches(
+            &parameter_name.value,
+            parameter_name.quote_style.is_some(),
+            &name.value,
+            name.quote_style.is_some(),
+        )
+    })
+}
+
+impl IndexSettingsChange {
+    /// Applies the change to the storage parameters of an index, comparing
+    /// their names through the provided casing.
+    pub(super) fn apply(&self, parameters: &mut Vec<Expr>, casing: IdentifierCasing) {
+        match self {
+            Self::Set(settings) => {
+                for setting in settings {
+                    if let Some(name) = parameter_name(setting) {
+                        parameters.retain(|parameter| !is_named(parameter, name, casing));
+                    }
+                    parameters.push(setting.clone());
+                }
+            }
+            Self::Reset(names) => {
+                parameters.retain(|parameter| {
+                    !names.iter().any(|name| is_named(parameter, name, casing))
+                });
+            }
+        }
+    }
+}
+
+/// Parses an `ALTER INDEX ... SET (...)` or `ALTER INDEX ... RESET (...)`
+/// statement from its tokens, without the trailing semicolon.
+///
+/// Returns `None` for any other statement, including the other forms of
+/// `ALTER INDEX`.
+pub(super) fn parse_index_settings(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Option<(ObjectName, IndexSettingsChange)>, ParserError> {
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(tokens.to_vec());
+    if !parser.parse_keywords(&[Keyword::ALTER, Keyword::INDEX]) {
+        return Ok(None);
+    }
+    let name = parser.parse_object_name(false)?;
+    let change = if parser.parse_keyword(Keyword::SET) {
+        parser.expect_token(&Token::LParen)?;
+        let settings = parser.parse_comma_separated(Parser::parse_expr)?;
+        parser.expect_token(&Token::RParen)?;
+        IndexSettingsChange::Set(settings)
+    } else if parser.parse_keyword(Keyword::RESET) {
+        parser.expect_token(&Token::LParen)?;
+        let names = parser.parse_comma_separated(Parser::parse_identifier)?;
+        parser.expect_token(&Token::RParen)?;
+        IndexSettingsChange::Reset(names)
+    } else {
+        return Ok(None);
+    };
+    parser.expect_token(&Token::EOF)?;
+    Ok(Some((name, change)))
+}
@@ -0,0 +1,250 @@
+//! Handling of the SQL Server constructs which `sqlparser` cannot parse.
+//!
+//! Scripts written for SQL Server separate their batches with `GO`, which is
+//! a command of the client tools rather than a statement, and declare the
+//! clustering of their indexes and constraints with the `CLUSTERED` and
+//! `NONCLUSTERED` keywords. Both are blanked out of the SQL before parsing:
+//! the lines made of a `GO` command become statement separators, and the
+//! indexes created `CLUSTERED` are recovered from the spans of their names.
+
+use alloc::{string::String, vec::Vec};
+use core::any::TypeId;
+
+use sqlparser::{
+    ast::{ObjectName, Spanned, Statement},
+    dialect::{Dialect, MsSqlDialect},
+    keywords::Keyword,
+    tokenizer::{Span, Token, TokenWithSpan, Tokenizer},
+};
+
+use super::ParserDB;
+use crate::{traits::TableLike, utils::byte_offset};
+
+/// An index declared `CLUSTERED`, along with the name of its table as written
+/// in the statement creating it.
+pub(super) type ClusteredIndex = (ObjectName, ObjectName);
+
+/// Returns whether the line is a `GO` command, optionally followed by the
+/// number of times the batch is to be executed.
+fn is_batch_separator(line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    words.next().is_some_and(|word| word.eq_ignore_ascii_case("GO"))
+        && words.next().is_none_or(|count| count.bytes().all(|byte| byte.is_ascii_digit()))
+        && words.next().is_none()
+}
+
+/// Replaces the `GO` batch separators of a SQL Server script by semicolons,
+/// so that the batches are parsed as a sequence of statements.
+///
+/// The separators are padded with spaces, so that the locations of the
+/// remaining tokens are preserved. Lines within string literals and comments
+/// are kept as they are. The SQL of any other dialect, and SQL which cannot
+/// be tokenized, is returned as is.
+pub(super) fn strip_batch_separators(dialect: &dyn Dialect, sql: &str) -> String {
+    if dialect.dialect() != TypeId::of::<MsSqlDialect>() {
+        return String::from(sql);
+    }
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return String::from(sql);
+    };
+    // The words within string literals and comments are part of their
+    // tokens, so only the lines on which a `GO` word starts are separators.
+    let separator_lines: Vec<u64> = tokens
+        .iter()
+        .filter(|token| is_word(token, "GO"))
+        .map(|token| token.span.start.line)
+        .collect();
+
+    let mut stripped = String::with_capacity(sql.len());
+    for (line_number, line) in (1..).zip(sql.split_inclusive('\n')) {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if is_batch_separator(content) && separator_lines.contains(&line_number) {
+            stripped.push(';');
+            stripped.push_str(&" ".repeat(content.chars().count() - 1));
+            stripped.push_str(&line[content.len()..]);
+        } else {
+            stripped.push_str(line);
+        }
+    }
+    stripped
+}
+
+/// Returns whether the token is a word matching the provided value,
+/// case-insensitively.
+fn is_word(token: &TokenWithSpan, value: &str) -> bool {
+    matches!(&token.token, Token::Word(word) if word.value.eq_ignore_ascii_case(value))
+}
+
+/// Returns whether the token is the provided keyword.
+fn is_keyword(token: &TokenWithSpan, keyword: Keyword) -> bool {
+    matches!(&token.token, Token::Word(word) if word.keyword == keyword)
+}
+
+/// Blanks out the `CLUSTERED` and `NONCLUSTERED` keywords of a SQL Server
+/// script, returning the SQL without them along with the spans of the names
+/// of the indexes created `CLUSTERED`.
+///
+/// The keywords are only blanked out after `CREATE`, `CREATE UNIQUE`,
+/// `PRIMARY KEY` and `UNIQUE`. The clustering of primary key and unique
+/// constraints is not tracked. The SQL of any other dialect, and SQL which
+/// cannot be tokenized, is returned as is.
+pub(super) fn strip_clustering(dialect: &dyn Dialect, sql: &str) -> (String, Vec<Span>) {
+    let mut stripped = String::from(sql);
+    if dialect.dialect() != TypeId::of::<MsSqlDialect>() {
+        return (stripped, Vec::new());
+    }
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return (stripped, Vec::new());
+    };
+    let tokens: Vec<TokenWithSpan> =
+        tokens.into_iter().filter(|token| !matches!(token.token, Token::Whitespace(_))).collect();
+
+    let mut clustered = Vec::new();
+    for (position, token) in tokens.iter().enumerate() {
+        let is_clustered = is_word(token, "CLUSTERED");
+        if (!is_clustered && !is_word(token, "NONCLUSTERED"))
+            || position.checked_sub(1).is_none_or(|previous| {
+                !is_keyword(&tokens[previous], Keyword::CREATE)
+                    && !is_keyword(&tokens[previous], Keyword::UNIQUE)
+                    && !is_keyword(&tokens[previous], Keyword::KEY)
+            })
+        {
+            continue;
+        }
+        if let (Some(start), Some(end)) =
+            (byte_offset(sql, token.span.start), byte_offset(sql, token.span.end))
+        {
+            stripped.replace_range(start..end, &" ".repeat(end - start));
+        }
+        if is_clustered
+            && let [index, rest @ ..] = &tokens[position + 1..]
+            && is_keyword(index, Keyword::INDEX)
+            && let Some(on) = rest.iter().find(|token| is_keyword(token, Keyword::ON))
+        {
+            clustered.push(Span::new(index.span.end, on.span.start));
+        }
+    }
+    (stripped, clustered)
+}
+
+/// Returns the names of the indexes created by the statements whose names
+/// lie within the provided spans, as returned by [`strip_clustering`], along
+/// with the names of their tables.
+pub(super) fn clustered_indexes(
+    statements: &[Statement],
+    clustered: &[Span],
+) -> Vec<ClusteredIndex> {
+    if clustered.is_empty() {
+        return Vec::new();
+    }
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::CreateIndex(create_index) => {
+                let name = create_index.name.as_ref()?;
+                let start = name.span().start;
+                clustered
+                    .iter()
+                    .any(|span| span.start <= start && start < span.end)
+                    .then(|| (create_index.table_name.clone(), name.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+impl ParserDB {
+    /// Flags the indexes created `CLUSTERED`.
+    pub(super) fn mark_clustered_indexes(&mut self, indexes: &[ClusteredIndex]) {
+        if indexes.is_empty() {
+            return;
+        }
+        for (index, metadata) in self.indices_metadata_mut() {
+            if indexes.iter().any(|(table_name, name)| {
+                index.attribute().name.as_ref() == Some(name)
+                    && metadata.table().name == *table_name
+            }) {
+                metadata.set_clustered(true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{GenericDialect, MsSqlDialect};
+
+    use super::*;
+    use crate::traits::{ColumnLike, DatabaseLike, IndexLike};
+
+    #[test]
+    fn test_batch_separators_are_replaced() {
+        let sql = "CREATE TABLE a (id INT)\nGO\nCREATE TABLE b (id INT)\r\n  go 2\n";
+        assert_eq!(
+            strip_batch_separators(&MsSqlDialect {}, sql),
+            "CREATE TABLE a (id INT)\n; \nCREATE TABLE b (id INT)\r\n;     \n"
+        );
+        assert_eq!(strip_batch_separators(&GenericDialect {}, sql), sql);
+        assert!(!is_batch_separator("GOTO label"));
+        assert!(!is_batch_separator("GO TO"));
+    }
+
+    #[test]
+    fn test_batch_separators_within_literals_are_kept() {
+        let sql = "SELECT 'a\nGO\nb'\nGO\n/*\ngo 2\n*/\n";
+        assert_eq!(
+            strip_batch_separators(&MsSqlDialect {}, sql),
+            "SELECT 'a\nGO\nb'\n; \n/*\ngo 2\n*/\n"
+        );
+
+        let db = ParserDB::parse::<MsSqlDialect>(
+            "CREATE TABLE notes (body NVARCHAR(100) DEFAULT 'first\nGO\nsecond')\nGO\n",
+        )
+        .expect("Failed to parse");
+        let body = db.table(None, "notes").unwrap().column("body", &db).unwrap();
+        assert!(body.default_value().is_some_and(|value| value.contains("\nGO\n")));
+    }
+
+    #[test]
+    fn test_sql_server_script_is_parsed() {
+        let db = ParserDB::parse::<MsSqlDialect>(
+            "
+            CREATE SCHEMA sales;
+            GO
+            CREATE TABLE sales.customers (
+                id INT IDENTITY(1,1) NOT NULL,
+                name NVARCHAR(100) NOT NULL,
+                is_active BIT NOT NULL,
+                CONSTRAINT pk_customers PRIMARY KEY CLUSTERED (id)
+            )
+            GO
+            CREATE TABLE sales.orders (
+                id BIGINT IDENTITY(1,1) PRIMARY KEY NONCLUSTERED,
+                customer_id INT NOT NULL REFERENCES sales.customers (id),
+                placed_at DATETIME NOT NULL
+            )
+            GO
+            CREATE CLUSTERED INDEX ix_orders_placed_at ON sales.orders (placed_at)
+            GO
+            CREATE UNIQUE NONCLUSTERED INDEX ix_orders_customer ON sales.orders (customer_id, id)
+            GO
+            ",
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(db.schemas().count(), 1);
+        let customers = db.table(Some("sales"), "customers").unwrap();
+        let orders = db.table(Some("sales"), "orders").unwrap();
+        assert!(customers.column("id", &db).unwrap().is_auto_generated(&db));
+        assert!(orders.column("id", &db).unwrap().is_auto_generated(&db));
+        assert!(!orders.column("placed_at", &db).unwrap().is_auto_generated(&db));
+        assert!(customers.has_primary_key(&db));
+        assert!(orders.has_primary_key(&db));
+
+        let clustering: Vec<(&str, bool)> = orders
+            .indices(&db)
+            .map(|index| (index.index_name().unwrap(), index.is_clustered(&db)))
+            .collect();
+        assert_eq!(clustering, [("ix_orders_placed_at", true), ("ix_orders_customer", false)]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Tracking of the check constraints declared `NO INHERIT`.
+//!
+//! As `sqlparser` does not parse the `NO INHERIT` flag of check constraints,
+//! the flag is blanked out of the SQL before parsing, and the constraints it
+//! followed are recovered from the spans of their expressions.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::{
+    ast::{
+        AlterTableOperation, CheckConstraint, ColumnOption, ObjectName, Spanned, Statement,
+        TableConstraint,
+    },
+    dialect::Dialect,
+    keywords::Keyword,
+    tokenizer::{Span, Token, TokenWithSpan, Tokenizer},
+};
+
+use super::ParserDB;
+use crate::utils::byte_offset;
+
+/// A check constraint declared `NO INHERIT`, along with the name of its
+/// table as written in the statement declaring it.
+pub(super) type NoInheritCheck = (ObjectName, CheckConstraint);
+
+/// Returns whether the token is the provided keyword.
+fn is_keyword(token: &TokenWithSpan, keyword: Keyword) -> bool {
+    matches!(&token.token, Token::Word(word) if word.keyword == keyword)
+}
+
+/// Blanks out the `NO INHERIT` flags following check constraints, returning
+/// the SQL without them along with the spans of the parenthesized
+/// expressions of the flagged constraints.
+///
+/// The flags are replaced by spaces, so that the locations of the remaining
+/// tokens are preserved. SQL which cannot be tokenized is returned as is,
+/// leaving the parser to report the error.
+pub(super) fn strip_no_inherit(dialect: &dyn Dialect, sql: &str) -> (String, Vec<Span>) {
+    let mut stripped = String::from(sql);
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return (stripped, Vec::new());
+    };
+    let tokens: Vec<TokenWithSpan> =
+        tokens.into_iter().filter(|token| !matches!(token.token, Token::Whitespace(_))).collect();
+
+    let mut flagged = Vec::new();
+    for (position, token) in tokens.iter().enumerate() {
+        if !is_keyword(token, Keyword::CHECK)
+            || tokens.get(position + 1).is_none_or(|open| open.token != Token::LParen)
+        {
+            continue;
+        }
+        let mut depth = 0usize;
+        let Some(close) = tokens.iter().skip(position + 1).position(|token| {
+            match token.token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        }) else {
+            continue;
+        };
+        let close = position + 1 + close;
+        if let [no, inherit, ..] = &tokens[close + 1..]
+            && is_keyword(no, Keyword::NO)
+            && is_keyword(inherit, Keyword::INHERIT)
+        {
+            for flag in [no, inherit] {
+                if let (Some(start), Some(end)) =
+                    (byte_offset(sql, flag.span.start), byte_offset(sql, flag.span.end))
+                {
+                    stripped.replace_range(start..end, &" ".repeat(end - start));
+                }
+            }
+            flagged.push(Span::new(tokens[position + 1].span.start, tokens[close].span.end));
+        }
+    }
+    (stripped, flagged)
+}
+
+/// Returns the check constraints of the statements whose expressions lie
+/// within the provided spans, as returned by [`strip_no_inherit`].
+pub(super) fn no_inherit_checks(statements: &[Statement], flagged: &[Span]) -> Vec<NoInheritCheck> {
+    let is_flagged = |check: &&CheckConstraint| {
+        let start = check.expr.span().start;
+        flagged.iter().any(|span| span.start <= start && start < span.end)
+    };
+    let mut checks = Vec::new();
+    if flagged.is_empty() {
+        return checks;
+    }
+    for statement in statements {
+        match statement {
+            Statement::CreateTable(create_table) => {
+                let column_checks =
+                    create_table.columns.iter().flat_map(|column| &column.options).filter_map(
+                        |option| match &option.option {
+                            ColumnOption::Check(check) => Some(check),
+                            _ => None,
+                        },
+                    );
+                let table_checks =
+                    create_table.constraints.iter().filter_map(|constraint| match constraint {
+                        TableConstraint::Check(check) => Some(check),
+                        _ => None,
+                    });
+                checks.extend(
+                    column_checks
+                        .chain(table_checks)
+                        .filter(is_flagged)
+                        .map(|check| (create_table.name.clone(), check.clone())),
+                );
+            }
+            Statement::AlterTable(alter_table) => {
+                checks.extend(
+                    alter_table
+                        .operations
+                        .iter()
+                        .filter_map(|operation| match operation {
+                            AlterTableOperation::AddConstraint {
+                                constraint: TableConstraint::Check(check),
+                                ..
+                            } => Some(check),
+                            _ => None,
+                        })
+                        .filter(is_flagged)
+                        .map(|check| (alter_table.name.clone(), check.clone())),
+                );
+            }
+            _ => {}
+        }
+    }
+    checks
+}
+
+impl ParserDB {
+    /// Flags the check constraints declared `NO INHERIT`.
+    pub(super) fn mark_no_inherit_checks(&mut self, checks: &[NoInheritCheck]) {
+        if checks.is_empty() {
+            return;
+        }
+        for (check, metadata) in self.check_constraints_metadata_mut() {
+            if checks.iter().any(|(table_name, no_inherit)| {
+                no_inherit == check.attribute() && metadata.table().name == *table_name
+            }) {
+                metadata.set_no_inherit(true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        impls::SqlparserDialect,
+        structs::ParserDB,
+        traits::{CheckConstraintLike, DatabaseLike, TableLike},
+    };
+
+    #[test]
+    fn test_no_inherit_flags_are_tracked() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE parent (
+                id INT PRIMARY KEY,
+                age INT CHECK (age > 0)
+                    NO
+                    INHERIT,
+                weight INT,
+                CONSTRAINT parent_weight_check CHECK ((weight + 1) > (0)) NO INHERIT
+            );
+            CREATE TABLE child (id INT PRIMARY KEY REFERENCES parent (id), age INT CHECK (age > 0));
+            ALTER TABLE child ADD CONSTRAINT child_id_check CHECK (id > 0) NO INHERIT;
+            ",
+        )
+        .expect("Failed to parse");
+
+        let parent = db.table(None, "parent").unwrap();
+        let flags: Vec<bool> =
+            parent.check_constraints(&db).map(|check| check.no_inherit(&db)).collect();
+        assert_eq!(flags, [true, true]);
+
+        let child = db.table(None, "child").unwrap();
+        let flags: Vec<(String, bool)> = child
+            .check_constraints(&db)
+            .map(|check| (check.expression(&db).to_string(), check.no_inherit(&db)))
+            .collect();
+        assert_eq!(flags, [("age > 0".to_string(), false), ("id > 0".to_string(), true)]);
+    }
+
+    #[test]
+    fn test_no_inherit_checks_are_not_inherited() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE parent (id INT PRIMARY KEY, age INT CHECK (age > 0) NO INHERIT);
+            CREATE TABLE child (id INT PRIMARY KEY REFERENCES parent (id));
+            CREATE TABLE grandchild (id INT PRIMARY KEY REFERENCES child (id));
+            ",
+        )
+        .expect("Failed to parse");
+
+        assert!(
+            db.table(None, "parent")
+                .unwrap()
+                .has_non_tautological_check_constraints_in_hierarchy(&db)
+        );
+        assert!(
+            !db.table(None, "child")
+                .unwrap()
+                .has_non_tautological_check_constraints_in_hierarchy(&db)
+        );
+        assert!(
+            !db.table(None, "grandchild")
+                .unwrap()
+                .has_non_tautological_check_constraints_in_hierarchy(&db)
+        );
+    }
+
+    #[test]
+    fn test_no_inherit_is_rendered() {
+        let sql = "CREATE TABLE parent (id INT, CHECK (id > 0) NO INHERIT);";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse");
+        assert_eq!(db.to_sql(SqlparserDialect::PostgreSql), format!("{sql}\n"));
+        assert_eq!(
+            db.to_sql(SqlparserDialect::SQLite),
+            "CREATE TABLE parent (id INT, CHECK (id > 0));\n"
+        );
+    }
+}
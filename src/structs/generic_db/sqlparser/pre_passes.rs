@@ -0,0 +1,111 @@
+//! Text pre-passes stripping from the SQL the syntax `sqlparser` can't parse.
+//!
+//! The pre-passes run one after the other on the SQL text, and record where
+//! the syntax they strip was found, so that it is restored on the objects
+//! parsed from the stripped SQL:
+//!
+//! - the `GO` batch separators and the `CLUSTERED` indexes of MSSQL, in
+//!   [`mssql`],
+//! - the `NO INHERIT` check constraints, in [`no_inherit`],
+//! - the `UNLOGGED` sequences, in [`unlogged_sequences`],
+//! - the statements `sqlparser` can't parse at all, in
+//!   [`unparsed_statements`].
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::{
+    ast::{ObjectName, Statement},
+    dialect::Dialect,
+    parser::{Parser, ParserError},
+    tokenizer::Span,
+};
+
+use super::{
+    ParserDB,
+    mssql::{self, ClusteredIndex},
+    no_inherit::{self, NoInheritCheck},
+    unlogged_sequences,
+    unparsed_statements::{self, Unparsed},
+};
+
+/// SQL text stripped of the syntax `sqlparser` can't parse.
+pub(super) struct StrippedSql {
+    /// The SQL left once stripped.
+    pub(super) sql: String,
+    /// Spans of the indexes declared `CLUSTERED` in the stripped SQL.
+    clustered_spans: Vec<Span>,
+    /// Spans of the check constraints declared `NO INHERIT` in the stripped
+    /// SQL.
+    no_inherit_spans: Vec<Span>,
+    /// Spans of the sequences created `UNLOGGED` in the stripped SQL.
+    unlogged_spans: Vec<Span>,
+    /// Statements stripped from the SQL, positioned among the statements
+    /// left.
+    pub(super) unparsed: Vec<Unparsed>,
+}
+
+impl StrippedSql {
+    /// Runs the pre-passes on the provided SQL.
+    pub(super) fn new(dialect: &dyn Dialect, sql: &str) -> Self {
+        let sql = mssql::strip_batch_separators(dialect, sql);
+        let (sql, clustered_spans) = mssql::strip_clustering(dialect, &sql);
+        let (sql, no_inherit_spans) = no_inherit::strip_no_inherit(dialect, &sql);
+        let (sql, unlogged_spans) = unlogged_sequences::strip_unlogged_sequences(dialect, &sql);
+        let (sql, unparsed) = unparsed_statements::strip_unparsed_statements(dialect, &sql);
+        Self { sql, clustered_spans, no_inherit_spans, unlogged_spans, unparsed }
+    }
+
+    /// Parses the statements left in the SQL, along with the syntax stripped
+    /// from them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the statements left can't be parsed.
+    pub(super) fn parse(
+        &self,
+        dialect: &dyn Dialect,
+    ) -> Result<(Vec<Statement>, StrippedSyntax), ParserError> {
+        let statements = Parser::new(dialect).try_with_sql(&self.sql)?.parse_statements()?;
+        let stripped = StrippedSyntax {
+            no_inherit_checks: no_inherit::no_inherit_checks(&statements, &self.no_inherit_spans),
+            clustered_indexes: mssql::clustered_indexes(&statements, &self.clustered_spans),
+            unlogged_sequences: unlogged_sequences::unlogged_sequences(
+                &statements,
+                &self.unlogged_spans,
+            ),
+        };
+        Ok((statements, stripped))
+    }
+}
+
+/// The syntax stripped from the statements, to restore on the objects they
+/// create.
+#[derive(Debug, Clone, Default)]
+pub(super) struct StrippedSyntax {
+    /// Check constraints declared `NO INHERIT`.
+    no_inherit_checks: Vec<NoInheritCheck>,
+    /// Indexes declared `CLUSTERED`.
+    clustered_indexes: Vec<ClusteredIndex>,
+    /// Sequences created `UNLOGGED`.
+    unlogged_sequences: Vec<ObjectName>,
+}
+
+impl StrippedSyntax {
+    /// Appends the syntax stripped from other statements.
+    #[cfg(feature = "std")]
+    pub(super) fn extend(&mut self, other: &Self) {
+        self.no_inherit_checks.extend(other.no_inherit_checks.iter().cloned());
+        self.clustered_indexes.extend(other.clustered_indexes.iter().cloned());
+        self.unlogged_sequences.extend(other.unlogged_sequences.iter().cloned());
+    }
+}
+
+impl ParserDB {
+    /// Restores the syntax stripped before parsing on the objects of the
+    /// database.
+    pub(super) fn restore_stripped_syntax(&mut self, stripped: &StrippedSyntax) {
+        self.mark_no_inherit_checks(&stripped.no_inherit_checks);
+        self.mark_clustered_indexes(&stripped.clustered_indexes);
+        self.mark_unlogged_sequences(&stripped.unlogged_sequences);
+    }
+}
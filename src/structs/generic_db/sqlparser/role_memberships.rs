@@ -0,0 +1,105 @@
+//! Memberships of roles in other roles, as granted by `CREATE ROLE ... IN
+//! ROLE` and `GRANT role TO role`.
+
+use alloc::{sync::Arc, vec::Vec};
+
+use sqlparser::ast::Ident;
+
+use super::{ParserDB, ParserDBBuilder, object_name_last_identifier};
+use crate::structs::IdentifierCasing;
+
+impl ParserDBBuilder {
+    /// Checks whether the role `role` is a member of the role `ancestor`,
+    /// directly or transitively, or is `ancestor` itself.
+    pub(super) fn is_role_member_of(&self, role: &Ident, ancestor: &Ident) -> bool {
+        let casing = self.identifier_casing();
+        let mut visited: Vec<&Ident> = Vec::new();
+        let mut pending = vec![ancestor];
+        while let Some(current) = pending.pop() {
+            if idents_match(current, role, casing) {
+                return true;
+            }
+            if visited.iter().any(|seen| idents_match(seen, current, casing)) {
+                continue;
+            }
+            visited.push(current);
+            // The roles having `current` as a parent are members of `ancestor`.
+            for (candidate, ()) in self.roles() {
+                if candidate.in_role.iter().any(|parent| idents_match(parent, current, casing))
+                    && let Some(name) =
+                        candidate.names.first().and_then(object_name_last_identifier)
+                {
+                    pending.push(name);
+                }
+            }
+        }
+        false
+    }
+}
+
+impl ParserDB {
+    /// Helper function to grant or revoke the membership of roles in other
+    /// roles, as done by `GRANT role TO role` and `REVOKE role FROM role`.
+    ///
+    /// Memberships are stored in the `IN ROLE` clause of the member roles.
+    /// Granting a membership which would make a role a member of itself,
+    /// directly or transitively, is rejected.
+    pub(super) fn role_membership_checked(
+        builder: &mut ParserDBBuilder,
+        grant: bool,
+        roles: &[Ident],
+        members: &[Ident],
+    ) -> Result<(), crate::errors::Error> {
+        let casing = builder.identifier_casing();
+        for ident in roles.iter().chain(members) {
+            if !builder.role_exists(ident) {
+                return Err(crate::errors::Error::RoleNotFoundForGrant {
+                    role_name: ident.value.clone(),
+                });
+            }
+        }
+
+        for member in members {
+            for role in roles {
+                if grant && builder.is_role_member_of(role, member) {
+                    return Err(crate::errors::Error::RoleMembershipCycle {
+                        role_name: role.value.clone(),
+                        member_name: member.value.clone(),
+                    });
+                }
+                let Some(position) =
+                    builder.role_position(&member.value, member.quote_style.is_some())
+                else {
+                    continue;
+                };
+                let (member_role, ()) = &mut builder.roles_mut()[position];
+                let is_member =
+                    member_role.in_role.iter().any(|parent| idents_match(parent, role, casing));
+                if grant {
+                    if !is_member {
+                        Arc::make_mut(member_role).in_role.push(role.clone());
+                    }
+                } else if is_member {
+                    Arc::make_mut(member_role)
+                        .in_role
+                        .retain(|parent| !idents_match(parent, role, casing));
+                } else {
+                    return Err(crate::errors::Error::RevokeNotFound(format!(
+                        "No membership of `{member}` in role `{role}` found for REVOKE"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether two identifiers designate the same object.
+fn idents_match(left: &Ident, right: &Ident, casing: IdentifierCasing) -> bool {
+    casing.matches(
+        left.value.as_str(),
+        left.quote_style.is_some(),
+        right.value.as_str(),
+        right.quote_style.is_some(),
+    )
+}
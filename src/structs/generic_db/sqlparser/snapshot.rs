@@ -0,0 +1,368 @@
+//! Serialization of a [`ParserDB`] through its [`SchemaSnapshot`], with the
+//! `serde` feature.
+
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+use sqlparser::ast::{CreateTable, PrimaryKeyConstraint, Statement, TableConstraint};
+
+use super::{
+    ParserDB, Validator, created_function_metadata, ddl::column_definition, mssql::ClusteredIndex,
+    no_inherit::NoInheritCheck,
+};
+use crate::{
+    structs::{
+        ObjectFilter, ParserOptions, SNAPSHOT_FORMAT_VERSION, SchemaSnapshot, ValidationClass,
+        ValidationLevel,
+    },
+    traits::{TableLike, UniqueIndexLike},
+};
+
+impl ParserDB {
+    /// Takes the versioned snapshot of the database, from which it can be
+    /// rebuilt with [`Self::from_snapshot`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// let json = serde_json::to_string(&db)?;
+    /// let reloaded: ParserDB = serde_json::from_str(&json)?;
+    /// assert_eq!(reloaded.tables().count(), 2);
+    /// assert_eq!(reloaded.foreign_keys().count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_snapshot(&self) -> SchemaSnapshot {
+        SchemaSnapshot::new(self)
+    }
+
+    /// Rebuilds a database from its snapshot, taken with
+    /// [`Self::to_snapshot`], without parsing any SQL.
+    ///
+    /// The objects holding no metadata derived from their definitions are
+    /// added as they are, while the tables, their indices and the policies
+    /// are applied as the statements they are defined by, so that their
+    /// metadata is derived again. As the snapshot was taken from a database
+    /// which passed its own validation, the default values of the columns
+    /// are not validated again against their data types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format version of the snapshot is not
+    /// [`SNAPSHOT_FORMAT_VERSION`], or if its tables, indices or policies
+    /// cannot be validated.
+    pub fn from_snapshot(snapshot: &SchemaSnapshot) -> Result<Self, crate::errors::Error> {
+        if snapshot.format_version() != SNAPSHOT_FORMAT_VERSION {
+            return Err(crate::errors::Error::UnsupportedSnapshotVersion {
+                found: snapshot.format_version(),
+                expected: SNAPSHOT_FORMAT_VERSION,
+            });
+        }
+        let object_filter = ObjectFilter::default();
+        let mut validator = Validator::aborting(
+            ParserOptions::default().with_level(ValidationClass::Types, ValidationLevel::Ignore),
+        );
+        let mut builder = Self::initial_builder(
+            snapshot.catalog_name().to_string(),
+            snapshot.dialect(),
+            &object_filter,
+            &validator,
+        );
+        for role in snapshot.roles() {
+            builder.push_role(Arc::new(role.clone()), ());
+        }
+        for schema in snapshot.schemas() {
+            builder.push_schema(Arc::new(schema.clone()), ());
+        }
+        for extension in snapshot.extensions() {
+            builder.push_extension(extension.clone());
+        }
+        for custom_type in snapshot.custom_types() {
+            builder.push_custom_type(Arc::new(custom_type.clone()), ());
+        }
+        for function in snapshot.functions() {
+            let mut metadata = created_function_metadata(&builder, function.definition());
+            metadata.set_comment(function.comment().map(ToString::to_string));
+            builder.push_function(Arc::new(function.definition().clone()), metadata);
+        }
+        for sequence in snapshot.sequences() {
+            builder.push_sequence(Arc::new(sequence.clone()), ());
+        }
+
+        let statements = snapshot
+            .tables()
+            .iter()
+            .map(|table| Statement::CreateTable(table.definition().clone()))
+            .chain(
+                snapshot
+                    .indices()
+                    .iter()
+                    .map(|index| Statement::CreateIndex(index.definition().clone())),
+            )
+            .chain(snapshot.policies().iter().cloned().map(Statement::CreatePolicy))
+            .collect();
+        Self::apply_statements(
+            &mut builder,
+            statements,
+            &object_filter,
+            &[],
+            &[],
+            &mut validator,
+            None,
+        )?;
+
+        for view in snapshot.views() {
+            builder.push_view(Arc::new(view.clone()), ());
+        }
+        for trigger in snapshot.triggers() {
+            builder.push_trigger(Arc::new(trigger.clone()), ());
+        }
+        for grant in snapshot.table_grants() {
+            builder.push_table_grant(Arc::new(grant.clone()), ());
+        }
+        for grant in snapshot.column_grants() {
+            builder.push_column_grant(Arc::new(grant.clone()), ());
+        }
+        // Default privileges only apply to the tables created after them,
+        // which are restored from the snapshots of the tables.
+        for default_privilege in snapshot.default_privileges() {
+            builder.push_default_privilege(Arc::new(default_privilege.clone()), ());
+        }
+        if let Some(timezone) = snapshot.timezone() {
+            builder.set_timezone(timezone.to_string());
+        }
+        builder.set_search_path(Some(snapshot.search_path().to_vec()));
+
+        let mut db = Self::build(builder, &mut validator)?;
+        for (table, metadata) in db.tables_metadata_mut() {
+            let Some(table_snapshot) = snapshot
+                .tables()
+                .iter()
+                .find(|candidate| candidate.definition().name == table.name)
+            else {
+                continue;
+            };
+            metadata.set_comment(table_snapshot.comment().map(ToString::to_string));
+            for column in table_snapshot.columns() {
+                metadata
+                    .set_column_comment(column.name(), column.comment().map(ToString::to_string));
+            }
+            metadata.set_rls_enabled(table_snapshot.has_row_level_security());
+            metadata.set_rls_forced(table_snapshot.has_forced_row_level_security());
+            for default_privilege in table_snapshot.default_privileges() {
+                metadata.add_default_privilege(Arc::new(default_privilege.clone()));
+            }
+        }
+        let no_inherit_checks: Vec<NoInheritCheck> = snapshot
+            .tables()
+            .iter()
+            .flat_map(|table| {
+                table
+                    .no_inherit_checks()
+                    .iter()
+                    .map(|check| (table.definition().name.clone(), check.clone()))
+            })
+            .collect();
+        db.mark_no_inherit_checks(&no_inherit_checks);
+        let clustered_indexes: Vec<ClusteredIndex> = snapshot
+            .indices()
+            .iter()
+            .filter(|index| index.is_clustered())
+            .filter_map(|index| {
+                let definition = index.definition();
+                Some((definition.table_name.clone(), definition.name.clone()?))
+            })
+            .collect();
+        db.mark_clustered_indexes(&clustered_indexes);
+        Ok(db)
+    }
+
+    /// Returns the statement creating the table as it stands, with its
+    /// current columns and constraints, the constraints declared inline
+    /// being moved to the table.
+    pub(crate) fn table_definition(&self, table: &CreateTable) -> CreateTable {
+        let mut constraints = Vec::new();
+        for unique_index in table.unique_indices(self) {
+            let unique_constraint = unique_index.attribute().clone();
+            constraints.push(match table.primary_key(self) {
+                // The constraint of an inline primary key is kept without
+                // columns, which are the ones of its index.
+                Some(primary_key) if unique_index.is_primary_key(self) => {
+                    TableConstraint::PrimaryKey(PrimaryKeyConstraint {
+                        columns: unique_constraint.columns,
+                        ..primary_key.attribute().clone()
+                    })
+                }
+                _ => TableConstraint::Unique(unique_constraint),
+            });
+        }
+        constraints.extend(
+            table
+                .foreign_keys(self)
+                .map(|foreign_key| TableConstraint::ForeignKey(foreign_key.attribute().clone())),
+        );
+        constraints.extend(
+            table
+                .check_constraints(self)
+                .map(|check| TableConstraint::Check(check.attribute().clone())),
+        );
+        CreateTable {
+            columns: table
+                .columns(self)
+                .map(|column| column_definition(column.attribute()))
+                .collect(),
+            constraints,
+            ..table.clone()
+        }
+    }
+}
+
+impl Serialize for ParserDB {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_snapshot().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ParserDB {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let snapshot = SchemaSnapshot::deserialize(deserializer)?;
+        Self::from_snapshot(&snapshot).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MsSqlDialect, PostgreSqlDialect};
+
+    use super::*;
+    use crate::{
+        diff::SchemaDiff,
+        structs::TableSnapshot,
+        traits::{CheckConstraintLike, DatabaseLike, FunctionLike, IndexLike, TableLike},
+    };
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE SCHEMA app;
+            CREATE TABLE app.users (id INT PRIMARY KEY, name TEXT NOT NULL DEFAULT 'anonymous');
+            CREATE TABLE app.posts (
+                id INT PRIMARY KEY,
+                author_id INT,
+                CONSTRAINT posts_author_fk FOREIGN KEY (author_id) REFERENCES app.users (id),
+                CONSTRAINT positive_id CHECK (id > 0) NO INHERIT
+            );
+            CREATE INDEX posts_author_idx ON app.posts (author_id);
+            ",
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&db).unwrap();
+        let reloaded: ParserDB = serde_json::from_str(&json).unwrap();
+        assert!(SchemaDiff::between(&db, &reloaded).is_empty());
+        assert_eq!(reloaded.to_snapshot(), db.to_snapshot());
+        let posts = reloaded.table(Some("app"), "posts").unwrap();
+        assert!(posts.check_constraints(&reloaded).all(|check| check.no_inherit(&reloaded)));
+
+        let snapshot: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot["format_version"], SNAPSHOT_FORMAT_VERSION);
+        assert_eq!(snapshot["dialect"], "PostgreSql");
+        assert!(snapshot.get("sql").is_none());
+        let posts = &snapshot["tables"][1];
+        assert_eq!(posts["name"], "posts");
+        assert_eq!(posts["foreign_keys"][0]["name"], "posts_author_fk");
+        assert_eq!(posts["foreign_keys"][0]["referenced_table"], "users");
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_with_foreign_key_cycle() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+            CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users (id));
+            ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams (id);
+            ",
+        )
+        .unwrap();
+        assert!(db.table_dependency_graph().cycle().is_some());
+
+        let json = serde_json::to_string(&db).unwrap();
+        let reloaded: ParserDB = serde_json::from_str(&json).unwrap();
+        assert!(SchemaDiff::between(&db, &reloaded).is_empty());
+        let snapshot = db.to_snapshot();
+        assert_eq!(reloaded.to_snapshot(), snapshot);
+        let names: Vec<&str> = snapshot.tables().iter().map(TableSnapshot::name).collect();
+        assert_eq!(names, ["teams", "users"]);
+    }
+
+    #[test]
+    fn test_snapshot_keeps_metadata() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE users (id INT PRIMARY KEY, name TEXT);
+            ALTER TABLE users ADD COLUMN email TEXT;
+            ALTER TABLE users ENABLE ROW LEVEL SECURITY;
+            COMMENT ON TABLE users IS 'Registered users';
+            COMMENT ON COLUMN users.email IS 'Contact address';
+            CREATE FUNCTION add_one(x INT) RETURNS INT AS 'SELECT x + 1;';
+            COMMENT ON FUNCTION add_one(INT) IS 'Increments';
+            ",
+        )
+        .unwrap();
+
+        let snapshot = db.to_snapshot();
+        assert_eq!(snapshot.tables()[0].definition().columns.len(), 3);
+        assert_eq!(snapshot.functions().len(), 1);
+
+        let reloaded = ParserDB::from_snapshot(&snapshot).unwrap();
+        let users = reloaded.table(None, "users").unwrap();
+        let metadata = reloaded.table_metadata(users).unwrap();
+        assert_eq!(users.columns(&reloaded).count(), 3);
+        assert!(users.has_row_level_security(&reloaded));
+        assert_eq!(metadata.comment(), Some("Registered users"));
+        assert_eq!(metadata.column_comment("email"), Some("Contact address"));
+        let add_one =
+            reloaded.functions().find(|function| !function.is_builtin(&reloaded)).unwrap();
+        let function_metadata = reloaded.function_metadata(add_one).unwrap();
+        assert_eq!(function_metadata.comment(), Some("Increments"));
+        assert_eq!(reloaded.to_snapshot(), snapshot);
+    }
+
+    #[test]
+    fn test_snapshot_keeps_clustered_indexes() {
+        let db = ParserDB::parse::<MsSqlDialect>(
+            "
+            CREATE TABLE orders (id INT, placed DATE);
+            CREATE CLUSTERED INDEX orders_placed ON orders (placed);
+            ",
+        )
+        .unwrap();
+
+        let reloaded = ParserDB::from_snapshot(&db.to_snapshot()).unwrap();
+        assert!(reloaded.indexes().all(|index| index.is_clustered(&reloaded)));
+    }
+
+    #[test]
+    fn test_snapshot_with_unsupported_version() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (id INT);").unwrap();
+        let mut snapshot = serde_json::to_value(&db).unwrap();
+        snapshot["format_version"] = (SNAPSHOT_FORMAT_VERSION + 1).into();
+        assert!(matches!(
+            ParserDB::from_snapshot(&serde_json::from_value(snapshot.clone()).unwrap()),
+            Err(crate::errors::Error::UnsupportedSnapshotVersion { .. })
+        ));
+        assert!(serde_json::from_value::<ParserDB>(snapshot).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! Recording of the location of the statement creating each schema object.
+//!
+//! The statements only ever append the objects they create to the builder,
+//! so the objects created by a statement are the trailing objects without a
+//! location once it is applied. Locations are recorded for the tables, check
+//! constraints, indices, unique indices, functions and policies, whose
+//! metadata can hold one.
+
+use alloc::sync::Arc;
+
+use sqlparser::{ast::CreateFunction, tokenizer::Span};
+
+use crate::{
+    errors::Error,
+    structs::{ParserDBBuilder, SourceLocation},
+    traits::Metadata,
+};
+
+/// A file the statements were read from.
+#[derive(Debug, Clone)]
+pub(super) struct SourceFile {
+    /// Index of the first statement of the file among all the statements.
+    pub(super) first_statement: usize,
+    /// Path of the file.
+    pub(super) path: Arc<str>,
+}
+
+/// Returns the path of the file the statement at the provided position was
+/// read from, if any, along with the index of the statement in that file.
+fn locate_statement(files: &[SourceFile], position: usize) -> (Option<Arc<str>>, usize) {
+    match files.partition_point(|file| file.first_statement <= position).checked_sub(1) {
+        Some(index) => {
+            let file = &files[index];
+            (Some(file.path.clone()), position - file.first_statement)
+        }
+        None => (None, position),
+    }
+}
+
+/// Returns the error raised by an unsupported statement, located by its span
+/// until [`locate_unsupported_statement`] locates it among the statements.
+pub(super) fn unsupported_statement(statement: String, span: Span) -> Error {
+    Error::UnsupportedStatement {
+        statement,
+        location: SourceLocation::new(None, 0, Some(span).filter(|span| *span != Span::empty())),
+    }
+}
+
+/// Sets the file and the index of the statement at the provided position on
+/// the error it raised, if it is an unsupported statement error.
+pub(super) fn locate_unsupported_statement(
+    error: Error,
+    files: &[SourceFile],
+    position: usize,
+) -> Error {
+    match error {
+        Error::UnsupportedStatement { statement, location } => {
+            let (path, statement_index) = locate_statement(files, position);
+            Error::UnsupportedStatement {
+                statement,
+                location: SourceLocation::new(path, statement_index, location.span()),
+            }
+        }
+        error => error,
+    }
+}
+
+/// Sets the location of the trailing objects without one, stopping at the
+/// first object for which `is_builtin` holds.
+fn record<T: Metadata>(
+    entries: &mut [(Arc<T>, T::Meta)],
+    location: &impl Fn(Option<Span>) -> SourceLocation,
+    is_builtin: impl Fn(&Arc<T>) -> bool,
+    set: impl Fn(&mut T::Meta, SourceLocation),
+) {
+    for (object, metadata) in entries.iter_mut().rev() {
+        if is_builtin(object) || object.object_source_location(metadata).is_some() {
+            break;
+        }
+        let span = object.source_span();
+        set(metadata, location(span));
+    }
+}
+
+/// Records the location of the objects created by the statement at the
+/// provided position.
+///
+/// # Arguments
+///
+/// * `builder` - The builder the statement was applied to.
+/// * `files` - The files the statements were read from, if any.
+/// * `position` - The index of the statement among all the statements.
+/// * `builtins` - The built-in functions, which have no location.
+pub(super) fn record_source_locations(
+    builder: &mut ParserDBBuilder,
+    files: &[SourceFile],
+    position: usize,
+    builtins: &[Arc<CreateFunction>],
+) {
+    let (path, statement_index) = locate_statement(files, position);
+    let location = |span: Option<Span>| SourceLocation::new(path.clone(), statement_index, span);
+    record(
+        builder.tables_mut(),
+        &location,
+        |_| false,
+        |metadata, location| {
+            metadata.set_source_location(Some(location));
+        },
+    );
+    record(
+        builder.check_constraints_mut(),
+        &location,
+        |_| false,
+        |metadata, location| {
+            metadata.set_source_location(Some(location));
+        },
+    );
+    record(
+        builder.indices_mut(),
+        &location,
+        |_| false,
+        |metadata, location| {
+            metadata.set_source_location(Some(location));
+        },
+    );
+    record(
+        builder.unique_indices_mut(),
+        &location,
+        |_| false,
+        |metadata, location| {
+            metadata.set_source_location(Some(location));
+        },
+    );
+    record(
+        builder.policies_mut(),
+        &location,
+        |_| false,
+        |metadata, location| {
+            metadata.set_source_location(Some(location));
+        },
+    );
+    record(
+        builder.functions_mut(),
+        &location,
+        |function| builtins.iter().any(|builtin| Arc::ptr_eq(builtin, function)),
+        |metadata, location| metadata.set_source_location(Some(location)),
+    );
+}
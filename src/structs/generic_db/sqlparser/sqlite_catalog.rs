@@ -0,0 +1,285 @@
+//! Introspection of the schema of a live SQLite database.
+//!
+//! The schema is not rebuilt from `PRAGMA table_info` and `PRAGMA
+//! index_list`, which drop check constraints, generated columns, collations
+//! and trigger bodies, but parsed from the statements SQLite keeps in
+//! `sqlite_master`. The pragmas only order the tables by their foreign keys,
+//! and the foreign keys which cannot be resolved are kept unresolved, as
+//! SQLite itself does.
+
+use alloc::{string::String, vec::Vec};
+
+use rusqlite::Connection;
+use sqlparser::{dialect::SQLiteDialect, parser::Parser};
+
+use super::{ParserDB, validator::Validator};
+use crate::{
+    impls::SqlparserDialect,
+    structs::{ObjectFilter, ParserOptions},
+};
+
+/// A schema object read from `sqlite_master`.
+struct CatalogEntry {
+    /// The type of the object: `table`, `index`, `view` or `trigger`.
+    kind: String,
+    /// The name of the object.
+    name: String,
+    /// The statement creating the object, as kept up to date by SQLite.
+    sql: String,
+}
+
+/// Orders the tables so that every table follows the tables its foreign keys
+/// reference, as listed by `PRAGMA foreign_key_list`. Tables involved in
+/// reference cycles come last, in creation order.
+fn order_tables(
+    connection: &Connection,
+    tables: Vec<CatalogEntry>,
+) -> Result<Vec<CatalogEntry>, rusqlite::Error> {
+    let mut query = connection.prepare("SELECT \"table\" FROM pragma_foreign_key_list(?1)")?;
+    let mut pending = Vec::with_capacity(tables.len());
+    for table in tables {
+        let referenced = query
+            .query_map([&table.name], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        pending.push((table, referenced));
+    }
+
+    let mut ordered: Vec<CatalogEntry> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) =
+            pending.into_iter().partition(|(table, referenced)| {
+                referenced.iter().all(|name| {
+                    // SQLite resolves table names case-insensitively.
+                    name.eq_ignore_ascii_case(&table.name)
+                        || ordered.iter().any(|other| other.name.eq_ignore_ascii_case(name))
+                })
+            });
+        if ready.is_empty() {
+            ordered.extend(blocked.into_iter().map(|(table, _)| table));
+            break;
+        }
+        ordered.extend(ready.into_iter().map(|(table, _)| table));
+        pending = blocked;
+    }
+    Ok(ordered)
+}
+
+impl ParserDB {
+    /// Builds the database from the schema of a live SQLite connection.
+    ///
+    /// The statements creating the tables, indices, views and triggers are
+    /// read from `sqlite_master`, which SQLite rewrites as the schema is
+    /// altered, and parsed with the SQLite dialect. The columns are thus not
+    /// read from `PRAGMA table_info`, nor the indices from `PRAGMA
+    /// index_list`, which would lose their check constraints, generated
+    /// columns, collations and partial index predicates. As SQLite lets foreign
+    /// keys reference tables created later, the tables are first ordered
+    /// using `PRAGMA foreign_key_list`, so that every table follows the
+    /// tables it references. SQLite also accepts reference cycles and
+    /// references to tables which do not exist, so the foreign keys are
+    /// loaded as with [`ParserOptions::with_deferred_foreign_keys`]: those
+    /// whose targets are missing are kept unresolved, and
+    /// [`ParserDB::validate_foreign_key_targets`] reports them. The internal tables of SQLite and the indices
+    /// it creates for primary keys and unique constraints are skipped.
+    ///
+    /// The catalog of the returned database is named `main`, after the
+    /// schema SQLite assigns to the primary database of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection` - The connection to introspect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog cannot be queried, or if one of the
+    /// statements fails to parse or to validate, foreign key targets aside.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use rusqlite::Connection;
+    /// use sql_traits::prelude::*;
+    ///
+    /// let connection = Connection::open_in_memory()?;
+    /// connection.execute_batch(
+    ///     "
+    /// CREATE TABLE posts (id INTEGER PRIMARY KEY, author_id INTEGER REFERENCES users (id));
+    /// CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT UNIQUE);
+    /// CREATE INDEX posts_author_idx ON posts (author_id);
+    /// ",
+    /// )?;
+    /// let db = ParserDB::from_sqlite_connection(&connection)?;
+    /// let tables: Vec<&str> = db
+    ///     .table_dependency_graph()
+    ///     .topological_order()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(TableLike::table_name)
+    ///     .collect();
+    /// assert_eq!(tables, ["users", "posts"]);
+    /// assert_eq!(db.catalog_name(), "main");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_sqlite_connection(connection: &Connection) -> Result<Self, crate::errors::Error> {
+        let mut query = connection.prepare(
+            "SELECT type, name, sql FROM sqlite_master \
+             WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\' \
+             ORDER BY rowid",
+        )?;
+        let entries = query
+            .query_map([], |row| {
+                Ok(CatalogEntry { kind: row.get(0)?, name: row.get(1)?, sql: row.get(2)? })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        let (tables, others): (Vec<_>, Vec<_>) =
+            entries.into_iter().partition(|entry| entry.kind == "table");
+
+        let dialect = SQLiteDialect {};
+        let mut statements = Vec::new();
+        for entry in order_tables(connection, tables)?.iter().chain(&others) {
+            statements.extend(Parser::parse_sql(&dialect, &entry.sql)?);
+        }
+        Self::from_filtered_statements(
+            statements,
+            "main".into(),
+            SqlparserDialect::SQLite,
+            ObjectFilter::default(),
+            &[],
+            &[],
+            &mut Validator::aborting(ParserOptions::default().with_deferred_foreign_keys(true)),
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::{
+        errors::Error,
+        structs::ParserDB,
+        traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike, TriggerLike},
+    };
+
+    #[test]
+    fn test_introspect_sqlite_connection() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "
+                CREATE TABLE comments (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    post_id INTEGER NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+                    body TEXT CHECK (length(body) > 0)
+                );
+                CREATE TABLE posts (id INTEGER PRIMARY KEY, author_id INTEGER REFERENCES users (id));
+                CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT UNIQUE);
+                ALTER TABLE users ADD COLUMN name TEXT;
+                CREATE UNIQUE INDEX users_name_idx ON users (name);
+                CREATE VIEW post_authors AS SELECT posts.id, users.email FROM posts JOIN users ON users.id = posts.author_id;
+                ",
+            )
+            .unwrap();
+
+        let db = ParserDB::from_sqlite_connection(&connection).unwrap();
+        let tables: Vec<&str> = db
+            .table_dependency_graph()
+            .topological_order()
+            .unwrap()
+            .into_iter()
+            .map(TableLike::table_name)
+            .collect();
+        assert_eq!(tables, ["users", "posts", "comments"]);
+
+        let users = db.table(None, "users").unwrap();
+        let columns: Vec<&str> = users.columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["id", "email", "name"]);
+        assert_eq!(db.table(None, "posts").unwrap().foreign_keys(&db).count(), 1);
+        assert_eq!(db.table(None, "comments").unwrap().check_constraints(&db).count(), 1);
+        assert_eq!(db.views().count(), 1);
+        assert!(db.table(None, "sqlite_sequence").is_none());
+    }
+
+    #[test]
+    fn test_introspect_sqlite_cycles_and_dangling_references() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "
+                CREATE TABLE employees (
+                    id INTEGER PRIMARY KEY,
+                    team_id INTEGER,
+                    FOREIGN KEY (team_id) REFERENCES teams (id)
+                );
+                CREATE TABLE teams (
+                    id INTEGER PRIMARY KEY,
+                    lead_id INTEGER,
+                    FOREIGN KEY (lead_id) REFERENCES employees (id)
+                );
+                CREATE TABLE badges (
+                    id INTEGER PRIMARY KEY,
+                    holder_id INTEGER REFERENCES employees (id),
+                    issuer_id INTEGER,
+                    FOREIGN KEY (issuer_id) REFERENCES issuers (id)
+                );
+                ",
+            )
+            .unwrap();
+
+        let db = ParserDB::from_sqlite_connection(&connection).unwrap();
+        assert_eq!(db.tables().count(), 3);
+
+        let employees = db.table(None, "employees").unwrap();
+        let teams = db.table(None, "teams").unwrap();
+        let badges = db.table(None, "badges").unwrap();
+        assert!(employees.foreign_keys(&db).any(|fk| fk.referenced_table(&db) == Some(teams)));
+        assert!(teams.foreign_keys(&db).any(|fk| fk.referenced_table(&db) == Some(employees)));
+
+        let referenced: Vec<Option<&str>> = badges
+            .foreign_keys(&db)
+            .map(|fk| fk.referenced_table(&db).map(TableLike::table_name))
+            .collect();
+        assert_eq!(referenced.len(), 2);
+        assert!(referenced.contains(&Some("employees")));
+        assert!(referenced.contains(&None));
+
+        assert!(matches!(
+            db.validate_foreign_key_targets(),
+            Err(Error::ReferencedTableNotFoundForForeignKey { referenced_table, .. })
+                if referenced_table == "issuers"
+        ));
+    }
+
+    #[test]
+    fn test_introspect_sqlite_triggers() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "
+                CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT, updated_at TEXT);
+                CREATE TABLE audit (user_id INTEGER REFERENCES users (id), email TEXT);
+                CREATE TRIGGER users_audit AFTER UPDATE OF email ON users
+                FOR EACH ROW WHEN OLD.email IS NOT NEW.email
+                BEGIN
+                    INSERT INTO audit (user_id, email) VALUES (OLD.id, OLD.email);
+                    UPDATE users SET updated_at = datetime('now') WHERE id = NEW.id;
+                END;
+                CREATE TRIGGER users_cleanup BEFORE DELETE ON users
+                BEGIN
+                    DELETE FROM audit WHERE user_id = OLD.id;
+                END;
+                ",
+            )
+            .unwrap();
+
+        let db = ParserDB::from_sqlite_connection(&connection).unwrap();
+        let triggers: Vec<&str> = db.triggers().map(TriggerLike::name).collect();
+        assert_eq!(triggers, ["users_audit", "users_cleanup"]);
+        for trigger in db.triggers() {
+            assert_eq!(trigger.table(&db).table_name(), "users");
+        }
+    }
+}
@@ -0,0 +1,5162 @@
+//! Tests of the [`ParserDB`] parsing and validation.
+
+use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+use super::*;
+use crate::{
+    errors::{Error, LookupError},
+    traits::{DatabaseLike, TableLike, TriggerLike},
+};
+
+mod identifier_aware_lookup {
+    use sqlparser::{
+        ast::{Ident, ObjectName, ObjectNamePart},
+        dialect::PostgreSqlDialect,
+    };
+
+    use super::*;
+
+    fn ident(value: &str, quoted: bool) -> Ident {
+        if quoted { Ident::with_quote('"', value) } else { Ident::new(value) }
+    }
+
+    fn object_name(parts: &[(&str, bool)]) -> ObjectName {
+        ObjectName(
+            parts
+                .iter()
+                .map(|(value, quoted)| ObjectNamePart::Identifier(ident(value, *quoted)))
+                .collect(),
+        )
+    }
+
+    fn parse_postgres(sql: &str) -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse PostgreSQL SQL")
+    }
+
+    #[test]
+    fn quoted_table_lookup_requires_exact_case() {
+        let db = parse_postgres("CREATE TABLE \"Camel\" (id INT);");
+
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("Camel", true)]))
+                .expect("Lookup should succeed")
+                .is_some()
+        );
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("camel", true)]))
+                .expect("Lookup should succeed")
+                .is_none()
+        );
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("camel", false)]))
+                .expect("Lookup should succeed")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unquoted_table_lookup_resolves_via_folding() {
+        let db = parse_postgres("CREATE TABLE Foo (id INT);");
+
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("foo", false)]))
+                .expect("Lookup should succeed")
+                .is_some()
+        );
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("FOO", false)]))
+                .expect("Lookup should succeed")
+                .is_some()
+        );
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("foo", true)]))
+                .expect("Lookup should succeed")
+                .is_some()
+        );
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("Foo", true)]))
+                .expect("Lookup should succeed")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn schema_ident_resolution_handles_quoted_and_unquoted() {
+        let db = parse_postgres(
+            r#"
+                CREATE SCHEMA Foo;
+                CREATE SCHEMA "Bar";
+                "#,
+        );
+
+        assert!(db.resolve_schema_ident(&ident("foo", false)).is_some());
+        assert!(db.resolve_schema_ident(&ident("FOO", false)).is_some());
+        assert!(db.resolve_schema_ident(&ident("foo", true)).is_some());
+        assert!(db.resolve_schema_ident(&ident("Foo", true)).is_none());
+
+        assert!(db.resolve_schema_ident(&ident("Bar", true)).is_some());
+        assert!(db.resolve_schema_ident(&ident("bar", false)).is_none());
+    }
+
+    #[test]
+    fn alter_table_rls_lookup_uses_resolver_rules() {
+        let db = parse_postgres(
+            r"
+                CREATE TABLE Foo (id INT);
+                ALTER TABLE FOO ENABLE ROW LEVEL SECURITY;
+                ",
+        );
+        let foo = db.table(None, "foo").expect("Expected `foo` table to exist after ALTER TABLE");
+        assert!(
+            foo.has_row_level_security(&db),
+            "Unquoted ALTER TABLE lookup should resolve via identifier folding"
+        );
+
+        let db = parse_postgres(
+            r#"
+                CREATE TABLE Foo (id INT);
+                ALTER TABLE "Foo" ENABLE ROW LEVEL SECURITY;
+                "#,
+        );
+        let foo = db.table(None, "foo").expect("Expected `foo` table to exist after ALTER TABLE");
+        assert!(
+            !foo.has_row_level_security(&db),
+            "Quoted ALTER TABLE name should not match unquoted table with different case"
+        );
+    }
+
+    #[test]
+    fn grant_table_lookup_uses_resolver_rules() {
+        let sql = r"
+                CREATE TABLE Foo (id INT);
+                CREATE ROLE app_role;
+                GRANT SELECT ON FOO TO app_role;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(result.is_ok());
+
+        let sql = r#"
+                CREATE TABLE Foo (id INT);
+                CREATE ROLE app_role;
+                GRANT SELECT ON "Foo" TO app_role;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::TableNotFoundForGrant { table_name }) if table_name == "Foo"
+        ));
+    }
+
+    #[test]
+    fn create_index_attaches_to_correct_schema_table() {
+        let db = parse_postgres(
+            r"
+                CREATE SCHEMA s1;
+                CREATE SCHEMA s2;
+                CREATE TABLE s1.t (id INT);
+                CREATE TABLE s2.t (id INT);
+                CREATE INDEX idx_s2_t_id ON s2.t (id);
+                ",
+        );
+
+        let s1_t = db
+            .resolve_table_object_name(&object_name(&[("s1", false), ("t", false)]))
+            .expect("Lookup should succeed")
+            .expect("Expected table s1.t to exist");
+        let s2_t = db
+            .resolve_table_object_name(&object_name(&[("s2", false), ("t", false)]))
+            .expect("Lookup should succeed")
+            .expect("Expected table s2.t to exist");
+
+        assert_eq!(s1_t.indices(&db).count(), 0);
+        assert_eq!(s2_t.indices(&db).count(), 1);
+    }
+
+    #[test]
+    fn create_index_attachment_respects_quoted_schema_and_table_identity() {
+        let db = parse_postgres(
+            r#"
+                CREATE SCHEMA s;
+                CREATE SCHEMA "S";
+                CREATE TABLE s.t (id INT);
+                CREATE TABLE "S"."T" (id INT);
+                CREATE INDEX idx_quoted_t ON "S"."T" (id);
+                "#,
+        );
+
+        let unquoted = db
+            .resolve_table_object_name(&object_name(&[("s", false), ("t", false)]))
+            .expect("Lookup should succeed")
+            .expect("Expected table s.t to exist");
+        let quoted = db
+            .resolve_table_object_name(&object_name(&[("S", true), ("T", true)]))
+            .expect("Lookup should succeed")
+            .expect("Expected table \"S\".\"T\" to exist");
+
+        assert_eq!(unquoted.indices(&db).count(), 0);
+        assert_eq!(quoted.indices(&db).count(), 1);
+    }
+
+    #[test]
+    fn rename_table_lookup_uses_resolver_rules() {
+        let sql = r"
+                CREATE TABLE Foo (id INT);
+                ALTER TABLE FOO RENAME TO bar;
+            ";
+        let db = parse_postgres(sql);
+        assert!(db.table(None, "foo").is_none());
+        assert!(db.table(None, "bar").is_some());
+
+        let sql = r#"
+                CREATE TABLE Foo (id INT);
+                ALTER TABLE "Foo" RENAME TO bar;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::RenameTableNotFound { table_name }) if table_name == "Foo"
+        ));
+    }
+
+    #[test]
+    fn rename_table_statement_lookup_uses_resolver_rules() {
+        let sql = r"
+                CREATE TABLE Foo (id INT);
+                RENAME TABLE FOO TO bar;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql)
+            .expect("Expected unquoted RENAME TABLE lookup to resolve");
+        assert!(db.table(None, "foo").is_none());
+        assert!(db.table(None, "bar").is_some());
+
+        let sql = r#"
+                CREATE TABLE Foo (id INT);
+                RENAME TABLE "Foo" TO bar;
+            "#;
+        let result = ParserDB::parse::<GenericDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::RenameTableNotFound { table_name }) if table_name == "Foo"
+        ));
+    }
+
+    #[test]
+    fn alter_schema_rename_rejects_semantic_duplicate() {
+        let sql = r"
+                CREATE SCHEMA foo;
+                CREATE SCHEMA bar;
+                ALTER SCHEMA bar RENAME TO FOO;
+            ";
+
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::SchemaAlreadyExists { schema_name })
+                if schema_name.eq_ignore_ascii_case("foo")
+        ));
+    }
+
+    #[test]
+    fn alter_schema_rename_rejects_quoted_unquoted_equivalent_duplicate() {
+        let sql = r#"
+                CREATE SCHEMA foo;
+                CREATE SCHEMA bar;
+                ALTER SCHEMA bar RENAME TO "foo";
+            "#;
+
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::SchemaAlreadyExists { schema_name }) if schema_name == "foo"
+        ));
+    }
+
+    #[test]
+    fn implicit_public_helper_handles_mixed_public_cases() {
+        let db = parse_postgres(
+            r#"
+                CREATE TABLE public.foo (id INT);
+                CREATE SCHEMA "Public";
+                CREATE TABLE "Public".bar (id INT);
+                "#,
+        );
+
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("foo", false)]))
+                .expect("Lookup should succeed")
+                .is_none()
+        );
+
+        let resolved = db
+            .resolve_table_object_name_with_implicit_public(&object_name(&[("foo", false)]))
+            .expect("Lookup should succeed");
+        let resolved = resolved.expect("Expected implicit public fallback to resolve");
+        assert_eq!(
+            resolved.table_schema(),
+            Some("public"),
+            "Unqualified lookup should fallback to schema public"
+        );
+
+        assert!(
+            db.resolve_table_object_name_with_implicit_public(&object_name(&[("bar", false)]))
+                .expect("Lookup should succeed")
+                .is_none()
+        );
+
+        assert!(
+            db.resolve_table_object_name(&object_name(&[("Public", true), ("bar", false)]))
+                .expect("Lookup should succeed")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn invalid_object_name_is_reported() {
+        let db = parse_postgres("CREATE TABLE t (id INT);");
+        let invalid = object_name(&[("a", false), ("b", false), ("c", false)]);
+
+        let result = db.resolve_table_object_name(&invalid);
+        assert!(matches!(
+            result,
+            Err(LookupError::InvalidObjectName { object_name, .. }) if object_name == "a.b.c"
+        ));
+    }
+
+    #[test]
+    fn ambiguous_unqualified_and_public_tables_fail_at_build_time() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE TABLE public.t (id INT);
+            ";
+
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+        assert!(matches!(
+            result,
+            Err(Error::IdentifierLookupError(LookupError::TableLookupConflict {
+                table,
+                conflicting_table
+            })) if table == "public.t" && conflicting_table == "t"
+        ));
+    }
+}
+
+mod parser_variant_compatibility {
+    use sqlparser::dialect::{MsSqlDialect, PostgreSqlDialect};
+
+    use super::*;
+
+    #[test]
+    fn waitfor_statement_is_ignored_without_breaking_parse() {
+        let sql = "
+                WAITFOR DELAY '00:00:00';
+                CREATE TABLE t (id INT);
+            ";
+        let db = ParserDB::parse::<MsSqlDialect>(sql).expect("WAITFOR should be ignored");
+        assert!(db.table(None, "t").is_some());
+    }
+
+    #[test]
+    fn comment_on_role_statement_is_ignored_without_breaking_parse() {
+        let sql = "
+                CREATE ROLE app_role;
+                COMMENT ON ROLE app_role IS 'Application role';
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("COMMENT ON ROLE should parse");
+        assert!(db.role("app_role").is_some());
+    }
+}
+
+mod drop_function_errors {
+    use super::*;
+
+    #[test]
+    fn test_drop_function_not_found_error_type() {
+        let sql = "DROP FUNCTION nonexistent_func;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropFunctionNotFound { function_name }) if function_name == "nonexistent_func"
+        ));
+    }
+
+    #[test]
+    fn test_drop_function_referenced_error_type() {
+        let sql = r"
+                CREATE FUNCTION is_valid(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
+                CREATE TABLE t (id INT CHECK (is_valid(id)));
+                DROP FUNCTION is_valid;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::FunctionReferenced { function_name }) if function_name == "is_valid"
+        ));
+    }
+
+    #[test]
+    fn test_drop_function_if_exists_not_found_succeeds() {
+        let sql = "DROP FUNCTION IF EXISTS nonexistent_func;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_drop_function_if_exists_referenced_still_fails() {
+        let sql = r"
+                CREATE FUNCTION is_valid(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
+                CREATE TABLE t (id INT CHECK (is_valid(id)));
+                DROP FUNCTION IF EXISTS is_valid;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        // IF EXISTS doesn't bypass the reference check
+        assert!(matches!(
+            result,
+            Err(Error::FunctionReferenced { function_name }) if function_name == "is_valid"
+        ));
+    }
+
+    #[test]
+    fn test_drop_quoted_function_succeeds() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
+                DROP FUNCTION "FooBar";
+            "#;
+        let db = ParserDB::parse::<GenericDialect>(sql)
+            .expect("Quoted DROP FUNCTION should match quoted CREATE FUNCTION");
+
+        assert!(db.function("\"FooBar\"").is_none());
+    }
+
+    #[test]
+    fn test_drop_unquoted_does_not_match_quoted_function() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
+                DROP FUNCTION foobar;
+            "#;
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropFunctionNotFound { function_name }) if function_name == "foobar"
+        ));
+    }
+}
+
+mod drop_table_errors {
+    use super::*;
+
+    #[test]
+    fn test_drop_table_not_found_error_type() {
+        let sql = "DROP TABLE nonexistent_table;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropTableNotFound { table_name }) if table_name == "nonexistent_table"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_referenced_error_type() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+                DROP TABLE parent;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::TableReferenced { table_name }) if table_name == "parent"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_if_exists_not_found_succeeds() {
+        let sql = "DROP TABLE IF EXISTS nonexistent_table;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_drop_table_if_exists_referenced_still_fails() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+                DROP TABLE IF EXISTS parent;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        // IF EXISTS doesn't bypass the reference check
+        assert!(matches!(
+            result,
+            Err(Error::TableReferenced { table_name }) if table_name == "parent"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_cascade_bypasses_reference_check() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+                DROP TABLE parent CASCADE;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(result.is_ok());
+        let db = result.unwrap();
+        assert!(db.table(None, "parent").is_none());
+        assert!(db.table(None, "child").is_some());
+    }
+
+    #[test]
+    fn test_drop_table_referenced_by_function_fails() {
+        let sql = r"
+                CREATE TABLE logs (id INT);
+                CREATE FUNCTION purge_logs() RETURNS VOID AS $$
+                BEGIN
+                    DELETE FROM logs;
+                END;
+                $$ LANGUAGE plpgsql;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(&format!("{sql} DROP TABLE logs;"));
+        assert!(matches!(
+            result,
+            Err(Error::TableReferencedByFunction { table_name, function_name })
+                if table_name == "logs" && function_name == "purge_logs"
+        ));
+
+        let db = ParserDB::parse::<GenericDialect>(&format!("{sql} DROP TABLE logs CASCADE;"))
+            .expect("CASCADE bypasses the function check");
+        assert!(db.table(None, "logs").is_none());
+        assert!(db.function("purge_logs").is_some());
+    }
+
+    #[test]
+    fn test_function_relations_are_collected_at_creation() {
+        let sql = r"
+                CREATE FUNCTION purge_logs() RETURNS VOID AS $$
+                BEGIN
+                    DELETE FROM logs;
+                END;
+                $$ LANGUAGE plpgsql;
+                CREATE TABLE logs (id INT);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+        let function = db.function("purge_logs").unwrap();
+        let relations: Vec<String> = db
+            .function_metadata(function)
+            .and_then(FunctionMetadata::referenced_relations)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(relations, ["logs"]);
+
+        // The function created before the table still depends on it.
+        let result = ParserDB::parse::<GenericDialect>(&format!("{sql} DROP TABLE logs;"));
+        assert!(matches!(
+            result,
+            Err(Error::TableReferencedByFunction { function_name, .. })
+                if function_name == "purge_logs"
+        ));
+    }
+
+    #[test]
+    fn test_unparsable_function_body_is_reported() {
+        let sql = r"
+                CREATE TABLE logs (id INT);
+                CREATE FUNCTION broken() RETURNS VOID AS $$
+                BEGIN
+                    DELEET FROM logs;
+                END;
+                $$ LANGUAGE plpgsql;
+                CREATE FUNCTION greet() RETURNS TEXT AS $$ return 'hi' $$ LANGUAGE plpython3u;
+                DROP TABLE logs;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+        assert!(db.table(None, "logs").is_none());
+        let function = db.function("broken").unwrap();
+        assert!(
+            db.function_metadata(function)
+                .and_then(FunctionMetadata::referenced_relations)
+                .is_none()
+        );
+        assert_eq!(db.validation_warnings().len(), 1);
+        let warning = &db.validation_warnings()[0];
+        assert_eq!(warning.class(), ValidationClass::UnsupportedStatements);
+        assert!(warning.message().contains("`broken`"));
+
+        let options = ParserOptions::default()
+            .with_level(ValidationClass::UnsupportedStatements, ValidationLevel::Error);
+        assert!(matches!(
+            ParserDB::parse_with_options::<GenericDialect>(sql, options),
+            Err(Error::UnparsableFunctionBody { function_name }) if function_name == "broken"
+        ));
+    }
+
+    #[test]
+    fn test_drop_multiple_tables() {
+        let sql = r"
+                CREATE TABLE t1 (id INT PRIMARY KEY);
+                CREATE TABLE t2 (id INT PRIMARY KEY);
+                CREATE TABLE t3 (id INT PRIMARY KEY);
+                DROP TABLE t1, t2;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(result.is_ok());
+        let db = result.unwrap();
+        assert!(db.table(None, "t1").is_none());
+        assert!(db.table(None, "t2").is_none());
+        assert!(db.table(None, "t3").is_some());
+    }
+
+    #[test]
+    fn test_drop_multiple_tables_one_referenced_fails() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+                CREATE TABLE other (id INT PRIMARY KEY);
+                DROP TABLE parent, other;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        // Should fail because parent is referenced
+        assert!(matches!(
+            result,
+            Err(Error::TableReferenced { table_name }) if table_name == "parent"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_with_same_name_in_other_schema_only_removes_target() {
+        let sql = r"
+                CREATE SCHEMA s1;
+                CREATE SCHEMA s2;
+                CREATE TABLE s1.t (id INT PRIMARY KEY);
+                CREATE TABLE s2.t (id INT PRIMARY KEY);
+                CREATE INDEX idx_s2_t_id ON s2.t (id);
+                DROP TABLE s1.t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        assert!(db.table(Some("s1"), "t").is_none());
+        let s2_t = db.table(Some("s2"), "t").expect("s2.t should still exist");
+        assert_eq!(
+            s2_t.indices(&db).count(),
+            1,
+            "Dropping s1.t must not remove indices attached to s2.t",
+        );
+    }
+
+    #[test]
+    fn test_drop_table_reference_check_is_schema_aware() {
+        let sql = r"
+                CREATE SCHEMA s1;
+                CREATE SCHEMA s2;
+                CREATE TABLE s1.parent (id INT PRIMARY KEY);
+                CREATE TABLE s1.child (id INT, parent_id INT REFERENCES s1.parent(id));
+                CREATE TABLE s2.parent (id INT PRIMARY KEY);
+                DROP TABLE s2.parent;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql)
+            .expect("References to s1.parent must not block dropping same-name table s2.parent");
+
+        assert!(db.table(Some("s2"), "parent").is_none());
+        assert!(db.table(Some("s1"), "parent").is_some());
+        assert!(db.table(Some("s1"), "child").is_some());
+    }
+}
+
+mod is_function_used_tests {
+    use super::*;
+
+    #[test]
+    fn test_is_function_used_returns_false_when_no_references() {
+        // Parse SQL with a function but no references
+        let sql = r"
+                CREATE FUNCTION my_func() RETURNS INT AS 'SELECT 1;';
+                CREATE TABLE t (id INT);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // The function exists but isn't used by any schema object
+        assert!(db.function("my_func").is_some());
+    }
+
+    #[test]
+    fn test_function_used_by_check_constraint() {
+        let sql = r"
+                CREATE FUNCTION is_positive(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
+                CREATE TABLE t (id INT CHECK (is_positive(id)));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Function should exist and be used
+        assert!(db.function("is_positive").is_some());
+
+        // Verify dropping it would fail
+        let drop_sql = format!("{sql}\nDROP FUNCTION is_positive;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
+    }
+
+    #[test]
+    fn test_function_used_by_policy_using_clause() {
+        let sql = r"
+                CREATE FUNCTION check_access() RETURNS BOOLEAN AS 'SELECT true;';
+                CREATE TABLE t (id INT);
+                CREATE POLICY my_policy ON t USING (check_access());
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Function should exist
+        assert!(db.function("check_access").is_some());
+
+        // Verify dropping it would fail
+        let drop_sql = format!("{sql}\nDROP FUNCTION check_access;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
+    }
+
+    #[test]
+    fn test_function_used_by_policy_with_check_clause() {
+        let sql = r"
+                CREATE FUNCTION validate() RETURNS BOOLEAN AS 'SELECT true;';
+                CREATE TABLE t (id INT);
+                CREATE POLICY my_policy ON t WITH CHECK (validate());
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Function should exist
+        assert!(db.function("validate").is_some());
+
+        // Verify dropping it would fail
+        let drop_sql = format!("{sql}\nDROP FUNCTION validate;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
+    }
+
+    #[test]
+    fn test_function_used_by_trigger() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Function should exist
+        assert!(db.function("trigger_fn").is_some());
+
+        // Verify dropping it would fail
+        let drop_sql = format!("{sql}\nDROP FUNCTION trigger_fn;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
+    }
+
+    #[test]
+    fn test_function_used_by_schema_qualified_call() {
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE FUNCTION check_access() RETURNS BOOLEAN AS 'SELECT true;';
+                CREATE TABLE t (id INT CHECK (s.check_access()));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(db.function("check_access").is_some());
+
+        let drop_sql = format!("{sql}\nDROP FUNCTION check_access;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(matches!(result, Err(Error::FunctionReferenced { .. })));
+    }
+
+    #[test]
+    fn test_quoted_function_used_by_check_constraint() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"(x INT) RETURNS BOOLEAN AS 'SELECT x > 0;';
+                CREATE TABLE t (id INT CHECK ("FooBar"(id)));
+                DROP FUNCTION "FooBar";
+            "#;
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
+        ));
+    }
+
+    #[test]
+    fn test_quoted_function_used_by_policy() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"() RETURNS BOOLEAN AS 'SELECT true;';
+                CREATE TABLE t (id INT);
+                CREATE POLICY p ON t USING ("FooBar"());
+                DROP FUNCTION "FooBar";
+            "#;
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
+        ));
+    }
+
+    #[test]
+    fn test_quoted_function_used_by_trigger() {
+        let sql = r#"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION "FooBar"() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION "FooBar"();
+                DROP FUNCTION "FooBar";
+            "#;
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::FunctionReferenced { function_name }) if function_name == "FooBar"
+        ));
+    }
+}
+
+mod function_lookup_identifier_semantics {
+    use super::*;
+    use crate::traits::DatabaseLike;
+
+    #[test]
+    fn quoted_function_lookup_requires_exact_quoted_name() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
+            "#;
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(db.function("\"FooBar\"").is_some());
+        assert!(db.function("foobar").is_none());
+        assert!(db.function("\"foobar\"").is_none());
+    }
+
+    #[test]
+    fn unquoted_function_lookup_uses_identifier_folding() {
+        let sql = r"
+                CREATE FUNCTION foobar() RETURNS INT AS 'SELECT 1;';
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(db.function("foobar").is_some());
+        assert!(db.function("FOOBAR").is_some());
+        assert!(db.function("\"FOOBAR\"").is_none());
+    }
+
+    #[test]
+    fn database_like_function_lookup_is_identifier_aware() {
+        let sql = r#"
+                CREATE FUNCTION "FooBar"() RETURNS INT AS 'SELECT 1;';
+            "#;
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(<ParserDB as DatabaseLike>::function(&db, "\"FooBar\"").is_some());
+        assert!(<ParserDB as DatabaseLike>::function(&db, "foobar").is_none());
+        assert!(<ParserDB as DatabaseLike>::function(&db, "\"foobar\"").is_none());
+    }
+}
+
+mod function_overload_tests {
+    use super::*;
+    use crate::traits::DatabaseLike;
+
+    #[test]
+    fn builtin_overloads_are_grouped_in_deterministic_order() {
+        let db =
+            ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT);").expect("Failed to parse");
+
+        let arities: Vec<usize> =
+            db.function_overloads("uuidv7").map(|f| f.argument_type_names(&db).count()).collect();
+        assert_eq!(arities, vec![0, 1]);
+        assert_eq!(db.function("uuidv7").unwrap().argument_type_names(&db).count(), 0);
+    }
+
+    #[test]
+    fn overload_lookup_does_not_depend_on_declaration_order() {
+        let forward = ParserDB::parse::<GenericDialect>(
+            r"
+                CREATE FUNCTION area(w FLOAT, h FLOAT) RETURNS FLOAT AS 'SELECT w * h;';
+                CREATE FUNCTION area(r FLOAT) RETURNS FLOAT AS 'SELECT r * r;';
+                ",
+        )
+        .expect("Failed to parse");
+        let backward = ParserDB::parse::<GenericDialect>(
+            r"
+                CREATE FUNCTION area(r FLOAT) RETURNS FLOAT AS 'SELECT r * r;';
+                CREATE FUNCTION area(w FLOAT, h FLOAT) RETURNS FLOAT AS 'SELECT w * h;';
+                ",
+        )
+        .expect("Failed to parse");
+
+        let forward_arities: Vec<usize> = forward
+            .function_overloads("area")
+            .map(|f| f.argument_type_names(&forward).count())
+            .collect();
+        let backward_arities: Vec<usize> = backward
+            .function_overloads("area")
+            .map(|f| f.argument_type_names(&backward).count())
+            .collect();
+        assert_eq!(forward_arities.len(), 2);
+        assert_eq!(forward_arities, backward_arities);
+        assert_eq!(forward.function("area"), backward.function("area"));
+    }
+
+    #[test]
+    fn overloads_follow_identifier_semantics() {
+        let db = ParserDB::parse::<GenericDialect>(
+            r#"
+                CREATE FUNCTION foo(x INT) RETURNS INT AS 'SELECT x;';
+                CREATE FUNCTION FOO(x TEXT) RETURNS TEXT AS 'SELECT x;';
+                CREATE FUNCTION "Foo"(x INT) RETURNS INT AS 'SELECT x;';
+                "#,
+        )
+        .expect("Failed to parse");
+
+        assert_eq!(db.function_overloads("foo").count(), 2);
+        assert_eq!(db.function_overloads("\"Foo\"").count(), 1);
+        assert_eq!(<ParserDB as DatabaseLike>::function_overloads(&db, "FOO").count(), 2);
+        for function in db.functions() {
+            assert!(db.function_metadata(function).is_some());
+        }
+    }
+
+    #[test]
+    fn exact_duplicate_functions_are_stored_once() {
+        let sql = "CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;';";
+        let statements =
+            Parser::parse_sql(&GenericDialect {}, &format!("{sql}{sql}")).expect("Failed to parse");
+        let db = ParserDB::from_statements(statements, "test".to_string())
+            .expect("Failed to build database");
+
+        assert_eq!(db.function_overloads("one").count(), 1);
+    }
+}
+
+mod is_table_referenced_tests {
+    use super::*;
+
+    #[test]
+    fn test_table_not_referenced() {
+        let sql = r"
+                CREATE TABLE standalone (id INT PRIMARY KEY);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Table exists
+        assert!(db.table(None, "standalone").is_some());
+
+        // Can be dropped
+        let drop_sql = format!("{sql}\nDROP TABLE standalone;");
+        let result = ParserDB::parse::<GenericDialect>(&drop_sql);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_table_referenced_by_single_fk() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Both tables exist
+        assert!(db.table(None, "parent").is_some());
+        assert!(db.table(None, "child").is_some());
+
+        // Parent cannot be dropped (referenced)
+        let drop_parent = format!("{sql}\nDROP TABLE parent;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&drop_parent),
+            Err(Error::TableReferenced { table_name }) if table_name == "parent"
+        ));
+
+        // Child can be dropped (not referenced)
+        let drop_child = format!("{sql}\nDROP TABLE child;");
+        assert!(ParserDB::parse::<GenericDialect>(&drop_child).is_ok());
+    }
+
+    #[test]
+    fn test_table_referenced_by_multiple_fks() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child1 (id INT, parent_id INT REFERENCES parent(id));
+                CREATE TABLE child2 (id INT, parent_id INT REFERENCES parent(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(db.table(None, "parent").is_some());
+
+        // Parent cannot be dropped
+        let drop_sql = format!("{sql}\nDROP TABLE parent;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&drop_sql),
+            Err(Error::TableReferenced { .. })
+        ));
+    }
+
+    #[test]
+    fn test_self_referential_table_not_blocked() {
+        let sql = r"
+                CREATE TABLE tree (id INT PRIMARY KEY, parent_id INT REFERENCES tree(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        assert!(db.table(None, "tree").is_some());
+
+        // Self-referential table CAN be dropped
+        let drop_sql = format!("{sql}\nDROP TABLE tree;");
+        assert!(ParserDB::parse::<GenericDialect>(&drop_sql).is_ok());
+    }
+
+    #[test]
+    fn test_chain_of_references() {
+        let sql = r"
+                CREATE TABLE grandparent (id INT PRIMARY KEY);
+                CREATE TABLE parent (id INT PRIMARY KEY, gp_id INT REFERENCES grandparent(id));
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+            ";
+
+        // Cannot drop grandparent (referenced by parent)
+        let drop_gp = format!("{sql}\nDROP TABLE grandparent;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&drop_gp),
+            Err(Error::TableReferenced { table_name }) if table_name == "grandparent"
+        ));
+
+        // Cannot drop parent (referenced by child)
+        let drop_parent = format!("{sql}\nDROP TABLE parent;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&drop_parent),
+            Err(Error::TableReferenced { table_name }) if table_name == "parent"
+        ));
+
+        // Can drop child (not referenced)
+        let drop_child = format!("{sql}\nDROP TABLE child;");
+        assert!(ParserDB::parse::<GenericDialect>(&drop_child).is_ok());
+    }
+}
+
+mod remove_table_tests {
+    use super::*;
+    use crate::traits::{DatabaseLike, TableLike};
+
+    #[test]
+    fn test_remove_table_removes_columns() {
+        let sql = r"
+                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT, age INT);
+                CREATE TABLE t2 (id INT PRIMARY KEY);
+                DROP TABLE t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // t1 should be gone
+        assert!(db.table(None, "t1").is_none());
+
+        // t2 should still have its column
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.columns(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_remove_table_removes_indices() {
+        let sql = r"
+                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX idx_name ON t1(name);
+                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
+                CREATE INDEX idx_value ON t2(value);
+                DROP TABLE t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // t1's index should be gone, t2's should remain
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.indices(&db).count(), 1);
+
+        // Total indices across all tables should be 1 (only t2's index)
+        let total_indices: usize = db.tables().map(|t| t.indices(&db).count()).sum();
+        assert_eq!(total_indices, 1);
+    }
+
+    #[test]
+    fn test_remove_table_removes_foreign_keys() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT, parent_id INT REFERENCES parent(id));
+                DROP TABLE child;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Parent should exist with no foreign keys (parent doesn't have any FKs
+        // pointing out)
+        let parent = db.table(None, "parent").expect("parent should exist");
+        assert_eq!(parent.foreign_keys(&db).count(), 0);
+
+        // No foreign keys in the database (child's FK was removed with child)
+        let total_fks: usize = db.tables().map(|t| t.foreign_keys(&db).count()).sum();
+        assert_eq!(total_fks, 0);
+    }
+
+    #[test]
+    fn test_remove_table_removes_check_constraints() {
+        let sql = r"
+                CREATE TABLE t1 (id INT CHECK (id > 0));
+                CREATE TABLE t2 (value INT CHECK (value < 100));
+                DROP TABLE t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Only t2's check constraint should remain
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.check_constraints(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_remove_table_removes_triggers() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER trg1 BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION fn1();
+                CREATE TRIGGER trg2 BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION fn2();
+                DROP TABLE t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Only t2's trigger should remain
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.triggers(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_remove_table_removes_policies() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE POLICY p1 ON t1 USING (true);
+                CREATE POLICY p2 ON t2 USING (true);
+                DROP TABLE t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse");
+
+        // Only t2's policy should remain
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.policies(&db).count(), 1);
+    }
+}
+
+mod drop_index_tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_index_basic() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX idx_name ON t(name);
+                DROP INDEX idx_name;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Index should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.indices(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_index_if_exists_when_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX idx_name ON t(name);
+                DROP INDEX IF EXISTS idx_name;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Index should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.indices(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_index_if_exists_when_not_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY);
+                DROP INDEX IF EXISTS nonexistent_idx;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Should succeed without error
+        assert!(db.table(None, "t").is_some());
+    }
+
+    #[test]
+    fn test_drop_index_not_found_error_type() {
+        let sql = "DROP INDEX nonexistent_idx;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropIndexNotFound { index_name }) if index_name == "nonexistent_idx"
+        ));
+    }
+
+    #[test]
+    fn test_drop_multiple_indices() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, age INT);
+                CREATE INDEX idx_name ON t(name);
+                CREATE INDEX idx_age ON t(age);
+                DROP INDEX idx_name;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Only idx_age should remain
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.indices(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_index_then_recreate() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX idx_name ON t(name);
+                DROP INDEX idx_name;
+                CREATE INDEX idx_name ON t(name);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Index should exist again
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.indices(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_index_keeps_other_table_indices() {
+        let sql = r"
+                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
+                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
+                CREATE INDEX idx_t1_name ON t1(name);
+                CREATE INDEX idx_t2_value ON t2(value);
+                DROP INDEX idx_t1_name;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // t1 should have no indices
+        let t1 = db.table(None, "t1").expect("t1 should exist");
+        assert_eq!(t1.indices(&db).count(), 0);
+
+        // t2 should still have its index
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.indices(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_index_table_still_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, age INT);
+                CREATE INDEX idx_name ON t(name);
+                DROP INDEX idx_name;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Table should still exist with its columns
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.columns(&db).count(), 3);
+    }
+}
+
+mod index_enumeration_tests {
+    use super::*;
+    use crate::traits::IndexLike;
+
+    #[test]
+    fn test_indexes_enumerates_named_indexes() {
+        let sql = r"
+                CREATE TABLE t1 (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX idx_name ON t1(name);
+                CREATE TABLE t2 (id INT PRIMARY KEY, value TEXT);
+                CREATE INDEX idx_value ON t2(value);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let names: Vec<String> =
+            db.indexes().filter_map(|i| i.name().map(ToString::to_string)).collect();
+        assert_eq!(names, vec!["idx_name", "idx_value"]);
+    }
+
+    #[test]
+    fn test_anonymous_index_has_no_name() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
+                CREATE INDEX ON t(name);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let index = db.indexes().next().expect("index should exist");
+        assert!(index.name().is_none());
+    }
+
+    #[test]
+    fn test_indexes_excludes_unique_constraints() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT, UNIQUE (name));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        assert_eq!(db.indexes().count(), 0);
+
+        // A unique constraint viewed as an `IndexLike` exposes no
+        // `ObjectName` name accessor; its index name is an `Ident`.
+        let table = db.table(None, "t").expect("table should exist");
+        for ui in table.unique_indices(&db) {
+            assert!(IndexLike::name(ui).is_none());
+        }
+    }
+}
+
+mod drop_trigger_tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_trigger_basic() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER my_trigger ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Trigger should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_trigger_follows_identifier_casing() {
+        let sql = r#"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER Audit BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                CREATE TRIGGER "Keep" BEFORE UPDATE ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER AUDIT ON t;
+            "#;
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 1);
+        assert!(table.trigger(&db, "keep").is_none());
+        assert!(table.trigger(&db, "\"Keep\"").is_some());
+        assert!(db.trigger("\"Keep\"").is_some());
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&sql.replace("AUDIT", "keep")),
+            Err(Error::DropTriggerNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_drop_trigger_if_exists_when_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER IF EXISTS my_trigger ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Trigger should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_trigger_if_exists_when_not_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                DROP TRIGGER IF EXISTS nonexistent_trigger ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Should succeed without error
+        assert!(db.table(None, "t").is_some());
+    }
+
+    #[test]
+    fn test_drop_trigger_not_found_error_type() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                DROP TRIGGER nonexistent_trigger ON t;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropTriggerNotFound { trigger_name }) if trigger_name == "nonexistent_trigger"
+        ));
+    }
+
+    #[test]
+    fn test_drop_one_of_multiple_triggers() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER trigger1 BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION fn1();
+                CREATE TRIGGER trigger2 BEFORE UPDATE ON t FOR EACH ROW EXECUTE FUNCTION fn2();
+                DROP TRIGGER trigger1 ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Only trigger2 should remain
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_trigger_then_recreate() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER my_trigger ON t;
+                CREATE TRIGGER my_trigger AFTER INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Trigger should exist again
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_trigger_keeps_other_table_triggers() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION fn1() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE FUNCTION fn2() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER trigger1 BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION fn1();
+                CREATE TRIGGER trigger2 BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION fn2();
+                DROP TRIGGER trigger1 ON t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // t1 should have no triggers
+        let t1 = db.table(None, "t1").expect("t1 should exist");
+        assert_eq!(t1.triggers(&db).count(), 0);
+
+        // t2 should still have its trigger
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.triggers(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_trigger_after_dropping_earlier_trigger() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER first_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                CREATE TRIGGER second_trigger AFTER INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER first_trigger ON t;
+                DROP TRIGGER second_trigger ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.triggers(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_trigger_function_still_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER my_trigger BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER my_trigger ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Function should still exist after dropping trigger
+        assert!(db.function("trigger_fn").is_some());
+    }
+
+    #[test]
+    fn test_drop_same_named_trigger_on_one_table() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER audit BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                CREATE TRIGGER audit BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER audit ON t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let t1 = db.table(None, "t1").expect("t1 should exist");
+        assert!(t1.trigger(&db, "audit").is_none());
+
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        let trigger = t2.trigger(&db, "audit").expect("t2 should keep its trigger");
+        assert_eq!(trigger.table_name(), "t2");
+        assert_eq!(db.triggers().count(), 1);
+    }
+
+    #[test]
+    fn test_same_named_triggers_are_both_stored() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER audit BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                CREATE TRIGGER audit AFTER DELETE ON t1 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let tables: Vec<&str> = db.triggers().map(TriggerLike::table_name).collect();
+        assert_eq!(tables, vec!["t1", "t2"]);
+        for table_name in ["t1", "t2"] {
+            let table = db.table(None, table_name).expect("Table should exist");
+            let trigger = table.trigger(&db, "audit").expect("Trigger should exist");
+            assert_eq!(trigger.table(&db).table_name(), table_name);
+            assert!(db.trigger_metadata(trigger).is_some());
+        }
+    }
+
+    #[test]
+    fn test_drop_trigger_without_table_is_ambiguous() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER audit BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                CREATE TRIGGER audit BEFORE INSERT ON t2 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER audit;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropTriggerAmbiguous { trigger_name }) if trigger_name == "audit"
+        ));
+    }
+
+    #[test]
+    fn test_drop_trigger_on_wrong_table_not_found() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE FUNCTION trigger_fn() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+                CREATE TRIGGER audit BEFORE INSERT ON t1 FOR EACH ROW EXECUTE FUNCTION trigger_fn();
+                DROP TRIGGER audit ON t2;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropTriggerNotFound { trigger_name }) if trigger_name == "audit"
+        ));
+    }
+}
+
+mod drop_policy_tests {
+    use super::*;
+    use crate::traits::PolicyLike;
+
+    #[test]
+    fn test_drop_policy_basic() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE POLICY my_policy ON t USING (true);
+                DROP POLICY my_policy ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Policy should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.policies(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_policy_if_exists_when_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE POLICY my_policy ON t USING (true);
+                DROP POLICY IF EXISTS my_policy ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Policy should be removed
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.policies(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_policy_if_exists_when_not_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                DROP POLICY IF EXISTS nonexistent_policy ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Should succeed without error
+        assert!(db.table(None, "t").is_some());
+    }
+
+    #[test]
+    fn test_drop_policy_not_found_error_type() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                DROP POLICY nonexistent_policy ON t;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropPolicyNotFound { policy_name }) if policy_name == "nonexistent_policy"
+        ));
+    }
+
+    #[test]
+    fn test_drop_one_of_multiple_policies() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE POLICY policy1 ON t USING (true);
+                CREATE POLICY policy2 ON t USING (false);
+                DROP POLICY policy1 ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Only policy2 should remain
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.policies(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_policy_then_recreate() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE POLICY my_policy ON t USING (true);
+                DROP POLICY my_policy ON t;
+                CREATE POLICY my_policy ON t USING (false);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Policy should exist again
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.policies(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_policy_keeps_other_table_policies() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE POLICY policy1 ON t1 USING (true);
+                CREATE POLICY policy2 ON t2 USING (true);
+                DROP POLICY policy1 ON t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // t1 should have no policies
+        let t1 = db.table(None, "t1").expect("t1 should exist");
+        assert_eq!(t1.policies(&db).count(), 0);
+
+        // t2 should still have its policy
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        assert_eq!(t2.policies(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_homonymous_policies_are_resolved_by_table() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE POLICY visible ON t1 USING (true);
+                CREATE POLICY visible ON t2 USING (true);
+                ALTER POLICY visible ON t2 RENAME TO shown;
+                DROP POLICY visible ON t1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let t1 = db.table(None, "t1").expect("t1 should exist");
+        assert_eq!(t1.policies(&db).count(), 0);
+        let t2 = db.table(None, "t2").expect("t2 should exist");
+        let names: Vec<&str> = t2.policies(&db).map(PolicyLike::name).collect();
+        assert_eq!(names, ["shown"]);
+
+        let error = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t1 (id INT); CREATE TABLE t2 (id INT);
+                CREATE POLICY visible ON t1 USING (true);
+                ALTER POLICY visible ON t2 RENAME TO shown;",
+        )
+        .unwrap_err();
+        assert!(matches!(error, Error::AlterPolicyNotFound { .. }));
+    }
+
+    #[test]
+    fn test_drop_policy_table_still_exists() {
+        let sql = r"
+                CREATE TABLE t (id INT, name TEXT);
+                CREATE POLICY my_policy ON t USING (true);
+                DROP POLICY my_policy ON t;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Table should still exist with its columns
+        let table = db.table(None, "t").expect("Table should exist");
+        assert_eq!(table.columns(&db).count(), 2);
+    }
+}
+
+mod drop_role_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::RoleLike;
+
+    #[test]
+    fn test_drop_role_basic() {
+        let sql = r"
+                CREATE ROLE my_role;
+                DROP ROLE my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Role should be removed
+        assert!(db.role("my_role").is_none());
+    }
+
+    #[test]
+    fn test_drop_role_if_exists_when_exists() {
+        let sql = r"
+                CREATE ROLE my_role;
+                DROP ROLE IF EXISTS my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Role should be removed
+        assert!(db.role("my_role").is_none());
+    }
+
+    #[test]
+    fn test_drop_role_if_exists_when_not_exists() {
+        let sql = r"
+                DROP ROLE IF EXISTS nonexistent_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Should succeed without error
+        assert_eq!(db.roles().count(), 0);
+    }
+
+    #[test]
+    fn test_drop_role_not_found_error_type() {
+        let sql = "DROP ROLE nonexistent_role;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::DropRoleNotFound { role_name }) if role_name == "nonexistent_role"
+        ));
+    }
+
+    #[test]
+    fn test_drop_role_referenced_by_grant_fails() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON t TO my_role;
+                DROP ROLE my_role;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleReferenced { role_name }) if role_name == "my_role"
+        ));
+    }
+
+    #[test]
+    fn test_drop_role_after_revoking_grant_succeeds() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON t TO my_role;
+                REVOKE SELECT ON t FROM my_role;
+                DROP ROLE my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Should succeed after revoking");
+
+        // Role should be removed
+        assert!(db.role("my_role").is_none());
+    }
+
+    #[test]
+    fn test_drop_one_of_multiple_roles() {
+        let sql = r"
+                CREATE ROLE role1;
+                CREATE ROLE role2;
+                DROP ROLE role1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // role1 should be removed
+        assert!(db.role("role1").is_none());
+
+        // role2 should still exist
+        assert!(db.role("role2").is_some());
+    }
+
+    #[test]
+    fn test_drop_role_with_members_fails() {
+        let sql = r"
+                CREATE ROLE admin;
+                CREATE ROLE alice;
+                GRANT admin TO alice;
+                DROP ROLE admin;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleReferenced { role_name }) if role_name == "admin"
+        ));
+    }
+
+    #[test]
+    fn test_drop_role_owning_a_schema_fails() {
+        let sql = r"
+                CREATE ROLE Admin;
+                CREATE SCHEMA app AUTHORIZATION admin;
+                DROP ROLE ADMIN;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleReferenced { role_name }) if role_name == "ADMIN"
+        ));
+    }
+
+    #[test]
+    fn test_drop_member_role_succeeds() {
+        let sql = r"
+                CREATE ROLE admin;
+                CREATE ROLE alice IN ROLE admin;
+                DROP ROLE alice;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        assert!(db.role("alice").is_none());
+        assert_eq!(db.role("admin").unwrap().members(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_role_then_recreate() {
+        let sql = r"
+                CREATE ROLE my_role;
+                DROP ROLE my_role;
+                CREATE ROLE my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        // Role should exist again
+        assert!(db.role("my_role").is_some());
+    }
+
+    #[test]
+    fn test_roles_found_after_dropping_earlier_role() {
+        let sql = r"
+                CREATE ROLE reader, writer;
+                CREATE TABLE t (id INT);
+                DROP ROLE reader;
+                GRANT SELECT ON t TO writer;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        assert!(db.role("reader").is_none());
+        assert!(db.role("writer").is_some());
+    }
+}
+
+mod role_membership_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::RoleLike;
+
+    #[test]
+    fn test_grant_and_revoke_role_membership() {
+        let sql = r"
+                CREATE ROLE admin;
+                CREATE ROLE auditor;
+                CREATE ROLE alice;
+                GRANT admin, auditor TO alice WITH ADMIN OPTION GRANTED BY admin;
+                REVOKE auditor FROM alice;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let alice = db.role("alice").unwrap();
+        let parents: Vec<&str> = alice.member_of(&db).map(RoleLike::name).collect();
+        assert_eq!(parents, ["admin"]);
+        assert_eq!(db.role("auditor").unwrap().members(&db).count(), 0);
+        assert!(db.table_grants().next().is_none());
+    }
+
+    #[test]
+    fn test_dropped_role_membership_clauses_are_reported() {
+        let sql = r"
+                CREATE ROLE admin;
+                CREATE ROLE alice;
+                GRANT admin TO alice WITH ADMIN OPTION GRANTED BY admin;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let parents: Vec<&str> =
+            db.role("alice").unwrap().member_of(&db).map(RoleLike::name).collect();
+        assert_eq!(parents, ["admin"]);
+        let warnings = db.validation_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(
+            warnings
+                .iter()
+                .all(|warning| warning.class() == ValidationClass::UnsupportedStatements)
+        );
+        assert!(warnings[0].message().contains("WITH ADMIN OPTION"));
+        assert!(warnings[1].message().contains("GRANTED BY admin"));
+
+        let result = ParserDB::parse_with_options::<PostgreSqlDialect>(
+            sql,
+            ParserOptions::default()
+                .with_level(ValidationClass::UnsupportedStatements, ValidationLevel::Error),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedClause { clause, .. }) if clause == "WITH ADMIN OPTION"
+        ));
+    }
+
+    #[test]
+    fn test_grant_role_membership_is_idempotent() {
+        let sql = r"
+                CREATE ROLE admin;
+                CREATE ROLE alice IN ROLE admin;
+                GRANT admin TO alice;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        assert_eq!(db.role("alice").unwrap().member_of(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_grant_role_membership_cycle_fails() {
+        let sql = r"
+                CREATE ROLE a;
+                CREATE ROLE b IN ROLE a;
+                CREATE ROLE c;
+                GRANT b TO c;
+                GRANT c TO a;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleMembershipCycle { role_name, member_name })
+                if role_name == "c" && member_name == "a"
+        ));
+    }
+
+    #[test]
+    fn test_grant_role_to_itself_fails() {
+        let sql = "CREATE ROLE a; GRANT a TO a;";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RoleMembershipCycle { .. })));
+    }
+
+    #[test]
+    fn test_role_membership_unknown_role_fails() {
+        let sql = "CREATE ROLE alice; GRANT missing TO alice;";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleNotFoundForGrant { role_name }) if role_name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_revoke_missing_role_membership_fails() {
+        let sql = "CREATE ROLE admin; CREATE ROLE alice; REVOKE admin FROM alice;";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+}
+
+mod default_privilege_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{
+        privileges::TablePrivilege,
+        traits::{DefaultPrivilegeLike, RoleLike},
+    };
+
+    #[test]
+    fn test_default_privileges_apply_to_tables_in_their_schemas() {
+        let sql = r"
+                CREATE ROLE reader;
+                CREATE SCHEMA app;
+                CREATE SCHEMA audit;
+                ALTER DEFAULT PRIVILEGES IN SCHEMA app GRANT SELECT ON TABLES TO reader;
+                CREATE TABLE app.users (id INT);
+                CREATE TABLE audit.events (id INT);
+                CREATE TABLE notes (id INT);
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let users = db.table(Some("app"), "users").unwrap();
+        let grantees: Vec<&str> = users
+            .default_privileges(&db)
+            .flat_map(|default_privilege| default_privilege.grantees(&db))
+            .map(RoleLike::name)
+            .collect();
+        assert_eq!(grantees, ["reader"]);
+        assert_eq!(db.table(Some("audit"), "events").unwrap().default_privileges(&db).count(), 0);
+        assert_eq!(db.table(None, "notes").unwrap().default_privileges(&db).count(), 0);
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    #[test]
+    fn test_revoked_default_privileges_keep_existing_tables() {
+        let sql = r"
+                CREATE ROLE reader;
+                CREATE ROLE writer;
+                ALTER DEFAULT PRIVILEGES GRANT SELECT, INSERT ON TABLES TO reader, writer;
+                CREATE TABLE earlier (id INT);
+                ALTER DEFAULT PRIVILEGES REVOKE INSERT ON TABLES FROM writer;
+                ALTER DEFAULT PRIVILEGES IN SCHEMA public REVOKE SELECT ON TABLES FROM reader;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        assert_eq!(db.default_privileges().count(), 2);
+        let writer = db.role("writer").unwrap();
+        let writer_privileges: Vec<_> = db
+            .default_privileges()
+            .filter(|default_privilege| default_privilege.applies_to_role(writer, &db))
+            .flat_map(DefaultPrivilegeLike::privileges)
+            .collect();
+        assert_eq!(writer_privileges, [TablePrivilege::Select]);
+
+        let earlier = db.table(None, "earlier").unwrap();
+        assert_eq!(earlier.default_privileges(&db).count(), 1);
+        assert_eq!(earlier.default_privileges(&db).next().unwrap().table_privileges().len(), 2);
+    }
+
+    #[test]
+    fn test_default_privileges_for_missing_role_fail() {
+        let sql = "ALTER DEFAULT PRIVILEGES GRANT SELECT ON TABLES TO missing;";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleNotFoundForGrant { role_name }) if role_name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_drop_role_named_by_default_privileges_fails() {
+        let sql = r"
+                CREATE ROLE owner;
+                ALTER DEFAULT PRIVILEGES FOR ROLE owner GRANT SELECT ON TABLES TO PUBLIC;
+                DROP ROLE owner;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::RoleReferenced { role_name }) if role_name == "owner"
+        ));
+    }
+
+    #[test]
+    fn test_default_privileges_round_trip() {
+        let sql = r"
+                CREATE ROLE reader;
+                ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+        let ddl = db.to_sql(SqlparserDialect::PostgreSql);
+        assert!(ddl.ends_with(
+            "ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;\n"
+        ));
+
+        let reparsed = ParserDB::parse::<PostgreSqlDialect>(&ddl).expect("Failed to parse SQL");
+        assert_eq!(reparsed.default_privileges().count(), 1);
+    }
+}
+
+mod grant_revoke_semantics {
+    use sqlparser::{ast::Action, dialect::PostgreSqlDialect};
+
+    use super::*;
+    use crate::traits::{GrantLike, TableLike};
+
+    #[test]
+    fn test_revoke_partial_privilege_preserves_other_actions() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT, INSERT ON t TO my_role;
+                REVOKE SELECT ON t FROM my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let grants: Vec<_> = db.table_grants().collect();
+        assert_eq!(grants.len(), 1);
+        let grant = grants[0];
+        assert!(!grant.is_all_privileges());
+
+        let remaining_privileges: Vec<_> = grant.privileges(&db).collect();
+        assert_eq!(remaining_privileges.len(), 1);
+        assert!(matches!(remaining_privileges[0], Action::Insert { .. }));
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role = db.role("my_role").expect("Role should exist");
+        assert!(!table.can_select(role, &db), "SELECT should be revoked while INSERT remains");
+        assert!(table.can_insert(role, &db));
+    }
+
+    #[test]
+    fn test_grants_are_classified_by_scope() {
+        let sql = r"
+                CREATE TABLE t (id INT, name TEXT);
+                CREATE ROLE reader;
+                CREATE ROLE editor;
+                GRANT SELECT ON t TO reader;
+                GRANT UPDATE (name) ON t TO editor;
+                GRANT SELECT (id), DELETE ON t TO editor;
+                GRANT SELECT ON ALL TABLES IN SCHEMA public TO reader;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let table_grants: Vec<String> = db.table_grants().map(ToString::to_string).collect();
+        assert_eq!(
+            table_grants,
+            [
+                "GRANT SELECT ON t TO reader",
+                "GRANT DELETE ON t TO editor",
+                "GRANT SELECT ON ALL TABLES IN SCHEMA public TO reader",
+            ]
+        );
+        let column_grants: Vec<String> = db.column_grants().map(ToString::to_string).collect();
+        assert_eq!(
+            column_grants,
+            ["GRANT UPDATE (name) ON t TO editor", "GRANT SELECT (id) ON t TO editor"]
+        );
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let editor = db.role("editor").expect("Role should exist");
+        assert!(!table.can_select(editor, &db), "SELECT is only granted on a column");
+        assert!(!table.can_update(editor, &db), "UPDATE is only granted on a column");
+        assert!(table.can_delete(editor, &db));
+    }
+
+    #[test]
+    fn test_revoke_column_scoped_against_table_wide_action_is_unsupported() {
+        let sql = r"
+                CREATE TABLE t (id INT, name TEXT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON t TO my_role;
+                REVOKE SELECT (id) ON t FROM my_role;
+            ";
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedRevoke { reason, .. })
+                if reason.contains("column-scoped REVOKE against a table-wide action grant")
+        ));
+    }
+
+    #[test]
+    fn test_revoke_column_scoped_from_column_scoped_grant_keeps_remaining_columns() {
+        let sql = r"
+                CREATE TABLE t (id INT, name TEXT);
+                CREATE ROLE my_role;
+                GRANT SELECT (id, name) ON t TO my_role;
+                REVOKE SELECT (id) ON t FROM my_role;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let grant = db.column_grants().next().expect("Expected a remaining grant");
+        let remaining_privileges: Vec<_> = grant.privileges(&db).collect();
+
+        assert_eq!(remaining_privileges.len(), 1);
+        match remaining_privileges[0] {
+            Action::Select { columns: Some(columns) } => {
+                assert_eq!(columns.len(), 1);
+                assert_eq!(columns[0].value, "name");
+            }
+            other => panic!("Expected SELECT with one remaining column, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_revoke_from_first_grantee_keeps_second_grantee_privileges() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE a;
+                CREATE ROLE b;
+                GRANT SELECT ON t TO a, b;
+                REVOKE SELECT ON t FROM a;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role_a = db.role("a").expect("Role a should exist");
+        let role_b = db.role("b").expect("Role b should exist");
+
+        assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
+        assert!(table.can_select(role_b, &db), "SELECT should remain for b");
+    }
+
+    #[test]
+    fn test_revoke_from_second_grantee_keeps_first_grantee_privileges() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE a;
+                CREATE ROLE b;
+                GRANT SELECT ON t TO a, b;
+                REVOKE SELECT ON t FROM b;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role_a = db.role("a").expect("Role a should exist");
+        let role_b = db.role("b").expect("Role b should exist");
+
+        assert!(table.can_select(role_a, &db), "SELECT should remain for a");
+        assert!(!table.can_select(role_b, &db), "SELECT should be revoked for b");
+    }
+
+    #[test]
+    fn test_revoke_partial_privilege_from_one_grantee_preserves_other_grantee_actions() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE a;
+                CREATE ROLE b;
+                GRANT SELECT, INSERT ON t TO a, b;
+                REVOKE SELECT ON t FROM a;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role_a = db.role("a").expect("Role a should exist");
+        let role_b = db.role("b").expect("Role b should exist");
+
+        assert_eq!(db.table_grants().count(), 2);
+        assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
+        assert!(table.can_insert(role_a, &db), "INSERT should remain for a");
+        assert!(table.can_select(role_b, &db), "SELECT should remain for b");
+        assert!(table.can_insert(role_b, &db), "INSERT should remain for b");
+    }
+
+    #[test]
+    fn test_revoke_all_from_one_grantee_preserves_other_grantee_actions() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE a;
+                CREATE ROLE b;
+                GRANT SELECT, INSERT ON t TO a, b;
+                REVOKE ALL ON t FROM a;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role_a = db.role("a").expect("Role a should exist");
+        let role_b = db.role("b").expect("Role b should exist");
+
+        assert!(!table.can_select(role_a, &db), "SELECT should be revoked for a");
+        assert!(!table.can_insert(role_a, &db), "INSERT should be revoked for a");
+        assert!(table.can_select(role_b, &db), "SELECT should remain for b");
+        assert!(table.can_insert(role_b, &db), "INSERT should remain for b");
+    }
+
+    #[test]
+    fn test_revoke_all_removes_matching_grants() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT, INSERT ON t TO my_role;
+                REVOKE ALL ON t FROM my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        assert_eq!(db.table_grants().count(), 0);
+        assert_eq!(db.column_grants().count(), 0);
+    }
+
+    #[test]
+    fn test_public_grant_applies_to_non_public_role() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE app_user;
+                GRANT SELECT ON t TO PUBLIC;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let app_user = db.role("app_user").expect("Role should exist");
+        assert!(table.can_select(app_user, &db));
+    }
+
+    #[test]
+    fn test_schema_qualified_grant_applies_only_to_target_table() {
+        let sql = r"
+                CREATE SCHEMA s1;
+                CREATE SCHEMA s2;
+                CREATE TABLE s1.t (id INT);
+                CREATE TABLE s2.t (id INT);
+                CREATE ROLE app_role;
+                GRANT SELECT ON s1.t TO app_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+
+        let role = db.role("app_role").expect("Role should exist");
+        let s1_t = db.table(Some("s1"), "t").expect("s1.t should exist");
+        let s2_t = db.table(Some("s2"), "t").expect("s2.t should exist");
+
+        assert!(s1_t.can_select(role, &db));
+        assert!(!s2_t.can_select(role, &db));
+    }
+
+    #[test]
+    fn test_revoke_object_matching_is_case_insensitive_for_unquoted_identifiers() {
+        let sql = r"
+                CREATE TABLE T (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON T TO my_role;
+                REVOKE SELECT ON t FROM my_role;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role = db.role("my_role").expect("Role should exist");
+        assert!(!table.can_select(role, &db));
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    #[test]
+    fn test_revoke_object_matching_preserves_quoted_identifier_semantics() {
+        let sql = r#"
+                CREATE TABLE T (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON T TO my_role;
+                REVOKE SELECT ON "T" FROM my_role;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    #[test]
+    fn test_revoke_object_matching_does_not_match_quoted_grant_with_unquoted_lookup() {
+        let sql = r#"
+                CREATE TABLE "T" (id INT);
+                CREATE ROLE my_role;
+                GRANT SELECT ON "T" TO my_role;
+                REVOKE SELECT ON t FROM my_role;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    #[test]
+    fn test_revoke_function_object_matching_is_case_insensitive_for_unquoted_identifiers() {
+        let sql = r"
+                CREATE ROLE my_role;
+                GRANT EXECUTE ON FUNCTION F() TO my_role;
+                REVOKE EXECUTE ON FUNCTION f() FROM my_role;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse SQL");
+
+        assert_eq!(db.table_grants().count(), 0);
+        assert_eq!(db.column_grants().count(), 0);
+    }
+
+    #[test]
+    fn test_revoke_function_object_matching_preserves_quoted_identifier_semantics() {
+        let sql = r#"
+                CREATE ROLE my_role;
+                GRANT EXECUTE ON FUNCTION F() TO my_role;
+                REVOKE EXECUTE ON FUNCTION "F"() FROM my_role;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    #[test]
+    fn test_revoke_function_object_matching_does_not_match_quoted_grant_with_unquoted_lookup() {
+        let sql = r#"
+                CREATE ROLE my_role;
+                GRANT EXECUTE ON FUNCTION "F"() TO my_role;
+                REVOKE EXECUTE ON FUNCTION f() FROM my_role;
+            "#;
+        let result = ParserDB::parse::<PostgreSqlDialect>(sql);
+
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    // ----------------------------------------------------------------
+    // Coverage-targeted tests for `impls/sqlparser/grant.rs`:
+    // `partition_grantees_for_revoke`, `apply_revoke_to_grant`,
+    // `grant_objects_inner_match`, the per-column accessor branches,
+    // and the GrantObjects variants the existing tests don't reach.
+    // ----------------------------------------------------------------
+
+    /// `apply_revoke_to_grant` `(Privileges::All, Privileges::Actions)`
+    /// arm: `GRANT ALL PRIVILEGES` on a table is expanded into the
+    /// table privileges it stands for, so a partial revoke keeps the
+    /// privileges still held.
+    #[test]
+    fn test_revoke_select_against_all_privileges_keeps_other_privileges() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE my_role;
+                GRANT ALL PRIVILEGES ON t TO my_role;
+                REVOKE SELECT ON t FROM my_role;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+
+        let grant = db.table_grants().next().expect("grant must remain");
+        assert!(!grant.is_all_privileges(), "ALL PRIVILEGES is expanded into actions");
+        let privileges: Vec<_> = grant.privileges(&db).collect();
+        assert_eq!(privileges.len(), 6);
+        assert!(!privileges.iter().any(|action| matches!(action, Action::Select { .. })));
+
+        let table = db.table(None, "t").expect("Table should exist");
+        let role = db.role("my_role").expect("Role should exist");
+        assert!(!table.can_select(role, &db));
+        assert!(table.can_insert(role, &db));
+        assert!(table.can_delete(role, &db));
+    }
+
+    /// `apply_revoke_to_grant` `(Privileges::All, Privileges::Actions)`
+    /// arm on objects other than tables: which privileges `ALL` stands
+    /// for is not modelled, so the grant stays intact at ALL PRIVILEGES.
+    #[test]
+    fn test_revoke_execute_against_all_privileges_on_function_is_a_noop() {
+        let sql = r"
+                CREATE ROLE my_role;
+                GRANT ALL PRIVILEGES ON FUNCTION f() TO my_role;
+                REVOKE EXECUTE ON FUNCTION f() FROM my_role;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+
+        let grant = db.table_grants().next().expect("grant must remain");
+        assert!(grant.is_all_privileges());
+    }
+
+    /// Privilege-set subtraction on column-scoped grants: each revoked
+    /// action only removes its own columns, leaving the table grant of
+    /// the same statement untouched.
+    #[test]
+    fn test_revoke_subtracts_columns_of_each_action() {
+        let sql = r"
+                CREATE TABLE t (a INT, b INT);
+                CREATE ROLE r;
+                GRANT SELECT (a, b), UPDATE (a), INSERT ON t TO r;
+                REVOKE SELECT (a), UPDATE (a) ON t FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+
+        let table_grant = db.table_grants().next().expect("table grant must remain");
+        let privileges: Vec<_> = table_grant.privileges(&db).collect();
+        assert!(matches!(privileges[..], [Action::Insert { columns: None }]));
+
+        let column_grant = db.column_grants().next().expect("column grant must remain");
+        let privileges: Vec<_> = column_grant.privileges(&db).collect();
+        assert_eq!(privileges.len(), 1);
+        match privileges[0] {
+            Action::Select { columns: Some(columns) } => {
+                let names: Vec<_> = columns.iter().map(|c| c.value.as_str()).collect();
+                assert_eq!(names, vec!["b"]);
+            }
+            other => panic!("expected SELECT with columns, got {other:?}"),
+        }
+    }
+
+    /// `tables()` `GrantObjects::AllTablesInSchema` branch: a grant on
+    /// `ALL TABLES IN SCHEMA` should parse and produce one entry in the
+    /// `table_grants` iterator without panicking.
+    #[test]
+    fn test_grant_all_tables_in_schema_parses_and_is_indexed() {
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE TABLE s.t (id INT);
+                CREATE ROLE r;
+                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 1);
+    }
+
+    /// `grant_objects_inner_match` Function arm: an EXECUTE grant on a
+    /// concrete function object lands in `table_grants`.
+    #[test]
+    fn test_grant_execute_on_function() {
+        let sql = r"
+                CREATE FUNCTION f(x INT) RETURNS INT AS 'SELECT $1';
+                CREATE ROLE r;
+                GRANT EXECUTE ON FUNCTION f(INT) TO r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 1);
+    }
+
+    /// `apply_revoke_to_grant`'s object-mismatch fast-return: revoking
+    /// against a different table than the grant covers must leave the
+    /// original grant untouched and itself be a no-op (no error).
+    #[test]
+    fn test_revoke_on_different_table_leaves_original_grant_untouched() {
+        let sql = r"
+                CREATE TABLE t1 (id INT);
+                CREATE TABLE t2 (id INT);
+                CREATE ROLE r;
+                GRANT SELECT ON t1 TO r;
+                REVOKE SELECT ON t2 FROM r;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+        // A REVOKE that matches no grant returns `RevokeNotFound`.
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    /// `partition_grantees_for_revoke` grantee-mismatch path: revoking
+    /// from a role that doesn't appear as a grantee on the matching
+    /// grant must surface as `RevokeNotFound`.
+    #[test]
+    fn test_revoke_from_different_grantee_returns_not_found() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE r1;
+                CREATE ROLE r2;
+                GRANT SELECT ON t TO r1;
+                REVOKE SELECT ON t FROM r2;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+        assert!(matches!(result, Err(Error::RevokeNotFound(_))));
+    }
+
+    /// `columns()` per-column INSERT/UPDATE/REFERENCES arms: a multi-
+    /// action column-scoped grant must surface column grants for each
+    /// privileged action.
+    #[test]
+    fn test_grant_per_column_insert_update_references_creates_column_grants() {
+        let sql = r"
+                CREATE TABLE t (a INT, b INT);
+                CREATE ROLE r;
+                GRANT INSERT (a), UPDATE (b), REFERENCES (a) ON t TO r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        // Each per-column action creates its own column grant.
+        assert!(db.column_grants().count() >= 1, "at least one column grant expected");
+    }
+
+    /// `apply_revoke_to_grant`'s "drop the whole grant when no actions
+    /// remain" path: REVOKE ALL from a single-grantee grant removes
+    /// the grant entirely.
+    #[test]
+    fn test_revoke_all_privileges_from_only_grantee_drops_grant() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE r;
+                GRANT SELECT, INSERT ON t TO r;
+                REVOKE ALL PRIVILEGES ON t FROM r;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `apply_revoke_action_to_grant_action` partial-column path: a
+    /// column-scoped revoke that drops some columns but keeps others
+    /// must preserve the remaining columns under the same action.
+    #[test]
+    fn test_revoke_subset_of_column_scoped_grant_keeps_unrevoked_columns() {
+        let sql = r"
+                CREATE TABLE t (a INT, b INT, c INT);
+                CREATE ROLE r;
+                GRANT SELECT (a, b, c) ON t TO r;
+                REVOKE SELECT (b) ON t FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+
+        let grant = db.column_grants().next().expect("grant must remain");
+        let privileges: Vec<_> = grant.privileges(&db).collect();
+        assert_eq!(privileges.len(), 1);
+        match privileges[0] {
+            Action::Select { columns: Some(columns) } => {
+                let names: Vec<_> = columns.iter().map(|c| c.value.as_str()).collect();
+                assert_eq!(names, vec!["a", "c"], "only `b` should be revoked");
+            }
+            other => panic!("expected SELECT with columns, got {other:?}"),
+        }
+    }
+
+    /// `partition_grantees_for_revoke` multi-grantee path: revoking
+    /// from one of two grantees must keep the grant alive for the
+    /// other grantee (covers the `unmatched` partition + split).
+    #[test]
+    fn test_revoke_select_from_one_of_two_grantees_keeps_grant_for_other() {
+        let sql = r"
+                CREATE TABLE t (id INT);
+                CREATE ROLE r1;
+                CREATE ROLE r2;
+                GRANT SELECT ON t TO r1, r2;
+                REVOKE SELECT ON t FROM r1;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+
+        let table = db.table(None, "t").unwrap();
+        let r1 = db.role("r1").unwrap();
+        let r2 = db.role("r2").unwrap();
+        assert!(!table.can_select(r1, &db), "r1 should have had SELECT revoked");
+        assert!(table.can_select(r2, &db), "r2 should still have SELECT");
+    }
+
+    /// `grant_objects_inner_match` schema-list arm: REVOKE on
+    /// `ALL TABLES IN SCHEMA` matching a same-shape GRANT goes
+    /// through the giant merged arm that covers AllSequences/
+    /// AllTables/AllViews/AllMaterializedViews/... InSchema.
+    #[test]
+    fn test_revoke_all_tables_in_schema_matches_corresponding_grant() {
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE TABLE s.t (id INT);
+                CREATE ROLE r;
+                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
+                REVOKE SELECT ON ALL TABLES IN SCHEMA s FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0, "matching revoke removes the grant");
+    }
+
+    /// `grant_objects_inner_match` Schemas object-list arm:
+    /// GRANT USAGE ON SCHEMA + matching REVOKE traverses the
+    /// merged ObjectName-list arm.
+    #[test]
+    fn test_revoke_usage_on_schema_matches_grant() {
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE ROLE r;
+                GRANT USAGE ON SCHEMA s TO r;
+                REVOKE USAGE ON SCHEMA s FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `grant_objects_inner_match` Function arm: REVOKE on a
+    /// specific function-by-signature matches the corresponding
+    /// GRANT EXECUTE on that function.
+    #[test]
+    fn test_revoke_execute_on_function_matches_grant() {
+        let sql = r"
+                CREATE FUNCTION f(x INT) RETURNS INT AS 'SELECT $1';
+                CREATE ROLE r;
+                GRANT EXECUTE ON FUNCTION f(INT) TO r;
+                REVOKE EXECUTE ON FUNCTION f(INT) FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `applies_to_table` AllTablesInSchema branch: querying whether
+    /// an `ALL TABLES IN SCHEMA s` grant applies to a specific table
+    /// returns true iff the table's schema matches.
+    #[test]
+    fn test_all_tables_in_schema_grant_applies_to_matching_table() {
+        use crate::traits::TableGrantLike;
+
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE TABLE s.in_scope (id INT);
+                CREATE TABLE out_of_scope (id INT);
+                CREATE ROLE r;
+                GRANT SELECT ON ALL TABLES IN SCHEMA s TO r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        let grant = db.table_grants().next().expect("grant");
+        let in_scope = db.table(Some("s"), "in_scope").expect("in_scope table");
+        let out_of_scope = db.table(None, "out_of_scope").expect("out_of_scope table");
+
+        assert!(grant.applies_to_table(in_scope, &db));
+        assert!(!grant.applies_to_table(out_of_scope, &db));
+    }
+
+    /// `grant_objects_inner_match` Sequences object-list arm:
+    /// GRANT USAGE ON SEQUENCE + matching REVOKE traverses the
+    /// merged ObjectName-list arm (covers another row of the merged
+    /// pattern at lines ~239–261).
+    #[test]
+    fn test_revoke_usage_on_sequence_matches_grant() {
+        let sql = r"
+                CREATE ROLE r;
+                GRANT USAGE ON SEQUENCE my_seq TO r;
+                REVOKE USAGE ON SEQUENCE my_seq FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `grant_objects_inner_match` All-future-tables-in-schema arm.
+    #[test]
+    fn test_revoke_all_sequences_in_schema_matches_grant() {
+        let sql = r"
+                CREATE SCHEMA s;
+                CREATE ROLE r;
+                GRANT USAGE ON ALL SEQUENCES IN SCHEMA s TO r;
+                REVOKE USAGE ON ALL SEQUENCES IN SCHEMA s FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `grant_objects_inner_match` fallback arm (`(left, right) =>
+    /// left == right`): a GRANT and REVOKE on the same simple
+    /// pseudo-object that doesn't match a specific variant goes
+    /// through the structural-equality arm.
+    #[test]
+    fn test_revoke_on_database_object_matches_grant() {
+        let sql = r"
+                CREATE ROLE r;
+                GRANT CREATE ON DATABASE my_db TO r;
+                REVOKE CREATE ON DATABASE my_db FROM r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        assert_eq!(db.table_grants().count(), 0);
+    }
+
+    /// `ColumnGrantLike::columns` per-action branches: a multi-action
+    /// column grant (`INSERT (a), UPDATE (b), REFERENCES (c)`) surfaces
+    /// the union of columns across all three arms in `columns()`.
+    #[test]
+    fn test_column_grant_columns_iterator_covers_all_action_arms() {
+        use crate::traits::ColumnGrantLike;
+
+        let sql = r"
+                CREATE TABLE t (a INT, b INT, c INT);
+                CREATE ROLE r;
+                GRANT INSERT (a), UPDATE (b), REFERENCES (c) ON t TO r;
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("parse");
+        let table = db.table(None, "t").expect("table");
+
+        // Iterate every column grant and call `.columns(table, &db)`.
+        // This routes through the Insert/Update/References match
+        // arms in `impls/sqlparser/grant.rs::columns`.
+        let mut all_cols: Vec<&str> = Vec::new();
+        for cg in db.column_grants() {
+            for col in cg.columns(table, &db) {
+                all_cols.push(col.column_name());
+            }
+        }
+        all_cols.sort_unstable();
+        all_cols.dedup();
+        // The three actions reference three distinct columns.
+        assert!(all_cols.contains(&"a"));
+        assert!(all_cols.contains(&"b"));
+        assert!(all_cols.contains(&"c"));
+    }
+}
+
+mod foreign_key_target_validation {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    #[test]
+    fn column_option_reference_to_existing_target_validates() {
+        let sql = "
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn table_constraint_reference_to_existing_target_validates() {
+        let sql = "
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (
+                    id INT PRIMARY KEY,
+                    parent_id INT,
+                    FOREIGN KEY (parent_id) REFERENCES parent(id)
+                );
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn reference_to_missing_table_errors_naming_target_and_host() {
+        let sql = "
+                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES orders(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        match db.validate_foreign_key_targets() {
+            Err(Error::ReferencedTableNotFoundForForeignKey { referenced_table, host_table }) => {
+                assert_eq!(referenced_table, "orders");
+                assert_eq!(host_table, "child");
+            }
+            other => panic!("expected dangling-table error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reference_to_missing_column_errors_naming_column() {
+        let sql = "
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(missing));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        match db.validate_foreign_key_targets() {
+            Err(Error::ReferencedColumnNotFoundForForeignKey {
+                referenced_column,
+                referenced_table,
+                host_table,
+            }) => {
+                assert_eq!(referenced_column, "missing");
+                assert_eq!(referenced_table, "parent");
+                assert_eq!(host_table, "child");
+            }
+            other => panic!("expected dangling-column error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn bare_and_public_qualified_targets_resolve_identically() {
+        let bare = "
+                CREATE TABLE public.parent (id INT PRIMARY KEY);
+                CREATE TABLE public.child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
+            ";
+        let qualified = "
+                CREATE TABLE public.parent (id INT PRIMARY KEY);
+                CREATE TABLE public.child (
+                    id INT PRIMARY KEY,
+                    parent_id INT REFERENCES public.parent(id)
+                );
+            ";
+        let bare_db = ParserDB::parse::<PostgreSqlDialect>(bare).expect("parse");
+        let qualified_db = ParserDB::parse::<PostgreSqlDialect>(qualified).expect("parse");
+        assert!(bare_db.validate_foreign_key_targets().is_ok());
+        assert!(qualified_db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn forward_reference_validates() {
+        let sql = "
+                CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
+                CREATE TABLE parent (id INT PRIMARY KEY);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn self_referential_reference_validates() {
+        let sql = "
+                CREATE TABLE tree (
+                    id INT PRIMARY KEY,
+                    parent_id INT REFERENCES tree(id)
+                );
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn multiple_dangling_constraints_report_deterministic_first_error() {
+        let sql = "
+                CREATE TABLE a (id INT PRIMARY KEY, x INT REFERENCES missing_a(id));
+                CREATE TABLE b (id INT PRIMARY KEY, y INT REFERENCES missing_b(id));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let first = db.validate_foreign_key_targets().expect_err("dangling FKs must error");
+        let second = db.validate_foreign_key_targets().expect_err("dangling FKs must error");
+        assert_eq!(format!("{first}"), format!("{second}"));
+        match first {
+            Error::ReferencedTableNotFoundForForeignKey { referenced_table, .. } => {
+                assert_eq!(referenced_table, "missing_a");
+            }
+            other => panic!("expected dangling-table error, got {other:?}"),
+        }
+    }
+}
+
+mod foreign_key_column_validation {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+
+    const PARENT: &str = "CREATE TABLE parent (id INT, code TEXT, PRIMARY KEY (id, code));";
+
+    fn validate<D: Dialect + Default + 'static>(child: &str) -> Result<(), Error> {
+        let sql = format!("{PARENT}\n{child}");
+        ParserDB::parse::<D>(&sql).expect("parse").validate_foreign_key_columns()
+    }
+
+    #[test]
+    fn implicit_references_are_checked_against_the_primary_key() {
+        let child = "CREATE TABLE child (a INT8, b TEXT, FOREIGN KEY (a, b) REFERENCES parent);";
+        assert!(validate::<PostgreSqlDialect>(child).is_ok());
+        assert!(matches!(
+            validate::<PostgreSqlDialect>(
+                "CREATE TABLE child (a INT, FOREIGN KEY (a) REFERENCES parent);"
+            ),
+            Err(Error::ForeignKeyColumnCountMismatch {
+                host_columns: 1,
+                referenced_columns: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn column_counts_must_match() {
+        assert!(matches!(
+            validate::<PostgreSqlDialect>(
+                "CREATE TABLE child (a INT, FOREIGN KEY (a) REFERENCES parent (id, code));"
+            ),
+            Err(Error::ForeignKeyColumnCountMismatch { host_table, referenced_table, .. })
+                if host_table == "child" && referenced_table == "parent"
+        ));
+    }
+
+    #[test]
+    fn sqlite_columns_are_not_compared() {
+        let child = "CREATE TABLE child (a TEXT, b INT, FOREIGN KEY (a, b) REFERENCES parent);";
+        assert!(validate::<SQLiteDialect>(child).is_ok());
+        assert!(matches!(
+            validate::<PostgreSqlDialect>(child),
+            Err(Error::ForeignKeyColumnTypeMismatch { host_column, referenced_column, .. })
+                if host_column == "a" && referenced_column == "id"
+        ));
+    }
+}
+
+mod alter_table_add_column_tests {
+    use super::*;
+
+    #[test]
+    fn test_add_column_is_tracked() {
+        let sql = r"
+                CREATE TABLE users (id INT PRIMARY KEY);
+                ALTER TABLE users ADD COLUMN email TEXT;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let users = db.table(None, "users").expect("users must exist");
+
+        assert_eq!(users.number_of_columns(&db), 2);
+        assert!(users.column("email", &db).is_some());
+        assert!(db.column_metadata(users.column("email", &db).unwrap()).is_some());
+    }
+
+    #[test]
+    fn test_add_column_processes_options() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                CREATE TABLE child (id INT);
+                ALTER TABLE child ADD COLUMN parent_id INT UNIQUE REFERENCES parent(id);
+                ALTER TABLE child ADD COLUMN age INT CHECK (age > 0);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let child = db.table(None, "child").expect("child must exist");
+
+        assert_eq!(child.foreign_keys(&db).count(), 1);
+        assert_eq!(child.unique_indices(&db).count(), 1);
+        assert_eq!(child.check_constraints(&db).count(), 1);
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn test_add_primary_key_column() {
+        let sql = r"
+                CREATE TABLE items (name TEXT);
+                ALTER TABLE items ADD COLUMN id INT PRIMARY KEY;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let items = db.table(None, "items").expect("items must exist");
+
+        let primary_key: Vec<&str> =
+            items.primary_key_columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(primary_key, vec!["id"]);
+    }
+
+    #[test]
+    fn test_foreign_key_to_added_column_validates() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY);
+                ALTER TABLE parent ADD COLUMN code INT UNIQUE;
+                CREATE TABLE child (parent_code INT, FOREIGN KEY (parent_code) REFERENCES parent(code));
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn test_add_column_to_missing_table() {
+        let sql = "ALTER TABLE missing ADD COLUMN id INT;";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::AlterTableNotFound { table_name }) if table_name == "missing"
+        ));
+
+        let sql = "ALTER TABLE IF EXISTS missing ADD COLUMN id INT;";
+        assert!(ParserDB::parse::<GenericDialect>(sql).is_ok());
+    }
+
+    #[test]
+    fn test_add_existing_column() {
+        let sql = r"
+                CREATE TABLE users (id INT);
+                ALTER TABLE users ADD COLUMN ID INT;
+            ";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::ColumnAlreadyExists { column_name, table_name })
+                if column_name == "ID" && table_name == "users"
+        ));
+
+        let sql = r"
+                CREATE TABLE users (id INT);
+                ALTER TABLE users ADD COLUMN IF NOT EXISTS id INT;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let users = db.table(None, "users").expect("users must exist");
+        assert_eq!(users.number_of_columns(&db), 1);
+    }
+}
+
+mod alter_table_drop_column_tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_unused_column() {
+        let sql = r"
+                CREATE TABLE users (id INT PRIMARY KEY, nickname TEXT);
+                ALTER TABLE users DROP COLUMN nickname;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let users = db.table(None, "users").expect("users must exist");
+
+        assert_eq!(users.number_of_columns(&db), 1);
+        assert!(users.column("nickname", &db).is_none());
+
+        let before = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE users (id INT PRIMARY KEY, nickname TEXT);",
+        )
+        .expect("parse");
+        let nickname = before
+            .table(None, "users")
+            .and_then(|users| users.column("nickname", &before))
+            .expect("nickname must exist before the drop");
+        assert!(db.column_metadata(nickname).is_none());
+    }
+
+    #[test]
+    fn test_drop_missing_column() {
+        let sql = r"
+                CREATE TABLE users (id INT);
+                ALTER TABLE users DROP COLUMN nickname;
+            ";
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(sql),
+            Err(Error::DropColumnNotFound { column_name, table_name })
+                if column_name == "nickname" && table_name == "users"
+        ));
+
+        let sql = r"
+                CREATE TABLE users (id INT);
+                ALTER TABLE users DROP COLUMN IF EXISTS nickname;
+            ";
+        assert!(ParserDB::parse::<GenericDialect>(sql).is_ok());
+    }
+
+    #[test]
+    fn test_drop_column_used_by_dependents_fails() {
+        for schema in [
+            "CREATE TABLE t (a INT, b INT); CREATE INDEX idx ON t (lower(a));",
+            "CREATE TABLE t (a INT, b INT); CREATE INDEX idx ON t (b) WHERE a > 0;",
+            "CREATE TABLE t (a INT, b INT, UNIQUE (b, a));",
+            "CREATE TABLE t (a INT CHECK (a > 0), b INT);",
+            "CREATE TABLE t (a INT REFERENCES t (b), b INT PRIMARY KEY);",
+            "CREATE TABLE t (a INT, b INT); CREATE TABLE o (x INT REFERENCES t (a));",
+            "CREATE TABLE t (a INT, b INT); CREATE ROLE r; GRANT SELECT (a) ON t TO r;",
+        ] {
+            let sql = format!("{schema} ALTER TABLE t DROP COLUMN a;");
+            assert!(
+                matches!(
+                    ParserDB::parse::<GenericDialect>(&sql),
+                    Err(Error::ColumnReferenced { column_name, table_name })
+                        if column_name == "a" && table_name == "t"
+                ),
+                "dropping `a` must fail with: {schema}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_drop_column_cascade_removes_dependents() {
+        let sql = r"
+                CREATE TABLE parent (id INT PRIMARY KEY, code INT UNIQUE CHECK (code > 0), name TEXT);
+                CREATE TABLE child (id INT, parent_code INT REFERENCES parent (code));
+                CREATE INDEX parent_code_idx ON parent (code);
+                ALTER TABLE parent DROP COLUMN code CASCADE;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let parent = db.table(None, "parent").expect("parent must exist");
+        let child = db.table(None, "child").expect("child must exist");
+
+        assert_eq!(parent.number_of_columns(&db), 2);
+        assert_eq!(parent.indices(&db).count(), 0);
+        assert_eq!(parent.unique_indices(&db).count(), 1);
+        assert_eq!(parent.check_constraints(&db).count(), 0);
+        assert_eq!(child.foreign_keys(&db).count(), 0);
+        assert!(db.validate_foreign_key_targets().is_ok());
+    }
+
+    #[test]
+    fn test_drop_primary_key_column_cascade() {
+        let sql = r"
+                CREATE TABLE t (id INT PRIMARY KEY, name TEXT);
+                ALTER TABLE t DROP COLUMN id CASCADE;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let table = db.table(None, "t").expect("t must exist");
+
+        assert!(!table.has_primary_key(&db));
+        assert_eq!(table.unique_indices(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_column_cascade_narrows_column_grants() {
+        let sql = r"
+                CREATE TABLE t (a INT, b INT);
+                CREATE ROLE reader;
+                GRANT SELECT (a, b) ON t TO reader;
+                GRANT UPDATE (a) ON t TO reader;
+                ALTER TABLE t DROP COLUMN a CASCADE;
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+
+        let grants: Vec<String> = db.column_grants().map(ToString::to_string).collect();
+        assert_eq!(grants.len(), 1);
+        assert!(grants[0].contains("SELECT (b)"));
+    }
+}
+
+mod index_column_tests {
+    use super::*;
+    use crate::traits::IndexLike;
+
+    #[test]
+    fn test_index_columns_keep_order_and_options() {
+        let sql = r"
+                CREATE TABLE t (a INT, b INT, UNIQUE (b, a));
+                CREATE INDEX idx ON t (b DESC NULLS LAST, a);
+            ";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let table = db.table(None, "t").expect("t must exist");
+
+        let index = table.indices(&db).next().expect("index must exist");
+        let columns: Vec<&str> = index.columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, vec!["b", "a"]);
+        assert_eq!(index.index_columns()[0].column.options.asc, Some(false));
+        assert_eq!(index.index_columns()[0].column.options.nulls_first, Some(false));
+        assert_eq!(index.expression(&db).to_string(), "(b, a)");
+
+        let unique_index = table.unique_indices(&db).next().expect("unique index must exist");
+        let columns: Vec<&str> = unique_index.columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_single_column_expression_is_nested() {
+        let sql = "CREATE TABLE t (a INT); CREATE INDEX idx ON t (lower(a));";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let index = db.indexes().next().expect("index must exist");
+
+        assert!(matches!(index.expression(&db), Expr::Nested(_)));
+        assert!(!index.is_simple(&db));
+        assert_eq!(index.columns(&db).map(ColumnLike::column_name).collect::<Vec<_>>(), ["a"]);
+    }
+
+    #[test]
+    fn test_primary_key_columns_in_parentheses() {
+        let sql = "CREATE TABLE t (a INT, b INT, PRIMARY KEY ((a), b));";
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("parse");
+        let table = db.table(None, "t").expect("t must exist");
+
+        let columns: Vec<&str> =
+            table.primary_key_columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_primary_key_expression_fails() {
+        let sql = "CREATE TABLE t (a TEXT, PRIMARY KEY (lower(a)));";
+        let result = ParserDB::parse::<GenericDialect>(sql);
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidPrimaryKey { table_name, .. }) if table_name == "t"
+        ));
+    }
+}
+
+mod object_filter_tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use super::*;
+
+    fn filtered(sql: &str, filter: ObjectFilter) -> Result<ParserDB, Error> {
+        let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).expect("parse");
+        ParserDB::from_statements_with_filter(statements, "test".to_string(), filter)
+    }
+
+    const SQL: &str = r"
+            CREATE ROLE reader;
+            CREATE SCHEMA app;
+            CREATE SCHEMA audit;
+            CREATE TABLE audit.events (id INT PRIMARY KEY);
+            CREATE TABLE app.users (
+                id INT PRIMARY KEY,
+                last_event INT,
+                FOREIGN KEY (last_event) REFERENCES audit.events (id)
+            );
+            CREATE TABLE app.users_archive (id INT PRIMARY KEY REFERENCES app.users (id));
+            CREATE INDEX events_id ON audit.events (id);
+            ALTER TABLE audit.events ENABLE ROW LEVEL SECURITY;
+            CREATE POLICY events_policy ON audit.events TO reader USING (true);
+            GRANT SELECT ON app.users, audit.events TO reader;
+            DROP TABLE audit.events;
+        ";
+
+    #[test]
+    fn test_unrestricted_filter_loads_everything() {
+        let db = filtered(&SQL.replace("DROP TABLE audit.events;", ""), ObjectFilter::default())
+            .expect("build");
+        assert_eq!(db.tables().count(), 3);
+        assert_eq!(db.schemas().count(), 2);
+    }
+
+    #[test]
+    fn test_excluded_schema_is_skipped() {
+        let db = filtered(SQL, ObjectFilter::default().exclude_schema("audit")).expect("build");
+
+        assert_eq!(db.tables().count(), 2);
+        assert!(db.schema("audit").is_none());
+        assert_eq!(db.indexes().count(), 0);
+        assert_eq!(db.policies().count(), 0);
+
+        let users = db.table(Some("app"), "users").expect("users must exist");
+        assert_eq!(users.foreign_keys(&db).count(), 0);
+        let external: Vec<String> = db
+            .table_metadata(users)
+            .expect("users must have metadata")
+            .external_foreign_keys()
+            .map(|fk| fk.attribute().foreign_table.to_string())
+            .collect();
+        assert_eq!(external, vec!["audit.events"]);
+        assert!(db.validate_foreign_key_targets().is_ok());
+
+        let grant = db.table_grants().next().expect("grant must be kept");
+        assert!(matches!(
+            &grant.objects,
+            Some(sqlparser::ast::GrantObjects::Tables(tables))
+                if tables.len() == 1 && tables[0].to_string() == "app.users"
+        ));
+    }
+
+    #[test]
+    fn test_table_patterns() {
+        let db =
+            filtered(SQL, ObjectFilter::default().include_schema("app").exclude_table("*_archive"))
+                .expect("build");
+        let names: Vec<&str> = db.tables().map(TableLike::table_name).collect();
+        assert_eq!(names, vec!["users"]);
+
+        let db =
+            filtered(SQL, ObjectFilter::default().include_table("app.users_*")).expect("build");
+        let archive = db.table(Some("app"), "users_archive").expect("archive must exist");
+        assert_eq!(db.tables().count(), 1);
+        assert_eq!(
+            db.table_metadata(archive)
+                .expect("archive must have metadata")
+                .external_foreign_keys()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_patterns_follow_the_casing_of_the_dialect() {
+        let sql = "CREATE TABLE `Users` (id INT); CREATE TABLE `Users_Archive` (id INT);";
+        let statements = Parser::parse_sql(&MySqlDialect {}, sql).expect("parse");
+        let db = ParserDB::from_filtered_statements(
+            statements,
+            "test".to_string(),
+            SqlparserDialect::of::<MySqlDialect>(),
+            ObjectFilter::default().exclude_table("*_archive"),
+            &[],
+            &[],
+            &mut Validator::aborting(ParserOptions::default()),
+            None,
+        )
+        .expect("build");
+        let names: Vec<&str> = db.tables().map(TableLike::table_name).collect();
+        assert_eq!(names, vec!["Users"]);
+    }
+}
+
+mod alter_table_constraint_tests {
+    use super::*;
+
+    const SQL: &str = r"
+            CREATE TABLE parent (id INT, code TEXT);
+            CREATE TABLE child (id INT, parent_id INT, quantity INT);
+            ALTER TABLE parent ADD CONSTRAINT parent_pkey PRIMARY KEY (id);
+            ALTER TABLE parent ADD CONSTRAINT parent_code_key UNIQUE (code);
+            ALTER TABLE child ADD CONSTRAINT child_parent_fkey
+                FOREIGN KEY (parent_id) REFERENCES parent (id);
+            ALTER TABLE child ADD CONSTRAINT child_quantity_check CHECK (quantity > 0);
+        ";
+
+    #[test]
+    fn test_add_constraints() {
+        let db = ParserDB::parse::<GenericDialect>(SQL).expect("parse");
+        let parent = db.table(None, "parent").expect("parent must exist");
+        let child = db.table(None, "child").expect("child must exist");
+
+        assert_eq!(parent.primary_key_columns(&db).count(), 1);
+        assert_eq!(parent.unique_indices(&db).count(), 2);
+        assert_eq!(child.foreign_keys(&db).count(), 1);
+        assert_eq!(child.check_constraints(&db).count(), 1);
+    }
+
+    #[test]
+    fn test_add_constraint_is_validated() {
+        let sql = r"
+                CREATE TABLE child (id INT, parent_id INT);
+                ALTER TABLE child ADD CONSTRAINT child_parent_fkey
+                    FOREIGN KEY (parent_id) REFERENCES parent (id);
+            ";
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(sql),
+            Err(Error::ReferencedTableNotFoundForForeignKey { .. })
+        ));
+
+        let sql = r"
+                CREATE TABLE t (id INT);
+                ALTER TABLE t ADD CONSTRAINT t_check CHECK (missing > 0);
+            ";
+        assert!(ParserDB::parse::<GenericDialect>(sql).is_err());
+    }
+
+    #[test]
+    fn test_drop_constraints() {
+        let sql = format!(
+            "{SQL}
+                ALTER TABLE child DROP CONSTRAINT child_parent_fkey;
+                ALTER TABLE child DROP CONSTRAINT child_quantity_check;
+                ALTER TABLE parent DROP CONSTRAINT parent_code_key;
+                ALTER TABLE parent DROP CONSTRAINT parent_pkey;
+                "
+        );
+        let db = ParserDB::parse::<GenericDialect>(&sql).expect("parse");
+        let parent = db.table(None, "parent").expect("parent must exist");
+        let child = db.table(None, "child").expect("child must exist");
+
+        assert_eq!(parent.primary_key_columns(&db).count(), 0);
+        assert_eq!(parent.unique_indices(&db).count(), 0);
+        assert_eq!(child.foreign_keys(&db).count(), 0);
+        assert_eq!(child.check_constraints(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_drop_missing_constraint() {
+        let sql = format!("{SQL} ALTER TABLE child DROP CONSTRAINT missing;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&sql),
+            Err(Error::DropConstraintNotFound { constraint_name, .. }) if constraint_name == "missing"
+        ));
+
+        let sql = format!("{SQL} ALTER TABLE child DROP CONSTRAINT IF EXISTS missing;");
+        assert!(ParserDB::parse::<GenericDialect>(&sql).is_ok());
+    }
+}
+
+mod alter_index_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::IndexLike;
+
+    #[test]
+    fn test_renamed_index_is_dropped_by_its_new_name() {
+        let sql = "CREATE TABLE t (id INT);
+                 CREATE INDEX idx_old ON t (id);
+                 ALTER INDEX idx_old RENAME TO idx_new;";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).unwrap();
+        let table = db.table(None, "t").unwrap();
+        let index = table.indices(&db).next().unwrap();
+        assert_eq!(index.index_name(), Some("idx_new"));
+
+        let dropped = format!("{sql} DROP INDEX idx_new;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&dropped).unwrap();
+        assert_eq!(db.indexes().count(), 0);
+
+        let stale = format!("{sql} DROP INDEX idx_old;");
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(&stale),
+            Err(Error::DropIndexNotFound { index_name }) if index_name == "idx_old"
+        ));
+    }
+
+    #[test]
+    fn test_alter_missing_index_fails() {
+        for sql in [
+            "CREATE TABLE t (id INT); ALTER INDEX idx RENAME TO other;",
+            "CREATE TABLE t (id INT); ALTER INDEX idx SET (fillfactor = 70);",
+        ] {
+            assert!(matches!(
+                ParserDB::parse::<PostgreSqlDialect>(sql),
+                Err(Error::AlterIndexNotFound { index_name }) if index_name == "idx"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_storage_parameters_follow_the_renamed_index() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT);
+                 CREATE INDEX idx ON t (id);
+                 ALTER INDEX idx RENAME TO other;
+                 ALTER INDEX other SET (fillfactor = 70);",
+        )
+        .unwrap();
+        let table = db.table(None, "t").unwrap();
+        let index = table.indices(&db).next().unwrap();
+        assert_eq!(index.index_name(), Some("other"));
+        assert_eq!(index.storage_parameters().len(), 1);
+        assert_eq!(index.storage_parameters()[0].to_string(), "fillfactor = 70");
+    }
+}
+
+mod comment_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::ColumnLike;
+
+    #[test]
+    fn test_comments_take_precedence_over_sql_comments() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "-- the users
+                 CREATE TABLE users (
+                     -- the identifier
+                     id INT,
+                     name TEXT
+                 );
+                 COMMENT ON TABLE users IS 'Registered users';
+                 COMMENT ON COLUMN users.name IS 'Display name';",
+        )
+        .unwrap();
+        let users = db.table(None, "users").unwrap();
+        assert_eq!(users.table_doc(&db), Some("Registered users"));
+        let id = users.column("id", &db).unwrap();
+        assert_eq!(id.column_doc(&db), Some("the identifier"));
+        let name = users.column("name", &db).unwrap();
+        assert_eq!(name.column_doc(&db), Some("Display name"));
+    }
+
+    #[test]
+    fn test_comment_is_null_removes_the_comment() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE users (id INT);
+                 COMMENT ON TABLE users IS 'Registered users';
+                 COMMENT ON COLUMN users.id IS 'Identifier';
+                 COMMENT ON TABLE users IS NULL;
+                 COMMENT ON COLUMN users.id IS NULL;",
+        )
+        .unwrap();
+        let users = db.table(None, "users").unwrap();
+        assert_eq!(users.table_doc(&db), None);
+        assert_eq!(users.column("id", &db).unwrap().column_doc(&db), None);
+    }
+
+    #[test]
+    fn test_comment_on_missing_objects_fails() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("COMMENT ON TABLE users IS 'Users';"),
+            Err(Error::CommentTableNotFound { table_name }) if table_name == "users"
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE TABLE users (id INT); COMMENT ON COLUMN users.name IS 'Name';"
+            ),
+            Err(Error::CommentColumnNotFound { table_name, column_name })
+                if table_name == "users" && column_name == "name"
+        ));
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(
+                "CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+                     COMMENT ON FUNCTION one(INT) IS 'One';"
+            ),
+            Err(Error::CommentFunctionNotFound { function_name }) if function_name == "one"
+        ));
+    }
+
+    #[test]
+    fn test_function_comment_applies_to_all_overloads_without_arguments() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+                 CREATE FUNCTION one(x INT) RETURNS INT AS 'SELECT x;' LANGUAGE SQL;
+                 COMMENT ON FUNCTION one IS 'One';",
+        )
+        .unwrap();
+        assert!(db.function_overloads("one").all(|function| {
+            function.function_doc(&db) == Some("One")
+                && db.function_metadata(function).unwrap().comment() == Some("One")
+        }));
+    }
+
+    #[test]
+    fn test_function_comment_matches_unnormalized_argument_types() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE SCHEMA app;
+                 CREATE TYPE app.mood AS ENUM ('happy', 'sad');
+                 CREATE FUNCTION cheer(m app.mood) RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+                 CREATE FUNCTION cheer(m INT) RETURNS INT AS 'SELECT m;' LANGUAGE SQL;
+                 COMMENT ON FUNCTION cheer(app.mood) IS 'Cheers up';",
+        )
+        .unwrap();
+        let docs: Vec<Option<&str>> =
+            db.function_overloads("cheer").map(|function| function.function_doc(&db)).collect();
+        assert_eq!(docs.iter().filter(|doc| **doc == Some("Cheers up")).count(), 1);
+        assert!(docs.contains(&None));
+    }
+}
+
+mod dialect_capability_tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+
+    const SQL: &str = "
+            CREATE TABLE t (id INT);
+            CREATE ROLE reader;
+            CREATE ROLE writer;
+            GRANT SELECT ON t TO reader;
+            CREATE POLICY p ON t USING (id > 0);
+        ";
+
+    #[test]
+    fn test_postgres_supports_every_statement() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        assert!(db.dialect_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_generic_dialect_is_permissive() {
+        let db = ParserDB::parse::<GenericDialect>(SQL).unwrap();
+        assert!(db.dialect_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_sqlite_warns_once_per_statement_kind() {
+        let db = ParserDB::parse::<SQLiteDialect>(SQL).unwrap();
+        let capabilities: Vec<DialectCapability> =
+            db.dialect_warnings().iter().map(DialectWarning::capability).collect();
+        assert_eq!(
+            capabilities,
+            [DialectCapability::Roles, DialectCapability::Grants, DialectCapability::Policies]
+        );
+        assert_eq!(
+            db.dialect_warnings()[0].to_string(),
+            "CREATE ROLE statements parsed but SQLite has no roles"
+        );
+        // The statements are still applied.
+        assert_eq!(db.roles().count(), 2);
+    }
+
+    #[test]
+    fn test_mysql_warns_about_policies_only() {
+        let db = ParserDB::parse::<MySqlDialect>(SQL).unwrap();
+        assert_eq!(db.dialect_warnings().len(), 1);
+        assert_eq!(db.dialect_warnings()[0].statement(), "CREATE POLICY");
+        assert_eq!(db.dialect_warnings()[0].dialect(), "MySQL");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_files_are_loaded_under_their_dialect() {
+        let root = std::env::temp_dir()
+            .join(format!("sql_traits_dialect_of_files_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("0001_users.sql"), "CREATE TABLE Users (id INT);").unwrap();
+        let loaded = ParserDB::from_path::<MySqlDialect>(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let db = loaded.unwrap();
+        assert_eq!(*DatabaseLike::dialect(&db), SqlparserDialect::MySql);
+        assert!(db.table(None, "USERS").is_some());
+    }
+}
+
+mod view_tests {
+    use super::*;
+    use crate::traits::ViewLike;
+
+    const SQL: &str = "
+            CREATE TABLE users (id INT PRIMARY KEY, name TEXT);
+            CREATE TABLE logs (id INT);
+            CREATE VIEW user_names AS SELECT name FROM users;
+        ";
+
+    #[test]
+    fn test_create_view() {
+        let db = ParserDB::parse::<GenericDialect>(SQL).unwrap();
+        let views: Vec<&str> = db.views().map(ViewLike::view_name).collect();
+        assert_eq!(views, ["user_names"]);
+        let view = db.view(None, "user_names").unwrap();
+        assert!(view.depends_on(&db, db.table(None, "users").unwrap()));
+    }
+
+    #[test]
+    fn test_duplicate_view_fails() {
+        let sql = format!("{SQL} CREATE VIEW user_names AS SELECT id FROM users;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&sql),
+            Err(Error::ViewAlreadyExists { view_name }) if view_name == "user_names"
+        ));
+    }
+
+    #[test]
+    fn test_create_view_if_not_exists_keeps_existing() {
+        let sql = format!("{SQL} CREATE VIEW IF NOT EXISTS user_names AS SELECT id FROM logs;");
+        let db = ParserDB::parse::<GenericDialect>(&sql).unwrap();
+        let view = db.view(None, "user_names").unwrap();
+        assert!(view.depends_on(&db, db.table(None, "users").unwrap()));
+    }
+
+    #[test]
+    fn test_create_or_replace_view() {
+        let sql = format!("{SQL} CREATE OR REPLACE VIEW user_names AS SELECT id FROM logs;");
+        let db = ParserDB::parse::<GenericDialect>(&sql).unwrap();
+        assert_eq!(db.views().count(), 1);
+        let view = db.view(None, "user_names").unwrap();
+        assert!(view.depends_on(&db, db.table(None, "logs").unwrap()));
+        assert!(!view.depends_on(&db, db.table(None, "users").unwrap()));
+    }
+
+    #[test]
+    fn test_drop_view() {
+        let sql = format!("{SQL} DROP VIEW user_names; DROP VIEW IF EXISTS missing;");
+        let db = ParserDB::parse::<GenericDialect>(&sql).unwrap();
+        assert_eq!(db.views().count(), 0);
+    }
+
+    #[test]
+    fn test_drop_missing_view_fails() {
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>("DROP VIEW missing;"),
+            Err(Error::DropViewNotFound { view_name }) if view_name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_selected_by_view_fails() {
+        let sql = format!("{SQL} DROP TABLE users;");
+        assert!(matches!(
+            ParserDB::parse::<GenericDialect>(&sql),
+            Err(Error::TableReferencedByView { table_name, view_name })
+                if table_name == "users" && view_name == "user_names"
+        ));
+    }
+
+    #[test]
+    fn test_drop_table_cascade_drops_views() {
+        let sql = format!("{SQL} DROP TABLE users CASCADE;");
+        let db = ParserDB::parse::<GenericDialect>(&sql).unwrap();
+        assert!(db.table(None, "users").is_none());
+        assert_eq!(db.views().count(), 0);
+    }
+
+    #[test]
+    fn test_drop_unrelated_table() {
+        let sql = format!("{SQL} DROP TABLE logs;");
+        let db = ParserDB::parse::<GenericDialect>(&sql).unwrap();
+        assert_eq!(db.views().count(), 1);
+    }
+
+    #[test]
+    fn test_overqualified_view_relation_fails() {
+        let result = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (id INT); CREATE VIEW v AS SELECT id FROM d.c.b.t;",
+        );
+        assert!(matches!(result, Err(Error::IdentifierLookupError(_))));
+    }
+}
+
+mod duplicate_object_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    const FUNCTION: &str = "
+            CREATE FUNCTION f(x INT) RETURNS INT AS 'SELECT x;' LANGUAGE SQL;
+            COMMENT ON FUNCTION f(INT) IS 'Identity';
+        ";
+
+    #[test]
+    fn test_duplicate_table_fails() {
+        let result =
+            ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT); CREATE TABLE t (id INT);");
+        assert!(matches!(
+            result,
+            Err(Error::TableAlreadyExists { table_name }) if table_name == "t"
+        ));
+    }
+
+    #[test]
+    fn test_create_table_if_not_exists_keeps_existing() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (id INT); CREATE TABLE IF NOT EXISTS t (name TEXT);",
+        )
+        .unwrap();
+        assert_eq!(db.tables().count(), 1);
+        let table = db.table(None, "t").unwrap();
+        assert!(table.column("id", &db).is_some());
+        assert!(table.column("name", &db).is_none());
+    }
+
+    #[test]
+    fn test_create_or_replace_table() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (id INT);
+                CREATE INDEX t_id ON t (id);
+                CREATE OR REPLACE TABLE t (name TEXT);",
+        )
+        .unwrap();
+        assert_eq!(db.tables().count(), 1);
+        let table = db.table(None, "t").unwrap();
+        assert!(table.column("id", &db).is_none());
+        assert!(table.column("name", &db).is_some());
+        assert_eq!(table.indices(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_function_fails() {
+        let result = ParserDB::parse::<PostgreSqlDialect>(&format!(
+            "{FUNCTION} CREATE FUNCTION f(y INTEGER) RETURNS INT AS 'SELECT y;' LANGUAGE SQL;"
+        ));
+        assert!(matches!(
+            result,
+            Err(Error::FunctionAlreadyExists { function_name }) if function_name == "f"
+        ));
+    }
+
+    #[test]
+    fn test_create_or_replace_function() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(&format!(
+            "{FUNCTION}
+                CREATE OR REPLACE FUNCTION f(x INT) RETURNS INT AS 'SELECT x + 1;' LANGUAGE SQL;
+                CREATE FUNCTION f(x TEXT) RETURNS TEXT AS 'SELECT x;' LANGUAGE SQL;"
+        ))
+        .unwrap();
+        let overloads: Vec<_> = db.function_overloads("f").collect();
+        assert_eq!(overloads.len(), 2);
+        let replaced = overloads
+            .iter()
+            .find(|function| function.argument_type_names(&db).eq(["INT"]))
+            .unwrap();
+        assert_eq!(replaced.body(), Some("SELECT x + 1;"));
+        assert_eq!(replaced.function_doc(&db), Some("Identity"));
+    }
+
+    const IDEMPOTENT_MIGRATION: &str = "
+            CREATE ROLE IF NOT EXISTS reader;
+            CREATE TABLE IF NOT EXISTS t (id INT);
+            CREATE INDEX IF NOT EXISTS t_id ON t (id);
+        ";
+
+    #[test]
+    fn test_repeated_idempotent_migration_is_a_no_op() {
+        let once = ParserDB::parse::<PostgreSqlDialect>(IDEMPOTENT_MIGRATION).unwrap();
+        let twice = ParserDB::parse::<PostgreSqlDialect>(&format!(
+            "{IDEMPOTENT_MIGRATION}{IDEMPOTENT_MIGRATION}"
+        ))
+        .unwrap();
+        for db in [&once, &twice] {
+            assert_eq!(db.tables().count(), 1);
+            assert_eq!(db.roles().count(), 1);
+            assert_eq!(db.table(None, "t").unwrap().indices(db).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_create_index_if_not_exists_is_scoped_by_schema() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE SCHEMA other;
+                CREATE TABLE t (id INT);
+                CREATE TABLE other.t (id INT);
+                CREATE INDEX IF NOT EXISTS t_id ON t (id);
+                CREATE INDEX IF NOT EXISTS t_id ON other.t (id);",
+        )
+        .unwrap();
+        assert_eq!(db.table(Some("other"), "t").unwrap().indices(&db).count(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repeated_idempotent_migration_files() {
+        let root = std::env::temp_dir()
+            .join(format!("sql_traits_idempotent_migrations_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("0001_setup.sql"), IDEMPOTENT_MIGRATION).unwrap();
+        std::fs::write(root.join("0002_setup_again.sql"), IDEMPOTENT_MIGRATION).unwrap();
+        let loaded = ParserDB::from_path::<PostgreSqlDialect>(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let db = loaded.unwrap();
+        assert_eq!(db.tables().count(), 1);
+        assert_eq!(db.roles().count(), 1);
+        assert_eq!(db.table(None, "t").unwrap().indices(&db).count(), 1);
+    }
+}
+
+mod search_path_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::{ForeignKeyLike, IndexLike, TableGrantLike};
+
+    const TWO_SCHEMAS: &str = "
+            CREATE SCHEMA a;
+            CREATE SCHEMA b;
+            CREATE TABLE a.users (id INT PRIMARY KEY);
+            CREATE TABLE b.users (id INT PRIMARY KEY, name TEXT);
+            CREATE INDEX users_id ON a.users (id);
+            CREATE INDEX users_id ON b.users (id);
+            CREATE FUNCTION a.touch() RETURNS TRIGGER
+                AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+            CREATE FUNCTION b.touch() RETURNS TRIGGER
+                AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+        ";
+
+    fn parse_with_search_path(sql: &str, search_path: &[&str]) -> Result<ParserDB, Error> {
+        ParserDB::parse_with_options::<PostgreSqlDialect>(
+            &format!("{TWO_SCHEMAS}{sql}"),
+            ParserOptions::default().with_search_path(search_path.iter().copied()),
+        )
+    }
+
+    #[test]
+    fn test_unqualified_names_follow_the_search_path() {
+        let db = parse_with_search_path(
+            "CREATE TABLE posts (id INT, author_id INT REFERENCES users (id));
+                CREATE TRIGGER posts_touch BEFORE UPDATE ON users
+                    FOR EACH ROW EXECUTE FUNCTION touch();
+                GRANT SELECT ON users TO PUBLIC;",
+            &["b", "a"],
+        )
+        .unwrap();
+        let users = db.table(Some("b"), "users").unwrap();
+        // Unqualified objects are created in the first schema of the path.
+        let posts = db.table(Some("b"), "posts").unwrap();
+        assert!(db.table(None, "posts").is_none());
+        let foreign_key = posts.foreign_keys(&db).next().unwrap();
+        assert_eq!(foreign_key.referenced_table(&db), Some(users));
+
+        let trigger = db.triggers().next().unwrap();
+        assert_eq!(trigger.table(&db), users);
+        assert_eq!(trigger.function(&db).and_then(FunctionLike::schema), Some("b"));
+
+        let grant = db.table_grants().next().unwrap();
+        assert_eq!(grant.tables(&db).collect::<Vec<_>>(), [users]);
+    }
+
+    #[test]
+    fn test_unqualified_names_do_not_resolve_without_search_path() {
+        let result = parse_with_search_path(
+            "CREATE TABLE posts (id INT, author_id INT REFERENCES users (id));",
+            &[],
+        );
+        assert!(matches!(result, Err(Error::ReferencedTableNotFoundForForeignKey { .. })));
+
+        let result = parse_with_search_path(
+            "CREATE TRIGGER touch_users BEFORE UPDATE ON a.users
+                    FOR EACH ROW EXECUTE FUNCTION touch();",
+            &[],
+        );
+        assert!(matches!(result, Err(Error::FunctionNotFoundForTrigger { .. })));
+    }
+
+    #[test]
+    fn test_drops_are_scoped_by_schema() {
+        let db = parse_with_search_path(
+            "DROP INDEX b.users_id; DROP FUNCTION a.touch; DROP INDEX users_id;",
+            &["a"],
+        )
+        .unwrap();
+        for schema in ["a", "b"] {
+            assert_eq!(db.table(Some(schema), "users").unwrap().indices(&db).count(), 0);
+        }
+        let schemas: Vec<Option<&str>> = db
+            .functions()
+            .filter(|function| function.name() == "touch")
+            .map(FunctionLike::schema)
+            .collect();
+        assert_eq!(schemas, [Some("b")]);
+
+        let result = parse_with_search_path("DROP FUNCTION touch;", &[]);
+        assert!(matches!(result, Err(Error::DropFunctionNotFound { .. })));
+    }
+
+    #[test]
+    fn test_alter_index_is_scoped_by_schema() {
+        let db =
+            parse_with_search_path("ALTER INDEX b.users_id RENAME TO users_id_b;", &[]).unwrap();
+        let index_names = |schema: &str| -> Vec<String> {
+            db.table(Some(schema), "users")
+                .unwrap()
+                .indices(&db)
+                .filter_map(|index| index.index_name().map(str::to_owned))
+                .collect()
+        };
+        assert_eq!(index_names("a"), ["users_id"]);
+        assert_eq!(index_names("b"), ["users_id_b"]);
+    }
+
+    #[test]
+    fn test_set_search_path_applies_to_later_statements() {
+        let db = parse_with_search_path(
+            "SET search_path = a;
+                CREATE TABLE posts (id INT, author_id INT REFERENCES users (id));
+                SET search_path = b, a;
+                CREATE TRIGGER users_touch BEFORE UPDATE ON users
+                    FOR EACH ROW EXECUTE FUNCTION touch();",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(db.search_path(), ["b", "a"]);
+
+        let posts = db.table(Some("a"), "posts").unwrap();
+        assert!(db.table(None, "posts").is_none());
+        let foreign_key = posts.foreign_keys(&db).next().unwrap();
+        assert_eq!(foreign_key.referenced_table(&db), db.table(Some("a"), "users"));
+
+        let trigger = db.triggers().next().unwrap();
+        assert_eq!(trigger.table(&db), db.table(Some("b"), "users").unwrap());
+        assert_eq!(trigger.function(&db).and_then(FunctionLike::schema), Some("b"));
+    }
+
+    #[test]
+    fn test_objects_are_created_in_the_first_existing_schema_of_the_path() {
+        let db = parse_with_search_path(
+            "SET search_path = missing, b, a;
+                CREATE TABLE posts (id INT);
+                CREATE VIEW recent_posts AS SELECT id FROM posts;
+                CREATE SEQUENCE posts_seq;
+                CREATE TEMPORARY TABLE drafts (id INT);
+                SET search_path = missing;
+                CREATE TABLE comments (id INT);",
+            &[],
+        )
+        .unwrap();
+        assert!(db.table(Some("b"), "posts").is_some());
+        assert!(db.view(Some("b"), "recent_posts").is_some());
+        assert!(db.sequence(Some("b"), "posts_seq").is_some());
+        // Temporary tables are not created in the schemas of the path.
+        assert!(db.table(None, "drafts").is_some());
+        assert!(db.table(None, "comments").is_some());
+    }
+
+    #[test]
+    fn test_set_search_path_to_default_restores_the_configured_path() {
+        let db = parse_with_search_path("SET search_path = \"B\", 'a', public;", &["a"]).unwrap();
+        assert_eq!(db.search_path(), ["\"B\"", "a", "public"]);
+
+        let db = parse_with_search_path("SET search_path = b; SET search_path TO DEFAULT;", &["a"])
+            .unwrap();
+        assert_eq!(db.search_path(), ["a"]);
+    }
+}
+
+mod identifier_casing_tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+    use crate::traits::{CheckConstraintLike, ColumnLike, ForeignKeyLike, FunctionLike, ViewLike};
+
+    /// Asserts that the foreign key, the view and the check constraint
+    /// of the schema built by [`quoted_schema`] resolve the identifiers
+    /// they quote with a different case.
+    fn assert_quoted_identifiers_resolve(db: &ParserDB) {
+        let users = db.table(None, "users").unwrap();
+        let posts = db.table(None, "POSTS").unwrap();
+        let foreign_key = posts.foreign_keys(db).next().unwrap();
+        assert_eq!(foreign_key.referenced_table(db), Some(users));
+        let host: Vec<&str> = foreign_key.host_columns(db).map(ColumnLike::column_name).collect();
+        assert_eq!(host, ["Author"]);
+        let referenced: Vec<&str> =
+            foreign_key.referenced_columns(db).map(ColumnLike::column_name).collect();
+        assert_eq!(referenced, ["Id"]);
+
+        let view = db.view(None, "names").unwrap();
+        assert_eq!(view.referenced_tables(db).collect::<Vec<_>>(), [users]);
+        let columns: Vec<&str> = view.referenced_columns(db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["Name"]);
+
+        let check = users.check_constraints(db).next().unwrap();
+        let columns: Vec<&str> = check.columns(db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["Name"]);
+        assert!(check.functions(db).any(|function| function.name() == "length"));
+        assert!(check.function(db, "Length").is_some());
+    }
+
+    /// Returns the schema of the quoted-identifier tests, quoting its
+    /// identifiers with the provided quote.
+    fn quoted_schema(quote: char) -> String {
+        "CREATE TABLE `Users` (`Id` INT PRIMARY KEY, `Name` TEXT CHECK (`LENGTH`(`NAME`) > 0));
+            CREATE TABLE `Posts` (`Author` INT, FOREIGN KEY (`AUTHOR`) REFERENCES `USERS` (`ID`));
+            CREATE VIEW `Names` AS SELECT `NAME` FROM `users`;"
+            .replace('`', &quote.to_string())
+    }
+
+    #[test]
+    fn test_mysql_quoted_identifiers_ignore_case() {
+        let db = ParserDB::parse::<MySqlDialect>(&quoted_schema('`')).unwrap();
+        assert_quoted_identifiers_resolve(&db);
+    }
+
+    #[test]
+    fn test_sqlite_quoted_identifiers_ignore_case() {
+        let db = ParserDB::parse::<SQLiteDialect>(&quoted_schema('"')).unwrap();
+        assert_quoted_identifiers_resolve(&db);
+    }
+
+    #[test]
+    fn test_postgres_quoted_identifiers_match_exactly() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(&quoted_schema('"')),
+            Err(Error::UnknownColumnInCheckConstraint { column_name, .. })
+                if column_name == "NAME"
+        ));
+    }
+
+    #[test]
+    fn test_mysql_identifiers_ignore_case() {
+        let db = ParserDB::parse::<MySqlDialect>(
+            "CREATE TABLE `Users` (id INT PRIMARY KEY, Name TEXT);
+                CREATE TABLE posts (user_id INT, FOREIGN KEY (user_id) REFERENCES users (id));
+                CREATE ROLE Admin;",
+        )
+        .unwrap();
+        let users = db.table(None, "USERS").unwrap();
+        assert_eq!(users.table_name(), "Users");
+        assert!(users.column("name", &db).is_some());
+        assert!(db.column(None, "users", "NAME").is_some());
+        assert!(db.role("ADMIN").is_some());
+        assert!(db.function("COALESCE").is_some());
+        for (role, metadata) in &db.roles {
+            assert!(core::ptr::eq(db.role_metadata(role).unwrap(), metadata));
+        }
+        let posts = db.table(None, "posts").unwrap();
+        assert_eq!(posts.foreign_keys(&db).next().unwrap().referenced_table(&db), Some(users));
+        assert!(matches!(
+            ParserDB::parse::<MySqlDialect>(
+                "CREATE TABLE `Users` (id INT); CREATE TABLE users (id INT);"
+            ),
+            Err(Error::TableAlreadyExists { .. })
+        ));
+    }
+
+    #[test]
+    fn test_postgres_identifiers_fold_unquoted_names() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT);
+                CREATE ROLE Admin;
+                CREATE POLICY Readers ON t USING (id > 0);
+                DROP POLICY readers ON t;",
+        )
+        .unwrap();
+        assert!(db.role("admin").is_some());
+        assert!(db.role("\"Admin\"").is_none());
+        assert_eq!(db.policies().count(), 0);
+        assert!(db.table(None, "\"T\"").is_none());
+    }
+}
+
+mod sequence_tests {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+    use crate::traits::SequenceLike;
+
+    const SQL: &str = "
+            CREATE TABLE users (id INT DEFAULT nextval('users_id_seq'), name TEXT);
+            CREATE SEQUENCE users_id_seq OWNED BY users.id;
+            CREATE SEQUENCE free_seq;
+        ";
+
+    #[test]
+    fn test_create_sequence() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let sequences: Vec<&str> = db.sequences().map(SequenceLike::name).collect();
+        assert_eq!(sequences, ["free_seq", "users_id_seq"]);
+        let id = db.table(None, "users").unwrap().column("id", &db).unwrap();
+        assert_eq!(id.default_sequence(&db).map(SequenceLike::name), Some("users_id_seq"));
+        let sequence = db.sequence(None, "users_id_seq").unwrap();
+        assert_eq!(sequence.owner_column(&db), Some(id));
+    }
+
+    #[test]
+    fn test_duplicate_sequence_fails() {
+        let sql = format!("{SQL} CREATE SEQUENCE free_seq;");
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(&sql),
+            Err(Error::SequenceAlreadyExists { sequence_name }) if sequence_name == "free_seq"
+        ));
+        let sql = format!("{SQL} CREATE SEQUENCE IF NOT EXISTS free_seq;");
+        assert_eq!(ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap().sequences().count(), 2);
+    }
+
+    #[test]
+    fn test_sequence_owner_not_found_fails() {
+        for owner in ["users.missing", "missing.id", "id"] {
+            let sql = format!("{SQL} CREATE SEQUENCE s OWNED BY {owner};");
+            assert!(matches!(
+                ParserDB::parse::<PostgreSqlDialect>(&sql),
+                Err(Error::SequenceOwnerNotFound { sequence_name, .. }) if sequence_name == "s"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sequence_owned_by_none() {
+        let sql = format!("{SQL} CREATE SEQUENCE s OWNED BY NONE;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert!(db.sequence(None, "s").unwrap().owner_column(&db).is_none());
+    }
+
+    #[test]
+    fn test_drop_sequence() {
+        let sql = format!("{SQL} DROP SEQUENCE free_seq; DROP SEQUENCE IF EXISTS missing;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert!(db.sequence(None, "free_seq").is_none());
+        assert_eq!(db.sequences().count(), 1);
+    }
+
+    #[test]
+    fn test_drop_missing_sequence_fails() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("DROP SEQUENCE missing;"),
+            Err(Error::DropSequenceNotFound { sequence_name }) if sequence_name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_drop_sequence_used_by_default() {
+        let sql = format!("{SQL} DROP SEQUENCE users_id_seq;");
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(&sql),
+            Err(Error::SequenceReferenced { sequence_name, column_name, table_name })
+                if sequence_name == "users_id_seq" && column_name == "id" && table_name == "users"
+        ));
+
+        let sql = format!("{SQL} DROP SEQUENCE users_id_seq CASCADE;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert!(db.sequence(None, "users_id_seq").is_none());
+        let users = db.table(None, "users").unwrap();
+        let id = users.column("id", &db).unwrap();
+        assert_eq!(id.default_value(), None);
+        assert_eq!(users.columns(&db).count(), 2);
+    }
+
+    #[test]
+    fn test_drop_sequence_drops_grants() {
+        let sql = format!(
+            "{SQL} CREATE ROLE r;
+                GRANT USAGE ON SEQUENCE free_seq, users_id_seq TO r;
+                GRANT USAGE ON SEQUENCE free_seq TO r;
+                DROP SEQUENCE free_seq;"
+        );
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        let grants: Vec<String> = db.table_grants().map(ToString::to_string).collect();
+        assert_eq!(grants, ["GRANT USAGE ON SEQUENCE users_id_seq TO r"]);
+    }
+
+    #[test]
+    fn test_sequence_dependencies() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let users = db.table(None, "users").unwrap();
+        let sequence = db.sequence(None, "users_id_seq").unwrap();
+        assert_eq!(users.default_sequences(&db), [sequence]);
+        assert_eq!(users.owned_sequences(&db).collect::<Vec<_>>(), [sequence]);
+        let columns: Vec<&str> =
+            sequence.dependent_columns(&db).map(ColumnLike::column_name).collect();
+        assert_eq!(columns, ["id"]);
+        let free = db.sequence(None, "free_seq").unwrap();
+        assert_eq!(free.dependent_columns(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_temporary_sequence() {
+        let sql = format!("{SQL} CREATE TEMPORARY SEQUENCE scratch_seq;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert!(db.sequence(None, "scratch_seq").unwrap().is_temporary());
+        assert!(!db.sequence(None, "free_seq").unwrap().is_temporary());
+    }
+
+    #[test]
+    fn test_drop_table_drops_owned_sequences() {
+        let sql = format!("{SQL} DROP TABLE users;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        let sequences: Vec<&str> = db.sequences().map(SequenceLike::name).collect();
+        assert_eq!(sequences, ["free_seq"]);
+    }
+
+    #[test]
+    fn test_drop_column_drops_owned_sequences() {
+        let sql = format!("{SQL} ALTER TABLE users DROP COLUMN name;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert_eq!(db.sequences().count(), 2);
+
+        let sql = format!("{SQL} ALTER TABLE users DROP COLUMN id;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        let sequences: Vec<&str> = db.sequences().map(SequenceLike::name).collect();
+        assert_eq!(sequences, ["free_seq"]);
+    }
+
+    #[test]
+    fn test_sqlite_warns_about_sequences() {
+        let db = ParserDB::parse::<SQLiteDialect>("CREATE SEQUENCE s;").unwrap();
+        assert_eq!(db.dialect_warnings().len(), 1);
+        assert_eq!(db.dialect_warnings()[0].statement(), "CREATE SEQUENCE");
+    }
+}
+
+mod custom_type_tests {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+    use crate::traits::CustomTypeLike;
+
+    const SQL: &str = "
+            CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+            CREATE DOMAIN positive AS INT CONSTRAINT is_positive CHECK (VALUE > 0);
+            CREATE TABLE people (id INT, current_mood mood, age positive);
+        ";
+
+    #[test]
+    fn test_create_custom_types() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let types: Vec<&str> = db.custom_types().map(CustomTypeLike::name).collect();
+        assert_eq!(types, ["mood", "positive"]);
+
+        let people = db.table(None, "people").unwrap();
+        let current_mood = people.column("current_mood", &db).unwrap();
+        let mood = current_mood.custom_type(&db).unwrap();
+        assert!(mood.is_enum());
+        assert_eq!(mood.enum_variants().collect::<Vec<_>>(), ["sad", "ok", "happy"]);
+        assert_eq!(current_mood.resolved_data_type(&db), "mood");
+
+        let age = people.column("age", &db).unwrap();
+        assert_eq!(age.resolved_data_type(&db), "INT");
+        let positive = age.custom_type(&db).unwrap();
+        assert!(positive.is_domain());
+        let checks: Vec<String> = positive.domain_checks().map(ToString::to_string).collect();
+        assert_eq!(checks, ["VALUE > 0"]);
+    }
+
+    #[test]
+    fn test_duplicate_custom_type_fails() {
+        for statement in ["CREATE TYPE Mood AS ENUM ('x');", "CREATE DOMAIN mood AS TEXT;"] {
+            let sql = format!("{SQL} {statement}");
+            assert!(matches!(
+                ParserDB::parse::<PostgreSqlDialect>(&sql),
+                Err(Error::CustomTypeAlreadyExists { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_drop_custom_types() {
+        let sql =
+            format!("{SQL} DROP TYPE mood; DROP DOMAIN positive; DROP TYPE IF EXISTS missing;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert_eq!(db.custom_types().count(), 0);
+        let age = db.table(None, "people").unwrap().column("age", &db).unwrap();
+        assert!(age.custom_type(&db).is_none());
+        assert_eq!(age.resolved_data_type(&db), "positive");
+    }
+
+    #[test]
+    fn test_drop_missing_custom_type_fails() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>("DROP TYPE missing;"),
+            Err(Error::DropCustomTypeNotFound { type_name }) if type_name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_drop_schema_cascade_removes_custom_types() {
+        let sql = "
+                CREATE SCHEMA app;
+                CREATE TYPE app.mood AS ENUM ('sad');
+                DROP SCHEMA app;
+            ";
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(sql),
+            Err(Error::SchemaNotEmpty { schema_name }) if schema_name == "app"
+        ));
+        let sql = sql.replace("DROP SCHEMA app;", "DROP SCHEMA app CASCADE;");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert_eq!(db.custom_types().count(), 0);
+    }
+
+    #[test]
+    fn test_sqlite_warns_about_custom_types() {
+        let db = ParserDB::parse::<SQLiteDialect>("CREATE DOMAIN email AS TEXT;").unwrap();
+        assert_eq!(db.dialect_warnings().len(), 1);
+        assert_eq!(db.dialect_warnings()[0].statement(), "CREATE DOMAIN");
+    }
+}
+
+mod extension_tests {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+    use crate::traits::CheckConstraintLike;
+
+    const SQL: &str = "
+            CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA ext VERSION '1.3';
+            CREATE EXTENSION \"uuid-ossp\";
+            CREATE TABLE users (
+                id UUID DEFAULT uuid_generate_v4(),
+                password TEXT CHECK (password = crypt(password, gen_salt('bf')))
+            );
+        ";
+
+    #[test]
+    fn test_create_extensions() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let names: Vec<&str> = db.extensions().iter().map(Extension::name).collect();
+        assert_eq!(names, ["pgcrypto", "uuid-ossp"]);
+        let pgcrypto = db.extension("pgcrypto").unwrap();
+        assert_eq!(pgcrypto.schema(), Some("ext"));
+        assert_eq!(pgcrypto.version(), Some("1.3"));
+        assert!(db.extension("\"uuid-ossp\"").unwrap().is_quoted());
+        assert!(db.function("uuid_generate_v4").is_some());
+        assert!(db.function("st_isvalid").is_none());
+    }
+
+    #[test]
+    fn test_check_constraint_resolves_extension_functions() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let users = db.table(None, "users").unwrap();
+        let check = users.check_constraints(&db).next().unwrap();
+        let functions: Vec<&str> = check.functions(&db).map(FunctionLike::name).collect();
+        assert!(functions.contains(&"crypt"));
+        assert!(functions.contains(&"gen_salt"));
+    }
+
+    #[test]
+    fn test_duplicate_extension_fails() {
+        let sql = "CREATE EXTENSION citext; CREATE EXTENSION CITEXT;";
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(sql),
+            Err(Error::ExtensionAlreadyExists { extension_name }) if extension_name == "CITEXT"
+        ));
+    }
+
+    #[test]
+    fn test_drop_extension_removes_its_functions() {
+        let sql = "
+                CREATE FUNCTION st_area(INT) RETURNS INT;
+                CREATE EXTENSION postgis;
+                DROP EXTENSION postgis, missing;
+            ";
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(sql),
+            Err(Error::DropExtensionNotFound { extension_name }) if extension_name == "missing"
+        ));
+        let sql = sql.replace("DROP EXTENSION", "DROP EXTENSION IF EXISTS");
+        let db = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        assert!(db.extensions().is_empty());
+        assert!(db.function("st_isvalid").is_none());
+        assert_eq!(db.function_overloads("st_area").count(), 1);
+    }
+
+    #[test]
+    fn test_drop_referenced_extension_fails() {
+        let sql = format!("{SQL} DROP EXTENSION pgcrypto;");
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(&sql),
+            Err(Error::ExtensionReferenced { extension_name, .. }) if extension_name == "pgcrypto"
+        ));
+    }
+
+    #[test]
+    fn test_sqlite_warns_about_extensions() {
+        let db = ParserDB::parse::<SQLiteDialect>("CREATE EXTENSION citext;").unwrap();
+        assert_eq!(db.dialect_warnings().len(), 1);
+        assert_eq!(db.dialect_warnings()[0].capability(), DialectCapability::Extensions);
+    }
+}
+
+mod storage_order_tests {
+    use core::ptr;
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{
+        structs::generic_db::{
+            cmp_columns, cmp_functions, cmp_policies, cmp_roles, cmp_triggers, sequence_key,
+            table_key, view_key,
+        },
+        traits::{PolicyLike, RoleLike, SchemaLike, TriggerLike},
+    };
+
+    const SQL: &str = "
+            CREATE SCHEMA b;
+            CREATE SCHEMA a;
+            CREATE ROLE writer;
+            CREATE ROLE reader;
+            CREATE TABLE b.items (id INT PRIMARY KEY, price INT CHECK (price > 0));
+            CREATE TABLE a.items (id INT PRIMARY KEY, owner_id INT REFERENCES b.items (id));
+            CREATE TABLE users (id INT UNIQUE, name TEXT);
+            CREATE INDEX users_name_idx ON users (name);
+            CREATE FUNCTION touch() RETURNS TRIGGER AS 'BEGIN END' LANGUAGE plpgsql;
+            CREATE TRIGGER audit AFTER INSERT ON users FOR EACH ROW EXECUTE PROCEDURE touch();
+            CREATE TRIGGER audit AFTER INSERT ON a.items FOR EACH ROW EXECUTE PROCEDURE touch();
+            CREATE POLICY visible ON users USING (id > 0);
+            CREATE POLICY visible ON a.items USING (length('x') > 0);
+            CREATE VIEW b.listing AS SELECT id FROM b.items;
+            CREATE VIEW a.listing AS SELECT id FROM a.items;
+            CREATE SEQUENCE b.counter;
+            CREATE SEQUENCE a.counter;
+        ";
+
+    fn parse() -> ParserDB {
+        ParserDB::parse::<PostgreSqlDialect>(SQL).expect("schema should parse")
+    }
+
+    #[test]
+    fn test_storage_is_sorted_by_canonical_keys() {
+        let db = parse();
+        assert!(
+            db.tables
+                .is_sorted_by(|(a, _), (b, _)| { table_key(a.as_ref()) < table_key(b.as_ref()) })
+        );
+        assert!(
+            db.views.is_sorted_by(|(a, _), (b, _)| { view_key(a.as_ref()) < view_key(b.as_ref()) })
+        );
+        assert!(db.sequences.is_sorted_by(|(a, _), (b, _)| {
+            sequence_key(a.as_ref()) < sequence_key(b.as_ref())
+        }));
+        assert!(db.triggers.is_sorted_by(|(a, _), (b, _)| {
+            cmp_triggers(a.as_ref(), b.as_ref(), IdentifierCasing::Lowercase).is_lt()
+        }));
+        assert!(
+            db.policies
+                .is_sorted_by(|(a, _), (b, _)| { cmp_policies(a.as_ref(), b.as_ref()).is_lt() })
+        );
+        assert!(db.functions.is_sorted_by(|(a, _), (b, _)| {
+            cmp_functions(a.as_ref(), b.as_ref(), IdentifierCasing::Lowercase).is_lt()
+        }));
+        assert!(db.roles.is_sorted_by(|(a, _), (b, _)| {
+            cmp_roles(a.as_ref(), b.as_ref(), IdentifierCasing::Lowercase).is_lt()
+        }));
+        assert!(db.schemas.is_sorted_by(|(a, _), (b, _)| a.name() < b.name()));
+        assert!(db.columns.is_sorted_by(|(a, _), (b, _)| a < b));
+        assert!(db.columns.is_sorted_by(|(a, _), (b, _)| {
+            cmp_columns(a.as_ref(), b.as_ref(), IdentifierCasing::Lowercase).is_lt()
+        }));
+        assert!(db.check_constraints.is_sorted_by(|(a, _), (b, _)| a < b));
+    }
+
+    #[test]
+    fn test_metadata_lookups_agree_with_storage() {
+        let db = parse();
+        for (table, metadata) in &db.tables {
+            assert!(ptr::eq(db.table_metadata(table).unwrap(), metadata));
+        }
+        for (index, (table, _)) in db.tables.iter().enumerate() {
+            assert_eq!(db.table_id(table), Some(index));
+        }
+        for (column, metadata) in &db.columns {
+            assert!(ptr::eq(db.column_metadata(column).unwrap(), metadata));
+            let table = column.table();
+            let found =
+                db.column(table.table_schema(), table.table_name(), column.column_name()).unwrap();
+            assert!(ptr::eq(found, column.as_ref()));
+        }
+        for (index, metadata) in &db.indices {
+            assert!(ptr::eq(db.index_metadata(index).unwrap(), metadata));
+        }
+        for (index, metadata) in &db.unique_indices {
+            assert!(ptr::eq(db.unique_index_metadata(index).unwrap(), metadata));
+        }
+        for (key, metadata) in &db.foreign_keys {
+            assert!(ptr::eq(db.foreign_key_metadata(key).unwrap(), metadata));
+        }
+        for (constraint, metadata) in &db.check_constraints {
+            assert!(ptr::eq(db.check_constraint_metadata(constraint).unwrap(), metadata));
+        }
+        for (function, metadata) in &db.functions {
+            assert!(ptr::eq(db.function_metadata(function).unwrap(), metadata));
+        }
+        for (trigger, metadata) in &db.triggers {
+            assert!(ptr::eq(db.trigger_metadata(trigger).unwrap(), metadata));
+        }
+        for (policy, metadata) in &db.policies {
+            assert!(ptr::eq(db.policy_metadata(policy).unwrap(), metadata));
+        }
+        for (role, metadata) in &db.roles {
+            assert!(ptr::eq(db.role_metadata(role).unwrap(), metadata));
+        }
+        for (schema, metadata) in &db.schemas {
+            assert!(ptr::eq(db.schema_metadata(schema).unwrap(), metadata));
+        }
+    }
+
+    #[test]
+    fn test_homonymous_policies_keep_their_own_metadata() {
+        let db = parse();
+        let mut using_functions: Vec<usize> = db
+            .policies()
+            .filter(|policy| policy.name() == "visible")
+            .map(|policy| db.policy_metadata(policy).unwrap().using_functions().count())
+            .collect();
+        using_functions.sort_unstable();
+        assert_eq!(using_functions, [0, 1]);
+    }
+
+    #[test]
+    fn test_homonymous_triggers_are_keyed_by_table() {
+        let db = parse();
+        let tables: Vec<&str> = db
+            .triggers()
+            .filter(|trigger| trigger.name() == "audit")
+            .map(TriggerLike::table_name)
+            .collect();
+        assert_eq!(tables, ["users", "items"]);
+    }
+
+    #[test]
+    fn test_triggers_and_policies_are_indexed_by_table() {
+        let db = parse();
+        for index in [&db.table_triggers, &db.table_policies] {
+            let index = index.as_ref().unwrap();
+            assert!(index.is_sorted());
+            assert_eq!(index.len(), 2);
+        }
+
+        let items = db.table(Some("a"), "items").unwrap();
+        let triggers: Vec<Option<&str>> =
+            items.triggers(&db).map(TriggerLike::table_schema).collect();
+        assert_eq!(triggers, [Some("a")]);
+        let policies: Vec<&str> = db.policies_on(items).map(PolicyLike::name).collect();
+        assert_eq!(policies, ["visible"]);
+        let other_items = db.table(Some("b"), "items").unwrap();
+        assert_eq!(db.triggers_on(other_items).count(), 0);
+        assert_eq!(other_items.policies(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_foreign_key_references_resolve_the_schema() {
+        let db = parse();
+        let references = db.foreign_key_references.as_ref().unwrap();
+        assert!(references.is_sorted());
+        assert_eq!(references.len(), db.foreign_keys.len());
+
+        let referenced = db.table(Some("b"), "items").unwrap();
+        let hosts: Vec<Option<&str>> = referenced
+            .referencing_foreign_keys(&db)
+            .map(|foreign_key| foreign_key.table().table_schema())
+            .collect();
+        assert_eq!(hosts, [Some("a")]);
+        let host = db.table(Some("a"), "items").unwrap();
+        assert_eq!(db.references_to(host).count(), 0);
+    }
+}
+
+mod ddl_tests {
+    use sqlparser::dialect::{PostgreSqlDialect, SQLiteDialect};
+
+    use super::*;
+    use crate::diff::SchemaDiff;
+
+    const SQL: &str = r"
+            CREATE ROLE app_admin;
+            CREATE ROLE app_user IN ROLE app_admin;
+            CREATE SCHEMA app AUTHORIZATION app_admin;
+            CREATE EXTENSION pgcrypto WITH SCHEMA app VERSION '1.3';
+            CREATE TYPE mood AS ENUM ('happy', 'it''s complicated');
+            CREATE DOMAIN positive AS INT CHECK (VALUE > 0);
+            CREATE FUNCTION touch() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+            CREATE SEQUENCE ticket_seq;
+            CREATE TABLE users (
+                id INT PRIMARY KEY,
+                email TEXT UNIQUE NOT NULL,
+                feeling mood,
+                score positive DEFAULT 1
+            );
+            CREATE TABLE posts (
+                id INT PRIMARY KEY,
+                author_id INT REFERENCES users (id) ON DELETE CASCADE,
+                body TEXT CHECK (body <> '')
+            );
+            ALTER TABLE posts ENABLE ROW LEVEL SECURITY;
+            ALTER TABLE users ADD COLUMN password TEXT;
+            CREATE INDEX posts_author_idx ON posts (author_id);
+            CREATE VIEW post_authors AS SELECT posts.id, users.email FROM posts JOIN users ON users.id = posts.author_id;
+            CREATE TRIGGER posts_touch BEFORE UPDATE ON posts FOR EACH ROW EXECUTE FUNCTION touch();
+            CREATE POLICY own_posts ON posts TO app_user USING (author_id > 0);
+            GRANT SELECT ON posts TO app_user;
+        ";
+
+    #[test]
+    fn test_render_round_trips() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let sql = db.to_sql(SqlparserDialect::PostgreSql);
+        let reparsed = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+
+        let diff = SchemaDiff::between(&db, &reparsed);
+        assert!(diff.is_empty(), "{diff}");
+        assert_eq!(reparsed.to_sql(SqlparserDialect::PostgreSql), sql);
+        assert_eq!(reparsed.extensions(), db.extensions());
+        assert_eq!(reparsed.custom_types().count(), 2);
+        assert_eq!(reparsed.sequences().count(), 1);
+        assert_eq!(reparsed.views().count(), 1);
+        assert_eq!(reparsed.triggers().count(), 1);
+        assert!(reparsed.function("touch").is_some());
+        // Builtins and extension functions are registered again on parse,
+        // rather than rendered.
+        assert!(!sql.contains("FUNCTION crypt"));
+        assert!(!sql.contains("internal"));
+    }
+
+    #[test]
+    fn test_render_keeps_functions_in_internal_language() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE FUNCTION my_len(x TEXT) RETURNS INT AS 'textlen' LANGUAGE internal;",
+        )
+        .unwrap();
+        assert!(!db.function("my_len").unwrap().is_builtin(&db));
+        assert!(db.function("coalesce").unwrap().is_builtin(&db));
+        let sql = db.to_sql(SqlparserDialect::PostgreSql);
+        assert!(sql.contains("FUNCTION my_len"), "{sql}");
+    }
+
+    #[test]
+    fn test_render_orders_dependencies() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let sql = db.to_sql(SqlparserDialect::PostgreSql);
+        let position = |needle: &str| sql.find(needle).unwrap();
+        assert!(position("CREATE ROLE app_admin") < position("CREATE ROLE app_user"));
+        assert!(position("CREATE TABLE users") < position("CREATE TABLE posts"));
+        assert!(position("CREATE FUNCTION touch") < position("CREATE TRIGGER posts_touch"));
+        assert!(position("CREATE TABLE posts") < position("CREATE POLICY own_posts"));
+    }
+
+    #[test]
+    fn test_render_omits_unsupported_objects() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(SQL).unwrap();
+        let sql = db.to_sql(SqlparserDialect::SQLite);
+        assert!(!sql.contains("CREATE ROLE"));
+        assert!(!sql.contains("CREATE POLICY"));
+        assert!(!sql.contains("GRANT"));
+        assert!(sql.contains("CREATE TABLE posts"));
+        assert!(sql.contains("CREATE TRIGGER posts_touch"));
+
+        let empty = ParserDB::parse::<SQLiteDialect>("").unwrap();
+        assert_eq!(empty.to_sql(SqlparserDialect::SQLite), "");
+    }
+
+    #[test]
+    fn test_render_round_trips_foreign_key_cycles() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+                CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+                CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users (id));
+                ALTER TABLE users ADD CONSTRAINT users_team_fk FOREIGN KEY (team_id) REFERENCES teams (id);
+                CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+                ",
+        )
+        .unwrap();
+        assert!(db.table_dependency_graph().cycle().is_some());
+
+        let sql = db.to_sql(SqlparserDialect::PostgreSql);
+        let position = |needle: &str| sql.find(needle).unwrap();
+        assert!(
+            sql.contains("CREATE TABLE teams (id INT, owner_id INT, PRIMARY KEY (id));"),
+            "{sql}"
+        );
+        assert!(position("CREATE TABLE teams") < position("CREATE TABLE users"));
+        assert!(position("CREATE TABLE users") < position("CREATE TABLE posts"));
+        assert!(position("CREATE TABLE posts") < position("ALTER TABLE teams ADD"));
+
+        let reparsed = ParserDB::parse::<PostgreSqlDialect>(&sql).unwrap();
+        let diff = SchemaDiff::between(&db, &reparsed);
+        assert!(diff.is_empty(), "{diff}");
+        assert_eq!(reparsed.to_sql(SqlparserDialect::PostgreSql), sql);
+    }
+}
+
+mod validation_level_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ValidationWarning;
+
+    const SQL: &str = "
+            CREATE TABLE t (id INT, amount INT DEFAULT 'abc');
+            GRANT SELECT ON t TO reader;
+            CREATE VIEW v AS SELECT id FROM t;
+            DROP TABLE t;
+            CREATE TABLE u (id INT);
+        ";
+
+    fn options(level: ValidationLevel) -> ParserOptions {
+        ParserOptions::default()
+            .with_level(ValidationClass::MissingObjects, level)
+            .with_level(ValidationClass::DependentObjects, level)
+            .with_level(ValidationClass::Types, level)
+    }
+
+    #[test]
+    fn test_errors_abort_the_parse_by_default() {
+        assert!(matches!(
+            ParserDB::parse::<PostgreSqlDialect>(SQL),
+            Err(Error::RoleNotFoundForGrant { .. })
+        ));
+        assert!(matches!(
+            ParserDB::parse_with_options::<PostgreSqlDialect>(
+                SQL,
+                options(ValidationLevel::Warn)
+                    .with_level(ValidationClass::DependentObjects, ValidationLevel::Error),
+            ),
+            Err(Error::TableReferencedByView { .. })
+        ));
+    }
+
+    #[test]
+    fn test_warnings_are_collected_and_statements_skipped() {
+        let db =
+            ParserDB::parse_with_options::<PostgreSqlDialect>(SQL, options(ValidationLevel::Warn))
+                .unwrap();
+        assert!(db.table(None, "t").is_some());
+        assert!(db.table(None, "u").is_some());
+        assert_eq!(db.table_grants().count(), 0);
+        let classes: Vec<ValidationClass> =
+            db.validation_warnings().iter().map(ValidationWarning::class).collect();
+        assert_eq!(
+            classes,
+            [
+                ValidationClass::MissingObjects,
+                ValidationClass::DependentObjects,
+                ValidationClass::Types,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignored_errors_are_not_recorded() {
+        let db = ParserDB::parse_with_options::<PostgreSqlDialect>(
+            SQL,
+            options(ValidationLevel::Ignore),
+        )
+        .unwrap();
+        assert!(db.table(None, "t").is_some());
+        assert!(db.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_skipped_statements_are_rolled_back() {
+        let sql = "
+                CREATE TABLE t (id INT PRIMARY KEY);
+                CREATE SEQUENCE a;
+                DROP SEQUENCE a, b;
+                ALTER TABLE t ADD COLUMN x INT, ADD FOREIGN KEY (x) REFERENCES missing (id);
+                CREATE TABLE u (id INT);
+            ";
+        let db =
+            ParserDB::parse_with_options::<PostgreSqlDialect>(sql, options(ValidationLevel::Warn))
+                .unwrap();
+        assert!(db.sequence(None, "a").is_some());
+        let table = db.table(None, "t").unwrap();
+        assert_eq!(table.columns(&db).count(), 1);
+        assert!(db.table(None, "u").is_some());
+        assert_eq!(db.validation_warnings().len(), 2);
+    }
+
+    #[test]
+    fn test_unsupported_statements() {
+        let sql = "CREATE TYPE mood AS ENUM ('sad', 'happy');
+                ALTER TYPE mood ADD VALUE 'ok';
+                SET TIME ZONE 'UTC' || '';
+                CREATE TABLE t (id INT);";
+        let error = ParserDB::parse_with_options::<PostgreSqlDialect>(
+            sql,
+            ParserOptions::default()
+                .with_level(ValidationClass::UnsupportedStatements, ValidationLevel::Error),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::UnsupportedStatement { statement, location }
+                if statement.starts_with("ALTER TYPE mood") && location.statement_index() == 1
+        ));
+        assert_eq!(ValidationClass::of(&error), Some(ValidationClass::UnsupportedStatements));
+
+        // The statements are skipped with a warning by default, so that
+        // the schemas which used to load still do.
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).unwrap();
+        assert!(db.table(None, "t").is_some());
+        assert_eq!(db.validation_warnings().len(), 2);
+        assert!(
+            db.validation_warnings()
+                .iter()
+                .all(|warning| { warning.class() == ValidationClass::UnsupportedStatements })
+        );
+
+        // Ignoring the class restores the former behaviour of skipping
+        // the statements silently.
+        let db = ParserDB::parse_with_options::<PostgreSqlDialect>(
+            sql,
+            ParserOptions::default()
+                .with_level(ValidationClass::UnsupportedStatements, ValidationLevel::Ignore),
+        )
+        .unwrap();
+        assert!(db.table(None, "t").is_some());
+        assert!(db.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_statement_handlers_replace_built_in_processing() {
+        let sql = "CREATE TYPE mood AS ENUM ('sad', 'happy');
+                ALTER TYPE mood ADD VALUE 'ok';
+                CREATE TABLE t (id INT);
+                CREATE TABLE u (id INT);";
+        assert_eq!(
+            ParserDB::parse::<PostgreSqlDialect>(sql).unwrap().validation_warnings().len(),
+            1
+        );
+
+        let options = ParserOptions::default()
+            .with_statement_handler("AlterType", |builder, _| Ok(builder))
+            .with_statement_handler("CreateTable", |builder, statement| {
+                let Statement::CreateTable(create_table) = statement else {
+                    unreachable!("only `CreateTable` statements are handled");
+                };
+                if create_table.name.to_string() == "t" {
+                    return Ok(builder);
+                }
+                Ok(builder.add_table(Arc::new(create_table.clone()), TableMetadata::default())?)
+            });
+        assert_eq!(
+            options.statement_handlers().kinds().collect::<Vec<_>>(),
+            ["AlterType", "CreateTable"]
+        );
+        let db = ParserDB::parse_with_options::<PostgreSqlDialect>(sql, options).unwrap();
+        assert!(db.table(None, "t").is_none());
+        assert!(db.table(None, "u").is_some());
+        assert!(db.validation_warnings().is_empty());
+    }
+}
+
+mod observer_tests {
+    use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Outcomes(Vec<(&'static str, String)>);
+
+    impl ParseObserver for Outcomes {
+        fn on_processed(&mut self, statement: &Statement) {
+            self.0.push(("processed", statement.to_string()));
+        }
+
+        fn on_ignored(&mut self, statement: &Statement) {
+            self.0.push(("ignored", statement.to_string()));
+        }
+
+        fn on_unsupported(&mut self, statement: &Statement, _error: &Error) {
+            self.0.push(("unsupported", statement.to_string()));
+        }
+
+        fn on_failed(&mut self, statement: &Statement, _error: &Error) {
+            self.0.push(("failed", statement.to_string()));
+        }
+    }
+
+    fn observe(sql: &str) -> (Result<ParserDB, Error>, Vec<(&'static str, String)>) {
+        let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+        let mut outcomes = Outcomes::default();
+        let db = ParserDB::from_statements_with_observer(statements, "test".into(), &mut outcomes);
+        (db, outcomes.0)
+    }
+
+    #[test]
+    fn test_processed_and_ignored_statements_are_observed() {
+        let (db, outcomes) =
+            observe("CREATE TABLE t (id INT); INSERT INTO t VALUES (1); DROP TABLE t;");
+        assert!(db.unwrap().table(None, "t").is_none());
+        assert_eq!(
+            outcomes,
+            [
+                ("processed", "CREATE TABLE t (id INT)".to_string()),
+                ("ignored", "INSERT INTO t VALUES (1)".to_string()),
+                ("processed", "DROP TABLE t".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejected_statements_are_observed() {
+        let (db, outcomes) = observe("ALTER TYPE mood ADD VALUE 'ok';");
+        assert_eq!(
+            db.unwrap().validation_warnings()[0].class(),
+            ValidationClass::UnsupportedStatements
+        );
+        assert_eq!(outcomes, [("unsupported", "ALTER TYPE mood ADD VALUE 'ok'".to_string())]);
+
+        let (db, outcomes) = observe("DROP TABLE missing;");
+        assert!(matches!(db, Err(Error::DropTableNotFound { .. })));
+        assert_eq!(outcomes, [("failed", "DROP TABLE missing".to_string())]);
+    }
+}
+
+mod error_collection_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    const SQL: &str = "
+            CREATE TABLE t (id INT, amount INT DEFAULT 'abc');
+            GRANT SELECT ON t TO reader;
+            CREATE INDEX idx ON missing (id);
+            CREATE TABLE t (id INT);
+            CREATE TABLE u (id INT);
+        ";
+
+    #[test]
+    fn test_every_error_is_collected() {
+        let report =
+            ParserDB::parse_with_report::<PostgreSqlDialect>(SQL, ParserOptions::default())
+                .unwrap();
+        assert!(!report.is_valid());
+        assert!(matches!(
+            report.errors(),
+            [
+                Error::RoleNotFoundForGrant { .. },
+                Error::TableNotFoundForIndex { .. },
+                Error::TableAlreadyExists { .. },
+                Error::DefaultTypeMismatch { .. },
+            ]
+        ));
+        let (db, errors) = report.into_parts();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(db.tables().count(), 2);
+    }
+
+    #[test]
+    fn test_errors_record_the_span_of_their_statement() {
+        let report =
+            ParserDB::parse_with_report::<PostgreSqlDialect>(SQL, ParserOptions::default())
+                .unwrap();
+        let lines: Vec<Option<u64>> =
+            report.error_spans().iter().map(|span| span.map(|span| span.start.line)).collect();
+        assert_eq!(lines.len(), report.errors().len());
+        assert_eq!(lines[1..], [Some(4), Some(5), None]);
+    }
+
+    #[test]
+    fn test_downgraded_errors_are_not_collected() {
+        let options = ParserOptions::default()
+            .with_level(ValidationClass::MissingObjects, ValidationLevel::Warn)
+            .with_level(ValidationClass::Types, ValidationLevel::Ignore);
+        let report = ParserDB::parse_with_report::<PostgreSqlDialect>(SQL, options).unwrap();
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(report.database().validation_warnings().len(), 2);
+    }
+
+    #[test]
+    fn test_valid_schema_yields_the_database() {
+        let report = ParserDB::parse_with_report::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT);",
+            ParserOptions::default(),
+        )
+        .unwrap();
+        assert!(report.is_valid());
+        assert!(report.into_result().unwrap().table(None, "t").is_some());
+        assert!(
+            ParserDB::parse_with_report::<PostgreSqlDialect>(
+                "CREATE TABL",
+                ParserOptions::default()
+            )
+            .is_err()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+mod rollback_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    #[test]
+    fn test_verify_rollbacks() {
+        let root = std::env::temp_dir()
+            .join(format!("sql_traits_verify_rollbacks_{}", std::process::id()));
+        let first = root.join("0001_users");
+        let second = root.join("0002_posts");
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        std::fs::write(first.join("up.sql"), "CREATE TABLE users (id INT);").unwrap();
+        std::fs::write(first.join("down.sql"), "DROP TABLE users;").unwrap();
+        std::fs::write(second.join("up.sql"), "CREATE TABLE posts (id INT);").unwrap();
+        std::fs::write(second.join("down.sql"), "DROP TABLE posts; DROP TABLE users;").unwrap();
+
+        let paths = [root.as_path()];
+        let ignored =
+            ParserDB::verify_rollbacks::<PostgreSqlDialect>(&paths, RollbackSeverity::Ignore);
+        let warned =
+            ParserDB::verify_rollbacks::<PostgreSqlDialect>(&paths, RollbackSeverity::Warn);
+        let denied =
+            ParserDB::verify_rollbacks::<PostgreSqlDialect>(&paths, RollbackSeverity::Deny);
+        let loaded = ParserDB::from_path::<PostgreSqlDialect>(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(ignored.unwrap().is_empty());
+        let warned = warned.unwrap();
+        assert_eq!(warned.len(), 1);
+        assert_eq!(warned[0].object_name(), "users");
+        assert_eq!(warned[0].down_file(), second.join("down.sql"));
+        assert!(matches!(
+            denied,
+            Err(Error::RollbackMismatch(mismatch)) if mismatch.object_name() == "users"
+        ));
+        assert_eq!(loaded.unwrap().tables().count(), 2);
+    }
+
+    #[test]
+    fn test_unreadable_file_is_an_io_error() {
+        let root =
+            std::env::temp_dir().join(format!("sql_traits_unreadable_file_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let file = root.join("invalid.sql");
+        std::fs::write(&file, [0xff, 0xfe, 0xfd]).unwrap();
+        let loaded = ParserDB::from_path::<PostgreSqlDialect>(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(matches!(loaded, Err(Error::Io { path, .. }) if path == file));
+    }
+}
+
+mod source_location_tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::traits::{CheckConstraintLike, IndexLike, PolicyLike};
+
+    #[test]
+    fn test_objects_record_the_statement_creating_them() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT UNIQUE);
+                 ALTER TABLE t ADD CONSTRAINT positive CHECK (id > 0);
+                 CREATE INDEX idx ON t (id);
+                 CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+                 CREATE POLICY p ON t USING (id > 0);",
+        )
+        .unwrap();
+        let table = db.table(None, "t").unwrap();
+        let location = table.source_location(&db).unwrap();
+        assert_eq!((location.statement_index(), location.line()), (0, Some(1)));
+        let unique_index = table.unique_indices(&db).next().unwrap();
+        assert_eq!(unique_index.source_location(&db).unwrap().statement_index(), 0);
+        let check = table.check_constraints(&db).next().unwrap();
+        let location = check.source_location(&db).unwrap();
+        assert_eq!((location.statement_index(), location.line()), (1, Some(2)));
+        let index = table.indices(&db).next().unwrap();
+        assert_eq!(index.source_location(&db).unwrap().statement_index(), 2);
+        let function = db.function("one").unwrap();
+        assert_eq!(function.source_location(&db).unwrap().statement_index(), 3);
+        let policy = db.policies().next().unwrap();
+        assert_eq!(policy.source_location(&db).unwrap().statement_index(), 4);
+        assert!(db.function("now").unwrap().source_location(&db).is_none());
+    }
+
+    #[test]
+    fn test_renamed_tables_keep_their_location() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE t (id INT);
+                 CREATE TABLE u (id INT);
+                 ALTER TABLE t RENAME TO z;
+                 CREATE TABLE a (id INT);",
+        )
+        .unwrap();
+        let index_of = |name: &str| {
+            db.table(None, name).unwrap().source_location(&db).unwrap().statement_index()
+        };
+        assert_eq!((index_of("z"), index_of("u"), index_of("a")), (0, 1, 3));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_locations_are_relative_to_their_file() {
+        let root = std::env::temp_dir()
+            .join(format!("sql_traits_source_locations_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("a.sql"), "CREATE TABLE a (id INT);\nCREATE TABLE b (id INT);")
+            .unwrap();
+        std::fs::write(root.join("b.sql"), "\nCREATE TABLE c (id INT);").unwrap();
+        let loaded = ParserDB::from_path::<PostgreSqlDialect>(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let db = loaded.unwrap();
+        let location = db.table(None, "b").unwrap().source_location(&db).unwrap();
+        assert!(location.path().unwrap().ends_with("a.sql"));
+        assert_eq!((location.statement_index(), location.line()), (1, Some(2)));
+        let location = db.table(None, "c").unwrap().source_location(&db).unwrap();
+        assert!(location.path().unwrap().ends_with("b.sql"));
+        assert_eq!((location.statement_index(), location.line()), (0, Some(2)));
+        assert!(location.to_string().ends_with("b.sql:2:14"));
+    }
+}
@@ -0,0 +1,128 @@
+//! Tracking of the sequences created `UNLOGGED`.
+//!
+//! As `sqlparser` does not parse `CREATE UNLOGGED SEQUENCE`, the flag is
+//! blanked out of the SQL before parsing, and the sequences it preceded are
+//! recovered from the spans of their names.
+
+use alloc::{string::String, sync::Arc, vec::Vec};
+
+use sqlparser::{
+    ast::{ObjectName, Spanned, Statement},
+    dialect::Dialect,
+    keywords::Keyword,
+    tokenizer::{Span, Token, TokenWithSpan, Tokenizer},
+};
+
+use super::{ParserDB, sequence_matches_object_name};
+use crate::{traits::DialectLike, utils::byte_offset};
+
+/// Returns whether the token is the provided keyword.
+fn is_keyword(token: &TokenWithSpan, keyword: Keyword) -> bool {
+    matches!(&token.token, Token::Word(word) if word.keyword == keyword)
+}
+
+/// Blanks out the `UNLOGGED` flags of `CREATE UNLOGGED SEQUENCE` statements,
+/// returning the SQL without them along with the spans of the flagged
+/// statements, from their `SEQUENCE` keyword up to their end.
+///
+/// The flags are replaced by spaces, so that the locations of the remaining
+/// tokens are preserved. SQL which cannot be tokenized is returned as is,
+/// leaving the parser to report the error.
+pub(super) fn strip_unlogged_sequences(dialect: &dyn Dialect, sql: &str) -> (String, Vec<Span>) {
+    let mut stripped = String::from(sql);
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return (stripped, Vec::new());
+    };
+    let tokens: Vec<TokenWithSpan> =
+        tokens.into_iter().filter(|token| !matches!(token.token, Token::Whitespace(_))).collect();
+
+    let mut flagged = Vec::new();
+    for window in tokens.windows(3) {
+        let [create, unlogged, sequence] = window else {
+            continue;
+        };
+        if !is_keyword(create, Keyword::CREATE)
+            || !is_keyword(unlogged, Keyword::UNLOGGED)
+            || !is_keyword(sequence, Keyword::SEQUENCE)
+        {
+            continue;
+        }
+        if let (Some(start), Some(end)) =
+            (byte_offset(sql, unlogged.span.start), byte_offset(sql, unlogged.span.end))
+        {
+            stripped.replace_range(start..end, &" ".repeat(end - start));
+        }
+        let statement_end = tokens
+            .iter()
+            .skip_while(|token| token.span.start < sequence.span.start)
+            .find(|token| matches!(token.token, Token::SemiColon | Token::EOF))
+            .map_or(sequence.span.end, |token| token.span.start);
+        flagged.push(Span::new(sequence.span.start, statement_end));
+    }
+    (stripped, flagged)
+}
+
+/// Returns the names of the sequences created by the statements within the
+/// provided spans, as returned by [`strip_unlogged_sequences`].
+pub(super) fn unlogged_sequences(statements: &[Statement], flagged: &[Span]) -> Vec<ObjectName> {
+    if flagged.is_empty() {
+        return Vec::new();
+    }
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::CreateSequence { name, .. } => {
+                let start = name.span().start;
+                flagged
+                    .iter()
+                    .any(|span| span.start <= start && start < span.end)
+                    .then(|| name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+impl ParserDB {
+    /// Flags the sequences created `UNLOGGED`.
+    pub(super) fn mark_unlogged_sequences(&mut self, names: &[ObjectName]) {
+        if names.is_empty() {
+            return;
+        }
+        let casing = self.dialect().identifier_casing();
+        for sequence in self.sequences_mut() {
+            if names.iter().any(|name| sequence_matches_object_name(sequence, name, casing)) {
+                *sequence = Arc::new((**sequence).clone().with_unlogged(true));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        impls::SqlparserDialect,
+        structs::ParserDB,
+        traits::{DatabaseLike, SequenceLike},
+    };
+
+    #[test]
+    fn test_unlogged_sequences_are_tracked() {
+        let sql = "
+            CREATE UNLOGGED SEQUENCE cache_seq;
+            CREATE SEQUENCE users_id_seq;
+            CREATE UNLOGGED SEQUENCE IF NOT EXISTS app.hits_seq
+            ";
+        let db = ParserDB::parse::<PostgreSqlDialect>(sql).expect("Failed to parse");
+        assert!(db.sequence(None, "cache_seq").unwrap().is_unlogged());
+        assert!(!db.sequence(None, "users_id_seq").unwrap().is_unlogged());
+        assert!(db.sequence(Some("app"), "hits_seq").unwrap().is_unlogged());
+
+        let rendered = db.to_sql(SqlparserDialect::PostgreSql);
+        assert!(rendered.contains("CREATE UNLOGGED SEQUENCE cache_seq;"), "{rendered}");
+        let reparsed = ParserDB::parse::<PostgreSqlDialect>(&rendered).expect("Failed to parse");
+        assert!(reparsed.sequence(None, "cache_seq").unwrap().is_unlogged());
+    }
+}
@@ -0,0 +1,507 @@
+//! Stripping of the statements which `sqlparser` cannot parse but whose effect
+//! on the schema is tracked.
+//!
+//! Such statements are blanked out of the SQL before parsing, and applied
+//! once the statements preceding them are processed:
+//!
+//! * `ALTER INDEX ... SET (...)` and `ALTER INDEX ... RESET (...)`, as only the
+//!   `RENAME TO` form of `ALTER INDEX` is parsed;
+//! * `COMMENT ON FUNCTION ... IS ...`, as only the comments on tables,
+//!   columns and a few other objects are parsed;
+//! * `GRANT role TO role` and `REVOKE role FROM role`, as only the grants of
+//!   privileges on objects are parsed;
+//! * `ALTER DEFAULT PRIVILEGES ... ON TABLES`, which is not parsed at all.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use sqlparser::{
+    ast::{DataType, Ident, ObjectName},
+    dialect::Dialect,
+    keywords::Keyword,
+    parser::{Parser, ParserError},
+    tokenizer::{Token, TokenWithSpan, Tokenizer},
+};
+
+use super::index_settings::{self, IndexSettingsChange};
+use crate::{
+    errors::Error, privileges::TablePrivilege, structs::DefaultPrivilege, utils::byte_offset,
+};
+
+/// A statement stripped from the SQL before parsing.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum UnparsedStatement {
+    /// `ALTER INDEX ... SET (...)` or `ALTER INDEX ... RESET (...)`.
+    IndexSettings {
+        /// The name of the index as written in the statement.
+        name: ObjectName,
+        /// The change of the storage parameters.
+        change: IndexSettingsChange,
+    },
+    /// `COMMENT ON FUNCTION ... IS ...`.
+    FunctionComment {
+        /// The name of the function as written in the statement.
+        name: ObjectName,
+        /// The argument types identifying the overload, if provided.
+        arguments: Option<Vec<DataType>>,
+        /// The comment, or `None` when it is removed with `IS NULL`.
+        comment: Option<String>,
+    },
+    /// `GRANT role TO role` or `REVOKE role FROM role`.
+    RoleMembership {
+        /// Whether the membership is granted rather than revoked.
+        grant: bool,
+        /// The roles whose membership is granted or revoked.
+        roles: Vec<Ident>,
+        /// The roles becoming or ceasing to be members of the roles.
+        members: Vec<Ident>,
+        /// The clauses which are not modelled and are dropped, such as
+        /// `WITH ADMIN OPTION` or `GRANTED BY role`, as written in the
+        /// statement.
+        dropped_clauses: Vec<String>,
+    },
+    /// `ALTER DEFAULT PRIVILEGES ... { GRANT | REVOKE } ... ON TABLES`.
+    DefaultPrivileges {
+        /// Whether the default privileges are granted rather than revoked.
+        grant: bool,
+        /// The default privileges granted or revoked. When revoked, their
+        /// grantable flag stands for `REVOKE GRANT OPTION FOR`.
+        default_privilege: DefaultPrivilege,
+    },
+}
+
+impl UnparsedStatement {
+    /// Returns the errors reporting the clauses dropped from the statement.
+    pub(super) fn dropped_clause_errors(&self) -> Vec<Error> {
+        let Self::RoleMembership { grant, roles, members, dropped_clauses } = self else {
+            return Vec::new();
+        };
+        let names = |idents: &[Ident]| {
+            idents.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+        };
+        let statement = format!(
+            "{} {} {} {}",
+            if *grant { "GRANT" } else { "REVOKE" },
+            names(roles),
+            if *grant { "TO" } else { "FROM" },
+            names(members),
+        );
+        dropped_clauses
+            .iter()
+            .map(|clause| Error::UnsupportedClause {
+                statement: statement.clone(),
+                clause: clause.clone(),
+            })
+            .collect()
+    }
+}
+
+/// A statement stripped from the SQL, along with its position.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Unparsed {
+    /// The number of parsed statements preceding the stripped one.
+    pub(super) position: usize,
+    /// The stripped statement.
+    pub(super) statement: UnparsedStatement,
+}
+
+/// Parses a `COMMENT ON FUNCTION name [(type, ...)] IS ...` statement from
+/// its tokens, without the trailing semicolon.
+///
+/// Returns `None` for any other statement.
+fn parse_function_comment(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Option<UnparsedStatement>, ParserError> {
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(tokens.to_vec());
+    if !parser.parse_keywords(&[Keyword::COMMENT, Keyword::ON, Keyword::FUNCTION]) {
+        return Ok(None);
+    }
+    let name = parser.parse_object_name(false)?;
+    let arguments = if parser.consume_token(&Token::LParen) {
+        if parser.consume_token(&Token::RParen) {
+            Some(Vec::new())
+        } else {
+            let arguments = parser.parse_comma_separated(Parser::parse_data_type)?;
+            parser.expect_token(&Token::RParen)?;
+            Some(arguments)
+        }
+    } else {
+        None
+    };
+    parser.expect_keywords(&[Keyword::IS])?;
+    let comment = if parser.parse_keyword(Keyword::NULL) {
+        None
+    } else {
+        Some(parser.parse_literal_string()?)
+    };
+    parser.expect_token(&Token::EOF)?;
+    Ok(Some(UnparsedStatement::FunctionComment { name, arguments, comment }))
+}
+
+/// Keywords which start the list of privileges of a `GRANT` or `REVOKE`
+/// statement, and therefore cannot start a list of roles.
+const PRIVILEGE_KEYWORDS: &[Keyword] = &[
+    Keyword::ALL,
+    Keyword::ALTER,
+    Keyword::CONNECT,
+    Keyword::CREATE,
+    Keyword::DELETE,
+    Keyword::EXEC,
+    Keyword::EXECUTE,
+    Keyword::INSERT,
+    Keyword::REFERENCES,
+    Keyword::ROLE,
+    Keyword::SELECT,
+    Keyword::TEMP,
+    Keyword::TEMPORARY,
+    Keyword::TRIGGER,
+    Keyword::TRUNCATE,
+    Keyword::UPDATE,
+    Keyword::USAGE,
+];
+
+/// Parses a `GRANT role [, ...] TO role [, ...]` or `REVOKE role [, ...] FROM
+/// role [, ...]` statement from its tokens, without the trailing semicolon.
+///
+/// Only the memberships are tracked: the `WITH ... OPTION` and `GRANTED BY`
+/// clauses of `GRANT`, as well as the `GRANTED BY` clause of `REVOKE`, are
+/// accepted and listed as dropped clauses, so that they are reported when the
+/// statement is applied. The `CASCADE` and `RESTRICT` clauses of `REVOKE`
+/// are accepted silently, as they do not affect the memberships. Revoking an option of a membership
+/// with `REVOKE ... OPTION FOR` is not supported, and left to the parser.
+fn parse_role_membership(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Option<UnparsedStatement>, ParserError> {
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(tokens.to_vec());
+    let grant = match parser.parse_one_of_keywords(&[Keyword::GRANT, Keyword::REVOKE]) {
+        Some(Keyword::GRANT) => true,
+        Some(_) => false,
+        None => return Ok(None),
+    };
+    if let Token::Word(word) = parser.peek_token().token
+        && word.quote_style.is_none()
+        && PRIVILEGE_KEYWORDS.contains(&word.keyword)
+    {
+        return Ok(None);
+    }
+    let roles = parser.parse_comma_separated(Parser::parse_identifier)?;
+    parser.expect_keywords(&[if grant { Keyword::TO } else { Keyword::FROM }])?;
+    let members = parser.parse_comma_separated(Parser::parse_identifier)?;
+    let mut dropped_clauses = Vec::new();
+    if grant && parser.parse_keyword(Keyword::WITH) {
+        // `WITH ADMIN OPTION` or `WITH { ADMIN | INHERIT | SET } { OPTION |
+        // TRUE | FALSE } [, ...]`.
+        let options = parser.parse_comma_separated(|parser| {
+            let option = match parser.expect_one_of_keywords(&[
+                Keyword::ADMIN,
+                Keyword::INHERIT,
+                Keyword::SET,
+            ])? {
+                Keyword::ADMIN => "ADMIN",
+                Keyword::INHERIT => "INHERIT",
+                _ => "SET",
+            };
+            let value = match parser.expect_one_of_keywords(&[
+                Keyword::OPTION,
+                Keyword::TRUE,
+                Keyword::FALSE,
+            ])? {
+                Keyword::OPTION => "OPTION",
+                Keyword::TRUE => "TRUE",
+                _ => "FALSE",
+            };
+            Ok(format!("{option} {value}"))
+        })?;
+        dropped_clauses.push(format!("WITH {}", options.join(", ")));
+    }
+    if parser.parse_keywords(&[Keyword::GRANTED, Keyword::BY]) {
+        dropped_clauses.push(format!("GRANTED BY {}", parser.parse_identifier()?));
+    }
+    if !grant {
+        let _ = parser.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]);
+    }
+    parser.expect_token(&Token::EOF)?;
+    Ok(Some(UnparsedStatement::RoleMembership { grant, roles, members, dropped_clauses }))
+}
+
+/// Parses a privilege on tables, as listed by `ALTER DEFAULT PRIVILEGES`.
+fn parse_table_privilege(parser: &mut Parser<'_>) -> Result<TablePrivilege, ParserError> {
+    Ok(
+        match parser.expect_one_of_keywords(&[
+            Keyword::SELECT,
+            Keyword::INSERT,
+            Keyword::UPDATE,
+            Keyword::DELETE,
+            Keyword::TRUNCATE,
+            Keyword::REFERENCES,
+            Keyword::TRIGGER,
+        ])? {
+            Keyword::SELECT => TablePrivilege::Select,
+            Keyword::INSERT => TablePrivilege::Insert,
+            Keyword::UPDATE => TablePrivilege::Update,
+            Keyword::DELETE => TablePrivilege::Delete,
+            Keyword::TRUNCATE => TablePrivilege::Truncate,
+            Keyword::REFERENCES => TablePrivilege::References,
+            _ => TablePrivilege::Trigger,
+        },
+    )
+}
+
+/// Parses an `ALTER DEFAULT PRIVILEGES [FOR ROLE role [, ...]] [IN SCHEMA
+/// schema [, ...]] { GRANT | REVOKE } ... ON TABLES ...` statement from its
+/// tokens, without the trailing semicolon.
+///
+/// Returns `None` for any other statement, including the default privileges
+/// on other objects than tables, which are left to the parser.
+fn parse_default_privileges(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Option<UnparsedStatement>, ParserError> {
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(tokens.to_vec());
+    if !parser.parse_keywords(&[Keyword::ALTER, Keyword::DEFAULT, Keyword::PRIVILEGES]) {
+        return Ok(None);
+    }
+    let mut target_roles = Vec::new();
+    let mut schemas = Vec::new();
+    loop {
+        if parser.parse_keyword(Keyword::FOR) {
+            parser.expect_one_of_keywords(&[Keyword::ROLE, Keyword::USER])?;
+            target_roles.extend(parser.parse_comma_separated(Parser::parse_identifier)?);
+        } else if parser.parse_keywords(&[Keyword::IN, Keyword::SCHEMA]) {
+            schemas.extend(parser.parse_comma_separated(Parser::parse_identifier)?);
+        } else {
+            break;
+        }
+    }
+    let grant =
+        parser.expect_one_of_keywords(&[Keyword::GRANT, Keyword::REVOKE])? == Keyword::GRANT;
+    let grant_option_for =
+        !grant && parser.parse_keywords(&[Keyword::GRANT, Keyword::OPTION, Keyword::FOR]);
+    let privileges = if parser.parse_keyword(Keyword::ALL) {
+        let _ = parser.parse_keyword(Keyword::PRIVILEGES);
+        TablePrivilege::ALL.to_vec()
+    } else {
+        parser.parse_comma_separated(parse_table_privilege)?
+    };
+    parser.expect_keywords(&[Keyword::ON])?;
+    if !parser.parse_keyword(Keyword::TABLES) {
+        return Ok(None);
+    }
+    parser.expect_keywords(&[if grant { Keyword::TO } else { Keyword::FROM }])?;
+    let grantees = parser.parse_comma_separated(|parser| {
+        let _ = parser.parse_keyword(Keyword::GROUP);
+        parser.parse_identifier()
+    })?;
+    let grantable = if grant {
+        parser.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION])
+    } else {
+        let _ = parser.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]);
+        grant_option_for
+    };
+    parser.expect_token(&Token::EOF)?;
+    let default_privilege = DefaultPrivilege::new(privileges, grantees)
+        .with_target_roles(target_roles)
+        .with_schemas(schemas)
+        .with_grantable(grantable);
+    Ok(Some(UnparsedStatement::DefaultPrivileges { grant, default_privilege }))
+}
+
+/// Parses a statement which `sqlparser` cannot parse but whose effect on the
+/// schema is tracked, returning `None` for any other statement.
+fn parse_unparsed(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Option<UnparsedStatement>, ParserError> {
+    if let Some((name, change)) = index_settings::parse_index_settings(dialect, tokens)? {
+        return Ok(Some(UnparsedStatement::IndexSettings { name, change }));
+    }
+    if let Some(statement) = parse_role_membership(dialect, tokens)? {
+        return Ok(Some(statement));
+    }
+    if let Some(statement) = parse_default_privileges(dialect, tokens)? {
+        return Ok(Some(statement));
+    }
+    parse_function_comment(dialect, tokens)
+}
+
+/// Blanks out the statements which `sqlparser` cannot parse but whose effect
+/// on the schema is tracked, returning the SQL without them along with the
+/// stripped statements.
+///
+/// The statements are replaced by whitespace, so that the locations of the
+/// remaining tokens are preserved. Statements which cannot be parsed are
+/// kept, leaving the parser to report the error.
+pub(super) fn strip_unparsed_statements(
+    dialect: &dyn Dialect,
+    sql: &str,
+) -> (String, Vec<Unparsed>) {
+    let mut stripped = String::from(sql);
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return (stripped, Vec::new());
+    };
+    let tokens: Vec<TokenWithSpan> =
+        tokens.into_iter().filter(|token| !matches!(token.token, Token::Whitespace(_))).collect();
+
+    let mut unparsed = Vec::new();
+    let mut blanked = Vec::new();
+    let mut position = 0;
+    let mut at_statement_start = true;
+    let mut start = 0;
+    while start < tokens.len() {
+        if tokens[start].token == Token::SemiColon {
+            if !at_statement_start {
+                position += 1;
+            }
+            at_statement_start = true;
+            start += 1;
+            continue;
+        }
+        let end = tokens[start..]
+            .iter()
+            .position(|token| token.token == Token::SemiColon)
+            .map_or(tokens.len(), |end| start + end);
+        if at_statement_start
+            && let Ok(Some(statement)) = parse_unparsed(dialect, &tokens[start..end])
+        {
+            let last = tokens.get(end).unwrap_or(&tokens[end - 1]);
+            if let (Some(from), Some(to)) =
+                (byte_offset(sql, tokens[start].span.start), byte_offset(sql, last.span.end))
+            {
+                blanked.push(from..to);
+                unparsed.push(Unparsed { position, statement });
+                start = end + 1;
+                continue;
+            }
+        }
+        at_statement_start = false;
+        start = end;
+    }
+
+    // Ranges are blanked from the last one, so that replacing multi-byte
+    // characters does not shift the following ranges.
+    for range in blanked.into_iter().rev() {
+        let blank: String =
+            sql[range.clone()].chars().map(|c| if c == '\n' { '\n' } else { ' ' }).collect();
+        stripped.replace_range(range, &blank);
+    }
+    (stripped, unparsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::{
+        IndexSettingsChange, TablePrivilege, UnparsedStatement, strip_unparsed_statements,
+    };
+
+    #[test]
+    fn test_unparsed_statements_are_stripped_in_place() {
+        let sql = "CREATE TABLE t (id INT);\nALTER INDEX idx SET (fillfactor = 70);;\nALTER INDEX idx RENAME TO other;\nALTER INDEX other RESET (fillfactor)";
+        let (stripped, unparsed) = strip_unparsed_statements(&PostgreSqlDialect {}, sql);
+        assert_eq!(stripped.len(), sql.len());
+        assert_eq!(stripped.lines().count(), sql.lines().count());
+        let lines: Vec<&str> = stripped.lines().map(str::trim).collect();
+        assert_eq!(
+            lines,
+            ["CREATE TABLE t (id INT);", ";", "ALTER INDEX idx RENAME TO other;", ""]
+        );
+
+        assert_eq!(unparsed.len(), 2);
+        assert_eq!(unparsed[0].position, 1);
+        assert!(matches!(
+            &unparsed[0].statement,
+            UnparsedStatement::IndexSettings { name, change: IndexSettingsChange::Set(parameters) }
+                if name.to_string() == "idx" && parameters.len() == 1
+        ));
+        assert_eq!(unparsed[1].position, 2);
+        assert!(matches!(
+            &unparsed[1].statement,
+            UnparsedStatement::IndexSettings { change: IndexSettingsChange::Reset(names), .. }
+                if names.len() == 1 && names[0].value == "fillfactor"
+        ));
+    }
+
+    #[test]
+    fn test_role_memberships_are_stripped() {
+        let sql = "GRANT admin, \"Auditors\" TO alice WITH INHERIT FALSE, SET TRUE;
+                   GRANT SELECT ON t TO alice;
+                   REVOKE admin FROM alice CASCADE;
+                   REVOKE ADMIN OPTION FOR admin FROM alice;";
+        let (stripped, unparsed) = strip_unparsed_statements(&PostgreSqlDialect {}, sql);
+        let lines: Vec<&str> =
+            stripped.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        assert_eq!(
+            lines,
+            ["GRANT SELECT ON t TO alice;", "REVOKE ADMIN OPTION FOR admin FROM alice;"]
+        );
+        assert_eq!(unparsed.len(), 2);
+        assert!(matches!(
+            &unparsed[0].statement,
+            UnparsedStatement::RoleMembership { grant: true, roles, members, dropped_clauses }
+                if roles.len() == 2
+                    && roles[1].quote_style.is_some()
+                    && members.len() == 1
+                    && dropped_clauses == &["WITH INHERIT FALSE, SET TRUE"]
+        ));
+        assert_eq!(unparsed[1].position, 1);
+        assert!(matches!(
+            &unparsed[1].statement,
+            UnparsedStatement::RoleMembership { grant: false, .. }
+        ));
+    }
+
+    #[test]
+    fn test_default_privileges_are_stripped() {
+        let sql = "ALTER DEFAULT PRIVILEGES FOR ROLE owner IN SCHEMA app
+                       GRANT ALL PRIVILEGES ON TABLES TO GROUP staff, PUBLIC WITH GRANT OPTION;
+                   ALTER DEFAULT PRIVILEGES REVOKE GRANT OPTION FOR SELECT ON TABLES FROM staff;
+                   ALTER DEFAULT PRIVILEGES GRANT USAGE ON SEQUENCES TO staff;";
+        let (stripped, unparsed) = strip_unparsed_statements(&PostgreSqlDialect {}, sql);
+        assert_eq!(stripped.trim(), "ALTER DEFAULT PRIVILEGES GRANT USAGE ON SEQUENCES TO staff;");
+        assert_eq!(unparsed.len(), 2);
+        assert!(matches!(
+            &unparsed[0].statement,
+            UnparsedStatement::DefaultPrivileges { grant: true, default_privilege }
+                if default_privilege.target_role_idents().len() == 1
+                    && default_privilege.schema_idents().len() == 1
+                    && default_privilege.table_privileges() == TablePrivilege::ALL
+                    && default_privilege.grantee_idents().len() == 2
+                    && default_privilege.is_grantable()
+        ));
+        assert!(matches!(
+            &unparsed[1].statement,
+            UnparsedStatement::DefaultPrivileges { grant: false, default_privilege }
+                if default_privilege.table_privileges() == [TablePrivilege::Select]
+                    && default_privilege.is_grantable()
+        ));
+    }
+
+    #[test]
+    fn test_function_comments_are_stripped() {
+        let sql = "COMMENT ON FUNCTION add(INT, INT) IS 'Adds two integers';
+                   COMMENT ON FUNCTION greet IS NULL;
+                   COMMENT ON TABLE t IS 'kept';";
+        let (stripped, unparsed) = strip_unparsed_statements(&PostgreSqlDialect {}, sql);
+        assert_eq!(stripped.trim(), "COMMENT ON TABLE t IS 'kept';");
+        assert_eq!(unparsed.len(), 2);
+        assert!(unparsed.iter().all(|unparsed| unparsed.position == 0));
+        assert!(matches!(
+            &unparsed[0].statement,
+            UnparsedStatement::FunctionComment { name, arguments: Some(arguments), comment }
+                if name.to_string() == "add"
+                    && arguments.len() == 2
+                    && comment.as_deref() == Some("Adds two integers")
+        ));
+        assert!(matches!(
+            &unparsed[1].statement,
+            UnparsedStatement::FunctionComment { arguments: None, comment: None, .. }
+        ));
+    }
+}
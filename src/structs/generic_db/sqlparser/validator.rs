@@ -0,0 +1,115 @@
+//! Handling of the validation errors raised while building a database,
+//! according to the severity set by the [`ParserOptions`].
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::tokenizer::Span;
+
+use crate::{
+    errors::Error,
+    structs::{
+        BuiltinRegistry, ExtensionStrategy, ParserOptions, StatementHandlers, ValidationClass,
+        ValidationLevel, ValidationWarning,
+    },
+};
+
+/// Decides whether the validation errors abort the parse, are downgraded to
+/// warnings or are collected to be reported all at once.
+pub(super) struct Validator {
+    /// Severity of each class of validation errors.
+    options: ParserOptions,
+    /// Errors collected in place of aborting the parse, if they are collected,
+    /// along with the span of the statement raising them.
+    errors: Option<Vec<(Error, Option<Span>)>>,
+    /// Span of the statement being applied, if known and errors are
+    /// collected.
+    statement_span: Option<Span>,
+}
+
+impl Validator {
+    /// Creates a validator aborting the parse on the first error the options
+    /// do not downgrade.
+    pub(super) fn aborting(options: ParserOptions) -> Self {
+        Self { options, errors: None, statement_span: None }
+    }
+
+    /// Creates a validator collecting the errors the options do not
+    /// downgrade, so that the parse goes on.
+    pub(super) fn collecting(options: ParserOptions) -> Self {
+        Self { options, errors: Some(Vec::new()), statement_span: None }
+    }
+
+    /// Returns the severity of the errors of the provided class.
+    pub(super) fn level(&self, class: ValidationClass) -> ValidationLevel {
+        self.options.level(class)
+    }
+
+    /// Returns the rule detecting the foreign keys defining table extensions.
+    pub(super) fn extension_strategy(&self) -> &ExtensionStrategy {
+        self.options.extension_strategy()
+    }
+
+    /// Returns the built-in functions added to the database, if they differ
+    /// from those of the dialect.
+    pub(super) fn builtin_registry(&self) -> Option<&BuiltinRegistry> {
+        self.options.builtin_registry()
+    }
+
+    /// Returns the handlers applying the statements of given kinds.
+    pub(super) fn statement_handlers(&self) -> &StatementHandlers {
+        self.options.statement_handlers()
+    }
+
+    /// Returns the schemas searched, in order, for the objects referred to
+    /// without a schema.
+    pub(super) fn search_path(&self) -> &[String] {
+        self.options.search_path()
+    }
+
+    /// Returns whether the foreign keys referencing missing tables or columns
+    /// are kept unresolved rather than rejected.
+    pub(super) fn defers_foreign_keys(&self) -> bool {
+        self.options.defers_foreign_keys()
+    }
+
+    /// Returns whether every validation error aborts the parse, in which
+    /// case failing statements need not be rolled back.
+    pub(super) fn is_strict(&self) -> bool {
+        self.errors.is_none() && self.options.is_strict()
+    }
+
+    /// Sets the span of the statement about to be applied, which is only
+    /// computed when errors are collected.
+    pub(super) fn enter_statement(&mut self, span: impl FnOnce() -> Span) {
+        self.statement_span =
+            self.errors.as_ref().map(|_| span()).filter(|span| *span != Span::empty());
+    }
+
+    /// Clears the span of the statement being applied, for the errors not
+    /// raised by a parsed statement.
+    pub(super) fn leave_statement(&mut self) {
+        self.statement_span = None;
+    }
+
+    /// Returns the provided error if it aborts the parse, and otherwise the
+    /// warning to record in its place, if any.
+    pub(super) fn handle(&mut self, error: Error) -> Result<Option<ValidationWarning>, Error> {
+        match (ValidationClass::of(&error), self.options.level_of(&error)) {
+            (Some(class), ValidationLevel::Warn) => Ok(Some(ValidationWarning::new(class, &error))),
+            (_, ValidationLevel::Ignore) => Ok(None),
+            _ => {
+                let Some(errors) = &mut self.errors else {
+                    return Err(error);
+                };
+                errors.push((error, self.statement_span));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the collected errors, along with the span of the statement
+    /// raising them.
+    pub(super) fn into_errors(self) -> Vec<(Error, Option<Span>)> {
+        self.errors.unwrap_or_default()
+    }
+}
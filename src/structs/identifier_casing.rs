@@ -0,0 +1,127 @@
+//! Submodule defining the `IdentifierCasing` enum, describing how the
+//! identifiers of a dialect are compared.
+
+use alloc::borrow::Cow;
+
+use crate::utils::identifier_resolution::{normalize_identifier, parse_lookup_identifier};
+
+/// Rule comparing the identifiers of a dialect, as returned by
+/// [`DialectLike::identifier_casing`](crate::traits::DialectLike::identifier_casing).
+///
+/// Lookups and duplicate checks compare names through the rule of the
+/// dialect of the database, so that `Users`, `users` and `"Users"` designate
+/// the same objects as they would in the database itself.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::IdentifierCasing;
+///
+/// // PostgreSQL folds unquoted identifiers to lowercase.
+/// assert!(IdentifierCasing::Lowercase.matches("Users", false, "users", true));
+/// assert!(!IdentifierCasing::Lowercase.matches("Users", true, "users", false));
+/// // Oracle and Snowflake fold them to uppercase.
+/// assert!(IdentifierCasing::Uppercase.matches("users", false, "USERS", true));
+/// assert!(!IdentifierCasing::Uppercase.matches("users", false, "users", true));
+/// // MySQL, SQL Server and SQLite ignore case, even within quotes.
+/// assert!(IdentifierCasing::Insensitive.matches("Users", true, "users", true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum IdentifierCasing {
+    /// Unquoted identifiers are folded to lowercase, while quoted ones match
+    /// exactly, as in PostgreSQL.
+    #[default]
+    Lowercase,
+    /// Unquoted identifiers are folded to uppercase, while quoted ones match
+    /// exactly, as in Oracle and Snowflake.
+    Uppercase,
+    /// Identifiers match ignoring case, whether quoted or not, as in MySQL,
+    /// SQL Server and SQLite.
+    Insensitive,
+}
+
+impl IdentifierCasing {
+    /// Returns the form under which the provided identifier is compared.
+    ///
+    /// Surrounding whitespace is trimmed and the identifier is normalized to
+    /// Unicode NFC before case folding, as done by
+    /// [`normalize_identifier`] for the lowercase rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The identifier, without quotes.
+    /// * `quoted` - Whether the identifier is quoted.
+    #[must_use]
+    pub fn normalize(self, value: &str, quoted: bool) -> Cow<'_, str> {
+        match self {
+            Self::Lowercase => normalize_identifier(value, quoted),
+            Self::Uppercase if quoted => normalize_identifier(value, true),
+            Self::Uppercase => Cow::Owned(normalize_identifier(value, true).to_ascii_uppercase()),
+            Self::Insensitive => normalize_identifier(value, false),
+        }
+    }
+
+    /// Returns whether two identifiers designate the same object.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The first identifier, without quotes.
+    /// * `left_quoted` - Whether the first identifier is quoted.
+    /// * `right` - The second identifier, without quotes.
+    /// * `right_quoted` - Whether the second identifier is quoted.
+    #[must_use]
+    pub fn matches(self, left: &str, left_quoted: bool, right: &str, right_quoted: bool) -> bool {
+        self.normalize(left, left_quoted) == self.normalize(right, right_quoted)
+    }
+
+    /// Returns whether a stored identifier matches a textual lookup, where a
+    /// lookup wrapped in double quotes is a quoted identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `stored` - The stored identifier, without quotes.
+    /// * `stored_quoted` - Whether the stored identifier is quoted.
+    /// * `lookup` - The identifier looked up.
+    #[must_use]
+    pub fn matches_lookup(self, stored: &str, stored_quoted: bool, lookup: &str) -> bool {
+        let lookup = parse_lookup_identifier(lookup);
+        self.matches(stored, stored_quoted, lookup.value(), lookup.is_quoted())
+    }
+
+    /// Returns the provided schema, or `None` if it is the `public` schema
+    /// unqualified objects belong to.
+    pub(crate) fn non_default_schema(self, schema: Option<&str>, quoted: bool) -> Option<&str> {
+        schema.filter(|schema| !self.matches(schema, quoted, "public", false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lowercase_follows_postgres_rules() {
+        // Unquoted identifiers on both sides are folded.
+        assert!(IdentifierCasing::Lowercase.matches("Foo", false, "foo", false));
+        // Quoted identifiers keep their case.
+        assert!(IdentifierCasing::Lowercase.matches("foo", false, "foo", true));
+        assert!(!IdentifierCasing::Lowercase.matches("Foo", false, "Foo", true));
+        assert!(!IdentifierCasing::Lowercase.matches("Foo", true, "foo", true));
+
+        assert!(IdentifierCasing::Lowercase.matches_lookup("Foo", false, "\"foo\""));
+        assert!(!IdentifierCasing::Lowercase.matches_lookup("Foo", false, "\"Foo\""));
+        assert!(IdentifierCasing::Lowercase.matches_lookup("Foo", true, "\"Foo\""));
+        assert!(!IdentifierCasing::Lowercase.matches_lookup("Foo", true, "foo"));
+    }
+
+    #[test]
+    fn test_lookups_follow_the_casing() {
+        assert!(IdentifierCasing::Lowercase.matches_lookup("users", false, "USERS"));
+        assert!(!IdentifierCasing::Lowercase.matches_lookup("users", false, "\"USERS\""));
+        assert!(IdentifierCasing::Uppercase.matches_lookup("users", false, "\"USERS\""));
+        assert!(!IdentifierCasing::Uppercase.matches_lookup("Users", true, "users"));
+        assert!(IdentifierCasing::Insensitive.matches_lookup("Users", true, "\"USERS\""));
+        assert_eq!(IdentifierCasing::Uppercase.normalize(" users ", false), "USERS");
+        assert_eq!(IdentifierCasing::Insensitive.normalize("Users", true), "users");
+    }
+}
@@ -10,3 +10,5 @@ mod check_metadata;
 pub use check_metadata::CheckMetadata;
 mod policy_metadata;
 pub use policy_metadata::PolicyMetadata;
+mod function_metadata;
+pub use function_metadata::FunctionMetadata;
@@ -4,7 +4,10 @@ use alloc::{sync::Arc, vec::Vec};
 
 use sqlparser::ast::Expr;
 
-use crate::traits::{CheckConstraintLike, DatabaseLike};
+use crate::{
+    structs::SourceLocation,
+    traits::{CheckConstraintLike, DatabaseLike},
+};
 
 #[derive(Debug, Clone)]
 /// Struct collecting metadata about a check constraint.
@@ -17,6 +20,10 @@ pub struct CheckMetadata<U: CheckConstraintLike> {
     columns: Vec<Arc<<U::DB as DatabaseLike>::Column>>,
     /// The functions involved in the constraint.
     functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
+    /// Whether the constraint is declared `NO INHERIT`.
+    no_inherit: bool,
+    /// The location of the statement creating the constraint, if known.
+    source_location: Option<SourceLocation>,
 }
 
 impl<U: CheckConstraintLike> CheckMetadata<U> {
@@ -28,7 +35,7 @@ impl<U: CheckConstraintLike> CheckMetadata<U> {
         columns: Vec<Arc<<U::DB as DatabaseLike>::Column>>,
         functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
     ) -> Self {
-        Self { expression, table, columns, functions }
+        Self { expression, table, columns, functions, no_inherit: false, source_location: None }
     }
 
     /// Returns a reference to the expression defining the constraint.
@@ -45,6 +52,20 @@ impl<U: CheckConstraintLike> CheckMetadata<U> {
         &self.table
     }
 
+    /// Returns whether the constraint is declared `NO INHERIT`, and is thus
+    /// not enforced on the tables inheriting from its table.
+    #[must_use]
+    #[inline]
+    pub fn no_inherit(&self) -> bool {
+        self.no_inherit
+    }
+
+    /// Updates whether the constraint is declared `NO INHERIT`.
+    #[inline]
+    pub fn set_no_inherit(&mut self, no_inherit: bool) {
+        self.no_inherit = no_inherit;
+    }
+
     /// Returns an iterator over the columns involved in the constraint.
     ///
     /// # Example
@@ -93,4 +114,21 @@ impl<U: CheckConstraintLike> CheckMetadata<U> {
     pub fn functions(&self) -> impl Iterator<Item = &<U::DB as DatabaseLike>::Function> {
         self.functions.iter().map(core::convert::AsRef::as_ref)
     }
+
+    /// Returns the location of the statement creating the constraint, if known.
+    #[must_use]
+    #[inline]
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Sets the location of the statement creating the constraint.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_location` - The new location of the constraint.
+    #[inline]
+    pub fn set_source_location(&mut self, source_location: Option<SourceLocation>) {
+        self.source_location = source_location;
+    }
 }
@@ -0,0 +1,99 @@
+//! Submodule defining the `FunctionMetadata` struct.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::ObjectName;
+
+use crate::structs::SourceLocation;
+
+#[derive(Debug, Clone, Default)]
+/// Struct collecting metadata about a function.
+pub struct FunctionMetadata {
+    /// The comment set on the function by `COMMENT ON FUNCTION`.
+    comment: Option<String>,
+    /// The location of the statement creating the function, if known.
+    source_location: Option<SourceLocation>,
+    /// Whether the function is a built-in function added by the parser.
+    builtin: bool,
+    /// The names of the relations the body of the function reads or writes,
+    /// as written in the body, or `None` if they are not known.
+    referenced_relations: Option<Vec<ObjectName>>,
+}
+
+impl FunctionMetadata {
+    /// Returns the comment set on the function, if any.
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Sets the comment of the function, removing it when `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The new comment of the function.
+    #[inline]
+    pub fn set_comment(&mut self, comment: Option<String>) {
+        self.comment = comment;
+    }
+
+    /// Returns the location of the statement creating the function, if known.
+    #[must_use]
+    #[inline]
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Sets the location of the statement creating the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_location` - The new location of the function.
+    #[inline]
+    pub fn set_source_location(&mut self, source_location: Option<SourceLocation>) {
+        self.source_location = source_location;
+    }
+
+    /// Returns whether the function is a built-in function added by the
+    /// parser.
+    #[must_use]
+    #[inline]
+    pub fn is_builtin(&self) -> bool {
+        self.builtin
+    }
+
+    /// Sets whether the function is a built-in function added by the parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `builtin` - Whether the function is built-in.
+    #[inline]
+    pub fn set_builtin(&mut self, builtin: bool) {
+        self.builtin = builtin;
+    }
+
+    /// Returns the names of the relations the body of the function reads or
+    /// writes, as written in the body, or `None` if they are not known, as
+    /// is the case when the body cannot be parsed.
+    ///
+    /// The names are collected once, when the function is created, and are
+    /// resolved against the tables of the database when these are dropped.
+    #[must_use]
+    #[inline]
+    pub fn referenced_relations(&self) -> Option<&[ObjectName]> {
+        self.referenced_relations.as_deref()
+    }
+
+    /// Sets the names of the relations the body of the function reads or
+    /// writes.
+    ///
+    /// # Arguments
+    ///
+    /// * `referenced_relations` - The names of the relations, or `None` if
+    ///   they are not known.
+    #[inline]
+    pub fn set_referenced_relations(&mut self, referenced_relations: Option<Vec<ObjectName>>) {
+        self.referenced_relations = referenced_relations;
+    }
+}
@@ -4,7 +4,10 @@ use alloc::sync::Arc;
 
 use sqlparser::ast::Expr;
 
-use crate::traits::{DatabaseLike, IndexLike};
+use crate::{
+    structs::SourceLocation,
+    traits::{DatabaseLike, IndexLike},
+};
 
 #[derive(Debug, Clone)]
 /// Struct collecting metadata about an index.
@@ -13,13 +16,17 @@ pub struct IndexMetadata<I: IndexLike> {
     expression: Expr,
     /// The table on which the index is defined.
     table: Arc<<I::DB as DatabaseLike>::Table>,
+    /// The location of the statement creating the index, if known.
+    source_location: Option<SourceLocation>,
+    /// Whether the index is declared `CLUSTERED`.
+    clustered: bool,
 }
 
 impl<I: IndexLike> IndexMetadata<I> {
     /// Creates a new `IndexMetadata` instance.
     #[inline]
     pub fn new(expression: Expr, table: Arc<<I::DB as DatabaseLike>::Table>) -> Self {
-        Self { expression, table }
+        Self { expression, table, source_location: None, clustered: false }
     }
 
     /// Returns a reference to the expression defining the index.
@@ -68,6 +75,41 @@ impl<I: IndexLike> IndexMetadata<I> {
     pub fn table(&self) -> &<I::DB as DatabaseLike>::Table {
         &self.table
     }
+
+    /// Returns the location of the statement creating the index, if known.
+    #[must_use]
+    #[inline]
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Returns whether the index is declared `CLUSTERED`, as in SQL Server's
+    /// `CREATE CLUSTERED INDEX`.
+    #[must_use]
+    #[inline]
+    pub fn clustered(&self) -> bool {
+        self.clustered
+    }
+
+    /// Sets whether the index is declared `CLUSTERED`.
+    ///
+    /// # Arguments
+    ///
+    /// * `clustered` - Whether the index is clustered.
+    #[inline]
+    pub fn set_clustered(&mut self, clustered: bool) {
+        self.clustered = clustered;
+    }
+
+    /// Sets the location of the statement creating the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_location` - The new location of the index.
+    #[inline]
+    pub fn set_source_location(&mut self, source_location: Option<SourceLocation>) {
+        self.source_location = source_location;
+    }
 }
 
 /// Type alias for `IndexMetadata` to be used with unique indices.
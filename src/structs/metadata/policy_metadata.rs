@@ -2,7 +2,10 @@
 
 use alloc::{sync::Arc, vec::Vec};
 
-use crate::traits::{DatabaseLike, PolicyLike};
+use crate::{
+    structs::SourceLocation,
+    traits::{DatabaseLike, PolicyLike},
+};
 
 #[derive(Debug, Clone)]
 /// Struct collecting metadata about a policy.
@@ -11,6 +14,8 @@ pub struct PolicyMetadata<U: PolicyLike> {
     using_functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
     /// The functions involved in the check expression.
     check_functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
+    /// The location of the statement creating the policy, if known.
+    source_location: Option<SourceLocation>,
 }
 
 impl<U: PolicyLike> PolicyMetadata<U> {
@@ -21,7 +26,7 @@ impl<U: PolicyLike> PolicyMetadata<U> {
         using_functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
         check_functions: Vec<Arc<<U::DB as DatabaseLike>::Function>>,
     ) -> Self {
-        Self { using_functions, check_functions }
+        Self { using_functions, check_functions, source_location: None }
     }
 
     /// Returns an iterator over the functions involved in the using expression.
@@ -35,4 +40,21 @@ impl<U: PolicyLike> PolicyMetadata<U> {
     pub fn check_functions(&self) -> impl Iterator<Item = &<U::DB as DatabaseLike>::Function> {
         self.check_functions.iter().map(core::convert::AsRef::as_ref)
     }
+
+    /// Returns the location of the statement creating the policy, if known.
+    #[must_use]
+    #[inline]
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Sets the location of the statement creating the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_location` - The new location of the policy.
+    #[inline]
+    pub fn set_source_location(&mut self, source_location: Option<SourceLocation>) {
+        self.source_location = source_location;
+    }
 }
@@ -1,8 +1,11 @@
 //! Submodule defining a generic `TableMetadata` struct.
 
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{string::String, sync::Arc, vec::Vec};
 
-use crate::traits::{DatabaseLike, DocumentationMetadata, TableLike};
+use crate::{
+    structs::SourceLocation,
+    traits::{DatabaseLike, DocumentationMetadata, TableLike},
+};
 
 #[derive(Debug, Clone)]
 /// Metadata about a database table.
@@ -17,8 +20,15 @@ pub struct TableMetadata<T: TableLike> {
     unique_indices: Vec<Arc<<T::DB as DatabaseLike>::UniqueIndex>>,
     /// The foreign keys of the table.
     foreign_keys: Vec<Arc<<T::DB as DatabaseLike>::ForeignKey>>,
+    /// The foreign keys of the table referencing tables excluded from the
+    /// database by an object filter.
+    external_foreign_keys: Vec<Arc<<T::DB as DatabaseLike>::ForeignKey>>,
+    /// The primary key of the table, if it has one.
+    primary_key: Option<Arc<<T::DB as DatabaseLike>::PrimaryKey>>,
     /// The columns composing the primary key of the table.
-    primary_key: Vec<Arc<<T::DB as DatabaseLike>::Column>>,
+    primary_key_columns: Vec<Arc<<T::DB as DatabaseLike>::Column>>,
+    /// The default privileges granted on the table when it was created.
+    default_privileges: Vec<Arc<<T::DB as DatabaseLike>::DefaultPrivilege>>,
     /// Whether Row Level Security is enabled for the table.
     rls_enabled: bool,
     /// Whether Row Level Security is forced for the table (applies to table
@@ -26,6 +36,13 @@ pub struct TableMetadata<T: TableLike> {
     rls_forced: bool,
     /// The optional documentation associated with the table
     documentation: Option<<T as DocumentationMetadata>::Documentation>,
+    /// The comment set on the table by `COMMENT ON TABLE`.
+    comment: Option<String>,
+    /// The comments set on the columns of the table by `COMMENT ON COLUMN`,
+    /// keyed by column name.
+    column_comments: Vec<(String, String)>,
+    /// The location of the statement creating the table, if known.
+    source_location: Option<SourceLocation>,
 }
 
 impl<T: TableLike> Default for TableMetadata<T> {
@@ -36,10 +53,16 @@ impl<T: TableLike> Default for TableMetadata<T> {
             indices: Vec::new(),
             unique_indices: Vec::new(),
             foreign_keys: Vec::new(),
-            primary_key: Vec::new(),
+            external_foreign_keys: Vec::new(),
+            primary_key: None,
+            primary_key_columns: Vec::new(),
+            default_privileges: Vec::new(),
             rls_enabled: false,
             rls_forced: false,
             documentation: None,
+            comment: None,
+            column_comments: Vec::new(),
+            source_location: None,
         }
     }
 }
@@ -155,11 +178,38 @@ impl<T: TableLike> TableMetadata<T> {
         self.foreign_keys.iter()
     }
 
+    /// Returns an iterator over the foreign keys of the table referencing
+    /// tables excluded from the database by an object filter.
+    ///
+    /// These external references are not validated and are not part of the
+    /// foreign keys of the table.
+    #[inline]
+    pub fn external_foreign_keys(
+        &self,
+    ) -> impl Iterator<Item = &<T::DB as DatabaseLike>::ForeignKey> {
+        self.external_foreign_keys.iter().map(core::convert::AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the default privileges granted on the table
+    /// when it was created, in declaration order.
+    #[inline]
+    pub fn default_privileges(
+        &self,
+    ) -> impl Iterator<Item = &<T::DB as DatabaseLike>::DefaultPrivilege> {
+        self.default_privileges.iter().map(core::convert::AsRef::as_ref)
+    }
+
+    /// Returns the primary key of the table, if it has one.
+    #[inline]
+    pub fn primary_key(&self) -> Option<&<T::DB as DatabaseLike>::PrimaryKey> {
+        self.primary_key.as_deref()
+    }
+
     /// Returns an iterator over the columns composing the primary key of the
-    /// table.
+    /// table, in the order of their declaration in the primary key.
     #[inline]
     pub fn primary_key_columns(&self) -> impl Iterator<Item = &<T::DB as DatabaseLike>::Column> {
-        self.primary_key.iter().map(core::convert::AsRef::as_ref)
+        self.primary_key_columns.iter().map(core::convert::AsRef::as_ref)
     }
 
     /// Returns the documentation, if exists, for the table
@@ -174,6 +224,42 @@ impl<T: TableLike> TableMetadata<T> {
         self.documentation = Some(s);
     }
 
+    /// Returns the comment set on the table by `COMMENT ON TABLE`, if any.
+    #[inline]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Sets the comment of the table, removing it when `None`.
+    #[inline]
+    pub fn set_comment(&mut self, comment: Option<String>) {
+        self.comment = comment;
+    }
+
+    /// Returns the comment set on the column with the provided name by
+    /// `COMMENT ON COLUMN`, if any.
+    #[inline]
+    pub fn column_comment(&self, column_name: &str) -> Option<&str> {
+        self.column_comments
+            .iter()
+            .find(|(name, _)| name == column_name)
+            .map(|(_, comment)| comment.as_str())
+    }
+
+    /// Sets the comment of the column with the provided name, removing it
+    /// when `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_name` - The name of the column, as stored.
+    /// * `comment` - The new comment of the column.
+    pub fn set_column_comment(&mut self, column_name: &str, comment: Option<String>) {
+        self.column_comments.retain(|(name, _)| name != column_name);
+        if let Some(comment) = comment {
+            self.column_comments.push((column_name.into(), comment));
+        }
+    }
+
     /// Adds a column to the table metadata.
     ///
     /// # Arguments
@@ -227,13 +313,49 @@ impl<T: TableLike> TableMetadata<T> {
         self.foreign_keys.push(fk);
     }
 
-    /// Sets the columns composing the primary key of the table.
+    /// Adds a foreign key referencing a table excluded from the database by
+    /// an object filter.
     ///
     /// # Arguments
     ///
+    /// * `fk` - The external foreign key to add.
+    #[inline]
+    pub fn add_external_foreign_key(&mut self, fk: Arc<<T::DB as DatabaseLike>::ForeignKey>) {
+        self.external_foreign_keys.push(fk);
+    }
+
+    /// Adds a default privilege granted on the table when it was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `default_privilege` - The default privilege to add.
+    #[inline]
+    pub fn add_default_privilege(
+        &mut self,
+        default_privilege: Arc<<T::DB as DatabaseLike>::DefaultPrivilege>,
+    ) {
+        self.default_privileges.push(default_privilege);
+    }
+
+    /// Sets the primary key of the table and the columns composing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary_key` - The primary key of the table.
     /// * `pk_columns` - The columns composing the primary key.
-    pub fn set_primary_key(&mut self, pk_columns: Vec<Arc<<T::DB as DatabaseLike>::Column>>) {
-        self.primary_key = pk_columns;
+    pub fn set_primary_key(
+        &mut self,
+        primary_key: Arc<<T::DB as DatabaseLike>::PrimaryKey>,
+        pk_columns: Vec<Arc<<T::DB as DatabaseLike>::Column>>,
+    ) {
+        self.primary_key = Some(primary_key);
+        self.primary_key_columns = pk_columns;
+    }
+
+    /// Removes the primary key of the table, if it has one.
+    pub fn clear_primary_key(&mut self) {
+        self.primary_key = None;
+        self.primary_key_columns.clear();
     }
 
     /// Removes indices that don't match the predicate.
@@ -247,4 +369,123 @@ impl<T: TableLike> TableMetadata<T> {
     {
         self.indices.retain(f);
     }
+
+    /// Replaces an index of the table, keeping its position among the
+    /// indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index to replace.
+    /// * `replacement` - The index replacing it.
+    pub fn replace_index(
+        &mut self,
+        index: &Arc<<T::DB as DatabaseLike>::Index>,
+        replacement: &Arc<<T::DB as DatabaseLike>::Index>,
+    ) {
+        for candidate in &mut self.indices {
+            if Arc::ptr_eq(candidate, index) {
+                *candidate = replacement.clone();
+            }
+        }
+    }
+
+    /// Removes columns that don't match the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate function that returns `true` for columns to keep.
+    pub fn retain_columns<F>(&mut self, f: F)
+    where
+        F: FnMut(&Arc<<T::DB as DatabaseLike>::Column>) -> bool,
+    {
+        self.columns.retain(f);
+    }
+
+    /// Replaces a column of the table, keeping its position among the columns
+    /// and in the primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The column to replace.
+    /// * `replacement` - The column replacing it.
+    pub fn replace_column(
+        &mut self,
+        column: &Arc<<T::DB as DatabaseLike>::Column>,
+        replacement: &Arc<<T::DB as DatabaseLike>::Column>,
+    ) {
+        for candidate in self.columns.iter_mut().chain(self.primary_key_columns.iter_mut()) {
+            if Arc::ptr_eq(candidate, column) {
+                *candidate = replacement.clone();
+            }
+        }
+    }
+
+    /// Removes check constraints that don't match the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate function that returns `true` for check constraints
+    ///   to keep.
+    pub fn retain_check_constraints<F>(&mut self, f: F)
+    where
+        F: FnMut(&Arc<<T::DB as DatabaseLike>::CheckConstraint>) -> bool,
+    {
+        self.check_constraints.retain(f);
+    }
+
+    /// Removes unique indices that don't match the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate function that returns `true` for unique indices to
+    ///   keep.
+    pub fn retain_unique_indices<F>(&mut self, f: F)
+    where
+        F: FnMut(&Arc<<T::DB as DatabaseLike>::UniqueIndex>) -> bool,
+    {
+        self.unique_indices.retain(f);
+    }
+
+    /// Removes foreign keys that don't match the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate function that returns `true` for foreign keys to
+    ///   keep.
+    pub fn retain_foreign_keys<F>(&mut self, f: F)
+    where
+        F: FnMut(&Arc<<T::DB as DatabaseLike>::ForeignKey>) -> bool,
+    {
+        self.foreign_keys.retain(f);
+    }
+
+    /// Removes external foreign keys that don't match the predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate function that returns `true` for external foreign
+    ///   keys to keep.
+    pub fn retain_external_foreign_keys<F>(&mut self, f: F)
+    where
+        F: FnMut(&Arc<<T::DB as DatabaseLike>::ForeignKey>) -> bool,
+    {
+        self.external_foreign_keys.retain(f);
+    }
+
+    /// Returns the location of the statement creating the table, if known.
+    #[must_use]
+    #[inline]
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Sets the location of the statement creating the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_location` - The new location of the table.
+    #[inline]
+    pub fn set_source_location(&mut self, source_location: Option<SourceLocation>) {
+        self.source_location = source_location;
+    }
 }
@@ -0,0 +1,371 @@
+//! Submodule defining the `NameRegistry` struct, listing the names occupying
+//! the relation namespace of each schema of a database.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{
+        DatabaseLike, DialectLike, IndexLike, Metadata, ObjectKind, SequenceLike, TableLike,
+        ViewLike,
+    },
+    utils::identifier_resolution::parse_lookup_identifier,
+};
+
+/// Name of the schema objects without an explicit schema belong to.
+const DEFAULT_SCHEMA: &str = "public";
+
+/// A name registered in the relation namespace of a schema.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegisteredName {
+    /// The normalized name of the schema the name belongs to.
+    schema: String,
+    /// The normalized name of the object.
+    name: String,
+    /// The kind of the object owning the name.
+    kind: ObjectKind,
+}
+
+impl RegisteredName {
+    /// Creates a new registered name, normalizing the provided identifiers
+    /// under the casing of the dialect.
+    fn new(
+        casing: IdentifierCasing,
+        schema: Option<&str>,
+        schema_quoted: bool,
+        name: &str,
+        quoted: bool,
+        kind: ObjectKind,
+    ) -> Self {
+        let schema = casing.normalize(schema.unwrap_or(DEFAULT_SCHEMA), schema_quoted).into_owned();
+        Self { schema, name: casing.normalize(name, quoted).into_owned(), kind }
+    }
+
+    /// Returns the normalized name of the schema the name belongs to.
+    /// Objects declared without a schema are reported under `public`, folded
+    /// as the dialect folds unquoted identifiers.
+    #[must_use]
+    #[inline]
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// Returns the normalized name of the object.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the object owning the name.
+    #[must_use]
+    #[inline]
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+}
+
+/// Registry of the names occupying the relation namespace of each schema.
+///
+/// In PostgreSQL tables, views, sequences and indexes, including the indexes
+/// backing named unique and primary key constraints, share a single
+/// namespace per schema: creating an index named like an existing table
+/// fails. The registry lists these names so that collisions can be detected,
+/// and so that names generated programmatically can be checked for
+/// availability. Check and foreign key constraint names are scoped to their
+/// table and are therefore not registered.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "
+/// CREATE TABLE users (id INT, CONSTRAINT users_pkey PRIMARY KEY (id));
+/// CREATE SEQUENCE users_id_seq;
+/// CREATE INDEX users_id_idx ON users (id);
+/// ",
+/// )?;
+/// let registry = db.name_registry();
+/// let names: Vec<&str> = registry.names(Some("public")).map(|name| name.name()).collect();
+/// assert_eq!(names, ["users", "users_id_idx", "users_id_seq", "users_pkey"]);
+/// assert!(registry.contains(None, "users_pkey"));
+/// assert!(!registry.contains(None, "users_email_key"));
+/// assert_eq!(registry.collisions().count(), 0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NameRegistry {
+    /// The registered names, sorted by schema and name.
+    names: Vec<RegisteredName>,
+    /// The rule comparing the identifiers of the dialect of the database.
+    casing: IdentifierCasing,
+}
+
+impl NameRegistry {
+    /// Builds the registry of the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database whose names to register.
+    pub fn new<DB: DatabaseLike>(database: &DB) -> Self {
+        let casing = database.dialect().identifier_casing();
+        let mut names = Vec::new();
+        for table in database.tables() {
+            names.push(RegisteredName::new(
+                casing,
+                table.table_schema(),
+                table.table_schema_is_quoted(),
+                table.table_name(),
+                table.table_name_is_quoted(),
+                table.object_kind(),
+            ));
+            for unique_index in table.unique_indices(database) {
+                if let Some(name) = unique_index.index_name() {
+                    names.push(RegisteredName::new(
+                        casing,
+                        table.table_schema(),
+                        table.table_schema_is_quoted(),
+                        name,
+                        unique_index.index_name_is_quoted(),
+                        unique_index.object_kind(),
+                    ));
+                }
+            }
+        }
+        for index in database.indexes() {
+            if let Some(name) = index.index_name() {
+                let table = index.table(database);
+                names.push(RegisteredName::new(
+                    casing,
+                    table.table_schema(),
+                    table.table_schema_is_quoted(),
+                    name,
+                    index.index_name_is_quoted(),
+                    index.object_kind(),
+                ));
+            }
+        }
+        for view in database.views() {
+            names.push(RegisteredName::new(
+                casing,
+                view.view_schema(),
+                view.view_schema_is_quoted(),
+                view.view_name(),
+                view.view_name_is_quoted(),
+                view.object_kind(),
+            ));
+        }
+        for sequence in database.sequences() {
+            names.push(RegisteredName::new(
+                casing,
+                sequence.schema(),
+                sequence.schema_is_quoted(),
+                sequence.name(),
+                sequence.name_is_quoted(),
+                sequence.object_kind(),
+            ));
+        }
+        names.sort_unstable();
+        Self { names, casing }
+    }
+
+    /// Iterates over every registered name, sorted by schema and name.
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredName> {
+        self.names.iter()
+    }
+
+    /// Returns the number of registered names.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether no name is registered.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterates over the schemas holding at least one registered name.
+    pub fn schemas(&self) -> impl Iterator<Item = &str> {
+        let mut previous: Option<&str> = None;
+        self.names.iter().filter_map(move |registered| {
+            let schema = registered.schema();
+            if previous == Some(schema) {
+                return None;
+            }
+            previous = Some(schema);
+            Some(schema)
+        })
+    }
+
+    /// Iterates over the names registered in the provided schema, sorted by
+    /// name.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema to list, `None` meaning `public`. Surround
+    ///   the name with double quotes to match it case-sensitively.
+    pub fn names(&self, schema: Option<&str>) -> impl Iterator<Item = &RegisteredName> {
+        let schema = match schema {
+            Some(schema) => self.normalized_lookup(schema),
+            None => self.casing.normalize(DEFAULT_SCHEMA, false).into_owned(),
+        };
+        self.names.iter().filter(move |registered| registered.schema() == schema)
+    }
+
+    /// Returns whether the provided name is already taken in the provided
+    /// schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema to search, `None` meaning `public`.
+    /// * `name` - The name to search. Surround it with double quotes to
+    ///   match it case-sensitively.
+    #[must_use]
+    pub fn contains(&self, schema: Option<&str>, name: &str) -> bool {
+        let name = self.normalized_lookup(name);
+        self.names(schema).any(|registered| registered.name() == name)
+    }
+
+    /// Iterates over the pairs of objects sharing the same name in the same
+    /// schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT); CREATE INDEX users ON users (id);",
+    /// )?;
+    /// let registry = db.name_registry();
+    /// let (first, second) = registry.collisions().next().unwrap();
+    /// assert_eq!(first.name(), "users");
+    /// assert_eq!((first.kind(), second.kind()), (ObjectKind::Table, ObjectKind::Index));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collisions(&self) -> impl Iterator<Item = (&RegisteredName, &RegisteredName)> {
+        self.names.windows(2).filter_map(|pair| {
+            let [first, second] = pair else { return None };
+            (first.schema() == second.schema() && first.name() == second.name())
+                .then_some((first, second))
+        })
+    }
+
+    /// Normalizes a textual lookup identifier under the casing of the
+    /// dialect, an identifier wrapped in double quotes being quoted.
+    fn normalized_lookup(&self, identifier: &str) -> String {
+        let lookup = parse_lookup_identifier(identifier);
+        self.casing.normalize(lookup.value(), lookup.is_quoted()).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
+
+    use crate::{
+        errors::Error,
+        structs::ParserDB,
+        traits::{DatabaseLike, ObjectKind},
+    };
+
+    #[test]
+    fn test_registry_groups_names_by_schema() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE SCHEMA app;
+            CREATE TABLE app.\"Users\" (id INT, CONSTRAINT \"Users_pkey\" PRIMARY KEY (id));
+            CREATE VIEW app.active_users AS SELECT id FROM app.\"Users\";
+            CREATE TABLE logs (id INT UNIQUE);
+            ",
+        )
+        .expect("schema should parse");
+        let registry = db.name_registry();
+
+        assert_eq!(registry.len(), 4);
+        assert_eq!(registry.schemas().collect::<Vec<_>>(), ["app", "public"]);
+        let app: Vec<(&str, ObjectKind)> =
+            registry.names(Some("app")).map(|name| (name.name(), name.kind())).collect();
+        assert_eq!(
+            app,
+            [
+                ("Users", ObjectKind::Table),
+                ("Users_pkey", ObjectKind::UniqueIndex),
+                ("active_users", ObjectKind::View),
+            ]
+        );
+        assert!(registry.contains(Some("app"), "\"Users\""));
+        assert!(!registry.contains(Some("app"), "users"));
+        assert!(registry.contains(None, "LOGS"));
+    }
+
+    #[test]
+    fn test_index_named_like_table_is_rejected() {
+        let result = ParserDB::parse::<PostgreSqlDialect>(
+            "CREATE TABLE users (id INT); CREATE TABLE orders (id INT); CREATE INDEX orders ON users (id);",
+        );
+        assert!(matches!(
+            result,
+            Err(Error::NameCollision {
+                first_kind: ObjectKind::Table,
+                second_kind: ObjectKind::Index,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_constraints_sharing_a_name_are_rejected() {
+        let result = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE a (id INT, CONSTRAINT same_key UNIQUE (id));
+            CREATE TABLE b (id INT, CONSTRAINT same_key UNIQUE (id));
+            ",
+        );
+        assert!(matches!(result, Err(Error::NameCollision { .. })));
+    }
+
+    #[test]
+    fn test_same_name_in_distinct_schemas_is_accepted() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE SCHEMA app;
+            CREATE TABLE users (id INT);
+            CREATE SEQUENCE app.users;
+            ",
+        )
+        .expect("names in distinct schemas should not collide");
+        assert_eq!(db.name_registry().collisions().count(), 0);
+    }
+
+    #[test]
+    fn test_collisions_are_only_rejected_for_postgres() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE users (id INT); CREATE INDEX users ON users (id);",
+        )
+        .expect("generic dialect should not validate the namespace");
+        assert_eq!(db.name_registry().collisions().count(), 1);
+    }
+
+    #[test]
+    fn test_names_follow_the_casing_of_the_dialect() {
+        let db = ParserDB::parse::<MySqlDialect>("CREATE TABLE `Users` (id INT);")
+            .expect("schema should parse");
+        let registry = db.name_registry();
+
+        // MySQL ignores case, even within quotes.
+        assert!(registry.contains(None, "\"USERS\""));
+        assert!(registry.contains(Some("PUBLIC"), "users"));
+    }
+}
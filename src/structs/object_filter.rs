@@ -0,0 +1,159 @@
+//! Submodule defining the `ObjectFilter` struct, used to restrict the schemas
+//! and tables loaded into a database.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::ObjectName;
+
+use crate::{
+    structs::IdentifierCasing,
+    utils::{
+        glob_match::glob_match,
+        object_name::{object_name_last_part, schema_from_object_name},
+    },
+};
+
+/// Filter selecting the schemas and tables to load into a database.
+///
+/// Patterns are globs supporting `*` and `?`, matched against identifiers
+/// normalized under the [`IdentifierCasing`] of the dialect: in PostgreSQL,
+/// unquoted names are folded to lowercase while quoted names are kept as
+/// written, in MySQL every name is folded to lowercase. A table pattern containing a `.` is matched against
+/// the qualified `schema.table` name, otherwise against the bare table name.
+/// Unqualified tables are considered part of the `public` schema.
+///
+/// An object is admitted when it matches at least one include pattern (or
+/// there are none) and no exclude pattern. The default filter admits
+/// everything.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::ObjectFilter;
+///
+/// let filter = ObjectFilter::default().include_schema("app").exclude_table("*_archive");
+/// assert!(filter.admits_table(Some("app"), "users"));
+/// assert!(!filter.admits_table(Some("app"), "users_archive"));
+/// assert!(!filter.admits_table(Some("audit"), "events"));
+/// assert!(!filter.admits_table(None, "users"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectFilter {
+    /// Patterns of the schemas to include.
+    include_schemas: Vec<String>,
+    /// Patterns of the schemas to exclude.
+    exclude_schemas: Vec<String>,
+    /// Patterns of the tables to include.
+    include_tables: Vec<String>,
+    /// Patterns of the tables to exclude.
+    exclude_tables: Vec<String>,
+}
+
+/// Name of the schema unqualified tables are considered part of.
+const DEFAULT_SCHEMA: &str = "public";
+
+/// Returns whether the name is admitted by the include and exclude patterns.
+fn admitted_by(include: &[String], exclude: &[String], matches: impl Fn(&str) -> bool) -> bool {
+    (include.is_empty() || include.iter().any(|pattern| matches(pattern)))
+        && !exclude.iter().any(|pattern| matches(pattern))
+}
+
+impl ObjectFilter {
+    /// Adds a pattern of schemas to include.
+    #[must_use]
+    pub fn include_schema(mut self, pattern: impl Into<String>) -> Self {
+        self.include_schemas.push(pattern.into());
+        self
+    }
+
+    /// Adds a pattern of schemas to exclude.
+    #[must_use]
+    pub fn exclude_schema(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude_schemas.push(pattern.into());
+        self
+    }
+
+    /// Adds a pattern of tables to include.
+    #[must_use]
+    pub fn include_table(mut self, pattern: impl Into<String>) -> Self {
+        self.include_tables.push(pattern.into());
+        self
+    }
+
+    /// Adds a pattern of tables to exclude.
+    #[must_use]
+    pub fn exclude_table(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude_tables.push(pattern.into());
+        self
+    }
+
+    /// Returns whether the filter admits every object.
+    #[must_use]
+    pub fn is_unrestricted(&self) -> bool {
+        self.include_schemas.is_empty()
+            && self.exclude_schemas.is_empty()
+            && self.include_tables.is_empty()
+            && self.exclude_tables.is_empty()
+    }
+
+    /// Returns whether the schema with the provided normalized name is
+    /// admitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The normalized name of the schema.
+    #[must_use]
+    pub fn admits_schema(&self, schema: &str) -> bool {
+        admitted_by(&self.include_schemas, &self.exclude_schemas, |pattern| {
+            glob_match(pattern, schema)
+        })
+    }
+
+    /// Returns whether the table with the provided normalized names is
+    /// admitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The normalized name of the schema of the table, if any.
+    /// * `table` - The normalized name of the table.
+    #[must_use]
+    pub fn admits_table(&self, schema: Option<&str>, table: &str) -> bool {
+        let schema = schema.unwrap_or(DEFAULT_SCHEMA);
+        let qualified = format!("{schema}.{table}");
+        self.admits_schema(schema)
+            && admitted_by(&self.include_tables, &self.exclude_tables, |pattern| {
+                glob_match(pattern, if pattern.contains('.') { &qualified } else { table })
+            })
+    }
+
+    /// Returns whether the table named by the provided object name is
+    /// admitted, normalizing its parts under the provided casing.
+    pub(crate) fn admits_object_name(
+        &self,
+        object_name: &ObjectName,
+        casing: IdentifierCasing,
+    ) -> bool {
+        if self.is_unrestricted() {
+            return true;
+        }
+        let Some((table, table_quoted)) = object_name_last_part(object_name) else {
+            return true;
+        };
+        let schema = schema_from_object_name(object_name).map_or_else(
+            || casing.normalize(DEFAULT_SCHEMA, false),
+            |(schema, quoted)| casing.normalize(schema, quoted),
+        );
+        self.admits_table(Some(&schema), &casing.normalize(table, table_quoted))
+    }
+
+    /// Returns whether the schema named by the provided object name is
+    /// admitted, normalizing it under the provided casing.
+    pub(crate) fn admits_schema_object_name(
+        &self,
+        object_name: &ObjectName,
+        casing: IdentifierCasing,
+    ) -> bool {
+        object_name_last_part(object_name)
+            .is_none_or(|(schema, quoted)| self.admits_schema(&casing.normalize(schema, quoted)))
+    }
+}
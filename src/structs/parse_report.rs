@@ -0,0 +1,99 @@
+//! Submodule defining the `ParseReport` struct, pairing a partially built
+//! database with every validation error raised while building it.
+
+use alloc::vec::Vec;
+
+use sqlparser::tokenizer::Span;
+
+use crate::{errors::Error, structs::ParserDB};
+
+/// Database built by skipping the statements failing a validation, along
+/// with the errors they raised, as returned by
+/// [`ParserDB::parse_with_report`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sql_traits::structs::ParserOptions;
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let report = ParserDB::parse_with_report::<PostgreSqlDialect>(
+///     "CREATE TABLE t (id INT);
+///      GRANT SELECT ON t TO reader;
+///      DROP TABLE archived;",
+///     ParserOptions::default(),
+/// )?;
+/// assert_eq!(report.errors().len(), 2);
+/// assert!(report.database().table(None, "t").is_some());
+/// assert!(report.into_result().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ParseReport {
+    /// Database built from the statements passing the validations.
+    database: ParserDB,
+    /// Errors raised by the skipped statements, in the order they were
+    /// encountered.
+    errors: Vec<Error>,
+    /// Span of the statement raising each error, if known.
+    error_spans: Vec<Option<Span>>,
+}
+
+impl ParseReport {
+    /// Creates a new report from the errors and the span of the statement
+    /// raising them.
+    pub(crate) fn new(database: ParserDB, errors: Vec<(Error, Option<Span>)>) -> Self {
+        let (errors, error_spans) = errors.into_iter().unzip();
+        Self { database, errors, error_spans }
+    }
+
+    /// Returns the database built from the statements passing the
+    /// validations.
+    #[must_use]
+    #[inline]
+    pub fn database(&self) -> &ParserDB {
+        &self.database
+    }
+
+    /// Returns the errors raised by the skipped statements.
+    #[must_use]
+    #[inline]
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Returns the span of the statement raising each error, in the same
+    /// order as [`Self::errors`], or `None` for the errors raised once every
+    /// statement is applied, such as type mismatches.
+    #[must_use]
+    #[inline]
+    pub fn error_spans(&self) -> &[Option<Span>] {
+        &self.error_spans
+    }
+
+    /// Returns whether no validation error was raised.
+    #[must_use]
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the database and the errors.
+    #[must_use]
+    pub fn into_parts(self) -> (ParserDB, Vec<Error>) {
+        (self.database, self.errors)
+    }
+
+    /// Returns the database if no validation error was raised, and
+    /// otherwise every error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the collected errors if there is at least one.
+    pub fn into_result(self) -> Result<ParserDB, Vec<Error>> {
+        if self.errors.is_empty() { Ok(self.database) } else { Err(self.errors) }
+    }
+}
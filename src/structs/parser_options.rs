@@ -0,0 +1,401 @@
+//! Submodule defining the `ParserOptions` struct, controlling how strictly
+//! [`crate::structs::ParserDB::parse_with_options`] validates a schema, how
+//! it detects table extensions, which built-in functions it provides and how
+//! it applies custom statements.
+
+use alloc::{string::String, vec::Vec};
+
+use sqlparser::ast::Statement;
+
+use crate::{
+    errors::{Error, LookupError},
+    structs::{BuiltinRegistry, ExtensionStrategy, ParserDBBuilder, StatementHandlers},
+};
+
+/// Class of validation errors whose severity can be configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ValidationClass {
+    /// Statements referring to objects which do not exist, such as a grant
+    /// to a missing role or on a missing table, or a drop of a missing
+    /// object.
+    MissingObjects,
+    /// Drops of objects other objects still depend on, without `CASCADE`.
+    DependentObjects,
+    /// Creations of objects which already exist, and names shared by
+    /// relations of different kinds.
+    DuplicateObjects,
+    /// Column default values, function call arguments and foreign key
+    /// columns whose types do not match the declared or referenced ones.
+    Types,
+    /// Statements defining schema objects which are not modelled, namely
+    /// `ALTER TYPE`, `ALTER VIEW`, `CREATE PROCEDURE` and `CREATE MACRO`,
+    /// and `SET TIME ZONE` statements whose value is not a literal, as well
+    /// as the clauses dropped from the statements which are applied, namely
+    /// the `WITH { ADMIN | INHERIT | SET }` options and the `GRANTED BY`
+    /// clause of `GRANT role TO role` and `REVOKE role FROM role`, and the
+    /// bodies of the functions written in SQL or PL/pgSQL which cannot be
+    /// parsed, whose functions are created without tracking the tables they
+    /// depend on.
+    ///
+    /// These statements used to be skipped silently, and are now skipped
+    /// with a [`crate::structs::ValidationWarning`] by default: setting this
+    /// class to [`ValidationLevel::Error`] rejects them, and to
+    /// [`ValidationLevel::Ignore`] restores the former behaviour.
+    UnsupportedStatements,
+}
+
+impl ValidationClass {
+    /// Returns the class of the provided error, or `None` if the error does
+    /// not belong to a configurable class, as is the case for syntax errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::{errors::Error, structs::ValidationClass};
+    ///
+    /// let error = Error::RevokeNotFound("REVOKE SELECT ON t FROM reader".to_string());
+    /// assert_eq!(ValidationClass::of(&error), Some(ValidationClass::MissingObjects));
+    /// ```
+    #[must_use]
+    pub fn of(error: &Error) -> Option<Self> {
+        match error {
+            Error::IdentifierLookupError(LookupError::TableNotFound { .. })
+            | Error::UnknownColumnInCheckConstraint { .. }
+            | Error::ReferencedTableNotFoundForForeignKey { .. }
+            | Error::ReferencedColumnNotFoundForForeignKey { .. }
+            | Error::HostColumnNotFoundForForeignKey { .. }
+            | Error::TableNotFoundForTrigger { .. }
+            | Error::TableNotFoundForIndex { .. }
+            | Error::FunctionNotFoundForTrigger { .. }
+            | Error::RevokeNotFound(_)
+            | Error::RoleNotFoundForGrant { .. }
+            | Error::TableNotFoundForGrant { .. }
+            | Error::DropFunctionNotFound { .. }
+            | Error::DropTableNotFound { .. }
+            | Error::DropViewNotFound { .. }
+            | Error::DropSequenceNotFound { .. }
+            | Error::SequenceOwnerNotFound { .. }
+            | Error::DropCustomTypeNotFound { .. }
+            | Error::DropExtensionNotFound { .. }
+            | Error::DropIndexNotFound { .. }
+            | Error::DropTriggerNotFound { .. }
+            | Error::DropPolicyNotFound { .. }
+            | Error::DropRoleNotFound { .. }
+            | Error::DropSchemaNotFound { .. }
+            | Error::RenameTableNotFound { .. }
+            | Error::AlterTableNotFound { .. }
+            | Error::DropColumnNotFound { .. }
+            | Error::DropConstraintNotFound { .. }
+            | Error::AlterPolicyNotFound { .. }
+            | Error::AlterSchemaNotFound { .. }
+            | Error::AlterIndexNotFound { .. }
+            | Error::AlterRoleNotFound { .. }
+            | Error::CommentTableNotFound { .. }
+            | Error::CommentColumnNotFound { .. }
+            | Error::CommentFunctionNotFound { .. } => Some(Self::MissingObjects),
+            Error::FunctionReferenced { .. }
+            | Error::TableReferenced { .. }
+            | Error::TableReferencedByView { .. }
+            | Error::TableReferencedByFunction { .. }
+            | Error::SequenceReferenced { .. }
+            | Error::ExtensionReferenced { .. }
+            | Error::RoleReferenced { .. }
+            | Error::SchemaNotEmpty { .. }
+            | Error::ColumnReferenced { .. } => Some(Self::DependentObjects),
+            Error::TableAlreadyExists { .. }
+            | Error::FunctionAlreadyExists { .. }
+            | Error::ViewAlreadyExists { .. }
+            | Error::SequenceAlreadyExists { .. }
+            | Error::CustomTypeAlreadyExists { .. }
+            | Error::ExtensionAlreadyExists { .. }
+            | Error::NameCollision { .. }
+            | Error::SchemaAlreadyExists { .. }
+            | Error::ColumnAlreadyExists { .. } => Some(Self::DuplicateObjects),
+            Error::DefaultTypeMismatch { .. }
+            | Error::NoMatchingFunctionOverload { .. }
+            | Error::ForeignKeyColumnTypeMismatch { .. } => Some(Self::Types),
+            Error::UnsupportedStatement { .. }
+            | Error::UnsupportedClause { .. }
+            | Error::UnparsableFunctionBody { .. } => Some(Self::UnsupportedStatements),
+            _ => None,
+        }
+    }
+}
+
+/// Severity of the errors of a validation class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationLevel {
+    /// The error aborts the parse.
+    #[default]
+    Error,
+    /// The offending statement is skipped and the error is recorded as a
+    /// [`crate::structs::ValidationWarning`] on the resulting database.
+    Warn,
+    /// The offending statement is skipped silently.
+    Ignore,
+}
+
+/// Options controlling how strictly
+/// [`crate::structs::ParserDB::parse_with_options`] validates a schema, and
+/// which [`ExtensionStrategy`] and [`BuiltinRegistry`] the resulting database
+/// uses, along with the [`StatementHandlers`] applying the statements of
+/// given kinds and the search path resolving unqualified names.
+///
+/// By default, every validation error aborts the parse, as with
+/// [`crate::structs::ParserDB::parse`], except for the statements of
+/// [`ValidationClass::UnsupportedStatements`], which are skipped with a
+/// warning.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::{ParserOptions, ValidationClass, ValidationLevel};
+///
+/// let options =
+///     ParserOptions::default().with_level(ValidationClass::MissingObjects, ValidationLevel::Warn);
+/// assert_eq!(options.level(ValidationClass::MissingObjects), ValidationLevel::Warn);
+/// assert_eq!(options.level(ValidationClass::Types), ValidationLevel::Error);
+/// assert_eq!(options.level(ValidationClass::UnsupportedStatements), ValidationLevel::Warn);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParserOptions {
+    /// Severity of the references to missing objects.
+    missing_objects: ValidationLevel,
+    /// Severity of the drops of objects still depended upon.
+    dependent_objects: ValidationLevel,
+    /// Severity of the creations of existing objects.
+    duplicate_objects: ValidationLevel,
+    /// Severity of the type mismatches.
+    types: ValidationLevel,
+    /// Severity of the unsupported statements.
+    unsupported_statements: ValidationLevel,
+    /// Rule detecting the foreign keys defining table extensions.
+    extension_strategy: ExtensionStrategy,
+    /// Built-in functions, if they differ from those of the dialect.
+    builtin_registry: Option<BuiltinRegistry>,
+    /// Handlers applying the statements of given kinds.
+    statement_handlers: StatementHandlers,
+    /// Schemas searched, in order, for the objects referred to without a
+    /// schema.
+    search_path: Vec<String>,
+    /// Whether the foreign keys referencing missing tables or columns are
+    /// kept unresolved rather than rejected.
+    deferred_foreign_keys: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            missing_objects: ValidationLevel::Error,
+            dependent_objects: ValidationLevel::Error,
+            duplicate_objects: ValidationLevel::Error,
+            types: ValidationLevel::Error,
+            unsupported_statements: ValidationLevel::Warn,
+            extension_strategy: ExtensionStrategy::default(),
+            builtin_registry: None,
+            statement_handlers: StatementHandlers::default(),
+            search_path: Vec::new(),
+            deferred_foreign_keys: false,
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Sets the severity of the errors of the provided class.
+    #[must_use]
+    pub fn with_level(mut self, class: ValidationClass, level: ValidationLevel) -> Self {
+        match class {
+            ValidationClass::MissingObjects => self.missing_objects = level,
+            ValidationClass::DependentObjects => self.dependent_objects = level,
+            ValidationClass::DuplicateObjects => self.duplicate_objects = level,
+            ValidationClass::Types => self.types = level,
+            ValidationClass::UnsupportedStatements => self.unsupported_statements = level,
+        }
+        self
+    }
+
+    /// Returns the severity of the errors of the provided class.
+    #[must_use]
+    pub fn level(&self, class: ValidationClass) -> ValidationLevel {
+        match class {
+            ValidationClass::MissingObjects => self.missing_objects,
+            ValidationClass::DependentObjects => self.dependent_objects,
+            ValidationClass::DuplicateObjects => self.duplicate_objects,
+            ValidationClass::Types => self.types,
+            ValidationClass::UnsupportedStatements => self.unsupported_statements,
+        }
+    }
+
+    /// Returns the severity of the provided error, which is always
+    /// [`ValidationLevel::Error`] for errors outside the configurable classes.
+    #[must_use]
+    pub fn level_of(&self, error: &Error) -> ValidationLevel {
+        ValidationClass::of(error).map_or(ValidationLevel::Error, |class| self.level(class))
+    }
+
+    /// Sets the rule detecting the foreign keys which make their table an
+    /// extension of the table they reference.
+    #[must_use]
+    pub fn with_extension_strategy(mut self, extension_strategy: ExtensionStrategy) -> Self {
+        self.extension_strategy = extension_strategy;
+        self
+    }
+
+    /// Returns the rule detecting the foreign keys defining table extensions.
+    #[must_use]
+    pub fn extension_strategy(&self) -> &ExtensionStrategy {
+        &self.extension_strategy
+    }
+
+    /// Sets the built-in functions added to the database, in place of those
+    /// of the dialect returned by [`BuiltinRegistry::for_dialect`].
+    #[must_use]
+    pub fn with_builtin_registry(mut self, builtin_registry: BuiltinRegistry) -> Self {
+        self.builtin_registry = Some(builtin_registry);
+        self
+    }
+
+    /// Returns the built-in functions added to the database, if they differ
+    /// from those of the dialect.
+    #[must_use]
+    pub fn builtin_registry(&self) -> Option<&BuiltinRegistry> {
+        self.builtin_registry.as_ref()
+    }
+
+    /// Registers the handler applying the statements of the provided kind
+    /// in place of the built-in processing, as described in
+    /// [`StatementHandlers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The name of the [`Statement`] variant, such as
+    ///   `CreateProcedure`.
+    /// * `handler` - The handler applying the statements of that kind.
+    #[must_use]
+    pub fn with_statement_handler(
+        mut self,
+        kind: &str,
+        handler: impl Fn(ParserDBBuilder, &Statement) -> Result<ParserDBBuilder, Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.statement_handlers = self.statement_handlers.with_handler(kind, handler);
+        self
+    }
+
+    /// Returns the handlers applying the statements of given kinds.
+    #[must_use]
+    pub fn statement_handlers(&self) -> &StatementHandlers {
+        &self.statement_handlers
+    }
+
+    /// Sets the schemas searched, in order, for the tables, functions and
+    /// indexes referred to without a schema, as with `SET search_path` in
+    /// `PostgreSQL`.
+    ///
+    /// Unqualified names are first resolved against the objects declared
+    /// without a schema, then within each schema of the search path, the
+    /// first match winning. Objects created without a schema are placed in
+    /// the first schema of the path which exists. Schemas wrapped in double
+    /// quotes are quoted identifiers. The search path is empty by default, so
+    /// that unqualified names only resolve to objects declared without a
+    /// schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let sql = "
+    ///     CREATE SCHEMA app;
+    ///     CREATE TABLE app.users (id INT PRIMARY KEY);
+    ///     CREATE TABLE posts (id INT, author_id INT REFERENCES users (id));
+    /// ";
+    /// assert!(ParserDB::parse::<PostgreSqlDialect>(sql).is_err());
+    ///
+    /// let options = ParserOptions::default().with_search_path(["app"]);
+    /// let db = ParserDB::parse_with_options::<PostgreSqlDialect>(sql, options)?;
+    /// assert_eq!(db.search_path(), ["app"]);
+    /// let posts = db.table(Some("app"), "posts").unwrap();
+    /// let users = db.table(Some("app"), "users").unwrap();
+    /// assert!(posts.foreign_keys(&db).any(|fk| fk.referenced_table(&db) == Some(users)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_search_path<Sch: Into<String>>(
+        mut self,
+        search_path: impl IntoIterator<Item = Sch>,
+    ) -> Self {
+        self.search_path = search_path.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the schemas searched, in order, for the objects referred to
+    /// without a schema.
+    #[must_use]
+    pub fn search_path(&self) -> &[String] {
+        &self.search_path
+    }
+
+    /// Sets whether the foreign keys referencing tables or columns which do
+    /// not exist when they are declared are kept unresolved rather than
+    /// rejected, as engines such as SQLite only check them when rows are
+    /// written. Their targets may then be created by later statements, or
+    /// never, in which case
+    /// [`crate::traits::ForeignKeyLike::referenced_table`] returns `None`;
+    /// [`crate::structs::ParserDB::validate_foreign_key_targets`] checks them
+    /// once the schema is loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::SQLiteDialect;
+    ///
+    /// let sql = "
+    ///     CREATE TABLE posts (id INT, author_id INT, FOREIGN KEY (author_id) REFERENCES users (id));
+    ///     CREATE TABLE users (id INT PRIMARY KEY);
+    /// ";
+    /// assert!(ParserDB::parse::<SQLiteDialect>(sql).is_err());
+    ///
+    /// let options = ParserOptions::default().with_deferred_foreign_keys(true);
+    /// let db = ParserDB::parse_with_options::<SQLiteDialect>(sql, options)?;
+    /// let posts = db.table(None, "posts").unwrap();
+    /// let users = db.table(None, "users").unwrap();
+    /// assert!(posts.foreign_keys(&db).any(|fk| fk.referenced_table(&db) == Some(users)));
+    /// assert!(db.validate_foreign_key_targets().is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_deferred_foreign_keys(mut self, deferred_foreign_keys: bool) -> Self {
+        self.deferred_foreign_keys = deferred_foreign_keys;
+        self
+    }
+
+    /// Returns whether the foreign keys referencing missing tables or columns
+    /// are kept unresolved rather than rejected.
+    #[must_use]
+    pub fn defers_foreign_keys(&self) -> bool {
+        self.deferred_foreign_keys
+    }
+
+    /// Returns whether every validation error aborts the parse.
+    #[must_use]
+    pub fn is_strict(&self) -> bool {
+        [
+            ValidationClass::MissingObjects,
+            ValidationClass::DependentObjects,
+            ValidationClass::DuplicateObjects,
+            ValidationClass::Types,
+            ValidationClass::UnsupportedStatements,
+        ]
+        .into_iter()
+        .all(|class| self.level(class) == ValidationLevel::Error)
+    }
+}
@@ -0,0 +1,310 @@
+//! Submodule defining the `PolicyCoverage` struct, reporting which commands
+//! and roles the row level security policies of a table cover.
+
+use alloc::vec::Vec;
+
+use sqlparser::ast::{CreatePolicyCommand, Owner};
+
+use crate::traits::{DatabaseLike, PolicyLike, RoleLike, TableLike};
+
+/// The commands a row level security policy can be restricted to, in the
+/// order [`PolicyCoverage`] reports them.
+pub const POLICY_COMMANDS: [CreatePolicyCommand; 4] = [
+    CreatePolicyCommand::Select,
+    CreatePolicyCommand::Insert,
+    CreatePolicyCommand::Update,
+    CreatePolicyCommand::Delete,
+];
+
+/// Returns whether a policy declared for `declared` applies to `command`.
+fn command_applies(declared: CreatePolicyCommand, command: CreatePolicyCommand) -> bool {
+    declared == CreatePolicyCommand::All || declared == command
+}
+
+/// Returns whether the owner of a `TO` clause designates one of the provided
+/// role names, or `PUBLIC`.
+///
+/// `CURRENT_USER`, `CURRENT_ROLE` and `SESSION_USER` depend on the session
+/// and are never considered matching.
+fn owner_matches(owner: &Owner, role_names: &[&str]) -> bool {
+    match owner {
+        Owner::Ident(ident) => {
+            (ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("public"))
+                || role_names.contains(&ident.value.as_str())
+        }
+        Owner::CurrentUser | Owner::CurrentRole | Owner::SessionUser => false,
+    }
+}
+
+/// Report of the commands and roles covered by the row level security
+/// policies of a table.
+///
+/// Once row level security is enabled on a table, PostgreSQL denies any row
+/// to the commands and roles no policy applies to. A command without any
+/// policy, or a role no policy of a command applies to, is therefore a gap
+/// which is either intended or a bug: the report lists them for review.
+///
+/// A policy declared `FOR ALL` covers every command, and a policy without a
+/// `TO` clause or declared `TO PUBLIC` covers every role. A policy declared
+/// for a role also covers the roles inheriting from it.
+#[derive(Debug, Clone)]
+pub struct PolicyCoverage<'db, DB: DatabaseLike> {
+    /// The database the table belongs to.
+    database: &'db DB,
+    /// The table the report is about.
+    table: &'db DB::Table,
+    /// The policies defined on the table.
+    policies: Vec<&'db DB::Policy>,
+}
+
+impl<'db, DB: DatabaseLike> PolicyCoverage<'db, DB> {
+    /// Builds the report for the provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the table belongs to.
+    /// * `table` - The table to build the report for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::ast::CreatePolicyCommand;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE documents (id INT, owner TEXT);
+    /// ALTER TABLE documents ENABLE ROW LEVEL SECURITY;
+    /// CREATE POLICY read_documents ON documents FOR SELECT USING (true);
+    /// ",
+    /// )?;
+    /// let coverage = PolicyCoverage::new(&db, db.table(None, "documents").unwrap());
+    /// assert!(coverage.is_covered(CreatePolicyCommand::Select));
+    /// assert!(!coverage.is_covered(CreatePolicyCommand::Delete));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, table: &'db DB::Table) -> Self {
+        Self { database, table, policies: table.policies(database).collect() }
+    }
+
+    /// Returns the table the report is about.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns whether row level security is enabled on the table, without
+    /// which its policies are not enforced and the report has no gaps.
+    #[must_use]
+    #[inline]
+    pub fn has_row_level_security(&self) -> bool {
+        self.table.has_row_level_security(self.database)
+    }
+
+    /// Iterates over the policies of the table applying to the provided
+    /// command, including the ones declared `FOR ALL`.
+    ///
+    /// Passing [`CreatePolicyCommand::All`] only yields the policies declared
+    /// `FOR ALL`.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to get the policies of.
+    pub fn policies(
+        &self,
+        command: CreatePolicyCommand,
+    ) -> impl Iterator<Item = &'db DB::Policy> + '_ {
+        self.policies
+            .iter()
+            .copied()
+            .filter(move |policy| command_applies(policy.command(), command))
+    }
+
+    /// Returns whether at least one policy applies to the provided command.
+    ///
+    /// Passing [`CreatePolicyCommand::All`] returns whether every command of
+    /// [`POLICY_COMMANDS`] is covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to check.
+    #[must_use]
+    pub fn is_covered(&self, command: CreatePolicyCommand) -> bool {
+        if command == CreatePolicyCommand::All {
+            return self.uncovered_commands().next().is_none();
+        }
+        self.policies(command).next().is_some()
+    }
+
+    /// Iterates over the commands of [`POLICY_COMMANDS`] no policy applies
+    /// to.
+    pub fn uncovered_commands(&self) -> impl Iterator<Item = CreatePolicyCommand> + '_ {
+        POLICY_COMMANDS.into_iter().filter(|command| self.policies(*command).next().is_none())
+    }
+
+    /// Returns whether at least one policy applies to the provided command
+    /// when run by the provided role.
+    ///
+    /// Superusers and roles declared `BYPASSRLS` are not subject to row level
+    /// security, and are therefore always covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to check, where [`CreatePolicyCommand::All`]
+    ///   checks every command of [`POLICY_COMMANDS`].
+    /// * `role` - The role running the command.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::CreatePolicyCommand, dialect::PostgreSqlDialect};
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE staff;
+    /// CREATE ROLE editor IN ROLE staff;
+    /// CREATE ROLE guest;
+    /// CREATE TABLE documents (id INT);
+    /// ALTER TABLE documents ENABLE ROW LEVEL SECURITY;
+    /// CREATE POLICY staff_documents ON documents TO staff USING (true);
+    /// ",
+    /// )?;
+    /// let coverage = db.table(None, "documents").unwrap().policy_coverage(&db);
+    /// let editor = db.role("editor").unwrap();
+    /// let guest = db.role("guest").unwrap();
+    /// assert!(coverage.is_covered_for(CreatePolicyCommand::All, editor));
+    /// assert!(!coverage.is_covered_for(CreatePolicyCommand::Select, guest));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_covered_for(&self, command: CreatePolicyCommand, role: &'db DB::Role) -> bool {
+        if role.is_superuser() || role.can_bypass_rls() {
+            return true;
+        }
+        let role_names = self.effective_role_names(role);
+        let covers_role = |policy: &&'db DB::Policy| {
+            let mut owners = policy.roles(self.database).peekable();
+            owners.peek().is_none() || owners.any(|owner| owner_matches(owner, &role_names))
+        };
+        if command == CreatePolicyCommand::All {
+            return POLICY_COMMANDS
+                .into_iter()
+                .all(|command| self.policies(command).any(|policy| covers_role(&policy)));
+        }
+        self.policies(command).any(|policy| covers_role(&policy))
+    }
+
+    /// Iterates over the roles of the database no policy of the provided
+    /// command applies to, as checked by [`PolicyCoverage::is_covered_for`].
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to check.
+    pub fn uncovered_roles(
+        &self,
+        command: CreatePolicyCommand,
+    ) -> impl Iterator<Item = &'db DB::Role> + '_ {
+        self.database.roles().filter(move |role| !self.is_covered_for(command, role))
+    }
+
+    /// Returns whether row level security is enabled on the table and some
+    /// command of [`POLICY_COMMANDS`] has no policy.
+    #[must_use]
+    pub fn has_gaps(&self) -> bool {
+        self.has_row_level_security() && !self.is_covered(CreatePolicyCommand::All)
+    }
+
+    /// Returns the name of the provided role along with the names of the
+    /// roles whose privileges it inherits, directly or transitively.
+    fn effective_role_names(&self, role: &'db DB::Role) -> Vec<&'db str> {
+        let mut names = vec![role.name()];
+        let mut pending = vec![role];
+        while let Some(current) = pending.pop() {
+            if !current.inherits() {
+                continue;
+            }
+            for parent in current.member_of(self.database) {
+                if !names.contains(&parent.name()) {
+                    names.push(parent.name());
+                    pending.push(parent);
+                }
+            }
+        }
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{ast::CreatePolicyCommand, dialect::PostgreSqlDialect};
+
+    use super::POLICY_COMMANDS;
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, RoleLike, TableLike},
+    };
+
+    #[test]
+    fn test_all_policy_covers_every_command() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE t (id INT);
+            ALTER TABLE t ENABLE ROW LEVEL SECURITY;
+            CREATE POLICY p ON t USING (true);
+            ",
+        )
+        .expect("Failed to parse");
+
+        let coverage = db.table(None, "t").unwrap().policy_coverage(&db);
+        assert!(coverage.has_row_level_security());
+        assert!(!coverage.has_gaps());
+        for command in POLICY_COMMANDS {
+            assert_eq!(coverage.policies(command).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_uncovered_commands_and_roles() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE reader;
+            CREATE ROLE writer;
+            CREATE ROLE admin BYPASSRLS;
+            CREATE TABLE t (id INT);
+            ALTER TABLE t ENABLE ROW LEVEL SECURITY;
+            CREATE POLICY read ON t FOR SELECT TO PUBLIC USING (true);
+            CREATE POLICY write ON t FOR INSERT TO writer WITH CHECK (true);
+            ",
+        )
+        .expect("Failed to parse");
+
+        let coverage = db.table(None, "t").unwrap().policy_coverage(&db);
+        assert!(coverage.has_gaps());
+        assert_eq!(
+            coverage.uncovered_commands().collect::<Vec<_>>(),
+            [CreatePolicyCommand::Update, CreatePolicyCommand::Delete]
+        );
+        assert_eq!(coverage.uncovered_roles(CreatePolicyCommand::Select).count(), 0);
+        let uncovered_inserters: Vec<&str> =
+            coverage.uncovered_roles(CreatePolicyCommand::Insert).map(RoleLike::name).collect();
+        assert_eq!(uncovered_inserters, ["reader"]);
+    }
+
+    #[test]
+    fn test_disabled_rls_has_no_gaps() {
+        let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE t (id INT);")
+            .expect("Failed to parse");
+
+        let coverage = db.table(None, "t").unwrap().policy_coverage(&db);
+        assert!(!coverage.has_row_level_security());
+        assert!(!coverage.is_covered(CreatePolicyCommand::Select));
+        assert!(!coverage.has_gaps());
+        assert_eq!(db.rls_gaps().count(), 0);
+    }
+}
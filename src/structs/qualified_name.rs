@@ -0,0 +1,255 @@
+//! Submodule defining the `QualifiedName` struct, naming a schema object
+//! along with the schema it belongs to.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Display;
+
+use sqlparser::ast::{Ident, ObjectName};
+
+use crate::{
+    structs::IdentifierCasing,
+    utils::{
+        identifier_resolution::parse_lookup_identifier,
+        object_name::{object_name_last_part, schema_from_object_name},
+    },
+};
+
+/// Name of a schema object, optionally qualified by its schema, used to look
+/// up tables, functions, indexes and triggers.
+///
+/// Both parts follow the PostgreSQL identifier semantics: unquoted parts are
+/// folded to lowercase, quoted ones match exactly. An unqualified name only
+/// matches objects without a schema, unless it is resolved through a search
+/// path with [`QualifiedName::search_candidates`].
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::QualifiedName;
+///
+/// let name = QualifiedName::new(Some("app"), "\"Users\"");
+/// assert_eq!(name.schema(), Some("app"));
+/// assert_eq!(name.to_string(), "app.\"Users\"");
+/// assert!(name.matches(Some("APP"), false, "Users", true));
+/// assert!(!name.matches(Some("billing"), false, "Users", true));
+/// assert!(!name.matches(None, false, "Users", true));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualifiedName {
+    /// Name of the schema, if the name is qualified.
+    schema: Option<String>,
+    /// Whether the name of the schema is quoted.
+    schema_quoted: bool,
+    /// Name of the object.
+    name: String,
+    /// Whether the name of the object is quoted.
+    quoted: bool,
+}
+
+impl QualifiedName {
+    /// Creates a new qualified name, treating the parts wrapped in double
+    /// quotes as quoted identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The name of the schema, if any.
+    /// * `name` - The name of the object.
+    #[must_use]
+    pub fn new(schema: Option<&str>, name: &str) -> Self {
+        let schema = schema.map(parse_lookup_identifier);
+        let name = parse_lookup_identifier(name);
+        Self::from_parts(
+            schema.as_ref().map(|schema| (schema.value(), schema.is_quoted())),
+            (name.value(), name.is_quoted()),
+        )
+    }
+
+    /// Creates a qualified name from its parts, given as values along with
+    /// whether they are quoted.
+    pub(crate) fn from_parts(schema: Option<(&str, bool)>, name: (&str, bool)) -> Self {
+        Self {
+            schema: schema.map(|(schema, _)| String::from(schema)),
+            schema_quoted: schema.is_some_and(|(_, quoted)| quoted),
+            name: String::from(name.0),
+            quoted: name.1,
+        }
+    }
+
+    /// Creates a qualified name from the last two parts of an object name,
+    /// or returns `None` if the object name is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::QualifiedName;
+    /// use sqlparser::ast::{Ident, ObjectName};
+    ///
+    /// let object_name = ObjectName::from(vec![Ident::new("app"), Ident::new("users")]);
+    /// let name = QualifiedName::from_object_name(&object_name).unwrap();
+    /// assert_eq!(name, QualifiedName::new(Some("app"), "users"));
+    /// ```
+    #[must_use]
+    pub fn from_object_name(object_name: &ObjectName) -> Option<Self> {
+        Some(Self::from_parts(
+            schema_from_object_name(object_name),
+            object_name_last_part(object_name)?,
+        ))
+    }
+
+    /// Returns the object name made of the parts of this name.
+    pub(crate) fn to_object_name(&self) -> ObjectName {
+        let ident = |value: &str, quoted: bool| {
+            if quoted { Ident::with_quote('"', value) } else { Ident::new(value) }
+        };
+        let mut parts = Vec::with_capacity(2);
+        if let Some(schema) = &self.schema {
+            parts.push(ident(schema, self.schema_quoted));
+        }
+        parts.push(ident(&self.name, self.quoted));
+        ObjectName::from(parts)
+    }
+
+    /// Returns the name of the schema, if the name is qualified.
+    #[must_use]
+    #[inline]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns whether the name of the schema is quoted.
+    #[must_use]
+    #[inline]
+    pub fn schema_is_quoted(&self) -> bool {
+        self.schema_quoted
+    }
+
+    /// Returns the name of the object.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether the name of the object is quoted.
+    #[must_use]
+    #[inline]
+    pub fn name_is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns whether the name designates the object of the provided schema
+    /// and name. An unqualified name only designates objects without a
+    /// schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the object, if any.
+    /// * `schema_quoted` - Whether the schema of the object is quoted.
+    /// * `name` - The name of the object.
+    /// * `quoted` - Whether the name of the object is quoted.
+    #[must_use]
+    pub fn matches(
+        &self,
+        schema: Option<&str>,
+        schema_quoted: bool,
+        name: &str,
+        quoted: bool,
+    ) -> bool {
+        self.matches_with_casing(IdentifierCasing::Lowercase, schema, schema_quoted, name, quoted)
+    }
+
+    /// Returns whether the name designates the object of the provided schema
+    /// and name, comparing identifiers with the provided casing rather than
+    /// with the PostgreSQL rules used by [`QualifiedName::matches`].
+    ///
+    /// # Arguments
+    ///
+    /// * `casing` - The rule comparing identifiers.
+    /// * `schema` - The schema of the object, if any.
+    /// * `schema_quoted` - Whether the schema of the object is quoted.
+    /// * `name` - The name of the object.
+    /// * `quoted` - Whether the name of the object is quoted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::{IdentifierCasing, QualifiedName};
+    ///
+    /// let name = QualifiedName::new(Some("app"), "\"Users\"");
+    /// assert!(!name.matches(Some("app"), false, "users", false));
+    /// let insensitive = IdentifierCasing::Insensitive;
+    /// assert!(name.matches_with_casing(insensitive, Some("app"), false, "users", false));
+    /// ```
+    #[must_use]
+    pub fn matches_with_casing(
+        &self,
+        casing: IdentifierCasing,
+        schema: Option<&str>,
+        schema_quoted: bool,
+        name: &str,
+        quoted: bool,
+    ) -> bool {
+        casing.matches(&self.name, self.quoted, name, quoted)
+            && match (self.schema.as_deref(), schema) {
+                (None, None) => true,
+                (Some(own_schema), Some(schema)) => {
+                    casing.matches(own_schema, self.schema_quoted, schema, schema_quoted)
+                }
+                _ => false,
+            }
+    }
+
+    /// Returns the names to look up, in order, to resolve this name through
+    /// the provided search path.
+    ///
+    /// A qualified name is only looked up as is. An unqualified name is
+    /// first looked up among the objects without a schema, then within each
+    /// schema of the search path, so that the first match wins. Schemas
+    /// wrapped in double quotes are quoted identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::QualifiedName;
+    ///
+    /// let search_path = ["app".to_string(), "public".to_string()];
+    /// let candidates = QualifiedName::new(None, "users").search_candidates(&search_path);
+    /// assert_eq!(
+    ///     candidates,
+    ///     [
+    ///         QualifiedName::new(None, "users"),
+    ///         QualifiedName::new(Some("app"), "users"),
+    ///         QualifiedName::new(Some("public"), "users"),
+    ///     ]
+    /// );
+    /// let qualified = QualifiedName::new(Some("billing"), "users");
+    /// assert_eq!(qualified.search_candidates(&search_path), [qualified]);
+    /// ```
+    #[must_use]
+    pub fn search_candidates(&self, search_path: &[String]) -> Vec<Self> {
+        let mut candidates = vec![self.clone()];
+        if self.schema.is_none() {
+            candidates.extend(search_path.iter().map(|schema| {
+                let schema = parse_lookup_identifier(schema);
+                Self::from_parts(
+                    Some((schema.value(), schema.is_quoted())),
+                    (&self.name, self.quoted),
+                )
+            }));
+        }
+        candidates
+    }
+}
+
+impl Display for QualifiedName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let write_part = |f: &mut core::fmt::Formatter<'_>, part: &str, quoted: bool| {
+            if quoted { write!(f, "\"{}\"", part.replace('"', "\"\"")) } else { f.write_str(part) }
+        };
+        if let Some(schema) = &self.schema {
+            write_part(f, schema, self.schema_quoted)?;
+            f.write_str(".")?;
+        }
+        write_part(f, &self.name, self.quoted)
+    }
+}
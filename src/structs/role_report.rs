@@ -0,0 +1,285 @@
+//! Submodule defining the `RoleReport` struct, summarizing the objects owned
+//! by a role and the responsibilities it holds in the database.
+
+use alloc::vec::Vec;
+
+use crate::traits::{DatabaseLike, DialectLike, GrantLike, RoleLike, SchemaLike};
+
+/// Report of the ownership and grant responsibilities of a role.
+///
+/// The report answers the question "what happens if this role is dropped":
+/// it lists the objects the role owns, the privileges granted to it, the
+/// privileges it granted to others, the policies whose `TO` clause mentions it
+/// and the role memberships involving it.
+///
+/// Ownership is currently tracked for schemas only, through their
+/// `AUTHORIZATION` clause.
+#[derive(Debug, Clone)]
+pub struct RoleReport<'db, DB: DatabaseLike> {
+    /// The role the report is about.
+    role: &'db DB::Role,
+    /// The schemas owned by the role.
+    owned_schemas: Vec<&'db DB::Schema>,
+    /// The table grants whose grantees include the role.
+    table_grants: Vec<&'db DB::TableGrant>,
+    /// The column grants whose grantees include the role.
+    column_grants: Vec<&'db DB::ColumnGrant>,
+    /// The table grants issued `GRANTED BY` the role.
+    granted_table_grants: Vec<&'db DB::TableGrant>,
+    /// The column grants issued `GRANTED BY` the role.
+    granted_column_grants: Vec<&'db DB::ColumnGrant>,
+    /// The policies whose `TO` clause mentions the role.
+    policies: Vec<&'db DB::Policy>,
+    /// The roles this role is a member of.
+    member_of: Vec<&'db DB::Role>,
+    /// The roles which are members of this role.
+    members: Vec<&'db DB::Role>,
+}
+
+impl<'db, DB: DatabaseLike> RoleReport<'db, DB> {
+    /// Builds the report for the provided role.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the role belongs to.
+    /// * `role` - The role to build the report for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE admin;
+    /// CREATE ROLE app_user;
+    /// CREATE SCHEMA app AUTHORIZATION admin;
+    /// CREATE TABLE t (id INT);
+    /// GRANT SELECT ON t TO app_user GRANTED BY admin;
+    /// ",
+    /// )?;
+    /// let report = RoleReport::new(&db, db.role("admin").unwrap());
+    /// assert_eq!(report.owned_schemas().len(), 1);
+    /// assert_eq!(report.granted_table_grants().len(), 1);
+    /// assert!(report.table_grants().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, role: &'db DB::Role) -> Self {
+        let granted_by_role = |grantor: Option<&DB::Role>| grantor.is_some_and(|r| r == role);
+        let casing = database.dialect().identifier_casing();
+
+        Self {
+            role,
+            owned_schemas: database
+                .schemas()
+                .filter(|schema| {
+                    schema.authorization().is_some_and(|owner| {
+                        casing.matches(
+                            owner,
+                            schema.authorization_is_quoted(),
+                            role.name(),
+                            role.name_is_quoted(),
+                        )
+                    })
+                })
+                .collect(),
+            table_grants: database
+                .table_grants()
+                .filter(|grant| grant.applies_to_role(role, database))
+                .collect(),
+            column_grants: database
+                .column_grants()
+                .filter(|grant| grant.applies_to_role(role, database))
+                .collect(),
+            granted_table_grants: database
+                .table_grants()
+                .filter(|grant| granted_by_role(grant.granted_by(database)))
+                .collect(),
+            granted_column_grants: database
+                .column_grants()
+                .filter(|grant| granted_by_role(grant.granted_by(database)))
+                .collect(),
+            policies: role.policies(database).collect(),
+            member_of: role.member_of(database).collect(),
+            members: role.members(database).collect(),
+        }
+    }
+
+    /// Returns the role the report is about.
+    #[must_use]
+    #[inline]
+    pub fn role(&self) -> &'db DB::Role {
+        self.role
+    }
+
+    /// Returns the schemas owned by the role.
+    #[must_use]
+    #[inline]
+    pub fn owned_schemas(&self) -> &[&'db DB::Schema] {
+        &self.owned_schemas
+    }
+
+    /// Returns the table grants whose grantees include the role.
+    #[must_use]
+    #[inline]
+    pub fn table_grants(&self) -> &[&'db DB::TableGrant] {
+        &self.table_grants
+    }
+
+    /// Returns the column grants whose grantees include the role.
+    #[must_use]
+    #[inline]
+    pub fn column_grants(&self) -> &[&'db DB::ColumnGrant] {
+        &self.column_grants
+    }
+
+    /// Returns the table grants issued `GRANTED BY` the role.
+    #[must_use]
+    #[inline]
+    pub fn granted_table_grants(&self) -> &[&'db DB::TableGrant] {
+        &self.granted_table_grants
+    }
+
+    /// Returns the column grants issued `GRANTED BY` the role.
+    #[must_use]
+    #[inline]
+    pub fn granted_column_grants(&self) -> &[&'db DB::ColumnGrant] {
+        &self.granted_column_grants
+    }
+
+    /// Returns the policies whose `TO` clause mentions the role.
+    #[must_use]
+    #[inline]
+    pub fn policies(&self) -> &[&'db DB::Policy] {
+        &self.policies
+    }
+
+    /// Returns the roles this role is a member of.
+    #[must_use]
+    #[inline]
+    pub fn member_of(&self) -> &[&'db DB::Role] {
+        &self.member_of
+    }
+
+    /// Returns the roles which are members of this role.
+    #[must_use]
+    #[inline]
+    pub fn members(&self) -> &[&'db DB::Role] {
+        &self.members
+    }
+
+    /// Returns whether dropping the role would leave dangling references,
+    /// that is whether it owns objects, holds or granted privileges, or is
+    /// mentioned by policies.
+    ///
+    /// Memberships are not taken into account, as they are revoked along with
+    /// the role.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE ROLE unused;
+    /// CREATE TABLE t (id INT);
+    /// CREATE POLICY p ON t TO reader USING (true);
+    /// ",
+    /// )?;
+    /// assert!(RoleReport::new(&db, db.role("reader").unwrap()).has_dependencies());
+    /// assert!(!RoleReport::new(&db, db.role("unused").unwrap()).has_dependencies());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn has_dependencies(&self) -> bool {
+        !(self.owned_schemas.is_empty()
+            && self.table_grants.is_empty()
+            && self.column_grants.is_empty()
+            && self.granted_table_grants.is_empty()
+            && self.granted_column_grants.is_empty()
+            && self.policies.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        structs::{ParserDB, RoleReport},
+        traits::{DatabaseLike, RoleLike},
+    };
+
+    #[test]
+    fn test_report_collects_memberships_and_policies() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE parent_role;
+            CREATE ROLE child_role IN ROLE parent_role;
+            CREATE TABLE t (id INT);
+            CREATE POLICY p ON t TO parent_role USING (true);
+            GRANT SELECT (id) ON t TO child_role;
+            ",
+        )
+        .expect("Failed to parse");
+
+        let parent = RoleReport::new(&db, db.role("parent_role").unwrap());
+        assert_eq!(parent.role().name(), "parent_role");
+        assert_eq!(parent.policies().len(), 1);
+        assert!(parent.member_of().is_empty());
+        assert_eq!(parent.members().len(), 1);
+        assert_eq!(parent.members()[0].name(), "child_role");
+        assert!(parent.has_dependencies());
+
+        let child = RoleReport::new(&db, db.role("child_role").unwrap());
+        assert_eq!(child.member_of().len(), 1);
+        assert!(child.members().is_empty());
+        assert_eq!(child.column_grants().len(), 1);
+        assert!(child.granted_column_grants().is_empty());
+        assert!(child.owned_schemas().is_empty());
+    }
+
+    #[test]
+    fn test_owners_report_covers_every_role() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE a;
+            CREATE ROLE b;
+            CREATE SCHEMA s AUTHORIZATION b;
+            ",
+        )
+        .expect("Failed to parse");
+
+        let reports = db.owners_report();
+        let owners: Vec<(&str, usize)> = reports
+            .iter()
+            .map(|report| (report.role().name(), report.owned_schemas().len()))
+            .collect();
+        assert_eq!(owners, vec![("a", 0), ("b", 1)]);
+    }
+
+    #[test]
+    fn test_owned_schemas_follow_identifier_casing() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE admin;
+            CREATE SCHEMA a AUTHORIZATION Admin;
+            CREATE SCHEMA b AUTHORIZATION \"admin\";
+            CREATE SCHEMA c AUTHORIZATION \"Admin\";
+            ",
+        )
+        .expect("Failed to parse");
+
+        let report = RoleReport::new(&db, db.role("admin").unwrap());
+        let owned: Vec<&str> = report.owned_schemas().iter().map(|schema| schema.name()).collect();
+        assert_eq!(owned, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,273 @@
+//! Submodule defining the `RoleView` struct, describing the schema as seen by
+//! a role through its privileges.
+
+use alloc::vec::Vec;
+
+use crate::{
+    privileges::TablePrivilege,
+    traits::{ColumnLike, DatabaseLike, RoleLike, TableLike},
+};
+
+/// A table as seen by a role, restricted to the columns the role can access.
+#[derive(Debug, Clone)]
+pub struct TableView<'db, DB: DatabaseLike> {
+    /// The table.
+    table: &'db DB::Table,
+    /// The columns the role can access, in table order.
+    columns: Vec<&'db DB::Column>,
+    /// The accessible columns the role can write but not read.
+    write_only_columns: Vec<&'db DB::Column>,
+    /// Whether the rows of the table are filtered by row-level security.
+    row_level_security: bool,
+}
+
+impl<'db, DB: DatabaseLike> TableView<'db, DB> {
+    /// Returns the table.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns the columns the role can read or write, in table order.
+    #[must_use]
+    #[inline]
+    pub fn columns(&self) -> &[&'db DB::Column] {
+        &self.columns
+    }
+
+    /// Returns whether the role can read or write the column.
+    #[must_use]
+    pub fn is_visible(&self, column: &DB::Column) -> bool {
+        self.columns.iter().any(|visible| *visible == column)
+    }
+
+    /// Returns whether the role can write the column but not read it back.
+    #[must_use]
+    pub fn is_write_only(&self, column: &DB::Column) -> bool {
+        self.write_only_columns.iter().any(|write_only| *write_only == column)
+    }
+
+    /// Returns whether the rows the role sees are filtered by row-level
+    /// security policies.
+    #[must_use]
+    #[inline]
+    pub fn has_row_level_security(&self) -> bool {
+        self.row_level_security
+    }
+}
+
+/// The schema as seen by a role, meant to be rendered as client-facing
+/// documentation without leaking the structure the role cannot access.
+///
+/// A column is visible when the role effectively holds a table-level or
+/// column-level `SELECT`, `INSERT` or `UPDATE` privilege covering it, as
+/// resolved by [`DatabaseLike::effective_privileges`], and a table is
+/// visible when at least one of its columns is. The privileges granted to
+/// `PUBLIC` and inherited through role memberships are thus taken into
+/// account, and superusers see every column. Columns the role can only write
+/// are marked as write-only, and tables with row-level security enabled are
+/// marked as filtered unless the role bypasses it.
+///
+/// The [`json_schema`](crate::export::json_schema) and
+/// [`diagram`](crate::export::diagram) exporters render this view when
+/// provided with a role.
+#[derive(Debug, Clone)]
+pub struct RoleView<'db, DB: DatabaseLike> {
+    /// The role the view is built for.
+    role: &'db DB::Role,
+    /// The tables visible to the role, in database order.
+    tables: Vec<TableView<'db, DB>>,
+}
+
+impl<'db, DB: DatabaseLike> RoleView<'db, DB> {
+    /// Builds the view of the database as seen by the provided role.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the role belongs to.
+    /// * `role` - The role to build the view for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE writers;
+    /// CREATE ROLE partner IN ROLE writers;
+    /// CREATE TABLE orders (id INT, total INT, margin INT);
+    /// CREATE TABLE feedback (id INT, message TEXT);
+    /// CREATE TABLE audit (id INT);
+    /// ALTER TABLE orders ENABLE ROW LEVEL SECURITY;
+    /// GRANT SELECT (id, total) ON orders TO partner;
+    /// GRANT INSERT ON feedback TO writers;
+    /// ",
+    /// )?;
+    /// let view = RoleView::new(&db, db.role("partner").unwrap());
+    /// let tables: Vec<&str> =
+    ///     view.tables().iter().map(|table| table.table().table_name()).collect();
+    /// assert_eq!(tables, ["feedback", "orders"]);
+    ///
+    /// let orders = &view.tables()[1];
+    /// let columns: Vec<&str> = orders.columns().iter().map(|c| c.column_name()).collect();
+    /// assert_eq!(columns, ["id", "total"]);
+    /// assert!(orders.has_row_level_security());
+    ///
+    /// let feedback = &view.tables()[0];
+    /// assert!(feedback.columns().iter().all(|column| feedback.is_write_only(column)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, role: &'db DB::Role) -> Self {
+        let tables = database
+            .tables()
+            .filter_map(|table| {
+                let privileges = database.effective_privileges(role, table);
+                let mut columns = Vec::new();
+                let mut write_only_columns = Vec::new();
+                for column in table.columns(database) {
+                    let has = |privilege| {
+                        role.is_superuser()
+                            || privileges.has_column(
+                                column.column_name(),
+                                column.column_name_is_quoted(),
+                                privilege,
+                            )
+                    };
+                    let readable = has(TablePrivilege::Select);
+                    let writable = has(TablePrivilege::Insert) || has(TablePrivilege::Update);
+                    if writable && !readable {
+                        write_only_columns.push(column);
+                    }
+                    if readable || writable {
+                        columns.push(column);
+                    }
+                }
+                if columns.is_empty() {
+                    return None;
+                }
+                Some(TableView {
+                    table,
+                    columns,
+                    write_only_columns,
+                    row_level_security: table.has_row_level_security(database)
+                        && !role.is_superuser()
+                        && !role.can_bypass_rls(),
+                })
+            })
+            .collect();
+
+        Self { role, tables }
+    }
+
+    /// Returns the role the view is built for.
+    #[must_use]
+    #[inline]
+    pub fn role(&self) -> &'db DB::Role {
+        self.role
+    }
+
+    /// Returns the tables visible to the role, in database order.
+    #[must_use]
+    #[inline]
+    pub fn tables(&self) -> &[TableView<'db, DB>] {
+        &self.tables
+    }
+
+    /// Returns the view of the provided table, if it is visible to the role.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table to look up.
+    #[must_use]
+    pub fn table(&self, table: &DB::Table) -> Option<&TableView<'db, DB>> {
+        self.tables.iter().find(|view| view.table == table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+
+    use crate::{
+        structs::{ParserDB, RoleView},
+        traits::{ColumnLike, DatabaseLike, TableLike},
+    };
+
+    #[test]
+    fn test_view_hides_inaccessible_structure() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE partner;
+            CREATE ROLE auditor BYPASSRLS;
+            CREATE TABLE accounts (id INT, email TEXT, password_hash TEXT);
+            CREATE TABLE internal (id INT);
+            ALTER TABLE accounts ENABLE ROW LEVEL SECURITY;
+            GRANT SELECT (id, email), UPDATE (password_hash) ON accounts TO partner;
+            GRANT SELECT ON accounts TO auditor;
+            ",
+        )
+        .expect("Failed to parse");
+
+        let partner = RoleView::new(&db, db.role("partner").unwrap());
+        assert_eq!(partner.tables().len(), 1);
+        let accounts = &partner.tables()[0];
+        assert_eq!(accounts.table().table_name(), "accounts");
+        let columns: Vec<(&str, bool)> = accounts
+            .columns()
+            .iter()
+            .map(|column| (column.column_name(), accounts.is_write_only(column)))
+            .collect();
+        assert_eq!(columns, [("id", false), ("email", false), ("password_hash", true)]);
+        assert!(accounts.has_row_level_security());
+
+        let auditor = RoleView::new(&db, db.role("auditor").unwrap());
+        assert_eq!(auditor.tables().len(), 1);
+        assert_eq!(auditor.tables()[0].columns().len(), 3);
+        assert!(!auditor.tables()[0].has_row_level_security());
+    }
+
+    #[test]
+    fn test_view_follows_memberships_and_casing() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            r#"
+            CREATE ROLE reader;
+            CREATE ROLE partner;
+            CREATE ROLE root SUPERUSER;
+            CREATE TABLE accounts (id INT, "Email" TEXT, secret TEXT);
+            GRANT SELECT (ID, "Email") ON accounts TO reader;
+            GRANT reader TO partner;
+            "#,
+        )
+        .expect("Failed to parse");
+        let partner = RoleView::new(&db, db.role("partner").unwrap());
+        let columns: Vec<&str> =
+            partner.tables()[0].columns().iter().map(|column| column.column_name()).collect();
+        assert_eq!(columns, ["id", "Email"]);
+        let root = RoleView::new(&db, db.role("root").unwrap());
+        assert_eq!(root.tables()[0].columns().len(), 3);
+
+        let db = ParserDB::parse::<MySqlDialect>(
+            "
+            CREATE ROLE partner;
+            CREATE TABLE accounts (id INT, `Email` TEXT, secret TEXT);
+            GRANT SELECT (`EMAIL`) ON accounts TO partner;
+            ",
+        )
+        .expect("Failed to parse");
+        let partner = RoleView::new(&db, db.role("partner").unwrap());
+        let accounts = db.table(None, "accounts").unwrap();
+        let columns: Vec<&str> = partner
+            .table(accounts)
+            .unwrap()
+            .columns()
+            .iter()
+            .map(|column| column.column_name())
+            .collect();
+        assert_eq!(columns, ["Email"]);
+    }
+}
@@ -0,0 +1,490 @@
+//! Submodule verifying that the `down.sql` file of a migration only undoes
+//! the objects created by its sibling `up.sql` file.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use sqlparser::ast::{
+    AlterTableOperation, Ident, ObjectName, ObjectNamePart, ObjectType, SchemaName, Statement,
+    TableConstraint,
+};
+
+use crate::{
+    structs::IdentifierCasing,
+    utils::object_name::{object_name_last_part, schema_from_object_name},
+};
+
+/// How the `down.sql` files conflicting with their sibling `up.sql` files are
+/// reported.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::structs::RollbackSeverity;
+///
+/// assert_eq!(RollbackSeverity::default(), RollbackSeverity::Ignore);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RollbackSeverity {
+    /// The `down.sql` files are not verified, as when loading migrations.
+    #[default]
+    Ignore,
+    /// The conflicts are returned for the caller to report.
+    Warn,
+    /// The first conflict is returned as an error.
+    Deny,
+}
+
+/// Kinds of objects a `down.sql` file may drop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UndoneKind {
+    Table,
+    Column,
+    Constraint,
+    Index,
+    View,
+    Function,
+    Trigger,
+    Policy,
+    Role,
+    Schema,
+    Sequence,
+    Type,
+}
+
+impl UndoneKind {
+    /// Returns the name of the kind, as used in the reported conflicts.
+    fn description(self) -> &'static str {
+        match self {
+            Self::Table => "table",
+            Self::Column => "column",
+            Self::Constraint => "constraint",
+            Self::Index => "index",
+            Self::View => "view",
+            Self::Function => "function",
+            Self::Trigger => "trigger",
+            Self::Policy => "policy",
+            Self::Role => "role",
+            Self::Schema => "schema",
+            Self::Sequence => "sequence",
+            Self::Type => "type",
+        }
+    }
+}
+
+/// An object created by an `up.sql` file or dropped by a `down.sql` file.
+#[derive(Debug, Clone)]
+struct MigrationObject {
+    /// The kind of the object.
+    kind: UndoneKind,
+    /// The name of the object.
+    name: ObjectName,
+    /// The table of the object, for columns, constraints, triggers and
+    /// policies, when known.
+    table: Option<ObjectName>,
+}
+
+impl MigrationObject {
+    /// Creates an object which does not belong to a table.
+    fn new(kind: UndoneKind, name: ObjectName) -> Self {
+        Self { kind, name, table: None }
+    }
+
+    /// Creates an object belonging to the provided table.
+    fn on_table(kind: UndoneKind, name: &Ident, table: Option<&ObjectName>) -> Self {
+        Self {
+            kind,
+            name: ObjectName(vec![ObjectNamePart::Identifier(name.clone())]),
+            table: table.cloned(),
+        }
+    }
+
+    /// Returns whether the dropped object is the provided created object.
+    fn is_undoing(&self, created: &Self, casing: IdentifierCasing) -> bool {
+        self.kind == created.kind
+            && names_match(&self.name, &created.name, casing)
+            && match (&self.table, &created.table) {
+                (Some(table), Some(created_table)) => names_match(table, created_table, casing),
+                _ => true,
+            }
+    }
+}
+
+/// Returns whether two possibly schema-qualified names designate the same
+/// object. Names only qualified on one side are matched by their last part.
+fn names_match(left: &ObjectName, right: &ObjectName, casing: IdentifierCasing) -> bool {
+    let (Some((left_name, left_quoted)), Some((right_name, right_quoted))) =
+        (object_name_last_part(left), object_name_last_part(right))
+    else {
+        return false;
+    };
+    casing.matches(left_name, left_quoted, right_name, right_quoted)
+        && match (schema_from_object_name(left), schema_from_object_name(right)) {
+            (Some((left_schema, left_quoted)), Some((right_schema, right_quoted))) => {
+                casing.matches(left_schema, left_quoted, right_schema, right_quoted)
+            }
+            _ => true,
+        }
+}
+
+/// Returns the name of a table constraint, if it has one.
+fn constraint_name(constraint: &TableConstraint) -> Option<&Ident> {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => primary_key.name.as_ref(),
+        TableConstraint::Unique(unique) => unique.name.as_ref(),
+        TableConstraint::ForeignKey(foreign_key) => foreign_key.name.as_ref(),
+        TableConstraint::Check(check) => check.name.as_ref(),
+        _ => None,
+    }
+}
+
+/// Returns the objects created by the statements of an `up.sql` file.
+fn created_objects(statements: &[Statement]) -> Vec<MigrationObject> {
+    let mut objects = Vec::new();
+    for statement in statements {
+        match statement {
+            Statement::CreateTable(create_table) => {
+                let table = Some(&create_table.name);
+                objects.push(MigrationObject::new(UndoneKind::Table, create_table.name.clone()));
+                for column in &create_table.columns {
+                    objects.push(MigrationObject::on_table(
+                        UndoneKind::Column,
+                        &column.name,
+                        table,
+                    ));
+                    objects.extend(column.options.iter().filter_map(|option| {
+                        option.name.as_ref().map(|name| {
+                            MigrationObject::on_table(UndoneKind::Constraint, name, table)
+                        })
+                    }));
+                }
+                objects.extend(create_table.constraints.iter().filter_map(|constraint| {
+                    constraint_name(constraint)
+                        .map(|name| MigrationObject::on_table(UndoneKind::Constraint, name, table))
+                }));
+            }
+            Statement::AlterTable(alter_table) => {
+                let table = Some(&alter_table.name);
+                for operation in &alter_table.operations {
+                    match operation {
+                        AlterTableOperation::AddColumn { column_def, .. } => {
+                            objects.push(MigrationObject::on_table(
+                                UndoneKind::Column,
+                                &column_def.name,
+                                table,
+                            ));
+                        }
+                        AlterTableOperation::AddConstraint { constraint, .. } => {
+                            objects.extend(constraint_name(constraint).map(|name| {
+                                MigrationObject::on_table(UndoneKind::Constraint, name, table)
+                            }));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Statement::CreateIndex(create_index) => {
+                objects.extend(
+                    create_index
+                        .name
+                        .clone()
+                        .map(|name| MigrationObject::new(UndoneKind::Index, name)),
+                );
+            }
+            Statement::CreateView(create_view) => {
+                objects.push(MigrationObject::new(UndoneKind::View, create_view.name.clone()));
+            }
+            Statement::CreateFunction(create_function) => {
+                objects
+                    .push(MigrationObject::new(UndoneKind::Function, create_function.name.clone()));
+            }
+            Statement::CreateTrigger(create_trigger) => {
+                objects.push(MigrationObject {
+                    kind: UndoneKind::Trigger,
+                    name: create_trigger.name.clone(),
+                    table: Some(create_trigger.table_name.clone()),
+                });
+            }
+            Statement::CreatePolicy(create_policy) => {
+                objects.push(MigrationObject::on_table(
+                    UndoneKind::Policy,
+                    &create_policy.name,
+                    Some(&create_policy.table_name),
+                ));
+            }
+            Statement::CreateRole(create_role) => {
+                objects.extend(
+                    create_role
+                        .names
+                        .iter()
+                        .map(|name| MigrationObject::new(UndoneKind::Role, name.clone())),
+                );
+            }
+            Statement::CreateSchema {
+                schema_name: SchemaName::Simple(name) | SchemaName::NamedAuthorization(name, _),
+                ..
+            } => {
+                objects.push(MigrationObject::new(UndoneKind::Schema, name.clone()));
+            }
+            Statement::CreateSchema {
+                schema_name: SchemaName::UnnamedAuthorization(authorization),
+                ..
+            } => {
+                objects.push(MigrationObject::new(
+                    UndoneKind::Schema,
+                    ObjectName(vec![ObjectNamePart::Identifier(authorization.clone())]),
+                ));
+            }
+            Statement::CreateSequence { name, .. } => {
+                objects.push(MigrationObject::new(UndoneKind::Sequence, name.clone()));
+            }
+            Statement::CreateType { name, .. } => {
+                objects.push(MigrationObject::new(UndoneKind::Type, name.clone()));
+            }
+            Statement::CreateDomain(create_domain) => {
+                objects.push(MigrationObject::new(UndoneKind::Type, create_domain.name.clone()));
+            }
+            _ => {}
+        }
+    }
+    objects
+}
+
+/// Returns the objects dropped by a statement of a `down.sql` file.
+fn dropped_objects(statement: &Statement) -> Vec<MigrationObject> {
+    match statement {
+        Statement::Drop { object_type, names, .. } => {
+            let kind = match object_type {
+                ObjectType::Table => UndoneKind::Table,
+                ObjectType::View | ObjectType::MaterializedView => UndoneKind::View,
+                ObjectType::Index => UndoneKind::Index,
+                ObjectType::Role => UndoneKind::Role,
+                ObjectType::Schema => UndoneKind::Schema,
+                ObjectType::Sequence => UndoneKind::Sequence,
+                ObjectType::Type => UndoneKind::Type,
+                _ => return Vec::new(),
+            };
+            names.iter().map(|name| MigrationObject::new(kind, name.clone())).collect()
+        }
+        Statement::DropFunction(drop_function) => drop_function
+            .func_desc
+            .iter()
+            .map(|function| MigrationObject::new(UndoneKind::Function, function.name.clone()))
+            .collect(),
+        Statement::DropTrigger(drop_trigger) => vec![MigrationObject {
+            kind: UndoneKind::Trigger,
+            name: drop_trigger.trigger_name.clone(),
+            table: drop_trigger.table_name.clone(),
+        }],
+        Statement::DropPolicy(drop_policy) => vec![MigrationObject::on_table(
+            UndoneKind::Policy,
+            &drop_policy.name,
+            Some(&drop_policy.table_name),
+        )],
+        Statement::DropDomain(drop_domain) => {
+            vec![MigrationObject::new(UndoneKind::Type, drop_domain.name.clone())]
+        }
+        Statement::AlterTable(alter_table) => {
+            let table = Some(&alter_table.name);
+            alter_table
+                .operations
+                .iter()
+                .flat_map(|operation| match operation {
+                    AlterTableOperation::DropColumn { column_names, .. } => column_names
+                        .iter()
+                        .map(|column| MigrationObject::on_table(UndoneKind::Column, column, table))
+                        .collect(),
+                    AlterTableOperation::DropConstraint { name, .. } => {
+                        vec![MigrationObject::on_table(UndoneKind::Constraint, name, table)]
+                    }
+                    _ => Vec::new(),
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// A statement of a `down.sql` file dropping an object which its sibling
+/// `up.sql` file does not create, which would break the rollback of the
+/// migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollbackMismatch {
+    /// The `down.sql` file containing the statement.
+    down_file: PathBuf,
+    /// The offending statement.
+    statement: String,
+    /// The kind of the dropped object.
+    kind: &'static str,
+    /// The name of the dropped object.
+    name: String,
+}
+
+impl RollbackMismatch {
+    /// Returns the `down.sql` file containing the offending statement.
+    #[must_use]
+    #[inline]
+    pub fn down_file(&self) -> &Path {
+        &self.down_file
+    }
+
+    /// Returns the offending statement.
+    #[must_use]
+    #[inline]
+    pub fn statement(&self) -> &str {
+        &self.statement
+    }
+
+    /// Returns the kind of the dropped object, such as `table` or `column`.
+    #[must_use]
+    #[inline]
+    pub fn object_kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Returns the name of the dropped object.
+    #[must_use]
+    #[inline]
+    pub fn object_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for RollbackMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` drops {} `{}`, which its up.sql does not create: {}",
+            self.down_file.display(),
+            self.kind,
+            self.name,
+            self.statement
+        )
+    }
+}
+
+/// Returns the statements of the `down.sql` file dropping objects which the
+/// statements of its sibling `up.sql` file do not create.
+///
+/// Columns and constraints are considered created along with their table,
+/// and the names of the objects are compared through the provided casing.
+pub(crate) fn rollback_mismatches(
+    up: &[Statement],
+    down: &[Statement],
+    down_file: &Path,
+    casing: IdentifierCasing,
+) -> Vec<RollbackMismatch> {
+    let created = created_objects(up);
+    let table_created = |table: &ObjectName| {
+        created.iter().any(|object| {
+            object.kind == UndoneKind::Table && names_match(&object.name, table, casing)
+        })
+    };
+
+    let mut mismatches = Vec::new();
+    for statement in down {
+        for dropped in dropped_objects(statement) {
+            let undoes_created = created.iter().any(|object| dropped.is_undoing(object, casing))
+                || (matches!(dropped.kind, UndoneKind::Column | UndoneKind::Constraint)
+                    && dropped.table.as_ref().is_some_and(table_created));
+            if !undoes_created {
+                mismatches.push(RollbackMismatch {
+                    down_file: down_file.to_path_buf(),
+                    statement: statement.to_string(),
+                    kind: dropped.kind.description(),
+                    name: dropped.name.to_string(),
+                });
+            }
+        }
+    }
+    mismatches
+}
+
+/// Returns the `down.sql` files found in the provided path, recursively.
+///
+/// # Errors
+///
+/// Returns an error if a directory or one of its entries can't be read.
+pub(crate) fn search_down_files(path: &Path) -> Result<Vec<PathBuf>, crate::errors::Error> {
+    let io_error = |error| crate::errors::Error::Io { path: path.to_path_buf(), error };
+    let mut down_files = Vec::new();
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.is_dir() {
+                down_files.extend(search_down_files(&path)?);
+            } else if path.file_name().is_some_and(|name| name == "down.sql") {
+                down_files.push(path);
+            }
+        }
+    } else if path.file_name().is_some_and(|name| name == "down.sql") {
+        down_files.push(path.to_path_buf());
+    }
+    down_files.sort_unstable();
+    Ok(down_files)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+
+    use super::*;
+
+    fn parse(sql: &str) -> Vec<Statement> {
+        Parser::parse_sql(&PostgreSqlDialect {}, sql).expect("Failed to parse")
+    }
+
+    #[test]
+    fn test_down_undoing_up_has_no_mismatch() {
+        let up = parse(
+            "
+            CREATE TABLE users (id INT, CONSTRAINT users_pk PRIMARY KEY (id));
+            CREATE INDEX users_id_idx ON users (id);
+            ALTER TABLE posts ADD COLUMN author_id INT;
+            ALTER TABLE posts ADD CONSTRAINT posts_author_fk FOREIGN KEY (author_id) REFERENCES users (id);
+            CREATE ROLE reader;
+            ",
+        );
+        let down = parse(
+            "
+            DROP ROLE reader;
+            ALTER TABLE posts DROP CONSTRAINT posts_author_fk;
+            ALTER TABLE posts DROP COLUMN author_id;
+            DROP INDEX users_id_idx;
+            ALTER TABLE users DROP CONSTRAINT users_pk;
+            DROP TABLE IF EXISTS users;
+            ",
+        );
+        assert!(
+            rollback_mismatches(&up, &down, Path::new("down.sql"), IdentifierCasing::Lowercase)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_down_dropping_foreign_objects() {
+        let up = parse("CREATE TABLE users (id INT); ALTER TABLE posts ADD COLUMN author_id INT;");
+        let down = parse(
+            "
+            DROP TABLE users, posts;
+            ALTER TABLE posts DROP COLUMN title;
+            ",
+        );
+        let mismatches =
+            rollback_mismatches(&up, &down, Path::new("m1/down.sql"), IdentifierCasing::Lowercase);
+        let objects: Vec<(&str, &str)> = mismatches
+            .iter()
+            .map(|mismatch| (mismatch.object_kind(), mismatch.object_name()))
+            .collect();
+        assert_eq!(objects, [("table", "posts"), ("column", "title")]);
+        assert_eq!(mismatches[0].down_file(), Path::new("m1/down.sql"));
+        assert_eq!(
+            mismatches[0].to_string(),
+            "`m1/down.sql` drops table `posts`, which its up.sql does not create: DROP TABLE users, posts"
+        );
+    }
+}
@@ -6,6 +6,7 @@ use alloc::string::String;
 ///
 /// This struct stores the schema name and optional authorization owner.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Schema {
     /// The name of the schema.
     name: String,
@@ -13,13 +14,15 @@ pub struct Schema {
     quoted: bool,
     /// The authorization owner of the schema, if specified.
     authorization: Option<String>,
+    /// Whether the authorization owner identifier was quoted in SQL.
+    authorization_quoted: bool,
 }
 
 impl Schema {
     /// Creates a new `Schema` with the given name.
     #[must_use]
     pub fn new(name: String) -> Self {
-        Self { name, quoted: false, authorization: None }
+        Self { name, quoted: false, authorization: None, authorization_quoted: false }
     }
 
     /// Creates a new `Schema` with the given name and authorization owner.
@@ -36,7 +39,12 @@ impl Schema {
     /// ```
     #[must_use]
     pub fn with_authorization(name: String, authorization: String) -> Self {
-        Self { name, quoted: false, authorization: Some(authorization) }
+        Self {
+            name,
+            quoted: false,
+            authorization: Some(authorization),
+            authorization_quoted: false,
+        }
     }
 
     /// Creates a new `Schema` with quoted-name metadata.
@@ -53,7 +61,7 @@ impl Schema {
     /// ```
     #[must_use]
     pub fn with_quoted(name: String, quoted: bool) -> Self {
-        Self { name, quoted, authorization: None }
+        Self { name, quoted, authorization: None, authorization_quoted: false }
     }
 
     /// Creates a new `Schema` with authorization and quoted-name metadata.
@@ -75,7 +83,25 @@ impl Schema {
         authorization: String,
         quoted: bool,
     ) -> Self {
-        Self { name, quoted, authorization: Some(authorization) }
+        Self { name, quoted, authorization: Some(authorization), authorization_quoted: false }
+    }
+
+    /// Returns the schema with its authorization owner marked as quoted or
+    /// not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Schema;
+    ///
+    /// let s = Schema::with_authorization("app".to_string(), "Admin".to_string())
+    ///     .with_quoted_authorization(true);
+    /// assert!(s.is_authorization_quoted());
+    /// ```
+    #[must_use]
+    pub fn with_quoted_authorization(mut self, authorization_quoted: bool) -> Self {
+        self.authorization_quoted = authorization_quoted;
+        self
     }
 
     /// Returns the name of the schema.
@@ -90,6 +116,12 @@ impl Schema {
         self.authorization.as_deref()
     }
 
+    /// Returns whether the authorization owner identifier was quoted in SQL.
+    #[must_use]
+    pub fn is_authorization_quoted(&self) -> bool {
+        self.authorization_quoted
+    }
+
     /// Returns whether this schema name was quoted in SQL.
     #[must_use]
     pub fn is_quoted(&self) -> bool {
@@ -0,0 +1,588 @@
+//! Submodule defining the `SchemaSnapshot` struct, the versioned model a
+//! [`ParserDB`] is serialized through, along with the snapshots of the
+//! objects it holds.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::{
+    CheckConstraint, CreateFunction, CreateIndex, CreatePolicy, CreateRole, CreateTable,
+    CreateTrigger, CreateView, Grant,
+};
+
+use crate::{
+    impls::SqlparserDialect,
+    structs::{
+        CustomType, DefaultPrivilege, Extension, ParserDB, Schema, Sequence, TableMetadata,
+        metadata::FunctionMetadata,
+    },
+    traits::{
+        CheckConstraintLike, ColumnLike, DatabaseLike, ForeignKeyLike, FunctionLike, IndexLike,
+        TableLike,
+    },
+};
+
+/// Version of the snapshot format written by [`ParserDB::to_snapshot`], and
+/// the only one [`ParserDB::from_snapshot`] accepts.
+///
+/// The version is bumped whenever the fields of the snapshot, or the meaning
+/// of their values, change.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 2;
+
+/// Versioned snapshot of the schema of a [`ParserDB`], as serialized with
+/// the `serde` feature.
+///
+/// The objects of the database are stored as their definitions, which are
+/// either the syntax trees of the statements creating them or the structs
+/// modelling them, along with the metadata their definitions do not carry,
+/// such as their comments. The database is rebuilt from them without
+/// parsing any SQL. The columns, primary keys and foreign keys of the tables
+/// are also summarized, so that tools which do not model the syntax trees
+/// can consume the snapshot: the summaries are ignored when the database is
+/// rebuilt.
+///
+/// The built-in functions are not stored, as they are provided again by the
+/// dialect when the database is rebuilt.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{prelude::*, structs::SNAPSHOT_FORMAT_VERSION};
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL);",
+/// )?;
+/// let snapshot = db.to_snapshot();
+/// assert_eq!(snapshot.format_version(), SNAPSHOT_FORMAT_VERSION);
+///
+/// let users = &snapshot.tables()[0];
+/// assert_eq!(users.name(), "users");
+/// assert_eq!(users.definition().columns.len(), 2);
+/// assert_eq!(users.primary_key(), ["id"]);
+/// assert!(!users.columns()[1].is_nullable());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaSnapshot {
+    /// Version of the format of the snapshot.
+    format_version: u32,
+    /// Name of the catalog of the database.
+    catalog_name: String,
+    /// Dialect the database was parsed under.
+    dialect: SqlparserDialect,
+    /// Timezone of the database, if set.
+    timezone: Option<String>,
+    /// Schemas searched for the objects referred to without a schema.
+    search_path: Vec<String>,
+    /// Roles of the database.
+    roles: Vec<CreateRole>,
+    /// Schemas of the database.
+    schemas: Vec<Schema>,
+    /// Extensions installed in the database.
+    extensions: Vec<Extension>,
+    /// User-defined types of the database.
+    custom_types: Vec<CustomType>,
+    /// Functions of the database, built-in functions excluded.
+    functions: Vec<FunctionSnapshot>,
+    /// Sequences of the database.
+    sequences: Vec<Sequence>,
+    /// Tables of the database, referenced tables first unless their foreign
+    /// keys form a cycle.
+    tables: Vec<TableSnapshot>,
+    /// Indices of the tables, in the order of their tables.
+    indices: Vec<IndexSnapshot>,
+    /// Views of the database.
+    views: Vec<CreateView>,
+    /// Triggers of the database.
+    triggers: Vec<CreateTrigger>,
+    /// Row-level security policies of the database.
+    policies: Vec<CreatePolicy>,
+    /// Grants of privileges on tables.
+    table_grants: Vec<Grant>,
+    /// Grants of privileges on columns.
+    column_grants: Vec<Grant>,
+    /// Default privileges granted on the tables created in the future.
+    default_privileges: Vec<DefaultPrivilege>,
+}
+
+impl SchemaSnapshot {
+    /// Takes the snapshot of the provided database.
+    pub(crate) fn new(database: &ParserDB) -> Self {
+        let graph = database.table_dependency_graph();
+        // Tables whose foreign keys form a cycle cannot all come after the
+        // tables they reference, and are sorted by schema and name instead.
+        let tables = graph.topological_order().unwrap_or_else(|_| {
+            let mut tables = graph.tables().to_vec();
+            tables.sort_by(|left, right| {
+                (left.table_schema(), left.table_name())
+                    .cmp(&(right.table_schema(), right.table_name()))
+            });
+            tables
+        });
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            catalog_name: database.catalog_name().to_string(),
+            dialect: *DatabaseLike::dialect(database),
+            timezone: database.timezone().map(ToString::to_string),
+            search_path: database.search_path().to_vec(),
+            roles: database.roles().cloned().collect(),
+            schemas: DatabaseLike::schemas(database).cloned().collect(),
+            extensions: database.extensions().to_vec(),
+            custom_types: database.custom_types().cloned().collect(),
+            functions: database
+                .functions()
+                .filter(|function| !function.is_builtin(database))
+                .map(|function| {
+                    FunctionSnapshot {
+                        definition: function.clone(),
+                        comment: database
+                            .function_metadata(function)
+                            .and_then(FunctionMetadata::comment)
+                            .map(ToString::to_string),
+                    }
+                })
+                .collect(),
+            sequences: database.sequences().cloned().collect(),
+            indices: tables
+                .iter()
+                .flat_map(|table| table.indices(database))
+                .map(|index| {
+                    IndexSnapshot {
+                        definition: index.attribute().clone(),
+                        clustered: index.is_clustered(database),
+                    }
+                })
+                .collect(),
+            tables: tables.iter().map(|table| TableSnapshot::new(table, database)).collect(),
+            views: database.views().cloned().collect(),
+            triggers: database.triggers().cloned().collect(),
+            policies: database.policies().cloned().collect(),
+            table_grants: database.table_grants().cloned().collect(),
+            column_grants: database.column_grants().cloned().collect(),
+            default_privileges: database.default_privileges().cloned().collect(),
+        }
+    }
+
+    /// Returns the version of the format of the snapshot.
+    #[must_use]
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Returns the name of the catalog of the database.
+    #[must_use]
+    pub fn catalog_name(&self) -> &str {
+        &self.catalog_name
+    }
+
+    /// Returns the dialect the database was parsed under.
+    #[must_use]
+    pub fn dialect(&self) -> SqlparserDialect {
+        self.dialect
+    }
+
+    /// Returns the timezone of the database, if set.
+    #[must_use]
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+
+    /// Returns the schemas searched, in order, for the objects referred to
+    /// without a schema.
+    #[must_use]
+    pub fn search_path(&self) -> &[String] {
+        &self.search_path
+    }
+
+    /// Returns the roles of the database.
+    #[must_use]
+    pub fn roles(&self) -> &[CreateRole] {
+        &self.roles
+    }
+
+    /// Returns the schemas of the database.
+    #[must_use]
+    pub fn schemas(&self) -> &[Schema] {
+        &self.schemas
+    }
+
+    /// Returns the extensions installed in the database.
+    #[must_use]
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    /// Returns the user-defined types of the database.
+    #[must_use]
+    pub fn custom_types(&self) -> &[CustomType] {
+        &self.custom_types
+    }
+
+    /// Returns the functions of the database, built-in functions excluded.
+    #[must_use]
+    pub fn functions(&self) -> &[FunctionSnapshot] {
+        &self.functions
+    }
+
+    /// Returns the sequences of the database.
+    #[must_use]
+    pub fn sequences(&self) -> &[Sequence] {
+        &self.sequences
+    }
+
+    /// Returns the snapshots of the tables of the database, in the order of
+    /// [`TableDependencyGraph::topological_order`] so that referenced tables
+    /// come first, or sorted by schema and name when their foreign keys form
+    /// a cycle.
+    ///
+    /// [`TableDependencyGraph::topological_order`]: crate::structs::TableDependencyGraph::topological_order
+    #[must_use]
+    pub fn tables(&self) -> &[TableSnapshot] {
+        &self.tables
+    }
+
+    /// Returns the snapshots of the indices of the tables, in the order of
+    /// their tables.
+    #[must_use]
+    pub fn indices(&self) -> &[IndexSnapshot] {
+        &self.indices
+    }
+
+    /// Returns the views of the database.
+    #[must_use]
+    pub fn views(&self) -> &[CreateView] {
+        &self.views
+    }
+
+    /// Returns the triggers of the database.
+    #[must_use]
+    pub fn triggers(&self) -> &[CreateTrigger] {
+        &self.triggers
+    }
+
+    /// Returns the row-level security policies of the database.
+    #[must_use]
+    pub fn policies(&self) -> &[CreatePolicy] {
+        &self.policies
+    }
+
+    /// Returns the grants of privileges on tables.
+    #[must_use]
+    pub fn table_grants(&self) -> &[Grant] {
+        &self.table_grants
+    }
+
+    /// Returns the grants of privileges on columns.
+    #[must_use]
+    pub fn column_grants(&self) -> &[Grant] {
+        &self.column_grants
+    }
+
+    /// Returns the default privileges granted on the tables created in the
+    /// future.
+    #[must_use]
+    pub fn default_privileges(&self) -> &[DefaultPrivilege] {
+        &self.default_privileges
+    }
+}
+
+/// Snapshot of a function within a [`SchemaSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSnapshot {
+    /// Statement creating the function.
+    definition: CreateFunction,
+    /// Comment set on the function, if any.
+    comment: Option<String>,
+}
+
+impl FunctionSnapshot {
+    /// Returns the statement creating the function.
+    #[must_use]
+    pub fn definition(&self) -> &CreateFunction {
+        &self.definition
+    }
+
+    /// Returns the comment set on the function, if any.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
+
+/// Snapshot of an index within a [`SchemaSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexSnapshot {
+    /// Statement creating the index.
+    definition: CreateIndex,
+    /// Whether the index is clustered.
+    clustered: bool,
+}
+
+impl IndexSnapshot {
+    /// Returns the statement creating the index.
+    #[must_use]
+    pub fn definition(&self) -> &CreateIndex {
+        &self.definition
+    }
+
+    /// Returns whether the index is clustered.
+    #[must_use]
+    pub fn is_clustered(&self) -> bool {
+        self.clustered
+    }
+}
+
+/// Snapshot of a table within a [`SchemaSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSnapshot {
+    /// Schema of the table, if qualified.
+    schema: Option<String>,
+    /// Name of the table.
+    name: String,
+    /// Statement creating the table as it stands, with its current columns
+    /// and constraints.
+    definition: CreateTable,
+    /// Comment set on the table, if any.
+    comment: Option<String>,
+    /// Whether row-level security is enabled on the table.
+    row_level_security: bool,
+    /// Whether row-level security is forced on the table.
+    forced_row_level_security: bool,
+    /// Check constraints of the table declared `NO INHERIT`.
+    no_inherit_checks: Vec<CheckConstraint>,
+    /// Default privileges granted on the table when it was created.
+    default_privileges: Vec<DefaultPrivilege>,
+    /// Columns of the table, in declaration order.
+    columns: Vec<ColumnSnapshot>,
+    /// Names of the columns of the primary key of the table.
+    primary_key: Vec<String>,
+    /// Foreign keys of the table.
+    foreign_keys: Vec<ForeignKeySnapshot>,
+}
+
+impl TableSnapshot {
+    /// Takes the snapshot of the provided table of the provided database.
+    fn new(table: &<ParserDB as DatabaseLike>::Table, database: &ParserDB) -> Self {
+        let metadata = database.table_metadata(table);
+        Self {
+            schema: table.table_schema().map(ToString::to_string),
+            name: table.table_name().to_string(),
+            definition: database.table_definition(table),
+            comment: metadata.and_then(TableMetadata::comment).map(ToString::to_string),
+            row_level_security: table.has_row_level_security(database),
+            forced_row_level_security: table.has_forced_row_level_security(database),
+            no_inherit_checks: table
+                .check_constraints(database)
+                .filter(|check| check.no_inherit(database))
+                .map(|check| check.attribute().clone())
+                .collect(),
+            default_privileges: table.default_privileges(database).cloned().collect(),
+            columns: table
+                .columns(database)
+                .map(|column| {
+                    ColumnSnapshot {
+                        name: column.column_name().to_string(),
+                        data_type: column.data_type(database).to_string(),
+                        nullable: column.is_nullable(database),
+                        default_value: column.default_value(),
+                        comment: metadata
+                            .and_then(|metadata| metadata.column_comment(column.column_name()))
+                            .map(ToString::to_string),
+                    }
+                })
+                .collect(),
+            primary_key: column_names(table.primary_key_columns(database)),
+            foreign_keys: table
+                .foreign_keys(database)
+                .filter_map(|foreign_key| {
+                    let referenced_table = foreign_key.referenced_table(database)?;
+                    Some(ForeignKeySnapshot {
+                        name: foreign_key.foreign_key_name().map(ToString::to_string),
+                        columns: column_names(foreign_key.host_columns(database)),
+                        referenced_schema: referenced_table.table_schema().map(ToString::to_string),
+                        referenced_table: referenced_table.table_name().to_string(),
+                        referenced_columns: column_names(foreign_key.referenced_columns(database)),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the schema of the table, if qualified.
+    #[must_use]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the name of the table.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the statement creating the table as it stands, with its
+    /// current columns and constraints, the constraints declared inline
+    /// being moved to the table.
+    #[must_use]
+    pub fn definition(&self) -> &CreateTable {
+        &self.definition
+    }
+
+    /// Returns the comment set on the table, if any.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Returns whether row-level security is enabled on the table.
+    #[must_use]
+    pub fn has_row_level_security(&self) -> bool {
+        self.row_level_security
+    }
+
+    /// Returns whether row-level security is forced on the table.
+    #[must_use]
+    pub fn has_forced_row_level_security(&self) -> bool {
+        self.forced_row_level_security
+    }
+
+    /// Returns the check constraints of the table declared `NO INHERIT`.
+    #[must_use]
+    pub fn no_inherit_checks(&self) -> &[CheckConstraint] {
+        &self.no_inherit_checks
+    }
+
+    /// Returns the default privileges granted on the table when it was
+    /// created.
+    #[must_use]
+    pub fn default_privileges(&self) -> &[DefaultPrivilege] {
+        &self.default_privileges
+    }
+
+    /// Returns the columns of the table, in declaration order.
+    #[must_use]
+    pub fn columns(&self) -> &[ColumnSnapshot] {
+        &self.columns
+    }
+
+    /// Returns the names of the columns of the primary key of the table.
+    #[must_use]
+    pub fn primary_key(&self) -> &[String] {
+        &self.primary_key
+    }
+
+    /// Returns the foreign keys of the table.
+    #[must_use]
+    pub fn foreign_keys(&self) -> &[ForeignKeySnapshot] {
+        &self.foreign_keys
+    }
+}
+
+/// Collects the names of the provided columns.
+fn column_names<'db>(
+    columns: impl Iterator<Item = &'db <ParserDB as DatabaseLike>::Column>,
+) -> Vec<String> {
+    columns.map(|column| column.column_name().to_string()).collect()
+}
+
+/// Summary of a column within a [`TableSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnSnapshot {
+    /// Name of the column.
+    name: String,
+    /// Data type of the column, as written in SQL.
+    data_type: String,
+    /// Whether the column accepts `NULL` values.
+    nullable: bool,
+    /// Default value of the column, if any.
+    default_value: Option<String>,
+    /// Comment set on the column, if any.
+    comment: Option<String>,
+}
+
+impl ColumnSnapshot {
+    /// Returns the name of the column.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the data type of the column, as written in SQL.
+    #[must_use]
+    pub fn data_type(&self) -> &str {
+        &self.data_type
+    }
+
+    /// Returns whether the column accepts `NULL` values.
+    #[must_use]
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Returns the default value of the column, if any.
+    #[must_use]
+    pub fn default_value(&self) -> Option<&str> {
+        self.default_value.as_deref()
+    }
+
+    /// Returns the comment set on the column, if any.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
+
+/// Summary of a foreign key within a [`TableSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForeignKeySnapshot {
+    /// Name of the foreign key constraint, if named.
+    name: Option<String>,
+    /// Names of the columns of the table holding the foreign key.
+    columns: Vec<String>,
+    /// Schema of the referenced table, if qualified.
+    referenced_schema: Option<String>,
+    /// Name of the referenced table.
+    referenced_table: String,
+    /// Names of the referenced columns, matching the columns in order.
+    referenced_columns: Vec<String>,
+}
+
+impl ForeignKeySnapshot {
+    /// Returns the name of the foreign key constraint, if named.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the names of the columns of the table holding the foreign
+    /// key.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the schema of the referenced table, if qualified.
+    #[must_use]
+    pub fn referenced_schema(&self) -> Option<&str> {
+        self.referenced_schema.as_deref()
+    }
+
+    /// Returns the name of the referenced table.
+    #[must_use]
+    pub fn referenced_table(&self) -> &str {
+        &self.referenced_table
+    }
+
+    /// Returns the names of the referenced columns, matching the columns in
+    /// order.
+    #[must_use]
+    pub fn referenced_columns(&self) -> &[String] {
+        &self.referenced_columns
+    }
+}
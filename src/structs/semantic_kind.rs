@@ -0,0 +1,176 @@
+//! Submodule defining the `SemanticKind` enum, classifying the role a column
+//! plays in its table, and the `SemanticRules` struct the classification
+//! follows.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The role a column plays in its table, as returned by
+/// [`ColumnLike::semantic_kind`](crate::traits::ColumnLike::semantic_kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticKind {
+    /// A timestamp recording when the row was created.
+    CreatedAt,
+    /// A timestamp recording when the row was last updated.
+    UpdatedAt,
+    /// A universally unique identifier.
+    Uuid,
+    /// A boolean, or a nullable timestamp, marking the row as deleted without
+    /// removing it.
+    SoftDeleteFlag,
+}
+
+/// Rules classifying the columns into [`SemanticKind`]s.
+///
+/// A column is classified, in order, as:
+///
+/// 1. [`SemanticKind::CreatedAt`] when it is a timestamp named as a creation
+///    timestamp;
+/// 2. [`SemanticKind::UpdatedAt`] when it is a timestamp named as an update
+///    timestamp, or assigned by a maintenance trigger firing on `UPDATE`;
+/// 3. [`SemanticKind::SoftDeleteFlag`] when it is a boolean or a nullable
+///    timestamp named as a soft-delete flag;
+/// 4. [`SemanticKind::Uuid`] when it is a `UUID`, or defaults to a call to a
+///    UUID generating function.
+///
+/// Names are compared case-insensitively, and each list of names can be
+/// replaced to follow the conventions of a schema.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE posts (id INT, written TIMESTAMP, published_at TIMESTAMP);",
+/// )?;
+/// let table = db.table(None, "posts").unwrap();
+/// let written = table.column("written", &db).unwrap();
+/// assert_eq!(written.semantic_kind(&db), None);
+///
+/// let rules = SemanticRules::default().with_created_at_names(["written"]);
+/// assert_eq!(written.semantic_kind_with_rules(&db, &rules), Some(SemanticKind::CreatedAt));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SemanticRules {
+    /// The names of the creation timestamps.
+    created_at_names: Vec<String>,
+    /// The names of the update timestamps.
+    updated_at_names: Vec<String>,
+    /// The names of the soft-delete flags.
+    soft_delete_names: Vec<String>,
+    /// The names of the functions generating UUIDs.
+    uuid_functions: Vec<String>,
+}
+
+/// Collects the provided names into owned strings.
+fn to_names<S: ToString>(names: impl IntoIterator<Item = S>) -> Vec<String> {
+    names.into_iter().map(ToString::to_string).collect()
+}
+
+impl Default for SemanticRules {
+    fn default() -> Self {
+        Self {
+            created_at_names: to_names([
+                "created_at",
+                "created_on",
+                "creation_date",
+                "inserted_at",
+                "created",
+            ]),
+            updated_at_names: to_names([
+                "updated_at",
+                "updated_on",
+                "modified_at",
+                "last_modified",
+                "updated",
+            ]),
+            soft_delete_names: to_names([
+                "deleted_at",
+                "deleted",
+                "is_deleted",
+                "archived_at",
+                "is_archived",
+            ]),
+            uuid_functions: to_names([
+                "gen_random_uuid",
+                "uuid_generate_v1",
+                "uuid_generate_v1mc",
+                "uuid_generate_v4",
+                "uuid_generate_v7",
+                "uuidv4",
+                "uuidv7",
+            ]),
+        }
+    }
+}
+
+impl SemanticRules {
+    /// Replaces the names of the creation timestamps.
+    #[must_use]
+    pub fn with_created_at_names<S: ToString>(
+        mut self,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.created_at_names = to_names(names);
+        self
+    }
+
+    /// Replaces the names of the update timestamps.
+    #[must_use]
+    pub fn with_updated_at_names<S: ToString>(
+        mut self,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.updated_at_names = to_names(names);
+        self
+    }
+
+    /// Replaces the names of the soft-delete flags.
+    #[must_use]
+    pub fn with_soft_delete_names<S: ToString>(
+        mut self,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.soft_delete_names = to_names(names);
+        self
+    }
+
+    /// Replaces the names of the functions generating UUIDs, recognized in
+    /// the default values of the columns.
+    #[must_use]
+    pub fn with_uuid_functions<S: ToString>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.uuid_functions = to_names(names);
+        self
+    }
+
+    /// Returns whether the column name is the name of a creation timestamp.
+    pub(crate) fn is_created_at_name(&self, column_name: &str) -> bool {
+        self.created_at_names.iter().any(|name| name.eq_ignore_ascii_case(column_name))
+    }
+
+    /// Returns whether the column name is the name of an update timestamp.
+    pub(crate) fn is_updated_at_name(&self, column_name: &str) -> bool {
+        self.updated_at_names.iter().any(|name| name.eq_ignore_ascii_case(column_name))
+    }
+
+    /// Returns whether the column name is the name of a soft-delete flag.
+    pub(crate) fn is_soft_delete_name(&self, column_name: &str) -> bool {
+        self.soft_delete_names.iter().any(|name| name.eq_ignore_ascii_case(column_name))
+    }
+
+    /// Returns whether the default value calls a function generating UUIDs,
+    /// as in `gen_random_uuid()` or `public.uuid_generate_v4()`.
+    pub(crate) fn generates_uuid(&self, default_value: &str) -> bool {
+        let Some((function, _)) = default_value.split_once('(') else {
+            return false;
+        };
+        let function = function.rsplit('.').next().unwrap_or(function).trim().trim_matches('"');
+        self.uuid_functions.iter().any(|name| name.eq_ignore_ascii_case(function))
+    }
+}
@@ -0,0 +1,161 @@
+//! Sequence struct for storing parsed sequence information.
+
+use alloc::string::String;
+
+use sqlparser::ast::{Ident, ObjectName};
+
+/// A database sequence parsed from a CREATE SEQUENCE statement.
+///
+/// This struct stores the sequence name, its optional schema, whether it is
+/// temporary or unlogged and the column owning the sequence, if any.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sequence {
+    /// The name of the sequence.
+    name: String,
+    /// Whether the sequence identifier was quoted in SQL.
+    quoted: bool,
+    /// The schema of the sequence, with whether it was quoted in SQL.
+    schema: Option<(String, bool)>,
+    /// Whether the sequence was created with `CREATE TEMPORARY SEQUENCE`.
+    temporary: bool,
+    /// Whether the sequence was created with `CREATE UNLOGGED SEQUENCE`.
+    unlogged: bool,
+    /// The table and column owning the sequence, if specified.
+    owned_by: Option<(ObjectName, Ident)>,
+}
+
+impl Sequence {
+    /// Creates a new `Sequence` with the given name and quoted-name metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Sequence;
+    ///
+    /// let s = Sequence::new("users_id_seq".to_string(), false);
+    /// assert_eq!(s.name(), "users_id_seq");
+    /// assert!(!s.is_quoted());
+    /// assert_eq!(s.schema(), None);
+    /// assert!(!s.is_temporary());
+    /// assert!(s.owned_by().is_none());
+    /// ```
+    #[must_use]
+    pub fn new(name: String, quoted: bool) -> Self {
+        Self { name, quoted, schema: None, temporary: false, unlogged: false, owned_by: None }
+    }
+
+    /// Sets the schema of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Sequence;
+    ///
+    /// let s = Sequence::new("users_id_seq".to_string(), false).with_schema("App".to_string(), true);
+    /// assert_eq!(s.schema(), Some("App"));
+    /// assert!(s.schema_is_quoted());
+    /// ```
+    #[must_use]
+    pub fn with_schema(mut self, schema: String, quoted: bool) -> Self {
+        self.schema = Some((schema, quoted));
+        self
+    }
+
+    /// Sets whether the sequence is temporary, i.e. dropped at the end of the
+    /// session creating it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Sequence;
+    ///
+    /// let s = Sequence::new("scratch_seq".to_string(), false).with_temporary(true);
+    /// assert!(s.is_temporary());
+    /// ```
+    #[must_use]
+    pub fn with_temporary(mut self, temporary: bool) -> Self {
+        self.temporary = temporary;
+        self
+    }
+
+    /// Sets whether the sequence is unlogged, i.e. its changes are not written
+    /// to the write-ahead log.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Sequence;
+    ///
+    /// let s = Sequence::new("cache_seq".to_string(), false).with_unlogged(true);
+    /// assert!(s.is_unlogged());
+    /// ```
+    #[must_use]
+    pub fn with_unlogged(mut self, unlogged: bool) -> Self {
+        self.unlogged = unlogged;
+        self
+    }
+
+    /// Sets the table and column owning the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::Sequence;
+    /// use sqlparser::ast::{Ident, ObjectName};
+    ///
+    /// let s = Sequence::new("users_id_seq".to_string(), false)
+    ///     .with_owner(ObjectName::from(vec![Ident::new("users")]), Ident::new("id"));
+    /// let (table, column) = s.owned_by().unwrap();
+    /// assert_eq!(table.to_string(), "users");
+    /// assert_eq!(column.value, "id");
+    /// ```
+    #[must_use]
+    pub fn with_owner(mut self, table: ObjectName, column: Ident) -> Self {
+        self.owned_by = Some((table, column));
+        self
+    }
+
+    /// Returns the name of the sequence.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether this sequence name was quoted in SQL.
+    #[must_use]
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the schema of the sequence, if specified.
+    #[must_use]
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_ref().map(|(schema, _)| schema.as_str())
+    }
+
+    /// Returns whether the schema of the sequence was quoted in SQL.
+    #[must_use]
+    pub fn schema_is_quoted(&self) -> bool {
+        self.schema.as_ref().is_some_and(|(_, quoted)| *quoted)
+    }
+
+    /// Returns whether the sequence is temporary.
+    #[must_use]
+    pub fn is_temporary(&self) -> bool {
+        self.temporary
+    }
+
+    /// Returns whether the sequence is unlogged.
+    #[must_use]
+    pub fn is_unlogged(&self) -> bool {
+        self.unlogged
+    }
+
+    /// Returns the table name and column identifier owning the sequence, if
+    /// specified via `OWNED BY`.
+    #[must_use]
+    pub fn owned_by(&self) -> Option<(&ObjectName, &Ident)> {
+        self.owned_by.as_ref().map(|(table, column)| (table, column))
+    }
+}
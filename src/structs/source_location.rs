@@ -0,0 +1,103 @@
+//! Submodule defining the `SourceLocation` struct, pointing at the statement
+//! a schema object was created by.
+
+use alloc::sync::Arc;
+use core::fmt::Display;
+
+use sqlparser::tokenizer::Span;
+
+/// Location of the statement creating a schema object in the SQL it was
+/// parsed from.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let db = ParserDB::parse::<PostgreSqlDialect>(
+///     "CREATE TABLE a (id INT);\nCREATE TABLE b (id INT);",
+/// )?;
+/// let location = db.table(None, "b").unwrap().source_location(&db).unwrap();
+/// assert_eq!(location.path(), None);
+/// assert_eq!(location.statement_index(), 1);
+/// assert_eq!(location.line(), Some(2));
+/// assert_eq!(location.to_string(), "2:14");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// Path of the file the statement was read from, if any.
+    path: Option<Arc<str>>,
+    /// Index of the statement among the statements parsed from its file.
+    statement_index: usize,
+    /// Span of the name of the object, if known.
+    span: Option<Span>,
+}
+
+impl SourceLocation {
+    /// Creates a new location.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file the statement was read from, if any.
+    /// * `statement_index` - The index of the statement in its file.
+    /// * `span` - The span of the name of the object, if known.
+    #[must_use]
+    pub fn new(path: Option<Arc<str>>, statement_index: usize, span: Option<Span>) -> Self {
+        Self { path, statement_index, span }
+    }
+
+    /// Returns the path of the file the statement was read from, if the
+    /// schema was loaded from files.
+    #[must_use]
+    #[inline]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Returns the zero-based index of the statement among the statements
+    /// parsed from its file.
+    #[must_use]
+    #[inline]
+    pub fn statement_index(&self) -> usize {
+        self.statement_index
+    }
+
+    /// Returns the span of the name of the object, if known.
+    #[must_use]
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Returns the one-based line the name of the object starts on, if
+    /// known.
+    #[must_use]
+    #[inline]
+    pub fn line(&self) -> Option<u64> {
+        self.span.map(|span| span.start.line)
+    }
+
+    /// Returns the one-based column the name of the object starts at, if
+    /// known.
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> Option<u64> {
+        self.span.map(|span| span.start.column)
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{path}:")?;
+        }
+        match self.span {
+            Some(span) => write!(f, "{}:{}", span.start.line, span.start.column),
+            None => write!(f, "statement {}", self.statement_index),
+        }
+    }
+}
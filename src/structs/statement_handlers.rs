@@ -0,0 +1,154 @@
+//! Submodule defining the `StatementHandlers` registry, applying the
+//! statements of the registered kinds in place of the built-in processing.
+
+use alloc::{collections::BTreeMap, string::String, sync::Arc};
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+use sqlparser::ast::Statement;
+
+use crate::{errors::Error, structs::ParserDBBuilder};
+
+/// Handler applying a statement to the builder of a database, used by
+/// [`StatementHandlers`].
+pub type StatementHandler =
+    dyn Fn(ParserDBBuilder, &Statement) -> Result<ParserDBBuilder, Error> + Send + Sync;
+
+/// Registry of the [`StatementHandler`]s applying the statements of given
+/// kinds, set through [`ParserOptions::with_statement_handler`].
+///
+/// The kind of a statement is the name of its [`Statement`] variant, such as
+/// `CreateProcedure` or `CreateDatabase`, as returned by
+/// [`StatementHandlers::kind_of`]. A statement of a registered kind is
+/// applied by its handler in place of the built-in processing, so that
+/// vendor-specific objects can be modelled and unsupported statements
+/// accepted.
+///
+/// [`ParserOptions::with_statement_handler`]: crate::structs::ParserOptions::with_statement_handler
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::sync::Arc;
+///
+/// use sql_traits::prelude::*;
+/// use sqlparser::{ast::Statement, dialect::MySqlDialect};
+///
+/// // MySQL databases are modelled as schemas.
+/// let options =
+///     ParserOptions::default().with_statement_handler("CreateDatabase", |builder, statement| {
+///         let Statement::CreateDatabase { db_name, .. } = statement else {
+///             return Ok(builder);
+///         };
+///         Ok(builder.add_schema(Arc::new(Schema::new(db_name.to_string())), ()))
+///     });
+/// let db = ParserDB::parse_with_options::<MySqlDialect>(
+///     "CREATE DATABASE library; CREATE TABLE library.books (id INT);",
+///     options,
+/// )?;
+/// assert!(db.schema("library").is_some());
+/// assert!(db.table(Some("library"), "books").is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct StatementHandlers {
+    /// Handlers, keyed by the kind of the statements they apply.
+    handlers: BTreeMap<String, Arc<StatementHandler>>,
+}
+
+impl StatementHandlers {
+    /// Registers the handler applying the statements of the provided kind,
+    /// replacing any handler previously registered for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The name of the [`Statement`] variant, such as
+    ///   `CreateProcedure`.
+    /// * `handler` - The handler applying the statements of that kind.
+    #[must_use]
+    pub fn with_handler(
+        mut self,
+        kind: &str,
+        handler: impl Fn(ParserDBBuilder, &Statement) -> Result<ParserDBBuilder, Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.handlers.insert(String::from(kind), Arc::new(handler));
+        self
+    }
+
+    /// Returns the handler registered for the kind of the provided
+    /// statement, if any.
+    #[must_use]
+    pub fn handler(&self, statement: &Statement) -> Option<Arc<StatementHandler>> {
+        if self.handlers.is_empty() {
+            return None;
+        }
+        self.handlers.get(&Self::kind_of(statement)).cloned()
+    }
+
+    /// Returns whether no handler is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Returns the kinds of the statements a handler is registered for.
+    pub fn kinds(&self) -> impl Iterator<Item = &str> {
+        self.handlers.keys().map(String::as_str)
+    }
+
+    /// Returns the kind of the provided statement, that is the name of its
+    /// [`Statement`] variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sql_traits::structs::StatementHandlers;
+    /// use sqlparser::{dialect::GenericDialect, parser::Parser};
+    ///
+    /// let sql = "CREATE TABLE t (id INT); INSERT INTO t VALUES (1);";
+    /// let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+    /// assert_eq!(StatementHandlers::kind_of(&statements[0]), "CreateTable");
+    /// assert_eq!(StatementHandlers::kind_of(&statements[1]), "Insert");
+    /// ```
+    #[must_use]
+    pub fn kind_of(statement: &Statement) -> String {
+        format!("{statement:?}").chars().take_while(char::is_ascii_alphanumeric).collect()
+    }
+}
+
+impl Debug for StatementHandlers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.kinds()).finish()
+    }
+}
+
+/// Registries are equal when they share the same handlers for the same
+/// kinds.
+impl PartialEq for StatementHandlers {
+    fn eq(&self, other: &Self) -> bool {
+        self.handlers.len() == other.handlers.len()
+            && self.handlers.iter().zip(&other.handlers).all(
+                |((kind, handler), (other_kind, other_handler))| {
+                    kind == other_kind && Arc::ptr_eq(handler, other_handler)
+                },
+            )
+    }
+}
+
+impl Eq for StatementHandlers {}
+
+impl Hash for StatementHandlers {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (kind, handler) in &self.handlers {
+            kind.hash(state);
+            Arc::as_ptr(handler).cast::<()>().hash(state);
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! Submodule defining the `TableDependencyGraph` struct, describing the
+//! dependencies between the tables of a database induced by their foreign
+//! keys and by the sequences their column defaults use.
+
+use alloc::{collections::VecDeque, vec::Vec};
+use core::fmt::Display;
+
+use crate::{
+    traits::{DatabaseLike, ForeignKeyLike, SequenceLike, TableLike},
+    utils::graph::{kahn_ordering, strongly_connected_components, successors},
+};
+
+/// Cycle of foreign keys between tables, which prevents ordering them so
+/// that every table comes after the tables it references.
+#[derive(Debug, Clone)]
+pub struct TableCycle<'db, DB: DatabaseLike> {
+    /// The tables of the cycle, each referencing the next one and the last
+    /// one referencing the first one.
+    tables: Vec<&'db DB::Table>,
+}
+
+impl<'db, DB: DatabaseLike> TableCycle<'db, DB> {
+    /// Returns the tables of the cycle, each referencing the next one and the
+    /// last one referencing the first one.
+    #[must_use]
+    #[inline]
+    pub fn tables(&self) -> &[&'db DB::Table] {
+        &self.tables
+    }
+}
+
+impl<DB: DatabaseLike> Display for TableCycle<'_, DB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Foreign keys form a cycle between tables")?;
+        for (position, table) in self.tables.iter().chain(self.tables.first()).enumerate() {
+            let separator = if position == 0 { " " } else { " -> " };
+            match table.table_schema() {
+                Some(schema) => write!(f, "{separator}`{schema}.{}`", table.table_name())?,
+                None => write!(f, "{separator}`{}`", table.table_name())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> core::error::Error for TableCycle<'_, DB> {}
+
+/// Graph of the dependencies between the tables of a database.
+///
+/// A table depends on the tables its foreign keys reference, and on the
+/// tables owning the sequences its column defaults advance, as such sequences
+/// are created along with their owning table. The edges of the graph go from
+/// each referenced table to the tables referencing it. Self-references are
+/// ignored, as they do not constrain the order in which tables can be
+/// created.
+#[derive(Debug, Clone)]
+pub struct TableDependencyGraph<'db, DB: DatabaseLike> {
+    /// The tables of the database, in the order of the database.
+    tables: Vec<&'db DB::Table>,
+    /// The sorted `(referenced, referencing)` edges, as indices into
+    /// `tables`.
+    edges: Vec<(usize, usize)>,
+}
+
+impl<'db, DB: DatabaseLike> TableDependencyGraph<'db, DB> {
+    /// Builds the dependency graph of the tables of the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to build the graph for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// ",
+    /// )?;
+    /// let graph = TableDependencyGraph::new(&db);
+    /// let tables: Vec<&str> = graph.tables().iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(tables, ["posts", "users"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB) -> Self {
+        let tables: Vec<&'db DB::Table> = database.tables().collect();
+        let mut edges: Vec<(usize, usize)> = tables
+            .iter()
+            .enumerate()
+            .flat_map(|(table_number, table)| {
+                table.foreign_keys(database).filter_map(move |foreign_key| {
+                    let referenced_table = foreign_key.referenced_table(database)?;
+                    if referenced_table == *table {
+                        return None;
+                    }
+                    database
+                        .table_id(referenced_table)
+                        .map(|referenced_table_number| (referenced_table_number, table_number))
+                })
+            })
+            .chain(tables.iter().enumerate().flat_map(|(table_number, table)| {
+                // A sequence owned by a column of another table is created
+                // along with that table, and before the defaults using it.
+                table.default_sequences(database).into_iter().filter_map(move |sequence| {
+                    let owner_table = sequence.owner_table(database)?;
+                    if owner_table == *table {
+                        return None;
+                    }
+                    database.table_id(owner_table).map(|owner_number| (owner_number, table_number))
+                })
+            }))
+            .collect();
+        // Tables may reference the same table through several foreign keys.
+        edges.sort_unstable();
+        edges.dedup();
+
+        Self { tables, edges }
+    }
+
+    /// Returns the tables of the graph, in the order of the database.
+    #[must_use]
+    #[inline]
+    pub fn tables(&self) -> &[&'db DB::Table] {
+        &self.tables
+    }
+
+    /// Returns the edges leaving the table at the provided index.
+    fn successors(&self, table_number: usize) -> &[(usize, usize)] {
+        successors(&self.edges, table_number)
+    }
+
+    /// Returns the index of the provided table in the graph, if any.
+    fn table_number(&self, table: &DB::Table) -> Option<usize> {
+        self.tables.iter().position(|candidate| *candidate == table)
+    }
+
+    /// Returns an iterator over the tables referenced by the provided table,
+    /// excluding the table itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The referencing table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, manager_id INT REFERENCES users(id));
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// ",
+    /// )?;
+    /// let graph = db.table_dependency_graph();
+    /// let posts = db.table(None, "posts").unwrap();
+    /// let users = db.table(None, "users").unwrap();
+    /// assert_eq!(graph.dependencies(posts).collect::<Vec<_>>(), [users]);
+    /// assert_eq!(graph.dependencies(users).count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dependencies<'a>(
+        &'a self,
+        table: &'a DB::Table,
+    ) -> impl Iterator<Item = &'db DB::Table> + 'a {
+        let table_number = self.table_number(table);
+        self.edges
+            .iter()
+            .filter(move |(_, referencing)| Some(*referencing) == table_number)
+            .map(|(referenced, _)| self.tables[*referenced])
+    }
+
+    /// Returns an iterator over the tables referencing the provided table,
+    /// excluding the table itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The referenced table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// ",
+    /// )?;
+    /// let graph = db.table_dependency_graph();
+    /// let posts = db.table(None, "posts").unwrap();
+    /// let users = db.table(None, "users").unwrap();
+    /// assert_eq!(graph.dependents(users).collect::<Vec<_>>(), [posts]);
+    /// assert_eq!(graph.dependents(posts).count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dependents<'a>(
+        &'a self,
+        table: &'a DB::Table,
+    ) -> impl Iterator<Item = &'db DB::Table> + 'a {
+        self.table_number(table)
+            .map(|table_number| self.successors(table_number))
+            .unwrap_or_default()
+            .iter()
+            .map(|(_, referencing)| self.tables[*referencing])
+    }
+
+    /// Returns the strongly connected components of the graph, as indices
+    /// into `tables`, with the referenced components first.
+    fn component_numbers(&self) -> Vec<Vec<usize>> {
+        strongly_connected_components(self.tables.len(), &self.edges)
+    }
+
+    /// Returns the strongly connected components of the graph, i.e. the
+    /// maximal groups of tables which transitively reference each other.
+    ///
+    /// Every table belongs to exactly one component, and components with more
+    /// than one table are cycles of foreign keys. The components are sorted so
+    /// that each component comes after the components it references, and the
+    /// tables of each component are in the order of the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+    /// CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users(id));
+    /// ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams(id);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// ",
+    /// )?;
+    /// let components: Vec<Vec<&str>> = db
+    ///     .table_dependency_graph()
+    ///     .strongly_connected_components()
+    ///     .into_iter()
+    ///     .map(|component| component.into_iter().map(|t| t.table_name()).collect())
+    ///     .collect();
+    /// assert_eq!(components, [vec!["teams", "users"], vec!["posts"]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&'db DB::Table>> {
+        self.component_numbers()
+            .into_iter()
+            .map(|component| component.into_iter().map(|member| self.tables[member]).collect())
+            .collect()
+    }
+
+    /// Returns a cycle of foreign keys between the tables, if any.
+    ///
+    /// The cycle is among the tables of the first strongly connected
+    /// component with more than one table, starting from its first table and
+    /// going through as few tables as possible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+    /// CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users(id));
+    /// ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams(id);
+    /// ",
+    /// )?;
+    /// let cycle = db.table_dependency_graph().cycle().unwrap();
+    /// let tables: Vec<&str> = cycle.tables().iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(tables, ["teams", "users"]);
+    /// assert_eq!(
+    ///     cycle.to_string(),
+    ///     "Foreign keys form a cycle between tables `teams` -> `users` -> `teams`"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn cycle(&self) -> Option<TableCycle<'db, DB>> {
+        let component =
+            self.component_numbers().into_iter().find(|component| component.len() > 1)?;
+        let start = component[0];
+
+        // Breadth-first search of the shortest path from the start back to
+        // itself, through the referencing tables.
+        let mut parents = vec![None; self.tables.len()];
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for &(_, successor) in self.successors(node) {
+                if successor == start {
+                    let mut path = vec![node];
+                    let mut current = node;
+                    while let Some(parent) = parents[current] {
+                        path.push(parent);
+                        current = parent;
+                    }
+                    // The path goes from the last referencing table back to
+                    // the start, so each table references the next one once
+                    // the start is moved to the front.
+                    path.rotate_right(1);
+                    return Some(TableCycle {
+                        tables: path.into_iter().map(|member| self.tables[member]).collect(),
+                    });
+                }
+                if parents[successor].is_none() && component.binary_search(&successor).is_ok() {
+                    parents[successor] = Some(node);
+                    queue.push_back(successor);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the tables sorted so that every table comes after the tables
+    /// it references, as a Kahn's ordering.
+    ///
+    /// # Errors
+    ///
+    /// * If the foreign keys form a cycle, which is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// ",
+    /// )?;
+    /// let ordered: Vec<&str> = db
+    ///     .table_dependency_graph()
+    ///     .topological_order()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|t| t.table_name())
+    ///     .collect();
+    /// assert_eq!(ordered, ["users", "posts"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn topological_order(&self) -> Result<Vec<&'db DB::Table>, TableCycle<'db, DB>> {
+        match kahn_ordering(self.tables.len(), &self.edges) {
+            Some(ordering) => Ok(ordering.into_iter().map(|node| self.tables[node]).collect()),
+            // Self-references are not edges of the graph, so that the cycles
+            // preventing the ordering span several tables and are found.
+            None => Err(self.cycle().unwrap_or_else(|| TableCycle { tables: self.tables.clone() })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
+
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, TableLike},
+    };
+
+    fn names<'a>(
+        tables: impl IntoIterator<Item = &'a <ParserDB as DatabaseLike>::Table>,
+    ) -> Vec<&'a str> {
+        tables.into_iter().map(TableLike::table_name).collect()
+    }
+
+    #[test]
+    fn test_self_references_are_ignored() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE nodes (id INT PRIMARY KEY, parent_id INT REFERENCES nodes(id));",
+        )
+        .unwrap();
+        let graph = db.table_dependency_graph();
+        assert!(graph.cycle().is_none());
+        assert_eq!(names(graph.topological_order().unwrap()), ["nodes"]);
+        assert_eq!(graph.strongly_connected_components().len(), 1);
+    }
+
+    #[test]
+    fn test_shortest_cycle_is_reported() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE a (id INT PRIMARY KEY, b_id INT, d_id INT);
+            CREATE TABLE b (id INT PRIMARY KEY, c_id INT);
+            CREATE TABLE c (id INT PRIMARY KEY, a_id INT REFERENCES a(id));
+            CREATE TABLE d (id INT PRIMARY KEY, a_id INT REFERENCES a(id));
+            ALTER TABLE a ADD FOREIGN KEY (b_id) REFERENCES b(id);
+            ALTER TABLE b ADD FOREIGN KEY (c_id) REFERENCES c(id);
+            ALTER TABLE a ADD FOREIGN KEY (d_id) REFERENCES d(id);
+            CREATE TABLE e (id INT PRIMARY KEY, a_id INT REFERENCES a(id));
+            ",
+        )
+        .unwrap();
+        let graph = db.table_dependency_graph();
+        let cycle = graph.topological_order().unwrap_err();
+        assert_eq!(names(cycle.tables().iter().copied()), ["a", "d"]);
+        assert_eq!(
+            graph.strongly_connected_components().into_iter().map(names).collect::<Vec<_>>(),
+            [vec!["a", "b", "c", "d"], vec!["e"]]
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_topological_order_matches_table_dag() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "
+            CREATE TABLE users (id INT PRIMARY KEY);
+            CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+            CREATE TABLE comments (id INT PRIMARY KEY, post_id INT REFERENCES posts(id));
+            CREATE TABLE tags (id INT PRIMARY KEY);
+            ",
+        )
+        .unwrap();
+        let ordered = db.table_dependency_graph().topological_order().unwrap();
+        assert_eq!(ordered, db.table_dag());
+        let position = |name: &str| ordered.iter().position(|t| t.table_name() == name);
+        assert!(position("users") < position("posts"));
+        assert!(position("posts") < position("comments"));
+    }
+
+    #[test]
+    fn test_owned_sequences_order_tables() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE invoices (id INT);
+            CREATE SEQUENCE invoices_id_seq OWNED BY invoices.id;
+            CREATE TABLE billing (id INT DEFAULT nextval('invoices_id_seq'));
+            ",
+        )
+        .unwrap();
+        let graph = db.table_dependency_graph();
+        let billing = db.table(None, "billing").unwrap();
+        let invoices = db.table(None, "invoices").unwrap();
+        assert_eq!(graph.dependencies(billing).collect::<Vec<_>>(), [invoices]);
+        assert_eq!(names(graph.topological_order().unwrap()), ["invoices", "billing"]);
+    }
+}
@@ -0,0 +1,260 @@
+//! Submodule defining the `TriggerCascadeGraph` struct, describing which
+//! triggers of a database may fire other triggers through the writes of
+//! their functions.
+
+use alloc::vec::Vec;
+
+use sqlparser::ast::TriggerEvent;
+
+use crate::{
+    traits::{DatabaseLike, DmlKind, TriggerLike, trigger::trigger_writes},
+    utils::graph::{strongly_connected_components, successors},
+};
+
+/// Returns whether a write of the provided kind fires a trigger on the
+/// provided event.
+fn fires(kind: DmlKind, event: &TriggerEvent) -> bool {
+    matches!(
+        (kind, event),
+        (DmlKind::Insert, TriggerEvent::Insert)
+            | (DmlKind::Update, TriggerEvent::Update(_))
+            | (DmlKind::Delete, TriggerEvent::Delete)
+    )
+}
+
+/// Graph of the triggers of a database which may fire each other.
+///
+/// The edges of the graph go from each trigger to the triggers fired by the
+/// writes of its function, as returned by [`TriggerLike::writes_to_tables`]:
+/// a write fires the triggers of the written table listening to the same
+/// event. The graph is conservative, as the conditions of the triggers and
+/// of the statements of their functions are not evaluated, and an `UPDATE`
+/// fires the triggers restricted to some columns regardless of the columns
+/// it sets.
+///
+/// Triggers whose function cannot be analyzed, because its body cannot be
+/// parsed or writes to an ambiguous relation, have no edges and are listed
+/// by [`TriggerCascadeGraph::unanalyzed_triggers`].
+#[derive(Debug, Clone)]
+pub struct TriggerCascadeGraph<'db, DB: DatabaseLike> {
+    /// The triggers of the database, in the order of the database.
+    triggers: Vec<&'db DB::Trigger>,
+    /// The sorted `(firing, fired)` edges, as indices into `triggers`.
+    edges: Vec<(usize, usize)>,
+    /// The indices of the triggers whose function cannot be analyzed.
+    unanalyzed: Vec<usize>,
+}
+
+impl<'db, DB: DatabaseLike> TriggerCascadeGraph<'db, DB> {
+    /// Builds the cascade graph of the triggers of the provided database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database to build the graph for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE orders (id INT);
+    /// CREATE TABLE order_log (order_id INT);
+    /// CREATE FUNCTION log_order() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     INSERT INTO order_log (order_id) VALUES (NEW.id);
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE FUNCTION check_log() RETURNS TRIGGER AS $$
+    /// BEGIN RETURN NEW; END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER log_orders AFTER INSERT ON orders
+    /// FOR EACH ROW EXECUTE FUNCTION log_order();
+    /// CREATE TRIGGER check_logs BEFORE INSERT ON order_log
+    /// FOR EACH ROW EXECUTE FUNCTION check_log();
+    /// ",
+    /// )?;
+    /// let graph = TriggerCascadeGraph::new(&db);
+    /// let log_orders = graph.triggers()[0];
+    /// let fired: Vec<&str> = graph.fired_triggers(log_orders).map(TriggerLike::name).collect();
+    /// assert_eq!(fired, ["check_logs"]);
+    /// assert!(!graph.has_cycles());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB) -> Self {
+        let triggers: Vec<&'db DB::Trigger> = database.triggers().collect();
+        let mut edges = Vec::new();
+        let mut unanalyzed = Vec::new();
+        for (firing_number, trigger) in triggers.iter().enumerate() {
+            let Ok(writes) = trigger_writes(*trigger, database) else {
+                unanalyzed.push(firing_number);
+                continue;
+            };
+            for (table, kind) in writes {
+                for (fired_number, fired) in triggers.iter().enumerate() {
+                    if fired.table(database) == table
+                        && fired.events().iter().any(|event| fires(kind, event))
+                    {
+                        edges.push((firing_number, fired_number));
+                    }
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+
+        Self { triggers, edges, unanalyzed }
+    }
+
+    /// Returns the triggers of the graph, in the order of the database.
+    #[must_use]
+    #[inline]
+    pub fn triggers(&self) -> &[&'db DB::Trigger] {
+        &self.triggers
+    }
+
+    /// Returns an iterator over the triggers which the writes of the function
+    /// of the provided trigger may fire.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger` - The firing trigger.
+    pub fn fired_triggers<'a>(
+        &'a self,
+        trigger: &'a DB::Trigger,
+    ) -> impl Iterator<Item = &'db DB::Trigger> + 'a {
+        let edges = self
+            .triggers
+            .iter()
+            .position(|candidate| *candidate == trigger)
+            .map_or(&[][..], |trigger_number| successors(&self.edges, trigger_number));
+        edges.iter().map(|(_, fired)| self.triggers[*fired])
+    }
+
+    /// Returns the groups of triggers which may fire each other endlessly,
+    /// each group listing its triggers in the order of the database.
+    ///
+    /// A group holds either several triggers firing each other in a loop, or
+    /// a single trigger firing itself, such as an `AFTER UPDATE` trigger
+    /// whose function updates the table of the trigger.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE counters (hits INT);
+    /// CREATE FUNCTION bump() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     UPDATE counters SET hits = hits + 1;
+    ///     RETURN NULL;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER bump_counters AFTER UPDATE ON counters
+    /// FOR EACH STATEMENT EXECUTE FUNCTION bump();
+    /// ",
+    /// )?;
+    /// let graph = db.trigger_cascade_graph();
+    /// let cycles = graph.cycles();
+    /// assert_eq!(cycles.len(), 1);
+    /// assert_eq!(cycles[0][0].name(), "bump_counters");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<&'db DB::Trigger>> {
+        strongly_connected_components(self.triggers.len(), &self.edges)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || successors(&self.edges, component[0])
+                        .iter()
+                        .any(|(_, fired)| *fired == component[0])
+            })
+            .map(|component| component.into_iter().map(|number| self.triggers[number]).collect())
+            .collect()
+    }
+
+    /// Returns whether some triggers may fire each other endlessly.
+    #[must_use]
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles().is_empty()
+    }
+
+    /// Returns an iterator over the triggers whose function cannot be
+    /// analyzed, and which may therefore fire triggers missing from the
+    /// graph.
+    pub fn unanalyzed_triggers(&self) -> impl Iterator<Item = &'db DB::Trigger> + '_ {
+        self.unanalyzed.iter().map(|trigger_number| self.triggers[*trigger_number])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, TriggerLike},
+    };
+
+    #[test]
+    fn test_trigger_cascades() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE accounts (id INT, balance INT);
+            CREATE TABLE mirrors (id INT, balance INT);
+            CREATE TABLE audit (note TEXT);
+            CREATE FUNCTION mirror_account() RETURNS TRIGGER AS $$
+            BEGIN
+                INSERT INTO mirrors (id, balance) VALUES (NEW.id, NEW.balance);
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE FUNCTION unmirror_account() RETURNS TRIGGER AS $$
+            BEGIN
+                INSERT INTO accounts (id, balance) VALUES (NEW.id, NEW.balance);
+                DELETE FROM audit;
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE FUNCTION broken() RETURNS TRIGGER AS $$ BEGIN INSERT INTO; END; $$
+            LANGUAGE plpgsql;
+            CREATE TRIGGER mirror AFTER INSERT ON accounts
+            FOR EACH ROW EXECUTE FUNCTION mirror_account();
+            CREATE TRIGGER unmirror AFTER INSERT ON mirrors
+            FOR EACH ROW EXECUTE FUNCTION unmirror_account();
+            CREATE TRIGGER audit_updates AFTER UPDATE ON audit
+            FOR EACH ROW EXECUTE FUNCTION broken();
+            ",
+        )
+        .expect("Failed to parse");
+
+        let graph = db.trigger_cascade_graph();
+        let [mirror, unmirror, audit_updates] = graph.triggers() else {
+            panic!("Expected three triggers");
+        };
+        let fired: Vec<&str> = graph.fired_triggers(mirror).map(TriggerLike::name).collect();
+        assert_eq!(fired, ["unmirror"]);
+        let fired: Vec<&str> = graph.fired_triggers(unmirror).map(TriggerLike::name).collect();
+        assert_eq!(fired, ["mirror"]);
+        assert_eq!(graph.fired_triggers(audit_updates).count(), 0);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        let cycle: Vec<&str> = cycles[0].iter().copied().map(TriggerLike::name).collect();
+        assert_eq!(cycle, ["mirror", "unmirror"]);
+        assert!(graph.has_cycles());
+        let unanalyzed: Vec<&str> = graph.unanalyzed_triggers().map(TriggerLike::name).collect();
+        assert_eq!(unanalyzed, ["audit_updates"]);
+    }
+}
@@ -0,0 +1,188 @@
+//! Submodule providing the [`TypeNormalizer`] implementations of the
+//! `PostgreSQL`, MySQL and SQLite dialects.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::traits::TypeNormalizer;
+
+/// Normalizer of the data types declared under `PostgreSQL`.
+///
+/// Besides the standard spellings, it maps the internal names of the types,
+/// such as `int8` or `timestamptz`, and the serial pseudo-types to their
+/// canonical data types. `FLOAT(p)` is single precision up to 24 bits of
+/// precision, and double precision otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PostgresTypeNormalizer;
+
+/// Normalizer of the data types declared under MySQL and `MariaDB`.
+///
+/// `TINYINT(1)` is the idiomatic spelling of a boolean, `DATETIME` is a
+/// timestamp without time zone, and the `TINY`, `MEDIUM` and `LONG`
+/// variants of `TEXT` and `BLOB` belong to their families. `FLOAT` is
+/// single precision and `REAL` double precision, and the signedness of the
+/// numeric types is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct MySqlTypeNormalizer;
+
+/// Normalizer of the data types declared under SQLite.
+///
+/// The data types with a conventional meaning, such as `BOOLEAN`, `DATE`
+/// or `DATETIME`, are mapped to it, and every other data type is mapped
+/// following the SQLite type affinity rules: integers are stored on 64 bits
+/// and so are `BIGINT`, floating point numbers are `DOUBLE PRECISION`, and
+/// the text and blob affinities are `TEXT` and `BLOB`. Any data type is
+/// therefore known to this normalizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SqliteTypeNormalizer;
+
+impl TypeNormalizer for PostgresTypeNormalizer {
+    fn canonical_type(&self, data_type: &str) -> Option<&'static str> {
+        let (name, arguments) = split_data_type(data_type);
+        match name.as_str() {
+            "FLOAT" if first_argument(arguments).is_some_and(|precision| precision <= 24) => {
+                Some("REAL")
+            }
+            name => standard_type(name),
+        }
+    }
+}
+
+impl TypeNormalizer for MySqlTypeNormalizer {
+    fn canonical_type(&self, data_type: &str) -> Option<&'static str> {
+        let (name, arguments) = split_data_type(data_type);
+        let name = name
+            .split(' ')
+            .filter(|word| !matches!(*word, "SIGNED" | "UNSIGNED" | "ZEROFILL"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(match name.as_str() {
+            "TINYINT" if first_argument(arguments) == Some(1) => "BOOLEAN",
+            "TINYINT" | "YEAR" => "SMALLINT",
+            "FLOAT" if first_argument(arguments).is_some_and(|precision| precision > 24) => {
+                "DOUBLE PRECISION"
+            }
+            "FLOAT" => "REAL",
+            "REAL" => "DOUBLE PRECISION",
+            "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" => "TEXT",
+            "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" => "BLOB",
+            name => return standard_type(name),
+        })
+    }
+}
+
+impl TypeNormalizer for SqliteTypeNormalizer {
+    fn canonical_type(&self, data_type: &str) -> Option<&'static str> {
+        let (name, _) = split_data_type(data_type);
+        let contains_any =
+            |patterns: &[&str]| patterns.iter().any(|pattern| name.contains(pattern));
+        Some(match name.as_str() {
+            "BOOL" | "BOOLEAN" => "BOOLEAN",
+            "DATE" => "DATE",
+            "TIME" => "TIME",
+            "DATETIME" | "TIMESTAMP" => "TIMESTAMP",
+            "UUID" => "UUID",
+            "JSON" => "JSON",
+            _ if name.contains("INT") => "BIGINT",
+            _ if contains_any(&["CHAR", "CLOB", "TEXT"]) => "TEXT",
+            _ if name.is_empty() || name.contains("BLOB") => "BLOB",
+            _ if contains_any(&["REAL", "FLOA", "DOUB"]) => "DOUBLE PRECISION",
+            _ => "NUMERIC",
+        })
+    }
+}
+
+/// Returns the canonical data type of the provided upper-case data type
+/// name, following the spellings shared by most dialects.
+fn standard_type(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "BOOL" | "BOOLEAN" => "BOOLEAN",
+        "INT2" | "SMALLINT" | "SMALLSERIAL" | "SERIAL2" => "SMALLINT",
+        "INT" | "INT4" | "INTEGER" | "MEDIUMINT" | "SERIAL" | "SERIAL4" => "INT",
+        "INT8" | "BIGINT" | "BIGSERIAL" | "SERIAL8" => "BIGINT",
+        "FLOAT4" | "REAL" => "REAL",
+        "FLOAT" | "FLOAT8" | "DOUBLE" | "DOUBLE PRECISION" => "DOUBLE PRECISION",
+        "DEC" | "DECIMAL" | "NUMERIC" => "NUMERIC",
+        "VARCHAR" | "CHARACTER VARYING" | "CHAR VARYING" | "NVARCHAR" | "VARCHAR2" => "VARCHAR",
+        "CHAR" | "CHARACTER" | "BPCHAR" | "NCHAR" => "CHAR",
+        "TEXT" | "CLOB" | "STRING" => "TEXT",
+        "BLOB" | "BYTEA" | "BINARY" | "VARBINARY" | "BYTES" => "BLOB",
+        "DATE" => "DATE",
+        "TIME" | "TIME WITHOUT TIME ZONE" => "TIME",
+        "TIMETZ" | "TIME WITH TIME ZONE" => "TIME WITH TIME ZONE",
+        "DATETIME" | "TIMESTAMP" | "TIMESTAMP WITHOUT TIME ZONE" => "TIMESTAMP",
+        "TIMESTAMPTZ" | "TIMESTAMP WITH TIME ZONE" => "TIMESTAMP WITH TIME ZONE",
+        "INTERVAL" => "INTERVAL",
+        "UUID" => "UUID",
+        "JSON" | "JSONB" => "JSON",
+        _ => return None,
+    })
+}
+
+/// Splits the provided declared data type into its upper-case name, with
+/// its words separated by single spaces, and its parenthesized arguments.
+fn split_data_type(data_type: &str) -> (String, Option<&str>) {
+    let data_type = data_type.trim();
+    let (name, arguments) = match (data_type.find('('), data_type.rfind(')')) {
+        (Some(open), Some(close)) if open < close => (
+            format!("{} {}", &data_type[..open], &data_type[close + 1..]),
+            Some(data_type[open + 1..close].trim()),
+        ),
+        _ => (String::from(data_type), None),
+    };
+    let name = name
+        .split_whitespace()
+        .map(|word| word.trim_matches('"').to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (name, arguments)
+}
+
+/// Returns the first numeric argument of a data type, such as the display
+/// width of `TINYINT(1)` or the precision of `FLOAT(53)`.
+fn first_argument(arguments: Option<&str>) -> Option<u64> {
+    arguments?.split(',').next()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postgres_type_normalizer() {
+        let normalizer = PostgresTypeNormalizer;
+        assert_eq!(normalizer.canonical_type("bool"), Some("BOOLEAN"));
+        assert_eq!(normalizer.canonical_type("SERIAL"), Some("INT"));
+        assert_eq!(normalizer.canonical_type("character varying(255)"), Some("VARCHAR"));
+        assert_eq!(normalizer.canonical_type("timestamptz"), Some("TIMESTAMP WITH TIME ZONE"));
+        assert_eq!(normalizer.canonical_type("FLOAT(24)"), Some("REAL"));
+        assert_eq!(normalizer.canonical_type("FLOAT"), Some("DOUBLE PRECISION"));
+        assert_eq!(normalizer.canonical_type("NUMERIC(10, 2)"), Some("NUMERIC"));
+        assert_eq!(normalizer.canonical_type("TINYINT(1)"), None);
+    }
+
+    #[test]
+    fn test_mysql_type_normalizer() {
+        let normalizer = MySqlTypeNormalizer;
+        assert_eq!(normalizer.canonical_type("TINYINT(1)"), Some("BOOLEAN"));
+        assert_eq!(normalizer.canonical_type("TINYINT(4)"), Some("SMALLINT"));
+        assert_eq!(normalizer.canonical_type("INT(11) UNSIGNED"), Some("INT"));
+        assert_eq!(normalizer.canonical_type("DATETIME"), Some("TIMESTAMP"));
+        assert_eq!(normalizer.canonical_type("LONGTEXT"), Some("TEXT"));
+        assert_eq!(normalizer.canonical_type("MEDIUMBLOB"), Some("BLOB"));
+        assert_eq!(normalizer.canonical_type("FLOAT"), Some("REAL"));
+        assert_eq!(normalizer.canonical_type("REAL"), Some("DOUBLE PRECISION"));
+        assert_eq!(normalizer.canonical_type("ENUM('a', 'b')"), None);
+    }
+
+    #[test]
+    fn test_sqlite_type_normalizer() {
+        let normalizer = SqliteTypeNormalizer;
+        assert_eq!(normalizer.canonical_type("BOOLEAN"), Some("BOOLEAN"));
+        assert_eq!(normalizer.canonical_type("INTEGER"), Some("BIGINT"));
+        assert_eq!(normalizer.canonical_type("NVARCHAR(100)"), Some("TEXT"));
+        assert_eq!(normalizer.canonical_type(""), Some("BLOB"));
+        assert_eq!(normalizer.canonical_type("DOUBLE"), Some("DOUBLE PRECISION"));
+        assert_eq!(normalizer.canonical_type("DATETIME"), Some("TIMESTAMP"));
+        assert_eq!(normalizer.canonical_type("DECIMAL(10, 5)"), Some("NUMERIC"));
+    }
+}
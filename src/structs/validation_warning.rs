@@ -0,0 +1,70 @@
+//! Submodule defining the warnings recorded in place of the validation errors
+//! downgraded by [`crate::structs::ParserOptions`].
+
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+use crate::{errors::Error, structs::ValidationClass};
+
+/// Warning recorded when a statement failing a validation whose class is set
+/// to [`crate::structs::ValidationLevel::Warn`] is skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sql_traits::structs::{ParserOptions, ValidationClass, ValidationLevel};
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let options =
+///     ParserOptions::default().with_level(ValidationClass::MissingObjects, ValidationLevel::Warn);
+/// let db = ParserDB::parse_with_options::<PostgreSqlDialect>(
+///     "CREATE TABLE t (id INT); GRANT SELECT ON t TO reader;",
+///     options,
+/// )?;
+/// assert_eq!(db.validation_warnings().len(), 1);
+/// assert_eq!(db.validation_warnings()[0].class(), ValidationClass::MissingObjects);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationWarning {
+    /// Class of the downgraded error.
+    class: ValidationClass,
+    /// Message of the downgraded error.
+    message: String,
+}
+
+impl ValidationWarning {
+    /// Creates a new warning from the downgraded error.
+    ///
+    /// # Arguments
+    ///
+    /// * `class` - The class of the error.
+    /// * `error` - The downgraded error.
+    #[must_use]
+    pub fn new(class: ValidationClass, error: &Error) -> Self {
+        Self { class, message: error.to_string() }
+    }
+
+    /// Returns the class of the downgraded error.
+    #[must_use]
+    #[inline]
+    pub fn class(&self) -> ValidationClass {
+        self.class
+    }
+
+    /// Returns the message of the downgraded error.
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
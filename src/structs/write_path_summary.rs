@@ -0,0 +1,335 @@
+//! Submodule defining the `WritePathSummary` struct, summarizing what happens
+//! when rows are inserted into or updated in a table.
+
+use alloc::vec::Vec;
+
+use sqlparser::ast::{TriggerEvent, TriggerObject, TriggerObjectKind, TriggerPeriod};
+
+use crate::traits::{ColumnLike, DatabaseLike, TableLike, TriggerLike};
+
+/// A trigger firing while a row is written, along with the columns of the
+/// row it overwrites.
+#[derive(Debug, Clone)]
+pub struct FiredTrigger<'db, DB: DatabaseLike> {
+    /// The trigger firing.
+    trigger: &'db DB::Trigger,
+    /// The columns the trigger overwrites.
+    overwritten_columns: Vec<&'db DB::Column>,
+}
+
+impl<'db, DB: DatabaseLike> FiredTrigger<'db, DB> {
+    /// Returns the trigger firing.
+    #[must_use]
+    #[inline]
+    pub fn trigger(&self) -> &'db DB::Trigger {
+        self.trigger
+    }
+
+    /// Returns the columns of the row the trigger overwrites, as found by
+    /// [`TriggerLike::maintenance_assignments`].
+    ///
+    /// Only `BEFORE ... FOR EACH ROW` triggers can overwrite the row being
+    /// written, so this is empty for the other triggers, as well as for the
+    /// triggers whose function is not a maintenance function.
+    #[must_use]
+    #[inline]
+    pub fn overwritten_columns(&self) -> &[&'db DB::Column] {
+        &self.overwritten_columns
+    }
+}
+
+/// The steps of writing a row with a single kind of statement, either
+/// `INSERT` or `UPDATE`, in the order PostgreSQL performs them: the `BEFORE`
+/// triggers fire, the constraints are checked, and the `AFTER` triggers
+/// fire.
+#[derive(Debug, Clone)]
+pub struct WritePath<'db, DB: DatabaseLike> {
+    /// The triggers firing before the row is written.
+    before_triggers: Vec<FiredTrigger<'db, DB>>,
+    /// The columns declared `NOT NULL`, including the primary key columns.
+    not_null_columns: Vec<&'db DB::Column>,
+    /// The check constraints of the table.
+    check_constraints: Vec<&'db DB::CheckConstraint>,
+    /// The unique indices of the table, including its primary key.
+    unique_indices: Vec<&'db DB::UniqueIndex>,
+    /// The foreign keys of the table.
+    foreign_keys: Vec<&'db DB::ForeignKey>,
+    /// The triggers firing after the row is written.
+    after_triggers: Vec<FiredTrigger<'db, DB>>,
+}
+
+/// Returns whether the trigger fires once per row.
+fn is_row_level<T: TriggerLike>(trigger: &T) -> bool {
+    matches!(
+        trigger.orientation(),
+        Some(
+            TriggerObjectKind::For(TriggerObject::Row)
+                | TriggerObjectKind::ForEach(TriggerObject::Row)
+        )
+    )
+}
+
+impl<'db, DB: DatabaseLike> WritePath<'db, DB> {
+    /// Builds the write path of the statements whose trigger events satisfy
+    /// the provided predicate.
+    fn new(
+        database: &'db DB,
+        table: &'db DB::Table,
+        fires_on: impl Fn(&TriggerEvent) -> bool,
+    ) -> Self {
+        let mut before_triggers = Vec::new();
+        let mut after_triggers = Vec::new();
+        for trigger in table.triggers(database) {
+            if !trigger.events().iter().any(&fires_on) {
+                continue;
+            }
+            match trigger.timing() {
+                Some(TriggerPeriod::Before) => before_triggers.push(trigger),
+                Some(TriggerPeriod::After | TriggerPeriod::For) => after_triggers.push(trigger),
+                _ => {}
+            }
+        }
+        // Statement-level triggers wrap the row-level ones, and triggers
+        // sharing a timing fire in alphabetical order of their names.
+        before_triggers.sort_by(|left, right| {
+            is_row_level(*left)
+                .cmp(&is_row_level(*right))
+                .then_with(|| left.name().cmp(right.name()))
+        });
+        after_triggers.sort_by(|left, right| {
+            is_row_level(*right)
+                .cmp(&is_row_level(*left))
+                .then_with(|| left.name().cmp(right.name()))
+        });
+
+        Self {
+            before_triggers: before_triggers
+                .into_iter()
+                .map(|trigger| FiredTrigger {
+                    trigger,
+                    overwritten_columns: if is_row_level(trigger) {
+                        trigger
+                            .maintenance_assignments(database)
+                            .map(|(column, _)| column)
+                            .collect()
+                    } else {
+                        Vec::new()
+                    },
+                })
+                .collect(),
+            not_null_columns: table
+                .columns(database)
+                .filter(|column| !column.is_nullable(database))
+                .collect(),
+            check_constraints: table.check_constraints(database).collect(),
+            unique_indices: table.unique_indices(database).collect(),
+            foreign_keys: table.foreign_keys(database).collect(),
+            after_triggers: after_triggers
+                .into_iter()
+                .map(|trigger| FiredTrigger { trigger, overwritten_columns: Vec::new() })
+                .collect(),
+        }
+    }
+
+    /// Returns the triggers firing before the row is written, in firing
+    /// order.
+    #[must_use]
+    #[inline]
+    pub fn before_triggers(&self) -> &[FiredTrigger<'db, DB>] {
+        &self.before_triggers
+    }
+
+    /// Returns the columns overwritten by the `BEFORE` triggers, in the
+    /// order they are overwritten. A column overwritten by several triggers
+    /// is listed once per trigger.
+    pub fn overwritten_columns(&self) -> impl Iterator<Item = &'db DB::Column> {
+        self.before_triggers.iter().flat_map(|fired| fired.overwritten_columns.iter().copied())
+    }
+
+    /// Returns the columns declared `NOT NULL`, including the primary key
+    /// columns, checked once the `BEFORE` triggers fired.
+    #[must_use]
+    #[inline]
+    pub fn not_null_columns(&self) -> &[&'db DB::Column] {
+        &self.not_null_columns
+    }
+
+    /// Returns the check constraints checked once the `BEFORE` triggers
+    /// fired.
+    #[must_use]
+    #[inline]
+    pub fn check_constraints(&self) -> &[&'db DB::CheckConstraint] {
+        &self.check_constraints
+    }
+
+    /// Returns the unique indices, including the primary key, checked once
+    /// the `BEFORE` triggers fired.
+    #[must_use]
+    #[inline]
+    pub fn unique_indices(&self) -> &[&'db DB::UniqueIndex] {
+        &self.unique_indices
+    }
+
+    /// Returns the foreign keys checked once the `BEFORE` triggers fired.
+    #[must_use]
+    #[inline]
+    pub fn foreign_keys(&self) -> &[&'db DB::ForeignKey] {
+        &self.foreign_keys
+    }
+
+    /// Returns the triggers firing after the row is written, in firing
+    /// order.
+    #[must_use]
+    #[inline]
+    pub fn after_triggers(&self) -> &[FiredTrigger<'db, DB>] {
+        &self.after_triggers
+    }
+}
+
+/// Summary of what happens when rows are written to a table, for `INSERT`
+/// and `UPDATE` statements separately.
+///
+/// Triggers restricted to `UPDATE OF` some columns are listed on the
+/// `UPDATE` path, as they fire whenever one of those columns is written,
+/// while `WHEN` conditions are not evaluated.
+#[derive(Debug, Clone)]
+pub struct WritePathSummary<'db, DB: DatabaseLike> {
+    /// The table the summary is about.
+    table: &'db DB::Table,
+    /// The steps of an `INSERT`.
+    insert: WritePath<'db, DB>,
+    /// The steps of an `UPDATE`.
+    update: WritePath<'db, DB>,
+}
+
+impl<'db, DB: DatabaseLike> WritePathSummary<'db, DB> {
+    /// Builds the summary for the provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the table belongs to.
+    /// * `table` - The table to build the summary for.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE brands (id INT PRIMARY KEY, name TEXT NOT NULL, edited_at TIMESTAMP);
+    /// CREATE FUNCTION touch() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     NEW.edited_at = CURRENT_TIMESTAMP;
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER touch_brands BEFORE UPDATE ON brands FOR EACH ROW EXECUTE FUNCTION touch();
+    /// ",
+    /// )?;
+    /// let brands = db.table(None, "brands").unwrap();
+    /// let summary = WritePathSummary::new(&db, brands);
+    /// assert!(summary.insert().before_triggers().is_empty());
+    ///
+    /// let update = summary.update();
+    /// assert_eq!(update.before_triggers()[0].trigger().name(), "touch_brands");
+    /// let overwritten: Vec<&str> = update.overwritten_columns().map(|c| c.column_name()).collect();
+    /// assert_eq!(overwritten, ["edited_at"]);
+    /// assert_eq!(update.not_null_columns().len(), 2);
+    /// assert_eq!(update.unique_indices().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(database: &'db DB, table: &'db DB::Table) -> Self {
+        Self {
+            table,
+            insert: WritePath::new(database, table, |event| matches!(event, TriggerEvent::Insert)),
+            update: WritePath::new(database, table, |event| {
+                matches!(event, TriggerEvent::Update(_))
+            }),
+        }
+    }
+
+    /// Returns the table the summary is about.
+    #[must_use]
+    #[inline]
+    pub fn table(&self) -> &'db DB::Table {
+        self.table
+    }
+
+    /// Returns the steps of an `INSERT` into the table.
+    #[must_use]
+    #[inline]
+    pub fn insert(&self) -> &WritePath<'db, DB> {
+        &self.insert
+    }
+
+    /// Returns the steps of an `UPDATE` of the table.
+    #[must_use]
+    #[inline]
+    pub fn update(&self) -> &WritePath<'db, DB> {
+        &self.update
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use crate::{
+        structs::{FiredTrigger, ParserDB, WritePathSummary},
+        traits::{DatabaseLike, TriggerLike},
+    };
+
+    fn names<'db>(triggers: &[FiredTrigger<'db, ParserDB>]) -> Vec<&'db str> {
+        triggers.iter().map(|fired| fired.trigger().name()).collect()
+    }
+
+    #[test]
+    fn test_triggers_are_ordered_and_split_by_event() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE t (id INT PRIMARY KEY, parent_id INT REFERENCES t (id), updated_at TIMESTAMP, CHECK (id > 0));
+            CREATE FUNCTION touch() RETURNS TRIGGER AS $$
+            BEGIN
+                NEW.updated_at = CURRENT_TIMESTAMP;
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE FUNCTION audit() RETURNS TRIGGER AS $$ BEGIN RETURN NULL; END; $$ LANGUAGE plpgsql;
+            CREATE TRIGGER b_touch BEFORE INSERT OR UPDATE ON t FOR EACH ROW EXECUTE FUNCTION touch();
+            CREATE TRIGGER a_touch BEFORE UPDATE ON t FOR EACH ROW EXECUTE FUNCTION touch();
+            CREATE TRIGGER z_log BEFORE UPDATE ON t FOR EACH STATEMENT EXECUTE FUNCTION audit();
+            CREATE TRIGGER audit_rows AFTER INSERT ON t FOR EACH ROW EXECUTE FUNCTION audit();
+            CREATE TRIGGER audit_statement AFTER INSERT ON t FOR EACH STATEMENT EXECUTE FUNCTION audit();
+            CREATE TRIGGER purge AFTER DELETE ON t FOR EACH ROW EXECUTE FUNCTION audit();
+            ",
+        )
+        .expect("Failed to parse");
+        let table = db.table(None, "t").unwrap();
+        let summary = WritePathSummary::new(&db, table);
+        assert_eq!(summary.table(), table);
+
+        let insert = summary.insert();
+        assert_eq!(names(insert.before_triggers()), ["b_touch"]);
+        assert_eq!(names(insert.after_triggers()), ["audit_rows", "audit_statement"]);
+        assert_eq!(insert.overwritten_columns().count(), 1);
+
+        let update = summary.update();
+        assert_eq!(names(update.before_triggers()), ["z_log", "a_touch", "b_touch"]);
+        assert!(update.before_triggers()[0].overwritten_columns().is_empty());
+        assert_eq!(update.overwritten_columns().count(), 2);
+        assert!(update.after_triggers().is_empty());
+
+        for path in [insert, update] {
+            assert_eq!(path.not_null_columns().len(), 1);
+            assert_eq!(path.check_constraints().len(), 1);
+            assert_eq!(path.unique_indices().len(), 1);
+            assert_eq!(path.foreign_keys().len(), 1);
+        }
+    }
+}
@@ -3,33 +3,52 @@
 pub mod column;
 pub mod database;
 pub mod dialect;
+pub mod parse_observer;
 pub mod table;
 pub mod type_match;
-use core::fmt::Debug;
+pub mod type_normalizer;
+use alloc::borrow::Cow;
+use core::fmt::{Debug, Display};
+
+use sqlparser::tokenizer::Span;
+
+use crate::structs::SourceLocation;
 
 pub use column::ColumnLike;
 pub mod index;
 pub use database::DatabaseLike;
 pub use dialect::DialectLike;
 pub use index::IndexLike;
+pub use parse_observer::ParseObserver;
 pub use table::TableLike;
 pub use type_match::{TypeMatch, TypeMatchLike};
+pub use type_normalizer::TypeNormalizer;
 pub mod check_constraint;
 pub use check_constraint::CheckConstraintLike;
 pub mod unique_index;
 pub use unique_index::UniqueIndexLike;
+pub mod primary_key;
+pub use primary_key::PrimaryKeyLike;
 pub mod foreign_key;
 pub use foreign_key::ForeignKeyLike;
 pub mod function_like;
 pub use function_like::FunctionLike;
 pub mod trigger;
-pub use trigger::TriggerLike;
+pub use trigger::{MaintenanceKind, TriggerLike};
 pub mod policy;
 pub use policy::PolicyLike;
 pub mod role;
 pub use role::RoleLike;
 pub mod schema;
 pub use schema::SchemaLike;
+pub mod view;
+pub use view::ViewLike;
+pub mod sequence;
+pub use sequence::SequenceLike;
+pub mod custom_type;
+pub use custom_type::CustomTypeLike;
+pub mod default_privilege;
+pub use default_privilege::DefaultPrivilegeLike;
 pub mod grant;
 pub use grant::{ColumnGrantLike, GrantLike, TableGrantLike};
 pub mod data_statement;
@@ -39,18 +58,205 @@ pub use dql::DQLLike;
 pub mod dml;
 pub use dml::{DMLLike, DmlKind, DmlStatement};
 
+/// Kind of a database object, as reported by [`Metadata::object_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ObjectKind {
+    /// A schema.
+    Schema,
+    /// A table.
+    Table,
+    /// A column of a table.
+    Column,
+    /// A non-unique index.
+    Index,
+    /// A unique index or constraint.
+    UniqueIndex,
+    /// A primary key constraint.
+    PrimaryKey,
+    /// A foreign key constraint.
+    ForeignKey,
+    /// A check constraint.
+    CheckConstraint,
+    /// A function.
+    Function,
+    /// A trigger.
+    Trigger,
+    /// A row-level security policy.
+    Policy,
+    /// A role.
+    Role,
+    /// A grant of privileges.
+    Grant,
+    /// A view or materialized view.
+    View,
+    /// A sequence.
+    Sequence,
+    /// A user-defined type, such as an enumeration or a domain.
+    CustomType,
+}
+
+impl Display for ObjectKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Schema => "schema",
+            Self::Table => "table",
+            Self::Column => "column",
+            Self::Index => "index",
+            Self::UniqueIndex => "unique index",
+            Self::PrimaryKey => "primary key",
+            Self::ForeignKey => "foreign key",
+            Self::CheckConstraint => "check constraint",
+            Self::Function => "function",
+            Self::Trigger => "trigger",
+            Self::Policy => "policy",
+            Self::Role => "role",
+            Self::Grant => "grant",
+            Self::View => "view",
+            Self::Sequence => "sequence",
+            Self::CustomType => "type",
+        })
+    }
+}
+
 /// Trait for associating a metadata struct to a given type.
+///
+/// Besides the associated metadata type, the trait provides a uniform way to
+/// describe any database object, so that generic code can report on objects
+/// without matching on each `*Like` trait separately.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "
+/// -- Registered users
+/// CREATE TABLE users (id INT PRIMARY KEY, age INT CHECK (age > 0));
+/// ",
+/// )?;
+/// let table = db.table(None, "users").unwrap();
+/// assert_eq!(table.object_kind(), ObjectKind::Table);
+/// assert_eq!(table.display_name(), "users");
+/// assert_eq!(table.object_doc(db.table_metadata(table).unwrap()), Some("Registered users"));
+/// assert!(table.source_span().is_some());
+///
+/// let descriptions: Vec<String> = table
+///     .columns(&db)
+///     .map(|column| format!("{} {}", column.object_kind(), column.display_name()))
+///     .collect();
+/// assert_eq!(descriptions, vec!["column id", "column age"]);
+/// # Ok(())
+/// # }
+/// ```
 pub trait Metadata {
     /// The associated metadata type.
     type Meta: Clone + Debug + Send + Sync;
+
+    /// Returns the kind of the object.
+    fn object_kind(&self) -> ObjectKind;
+
+    /// Returns a human-readable name for the object.
+    ///
+    /// This is the name of the object when it has one, and the rendering of
+    /// its definition for anonymous objects such as unnamed constraints or
+    /// grants.
+    fn display_name(&self) -> Cow<'_, str>;
+
+    /// Returns the name of the schema the object belongs to, if known.
+    ///
+    /// Objects attached to a table, such as columns and constraints, report
+    /// the schema of their table.
+    #[inline]
+    fn object_schema(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the documentation of the object, if any is stored in its
+    /// metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - The metadata associated with the object in its database.
+    #[inline]
+    fn object_doc<'a>(&'a self, _meta: &'a Self::Meta) -> Option<&'a str> {
+        None
+    }
+
+    /// Returns the location of the name of the object in the SQL it was
+    /// parsed from, if known.
+    #[inline]
+    fn source_span(&self) -> Option<Span> {
+        None
+    }
+
+    /// Returns the location of the statement creating the object, if it is
+    /// stored in its metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - The metadata associated with the object in its database.
+    #[inline]
+    fn object_source_location<'a>(&'a self, _meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        None
+    }
 }
 
 impl<M: Metadata> Metadata for &M {
     type Meta = M::Meta;
+
+    fn object_kind(&self) -> ObjectKind {
+        (*self).object_kind()
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        (*self).display_name()
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        (*self).object_schema()
+    }
+
+    fn object_doc<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a str> {
+        (*self).object_doc(meta)
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        (*self).source_span()
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        (*self).object_source_location(meta)
+    }
 }
 
 impl<M: Metadata> Metadata for alloc::sync::Arc<M> {
     type Meta = M::Meta;
+
+    fn object_kind(&self) -> ObjectKind {
+        self.as_ref().object_kind()
+    }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        self.as_ref().display_name()
+    }
+
+    fn object_schema(&self) -> Option<&str> {
+        self.as_ref().object_schema()
+    }
+
+    fn object_doc<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a str> {
+        self.as_ref().object_doc(meta)
+    }
+
+    fn source_span(&self) -> Option<Span> {
+        self.as_ref().source_span()
+    }
+
+    fn object_source_location<'a>(&'a self, meta: &'a Self::Meta) -> Option<&'a SourceLocation> {
+        self.as_ref().object_source_location(meta)
+    }
 }
 
 /// Trait for associating documentation struct with a given type
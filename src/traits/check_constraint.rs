@@ -5,20 +5,34 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
-use core::{borrow::Borrow, fmt::Debug};
+use core::{borrow::Borrow, fmt::Debug, ops::Bound};
 
 use sqlparser::ast::{
     BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, FunctionArguments, Ident, Value,
 };
 
-use crate::traits::{
-    DatabaseLike, Metadata, TableLike, column::ColumnLike, function_like::FunctionLike,
+use crate::{
+    structs::SourceLocation,
+    traits::{
+        DatabaseLike, DialectLike, Metadata, TableLike, column::ColumnLike,
+        function_like::FunctionLike,
+    },
+    utils::{
+        allowed_values::allowed_values,
+        check_implication::{expression_implies, numeric_bounds},
+        pattern::pattern,
+    },
 };
 
+/// The functions returning the length of a text, recognized in text length
+/// bounds.
+pub(crate) const LENGTH_FUNCTIONS: [&str; 5] =
+    ["length", "len", "char_length", "character_length", "octet_length"];
+
 /// Helper function to determine if an expression evaluates to a constant
 /// boolean value. Returns `Some(true)` if always true, `Some(false)` if always
 /// false, and `None` otherwise.
-fn evaluate_constant_expr<DB: DatabaseLike>(
+pub(crate) fn evaluate_constant_expr<DB: DatabaseLike>(
     database: &DB,
     columns: &[&<DB as DatabaseLike>::Column],
     expr: &Expr,
@@ -135,6 +149,14 @@ fn evaluate_constant_expr<DB: DatabaseLike>(
     }
 }
 
+/// Helper to find a column of an expression by name
+fn find_column<'db, DB: DatabaseLike>(
+    columns: &[&'db DB::Column],
+    name: &str,
+) -> Option<&'db DB::Column> {
+    columns.iter().copied().find(|column| column.column_name() == name)
+}
+
 /// Helper to extract column names from nullability checks in an AND chain
 fn extract_null_columns(expr: &Expr, is_null: bool) -> Option<Vec<&Ident>> {
     use sqlparser::ast::BinaryOperator;
@@ -183,27 +205,25 @@ enum BoundDirection {
 
 /// Helper to resolve the global bound for a column.
 /// This prevents infinite recursion by tracking visited columns.
-fn resolve_global_bound<C>(
-    database: &C::DB,
-    table: &<C::DB as DatabaseLike>::Table,
+fn resolve_global_bound<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
     target_col: &str,
     visited_cols: &mut Vec<String>,
     direction: BoundDirection,
-) -> Option<usize>
-where
-    C: CheckConstraintLike,
-{
+) -> Option<usize> {
     visited_cols.push(target_col.to_string());
 
     let mut bound_agg = None;
 
     for constraint in table.check_constraints(database) {
-        let cc: &C = constraint.borrow();
-        let expr = cc.expression(database);
+        let columns = constraint.columns(database).collect::<Vec<_>>();
+        let expr = constraint.expression(database);
         if let Some(bound) = check_text_length_bound_recursive(
             database,
+            table,
+            &columns,
             expr,
-            cc,
             Some(target_col),
             visited_cols,
             direction,
@@ -222,19 +242,17 @@ where
 
 /// Helper to extract length limit from an expression
 #[allow(clippy::too_many_arguments)]
-fn get_length_bound<C>(
-    database: &C::DB,
+fn get_length_bound<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
     func_expr: &Expr,
     op: &BinaryOperator,
     val_expr: &Expr,
-    check_constraint: &C,
     target_col: Option<&str>,
     visited_cols: &mut Vec<String>,
     direction: BoundDirection,
-) -> Option<usize>
-where
-    C: CheckConstraintLike,
-{
+) -> Option<usize> {
     // Check Operator matches direction
     let is_inclusive = match (direction, op) {
         (BoundDirection::Upper, BinaryOperator::Lt)
@@ -250,8 +268,7 @@ where
     };
 
     let name_str = name.to_string();
-    let valid_funcs = ["length", "len", "char_length", "character_length", "octet_length"];
-    if !valid_funcs.iter().any(|&f| name_str.eq_ignore_ascii_case(f)) {
+    if !LENGTH_FUNCTIONS.iter().any(|&f| name_str.eq_ignore_ascii_case(f)) {
         return None;
     }
 
@@ -273,8 +290,7 @@ where
     }
 
     // Verify it's a textual column
-    if !check_constraint.column(database, &col_ident.value).is_some_and(|c| c.is_textual(database))
-    {
+    if !find_column::<DB>(columns, &col_ident.value).is_some_and(|c| c.is_textual(database)) {
         return None;
     }
 
@@ -293,7 +309,7 @@ where
     // Case 2: Another Function Call (Transitive Check)
     if let Expr::Function(Function { name: inner_name, args: inner_args, .. }) = val_expr {
         let inner_name_str = inner_name.to_string();
-        if valid_funcs.iter().any(|&f| inner_name_str.eq_ignore_ascii_case(f)) {
+        if LENGTH_FUNCTIONS.iter().any(|&f| inner_name_str.eq_ignore_ascii_case(f)) {
             let inner_args_list = match inner_args {
                 FunctionArguments::List(list) => &list.args,
                 _ => return None,
@@ -312,8 +328,7 @@ where
                     return None;
                 }
 
-                let table = check_constraint.table(database);
-                if let Some(limit) = resolve_global_bound::<C>(
+                if let Some(limit) = resolve_global_bound(
                     database,
                     table,
                     &inner_col_ident.value,
@@ -334,26 +349,25 @@ where
 
 /// Helper function to recursively determine the bound of a text length
 /// constraint.
-fn check_text_length_bound_recursive<C>(
-    database: &C::DB,
+fn check_text_length_bound_recursive<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
     expr: &Expr,
-    check_constraint: &C,
     target_col: Option<&str>,
     visited_cols: &mut Vec<String>,
     direction: BoundDirection,
-) -> Option<usize>
-where
-    C: CheckConstraintLike,
-{
+) -> Option<usize> {
     match expr {
         Expr::BinaryOp { left, op, right } => {
             // Check direct comparison: func(col) <op> right
             if let Some(bound) = get_length_bound(
                 database,
+                table,
+                columns,
                 left,
                 op,
                 right,
-                check_constraint,
                 target_col,
                 visited_cols,
                 direction,
@@ -366,10 +380,11 @@ where
             // If `op` is Gt, `swap` is Lt. `get_length_bound` accepts `Lt` for `Upper`.
             if let Some(bound) = get_length_bound(
                 database,
+                table,
+                columns,
                 right,
                 &swap_cmp_op(op),
                 left,
-                check_constraint,
                 target_col,
                 visited_cols,
                 direction,
@@ -380,16 +395,18 @@ where
             if matches!(op, BinaryOperator::And) {
                 let l = check_text_length_bound_recursive(
                     database,
+                    table,
+                    columns,
                     left,
-                    check_constraint,
                     target_col,
                     visited_cols,
                     direction,
                 );
                 let r = check_text_length_bound_recursive(
                     database,
+                    table,
+                    columns,
                     right,
-                    check_constraint,
                     target_col,
                     visited_cols,
                     direction,
@@ -408,16 +425,18 @@ where
             if matches!(op, BinaryOperator::Or) {
                 let l = check_text_length_bound_recursive(
                     database,
+                    table,
+                    columns,
                     left,
-                    check_constraint,
                     target_col,
                     visited_cols,
                     direction,
                 );
                 let r = check_text_length_bound_recursive(
                     database,
+                    table,
+                    columns,
                     right,
-                    check_constraint,
                     target_col,
                     visited_cols,
                     direction,
@@ -433,8 +452,9 @@ where
         Expr::Nested(inner) => {
             check_text_length_bound_recursive(
                 database,
+                table,
+                columns,
                 inner,
-                check_constraint,
                 target_col,
                 visited_cols,
                 direction,
@@ -446,10 +466,11 @@ where
 
 /// Helper function to recursively determine if an expression checks for a
 /// not-empty text constraint.
-fn check_not_empty_text_recursive<C>(database: &C::DB, expr: &Expr, check_constraint: &C) -> bool
-where
-    C: CheckConstraintLike,
-{
+fn check_not_empty_text_recursive<DB: DatabaseLike>(
+    database: &DB,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> bool {
     match expr {
         Expr::BinaryOp { left, op, right } => {
             if matches!(op, BinaryOperator::NotEq) {
@@ -459,8 +480,7 @@ where
                         && let Value::SingleQuotedString(s) = &val_wrapper.value
                         && s.is_empty()
                     {
-                        return check_constraint
-                            .column(database, &ident.value)
+                        return find_column::<DB>(columns, &ident.value)
                             .is_some_and(|c| c.is_textual(database));
                     }
                     false
@@ -474,17 +494,122 @@ where
             }
 
             if matches!(op, BinaryOperator::And) {
-                return check_not_empty_text_recursive(database, left, check_constraint)
-                    || check_not_empty_text_recursive(database, right, check_constraint);
+                return check_not_empty_text_recursive(database, columns, left)
+                    || check_not_empty_text_recursive(database, columns, right);
             }
 
             false
         }
-        Expr::Nested(inner) => check_not_empty_text_recursive(database, inner, check_constraint),
+        Expr::Nested(inner) => check_not_empty_text_recursive(database, columns, inner),
         _ => false,
     }
 }
 
+/// Returns whether the expression always holds for the provided columns.
+pub(crate) fn is_tautology_expr<DB: DatabaseLike>(
+    database: &DB,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> bool {
+    evaluate_constant_expr(database, columns, expr) == Some(true)
+}
+
+/// Returns whether the expression can never hold for the provided columns,
+/// either because it is constantly false or because it enforces
+/// contradicting text length bounds.
+pub(crate) fn is_negation_expr<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> bool {
+    if evaluate_constant_expr(database, columns, expr) == Some(false) {
+        return true;
+    }
+
+    // upper is exclusive upper bound, lower is inclusive lower bound
+    // if lower >= upper, then impossible
+    matches!(
+        (
+            upper_text_length_bound(database, table, columns, expr),
+            lower_text_length_bound(database, table, columns, expr),
+        ),
+        (Some(upper), Some(lower)) if lower >= upper
+    )
+}
+
+/// Returns whether the expression states that a group of at least two
+/// columns are either all NULL or all NOT NULL.
+pub(crate) fn is_mutual_nullability_expr(expr: &Expr) -> bool {
+    // Must be an OR expression
+    let Expr::BinaryOp { left, op: BinaryOperator::Or, right } = expr else {
+        return false;
+    };
+
+    // Left side should be all NULL checks, right side all NOT NULL checks (or vice
+    // versa)
+    if let (Some(null_cols), Some(not_null_cols)) =
+        (extract_null_columns(left, true), extract_null_columns(right, false))
+    {
+        null_cols == not_null_cols && null_cols.len() >= 2
+    } else if let (Some(not_null_cols), Some(null_cols)) =
+        (extract_null_columns(left, false), extract_null_columns(right, true))
+    {
+        null_cols == not_null_cols && null_cols.len() >= 2
+    } else {
+        false
+    }
+}
+
+/// Returns whether the expression forbids empty strings in a textual column.
+pub(crate) fn is_not_empty_text_expr<DB: DatabaseLike>(
+    database: &DB,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> bool {
+    check_not_empty_text_recursive(database, columns, expr)
+}
+
+/// Returns the exclusive upper bound the expression enforces on the length
+/// of a textual column, if any.
+pub(crate) fn upper_text_length_bound<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> Option<usize> {
+    let mut visited_cols = Vec::new();
+    check_text_length_bound_recursive(
+        database,
+        table,
+        columns,
+        expr,
+        None,
+        &mut visited_cols,
+        BoundDirection::Upper,
+    )
+}
+
+/// Returns the inclusive lower bound the expression enforces on the length
+/// of a textual column, if any.
+pub(crate) fn lower_text_length_bound<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    expr: &Expr,
+) -> Option<usize> {
+    let mut visited_cols = Vec::new();
+    check_text_length_bound_recursive(
+        database,
+        table,
+        columns,
+        expr,
+        None,
+        &mut visited_cols,
+        BoundDirection::Lower,
+    )
+}
+
 /// A check constraint is a rule that specifies a condition that must be met
 /// for data to be inserted or updated in a table. This trait represents such
 /// a check constraint in a database-agnostic way.
@@ -551,6 +676,66 @@ pub trait CheckConstraintLike:
     /// ```
     fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table;
 
+    /// Returns the location of the statement creating the check constraint, if known.
+    ///
+    /// The location records the file the statement was read from when the
+    /// database is loaded from paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the check constraint belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE t (id INT);
+    ///      ALTER TABLE t ADD CONSTRAINT positive CHECK (id > 0);",
+    /// )?;
+    /// let check = db.table(None, "t").unwrap().check_constraints(&db).next().unwrap();
+    /// assert_eq!(check.source_location(&db).unwrap().statement_index(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn source_location<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        None
+    }
+
+    /// Returns whether the check constraint is declared `NO INHERIT`, in which
+    /// case it only applies to its own table and not to the tables inheriting
+    /// from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the check
+    ///   constraint from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE my_table (id INT CHECK (id > 0) NO INHERIT, age INT CHECK (age > 0));",
+    /// )?;
+    /// let table = db.table(None, "my_table").unwrap();
+    /// let flags: Vec<bool> = table.check_constraints(&db).map(|cc| cc.no_inherit(&db)).collect();
+    /// assert_eq!(flags, vec![true, false]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn no_inherit(&self, database: &Self::DB) -> bool;
+
     /// Iterates over the columns involved in the check constraint.
     ///
     /// # Arguments
@@ -726,13 +911,8 @@ pub trait CheckConstraintLike:
         database: &'db Self::DB,
         name: &str,
     ) -> Option<&'db <Self::DB as DatabaseLike>::Function> {
-        self.functions(database).find(|f| {
-            crate::utils::identifier_resolution::stored_identifier_matches_lookup(
-                f.name(),
-                f.name_is_quoted(),
-                name,
-            )
-        })
+        let casing = database.dialect().identifier_casing();
+        self.functions(database).find(|f| casing.matches_lookup(f.name(), f.name_is_quoted(), name))
     }
 
     /// Returns whether the check constraint involves any functions.
@@ -862,14 +1042,7 @@ pub trait CheckConstraintLike:
     /// ```
     fn is_tautology(&self, database: &Self::DB) -> bool {
         let columns = self.columns(database).collect::<Vec<_>>();
-        let expr = self.expression(database);
-
-        // First check using expression analysis
-        if let Some(true) = evaluate_constant_expr(database, &columns, expr) {
-            return true;
-        }
-
-        false
+        is_tautology_expr(database, &columns, self.expression(database))
     }
 
     /// Returns whether the check constraint is a negation (always false).
@@ -926,26 +1099,7 @@ pub trait CheckConstraintLike:
     /// ```
     fn is_negation(&self, database: &Self::DB) -> bool {
         let columns = self.columns(database).collect::<Vec<_>>();
-        let expr = self.expression(database);
-
-        // First check using expression analysis
-        if let Some(false) = evaluate_constant_expr(database, &columns, expr) {
-            return true;
-        }
-
-        // Check for contradicting length constraints
-        if let (Some(upper), Some(lower)) = (
-            self.is_upper_bounded_text_constraint(database),
-            self.is_lower_bounded_text_constraint(database),
-        ) {
-            // upper is exclusive upper bound, lower is inclusive lower bound
-            // if lower >= upper, then impossible
-            if lower >= upper {
-                return true;
-            }
-        }
-
-        false
+        is_negation_expr(database, self.table(database), &columns, self.expression(database))
     }
 
     /// Returns whether the check constraint is a mutual nullability constraint.
@@ -989,28 +1143,7 @@ pub trait CheckConstraintLike:
     /// # }
     /// ```
     fn is_mutual_nullability_constraint(&self, database: &Self::DB) -> bool {
-        use sqlparser::ast::{BinaryOperator, Expr};
-
-        let expr = self.expression(database);
-
-        // Must be an OR expression
-        let Expr::BinaryOp { left, op: BinaryOperator::Or, right } = expr else {
-            return false;
-        };
-
-        // Left side should be all NULL checks, right side all NOT NULL checks (or vice
-        // versa)
-        if let (Some(null_cols), Some(not_null_cols)) =
-            (extract_null_columns(left, true), extract_null_columns(right, false))
-        {
-            null_cols == not_null_cols && null_cols.len() >= 2
-        } else if let (Some(not_null_cols), Some(null_cols)) =
-            (extract_null_columns(left, false), extract_null_columns(right, true))
-        {
-            null_cols == not_null_cols && null_cols.len() >= 2
-        } else {
-            false
-        }
+        is_mutual_nullability_expr(self.expression(database))
     }
 
     /// Returns whether the check constraint checks that a textual column is not
@@ -1050,8 +1183,8 @@ pub trait CheckConstraintLike:
     /// # }
     /// ```
     fn is_not_empty_text_constraint(&self, database: &Self::DB) -> bool {
-        let expr = self.expression(database);
-        check_not_empty_text_recursive(database, expr, self)
+        let columns = self.columns(database).collect::<Vec<_>>();
+        is_not_empty_text_expr(database, &columns, self.expression(database))
     }
 
     /// Returns the upper bound of a text length constraint if the constraint
@@ -1112,16 +1245,8 @@ pub trait CheckConstraintLike:
     /// # }
     /// ```
     fn is_upper_bounded_text_constraint(&self, database: &Self::DB) -> Option<usize> {
-        let expr = self.expression(database);
-        let mut visited_cols = Vec::new();
-        check_text_length_bound_recursive(
-            database,
-            expr,
-            self,
-            None,
-            &mut visited_cols,
-            BoundDirection::Upper,
-        )
+        let columns = self.columns(database).collect::<Vec<_>>();
+        upper_text_length_bound(database, self.table(database), &columns, self.expression(database))
     }
 
     /// Returns the lower bound of a text length constraint if the constraint
@@ -1172,15 +1297,231 @@ pub trait CheckConstraintLike:
     /// # }
     /// ```
     fn is_lower_bounded_text_constraint(&self, database: &Self::DB) -> Option<usize> {
-        let expr = self.expression(database);
-        let mut visited_cols = Vec::new();
-        check_text_length_bound_recursive(
+        let columns = self.columns(database).collect::<Vec<_>>();
+        lower_text_length_bound(database, self.table(database), &columns, self.expression(database))
+    }
+
+    /// Returns the bounds the check constraint enforces on the value of the
+    /// provided column, if any.
+    ///
+    /// The bounds combine the comparisons of the column with numbers,
+    /// including `BETWEEN`, in the `AND`-ed conditions of the constraint, and
+    /// its comparisons with other columns, whose bounds are in turn resolved
+    /// from all the check constraints of the table. As `NULL` satisfies a
+    /// check constraint, the column may also be `NULL` unless it is declared
+    /// `NOT NULL`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the table
+    ///   from.
+    /// * `column` - The bounded column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::ops::Bound;
+    ///
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE people (
+    ///         age INT CHECK (age >= 0 AND age < 150),
+    ///         retired_at INT CHECK (retired_at <= age),
+    ///         name TEXT CHECK (length(name) < 10)
+    ///     );",
+    /// )?;
+    /// let table = db.table(None, "people").unwrap();
+    /// let check_constraints: Vec<_> = table.check_constraints(&db).collect();
+    /// let [cc_age, cc_retired_at, cc_name] = &check_constraints.as_slice() else {
+    ///     panic!("Expected three check constraints");
+    /// };
+    /// let age = table.column("age", &db).unwrap();
+    /// let retired_at = table.column("retired_at", &db).unwrap();
+    /// let name = table.column("name", &db).unwrap();
+    /// assert_eq!(
+    ///     cc_age.numeric_bounds(&db, age),
+    ///     Some((Bound::Included(0.0), Bound::Excluded(150.0)))
+    /// );
+    /// assert_eq!(
+    ///     cc_retired_at.numeric_bounds(&db, retired_at),
+    ///     Some((Bound::Unbounded, Bound::Excluded(150.0)))
+    /// );
+    /// assert_eq!(cc_retired_at.numeric_bounds(&db, age), None);
+    /// assert_eq!(cc_name.numeric_bounds(&db, name), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn numeric_bounds(
+        &self,
+        database: &Self::DB,
+        column: &<Self::DB as DatabaseLike>::Column,
+    ) -> Option<(Bound<f64>, Bound<f64>)> {
+        numeric_bounds(database, self.table(database), self.expression(database), column)
+    }
+
+    /// Returns the values this check constraint allows in the provided
+    /// column, without duplicates, or `None` if it does not restrict the
+    /// column to a list of literals.
+    ///
+    /// The recognized forms are `column IN (...)`, chains of
+    /// `column = ... OR column = ...`, and `column = ANY (ARRAY[...])`, as
+    /// PostgreSQL writes back `IN` lists, looking through casts. A
+    /// `column IS NULL` disjunct allows no further value, and conjunctions
+    /// intersect the values allowed by their sides.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   expression from.
+    /// * `column` - The restricted column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE posts (
+    ///         status TEXT CHECK (status IN ('draft', 'published')),
+    ///         kind TEXT CHECK (kind = 'note' OR kind = 'article'),
+    ///         lang TEXT CHECK ((lang)::text = ANY ((ARRAY['en', 'fr'])::text[])),
+    ///         score INT CHECK (score > 0)
+    ///     );",
+    /// )?;
+    /// let table = db.table(None, "posts").unwrap();
+    /// let check_constraints: Vec<_> = table.check_constraints(&db).collect();
+    /// let values = |number: usize, name: &str| {
+    ///     let column = table.column(name, &db).unwrap();
+    ///     check_constraints[number]
+    ///         .allowed_values(&db, column)
+    ///         .map(|values| values.iter().map(ToString::to_string).collect::<Vec<_>>())
+    /// };
+    /// assert_eq!(values(0, "status").unwrap(), ["'draft'", "'published'"]);
+    /// assert_eq!(values(1, "kind").unwrap(), ["'note'", "'article'"]);
+    /// assert_eq!(values(2, "lang").unwrap(), ["'en'", "'fr'"]);
+    /// assert_eq!(values(1, "status"), None);
+    /// assert_eq!(values(3, "score"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn allowed_values(
+        &self,
+        database: &Self::DB,
+        column: &<Self::DB as DatabaseLike>::Column,
+    ) -> Option<Vec<Value>> {
+        allowed_values(self.expression(database), column, database.dialect().identifier_casing())
+    }
+
+    /// Returns the regular expression this check constraint matches the
+    /// provided column against, or `None` if it matches it against no
+    /// pattern.
+    ///
+    /// POSIX regular expressions, as in `column ~ '...'` or the
+    /// case-insensitive `column ~* '...'`, are returned as written. `LIKE`,
+    /// `ILIKE` and `SIMILAR TO` patterns without an explicit `ESCAPE` are
+    /// translated into regular expressions anchored to the whole value. Of
+    /// several patterns combined with `AND`, the first one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   expression from.
+    /// * `column` - The column matched against the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     r"CREATE TABLE authors (
+    ///         doi TEXT CHECK (doi ~ '^10\.\d{4,9}/'),
+    ///         orcid TEXT CHECK (orcid SIMILAR TO '[0-9]{4}(-[0-9]{4}){3}'),
+    ///         handle TEXT CHECK (handle LIKE '@%'),
+    ///         age INT CHECK (age > 0)
+    ///     );",
+    /// )?;
+    /// let table = db.table(None, "authors").unwrap();
+    /// let check_constraints: Vec<_> = table.check_constraints(&db).collect();
+    /// let regex = |number: usize, name: &str| {
+    ///     check_constraints[number].pattern(&db, table.column(name, &db).unwrap())
+    /// };
+    /// assert_eq!(regex(0, "doi").unwrap(), r"^10\.\d{4,9}/");
+    /// assert_eq!(regex(1, "orcid").unwrap(), r"^(?:[0-9]{4}(\-[0-9]{4}){3})$");
+    /// assert_eq!(regex(2, "handle").unwrap(), "^(?:@.*)$");
+    /// assert_eq!(regex(3, "age"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn pattern(
+        &self,
+        database: &Self::DB,
+        column: &<Self::DB as DatabaseLike>::Column,
+    ) -> Option<String> {
+        pattern(self.expression(database), column, database.dialect().identifier_casing())
+    }
+
+    /// Returns whether every row satisfying this check constraint also
+    /// satisfies the other one, making the other one redundant.
+    ///
+    /// Implication is decided on a fragment of SQL: the `AND`-ed comparisons
+    /// of columns, or of the lengths of textual columns, with numbers
+    /// (including `BETWEEN`), the equalities of columns with text or boolean
+    /// literals, and the `IS NOT NULL` checks, where `NOT NULL` columns are
+    /// never `NULL`. Check constraints of different tables never imply each
+    /// other, and neither do those outside of the fragment.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the table
+    ///   from.
+    /// * `other` - The check constraint which may be implied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE my_table (
+    ///         price INT NOT NULL,
+    ///         name TEXT,
+    ///         CHECK (price BETWEEN 1 AND 100 AND length(name) <= 10),
+    ///         CHECK (price > 0),
+    ///         CHECK (length(name) < 20 AND price IS NOT NULL),
+    ///         CHECK (price > 0 OR name IS NULL)
+    ///     );",
+    /// )?;
+    /// let table = db.table(None, "my_table").unwrap();
+    /// let check_constraints: Vec<_> = table.check_constraints(&db).collect();
+    /// let [cc1, cc2, cc3, cc4] = &check_constraints.as_slice() else {
+    ///     panic!("Expected four check constraints");
+    /// };
+    /// assert!(cc1.implies(&db, cc2));
+    /// assert!(cc1.implies(&db, cc3));
+    /// assert!(!cc2.implies(&db, cc1));
+    /// assert!(!cc2.implies(&db, cc4)); // OR is outside of the fragment
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn implies(&self, database: &Self::DB, other: &Self) -> bool {
+        let table = self.table(database);
+        if table != other.table(database) {
+            return false;
+        }
+        let columns = table.columns(database).collect::<Vec<_>>();
+        expression_implies(
             database,
-            expr,
-            self,
-            None,
-            &mut visited_cols,
-            BoundDirection::Lower,
+            &columns,
+            self.expression(database),
+            other.expression(database),
         )
     }
 }
@@ -3,9 +3,15 @@
 use alloc::{string::String, vec::Vec};
 use core::{borrow::Borrow, fmt::Debug, hash::Hash};
 
+use sqlparser::ast::TriggerEvent;
+
 use crate::{
-    traits::{CheckConstraintLike, DatabaseLike, ForeignKeyLike, IndexLike, Metadata, TableLike},
-    utils::normalize_postgres_type,
+    structs::{SemanticKind, SemanticRules},
+    traits::{
+        CheckConstraintLike, CustomTypeLike, DatabaseLike, DialectLike, ForeignKeyLike, IndexLike,
+        Metadata, TableLike, TriggerLike,
+    },
+    utils::{nextval::nextval_sequence_name, normalize_postgres_type},
 };
 
 /// A trait for types that can be treated as SQL columns.
@@ -48,7 +54,9 @@ pub trait ColumnLike:
         false
     }
 
-    /// Returns the documentation of the column, if any.
+    /// Returns the documentation of the column, if any, as set by `COMMENT ON
+    /// COLUMN`, or else by an inline `COMMENT` option or by the SQL comments
+    /// preceding its definition.
     ///
     /// # Arguments
     ///
@@ -80,6 +88,47 @@ pub trait ColumnLike:
     where
         Self: 'db;
 
+    /// Returns the documentation of the column, if any, as
+    /// [`column_doc`](ColumnLike::column_doc) does.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the column
+    ///   documentation from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (
+    ///     -- The identifier of the user
+    ///     id INT,
+    ///     name TEXT
+    /// );",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let fields: Vec<String> = table
+    ///     .columns(&db)
+    ///     .map(|column| match column.doc(&db) {
+    ///         Some(doc) => format!("/// {doc}\n{}", column.column_name()),
+    ///         None => column.column_name().to_owned(),
+    ///     })
+    ///     .collect();
+    /// assert_eq!(fields, ["/// The identifier of the user\nid", "name"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn doc<'db>(&'db self, database: &'db Self::DB) -> Option<&'db str>
+    where
+        Self: 'db,
+    {
+        self.column_doc(database)
+    }
+
     /// Returns the data type of the column as a string.
     ///
     /// # Example
@@ -231,6 +280,138 @@ pub trait ColumnLike:
         normalize_postgres_type(self.data_type(database))
     }
 
+    /// Returns the user-defined type, such as an enumeration or a domain,
+    /// which the column is declared with, if any.
+    ///
+    /// Types qualified by a schema other than `public` are not considered,
+    /// as the data type of the column does not carry its schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the type
+    ///   from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TYPE mood AS ENUM ('sad', 'happy');
+    /// CREATE DOMAIN positive AS INT CHECK (VALUE > 0);
+    /// CREATE TABLE people (id INT, current_mood mood, age positive);
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "people").unwrap();
+    /// let mood = table.column("current_mood", &db).unwrap().custom_type(&db).unwrap();
+    /// let labels: Vec<&str> = mood.enum_variants().collect();
+    /// assert_eq!(labels, ["sad", "happy"]);
+    /// let age = table.column("age", &db).unwrap().custom_type(&db).unwrap();
+    /// assert_eq!(age.domain_checks().count(), 1);
+    /// assert!(table.column("id", &db).unwrap().custom_type(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_type<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::CustomType> {
+        let data_type = self.data_type(database);
+        let casing = database.dialect().identifier_casing();
+        database.custom_types().find(|custom_type| {
+            casing.matches(
+                custom_type.name(),
+                custom_type.name_is_quoted(),
+                data_type,
+                custom_type.name_is_quoted(),
+            ) && custom_type.schema().is_none_or(|schema| {
+                casing.matches(schema, custom_type.schema_is_quoted(), "public", false)
+            })
+        })
+    }
+
+    /// Returns the data type of the column, resolving domains to their base
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the column
+    ///   data type from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE DOMAIN email AS VARCHAR(255);
+    /// CREATE TABLE users (id INT, contact email);
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let contact = table.column("contact", &db).unwrap();
+    /// assert_eq!(contact.data_type(&db), "email");
+    /// assert_eq!(contact.resolved_data_type(&db), "VARCHAR");
+    /// assert_eq!(table.column("id", &db).unwrap().resolved_data_type(&db), "INT");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn resolved_data_type<'db>(&'db self, database: &'db Self::DB) -> &'db str {
+        self.custom_type(database)
+            .and_then(CustomTypeLike::domain_base_type)
+            .unwrap_or_else(|| self.data_type(database))
+    }
+
+    /// Returns the canonical data type of the column under the dialect of
+    /// its database, falling back to its
+    /// [`resolved_data_type`](Self::resolved_data_type) when the data type
+    /// is not known to the [`TypeNormalizer`](crate::traits::TypeNormalizer)
+    /// of the dialect.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the column
+    ///   data type and the dialect from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect, SQLiteDialect};
+    ///
+    /// let mysql = ParserDB::parse::<MySqlDialect>(
+    ///     "CREATE TABLE t (flag TINYINT(1), level TINYINT, created DATETIME, body LONGTEXT);",
+    /// )?;
+    /// let postgres = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE t (flag BOOL, level INT2, created TIMESTAMP, body TEXT);",
+    /// )?;
+    /// let sqlite = ParserDB::parse::<SQLiteDialect>(
+    ///     "CREATE TABLE t (flag BOOLEAN, level INTEGER, created DATETIME, body VARCHAR(10));",
+    /// )?;
+    /// let normalized = |db: &ParserDB| -> Vec<String> {
+    ///     let table = db.table(None, "t").unwrap();
+    ///     table.columns(db).map(|column| column.normalized_type(db).to_string()).collect()
+    /// };
+    /// assert_eq!(normalized(&mysql), ["BOOLEAN", "SMALLINT", "TIMESTAMP", "TEXT"]);
+    /// assert_eq!(normalized(&postgres), ["BOOLEAN", "SMALLINT", "TIMESTAMP", "TEXT"]);
+    /// assert_eq!(normalized(&sqlite), ["BOOLEAN", "BIGINT", "TIMESTAMP", "TEXT"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn normalized_type<'db>(&'db self, database: &'db Self::DB) -> &'db str {
+        database
+            .dialect()
+            .canonical_type(database, self.borrow())
+            .unwrap_or_else(|| self.resolved_data_type(database))
+    }
+
     /// Returns whether the column type is textual.
     ///
     /// # Arguments
@@ -348,6 +529,197 @@ pub trait ColumnLike:
         self.default_value().is_some()
     }
 
+    /// Returns the sequence advanced by the default value of the column, if
+    /// the default is a `nextval('...')` call naming a sequence of the
+    /// database.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   sequence from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SEQUENCE users_id_seq;
+    /// CREATE TABLE users (
+    ///     id INT DEFAULT nextval('users_id_seq'::regclass),
+    ///     legacy_id INT DEFAULT nextval('missing_seq'),
+    ///     name TEXT DEFAULT 'anonymous'
+    /// );
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let id = table.column("id", &db).unwrap();
+    /// assert_eq!(id.default_sequence(&db).map(SequenceLike::name), Some("users_id_seq"));
+    /// assert!(table.column("legacy_id", &db).unwrap().default_sequence(&db).is_none());
+    /// assert!(table.column("name", &db).unwrap().default_sequence(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn default_sequence<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Sequence> {
+        let default_value = self.default_value()?;
+        let (schema, name) = nextval_sequence_name(&default_value)?;
+        database.sequence(schema, name)
+    }
+
+    /// Returns whether the values of the column are generated by the
+    /// database, so that they must not be supplied on insert.
+    ///
+    /// The default implementation recognizes the serial types and defaults
+    /// drawing from a sequence with `nextval('...')`, while implementations
+    /// are expected to also recognize the identity and auto-increment
+    /// options of their columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the column
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (
+    ///     id BIGSERIAL PRIMARY KEY,
+    ///     code INT GENERATED ALWAYS AS IDENTITY,
+    ///     legacy_id INT DEFAULT nextval('legacy_seq'),
+    ///     name TEXT DEFAULT 'anonymous'
+    /// );
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let generated: Vec<&str> = table
+    ///     .columns(&db)
+    ///     .filter(|column| column.is_auto_generated(&db))
+    ///     .map(|column| column.column_name())
+    ///     .collect();
+    /// assert_eq!(generated, ["id", "code", "legacy_id"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_auto_generated(&self, _database: &Self::DB) -> bool {
+        self.is_generated()
+            || self.default_value().is_some_and(|value| nextval_sequence_name(&value).is_some())
+    }
+
+    /// Returns the role the column plays in its table, following the default
+    /// [`SemanticRules`].
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   column type and the triggers of its table from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE posts (
+    ///     id UUID PRIMARY KEY,
+    ///     token TEXT DEFAULT gen_random_uuid(),
+    ///     created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    ///     touched TIMESTAMPTZ,
+    ///     is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    ///     title TEXT
+    /// );
+    /// CREATE FUNCTION touch() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     NEW.touched = now();
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER posts_touch BEFORE UPDATE ON posts
+    /// FOR EACH ROW EXECUTE FUNCTION touch();
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "posts").unwrap();
+    /// let kinds: Vec<Option<SemanticKind>> =
+    ///     table.columns(&db).map(|column| column.semantic_kind(&db)).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         Some(SemanticKind::Uuid),
+    ///         Some(SemanticKind::Uuid),
+    ///         Some(SemanticKind::CreatedAt),
+    ///         Some(SemanticKind::UpdatedAt),
+    ///         Some(SemanticKind::SoftDeleteFlag),
+    ///         None,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn semantic_kind(&self, database: &Self::DB) -> Option<SemanticKind> {
+        self.semantic_kind_with_rules(database, &SemanticRules::default())
+    }
+
+    /// Returns the role the column plays in its table, following the
+    /// provided [`SemanticRules`].
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   column type and the triggers of its table from.
+    /// * `rules` - The rules to classify the column with.
+    fn semantic_kind_with_rules(
+        &self,
+        database: &Self::DB,
+        rules: &SemanticRules,
+    ) -> Option<SemanticKind> {
+        let data_type = normalize_postgres_type(self.resolved_data_type(database));
+        let is_timestamp = matches!(
+            data_type,
+            "timestamp without time zone" | "timestamp with time zone" | "DATETIME"
+        );
+        let name = self.column_name();
+        if is_timestamp && rules.is_created_at_name(name) {
+            return Some(SemanticKind::CreatedAt);
+        }
+        if is_timestamp
+            && (rules.is_updated_at_name(name)
+                || self.table(database).triggers(database).any(|trigger| {
+                    trigger.events().iter().any(|event| matches!(event, TriggerEvent::Update(_)))
+                        && trigger
+                            .maintenance_assignments(database)
+                            .any(|(column, _)| column == self.borrow())
+                }))
+        {
+            return Some(SemanticKind::UpdatedAt);
+        }
+        if (data_type == "boolean" || (is_timestamp && self.is_nullable(database)))
+            && rules.is_soft_delete_name(name)
+        {
+            return Some(SemanticKind::SoftDeleteFlag);
+        }
+        if data_type == "UUID"
+            || self.default_value().is_some_and(|value| rules.generates_uuid(&value))
+        {
+            return Some(SemanticKind::Uuid);
+        }
+        None
+    }
+
     /// Returns the table that this column belongs to.
     ///
     /// # Arguments
@@ -506,8 +878,9 @@ pub trait ColumnLike:
             if !fk.is_referenced_primary_key(database) || fk.is_composite(database) {
                 return false;
             }
-            let referenced_table = fk.referenced_table(database);
-            referenced_table == table || referenced_table.is_descendant_of(database, table)
+            fk.referenced_table(database).is_some_and(|referenced_table| {
+                referenced_table == table || referenced_table.is_descendant_of(database, table)
+            })
         })
     }
 
@@ -590,6 +963,92 @@ pub trait ColumnLike:
         self.foreign_keys(database).next().is_some()
     }
 
+    /// Returns the foreign key this column participates in, together with the
+    /// column it references.
+    ///
+    /// For composite foreign keys, the returned column is the referenced
+    /// column paired with this column. When the column belongs to several
+    /// foreign keys, the first one is returned: use
+    /// [`foreign_keys`](ColumnLike::foreign_keys) to inspect all of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query foreign
+    ///   keys from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE referenced_table (id INT, name TEXT, PRIMARY KEY (id, name));
+    /// CREATE TABLE host_table (
+    ///     ref_id INT,
+    ///     ref_name TEXT,
+    ///     notes TEXT,
+    ///     FOREIGN KEY (ref_id, ref_name) REFERENCES referenced_table(id, name)
+    /// );
+    /// ",
+    /// )?;
+    /// let host_table = db.table(None, "host_table").unwrap();
+    /// let ref_name = host_table.column("ref_name", &db).unwrap();
+    /// let (foreign_key, referenced) = ref_name.references(&db).unwrap();
+    /// assert_eq!(foreign_key.referenced_table(&db).unwrap().table_name(), "referenced_table");
+    /// assert_eq!(referenced.column_name(), "name");
+    /// let notes = host_table.column("notes", &db).unwrap();
+    /// assert!(notes.references(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn references<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<(
+        &'db <Self::DB as DatabaseLike>::ForeignKey,
+        &'db <Self::DB as DatabaseLike>::Column,
+    )>
+    where
+        Self: 'db,
+    {
+        self.foreign_keys(database).next().map(|foreign_key| {
+            (foreign_key, foreign_key.referenced_column_for_host_column(database, self.borrow()))
+        })
+    }
+
+    /// Returns whether the column references another column through a
+    /// foreign key.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query foreign
+    ///   keys from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE parent (id INT PRIMARY KEY);
+    /// CREATE TABLE child (id INT PRIMARY KEY, parent_id INT REFERENCES parent(id));
+    /// ",
+    /// )?;
+    /// let child = db.table(None, "child").unwrap();
+    /// assert!(child.column("parent_id", &db).unwrap().is_foreign_key(&db));
+    /// assert!(!child.column("id", &db).unwrap().is_foreign_key(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_foreign_key(&self, database: &Self::DB) -> bool {
+        self.references(database).is_some()
+    }
+
     /// Returns the non-composite foreign keys associated with this column.
     ///
     /// # Arguments
@@ -1047,6 +1506,11 @@ where
         (*self).default_value()
     }
 
+    #[inline]
+    fn is_auto_generated(&self, database: &Self::DB) -> bool {
+        (*self).is_auto_generated(database)
+    }
+
     #[inline]
     fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
@@ -1101,6 +1565,11 @@ where
         (**self).default_value()
     }
 
+    #[inline]
+    fn is_auto_generated(&self, database: &Self::DB) -> bool {
+        (**self).is_auto_generated(database)
+    }
+
     #[inline]
     fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
@@ -0,0 +1,242 @@
+//! Submodule providing a trait for describing SQL user-defined types.
+
+use core::fmt::Debug;
+
+use sqlparser::ast::Expr;
+
+use crate::traits::{DatabaseLike, Metadata};
+
+/// A trait for types that can be treated as SQL user-defined types.
+///
+/// User-defined types are enumerations, domains (a base type restricted by
+/// CHECK constraints) and other types, such as composite types, which
+/// columns may reference as their data type.
+pub trait CustomTypeLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
+    /// The database type the custom type belongs to.
+    type DB: DatabaseLike;
+
+    /// Returns the name of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TYPE mood AS ENUM ('sad', 'happy');")?;
+    /// let mood = db.custom_type(None, "mood").unwrap();
+    /// assert_eq!(mood.name(), "mood");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn name(&self) -> &str;
+
+    /// Returns whether the type identifier was quoted in SQL.
+    ///
+    /// Quoted identifiers are resolved case-sensitively in PostgreSQL.
+    #[inline]
+    fn name_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the schema of the type, if specified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE DOMAIN app.email AS TEXT;")?;
+    /// let email = db.custom_type(Some("app"), "email").unwrap();
+    /// assert_eq!(email.schema(), Some("app"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn schema(&self) -> Option<&str>;
+
+    /// Returns whether the schema identifier of the type was quoted in SQL.
+    #[inline]
+    fn schema_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the type is an enumeration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TYPE mood AS ENUM ('sad', 'happy'); CREATE DOMAIN email AS TEXT;",
+    /// )?;
+    /// assert!(db.custom_type(None, "mood").unwrap().is_enum());
+    /// assert!(!db.custom_type(None, "email").unwrap().is_enum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_enum(&self) -> bool;
+
+    /// Returns an iterator over the labels of the enumeration, in declaration
+    /// order. The iterator is empty for types which are not enumerations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TYPE mood AS ENUM ('sad', 'happy');")?;
+    /// let labels: Vec<&str> = db.custom_type(None, "mood").unwrap().enum_variants().collect();
+    /// assert_eq!(labels, ["sad", "happy"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn enum_variants(&self) -> impl Iterator<Item = &str>;
+
+    /// Returns the normalized base type of the domain, or `None` for types
+    /// which are not domains.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TYPE mood AS ENUM ('sad', 'happy'); CREATE DOMAIN email AS VARCHAR(255);",
+    /// )?;
+    /// assert_eq!(db.custom_type(None, "email").unwrap().domain_base_type(), Some("VARCHAR"));
+    /// assert_eq!(db.custom_type(None, "mood").unwrap().domain_base_type(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn domain_base_type(&self) -> Option<&str>;
+
+    /// Returns whether the type is a domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE DOMAIN email AS TEXT;")?;
+    /// assert!(db.custom_type(None, "email").unwrap().is_domain());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_domain(&self) -> bool {
+        self.domain_base_type().is_some()
+    }
+
+    /// Returns an iterator over the expressions of the CHECK constraints of
+    /// the domain. The iterator is empty for types which are not domains.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE DOMAIN positive AS INT CHECK (VALUE > 0);",
+    /// )?;
+    /// let checks: Vec<String> = db
+    ///     .custom_type(None, "positive")
+    ///     .unwrap()
+    ///     .domain_checks()
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(checks, ["VALUE > 0"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn domain_checks(&self) -> impl Iterator<Item = &Expr>;
+}
+
+impl<T: CustomTypeLike> CustomTypeLike for &T {
+    type DB = T::DB;
+
+    fn name(&self) -> &str {
+        (*self).name()
+    }
+
+    fn name_is_quoted(&self) -> bool {
+        (*self).name_is_quoted()
+    }
+
+    fn schema(&self) -> Option<&str> {
+        (*self).schema()
+    }
+
+    fn schema_is_quoted(&self) -> bool {
+        (*self).schema_is_quoted()
+    }
+
+    fn is_enum(&self) -> bool {
+        (*self).is_enum()
+    }
+
+    fn enum_variants(&self) -> impl Iterator<Item = &str> {
+        (*self).enum_variants()
+    }
+
+    fn domain_base_type(&self) -> Option<&str> {
+        (*self).domain_base_type()
+    }
+
+    fn is_domain(&self) -> bool {
+        (*self).is_domain()
+    }
+
+    fn domain_checks(&self) -> impl Iterator<Item = &Expr> {
+        (*self).domain_checks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::structs::ParserDB;
+
+    /// Exercises the `impl CustomTypeLike for &T` blanket forwarding.
+    #[test]
+    fn test_reference_impl() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE SCHEMA app;
+            CREATE TYPE app.\"Mood\" AS ENUM ('sad', 'happy');
+            CREATE DOMAIN positive AS INT CHECK (VALUE > 0);
+            ",
+        )
+        .unwrap();
+        let mood = db.custom_type(Some("app"), "\"Mood\"").expect("enum");
+        let positive = db.custom_type(None, "positive").expect("domain");
+
+        let m_ref: &<ParserDB as DatabaseLike>::CustomType = mood;
+        assert_eq!(<&_ as CustomTypeLike>::name(&m_ref), "Mood");
+        assert!(<&_ as CustomTypeLike>::name_is_quoted(&m_ref));
+        assert_eq!(<&_ as CustomTypeLike>::schema(&m_ref), Some("app"));
+        assert!(!<&_ as CustomTypeLike>::schema_is_quoted(&m_ref));
+        assert!(<&_ as CustomTypeLike>::is_enum(&m_ref));
+        assert_eq!(<&_ as CustomTypeLike>::enum_variants(&m_ref).count(), 2);
+        assert!(!<&_ as CustomTypeLike>::is_domain(&m_ref));
+
+        let p_ref: &<ParserDB as DatabaseLike>::CustomType = positive;
+        assert_eq!(<&_ as CustomTypeLike>::domain_base_type(&p_ref), Some("INT"));
+        assert_eq!(<&_ as CustomTypeLike>::domain_checks(&p_ref).count(), 1);
+    }
+}
@@ -1,21 +1,22 @@
 //! Submodule providing a trait for describing SQL Database-like entities.
 
-use alloc::vec::Vec;
-use core::{borrow::Borrow, fmt::Debug};
-
-use geometric_traits::{
-    impls::CSR2D,
-    prelude::{GenericEdgesBuilder, Kahn, SquareCSR2D},
-    traits::EdgesBuilder,
-};
+use alloc::{string::String, vec::Vec};
+use core::fmt::Debug;
 
 use crate::{
+    diff::SchemaDiff,
+    lint::{LintReport, LintRuleset},
+    privileges::PrivilegeSet,
+    structs::{
+        ExtensionHierarchy, ExtensionStrategy, FunctionDependencyGraph, NameRegistry, RoleReport,
+        TableDependencyGraph, TriggerCascadeGraph,
+    },
     traits::{
-        CheckConstraintLike, ColumnGrantLike, ColumnLike, DialectLike, ForeignKeyLike,
-        FunctionLike, IndexLike, PolicyLike, RoleLike, SchemaLike, TableGrantLike, TableLike,
-        TriggerLike, UniqueIndexLike,
+        CheckConstraintLike, ColumnGrantLike, ColumnLike, CustomTypeLike, DefaultPrivilegeLike,
+        DialectLike, ForeignKeyLike, FunctionLike, IndexLike, PolicyLike, PrimaryKeyLike, RoleLike,
+        SchemaLike, SequenceLike, TableGrantLike, TableLike, TriggerLike, UniqueIndexLike,
+        ViewLike,
     },
-    utils::identifier_resolution::stored_identifier_matches_lookup,
 };
 
 /// A trait for types that can be treated as SQL databases.
@@ -34,6 +35,8 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     type Trigger: TriggerLike<DB = Self>;
     /// Type of the unique indexes in the schema.
     type UniqueIndex: UniqueIndexLike<DB = Self>;
+    /// Type of the primary keys in the schema.
+    type PrimaryKey: PrimaryKeyLike<DB = Self>;
     /// Type of the check constraints in the schema.
     type CheckConstraint: CheckConstraintLike<DB = Self>;
     /// Type of the policies in the schema.
@@ -46,6 +49,14 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     type ColumnGrant: ColumnGrantLike<DB = Self>;
     /// Type of the schemas in the database.
     type Schema: SchemaLike<DB = Self>;
+    /// Type of the views in the database.
+    type View: ViewLike<DB = Self>;
+    /// Type of the sequences in the database.
+    type Sequence: SequenceLike<DB = Self>;
+    /// Type of the user-defined types in the database.
+    type CustomType: CustomTypeLike<DB = Self>;
+    /// Type of the default privileges in the database.
+    type DefaultPrivilege: DefaultPrivilegeLike<DB = Self>;
     /// SQL dialect this database is expressed in.
     ///
     /// The dialect owns per-column type predicates (`is_bool`, `is_uuid`,
@@ -57,6 +68,23 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// Returns the SQL dialect this database is expressed in.
     fn dialect(&self) -> &Self::Dialect;
 
+    /// Returns the rule detecting the foreign keys which make their table an
+    /// extension of the table they reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sql_traits::structs::ExtensionStrategy;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT);")?;
+    /// assert_eq!(db.extension_strategy(), &ExtensionStrategy::PkFkAnyName);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extension_strategy(&self) -> &ExtensionStrategy;
+
     /// Returns the name of the database.
     ///
     /// # Example
@@ -110,6 +138,43 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// ```
     fn timezone(&self) -> Option<&str>;
 
+    /// Returns the schemas searched, in order, for the tables and functions
+    /// referred to without a schema.
+    ///
+    /// This is the path configured through
+    /// [`ParserOptions::with_search_path`](crate::structs::ParserOptions::with_search_path),
+    /// as last changed by the `SET search_path` statements of the script.
+    /// The objects created without a schema are placed in the first schema
+    /// of the path effective at their creation which exists. Databases which
+    /// do not track a search path return an empty one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE SCHEMA app;
+    /// CREATE TABLE app.users (id INT PRIMARY KEY);
+    /// SET search_path = app, public;
+    /// CREATE TABLE posts (id INT, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// assert_eq!(db.search_path(), ["app", "public"]);
+    /// assert!(db.table(Some("app"), "posts").is_some());
+    ///
+    /// let db_no_path = ParserDB::parse::<GenericDialect>("CREATE TABLE t (id INT);")?;
+    /// assert!(db_no_path.search_path().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn search_path(&self) -> &[String] {
+        &[]
+    }
+
     /// Iterates over the tables defined in the schema.
     ///
     /// # Example
@@ -176,6 +241,125 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// ```
     fn indexes(&self) -> impl Iterator<Item = &Self::Index>;
 
+    /// Iterates over the foreign keys which are not covered by any index or
+    /// unique index of their host table, as reported by
+    /// [`ForeignKeyLike::is_indexed`]. Deleting or updating a referenced row
+    /// requires a scan of the host table of such foreign keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE profiles (id INT PRIMARY KEY REFERENCES users (id));
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// CREATE TABLE comments (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// CREATE INDEX comments_author ON comments (author_id);
+    /// ",
+    /// )?;
+    /// let unindexed: Vec<&str> = db
+    ///     .unindexed_foreign_keys()
+    ///     .map(|foreign_key| foreign_key.host_table(&db).table_name())
+    ///     .collect();
+    /// assert_eq!(unindexed, ["posts"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn unindexed_foreign_keys(&self) -> impl Iterator<Item = &Self::ForeignKey> {
+        self.tables()
+            .flat_map(move |table| table.foreign_keys(self))
+            .filter(move |foreign_key| !foreign_key.is_indexed(self))
+    }
+
+    /// Returns the check constraints implied by another check constraint of
+    /// the same table, each paired with the first constraint implying it.
+    ///
+    /// Of two equivalent check constraints, only the one declared last is
+    /// returned. Implication is decided as by [`CheckConstraintLike::implies`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE products (
+    ///     price INT CHECK (price > 0),
+    ///     stock INT CHECK (stock >= 0),
+    ///     CHECK (price BETWEEN 1 AND 1000),
+    ///     CHECK (stock > -1)
+    /// );
+    /// ",
+    /// )?;
+    /// let redundant: Vec<(String, String)> = db
+    ///     .redundant_check_constraints()
+    ///     .into_iter()
+    ///     .map(|(redundant, implying)| {
+    ///         (redundant.expression(&db).to_string(), implying.expression(&db).to_string())
+    ///     })
+    ///     .collect();
+    /// assert_eq!(
+    ///     redundant,
+    ///     [
+    ///         ("price > 0".to_string(), "price BETWEEN 1 AND 1000".to_string()),
+    ///         ("stock > -1".to_string(), "stock >= 0".to_string()),
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn redundant_check_constraints(&self) -> Vec<(&Self::CheckConstraint, &Self::CheckConstraint)> {
+        let mut redundant = Vec::new();
+        for table in self.tables() {
+            let constraints: Vec<&Self::CheckConstraint> = table.check_constraints(self).collect();
+            for (number, constraint) in constraints.iter().enumerate() {
+                let implying = constraints.iter().enumerate().find(|(other_number, other)| {
+                    *other_number != number
+                        && other.implies(self, constraint)
+                        && (*other_number < number || !constraint.implies(self, other))
+                });
+                if let Some((_, implying)) = implying {
+                    redundant.push((*constraint, *implying));
+                }
+            }
+        }
+        redundant
+    }
+
+    /// Runs the rules of the provided ruleset against the database,
+    /// returning the lints they raise.
+    ///
+    /// # Arguments
+    ///
+    /// * `ruleset` - The rules to run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{lint::LintRuleset, prelude::*};
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// -- Registered users
+    /// CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL);
+    /// ",
+    /// )?;
+    /// assert!(db.lint(&LintRuleset::recommended()).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn lint(&self, ruleset: &LintRuleset<Self>) -> LintReport {
+        ruleset.lint(self)
+    }
+
     /// Returns whether the database has at least one table.
     ///
     /// # Example
@@ -257,8 +441,8 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
         self.tables().map(|table| table.columns(self).count()).max().unwrap_or(0)
     }
 
-    /// Returns tables as a Kahn's ordering based on foreign key dependencies,
-    /// ignoring potential self-references which would create cycles.
+    /// Returns the graph of the dependencies between the tables induced by
+    /// their foreign keys, ignoring self-references.
     ///
     /// # Example
     ///
@@ -268,6 +452,104 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     ///
     /// let db = ParserDB::parse::<GenericDialect>(
     ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+    /// CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users(id));
+    /// ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams(id);
+    /// ",
+    /// )?;
+    /// let graph = db.table_dependency_graph();
+    /// let cycle = graph.topological_order().unwrap_err();
+    /// assert_eq!(cycle.tables().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn table_dependency_graph(&self) -> TableDependencyGraph<'_, Self> {
+        TableDependencyGraph::new(self)
+    }
+
+    /// Returns the graph of the tables which the bodies of the functions
+    /// read or write.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE FUNCTION user_count() RETURNS BIGINT AS 'SELECT count(*) FROM users;' LANGUAGE SQL;
+    /// ",
+    /// )?;
+    /// let graph = db.function_dependency_graph();
+    /// let function = db.function("user_count").unwrap();
+    /// let tables: Vec<&str> = graph.dependencies(function).map(TableLike::table_name).collect();
+    /// assert_eq!(tables, ["users"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn function_dependency_graph(&self) -> FunctionDependencyGraph<'_, Self> {
+        FunctionDependencyGraph::new(self)
+    }
+
+    /// Returns the graph of the triggers which may fire each other through
+    /// the writes of their functions, to detect potential infinite trigger
+    /// loops.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE pings (id INT);
+    /// CREATE TABLE pongs (id INT);
+    /// CREATE FUNCTION ping() RETURNS TRIGGER AS $$
+    /// BEGIN INSERT INTO pongs (id) VALUES (NEW.id); RETURN NEW; END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE FUNCTION pong() RETURNS TRIGGER AS $$
+    /// BEGIN INSERT INTO pings (id) VALUES (NEW.id); RETURN NEW; END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER on_ping AFTER INSERT ON pings FOR EACH ROW EXECUTE FUNCTION ping();
+    /// CREATE TRIGGER on_pong AFTER INSERT ON pongs FOR EACH ROW EXECUTE FUNCTION pong();
+    /// ",
+    /// )?;
+    /// let graph = db.trigger_cascade_graph();
+    /// assert!(graph.has_cycles());
+    /// assert_eq!(graph.cycles()[0].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trigger_cascade_graph(&self) -> TriggerCascadeGraph<'_, Self> {
+        TriggerCascadeGraph::new(self)
+    }
+
+    /// Returns tables as a Kahn's ordering based on the dependencies of
+    /// [`TableDependencyGraph`], namely foreign keys and sequences owned by
+    /// another table, ignoring potential self-references which would create
+    /// cycles.
+    ///
+    /// Deprecated, as valid schemas may hold cycles of foreign keys: use
+    /// [`TableDependencyGraph::topological_order`], which reports them.
+    ///
+    /// # Panics
+    ///
+    /// * If the foreign keys form a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
     /// CREATE TABLE users (
     ///    id SERIAL PRIMARY KEY,
     ///   name TEXT NOT NULL
@@ -291,55 +573,126 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// # Ok(())
     /// # }
     /// ```
+    #[deprecated(
+        note = "panics on cycles of foreign keys; use `table_dependency_graph().topological_order()`"
+    )]
     fn table_dag(&self) -> Vec<&Self::Table> {
-        let tables = self.tables().collect::<Vec<&Self::Table>>();
-
-        let mut edges = tables
-            .iter()
-            .enumerate()
-            .flat_map(|(table_number, table)| {
-                let tables_ref = tables.as_slice();
-                table
-                    .foreign_keys(self)
-                    .map(Borrow::borrow)
-                    .filter_map(move |fk| {
-                        let referenced_table = fk.referenced_table(self).borrow();
-                        // We ignore self-references to avoid cycles in the DAG.
-                        if referenced_table == *table {
-                            return None;
-                        }
-                        Some(tables_ref.binary_search(&referenced_table).unwrap_or_else(|_| panic!("Referenced table '{}' not found in database '{}' - Tables are {:?}",
-                            referenced_table.table_name(),
-                            self.catalog_name(),
-                            tables_ref.iter().map(TableLike::table_name).collect::<Vec<&str>>())))
-                    })
-                    .map(move |referenced_table_number| (referenced_table_number, table_number))
-            })
-            .collect::<Vec<(usize, usize)>>();
-
-        // There is no guarantee that the foreign keys in a table are ordered,
-        // so it is necessary to sort and deduplicate the edges.
-        edges.sort_unstable();
-        // Furthermore, there is no guarantee that there are no foreign keys
-        // referencing the same table, so we deduplicate the edges as well.
-        edges.dedup();
-
-        let dag: SquareCSR2D<CSR2D<usize, usize, usize>> = GenericEdgesBuilder::default()
-            .expected_shape(tables.len())
-            .edges(edges)
-            .build()
-            .expect("Failed to build table dependency DAG");
-        let dag_ordering = dag.kahn().expect("Failed to compute Kahn's ordering");
-
-        let mut ordered_tables = tables.clone();
-        for (table_index, table) in dag_ordering.into_iter().zip(tables.iter()) {
-            ordered_tables[table_index] = table;
-        }
+        self.table_dependency_graph().topological_order().unwrap_or_else(|cycle| panic!("{cycle}"))
+    }
 
-        ordered_tables
+    /// Returns the hierarchy of the extension relationships between the
+    /// tables of the database, i.e. of the tables whose primary key is a
+    /// foreign key to the primary key of another table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE animals (id INT PRIMARY KEY);
+    /// CREATE TABLE dogs (id INT PRIMARY KEY REFERENCES animals(id));
+    /// CREATE TABLE puppies (id INT PRIMARY KEY REFERENCES dogs(id));
+    /// ",
+    /// )?;
+    /// let hierarchy = db.extension_hierarchy();
+    /// let roots: Vec<&str> = hierarchy.roots().map(TableLike::table_name).collect();
+    /// assert_eq!(roots, ["animals"]);
+    /// assert!(hierarchy.diamonds().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extension_hierarchy(&self) -> ExtensionHierarchy<'_, Self> {
+        ExtensionHierarchy::new(self)
+    }
+
+    /// Returns the registry of the names occupying the relation namespace of
+    /// each schema, i.e. the names of tables, views, sequences, indexes and
+    /// named unique and primary key constraints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE users (id INT); CREATE INDEX users_id_idx ON users (id);",
+    /// )?;
+    /// let registry = db.name_registry();
+    /// assert!(registry.contains(None, "users_id_idx"));
+    /// assert!(!registry.contains(None, "users_id_seq"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn name_registry(&self) -> NameRegistry {
+        NameRegistry::new(self)
+    }
+
+    /// Returns whether the database has the same structure as another one,
+    /// or the report of their differences otherwise.
+    ///
+    /// The databases are compared through
+    /// [`SchemaDiff::between_ignoring_constraint_names`]: the order in which
+    /// objects are declared, the names of indices and constraints and the
+    /// quoting of identifiers resolving to the same name are not taken into
+    /// account. This makes it possible to check that a sequence of migrations
+    /// yields the same schema as its declarative definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The database to compare against.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SchemaDiff`] from this database to `other` if the two
+    /// databases differ.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let declarative = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, email TEXT UNIQUE);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// let migrated = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT);
+    /// CREATE TABLE \"users\" (id INT PRIMARY KEY);
+    /// ALTER TABLE users ADD COLUMN email TEXT;
+    /// ALTER TABLE users ADD CONSTRAINT users_email_key UNIQUE (email);
+    /// ALTER TABLE posts ADD CONSTRAINT posts_author_fk FOREIGN KEY (author_id) REFERENCES users (id);
+    /// ",
+    /// )?;
+    /// assert!(declarative.schema_equivalent(&migrated).is_ok());
+    ///
+    /// let outdated = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, email TEXT);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// let report = declarative.schema_equivalent(&outdated).unwrap_err();
+    /// assert_eq!(report.to_string(), "removed unique index (email) on users\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn schema_equivalent<Other: DatabaseLike>(&self, other: &Other) -> Result<(), SchemaDiff> {
+        let diff = SchemaDiff::between_ignoring_constraint_names(self, other);
+        if diff.is_empty() { Ok(()) } else { Err(diff) }
     }
 
-    /// Iterates over the functions created in the database.
+    /// Iterates over the functions of the database, including the built-in
+    /// functions added by the parser.
     ///
     /// # Example
     ///
@@ -353,13 +706,12 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// CREATE FUNCTION greet(name TEXT) RETURNS TEXT AS 'SELECT \"Hello, \" || name;';
     /// ",
     /// )?;
-    /// let function_names: Vec<&str> = db.functions().map(|f| f.name()).collect();
-    ///
-    /// // There will be more than two functions because the parser may add
-    /// // additional built-in functions automatically. We check that certainly
-    /// // our two functions are present.
-    /// assert!(function_names.contains(&"add_one"));
-    /// assert!(function_names.contains(&"greet"));
+    /// // The built-in functions added by the parser, such as `coalesce`, are
+    /// // told apart by `FunctionLike::is_builtin`.
+    /// assert!(db.functions().any(|f| f.name() == "coalesce"));
+    /// let function_names: Vec<&str> =
+    ///     db.functions().filter(|f| !f.is_builtin(&db)).map(|f| f.name()).collect();
+    /// assert_eq!(function_names, ["add_one", "greet"]);
     ///
     /// # Ok(())
     /// # }
@@ -400,7 +752,9 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// # }
     /// ```
     ///
-    /// PostgreSQL-style identifier resolution is applied:
+    /// Names are compared following the
+    /// [`identifier_casing`](DialectLike::identifier_casing) of the dialect.
+    /// Under PostgreSQL:
     ///
     /// - Unquoted lookup names are case-insensitive.
     /// - Quoted lookup names are case-sensitive.
@@ -408,7 +762,7 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// ```rust
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use sql_traits::prelude::*;
-    /// use sqlparser::dialect::PostgreSqlDialect;
+    /// use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
     ///
     /// let db = ParserDB::parse::<PostgreSqlDialect>(
     ///     r#"
@@ -423,6 +777,10 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     ///
     /// assert!(db.table(None, "\"Bar\"").is_some());
     /// assert!(db.table(None, "bar").is_none());
+    ///
+    /// // MySQL ignores the case of quoted names as well.
+    /// let db = ParserDB::parse::<MySqlDialect>("CREATE TABLE `Bar` (id INT);")?;
+    /// assert!(db.table(None, "bar").is_some());
     /// # Ok(())
     /// # }
     /// ```
@@ -490,6 +848,179 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
         self.tables().nth(table_id)
     }
 
+    /// Iterates over the columns of every table of the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT, name TEXT); CREATE TABLE posts (id INT);",
+    /// )?;
+    /// let mut columns: Vec<(&str, &str)> = db
+    ///     .columns()
+    ///     .map(|column| (ColumnLike::table(column, &db).table_name(), column.column_name()))
+    ///     .collect();
+    /// columns.sort_unstable();
+    /// assert_eq!(columns, [("posts", "id"), ("users", "id"), ("users", "name")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn columns(&self) -> impl Iterator<Item = &Self::Column>;
+
+    /// Returns the column with the given name of the table with the given
+    /// schema and name, if it exists.
+    ///
+    /// Names are matched following the same identifier rules as
+    /// [`DatabaseLike::table`].
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - Optional schema name of the table.
+    /// * `table_name` - Name of the table.
+    /// * `column_name` - Name of the column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE app.Users (Id INT, \"Name\" TEXT); CREATE TABLE posts (id INT);",
+    /// )?;
+    /// let id = db.column(Some("app"), "users", "id").unwrap();
+    /// assert_eq!(ColumnLike::table(id, &db).table_name(), "Users");
+    /// assert!(db.column(Some("app"), "users", "\"Name\"").is_some());
+    /// assert!(db.column(Some("app"), "users", "name").is_none());
+    /// assert!(db.column(None, "users", "id").is_none());
+    /// assert!(db.column(None, "posts", "id").is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn column(
+        &self,
+        schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> Option<&Self::Column> {
+        self.table(schema, table_name)?.column(column_name, self)
+    }
+
+    /// Iterates over the foreign keys referencing the given table, including
+    /// the self-referential ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The referenced table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, manager_id INT REFERENCES users(id));
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// CREATE TABLE tags (id INT PRIMARY KEY);
+    /// ",
+    /// )?;
+    /// let users = db.table(None, "users").unwrap();
+    /// let mut hosts: Vec<&str> = db
+    ///     .references_to(users)
+    ///     .map(|foreign_key| foreign_key.host_table(&db).table_name())
+    ///     .collect();
+    /// hosts.sort_unstable();
+    /// assert_eq!(hosts, ["posts", "users"]);
+    /// let tags = db.table(None, "tags").unwrap();
+    /// assert_eq!(db.references_to(tags).count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn references_to<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::ForeignKey> {
+        self.tables()
+            .flat_map(move |host_table| host_table.foreign_keys(self))
+            .filter(move |foreign_key| foreign_key.referenced_table(self) == Some(table))
+    }
+
+    /// Iterates over the triggers defined on the given table, in the order
+    /// of [`Self::triggers`].
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table the triggers are defined on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE orders (id INT);
+    /// CREATE TABLE items (id INT);
+    /// CREATE FUNCTION f() RETURNS TRIGGER AS 'BEGIN END;' LANGUAGE plpgsql;
+    /// CREATE TRIGGER orders_audit AFTER INSERT ON orders FOR EACH ROW EXECUTE PROCEDURE f();
+    /// CREATE TRIGGER items_audit AFTER INSERT ON items FOR EACH ROW EXECUTE PROCEDURE f();
+    /// ",
+    /// )?;
+    /// let orders = db.table(None, "orders").unwrap();
+    /// let triggers: Vec<&str> = db.triggers_on(orders).map(|t| t.name()).collect();
+    /// assert_eq!(triggers, ["orders_audit"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn triggers_on<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::Trigger> {
+        self.triggers().filter(move |trigger| trigger.table(self) == table)
+    }
+
+    /// Iterates over the policies defined on the given table, in the order
+    /// of [`Self::policies`].
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The table the policies are defined on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE orders (id INT);
+    /// CREATE TABLE items (id INT);
+    /// CREATE POLICY read_orders ON orders USING (true);
+    /// CREATE POLICY read_items ON items USING (true);
+    /// CREATE POLICY write_orders ON orders FOR INSERT WITH CHECK (true);
+    /// ",
+    /// )?;
+    /// let orders = db.table(None, "orders").unwrap();
+    /// let policies: Vec<&str> = db.policies_on(orders).map(|p| p.name()).collect();
+    /// assert_eq!(policies, ["read_orders", "write_orders"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn policies_on<'db>(
+        &'db self,
+        table: &'db Self::Table,
+    ) -> impl Iterator<Item = &'db Self::Policy> {
+        self.policies().filter(move |policy| policy.table(self) == table)
+    }
+
     /// Returns the function with the given name.
     ///
     /// # Arguments
@@ -516,6 +1047,36 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// ```
     fn function(&self, name: &str) -> Option<&Self::Function>;
 
+    /// Iterates over all the overloads of the function with the provided
+    /// name.
+    ///
+    /// Functions may be overloaded on their argument types, in which case
+    /// [`function`](DatabaseLike::function) only returns one of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE FUNCTION area(r FLOAT) RETURNS FLOAT AS 'SELECT 3.14 * r * r;';
+    /// CREATE FUNCTION area(w FLOAT, h FLOAT) RETURNS FLOAT AS 'SELECT w * h;';
+    /// ",
+    /// )?;
+    /// assert_eq!(DatabaseLike::function_overloads(&db, "area").count(), 2);
+    /// assert_eq!(DatabaseLike::function_overloads(&db, "non_existent").count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn function_overloads(&self, name: &str) -> impl Iterator<Item = &Self::Function> {
+        let casing = self.dialect().identifier_casing();
+        self.functions().filter(move |function| {
+            casing.matches_lookup(function.name(), function.name_is_quoted(), name)
+        })
+    }
+
     /// Iterates over the policies defined in the schema.
     ///
     /// # Example
@@ -610,7 +1171,8 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// # }
     /// ```
     fn role(&self, name: &str) -> Option<&Self::Role> {
-        self.roles().find(|r| r.name() == name)
+        let casing = self.dialect().identifier_casing();
+        self.roles().find(|r| casing.matches_lookup(r.name(), r.name_is_quoted(), name))
     }
 
     /// Returns whether the database has any roles defined.
@@ -634,6 +1196,78 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
         self.roles().next().is_some()
     }
 
+    /// Returns, for each role, a report of the objects it owns and of the
+    /// grant responsibilities it holds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE admin;
+    /// CREATE ROLE app_user;
+    /// CREATE TABLE t (id INT);
+    /// GRANT SELECT ON t TO app_user GRANTED BY admin;
+    /// ",
+    /// )?;
+    /// let reports = db.owners_report();
+    /// assert_eq!(reports.len(), 2);
+    /// assert!(reports.iter().all(RoleReport::has_dependencies));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn owners_report(&self) -> Vec<RoleReport<'_, Self>> {
+        self.roles().map(|role| RoleReport::new(self, role)).collect()
+    }
+
+    /// Returns the privileges the role effectively holds on the table.
+    ///
+    /// The privileges combine the table and column grants to the role, the
+    /// grants to `PUBLIC`, and the grants to the roles the role is a member
+    /// of, directly or transitively, as long as it inherits their
+    /// privileges. A privilege is held `WITH GRANT OPTION` when any of the
+    /// grants conferring it is.
+    ///
+    /// Superusers bypass privilege checks altogether, which is not reflected
+    /// in the returned set.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role whose privileges to resolve.
+    /// * `table` - The table the privileges are held on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{privileges::TablePrivilege, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE writers;
+    /// CREATE ROLE app IN ROLE writers;
+    /// CREATE TABLE events (id INT, payload TEXT);
+    /// GRANT INSERT ON events TO writers WITH GRANT OPTION;
+    /// GRANT SELECT (id) ON events TO app;
+    /// ",
+    /// )?;
+    /// let events = db.table(None, "events").unwrap();
+    /// let privileges = db.effective_privileges(db.role("app").unwrap(), events);
+    /// assert!(privileges.is_grantable(TablePrivilege::Insert));
+    /// assert!(privileges.has_column("id", false, TablePrivilege::Select));
+    /// assert!(!privileges.has_column("payload", false, TablePrivilege::Select));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn effective_privileges(&self, role: &Self::Role, table: &Self::Table) -> PrivilegeSet {
+        crate::privileges::effective_privileges(self, role, table)
+    }
+
     /// Iterates over tables that have Row Level Security (RLS) enabled.
     ///
     /// This includes tables with either regular RLS or forced RLS.
@@ -667,6 +1301,43 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
         self.tables().filter(|table| table.has_row_level_security(self))
     }
 
+    /// Iterates over tables that have Row Level Security (RLS) enabled but no
+    /// policy for at least one of the `SELECT`, `INSERT`, `UPDATE` and
+    /// `DELETE` commands, which are therefore denied to every role subject to
+    /// RLS.
+    ///
+    /// Use [`TableLike::policy_coverage`] to find out which commands and
+    /// roles are not covered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE covered (id INT);
+    /// ALTER TABLE covered ENABLE ROW LEVEL SECURITY;
+    /// CREATE POLICY covered_all ON covered USING (true);
+    /// CREATE TABLE read_only (id INT);
+    /// ALTER TABLE read_only ENABLE ROW LEVEL SECURITY;
+    /// CREATE POLICY read_only_select ON read_only FOR SELECT USING (true);
+    /// CREATE TABLE locked (id INT);
+    /// ALTER TABLE locked ENABLE ROW LEVEL SECURITY;
+    /// CREATE TABLE no_rls_table (id INT);
+    /// ",
+    /// )?;
+    ///
+    /// let gaps: Vec<&str> = db.rls_gaps().map(|t| t.table_name()).collect();
+    /// assert_eq!(gaps, ["locked", "read_only"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn rls_gaps(&self) -> impl Iterator<Item = &Self::Table> {
+        self.rls_tables().filter(|table| table.policy_coverage(self).has_gaps())
+    }
+
     /// Iterates over tables that have forced Row Level Security (RLS) enabled.
     ///
     /// Forced RLS means that even the table owner is subject to RLS policies.
@@ -756,7 +1427,9 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     ///
     /// Table grants apply privileges to entire tables. This includes direct
     /// table grants (`GRANT ... ON table_name`) and schema-wide table grants
-    /// (`GRANT ... ON ALL TABLES IN SCHEMA`).
+    /// (`GRANT ... ON ALL TABLES IN SCHEMA`). Privileges restricted to some
+    /// columns are listed among the [`column_grants`](Self::column_grants)
+    /// instead.
     ///
     /// # Example
     ///
@@ -810,7 +1483,9 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// Iterates over the column grants defined in the database.
     ///
     /// Column grants apply privileges to specific columns within tables.
-    /// This allows fine-grained access control at the column level.
+    /// This allows fine-grained access control at the column level. A
+    /// `GRANT` statement mixing column and table privileges is split between
+    /// the column and the [`table_grants`](Self::table_grants).
     ///
     /// # Example
     ///
@@ -823,11 +1498,13 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     ///     "
     /// CREATE TABLE users (id INT, name TEXT, secret TEXT);
     /// CREATE ROLE app_user;
-    /// GRANT SELECT (id, name) ON users TO app_user;
+    /// GRANT SELECT (id, name), DELETE ON users TO app_user;
     /// ",
     /// )?;
     /// let column_grants: Vec<_> = db.column_grants().collect();
     /// assert_eq!(column_grants.len(), 1);
+    /// assert_eq!(column_grants[0].to_string(), "GRANT SELECT (id, name) ON users TO app_user");
+    /// assert_eq!(db.table_grants().count(), 1);
     /// # Ok(())
     /// # }
     /// ```
@@ -915,8 +1592,8 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     /// For identifier-aware lookup from parsed SQL AST, use
     /// [`ParserDB::resolve_schema_ident`](crate::structs::ParserDB::resolve_schema_ident).
     fn schema(&self, name: &str) -> Option<&Self::Schema> {
-        self.schemas()
-            .find(|s| stored_identifier_matches_lookup(s.name(), s.name_is_quoted(), name))
+        let casing = self.dialect().identifier_casing();
+        self.schemas().find(|s| casing.matches_lookup(s.name(), s.name_is_quoted(), name))
     }
 
     /// Returns whether the database has any schemas defined.
@@ -924,4 +1601,223 @@ pub trait DatabaseLike: Clone + Debug + Send + Sync {
     fn has_schemas(&self) -> bool {
         self.schemas().next().is_some()
     }
+
+    /// Iterates over the views and materialized views defined in the
+    /// database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, name TEXT);
+    /// CREATE VIEW user_names AS SELECT name FROM users;
+    /// CREATE VIEW user_ids AS SELECT id FROM users;
+    /// ",
+    /// )?;
+    /// let views: Vec<&str> = db.views().map(ViewLike::view_name).collect();
+    /// assert_eq!(views, ["user_ids", "user_names"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn views(&self) -> impl Iterator<Item = &Self::View>;
+
+    /// Returns the view with the given schema and name, if it exists.
+    ///
+    /// Names are matched following the same identifier rules as
+    /// [`DatabaseLike::table`].
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - Optional schema name of the view.
+    /// * `view_name` - Name of the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT); CREATE VIEW User_Ids AS SELECT id FROM users;",
+    /// )?;
+    /// assert!(db.view(None, "user_ids").is_some());
+    /// assert!(db.view(Some("public"), "user_ids").is_none());
+    /// assert!(db.view(None, "users").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn view(&self, schema: Option<&str>, view_name: &str) -> Option<&Self::View> {
+        let casing = self.dialect().identifier_casing();
+        self.views().find(|view| {
+            casing.matches_lookup(view.view_name(), view.view_name_is_quoted(), view_name)
+                && match (schema, view.view_schema()) {
+                    (None, None) => true,
+                    (Some(lookup_schema), Some(view_schema)) => casing.matches_lookup(
+                        view_schema,
+                        view.view_schema_is_quoted(),
+                        lookup_schema,
+                    ),
+                    _ => false,
+                }
+        })
+    }
+
+    /// Iterates over the sequences defined in the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE SEQUENCE users_id_seq; CREATE SEQUENCE orders_id_seq;",
+    /// )?;
+    /// let sequences: Vec<&str> = db.sequences().map(SequenceLike::name).collect();
+    /// assert_eq!(sequences, ["orders_id_seq", "users_id_seq"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sequences(&self) -> impl Iterator<Item = &Self::Sequence>;
+
+    /// Returns the sequence with the given schema and name, if it exists.
+    ///
+    /// Names are matched following the same identifier rules as
+    /// [`DatabaseLike::table`].
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - Optional schema name of the sequence.
+    /// * `sequence_name` - Name of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE SEQUENCE Users_Id_Seq;")?;
+    /// assert!(db.sequence(None, "users_id_seq").is_some());
+    /// assert!(db.sequence(Some("public"), "users_id_seq").is_none());
+    /// assert!(db.sequence(None, "\"Users_Id_Seq\"").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sequence(&self, schema: Option<&str>, sequence_name: &str) -> Option<&Self::Sequence> {
+        let casing = self.dialect().identifier_casing();
+        self.sequences().find(|sequence| {
+            casing.matches_lookup(sequence.name(), sequence.name_is_quoted(), sequence_name)
+                && match (schema, sequence.schema()) {
+                    (None, None) => true,
+                    (Some(lookup_schema), Some(sequence_schema)) => casing.matches_lookup(
+                        sequence_schema,
+                        sequence.schema_is_quoted(),
+                        lookup_schema,
+                    ),
+                    _ => false,
+                }
+        })
+    }
+
+    /// Iterates over the user-defined types, such as enumerations and
+    /// domains, defined in the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TYPE mood AS ENUM ('sad', 'happy'); CREATE DOMAIN email AS TEXT;",
+    /// )?;
+    /// let types: Vec<&str> = db.custom_types().map(CustomTypeLike::name).collect();
+    /// assert_eq!(types, ["email", "mood"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_types(&self) -> impl Iterator<Item = &Self::CustomType>;
+
+    /// Returns the user-defined type with the given schema and name, if it
+    /// exists.
+    ///
+    /// Names are matched following the same identifier rules as
+    /// [`DatabaseLike::table`].
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - Optional schema name of the type.
+    /// * `type_name` - Name of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TYPE Mood AS ENUM ('sad');")?;
+    /// assert!(db.custom_type(None, "mood").is_some());
+    /// assert!(db.custom_type(Some("public"), "mood").is_none());
+    /// assert!(db.custom_type(None, "\"Mood\"").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_type(&self, schema: Option<&str>, type_name: &str) -> Option<&Self::CustomType> {
+        let casing = self.dialect().identifier_casing();
+        self.custom_types().find(|custom_type| {
+            casing.matches_lookup(custom_type.name(), custom_type.name_is_quoted(), type_name)
+                && match (schema, custom_type.schema()) {
+                    (None, None) => true,
+                    (Some(lookup_schema), Some(type_schema)) => casing.matches_lookup(
+                        type_schema,
+                        custom_type.schema_is_quoted(),
+                        lookup_schema,
+                    ),
+                    _ => false,
+                }
+        })
+    }
+
+    /// Iterates over the default privileges declared with `ALTER DEFAULT
+    /// PRIVILEGES`, in declaration order.
+    ///
+    /// Revoked default privileges are removed, so that only the ones granted
+    /// on the tables created from now on are listed. The default privileges
+    /// granted on an existing table are listed by
+    /// [`TableLike::default_privileges`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE ROLE writer;
+    /// ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+    /// ALTER DEFAULT PRIVILEGES GRANT INSERT ON TABLES TO writer;
+    /// ALTER DEFAULT PRIVILEGES REVOKE INSERT ON TABLES FROM writer;
+    /// ",
+    /// )?;
+    /// let grantees: Vec<&str> = db
+    ///     .default_privileges()
+    ///     .flat_map(|default_privilege| default_privilege.grantees(&db))
+    ///     .map(RoleLike::name)
+    ///     .collect();
+    /// assert_eq!(grantees, ["reader"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn default_privileges(&self) -> impl Iterator<Item = &Self::DefaultPrivilege>;
 }
@@ -0,0 +1,248 @@
+//! Submodule providing a trait for describing SQL default privilege-like
+//! entities.
+
+use core::fmt::Debug;
+
+use crate::{
+    privileges::TablePrivilege,
+    traits::{DatabaseLike, Metadata},
+};
+
+/// A trait for types that can be treated as SQL default privileges.
+///
+/// Default privileges, as declared by `ALTER DEFAULT PRIVILEGES`, are not
+/// granted on existing objects: they are granted on each table created
+/// afterwards, which lists them in [`TableLike::default_privileges`].
+///
+/// [`TableLike::default_privileges`]: crate::traits::TableLike::default_privileges
+pub trait DefaultPrivilegeLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
+    /// The database type the default privileges belong to.
+    type DB: DatabaseLike;
+
+    /// Iterates over the roles whose future tables the privileges are granted
+    /// on, as listed by the `FOR ROLE` clause.
+    ///
+    /// Without a `FOR ROLE` clause, the privileges are granted on the tables
+    /// created by the role running the statement, and the iterator is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   default privileges belong.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE owner;
+    /// CREATE ROLE reader;
+    /// ALTER DEFAULT PRIVILEGES FOR ROLE owner GRANT SELECT ON TABLES TO reader;
+    /// ",
+    /// )?;
+    /// let default_privilege = db.default_privileges().next().unwrap();
+    /// let targets: Vec<&str> = default_privilege.target_roles(&db).map(RoleLike::name).collect();
+    /// assert_eq!(targets, ["owner"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn target_roles<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db;
+
+    /// Iterates over the names of the schemas whose future tables the
+    /// privileges are granted on, as listed by the `IN SCHEMA` clause.
+    ///
+    /// Without an `IN SCHEMA` clause, the privileges are granted on the
+    /// tables created in any schema, and the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+    /// ",
+    /// )?;
+    /// let default_privilege = db.default_privileges().next().unwrap();
+    /// assert_eq!(default_privilege.schemas().collect::<Vec<_>>(), ["public"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn schemas(&self) -> impl Iterator<Item = &str>;
+
+    /// Iterates over the privileges granted on the future tables.
+    ///
+    /// `ALL PRIVILEGES` is expanded into [`TablePrivilege::ALL`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::{privileges::TablePrivilege, prelude::*};
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE writer;
+    /// ALTER DEFAULT PRIVILEGES GRANT INSERT, UPDATE ON TABLES TO writer;
+    /// ",
+    /// )?;
+    /// let default_privilege = db.default_privileges().next().unwrap();
+    /// assert_eq!(
+    ///     default_privilege.privileges().collect::<Vec<_>>(),
+    ///     [TablePrivilege::Insert, TablePrivilege::Update]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn privileges(&self) -> impl Iterator<Item = TablePrivilege>;
+
+    /// Iterates over the roles receiving the privileges, leaving out
+    /// `PUBLIC`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   default privileges belong.
+    fn grantees<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db;
+
+    /// Returns whether the privileges are granted `WITH GRANT OPTION`.
+    fn is_grantable(&self) -> bool;
+
+    /// Returns whether the privileges are granted to the provided role,
+    /// either by name or through `PUBLIC`.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - The role to check against.
+    /// * `database` - The database context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE ROLE other;
+    /// ALTER DEFAULT PRIVILEGES GRANT SELECT ON TABLES TO reader;
+    /// ",
+    /// )?;
+    /// let default_privilege = db.default_privileges().next().unwrap();
+    /// assert!(default_privilege.applies_to_role(db.role("reader").unwrap(), &db));
+    /// assert!(!default_privilege.applies_to_role(db.role("other").unwrap(), &db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn applies_to_role(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB)
+    -> bool;
+}
+
+impl<P: DefaultPrivilegeLike> DefaultPrivilegeLike for &P {
+    type DB = P::DB;
+
+    fn target_roles<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db,
+    {
+        (*self).target_roles(database)
+    }
+
+    fn schemas(&self) -> impl Iterator<Item = &str> {
+        (*self).schemas()
+    }
+
+    fn privileges(&self) -> impl Iterator<Item = TablePrivilege> {
+        (*self).privileges()
+    }
+
+    fn grantees<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>
+    where
+        Self: 'db,
+    {
+        (*self).grantees(database)
+    }
+
+    fn is_grantable(&self) -> bool {
+        (*self).is_grantable()
+    }
+
+    fn applies_to_role(
+        &self,
+        role: &<Self::DB as DatabaseLike>::Role,
+        database: &Self::DB,
+    ) -> bool {
+        (*self).applies_to_role(role, database)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{structs::ParserDB, traits::RoleLike};
+
+    /// Exercises the `impl DefaultPrivilegeLike for &P` blanket forwarding.
+    #[test]
+    fn test_reference_impl() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE ROLE owner;
+            CREATE ROLE reader;
+            ALTER DEFAULT PRIVILEGES FOR ROLE owner IN SCHEMA app
+                GRANT SELECT ON TABLES TO reader, PUBLIC WITH GRANT OPTION;
+            ",
+        )
+        .unwrap();
+        let default_privilege = db.default_privileges().next().expect("default privilege");
+
+        let p_ref: &<ParserDB as DatabaseLike>::DefaultPrivilege = default_privilege;
+        let targets: Vec<&str> =
+            <&_ as DefaultPrivilegeLike>::target_roles(&p_ref, &db).map(RoleLike::name).collect();
+        assert_eq!(targets, ["owner"]);
+        assert_eq!(<&_ as DefaultPrivilegeLike>::schemas(&p_ref).collect::<Vec<_>>(), ["app"]);
+        assert_eq!(
+            <&_ as DefaultPrivilegeLike>::privileges(&p_ref).collect::<Vec<_>>(),
+            [TablePrivilege::Select]
+        );
+        let grantees: Vec<&str> =
+            <&_ as DefaultPrivilegeLike>::grantees(&p_ref, &db).map(RoleLike::name).collect();
+        assert_eq!(grantees, ["reader"]);
+        assert!(<&_ as DefaultPrivilegeLike>::is_grantable(&p_ref));
+        assert!(<&_ as DefaultPrivilegeLike>::applies_to_role(
+            &p_ref,
+            db.role("owner").unwrap(),
+            &db
+        ));
+    }
+}
@@ -17,7 +17,10 @@
 
 use core::{fmt::Debug, hash::Hash};
 
-use crate::traits::{DatabaseLike, TypeMatchLike};
+use crate::{
+    structs::{IdentifierCasing, PostgresTypeNormalizer},
+    traits::{ColumnLike, CustomTypeLike, DatabaseLike, TypeMatchLike, TypeNormalizer},
+};
 
 /// Dialect-scoped type classification for a database backend.
 ///
@@ -119,4 +122,65 @@ pub trait DialectLike: Debug + Clone + Default + Send + Sync + Hash + Eq + Ord +
         database: &Self::DB,
         column: &<Self::DB as DatabaseLike>::Column,
     ) -> Self::Match;
+
+    /// Returns the rule comparing identifiers under this dialect, used by
+    /// the lookups of objects by name and by the checks rejecting duplicate
+    /// objects.
+    ///
+    /// Dialects which do not provide one fold unquoted identifiers to
+    /// lowercase and match quoted ones exactly, as PostgreSQL does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::MySqlDialect;
+    ///
+    /// let db = ParserDB::parse::<MySqlDialect>("CREATE TABLE `Users` (id INT);")?;
+    /// assert_eq!(db.dialect().identifier_casing(), IdentifierCasing::Insensitive);
+    /// assert!(db.table(None, "users").is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn identifier_casing(&self) -> IdentifierCasing {
+        IdentifierCasing::Lowercase
+    }
+
+    /// Returns the normalizer of the data types declared under this dialect.
+    ///
+    /// Dialects which do not provide one use the
+    /// [`PostgresTypeNormalizer`], whose spellings are shared by most
+    /// dialects.
+    #[inline]
+    fn type_normalizer(&self) -> &dyn TypeNormalizer {
+        &PostgresTypeNormalizer
+    }
+
+    /// Returns the canonical data type of a column under this dialect, if
+    /// it is known to the [`type_normalizer`](Self::type_normalizer).
+    ///
+    /// The columns declared with a domain are normalized through the base
+    /// type of the domain, and the ones declared with any other user-defined
+    /// type, such as an enumeration, have no canonical data type. Backends
+    /// which keep the arguments of the declared data types, needed to tell
+    /// MySQL's `TINYINT(1)` from a `TINYINT`, should override this method.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the column belongs to.
+    /// * `column` - The column to normalize the data type of.
+    fn canonical_type(
+        &self,
+        database: &Self::DB,
+        column: &<Self::DB as DatabaseLike>::Column,
+    ) -> Option<&'static str> {
+        match column.custom_type(database) {
+            Some(custom_type) => custom_type
+                .domain_base_type()
+                .and_then(|base_type| self.type_normalizer().canonical_type(base_type)),
+            None => self.type_normalizer().canonical_type(column.data_type(database)),
+        }
+    }
 }
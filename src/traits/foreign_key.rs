@@ -3,9 +3,12 @@
 use alloc::vec::Vec;
 use core::{borrow::Borrow, fmt::Debug};
 
-use sqlparser::ast::ConstraintReferenceMatchKind;
+use sqlparser::ast::{ConstraintReferenceMatchKind, Expr};
 
-use crate::traits::{ColumnLike, DatabaseLike, IndexLike, Metadata, TableLike};
+use crate::{
+    structs::{ExtensionCandidate, ExtensionStrategy},
+    traits::{ColumnLike, DatabaseLike, DialectLike, IndexLike, Metadata, TableLike},
+};
 
 /// A foreign key constraint is a rule that specifies a relationship between
 /// two tables. This trait represents such a foreign key constraint in a
@@ -117,7 +120,9 @@ pub trait ForeignKeyLike:
     where
         Self: 'db;
 
-    /// Returns the referenced table that the foreign key points to.
+    /// Returns the referenced table that the foreign key points to, or `None`
+    /// if it is not in the database, as can be the case of the foreign keys
+    /// loaded from a catalog or assembled from a builder.
     ///
     /// # Arguments
     ///
@@ -141,7 +146,7 @@ pub trait ForeignKeyLike:
     /// )?;
     /// let host_table = db.table(None, "host_table").unwrap();
     /// let foreign_key = host_table.foreign_keys(&db).next().expect("Should have a foreign key");
-    /// let referenced_table = foreign_key.referenced_table(&db);
+    /// let referenced_table = foreign_key.referenced_table(&db).unwrap();
     /// assert_eq!(referenced_table.table_name(), "referenced_table");
     /// # Ok(())
     /// # }
@@ -149,11 +154,14 @@ pub trait ForeignKeyLike:
     fn referenced_table<'db>(
         &self,
         database: &'db Self::DB,
-    ) -> &'db <Self::DB as DatabaseLike>::Table;
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Table>;
 
     /// Returns an iterator over the columns in the host table that are part of
     /// the foreign key.
     ///
+    /// No column is returned if any of them is not in the host table, so
+    /// that the host and referenced columns never pair up wrongly.
+    ///
     /// # Arguments
     ///
     /// * `database` - A reference to the database instance to which the foreign
@@ -383,7 +391,7 @@ pub trait ForeignKeyLike:
     /// ```rust
     /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use sql_traits::prelude::*;
-    /// use sqlparser::ast::ConstraintReferenceMatchKind;
+    /// use sqlparser::ast::{ConstraintReferenceMatchKind, Expr};
     ///
     /// let db = ParserDB::parse::<GenericDialect>(
     ///     "
@@ -551,6 +559,9 @@ pub trait ForeignKeyLike:
     /// Returns an iterator over the columns in the referenced table that are
     /// part of the foreign key.
     ///
+    /// No column is returned if the referenced table or any of the columns is
+    /// not in the database.
+    ///
     /// # Arguments
     ///
     /// * `database` - A reference to the database instance to which the foreign
@@ -642,6 +653,52 @@ pub trait ForeignKeyLike:
         if referenced_columns.next().is_none() { Some(first_column) } else { None }
     }
 
+    /// Returns an iterator over the pairs of host and referenced columns of
+    /// the foreign key, in the order of their declaration.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the foreign
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE referenced_table (id1 INT, id2 INT, PRIMARY KEY (id1, id2));
+    /// CREATE TABLE host_table (
+    ///     ref_id2 INT,
+    ///     ref_id1 INT,
+    ///     FOREIGN KEY (ref_id1, ref_id2) REFERENCES referenced_table(id1, id2)
+    /// );
+    /// ",
+    /// )?;
+    /// let host_table = db.table(None, "host_table").unwrap();
+    /// let foreign_key = host_table.foreign_keys(&db).next().expect("Should have a foreign key");
+    /// let pairs: Vec<(&str, &str)> = foreign_key
+    ///     .column_pairs(&db)
+    ///     .map(|(host, referenced)| (host.column_name(), referenced.column_name()))
+    ///     .collect();
+    /// assert_eq!(pairs, [("ref_id1", "id1"), ("ref_id2", "id2")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn column_pairs<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<
+        Item = (&'db <Self::DB as DatabaseLike>::Column, &'db <Self::DB as DatabaseLike>::Column),
+    >
+    where
+        Self: 'db,
+    {
+        self.host_columns(database).zip(self.referenced_columns(database))
+    }
+
     /// Returns whether the foreign key is self-referential, i.e., the host
     /// table is the same as the referenced table.
     ///
@@ -682,7 +739,7 @@ pub trait ForeignKeyLike:
     /// # }
     /// ```
     fn is_self_referential(&self, database: &Self::DB) -> bool {
-        self.host_table(database) == self.referenced_table(database)
+        self.referenced_table(database) == Some(self.host_table(database))
     }
 
     /// Returns whether the foreign key references any of the ancestor tables
@@ -720,11 +777,13 @@ pub trait ForeignKeyLike:
     /// )?;
     /// let child_table = db.table(None, "child").unwrap();
     /// let foreign_keys: Vec<_> = child_table.foreign_keys(&db).collect();
-    /// let parent_fk =
-    ///     foreign_keys.iter().find(|fk| fk.referenced_table(&db).table_name() == "parent").unwrap();
+    /// let parent_fk = foreign_keys
+    ///     .iter()
+    ///     .find(|fk| fk.referenced_table(&db).unwrap().table_name() == "parent")
+    ///     .unwrap();
     /// let grandparent_fk = foreign_keys
     ///     .iter()
-    ///     .find(|fk| fk.referenced_table(&db).table_name() == "grandparent")
+    ///     .find(|fk| fk.referenced_table(&db).unwrap().table_name() == "grandparent")
     ///     .unwrap();
     ///
     /// assert!(parent_fk.references_ancestor_table(&db));
@@ -733,7 +792,7 @@ pub trait ForeignKeyLike:
     /// let child_other_table = db.table(None, "child_other").unwrap();
     /// let other_fk = child_other_table
     ///     .foreign_keys(&db)
-    ///     .find(|fk| fk.referenced_table(&db).table_name() == "other")
+    ///     .find(|fk| fk.referenced_table(&db).unwrap().table_name() == "other")
     ///     .unwrap();
     /// assert!(!other_fk.references_ancestor_table(&db));
     /// # Ok(())
@@ -741,8 +800,9 @@ pub trait ForeignKeyLike:
     /// ```
     fn references_ancestor_table(&self, database: &Self::DB) -> bool {
         let host_table = self.host_table(database);
-        let referenced_table = self.referenced_table(database);
-        host_table.ancestral_extended_tables(database).contains(&referenced_table)
+        self.referenced_table(database).is_some_and(|referenced_table| {
+            host_table.ancestral_extended_tables(database).contains(&referenced_table)
+        })
     }
 
     /// Returns whether the foreign key references the primary key of the
@@ -783,7 +843,9 @@ pub trait ForeignKeyLike:
     /// # }
     /// ```
     fn is_referenced_primary_key(&self, database: &Self::DB) -> bool {
-        let referenced_table = self.referenced_table(database);
+        let Some(referenced_table) = self.referenced_table(database) else {
+            return false;
+        };
         let mut pk_columns = referenced_table.primary_key_columns(database).peekable();
         let mut fk_columns = self.referenced_columns(database).peekable();
 
@@ -854,7 +916,7 @@ pub trait ForeignKeyLike:
     where
         Self: 'db,
     {
-        let referenced_table = self.referenced_table(database);
+        let referenced_table = self.referenced_table(database)?;
         let referenced_columns: Vec<_> = self.referenced_columns(database).collect();
         referenced_table.unique_indices(database).find(
             |index: &&<Self::DB as DatabaseLike>::UniqueIndex| {
@@ -865,6 +927,127 @@ pub trait ForeignKeyLike:
         )
     }
 
+    /// Returns the index of the host table covering the foreign key, i.e. an
+    /// index whose leading columns are exactly the host columns of the
+    /// foreign key, in any order, so that the rows referencing a given row
+    /// can be looked up without scanning the host table.
+    ///
+    /// Partial indices and indices whose leading entries are expressions
+    /// rather than plain columns do not cover a foreign key.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the foreign
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id), title TEXT);
+    /// CREATE TABLE comments (id INT PRIMARY KEY, author_id INT REFERENCES users (id), body TEXT);
+    /// CREATE INDEX posts_author ON posts (author_id, title);
+    /// CREATE INDEX comments_body ON comments (body, author_id);
+    /// ",
+    /// )?;
+    /// let posts_fk = db.table(None, "posts").unwrap().foreign_keys(&db).next().unwrap();
+    /// let comments_fk = db.table(None, "comments").unwrap().foreign_keys(&db).next().unwrap();
+    /// let index = posts_fk.covering_index(&db).unwrap();
+    /// assert_eq!(index.index_name(), Some("posts_author"));
+    /// assert!(comments_fk.covering_index(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn covering_index<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Index>
+    where
+        Self: 'db,
+    {
+        let host_columns: Vec<_> = self.host_columns(database).collect();
+        self.host_table(database)
+            .indices(database)
+            .find(|index| index_covers_columns(*index, database, &host_columns))
+    }
+
+    /// Returns the unique index of the host table covering the foreign key,
+    /// as defined for [`ForeignKeyLike::covering_index`]. Primary keys and
+    /// unique constraints are backed by such indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the foreign
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE profiles (id INT PRIMARY KEY REFERENCES users (id));
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// let profiles_fk = db.table(None, "profiles").unwrap().foreign_keys(&db).next().unwrap();
+    /// let posts_fk = db.table(None, "posts").unwrap().foreign_keys(&db).next().unwrap();
+    /// assert!(profiles_fk.covering_unique_index(&db).unwrap().is_primary_key(&db));
+    /// assert!(posts_fk.covering_unique_index(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn covering_unique_index<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::UniqueIndex>
+    where
+        Self: 'db,
+    {
+        let host_columns: Vec<_> = self.host_columns(database).collect();
+        self.host_table(database)
+            .unique_indices(database)
+            .find(|index| index_covers_columns(*index, database, &host_columns))
+    }
+
+    /// Returns whether the foreign key is covered by an index or by a unique
+    /// index of its host table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the foreign
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+    /// ",
+    /// )?;
+    /// let posts_fk = db.table(None, "posts").unwrap().foreign_keys(&db).next().unwrap();
+    /// assert!(!posts_fk.is_indexed(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_indexed(&self, database: &Self::DB) -> bool {
+        self.covering_index(database).is_some() || self.covering_unique_index(database).is_some()
+    }
+
     /// Returns whether the foreign key locally matches the primary key of the
     /// host table.
     ///
@@ -1004,15 +1187,21 @@ pub trait ForeignKeyLike:
     /// # }
     /// ```
     fn includes_referenced_primary_key(&self, database: &Self::DB) -> bool {
-        let referenced_table = self.referenced_table(database);
+        let Some(referenced_table) = self.referenced_table(database) else {
+            return false;
+        };
         let pk_columns: Vec<_> = referenced_table.primary_key_columns(database).collect();
         let fk_columns: Vec<_> = self.referenced_columns(database).collect();
         pk_columns.iter().all(|pk| fk_columns.contains(pk))
     }
 
     /// Returns whether the foreign key is an "extension" foreign key, i.e., it
-    /// references the primary key of another table, and the host table is not
-    /// self-referential.
+    /// makes its host table an extension of the referenced table according to
+    /// the [`ExtensionStrategy`] of the database.
+    ///
+    /// Under the default [`ExtensionStrategy::PkFkAnyName`], such a foreign key
+    /// goes from the primary key of its table to the primary key of another
+    /// table.
     ///
     /// # Arguments
     ///
@@ -1060,9 +1249,35 @@ pub trait ForeignKeyLike:
     /// # }
     /// ```
     fn is_extension_foreign_key(&self, database: &Self::DB) -> bool {
-        self.is_host_primary_key(database)
-            && self.is_referenced_primary_key(database)
-            && !self.is_self_referential(database)
+        let links_primary_keys = || {
+            self.is_host_primary_key(database)
+                && self.is_referenced_primary_key(database)
+                && !self.is_self_referential(database)
+        };
+        match database.extension_strategy() {
+            ExtensionStrategy::PkFkAnyName => links_primary_keys(),
+            ExtensionStrategy::PkFkSameName => {
+                links_primary_keys()
+                    && self
+                        .column_pairs(database)
+                        .all(|(host, referenced)| host.column_name() == referenced.column_name())
+            }
+            ExtensionStrategy::ExplicitComment => {
+                let Some(referenced_table) = self.referenced_table(database) else {
+                    return false;
+                };
+                !self.is_self_referential(database)
+                    && self.host_table(database).table_doc(database).is_some_and(|doc| {
+                        ExtensionStrategy::documents_extension(
+                            doc,
+                            referenced_table,
+                            database.dialect().identifier_casing(),
+                        )
+                    })
+            }
+            ExtensionStrategy::Custom(predicate) => ExtensionCandidate::new(self, database)
+                .is_some_and(|candidate| predicate(&candidate)),
+        }
     }
 
     /// Returns whether the key is a singleton foreign key, i.e. it is the only
@@ -1114,11 +1329,13 @@ pub trait ForeignKeyLike:
         if self.is_composite(database) {
             return false;
         }
-        let foreign_table = self.referenced_table(database);
+        let Some(foreign_table) = self.referenced_table(database) else {
+            return false;
+        };
         self.host_table(database)
             .foreign_keys(database)
             .map(Borrow::borrow)
-            .all(|fk: &Self| fk == self || fk.referenced_table(database) != foreign_table)
+            .all(|fk: &Self| fk == self || fk.referenced_table(database) != Some(foreign_table))
     }
 
     /// Returns the referenced column curresponding to the given host column in
@@ -1176,3 +1393,27 @@ pub trait ForeignKeyLike:
             .expect("Host column is not part of the foreign key")
     }
 }
+
+/// Returns whether the leading entries of the provided index are plain
+/// references to exactly the provided columns, in any order, and the index
+/// is not partial.
+fn index_covers_columns<I: IndexLike>(
+    index: &I,
+    database: &I::DB,
+    columns: &[&<I::DB as DatabaseLike>::Column],
+) -> bool {
+    let leading_entries = index.index_columns().get(..columns.len());
+    if columns.is_empty()
+        || index.predicate().is_some()
+        || !leading_entries.is_some_and(|entries| {
+            entries.iter().all(|entry| {
+                matches!(entry.column.expr, Expr::Identifier(_) | Expr::CompoundIdentifier(_))
+            })
+        })
+    {
+        return false;
+    }
+    let leading_columns: Vec<_> = index.columns(database).take(columns.len()).collect();
+    leading_columns.len() == columns.len()
+        && leading_columns.iter().all(|column| columns.contains(column))
+}
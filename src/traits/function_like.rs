@@ -1,11 +1,15 @@
 //! Submodule providing a trait for describing SQL Function-like entities.
 
 use alloc::vec::Vec;
-use core::{fmt::Debug, hash::Hash};
+use core::{fmt::Debug, hash::Hash, ops::ControlFlow};
+
+use sqlparser::ast::{FunctionBehavior, FunctionSecurity, Statement, Visit};
 
 use crate::{
-    traits::{DatabaseLike, Metadata},
-    utils::normalize_postgres_type,
+    errors::Error,
+    structs::SourceLocation,
+    traits::{ColumnLike, DataStatementLike, DatabaseLike, DialectLike, Metadata, TableLike},
+    utils::{normalize_postgres_type, query_identifiers::QueryIdentifiers},
 };
 
 /// A trait for describing SQL Function-like entities.
@@ -42,6 +46,37 @@ pub trait FunctionLike: Metadata + Debug + Clone + Hash + Ord + Eq + Send + Sync
         false
     }
 
+    /// Returns the name of the schema of the function, if specified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE FUNCTION app.add_one(x INT) RETURNS INT AS 'SELECT x + 1;';
+    /// CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;';
+    /// ",
+    /// )?;
+    /// assert_eq!(db.function("add_one").unwrap().schema(), Some("app"));
+    /// assert_eq!(db.function("one").unwrap().schema(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn schema(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns whether the schema identifier of the function was quoted in
+    /// SQL.
+    #[inline]
+    fn schema_is_quoted(&self) -> bool {
+        false
+    }
+
     /// Returns the argument type names (if any) of the function as strings.
     ///
     /// # Example
@@ -140,6 +175,367 @@ pub trait FunctionLike: Metadata + Debug + Clone + Hash + Ord + Eq + Send + Sync
     /// ```
     fn body(&self) -> Option<&str>;
 
+    /// Parses the body of the function into the SQL statements it runs, so
+    /// that the tables it reads or writes can be analyzed.
+    ///
+    /// Bodies written in PL/pgSQL are stripped of the control structures
+    /// wrapping their SQL statements, while the statements with no SQL
+    /// counterpart, such as assignments or `RAISE`, are skipped. A function
+    /// without a body yields no statements.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   function belongs, whose dialect the statements are parsed with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the statements of the body cannot be
+    /// parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::Statement, dialect::PostgreSqlDialect};
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE audit (note TEXT);
+    /// CREATE FUNCTION log_note(note TEXT) RETURNS VOID AS $$
+    /// BEGIN
+    ///     IF note IS NOT NULL THEN
+    ///         INSERT INTO audit (note) VALUES (note);
+    ///     END IF;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// ",
+    /// )?;
+    /// let statements = db.function("log_note").unwrap().parsed_body(&db)?;
+    /// assert_eq!(statements.len(), 1);
+    /// assert!(matches!(statements[0], Statement::Insert(_)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn parsed_body(&self, database: &Self::DB) -> Result<Vec<Statement>, Error>;
+
+    /// Returns the tables which the body of the function reads or writes,
+    /// in first-seen order and without duplicates.
+    ///
+    /// Relations which do not resolve to a table of the database, such as
+    /// common table expressions or temporary tables, are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   function belongs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body cannot be parsed, or if one of its
+    /// relations is ambiguous or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, name TEXT);
+    /// CREATE TABLE audit (note TEXT);
+    /// CREATE FUNCTION archive_user(user_id INT) RETURNS VOID AS $$
+    /// BEGIN
+    ///     INSERT INTO audit (note) SELECT name FROM users WHERE id = user_id;
+    ///     DELETE FROM users WHERE id = user_id;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// ",
+    /// )?;
+    /// let function = db.function("archive_user").unwrap();
+    /// let tables: Vec<&str> =
+    ///     function.referenced_tables(&db)?.into_iter().map(TableLike::table_name).collect();
+    /// assert_eq!(tables, ["audit", "users"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn referenced_tables<'db>(
+        &self,
+        database: &'db Self::DB,
+    ) -> Result<Vec<&'db <Self::DB as DatabaseLike>::Table>, Error> {
+        Ok(self.parsed_body(database)?.as_slice().referenced_tables(database)?)
+    }
+
+    /// Returns the columns of the referenced tables which the body of the
+    /// function uses.
+    ///
+    /// As for [`ViewLike::referenced_columns`], a column is considered used
+    /// when the body mentions its name, including as an argument or variable
+    /// of the same name, or when a statement projects a wildcard or inserts
+    /// without a column list, in which case every column of the referenced
+    /// tables is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   function belongs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body cannot be parsed, or if one of its
+    /// relations is ambiguous or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, name TEXT, secret TEXT);
+    /// CREATE FUNCTION rename_user(user_id INT, new_name TEXT) RETURNS VOID AS
+    ///     'UPDATE users SET name = new_name WHERE id = user_id;' LANGUAGE SQL;
+    /// ",
+    /// )?;
+    /// let function = db.function("rename_user").unwrap();
+    /// let columns: Vec<&str> =
+    ///     function.referenced_columns(&db)?.into_iter().map(ColumnLike::column_name).collect();
+    /// assert_eq!(columns, ["id", "name"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ViewLike::referenced_columns`]: crate::traits::ViewLike::referenced_columns
+    fn referenced_columns<'db>(
+        &self,
+        database: &'db Self::DB,
+    ) -> Result<Vec<&'db <Self::DB as DatabaseLike>::Column>, Error> {
+        let statements = self.parsed_body(database)?;
+        let tables = statements.as_slice().referenced_tables(database)?;
+        let mut collector = QueryIdentifiers::default();
+        let ControlFlow::Continue(()) = statements.visit(&mut collector);
+        let casing = database.dialect().identifier_casing();
+
+        Ok(tables
+            .into_iter()
+            .flat_map(|table| table.columns(database))
+            .filter(|column| {
+                collector.wildcard
+                    || collector.identifiers.iter().any(|(name, quoted)| {
+                        casing.matches(
+                            column.column_name(),
+                            column.column_name_is_quoted(),
+                            name,
+                            *quoted,
+                        )
+                    })
+            })
+            .collect())
+    }
+
+    /// Returns whether the function runs with the privileges of the role
+    /// calling it, which is the default, or of the role owning it, when
+    /// declared `SECURITY DEFINER`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::FunctionSecurity, dialect::PostgreSqlDialect};
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;
+    /// CREATE FUNCTION two() RETURNS INT AS 'SELECT 2;' LANGUAGE SQL SECURITY DEFINER;
+    /// ",
+    /// )?;
+    /// assert_eq!(db.function("one").unwrap().security(), FunctionSecurity::Invoker);
+    /// assert_eq!(db.function("two").unwrap().security(), FunctionSecurity::Definer);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn security(&self) -> FunctionSecurity;
+
+    /// Returns the volatility of the function, which is `VOLATILE` unless
+    /// declared `STABLE` or `IMMUTABLE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::FunctionBehavior, dialect::PostgreSqlDialect};
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL IMMUTABLE;
+    /// CREATE FUNCTION now_utc() RETURNS TIMESTAMP AS 'SELECT now();' LANGUAGE SQL STABLE;
+    /// CREATE FUNCTION roll() RETURNS FLOAT AS 'SELECT random();' LANGUAGE SQL;
+    /// ",
+    /// )?;
+    /// assert_eq!(db.function("one").unwrap().volatility(), FunctionBehavior::Immutable);
+    /// assert!(db.function("now_utc").unwrap().is_stable());
+    /// assert!(db.function("roll").unwrap().is_volatile());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn volatility(&self) -> FunctionBehavior;
+
+    /// Returns whether the function is `VOLATILE`, and may therefore have
+    /// side effects or return different results for the same arguments.
+    #[inline]
+    fn is_volatile(&self) -> bool {
+        self.volatility() == FunctionBehavior::Volatile
+    }
+
+    /// Returns whether the function is `STABLE`, and therefore returns the
+    /// same results for the same arguments within a single statement.
+    #[inline]
+    fn is_stable(&self) -> bool {
+        self.volatility() == FunctionBehavior::Stable
+    }
+
+    /// Returns whether the function is `IMMUTABLE`, and therefore always
+    /// returns the same results for the same arguments.
+    #[inline]
+    fn is_immutable(&self) -> bool {
+        self.volatility() == FunctionBehavior::Immutable
+    }
+
+    /// Returns whether the function sets the provided configuration
+    /// parameter while it runs, as with `SET search_path = public`.
+    ///
+    /// Parameter names are compared case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The name of the configuration parameter.
+    fn sets_configuration_parameter(&self, parameter: &str) -> bool;
+
+    /// Returns whether the function fixes the `search_path` while it runs,
+    /// which prevents `SECURITY DEFINER` functions from resolving objects
+    /// planted by their callers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE FUNCTION safe() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL
+    ///     SECURITY DEFINER SET search_path = pg_catalog;
+    /// CREATE FUNCTION unsafe() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL SECURITY DEFINER;
+    /// ",
+    /// )?;
+    /// assert!(db.function("safe").unwrap().has_fixed_search_path());
+    /// assert!(!db.function("unsafe").unwrap().has_fixed_search_path());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn has_fixed_search_path(&self) -> bool {
+        self.sets_configuration_parameter("search_path")
+    }
+
+    /// Returns whether the function is a built-in function added by the
+    /// parser, such as `coalesce` or `now`, rather than a function created
+    /// by the schema or provided by one of its extensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the function belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;",
+    /// )?;
+    /// assert!(!db.function("one").unwrap().is_builtin(&db));
+    /// assert!(db.function("coalesce").unwrap().is_builtin(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_builtin(&self, _database: &Self::DB) -> bool {
+        false
+    }
+
+    /// Returns the documentation of the function, as set by `COMMENT ON
+    /// FUNCTION`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE FUNCTION add(x INT, y INT) RETURNS INT AS 'SELECT x + y;' LANGUAGE SQL;
+    /// CREATE FUNCTION add(x TEXT, y TEXT) RETURNS TEXT AS 'SELECT x || y;' LANGUAGE SQL;
+    /// COMMENT ON FUNCTION add(INT, INT) IS 'Adds two integers';
+    /// ",
+    /// )?;
+    /// let docs: Vec<Option<&str>> =
+    ///     db.function_overloads("add").map(|function| function.function_doc(&db)).collect();
+    /// assert_eq!(docs.iter().flatten().collect::<Vec<_>>(), [&"Adds two integers"]);
+    /// assert_eq!(docs.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn function_doc<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db str> {
+        None
+    }
+
+    /// Returns the location of the statement creating the function, if known.
+    ///
+    /// The location records the file the statement was read from when the
+    /// database is loaded from paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the function belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE FUNCTION one() RETURNS INT AS 'SELECT 1;' LANGUAGE SQL;",
+    /// )?;
+    /// let function = db.function("one").unwrap();
+    /// assert_eq!(function.source_location(&db).unwrap().statement_index(), 0);
+    /// assert!(db.function("length").unwrap().source_location(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn source_location<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        None
+    }
+
     /// Returns the normalized return type name of the function as a string.
     ///
     /// # Example
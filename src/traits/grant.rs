@@ -175,6 +175,7 @@ pub trait GrantLike: Debug + Clone + Hash + Ord + Eq + Metadata + Send + Sync {
     /// # Arguments
     ///
     /// * `role` - The role to check against.
+    /// * `database` - The database context.
     ///
     /// # Example
     ///
@@ -196,13 +197,14 @@ pub trait GrantLike: Debug + Clone + Hash + Ord + Eq + Metadata + Send + Sync {
     /// let app_user = db.role("app_user").unwrap();
     /// let admin = db.role("admin").unwrap();
     /// let other_user = db.role("other_user").unwrap();
-    /// assert!(grant.applies_to_role(app_user));
-    /// assert!(grant.applies_to_role(admin));
-    /// assert!(!grant.applies_to_role(other_user));
+    /// assert!(grant.applies_to_role(app_user, &db));
+    /// assert!(grant.applies_to_role(admin, &db));
+    /// assert!(!grant.applies_to_role(other_user, &db));
     /// # Ok(())
     /// # }
     /// ```
-    fn applies_to_role(&self, role: &<Self::DB as DatabaseLike>::Role) -> bool;
+    fn applies_to_role(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB)
+    -> bool;
 }
 
 impl<T: GrantLike> GrantLike for &T {
@@ -237,8 +239,12 @@ impl<T: GrantLike> GrantLike for &T {
         (*self).granted_by(database)
     }
 
-    fn applies_to_role(&self, role: &<Self::DB as DatabaseLike>::Role) -> bool {
-        (*self).applies_to_role(role)
+    fn applies_to_role(
+        &self,
+        role: &<Self::DB as DatabaseLike>::Role,
+        database: &Self::DB,
+    ) -> bool {
+        (*self).applies_to_role(role, database)
     }
 }
 
@@ -498,7 +504,7 @@ mod tests {
         assert_eq!(tables.len(), 1);
 
         let app_user = db.role("app_user").expect("Role not found");
-        assert!(<&_ as GrantLike>::applies_to_role(&grant_ref, app_user));
+        assert!(<&_ as GrantLike>::applies_to_role(&grant_ref, app_user, &db));
     }
 
     /// Exercises the `impl ColumnGrantLike for &T` blanket forwarding —
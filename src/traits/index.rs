@@ -3,11 +3,12 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
-use sqlparser::ast::Expr;
+use sqlparser::ast::{Expr, IndexColumn};
 
 use crate::{
-    traits::{DatabaseLike, Metadata, TableLike},
-    utils::columns_in_expression::columns_in_expression,
+    structs::SourceLocation,
+    traits::{DatabaseLike, DialectLike, Metadata, TableLike},
+    utils::{columns_in_expression::columns_in_expression, object_name::object_name_last_part},
 };
 
 /// An index is a rule that specifies that the values in a column
@@ -35,6 +36,153 @@ pub trait IndexLike: Metadata + Ord + Eq + Debug + Clone + Send + Sync {
     /// ```
     fn name(&self) -> Option<&sqlparser::ast::ObjectName>;
 
+    /// Returns the name the index occupies among the relations of its
+    /// schema, or `None` for an anonymous index.
+    ///
+    /// Unlike [`IndexLike::name`], this also covers the indexes backing named
+    /// unique and primary key constraints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT, name TEXT, CONSTRAINT users_name_key UNIQUE (name));
+    ///      CREATE INDEX idx_id ON users (id);",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// assert_eq!(db.indexes().next().unwrap().index_name(), Some("idx_id"));
+    /// assert_eq!(table.unique_indices(&db).next().unwrap().index_name(), Some("users_name_key"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn index_name(&self) -> Option<&str> {
+        self.name().and_then(object_name_last_part).map(|(name, _)| name)
+    }
+
+    /// Returns whether the name of the index was quoted in SQL.
+    #[inline]
+    fn index_name_is_quoted(&self) -> bool {
+        self.name().and_then(object_name_last_part).is_some_and(|(_, quoted)| quoted)
+    }
+
+    /// Returns the columns of the index in declaration order, including their
+    /// per-column ordering options and operator classes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT, name TEXT, UNIQUE (name, id));
+    ///      CREATE INDEX idx_name ON users (name DESC, id);",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let index = table.indices(&db).next().unwrap();
+    /// let index_columns = index.index_columns();
+    /// assert_eq!(index_columns.len(), 2);
+    /// assert_eq!(index_columns[0].column.options.asc, Some(false));
+    /// assert_eq!(index_columns[1].column.expr.to_string(), "id");
+    ///
+    /// let unique_index = table.unique_indices(&db).next().unwrap();
+    /// let names: Vec<String> =
+    ///     unique_index.index_columns().iter().map(|c| c.column.expr.to_string()).collect();
+    /// assert_eq!(names, vec!["name", "id"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn index_columns(&self) -> &[IndexColumn];
+
+    /// Returns the predicate of a partial index, i.e. its `WHERE` clause, if
+    /// any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT, name TEXT, UNIQUE (name));
+    ///      CREATE INDEX idx_named ON users (id) WHERE name IS NOT NULL;",
+    /// )?;
+    /// let table = db.table(None, "users").unwrap();
+    /// let index = table.indices(&db).next().unwrap();
+    /// assert_eq!(index.predicate().unwrap().to_string(), "name IS NOT NULL");
+    /// let unique_index = table.unique_indices(&db).next().unwrap();
+    /// assert!(unique_index.predicate().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn predicate(&self) -> Option<&Expr> {
+        None
+    }
+
+    /// Returns the storage parameters of the index, as set by its `WITH`
+    /// clause and by later `ALTER INDEX ... SET` and `ALTER INDEX ... RESET`
+    /// statements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE users (id INT, name TEXT);
+    ///      CREATE INDEX idx_name ON users (name) WITH (fillfactor = 70, deduplicate_items = off);
+    ///      ALTER INDEX idx_name SET (fillfactor = 90);
+    ///      ALTER INDEX idx_name RESET (deduplicate_items);",
+    /// )?;
+    /// let index = db.indexes().next().unwrap();
+    /// let parameters: Vec<String> =
+    ///     index.storage_parameters().iter().map(ToString::to_string).collect();
+    /// assert_eq!(parameters, ["fillfactor = 90"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn storage_parameters(&self) -> &[Expr] {
+        &[]
+    }
+
+    /// Returns whether the index is declared `CLUSTERED`, as in SQL Server's
+    /// `CREATE CLUSTERED INDEX`, ordering the rows of its table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the index belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::MsSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<MsSqlDialect>(
+    ///     "CREATE TABLE t (id INT, name NVARCHAR(50));
+    ///      CREATE CLUSTERED INDEX ix_t_id ON t (id);
+    ///      CREATE NONCLUSTERED INDEX ix_t_name ON t (name);",
+    /// )?;
+    /// let clustered: Vec<(Option<&str>, bool)> =
+    ///     db.indexes().map(|index| (index.index_name(), index.is_clustered(&db))).collect();
+    /// assert_eq!(clustered, [(Some("ix_t_id"), true), (Some("ix_t_name"), false)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_clustered(&self, _database: &Self::DB) -> bool {
+        false
+    }
+
     /// Returns the expression of the index as an SQL AST node.
     ///
     /// # Example
@@ -81,6 +229,39 @@ pub trait IndexLike: Metadata + Ord + Eq + Debug + Clone + Send + Sync {
     where
         Self: 'db;
 
+    /// Returns the location of the statement creating the index, if known.
+    ///
+    /// The location records the file the statement was read from when the
+    /// database is loaded from paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the index belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE t (id INT);
+    ///      CREATE INDEX idx ON t (id);",
+    /// )?;
+    /// let index = db.table(None, "t").unwrap().indices(&db).next().unwrap();
+    /// assert_eq!(index.source_location(&db).unwrap().statement_index(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn source_location<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        None
+    }
+
     /// Returns whether the index is defined using simply columns
     /// and no other expressions.
     ///
@@ -109,7 +290,8 @@ pub trait IndexLike: Metadata + Ord + Eq + Debug + Clone + Send + Sync {
         matches!(inner_expr, Expr::Identifier(_) | Expr::CompoundIdentifier(_) | Expr::Tuple(_))
     }
 
-    /// Returns the columns which appear in the index.
+    /// Returns the columns which appear in the index, in the order of the
+    /// index columns.
     ///
     /// # Example
     ///
@@ -136,15 +318,21 @@ pub trait IndexLike: Metadata + Ord + Eq + Debug + Clone + Send + Sync {
         Self: 'db,
     {
         let table = <Self as IndexLike>::table(self, database);
-        let expr = self.expression(database);
 
         let all_columns: Vec<&<Self::DB as DatabaseLike>::Column> =
             table.columns(database).collect();
 
         let table_name = table.table_name();
+        let casing = database.dialect().identifier_casing();
 
-        let found_cols: Vec<&<Self::DB as DatabaseLike>::Column> =
-            columns_in_expression(expr, table_name, &all_columns).unwrap_or_default();
+        let found_cols: Vec<&<Self::DB as DatabaseLike>::Column> = self
+            .index_columns()
+            .iter()
+            .flat_map(|index_column| {
+                columns_in_expression(&index_column.column.expr, table_name, &all_columns, casing)
+                    .unwrap_or_default()
+            })
+            .collect();
 
         found_cols.into_iter()
     }
@@ -0,0 +1,94 @@
+//! Submodule defining the `ParseObserver` trait, notified of the outcome of
+//! each statement while a database is built from statements.
+
+use sqlparser::ast::Statement;
+
+use crate::errors::Error;
+
+/// Trait for the observers notified of the outcome of each statement while
+/// a database is built by
+/// [`ParserDB::from_statements_with_observer`](crate::structs::ParserDB::from_statements_with_observer).
+///
+/// Every method does nothing by default, so that observers only implement
+/// the notifications they are interested in. Each statement is reported
+/// exactly once, in the order of the statements.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::prelude::*;
+/// use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+///
+/// #[derive(Default)]
+/// struct IgnoredStatements(Vec<String>);
+///
+/// impl ParseObserver for IgnoredStatements {
+///     fn on_ignored(&mut self, statement: &Statement) {
+///         self.0.push(statement.to_string());
+///     }
+/// }
+///
+/// let statements = Parser::parse_sql(
+///     &PostgreSqlDialect {},
+///     "CREATE TABLE t (id INT); INSERT INTO t VALUES (1);",
+/// )?;
+/// let mut observer = IgnoredStatements::default();
+/// let db = ParserDB::from_statements_with_observer(statements, "db".to_string(), &mut observer)?;
+/// assert!(db.table(None, "t").is_some());
+/// assert_eq!(observer.0, ["INSERT INTO t VALUES (1)"]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ParseObserver {
+    /// Called once a statement was applied to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The applied statement.
+    fn on_processed(&mut self, _statement: &Statement) {}
+
+    /// Called for a statement skipped as it does not concern the schema, such
+    /// as an `INSERT`, or concerns objects excluded by the object filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The ignored statement.
+    fn on_ignored(&mut self, _statement: &Statement) {}
+
+    /// Called for a statement defining schema objects which are not
+    /// modelled, whether the parse is aborted or the statement skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The unsupported statement.
+    /// * `error` - The [`Error::UnsupportedStatement`] it raised.
+    fn on_unsupported(&mut self, _statement: &Statement, _error: &Error) {}
+
+    /// Called for a statement failing any other validation, whether the
+    /// parse is aborted or the statement skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The failing statement.
+    /// * `error` - The error it raised.
+    fn on_failed(&mut self, _statement: &Statement, _error: &Error) {}
+}
+
+impl<O: ParseObserver + ?Sized> ParseObserver for &mut O {
+    fn on_processed(&mut self, statement: &Statement) {
+        (**self).on_processed(statement);
+    }
+
+    fn on_ignored(&mut self, statement: &Statement) {
+        (**self).on_ignored(statement);
+    }
+
+    fn on_unsupported(&mut self, statement: &Statement, error: &Error) {
+        (**self).on_unsupported(statement, error);
+    }
+
+    fn on_failed(&mut self, statement: &Statement, error: &Error) {
+        (**self).on_failed(statement, error);
+    }
+}
@@ -4,7 +4,10 @@ use core::{borrow::Borrow, fmt::Debug, hash::Hash};
 
 use sqlparser::ast::{CreatePolicyCommand, Expr, Owner};
 
-use crate::traits::{DatabaseLike, DocumentationMetadata, Metadata};
+use crate::{
+    structs::SourceLocation,
+    traits::{DatabaseLike, DocumentationMetadata, Metadata},
+};
 
 /// A trait for types that can be treated as SQL policies.
 pub trait PolicyLike:
@@ -68,6 +71,39 @@ pub trait PolicyLike:
     where
         Self: 'db;
 
+    /// Returns the location of the statement creating the policy, if known.
+    ///
+    /// The location records the file the statement was read from when the
+    /// database is loaded from paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the policy belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TABLE t (id INT);
+    ///      CREATE POLICY p ON t USING (id > 0);",
+    /// )?;
+    /// let policy = db.policies().next().unwrap();
+    /// assert_eq!(policy.source_location(&db).unwrap().statement_index(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn source_location<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        None
+    }
+
     /// Returns the command the policy applies to.
     ///
     /// # Example
@@ -247,6 +283,13 @@ where
         (*self).table(database)
     }
 
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        (*self).source_location(database)
+    }
+
     fn command(&self) -> CreatePolicyCommand {
         (*self).command()
     }
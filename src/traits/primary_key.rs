@@ -0,0 +1,171 @@
+//! Submodule defining the `PrimaryKeyLike` trait for SQL primary keys.
+
+use core::fmt::Debug;
+
+use crate::traits::{DatabaseLike, Metadata, TableLike, UniqueIndexLike};
+
+/// A primary key is a rule that specifies that the values in a column (or a
+/// group of columns) identify the rows of a table, being unique and not null.
+/// This trait represents such a primary key in a database-agnostic way, while
+/// the uniqueness it implies is also reported among the unique indices of its
+/// table.
+pub trait PrimaryKeyLike: Metadata + Ord + Eq + Debug + Clone + Send + Sync {
+    /// The database type the primary key belongs to.
+    type DB: DatabaseLike;
+
+    /// Returns the name of the primary key, if it has one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE named (id INT, CONSTRAINT named_pkey PRIMARY KEY (id));
+    /// CREATE TABLE unnamed (id INT PRIMARY KEY);
+    /// ",
+    /// )?;
+    /// let named = db.table(None, "named").unwrap().primary_key(&db).unwrap();
+    /// let unnamed = db.table(None, "unnamed").unwrap().primary_key(&db).unwrap();
+    /// assert_eq!(named.primary_key_name(), Some("named_pkey"));
+    /// assert_eq!(unnamed.primary_key_name(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn primary_key_name(&self) -> Option<&str>;
+
+    /// Returns whether the primary key was declared inline, as an option of
+    /// its column, rather than as a table constraint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE inline (id INT PRIMARY KEY);
+    /// CREATE TABLE constraint_pk (id INT, PRIMARY KEY (id));
+    /// ",
+    /// )?;
+    /// let inline = db.table(None, "inline").unwrap().primary_key(&db).unwrap();
+    /// let constraint = db.table(None, "constraint_pk").unwrap().primary_key(&db).unwrap();
+    /// assert!(inline.is_inline());
+    /// assert!(!constraint.is_inline());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_inline(&self) -> bool;
+
+    /// Returns a reference to the table the primary key belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the primary
+    ///   key belongs.
+    fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
+    where
+        Self: 'db;
+
+    /// Iterates over the columns composing the primary key, in the order of
+    /// their declaration in the primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the primary
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE my_table (a INT, b INT, PRIMARY KEY (b, a));",
+    /// )?;
+    /// let primary_key = db.table(None, "my_table").unwrap().primary_key(&db).unwrap();
+    /// let columns: Vec<&str> =
+    ///     primary_key.columns(&db).map(|column| column.column_name()).collect();
+    /// assert_eq!(columns, ["b", "a"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db,
+    {
+        self.table(database).primary_key_columns(database)
+    }
+
+    /// Returns whether the primary key is composed of more than one column.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the primary
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE single (id INT PRIMARY KEY);
+    /// CREATE TABLE composite (a INT, b INT, PRIMARY KEY (a, b));
+    /// ",
+    /// )?;
+    /// let single = db.table(None, "single").unwrap().primary_key(&db).unwrap();
+    /// let composite = db.table(None, "composite").unwrap().primary_key(&db).unwrap();
+    /// assert!(!single.is_composite(&db));
+    /// assert!(composite.is_composite(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_composite(&self, database: &Self::DB) -> bool {
+        self.columns(database).nth(1).is_some()
+    }
+
+    /// Returns the unique index enforcing the uniqueness of the primary key.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the primary
+    ///   key belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE my_table (id INT PRIMARY KEY, name TEXT UNIQUE);",
+    /// )?;
+    /// let primary_key = db.table(None, "my_table").unwrap().primary_key(&db).unwrap();
+    /// let unique_index = primary_key.unique_index(&db).unwrap();
+    /// assert!(unique_index.is_primary_key(&db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn unique_index<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::UniqueIndex>
+    where
+        Self: 'db,
+    {
+        self.table(database).unique_indices(database).find(|index| index.is_primary_key(database))
+    }
+}
@@ -29,6 +29,14 @@ pub trait RoleLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
     /// ```
     fn name(&self) -> &str;
 
+    /// Returns whether the role identifier was quoted in SQL.
+    ///
+    /// Quoted identifiers are resolved case-sensitively in PostgreSQL.
+    #[inline]
+    fn name_is_quoted(&self) -> bool {
+        false
+    }
+
     /// Returns whether this role has the `SUPERUSER` attribute.
     ///
     /// # Example
@@ -260,6 +268,36 @@ pub trait RoleLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
         database: &'db Self::DB,
     ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>;
 
+    /// Returns the roles that are members of this role, either through their
+    /// `IN ROLE` clause or through `GRANT role TO member`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE admin;
+    /// CREATE ROLE alice;
+    /// CREATE ROLE bob IN ROLE admin;
+    /// GRANT admin TO alice;
+    /// ",
+    /// )?;
+    ///
+    /// let admin = db.role("admin").unwrap();
+    /// let members: Vec<&str> = admin.members(&db).map(|role| role.name()).collect();
+    /// assert_eq!(members, ["alice", "bob"]);
+    /// assert_eq!(db.role("alice").unwrap().member_of(&db).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn members<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role>;
+
     /// Returns the policies that reference this role.
     ///
     /// # Example
@@ -296,6 +334,10 @@ impl<T: RoleLike> RoleLike for &T {
         (*self).name()
     }
 
+    fn name_is_quoted(&self) -> bool {
+        (*self).name_is_quoted()
+    }
+
     fn is_superuser(&self) -> bool {
         (*self).is_superuser()
     }
@@ -335,6 +377,13 @@ impl<T: RoleLike> RoleLike for &T {
         (*self).member_of(database)
     }
 
+    fn members<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Role> {
+        (*self).members(database)
+    }
+
     fn policies<'db>(
         &'db self,
         database: &'db Self::DB,
@@ -391,4 +440,31 @@ mod tests {
         let policies: Vec<_> = <&_ as RoleLike>::policies(&role_ref, &db).collect();
         assert_eq!(policies.len(), 1);
     }
+
+    #[test]
+    fn test_alter_role_updates_options() {
+        let db = parse_postgres(
+            r"
+            CREATE ROLE app NOLOGIN;
+            ALTER ROLE app WITH LOGIN SUPERUSER PASSWORD 'secret' CONNECTION LIMIT 3;
+            ALTER ROLE app NOSUPERUSER NOINHERIT;
+            ALTER ROLE app SET search_path TO app_schema;
+        ",
+        );
+        let role = db.role("app").expect("Role not found");
+        assert!(role.can_login());
+        assert!(!role.is_superuser());
+        assert!(!role.inherits());
+        assert_eq!(role.connection_limit(), Some(3));
+    }
+
+    #[test]
+    fn test_alter_role_requires_existing_role() {
+        let statements =
+            Parser::parse_sql(&PostgreSqlDialect {}, "ALTER ROLE missing LOGIN;").unwrap();
+        assert!(matches!(
+            ParserDB::from_statements(statements, "test".to_string()),
+            Err(crate::errors::Error::AlterRoleNotFound { role_name }) if role_name == "missing"
+        ));
+    }
 }
@@ -2,7 +2,9 @@
 
 use core::fmt::Debug;
 
-use crate::traits::{DatabaseLike, Metadata};
+use crate::traits::{
+    DatabaseLike, DialectLike, FunctionLike, Metadata, SequenceLike, TableLike, ViewLike,
+};
 
 /// A trait for types that can be treated as SQL schemas.
 ///
@@ -57,6 +59,218 @@ pub trait SchemaLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
     /// # }
     /// ```
     fn authorization(&self) -> Option<&str>;
+
+    /// Returns whether the authorization owner identifier was quoted in SQL.
+    #[inline]
+    fn authorization_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns whether an object qualified by the provided schema belongs to
+    /// the current schema.
+    ///
+    /// Unqualified objects are considered part of the `public` schema, and
+    /// the schema names are compared following the casing rules of the
+    /// dialect of the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_schema` - The schema qualifying the object, if any.
+    /// * `object_schema_quoted` - Whether the schema identifier of the object
+    ///   was quoted in SQL.
+    /// * `database` - A reference to the database instance to which the schema
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE SCHEMA app; CREATE SCHEMA public;")?;
+    /// let app = db.schema("app").unwrap();
+    /// assert!(app.contains(Some("APP"), false, &db));
+    /// assert!(!app.contains(Some("APP"), true, &db));
+    /// assert!(!app.contains(None, false, &db));
+    /// assert!(db.schema("public").unwrap().contains(None, false, &db));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn contains(
+        &self,
+        object_schema: Option<&str>,
+        object_schema_quoted: bool,
+        database: &Self::DB,
+    ) -> bool {
+        let (object_schema, object_schema_quoted) =
+            object_schema.map_or(("public", false), |schema| (schema, object_schema_quoted));
+        database.dialect().identifier_casing().matches(
+            self.name(),
+            self.name_is_quoted(),
+            object_schema,
+            object_schema_quoted,
+        )
+    }
+
+    /// Returns an iterator over the tables of the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the schema
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SCHEMA public;
+    /// CREATE SCHEMA app;
+    /// CREATE TABLE users (id INT);
+    /// CREATE TABLE public.logs (id INT);
+    /// CREATE TABLE app.orders (id INT);
+    /// ",
+    /// )?;
+    /// let public: Vec<&str> =
+    ///     db.schema("public").unwrap().tables(&db).map(TableLike::table_name).collect();
+    /// assert_eq!(public, ["users", "logs"]);
+    /// let app: Vec<&str> = db.schema("app").unwrap().tables(&db).map(TableLike::table_name).collect();
+    /// assert_eq!(app, ["orders"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        database.tables().filter(move |table| {
+            self.contains(table.table_schema(), table.table_schema_is_quoted(), database)
+        })
+    }
+
+    /// Returns an iterator over the functions of the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the schema
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SCHEMA app;
+    /// CREATE FUNCTION app.add_one(x INT) RETURNS INT AS 'SELECT x + 1' LANGUAGE sql;
+    /// CREATE FUNCTION one() RETURNS INT AS 'SELECT 1' LANGUAGE sql;
+    /// ",
+    /// )?;
+    /// let app: Vec<&str> =
+    ///     db.schema("app").unwrap().functions(&db).map(FunctionLike::name).collect();
+    /// assert_eq!(app, ["add_one"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn functions<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Function>
+    where
+        Self: 'db,
+    {
+        database.functions().filter(move |function| {
+            self.contains(function.schema(), function.schema_is_quoted(), database)
+        })
+    }
+
+    /// Returns an iterator over the views of the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the schema
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SCHEMA app;
+    /// CREATE TABLE users (id INT);
+    /// CREATE VIEW app.user_ids AS SELECT id FROM users;
+    /// ",
+    /// )?;
+    /// let app: Vec<&str> = db.schema("app").unwrap().views(&db).map(ViewLike::view_name).collect();
+    /// assert_eq!(app, ["user_ids"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn views<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::View>
+    where
+        Self: 'db,
+    {
+        database.views().filter(move |view| {
+            self.contains(view.view_schema(), view.view_schema_is_quoted(), database)
+        })
+    }
+
+    /// Returns an iterator over the sequences of the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the schema
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SCHEMA app;
+    /// CREATE SEQUENCE app.order_seq;
+    /// CREATE SEQUENCE user_seq;
+    /// ",
+    /// )?;
+    /// let app: Vec<&str> =
+    ///     db.schema("app").unwrap().sequences(&db).map(SequenceLike::name).collect();
+    /// assert_eq!(app, ["order_seq"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sequences<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Sequence>
+    where
+        Self: 'db,
+    {
+        database.sequences().filter(move |sequence| {
+            self.contains(sequence.schema(), sequence.schema_is_quoted(), database)
+        })
+    }
 }
 
 /// Blanket implementation for references to `SchemaLike` types.
@@ -74,6 +288,59 @@ impl<S: SchemaLike> SchemaLike for &S {
     fn authorization(&self) -> Option<&str> {
         (*self).authorization()
     }
+
+    fn authorization_is_quoted(&self) -> bool {
+        (*self).authorization_is_quoted()
+    }
+
+    fn contains(
+        &self,
+        object_schema: Option<&str>,
+        object_schema_quoted: bool,
+        database: &Self::DB,
+    ) -> bool {
+        (*self).contains(object_schema, object_schema_quoted, database)
+    }
+
+    fn tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        (*self).tables(database)
+    }
+
+    fn functions<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Function>
+    where
+        Self: 'db,
+    {
+        (*self).functions(database)
+    }
+
+    fn views<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::View>
+    where
+        Self: 'db,
+    {
+        (*self).views(database)
+    }
+
+    fn sequences<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Sequence>
+    where
+        Self: 'db,
+    {
+        (*self).sequences(database)
+    }
 }
 
 #[cfg(test)]
@@ -428,6 +695,73 @@ mod tests {
         assert!(db.schema("existing").is_some());
     }
 
+    #[test]
+    fn test_drop_schema_with_function_fails_restrict() {
+        let result = parse_postgres(
+            "
+            CREATE SCHEMA my_schema;
+            CREATE FUNCTION my_schema.one() RETURNS INT AS 'SELECT 1' LANGUAGE sql;
+            DROP SCHEMA my_schema;
+            ",
+        );
+
+        assert!(
+            matches!(result, Err(crate::errors::Error::SchemaNotEmpty { schema_name }) if schema_name == "my_schema")
+        );
+    }
+
+    #[test]
+    fn test_drop_schema_cascade_removes_functions_and_views() {
+        let db = parse_postgres(
+            "
+            CREATE SCHEMA my_schema;
+            CREATE TABLE users (id INT);
+            CREATE FUNCTION my_schema.one() RETURNS INT AS 'SELECT 1' LANGUAGE sql;
+            CREATE FUNCTION two() RETURNS INT AS 'SELECT 2' LANGUAGE sql;
+            CREATE VIEW my_schema.user_ids AS SELECT id FROM users;
+            DROP SCHEMA my_schema CASCADE;
+            ",
+        )
+        .unwrap();
+
+        assert!(db.function("one").is_none());
+        assert!(db.function("two").is_some());
+        assert!(db.view(Some("my_schema"), "user_ids").is_none());
+        assert!(db.table(None, "users").is_some());
+    }
+
+    #[test]
+    fn test_schema_objects() {
+        let db = parse_postgres(
+            r#"
+            CREATE SCHEMA public;
+            CREATE SCHEMA "App";
+            CREATE TABLE users (id INT);
+            CREATE TABLE "App".orders (id INT);
+            CREATE TABLE app.logs (id INT);
+            CREATE FUNCTION "App".one() RETURNS INT AS 'SELECT 1' LANGUAGE sql;
+            CREATE FUNCTION two() RETURNS INT AS 'SELECT 2' LANGUAGE sql;
+            CREATE VIEW "App".user_ids AS SELECT id FROM users;
+            "#,
+        )
+        .unwrap();
+
+        let app = db.schema("\"App\"").expect("App schema");
+        let tables: Vec<&str> = app.tables(&db).map(TableLike::table_name).collect();
+        assert_eq!(tables, ["orders"]);
+        let functions: Vec<&str> = app.functions(&db).map(FunctionLike::name).collect();
+        assert_eq!(functions, ["one"]);
+        let views: Vec<&str> = app.views(&db).map(ViewLike::view_name).collect();
+        assert_eq!(views, ["user_ids"]);
+
+        let public = db.schema("public").expect("public schema");
+        let tables: Vec<&str> = public.tables(&db).map(TableLike::table_name).collect();
+        assert_eq!(tables, ["users"]);
+        let functions: Vec<&str> = public.functions(&db).map(FunctionLike::name).collect();
+        assert_eq!(functions, ["two"]);
+        assert_eq!(public.views(&db).count(), 0);
+    }
+
     /// Exercises the `impl SchemaLike for &S` blanket forwarding — same
     /// pattern as `traits::column::tests::reference_impl::test_all_methods`.
     mod reference_impl {
@@ -444,6 +778,11 @@ mod tests {
             assert_eq!(<&_ as SchemaLike>::name(&s_ref), "my_schema");
             assert!(!<&_ as SchemaLike>::name_is_quoted(&s_ref));
             assert_eq!(<&_ as SchemaLike>::authorization(&s_ref), Some("admin"));
+            assert!(<&_ as SchemaLike>::contains(&s_ref, Some("MY_SCHEMA"), false, &db));
+            assert_eq!(<&_ as SchemaLike>::tables(&s_ref, &db).count(), 0);
+            assert_eq!(<&_ as SchemaLike>::functions(&s_ref, &db).count(), 0);
+            assert_eq!(<&_ as SchemaLike>::views(&s_ref, &db).count(), 0);
+            assert_eq!(<&_ as SchemaLike>::sequences(&s_ref, &db).count(), 0);
         }
     }
 }
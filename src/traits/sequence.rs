@@ -0,0 +1,315 @@
+//! Submodule providing a trait for describing SQL Sequence-like entities.
+
+use core::fmt::Debug;
+
+use crate::traits::{ColumnLike, DatabaseLike, Metadata, TableLike};
+
+/// A trait for types that can be treated as SQL sequences.
+///
+/// Sequences are number generators, most commonly used to provide the
+/// default values of auto-incrementing columns. A sequence may be owned by a
+/// column, in which case it is dropped along with it.
+pub trait SequenceLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
+    /// The database type the sequence belongs to.
+    type DB: DatabaseLike;
+
+    /// Returns the name of the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE SEQUENCE users_id_seq;")?;
+    /// let sequence = db.sequence(None, "users_id_seq").unwrap();
+    /// assert_eq!(sequence.name(), "users_id_seq");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn name(&self) -> &str;
+
+    /// Returns whether the sequence identifier was quoted in SQL.
+    ///
+    /// Quoted identifiers are resolved case-sensitively in PostgreSQL.
+    #[inline]
+    fn name_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the schema of the sequence, if specified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE SEQUENCE app.users_id_seq;")?;
+    /// let sequence = db.sequence(Some("app"), "users_id_seq").unwrap();
+    /// assert_eq!(sequence.schema(), Some("app"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn schema(&self) -> Option<&str>;
+
+    /// Returns whether the schema identifier of the sequence was quoted in
+    /// SQL.
+    #[inline]
+    fn schema_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the sequence is temporary, i.e. dropped at the end of
+    /// the session creating it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE TEMPORARY SEQUENCE scratch_seq; CREATE SEQUENCE users_id_seq;",
+    /// )?;
+    /// assert!(db.sequence(None, "scratch_seq").unwrap().is_temporary());
+    /// assert!(!db.sequence(None, "users_id_seq").unwrap().is_temporary());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_temporary(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the sequence is unlogged, i.e. its changes are not
+    /// written to the write-ahead log.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "CREATE UNLOGGED SEQUENCE cache_seq; CREATE SEQUENCE users_id_seq;",
+    /// )?;
+    /// assert!(db.sequence(None, "cache_seq").unwrap().is_unlogged());
+    /// assert!(!db.sequence(None, "users_id_seq").unwrap().is_unlogged());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn is_unlogged(&self) -> bool {
+        false
+    }
+
+    /// Returns the column owning the sequence, as declared by its `OWNED BY`
+    /// clause, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   sequence belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE SEQUENCE users_id_seq OWNED BY users.id;
+    /// CREATE SEQUENCE free_seq;
+    /// ",
+    /// )?;
+    /// let owned = db.sequence(None, "users_id_seq").unwrap();
+    /// assert_eq!(owned.owner_column(&db).map(ColumnLike::column_name), Some("id"));
+    /// assert!(db.sequence(None, "free_seq").unwrap().owner_column(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn owner_column<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db;
+
+    /// Returns the table of the column owning the sequence, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   sequence belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE SEQUENCE users_id_seq OWNED BY users.id;
+    /// ",
+    /// )?;
+    /// let sequence = db.sequence(None, "users_id_seq").unwrap();
+    /// assert_eq!(sequence.owner_table(&db).map(TableLike::table_name), Some("users"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn owner_table<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        self.owner_column(database).map(|column| column.table(database))
+    }
+
+    /// Iterates over the columns whose default value advances the sequence,
+    /// which keep the sequence from being dropped without `CASCADE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the
+    ///   sequence belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SEQUENCE ids;
+    /// CREATE TABLE users (id INT DEFAULT nextval('ids'), name TEXT);
+    /// CREATE TABLE orders (id INT DEFAULT nextval('ids'));
+    /// ",
+    /// )?;
+    /// let sequence = db.sequence(None, "ids").unwrap();
+    /// let columns: Vec<(&str, &str)> = sequence
+    ///     .dependent_columns(&db)
+    ///     .map(|column| (column.table(&db).table_name(), column.column_name()))
+    ///     .collect();
+    /// assert_eq!(columns, [("orders", "id"), ("users", "id")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn dependent_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db,
+    {
+        database.tables().flat_map(move |table| {
+            table.columns(database).filter(move |column| {
+                column.default_sequence(database).is_some_and(|sequence| {
+                    sequence.name() == self.name() && sequence.schema() == self.schema()
+                })
+            })
+        })
+    }
+}
+
+impl<S: SequenceLike> SequenceLike for &S {
+    type DB = S::DB;
+
+    fn name(&self) -> &str {
+        (*self).name()
+    }
+
+    fn name_is_quoted(&self) -> bool {
+        (*self).name_is_quoted()
+    }
+
+    fn schema(&self) -> Option<&str> {
+        (*self).schema()
+    }
+
+    fn schema_is_quoted(&self) -> bool {
+        (*self).schema_is_quoted()
+    }
+
+    fn is_temporary(&self) -> bool {
+        (*self).is_temporary()
+    }
+
+    fn is_unlogged(&self) -> bool {
+        (*self).is_unlogged()
+    }
+
+    fn owner_column<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db,
+    {
+        (*self).owner_column(database)
+    }
+
+    fn owner_table<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        (*self).owner_table(database)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{
+        structs::ParserDB,
+        traits::{ColumnLike, TableLike},
+    };
+
+    /// Exercises the `impl SequenceLike for &S` blanket forwarding.
+    #[test]
+    fn test_reference_impl() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE SCHEMA app;
+            CREATE TABLE app.users (id INT);
+            CREATE SEQUENCE app.\"UsersSeq\" OWNED BY app.users.id;
+            ",
+        )
+        .unwrap();
+        let sequence = db.sequence(Some("app"), "\"UsersSeq\"").expect("sequence");
+
+        let s_ref: &<ParserDB as DatabaseLike>::Sequence = sequence;
+        assert_eq!(<&_ as SequenceLike>::name(&s_ref), "UsersSeq");
+        assert!(<&_ as SequenceLike>::name_is_quoted(&s_ref));
+        assert_eq!(<&_ as SequenceLike>::schema(&s_ref), Some("app"));
+        assert!(!<&_ as SequenceLike>::schema_is_quoted(&s_ref));
+        assert!(!<&_ as SequenceLike>::is_temporary(&s_ref));
+        assert_eq!(
+            <&_ as SequenceLike>::owner_column(&s_ref, &db).map(ColumnLike::column_name),
+            Some("id")
+        );
+        assert_eq!(
+            <&_ as SequenceLike>::owner_table(&s_ref, &db).map(TableLike::table_name),
+            Some("users")
+        );
+    }
+}
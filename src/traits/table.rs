@@ -1,18 +1,18 @@
 //! Submodule providing a trait for describing SQL Table-like entities.
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
 use core::{borrow::Borrow, fmt::Debug, hash::Hash};
 
 use crate::{
     structs::{
-        SchemaFingerprint,
+        PolicyCoverage, SchemaFingerprint, SourceLocation, WritePathSummary,
         fingerprint::{FingerprintError, compute_persistence_v1},
     },
     traits::{
-        ColumnLike, DatabaseLike, DocumentationMetadata, ForeignKeyLike, GrantLike, Metadata,
-        PolicyLike, TableGrantLike, TriggerLike, check_constraint::CheckConstraintLike,
+        ColumnLike, DatabaseLike, DialectLike, DocumentationMetadata, ForeignKeyLike, GrantLike,
+        Metadata, PolicyLike, SequenceLike, TableGrantLike, TriggerLike,
+        check_constraint::CheckConstraintLike,
     },
-    utils::identifier_resolution::stored_identifier_matches_lookup,
 };
 
 /// A trait for types that can be treated as SQL tables.
@@ -113,10 +113,105 @@ pub trait TableLike:
     where
         Self: 'db,
     {
-        database.triggers().filter(|t| t.table(database).table_name() == self.table_name())
+        database.triggers_on(self.borrow())
     }
 
-    /// Returns the documentation of the table, if any.
+    /// Returns the trigger with the provided name defined on the table, if
+    /// any.
+    ///
+    /// Trigger names are only unique within a table, so this is the
+    /// unambiguous way to look up a trigger by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   triggers from.
+    /// * `name` - The name of the trigger to retrieve.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE a (id INT);
+    /// CREATE TABLE b (id INT);
+    /// CREATE FUNCTION my_func() RETURNS TRIGGER AS $$ BEGIN END; $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER audit BEFORE INSERT ON a FOR EACH ROW EXECUTE FUNCTION my_func();
+    /// CREATE TRIGGER audit AFTER DELETE ON b FOR EACH ROW EXECUTE FUNCTION my_func();
+    /// ",
+    /// )?;
+    /// let b = db.table(None, "b").unwrap();
+    /// let trigger = b.trigger(&db, "audit").unwrap();
+    /// assert_eq!(trigger.table(&db).table_name(), "b");
+    /// assert!(b.trigger(&db, "missing").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trigger<'db>(
+        &'db self,
+        database: &'db Self::DB,
+        name: &str,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Trigger>
+    where
+        Self: 'db,
+    {
+        let casing = database.dialect().identifier_casing();
+        self.triggers(database)
+            .find(|trigger| casing.matches_lookup(trigger.name(), trigger.name_is_quoted(), name))
+    }
+
+    /// Returns a summary of what happens when rows are inserted into or
+    /// updated in the table: the triggers firing in order, the columns they
+    /// overwrite and the constraints checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   triggers and constraints from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE posts (id INT PRIMARY KEY, slug TEXT, CHECK (slug <> ''));
+    /// CREATE FUNCTION slugify() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     NEW.slug = lower(NEW.slug);
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER posts_slug BEFORE INSERT ON posts FOR EACH ROW EXECUTE FUNCTION slugify();
+    /// ",
+    /// )?;
+    /// let posts = db.table(None, "posts").unwrap();
+    /// let summary = posts.write_path_summary(&db);
+    /// let overwritten: Vec<&str> =
+    ///     summary.insert().overwritten_columns().map(|column| column.column_name()).collect();
+    /// assert_eq!(overwritten, ["slug"]);
+    /// assert_eq!(summary.insert().check_constraints().len(), 1);
+    /// assert!(summary.update().before_triggers().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn write_path_summary<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> WritePathSummary<'db, Self::DB>
+    where
+        Self: 'db,
+    {
+        WritePathSummary::new(database, self.borrow())
+    }
+
+    /// Returns the documentation of the table, if any, as set by `COMMENT ON
+    /// TABLE` or else by the SQL comments preceding its definition.
     ///
     /// # Arguments
     ///
@@ -147,6 +242,36 @@ pub trait TableLike:
     where
         Self: 'db;
 
+    /// Returns the location of the statement creating the table, if known.
+    ///
+    /// The location records the file the statement was read from when the
+    /// database is loaded from paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database the table belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>("CREATE TABLE a (id INT);\nCREATE TABLE b (id INT);")?;
+    /// let location = db.table(None, "b").unwrap().source_location(&db).unwrap();
+    /// assert_eq!((location.statement_index(), location.line()), (1, Some(2)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn source_location<'db>(&'db self, _database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        None
+    }
+
     /// The schema name of the table, if it has one.
     ///
     /// # Example
@@ -175,6 +300,59 @@ pub trait TableLike:
         false
     }
 
+    /// Returns the options the table is created with, such as MySQL's
+    /// `ENGINE=InnoDB` or `DEFAULT CHARSET=utf8mb4`, as pairs of upper-case
+    /// option names and their values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::MySqlDialect;
+    ///
+    /// let db = ParserDB::parse::<MySqlDialect>(
+    ///     "CREATE TABLE t (id INT PRIMARY KEY AUTO_INCREMENT)
+    ///     ENGINE=InnoDB AUTO_INCREMENT=42 DEFAULT CHARSET=utf8mb4;",
+    /// )?;
+    /// let table = db.table(None, "t").unwrap();
+    /// let options = table.table_options();
+    /// assert!(options.contains(&("ENGINE".to_string(), "InnoDB".to_string())));
+    /// assert!(options.contains(&("AUTO_INCREMENT".to_string(), "42".to_string())));
+    /// assert!(options.contains(&("DEFAULT CHARSET".to_string(), "utf8mb4".to_string())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn table_options(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Returns the storage engine of the table, as set by MySQL's `ENGINE`
+    /// option, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::MySqlDialect;
+    ///
+    /// let db = ParserDB::parse::<MySqlDialect>(
+    ///     "CREATE TABLE t (id INT) ENGINE=MyISAM; CREATE TABLE u (id INT);",
+    /// )?;
+    /// assert_eq!(db.table(None, "t").unwrap().storage_engine().as_deref(), Some("MyISAM"));
+    /// assert_eq!(db.table(None, "u").unwrap().storage_engine(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn storage_engine(&self) -> Option<String> {
+        self.table_options()
+            .into_iter()
+            .find_map(|(name, value)| (name == "ENGINE").then_some(value))
+    }
+
     /// Returns the table ID according to its position in the database's table
     /// iterator.
     ///
@@ -404,9 +582,9 @@ pub trait TableLike:
     where
         Self: 'db,
     {
-        TableLike::columns(self, database).find(|col| {
-            stored_identifier_matches_lookup(col.column_name(), col.column_name_is_quoted(), name)
-        })
+        let casing = database.dialect().identifier_casing();
+        TableLike::columns(self, database)
+            .find(|col| casing.matches_lookup(col.column_name(), col.column_name_is_quoted(), name))
     }
 
     /// Returns the corresponding column by ID position in the table's column
@@ -481,6 +659,42 @@ pub trait TableLike:
         TableLike::columns(self, database).any(|col| col == column)
     }
 
+    /// Returns the primary key of the table, if it has one.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE my_table (id1 INT, id2 INT, CONSTRAINT my_pk PRIMARY KEY (id2, id1));
+    /// CREATE TABLE my_no_pk_table (id INT);
+    /// ",
+    /// )?;
+    /// let table = db.table(None, "my_table").unwrap();
+    /// let primary_key = table.primary_key(&db).unwrap();
+    /// assert_eq!(primary_key.primary_key_name(), Some("my_pk"));
+    /// assert!(!primary_key.is_inline());
+    /// // The primary key is also reported among the unique indices.
+    /// assert!(table.unique_indices(&db).any(|index| index.is_primary_key(&db)));
+    /// let no_pk_table = db.table(None, "my_no_pk_table").unwrap();
+    /// assert!(no_pk_table.primary_key(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn primary_key<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::PrimaryKey>
+    where
+        Self: 'db;
+
     /// Iterates over the primary key columns of the table using the provided
     /// schema.
     ///
@@ -920,7 +1134,9 @@ pub trait TableLike:
     }
 
     /// Returns whether the table or any of its ancestral extended tables have
-    /// non-tautological check constraints.
+    /// non-tautological check constraints. The constraints of the ancestral
+    /// tables declared `NO INHERIT` are not considered, as they do not apply
+    /// to the tables extending them.
     ///
     /// # Arguments
     ///
@@ -932,6 +1148,7 @@ pub trait TableLike:
     /// ```rust
     /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
     /// let db = ParserDB::parse::<GenericDialect>(
     ///     "
     /// CREATE TABLE parent_table (id INT PRIMARY KEY, age INT CHECK (age > 0));
@@ -945,16 +1162,28 @@ pub trait TableLike:
     /// assert!(child_table.has_non_tautological_check_constraints_in_hierarchy(&db));
     /// let another_table = db.table(None, "another_table").unwrap();
     /// assert!(!another_table.has_non_tautological_check_constraints_in_hierarchy(&db));
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE parent_table (id INT PRIMARY KEY, age INT CHECK (age > 0) NO INHERIT);
+    /// CREATE TABLE child_table (id INT PRIMARY KEY REFERENCES parent_table(id), salary INT);
+    /// ",
+    /// )?;
+    /// let parent_table = db.table(None, "parent_table").unwrap();
+    /// assert!(parent_table.has_non_tautological_check_constraints_in_hierarchy(&db));
+    /// let child_table = db.table(None, "child_table").unwrap();
+    /// assert!(!child_table.has_non_tautological_check_constraints_in_hierarchy(&db));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     fn has_non_tautological_check_constraints_in_hierarchy(&self, database: &Self::DB) -> bool {
         self.has_non_tautological_check_constraints(database)
-            || self
-                .ancestral_extended_tables(database)
-                .into_iter()
-                .any(|table| table.has_non_tautological_check_constraints(database))
+            || self.ancestral_extended_tables(database).into_iter().any(|table| {
+                table
+                    .non_tautological_check_constraints(database)
+                    .any(|check_constraint| !check_constraint.no_inherit(database))
+            })
     }
 
     /// Iterates over the indices associated with the table.
@@ -1115,6 +1344,45 @@ pub trait TableLike:
         self.foreign_keys(database).any(move |fk| !fk.is_self_referential(database))
     }
 
+    /// Iterates over the foreign keys referencing the table, including the
+    /// self-referential ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE parent_table (id INT PRIMARY KEY);
+    /// CREATE TABLE child_table (id INT PRIMARY KEY, parent_id INT REFERENCES parent_table(id));
+    /// ",
+    /// )?;
+    /// let parent_table = db.table(None, "parent_table").unwrap();
+    /// let foreign_keys = parent_table.referencing_foreign_keys(&db).collect::<Vec<_>>();
+    /// assert_eq!(foreign_keys.len(), 1);
+    /// assert_eq!(foreign_keys[0].host_table(&db).table_name(), "child_table");
+    /// let child_table = db.table(None, "child_table").unwrap();
+    /// assert_eq!(child_table.referencing_foreign_keys(&db).count(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn referencing_foreign_keys<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::ForeignKey>
+    where
+        Self: 'db,
+    {
+        database.references_to(self.borrow())
+    }
+
     /// Iterates over the foreign keys in the current table which refer to
     /// ancestors of the provided table.
     ///
@@ -1162,9 +1430,9 @@ pub trait TableLike:
     {
         let ancestors = table.ancestral_extended_tables(database);
         self.foreign_keys(database).filter(move |fk| {
-            let referenced_table = fk.referenced_table(database);
-            ancestors.iter().any(|ancestor| (*ancestor).borrow() == referenced_table)
-                && fk.is_referenced_primary_key(database)
+            fk.referenced_table(database).is_some_and(|referenced_table| {
+                ancestors.iter().any(|ancestor| (*ancestor).borrow() == referenced_table)
+            }) && fk.is_referenced_primary_key(database)
         })
     }
 
@@ -1206,7 +1474,7 @@ pub trait TableLike:
         let mut referenced_tables = Vec::new();
 
         for foreign_key in self.foreign_keys(database) {
-            referenced_tables.push(foreign_key.referenced_table(database));
+            referenced_tables.extend(foreign_key.referenced_table(database));
         }
 
         referenced_tables.sort_unstable();
@@ -1257,6 +1525,116 @@ pub trait TableLike:
         referenced_tables
     }
 
+    /// Returns the (deduplicated and sorted) tables which are referenced by
+    /// the current table via foreign keys, either directly or through other
+    /// tables, i.e. the tables which must exist before rows can be inserted
+    /// into the current table. The current table is never included, even when
+    /// it belongs to a cycle of foreign keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+    /// CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users(id));
+    /// ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams(id);
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// CREATE TABLE comments (id INT PRIMARY KEY, post_id INT REFERENCES posts(id));
+    /// ",
+    /// )?;
+    /// let comments = db.table(None, "comments").unwrap();
+    /// let ancestors: Vec<&str> =
+    ///     comments.ancestor_tables(&db).into_iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(ancestors, ["posts", "teams", "users"]);
+    /// let users = db.table(None, "users").unwrap();
+    /// let ancestors: Vec<&str> =
+    ///     users.ancestor_tables(&db).into_iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(ancestors, ["teams"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn ancestor_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Vec<&'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        let current: &'db <Self::DB as DatabaseLike>::Table = self.borrow();
+        let mut ancestors = BTreeSet::new();
+        let mut pending = vec![current];
+        while let Some(table) = pending.pop() {
+            for referenced_table in table.referenced_tables(database) {
+                if referenced_table != current && ancestors.insert(referenced_table) {
+                    pending.push(referenced_table);
+                }
+            }
+        }
+        ancestors.into_iter().collect()
+    }
+
+    /// Returns the (deduplicated and sorted) tables which reference the
+    /// current table via foreign keys, either directly or through other
+    /// tables, i.e. the tables whose rows may depend on the rows of the
+    /// current table. The current table is never included, even when it
+    /// belongs to a cycle of foreign keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT PRIMARY KEY, manager_id INT REFERENCES users(id));
+    /// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users(id));
+    /// CREATE TABLE comments (id INT PRIMARY KEY, post_id INT REFERENCES posts(id));
+    /// CREATE TABLE tags (id INT PRIMARY KEY);
+    /// ",
+    /// )?;
+    /// let users = db.table(None, "users").unwrap();
+    /// let descendants: Vec<&str> =
+    ///     users.descendant_tables(&db).into_iter().map(|t| t.table_name()).collect();
+    /// assert_eq!(descendants, ["comments", "posts"]);
+    /// let tags = db.table(None, "tags").unwrap();
+    /// assert!(tags.descendant_tables(&db).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn descendant_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Vec<&'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        let current: &'db <Self::DB as DatabaseLike>::Table = self.borrow();
+        let mut descendants = BTreeSet::new();
+        let mut pending = vec![current];
+        while let Some(table) = pending.pop() {
+            for foreign_key in table.referencing_foreign_keys(database) {
+                let host_table = foreign_key.host_table(database);
+                if host_table != current && descendants.insert(host_table) {
+                    pending.push(host_table);
+                }
+            }
+        }
+        descendants.into_iter().collect()
+    }
+
     /// Returns the foreign keys which are used to define extensions.
     ///
     /// # Arguments
@@ -1329,7 +1707,7 @@ pub trait TableLike:
     where
         Self: 'db,
     {
-        self.extension_foreign_keys(database).map(|fk| fk.referenced_table(database))
+        self.extension_foreign_keys(database).filter_map(|fk| fk.referenced_table(database))
     }
 
     /// Returns the root table of the extension hierarchy for the current
@@ -1447,6 +1825,90 @@ pub trait TableLike:
         self.extending_tables(database).next().is_some()
     }
 
+    /// Returns the table directly extended by the current table, if any,
+    /// according to the [`ExtensionStrategy`](crate::structs::ExtensionStrategy)
+    /// of the database. When the table extends several tables, the first
+    /// extended table is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE grandparent_table (id INT PRIMARY KEY);
+    /// CREATE TABLE parent_table (id INT PRIMARY KEY REFERENCES grandparent_table(id));
+    /// CREATE TABLE child_table (id INT PRIMARY KEY REFERENCES parent_table(id));
+    /// ",
+    /// )?;
+    /// let child_table = db.table(None, "child_table").unwrap();
+    /// let parent_table = db.table(None, "parent_table").unwrap();
+    /// let grandparent_table = db.table(None, "grandparent_table").unwrap();
+    /// assert_eq!(child_table.extension_parent(&db), Some(parent_table));
+    /// assert_eq!(parent_table.extension_parent(&db), Some(grandparent_table));
+    /// assert!(grandparent_table.extension_parent(&db).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn extension_parent<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        self.extended_tables(database).next()
+    }
+
+    /// Returns the tables directly extending the current table, according to
+    /// the [`ExtensionStrategy`](crate::structs::ExtensionStrategy) of the
+    /// database. Unlike [`TableLike::extending_tables`], the tables extending
+    /// these children are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE grandparent_table (id INT PRIMARY KEY);
+    /// CREATE TABLE parent_table (id INT PRIMARY KEY REFERENCES grandparent_table(id));
+    /// CREATE TABLE child_table (id INT PRIMARY KEY REFERENCES parent_table(id));
+    /// ",
+    /// )?;
+    /// let grandparent_table = db.table(None, "grandparent_table").unwrap();
+    /// let children: Vec<&str> =
+    ///     grandparent_table.extension_children(&db).map(|table| table.table_name()).collect();
+    /// assert_eq!(children, ["parent_table"]);
+    /// assert_eq!(grandparent_table.extending_tables(&db).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extension_children<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        self.referencing_foreign_keys(database)
+            .filter(move |foreign_key| foreign_key.is_extension_foreign_key(database))
+            .map(move |foreign_key| foreign_key.host_table(database))
+    }
+
     /// Returns the first extension foreign key found in the current table which
     /// references to the provided table or any of its descendants.
     ///
@@ -1500,10 +1962,12 @@ pub trait TableLike:
         Self: 'db,
     {
         self.extension_foreign_keys(database).find(|fk| {
-            let referenced_table: &<Self::DB as DatabaseLike>::Table =
-                fk.referenced_table(database);
-            referenced_table == table
-                || referenced_table.extension_foreign_key_to(database, table).is_some()
+            fk.referenced_table(database).is_some_and(
+                |referenced_table: &<Self::DB as DatabaseLike>::Table| {
+                    referenced_table == table
+                        || referenced_table.extension_foreign_key_to(database, table).is_some()
+                },
+            )
         })
     }
 
@@ -1555,7 +2019,7 @@ pub trait TableLike:
     where
         Self: 'db,
     {
-        self.extension_foreign_key_to(database, table).map(|fk| fk.referenced_table(database))
+        self.extension_foreign_key_to(database, table).and_then(|fk| fk.referenced_table(database))
     }
 
     /// Returns the unique tables which are extended by either the current
@@ -1652,10 +2116,13 @@ pub trait TableLike:
             return ancestral_extended_tables;
         }
 
+        // The strongly connected components keep referenced tables first,
+        // even when foreign keys elsewhere in the database form a cycle.
         let sorted_dag = database
-            .table_dag()
+            .table_dependency_graph()
+            .strongly_connected_components()
             .into_iter()
-            .map(core::borrow::Borrow::borrow)
+            .flatten()
             .collect::<Vec<&<Self::DB as DatabaseLike>::Table>>();
 
         ancestral_extended_tables.sort_by_key(|table| {
@@ -1713,7 +2180,7 @@ pub trait TableLike:
             if fk.host_columns(database).all(|col| col == column)
                 && fk.is_referenced_primary_key(database)
             {
-                referenced_tables.push(fk.referenced_table(database));
+                referenced_tables.extend(fk.referenced_table(database));
             }
         }
 
@@ -2029,9 +2496,11 @@ pub trait TableLike:
             return true;
         }
         self.foreign_keys(database).any(|fk| {
-            let referenced_table = fk.referenced_table(database);
-            referenced_table == other
-                || referenced_table != self.borrow() && referenced_table.depends_on(database, other)
+            fk.referenced_table(database).is_some_and(|referenced_table| {
+                referenced_table == other
+                    || referenced_table != self.borrow()
+                        && referenced_table.depends_on(database, other)
+            })
         })
     }
 
@@ -2074,10 +2543,7 @@ pub trait TableLike:
     /// # }
     /// ```
     fn refers_to(&self, database: &Self::DB, other: &<Self::DB as DatabaseLike>::Table) -> bool {
-        self.foreign_keys(database).any(|fk| {
-            let referenced_table = fk.referenced_table(database);
-            referenced_table == other
-        })
+        self.foreign_keys(database).any(|fk| fk.referenced_table(database) == Some(other))
     }
 
     /// Returns an iterator over all tables that depend directly or indirectly
@@ -2152,6 +2618,94 @@ pub trait TableLike:
         self.dependent_tables(database).next().is_some()
     }
 
+    /// Returns the sequences advanced by the default values of the columns of
+    /// the table, which must exist before the table is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE SEQUENCE ids;
+    /// CREATE TABLE users (id INT DEFAULT nextval('ids'), legacy_id INT DEFAULT nextval('ids'));
+    /// CREATE TABLE tags (id INT);
+    /// ",
+    /// )?;
+    /// let users = db.table(None, "users").unwrap();
+    /// let sequences: Vec<&str> =
+    ///     users.default_sequences(&db).into_iter().map(SequenceLike::name).collect();
+    /// assert_eq!(sequences, ["ids"]);
+    /// assert!(db.table(None, "tags").unwrap().default_sequences(&db).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn default_sequences<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Vec<&'db <Self::DB as DatabaseLike>::Sequence>
+    where
+        Self: 'db,
+    {
+        let mut sequences = Vec::new();
+        for sequence in
+            self.columns(database).filter_map(|column| column.default_sequence(database))
+        {
+            if !sequences.contains(&sequence) {
+                sequences.push(sequence);
+            }
+        }
+        sequences
+    }
+
+    /// Iterates over the sequences owned by a column of the table, which are
+    /// dropped along with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE SEQUENCE users_id_seq OWNED BY users.id;
+    /// CREATE SEQUENCE free_seq;
+    /// ",
+    /// )?;
+    /// let users = db.table(None, "users").unwrap();
+    /// let sequences: Vec<&str> = users.owned_sequences(&db).map(SequenceLike::name).collect();
+    /// assert_eq!(sequences, ["users_id_seq"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn owned_sequences<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Sequence>
+    where
+        Self: 'db,
+    {
+        database.sequences().filter(move |sequence| {
+            sequence.owner_table(database).is_some_and(|table| table == self.borrow())
+        })
+    }
+
     /// Returns the most recent common ancestor table between the current table
     /// and all of the provided tables, if any.
     ///
@@ -2564,13 +3118,56 @@ pub trait TableLike:
     where
         Self: 'db,
     {
-        database.policies().filter(move |policy| policy.table(database).borrow() == self.borrow())
+        database.policies_on(self.borrow())
+    }
+
+    /// Returns the report of the commands and roles covered by the row level
+    /// security policies of the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to query the
+    ///   policies and roles from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::{ast::CreatePolicyCommand, dialect::PostgreSqlDialect};
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE ROLE writer;
+    /// CREATE TABLE documents (id INT);
+    /// ALTER TABLE documents ENABLE ROW LEVEL SECURITY;
+    /// CREATE POLICY read_documents ON documents FOR SELECT USING (true);
+    /// CREATE POLICY write_documents ON documents FOR INSERT TO writer WITH CHECK (true);
+    /// ",
+    /// )?;
+    /// let coverage = db.table(None, "documents").unwrap().policy_coverage(&db);
+    /// assert!(coverage.has_gaps());
+    /// let uncovered: Vec<CreatePolicyCommand> = coverage.uncovered_commands().collect();
+    /// assert_eq!(uncovered, [CreatePolicyCommand::Update, CreatePolicyCommand::Delete]);
+    /// let readers_only: Vec<&str> =
+    ///     coverage.uncovered_roles(CreatePolicyCommand::Insert).map(|role| role.name()).collect();
+    /// assert_eq!(readers_only, ["reader"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn policy_coverage<'db>(&'db self, database: &'db Self::DB) -> PolicyCoverage<'db, Self::DB>
+    where
+        Self: 'db,
+    {
+        PolicyCoverage::new(database, self.borrow())
     }
 
     /// Returns an iterator over the grants that apply to this table.
     ///
     /// This includes both direct table grants (`GRANT ... ON table_name`)
-    /// and schema-wide grants (`GRANT ... ON ALL TABLES IN SCHEMA`).
+    /// and schema-wide grants (`GRANT ... ON ALL TABLES IN SCHEMA`), but not
+    /// the column grants, which only apply to some of its columns.
     ///
     /// # Example
     ///
@@ -2607,6 +3204,42 @@ pub trait TableLike:
         database.table_grants().filter(move |grant| grant.applies_to_table(self.borrow(), database))
     }
 
+    /// Returns an iterator over the default privileges granted on this table
+    /// when it was created, as declared by the `ALTER DEFAULT PRIVILEGES`
+    /// statements preceding its creation.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the table
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE ROLE reader;
+    /// CREATE TABLE before (id INT);
+    /// ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT ON TABLES TO reader;
+    /// CREATE TABLE after (id INT);
+    /// ",
+    /// )?;
+    /// assert_eq!(db.table(None, "before").unwrap().default_privileges(&db).count(), 0);
+    /// assert_eq!(db.table(None, "after").unwrap().default_privileges(&db).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn default_privileges<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::DefaultPrivilege>
+    where
+        Self: 'db;
+
     /// Returns whether the given role can read (SELECT) from this table.
     ///
     /// A role can read if there's a grant that:
@@ -2642,7 +3275,7 @@ pub trait TableLike:
     fn can_select(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB) -> bool {
         use sqlparser::ast::Action;
         self.grants(database).any(|grant| {
-            grant.applies_to_role(role)
+            grant.applies_to_role(role, database)
                 && (grant.is_all_privileges()
                     || grant.privileges(database).any(|p| matches!(p, Action::Select { .. })))
         })
@@ -2674,7 +3307,7 @@ pub trait TableLike:
     fn can_insert(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB) -> bool {
         use sqlparser::ast::Action;
         self.grants(database).any(|grant| {
-            grant.applies_to_role(role)
+            grant.applies_to_role(role, database)
                 && (grant.is_all_privileges()
                     || grant.privileges(database).any(|p| matches!(p, Action::Insert { .. })))
         })
@@ -2706,7 +3339,7 @@ pub trait TableLike:
     fn can_update(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB) -> bool {
         use sqlparser::ast::Action;
         self.grants(database).any(|grant| {
-            grant.applies_to_role(role)
+            grant.applies_to_role(role, database)
                 && (grant.is_all_privileges()
                     || grant.privileges(database).any(|p| matches!(p, Action::Update { .. })))
         })
@@ -2738,7 +3371,7 @@ pub trait TableLike:
     fn can_delete(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB) -> bool {
         use sqlparser::ast::Action;
         self.grants(database).any(|grant| {
-            grant.applies_to_role(role)
+            grant.applies_to_role(role, database)
                 && (grant.is_all_privileges()
                     || grant.privileges(database).any(|p| matches!(p, Action::Delete)))
         })
@@ -2807,7 +3440,7 @@ pub trait TableLike:
     fn can_truncate(&self, role: &<Self::DB as DatabaseLike>::Role, database: &Self::DB) -> bool {
         use sqlparser::ast::Action;
         self.grants(database).any(|grant| {
-            grant.applies_to_role(role)
+            grant.applies_to_role(role, database)
                 && (grant.is_all_privileges()
                     || grant.privileges(database).any(|p| matches!(p, Action::Truncate)))
         })
@@ -2828,6 +3461,10 @@ where
         T::table_name_is_quoted(self)
     }
 
+    fn table_options(&self) -> Vec<(String, String)> {
+        T::table_options(self)
+    }
+
     fn table_doc<'db>(&'db self, database: &'db Self::DB) -> Option<&'db str>
     where
         Self: 'db,
@@ -2835,6 +3472,13 @@ where
         T::table_doc(self, database)
     }
 
+    fn source_location<'db>(&'db self, database: &'db Self::DB) -> Option<&'db SourceLocation>
+    where
+        Self: 'db,
+    {
+        T::source_location(self, database)
+    }
+
     fn table_schema(&self) -> Option<&str> {
         T::table_schema(self)
     }
@@ -2876,6 +3520,16 @@ where
         T::has_forced_row_level_security(self, database)
     }
 
+    fn primary_key<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Option<&'db <Self::DB as DatabaseLike>::PrimaryKey>
+    where
+        Self: 'db,
+    {
+        T::primary_key(self, database)
+    }
+
     fn primary_key_columns<'db>(
         &'db self,
         database: &'db Self::DB,
@@ -2925,6 +3579,26 @@ where
     {
         T::foreign_keys(self, database)
     }
+
+    fn referencing_foreign_keys<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::ForeignKey>
+    where
+        Self: 'db,
+    {
+        T::referencing_foreign_keys(self, database)
+    }
+
+    fn default_privileges<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::DefaultPrivilege>
+    where
+        Self: 'db,
+    {
+        T::default_privileges(self, database)
+    }
 }
 
 #[cfg(test)]
@@ -1,13 +1,78 @@
 //! Submodule providing a trait for describing SQL Trigger-like entities.
 
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::{fmt::Debug, ops::ControlFlow};
+
+use sqlparser::ast::{Expr, Ident, ObjectNamePart, Statement, visit_expressions};
 
 use crate::{
-    traits::{DatabaseLike, FunctionLike, Metadata},
-    utils::maintenance_trigger_parser::parse_maintenance_body,
+    errors::{Error, LookupError},
+    traits::{
+        ColumnLike, DMLLike, DatabaseLike, DialectLike, DmlKind, DmlStatement, FunctionLike,
+        Metadata, TableLike,
+    },
+    utils::maintenance_trigger_parser::{
+        is_changed_row_condition, parse_maintenance_body, parse_maintenance_kinds,
+    },
 };
 
+/// Returns whether the identifier designates the `OLD` or `NEW` row.
+fn is_row_record(ident: &Ident) -> bool {
+    ident.quote_style.is_none()
+        && (ident.value.eq_ignore_ascii_case("OLD") || ident.value.eq_ignore_ascii_case("NEW"))
+}
+
+/// A benign pattern recognized in a maintenance trigger, as reported by
+/// [`TriggerLike::maintenance_kinds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MaintenanceKind {
+    /// An assignment of a column of the new row, as in
+    /// `NEW.updated_at = now()`.
+    Assignment,
+    /// An assignment filling a column of the new row with a default, as in
+    /// `NEW.column = COALESCE(NEW.column, default)`.
+    CoalesceDefault,
+    /// Assignments only run for an operation, as in
+    /// `IF TG_OP = 'UPDATE' THEN ... END IF;`.
+    OperationGuard(DmlKind),
+    /// A trigger condition only firing the trigger when the row changes, as
+    /// in `WHEN (OLD.* IS DISTINCT FROM NEW.*)`.
+    ChangedRowCondition,
+}
+
+/// Returns the tables which the function of the trigger writes to, along
+/// with the kind of statement writing to them, without duplicates.
+///
+/// Writes to relations which do not resolve to a single table of the
+/// database, such as temporary tables, are skipped.
+pub(crate) fn trigger_writes<'db, T: TriggerLike>(
+    trigger: &'db T,
+    database: &'db T::DB,
+) -> Result<Vec<(&'db <T::DB as DatabaseLike>::Table, DmlKind)>, Error> {
+    let Some(function) = trigger.function(database) else {
+        return Ok(Vec::new());
+    };
+    let mut writes = Vec::new();
+    for statement in function.parsed_body(database)? {
+        let (target, kind) = match &statement {
+            Statement::Insert(insert) => (insert.target_table(database), insert.kind()),
+            Statement::Update(update) => (update.target_table(database), update.kind()),
+            Statement::Delete(delete) => (delete.target_table(database), delete.kind()),
+            _ => continue,
+        };
+        match target {
+            Ok(table) => {
+                if !writes.contains(&(table, kind)) {
+                    writes.push((table, kind));
+                }
+            }
+            Err(LookupError::TableNotFound { .. } | LookupError::InvalidObjectName { .. }) => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+    Ok(writes)
+}
+
 /// A trait for types that can be treated as SQL triggers.
 pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     /// The database type the trigger belongs to.
@@ -64,6 +129,14 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     /// ```
     fn name(&self) -> &str;
 
+    /// Returns whether the trigger identifier was quoted in SQL.
+    ///
+    /// Quoted identifiers are resolved case-sensitively in PostgreSQL.
+    #[inline]
+    fn name_is_quoted(&self) -> bool {
+        false
+    }
+
     /// Returns the table the trigger is associated with.
     ///
     /// # Arguments
@@ -124,6 +197,55 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     where
         Self: 'db;
 
+    /// Returns the name of the table the trigger is defined on, as written in
+    /// the trigger definition.
+    ///
+    /// Like [`Self::function_name`], this does not require a database
+    /// reference, so that triggers can be keyed by their table while the
+    /// schema is still being constructed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE my_table (id INT);
+    /// CREATE FUNCTION my_function() RETURNS TRIGGER AS $$ BEGIN END; $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER my_trigger AFTER INSERT ON my_table FOR EACH ROW EXECUTE FUNCTION my_function();
+    /// ",
+    /// )?;
+    /// let trigger = db.triggers().next().unwrap();
+    /// assert_eq!(trigger.table_name(), "my_table");
+    /// assert_eq!(trigger.table_schema(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn table_name(&self) -> &str;
+
+    /// Returns whether the name of the table the trigger is defined on was
+    /// quoted in the trigger definition.
+    #[inline]
+    fn table_name_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns the schema of the table the trigger is defined on, if the
+    /// trigger definition qualifies the table name.
+    #[inline]
+    fn table_schema(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns whether the schema of the table the trigger is defined on was
+    /// quoted in the trigger definition.
+    #[inline]
+    fn table_schema_is_quoted(&self) -> bool {
+        false
+    }
+
     /// Returns the events that fire the trigger.
     ///
     /// # Example
@@ -288,6 +410,111 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     /// ```
     fn orientation(&self) -> Option<sqlparser::ast::TriggerObjectKind>;
 
+    /// Returns the `WHEN` condition of the trigger, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE my_table (id INT);
+    /// CREATE FUNCTION my_function() RETURNS TRIGGER AS $$ BEGIN END; $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER my_trigger
+    /// AFTER UPDATE ON my_table
+    /// FOR EACH ROW
+    /// WHEN (OLD.id <> NEW.id)
+    /// EXECUTE FUNCTION my_function();
+    /// ",
+    /// )?;
+    /// let trigger = db.triggers().next().unwrap();
+    /// assert_eq!(trigger.condition().unwrap().to_string(), "(OLD.id <> NEW.id)");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn condition(&self) -> Option<&Expr>;
+
+    /// Returns the columns of the table of the trigger referenced by its
+    /// `WHEN` condition, in the order of the table.
+    ///
+    /// Columns are referenced through the `OLD` and `NEW` rows, and a
+    /// reference to a whole row, as in `OLD.* IS DISTINCT FROM NEW.*`,
+    /// references every column of the table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE accounts (id INT, balance INT, note TEXT);
+    /// CREATE FUNCTION audit() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER audit_balance
+    /// AFTER UPDATE ON accounts
+    /// FOR EACH ROW
+    /// WHEN (OLD.balance IS DISTINCT FROM NEW.balance AND NEW.id > 0)
+    /// EXECUTE FUNCTION audit();
+    /// ",
+    /// )?;
+    /// let trigger = db.triggers().next().unwrap();
+    /// let columns: Vec<&str> =
+    ///     trigger.condition_columns(&db).into_iter().map(ColumnLike::column_name).collect();
+    /// assert_eq!(columns, ["id", "balance"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn condition_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Vec<&'db <Self::DB as DatabaseLike>::Column> {
+        let Some(condition) = self.condition() else {
+            return Vec::new();
+        };
+        let mut whole_row = false;
+        let mut referenced: Vec<&Ident> = Vec::new();
+        let _ = visit_expressions(condition, |expr| {
+            match expr {
+                Expr::Identifier(ident) => whole_row |= is_row_record(ident),
+                Expr::QualifiedWildcard(name, _) => {
+                    whole_row |= matches!(
+                        name.0.as_slice(),
+                        [ObjectNamePart::Identifier(record)] if is_row_record(record)
+                    );
+                }
+                Expr::CompoundIdentifier(idents) => {
+                    if let [record, column] = idents.as_slice()
+                        && is_row_record(record)
+                    {
+                        referenced.push(column);
+                    }
+                }
+                _ => {}
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        let casing = database.dialect().identifier_casing();
+        self.table(database)
+            .columns(database)
+            .filter(|column| {
+                whole_row
+                    || referenced.iter().any(|ident| {
+                        casing.matches(
+                            column.column_name(),
+                            column.column_name_is_quoted(),
+                            &ident.value,
+                            ident.quote_style.is_some(),
+                        )
+                    })
+            })
+            .collect()
+    }
+
     /// Returns the function the trigger executes.
     ///
     /// # Arguments
@@ -374,7 +601,9 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     ///
     /// A maintenance trigger is defined as a trigger that solely consists of
     /// updating values in `NEW.{column_name} = ...` and concludes by
-    /// returning `NEW`.
+    /// returning `NEW`. The assignments may be guarded by an
+    /// `IF TG_OP = '...' THEN` block, and the trigger may only fire when the
+    /// row changes, as detailed by [`MaintenanceKind`].
     ///
     /// # Example
     ///
@@ -426,17 +655,62 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
     /// ```
     #[inline]
     fn is_maintenance_trigger<'db>(&'db self, database: &'db Self::DB) -> bool {
-        let Some(function) = self.function(database) else {
-            return false;
+        !self.maintenance_kinds(database).is_empty()
+    }
+
+    /// Returns the benign patterns matched by a maintenance trigger, in
+    /// first-seen order and without duplicates, or an empty vector when the
+    /// trigger is not a maintenance trigger.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE brands (id INT, name TEXT, edited_at TIMESTAMP);
+    /// CREATE FUNCTION touch_brands() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     NEW.name = COALESCE(NEW.name, 'unnamed');
+    ///     IF TG_OP = 'UPDATE' THEN
+    ///         NEW.edited_at := now();
+    ///     END IF;
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER touch_brands
+    /// BEFORE INSERT OR UPDATE ON brands
+    /// FOR EACH ROW
+    /// EXECUTE FUNCTION touch_brands();
+    /// ",
+    /// )?;
+    /// let trigger = db.triggers().next().unwrap();
+    /// assert_eq!(
+    ///     trigger.maintenance_kinds(&db),
+    ///     [
+    ///         MaintenanceKind::CoalesceDefault,
+    ///         MaintenanceKind::OperationGuard(DmlKind::Update),
+    ///         MaintenanceKind::Assignment,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn maintenance_kinds<'db>(&'db self, database: &'db Self::DB) -> Vec<MaintenanceKind> {
+        let condition = self.condition();
+        if condition.is_some_and(|condition| !is_changed_row_condition(condition)) {
+            return Vec::new();
+        }
+        let Some(body) = self.function(database).and_then(FunctionLike::body) else {
+            return Vec::new();
         };
-        let Some(body) = function.body() else {
-            return false;
+        let Ok(kinds) = parse_maintenance_kinds(body, self.table(database), database) else {
+            return Vec::new();
         };
-        let table = self.table(database);
-
-        let result = parse_maintenance_body(body, table, database);
-
-        result.is_ok()
+        condition.map(|_| MaintenanceKind::ChangedRowCondition).into_iter().chain(kinds).collect()
     }
 
     /// Returns the assignments in a maintenance trigger.
@@ -484,7 +758,8 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
         &'db self,
         database: &'db Self::DB,
     ) -> impl Iterator<Item = (&'db <Self::DB as DatabaseLike>::Column, sqlparser::ast::Expr)> {
-        if let Some(function) = self.function(database)
+        if self.condition().is_none_or(is_changed_row_condition)
+            && let Some(function) = self.function(database)
             && let Some(body) = function.body()
         {
             let table = self.table(database);
@@ -494,6 +769,59 @@ pub trait TriggerLike: Clone + Debug + Metadata + Send + Sync {
         }
         .into_iter()
     }
+
+    /// Returns the tables which the function of the trigger inserts into,
+    /// updates or deletes from, in first-seen order and without duplicates.
+    ///
+    /// The writes may fire the triggers of the written tables in turn, as
+    /// analyzed by [`DatabaseLike::trigger_cascade_graph`]. Triggers which do
+    /// not execute a function, such as SQLite triggers, write to no table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body of the function cannot be parsed, or if
+    /// one of the written relations is ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE orders (id INT, total INT);
+    /// CREATE TABLE order_log (order_id INT);
+    /// CREATE FUNCTION log_order() RETURNS TRIGGER AS $$
+    /// BEGIN
+    ///     INSERT INTO order_log (order_id) VALUES (NEW.id);
+    ///     RETURN NEW;
+    /// END;
+    /// $$ LANGUAGE plpgsql;
+    /// CREATE TRIGGER log_orders AFTER INSERT ON orders
+    /// FOR EACH ROW EXECUTE FUNCTION log_order();
+    /// ",
+    /// )?;
+    /// let trigger = db.triggers().next().unwrap();
+    /// let tables: Vec<&str> =
+    ///     trigger.writes_to_tables(&db)?.into_iter().map(TableLike::table_name).collect();
+    /// assert_eq!(tables, ["order_log"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn writes_to_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Result<Vec<&'db <Self::DB as DatabaseLike>::Table>, Error> {
+        let mut tables: Vec<&'db <Self::DB as DatabaseLike>::Table> = Vec::new();
+        for (table, _) in trigger_writes(self, database)? {
+            if !tables.contains(&table) {
+                tables.push(table);
+            }
+        }
+        Ok(tables)
+    }
 }
 
 impl<T: TriggerLike> TriggerLike for &T {
@@ -503,6 +831,10 @@ impl<T: TriggerLike> TriggerLike for &T {
         (*self).name()
     }
 
+    fn name_is_quoted(&self) -> bool {
+        (*self).name_is_quoted()
+    }
+
     fn table<'db>(&'db self, database: &'db Self::DB) -> &'db <Self::DB as DatabaseLike>::Table
     where
         Self: 'db,
@@ -510,6 +842,22 @@ impl<T: TriggerLike> TriggerLike for &T {
         (*self).table(database)
     }
 
+    fn table_name(&self) -> &str {
+        (*self).table_name()
+    }
+
+    fn table_name_is_quoted(&self) -> bool {
+        (*self).table_name_is_quoted()
+    }
+
+    fn table_schema(&self) -> Option<&str> {
+        (*self).table_schema()
+    }
+
+    fn table_schema_is_quoted(&self) -> bool {
+        (*self).table_schema_is_quoted()
+    }
+
     fn events(&self) -> &[sqlparser::ast::TriggerEvent] {
         (*self).events()
     }
@@ -522,6 +870,17 @@ impl<T: TriggerLike> TriggerLike for &T {
         (*self).orientation()
     }
 
+    fn condition(&self) -> Option<&Expr> {
+        (*self).condition()
+    }
+
+    fn condition_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Vec<&'db <Self::DB as DatabaseLike>::Column> {
+        (*self).condition_columns(database)
+    }
+
     fn function<'db>(
         &'db self,
         database: &'db Self::DB,
@@ -544,12 +903,23 @@ impl<T: TriggerLike> TriggerLike for &T {
         (*self).is_maintenance_trigger(database)
     }
 
+    fn maintenance_kinds<'db>(&'db self, database: &'db Self::DB) -> Vec<MaintenanceKind> {
+        (*self).maintenance_kinds(database)
+    }
+
     fn maintenance_assignments<'db>(
         &'db self,
         database: &'db Self::DB,
     ) -> impl Iterator<Item = (&'db <Self::DB as DatabaseLike>::Column, sqlparser::ast::Expr)> {
         (*self).maintenance_assignments(database)
     }
+
+    fn writes_to_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> Result<Vec<&'db <Self::DB as DatabaseLike>::Table>, Error> {
+        (*self).writes_to_tables(database)
+    }
 }
 
 #[cfg(test)]
@@ -669,5 +1039,117 @@ mod tests {
 
         assert!(!trigger_ref.is_maintenance_trigger(&db));
         assert_eq!(trigger_ref.maintenance_assignments(&db).count(), 0);
+        assert!(trigger_ref.writes_to_tables(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trigger_writes_to_tables() {
+        let sql = r"
+            CREATE TABLE orders (id INT, total INT);
+            CREATE TABLE order_log (order_id INT);
+            CREATE TABLE totals (total INT);
+            CREATE FUNCTION track_order() RETURNS TRIGGER AS $$
+            BEGIN
+                INSERT INTO order_log (order_id) VALUES (NEW.id);
+                IF NEW.total > 0 THEN
+                    UPDATE totals SET total = total + NEW.total;
+                END IF;
+                INSERT INTO order_log (order_id) VALUES (OLD.id);
+                DELETE FROM scratch;
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE TRIGGER track_orders
+            AFTER INSERT ON orders
+            FOR EACH ROW
+            EXECUTE FUNCTION track_order();
+        ";
+
+        let db = ParserDB::parse::<GenericDialect>(sql).expect("Failed to parse SQL");
+        let trigger = db.triggers().next().expect("No trigger found");
+        let tables: Vec<&str> =
+            trigger.writes_to_tables(&db).unwrap().into_iter().map(TableLike::table_name).collect();
+        assert_eq!(tables, ["order_log", "totals"]);
+
+        let writes = trigger_writes(trigger, &db).unwrap();
+        assert_eq!(writes.len(), 2);
+        assert_eq!(writes[1].1, DmlKind::Update);
+    }
+
+    #[test]
+    fn test_maintenance_trigger_conditions() {
+        use sqlparser::dialect::PostgreSqlDialect;
+
+        let trigger_sql = |condition: &str| {
+            format!(
+                "
+                CREATE TABLE users (id INT, updated_at TIMESTAMP);
+                CREATE FUNCTION touch() RETURNS TRIGGER AS $$
+                BEGIN
+                    NEW.updated_at := now();
+                    RETURN NEW;
+                END;
+                $$ LANGUAGE plpgsql;
+                CREATE TRIGGER touch_users
+                BEFORE UPDATE ON users
+                FOR EACH ROW
+                {condition}
+                EXECUTE FUNCTION touch();
+                "
+            )
+        };
+
+        let db = ParserDB::parse::<PostgreSqlDialect>(&trigger_sql(
+            "WHEN (OLD.* IS DISTINCT FROM NEW.*)",
+        ))
+        .expect("Failed to parse SQL");
+        let trigger = db.triggers().next().expect("No trigger found");
+        assert!(trigger.is_maintenance_trigger(&db));
+        assert_eq!(
+            trigger.maintenance_kinds(&db),
+            [MaintenanceKind::ChangedRowCondition, MaintenanceKind::Assignment]
+        );
+        assert_eq!(trigger.maintenance_assignments(&db).count(), 1);
+
+        let db = ParserDB::parse::<PostgreSqlDialect>(&trigger_sql("WHEN (NEW.id > 10)"))
+            .expect("Failed to parse SQL");
+        let trigger = db.triggers().next().expect("No trigger found");
+        assert!(!trigger.is_maintenance_trigger(&db));
+        assert!(trigger.maintenance_kinds(&db).is_empty());
+        assert_eq!(trigger.maintenance_assignments(&db).count(), 0);
+    }
+
+    #[test]
+    fn test_condition_columns() {
+        use sqlparser::dialect::PostgreSqlDialect;
+
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            r#"
+            CREATE TABLE accounts (id INT, "Balance" INT, note TEXT);
+            CREATE FUNCTION audit() RETURNS TRIGGER AS $$ BEGIN RETURN NEW; END; $$
+            LANGUAGE plpgsql;
+            CREATE TRIGGER audit_balance AFTER UPDATE ON accounts FOR EACH ROW
+            WHEN (OLD."Balance" <> NEW."Balance" OR OLD.balance IS NULL)
+            EXECUTE FUNCTION audit();
+            CREATE TRIGGER audit_rows AFTER UPDATE ON accounts FOR EACH ROW
+            WHEN (OLD.* IS DISTINCT FROM NEW.*)
+            EXECUTE FUNCTION audit();
+            CREATE TRIGGER audit_all AFTER UPDATE ON accounts FOR EACH ROW
+            EXECUTE FUNCTION audit();
+            "#,
+        )
+        .expect("Failed to parse SQL");
+        let columns = |name: &str| {
+            let trigger = db.triggers().find(|trigger| trigger.name() == name).unwrap();
+            trigger
+                .condition_columns(&db)
+                .into_iter()
+                .map(ColumnLike::column_name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(columns("audit_balance"), ["Balance"]);
+        assert_eq!(columns("audit_rows"), ["id", "Balance", "note"]);
+        assert!(columns("audit_all").is_empty());
     }
 }
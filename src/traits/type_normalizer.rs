@@ -0,0 +1,47 @@
+//! Submodule defining the `TypeNormalizer` trait, mapping the data types
+//! declared under a SQL dialect to a canonical vocabulary shared by every
+//! dialect.
+
+use core::fmt::Debug;
+
+/// Trait for the dialect-specific mappings of declared SQL data types to
+/// canonical data types.
+///
+/// The canonical data types are the upper-case names `BOOLEAN`,
+/// `SMALLINT`, `INT`, `BIGINT`, `REAL`, `DOUBLE PRECISION`, `NUMERIC`,
+/// `VARCHAR`, `CHAR`, `TEXT`, `BLOB`, `DATE`, `TIME`,
+/// `TIME WITH TIME ZONE`, `TIMESTAMP`, `TIMESTAMP WITH TIME ZONE`,
+/// `INTERVAL`, `UUID` and `JSON`, so that columns declared as `TINYINT(1)`
+/// under MySQL and as `BOOL` under `PostgreSQL` both read as `BOOLEAN`.
+///
+/// The normalizer of a dialect is selected by
+/// [`DialectLike::type_normalizer`](crate::traits::DialectLike::type_normalizer),
+/// and applied to the columns by
+/// [`ColumnLike::normalized_type`](crate::traits::ColumnLike::normalized_type).
+///
+/// # Example
+///
+/// ```rust
+/// use sql_traits::prelude::*;
+///
+/// assert_eq!(MySqlTypeNormalizer.canonical_type("TINYINT(1)"), Some("BOOLEAN"));
+/// assert_eq!(PostgresTypeNormalizer.canonical_type("int8"), Some("BIGINT"));
+/// assert_eq!(SqliteTypeNormalizer.canonical_type("VARYING CHARACTER(70)"), Some("TEXT"));
+/// assert_eq!(PostgresTypeNormalizer.canonical_type("geometry"), None);
+/// ```
+pub trait TypeNormalizer: Debug + Send + Sync {
+    /// Returns the canonical data type of the provided declared data type,
+    /// if it is known to the normalizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - The data type as declared, including its arguments,
+    ///   as in `TINYINT(1)` or `VARCHAR(255)`.
+    fn canonical_type(&self, data_type: &str) -> Option<&'static str>;
+}
+
+impl<N: TypeNormalizer + ?Sized> TypeNormalizer for &N {
+    fn canonical_type(&self, data_type: &str) -> Option<&'static str> {
+        (**self).canonical_type(data_type)
+    }
+}
@@ -0,0 +1,283 @@
+//! Submodule providing a trait for describing SQL View-like entities.
+
+use core::fmt::Debug;
+
+use crate::traits::{DatabaseLike, Metadata};
+
+/// A trait for types that can be treated as SQL views.
+///
+/// Views are named queries over the tables of the database. Materialized
+/// views additionally store the result of their query.
+pub trait ViewLike: Debug + Clone + Ord + Eq + Metadata + Send + Sync {
+    /// The database type the view belongs to.
+    type DB: DatabaseLike;
+
+    /// Returns the name of the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT); CREATE VIEW active_users AS SELECT id FROM users;",
+    /// )?;
+    /// let view = db.view(None, "active_users").unwrap();
+    /// assert_eq!(view.view_name(), "active_users");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn view_name(&self) -> &str;
+
+    /// Returns whether the view identifier was quoted in SQL.
+    ///
+    /// Quoted identifiers are resolved case-sensitively in PostgreSQL.
+    #[inline]
+    fn view_name_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns the name of the schema of the view, if specified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE users (id INT); CREATE VIEW app.user_ids AS SELECT id FROM users;",
+    /// )?;
+    /// let view = db.view(Some("app"), "user_ids").unwrap();
+    /// assert_eq!(view.view_schema(), Some("app"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn view_schema(&self) -> Option<&str>;
+
+    /// Returns whether the schema identifier of the view was quoted in SQL.
+    #[inline]
+    fn view_schema_is_quoted(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the view is materialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::PostgreSqlDialect;
+    ///
+    /// let db = ParserDB::parse::<PostgreSqlDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE VIEW user_ids AS SELECT id FROM users;
+    /// CREATE MATERIALIZED VIEW user_count AS SELECT count(id) FROM users;
+    /// ",
+    /// )?;
+    /// assert!(!db.view(None, "user_ids").unwrap().is_materialized());
+    /// assert!(db.view(None, "user_count").unwrap().is_materialized());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_materialized(&self) -> bool;
+
+    /// Returns an iterator over the tables the query of the view selects
+    /// from, including the tables of its subqueries and common table
+    /// expressions.
+    ///
+    /// Relations which do not resolve to a table of the database, such as
+    /// common table expressions or other views, are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the view
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, name TEXT);
+    /// CREATE TABLE orders (id INT, user_id INT);
+    /// CREATE VIEW user_orders AS
+    ///     SELECT users.name, orders.id FROM users JOIN orders ON orders.user_id = users.id;
+    /// ",
+    /// )?;
+    /// let view = db.view(None, "user_orders").unwrap();
+    /// let tables: Vec<&str> = view.referenced_tables(&db).map(TableLike::table_name).collect();
+    /// assert_eq!(tables, ["users", "orders"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn referenced_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db;
+
+    /// Returns an iterator over the columns of the referenced tables which
+    /// the query of the view uses.
+    ///
+    /// A column is considered used when the query mentions its name, or when
+    /// the query projects a wildcard, in which case every column of the
+    /// referenced tables is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the view
+    ///   belongs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT, name TEXT, secret TEXT);
+    /// CREATE VIEW public_users AS SELECT id, name FROM users WHERE id > 0;
+    /// ",
+    /// )?;
+    /// let view = db.view(None, "public_users").unwrap();
+    /// let columns: Vec<&str> =
+    ///     view.referenced_columns(&db).map(ColumnLike::column_name).collect();
+    /// assert_eq!(columns, ["id", "name"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn referenced_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db;
+
+    /// Returns whether the view selects from the provided table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - A reference to the database instance to which the view
+    ///   belongs.
+    /// * `table` - The table to check.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "
+    /// CREATE TABLE users (id INT);
+    /// CREATE TABLE logs (id INT);
+    /// CREATE VIEW user_ids AS SELECT id FROM users;
+    /// ",
+    /// )?;
+    /// let view = db.view(None, "user_ids").unwrap();
+    /// assert!(view.depends_on(&db, db.table(None, "users").unwrap()));
+    /// assert!(!view.depends_on(&db, db.table(None, "logs").unwrap()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn depends_on(&self, database: &Self::DB, table: &<Self::DB as DatabaseLike>::Table) -> bool {
+        self.referenced_tables(database).any(|referenced| referenced == table)
+    }
+}
+
+impl<V: ViewLike> ViewLike for &V {
+    type DB = V::DB;
+
+    fn view_name(&self) -> &str {
+        (*self).view_name()
+    }
+
+    fn view_name_is_quoted(&self) -> bool {
+        (*self).view_name_is_quoted()
+    }
+
+    fn view_schema(&self) -> Option<&str> {
+        (*self).view_schema()
+    }
+
+    fn view_schema_is_quoted(&self) -> bool {
+        (*self).view_schema_is_quoted()
+    }
+
+    fn is_materialized(&self) -> bool {
+        (*self).is_materialized()
+    }
+
+    fn referenced_tables<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Table>
+    where
+        Self: 'db,
+    {
+        (*self).referenced_tables(database)
+    }
+
+    fn referenced_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column>
+    where
+        Self: 'db,
+    {
+        (*self).referenced_columns(database)
+    }
+
+    fn depends_on(&self, database: &Self::DB, table: &<Self::DB as DatabaseLike>::Table) -> bool {
+        (*self).depends_on(database, table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+    use crate::{
+        structs::ParserDB,
+        traits::{ColumnLike, TableLike},
+    };
+
+    /// Exercises the `impl ViewLike for &V` blanket forwarding.
+    #[test]
+    fn test_reference_impl() {
+        let db = ParserDB::parse::<PostgreSqlDialect>(
+            "
+            CREATE TABLE users (id INT, name TEXT);
+            CREATE MATERIALIZED VIEW app.\"UserNames\" AS SELECT name FROM users;
+            ",
+        )
+        .unwrap();
+        let view = db.view(Some("app"), "\"UserNames\"").expect("view");
+        let users = db.table(None, "users").unwrap();
+
+        let v_ref: &<ParserDB as DatabaseLike>::View = view;
+        assert_eq!(<&_ as ViewLike>::view_name(&v_ref), "UserNames");
+        assert!(<&_ as ViewLike>::view_name_is_quoted(&v_ref));
+        assert_eq!(<&_ as ViewLike>::view_schema(&v_ref), Some("app"));
+        assert!(!<&_ as ViewLike>::view_schema_is_quoted(&v_ref));
+        assert!(<&_ as ViewLike>::is_materialized(&v_ref));
+        let tables: Vec<&str> =
+            <&_ as ViewLike>::referenced_tables(&v_ref, &db).map(TableLike::table_name).collect();
+        assert_eq!(tables, ["users"]);
+        let columns: Vec<&str> = <&_ as ViewLike>::referenced_columns(&v_ref, &db)
+            .map(ColumnLike::column_name)
+            .collect();
+        assert_eq!(columns, ["name"]);
+        assert!(<&_ as ViewLike>::depends_on(&v_ref, &db, users));
+    }
+}
@@ -4,13 +4,28 @@ mod normalize_postgres_type;
 pub use normalize_postgres_type::normalize_postgres_type;
 mod normalize_sqlparser_type;
 pub use normalize_sqlparser_type::normalize_sqlparser_type;
+pub(crate) use normalize_sqlparser_type::try_normalize_sqlparser_type;
 pub mod columns_in_expression;
 pub use columns_in_expression::columns_in_expression;
 mod last_str;
 pub use last_str::last_str;
+mod byte_offset;
+pub(crate) use byte_offset::byte_offset;
 mod common_snake_affix;
 pub use common_snake_affix::{common_column_name_snake_prefix, common_column_name_snake_suffix};
+pub(crate) mod allowed_values;
+pub(crate) mod check_implication;
+pub(crate) mod data_type_aliases;
 pub mod fingerprint_type_token;
+pub(crate) mod function_body;
+pub(crate) mod glob_match;
+pub(crate) mod graph;
 pub mod identifier_resolution;
 pub mod maintenance_trigger_parser;
+pub(crate) mod nextval;
 pub(crate) mod object_name;
+pub(crate) mod pattern;
+pub(crate) mod query_identifiers;
+pub(crate) mod rust_keywords;
+pub mod typing;
+pub use typing::infer_type;
@@ -0,0 +1,175 @@
+//! Extraction of the values a check constraint allows in a column, as in
+//! `CHECK (status IN ('draft', 'published'))`.
+
+use alloc::{format, vec::Vec};
+
+use sqlparser::ast::{BinaryOperator, Expr, UnaryOperator, Value};
+
+use crate::{structs::IdentifierCasing, traits::ColumnLike};
+
+/// Returns whether the expression is the provided column, comparing their
+/// names through the provided casing.
+pub(crate) fn is_column<C: ColumnLike>(expr: &Expr, column: &C, casing: IdentifierCasing) -> bool {
+    match expr {
+        Expr::Identifier(ident) => casing.matches(
+            column.column_name(),
+            column.column_name_is_quoted(),
+            &ident.value,
+            ident.quote_style.is_some(),
+        ),
+        Expr::Nested(inner) | Expr::Cast { expr: inner, .. } => is_column(inner, column, casing),
+        _ => false,
+    }
+}
+
+/// Returns the value of a literal expression, looking through casts such as
+/// `'draft'::text`.
+pub(crate) fn literal(expr: &Expr) -> Option<Value> {
+    match expr {
+        Expr::Value(value) if !matches!(value.value, Value::Null | Value::Placeholder(_)) => {
+            Some(value.value.clone())
+        }
+        Expr::UnaryOp { op: UnaryOperator::Minus, expr } => match literal(expr)? {
+            Value::Number(number, long) => Some(Value::Number(format!("-{number}"), long)),
+            _ => None,
+        },
+        Expr::Nested(inner) | Expr::Cast { expr: inner, .. } => literal(inner),
+        _ => None,
+    }
+}
+
+/// Returns the literals of a list of expressions, or `None` if one of them
+/// is not a literal.
+fn literals(list: &[Expr]) -> Option<Vec<Value>> {
+    list.iter().map(literal).collect()
+}
+
+/// Returns the values the expression allows in the column, without
+/// duplicates, or `None` when it allows any value.
+///
+/// # Arguments
+///
+/// * `expr` - The expression of the check constraint.
+/// * `column` - The column whose values are allowed.
+/// * `casing` - The casing the identifiers are compared through.
+pub(crate) fn allowed_values<C: ColumnLike>(
+    expr: &Expr,
+    column: &C,
+    casing: IdentifierCasing,
+) -> Option<Vec<Value>> {
+    let values = match expr {
+        Expr::InList { expr, list, negated: false } if is_column(expr, column, casing) => {
+            literals(list)?
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::Eq, right } => {
+            if is_column(left, column, casing) {
+                vec![literal(right)?]
+            } else if is_column(right, column, casing) {
+                vec![literal(left)?]
+            } else {
+                return None;
+            }
+        }
+        Expr::AnyOp { left, compare_op: BinaryOperator::Eq, right, .. }
+            if is_column(left, column, casing) =>
+        {
+            let mut right = right.as_ref();
+            while let Expr::Nested(inner) | Expr::Cast { expr: inner, .. } = right {
+                right = inner;
+            }
+            let Expr::Array(array) = right else {
+                return None;
+            };
+            literals(&array.elem)?
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::Or, right } => {
+            // `NULL` satisfies any check constraint, so `column IS NULL`
+            // allows no other value.
+            let disjunct_values = |disjunct: &Expr| match disjunct {
+                Expr::IsNull(inner) if is_column(inner, column, casing) => Some(Vec::new()),
+                _ => allowed_values(disjunct, column, casing),
+            };
+            let mut values = disjunct_values(left)?;
+            values.extend(disjunct_values(right)?);
+            values
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+            match (allowed_values(left, column, casing), allowed_values(right, column, casing)) {
+                (Some(left), Some(right)) => {
+                    left.into_iter().filter(|value| right.contains(value)).collect()
+                }
+                (left, right) => left.or(right)?,
+            }
+        }
+        Expr::Nested(inner) => allowed_values(inner, column, casing)?,
+        _ => return None,
+    };
+    let mut unique = Vec::with_capacity(values.len());
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    Some(unique)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use sqlparser::{
+        dialect::{GenericDialect, PostgreSqlDialect},
+        parser::Parser,
+    };
+
+    use super::*;
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, DialectLike, TableLike},
+    };
+
+    fn values(condition: &str) -> Option<Vec<String>> {
+        let db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (status TEXT, level INT)")
+            .expect("Failed to parse");
+        let column = db.table(None, "t").unwrap().column("status", &db).unwrap();
+        let expr = Parser::new(&PostgreSqlDialect {})
+            .try_with_sql(condition)
+            .and_then(|mut parser| parser.parse_expr())
+            .expect("Failed to parse expression");
+        allowed_values(&expr, column, db.dialect().identifier_casing())
+            .map(|values| values.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_in_list() {
+        assert_eq!(values("status IN ('a', 'b', 'a')").unwrap(), ["'a'", "'b'"]);
+        assert_eq!(values("status NOT IN ('a', 'b')"), None);
+        assert_eq!(values("level IN (1, 2)"), None);
+        assert_eq!(values("status IN ('a', lower('B'))"), None);
+    }
+
+    #[test]
+    fn test_equality_chains() {
+        assert_eq!(
+            values("status = 'a' OR ('b' = status OR status = 'c')").unwrap(),
+            ["'a'", "'b'", "'c'"]
+        );
+        assert_eq!(values("status IS NULL OR status = 'a'").unwrap(), ["'a'"]);
+        assert_eq!(values("status = 'a' OR level = 1"), None);
+    }
+
+    #[test]
+    fn test_any_array() {
+        assert_eq!(
+            values("(status)::text = ANY ((ARRAY['a'::character varying, 'b'])::text[])").unwrap(),
+            ["'a'", "'b'"]
+        );
+    }
+
+    #[test]
+    fn test_conjunctions() {
+        assert_eq!(values("status IN ('a', 'b', 'c') AND status <> 'b'").unwrap().len(), 3);
+        assert_eq!(values("status IN ('a', 'b') AND status IN ('b', 'c')").unwrap(), ["'b'"]);
+        assert_eq!(values("level > 0 AND status = 'a'").unwrap(), ["'a'"]);
+    }
+}
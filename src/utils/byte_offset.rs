@@ -0,0 +1,26 @@
+//! Conversion of the locations reported by the `sqlparser` tokenizer into
+//! byte offsets.
+
+use sqlparser::tokenizer::Location;
+
+/// Returns the byte offset of a location within the SQL, or `None` if the
+/// location lies outside of it.
+///
+/// # Arguments
+///
+/// * `sql` - The SQL the location was reported for.
+/// * `location` - The one-based line and column of a character.
+pub(crate) fn byte_offset(sql: &str, location: Location) -> Option<usize> {
+    let line_start = if location.line <= 1 {
+        0
+    } else {
+        let line = usize::try_from(location.line).ok()?;
+        sql.match_indices('\n').nth(line - 2)?.0 + 1
+    };
+    let column = usize::try_from(location.column).ok()?.checked_sub(1)?;
+    sql[line_start..]
+        .char_indices()
+        .map(|(offset, _)| line_start + offset)
+        .chain(core::iter::once(sql.len()))
+        .nth(column)
+}
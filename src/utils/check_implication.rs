@@ -0,0 +1,557 @@
+//! Implication between check constraint expressions, and numeric ranges they
+//! enforce, decided on a fragment of SQL.
+//!
+//! The expressions are split into their `AND`-ed conjuncts, and each conjunct
+//! is read as a fact about a single column of the table:
+//!
+//! * a comparison of the column, or of the length of a textual column, with
+//!   a number, including `BETWEEN`;
+//! * an equality of the column with a text or boolean literal;
+//! * an `IS NOT NULL` check of the column.
+//!
+//! A premise implies a conclusion when every fact of the conclusion follows
+//! from the facts of the premise. Conjuncts of the premise outside of the
+//! fragment are ignored, which only weakens it, while a conclusion with a
+//! conjunct outside of the fragment is never implied.
+//!
+//! The numeric range of a column combines the comparisons of the column with
+//! numbers and, transitively, with other columns whose range is enforced by
+//! the check constraints of the table.
+
+use alloc::vec::Vec;
+use core::{cmp::Ordering, ops};
+
+use sqlparser::ast::{
+    BinaryOperator, Expr, FunctionArg, FunctionArgExpr, FunctionArguments, UnaryOperator, Value,
+};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::{
+        CheckConstraintLike, ColumnLike, DatabaseLike, DialectLike, TableLike,
+        check_constraint::LENGTH_FUNCTIONS,
+    },
+};
+
+/// The quantity of a column a range applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Measure {
+    /// The value of the column.
+    Value,
+    /// The length of the text in the column.
+    Length,
+}
+
+/// A bound of a range of numbers.
+#[derive(Debug, Clone, Copy)]
+struct Bound {
+    /// The value of the bound.
+    value: f64,
+    /// Whether the value itself is in the range.
+    inclusive: bool,
+}
+
+/// A range of numbers, unbounded on the sides without a bound.
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    /// The lower bound of the range, if any.
+    lower: Option<Bound>,
+    /// The upper bound of the range, if any.
+    upper: Option<Bound>,
+}
+
+/// Returns whether the lower bound `tighter` excludes at least the numbers
+/// the lower bound `looser` excludes.
+fn lower_within(tighter: Bound, looser: Bound) -> bool {
+    match tighter.value.total_cmp(&looser.value) {
+        Ordering::Greater => true,
+        Ordering::Equal => looser.inclusive || !tighter.inclusive,
+        Ordering::Less => false,
+    }
+}
+
+/// Returns whether the upper bound `tighter` excludes at least the numbers
+/// the upper bound `looser` excludes.
+fn upper_within(tighter: Bound, looser: Bound) -> bool {
+    match tighter.value.total_cmp(&looser.value) {
+        Ordering::Less => true,
+        Ordering::Equal => looser.inclusive || !tighter.inclusive,
+        Ordering::Greater => false,
+    }
+}
+
+impl Range {
+    /// Returns the range enforced by a `quantity <op> value` comparison.
+    fn from_comparison(op: &BinaryOperator, value: f64) -> Option<Self> {
+        let inclusive = Some(Bound { value, inclusive: true });
+        let exclusive = Some(Bound { value, inclusive: false });
+        Some(match op {
+            BinaryOperator::Gt => Self { lower: exclusive, upper: None },
+            BinaryOperator::GtEq => Self { lower: inclusive, upper: None },
+            BinaryOperator::Lt => Self { lower: None, upper: exclusive },
+            BinaryOperator::LtEq => Self { lower: None, upper: inclusive },
+            BinaryOperator::Eq => Self { lower: inclusive, upper: inclusive },
+            _ => return None,
+        })
+    }
+
+    /// Returns the intersection of the two ranges.
+    fn intersect(self, other: Self) -> Self {
+        Self {
+            lower: match (self.lower, other.lower) {
+                (Some(left), Some(right)) => {
+                    Some(if lower_within(left, right) { left } else { right })
+                }
+                (left, right) => left.or(right),
+            },
+            upper: match (self.upper, other.upper) {
+                (Some(left), Some(right)) => {
+                    Some(if upper_within(left, right) { left } else { right })
+                }
+                (left, right) => left.or(right),
+            },
+        }
+    }
+
+    /// Returns the range as a pair of lower and upper bounds.
+    fn into_bounds(self) -> (ops::Bound<f64>, ops::Bound<f64>) {
+        let convert = |bound: Option<Bound>| match bound {
+            Some(Bound { value, inclusive: true }) => ops::Bound::Included(value),
+            Some(Bound { value, inclusive: false }) => ops::Bound::Excluded(value),
+            None => ops::Bound::Unbounded,
+        };
+        (convert(self.lower), convert(self.upper))
+    }
+
+    /// Returns whether every number of the other range is in this range.
+    fn contains(self, other: Self) -> bool {
+        let lower = self
+            .lower
+            .is_none_or(|looser| other.lower.is_some_and(|tighter| lower_within(tighter, looser)));
+        let upper = self
+            .upper
+            .is_none_or(|looser| other.upper.is_some_and(|tighter| upper_within(tighter, looser)));
+        lower && upper
+    }
+}
+
+/// A fact a conjunct enforces on a single column, identified by its index
+/// in the columns of the table.
+#[derive(Debug, Clone, Copy)]
+enum Fact<'a> {
+    /// The measure of the column is in the range, or the column is `NULL`.
+    Range(usize, Measure, Range),
+    /// The column equals the literal, or is `NULL`.
+    Equals(usize, &'a Value),
+    /// The column is not `NULL`.
+    NotNull(usize),
+}
+
+/// Returns the `AND`-ed conjuncts of the expression.
+fn conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+            let mut left = conjuncts(left);
+            left.extend(conjuncts(right));
+            left
+        }
+        Expr::Nested(inner) => conjuncts(inner),
+        _ => vec![expr],
+    }
+}
+
+/// Returns the index of the column the expression designates, if any.
+fn column_index<C: ColumnLike>(
+    expr: &Expr,
+    columns: &[&C],
+    casing: IdentifierCasing,
+) -> Option<usize> {
+    match expr {
+        Expr::Identifier(ident) => columns.iter().position(|column| {
+            casing.matches(
+                column.column_name(),
+                column.column_name_is_quoted(),
+                &ident.value,
+                ident.quote_style.is_some(),
+            )
+        }),
+        Expr::Nested(inner) => column_index(inner, columns, casing),
+        _ => None,
+    }
+}
+
+/// Returns the column and the measure of it the expression designates, as
+/// in `price` or `length(name)`.
+fn measured_column<C: ColumnLike>(
+    expr: &Expr,
+    columns: &[&C],
+    casing: IdentifierCasing,
+) -> Option<(usize, Measure)> {
+    match expr {
+        Expr::Function(function)
+            if LENGTH_FUNCTIONS
+                .iter()
+                .any(|name| function.name.to_string().eq_ignore_ascii_case(name)) =>
+        {
+            let FunctionArguments::List(list) = &function.args else {
+                return None;
+            };
+            let [FunctionArg::Unnamed(FunctionArgExpr::Expr(argument))] = list.args.as_slice()
+            else {
+                return None;
+            };
+            Some((column_index(argument, columns, casing)?, Measure::Length))
+        }
+        Expr::Nested(inner) => measured_column(inner, columns, casing),
+        _ => Some((column_index(expr, columns, casing)?, Measure::Value)),
+    }
+}
+
+/// Returns the value of a numeric literal expression.
+fn number(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Value(value) => match &value.value {
+            Value::Number(number, _) => number.parse().ok(),
+            _ => None,
+        },
+        Expr::UnaryOp { op: UnaryOperator::Minus, expr } => number(expr).map(|value| -value),
+        Expr::Nested(inner) => number(inner),
+        _ => None,
+    }
+}
+
+/// Returns the text or boolean literal of the expression.
+fn literal(expr: &Expr) -> Option<&Value> {
+    match expr {
+        Expr::Value(value)
+            if matches!(value.value, Value::SingleQuotedString(_) | Value::Boolean(_)) =>
+        {
+            Some(&value.value)
+        }
+        Expr::Nested(inner) => literal(inner),
+        _ => None,
+    }
+}
+
+/// Returns the comparison operator with its operands swapped, so that
+/// `a <op> b` is equivalent to `b <swapped op> a`.
+fn swap_comparison(op: &BinaryOperator) -> BinaryOperator {
+    match op {
+        BinaryOperator::Gt => BinaryOperator::Lt,
+        BinaryOperator::GtEq => BinaryOperator::LtEq,
+        BinaryOperator::Lt => BinaryOperator::Gt,
+        BinaryOperator::LtEq => BinaryOperator::GtEq,
+        other => other.clone(),
+    }
+}
+
+/// Returns the fact a `left <op> right` comparison enforces, if any.
+fn comparison_fact<'a, C: ColumnLike>(
+    left: &'a Expr,
+    op: &BinaryOperator,
+    right: &'a Expr,
+    columns: &[&C],
+    casing: IdentifierCasing,
+) -> Option<Fact<'a>> {
+    let (column, measure) = measured_column(left, columns, casing)?;
+    if let Some(value) = number(right) {
+        return Some(Fact::Range(column, measure, Range::from_comparison(op, value)?));
+    }
+    if measure == Measure::Value && *op == BinaryOperator::Eq {
+        return Some(Fact::Equals(column, literal(right)?));
+    }
+    None
+}
+
+/// Returns the fact the conjunct enforces, if it is in the fragment.
+fn fact<'a, C: ColumnLike>(
+    conjunct: &'a Expr,
+    columns: &[&C],
+    casing: IdentifierCasing,
+) -> Option<Fact<'a>> {
+    match conjunct {
+        Expr::IsNotNull(expr) => Some(Fact::NotNull(column_index(expr, columns, casing)?)),
+        Expr::BinaryOp { left, op, right } => comparison_fact(left, op, right, columns, casing)
+            .or_else(|| comparison_fact(right, &swap_comparison(op), left, columns, casing)),
+        Expr::Between { expr, negated: false, low, high } => {
+            let (column, measure) = measured_column(expr, columns, casing)?;
+            let range = Range {
+                lower: Some(Bound { value: number(low)?, inclusive: true }),
+                upper: Some(Bound { value: number(high)?, inclusive: true }),
+            };
+            Some(Fact::Range(column, measure, range))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether the facts of the premise imply the provided fact.
+fn implied<DB: DatabaseLike>(
+    database: &DB,
+    columns: &[&DB::Column],
+    premise: &[Fact<'_>],
+    conclusion: Fact<'_>,
+) -> bool {
+    match conclusion {
+        Fact::NotNull(column) => {
+            !columns[column].is_nullable(database)
+                || premise
+                    .iter()
+                    .any(|fact| matches!(fact, Fact::NotNull(other) if *other == column))
+        }
+        Fact::Equals(column, value) => premise.iter().any(|fact| {
+            matches!(
+                fact,
+                Fact::Equals(other, other_value) if *other == column && *other_value == value
+            )
+        }),
+        Fact::Range(column, measure, range) => premise
+            .iter()
+            .filter_map(|fact| match fact {
+                Fact::Range(other, other_measure, other_range)
+                    if *other == column && *other_measure == measure =>
+                {
+                    Some(*other_range)
+                }
+                _ => None,
+            })
+            .reduce(Range::intersect)
+            .is_some_and(|premise_range| range.contains(premise_range)),
+    }
+}
+
+/// Returns whether every row satisfying the premise also satisfies the
+/// conclusion, as far as the fragment of SQL described in the module goes.
+///
+/// # Arguments
+///
+/// * `database` - The database the expressions belong to.
+/// * `columns` - The columns of the table of the expressions.
+/// * `premise` - The implying expression.
+/// * `conclusion` - The implied expression.
+pub(crate) fn expression_implies<DB: DatabaseLike>(
+    database: &DB,
+    columns: &[&DB::Column],
+    premise: &Expr,
+    conclusion: &Expr,
+) -> bool {
+    let casing = database.dialect().identifier_casing();
+    let premise: Vec<Fact<'_>> = conjuncts(premise)
+        .into_iter()
+        .filter_map(|conjunct| fact(conjunct, columns, casing))
+        .collect();
+    let Some(conclusion) = conjuncts(conclusion)
+        .into_iter()
+        .map(|conjunct| fact(conjunct, columns, casing))
+        .collect::<Option<Vec<Fact<'_>>>>()
+    else {
+        return false;
+    };
+    conclusion.into_iter().all(|fact| implied(database, columns, &premise, fact))
+}
+
+/// Returns the range a comparison of the column with another column
+/// enforces, given the range of the other column enforced by the check
+/// constraints of the table.
+fn transitive_range<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    conjunct: &Expr,
+    column: usize,
+    visited: &mut Vec<usize>,
+) -> Option<Range> {
+    let Expr::BinaryOp { left, op, right } = conjunct else {
+        return None;
+    };
+    let casing = database.dialect().identifier_casing();
+    let (op, other) = if column_index(left, columns, casing) == Some(column) {
+        (op.clone(), column_index(right, columns, casing)?)
+    } else if column_index(right, columns, casing) == Some(column) {
+        (swap_comparison(op), column_index(left, columns, casing)?)
+    } else {
+        return None;
+    };
+    if visited.contains(&other) {
+        return None;
+    }
+    let other_range = table_range(database, table, columns, other, visited)?;
+    let strict = |bound: Bound| Bound { inclusive: false, ..bound };
+    let range = match op {
+        BinaryOperator::Gt => Range { lower: other_range.lower.map(strict), upper: None },
+        BinaryOperator::GtEq => Range { lower: other_range.lower, upper: None },
+        BinaryOperator::Lt => Range { lower: None, upper: other_range.upper.map(strict) },
+        BinaryOperator::LtEq => Range { lower: None, upper: other_range.upper },
+        BinaryOperator::Eq => other_range,
+        _ => return None,
+    };
+    (range.lower.is_some() || range.upper.is_some()).then_some(range)
+}
+
+/// Returns the range the expression enforces on the value of the column, if
+/// any.
+fn value_range<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    expr: &Expr,
+    column: usize,
+    visited: &mut Vec<usize>,
+) -> Option<Range> {
+    let casing = database.dialect().identifier_casing();
+    let mut range: Option<Range> = None;
+    for conjunct in conjuncts(expr) {
+        let conjunct_range = match fact(conjunct, columns, casing) {
+            Some(Fact::Range(other, Measure::Value, conjunct_range)) if other == column => {
+                Some(conjunct_range)
+            }
+            Some(_) => None,
+            None => transitive_range(database, table, columns, conjunct, column, visited),
+        };
+        if let Some(conjunct_range) = conjunct_range {
+            range = Some(range.map_or(conjunct_range, |range| range.intersect(conjunct_range)));
+        }
+    }
+    range
+}
+
+/// Returns the range the check constraints of the table enforce on the value
+/// of the column, if any.
+fn table_range<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    columns: &[&DB::Column],
+    column: usize,
+    visited: &mut Vec<usize>,
+) -> Option<Range> {
+    visited.push(column);
+    let mut range: Option<Range> = None;
+    for constraint in table.check_constraints(database) {
+        let expr = constraint.expression(database);
+        if let Some(constraint_range) = value_range(database, table, columns, expr, column, visited)
+        {
+            range = Some(range.map_or(constraint_range, |range| range.intersect(constraint_range)));
+        }
+    }
+    visited.pop();
+    range
+}
+
+/// Returns the bounds the expression enforces on the value of the column,
+/// following the comparisons with other columns of the table through the
+/// check constraints of the table.
+///
+/// # Arguments
+///
+/// * `database` - The database the expression belongs to.
+/// * `table` - The table of the expression.
+/// * `expr` - The expression enforcing the bounds.
+/// * `column` - The bounded column.
+pub(crate) fn numeric_bounds<DB: DatabaseLike>(
+    database: &DB,
+    table: &DB::Table,
+    expr: &Expr,
+    column: &DB::Column,
+) -> Option<(ops::Bound<f64>, ops::Bound<f64>)> {
+    let columns: Vec<&DB::Column> = table.columns(database).collect();
+    let column = columns.iter().position(|candidate| *candidate == column)?;
+    let mut visited = vec![column];
+    value_range(database, table, &columns, expr, column, &mut visited).map(Range::into_bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+    use super::*;
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, TableLike},
+    };
+
+    fn implies(premise: &str, conclusion: &str) -> bool {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (a INT, b INT NOT NULL, name TEXT, active BOOLEAN)",
+        )
+        .expect("Failed to parse");
+        let table = db.table(None, "t").unwrap();
+        let columns: Vec<_> = table.columns(&db).collect();
+        let parse = |sql: &str| {
+            Parser::new(&GenericDialect {})
+                .try_with_sql(sql)
+                .and_then(|mut parser| parser.parse_expr())
+                .expect("Failed to parse expression")
+        };
+        expression_implies(&db, &columns, &parse(premise), &parse(conclusion))
+    }
+
+    #[test]
+    fn test_numeric_bounds() {
+        assert!(implies("a > 10", "a > 0"));
+        assert!(implies("a >= 1", "a >= 1"));
+        assert!(implies("a > 1", "a >= 1"));
+        assert!(!implies("a >= 1", "a > 1"));
+        assert!(implies("a BETWEEN 2 AND 5", "a > 1 AND a <= 5"));
+        assert!(implies("a > 0 AND a < 5", "0 < a"));
+        assert!(implies("a = 3", "a BETWEEN 0 AND 10"));
+        assert!(implies("a >= -1.5", "a > -2"));
+        assert!(!implies("a > 0", "a < 10"));
+        assert!(!implies("b > 0", "a > 0"));
+    }
+
+    #[test]
+    fn test_length_bounds() {
+        assert!(implies("length(name) <= 5", "length(name) < 10"));
+        assert!(implies("length(name) > 3 AND name <> ''", "length(name) >= 1"));
+        assert!(!implies("length(name) < 10", "length(name) < 5"));
+        assert!(!implies("name = 'abc'", "length(name) < 5"));
+    }
+
+    #[test]
+    fn test_not_null_and_literals() {
+        assert!(implies("a IS NOT NULL AND a > 0", "a IS NOT NULL"));
+        assert!(implies("a > 0", "b IS NOT NULL"));
+        assert!(!implies("a > 0", "a IS NOT NULL"));
+        assert!(implies("name = 'x' AND active = TRUE", "active = TRUE"));
+        assert!(!implies("name = 'x'", "name = 'y'"));
+    }
+
+    #[test]
+    fn test_conclusions_outside_of_the_fragment() {
+        assert!(!implies("a > 0", "a > 0 OR b > 0"));
+        assert!(!implies("a > 0", "a <> 5"));
+        assert!(!implies("a > 0", "TRUE"));
+    }
+
+    #[test]
+    fn test_numeric_bounds() {
+        use core::ops::Bound::{Excluded, Included, Unbounded};
+
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE t (
+                age INT CHECK (age >= 0 AND age < 150),
+                min_price INT CHECK (min_price > 0),
+                max_price INT CHECK (max_price >= min_price AND max_price <= 1000),
+                discount INT CHECK (discount < max_price),
+                a INT CHECK (a < b),
+                b INT CHECK (b < a),
+                name TEXT CHECK (length(name) < 10)
+            )",
+        )
+        .expect("Failed to parse");
+        let table = db.table(None, "t").unwrap();
+        let bounds = |name: &str| {
+            let column = table.column(name, &db).unwrap();
+            table.check_constraints(&db).find_map(|constraint| {
+                numeric_bounds(&db, table, constraint.expression(&db), column)
+            })
+        };
+
+        assert_eq!(bounds("age"), Some((Included(0.0), Excluded(150.0))));
+        assert_eq!(bounds("min_price"), Some((Excluded(0.0), Unbounded)));
+        assert_eq!(bounds("max_price"), Some((Excluded(0.0), Included(1000.0))));
+        assert_eq!(bounds("discount"), Some((Unbounded, Excluded(1000.0))));
+        assert_eq!(bounds("a"), None);
+        assert_eq!(bounds("name"), None);
+    }
+}
@@ -1,10 +1,21 @@
 //! Functions to extract columns from SQL expressions.
 
 use alloc::{string::ToString, vec::Vec};
+use core::ops::ControlFlow;
 
-use sqlparser::ast::Expr;
+use sqlparser::ast::{Expr, Ident, visit_expressions};
 
-use crate::traits::column::ColumnLike;
+use crate::{structs::IdentifierCasing, traits::column::ColumnLike};
+
+/// Returns whether the identifier designates the column.
+fn column_matches<C: ColumnLike>(column: &C, ident: &Ident, casing: IdentifierCasing) -> bool {
+    casing.matches(
+        column.column_name(),
+        column.column_name_is_quoted(),
+        &ident.value,
+        ident.quote_style.is_some(),
+    )
+}
 
 /// Extracts columns from a SQL expression.
 ///
@@ -13,6 +24,7 @@ use crate::traits::column::ColumnLike;
 /// * `expr` - The SQL expression to extract columns from.
 /// * `table_name` - The name of the table the expression belongs to.
 /// * `columns` - The list of columns available in the table.
+/// * `casing` - The casing the column names are compared through.
 ///
 /// # Returns
 ///
@@ -26,13 +38,13 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
     expr: &Expr,
     table_name: &str,
     columns: &[C],
+    casing: IdentifierCasing,
 ) -> Result<Vec<C>, crate::errors::Error> {
     let mut result = Vec::new();
 
     match expr {
         Expr::Identifier(ident) => {
-            if let Some(col) = columns.iter().find(|col| col.column_name() == ident.value.as_str())
-            {
+            if let Some(col) = columns.iter().find(|col| column_matches(*col, ident, casing)) {
                 result.push(col.clone());
             } else {
                 return Err(crate::errors::Error::UnknownColumnInCheckConstraint {
@@ -44,7 +56,7 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
         Expr::CompoundIdentifier(idents) => {
             if let Some(last_ident) = idents.last() {
                 if let Some(col) =
-                    columns.iter().find(|col| col.column_name() == last_ident.value.as_str())
+                    columns.iter().find(|col| column_matches(*col, last_ident, casing))
                 {
                     result.push(col.clone());
                 } else {
@@ -56,22 +68,22 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
             }
         }
         Expr::BinaryOp { left, right, .. } => {
-            result.extend(columns_in_expression(left, table_name, columns)?);
-            result.extend(columns_in_expression(right, table_name, columns)?);
+            result.extend(columns_in_expression(left, table_name, columns, casing)?);
+            result.extend(columns_in_expression(right, table_name, columns, casing)?);
         }
         Expr::Nested(nested_expr) => {
-            result.extend(columns_in_expression(nested_expr, table_name, columns)?);
+            result.extend(columns_in_expression(nested_expr, table_name, columns, casing)?);
         }
         Expr::Between { expr, negated: _, low, high } => {
-            result.extend(columns_in_expression(expr, table_name, columns)?);
-            result.extend(columns_in_expression(low, table_name, columns)?);
-            result.extend(columns_in_expression(high, table_name, columns)?);
+            result.extend(columns_in_expression(expr, table_name, columns, casing)?);
+            result.extend(columns_in_expression(low, table_name, columns, casing)?);
+            result.extend(columns_in_expression(high, table_name, columns, casing)?);
         }
         Expr::UnaryOp { expr, .. }
         | Expr::Cast { expr, .. }
         | Expr::IsNull(expr)
         | Expr::IsNotNull(expr) => {
-            result.extend(columns_in_expression(expr, table_name, columns)?);
+            result.extend(columns_in_expression(expr, table_name, columns, casing)?);
         }
         Expr::Function(func) => {
             if let sqlparser::ast::FunctionArguments::List(args) = &func.args {
@@ -84,7 +96,8 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
                         | sqlparser::ast::FunctionArg::Unnamed(
                             sqlparser::ast::FunctionArgExpr::Expr(expr),
                         ) => {
-                            result.extend(columns_in_expression(expr, table_name, columns)?);
+                            result
+                                .extend(columns_in_expression(expr, table_name, columns, casing)?);
                         }
                         sqlparser::ast::FunctionArg::ExprNamed { .. }
                         | sqlparser::ast::FunctionArg::Named { .. }
@@ -94,19 +107,19 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
             }
         }
         Expr::InList { expr, list, .. } => {
-            result.extend(columns_in_expression(expr, table_name, columns)?);
+            result.extend(columns_in_expression(expr, table_name, columns, casing)?);
             for list_expr in list {
-                result.extend(columns_in_expression(list_expr, table_name, columns)?);
+                result.extend(columns_in_expression(list_expr, table_name, columns, casing)?);
             }
         }
         Expr::InSubquery { expr, .. } => {
-            result.extend(columns_in_expression(expr, table_name, columns)?);
+            result.extend(columns_in_expression(expr, table_name, columns, casing)?);
             // Note: We don't traverse into subqueries as they have their own
             // column scope
         }
         Expr::Tuple(exprs) => {
             for expr in exprs {
-                result.extend(columns_in_expression(expr, table_name, columns)?);
+                result.extend(columns_in_expression(expr, table_name, columns, casing)?);
             }
         }
         _ => {}
@@ -117,6 +130,31 @@ pub fn columns_in_expression<C: ColumnLike + Clone>(
     Ok(result.into_iter().filter(|col| seen.insert(col.clone())).collect())
 }
 
+/// Returns whether the provided expression mentions the provided column.
+///
+/// Unlike [`columns_in_expression`], the whole expression tree is visited,
+/// including subqueries, and identifiers are compared with the identifier
+/// semantics of the column under the provided casing.
+pub(crate) fn expression_references_column<C: ColumnLike>(
+    expr: &Expr,
+    column: &C,
+    casing: IdentifierCasing,
+) -> bool {
+    visit_expressions(expr, |expression: &Expr| {
+        let ident = match expression {
+            Expr::Identifier(ident) => Some(ident),
+            Expr::CompoundIdentifier(idents) => idents.last(),
+            _ => None,
+        };
+        if ident.is_some_and(|ident| column_matches(column, ident, casing)) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::sync::Arc;
@@ -156,7 +194,7 @@ mod tests {
         let expr = Expr::Identifier(Ident::new("a"));
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].column_name(), "a");
     }
@@ -168,7 +206,7 @@ mod tests {
         let expr = Expr::CompoundIdentifier(vec![Ident::new("t"), Ident::new("a")]);
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].column_name(), "a");
     }
@@ -185,7 +223,7 @@ mod tests {
         };
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].column_name(), "a");
         assert_eq!(result[1].column_name(), "b");
@@ -203,7 +241,7 @@ mod tests {
         };
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].column_name(), "a");
     }
@@ -231,7 +269,7 @@ mod tests {
         });
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].column_name(), "a");
     }
@@ -251,7 +289,7 @@ mod tests {
         };
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 3);
         assert_eq!(result[0].column_name(), "a");
         assert_eq!(result[1].column_name(), "b");
@@ -271,7 +309,7 @@ mod tests {
         };
 
         let result: Vec<<ParserDB as DatabaseLike>::Column> =
-            columns_in_expression(&expr, "t", &columns).unwrap();
+            columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase).unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].column_name(), "a");
         assert_eq!(result[1].column_name(), "b");
@@ -283,7 +321,7 @@ mod tests {
         let columns = vec![col_a.clone()];
         let expr = Expr::Identifier(Ident::new("b"));
 
-        let result = columns_in_expression(&expr, "t", &columns);
+        let result = columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase);
         assert!(result.is_err());
         match result.err().unwrap() {
             crate::errors::Error::UnknownColumnInCheckConstraint { column_name, table_name } => {
@@ -304,7 +342,7 @@ mod tests {
         // `t.b` — last ident is `b`, not in our column list.
         let expr = Expr::CompoundIdentifier(vec![Ident::new("t"), Ident::new("b")]);
 
-        let result = columns_in_expression(&expr, "t", &columns);
+        let result = columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase);
         assert!(result.is_err());
         match result.err().unwrap() {
             crate::errors::Error::UnknownColumnInCheckConstraint { column_name, table_name } => {
@@ -330,8 +368,8 @@ mod tests {
             Expr::Identifier(Ident::new("c")),
         ]);
 
-        let result =
-            columns_in_expression(&expr, "t", &columns).expect("tuple of known columns parses");
+        let result = columns_in_expression(&expr, "t", &columns, IdentifierCasing::Lowercase)
+            .expect("tuple of known columns parses");
         let names: Vec<&str> = result.iter().map(ColumnLike::column_name).collect();
         assert_eq!(names, vec!["a", "b", "c"]);
     }
@@ -0,0 +1,76 @@
+//! Aliases of the SQL data types, shared by the code generation backends and
+//! by the exporters, so that they all recognize the same spellings.
+
+use alloc::{string::String, vec::Vec};
+
+/// Spellings of the SQL data types, along with the name they are an alias of.
+const ALIASES: &[(&str, &str)] = &[
+    ("INT2", "SMALLINT"),
+    ("SMALLSERIAL", "SMALLINT"),
+    ("SERIAL2", "SMALLINT"),
+    ("MEDIUMINT", "INT"),
+    ("INTEGER", "INT"),
+    ("INT4", "INT"),
+    ("SERIAL", "INT"),
+    ("SERIAL4", "INT"),
+    ("INT8", "BIGINT"),
+    ("BIGSERIAL", "BIGINT"),
+    ("SERIAL8", "BIGINT"),
+    ("FLOAT4", "REAL"),
+    ("FLOAT", "DOUBLE PRECISION"),
+    ("FLOAT8", "DOUBLE PRECISION"),
+    ("DOUBLE", "DOUBLE PRECISION"),
+    ("BOOL", "BOOLEAN"),
+    ("DEC", "NUMERIC"),
+    ("DECIMAL", "NUMERIC"),
+    ("CHARACTER", "CHAR"),
+    ("BPCHAR", "CHAR"),
+    ("NCHAR", "CHAR"),
+    ("CHARACTER VARYING", "VARCHAR"),
+    ("CHAR VARYING", "VARCHAR"),
+    ("NVARCHAR", "VARCHAR"),
+    ("VARCHAR2", "VARCHAR"),
+    ("CLOB", "TEXT"),
+    ("STRING", "TEXT"),
+    ("BYTES", "BLOB"),
+    ("BINARY", "BLOB"),
+    ("VARBINARY", "BLOB"),
+    ("TIME WITHOUT TIME ZONE", "TIME"),
+    ("TIMETZ", "TIME WITH TIME ZONE"),
+    ("TIMESTAMP WITHOUT TIME ZONE", "TIMESTAMP"),
+    ("DATETIME", "TIMESTAMP"),
+    ("TIMESTAMPTZ", "TIMESTAMP WITH TIME ZONE"),
+];
+
+/// Returns the name of the provided data type in upper case, without quotes,
+/// with its words separated by single spaces and with its aliases resolved,
+/// as in `INT` for `int4` or `TIMESTAMP WITH TIME ZONE` for `timestamptz`.
+///
+/// The data types only some backends tell apart from their closest
+/// counterpart, such as `JSONB` from `JSON` or `BYTEA` from `BLOB`, are
+/// kept as they are.
+pub(crate) fn canonical_data_type(data_type: &str) -> String {
+    let name = data_type
+        .split_whitespace()
+        .map(|word| word.trim_matches('"').to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+    match ALIASES.iter().find(|(alias, _)| *alias == name) {
+        Some((_, canonical)) => String::from(*canonical),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_data_type() {
+        assert_eq!(canonical_data_type("int4"), "INT");
+        assert_eq!(canonical_data_type(" \"timestamptz\" "), "TIMESTAMP WITH TIME ZONE");
+        assert_eq!(canonical_data_type("character  varying"), "VARCHAR");
+        assert_eq!(canonical_data_type("jsonb"), "JSONB");
+        assert_eq!(canonical_data_type("geometry"), "GEOMETRY");
+    }
+}
@@ -0,0 +1,425 @@
+//! Parsing of function bodies into the SQL statements they run.
+//!
+//! Bodies written in SQL are parsed as they are. Bodies written in PL/pgSQL
+//! are split on their semicolons, and the control structures wrapping the
+//! SQL statements are stripped:
+//!
+//! * the `DECLARE` section and the `BEGIN` and `END` delimiting blocks,
+//!   along with the `<<label>>` preceding them;
+//! * the conditions of `IF`, `ELSIF`, `CASE`, `WHILE`, `FOR` and `FOREACH` up
+//!   to their `THEN` or `LOOP` keyword, along with `ELSE`, `LOOP` and the
+//!   `END IF`, `END CASE` or `END LOOP` closing them;
+//! * the `WHEN ... THEN` branches of a `CASE` statement or of an `EXCEPTION`
+//!   section.
+//!
+//! `PERFORM` is read as `SELECT` and `RETURN QUERY` as the query it returns,
+//! and the `INTO` targets of `SELECT` statements and `RETURNING` clauses are
+//! stripped. The statements with no SQL counterpart, such as assignments,
+//! `RAISE`, `RETURN` and `RETURN NEXT`, or the dynamic SQL of `EXECUTE` and
+//! `RETURN QUERY EXECUTE`, are skipped.
+//!
+//! Other PL/pgSQL statements are not supported, and make the body fail to
+//! parse. Neither are the queries of the `FOR ... IN query LOOP` loops and
+//! the conditions of the control structures parsed, so that the relations
+//! they read are not reported, and neither are those of dynamic SQL.
+
+use alloc::vec::Vec;
+use core::{convert::Infallible, ops::ControlFlow};
+
+use sqlparser::{
+    ast::{CreateFunction, ObjectName, Statement, visit_relations},
+    dialect::Dialect,
+    parser::{Parser, ParserError},
+    tokenizer::{Token, TokenWithSpan, Tokenizer},
+};
+
+use crate::traits::FunctionLike;
+
+/// The PL/pgSQL statements which are skipped, as they have no SQL
+/// counterpart.
+const SKIPPED_STATEMENTS: [&str; 12] = [
+    "ASSERT", "CALL", "CLOSE", "CONTINUE", "EXECUTE", "EXIT", "FETCH", "GET", "MOVE", "NULL",
+    "OPEN", "RAISE",
+];
+
+/// Returns whether the token is the provided unquoted word.
+fn is_word(token: &TokenWithSpan, word: &str) -> bool {
+    matches!(
+        &token.token,
+        Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word)
+    )
+}
+
+/// Returns the tokens following the first of the provided words, or an
+/// empty slice when none of them is found.
+fn after_first_word<'t>(tokens: &'t [TokenWithSpan], words: &[&str]) -> &'t [TokenWithSpan] {
+    tokens
+        .iter()
+        .position(|token| words.iter().any(|word| is_word(token, word)))
+        .map_or(&[][..], |index| &tokens[index + 1..])
+}
+
+/// Returns the number of tokens of the variable, possibly qualified as in
+/// `item.total`, starting the tokens, or zero if they do not start with one.
+fn variable_length(tokens: &[TokenWithSpan]) -> usize {
+    let mut length = 0;
+    while matches!(tokens.get(length).map(|token| &token.token), Some(Token::Word(_))) {
+        length += 1;
+        if !matches!(tokens.get(length).map(|token| &token.token), Some(Token::Period)) {
+            return length;
+        }
+        length += 1;
+    }
+    0
+}
+
+/// Returns whether the tokens assign a variable, as in `total := total + 1`
+/// or `item.total := 0`.
+fn is_assignment(tokens: &[TokenWithSpan]) -> bool {
+    let length = variable_length(tokens);
+    length > 0
+        && matches!(
+            tokens.get(length).map(|token| &token.token),
+            Some(Token::Assignment | Token::Eq)
+        )
+}
+
+/// Returns the number of tokens of the `<<label>>` starting the tokens, or
+/// zero if they do not start with one.
+fn label_length(tokens: &[TokenWithSpan]) -> usize {
+    match tokens {
+        [
+            TokenWithSpan { token: Token::ShiftLeft, .. },
+            TokenWithSpan { token: Token::Word(_), .. },
+            TokenWithSpan { token: Token::ShiftRight, .. },
+            ..,
+        ] => 3,
+        _ => 0,
+    }
+}
+
+/// Removes the `INTO` targets of a `SELECT` statement or of a `RETURNING`
+/// clause, as in `SELECT count(*) INTO STRICT total FROM items`, which
+/// PL/pgSQL assigns to variables, whereas SQL would read them as the table
+/// created by `SELECT INTO`.
+///
+/// The `INTO` keywords of `INSERT INTO` and `MERGE INTO`, and those nested
+/// within parentheses, are kept.
+fn strip_into_targets(tokens: &mut Vec<TokenWithSpan>) {
+    let mut depth = 0_usize;
+    let mut previous: Option<&TokenWithSpan> = None;
+    let Some(index) = tokens.iter().position(|token| {
+        match token.token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        let is_target = depth == 0
+            && is_word(token, "INTO")
+            && !previous.is_some_and(|previous| {
+                ["INSERT", "MERGE"].iter().any(|word| is_word(previous, word))
+            });
+        previous = Some(token);
+        is_target
+    }) else {
+        return;
+    };
+    let mut end = index + 1;
+    if tokens.get(end).is_some_and(|token| is_word(token, "STRICT")) {
+        end += 1;
+    }
+    loop {
+        end += variable_length(&tokens[end..]);
+        if !matches!(tokens.get(end).map(|token| &token.token), Some(Token::Comma)) {
+            break;
+        }
+        end += 1;
+    }
+    tokens.drain(index..end);
+}
+
+/// Strips the control structures preceding the SQL statement of a PL/pgSQL
+/// statement, returning its tokens, or an empty slice when it has no SQL
+/// counterpart.
+fn strip_control_structures(mut tokens: &[TokenWithSpan]) -> &[TokenWithSpan] {
+    loop {
+        let Some(first) = tokens.first() else {
+            return tokens;
+        };
+        tokens = if label_length(tokens) > 0 {
+            &tokens[label_length(tokens)..]
+        } else if ["BEGIN", "ELSE", "LOOP"].iter().any(|word| is_word(first, word)) {
+            &tokens[1..]
+        } else if ["IF", "ELSIF", "ELSEIF", "CASE", "WHILE", "FOR", "FOREACH"]
+            .iter()
+            .any(|word| is_word(first, word))
+        {
+            after_first_word(&tokens[1..], &["THEN", "LOOP"])
+        } else if ["EXCEPTION", "WHEN"].iter().any(|word| is_word(first, word)) {
+            after_first_word(&tokens[1..], &["THEN"])
+        } else if is_word(first, "END")
+            || SKIPPED_STATEMENTS.iter().any(|word| is_word(first, word))
+            || is_assignment(tokens)
+        {
+            return &[];
+        } else if is_word(first, "RETURN") {
+            // `RETURN QUERY EXECUTE` runs dynamic SQL, and `RETURN` and
+            // `RETURN NEXT` return values rather than run a query.
+            return if tokens.get(1).is_some_and(|token| is_word(token, "QUERY"))
+                && !tokens.get(2).is_some_and(|token| is_word(token, "EXECUTE"))
+            {
+                &tokens[2..]
+            } else {
+                &[]
+            };
+        } else {
+            return tokens;
+        };
+    }
+}
+
+/// Parses a single statement from its tokens, without the trailing
+/// semicolon.
+fn parse_statement(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<Statement, ParserError> {
+    let mut tokens = tokens.to_vec();
+    if is_word(&tokens[0], "PERFORM") {
+        tokens[0].token = Token::make_keyword("SELECT");
+    }
+    strip_into_targets(&mut tokens);
+    let mut parser = Parser::new(dialect).with_tokens_with_locations(tokens);
+    let statement = parser.parse_statement()?;
+    parser.expect_token(&Token::EOF)?;
+    Ok(statement)
+}
+
+/// Parses the body of a function into the SQL statements it runs.
+///
+/// # Arguments
+///
+/// * `dialect` - The dialect to parse the statements with.
+/// * `body` - The body of the function, without its quotes.
+/// * `procedural` - Whether the body is written in PL/pgSQL rather than SQL.
+///
+/// # Errors
+///
+/// Returns an error if the body cannot be tokenized, or if one of its
+/// statements cannot be parsed.
+fn parse_function_body(
+    dialect: &dyn Dialect,
+    body: &str,
+    procedural: bool,
+) -> Result<Vec<Statement>, ParserError> {
+    if !procedural {
+        return Parser::parse_sql(dialect, body);
+    }
+    let tokens: Vec<TokenWithSpan> = Tokenizer::new(dialect, body)
+        .tokenize_with_location()?
+        .into_iter()
+        .filter(|token| !matches!(token.token, Token::Whitespace(_)))
+        .collect();
+
+    let mut statements = Vec::new();
+    let mut declaring = false;
+    for mut chunk in tokens.split(|token| token.token == Token::SemiColon) {
+        if chunk.get(label_length(chunk)).is_some_and(|token| is_word(token, "DECLARE")) {
+            declaring = true;
+        }
+        if declaring {
+            let Some(begin) = chunk.iter().position(|token| is_word(token, "BEGIN")) else {
+                continue;
+            };
+            declaring = false;
+            chunk = &chunk[begin..];
+        }
+        let chunk = strip_control_structures(chunk);
+        if !chunk.is_empty() {
+            statements.push(parse_statement(dialect, chunk)?);
+        }
+    }
+    Ok(statements)
+}
+
+/// Parses the body of a `CREATE FUNCTION` statement into the SQL statements
+/// it runs, reading it as PL/pgSQL when declared `LANGUAGE plpgsql`.
+///
+/// A function without a body yields no statements.
+///
+/// # Errors
+///
+/// Returns an error if the body cannot be tokenized, or if one of its
+/// statements cannot be parsed.
+pub(crate) fn parse_create_function_body(
+    dialect: &dyn Dialect,
+    function: &CreateFunction,
+) -> Result<Vec<Statement>, ParserError> {
+    let Some(body) = function.body() else {
+        return Ok(Vec::new());
+    };
+    let procedural = function
+        .language
+        .as_ref()
+        .is_some_and(|language| language.value.eq_ignore_ascii_case("plpgsql"));
+    parse_function_body(dialect, body, procedural)
+}
+
+/// Returns the names of the relations the body of a `CREATE FUNCTION`
+/// statement reads or writes, as written in the body.
+///
+/// Bodies written in a language other than SQL or PL/pgSQL, such as the
+/// library paths of C functions, are not parsed and yield no relations.
+///
+/// # Errors
+///
+/// Returns an error if a body written in SQL or PL/pgSQL cannot be parsed.
+pub(crate) fn create_function_body_relations(
+    dialect: &dyn Dialect,
+    function: &CreateFunction,
+) -> Result<Vec<ObjectName>, ParserError> {
+    let parsed = function.language.as_ref().is_none_or(|language| {
+        ["sql", "plpgsql"].iter().any(|parsed| language.value.eq_ignore_ascii_case(parsed))
+    });
+    if !parsed {
+        return Ok(Vec::new());
+    }
+    let mut relations = Vec::new();
+    let ControlFlow::Continue(()) =
+        visit_relations(&parse_create_function_body(dialect, function)?, |relation| {
+            relations.push(relation.clone());
+            ControlFlow::<Infallible>::Continue(())
+        });
+    Ok(relations)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::*;
+
+    fn parse(body: &str) -> Vec<String> {
+        parse_function_body(&PostgreSqlDialect {}, body, true)
+            .expect("body should parse")
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_sql_body() {
+        let statements =
+            parse_function_body(&PostgreSqlDialect {}, "SELECT 1; SELECT 2;", false).unwrap();
+        assert_eq!(statements.len(), 2);
+    }
+
+    #[test]
+    fn test_plpgsql_block() {
+        let statements = parse(
+            "
+            DECLARE
+                total INT := 0;
+                label TEXT;
+            BEGIN
+                total := total + 1;
+                INSERT INTO audit (note) VALUES ('it''s done');
+                IF total > 1 THEN
+                    UPDATE counters SET hits = total;
+                ELSIF total = 0 THEN
+                    RAISE NOTICE 'empty';
+                ELSE
+                    DELETE FROM counters;
+                END IF;
+                FOR item IN SELECT * FROM items LOOP
+                    PERFORM notify(item.id);
+                END LOOP;
+                RETURN QUERY SELECT id FROM items;
+                RETURN NULL;
+            EXCEPTION WHEN others THEN
+                DELETE FROM audit;
+            END;
+            ",
+        );
+        assert_eq!(
+            statements,
+            [
+                "INSERT INTO audit (note) VALUES ('it''s done')",
+                "UPDATE counters SET hits = total",
+                "DELETE FROM counters",
+                "SELECT notify(item.id)",
+                "SELECT id FROM items",
+                "DELETE FROM audit",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plpgsql_into_targets() {
+        let statements = parse(
+            "
+            BEGIN
+                SELECT count(*), max(id) INTO STRICT total, item.last_id FROM items;
+                INSERT INTO audit (note) VALUES ('x') RETURNING id INTO audit_id;
+                SELECT id INTO found FROM items WHERE id IN (SELECT item_id FROM orders);
+            END;
+            ",
+        );
+        assert_eq!(
+            statements,
+            [
+                "SELECT count(*), max(id) FROM items",
+                "INSERT INTO audit (note) VALUES ('x') RETURNING id",
+                "SELECT id FROM items WHERE id IN (SELECT item_id FROM orders)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plpgsql_control_statements() {
+        let statements = parse(
+            "
+            <<outer>>
+            DECLARE
+                item RECORD;
+            BEGIN
+                <<scan>>
+                FOREACH tag IN ARRAY tags LOOP
+                    CASE tag
+                        WHEN 'a' THEN
+                            DELETE FROM tags_a;
+                        WHEN 'b' THEN
+                            item.total := item.total + 1;
+                        ELSE
+                            UPDATE tags_other SET hits = hits + 1;
+                    END CASE;
+                END LOOP scan;
+                IF found THEN
+                    RETURN NEXT item;
+                ELSEIF missing THEN
+                    RETURN QUERY EXECUTE 'SELECT * FROM ' || name;
+                END IF;
+                RETURN QUERY SELECT id FROM items;
+            END outer;
+            ",
+        );
+        assert_eq!(
+            statements,
+            ["DELETE FROM tags_a", "UPDATE tags_other SET hits = hits + 1", "SELECT id FROM items"]
+        );
+    }
+
+    #[test]
+    fn test_plpgsql_without_sql() {
+        assert!(parse("BEGIN RETURN NEW; END;").is_empty());
+    }
+
+    #[test]
+    fn test_unparsable_statement_fails() {
+        assert!(
+            parse_function_body(&PostgreSqlDialect {}, "BEGIN INSERT INTO; END;", true).is_err()
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Submodule providing a minimal glob matcher for object names.
+
+use alloc::vec::Vec;
+
+/// Returns whether the provided text matches the glob pattern.
+///
+/// The pattern supports `*`, matching any sequence of characters (including
+/// the empty one), and `?`, matching exactly one character. Every other
+/// character matches itself.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern.
+/// * `text` - The text to match against the pattern.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and of the text character it
+    // is currently expanded up to, used to backtrack on mismatches.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => {
+                let Some((star, expanded)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star, expanded + 1));
+                p = star + 1;
+                t = expanded + 1;
+            }
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_match;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("users", "users"));
+        assert!(!glob_match("users", "user"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("audit_*", "audit_log"));
+        assert!(glob_match("*_log", "audit_log"));
+        assert!(glob_match("a*b*c", "axxbyybc"));
+        assert!(!glob_match("a*b*c", "axxbyyb"));
+        assert!(glob_match("tmp_??", "tmp_01"));
+        assert!(!glob_match("tmp_??", "tmp_1"));
+    }
+}
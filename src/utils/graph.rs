@@ -0,0 +1,117 @@
+//! Graph algorithms shared by the dependency graphs, whose edges are stored
+//! as `(source, target)` pairs of node indices sorted by source.
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+/// Returns the edges leaving the provided node.
+///
+/// # Arguments
+///
+/// * `edges` - The edges of the graph, sorted by source.
+/// * `node` - The index of the node.
+pub(crate) fn successors(edges: &[(usize, usize)], node: usize) -> &[(usize, usize)] {
+    let start = edges.partition_point(|(source, _)| *source < node);
+    let length = edges[start..].partition_point(|(source, _)| *source == node);
+    &edges[start..start + length]
+}
+
+/// Returns the strongly connected components of the graph, with the nodes of
+/// each component sorted, and each component coming before the components
+/// reachable from it.
+///
+/// # Arguments
+///
+/// * `node_count` - The number of nodes of the graph.
+/// * `edges` - The edges of the graph, sorted by source.
+pub(crate) fn strongly_connected_components(
+    node_count: usize,
+    edges: &[(usize, usize)],
+) -> Vec<Vec<usize>> {
+    // Iterative version of Tarjan's algorithm, which emits each component
+    // after the components reachable from it.
+    let unvisited = usize::MAX;
+    let mut index = vec![unvisited; node_count];
+    let mut lowlink = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..node_count {
+        if index[root] != unvisited {
+            continue;
+        }
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        let mut call_stack = vec![(root, 0)];
+
+        while let Some((node, next_edge)) = call_stack.last_mut() {
+            let node = *node;
+            if let Some(&(_, successor)) = successors(edges, node).get(*next_edge) {
+                *next_edge += 1;
+                if index[successor] == unvisited {
+                    index[successor] = next_index;
+                    lowlink[successor] = next_index;
+                    next_index += 1;
+                    stack.push(successor);
+                    on_stack[successor] = true;
+                    call_stack.push((successor, 0));
+                } else if on_stack[successor] {
+                    lowlink[node] = lowlink[node].min(index[successor]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
+            if lowlink[node] == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components.reverse();
+    components
+}
+
+/// Returns the nodes of the graph sorted so that every node comes after the
+/// sources of its incoming edges, as a Kahn's ordering, or `None` if the
+/// graph has a cycle.
+///
+/// # Arguments
+///
+/// * `node_count` - The number of nodes of the graph.
+/// * `edges` - The edges of the graph, sorted by source.
+pub(crate) fn kahn_ordering(node_count: usize, edges: &[(usize, usize)]) -> Option<Vec<usize>> {
+    let mut in_degrees = vec![0_usize; node_count];
+    for &(_, target) in edges {
+        in_degrees[target] += 1;
+    }
+    let mut ready: VecDeque<usize> =
+        (0..node_count).filter(|&node| in_degrees[node] == 0).collect();
+    let mut ordering = Vec::with_capacity(node_count);
+    while let Some(node) = ready.pop_front() {
+        ordering.push(node);
+        for &(_, successor) in successors(edges, node) {
+            in_degrees[successor] -= 1;
+            if in_degrees[successor] == 0 {
+                ready.push_back(successor);
+            }
+        }
+    }
+    (ordering.len() == node_count).then_some(ordering)
+}
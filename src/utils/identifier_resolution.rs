@@ -1,9 +1,10 @@
-//! Identifier resolution helpers with PostgreSQL matching semantics.
+//! Identifier resolution helpers with PostgreSQL normalization semantics.
 //!
 //! PostgreSQL folds unquoted identifiers to lowercase and treats quoted
 //! identifiers as exact/case-sensitive. Identifiers are additionally
 //! Unicode-NFC-normalized and whitespace-trimmed per
-//! FINGERPRINT_SPEC §7.1.
+//! FINGERPRINT_SPEC §7.1. Identifiers are compared following the casing
+//! rules of the dialect through [`IdentifierCasing`](crate::structs::IdentifierCasing).
 
 use alloc::borrow::Cow;
 
@@ -49,33 +50,6 @@ pub fn parse_lookup_identifier(name: &str) -> LookupIdentifier<'_> {
     }
 }
 
-/// Returns whether two identifiers refer to the same object following
-/// PostgreSQL rules:
-/// - quoted identifiers: exact/case-sensitive
-/// - unquoted identifiers: case-insensitive via lowercase folding
-#[must_use]
-pub fn identifiers_match(
-    left_value: &str,
-    left_quoted: bool,
-    right_value: &str,
-    right_quoted: bool,
-) -> bool {
-    let left = normalize_identifier(left_value, left_quoted);
-    let right = normalize_identifier(right_value, right_quoted);
-    left == right
-}
-
-/// Returns whether a stored identifier matches a textual lookup identifier.
-#[must_use]
-pub fn stored_identifier_matches_lookup(
-    stored_value: &str,
-    stored_quoted: bool,
-    lookup: &str,
-) -> bool {
-    let lookup_ident = parse_lookup_identifier(lookup);
-    identifiers_match(stored_value, stored_quoted, lookup_ident.value(), lookup_ident.is_quoted())
-}
-
 /// Normalizes an identifier for comparison and fingerprint encoding.
 ///
 /// Applies the FINGERPRINT_SPEC §7.1 / audit §5 rules:
@@ -107,10 +81,7 @@ pub fn normalize_identifier(value: &str, quoted: bool) -> Cow<'_, str> {
 
 #[cfg(test)]
 mod tests {
-    use super::{
-        identifiers_match, normalize_identifier, parse_lookup_identifier,
-        stored_identifier_matches_lookup,
-    };
+    use super::{normalize_identifier, parse_lookup_identifier};
 
     // ---------------------------------------------------------------
     // Spec §7.1 normalization tests (audit §5, P-02).
@@ -184,25 +155,4 @@ mod tests {
         assert_eq!(ident.value(), "a\"b");
         assert!(ident.is_quoted());
     }
-
-    #[test]
-    fn test_identifiers_match_postgres_rules() {
-        // unquoted on both sides => case-insensitive
-        assert!(identifiers_match("Foo", false, "foo", false));
-        // quoted side preserves case
-        assert!(identifiers_match("foo", false, "foo", true));
-        assert!(!identifiers_match("Foo", false, "Foo", true));
-        assert!(!identifiers_match("Foo", true, "foo", true));
-    }
-
-    #[test]
-    fn test_stored_identifier_matches_lookup() {
-        assert!(stored_identifier_matches_lookup("Foo", false, "foo"));
-        assert!(stored_identifier_matches_lookup("Foo", false, "\"foo\""));
-        assert!(!stored_identifier_matches_lookup("Foo", false, "\"Foo\""));
-
-        assert!(stored_identifier_matches_lookup("Foo", true, "\"Foo\""));
-        assert!(!stored_identifier_matches_lookup("Foo", true, "\"foo\""));
-        assert!(!stored_identifier_matches_lookup("Foo", true, "foo"));
-    }
 }
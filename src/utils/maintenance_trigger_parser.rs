@@ -4,14 +4,17 @@ use alloc::{boxed::Box, string::String, vec::Vec};
 use core::iter::Filter;
 
 use sqlparser::{
-    ast::Expr,
+    ast::{
+        BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, FunctionArguments,
+        ObjectNamePart, Value,
+    },
     dialect::PostgreSqlDialect,
     keywords::Keyword,
     parser::Parser,
     tokenizer::{Token, Tokenizer},
 };
 
-use crate::traits::{DatabaseLike, TableLike};
+use crate::traits::{DatabaseLike, DmlKind, MaintenanceKind, TableLike};
 
 /// Result type for maintenance assignments.
 pub type MaintenanceAssignments<'a, T> =
@@ -19,6 +22,9 @@ pub type MaintenanceAssignments<'a, T> =
 
 /// Parses the body of a trigger function.
 ///
+/// The assignments may be guarded by `IF TG_OP = '...' THEN ... END IF;`
+/// blocks, which are not nested.
+///
 /// # Arguments
 ///
 /// * `body` - The SQL body of the trigger function.
@@ -41,6 +47,86 @@ pub fn parse_maintenance_body<'a, T>(
     table: &'a T,
     database: &'a T::DB,
 ) -> Result<MaintenanceAssignments<'a, T>, ()>
+where
+    T: TableLike,
+{
+    parse_maintenance_statements(body, table, database).map(|(assignments, _)| assignments)
+}
+
+/// Parses the body of a trigger function, returning the benign patterns it
+/// matches in first-seen order and without duplicates.
+///
+/// # Arguments
+///
+/// * `body` - The SQL body of the trigger function.
+/// * `table` - The table the trigger is defined on.
+/// * `database` - The database context for looking up columns.
+///
+/// # Errors
+///
+/// Returns `Err(())` if the body is not a valid maintenance trigger body or
+/// contains invalid column references.
+#[allow(clippy::result_unit_err)]
+pub fn parse_maintenance_kinds<T>(
+    body: &str,
+    table: &T,
+    database: &T::DB,
+) -> Result<Vec<MaintenanceKind>, ()>
+where
+    T: TableLike,
+{
+    parse_maintenance_statements(body, table, database).map(|(_, kinds)| kinds)
+}
+
+/// Returns whether the trigger condition only fires the trigger when the row
+/// changes, as in `WHEN (OLD.* IS DISTINCT FROM NEW.*)`.
+///
+/// # Arguments
+///
+/// * `condition` - The `WHEN` condition of the trigger.
+#[must_use]
+pub fn is_changed_row_condition(condition: &Expr) -> bool {
+    match condition {
+        Expr::Nested(inner) => is_changed_row_condition(inner),
+        Expr::IsDistinctFrom(left, right) => {
+            matches!(
+                (row_record(left), row_record(right)),
+                (Some(left), Some(right)) if left != right
+            )
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the expression designates the whole `NEW` row (`true`) or
+/// the whole `OLD` row (`false`), as in `NEW` or `NEW.*`.
+fn row_record(expr: &Expr) -> Option<bool> {
+    let ident = match expr {
+        Expr::Identifier(ident) => ident,
+        Expr::QualifiedWildcard(name, _) => match name.0.as_slice() {
+            [ObjectNamePart::Identifier(ident)] => ident,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if ident.quote_style.is_some() {
+        None
+    } else if ident.value.eq_ignore_ascii_case("NEW") {
+        Some(true)
+    } else if ident.value.eq_ignore_ascii_case("OLD") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parses the statements of a trigger function body, returning its
+/// assignments along with the benign patterns they match.
+fn parse_maintenance_statements<'a, T>(
+    body: &str,
+    table: &'a T,
+    database: &'a T::DB,
+) -> Result<(MaintenanceAssignments<'a, T>, Vec<MaintenanceKind>), ()>
 where
     T: TableLike,
 {
@@ -51,6 +137,8 @@ where
     };
 
     let mut assignments = Vec::new();
+    let mut kinds = Vec::new();
+    let mut guard_start = None;
     let mut iter = MaintenanceBodyIterator::new(tokens);
 
     loop {
@@ -60,8 +148,29 @@ where
                 let Some(column) = table.column(&col_name, database) else {
                     return Err(());
                 };
+                let kind = if coalesce_target(&expr)
+                    .and_then(|target| table.column(&target, database))
+                    .is_some_and(|target| core::ptr::eq(target, column))
+                {
+                    MaintenanceKind::CoalesceDefault
+                } else {
+                    MaintenanceKind::Assignment
+                };
+                push_kind(&mut kinds, kind);
                 assignments.push((column, *expr));
             }
+            Some(Ok(MaintenanceToken::Guard(operations))) => {
+                guard_start = Some(assignments.len());
+                for operation in operations {
+                    push_kind(&mut kinds, MaintenanceKind::OperationGuard(operation));
+                }
+            }
+            Some(Ok(MaintenanceToken::EndGuard)) => {
+                // A guard without assignments is not a maintenance pattern.
+                if guard_start.take() == Some(assignments.len()) {
+                    return Err(());
+                }
+            }
             Some(Ok(MaintenanceToken::End)) => {
                 iter.finalize()?;
                 break;
@@ -74,13 +183,101 @@ where
         return Err(());
     }
 
-    Ok(assignments)
+    Ok((assignments, kinds))
+}
+
+/// Appends the kind unless it was already matched.
+fn push_kind(kinds: &mut Vec<MaintenanceKind>, kind: MaintenanceKind) {
+    if !kinds.contains(&kind) {
+        kinds.push(kind);
+    }
+}
+
+/// Returns the name of the column of the new row an expression of the form
+/// `COALESCE(NEW.column, ...)` defaults, if it has that form.
+fn coalesce_target(expr: &Expr) -> Option<String> {
+    let Expr::Function(Function { name, args: FunctionArguments::List(list), .. }) = expr else {
+        return None;
+    };
+    if !matches!(
+        name.0.as_slice(),
+        [ObjectNamePart::Identifier(ident)] if ident.value.eq_ignore_ascii_case("COALESCE")
+    ) || list.args.len() < 2
+    {
+        return None;
+    }
+    let FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::CompoundIdentifier(idents))) =
+        &list.args[0]
+    else {
+        return None;
+    };
+    match idents.as_slice() {
+        [record, column]
+            if record.quote_style.is_none() && record.value.eq_ignore_ascii_case("NEW") =>
+        {
+            Some(column_lookup_name(&column.value, column.quote_style.is_some()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the name under which a column identifier is looked up in its
+/// table, keeping quoted identifiers quoted.
+fn column_lookup_name(value: &str, quoted: bool) -> String {
+    if quoted { format!("\"{}\"", value.replace('\"', "\"\"")) } else { value.into() }
+}
+
+/// Returns the operations a `TG_OP` guard condition restricts assignments
+/// to, as in `TG_OP = 'UPDATE'` or `TG_OP IN ('INSERT', 'UPDATE')`, or `None`
+/// if the condition is not such a guard.
+fn guard_operations(condition: &Expr) -> Option<Vec<DmlKind>> {
+    match condition {
+        Expr::Nested(inner) => guard_operations(inner),
+        Expr::BinaryOp { left, op: BinaryOperator::Or, right } => {
+            let mut operations = guard_operations(left)?;
+            operations.extend(guard_operations(right)?);
+            Some(operations)
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::Eq, right } if is_tg_op(left) => {
+            Some(vec![operation(right)?])
+        }
+        Expr::InList { expr, list, negated: false } if is_tg_op(expr) => {
+            list.iter().map(operation).collect()
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether the expression is the `TG_OP` variable.
+fn is_tg_op(expr: &Expr) -> bool {
+    matches!(expr, Expr::Identifier(ident) if ident.value.eq_ignore_ascii_case("TG_OP"))
+}
+
+/// Returns the operation named by a string literal compared to `TG_OP`.
+fn operation(expr: &Expr) -> Option<DmlKind> {
+    let Expr::Value(value) = expr else {
+        return None;
+    };
+    let Value::SingleQuotedString(name) = &value.value else {
+        return None;
+    };
+    match name.as_str() {
+        "INSERT" => Some(DmlKind::Insert),
+        "UPDATE" => Some(DmlKind::Update),
+        "DELETE" => Some(DmlKind::Delete),
+        _ => None,
+    }
 }
 
 /// A token representing a high-level construct in a maintenance trigger body.
 pub enum MaintenanceToken {
     /// An assignment to a column (e.g., `NEW.col = expr;`).
     Assignment(String, Box<Expr>),
+    /// The start of a block only run for some operations (e.g.,
+    /// `IF TG_OP = 'UPDATE' THEN`).
+    Guard(Vec<DmlKind>),
+    /// The end of a guarded block (e.g., `END IF;`).
+    EndGuard,
     /// The end of the maintenance statements (e.g., `RETURN NEW;`).
     End,
 }
@@ -90,6 +287,8 @@ type TokenFilterIter = Filter<alloc::vec::IntoIter<Token>, fn(&Token) -> bool>;
 /// An iterator over the high-level tokens of a maintenance trigger body.
 struct MaintenanceBodyIterator {
     tokens: core::iter::Peekable<TokenFilterIter>,
+    /// Whether the iterator is within a guarded block.
+    guarded: bool,
 }
 
 /// Predicate to skip whitespace tokens.
@@ -113,7 +312,7 @@ impl MaintenanceBodyIterator {
             }
         }
 
-        Self { tokens }
+        Self { tokens, guarded: false }
     }
 
     /// Verifies that the remaining tokens are valid (i.e., optional `END`
@@ -165,6 +364,30 @@ impl MaintenanceBodyIterator {
             false
         }
     }
+
+    /// Parses the condition of a guard following its `IF` keyword, up to and
+    /// including its `THEN` keyword.
+    fn next_guard(&mut self) -> Result<MaintenanceToken, ()> {
+        // Guards are not nested.
+        if self.guarded {
+            return Err(());
+        }
+
+        let mut condition_tokens = Vec::new();
+        loop {
+            match self.tokens.next() {
+                Some(Token::Word(w)) if w.keyword == Keyword::THEN => break,
+                Some(Token::EOF) | None => return Err(()),
+                Some(t) => condition_tokens.push(t),
+            }
+        }
+
+        let mut parser = Parser::new(&PostgreSqlDialect {}).with_tokens(condition_tokens);
+        let condition = parser.parse_expr().map_err(|_| ())?;
+        let operations = guard_operations(&condition).ok_or(())?;
+        self.guarded = true;
+        Ok(MaintenanceToken::Guard(operations))
+    }
 }
 
 impl Iterator for MaintenanceBodyIterator {
@@ -175,6 +398,10 @@ impl Iterator for MaintenanceBodyIterator {
 
         // Check for RETURN NEW; (End condition for assignments)
         if self.matches_keyword(Keyword::RETURN) {
+            // Returning from within a guard is not a maintenance pattern.
+            if self.guarded {
+                return Some(Err(()));
+            }
             self.tokens.next();
             if !self.consume_keyword(Keyword::NEW) {
                 return Some(Err(()));
@@ -185,6 +412,20 @@ impl Iterator for MaintenanceBodyIterator {
             return Some(Ok(MaintenanceToken::End));
         }
 
+        // Check for IF <guard> THEN
+        if self.consume_keyword(Keyword::IF) {
+            return Some(self.next_guard());
+        }
+
+        // Check for END IF; closing a guard
+        if self.guarded && self.consume_keyword(Keyword::END) {
+            if !self.consume_keyword(Keyword::IF) || !self.consume_token(&Token::SemiColon) {
+                return Some(Err(()));
+            }
+            self.guarded = false;
+            return Some(Ok(MaintenanceToken::EndGuard));
+        }
+
         // Must be NEW.col = expr;
         if !self.consume_keyword(Keyword::NEW) {
             if let Some(Token::EOF) = self.tokens.peek() {
@@ -198,13 +439,7 @@ impl Iterator for MaintenanceBodyIterator {
 
         // Column Name
         let col_name = match self.tokens.peek() {
-            Some(Token::Word(w)) => {
-                if w.quote_style.is_some() {
-                    format!("\"{}\"", w.value.replace('\"', "\"\""))
-                } else {
-                    w.value.clone()
-                }
-            }
+            Some(Token::Word(w)) => column_lookup_name(&w.value, w.quote_style.is_some()),
             Some(Token::SingleQuotedString(s)) => s.clone(),
             _ => return Some(Err(())),
         };
@@ -275,6 +510,13 @@ mod tests {
         parse_maintenance_body(body, &table, &db).map(|v| v.len())
     }
 
+    fn kinds(schema_sql: &str, body: &str) -> Result<Vec<MaintenanceKind>, ()> {
+        let db =
+            ParserDB::parse::<GenericDialect>(schema_sql).expect("Failed to create DB from schema");
+        let table = db.table(None, "t").expect("Failed to find table 't'");
+        parse_maintenance_kinds(body, &table, &db)
+    }
+
     #[test]
     fn test_valid_single_assignment() {
         let schema = "CREATE TABLE t (a INT)";
@@ -402,4 +644,102 @@ mod tests {
         let body = "BEGIN END;";
         assert!(parse(schema, body).is_err());
     }
+
+    #[test]
+    fn test_plain_assignment_kind() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body = "BEGIN NEW.a = 1; RETURN NEW; END;";
+        assert_eq!(kinds(schema, body), Ok(vec![MaintenanceKind::Assignment]));
+    }
+
+    #[test]
+    fn test_operation_guard() {
+        let schema = "CREATE TABLE t (a INT, updated_at TIMESTAMP)";
+        let body = "BEGIN NEW.a = 1; IF TG_OP = 'UPDATE' THEN NEW.updated_at := now(); END IF; \
+                    RETURN NEW; END;";
+        assert_eq!(parse(schema, body), Ok(2));
+        assert_eq!(
+            kinds(schema, body),
+            Ok(vec![MaintenanceKind::Assignment, MaintenanceKind::OperationGuard(DmlKind::Update)])
+        );
+    }
+
+    #[test]
+    fn test_operation_guard_on_several_operations() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body =
+            "BEGIN IF TG_OP IN ('INSERT', 'UPDATE') THEN NEW.a = 1; END IF; RETURN NEW; END;";
+        assert_eq!(
+            kinds(schema, body),
+            Ok(vec![
+                MaintenanceKind::OperationGuard(DmlKind::Insert),
+                MaintenanceKind::OperationGuard(DmlKind::Update),
+                MaintenanceKind::Assignment,
+            ])
+        );
+        let body = "BEGIN IF TG_OP = 'INSERT' OR TG_OP = 'DELETE' THEN NEW.a = 1; END IF; \
+                    RETURN NEW; END;";
+        assert_eq!(
+            kinds(schema, body),
+            Ok(vec![
+                MaintenanceKind::OperationGuard(DmlKind::Insert),
+                MaintenanceKind::OperationGuard(DmlKind::Delete),
+                MaintenanceKind::Assignment,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_non_operation_guard_fails() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body = "BEGIN IF NEW.a > 1 THEN NEW.a = 1; END IF; RETURN NEW; END;";
+        assert!(parse(schema, body).is_err());
+    }
+
+    #[test]
+    fn test_nested_guard_fails() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body = "BEGIN IF TG_OP = 'UPDATE' THEN IF TG_OP = 'UPDATE' THEN NEW.a = 1; END IF; \
+                    END IF; RETURN NEW; END;";
+        assert!(parse(schema, body).is_err());
+    }
+
+    #[test]
+    fn test_unclosed_guard_fails() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body = "BEGIN IF TG_OP = 'UPDATE' THEN NEW.a = 1; RETURN NEW; END;";
+        assert!(parse(schema, body).is_err());
+    }
+
+    #[test]
+    fn test_empty_guard_fails() {
+        let schema = "CREATE TABLE t (a INT)";
+        let body = "BEGIN NEW.a = 1; IF TG_OP = 'UPDATE' THEN END IF; RETURN NEW; END;";
+        assert!(parse(schema, body).is_err());
+    }
+
+    #[test]
+    fn test_coalesce_default() {
+        let schema = "CREATE TABLE t (a INT, b INT)";
+        let body = "BEGIN NEW.a = COALESCE(NEW.a, 0); RETURN NEW; END;";
+        assert_eq!(kinds(schema, body), Ok(vec![MaintenanceKind::CoalesceDefault]));
+        // Defaulting another column is a plain assignment.
+        let body = "BEGIN NEW.a = COALESCE(NEW.b, 0); RETURN NEW; END;";
+        assert_eq!(kinds(schema, body), Ok(vec![MaintenanceKind::Assignment]));
+    }
+
+    #[test]
+    fn test_changed_row_condition() {
+        let condition = |sql: &str| {
+            Parser::new(&PostgreSqlDialect {})
+                .try_with_sql(sql)
+                .and_then(|mut parser| parser.parse_expr())
+                .expect("Failed to parse condition")
+        };
+        assert!(is_changed_row_condition(&condition("(OLD.* IS DISTINCT FROM NEW.*)")));
+        assert!(is_changed_row_condition(&condition("NEW IS DISTINCT FROM OLD")));
+        assert!(!is_changed_row_condition(&condition("OLD.* IS DISTINCT FROM OLD.*")));
+        assert!(!is_changed_row_condition(&condition("OLD.a IS DISTINCT FROM NEW.a")));
+        assert!(!is_changed_row_condition(&condition("NEW.a > 10")));
+    }
 }
@@ -0,0 +1,54 @@
+//! Submodule extracting the sequence named by a `nextval(...)` column default.
+
+/// Returns the `(schema, name)` of the sequence advanced by a
+/// `nextval('...')` expression, if the provided default value is one.
+///
+/// The function name is matched case-insensitively and a trailing cast of the
+/// literal (such as `::regclass`) is ignored. The schema and name parts are
+/// returned as lookup identifiers, keeping their double quotes if any.
+pub(crate) fn nextval_sequence_name(default_value: &str) -> Option<(Option<&str>, &str)> {
+    let default_value = default_value.trim();
+    let prefix = default_value.get(..7)?;
+    if !prefix.eq_ignore_ascii_case("nextval") {
+        return None;
+    }
+    let arguments = default_value[7..].trim_start().strip_prefix('(')?.trim_start();
+    let literal = arguments.strip_prefix('\'')?;
+    let literal = &literal[..literal.find('\'')?];
+
+    let mut in_quotes = false;
+    let mut separator = None;
+    for (index, character) in literal.char_indices() {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            '.' if !in_quotes => separator = Some(index),
+            _ => {}
+        }
+    }
+
+    match separator {
+        Some(index) => Some((Some(&literal[..index]), &literal[index + 1..])),
+        None => Some((None, literal)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nextval_sequence_name;
+
+    #[test]
+    fn test_nextval_sequence_name() {
+        assert_eq!(nextval_sequence_name("nextval('users_id_seq')"), Some((None, "users_id_seq")));
+        assert_eq!(
+            nextval_sequence_name("NEXTVAL('app.users_id_seq'::REGCLASS)"),
+            Some((Some("app"), "users_id_seq"))
+        );
+        assert_eq!(
+            nextval_sequence_name("nextval('\"My.Schema\".\"Seq\"')"),
+            Some((Some("\"My.Schema\""), "\"Seq\""))
+        );
+        assert_eq!(nextval_sequence_name("0"), None);
+        assert_eq!(nextval_sequence_name("NOW()"), None);
+        assert_eq!(nextval_sequence_name("nextval(seq)"), None);
+    }
+}
@@ -1,8 +1,11 @@
 //! Submodule providing a function for normalizing `SQLParser` data types.
 
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
-use sqlparser::ast::{DataType, ObjectName, ObjectNamePart, TimezoneInfo};
+use sqlparser::{
+    ast::{DataType, ObjectName, ObjectNamePart, TimezoneInfo},
+    keywords::ALL_KEYWORDS,
+};
 
 /// Normalizes `SQLParser` data types to a standard representation.
 ///
@@ -47,23 +50,53 @@ use sqlparser::ast::{DataType, ObjectName, ObjectNamePart, TimezoneInfo};
 ///     vec![],
 /// );
 /// assert_eq!(normalize_sqlparser_type(&custom_other), "OTHER");
-/// ```
-///
-/// # Panics
-///
-/// Panics on data types not yet supported by the normalizer:
-///
-/// ```should_panic
-/// use sql_traits::utils::normalize_sqlparser_type;
-/// use sqlparser::ast::DataType;
 ///
-/// // `HugeInt` has no canonical mapping yet — calling normalize panics.
-/// normalize_sqlparser_type(&DataType::HugeInt);
+/// // Data types without a standard representation are named as written,
+/// // without their parameters.
+/// let qualified = DataType::Custom(
+///     ObjectName(vec![
+///         ObjectNamePart::Identifier(sqlparser::ast::Ident::new("public")),
+///         ObjectNamePart::Identifier(sqlparser::ast::Ident::new("citext")),
+///     ]),
+///     vec![],
+/// );
+/// assert_eq!(normalize_sqlparser_type(&qualified), "citext");
+/// assert_eq!(normalize_sqlparser_type(&DataType::Regclass), "REGCLASS");
 /// ```
 #[must_use]
 #[inline]
 pub fn normalize_sqlparser_type(sqlparser_type: &DataType) -> &str {
-    match sqlparser_type {
+    try_normalize_sqlparser_type(sqlparser_type)
+        .unwrap_or_else(|| written_type_name(sqlparser_type))
+}
+
+/// Returns the name of a data type without a standard representation, as
+/// written and without its parameters.
+///
+/// Custom types are named by the last part of their name, and built-in types
+/// by the keyword they are written with. Types whose name is not a keyword,
+/// such as the omitted type of a SQLite column, are named by an empty string.
+fn written_type_name(sqlparser_type: &DataType) -> &str {
+    if let DataType::Custom(ObjectName(object_names), _) = sqlparser_type
+        && let Some(ObjectNamePart::Identifier(ident)) = object_names.last()
+    {
+        return ident.value.as_str();
+    }
+    let rendered = sqlparser_type.to_string();
+    let name: String = rendered
+        .chars()
+        .take_while(|character| character.is_ascii_alphanumeric() || *character == '_')
+        .map(|character| character.to_ascii_uppercase())
+        .collect();
+    ALL_KEYWORDS.binary_search(&name.as_str()).map_or("", |index| ALL_KEYWORDS[index])
+}
+
+/// Normalizes `SQLParser` data types to a standard representation, returning
+/// `None` for the data types without one, which
+/// [`normalize_sqlparser_type`] names as written instead.
+#[must_use]
+pub(crate) fn try_normalize_sqlparser_type(sqlparser_type: &DataType) -> Option<&str> {
+    Some(match sqlparser_type {
         // INT family. Unsigned variants fold to their signed token, dropping
         // `UNSIGNED` as the display width is dropped.
         DataType::Int(_)
@@ -87,6 +120,8 @@ pub fn normalize_sqlparser_type(sqlparser_type: &DataType) -> &str {
         DataType::Numeric(_) | DataType::BigNumeric(_) => "NUMERIC",
         // BOOL family
         DataType::Bool | DataType::Boolean => "BOOLEAN",
+        // BIT, as the SQL Server boolean
+        DataType::Bit(_) => "BIT",
         // STRING family
         DataType::Text => "TEXT",
         DataType::Varchar(_) => "VARCHAR",
@@ -113,6 +148,8 @@ pub fn normalize_sqlparser_type(sqlparser_type: &DataType) -> &str {
         DataType::Timestamp(_, TimezoneInfo::Tz) => "TIMESTAMPTZ",
         // MySQL wall-clock timestamp, precision dropped like a length.
         DataType::Datetime(_) => "DATETIME",
+        // INTERVAL, fields and precision dropped like a length.
+        DataType::Interval { .. } => "INTERVAL",
         // UUID
         DataType::Uuid => "UUID",
         // JSON family
@@ -122,29 +159,23 @@ pub fn normalize_sqlparser_type(sqlparser_type: &DataType) -> &str {
         // for value decoding, just as a length is not (`VARCHAR(255)` -> "VARCHAR").
         DataType::Enum(..) => "ENUM",
         DataType::Set(..) => "SET",
+        // Arrays normalize to their family token, dropping the element type.
+        DataType::Array(_) => "ARRAY",
         // Custom: single-ident pass-through; pinned GEOGRAPHY/GEOMETRY recognition.
         DataType::Custom(ObjectName(object_names), segments) => {
-            if let [ObjectNamePart::Identifier(ident)] = object_names.as_slice() {
-                if ident.value.as_str() == "GEOGRAPHY" && segments == &["Point", "4326"] {
-                    return "GEOGRAPHY(Point, 4326)";
-                }
-                if ident.value.as_str() == "GEOMETRY" && segments == &["Point", "4326"] {
-                    return "GEOMETRY(Point, 4326)";
-                }
-                ident.value.as_str()
-            } else {
-                unimplemented!(
-                    "Normalization for custom SQLParser data type `{sqlparser_type:?}` is not yet implemented for object names `{object_names:?}` and segments `{segments:?}`"
-                )
+            let [ObjectNamePart::Identifier(ident)] = object_names.as_slice() else {
+                return None;
+            };
+            if ident.value.as_str() == "GEOGRAPHY" && segments == &["Point", "4326"] {
+                return Some("GEOGRAPHY(Point, 4326)");
             }
+            if ident.value.as_str() == "GEOMETRY" && segments == &["Point", "4326"] {
+                return Some("GEOMETRY(Point, 4326)");
+            }
+            ident.value.as_str()
         }
-        _ => {
-            unimplemented!(
-                "Normalization for SQLParser data type `{sqlparser_type:?}` is not yet implemented `{}`",
-                sqlparser_type.to_string()
-            )
-        }
-    }
+        _ => return None,
+    })
 }
 
 #[cfg(test)]
@@ -164,6 +195,7 @@ mod tests {
         assert_eq!(normalize_sqlparser_type(&DataType::SmallInt(None)), "SMALLINT");
         assert_eq!(normalize_sqlparser_type(&DataType::Bool), "BOOLEAN");
         assert_eq!(normalize_sqlparser_type(&DataType::Boolean), "BOOLEAN");
+        assert_eq!(normalize_sqlparser_type(&DataType::Bit(None)), "BIT");
     }
 
     #[test]
@@ -208,25 +240,25 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Normalization for SQLParser data type")]
-    fn test_normalize_sqlparser_type_unimplemented_huge_int() {
-        // `HugeInt` is genuinely unsupported by the current normalizer;
-        // it keeps the unimplemented-fallthrough path under test after
-        // BigInt and Date were promoted to supported variants.
-        let _ = normalize_sqlparser_type(&DataType::HugeInt);
+    fn test_normalize_sqlparser_type_written_name() {
+        // Data types without a standard representation are named by the
+        // keyword they are written with, without panicking.
+        assert_eq!(try_normalize_sqlparser_type(&DataType::Regclass), None);
+        assert_eq!(normalize_sqlparser_type(&DataType::Regclass), "REGCLASS");
+        assert_eq!(normalize_sqlparser_type(&DataType::Unspecified), "");
     }
 
     #[test]
-    #[should_panic(expected = "Normalization for custom SQLParser data type")]
-    fn test_normalize_sqlparser_type_custom_unimplemented() {
+    fn test_normalize_sqlparser_type_custom_written_name() {
         let custom = DataType::Custom(
             ObjectName(vec![
                 ObjectNamePart::Identifier(Ident::new("Many")),
                 ObjectNamePart::Identifier(Ident::new("Parts")),
             ]),
-            vec![],
+            vec!["1".to_string()],
         );
-        let _ = normalize_sqlparser_type(&custom);
+        assert_eq!(try_normalize_sqlparser_type(&custom), None);
+        assert_eq!(normalize_sqlparser_type(&custom), "Parts");
     }
 
     // -----------------------------------------------------------------
@@ -313,6 +345,10 @@ mod tests {
     #[test]
     fn test_normalize_sqlparser_type_date_family() {
         assert_eq!(normalize_sqlparser_type(&DataType::Date), "DATE");
+        assert_eq!(
+            normalize_sqlparser_type(&DataType::Interval { fields: None, precision: None }),
+            "INTERVAL"
+        );
     }
 
     #[test]
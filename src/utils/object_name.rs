@@ -2,8 +2,8 @@
 //! [`DatabaseLike`].
 //!
 //! These helpers are the single source of truth for turning a parsed SQL object
-//! name (`table`, `schema.table`) into a resolved table, applying PostgreSQL
-//! identifier semantics through [`identifiers_match`].
+//! name (`table`, `schema.table`) into a resolved table, comparing identifiers
+//! through the [`IdentifierCasing`] of the dialect.
 //! They are generic over [`TableLike`] so that both the concrete `ParserDB`
 //! resolution paths and the trait-on-AST data-statement analysis share one
 //! implementation.
@@ -14,12 +14,15 @@ use alloc::{
     vec::Vec,
 };
 
-use sqlparser::ast::{Ident, ObjectName, ObjectNamePart};
+use sqlparser::{
+    ast::{Ident, ObjectName, ObjectNamePart},
+    tokenizer::Span,
+};
 
 use crate::{
     errors::LookupError,
-    traits::{DatabaseLike, TableLike},
-    utils::identifier_resolution::identifiers_match,
+    structs::{IdentifierCasing, QualifiedName},
+    traits::{DatabaseLike, DialectLike, TableLike},
 };
 
 /// Returns the last identifier part of an object name as `(value, quoted)`.
@@ -38,6 +41,20 @@ pub(crate) fn object_name_last_part(object_name: &ObjectName) -> Option<(&str, b
     }
 }
 
+/// Returns the span of an identifier, or `None` when it carries no location.
+pub(crate) fn ident_span(ident: &Ident) -> Option<Span> {
+    (ident.span != Span::empty()).then_some(ident.span)
+}
+
+/// Returns the span of the last identifier part of an object name, or `None`
+/// when it carries no location.
+pub(crate) fn object_name_span(object_name: &ObjectName) -> Option<Span> {
+    match object_name.0.last() {
+        Some(ObjectNamePart::Identifier(ident)) => ident_span(ident),
+        _ => None,
+    }
+}
+
 /// Extracts the schema component (the second-to-last part) of an object name as
 /// `(value, quoted)`, when the name has more than one part.
 ///
@@ -64,12 +81,16 @@ pub(crate) fn schema_from_object_name(object_name: &ObjectName) -> Option<(&str,
 ///
 /// This is the matching style used by grant resolution, where object names may
 /// carry catalog-qualified prefixes.
-pub(crate) fn table_matches_object_name<T: TableLike>(table: &T, object_name: &ObjectName) -> bool {
+pub(crate) fn table_matches_object_name<T: TableLike>(
+    table: &T,
+    object_name: &ObjectName,
+    casing: IdentifierCasing,
+) -> bool {
     let Some((table_lookup_name, table_lookup_quoted)) = object_name_last_part(object_name) else {
         return false;
     };
 
-    if !identifiers_match(
+    if !casing.matches(
         table.table_name(),
         table.table_name_is_quoted(),
         table_lookup_name,
@@ -81,7 +102,7 @@ pub(crate) fn table_matches_object_name<T: TableLike>(table: &T, object_name: &O
     match (schema_from_object_name(object_name), table.table_schema()) {
         (None, None) => true,
         (Some((schema_lookup, schema_lookup_quoted)), Some(table_schema)) => {
-            identifiers_match(
+            casing.matches(
                 table_schema,
                 table.table_schema_is_quoted(),
                 schema_lookup,
@@ -137,8 +158,9 @@ pub(crate) fn table_matches_lookup_idents<T: TableLike>(
     table: &T,
     schema_ident: Option<&Ident>,
     table_ident: &Ident,
+    casing: IdentifierCasing,
 ) -> bool {
-    if !identifiers_match(
+    if !casing.matches(
         table.table_name(),
         table.table_name_is_quoted(),
         table_ident.value.as_str(),
@@ -150,7 +172,7 @@ pub(crate) fn table_matches_lookup_idents<T: TableLike>(
     match (schema_ident, table.table_schema()) {
         (None, None) => true,
         (Some(schema_ident), Some(table_schema)) => {
-            identifiers_match(
+            casing.matches(
                 table_schema,
                 table.table_schema_is_quoted(),
                 schema_ident.value.as_str(),
@@ -165,6 +187,15 @@ fn quoted_identifier(value: &str) -> String {
     alloc::format!("\"{}\"", value.replace('\"', "\"\""))
 }
 
+/// Renders an identifier already normalized with
+/// [`normalize_identifier`](crate::utils::identifier_resolution::normalize_identifier)
+/// back into SQL, quoting it unless it survives lowercase folding unchanged.
+pub(crate) fn render_normalized_identifier(normalized: &str) -> String {
+    let is_bare = normalized.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && normalized.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_bare { normalized.to_string() } else { quoted_identifier(normalized) }
+}
+
 /// Renders a table for inclusion in an ambiguity error, quoting parts that were
 /// originally quoted.
 pub(crate) fn render_table_candidate<T: TableLike>(table: &T) -> String {
@@ -223,14 +254,59 @@ pub(crate) fn resolve_table_from_candidates<'a, T: TableLike>(
 pub(crate) fn resolve_table_object_name_in_iter<'a, T: TableLike>(
     tables: impl Iterator<Item = &'a T>,
     object_name: &ObjectName,
+    casing: IdentifierCasing,
 ) -> Result<Option<&'a T>, LookupError> {
     let (schema_ident, table_ident) = object_name_identifiers(object_name)?;
     let candidates: Vec<&T> = tables
-        .filter(|table| table_matches_lookup_idents(*table, schema_ident, table_ident))
+        .filter(|table| table_matches_lookup_idents(*table, schema_ident, table_ident, casing))
         .collect();
     resolve_table_from_candidates(object_name, &candidates)
 }
 
+/// Resolves a table from a one-part or two-part object name against an iterator
+/// of tables, looking up unqualified names within each schema of the search
+/// path when no schema-less table matches.
+///
+/// # Errors
+///
+/// Returns an error when the object name is malformed for table lookup, or when
+/// the lookup is ambiguous.
+pub(crate) fn resolve_table_object_name_in_search_path<'a, T: TableLike>(
+    tables: impl Iterator<Item = &'a T>,
+    object_name: &ObjectName,
+    search_path: &[String],
+    casing: IdentifierCasing,
+) -> Result<Option<&'a T>, LookupError> {
+    if search_path.is_empty() {
+        return resolve_table_object_name_in_iter(tables, object_name, casing);
+    }
+    let (schema_ident, table_ident) = object_name_identifiers(object_name)?;
+    let lookup = QualifiedName::from_parts(
+        schema_ident.map(|ident| (ident.value.as_str(), ident.quote_style.is_some())),
+        (table_ident.value.as_str(), table_ident.quote_style.is_some()),
+    );
+    let tables: Vec<&T> = tables.collect();
+    for candidate in lookup.search_candidates(search_path) {
+        let candidates: Vec<&T> = tables
+            .iter()
+            .copied()
+            .filter(|table| {
+                candidate.matches_with_casing(
+                    casing,
+                    table.table_schema(),
+                    table.table_schema_is_quoted(),
+                    table.table_name(),
+                    table.table_name_is_quoted(),
+                )
+            })
+            .collect();
+        if let Some(table) = resolve_table_from_candidates(object_name, &candidates)? {
+            return Ok(Some(table));
+        }
+    }
+    Ok(None)
+}
+
 /// Resolves a table from an object name, falling back to schema `public` for
 /// unqualified names.
 ///
@@ -242,18 +318,19 @@ pub(crate) fn resolve_table_object_name_in_iter<'a, T: TableLike>(
 pub(crate) fn resolve_table_object_name_with_implicit_public_in_iter<'a, T: TableLike>(
     tables: impl Iterator<Item = &'a T>,
     object_name: &ObjectName,
+    casing: IdentifierCasing,
 ) -> Result<Option<&'a T>, LookupError> {
     let (schema_ident, table_ident) = object_name_identifiers(object_name)?;
     let table_refs: Vec<&T> = tables.collect();
 
     if schema_ident.is_some() {
-        return resolve_table_object_name_in_iter(table_refs.into_iter(), object_name);
+        return resolve_table_object_name_in_iter(table_refs.into_iter(), object_name, casing);
     }
 
     let unqualified_candidates: Vec<&T> = table_refs
         .iter()
         .copied()
-        .filter(|table| table_matches_lookup_idents(*table, None, table_ident))
+        .filter(|table| table_matches_lookup_idents(*table, None, table_ident, casing))
         .collect();
     let unqualified = resolve_table_from_candidates(object_name, &unqualified_candidates)?;
 
@@ -262,8 +339,8 @@ pub(crate) fn resolve_table_object_name_with_implicit_public_in_iter<'a, T: Tabl
         .copied()
         .filter(|table| {
             table.table_schema().is_some_and(|schema_name| {
-                identifiers_match(schema_name, table.table_schema_is_quoted(), "public", false)
-            }) && identifiers_match(
+                casing.matches(schema_name, table.table_schema_is_quoted(), "public", false)
+            }) && casing.matches(
                 table.table_name(),
                 table.table_name_is_quoted(),
                 table_ident.value.as_str(),
@@ -312,7 +389,11 @@ pub(crate) fn resolve_object_name<'db, DB: DatabaseLike>(
     object_name: &ObjectName,
     database: &'db DB,
 ) -> Result<Option<&'db DB::Table>, LookupError> {
-    resolve_table_object_name_in_iter(database.tables(), object_name)
+    resolve_table_object_name_in_iter(
+        database.tables(),
+        object_name,
+        database.dialect().identifier_casing(),
+    )
 }
 
 #[cfg(test)]
@@ -329,7 +410,11 @@ mod tests {
         resolve_table_object_name_with_implicit_public_in_iter, schema_from_object_name,
         table_matches_lookup_idents, table_matches_object_name,
     };
-    use crate::{errors::LookupError, prelude::ParserDB, traits::TableLike};
+    use crate::{
+        errors::LookupError, prelude::ParserDB, structs::IdentifierCasing, traits::TableLike,
+    };
+
+    const LOWERCASE: IdentifierCasing = IdentifierCasing::Lowercase;
 
     fn ident(value: &str, quoted: bool) -> Ident {
         if quoted { Ident::with_quote('"', value) } else { Ident::new(value) }
@@ -406,12 +491,22 @@ mod tests {
         let users = find(&tables, "users");
         let scoped = find(&tables, "scoped");
 
-        assert!(!table_matches_object_name(users, &ObjectName(Vec::new())));
-        assert!(!table_matches_object_name(users, &obj(&[("orders", false)])));
-        assert!(table_matches_object_name(users, &obj(&[("users", false)])));
-        assert!(table_matches_object_name(scoped, &obj(&[("s", false), ("scoped", false)])));
+        assert!(!table_matches_object_name(users, &ObjectName(Vec::new()), LOWERCASE));
+        assert!(!table_matches_object_name(users, &obj(&[("orders", false)]), LOWERCASE));
+        assert!(table_matches_object_name(users, &obj(&[("users", false)]), LOWERCASE));
+        let scoped_name = obj(&[("s", false), ("scoped", false)]);
+        assert!(table_matches_object_name(scoped, &scoped_name, LOWERCASE));
         // Schema asymmetry: qualified lookup against a schema-less table.
-        assert!(!table_matches_object_name(users, &obj(&[("s", false), ("users", false)])));
+        let qualified_users = obj(&[("s", false), ("users", false)]);
+        assert!(!table_matches_object_name(users, &qualified_users, LOWERCASE));
+        // Quoted names only match regardless of case under insensitive rules.
+        let bar = find(&tables, "Bar");
+        assert!(!table_matches_object_name(bar, &obj(&[("bar", false)]), LOWERCASE));
+        assert!(table_matches_object_name(
+            bar,
+            &obj(&[("bar", false)]),
+            IdentifierCasing::Insensitive
+        ));
     }
 
     #[test]
@@ -441,15 +536,16 @@ mod tests {
         let users = find(&tables, "users");
         let scoped = find(&tables, "scoped");
 
-        assert!(table_matches_lookup_idents(users, None, &ident("users", false)));
-        assert!(!table_matches_lookup_idents(users, None, &ident("orders", false)));
+        assert!(table_matches_lookup_idents(users, None, &ident("users", false), LOWERCASE));
+        assert!(!table_matches_lookup_idents(users, None, &ident("orders", false), LOWERCASE));
         assert!(table_matches_lookup_idents(
             scoped,
             Some(&ident("s", false)),
-            &ident("scoped", false)
+            &ident("scoped", false),
+            LOWERCASE
         ));
         // Asymmetry: unqualified lookup against a schema-qualified table.
-        assert!(!table_matches_lookup_idents(scoped, None, &ident("scoped", false)));
+        assert!(!table_matches_lookup_idents(scoped, None, &ident("scoped", false), LOWERCASE));
     }
 
     #[test]
@@ -483,15 +579,17 @@ mod tests {
     #[test]
     fn resolve_in_iter_rejects_overqualified_names() {
         let tables = fixtures();
-        let resolved = resolve_table_object_name_in_iter(tables.iter(), &obj(&[("users", false)]))
-            .expect("resolves")
-            .expect("matches");
+        let resolved =
+            resolve_table_object_name_in_iter(tables.iter(), &obj(&[("users", false)]), LOWERCASE)
+                .expect("resolves")
+                .expect("matches");
         assert_eq!(resolved.table_name(), "users");
 
         assert!(matches!(
             resolve_table_object_name_in_iter(
                 tables.iter(),
                 &obj(&[("a", false), ("b", false), ("c", false)]),
+                LOWERCASE,
             ),
             Err(LookupError::InvalidObjectName { .. })
         ));
@@ -505,6 +603,7 @@ mod tests {
         let scoped = resolve_table_object_name_with_implicit_public_in_iter(
             tables.iter(),
             &obj(&[("s", false), ("scoped", false)]),
+            LOWERCASE,
         )
         .expect("resolves")
         .expect("matches");
@@ -514,6 +613,7 @@ mod tests {
         let only_pub = resolve_table_object_name_with_implicit_public_in_iter(
             tables.iter(),
             &obj(&[("only_pub", false)]),
+            LOWERCASE,
         )
         .expect("resolves")
         .expect("matches");
@@ -524,6 +624,7 @@ mod tests {
             resolve_table_object_name_with_implicit_public_in_iter(
                 tables.iter(),
                 &obj(&[("things", false)]),
+                LOWERCASE,
             ),
             Err(LookupError::AmbiguousTableLookup { .. })
         ));
@@ -533,6 +634,7 @@ mod tests {
             resolve_table_object_name_with_implicit_public_in_iter(
                 tables.iter(),
                 &obj(&[("absent", false)]),
+                LOWERCASE,
             )
             .unwrap()
             .is_none()
@@ -0,0 +1,189 @@
+//! Extraction of the pattern a check constraint matches a column against, as
+//! in `CHECK (doi ~ '^10\.\d{4,9}/')`, translated into a regular expression.
+
+use alloc::{format, string::String};
+
+use sqlparser::ast::{BinaryOperator, Expr, Value};
+
+use crate::{
+    structs::IdentifierCasing,
+    traits::ColumnLike,
+    utils::allowed_values::{is_column, literal},
+};
+
+/// The characters with a special meaning in a regular expression.
+const REGEX_META_CHARACTERS: &str = r"\.+*?()|[]{}^$#&-~";
+
+/// Pushes the provided character to the regular expression, escaping it if
+/// it has a special meaning there.
+fn push_literal(regex: &mut String, character: char) {
+    if REGEX_META_CHARACTERS.contains(character) {
+        regex.push('\\');
+    }
+    regex.push(character);
+}
+
+/// Translates a `LIKE` or `SIMILAR TO` pattern into an anchored regular
+/// expression, or returns `None` if it ends with a lone escape character.
+///
+/// # Arguments
+///
+/// * `pattern` - The pattern, using `\` as escape character.
+/// * `similar` - Whether the pattern is a `SIMILAR TO` one, whose
+///   alternations, repetitions, groups and bracket expressions are kept.
+/// * `case_insensitive` - Whether the pattern ignores the case, as `ILIKE`
+///   does.
+fn translate(pattern: &str, similar: bool, case_insensitive: bool) -> Option<String> {
+    let mut regex = String::from(if case_insensitive { "(?i)^(?:" } else { "^(?:" });
+    let mut characters = pattern.chars();
+    // Whether the characters are within a `SIMILAR TO` bracket expression,
+    // which is copied as it is.
+    let mut in_brackets = false;
+    while let Some(character) = characters.next() {
+        match character {
+            ']' if in_brackets => {
+                in_brackets = false;
+                regex.push(character);
+            }
+            _ if in_brackets || (similar && "|*+?{}()".contains(character)) => {
+                regex.push(character);
+            }
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => push_literal(&mut regex, characters.next()?),
+            '[' if similar => {
+                in_brackets = true;
+                regex.push(character);
+            }
+            _ => push_literal(&mut regex, character),
+        }
+    }
+    regex.push_str(")$");
+    Some(regex)
+}
+
+/// Returns the text of a literal string expression.
+fn text(expr: &Expr) -> Option<String> {
+    match literal(expr)? {
+        Value::SingleQuotedString(text) | Value::EscapedStringLiteral(text) => Some(text),
+        _ => None,
+    }
+}
+
+/// Returns the regular expression the expression matches the column
+/// against, or `None` if it matches it against no pattern.
+///
+/// POSIX regular expressions (`~` and `~*`) are returned as written, while
+/// the `LIKE`, `ILIKE` and `SIMILAR TO` patterns are translated into
+/// anchored regular expressions, as they must match the whole value. Of
+/// several patterns in a conjunction, the first one is returned.
+///
+/// # Arguments
+///
+/// * `expr` - The expression of the check constraint.
+/// * `column` - The column matched against the pattern.
+/// * `casing` - The casing the identifiers are compared through.
+pub(crate) fn pattern<C: ColumnLike>(
+    expr: &Expr,
+    column: &C,
+    casing: IdentifierCasing,
+) -> Option<String> {
+    match expr {
+        Expr::BinaryOp { left, op: BinaryOperator::PGRegexMatch, right }
+            if is_column(left, column, casing) =>
+        {
+            text(right)
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::PGRegexIMatch, right }
+            if is_column(left, column, casing) =>
+        {
+            text(right).map(|regex| format!("(?i){regex}"))
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::PGLikeMatch, right }
+            if is_column(left, column, casing) =>
+        {
+            translate(&text(right)?, false, false)
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::PGILikeMatch, right }
+            if is_column(left, column, casing) =>
+        {
+            translate(&text(right)?, false, true)
+        }
+        Expr::Like { negated: false, any: false, expr, pattern, escape_char: None }
+            if is_column(expr, column, casing) =>
+        {
+            translate(&text(pattern)?, false, false)
+        }
+        Expr::ILike { negated: false, any: false, expr, pattern, escape_char: None }
+            if is_column(expr, column, casing) =>
+        {
+            translate(&text(pattern)?, false, true)
+        }
+        Expr::SimilarTo { negated: false, expr, pattern, escape_char: None }
+            if is_column(expr, column, casing) =>
+        {
+            translate(&text(pattern)?, true, false)
+        }
+        Expr::BinaryOp { left, op: BinaryOperator::And, right } => {
+            pattern(left, column, casing).or_else(|| pattern(right, column, casing))
+        }
+        Expr::Nested(inner) => pattern(inner, column, casing),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{
+        dialect::{GenericDialect, PostgreSqlDialect},
+        parser::Parser,
+    };
+
+    use super::*;
+    use crate::{
+        structs::ParserDB,
+        traits::{DatabaseLike, DialectLike, TableLike},
+    };
+
+    fn regex(condition: &str) -> Option<String> {
+        let db = ParserDB::parse::<GenericDialect>("CREATE TABLE t (doi TEXT, orcid TEXT)")
+            .expect("Failed to parse");
+        let column = db.table(None, "t").unwrap().column("doi", &db).unwrap();
+        let expr = Parser::new(&PostgreSqlDialect {})
+            .try_with_sql(condition)
+            .and_then(|mut parser| parser.parse_expr())
+            .expect("Failed to parse expression");
+        pattern(&expr, column, db.dialect().identifier_casing())
+    }
+
+    #[test]
+    fn test_posix_regex() {
+        assert_eq!(regex(r"doi ~ '^10\.\d+/'").unwrap(), r"^10\.\d+/");
+        assert_eq!(regex("(doi)::text ~* '^10'::text").unwrap(), "(?i)^10");
+        assert_eq!(regex("orcid ~ '^0000'"), None);
+        assert_eq!(regex("doi !~ '^10'"), None);
+    }
+
+    #[test]
+    fn test_like() {
+        assert_eq!(regex(r"doi LIKE '10.%/_\%'").unwrap(), r"^(?:10\..*/.%)$");
+        assert_eq!(regex("doi ILIKE 'a%'").unwrap(), "(?i)^(?:a.*)$");
+        assert_eq!(regex("doi ~~ 'a_'").unwrap(), "^(?:a.)$");
+        assert_eq!(regex("doi NOT LIKE 'a%'"), None);
+        assert_eq!(regex(r"doi LIKE 'a\'"), None);
+    }
+
+    #[test]
+    fn test_similar_to() {
+        assert_eq!(
+            regex("doi SIMILAR TO '(10|11).[0-9]{4}%'").unwrap(),
+            r"^(?:(10|11)\.[0-9]{4}.*)$"
+        );
+    }
+
+    #[test]
+    fn test_conjunctions() {
+        assert_eq!(regex("doi IS NOT NULL AND (doi ~ '^10')").unwrap(), "^10");
+        assert_eq!(regex("length(doi) < 100 OR doi ~ '^10'"), None);
+    }
+}
@@ -0,0 +1,91 @@
+//! Visitor collecting the identifiers mentioned by queries and statements,
+//! used to approximate the columns they use.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{convert::Infallible, ops::ControlFlow};
+
+use sqlparser::ast::{AssignmentTarget, Expr, Query, SelectItem, SetExpr, Statement, Visitor};
+
+use crate::utils::object_name::object_name_last_part;
+
+/// Visitor collecting the identifiers mentioned by a query, and whether any
+/// of its (sub)queries projects a wildcard.
+///
+/// Besides the identifiers of expressions, the columns listed by an `INSERT`
+/// and the columns assigned by an `UPDATE` are collected. An `INSERT`
+/// without a column list writes every column, and counts as a wildcard.
+#[derive(Default)]
+pub(crate) struct QueryIdentifiers {
+    /// The identifiers, as `(value, quoted)` pairs.
+    pub(crate) identifiers: Vec<(String, bool)>,
+    /// Whether a wildcard is projected.
+    pub(crate) wildcard: bool,
+}
+
+/// Returns whether the body of a query projects a wildcard.
+fn projects_wildcard(body: &SetExpr) -> bool {
+    match body {
+        SetExpr::Select(select) => select.projection.iter().any(|item| {
+            matches!(item, SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(..))
+        }),
+        SetExpr::Query(query) => projects_wildcard(&query.body),
+        SetExpr::SetOperation { left, right, .. } => {
+            projects_wildcard(left) || projects_wildcard(right)
+        }
+        _ => false,
+    }
+}
+
+impl Visitor for QueryIdentifiers {
+    type Break = Infallible;
+
+    fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<Self::Break> {
+        self.wildcard |= projects_wildcard(&query.body);
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Break> {
+        match statement {
+            Statement::Insert(insert) => {
+                self.wildcard |= insert.columns.is_empty();
+                self.identifiers.extend(
+                    insert
+                        .columns
+                        .iter()
+                        .map(|ident| (ident.value.clone(), ident.quote_style.is_some())),
+                );
+            }
+            Statement::Update(update) => {
+                for assignment in &update.assignments {
+                    let names = match &assignment.target {
+                        AssignmentTarget::ColumnName(name) => core::slice::from_ref(name),
+                        AssignmentTarget::Tuple(names) => names.as_slice(),
+                    };
+                    self.identifiers.extend(
+                        names
+                            .iter()
+                            .filter_map(object_name_last_part)
+                            .map(|(name, quoted)| (name.to_string(), quoted)),
+                    );
+                }
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &Expr) -> ControlFlow<Self::Break> {
+        let ident = match expr {
+            Expr::Identifier(ident) => Some(ident),
+            Expr::CompoundIdentifier(idents) => idents.last(),
+            _ => None,
+        };
+        if let Some(ident) = ident {
+            self.identifiers.push((ident.value.clone(), ident.quote_style.is_some()));
+        }
+        ControlFlow::Continue(())
+    }
+}
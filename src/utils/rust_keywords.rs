@@ -0,0 +1,20 @@
+//! Keywords of the Rust language, which the generated Rust code cannot use as
+//! identifiers.
+
+/// Keywords which cannot be used as identifiers, but can be used as raw
+/// identifiers.
+pub(crate) const RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords which cannot be used as identifiers, not even as raw identifiers.
+pub(crate) const RESERVED_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Returns whether the provided name is a keyword of the Rust language.
+pub(crate) fn is_rust_keyword(name: &str) -> bool {
+    RAW_KEYWORDS.contains(&name) || RESERVED_KEYWORDS.contains(&name)
+}
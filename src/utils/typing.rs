@@ -0,0 +1,321 @@
+//! Submodule providing the inference of the data types of SQL expressions.
+
+use alloc::vec::Vec;
+
+use sqlparser::ast::{
+    BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, FunctionArguments, UnaryOperator,
+    Value,
+};
+
+use crate::{
+    traits::{ColumnLike, DatabaseLike, DialectLike, FunctionLike, TableLike},
+    utils::{
+        normalize_sqlparser_type::try_normalize_sqlparser_type, object_name::object_name_last_part,
+    },
+};
+
+/// Normalized name of the boolean data type.
+const BOOLEAN: &str = "BOOLEAN";
+/// Normalized name of the textual data type.
+const TEXT: &str = "TEXT";
+/// Normalized name of the polymorphic type of the built-in functions.
+const ANY: &str = "any";
+
+/// Normalized numeric data types, from the narrowest to the widest, as
+/// promoted by the arithmetic operators. Synonyms share the same rank.
+const NUMERIC_PROMOTION: &[&[&str]] = &[
+    &["TINYINT"],
+    &["SMALLINT", "INT2"],
+    &["MEDIUMINT"],
+    &["INT", "INT4"],
+    &["BIGINT", "INT8"],
+    &["DECIMAL", "NUMERIC"],
+    &["REAL"],
+    &["FLOAT", "DOUBLE", "DOUBLE PRECISION"],
+];
+
+/// Infers the normalized data type of an expression evaluated on the rows of
+/// the provided table.
+///
+/// Column references resolve to the data type of the column, function calls
+/// to the return type of the matching overload (including the built-in
+/// functions registered by the database), casts to their target type and
+/// operators to the type they produce, with arithmetic operators promoting
+/// their numeric operands to the widest one.
+///
+/// The data types are normalized as [`ColumnLike::data_type`] returns them.
+///
+/// # Arguments
+///
+/// * `expr` - The expression to infer the data type of.
+/// * `table` - The table whose columns the expression may reference.
+/// * `database` - The database the table and the functions belong to.
+///
+/// # Returns
+///
+/// The normalized data type of the expression, or `None` if it cannot be
+/// inferred, as for `NULL` or references to unknown columns.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::{prelude::*, utils::infer_type};
+/// use sqlparser::{dialect::GenericDialect, parser::Parser};
+///
+/// let db = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT, price NUMERIC, name TEXT, created_at TIMESTAMP);",
+/// )?;
+/// let items = db.table(None, "items").unwrap();
+/// let infer = |sql: &str| -> Result<Option<String>, Box<dyn std::error::Error>> {
+///     let expr = Parser::new(&GenericDialect).try_with_sql(sql)?.parse_expr()?;
+///     Ok(infer_type(&expr, items, &db).map(str::to_owned))
+/// };
+/// assert_eq!(infer("price * 2")?.as_deref(), Some("NUMERIC"));
+/// assert_eq!(infer("id + 1")?.as_deref(), Some("INT"));
+/// assert_eq!(infer("length(name)")?.as_deref(), Some("INT"));
+/// assert_eq!(infer("coalesce(name, 'unnamed')")?.as_deref(), Some("TEXT"));
+/// assert_eq!(infer("CAST(id AS BIGINT)")?.as_deref(), Some("BIGINT"));
+/// assert_eq!(infer("created_at < now()")?.as_deref(), Some("BOOLEAN"));
+/// assert_eq!(infer("NULL")?, None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn infer_type<'db, DB: DatabaseLike>(
+    expr: &'db Expr,
+    table: &'db DB::Table,
+    database: &'db DB,
+) -> Option<&'db str> {
+    match expr {
+        Expr::Identifier(ident) => column_type(&ident.value, table, database),
+        Expr::CompoundIdentifier(idents) => column_type(&idents.last()?.value, table, database),
+        Expr::Value(value) => literal_type(&value.value),
+        Expr::Nested(inner)
+        | Expr::UnaryOp { op: UnaryOperator::Minus | UnaryOperator::Plus, expr: inner } => {
+            infer_type(inner, table, database)
+        }
+        Expr::Cast { data_type, .. } => try_normalize_sqlparser_type(data_type),
+        Expr::BinaryOp { left, op, right } => {
+            binary_operation_type(left, op, right, table, database)
+        }
+        Expr::UnaryOp { op: UnaryOperator::Not, .. }
+        | Expr::IsNull(_)
+        | Expr::IsNotNull(_)
+        | Expr::IsTrue(_)
+        | Expr::IsNotTrue(_)
+        | Expr::IsFalse(_)
+        | Expr::IsNotFalse(_)
+        | Expr::IsUnknown(_)
+        | Expr::IsNotUnknown(_)
+        | Expr::IsDistinctFrom(..)
+        | Expr::IsNotDistinctFrom(..)
+        | Expr::InList { .. }
+        | Expr::InSubquery { .. }
+        | Expr::Between { .. }
+        | Expr::Like { .. }
+        | Expr::ILike { .. }
+        | Expr::SimilarTo { .. }
+        | Expr::Exists { .. } => Some(BOOLEAN),
+        Expr::Case { conditions, else_result, .. } => conditions
+            .iter()
+            .map(|when| &when.result)
+            .chain(else_result.as_deref())
+            .find_map(|result| infer_type(result, table, database)),
+        Expr::Function(function) => function_type(function, table, database),
+        _ => None,
+    }
+}
+
+/// Returns the data type of the column of the table with the provided name.
+fn column_type<'db, DB: DatabaseLike>(
+    name: &str,
+    table: &'db DB::Table,
+    database: &'db DB,
+) -> Option<&'db str> {
+    table.column(name, database).map(|column| column.data_type(database))
+}
+
+/// Returns the data type of a literal value.
+fn literal_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Number(number, _) => Some(if number.contains(['.', 'e', 'E']) {
+            "NUMERIC"
+        } else if number.parse::<i32>().is_ok() {
+            "INT"
+        } else if number.parse::<i64>().is_ok() {
+            "BIGINT"
+        } else {
+            "NUMERIC"
+        }),
+        Value::SingleQuotedString(_)
+        | Value::EscapedStringLiteral(_)
+        | Value::NationalStringLiteral(_)
+        | Value::DollarQuotedString(_) => Some(TEXT),
+        Value::Boolean(_) => Some(BOOLEAN),
+        _ => None,
+    }
+}
+
+/// Returns whether the normalized data type is numeric.
+pub(crate) fn is_numeric_type(data_type: &str) -> bool {
+    numeric_rank(data_type).is_some()
+}
+
+/// Returns the rank of a numeric data type in [`NUMERIC_PROMOTION`], wider
+/// types having higher ranks.
+pub(crate) fn numeric_rank(data_type: &str) -> Option<usize> {
+    NUMERIC_PROMOTION
+        .iter()
+        .position(|synonyms| synonyms.iter().any(|numeric| numeric.eq_ignore_ascii_case(data_type)))
+}
+
+/// Returns the data type produced by a binary operation.
+fn binary_operation_type<'db, DB: DatabaseLike>(
+    left: &'db Expr,
+    op: &BinaryOperator,
+    right: &'db Expr,
+    table: &'db DB::Table,
+    database: &'db DB,
+) -> Option<&'db str> {
+    match op {
+        BinaryOperator::Eq
+        | BinaryOperator::NotEq
+        | BinaryOperator::Lt
+        | BinaryOperator::LtEq
+        | BinaryOperator::Gt
+        | BinaryOperator::GtEq
+        | BinaryOperator::And
+        | BinaryOperator::Or
+        | BinaryOperator::Xor
+        | BinaryOperator::PGRegexMatch
+        | BinaryOperator::PGRegexIMatch
+        | BinaryOperator::PGRegexNotMatch
+        | BinaryOperator::PGRegexNotIMatch
+        | BinaryOperator::PGLikeMatch
+        | BinaryOperator::PGILikeMatch
+        | BinaryOperator::PGNotLikeMatch
+        | BinaryOperator::PGNotILikeMatch => Some(BOOLEAN),
+        BinaryOperator::StringConcat => Some(TEXT),
+        BinaryOperator::Plus
+        | BinaryOperator::Minus
+        | BinaryOperator::Multiply
+        | BinaryOperator::Divide
+        | BinaryOperator::Modulo => {
+            // An operand of unknown type, such as `NULL`, does not change the
+            // type of the other one.
+            let operands = [infer_type(left, table, database), infer_type(right, table, database)]
+                .into_iter()
+                .flatten()
+                .map(|operand| numeric_rank(operand).map(|rank| (rank, operand)))
+                .collect::<Option<Vec<(usize, &str)>>>()?;
+            operands.into_iter().max_by_key(|(rank, _)| *rank).map(|(_, operand)| operand)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the return type of a function call, resolving the polymorphic
+/// return types of the built-in functions to the type of their arguments.
+fn function_type<'db, DB: DatabaseLike>(
+    function: &'db Function,
+    table: &'db DB::Table,
+    database: &'db DB,
+) -> Option<&'db str> {
+    let arguments: Vec<Option<&'db str>> = match &function.args {
+        FunctionArguments::List(list) => list
+            .args
+            .iter()
+            .map(|argument| match argument {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => {
+                    infer_type(expr, table, database)
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let (name, quoted) = object_name_last_part(&function.name)?;
+    let casing = database.dialect().identifier_casing();
+    let overloads: Vec<&DB::Function> = database
+        .functions()
+        .filter(|overload| casing.matches(overload.name(), overload.name_is_quoted(), name, quoted))
+        .collect();
+    let same_arity: Vec<&DB::Function> = overloads
+        .iter()
+        .copied()
+        .filter(|overload| overload.argument_type_names(database).count() == arguments.len())
+        .collect();
+    // Overloads whose argument types match the inferred ones are preferred,
+    // falling back to the ones taking as many arguments and then to the fully
+    // polymorphic ones, such as the variadic `coalesce`.
+    let overload = same_arity
+        .iter()
+        .find(|overload| {
+            overload.argument_type_names(database).zip(&arguments).all(|(declared, inferred)| {
+                declared == ANY || inferred.is_none_or(|inferred| inferred == declared)
+            })
+        })
+        .or_else(|| same_arity.first())
+        .or_else(|| {
+            overloads.iter().find(|overload| {
+                overload.argument_type_names(database).all(|declared| declared == ANY)
+            })
+        })?;
+
+    let return_type = overload.return_type_name(database)?;
+    if return_type == ANY { arguments.into_iter().flatten().next() } else { Some(return_type) }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+    use super::infer_type;
+    use crate::prelude::*;
+
+    /// Infers the data type of the expression on the `items` table.
+    fn infer(db: &ParserDB, sql: &str) -> Option<alloc::string::String> {
+        let expr = Parser::new(&GenericDialect).try_with_sql(sql).unwrap().parse_expr().unwrap();
+        let items = db.table(None, "items").unwrap();
+        infer_type(&expr, items, db).map(alloc::string::ToString::to_string)
+    }
+
+    #[test]
+    fn test_infer_type_of_operators() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE items (id SMALLINT, quantity BIGINT, weight REAL, name TEXT);",
+        )
+        .unwrap();
+        assert_eq!(infer(&db, "id + quantity").as_deref(), Some("BIGINT"));
+        assert_eq!(infer(&db, "quantity * weight").as_deref(), Some("REAL"));
+        assert_eq!(infer(&db, "-(id + NULL)").as_deref(), Some("SMALLINT"));
+        assert_eq!(infer(&db, "id + 1.5").as_deref(), Some("NUMERIC"));
+        assert_eq!(infer(&db, "id + 3000000000").as_deref(), Some("BIGINT"));
+        assert_eq!(infer(&db, "name || id").as_deref(), Some("TEXT"));
+        assert_eq!(infer(&db, "name + 1"), None);
+        assert_eq!(
+            infer(&db, "name IS NOT NULL AND id BETWEEN 1 AND 5").as_deref(),
+            Some("BOOLEAN")
+        );
+        assert_eq!(infer(&db, "items.weight").as_deref(), Some("REAL"));
+        assert_eq!(infer(&db, "missing"), None);
+        assert_eq!(infer(&db, "CASE WHEN id > 0 THEN NULL ELSE name END").as_deref(), Some("TEXT"));
+    }
+
+    #[test]
+    fn test_infer_type_of_function_calls() {
+        let db = ParserDB::parse::<GenericDialect>(
+            "CREATE TABLE items (id INT, name TEXT);
+             CREATE FUNCTION label(x INT) RETURNS TEXT AS 'SELECT x::TEXT;';
+             CREATE FUNCTION label(x TEXT) RETURNS VARCHAR AS 'SELECT x;';",
+        )
+        .unwrap();
+        assert_eq!(infer(&db, "label(id)").as_deref(), Some("TEXT"));
+        assert_eq!(infer(&db, "label(name)").as_deref(), Some("VARCHAR"));
+        assert_eq!(infer(&db, "count(*)").as_deref(), Some("BIGINT"));
+        assert_eq!(infer(&db, "max(id)").as_deref(), Some("INT"));
+        assert_eq!(infer(&db, "coalesce(NULL, name, 'x')").as_deref(), Some("TEXT"));
+        assert_eq!(infer(&db, "unknown_function(id)"), None);
+    }
+}
@@ -0,0 +1,20 @@
+//! Submodule providing a stable, non-generic façade over [`ParserDB`].
+//!
+//! [`ParserDB`] is an alias of [`GenericDB`] with one type parameter per kind
+//! of database object, so that its full type changes whenever a new kind of
+//! object is tracked. Consumers which only need to inspect a parsed schema
+//! can instead rely on [`Schema`] and on the views it hands out, whose
+//! signatures do not mention any of those type parameters and are kept stable
+//! within this version of the API.
+//!
+//! The views are plain `Copy` handles borrowing the schema, so they can be
+//! stored, boxed or passed around without naming the traits of the crate.
+//!
+//! [`ParserDB`]: crate::structs::ParserDB
+//! [`GenericDB`]: crate::structs::GenericDB
+
+mod schema;
+mod views;
+
+pub use schema::Schema;
+pub use views::{Column, ForeignKey, Index, Table, View};
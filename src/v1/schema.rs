@@ -0,0 +1,148 @@
+//! Submodule defining the `Schema` façade over a parsed database.
+
+use sqlparser::dialect::Dialect;
+
+use super::{Table, View};
+use crate::{errors::Error, structs::ParserDB, traits::DatabaseLike};
+
+/// A parsed database schema, exposed through non-generic methods.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sql_traits::v1::Schema;
+/// use sqlparser::dialect::PostgreSqlDialect;
+///
+/// let schema = Schema::parse::<PostgreSqlDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL);
+///      CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+///      CREATE VIEW authors AS SELECT name FROM users;",
+/// )?;
+/// let tables: Vec<&str> = schema.tables().map(|table| table.name()).collect();
+/// assert_eq!(tables, ["posts", "users"]);
+///
+/// let posts = schema.table(None, "posts").unwrap();
+/// let foreign_key = posts.foreign_keys().next().unwrap();
+/// assert_eq!(foreign_key.referenced_table().unwrap().name(), "users");
+/// assert_eq!(schema.views().next().unwrap().name(), "authors");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schema {
+    /// The underlying database.
+    database: ParserDB,
+}
+
+impl Schema {
+    /// Parses the schema from the provided SQL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL cannot be parsed or describes an invalid
+    /// schema, as [`ParserDB::parse`] does.
+    pub fn parse<D: Dialect + Default + 'static>(sql: &str) -> Result<Self, Error> {
+        ParserDB::parse::<D>(sql).map(Self::from)
+    }
+
+    /// Returns the underlying database, for the analyses not covered by the
+    /// façade.
+    #[must_use]
+    #[inline]
+    pub fn as_parser_db(&self) -> &ParserDB {
+        &self.database
+    }
+
+    /// Returns the underlying database, consuming the façade.
+    #[must_use]
+    #[inline]
+    pub fn into_parser_db(self) -> ParserDB {
+        self.database
+    }
+
+    /// Returns the name of the catalog of the schema.
+    #[must_use]
+    #[inline]
+    pub fn catalog_name(&self) -> &str {
+        self.database.catalog_name()
+    }
+
+    /// Returns the tables of the schema, sorted by schema and name.
+    pub fn tables(&self) -> impl Iterator<Item = Table<'_>> {
+        self.database.tables().map(|table| Table::new(&self.database, table))
+    }
+
+    /// Returns the table with the provided name, optionally qualified by the
+    /// name of its schema.
+    #[must_use]
+    pub fn table(&self, schema: Option<&str>, name: &str) -> Option<Table<'_>> {
+        self.database.table(schema, name).map(|table| Table::new(&self.database, table))
+    }
+
+    /// Returns the views of the schema, sorted by schema and name.
+    pub fn views(&self) -> impl Iterator<Item = View<'_>> {
+        self.database.views().map(View::new)
+    }
+
+    /// Returns the view with the provided name, optionally qualified by the
+    /// name of its schema.
+    #[must_use]
+    pub fn view(&self, schema: Option<&str>, name: &str) -> Option<View<'_>> {
+        self.database.view(schema, name).map(View::new)
+    }
+}
+
+impl From<ParserDB> for Schema {
+    #[inline]
+    fn from(database: ParserDB) -> Self {
+        Self { database }
+    }
+}
+
+impl From<Schema> for ParserDB {
+    #[inline]
+    fn from(schema: Schema) -> Self {
+        schema.database
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use sqlparser::dialect::PostgreSqlDialect;
+
+    use super::Schema;
+
+    #[test]
+    fn test_views_expose_the_table_structure() {
+        let schema = Schema::parse::<PostgreSqlDialect>(
+            "CREATE TABLE users (id INT PRIMARY KEY, email TEXT NOT NULL, score INT DEFAULT 0);
+             CREATE INDEX users_email ON users (email);
+             CREATE MATERIALIZED VIEW emails AS SELECT email FROM users;",
+        )
+        .unwrap();
+        let users = schema.table(None, "users").unwrap();
+        assert_eq!(users.schema(), None);
+
+        let columns: Vec<&str> = users.columns().map(|column| column.name()).collect();
+        assert_eq!(columns, ["id", "email", "score"]);
+        let primary_key: Vec<&str> = users.primary_key_columns().map(|c| c.name()).collect();
+        assert_eq!(primary_key, ["id"]);
+
+        let email = users.column("email").unwrap();
+        assert!(!email.is_nullable());
+        assert_eq!(email.table().name(), "users");
+        let score = users.column("score").unwrap();
+        assert!(score.is_nullable());
+        assert_eq!(score.default_value().as_deref(), Some("0"));
+
+        let index = users.indices().next().unwrap();
+        assert_eq!(index.name(), Some("users_email"));
+        assert_eq!(index.columns().map(|column| column.name()).collect::<Vec<_>>(), ["email"]);
+
+        assert!(schema.view(None, "emails").unwrap().is_materialized());
+        assert!(schema.into_parser_db().table(None, "users").is_some());
+    }
+}
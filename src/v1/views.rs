@@ -0,0 +1,290 @@
+//! Submodule defining the views handed out by the `Schema` façade.
+
+use alloc::string::String;
+
+use crate::{
+    structs::ParserDB,
+    traits::{ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, TableLike, ViewLike},
+};
+
+/// The table definition stored by [`ParserDB`].
+type TableDef = <ParserDB as DatabaseLike>::Table;
+/// The column definition stored by [`ParserDB`].
+type ColumnDef = <ParserDB as DatabaseLike>::Column;
+/// The foreign key definition stored by [`ParserDB`].
+type ForeignKeyDef = <ParserDB as DatabaseLike>::ForeignKey;
+/// The index definition stored by [`ParserDB`].
+type IndexDef = <ParserDB as DatabaseLike>::Index;
+/// The view definition stored by [`ParserDB`].
+type ViewDef = <ParserDB as DatabaseLike>::View;
+
+/// A table of a [`Schema`](super::Schema).
+#[derive(Debug, Clone, Copy)]
+pub struct Table<'db> {
+    /// The database the table belongs to.
+    database: &'db ParserDB,
+    /// The table.
+    table: &'db TableDef,
+}
+
+impl<'db> Table<'db> {
+    /// Creates the view of a table of the provided database.
+    pub(super) fn new(database: &'db ParserDB, table: &'db TableDef) -> Self {
+        Self { database, table }
+    }
+
+    /// Returns the name of the table.
+    #[must_use]
+    #[inline]
+    pub fn name(self) -> &'db str {
+        self.table.table_name()
+    }
+
+    /// Returns the name of the schema of the table, if it is qualified.
+    #[must_use]
+    #[inline]
+    pub fn schema(self) -> Option<&'db str> {
+        self.table.table_schema()
+    }
+
+    /// Returns the documentation of the table, if any.
+    #[must_use]
+    #[inline]
+    pub fn doc(self) -> Option<&'db str> {
+        self.table.table_doc(self.database)
+    }
+
+    /// Returns the columns of the table, in declaration order.
+    pub fn columns(self) -> impl Iterator<Item = Column<'db>> {
+        TableLike::columns(self.table, self.database)
+            .map(move |column| Column::new(self.database, column))
+    }
+
+    /// Returns the column of the table with the provided name.
+    #[must_use]
+    pub fn column(self, name: &str) -> Option<Column<'db>> {
+        TableLike::column(self.table, name, self.database)
+            .map(|column| Column::new(self.database, column))
+    }
+
+    /// Returns the columns of the primary key of the table.
+    pub fn primary_key_columns(self) -> impl Iterator<Item = Column<'db>> {
+        self.table
+            .primary_key_columns(self.database)
+            .map(move |column| Column::new(self.database, column))
+    }
+
+    /// Returns the foreign keys declared by the table.
+    pub fn foreign_keys(self) -> impl Iterator<Item = ForeignKey<'db>> {
+        TableLike::foreign_keys(self.table, self.database)
+            .map(move |foreign_key| ForeignKey { database: self.database, foreign_key })
+    }
+
+    /// Returns the indices created on the table.
+    pub fn indices(self) -> impl Iterator<Item = Index<'db>> {
+        TableLike::indices(self.table, self.database)
+            .map(move |index| Index { database: self.database, index })
+    }
+
+    /// Returns whether row-level security is enabled on the table.
+    #[must_use]
+    #[inline]
+    pub fn has_row_level_security(self) -> bool {
+        self.table.has_row_level_security(self.database)
+    }
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'db> {
+    /// The database the column belongs to.
+    database: &'db ParserDB,
+    /// The column.
+    column: &'db ColumnDef,
+}
+
+impl<'db> Column<'db> {
+    /// Creates the view of a column of the provided database.
+    fn new(database: &'db ParserDB, column: &'db ColumnDef) -> Self {
+        Self { database, column }
+    }
+
+    /// Returns the name of the column.
+    #[must_use]
+    #[inline]
+    pub fn name(self) -> &'db str {
+        self.column.column_name()
+    }
+
+    /// Returns the data type of the column, as written in SQL.
+    #[must_use]
+    #[inline]
+    pub fn data_type(self) -> &'db str {
+        self.column.data_type(self.database)
+    }
+
+    /// Returns whether the column accepts `NULL` values.
+    #[must_use]
+    #[inline]
+    pub fn is_nullable(self) -> bool {
+        self.column.is_nullable(self.database)
+    }
+
+    /// Returns whether the values of the column are generated automatically.
+    #[must_use]
+    #[inline]
+    pub fn is_generated(self) -> bool {
+        self.column.is_generated()
+    }
+
+    /// Returns whether the column is part of the primary key of its table.
+    #[must_use]
+    #[inline]
+    pub fn is_primary_key(self) -> bool {
+        self.column.is_primary_key(self.database)
+    }
+
+    /// Returns the SQL default value of the column, if any.
+    #[must_use]
+    #[inline]
+    pub fn default_value(self) -> Option<String> {
+        self.column.default_value()
+    }
+
+    /// Returns the documentation of the column, if any.
+    #[must_use]
+    #[inline]
+    pub fn doc(self) -> Option<&'db str> {
+        self.column.column_doc(self.database)
+    }
+
+    /// Returns the table the column belongs to.
+    #[must_use]
+    #[inline]
+    pub fn table(self) -> Table<'db> {
+        Table::new(self.database, ColumnLike::table(self.column, self.database))
+    }
+}
+
+/// A foreign key of a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct ForeignKey<'db> {
+    /// The database the foreign key belongs to.
+    database: &'db ParserDB,
+    /// The foreign key.
+    foreign_key: &'db ForeignKeyDef,
+}
+
+impl<'db> ForeignKey<'db> {
+    /// Returns the name of the foreign key constraint, if it is named.
+    #[must_use]
+    #[inline]
+    pub fn name(self) -> Option<&'db str> {
+        self.foreign_key.foreign_key_name()
+    }
+
+    /// Returns the table declaring the foreign key.
+    #[must_use]
+    #[inline]
+    pub fn host_table(self) -> Table<'db> {
+        Table::new(self.database, self.foreign_key.host_table(self.database))
+    }
+
+    /// Returns the table referenced by the foreign key, or `None` if it is
+    /// not in the database.
+    #[must_use]
+    #[inline]
+    pub fn referenced_table(self) -> Option<Table<'db>> {
+        self.foreign_key
+            .referenced_table(self.database)
+            .map(|table| Table::new(self.database, table))
+    }
+
+    /// Returns the columns of the host table, in constraint order.
+    pub fn host_columns(self) -> impl Iterator<Item = Column<'db>> {
+        self.foreign_key
+            .host_columns(self.database)
+            .map(move |column| Column::new(self.database, column))
+    }
+
+    /// Returns the columns of the referenced table, in constraint order.
+    pub fn referenced_columns(self) -> impl Iterator<Item = Column<'db>> {
+        self.foreign_key
+            .referenced_columns(self.database)
+            .map(move |column| Column::new(self.database, column))
+    }
+
+    /// Returns whether deleting a referenced row deletes the referencing
+    /// rows.
+    #[must_use]
+    #[inline]
+    pub fn on_delete_cascade(self) -> bool {
+        self.foreign_key.on_delete_cascade(self.database)
+    }
+}
+
+/// An index created on a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct Index<'db> {
+    /// The database the index belongs to.
+    database: &'db ParserDB,
+    /// The index.
+    index: &'db IndexDef,
+}
+
+impl<'db> Index<'db> {
+    /// Returns the name of the index, if it is named.
+    #[must_use]
+    #[inline]
+    pub fn name(self) -> Option<&'db str> {
+        self.index.index_name()
+    }
+
+    /// Returns the table the index is created on.
+    #[must_use]
+    #[inline]
+    pub fn table(self) -> Table<'db> {
+        Table::new(self.database, IndexLike::table(self.index, self.database))
+    }
+
+    /// Returns the columns appearing in the index, in index order.
+    pub fn columns(self) -> impl Iterator<Item = Column<'db>> {
+        IndexLike::columns(self.index, self.database)
+            .map(move |column| Column::new(self.database, column))
+    }
+}
+
+/// A view of a [`Schema`](super::Schema).
+#[derive(Debug, Clone, Copy)]
+pub struct View<'db> {
+    /// The view.
+    view: &'db ViewDef,
+}
+
+impl<'db> View<'db> {
+    /// Creates the handle of a view of the database.
+    pub(super) fn new(view: &'db ViewDef) -> Self {
+        Self { view }
+    }
+
+    /// Returns the name of the view.
+    #[must_use]
+    #[inline]
+    pub fn name(self) -> &'db str {
+        self.view.view_name()
+    }
+
+    /// Returns the name of the schema of the view, if it is qualified.
+    #[must_use]
+    #[inline]
+    pub fn schema(self) -> Option<&'db str> {
+        self.view.view_schema()
+    }
+
+    /// Returns whether the view is materialized.
+    #[must_use]
+    #[inline]
+    pub fn is_materialized(self) -> bool {
+        self.view.is_materialized()
+    }
+}
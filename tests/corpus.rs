@@ -0,0 +1,81 @@
+//! Golden-file tests running the analyses of the crate on the example corpus
+//! shipped behind the `test-corpus` feature.
+#![cfg(feature = "test-corpus")]
+
+use sql_traits::{
+    corpus::{
+        CorpusDialect, MOBILE_NOTES, MULTI_TENANT, SAMPLE_TRACKING, SCHEMAS, render_table_dag,
+    },
+    prelude::*,
+};
+
+#[test]
+fn every_corpus_schema_matches_its_goldens() {
+    for schema in SCHEMAS {
+        let db = schema.parse().unwrap_or_else(|error| {
+            panic!("corpus schema `{}` must parse: {error}", schema.name())
+        });
+        if let Err(mismatch) = schema.verify(&db) {
+            panic!("{mismatch}");
+        }
+    }
+}
+
+#[test]
+fn corpus_schemas_have_unique_names() {
+    let mut names: Vec<&str> = SCHEMAS.iter().map(|schema| schema.name()).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), SCHEMAS.len());
+}
+
+#[test]
+fn multi_tenant_exposes_access_control() {
+    let db = MULTI_TENANT.parse().expect("multi-tenant schema parses");
+    assert_eq!(MULTI_TENANT.dialect(), CorpusDialect::PostgreSql);
+
+    let projects = db.table(Some("app"), "projects").expect("app.projects exists");
+    assert!(db.table_metadata(projects).expect("metadata exists").rls_enabled());
+    assert_eq!(db.policies().count(), 2);
+    assert_eq!(db.roles().count(), 3);
+    assert_eq!(db.triggers().count(), 1);
+}
+
+#[test]
+fn sample_tracking_keeps_self_references() {
+    let db = SAMPLE_TRACKING.parse().expect("sample-tracking schema parses");
+    let samples = db.table(None, "samples").expect("samples exists");
+    assert!(samples.foreign_keys(&db).any(|fk| fk.is_self_referential(&db)));
+}
+
+#[test]
+fn mobile_notes_is_sqlite() {
+    let db = MOBILE_NOTES.parse().expect("mobile schema parses");
+    assert_eq!(MOBILE_NOTES.dialect(), CorpusDialect::Sqlite);
+
+    let note_tags = db.table(None, "note_tags").expect("note_tags exists");
+    assert!(note_tags.has_composite_primary_key(&db));
+}
+
+#[test]
+fn verify_reports_mismatching_goldens() {
+    let db = MOBILE_NOTES.parse().expect("mobile schema parses");
+    let mismatch = MULTI_TENANT.verify(&db).expect_err("goldens of another schema must differ");
+    assert_eq!(mismatch.schema, "multi_tenant");
+    assert_eq!(mismatch.golden, "table_dag");
+    assert_eq!(mismatch.actual, MOBILE_NOTES.golden_table_dag());
+}
+
+#[test]
+fn render_table_dag_puts_foreign_key_cycles_on_one_level() {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id INT PRIMARY KEY, team_id INT);
+        CREATE TABLE teams (id INT PRIMARY KEY, owner_id INT REFERENCES users (id));
+        ALTER TABLE users ADD FOREIGN KEY (team_id) REFERENCES teams (id);
+        CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+        ",
+    )
+    .expect("cyclic schema parses");
+    assert_eq!(render_table_dag(&db), "0: teams, users\n1: posts\n");
+}
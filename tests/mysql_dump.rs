@@ -0,0 +1,107 @@
+//! Tests parsing the output of `mysqldump`, checking that the MySQL-specific
+//! column properties, table options and inline index definitions make it
+//! into the model.
+
+use sql_traits::prelude::*;
+use sqlparser::{ast::CreateTable, dialect::MySqlDialect};
+
+const DUMP: &str = "
+-- MySQL dump 10.13  Distrib 8.0.36, for Linux (x86_64)
+--
+-- Host: localhost    Database: library
+-- ------------------------------------------------------
+-- Server version	8.0.36
+
+--
+-- Table structure for table `authors`
+--
+
+DROP TABLE IF EXISTS `authors`;
+CREATE TABLE `authors` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `name` varchar(255) COLLATE utf8mb4_unicode_ci NOT NULL,
+  `is_active` tinyint(1) NOT NULL DEFAULT '1',
+  `created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_authors_name` (`name`)
+) ENGINE=InnoDB AUTO_INCREMENT=3 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+--
+-- Dumping data for table `authors`
+--
+
+INSERT INTO `authors` VALUES (1,'Ada Lovelace',1,'2024-01-01 00:00:00'),(2,'Alan Turing',1,'2024-01-02 00:00:00');
+
+--
+-- Table structure for table `books`
+--
+
+DROP TABLE IF EXISTS `books`;
+CREATE TABLE `books` (
+  `id` bigint unsigned NOT NULL AUTO_INCREMENT,
+  `author_id` int NOT NULL,
+  `isbn` char(13) NOT NULL,
+  `title` varchar(255) NOT NULL,
+  `published_on` date DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_books_isbn` (`isbn`),
+  KEY `idx_books_author` (`author_id`),
+  KEY `idx_books_author_title` (`author_id`,`title`),
+  CONSTRAINT `books_ibfk_1` FOREIGN KEY (`author_id`) REFERENCES `authors` (`id`) ON DELETE CASCADE
+) ENGINE=MyISAM DEFAULT CHARSET=utf8mb4;
+";
+
+#[test]
+fn mysqldump_keeps_auto_increment_columns() {
+    let db = ParserDB::parse::<MySqlDialect>(DUMP).expect("dump parses");
+    let authors = db.table(None, "authors").expect("authors exists");
+    let books = db.table(None, "books").expect("books exists");
+
+    let id = authors.column("id", &db).expect("authors.id exists");
+    assert!(id.is_auto_generated(&db));
+    assert!(!authors.column("name", &db).expect("authors.name exists").is_auto_generated(&db));
+    assert!(books.column("id", &db).expect("books.id exists").is_auto_generated(&db));
+    assert_eq!(
+        authors.column("is_active", &db).expect("authors.is_active exists").normalized_type(&db),
+        "BOOLEAN"
+    );
+}
+
+#[test]
+fn mysqldump_keeps_table_options() {
+    let db = ParserDB::parse::<MySqlDialect>(DUMP).expect("dump parses");
+    let authors = db.table(None, "authors").expect("authors exists");
+    let books = db.table(None, "books").expect("books exists");
+
+    assert_eq!(authors.storage_engine().as_deref(), Some("InnoDB"));
+    assert_eq!(books.storage_engine().as_deref(), Some("MyISAM"));
+
+    let options = authors.table_options();
+    assert!(options.contains(&("AUTO_INCREMENT".to_string(), "3".to_string())));
+    assert!(options.contains(&("DEFAULT CHARSET".to_string(), "utf8mb4".to_string())));
+    assert!(options.contains(&("COLLATE".to_string(), "utf8mb4_unicode_ci".to_string())));
+}
+
+#[test]
+fn mysqldump_keeps_inline_indices() {
+    let db = ParserDB::parse::<MySqlDialect>(DUMP).expect("dump parses");
+    let authors = db.table(None, "authors").expect("authors exists");
+    let books = db.table(None, "books").expect("books exists");
+
+    let index_names = |table: &CreateTable| -> Vec<String> {
+        table.indices(&db).filter_map(|index| index.index_name().map(str::to_string)).collect()
+    };
+    assert_eq!(index_names(authors), ["idx_authors_name"]);
+    assert_eq!(index_names(books), ["idx_books_author", "idx_books_author_title"]);
+
+    let composite = books
+        .indices(&db)
+        .find(|index| index.index_name() == Some("idx_books_author_title"))
+        .expect("composite index exists");
+    let columns: Vec<&str> = composite.columns(&db).map(|column| column.column_name()).collect();
+    assert_eq!(columns, ["author_id", "title"]);
+
+    // `UNIQUE KEY` and `PRIMARY KEY` remain unique indices.
+    assert_eq!(books.unique_indices(&db).count(), 2);
+    assert_eq!(books.foreign_keys(&db).count(), 1);
+}
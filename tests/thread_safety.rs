@@ -1,6 +1,6 @@
 //! Thread-safety integration tests for `ParserDB`.
 
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use sql_traits::prelude::*;
 
@@ -9,6 +9,28 @@ fn assert_send_sync<T: Send + Sync>() {}
 #[test]
 fn parser_db_is_send_sync() {
     assert_send_sync::<ParserDB>();
+    assert_send_sync::<ParserDBBuilder>();
+}
+
+#[test]
+fn parser_db_can_be_stored_in_once_lock() {
+    static SCHEMA: OnceLock<ParserDB> = OnceLock::new();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let db = SCHEMA.get_or_init(|| {
+                    ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY);")
+                        .expect("Failed to parse SQL")
+                });
+                assert!(db.table(None, "users").is_some());
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().expect("Thread should complete without panic");
+    }
 }
 
 #[test]